@@ -0,0 +1,126 @@
+//! Criterion benchmark comparing attribute style lookups.
+//!
+//! Lints once classified attributes as inner or outer by reading the source
+//! snippet behind each attribute's span. The benchmark builds a source file
+//! with thousands of attributes and measures that approach against
+//! [`whitaker::hir::attribute_kind`], which reads the style rustc recorded in
+//! HIR. Run it with `make bench`.
+#![feature(rustc_private)]
+
+// The benchmark uses the compiler crates directly, so it must link the
+// compiler's `rustc_driver` library like the lint crates do.
+extern crate rustc_driver;
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rustc_ast::AttrStyle;
+use rustc_ast::ast::AttrId;
+use rustc_hir as hir;
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+use rustc_span::{BytePos, DUMMY_SP, FileName, Span, Symbol, create_default_session_globals_then};
+use whitaker::hir::attribute_kind;
+
+/// Attributes in the generated fixture.
+const ATTRIBUTES: usize = 4_000;
+
+/// Attribute lines cycled through by the fixture, with their styles.
+const LINES: [(&str, AttrStyle); 4] = [
+    ("#[inline]\n", AttrStyle::Outer),
+    ("#![allow(dead_code)]\n", AttrStyle::Inner),
+    ("#[must_use]\n", AttrStyle::Outer),
+    ("#![warn(missing_docs)]\n", AttrStyle::Inner),
+];
+
+/// A source map holding the fixture and the attributes lowered from it.
+struct Fixture {
+    source_map: SourceMap,
+    attributes: Vec<hir::Attribute>,
+}
+
+fn fixture() -> Fixture {
+    let source_map = SourceMap::new(FilePathMapping::empty());
+    let lines: Vec<(&str, AttrStyle)> = LINES.iter().copied().cycle().take(ATTRIBUTES).collect();
+    let source: String = lines.iter().map(|(line, _)| *line).collect();
+    let file = source_map.new_source_file(FileName::Custom(String::from("attributes")), source);
+
+    let mut offset = file.start_pos;
+    let attributes = lines
+        .iter()
+        .map(|(line, style)| {
+            let width = u32::try_from(line.trim_end().len()).unwrap_or(u32::MAX);
+            let span = Span::with_root_ctxt(offset, offset + BytePos(width));
+            offset = offset + BytePos(u32::try_from(line.len()).unwrap_or(u32::MAX));
+            unparsed(span, *style)
+        })
+        .collect();
+    Fixture {
+        source_map,
+        attributes,
+    }
+}
+
+fn unparsed(span: Span, style: AttrStyle) -> hir::Attribute {
+    hir::Attribute::Unparsed(Box::new(hir::AttrItem {
+        path: hir::AttrPath {
+            segments: vec![Symbol::intern("allow")].into_boxed_slice(),
+            span: DUMMY_SP,
+        },
+        args: hir::AttrArgs::Empty,
+        id: hir::HashIgnoredAttrId {
+            attr_id: AttrId::from_u32(0),
+        },
+        style,
+        span,
+    }))
+}
+
+/// Counts inner attributes by re-reading each attribute's source text.
+fn inner_by_snippet(fixture: &Fixture) -> usize {
+    fixture
+        .attributes
+        .iter()
+        .filter(|attr| {
+            let hir::Attribute::Unparsed(item) = attr else {
+                return false;
+            };
+            fixture
+                .source_map
+                .span_to_snippet(item.span)
+                .is_ok_and(|snippet| snippet.starts_with("#!"))
+        })
+        .count()
+}
+
+/// Counts inner attributes from the style recorded in HIR.
+fn inner_by_hir_style(fixture: &Fixture) -> usize {
+    fixture
+        .attributes
+        .iter()
+        .filter(|attr| attribute_kind(attr).is_inner())
+        .count()
+}
+
+fn attribute_style(c: &mut Criterion) {
+    create_default_session_globals_then(|| {
+        let fixture = fixture();
+        assert_eq!(
+            inner_by_snippet(&fixture),
+            inner_by_hir_style(&fixture),
+            "both lookups should agree on the fixture"
+        );
+
+        let mut group = c.benchmark_group("attribute_style");
+        group.throughput(Throughput::Elements(ATTRIBUTES as u64));
+        group.bench_function(BenchmarkId::new("snippet", ATTRIBUTES), |b| {
+            b.iter(|| inner_by_snippet(black_box(&fixture)));
+        });
+        group.bench_function(BenchmarkId::new("hir_style", ATTRIBUTES), |b| {
+            b.iter(|| inner_by_hir_style(black_box(&fixture)));
+        });
+        group.finish();
+    });
+}
+
+criterion_group!(benches, attribute_style);
+criterion_main!(benches);
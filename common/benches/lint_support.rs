@@ -0,0 +1,68 @@
+//! Criterion benchmarks for the rustc-independent work behind lint passes.
+//!
+//! Each benchmark runs one shared analysis over the medium workload in
+//! `support/workload.rs`: localizer loading and diagnostic resolution, which
+//! every lint pays once per crate and once per finding, test-context
+//! detection, per-call context summaries with and without the per-body
+//! cache, and the duplicated block, data clump, and LCOM4 analyses.
+//! Run them with `make bench`; `tests/perf_budget.rs` enforces time budgets
+//! over the same inputs.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use whitaker_common::context::is_test_fn;
+use whitaker_common::data_clumps::{DataClumpThresholds, find_data_clumps};
+use whitaker_common::duplicated_blocks::{DuplicationThresholds, find_duplicated_blocks};
+use whitaker_common::i18n::get_localizer_for_lint;
+use whitaker_common::lcom4::cohesion_components;
+
+#[path = "support/workload.rs"]
+mod workload;
+
+fn localization(criterion: &mut Criterion) {
+    criterion.bench_function("localizer_for_lint", |bencher| {
+        bencher.iter(|| get_localizer_for_lint(black_box(workload::DIAGNOSTIC_LINT), None));
+    });
+    let localizer = get_localizer_for_lint(workload::DIAGNOSTIC_LINT, None);
+    criterion.bench_function("resolve_diagnostic", |bencher| {
+        bencher.iter(|| workload::resolve_diagnostic(black_box(&localizer)));
+    });
+}
+
+fn detection(criterion: &mut Criterion) {
+    let attribute_lists = workload::attribute_lists();
+    criterion.bench_function("test_context_detection", |bencher| {
+        bencher.iter(|| {
+            black_box(&attribute_lists)
+                .iter()
+                .filter(|attributes| is_test_fn(attributes))
+                .count()
+        });
+    });
+    criterion.bench_function("context_summaries_uncached", |bencher| {
+        bencher.iter(workload::summarise_sites_uncached);
+    });
+    criterion.bench_function("context_summaries_cached", |bencher| {
+        bencher.iter(workload::summarise_sites_cached);
+    });
+}
+
+fn analyses(criterion: &mut Criterion) {
+    let blocks = workload::blocks();
+    criterion.bench_function("duplicated_blocks", |bencher| {
+        bencher
+            .iter(|| find_duplicated_blocks(black_box(&blocks), DuplicationThresholds::default()));
+    });
+    let signatures = workload::signatures();
+    criterion.bench_function("data_clumps", |bencher| {
+        bencher.iter(|| find_data_clumps(black_box(&signatures), DataClumpThresholds::default()));
+    });
+    let methods = workload::methods();
+    criterion.bench_function("lcom4_components", |bencher| {
+        bencher.iter(|| cohesion_components(black_box(&methods)));
+    });
+}
+
+criterion_group!(lint_support, localization, detection, analyses);
+criterion_main!(lint_support);
@@ -0,0 +1,208 @@
+//! Deterministic inputs sized like a medium crate.
+//!
+//! The benchmarks and the performance budget test share these generators so
+//! that the budgets guard exactly what the benchmarks measure. "Medium" means
+//! a crate of a few thousand lines: hundreds of functions and statements,
+//! with enough repetition that each analysis has findings to report.
+
+use std::borrow::Cow;
+
+use whitaker_common::attributes::{Attribute, AttributeKind, AttributePath};
+use whitaker_common::context::{
+    ContextCache, ContextEntry, ContextKind, ContextPrefix, in_test_like_context,
+    in_test_like_context_with,
+};
+use whitaker_common::data_clumps::{FunctionSignature, Parameter};
+use whitaker_common::duplicated_blocks::{NormalizedBlock, NormalizedStatement};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::lcom4::{MethodInfo, MethodInfoBuilder};
+
+/// Number of functions in the medium workload.
+pub const FUNCTIONS: usize = 240;
+/// Number of statements in each function body.
+pub const STATEMENTS_PER_FUNCTION: usize = 16;
+/// Number of methods on the medium type used for cohesion analysis.
+pub const METHODS: usize = 80;
+/// Lint whose diagnostic is resolved when measuring localization.
+pub const DIAGNOSTIC_LINT: &str = "no_recursion";
+/// `expect` calls in the single function of the pathological context workload.
+pub const EXPECT_SITES: usize = 2_000;
+/// Modules nested around that function.
+pub const MODULE_DEPTH: usize = 12;
+
+const PARAMETER_POOL: &[(&str, &str)] = &[
+    ("x", "i32"),
+    ("y", "i32"),
+    ("width", "u32"),
+    ("height", "u32"),
+    ("name", "&str"),
+    ("config", "&Config"),
+    ("buffer", "&mut Vec<u8>"),
+    ("depth", "usize"),
+];
+
+const STATEMENT_KINDS: &[&str] = &["stmt:let", "stmt:semi", "stmt:expr"];
+
+const EXPRESSION_KINDS: &[&str] = &[
+    "expr:call",
+    "expr:method",
+    "expr:binary",
+    "expr:if",
+    "expr:match",
+    "expr:loop",
+    "expr:field",
+    "expr:closure",
+    "expr:assign",
+    "expr:return",
+];
+
+const OPERAND_TOKENS: &[&str] = &["local", "lit:int", "lit:str", "path", "field", "method"];
+
+/// Tokens per statement, enough for four statements to form a window.
+const TOKENS_PER_STATEMENT: usize = 8;
+
+/// Signatures drawing three to six parameters from a shared pool, so that
+/// recurring parameter groups form data clumps.
+#[must_use]
+pub fn signatures() -> Vec<FunctionSignature> {
+    (0..FUNCTIONS)
+        .map(|index| {
+            let arity = 3 + ((index / 7) & 3);
+            let parameters = PARAMETER_POOL
+                .iter()
+                .cycle()
+                .skip(index / 3)
+                .take(arity)
+                .map(|(name, ty)| Parameter::new(*name, *ty));
+            FunctionSignature::new(format!("function_{index}"), parameters)
+        })
+        .collect()
+}
+
+/// Function bodies of pseudo-random statements in the token vocabulary
+/// drivers emit. One function in eight shares the same body, so duplicates
+/// exist without dominating the input.
+#[must_use]
+pub fn blocks() -> Vec<NormalizedBlock> {
+    (0..FUNCTIONS)
+        .map(|owner| {
+            let body = if owner % 8 == 0 { 0 } else { owner };
+            let statements = (0..STATEMENTS_PER_FUNCTION)
+                .map(|index| statement(body * STATEMENTS_PER_FUNCTION + index))
+                .collect();
+            NormalizedBlock::new(owner, statements)
+        })
+        .collect()
+}
+
+fn statement(seed: usize) -> NormalizedStatement {
+    let mut state = seed;
+    let mut pick = |vocabulary: &[&'static str]| {
+        // Knuth's multiplicative hash spreads consecutive seeds apart.
+        state = state.wrapping_mul(2_654_435_761).wrapping_add(1) >> 3;
+        vocabulary[state % vocabulary.len()]
+    };
+    let kind = pick(STATEMENT_KINDS);
+    let expression = pick(EXPRESSION_KINDS);
+    let operands: Vec<&str> = (2..TOKENS_PER_STATEMENT)
+        .map(|_| pick(OPERAND_TOKENS))
+        .collect();
+    NormalizedStatement::new([kind, expression].into_iter().chain(operands))
+}
+
+/// Methods that each touch two of twenty fields and call a neighbour, giving
+/// several cohesion components.
+#[must_use]
+pub fn methods() -> Vec<MethodInfo> {
+    (0..METHODS)
+        .map(|index| {
+            let mut builder = MethodInfoBuilder::new(format!("method_{index}"));
+            builder.record_field_access(&format!("field_{}", index / 4), false);
+            builder.record_field_access(&format!("field_{}", index / 4 + 1), false);
+            builder.record_method_call(&format!("method_{}", index + 1), false);
+            builder.build()
+        })
+        .collect()
+}
+
+/// Attribute lists for every function, one in eight of them a test.
+#[must_use]
+pub fn attribute_lists() -> Vec<Vec<Attribute>> {
+    let outer = |path: &str| Attribute::new(AttributePath::from(path), AttributeKind::Outer);
+    (0..FUNCTIONS)
+        .map(|index| {
+            let mut attributes = vec![outer("doc"), outer("inline"), outer("must_use")];
+            if index & 7 == 0 {
+                attributes.push(outer("rstest"));
+            }
+            attributes
+        })
+        .collect()
+}
+
+/// Summarises the context of every `expect` call in one deeply nested
+/// function, collecting the ancestors outside its body again for each call.
+#[must_use]
+pub fn summarise_sites_uncached() -> usize {
+    (0..EXPECT_SITES)
+        .filter(|site| summarise_site(&body_prefix(), *site))
+        .count()
+}
+
+/// Summarises the same calls, collecting the ancestors outside the body once.
+#[must_use]
+pub fn summarise_sites_cached() -> usize {
+    let mut cache = ContextCache::new();
+    (0..EXPECT_SITES)
+        .filter(|site| summarise_site(cache.prefix_or_insert_with(0_usize, body_prefix), *site))
+        .count()
+}
+
+fn summarise_site(prefix: &ContextPrefix, site: usize) -> bool {
+    let inner: Vec<ContextEntry> = (0..=site % 4)
+        .map(|_| ContextEntry::new("block", ContextKind::Block, Vec::new()))
+        .collect();
+    let (entries, has_test_ancestry) = prefix.extend(inner, false);
+    has_test_ancestry || in_test_like_context_with(&entries, &[])
+}
+
+/// The ancestors outside the function's body: nested documented modules, an
+/// impl, and the function itself.
+fn body_prefix() -> ContextPrefix {
+    let outer = |path: &str| Attribute::new(AttributePath::from(path), AttributeKind::Outer);
+    let entries: Vec<ContextEntry> = (0..MODULE_DEPTH)
+        .map(|depth| {
+            ContextEntry::new(
+                format!("module_{depth}"),
+                ContextKind::Module,
+                vec![outer("doc"), outer("allow")],
+            )
+        })
+        .chain([
+            ContextEntry::new("impl", ContextKind::Impl, vec![outer("doc")]),
+            ContextEntry::function("load", vec![outer("doc"), outer("inline")]),
+        ])
+        .collect();
+    let has_test_ancestry = in_test_like_context(&entries);
+    ContextPrefix::new(entries, has_test_ancestry)
+}
+
+/// Resolves one localized diagnostic, as a lint does for each finding.
+#[must_use]
+pub fn resolve_diagnostic(localizer: &Localizer) -> DiagnosticMessageSet {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("function"), FluentValue::from("walk"));
+    args.insert(Cow::Borrowed("cycle"), FluentValue::from("walk -> walk"));
+    args.insert(Cow::Borrowed("kind"), FluentValue::from("direct"));
+    let resolution = MessageResolution {
+        lint_name: DIAGNOSTIC_LINT,
+        key: MessageKey::new(DIAGNOSTIC_LINT),
+        args: &args,
+    };
+    safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        DiagnosticMessageSet::new(String::new(), String::new(), String::new())
+    })
+}
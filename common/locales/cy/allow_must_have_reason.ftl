@@ -0,0 +1,11 @@
+## Dylai priodoleddau `#[allow(...)]` roi `reason` fel bod modd archwilio ataliadau.
+
+allow_must_have_reason = { $kind ->
+        [blank] Mae gan `{ $attribute }` `reason` gwag.
+       *[missing] Nid yw `{ $attribute }` yn dweud pam mae’r lint yn cael ei ganiatáu.
+    }
+    .note = Ni ellir archwilio ataliadau heb esboniad, ac ni all neb ddweud pryd mae’n ddiogel eu tynnu.
+    .help = { $comments ->
+        [accepted] Ychwanegwch `reason = "..."`, neu sylw `//` ar yr un llinell, yn esbonio pam nad yw’r lint yn berthnasol yma.
+       *[rejected] Ychwanegwch `reason = "..."` yn esbonio pam nad yw’r lint yn berthnasol yma.
+    }
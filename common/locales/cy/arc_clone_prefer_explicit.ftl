@@ -0,0 +1,6 @@
+## Dylid clonio gwerthoedd `Arc` ac `Rc` gyda `Arc::clone(&value)` yn hytrach na `.clone()`.
+
+arc_clone_prefer_explicit = Clôn o `{ $pointer }` wedi’i ysgrifennu fel `{ $receiver }.clone()`.
+    .note = Mae `{ $receiver }.clone()` yn darllen fel copi o’r gwerth a rennir, ond dim ond cynyddu cyfrif cyfeirio’r `{ $pointer }` y mae.
+    .help = Ysgrifennwch `{ $replacement }` fel bod copïo rhad y pwyntydd yn amlwg.
+    .suggestion = galwch `{ $pointer }::clone` yn benodol
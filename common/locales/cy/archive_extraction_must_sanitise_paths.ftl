@@ -0,0 +1,5 @@
+## Llwybrau cofnodion archif wedi’u huno â chyfeiriadur heb wiriad cyfyngu.
+
+archive_extraction_must_sanitise_paths = Llwybr cofnod archif wedi’i basio i `{ $method }` heb wiriad cyfyngu.
+    .note = Mae `{ $source }` yn dychwelyd y llwybr sydd wedi’i storio yn yr archif, a allai gynnwys `..` neu ddechrau wrth wraidd y system ffeiliau, felly gall `{ $method }` bwyntio y tu allan i’r cyfeiriadur allbwn.
+    .help = Gwrthodwch gofnodion y mae eu llwybr yn cynnwys cydrannau `..`, gwraidd, neu ragddodiad, neu canoneiddiwch y llwybr unedig a gwiriwch ei fod yn `starts_with` y cyfeiriadur allbwn.
@@ -0,0 +1,8 @@
+## Dylai dulliau nodwedd ddatgan `async fn` neu ddychwelyd `impl Future` yn hytrach na dyfodol mewn blwch pan fo’r gadwyn offer yn caniatáu.
+
+async_fn_returning_boxed_future_prefer_async_trait_or_impl = Mae’r dull `{ $method }` yn y nodwedd `{ $trait }` yn dychwelyd dyfodol mewn blwch.
+    .note = Mae `{ $return }` yn dyrannu cof ar bob galwad ac yn cuddio math y dyfodol, ac ers Rust 1.75 gall nodweddion ddatgan dulliau asyncronaidd yn uniongyrchol.
+    .help = { $form ->
+        [impl] Datganwch y dull fel `fn { $method }(...) -> impl Future<Output = { $output }> + Send`, sy’n cadw’r terfyn `Send`.
+       *[async] Datganwch y dull fel `async fn { $method }(...) -> { $output }`.
+    }
@@ -0,0 +1,5 @@
+## Dylai dolenni `for` sy’n aros am ddyfodolion nad ydynt yn dibynnu ar iteriadau cynharach eu rhedeg ar yr un pryd.
+
+await_in_loop_without_concurrency = Mae pob iteriad yn aros am ddyfodol a adeiladwyd o `{ $item }` cyn i’r iteriad nesaf ddechrau.
+    .note = Nid yw’r dyfodol yn dibynnu ar iteriadau cynharach, felly mae aros am y dyfodolion un ar y tro yn gadael dim ond un ohonynt yn gwneud cynnydd.
+    .help = Casglwch y dyfodolion ac arhoswch amdanynt gyda’i gilydd gyda `futures::future::join_all`, neu trowch yr eitemau’n ffrwd a defnyddiwch `buffer_unordered` i gyfyngu ar faint sy’n rhedeg ar unwaith.
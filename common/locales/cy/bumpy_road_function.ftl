@@ -10,11 +10,7 @@ bumpy_road_function = Mae sawl clwstwr o resymeg amodol nythiedig yn `{ $name }`
        *[other] Canfuwyd { $count } o "bumps" cymhlethdod uwchlaw’r trothwy { $threshold }.
     }
     .help = Tynnwch swyddogaethau cynorthwyol o’r rhanbarthau a amlygwyd i leihau’r cymhlethdod clwstredig.
-    .label = { $lines ->
-        [zero] Mae bump cymhlethdod { $index } yn ymestyn dros { $lines } o linellau.
-        [one] Mae bump cymhlethdod { $index } yn ymestyn dros { $lines } llinell.
-        [two] Mae bump cymhlethdod { $index } yn ymestyn dros { $lines } o linellau.
-        [few] Mae bump cymhlethdod { $index } yn ymestyn dros { $lines } o linellau.
-        [many] Mae bump cymhlethdod { $index } yn ymestyn dros { $lines } o linellau.
-       *[other] Mae bump cymhlethdod { $index } yn ymestyn dros { $lines } o linellau.
-    }
+    .bump = Mae bump cymhlethdod { $index } yn cwmpasu { $lines ->
+        [one] llinell { $start }
+       *[other] llinellau { $start }–{ $end }
+    }.
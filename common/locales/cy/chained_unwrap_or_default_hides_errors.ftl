@@ -0,0 +1,5 @@
+## Mae `unwrap_or_default` ar `Result` gyda gwall y gellir ei ddangos yn taflu’r methiant yn dawel.
+
+chained_unwrap_or_default_hides_errors = Mae `.unwrap_or_default()` yn taflu gwall `{ $error }` yn { $context }.
+    .note = Mae `{ $error }` yn gweithredu `Display`, felly bwriadwyd ei adrodd, ond mae `.unwrap_or_default()` yn disodli’r methiant â data diofyn sy’n edrych fel llwyddiant gwag.
+    .help = Lledaenwch y gwall gyda `?`, neu defnyddiwch `.unwrap_or_else(|error| ...)` i’w gofnodi cyn troi at werth diofyn.
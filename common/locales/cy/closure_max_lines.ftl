@@ -0,0 +1,5 @@
+## Dylai caeadau aros yn fyr.
+
+closure_max_lines = Mae corff y caead yn rhychwantu { $lines } llinell, sy’n fwy na’r terfyn o { $limit }.
+    .note = Mae caeadau hir yn cuddio rhesymeg sy’n haeddu enw a phrofion ei hun.
+    .help = Echdynnwch gorff y caead i swyddogaeth ag enw a’i phasio drwy lwybr neu ei galw o gaead byr.
@@ -0,0 +1,5 @@
+## Dylid dileu sylwadau sy’n cynnwys cod wedi’i droi’n sylw.
+
+commented_out_code = Mae’r sylw hwn yn cynnwys cod wedi’i droi’n sylw.
+    .note = Mae rheoli fersiynau eisoes yn cadw hanes cod sydd wedi’i ddileu.
+    .help = Dilëwch y cod sydd wedi’i droi’n sylw ac adferwch ef o reoli fersiynau os bydd ei angen eto.
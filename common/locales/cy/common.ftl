@@ -24,3 +24,31 @@ common-lint-count =
 
 #. Shown in diagnostics when referring to the preceding attribute name.
 common-attribute-fallback = y briodoledd flaenorol
+
+#. Yn disgrifio’r eitem sy’n amgáu safle diagnostig. Mae `$kind` yn un o
+#. function, method, closure, const, static, neu scope.
+common-enclosing-item = { $kind ->
+        [function] ffwythiant `{ $name }`
+        [method] dull `{ $name }`
+        [closure] caead o fewn `{ $name }`
+        [const] cysonyn `{ $name }`
+        [static] newidyn statig `{ $name }`
+       *[scope] y cwmpas o’i amgylch
+    }
+
+#. Yn cael ei adael ar fynegiad o fewn cynorthwyydd a restrir yn
+#. `blame_helpers`, y mae ei ganfyddiad yn cael ei adrodd ym mhob galwad iddo.
+common-blamed-helper = Mae’r canfyddiad yn y cynorthwyydd `{ $helper }`, a restrir yn `blame_helpers`, felly caiff ei adrodd lle gelwir y cynorthwyydd.
+
+#. Yn cael ei allyrru unwaith fesul lint a chrât pan ddaliodd
+#. `max_findings_per_lint_per_crate` ganfyddiadau yn ôl. Mae’r diagnostig yn
+#. pwyntio at y canfyddiad cyntaf na ddangoswyd.
+common-findings-suppressed = Cyrhaeddodd `{ $lint }` ei derfyn o { $limit } canfyddiad yn y crât hwn; ni ddangoswyd { $remaining } arall.
+    .note = Mae `max_findings_per_lint_per_crate` yn cyfyngu ar y canfyddiadau y mae pob lint yn eu hadrodd fesul crât. Mae’r diagnostig hwn yn pwyntio at y canfyddiad cyntaf na ddangoswyd.
+    .help = Codwch neu dilëwch `max_findings_per_lint_per_crate` yn `dylint.toml` i restru pob canfyddiad yn log yr adeiladu ac yn yr adroddiad JSON gan `whitaker check`.
+
+#. Yn cael ei allyrru unwaith fesul crât gan y gyfres pan fo `crate_summary`
+#. wedi’i alluogi. Mae cyfanswm pob lint yn dilyn fel nodyn ar wahân.
+common-crate-summary = Adroddodd Whitaker { $total } canfyddiad gan { $lints } lint yn y crât `{ $crate }`.
+    .note = Mae pob llinell yn rhoi cyfanswm lint ar gyfer y crât hwn, gan gynnwys canfyddiadau na ddangosodd `max_findings_per_lint_per_crate`.
+    .help = Gosodwch `crate_summary = false` yn `dylint.toml` i ddiffodd y crynodeb hwn.
@@ -0,0 +1,5 @@
+## Dylai meysydd `#[serde(default)]` mewn strwythurau ffurfweddu nodi eu gwerth rhagosodedig yn eu dogfennaeth.
+
+config_struct_fields_must_have_defaults_documented = Mae gan y maes `{ $field }` yn `{ $type }` werth rhagosodedig serde nad yw ei ddogfennaeth yn ei nodi.
+    .note = Ni all darllenwyr `{ $type }` weld pa werth y mae `{ $field }` yn ei gymryd pan fydd y ffurfweddiad yn ei hepgor.
+    .help = Nodwch y gwerth rhagosodedig yn sylw dogfennu'r maes, er enghraifft "Defaults to 3.".
@@ -0,0 +1,5 @@
+## Dylai adeiladwyr sy’n cymryd llawer o ddadleuon ddefnyddio adeiladydd.
+
+constructor_max_arguments = Mae `{ $constructor }` yn cymryd { $arguments } dadl, sy’n fwy na’r terfyn o { $limit }.
+    .note = Rhaid i alwyr roi pob dadl yn ei threfn, ac mae’n hawdd cyfnewid dadleuon o’r un math.
+    .help = Ychwanegwch adeiladydd ar gyfer `{ $type }`, neu grwpiwch y dadleuon mewn strwythur ffurfweddu.
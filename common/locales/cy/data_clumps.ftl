@@ -0,0 +1,5 @@
+## Dylai grwpiau o baramedrau sy’n teithio gyda’i gilydd ddod yn struct.
+
+data_clumps = Mae’r paramedrau { $parameters } yn ymddangos gyda’i gilydd mewn { $count } llofnod ffwythiant.
+    .note = Mae { $functions } hefyd yn cymryd yr un paramedrau.
+    .help = Grwpiwch { $parameters } mewn struct a phasiwch hwnnw yn lle hynny.
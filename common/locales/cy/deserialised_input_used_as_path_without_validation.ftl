@@ -0,0 +1,5 @@
+## Mewnbwn wedi’i ddad-gyfresu wedi’i basio i alwad system ffeiliau heb ei ddilysu.
+
+deserialised_input_used_as_path_without_validation = Mae mewnbwn wedi’i ddad-gyfresu yn cyrraedd `{ $sink }` heb ei ddilysu.
+    .note = Mae `{ $source }` yn datgodio data a ddewiswyd gan bwy bynnag a’i cynhyrchodd, felly gall y llwybr gynnwys `..` neu ddechrau wrth wraidd y system ffeiliau.
+    .help = Gwrthodwch lwybrau sydd â chydrannau `..`, gwraidd, neu ragddodiad cyn eu defnyddio, a rhestrwch y swyddogaeth wirio o dan `validators`.
@@ -0,0 +1,9 @@
+## Dylai enghreifftiau dogfennaeth `ignore` ddweud pam ar linell y ffens.
+
+doc_ignore_must_have_reason = Mae’r enghraifft ddogfennaeth a ffensiwyd â `{ $info }` yn cael ei hanwybyddu heb reswm.
+    .note = Nid yw enghraifft `ignore` byth yn cael ei chrynhoi na’i rhedeg, felly gall grwydro oddi wrth y cod y mae’n ei ddogfennu, ac ni all darllenwyr ddweud a oedd ei hepgor yn fwriadol.
+    .help = Rhowch y rheswm mewn cromfachau ar linell y ffens, fel yn `{ $info } (needs a running database)`, neu defnyddiwch `no_run` os yw’r enghraifft yn dal i grynhoi.
+
+doc_ignore_must_have_reason-crate = Mae { $ignored } o’r { $total } enghraifft ddogfennaeth yn y crate hwn yn cael eu hanwybyddu ({ $percent }%), uwchlaw’r terfyn o { $limit }%.
+    .note = Nid yw enghreifftiau a anwybyddir byth yn cael eu crynhoi, felly nid yw’r gyfran honno o ddogfennaeth y crate hwn yn cael ei gwirio.
+    .help = Gwnewch i enghreifftiau grynhoi gyda llinellau gosod cudd sy’n dechrau â `# `, neu marciwch nhw’n `no_run` pan na ddylent redeg.
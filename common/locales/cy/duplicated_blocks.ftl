@@ -0,0 +1,5 @@
+## Dylid echdynnu blociau bron yn unfath o ddatganiadau i ffwythiant a rennir.
+
+duplicated_blocks = Mae `{ $function }` yn ailadrodd { $statements } datganiad o `{ $original }`.
+    .note = Mae’r bloc yn `{ $original }` { $similarity }% yn debyg.
+    .help = Echdynnwch y datganiadau a rennir i ffwythiant cynorthwyol y gall y ddau ei alw.
@@ -0,0 +1,5 @@
+## Dylai gwallau I/O gael cyd-destun cyn i `?` eu trosi’n fath gwall cyfoethog.
+
+error_context_missing_on_io_operations = Mae `?` yn trosglwyddo’r gwall I/O o `{ $operation }` heb gyd-destun.
+    .note = Nid yw `std::io::Error` yn enwi’r weithred na’r llwybr a fethodd, a gall `{ $error }` gofnodi’r ddau.
+    .help = Ychwanegwch gyd-destun cyn `?`, er enghraifft gyda `.with_context(|| ...)`, gan enwi’r llwybr neu’r weithred.
@@ -0,0 +1,14 @@
+## Dylai mathau gwall cyhoeddus weithredu `Display` a `std::error::Error`.
+
+error_types_implement_error = Nid yw’r math gwall `{ $type }` yn gweithredu { $missing ->
+        [error] `std::error::Error`
+       *[both] `Display` na `std::error::Error`
+    }.
+    .note = { $role ->
+        [returned] `{ $type }` yw math gwall y swyddogaeth gyhoeddus `{ $function }`
+       *[named] Mae `{ $type }` yn gyhoeddus ac wedi’i enwi fel math gwall
+    }; mae ar goll { $missing ->
+        [error] `impl std::error::Error for { $type }`
+       *[both] `impl std::fmt::Display for { $type }` ac `impl std::error::Error for { $type }`
+    }.
+    .help = Deilliwch yr impls gyda `#[derive(thiserror::Error)]` a neges `#[error("...")]`, neu ysgrifennwch nhw â llaw, fel y gall galwyr argraffu’r gwall a’i ddefnyddio gyda `?`, `Box<dyn Error>`, a chadwyni ffynhonnell.
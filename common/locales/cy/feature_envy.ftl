@@ -0,0 +1,5 @@
+## Ni ddylai dulliau ddefnyddio math arall yn fwy na’u math eu hunain.
+
+feature_envy = Mae `{ $method }` yn defnyddio `{ $target }` yn fwy na’i fath ei hun ({ $foreign } mynediad yn erbyn { $own }).
+    .note = Mae dulliau sy’n gweithio’n bennaf â data math arall fel arfer yn perthyn i’r math hwnnw.
+    .help = Symudwch `{ $method }`, neu’r rhan sy’n gweithio â `{ $target }`, i `{ $target }`.
@@ -0,0 +1,5 @@
+## Dylai ail-allforion `pub use` sydd wedi’u gatio ar nodweddion gario `#[cfg_attr(docsrs, doc(cfg(...)))]` fel bod y ddogfennaeth yn dangos y gât.
+
+feature_gated_pub_use_must_have_cfg_doc = Nid oes gan yr ail-allforio sydd wedi’i gatio ar `{ $predicate }` briodoledd `doc(cfg(...))`.
+    .note = Mae dogfennaeth a adeiladwyd gyda phob nodwedd yn dangos yr ail-allforio heb ddweud bod arno angen `{ $predicate }`.
+    .help = Ychwanegwch `{ $attribute }` fel bod rustdoc yn labelu’r ail-allforio gyda’i gât.
@@ -3,3 +3,4 @@
 function_attrs_follow_docs = Rhaid i sylwadau doc ar { $subject } ddod o flaen priodoleddau allanol eraill.
     .note = Mae’r briodoledd { $attribute } yn ymddangos cyn y sylw doc.
     .help = Symudwch y sylw doc fel ei fod o flaen { $attribute } ar yr eitem.
+    .suggestion = symudwch y sylw doc uwchben { $attribute }
@@ -0,0 +1,5 @@
+## Ni ddylai swyddogaethau gael mwy o allanfeydd `return` a `?` na’r terfyn a osodwyd.
+
+function_max_return_points = Cafwyd { $total } pwynt dychwelyd yn { $context }, sy’n fwy na’r terfyn o { $limit }.
+    .note = Y tu allan i’w chymalau gwarchod mae gan y swyddogaeth { $returns } `return` a { $questions } `?`, ac mae pob un yn llwybr arall y mae’n rhaid i ddarllenwyr ei ddilyn.
+    .help = Tynnwch y camau rhwng allanfeydd i swyddogaethau cynorthwyol, neu cyfunwch wiriadau cysylltiedig fel bod gan y swyddogaeth lai o ffyrdd allan.
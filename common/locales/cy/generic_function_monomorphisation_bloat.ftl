@@ -0,0 +1,5 @@
+## Dylai swyddogaethau generig mawr a ddefnyddir gyda llawer o ddadleuon generig gwahanol ddirprwyo i swyddogaeth fewnol nad yw’n generig.
+
+generic_function_monomorphisation_bloat = Mae’r swyddogaeth generig `{ $name }` yn rhychwantu { $lines } llinell ac yn cael ei defnyddio gyda { $count } set wahanol o ddadleuon generig.
+    .note = Mae’r casglwr yn allyrru copi o’r corff cyfan ar gyfer pob set o ddadleuon generig, gan ychwanegu at amser adeiladu a maint y deuaidd.
+    .help = Symudwch y corff i swyddogaeth fewnol nad yw’n generig, a chadwch yn y lapiwr generig dim ond trosi’r dadleuon, fel `.as_ref()` neu `.into()`.
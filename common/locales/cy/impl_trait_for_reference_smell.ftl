@@ -0,0 +1,5 @@
+## Ni ddylid gweithredu nodweddion lleol ar gyfer `T` ac `&T` neu `&mut T` ill dau.
+
+impl_trait_for_reference_smell = Mae `{ $trait }` wedi’i weithredu ar gyfer `{ $self_ty }` yn ogystal ag ar gyfer `{ $referent }`.
+    .note = Mae’r gweithrediad ar gyfer `{ $referent }` yma. Mae galwadau dull yn dewis rhwng y ddau drwy gyfeirio awtomatig, felly gall yr un alwad gyrraedd y naill neu’r llall yn dibynnu ar sut y benthyciwyd y derbynnydd.
+    .help = Cadwch y gweithrediad ar gyfer `{ $referent }` yn unig a gadewch i alwyr ei gyrraedd drwy’r cyfeirnod, neu gwnewch i’r ddau ymddwyn yn union yr un fath.
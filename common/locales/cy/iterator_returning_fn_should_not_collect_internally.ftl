@@ -0,0 +1,8 @@
+## Dylai cynorthwywyr preifat ddychwelyd `impl Iterator` pan fo pob galwr yn ailadrodd dros y `Vec` a gasglwyd yn unig.
+
+iterator_returning_fn_should_not_collect_internally = Mae pob galwr i { $context } yn ailadrodd dros y `Vec<{ $item }>` y mae’n ei gasglu yn unig.
+    .note = Mae’r crate yn ei galw { $calls } { $calls ->
+        [one] waith
+       *[other] o weithiau
+    }, ac mae pob galwad yn cerdded drwy’r canlyniad unwaith heb ei fynegeio na’i gadw, felly dim ond i’w dreulio y caiff y fector ei ddyrannu.
+    .help = Dychwelwch `impl Iterator<Item = { $item }>` a thynnwch yr alwad `collect()` olaf fel bod eitemau’n llifo’n syth i’r galwyr.
@@ -0,0 +1,11 @@
+## Ni ddylai newidynnau lleol a dadleuon ddal gwerthoedd sy’n rhy fawr i’r pentwr.
+
+large_stack_values = { $kind ->
+        [argument] Mae’r ddadl
+       *[local] Mae’r newidyn lleol
+    } `{ $name }` yn { $context } yn { $size } beit, sy’n fwy na’r terfyn o { $limit }.
+    .note = Mae `{ $type }` yn defnyddio’r pentwr tra bo `{ $name }` yn fyw, ac mae pob symudiad yn copïo pob un o’r { $size } beit.
+    .help = { $kind ->
+        [argument] Cymerwch y ddadl drwy gyfeirnod neu fel `Box<{ $type }>`.
+       *[local] Dyrannwch y gwerth ar y domen, er enghraifft fel `Box<{ $type }>` neu `Vec`, fel mai dim ond pwyntydd sydd ar y pentwr.
+    }
@@ -0,0 +1,5 @@
+## Dylai crates lint sy’n cyfyngu eitemau i `dylint-driver` ddatgan modiwl bonyn ar gyfer adeiladau hebddo.
+
+lint_crate_missing_stub_for_non_driver_builds = Mae’r crate lint `{ $crate }` yn cyfyngu ei eitemau i `{ $feature }` ond nid oes ganddo fodiwl bonyn ar gyfer adeiladau hebddo.
+    .note = Nid yw adeiladau gyda’r nodweddion rhagosodedig yn crynhoi dim o god y lint, a dim ond pan fydd rhywun yn adeiladu `{ $crate }` heb `{ $feature }` y daw’r methiant i’r golwg.
+    .help = Ychwanegwch `#[cfg(not(feature = "{ $feature }"))] mod stub {"{"} pub fn { $crate }_disabled_stub() {"{"}{"}"} {"}"}` at wraidd y crate.
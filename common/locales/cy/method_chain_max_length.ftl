@@ -0,0 +1,5 @@
+## Dylai cadwyni dulliau aros yn fyr.
+
+method_chain_max_length = Mae cadwyn dulliau o { $calls } galwad yn fwy na’r terfyn o { $limit }.
+    .note = Mae cadwyni hir ar un derbynnydd yn aml yn cuddio haniaethiad canolradd coll.
+    .help = Rhwymwch ganlyniadau canolradd i newidynnau ag enwau neu echdynnwch swyddogaeth gynorthwyol; rhestrwch fathau adeiladwr o dan `builder_types` i’w heithrio.
@@ -0,0 +1,5 @@
+## Dylid crynhoi macros cofnodi a metrigau a elwir ar bob iteriad o’r ddolen fewnol y tu allan iddi.
+
+metrics_or_log_in_tight_loop = Mae `{ $name }!` yn rhedeg ar bob iteriad o’r ddolen hon.
+    .note = Mae pob galwad yn fformatio cofnod neu’n diweddaru metrig, a all gostio mwy na’r gwaith y mae’r ddolen yn ei wneud ar gyfer un eitem pan fo’r casgliad yn fawr.
+    .help = Crynhowch y tu mewn i’r ddolen, fel cyfrif, cyfanswm, neu ychydig o eitemau sampl, a chofnodwch y canlyniad unwaith ar ôl y ddolen.
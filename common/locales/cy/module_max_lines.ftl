@@ -1,5 +1,9 @@
 ## Canllaw hyd modiwl.
 
-module_max_lines = Mae modiwl { $module } yn ymestyn i { $lines } o linellau ac yn torri’r terfyn o { $limit }.
+module_max_lines = Mae modiwl { $module } { $mode ->
+        [code_only] yn cynnwys { $lines } o linellau cod
+        [non_blank] yn cynnwys { $lines } o linellau nad ydynt yn wag
+       *[physical] yn ymestyn i { $lines } o linellau
+    } ac yn torri’r terfyn o { $limit }.
     .note = Mae modiwlau mawr yn anoddach i’w hadolygu.
     .help = Rhannwch { $module } neu leihau’r cyfaint cyfrifoldebau.
@@ -0,0 +1,5 @@
+## Dylai modiwlau sylweddol gynnwys modiwl `#[cfg(test)]` neu fod â chydymaith `*_tests.rs`.
+
+module_must_have_tests = Nid oes profion gan y modiwl `{ $module }`.
+    .note = Mae `{ $module }` yn datgan { $items } eitem dros { $lines } llinell, ond nid yw’n cynnwys modiwl `#[cfg(test)]` ac nid oes ganddo ffeil gydymaith `{ $module }_tests.rs`.
+    .help = Ychwanegwch fodiwl `#[cfg(test)]` sy’n profi `{ $module }`, naill ai yn y ffeil neu wedi’i lwytho o ffeil `{ $module }_tests.rs` wrth ei ymyl.
@@ -0,0 +1,12 @@
+## Ni ddylai modiwlau ailadrodd enw eu rhiant, na mathau enw eu modiwl.
+
+module_named_after_parent = Mae modiwl `{ $path }` yn ailadrodd enw ei fodiwl rhiant.
+    .note = Mae llwybrau drwyddo’n darllen `{ $path }`, gan enwi’r un modiwl ddwywaith heb ddweud beth mae’r un mewnol yn ei ychwanegu.
+    .help = { $fix ->
+        [rename] Ailenwch ef ar ôl yr hyn sydd ynddo, fel `{ $parent }::{ $suggestion }`, neu symudwch ei eitemau i fyny i `{ $parent }`.
+       *[flatten] Symudwch ei eitemau i fyny i `{ $parent }`, neu ailenwch ef ar ôl yr hyn y mae’n ei ychwanegu at `{ $parent }`.
+    }
+
+module_named_after_parent-item = Mae `{ $item }` yn ailadrodd enw ei fodiwl `{ $module }`.
+    .note = Mae galwyr eisoes yn ysgrifennu `{ $module }::{ $item }`, felly mae enw’r modiwl yn ymddangos ddwywaith ym mhob llwybr.
+    .help = Ailenwch ef yn `{ $suggestion }` fel bod y llwybr yn darllen `{ $module }::{ $suggestion }`.
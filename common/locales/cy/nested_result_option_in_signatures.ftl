@@ -0,0 +1,13 @@
+## Ni ddylai llofnodion cyhoeddus nythu `Result` ac `Option` y tu hwnt i ddyfnder y gellir ei ffurfweddu.
+
+nested_result_option_in_signatures = Mae { $position ->
+        [parameter] paramedr `{ $parameter }`
+       *[return] math dychwelyd
+    } { $context } yn nythu { $depth } haen `Result` ac `Option`, sy’n uwch na’r terfyn o { $limit }.
+    .note = { $collapse ->
+        [same-error] Mae haen { $layer } yn ailadrodd y math gwall `{ $error }` o `Result` allanol, felly mae’n debyg y gall `?` ei blygu i’r haen honno.
+        [repeated-option] Mae haen { $layer } yn `Option` yn union y tu mewn i `Option` arall, felly mae’n debyg y gall `Option::flatten` blygu’r ddwy ynghyd.
+        [distinct-errors] Mae haen { $layer } yn methu gyda `{ $error }` y tu mewn i `Result` sy’n methu gyda `{ $outer_error }`, felly byddai enum gwall sy’n cwmpasu’r ddau yn gadael i’r ddwy haen gyfuno.
+       *[dedicated-enum] Nid oes unrhyw haen yn ailadrodd un arall, felly mae pob un yn cynrychioli canlyniad ar wahân y mae’n rhaid i alwyr ei ddatod yn eu tro.
+    }
+    .help = Gwastatewch y math gyda `?`, `Option::flatten`, neu enum gwall, neu dychwelwch enum penodol sy’n enwi pob canlyniad.
@@ -0,0 +1,9 @@
+## Mae blocio ar ddyfodol mewn cod async yn atal yr amser rhedeg.
+
+no_block_on_in_async = Mae `{ $callee }` yn blocio’r edau y tu mewn i { $context ->
+        [function] ffwythiant async
+        [block] bloc async
+       *[closure] cau async
+    }.
+    .note = Ni all yr edau sydd wedi’i blocio yrru’r amser rhedeg, felly mae gweithredwr un edau yn cloi ac mae un aml-edau yn atal ei dasgau eraill.
+    .help = Defnyddiwch `.await` ar y dyfodol yn lle hynny, neu symudwch y gwaith blocio i `spawn_blocking` neu edau benodol.
@@ -0,0 +1,9 @@
+## Mae taflu dolen uno edau yn datgysylltu’r edau.
+
+no_detached_threads = Mae’r edau hon wedi’i datgysylltu oherwydd bod ei `JoinHandle` { $discard ->
+        [binding] wedi’i rwymo i `_`
+        [drop] wedi’i basio i `drop`
+       *[statement] wedi’i ollwng ar ddiwedd y datganiad
+    }.
+    .note = Ni all unrhyw beth uno edau sydd wedi’i datgysylltu, felly nid oes neb yn sylwi ar ei phanigau a gall barhau ar ôl y prawf neu’r galwr a’i cychwynnodd.
+    .help = Cadwch y ddolen a’i huno â `join`, defnyddiwch `std::thread::scope`, neu rhestrwch y modiwl hwn yn `allowed_modules` os yw’r edau i fod i redeg yn y cefndir.
@@ -0,0 +1,6 @@
+## Dylid dileu blociau `else` ar ôl cangen `if` sy’n gorffen gyda `return`, `continue`, neu `break`.
+
+no_else_after_return = `else` diangen ar ôl bloc `if` sy’n gorffen gyda `{ $exit }`.
+    .note = Mae’r bloc `if` bob amser yn gadael gyda `{ $exit }`, felly dim ond pan fo’r amod yn anwir y mae corff yr `else` yn rhedeg eisoes, ac nid yw’r `else` ond yn ychwanegu nythu.
+    .help = Dilëwch yr `else` a thynnwch fewnoliad ei gorff fel bod yr `if` yn darllen fel cymal gwarchod; mae canghennau mwy gwastad hefyd yn lleihau’r nythu y mae `bumpy_road_function` yn ei fesur.
+    .suggestion = dilëwch yr `else` a thynnwch fewnoliad ei gorff
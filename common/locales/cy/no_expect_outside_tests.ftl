@@ -1,9 +1,14 @@
 ## Gwaherddir `expect` y tu allan i brofion.
 
-no_expect_outside_tests = Peidiwch â galw `expect` ar { $receiver } y tu allan i god profion.
+no_expect_outside_tests = Peidiwch â galw `{ $method }` ar { $receiver } y tu allan i god profion.
     .note = Daw’r galwad o { $context } nad yw’n cael ei adnabod fel prawf.
     .help = { $handling ->
         [option] Triniwch yr achos `None` ar gyfer { $receiver } neu symudwch y cod i brawf.
         [result] Triniwch yr amrywiad `Err` ar gyfer { $receiver } neu symudwch y cod i brawf.
+        [ok] Triniwch yr amrywiad `Ok` ar gyfer { $receiver } neu symudwch y cod i brawf.
        *[other] Triniwch y llwybr gwall ar gyfer { $receiver } neu symudwch y cod i brawf.
     }
+    .suggestion = { $fix ->
+        [propagate] lluosogwch y methiant gyda `?`
+       *[context] atodwch y neges gyda `{ $replacement }`
+    }
@@ -0,0 +1,5 @@
+## Mae cymharu gwerthoedd pwynt arnawf am gydraddoldeb union yn dibynnu ar dalgrynnu.
+
+no_float_equality = Mae cymharu gwerthoedd `{ $ty }` â `{ $operator }` yn profi am gydraddoldeb union.
+    .note = Mae talgrynnu’n gadael canlyniadau a ddylai fod yn gyfartal yn wahanol yn eu didau olaf, felly gall cymhariaeth union fethu neu lwyddo ar ddamwain.
+    .help = Cymharwch y gwahaniaeth â goddefiant, fel `(a - b).abs() <= { $ty }::EPSILON`, neu defnyddiwch `{ $ty }::total_cmp` pan fwriedir cymhariaeth union.
@@ -0,0 +1,8 @@
+## Dylai crates llyfrgell ail-allforio eitemau wrth eu henw yn hytrach na gyda `pub use path::*`.
+
+no_glob_reexports = Ail-allforio glob o `{ $source }` yn yr API cyhoeddus.
+    .note = Mae pob eitem gyhoeddus a ychwanegir at `{ $source }` neu a dynnir ohono yn newid API y crate hwn heb i’r newid ymddangos yma.
+    .help = { $fix ->
+        [list] Ail-allforiwch yr eitemau wrth eu henw: `{ $replacement }`.
+       *[remove] Nid yw’r glob yn gwneud dim yn gyhoeddus; tynnwch ef.
+    }
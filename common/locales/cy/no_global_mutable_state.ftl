@@ -0,0 +1,9 @@
+## Mae unedau newidiol ar lefel y crate yn cuddio cyflwr a rennir.
+
+no_global_mutable_state = Mae `{ $name }` yn uned newidiol fyd-eang a warchodir gan `{ $lock }`.
+    .note = Gall pob modiwl gyrraedd cyflwr byd-eang, felly mae galwyr yn dibynnu ar werthoedd nad ydynt byth yn eu derbyn ac mae profion yn ymyrryd â’i gilydd.
+    .help = Trosglwyddwch y cyflwr y tu ôl i `{ $name }` yn benodol, neu rhestrwch ei fodiwl yn `allowed_modules` os yw’r gwerth byd-eang yn fwriadol.
+
+no_global_mutable_state-static-mut = Mae eitem `static mut` `{ $name }` yn gyflwr newidiol byd-eang.
+    .note = Gall pob modiwl gyrraedd cyflwr byd-eang, felly mae galwyr yn dibynnu ar werthoedd nad ydynt byth yn eu derbyn ac mae profion yn ymyrryd â’i gilydd.
+    .help = Trosglwyddwch y cyflwr y tu ôl i `{ $name }` yn benodol, neu rhestrwch ei fodiwl yn `allowed_modules` os yw’r gwerth byd-eang yn fwriadol.
@@ -0,0 +1,5 @@
+## Ni ddylai `include_str!` ac `include_bytes!` fewnosod ffeiliau sy’n fwy na’r maint a ffurfweddwyd.
+
+no_include_str_of_large_files = Mae `{ $macro }!` yn mewnosod `{ $file }`, sy’n { $size }, uwchlaw’r terfyn o { $limit }.
+    .note = Caiff ffeiliau wedi’u mewnosod eu copïo i bob deuaidd sy’n cysylltu’r crate hwn, gan ei chwyddo ac arafu pob ailadeiladu.
+    .help = Llwythwch y ffeil wrth redeg, neu ei hanfon ochr yn ochr â’r deuaidd, yn hytrach na’i mewnosod.
@@ -0,0 +1,9 @@
+## Mae mynegeio â mynegai amser rhedeg yn achosi panig pan fo y tu allan i’r terfynau.
+
+no_indexing_outside_tests = Mae mynegeio’r { $container ->
+        [vec] `Vec`
+        [array] arae
+       *[slice] sleisen
+    } hon â `{ $index }` yn achosi panig pan fo’r mynegai y tu allan i’r terfynau.
+    .note = Y tu allan i brofion, mae mynegai y tu allan i’r terfynau yn atal y galwr yn lle gadael iddo ymdrin â’r elfen goll.
+    .help = Defnyddiwch `.get({ $index })` neu `.get_mut({ $index })` ac ymdriniwch â’r achos `None`.
@@ -0,0 +1,5 @@
+## Dylid trin gwenwyno cloeon yn benodol yn hytrach na gyda `.lock().unwrap()`.
+
+no_lock_unwrap = Daw `.{ $call }()` ar ôl `{ $lock }::{ $method }` yn { $context }.
+    .note = Dim ond pan fydd edefyn arall wedi mynd i banig wrth ddal y clo y mae `{ $lock }::{ $method }` yn methu, ac mae `.{ $call }()` yn troi’r gwenwyno hwnnw’n ail banig heb benderfynu a yw’r data a ddiogelir yn dal yn ddefnyddiadwy.
+    .help = Triniwch y `PoisonError` yn benodol, er enghraifft gyda `.unwrap_or_else(PoisonError::into_inner)` pan fo’r data’n parhau’n ddilys, neu defnyddiwch `parking_lot`, nad yw ei gloeon yn cael eu gwenwyno.
@@ -0,0 +1,15 @@
+## Mae castiau `as` rhifol a all dorri, talgrynnu neu newid arwydd yn colli gwybodaeth yn dawel.
+
+no_lossy_casts = Mae castio `{ $from }` i `{ $to }` ag `as` { $loss ->
+        [sign] yn lapio gwerthoedd negatif
+        [both] yn lapio gwerthoedd negatif ac yn torri gwerthoedd y tu allan i ystod `{ $to }`
+        [fraction] yn gollwng y rhan ffracsiynol ac yn dirlenwi gwerthoedd y tu allan i ystod `{ $to }`
+        [precision] yn talgrynnu gwerthoedd na all `{ $to }` eu cynrychioli’n union
+       *[range] yn torri gwerthoedd y tu allan i ystod `{ $to }`
+    }.
+    .note = Nid yw `as` byth yn methu, felly mae gwerth na all `{ $to }` ei ddal yn troi’n rhif gwahanol yn dawel.
+    .help = { $loss ->
+        [fraction] Talgrynnwch yn benodol gyda `trunc`, `round`, `floor` neu `ceil`, a gwiriwch fod y gwerth yn feidraidd ac o fewn ystod `{ $to }` cyn castio.
+        [precision] Cadwch y gwerth mewn math sy’n ei gynrychioli’n union, neu caniatewch y lint lle bwriedir y talgrynnu.
+       *[other] Defnyddiwch `{ $to }::try_from(value)` neu `value.try_into()` a thrafodwch y gwall, neu newidiwch y mathau fel bod y cast yn lledu.
+    }
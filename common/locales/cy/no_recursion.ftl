@@ -0,0 +1,8 @@
+## Ni ddylai ffwythiannau alw eu hunain, yn uniongyrchol na thrwy ffwythiannau eraill.
+
+no_recursion = { $kind ->
+        [direct] Mae `{ $function }` yn galw ei hun.
+       *[mutual] Mae `{ $function }` yn rhan o gylch galwadau ailadroddus.
+    }
+    .note = Mae’r alwad hon yn dechrau’r cylch `{ $cycle }`.
+    .help = Ailysgrifennwch yr ailadrodd fel dolen dros bentwr penodol, neu caniatewch y ffwythiant os yw ei ddyfnder yn gyfyngedig.
@@ -0,0 +1,9 @@
+## Ni ddylai rhwymiadau gysgodi gwerthoedd digyswllt.
+
+no_shadowing = Mae `{ $name }` yn cysgodi rhwymiad cynharach yn yr un cwmpas.
+    .note = Mae’r rhwymiad a gysgodir, `{ $name }`, wedi’i ddatgan yma.
+    .help = Rhowch ei enw ei hun i’r gwerth newydd fel bod pob enw’n cyfeirio at un gwerth.
+
+no_shadowing-nested = Mae `{ $name }` yn cysgodi rhwymiad o gwmpas amgáu.
+    .note = Mae’r rhwymiad a gysgodir, `{ $name }`, wedi’i ddatgan yma.
+    .help = Ailenwch y rhwymiad mewnol fel bod y `{ $name }` allanol yn parhau’n weladwy i ddarllenwyr.
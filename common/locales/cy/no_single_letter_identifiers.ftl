@@ -0,0 +1,8 @@
+## Ni ddylai rhwymiadau a pharamedrau gael enwau un llythyren y tu allan i ddolenni, caeadau byr, a rhifyddeg generig.
+
+no_single_letter_identifiers = { $kind ->
+        [parameter] Paramedr
+       *[binding] Rhwymiad
+    } un llythyren `{ $name }` yn { $context }.
+    .note = Nid yw `{ $name }` yn dweud dim am y gwerth sydd ynddo, felly rhaid i ddarllenwyr ei olrhain yn ôl i’r man lle caiff ei rwymo.
+    .help = Ailenwch `{ $name }` ar ôl yr hyn sydd ynddo, neu ychwanegwch ef at `allowed` os yw’r enw’n gonfensiwn yma.
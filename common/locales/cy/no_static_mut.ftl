@@ -0,0 +1,9 @@
+## Gwahardd `static mut` o blaid cyflwr byd-eang wedi’i gydamseru.
+
+no_static_mut = Mae eitem `static mut` `{ $name }` yn caniatáu newid byd-eang heb gydamseru.
+    .note = Mae cyfeiriadau at eitemau `static mut` wedi’u hanghymeradwyo ac mae angen `unsafe` ar bob mynediad.
+    .help = Amnewidiwch `{ $name }` gyda `std::sync::OnceLock`, `Mutex`, neu fath atomig.
+
+no_static_mut-access = Nid yw mynediad at eitem `static mut` `{ $name }` wedi’i gydamseru.
+    .note = Mae darllen neu ysgrifennu `{ $name }` yn rasio ag unrhyw edau arall sy’n ei gyffwrdd.
+    .help = Symudwch `{ $name }` i `std::sync::OnceLock`, `Mutex`, neu fath atomig, yna tynnwch y bloc `unsafe`.
@@ -0,0 +1,5 @@
+## Dylai rhifyddeg cyfanrifau mewn modiwlau ffurfweddedig ddefnyddio dulliau `checked_*`, `saturating_*`, neu `wrapping_*`.
+
+no_unchecked_arithmetic = `{ $operator }` heb ei wirio ar `{ $ty }` ym modiwl `{ $module }`.
+    .note = Mae `{ $module }` wedi’i ffurfweddu i drin gorlif yn benodol, ond mae `{ $operator }` yn mynd i banig ar orlif mewn adeiladau dadfygio ac yn lapio’n dawel mewn adeiladau rhyddhau.
+    .help = Defnyddiwch `{ $checked }`, `{ $saturating }`, neu `{ $wrapping }` i nodi beth ddylai ddigwydd ar orlif.
@@ -1,5 +1,5 @@
 ## Peidiwch â dibynnu ar `unwrap_or_else` sy’n panicio.
 
-no_unwrap_or_else_panic = Newidiwch `unwrap_or_else` ar { $receiver } i glôd nad yw’n panicio.
-    .note = Mae’r caead yn pasio panic i `unwrap_or_else`.
+no_unwrap_or_else_panic = Newidiwch `{ $method }` ar { $receiver } i glôd nad yw’n panicio.
+    .note = Mae’r caead yn pasio panic i `{ $method }`.
     .help = Lledaenwch y gwall neu defnyddiwch `expect` gyda neges eglur yn lle hynny.
@@ -0,0 +1,8 @@
+## Dylai paru ar enums a ddiffinnir yn yr un crate enwi eu hamrywiadau yn hytrach na defnyddio braich `_`.
+
+no_wildcard_match_on_local_enums = Braich cerdyn gwyllt mewn paru ar `{ $enum }`, a ddiffinnir gan y crate hwn.
+    .note = Byddai amrywiad a ychwanegir at `{ $enum }` yn disgyn i’r fraich hon yn dawel yn hytrach na methu â chrynhoi yma.
+    .help = { $fix ->
+        [list] Enwch yr amrywiadau sy’n weddill yn lle hynny: `{ $replacement }`.
+       *[spell] Rhowch batrymau sy’n enwi’r amrywiadau y mae’n eu cwmpasu yn lle `_`, fel bod pob amrywiad yn cael ei baru’n benodol.
+    }
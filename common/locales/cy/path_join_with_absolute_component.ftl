@@ -0,0 +1,11 @@
+## Mae uno cydran llwybr absoliwt yn taflu’r llwybr sylfaen.
+
+path_join_with_absolute_component = Mae `{ $method }` gyda `{ $component }` yn taflu’r llwybr sylfaen.
+    .note = Mae `{ $component }` yn dechrau { $anchor ->
+        [drive] gyda rhagddodiad gyriant
+       *[root] wrth wraidd y system ffeiliau
+    }, felly mae `{ $method }` yn ei ddychwelyd heb ei newid yn hytrach nag ymestyn y llwybr sylfaen.
+    .help = Tynnwch { $anchor ->
+        [drive] y rhagddodiad gyriant
+       *[root] y gwahanydd cyntaf
+    } i aros y tu mewn i’r llwybr sylfaen, neu defnyddiwch `{ $component }` ar ei ben ei hun os bwriedir llwybr absoliwt.
@@ -0,0 +1,6 @@
+## Dylid ysgrifennu mynegiadau `match` ar `bool` fel `if`/`else`.
+
+pattern_match_on_boolean = Dylid ysgrifennu `match` ar `bool` fel `if`/`else`.
+    .note = Nid yw’r breichiau ond yn gwahaniaethu rhwng `true` a `false`, sef yr hyn y mae `if`/`else` yn ei ddweud yn uniongyrchol heb nythu pob cangen o fewn braich.
+    .help = Rhowch `if` ar y gwerth a archwilir yn lle’r `match`, gan ddefnyddio braich `true` fel y bloc `if` a’r fraich arall fel y bloc `else`.
+    .suggestion = ailysgrifennwch y `match` fel `if`/`else`
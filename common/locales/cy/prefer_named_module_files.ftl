@@ -0,0 +1,5 @@
+## Ffafrio ffeiliau modiwl `foo.rs` dros `foo/mod.rs`.
+
+prefer_named_module_files = Mae modiwl `{ $module }` wedi’i weithredu mewn ffeil `mod.rs`.
+    .note = Diffinnir `{ $module }` yn `{ $path }`; mae’n well gennym `{ $suggestion }`.
+    .help = Symudwch `{ $path }` i `{ $suggestion }` a chadwch unrhyw is-fodiwlau yng nghyfeiriadur `{ $module }`.
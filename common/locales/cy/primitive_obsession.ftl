@@ -0,0 +1,8 @@
+## Mae ailadrodd un teip cyntefig noeth ar draws safleoedd eitem yn gwahodd dryswch.
+
+primitive_obsession = { $kind ->
+        [struct] Mae `{ $item }` yn storio `{ $primitive }` mewn { $count } maes.
+       *[function] Mae `{ $item }` yn cymryd `{ $primitive }` mewn { $count } paramedr.
+    }
+    .note = Mae { $positions } i gyd yn rhannu’r teip noeth `{ $primitive }`, felly does dim i atal pasio un lle mae un arall i fod.
+    .help = Cyflwynwch fath newydd (newtype) ar gyfer pob gwerth y mae `{ $primitive }` yn sefyll drosto, neu rhestrwch `{ $primitive }` yn `exempt_types`.
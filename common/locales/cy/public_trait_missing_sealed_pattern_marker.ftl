@@ -0,0 +1,8 @@
+## Dylai nodweddion cyhoeddus sydd i fod wedi’u selio ofyn am uwch-nodwedd na all crates i lawr yr afon ei henwi.
+
+public_trait_missing_sealed_pattern_marker = Gellir gweithredu’r nodwedd gyhoeddus `{ $name }` y tu allan i’r crate hwn.
+    .note = { $intent ->
+        [hidden] Mae `{ $name }` yn gofyn am y nodwedd gudd `{ $supertrait }`, ond mae `{ $supertrait }` yn gyhoeddus, felly gall crates i lawr yr afon weithredu’r ddwy.
+       *[listed] Mae `{ $name }` wedi’i rhestru yn `sealed_traits`, ond does dim yn atal crates i lawr yr afon rhag ei gweithredu, felly mae ychwanegu dull gofynnol yn newid sy’n torri.
+    }
+    .help = Gofynnwch am uwch-nodwedd a ddatganwyd mewn modiwl preifat, fel `private::Sealed`, a’i gweithredu ar gyfer mathau’r crate hwn yn unig.
@@ -0,0 +1,6 @@
+## Mae blociau `else` ar ôl cangen `if` sy’n gorffen gyda `continue` neu `break` yn ddiangen.
+
+redundant_else_block_after_loop_control = `else` diangen ar ôl bloc `if` sy’n gorffen gyda `{ $control }`.
+    .note = Mae’r bloc `if` bob amser yn gorffen yr iteriad gyda `{ $control }`, felly dim ond pan fo’r amod yn anwir y mae corff yr `else` yn rhedeg eisoes, ac nid yw’r `else` ond yn ychwanegu nythu.
+    .help = Dilëwch yr `else` a thynnwch fewnoliad ei gorff fel bod yr `if` yn darllen fel cymal gwarchod a bod gweddill corff y ddolen yn aros ar un lefel mewnoliad.
+    .suggestion = dilëwch yr `else` a thynnwch fewnoliad ei gorff
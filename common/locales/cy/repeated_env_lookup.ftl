@@ -0,0 +1,8 @@
+## Dylid darllen newidyn amgylchedd unwaith yn hytrach na’i chwilio dro ar ôl tro yn yr un cwmpas.
+
+repeated_env_lookup = Mae’r newidyn amgylchedd `{ $key }` yn cael ei chwilio eto yn yr un { $scope ->
+        [impl] bloc `impl`
+       *[function] ffwythiant
+    }.
+    .note = Mae `{ $key }` yn cael ei chwilio gyntaf yma.
+    .help = Darllenwch `{ $key }` unwaith i newidyn lleol neu strwythur ffurfweddu ac ailddefnyddiwch y gwerth.
@@ -0,0 +1,5 @@
+## Dylai tasgau a silir sy’n dolennu dros `.await` wylio tocyn canslo fel y gellir eu cau.
+
+spawned_task_missing_cancellation_handling = Mae’r dasg a silwyd gyda `{ $callee }` yn dolennu dros `.await` heb wylio am ganslo.
+    .note = Nid oes dim yn y dasg yn darllen tocyn canslo, felly ni all cau graslon atal y ddolen; mae’r dasg yn rhedeg nes i’r amser rhedeg ei gollwng hanner ffordd drwy iteriad.
+    .help = Pasiwch `CancellationToken` i’r dasg a defnyddiwch `select!` ar `token.cancelled()` ochr yn ochr â’r gwaith y mae’r ddolen yn aros amdano, yna torrwch allan o’r ddolen pan fydd yn tanio.
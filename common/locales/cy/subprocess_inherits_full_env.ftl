@@ -0,0 +1,5 @@
+## Gorchmynion mewn modiwlau sy’n sensitif o ran diogelwch wedi’u lansio gydag amgylchedd llawn y rhiant.
+
+subprocess_inherits_full_env = Mae `{ $method }` yn rhedeg gorchymyn sy’n etifeddu amgylchedd llawn y broses hon.
+    .note = Mae’r gorchymyn a adeiladwyd yma yn dechrau o gopi o amgylchedd y rhiant, gan gynnwys unrhyw docynnau neu fanylion mewngofnodi sydd ynddo.
+    .help = Galwch `env_clear()` a phasiwch dim ond y newidynnau sydd eu hangen ar y plentyn gydag `env` neu `envs`, neu tynnwch newidynnau sensitif gydag `env_remove`.
@@ -0,0 +1,5 @@
+## Dylai modiwlau `#[cfg(test)]` mawr fyw yn eu ffeil eu hunain, wedi’u llwytho â `#[path]`.
+
+test_file_in_src_should_use_path_attribute_pattern = Mae’r modiwl prawf `{ $module }` wedi’i ysgrifennu yn y ffeil dros { $lines } llinell, uwchlaw’r terfyn o { $limit }.
+    .note = Mae profion yn y ffeil yn gwneud ffeiliau cynhyrchu yn hirach i’w darllen a’u hadolygu, tra bo ffeil ar wahân yn cadw’r cod a’i brofion ar wahân.
+    .help = Symudwch y corff i `{ $path }` a’i ddatgan gyda `#[cfg(test)] #[path = "{ $path }"] mod { $module };`.
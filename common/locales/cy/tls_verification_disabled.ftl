@@ -0,0 +1,5 @@
+## Analluogi dilysu tystysgrif neu enw gwesteiwr TLS y tu allan i brofion.
+
+tls_verification_disabled = Mae `{ $call }` yn analluogi dilysu TLS y tu allan i brofion.
+    .note = Heb wiriadau tystysgrif ac enw gwesteiwr, gall unrhyw un ar lwybr y rhwydwaith ddynwared y gweinydd a darllen neu newid y traffig.
+    .help = Tynnwch yr alwad. I ymddiried mewn tystysgrif breifat neu hunan-lofnodedig, ychwanegwch hi fel tystysgrif wraidd yn lle hynny, neu cyfyngwch yr alwad i god prawf.
@@ -0,0 +1,12 @@
+## Dylai negeseuon expect a panic ddisgrifio’r amod a fethodd.
+
+uninformative_panic_message = { $call ->
+        [expect] Nid yw’r neges expect hon yn disgrifio beth aeth o’i le.
+       *[panic] Nid yw’r neges panic hon yn disgrifio beth aeth o’i le.
+    }
+    .note = { $problem ->
+        [empty] Mae’r neges yn wag.
+        [generic] Mae’r neges "{ $message }" yn ymadrodd cyffredinol sy’n addas i unrhyw fethiant.
+       *[short] Mae’r neges "{ $message }" yn fyrrach na { $min_length } nod.
+    }
+    .help = Nodwch yr amod a ddylai fod wedi dal, er enghraifft "config should be validated at start-up".
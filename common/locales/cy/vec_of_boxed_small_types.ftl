@@ -0,0 +1,5 @@
+## Dylai fectorau storio gwerthoedd bach â maint sefydlog yn uniongyrchol yn lle bocsio pob elfen.
+
+vec_of_boxed_small_types = Mae `Vec<Box<{ $element }>>` yn bocsio elfennau o ddim ond { $size } beit.
+    .note = Mae pob elfen mewn dyraniad cof ei hun, felly mae adeiladu’r fector yn dyrannu unwaith i bob eitem ac mae pob mynediad yn dilyn pwyntydd; mae’n rhatach storio gwerthoedd â maint sefydlog o { $limit } beit neu lai yn fewnol.
+    .help = Storiwch yr elfennau yn uniongyrchol fel `Vec<{ $element }>`.
@@ -0,0 +1,11 @@
+## `#[allow(...)]` attributes should give a `reason` so suppressions stay auditable.
+
+allow_must_have_reason = { $kind ->
+        [blank] `{ $attribute }` has an empty `reason`.
+       *[missing] `{ $attribute }` does not say why the lint is allowed.
+    }
+    .note = Unexplained suppressions cannot be audited, and nobody can tell when they are safe to remove.
+    .help = { $comments ->
+        [accepted] Add `reason = "..."`, or a `//` comment on the same line, explaining why the lint does not apply here.
+       *[rejected] Add `reason = "..."` explaining why the lint does not apply here.
+    }
@@ -0,0 +1,6 @@
+## `Arc` and `Rc` values should be cloned with `Arc::clone(&value)` rather than `.clone()`.
+
+arc_clone_prefer_explicit = Clone of an `{ $pointer }` written as `{ $receiver }.clone()`.
+    .note = `{ $receiver }.clone()` reads like a copy of the shared value, but it only increments the `{ $pointer }` reference count.
+    .help = Write `{ $replacement }` so the cheap pointer copy is explicit.
+    .suggestion = call `{ $pointer }::clone` explicitly
@@ -0,0 +1,5 @@
+## Archive entry paths joined onto a directory without a containment check.
+
+archive_extraction_must_sanitise_paths = Archive entry path passed to `{ $method }` without a containment check.
+    .note = `{ $source }` returns the path stored in the archive, which may contain `..` or start at the filesystem root, so `{ $method }` can point outside the output directory.
+    .help = Reject entries whose path has `..`, root, or prefix components, or canonicalise the joined path and check that it `starts_with` the output directory.
@@ -0,0 +1,8 @@
+## Trait methods should declare `async fn` or return `impl Future` instead of a boxed future when the toolchain allows.
+
+async_fn_returning_boxed_future_prefer_async_trait_or_impl = Method `{ $method }` in trait `{ $trait }` returns a boxed future.
+    .note = `{ $return }` allocates on every call and erases the future's type, and since Rust 1.75 traits can declare asynchronous methods directly.
+    .help = { $form ->
+        [impl] Declare the method as `fn { $method }(...) -> impl Future<Output = { $output }> + Send`, which keeps the `Send` bound.
+       *[async] Declare the method as `async fn { $method }(...) -> { $output }`.
+    }
@@ -0,0 +1,5 @@
+## `for` loops awaiting futures that do not depend on earlier iterations should run them concurrently.
+
+await_in_loop_without_concurrency = Each iteration awaits a future built from `{ $item }` before the next iteration starts.
+    .note = The future does not depend on earlier iterations, so awaiting the futures one at a time leaves only one of them making progress.
+    .help = Collect the futures and await them together with `futures::future::join_all`, or turn the items into a stream and use `buffer_unordered` to bound how many run at once.
@@ -6,7 +6,7 @@ bumpy_road_function = Multiple clusters of nested conditional logic in `{ $name
        *[other] bumps
     } above the threshold { $threshold }.
     .help = Extract helper functions from the highlighted regions to reduce clustered complexity.
-    .label = Complexity bump { $index } spans { $lines } { $lines ->
-        [one] line
-       *[other] lines
+    .bump = Complexity bump { $index } covers { $lines ->
+        [one] line { $start }
+       *[other] lines { $start }–{ $end }
     }.
@@ -0,0 +1,5 @@
+## `unwrap_or_default` on a `Result` with a displayable error silently discards the failure.
+
+chained_unwrap_or_default_hides_errors = `.unwrap_or_default()` discards a `{ $error }` error in { $context }.
+    .note = `{ $error }` implements `Display`, so it was meant to be reported, but `.unwrap_or_default()` replaces the failure with default data that looks like an empty success.
+    .help = Propagate the error with `?`, or use `.unwrap_or_else(|error| ...)` to log it before falling back to a default.
@@ -0,0 +1,5 @@
+## Closures should stay short.
+
+closure_max_lines = Closure body spans { $lines } lines, exceeding the limit of { $limit }.
+    .note = Long closures hide logic that deserves a name and tests of its own.
+    .help = Extract the closure body into a named function and pass it by path or call it from a short closure.
@@ -0,0 +1,5 @@
+## Comments containing commented-out code should be deleted.
+
+commented_out_code = This comment contains commented-out code.
+    .note = Version control already keeps the history of deleted code.
+    .help = Delete the commented-out code and recover it from version control if it is needed again.
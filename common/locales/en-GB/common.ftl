@@ -13,3 +13,36 @@ common-lint-count = Count your lints: { $lint }.
 
 #. Shown in diagnostics to refer to the preceding attribute name.
 common-attribute-fallback = the preceding attribute
+
+#. Describes the item enclosing a diagnostic site. `$kind` is one of function,
+#. method, closure, const, static, or scope; closures name their parent item.
+common-enclosing-item = { $kind ->
+        [function] function `{ $name }`
+        [method] method `{ $name }`
+        [closure] a closure in `{ $name }`
+        [const] constant `{ $name }`
+        [static] static `{ $name }`
+       *[scope] the surrounding scope
+    }
+
+#. Left on an expression inside a helper listed in `blame_helpers`, whose
+#. finding is reported at each call to the helper instead.
+common-blamed-helper = The finding is in helper `{ $helper }`, which `blame_helpers` lists, so it is reported where the helper is called.
+
+#. Emitted once per lint and crate when `max_findings_per_lint_per_crate`
+#. held findings back. The diagnostic points at the first finding not shown.
+common-findings-suppressed = `{ $lint }` reached its limit of { $limit } findings in this crate; { $remaining } more were not shown.
+    .note = `max_findings_per_lint_per_crate` caps the findings each lint reports per crate. This diagnostic points at the first finding that was not shown.
+    .help = Raise or remove `max_findings_per_lint_per_crate` in `dylint.toml` to list every finding in the build log and in the JSON report from `whitaker check`.
+
+#. Emitted once per crate by the suite when `crate_summary` is enabled. Each
+#. lint's total follows as a separate note.
+common-crate-summary = Whitaker reported { $total } { $total ->
+        [one] finding
+       *[other] findings
+    } from { $lints } { $lints ->
+        [one] lint
+       *[other] lints
+    } in crate `{ $crate }`.
+    .note = Each line gives a lint's total for this crate, including findings that `max_findings_per_lint_per_crate` did not show.
+    .help = Set `crate_summary = false` in `dylint.toml` to turn this summary off.
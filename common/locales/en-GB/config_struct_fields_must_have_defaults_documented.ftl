@@ -0,0 +1,5 @@
+## `#[serde(default)]` fields of configuration structs should state their default in their documentation.
+
+config_struct_fields_must_have_defaults_documented = Field `{ $field }` of `{ $type }` has a serde default its documentation does not state.
+    .note = Readers of `{ $type }` cannot tell which value `{ $field }` takes when the configuration omits it.
+    .help = State the default in the field's doc comment, for example "Defaults to 3.".
@@ -0,0 +1,5 @@
+## Constructors taking many arguments should use a builder.
+
+constructor_max_arguments = `{ $constructor }` takes { $arguments } arguments, exceeding the limit of { $limit }.
+    .note = Callers must supply every argument in order, and arguments of the same type are easily swapped.
+    .help = Add a builder for `{ $type }`, or group the arguments into a configuration struct.
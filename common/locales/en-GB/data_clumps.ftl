@@ -0,0 +1,5 @@
+## Groups of parameters that travel together should become a struct.
+
+data_clumps = Parameters { $parameters } appear together in { $count } function signatures.
+    .note = The same parameters are also taken by { $functions }.
+    .help = Group { $parameters } into a struct and pass that instead.
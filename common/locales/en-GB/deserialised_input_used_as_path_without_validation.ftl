@@ -0,0 +1,5 @@
+## Deserialised input passed to a filesystem call without validation.
+
+deserialised_input_used_as_path_without_validation = Deserialised input reaches `{ $sink }` without validation.
+    .note = `{ $source }` decodes data chosen by whoever produced it, so the path may contain `..` or start at the filesystem root.
+    .help = Reject paths with `..`, root, or prefix components before using them, and list the checking function under `validators`.
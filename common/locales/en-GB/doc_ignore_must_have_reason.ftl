@@ -0,0 +1,9 @@
+## `ignore` doc examples should say why on the fence line.
+
+doc_ignore_must_have_reason = Doc example fenced with `{ $info }` is ignored without a reason.
+    .note = An `ignore` example is never compiled or run, so it can drift from the code it documents, and readers cannot tell whether skipping it was deliberate.
+    .help = Give the reason in parentheses on the fence line, as in `{ $info } (needs a running database)`, or use `no_run` if the example still compiles.
+
+doc_ignore_must_have_reason-crate = { $ignored } of the { $total } doc examples in this crate are ignored ({ $percent }%), above the limit of { $limit }%.
+    .note = Ignored examples are never compiled, so that share of this crate's documentation goes unchecked.
+    .help = Make examples compile with hidden setup lines starting with `# `, or mark them `no_run` when they must not execute.
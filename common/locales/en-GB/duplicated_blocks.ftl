@@ -0,0 +1,5 @@
+## Near-identical blocks of statements should be extracted into a shared function.
+
+duplicated_blocks = `{ $function }` repeats { $statements } statements from `{ $original }`.
+    .note = The block in `{ $original }` is { $similarity }% similar.
+    .help = Extract the shared statements into a helper function that both can call.
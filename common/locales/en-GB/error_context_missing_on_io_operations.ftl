@@ -0,0 +1,5 @@
+## I/O errors should gain context before `?` converts them into a rich error type.
+
+error_context_missing_on_io_operations = `?` passes the I/O error from `{ $operation }` on without context.
+    .note = A `std::io::Error` names neither the operation nor the path that failed, and `{ $error }` can record both.
+    .help = Attach context before `?`, for example with `.with_context(|| ...)`, naming the path or operation.
@@ -0,0 +1,14 @@
+## Public error types should implement `Display` and `std::error::Error`.
+
+error_types_implement_error = Error type `{ $type }` does not implement { $missing ->
+        [error] `std::error::Error`
+       *[both] `Display` or `std::error::Error`
+    }.
+    .note = { $role ->
+        [returned] `{ $type }` is the error type of public function `{ $function }`
+       *[named] `{ $type }` is public and named as an error type
+    }; it is missing { $missing ->
+        [error] `impl std::error::Error for { $type }`
+       *[both] `impl std::fmt::Display for { $type }` and `impl std::error::Error for { $type }`
+    }.
+    .help = Derive the impls with `#[derive(thiserror::Error)]` and an `#[error("...")]` message, or write them by hand, so callers can print the error and use it with `?`, `Box<dyn Error>`, and source chains.
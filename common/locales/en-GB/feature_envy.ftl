@@ -0,0 +1,5 @@
+## Methods should not use another type more than their own.
+
+feature_envy = `{ $method }` uses `{ $target }` more than its own type ({ $foreign } accesses against { $own }).
+    .note = Methods that mostly work with another type's data usually belong on that type.
+    .help = Move `{ $method }`, or the part that works with `{ $target }`, onto `{ $target }`.
@@ -0,0 +1,5 @@
+## Feature-gated `pub use` re-exports should carry `#[cfg_attr(docsrs, doc(cfg(...)))]` so documentation shows the gate.
+
+feature_gated_pub_use_must_have_cfg_doc = Re-export gated on `{ $predicate }` has no `doc(cfg(...))` attribute.
+    .note = Documentation built with every feature shows the re-export without saying it needs `{ $predicate }`.
+    .help = Add `{ $attribute }` so rustdoc labels the re-export with its gate.
@@ -0,0 +1,5 @@
+## Functions should not have more `return` and `?` exits than the configured limit.
+
+function_max_return_points = Found { $total } return points in { $context }, above the limit of { $limit }.
+    .note = Outside its guard clauses the function has { $returns } `return` and { $questions } `?`, and each is another path readers must trace.
+    .help = Extract the steps between exits into helper functions, or combine related checks so the function has fewer ways out.
@@ -0,0 +1,5 @@
+## Large generic functions instantiated with many distinct generic arguments should delegate to an inner non-generic function.
+
+generic_function_monomorphisation_bloat = Generic function `{ $name }` spans { $lines } lines and is instantiated with { $count } distinct sets of generic arguments.
+    .note = The compiler emits a copy of the whole body for each set of generic arguments, adding to build time and binary size.
+    .help = Move the body into an inner non-generic function, and keep only the conversion of the arguments, such as `.as_ref()` or `.into()`, in the generic wrapper.
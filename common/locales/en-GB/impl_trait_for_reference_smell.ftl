@@ -0,0 +1,5 @@
+## Local traits should not be implemented for both `T` and `&T` or `&mut T`.
+
+impl_trait_for_reference_smell = `{ $trait }` is implemented for `{ $self_ty }` as well as for `{ $referent }`.
+    .note = The implementation for `{ $referent }` is here. Method calls choose between the two by auto-referencing, so the same call can reach either one depending on how the receiver was borrowed.
+    .help = Keep only the implementation for `{ $referent }` and let callers reach it through the reference, or make both behave identically.
@@ -0,0 +1,8 @@
+## Private helpers should return `impl Iterator` when every caller only iterates the collected `Vec`.
+
+iterator_returning_fn_should_not_collect_internally = Every caller of { $context } only iterates the `Vec<{ $item }>` it collects.
+    .note = The crate calls it { $calls } { $calls ->
+        [one] time
+       *[other] times
+    }, and each call walks the result once without indexing or keeping it, so the vector is allocated only to be consumed.
+    .help = Return `impl Iterator<Item = { $item }>` and drop the final `collect()` so items flow straight to the callers.
@@ -0,0 +1,11 @@
+## Local variables and arguments should not hold values too large for the stack.
+
+large_stack_values = { $kind ->
+        [argument] Argument
+       *[local] Local variable
+    } `{ $name }` in { $context } is { $size } bytes, above the limit of { $limit }.
+    .note = `{ $type }` occupies the stack while `{ $name }` is live, and every move copies all { $size } bytes.
+    .help = { $kind ->
+        [argument] Take the argument by reference or as a `Box<{ $type }>`.
+       *[local] Allocate the value on the heap, for example as a `Box<{ $type }>` or a `Vec`, so only a pointer lives on the stack.
+    }
@@ -0,0 +1,5 @@
+## Lint crates gating items on `dylint-driver` should declare a stub module for builds without it.
+
+lint_crate_missing_stub_for_non_driver_builds = Lint crate `{ $crate }` gates its items on `{ $feature }` but has no stub module for builds without it.
+    .note = Builds with default features compile none of the lint's code, and the breakage only shows when someone builds `{ $crate }` without `{ $feature }`.
+    .help = Add `#[cfg(not(feature = "{ $feature }"))] mod stub {"{"} pub fn { $crate }_disabled_stub() {"{"}{"}"} {"}"}` to the crate root.
@@ -0,0 +1,5 @@
+## Method chains should stay short.
+
+method_chain_max_length = Method chain of { $calls } calls exceeds the limit of { $limit }.
+    .note = Long chains on a single receiver often hide a missing intermediate abstraction.
+    .help = Bind intermediate results to named variables or extract a helper; list builder types under `builder_types` to exempt them.
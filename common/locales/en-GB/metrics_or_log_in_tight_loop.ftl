@@ -0,0 +1,5 @@
+## Logging and metrics macros called on every iteration of an innermost loop should be aggregated outside it.
+
+metrics_or_log_in_tight_loop = `{ $name }!` runs on every iteration of this loop.
+    .note = Each call formats a record or updates a metric, which can cost more than the work the loop does for one item when the collection is large.
+    .help = Aggregate inside the loop, such as a count, a total, or a few sample items, and log or record the result once after the loop.
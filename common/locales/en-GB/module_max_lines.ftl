@@ -1,5 +1,9 @@
 ## Module length guidance.
 
-module_max_lines = Module { $module } spans { $lines } lines, exceeding the allowed { $limit }.
+module_max_lines = Module { $module } { $mode ->
+        [code_only] has { $lines } lines of code
+        [non_blank] has { $lines } non-blank lines
+       *[physical] spans { $lines } lines
+    }, exceeding the allowed { $limit }.
     .note = Large modules are harder to navigate and review.
     .help = Split { $module } into smaller modules or reduce its responsibilities.
@@ -0,0 +1,11 @@
+## Non-trivial modules should contain a `#[cfg(test)]` module or have a `*_tests.rs` companion.
+
+module_must_have_tests = Module `{ $module }` has no tests.
+    .note = `{ $module }` declares { $items } { $items ->
+        [one] item
+       *[other] items
+    } over { $lines } { $lines ->
+        [one] line
+       *[other] lines
+    }, but contains no `#[cfg(test)]` module and has no `{ $module }_tests.rs` companion file.
+    .help = Add a `#[cfg(test)]` module covering `{ $module }`, inline or loaded from a `{ $module }_tests.rs` file beside it.
@@ -0,0 +1,12 @@
+## Modules should not repeat their parent's name, nor types the name of their module.
+
+module_named_after_parent = Module `{ $path }` repeats the name of its parent module.
+    .note = Paths through it read `{ $path }`, naming the same module twice without saying what the inner one adds.
+    .help = { $fix ->
+        [rename] Rename it after what it holds, such as `{ $parent }::{ $suggestion }`, or move its items up into `{ $parent }`.
+       *[flatten] Move its items up into `{ $parent }`, or rename it after what it adds to `{ $parent }`.
+    }
+
+module_named_after_parent-item = `{ $item }` repeats the name of its module `{ $module }`.
+    .note = Callers already write `{ $module }::{ $item }`, so the module name appears twice in every path.
+    .help = Rename it to `{ $suggestion }` so the path reads `{ $module }::{ $suggestion }`.
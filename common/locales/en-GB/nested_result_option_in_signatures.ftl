@@ -0,0 +1,13 @@
+## Public signatures should not nest `Result` and `Option` beyond a configurable depth.
+
+nested_result_option_in_signatures = { $position ->
+        [parameter] Parameter `{ $parameter }`
+       *[return] The return type
+    } of { $context } nests { $depth } `Result` and `Option` layers, above the limit of { $limit }.
+    .note = { $collapse ->
+        [same-error] Layer { $layer } repeats the error type `{ $error }` of an outer `Result`, so `?` can likely fold it into that layer.
+        [repeated-option] Layer { $layer } is an `Option` directly inside another `Option`, so `Option::flatten` can likely fold the two together.
+        [distinct-errors] Layer { $layer } fails with `{ $error }` inside a `Result` that fails with `{ $outer_error }`, so an error enum covering both would let the two layers collapse.
+       *[dedicated-enum] No layer repeats another, so each encodes a separate outcome that callers must unpick in order.
+    }
+    .help = Flatten the type with `?`, `Option::flatten`, or an error enum, or return a dedicated enum that names each outcome.
@@ -0,0 +1,9 @@
+## Blocking on a future inside async code stalls the runtime.
+
+no_block_on_in_async = `{ $callee }` blocks the thread inside { $context ->
+        [function] an async function
+        [block] an async block
+       *[closure] an async closure
+    }.
+    .note = The blocked thread cannot drive the runtime, so a single-threaded executor deadlocks and a multi-threaded one stalls its other tasks.
+    .help = `.await` the future instead, or move the blocking work into `spawn_blocking` or a dedicated thread.
@@ -0,0 +1,9 @@
+## Discarding a thread's join handle detaches the thread.
+
+no_detached_threads = This thread is detached because its `JoinHandle` is { $discard ->
+        [binding] bound to `_`
+        [drop] passed to `drop`
+       *[statement] dropped at the end of the statement
+    }.
+    .note = Nothing can join a detached thread, so its panics go unobserved and it can outlive the test or caller that started it.
+    .help = Keep the handle and `join` it, use `std::thread::scope`, or list this module in `allowed_modules` if the thread is meant to run in the background.
@@ -0,0 +1,6 @@
+## `else` blocks after an `if` branch ending in `return`, `continue`, or `break` should be removed.
+
+no_else_after_return = Unnecessary `else` after an `if` block that ends with `{ $exit }`.
+    .note = The `if` block always leaves with `{ $exit }`, so the `else` body already runs only when the condition is false and the `else` just adds nesting.
+    .help = Remove the `else` and dedent its body so the `if` reads as a guard clause; flatter branches also lower the nesting `bumpy_road_function` measures.
+    .suggestion = remove the `else` and dedent its body
@@ -0,0 +1,5 @@
+## Comparing floating-point values for exact equality depends on rounding.
+
+no_float_equality = Comparing `{ $ty }` values with `{ $operator }` tests for exact equality.
+    .note = Rounding leaves results that should be equal differing in their last bits, so an exact comparison can fail or succeed by accident.
+    .help = Compare the difference against a tolerance, such as `(a - b).abs() <= { $ty }::EPSILON`, or use `{ $ty }::total_cmp` when an exact comparison is intended.
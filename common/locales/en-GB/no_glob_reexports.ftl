@@ -0,0 +1,8 @@
+## Library crates should re-export items by name rather than with `pub use path::*`.
+
+no_glob_reexports = Glob re-export of `{ $source }` in the public API.
+    .note = Every public item added to or removed from `{ $source }` changes this crate's API without the change showing here.
+    .help = { $fix ->
+        [list] Re-export the items by name: `{ $replacement }`.
+       *[remove] The glob makes nothing public; remove it.
+    }
@@ -0,0 +1,9 @@
+## Crate-level mutable singletons hide shared state.
+
+no_global_mutable_state = `{ $name }` is a global mutable singleton guarded by a `{ $lock }`.
+    .note = Every module can reach global state, so callers depend on values they never receive and tests interfere with one another.
+    .help = Pass the state behind `{ $name }` explicitly, or list its module in `allowed_modules` if the global is deliberate.
+
+no_global_mutable_state-static-mut = `static mut` item `{ $name }` is global mutable state.
+    .note = Every module can reach global state, so callers depend on values they never receive and tests interfere with one another.
+    .help = Pass the state behind `{ $name }` explicitly, or list its module in `allowed_modules` if the global is deliberate.
@@ -0,0 +1,5 @@
+## `include_str!` and `include_bytes!` should not embed files above the configured size.
+
+no_include_str_of_large_files = `{ $macro }!` embeds `{ $file }`, which is { $size }, above the limit of { $limit }.
+    .note = Embedded files are copied into every binary that links this crate, bloating it and slowing each rebuild.
+    .help = Load the file at run time, or ship it alongside the binary, instead of embedding it.
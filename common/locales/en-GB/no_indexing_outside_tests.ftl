@@ -0,0 +1,9 @@
+## Indexing with a run-time index panics when it is out of bounds.
+
+no_indexing_outside_tests = Indexing this { $container ->
+        [vec] `Vec`
+        [array] array
+       *[slice] slice
+    } with `{ $index }` panics when the index is out of bounds.
+    .note = Outside tests, an out-of-bounds index aborts the caller instead of letting it handle the missing element.
+    .help = Use `.get({ $index })` or `.get_mut({ $index })` and handle the `None` case.
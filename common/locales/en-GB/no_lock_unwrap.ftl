@@ -0,0 +1,5 @@
+## Lock poisoning should be handled explicitly rather than with `.lock().unwrap()`.
+
+no_lock_unwrap = `{ $lock }::{ $method }` is followed by `.{ $call }()` in { $context }.
+    .note = `{ $lock }::{ $method }` fails only when another thread panicked while holding the lock, and `.{ $call }()` turns that poisoning into a second panic without deciding whether the protected data is still usable.
+    .help = Handle the `PoisonError` explicitly, such as with `.unwrap_or_else(PoisonError::into_inner)` when the data stays valid, or use `parking_lot`, whose locks do not poison.
@@ -0,0 +1,15 @@
+## Numeric `as` casts that can truncate, round, or change sign lose information silently.
+
+no_lossy_casts = Casting `{ $from }` to `{ $to }` with `as` { $loss ->
+        [sign] wraps negative values
+        [both] wraps negative values and truncates values outside the range of `{ $to }`
+        [fraction] drops the fractional part and saturates values outside the range of `{ $to }`
+        [precision] rounds values that `{ $to }` cannot represent exactly
+       *[range] truncates values outside the range of `{ $to }`
+    }.
+    .note = `as` never fails, so a value `{ $to }` cannot hold silently becomes a different number.
+    .help = { $loss ->
+        [fraction] Round explicitly with `trunc`, `round`, `floor`, or `ceil`, and check that the value is finite and within the range of `{ $to }` before casting.
+        [precision] Keep the value in a type that represents it exactly, or allow the lint where the rounding is intended.
+       *[other] Use `{ $to }::try_from(value)` or `value.try_into()` and handle the error, or change the types so that the cast widens.
+    }
@@ -0,0 +1,8 @@
+## Functions should not recurse, directly or through other functions.
+
+no_recursion = { $kind ->
+        [direct] `{ $function }` calls itself.
+       *[mutual] `{ $function }` is part of a recursive call cycle.
+    }
+    .note = This call starts the cycle `{ $cycle }`.
+    .help = Rewrite the recursion as a loop over an explicit stack, or allow the function if its depth is bounded.
@@ -0,0 +1,9 @@
+## Bindings should not shadow unrelated values.
+
+no_shadowing = `{ $name }` shadows an earlier binding in the same scope.
+    .note = The shadowed binding `{ $name }` is declared here.
+    .help = Give the new value its own name so each name refers to one value.
+
+no_shadowing-nested = `{ $name }` shadows a binding from an enclosing scope.
+    .note = The shadowed binding `{ $name }` is declared here.
+    .help = Rename the inner binding so the outer `{ $name }` stays visible to readers.
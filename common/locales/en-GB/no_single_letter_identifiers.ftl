@@ -0,0 +1,8 @@
+## Bindings and parameters should not have single-letter names outside loops, short closures, and generic arithmetic.
+
+no_single_letter_identifiers = Single-letter { $kind ->
+        [parameter] parameter
+       *[binding] binding
+    } `{ $name }` in { $context }.
+    .note = `{ $name }` says nothing about the value it holds, so readers must trace it back to where it is bound.
+    .help = Rename `{ $name }` after what it holds, or add it to `allowed` if the name is a convention here.
@@ -0,0 +1,9 @@
+## Forbid `static mut` in favour of synchronised global state.
+
+no_static_mut = `static mut` item `{ $name }` permits unsynchronised global mutation.
+    .note = References to `static mut` items are deprecated and every access requires `unsafe`.
+    .help = Replace `{ $name }` with a `std::sync::OnceLock`, a `Mutex`, or an atomic type.
+
+no_static_mut-access = Access to `static mut` item `{ $name }` is unsynchronised.
+    .note = Reading or writing `{ $name }` races with any other thread that touches it.
+    .help = Migrate `{ $name }` to a `std::sync::OnceLock`, a `Mutex`, or an atomic type, then remove the `unsafe` block.
@@ -0,0 +1,5 @@
+## Integer arithmetic in configured modules should use `checked_*`, `saturating_*`, or `wrapping_*` methods.
+
+no_unchecked_arithmetic = Unchecked `{ $operator }` on `{ $ty }` in module `{ $module }`.
+    .note = `{ $module }` is configured to handle overflow explicitly, but `{ $operator }` panics on overflow in debug builds and wraps silently in release builds.
+    .help = Use `{ $checked }`, `{ $saturating }`, or `{ $wrapping }` to state what should happen on overflow.
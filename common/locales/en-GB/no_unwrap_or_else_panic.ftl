@@ -1,5 +1,5 @@
-## Discourage panicking unwrap_or_else fallbacks.
+## Discourage panicking unwrap_or_else and sibling fallbacks.
 
-no_unwrap_or_else_panic = Replace unwrap_or_else on { $receiver } with a non-panicking fallback.
-    .note = The closure supplied to unwrap_or_else triggers a panic.
+no_unwrap_or_else_panic = Replace { $method } on { $receiver } with a non-panicking fallback.
+    .note = The closure supplied to { $method } triggers a panic.
     .help = Propagate the error or use expect with a descriptive message instead of panicking.
@@ -0,0 +1,8 @@
+## Matches on enums defined in the same crate should name their variants rather than use a `_` arm.
+
+no_wildcard_match_on_local_enums = Wildcard arm in a match on `{ $enum }`, which this crate defines.
+    .note = A variant added to `{ $enum }` would fall into this arm silently instead of failing to compile here.
+    .help = { $fix ->
+        [list] Name the remaining variants instead: `{ $replacement }`.
+       *[spell] Replace `_` with patterns naming the variants it covers, so every variant is matched explicitly.
+    }
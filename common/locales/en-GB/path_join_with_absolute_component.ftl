@@ -0,0 +1,11 @@
+## Joining an absolute path component discards the base path.
+
+path_join_with_absolute_component = `{ $method }` with `{ $component }` discards the base path.
+    .note = `{ $component }` starts { $anchor ->
+        [drive] with a drive prefix
+       *[root] at the filesystem root
+    }, so `{ $method }` returns it unchanged instead of extending the base path.
+    .help = Remove { $anchor ->
+        [drive] the drive prefix
+       *[root] the leading separator
+    } to stay inside the base path, or use `{ $component }` on its own if an absolute path is intended.
@@ -0,0 +1,6 @@
+## `match` expressions on a `bool` should be written as `if`/`else`.
+
+pattern_match_on_boolean = `match` on a `bool` should be written as `if`/`else`.
+    .note = The arms only tell `true` from `false`, which `if`/`else` says directly without nesting each branch inside an arm.
+    .help = Replace the `match` with an `if` on the scrutinee, using the `true` arm as the `if` block and the other arm as the `else` block.
+    .suggestion = rewrite the `match` as `if`/`else`
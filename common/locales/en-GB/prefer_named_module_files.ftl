@@ -0,0 +1,5 @@
+## Prefer `foo.rs` module files over `foo/mod.rs`.
+
+prefer_named_module_files = Module `{ $module }` is implemented in a `mod.rs` file.
+    .note = `{ $module }` is defined in `{ $path }`; prefer `{ $suggestion }`.
+    .help = Move `{ $path }` to `{ $suggestion }` and keep any submodules in the `{ $module }` directory.
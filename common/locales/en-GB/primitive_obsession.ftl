@@ -0,0 +1,8 @@
+## Repeating one bare primitive across an item's positions invites mix-ups.
+
+primitive_obsession = { $kind ->
+        [struct] `{ $item }` stores `{ $primitive }` in { $count } fields.
+       *[function] `{ $item }` takes `{ $primitive }` in { $count } parameters.
+    }
+    .note = { $positions } share the bare type `{ $primitive }`, so nothing stops one being passed where another is meant.
+    .help = Introduce a newtype for each value that `{ $primitive }` stands in for, or list `{ $primitive }` in `exempt_types`.
@@ -0,0 +1,8 @@
+## Public traits meant to be sealed should require a supertrait downstream crates cannot name.
+
+public_trait_missing_sealed_pattern_marker = Public trait `{ $name }` can be implemented outside this crate.
+    .note = { $intent ->
+        [hidden] `{ $name }` requires the hidden trait `{ $supertrait }`, but `{ $supertrait }` is public, so downstream crates can implement both.
+       *[listed] `{ $name }` is listed in `sealed_traits`, but nothing stops downstream crates implementing it, so adding a required method is a breaking change.
+    }
+    .help = Require a supertrait declared in a private module, such as `private::Sealed`, and implement it only for this crate's types.
@@ -0,0 +1,6 @@
+## `else` blocks after an `if` branch ending in `continue` or `break` are redundant.
+
+redundant_else_block_after_loop_control = Redundant `else` after an `if` block that ends with `{ $control }`.
+    .note = The `if` block always ends the iteration with `{ $control }`, so the `else` body already runs only when the condition is false and the `else` just adds nesting.
+    .help = Remove the `else` and dedent its body so the `if` reads as a guard clause and the rest of the loop body stays at one indentation level.
+    .suggestion = remove the `else` and dedent its body
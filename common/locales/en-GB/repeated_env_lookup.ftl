@@ -0,0 +1,8 @@
+## An environment variable should be read once rather than looked up repeatedly in one scope.
+
+repeated_env_lookup = Environment variable `{ $key }` is looked up again in the same { $scope ->
+        [impl] `impl` block
+       *[function] function
+    }.
+    .note = `{ $key }` is first looked up here.
+    .help = Read `{ $key }` once into a local or a configuration struct and reuse the value.
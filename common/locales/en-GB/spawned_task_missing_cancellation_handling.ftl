@@ -0,0 +1,5 @@
+## Spawned tasks that loop over `.await` should watch a cancellation token so they can be shut down.
+
+spawned_task_missing_cancellation_handling = The task spawned with `{ $callee }` loops over `.await` without watching for cancellation.
+    .note = Nothing in the task reads a cancellation token, so a graceful shutdown cannot stop the loop; the task runs until the runtime drops it mid-iteration.
+    .help = Pass a `CancellationToken` into the task and `select!` on `token.cancelled()` alongside the work the loop awaits, then break out of the loop when it fires.
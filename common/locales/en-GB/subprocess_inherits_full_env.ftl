@@ -0,0 +1,5 @@
+## Commands in security-sensitive modules spawned with the full parent environment.
+
+subprocess_inherits_full_env = `{ $method }` runs a command that inherits this process's full environment.
+    .note = The command built here starts from a copy of the parent environment, including any tokens or credentials it holds.
+    .help = Call `env_clear()` and pass only the variables the child needs with `env` or `envs`, or remove sensitive variables with `env_remove`.
@@ -0,0 +1,5 @@
+## Large `#[cfg(test)]` modules should live in their own file, loaded with `#[path]`.
+
+test_file_in_src_should_use_path_attribute_pattern = Test module `{ $module }` is written inline over { $lines } lines, above the limit of { $limit }.
+    .note = Inline tests make production files longer to read and review, while a separate file keeps the code and its tests apart.
+    .help = Move the body to `{ $path }` and declare it with `#[cfg(test)] #[path = "{ $path }"] mod { $module };`.
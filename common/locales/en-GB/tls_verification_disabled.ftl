@@ -0,0 +1,5 @@
+## Disabling TLS certificate or hostname verification outside tests.
+
+tls_verification_disabled = `{ $call }` disables TLS verification outside tests.
+    .note = Without certificate and hostname checks, anyone on the network path can impersonate the server and read or alter the traffic.
+    .help = Remove the call. To trust a private or self-signed certificate, add it as a root certificate instead, or confine the call to test code.
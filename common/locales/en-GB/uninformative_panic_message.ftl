@@ -0,0 +1,12 @@
+## Expect and panic messages should describe the invariant that failed.
+
+uninformative_panic_message = { $call ->
+        [expect] This expect message does not describe what went wrong.
+       *[panic] This panic message does not describe what went wrong.
+    }
+    .note = { $problem ->
+        [empty] The message is empty.
+        [generic] The message "{ $message }" is a generic phrase that fits any failure.
+       *[short] The message "{ $message }" is shorter than { $min_length } characters.
+    }
+    .help = State the invariant that should have held, such as "config should be validated at start-up".
@@ -0,0 +1,8 @@
+## Vectors should store small sized values directly rather than boxing each element.
+
+vec_of_boxed_small_types = `Vec<Box<{ $element }>>` boxes elements of only { $size ->
+        [one] { $size } byte
+       *[other] { $size } bytes
+    }.
+    .note = Each element sits in its own heap allocation, so building the vector allocates once per item and every access follows a pointer; sized values of { $limit } bytes or fewer are cheaper to store inline.
+    .help = Store the elements directly as `Vec<{ $element }>`.
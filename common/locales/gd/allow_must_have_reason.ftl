@@ -0,0 +1,11 @@
+## Bu chòir do bhuadhan `#[allow(...)]` `reason` a thoirt seachad gus an gabh casgaidhean sgrùdadh.
+
+allow_must_have_reason = { $kind ->
+        [blank] Tha `reason` falamh aig `{ $attribute }`.
+       *[missing] Chan eil `{ $attribute }` ag innse carson a tha an lint ceadaichte.
+    }
+    .note = Cha ghabh casgaidhean gun mhìneachadh sgrùdadh, agus chan urrainn do dhuine innse cuin a bhios e sàbhailte an toirt air falbh.
+    .help = { $comments ->
+        [accepted] Cuir `reason = "..."`, no beachd `//` air an aon loidhne, ris a mhìnicheas carson nach buin an lint an-seo.
+       *[rejected] Cuir `reason = "..."` ris a mhìnicheas carson nach buin an lint an-seo.
+    }
@@ -0,0 +1,6 @@
+## Bu chòir luachan `Arc` agus `Rc` a chlonadh le `Arc::clone(&value)` seach `.clone()`.
+
+arc_clone_prefer_explicit = Clon de `{ $pointer }` air a sgrìobhadh mar `{ $receiver }.clone()`.
+    .note = Tha `{ $receiver }.clone()` a’ leughadh mar lethbhreac den luach cho-roinnte, ach chan eil e ach a’ meudachadh cunntas iomraidh an `{ $pointer }`.
+    .help = Sgrìobh `{ $replacement }` gus am bi lethbhreac saor a’ phuing soilleir.
+    .suggestion = gairm `{ $pointer }::clone` gu follaiseach
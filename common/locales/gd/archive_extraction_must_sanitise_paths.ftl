@@ -0,0 +1,5 @@
+## Slighean inntrigidhean tasglainn ceangailte ri pasgan gun sgrùdadh cuingealachaidh.
+
+archive_extraction_must_sanitise_paths = Chaidh slighe inntrigidh tasglainn a thoirt do `{ $method }` gun sgrùdadh cuingealachaidh.
+    .note = Tillidh `{ $source }` an t-slighe a tha glèidhte san tasglann, agus dh’fhaodadh `..` a bhith innte no tòiseachadh aig freumh an t-siostaim fhaidhlichean, mar sin faodaidh `{ $method }` comharrachadh taobh a-muigh a’ phasgain às-chuir.
+    .help = Diùlt inntrigidhean aig a bheil co-phàirtean `..`, freumha, no ro-leasachain nan slighe, no dèan canonach dhen t-slighe cheangailte agus dearbh gu bheil i `starts_with` am pasgan às-chuir.
@@ -0,0 +1,8 @@
+## Bu chòir do mhodhan feart `async fn` a chur an cèill no `impl Future` a thilleadh an àite àm ri teachd ann am bogsa nuair a cheadaicheas an t-inneal.
+
+async_fn_returning_boxed_future_prefer_async_trait_or_impl = Tillidh am modh `{ $method }` san fheart `{ $trait }` àm ri teachd ann am bogsa.
+    .note = Riaraichidh `{ $return }` cuimhne aig gach gairm agus falaichidh e seòrsa an ama ri teachd, agus bho Rust 1.75 ’s urrainn do fheartan modhan neo-shioncronach a chur an cèill gu dìreach.
+    .help = { $form ->
+        [impl] Cuir am modh an cèill mar `fn { $method }(...) -> impl Future<Output = { $output }> + Send`, a ghleidheas a’ chrìoch `Send`.
+       *[async] Cuir am modh an cèill mar `async fn { $method }(...) -> { $output }`.
+    }
@@ -0,0 +1,5 @@
+## Bu chòir do lùban `for` a tha a’ feitheamh ri àmhailean nach eil an urra ri ath-thriallan nas tràithe an ruith aig an aon àm.
+
+await_in_loop_without_concurrency = Bidh gach ath-thriall a’ feitheamh ri àmhail a chaidh a thogail à `{ $item }` mus tòisich an ath-thriall as ath.
+    .note = Chan eil an àmhail an urra ri ath-thriallan nas tràithe, mar sin ma dh’fheitheas tu ris na h-àmhailean fear seach fear, cha bhi ach aon dhiubh a’ dèanamh adhartas.
+    .help = Cruinnich na h-àmhailean agus fuirich riutha còmhla le `futures::future::join_all`, no dèan sruth dhe na nithean agus cleachd `buffer_unordered` gus crìoch a chur air co mheud a ruitheas aig an aon àm.
@@ -8,9 +8,7 @@ bumpy_road_function = Tha iomadh cruinneachadh de loidsig chumhachail neadaichte
        *[other] bumps
     }" iom-fhillteachd a lorg os cionn na stairsnich { $threshold }.
     .help = Tarraing a-mach gnìomhan-taice bho na raointean comharraichte gus an iom-fhillteachd cruinnichte a lùghdachadh.
-    .label = Tha bump iom-fhillteachd { $index } a’ leudachadh thairis air { $lines } { $lines ->
-        [one] loidhne
-        [two] loidhnichean
-        [few] loidhnichean
-       *[other] loidhnichean
+    .bump = Tha bump iom-fhillteachd { $index } a’ còmhdachadh { $lines ->
+        [one] loidhne { $start }
+       *[other] loidhnichean { $start }–{ $end }
     }.
@@ -0,0 +1,5 @@
+## Tilgidh `unwrap_or_default` air `Result` le mearachd a ghabhas sealltainn am fàilligeadh gu sàmhach.
+
+chained_unwrap_or_default_hides_errors = Tilgidh `.unwrap_or_default()` mearachd `{ $error }` ann an { $context }.
+    .note = Tha `{ $error }` a’ cur `Display` an gnìomh, mar sin bha dùil aithris a dhèanamh air, ach cuiridh `.unwrap_or_default()` dàta bunaiteach a tha coltach ri soirbheachas falamh an àite an fhàilligidh.
+    .help = Sgaoil a’ mhearachd le `?`, no cleachd `.unwrap_or_else(|error| ...)` gus a clàradh mus till thu gu luach bunaiteach.
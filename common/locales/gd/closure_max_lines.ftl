@@ -0,0 +1,5 @@
+## Bu chòir do dhùnaidhean a bhith goirid.
+
+closure_max_lines = Tha bodhaig an dùnaidh a’ sìneadh thar { $lines } loidhnichean, nas motha na a’ chrìoch de { $limit }.
+    .note = Bidh dùnaidhean fada a’ falach loidsig a tha airidh air ainm agus deuchainnean fhèin.
+    .help = Tarraing bodhaig an dùnaidh a-mach gu gnìomh le ainm agus cuir seachad e le slighe no gairm e bho dhùnadh goirid.
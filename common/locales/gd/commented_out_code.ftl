@@ -0,0 +1,5 @@
+## Bu chòir beachdan anns a bheil còd air a chur na bheachd a sguabadh às.
+
+commented_out_code = Tha còd air a chur na bheachd anns a’ bheachd seo.
+    .note = Tha smachd air tionndaidhean a’ cumail eachdraidh a’ chòd a chaidh a sguabadh às mu thràth.
+    .help = Sguab às an còd air a chur na bheachd agus faigh air ais e bho smachd air tionndaidhean ma bhios feum air a-rithist.
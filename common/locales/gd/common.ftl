@@ -12,3 +12,31 @@ common-lint-count = Cunntas nan lints: { $lint }.
 
 #. Air a shealltainn ann an breithneachaidhean nuair a tha e a’ toirt iomradh air am buadh roimhe.
 common-attribute-fallback = am buadh roimhe
+
+#. A’ toirt cunntas air an nì a tha timcheall air làrach breithneachaidh. Is e
+#. `$kind` fear de function, method, closure, const, static, no scope.
+common-enclosing-item = { $kind ->
+        [function] gnìomh `{ $name }`
+        [method] modh `{ $name }`
+        [closure] dùnadh am broinn `{ $name }`
+        [const] cunbhalach `{ $name }`
+        [static] caochladair stàiteach `{ $name }`
+       *[scope] an raon mun cuairt
+    }
+
+#. Air fhàgail air eas-preisean am broinn gnìomh-taice a tha air liosta
+#. `blame_helpers`, agus thèid an lorg aige aithris aig gach gairm dha.
+common-blamed-helper = Tha an lorg sa ghnìomh-taice `{ $helper }`, a tha air liosta `blame_helpers`, agus mar sin thèid aithris far an tèid an gnìomh-taice a ghairm.
+
+#. Air a sgaoileadh aon turas airson gach lint is crate nuair a chùm
+#. `max_findings_per_lint_per_crate` loirg air ais. Tha an diagnosachd a’
+#. comharrachadh a’ chiad lorg nach deach a shealltainn.
+common-findings-suppressed = Ràinig `{ $lint }` a chrìoch de { $limit } loirg sa crate seo; cha deach { $remaining } eile a shealltainn.
+    .note = Cuingichidh `max_findings_per_lint_per_crate` na loirg a dh’aithriseas gach lint anns gach crate. Tha an diagnosachd seo a’ comharrachadh a’ chiad lorg nach deach a shealltainn.
+    .help = Àrdaich no thoir air falbh `max_findings_per_lint_per_crate` ann an `dylint.toml` gus gach lorg a liostadh ann an loga an togail agus san aithisg JSON o `whitaker check`.
+
+#. Air a sgaoileadh aon turas airson gach crate leis an t-sreath nuair a tha
+#. `crate_summary` air a chur an comas. Thig àireamh gach lint mar nòta fa leth.
+common-crate-summary = Dh’aithris Whitaker { $total } loirg o { $lints } lint sa crate `{ $crate }`.
+    .note = Bheir gach loidhne àireamh iomlan lint airson a’ crate seo, a’ gabhail a-steach loirg nach do sheall `max_findings_per_lint_per_crate`.
+    .help = Suidhich `crate_summary = false` ann an `dylint.toml` gus an geàrr-chunntas seo a chur dheth.
@@ -0,0 +1,5 @@
+## Bu chòir do raointean `#[serde(default)]` ann an structaran rèiteachaidh an luach bunaiteach aca innse nan docamaideadh.
+
+config_struct_fields_must_have_defaults_documented = Tha luach bunaiteach serde aig an raon `{ $field }` de `{ $type }` nach eil an docamaideadh aige ag innse.
+    .note = Chan urrainn do luchd-leughaidh `{ $type }` innse dè an luach a bhios aig `{ $field }` nuair a dh'fhàgas an rèiteachadh às e.
+    .help = Innis an luach bunaiteach ann am beachd docamaid an raoin, mar eisimpleir "Defaults to 3.".
@@ -0,0 +1,5 @@
+## Bu chòir do thogalaichean le mòran argamaidean togalach a chleachdadh.
+
+constructor_max_arguments = Gabhaidh `{ $constructor }` { $arguments } argamaidean, nas motha na a’ chrìoch de { $limit }.
+    .note = Feumaidh luchd-gairm gach argamaid a thoirt seachad san òrdugh cheart, agus tha e furasta argamaidean den aon seòrsa a mhalairt.
+    .help = Cuir togalach ris airson `{ $type }`, no cruinnich na h-argamaidean ann an structar rèiteachaidh.
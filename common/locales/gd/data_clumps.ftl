@@ -0,0 +1,5 @@
+## Bu chòir do bhuidhnean pharamadairean a shiùbhlas còmhla a bhith nan struct.
+
+data_clumps = Nochdaidh na paramadairean { $parameters } còmhla ann an { $count } soidhnidhean fhoincseanan.
+    .note = Gabhaidh { $functions } na h-aon pharamadairean cuideachd.
+    .help = Cuir { $parameters } còmhla ann an struct agus cuir sin seachad na àite.
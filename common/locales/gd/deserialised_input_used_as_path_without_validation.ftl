@@ -0,0 +1,5 @@
+## Ion-chur air a dhì-shreathachadh air a thoirt do ghairm siostam fhaidhlichean gun dearbhadh.
+
+deserialised_input_used_as_path_without_validation = Ruigidh ion-chur air a dhì-shreathachadh `{ $sink }` gun dearbhadh.
+    .note = Dì-chòdaichidh `{ $source }` dàta a thagh cò sam bith a rinn e, mar sin dh’fhaodadh `..` a bhith san t-slighe no tòiseachadh aig freumh an t-siostaim fhaidhlichean.
+    .help = Diùlt slighean aig a bheil co-phàirtean `..`, freumha, no ro-leasachain mus cleachd thu iad, agus cuir an gnìomh sgrùdaidh ri `validators`.
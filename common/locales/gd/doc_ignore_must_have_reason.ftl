@@ -0,0 +1,9 @@
+## Bu chòir do eisimpleirean docamaideachaidh `ignore` innse carson air loidhne na feansa.
+
+doc_ignore_must_have_reason = Thathar a’ leigeil seachad an eisimpleir docamaideachaidh le feansa `{ $info }` gun adhbhar.
+    .note = Cha tèid eisimpleir `ignore` a chur ri chèile no a ruith idir, mar sin faodaidh e gluasad air falbh bhon chòd a tha e a’ docamaideachadh, agus chan urrainn do leughadairean innse an deach a leigeil seachad a dh’aon ghnothach.
+    .help = Thoir an t-adhbhar eadar camagan air loidhne na feansa, mar ann an `{ $info } (needs a running database)`, no cleachd `no_run` ma tha an eisimpleir fhathast ga chur ri chèile.
+
+doc_ignore_must_have_reason-crate = Thathar a’ leigeil seachad { $ignored } de na { $total } eisimpleirean docamaideachaidh sa chrate seo ({ $percent }%), os cionn na crìche de { $limit }%.
+    .note = Cha tèid eisimpleirean a leigear seachad a chur ri chèile idir, mar sin chan eil a’ chuibhreann sin de dhocamaideachadh a’ chrate seo ga dhearbhadh.
+    .help = Dèan gun tèid eisimpleirean a chur ri chèile le loidhnichean rèiteachaidh falaichte a thòisicheas le `# `, no comharraich iad le `no_run` nuair nach bu chòir dhaibh ruith.
@@ -0,0 +1,5 @@
+## Bu chòir blocaichean aithrisean a tha cha mhòr co-ionann a thoirt a-mach gu foincsean co-roinnte.
+
+duplicated_blocks = Tha `{ $function }` ag ath-aithris { $statements } aithrisean à `{ $original }`.
+    .note = Tha am bloca ann an `{ $original }` { $similarity }% coltach.
+    .help = Thoir na h-aithrisean co-roinnte a-mach gu foincsean-taice as urrainn don dà chuid a ghairm.
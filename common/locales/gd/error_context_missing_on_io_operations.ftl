@@ -0,0 +1,5 @@
+## Bu chòir co-theacsa a chur ri mearachdan I/O mus atharraich `?` iad gu seòrsa mearachd beairteach.
+
+error_context_missing_on_io_operations = Tha `?` a’ cur a’ mhearachd I/O bho `{ $operation }` air adhart gun cho-theacsa.
+    .note = Chan ainmich `std::io::Error` an gnìomh no an t-slighe a dh’fhàillig, agus is urrainn do `{ $error }` an dà chuid a chlàradh.
+    .help = Cuir co-theacsa ris ro `?`, mar eisimpleir le `.with_context(|| ...)`, ag ainmeachadh na slighe no a’ ghnìomha.
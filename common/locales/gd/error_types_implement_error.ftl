@@ -0,0 +1,14 @@
+## Bu chòir do sheòrsachan mearachd poblach `Display` agus `std::error::Error` a bhuileachadh.
+
+error_types_implement_error = Cha bhuilich an seòrsa mearachd `{ $type }` { $missing ->
+        [error] `std::error::Error`
+       *[both] `Display` no `std::error::Error`
+    }.
+    .note = { $role ->
+        [returned] Is e `{ $type }` seòrsa mearachd a’ ghnìomh phoblaich `{ $function }`
+       *[named] Tha `{ $type }` poblach agus air ainmeachadh mar sheòrsa mearachd
+    }; tha { $missing ->
+        [error] `impl std::error::Error for { $type }`
+       *[both] `impl std::fmt::Display for { $type }` agus `impl std::error::Error for { $type }`
+    } a dhìth air.
+    .help = Dèan na impls le `#[derive(thiserror::Error)]` agus teachdaireachd `#[error("...")]`, no sgrìobh iad le làimh, gus an urrainn do ghairmichean a’ mhearachd a chlò-bhualadh agus a cleachdadh le `?`, `Box<dyn Error>`, agus slabhraidhean tùsail.
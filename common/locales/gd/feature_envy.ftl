@@ -0,0 +1,5 @@
+## Cha bu chòir do mhodhan seòrsa eile a chleachdadh nas motha na an seòrsa fhèin.
+
+feature_envy = Tha `{ $method }` a’ cleachdadh `{ $target }` nas motha na an seòrsa fhèin ({ $foreign } ruigsinneachd an aghaidh { $own }).
+    .note = Buinidh modhan a bhios ag obair sa mhòr-chuid le dàta seòrsa eile mar as trice don t-seòrsa sin.
+    .help = Gluais `{ $method }`, no am pàirt a tha ag obair le `{ $target }`, gu `{ $target }`.
@@ -0,0 +1,5 @@
+## Bu chòir do ath-às-mhalairtean `pub use` a tha air an geataichean air feartan `#[cfg_attr(docsrs, doc(cfg(...)))]` a ghiùlan gus an seall na docamaidean an geata.
+
+feature_gated_pub_use_must_have_cfg_doc = Chan eil buadh `doc(cfg(...))` aig an ath-às-mhalairt a tha air a geatachadh air `{ $predicate }`.
+    .note = Seallaidh docamaidean a chaidh a thogail leis a h-uile feart an ath-às-mhalairt gun innse gu bheil feum aice air `{ $predicate }`.
+    .help = Cuir `{ $attribute }` ris gus an cuir rustdoc leubail air an ath-às-mhalairt le a geata.
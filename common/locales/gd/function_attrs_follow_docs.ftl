@@ -3,3 +3,4 @@
 function_attrs_follow_docs = Feumaidh beachdan doc air { $subject } a bhith ro bhuadhan taobh a-muigh eile.
     .note = Tha am buadh { $attribute } a’ nochdadh mus am beachd doc.
     .help = Gluais am beachd doc gus a bhith ro { $attribute } air an nì.
+    .suggestion = gluais am beachd doc os cionn { $attribute }
@@ -0,0 +1,5 @@
+## Cha bu chòir barrachd às-fhalbhan `return` is `?` a bhith aig foincseanan na a’ chrìoch a chaidh a shuidheachadh.
+
+function_max_return_points = Chaidh { $total } puingean tillidh a lorg ann an { $context }, nas motha na a’ chrìoch de { $limit }.
+    .note = Taobh a-muigh a chlàsaichean-dìona tha { $returns } `return` agus { $questions } `?` aig an fhoincsean, agus ’s e slighe eile a th’ anns gach fear a dh’fheumas leughadairean leantainn.
+    .help = Tarraing na ceuman eadar às-fhalbhan a-mach gu foincseanan-taice, no cuir sgrùdaidhean co-cheangailte còmhla gus nach bi uiread de shlighean a-mach aig an fhoincsean.
@@ -0,0 +1,5 @@
+## Bu chòir do ghnìomhan coitcheann mòra a thèid a chleachdadh le iomadh seata de dh’argamaidean coitcheann tiomnadh do ghnìomh a-staigh nach eil coitcheann.
+
+generic_function_monomorphisation_bloat = Tha an gnìomh coitcheann `{ $name }` a’ sìneadh thar { $lines } loidhnichean agus ga chleachdadh le { $count } seataichean eadar-dhealaichte de dh’argamaidean coitcheann.
+    .note = Bidh an trusaiche a’ sgaoileadh lethbhreac den bhodhaig gu lèir airson gach seata de dh’argamaidean coitcheann, a’ cur ri ùine togail agus meud a’ bhinary.
+    .help = Gluais a’ bhodhaig gu gnìomh a-staigh nach eil coitcheann, agus cùm a-mhàin tionndadh nan argamaidean, leithid `.as_ref()` no `.into()`, sa chòmhdach choitcheann.
@@ -0,0 +1,5 @@
+## Cha bu chòir feartan ionadail a bhith air an cur an gnìomh airson `T` agus `&T` no `&mut T` le chèile.
+
+impl_trait_for_reference_smell = Tha `{ $trait }` air a chur an gnìomh airson `{ $self_ty }` a bharrachd air `{ $referent }`.
+    .note = Tha an cur an gnìomh airson `{ $referent }` an seo. Bidh gairmean dòigh a’ taghadh eatorra le fèin-iomradh, mar sin faodaidh an aon ghairm an dàrna tè a ruigsinn a rèir mar a chaidh an gabhadair fhaighinn air iasad.
+    .help = Cùm a-mhàin an cur an gnìomh airson `{ $referent }` agus leig le luchd-gairm a ruigsinn tron iomradh, no thoir air an dà chuid an aon ghiùlan a bhith aca.
@@ -0,0 +1,10 @@
+## Bu chòir do chuidichean prìobhaideach `impl Iterator` a thilleadh nuair nach dèan gach neach-gairm ach ath-aithris thar a’ `Vec` a chaidh a chruinneachadh.
+
+iterator_returning_fn_should_not_collect_internally = Chan eil gach neach-gairm aig { $context } a’ dèanamh ach ath-aithris thar a’ `Vec<{ $item }>` a tha e a’ cruinneachadh.
+    .note = Tha an crate ga ghairm { $calls } { $calls ->
+        [one] turas
+        [two] thuras
+        [few] tursan
+       *[other] turas
+    }, agus bidh gach gairm a’ coiseachd tron toradh aon turas gun chlàr-amais no gun a chumail, mar sin chan eil an vector air a riarachadh ach airson a chaitheamh.
+    .help = Till `impl Iterator<Item = { $item }>` agus thoir air falbh an gairm `collect()` mu dheireadh gus am bi na nithean a’ sruthadh dìreach chun luchd-gairm.
@@ -0,0 +1,11 @@
+## Cha bu chòir do chaochladairean ionadail is argamaidean luachan ro mhòr airson na stac a chumail.
+
+large_stack_values = { $kind ->
+        [argument] Tha an argamaid
+       *[local] Tha an caochladair ionadail
+    } `{ $name }` ann an { $context } { $size } baidht, nas motha na a’ chrìoch de { $limit }.
+    .note = Tha `{ $type }` a’ gabhail àite air an stac fhad ’s a tha `{ $name }` beò, agus bidh gach gluasad a’ dèanamh lethbhreac de na { $size } baidht uile.
+    .help = { $kind ->
+        [argument] Gabh an argamaid mar iomradh no mar `Box<{ $type }>`.
+       *[local] Riaraich an luach air an tiùrr, mar eisimpleir mar `Box<{ $type }>` no `Vec`, air chor ’s nach bi ach comharraiche air an stac.
+    }
@@ -0,0 +1,5 @@
+## Bu chòir do chrates lint a chuingicheas nithean ri `dylint-driver` modal bun a chur an cèill airson thogalaichean às aonais.
+
+lint_crate_missing_stub_for_non_driver_builds = Tha an crate lint `{ $crate }` a’ cuingeachadh a nithean ri `{ $feature }` ach chan eil modal bun aige airson thogalaichean às aonais.
+    .note = Cha chuir togalaichean leis na feartan bunaiteach cruinn gin de chòd an lint, agus cha nochd am briseadh ach nuair a thogas cuideigin `{ $crate }` às aonais `{ $feature }`.
+    .help = Cuir `#[cfg(not(feature = "{ $feature }"))] mod stub {"{"} pub fn { $crate }_disabled_stub() {"{"}{"}"} {"}"}` ri freumh a’ chrate.
@@ -0,0 +1,5 @@
+## Bu chòir do shlabhraidhean mhodhan a bhith goirid.
+
+method_chain_max_length = Tha slabhraidh mhodhan de { $calls } gairmean nas fhaide na a’ chrìoch de { $limit }.
+    .note = Gu tric bidh slabhraidhean fada air aon ghlacadair a’ falach astarachadh meadhanach a tha a dhìth.
+    .help = Ceangail toraidhean meadhanach ri caochladairean le ainmean no tarraing a-mach gnìomh-taice; liostaich seòrsaichean togalaiche fo `builder_types` gus an saoradh.
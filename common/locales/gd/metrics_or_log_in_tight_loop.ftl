@@ -0,0 +1,5 @@
+## Bu chòir macros logaidh is meatrachdan a thèid a ghairm air gach ath-thriall de lùb as fhaide a-staigh a chruinneachadh taobh a-muigh na lùib.
+
+metrics_or_log_in_tight_loop = Bidh `{ $name }!` a’ ruith air gach ath-thriall dhen lùb seo.
+    .note = Bidh gach gairm a’ cruthachadh clàr no ag ùrachadh meatrachd, agus faodaidh sin barrachd a chosg na an obair a nì an lùb airson aon nì nuair a tha an cruinneachadh mòr.
+    .help = Cruinnich taobh a-staigh na lùib, mar chunntas, iomlan, no beagan nithean sampaill, agus logaich no clàraich an toradh aon turas às dèidh na lùib.
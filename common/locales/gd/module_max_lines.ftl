@@ -1,5 +1,9 @@
 ## Riaghailt air faid mòideil.
 
-module_max_lines = Tha mòideal { $module } a’ leudachadh gu { $lines } loidhnichean agus a’ briseadh an crìoch { $limit }.
+module_max_lines = Tha mòideal { $module } { $mode ->
+        [code_only] a’ gabhail a-steach { $lines } loidhnichean còd
+        [non_blank] a’ gabhail a-steach { $lines } loidhnichean nach eil bàn
+       *[physical] a’ leudachadh gu { $lines } loidhnichean
+    } agus a’ briseadh an crìoch { $limit }.
     .note = Tha mòidealan mòra nas duilghe an ath-sgrùdadh.
     .help = Roinn { $module } no lughdaich an luchd dleastanais.
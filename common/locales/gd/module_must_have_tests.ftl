@@ -0,0 +1,15 @@
+## Bu chòir modal `#[cfg(test)]` a bhith am broinn mhodalan susbainteach no companach `*_tests.rs` a bhith aca.
+
+module_must_have_tests = Chan eil deuchainnean aig a’ mhodal `{ $module }`.
+    .note = Tha `{ $module }` a’ foillseachadh { $items } { $items ->
+        [one] nì
+        [two] nì
+        [few] nithean
+       *[other] nì
+    } thar { $lines } { $lines ->
+        [one] loidhne
+        [two] loidhne
+        [few] loidhnichean
+       *[other] loidhne
+    }, ach chan eil modal `#[cfg(test)]` na bhroinn agus chan eil faidhle companach `{ $module }_tests.rs` aige.
+    .help = Cuir modal `#[cfg(test)]` ris a nì deuchainn air `{ $module }`, taobh a-staigh an fhaidhle no air a luchdadh o fhaidhle `{ $module }_tests.rs` ri thaobh.
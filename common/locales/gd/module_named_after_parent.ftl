@@ -0,0 +1,12 @@
+## Cha bu chòir do mhòidealan ainm am pàrant ath-aithris, no do sheòrsaichean ainm am mòideil.
+
+module_named_after_parent = Tha am mòideal `{ $path }` ag ath-aithris ainm a mhòideil phàraint.
+    .note = Tha slighean troimhe a’ leughadh `{ $path }`, ag ainmeachadh an aon mhòideal dà thuras gun innse dè tha am fear a-staigh a’ cur ris.
+    .help = { $fix ->
+        [rename] Thoir ainm ùr air a rèir na tha ann, mar `{ $parent }::{ $suggestion }`, no gluais na nithean aige suas gu `{ $parent }`.
+       *[flatten] Gluais na nithean aige suas gu `{ $parent }`, no thoir ainm ùr air a rèir na tha e a’ cur ri `{ $parent }`.
+    }
+
+module_named_after_parent-item = Tha `{ $item }` ag ath-aithris ainm a mhòideil `{ $module }`.
+    .note = Bidh luchd-gairm mu thràth a’ sgrìobhadh `{ $module }::{ $item }`, mar sin nochdaidh ainm a’ mhòideil dà thuras anns gach slighe.
+    .help = Thoir `{ $suggestion }` mar ainm air gus am bi an t-slighe a’ leughadh `{ $module }::{ $suggestion }`.
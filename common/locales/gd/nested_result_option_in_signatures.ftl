@@ -0,0 +1,13 @@
+## Cha bu chòir do shoidhnichean poblach `Result` agus `Option` a neadachadh nas doimhne na doimhneachd a ghabhas rèiteachadh.
+
+nested_result_option_in_signatures = Tha { $position ->
+        [parameter] am paramadair `{ $parameter }`
+       *[return] an seòrsa tillidh
+    } aig { $context } a’ neadachadh { $depth } sreathan `Result` agus `Option`, nas motha na a’ chrìoch de { $limit }.
+    .note = { $collapse ->
+        [same-error] Tha sreath { $layer } ag ath-aithris an seòrsa mearachd `{ $error }` aig `Result` a-muigh, mar sin is dòcha gun urrainn do `?` a phasgadh a-steach don t-sreath sin.
+        [repeated-option] Tha sreath { $layer } na `Option` dìreach am broinn `Option` eile, mar sin is dòcha gun urrainn do `Option::flatten` an dà chuid a phasgadh còmhla.
+        [distinct-errors] Tha sreath { $layer } a’ fàiligeadh le `{ $error }` am broinn `Result` a tha a’ fàiligeadh le `{ $outer_error }`, mar sin leigeadh enum mhearachdan a chòmhdaicheas an dà chuid leis an dà shreath tuiteam còmhla.
+       *[dedicated-enum] Chan eil sreath sam bith ag ath-aithris tè eile, mar sin tha gach tè a’ riochdachadh toradh fa leth a dh’fheumas luchd-gairm fhuasgladh ann an òrdugh.
+    }
+    .help = Dèan an seòrsa rèidh le `?`, `Option::flatten`, no enum mhearachdan, no till enum sònraichte a dh’ainmicheas gach toradh.
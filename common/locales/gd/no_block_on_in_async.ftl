@@ -0,0 +1,9 @@
+## Cuiridh bacadh air àm ri teachd ann an còd async stad air an àm-ruith.
+
+no_block_on_in_async = Bacaidh `{ $callee }` an snàithlean am broinn { $context ->
+        [function] gnìomh async
+        [block] bloca async
+       *[closure] dùnadh async
+    }.
+    .note = Chan urrainn don t-snàithlean bacte an t-àm-ruith a stiùireadh, mar sin thig glas air gnìomhaiche aon-snàithlein agus cuiridh fear ioma-snàithlein stad air a chuid obrach eile.
+    .help = Cleachd `.await` air an àm ri teachd na àite, no gluais an obair bhacaidh gu `spawn_blocking` no snàithlean sònraichte.
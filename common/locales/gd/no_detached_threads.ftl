@@ -0,0 +1,9 @@
+## Ma thilgear làmh-cheangail snàithlein, thèid an snàithlean a sgaoileadh.
+
+no_detached_threads = Tha an snàithlean seo sgaoilte oir chaidh a `JoinHandle` { $discard ->
+        [binding] a cheangal ri `_`
+        [drop] a thoirt do `drop`
+       *[statement] a leigeil às aig deireadh na h-aithris
+    }.
+    .note = Chan urrainn do dhad snàithlean sgaoilte a cheangal, mar sin cha mhothaichear dha chlisgeadh agus faodaidh e leantainn às dèidh na deuchainn no an neach-gairm a thòisich e.
+    .help = Glèidh an làmh agus cleachd `join` oirre, cleachd `std::thread::scope`, no cuir am modal seo ri `allowed_modules` ma tha an snàithlean gu ruith sa chùlaibh.
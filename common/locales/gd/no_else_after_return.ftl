@@ -0,0 +1,6 @@
+## Bu chòir bacaidhean `else` às dèidh meur `if` a chrìochnaicheas le `return`, `continue`, no `break` a thoirt air falbh.
+
+no_else_after_return = `else` gun fheum às dèidh bacadh `if` a chrìochnaicheas le `{ $exit }`.
+    .note = Bidh am bacadh `if` an-còmhnaidh a’ fàgail le `{ $exit }`, mar sin chan eil bodhaig an `else` a’ ruith ach nuair a tha an cumha ceàrr, agus chan eil an `else` ach a’ cur neadachadh ris.
+    .help = Thoir air falbh an `else` agus lùghdaich eag a bhodhaig gus am bi an `if` na chlàs-dìona; bidh meuran nas còmhnairde cuideachd a’ lùghdachadh an neadachaidh a tha `bumpy_road_function` a’ tomhas.
+    .suggestion = thoir air falbh an `else` agus lùghdaich eag a bhodhaig
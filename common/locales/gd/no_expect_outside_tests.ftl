@@ -1,9 +1,14 @@
 ## Tha `expect` toirmisgte taobh a-muigh deuchainnean.
 
-no_expect_outside_tests = Na gairm `expect` air { $receiver } taobh a-muigh còd deuchainnean.
+no_expect_outside_tests = Na gairm `{ $method }` air { $receiver } taobh a-muigh còd deuchainnean.
     .note = Tha an gairm a’ tighinn bho { $context } nach eil air aithneachadh mar dheuchainn.
     .help = { $handling ->
         [option] Dèilig ri cùis `None` aig { $receiver } no gluais an còd gu deuchainn.
         [result] Dèilig ri caochladh `Err` aig { $receiver } no gluais an còd gu deuchainn.
+        [ok] Dèilig ri caochladh `Ok` aig { $receiver } no gluais an còd gu deuchainn.
        *[other] Dèilig ris an t-slighe mhearachd aig { $receiver } no gluais an còd gu deuchainn.
     }
+    .suggestion = { $fix ->
+        [propagate] sgaoil am fàillinn le `?`
+       *[context] ceangail an teachdaireachd le `{ $replacement }`
+    }
@@ -0,0 +1,5 @@
+## Tha coimeas luachan puing-fleòdraidh airson co-ionannachd chruinn an urra ri cruinneachadh.
+
+no_float_equality = Ma thèid luachan `{ $ty }` a choimeas le `{ $operator }`, thathar a’ dearbhadh co-ionannachd chruinn.
+    .note = Fàgaidh cruinneachadh toraidhean a bu chòir a bhith co-ionann eadar-dhealaichte sna biodan mu dheireadh aca, agus mar sin dh’fhaodadh coimeas cruinn fàiligeadh no soirbheachadh gun fhiosta.
+    .help = Coimeas an diofar ri fulangas, leithid `(a - b).abs() <= { $ty }::EPSILON`, no cleachd `{ $ty }::total_cmp` nuair a tha thu ag iarraidh coimeas cruinn.
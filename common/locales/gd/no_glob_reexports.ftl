@@ -0,0 +1,8 @@
+## Bu chòir do chrates leabharlainn nithean ath-às-phortadh air an ainm seach le `pub use path::*`.
+
+no_glob_reexports = Ath-às-phortadh glob de `{ $source }` san API phoblach.
+    .note = Atharraichidh gach nì poblach a thèid a chur ri `{ $source }` no a thoirt às API a’ chrate seo gun an t-atharrachadh a nochdadh an seo.
+    .help = { $fix ->
+        [list] Ath-às-phortaich na nithean air an ainm: `{ $replacement }`.
+       *[remove] Chan eil an glob a’ dèanamh dad poblach; thoir air falbh e.
+    }
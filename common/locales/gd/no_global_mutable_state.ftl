@@ -0,0 +1,9 @@
+## Bidh aonaran caochlaideach aig ìre a’ chrate a’ falach staid cho-roinnte.
+
+no_global_mutable_state = Tha `{ $name }` na aonaran caochlaideach cruinneil fo dhìon `{ $lock }`.
+    .note = Ruigidh gach modal staid chruinneil, mar sin bidh luchd-gairm an eisimeil luachan nach fhaigh iad idir agus bidh deuchainnean a’ bacadh a chèile.
+    .help = Thoir seachad an staid air cùlaibh `{ $name }` gu soilleir, no cuir a mhodal ri `allowed_modules` ma tha an luach cruinneil a dh’aon ghnothach.
+
+no_global_mutable_state-static-mut = Tha an nì `static mut` `{ $name }` na staid chaochlaideach chruinneil.
+    .note = Ruigidh gach modal staid chruinneil, mar sin bidh luchd-gairm an eisimeil luachan nach fhaigh iad idir agus bidh deuchainnean a’ bacadh a chèile.
+    .help = Thoir seachad an staid air cùlaibh `{ $name }` gu soilleir, no cuir a mhodal ri `allowed_modules` ma tha an luach cruinneil a dh’aon ghnothach.
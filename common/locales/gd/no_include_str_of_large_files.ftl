@@ -0,0 +1,5 @@
+## Cha bu chòir do `include_str!` agus `include_bytes!` faidhlichean nas motha na am meud a chaidh a rèiteachadh a leabachadh.
+
+no_include_str_of_large_files = Tha `{ $macro }!` a’ leabachadh `{ $file }`, a tha { $size }, os cionn a’ chrìoch de { $limit }.
+    .note = Thèid lethbhreac de dh’fhaidhlichean leabaichte a chur anns gach faidhle bìnearaidh a cheanglas an crate seo, ga dhèanamh nas motha agus a’ slaodadh gach ath-thogail.
+    .help = Luchdaich am faidhle aig àm ruith, no cuir e còmhla ris a’ bhìnearaidh, seach a leabachadh.
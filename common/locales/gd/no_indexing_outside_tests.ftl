@@ -0,0 +1,9 @@
+## Ma thèid clàr-amais aig àm ruith taobh a-muigh nan crìochan, thig clisgeadh.
+
+no_indexing_outside_tests = Ma thèid { $container ->
+        [vec] an `Vec`
+        [array] an t-sreath
+       *[slice] an sliseag
+    } seo a chlàr-amais le `{ $index }`, thig clisgeadh nuair a bhios an clàr-amais taobh a-muigh nan crìochan.
+    .note = Taobh a-muigh deuchainnean, cuiridh clàr-amais taobh a-muigh nan crìochan stad air an neach-gairm an àite cothrom a thoirt dha dèiligeadh ris an eileamaid a tha a dhìth.
+    .help = Cleachd `.get({ $index })` no `.get_mut({ $index })` agus dèilig ris a’ chùis `None`.
@@ -0,0 +1,5 @@
+## Bu chòir puinnseanachadh ghlasan a làimhseachadh gu soilleir seach le `.lock().unwrap()`.
+
+no_lock_unwrap = Tha `.{ $call }()` a’ leantainn `{ $lock }::{ $method }` ann an { $context }.
+    .note = Chan fhàillig `{ $lock }::{ $method }` ach nuair a chaidh snàithlean eile ann am panic fhad ’s a bha e a’ cumail a’ ghlais, agus tionndaidhidh `.{ $call }()` am puinnseanachadh sin gu dàrna panic gun cho-dhùnadh a bheil an dàta dìonta fhathast feumail.
+    .help = Làimhsich am `PoisonError` gu soilleir, mar eisimpleir le `.unwrap_or_else(PoisonError::into_inner)` nuair a tha an dàta fhathast dligheach, no cleachd `parking_lot`, nach bi a ghlasan air am puinnseanachadh.
@@ -0,0 +1,15 @@
+## Caillidh tilgeadh `as` àireamhach a ghearras, a chruinnicheas no a dh’atharraicheas an soidhne fiosrachadh gun fhiosta.
+
+no_lossy_casts = Ma thèid `{ $from }` a thilgeadh gu `{ $to }` le `as`, { $loss ->
+        [sign] paisgear luachan àicheil
+        [both] paisgear luachan àicheil agus gearrar luachan taobh a-muigh raon `{ $to }`
+        [fraction] caillear a’ phàirt bhloighteach agus sàthaichear luachan taobh a-muigh raon `{ $to }`
+        [precision] cruinnichear luachan nach urrainn do `{ $to }` a riochdachadh gu pongail
+       *[range] gearrar luachan taobh a-muigh raon `{ $to }`
+    }.
+    .note = Cha bhi `as` a’ fàiligeadh idir, agus mar sin fàsaidh luach nach urrainn do `{ $to }` a chumail na àireamh eile gun fhiosta.
+    .help = { $loss ->
+        [fraction] Cruinnich gu soilleir le `trunc`, `round`, `floor` no `ceil`, agus dearbh gu bheil an luach crìochnaichte agus am broinn raon `{ $to }` mus tilg thu e.
+        [precision] Cùm an luach ann an seòrsa a riochdaicheas e gu pongail, no ceadaich an lint far a bheil an cruinneachadh a dh’aon ghnothach.
+       *[other] Cleachd `{ $to }::try_from(value)` no `value.try_into()` agus làimhsich a’ mhearachd, no atharraich na seòrsachan gus am fàs an tilgeadh nas leithne.
+    }
@@ -0,0 +1,8 @@
+## Cha bu chòir do dh’fhoincseanan iad fhèin a ghairm, gu dìreach no tro fhoincseanan eile.
+
+no_recursion = { $kind ->
+        [direct] Tha `{ $function }` ga ghairm fhèin.
+       *[mutual] Tha `{ $function }` na phàirt de chearcall ghairmean ath-chùrsach.
+    }
+    .note = Tòisichidh a’ ghairm seo an cearcall `{ $cycle }`.
+    .help = Ath-sgrìobh an ath-chùrsadh mar lùb thar stac shoilleir, no ceadaich am foincsean ma tha crìoch air a dhoimhneachd.
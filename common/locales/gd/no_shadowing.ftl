@@ -0,0 +1,9 @@
+## Cha bu chòir do cheanglaichean sgàil a chur air luachan gun cheangal.
+
+no_shadowing = Tha `{ $name }` a’ cur sgàil air ceangal nas tràithe san aon raon.
+    .note = Tha an ceangal fo sgàil, `{ $name }`, air a chur an cèill an seo.
+    .help = Thoir ainm fhèin don luach ùr gus am bi gach ainm a’ comharrachadh aon luach.
+
+no_shadowing-nested = Tha `{ $name }` a’ cur sgàil air ceangal o raon a tha ga chuairteachadh.
+    .note = Tha an ceangal fo sgàil, `{ $name }`, air a chur an cèill an seo.
+    .help = Thoir ainm ùr air a’ cheangal a-staigh gus am bi an `{ $name }` a-muigh fhathast ri fhaicinn do luchd-leughaidh.
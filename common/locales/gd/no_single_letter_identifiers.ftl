@@ -0,0 +1,8 @@
+## Cha bu chòir ainmean aon-litir a bhith aig ceanglaichean is paramadairean taobh a-muigh lùban, dùnaidhean goirid, agus àireamhachd choitcheann.
+
+no_single_letter_identifiers = { $kind ->
+        [parameter] Paramadair
+       *[binding] Ceangal
+    } aon-litir `{ $name }` ann an { $context }.
+    .note = Chan eil `{ $name }` ag innse dad mun luach a th’ ann, mar sin feumaidh leughadairean a lorg air ais far an deach a cheangal.
+    .help = Thoir ainm ùr air `{ $name }` a rèir na tha ann, no cuir ris `allowed` e mas e gnàthas a th’ anns an ainm an seo.
@@ -0,0 +1,9 @@
+## Cuir casg air `static mut` airson staid cruinneil co-chòrdaichte.
+
+no_static_mut = Tha an nì `static mut` `{ $name }` a’ ceadachadh atharrachadh cruinneil gun cho-chòrdadh.
+    .note = Chan eilear a’ moladh iomraidhean air nithean `static mut` tuilleadh agus feumaidh gach ruigsinneachd `unsafe`.
+    .help = Cuir `std::sync::OnceLock`, `Mutex`, no seòrsa atamach an àite `{ $name }`.
+
+no_static_mut-access = Chan eil ruigsinneachd air an nì `static mut` `{ $name }` co-chòrdaichte.
+    .note = Bidh leughadh no sgrìobhadh `{ $name }` a’ rèiseadh le snàithlean sam bith eile a bhios ga bhualadh.
+    .help = Gluais `{ $name }` gu `std::sync::OnceLock`, `Mutex`, no seòrsa atamach, agus thoir air falbh am bloca `unsafe`.
@@ -1,5 +1,7 @@
 ## Bacar std::fs gus cumail ri modail comasan don t-siostam fhaidhlichean.
 
-no_std_fs_operations = Tha gnìomh std::fs `{ $operation }` a’ seachnadh a’ phoileasaidh comasan airson an t-siostaim fhaidhlichean.
-    .note = Bidh std::fs a’ dol an sàs leis an eòlas àrainneachd; gabhaibh làmhan `cap_std::fs::Dir` agus slighean camino gus am bi an neach-gairm a’ taghadh na comasan.
-    .help = Cuir seachad `cap_std::fs::Dir` agus paramadairean `camino::Utf8Path`/`Utf8PathBuf` tron API seach std::fs a ghairm gu dìreach.
+no_std_fs_operations = Tha gnìomh { $module } `{ $operation }` a’ seachnadh a’ phoileasaidh comasan airson an t-siostaim fhaidhlichean.
+    .note = Bidh { $module } a’ dol an sàs leis an eòlas àrainneachd; gabhaibh làmhan `cap_std::fs::Dir` agus slighean camino gus am bi an neach-gairm a’ taghadh na comasan.
+    .help = Cuir seachad `cap_std::fs::Dir` agus paramadairean `camino::Utf8Path`/`Utf8PathBuf` tron API seach { $module } a ghairm gu dìreach.
+    .suggestion = gairm `{ $function }` tron `{ $filesystem_trait }` a chaidh a thoirt a-steach
+    .trait_help = Gabh ri buileachadh de `{ $filesystem_trait }` agus dèan an gnìomh { $module } seo troimhe.
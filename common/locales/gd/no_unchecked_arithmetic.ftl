@@ -0,0 +1,5 @@
+## Bu chòir do dh’àireamhachd shlànairean ann am mòidealan rèitichte dòighean `checked_*`, `saturating_*`, no `wrapping_*` a chleachdadh.
+
+no_unchecked_arithmetic = `{ $operator }` gun dearbhadh air `{ $ty }` sa mhòideal `{ $module }`.
+    .note = Tha `{ $module }` air a rèiteachadh gus cur-thairis a làimhseachadh gu soilleir, ach thèid `{ $operator }` ann am panic le cur-thairis ann an togalaichean dì-bhugachaidh agus pasgaidh e gu sàmhach ann an togalaichean fuasglaidh.
+    .help = Cleachd `{ $checked }`, `{ $saturating }`, no `{ $wrapping }` gus innse dè bu chòir tachairt le cur-thairis.
@@ -1,5 +1,5 @@
 ## Seachain `unwrap_or_else` a tha a’ clisgeadh.
 
-no_unwrap_or_else_panic = Cuir an àite unwrap_or_else air { $receiver } le làimhseachadh nach eil a’ clisgeadh.
-    .note = Tha an dùnadh a bheir thu do unwrap_or_else ag adhbhrachadh clisgeadh.
+no_unwrap_or_else_panic = Cuir an àite { $method } air { $receiver } le làimhseachadh nach eil a’ clisgeadh.
+    .note = Tha an dùnadh a bheir thu do { $method } ag adhbhrachadh clisgeadh.
     .help = Sgaoil am mearachd no cleachd expect le teachdaireachd shoilleir an àite clisgeadh.
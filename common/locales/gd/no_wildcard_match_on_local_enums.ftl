@@ -0,0 +1,8 @@
+## Bu chòir do mhaidsichean air enums a tha air am mìneachadh san aon crate na caochlaidhean aca ainmeachadh seach gàirdean `_` a chleachdadh.
+
+no_wildcard_match_on_local_enums = Gàirdean cairt fhiadhaich ann am maids air `{ $enum }`, a tha an crate seo a’ mìneachadh.
+    .note = Thuiteadh caochladh a thèid a chur ri `{ $enum }` a-steach don ghàirdean seo gu sàmhach seach a bhith a’ fàiligeadh ri cur ri chèile an seo.
+    .help = { $fix ->
+        [list] Ainmich na caochlaidhean a tha air fhàgail na àite: `{ $replacement }`.
+       *[spell] Cuir pàtranan a dh’ainmicheas na caochlaidhean a tha e a’ còmhdachadh an àite `_`, gus am bi gach caochladh air a mhaidseadh gu soilleir.
+    }
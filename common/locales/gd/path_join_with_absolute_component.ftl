@@ -0,0 +1,11 @@
+## Ma cheanglas tu earrann slighe iomlan, thèid an t-slighe bhunasach a thilgeil air falbh.
+
+path_join_with_absolute_component = Tilgidh `{ $method }` le `{ $component }` an t-slighe bhunasach air falbh.
+    .note = Tòisichidh `{ $component }` { $anchor ->
+        [drive] le ro-leasachan draibh
+       *[root] aig freumh an t-siostaim fhaidhlichean
+    }, mar sin tillidh `{ $method }` e gun atharrachadh an àite an t-slighe bhunasach a leudachadh.
+    .help = Thoir air falbh { $anchor ->
+        [drive] an ro-leasachan draibh
+       *[root] an sgaradair air thoiseach
+    } gus fuireach am broinn na slighe bunasaich, no cleachd `{ $component }` leis fhèin mas e slighe iomlan a tha fa-near dhut.
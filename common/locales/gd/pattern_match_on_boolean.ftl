@@ -0,0 +1,6 @@
+## Bu chòir abairtean `match` air `bool` a sgrìobhadh mar `if`/`else`.
+
+pattern_match_on_boolean = Bu chòir `match` air `bool` a sgrìobhadh mar `if`/`else`.
+    .note = Chan eil na gàirdeanan ach a’ dèanamh eadar-dhealachadh eadar `true` agus `false`, rud a tha `if`/`else` ag ràdh gu dìreach gun a bhith a’ neadachadh gach meur am broinn gàirdein.
+    .help = Cuir `if` air an luach a thathar a’ sgrùdadh an àite a’ `match`, a’ cleachdadh gàirdean `true` mar am bacadh `if` agus an gàirdean eile mar am bacadh `else`.
+    .suggestion = ath-sgrìobh am `match` mar `if`/`else`
@@ -0,0 +1,5 @@
+## B’ fheàrr faidhlichean mòideil `foo.rs` seach `foo/mod.rs`.
+
+prefer_named_module_files = Tha am mòideal `{ $module }` air a chur an gnìomh ann am faidhle `mod.rs`.
+    .note = Tha `{ $module }` air a mhìneachadh ann an `{ $path }`; b’ fheàrr `{ $suggestion }`.
+    .help = Gluais `{ $path }` gu `{ $suggestion }` agus cùm fo-mhòidealan sam bith sa phasgan `{ $module }`.
@@ -0,0 +1,8 @@
+## Ma tha an aon seòrsa bunasach lom ann an iomadh àite de nì, thig troimh-a-chèile às.
+
+primitive_obsession = { $kind ->
+        [struct] Tha `{ $item }` a’ stòradh `{ $primitive }` ann an { $count } raointean.
+       *[function] Tha `{ $item }` a’ gabhail `{ $primitive }` ann an { $count } paramadairean.
+    }
+    .note = Tha an aon seòrsa lom `{ $primitive }` aig { $positions } uile, mar sin chan eil dad a’ cur stad air fear dhiubh a thoirt seachad far a bheil fear eile a dhìth.
+    .help = Cruthaich newtype airson gach luach a tha `{ $primitive }` a’ riochdachadh, no cuir `{ $primitive }` ri `exempt_types`.
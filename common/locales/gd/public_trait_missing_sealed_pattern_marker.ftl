@@ -0,0 +1,8 @@
+## Bu chòir do thraits poblach a tha gu bhith seulaichte trait-àrd iarraidh nach urrainn do chrates leis an t-sruth ainmeachadh.
+
+public_trait_missing_sealed_pattern_marker = Faodar an trait phoblach `{ $name }` a chur an gnìomh taobh a-muigh a’ chrate seo.
+    .note = { $intent ->
+        [hidden] Tha `{ $name }` ag iarraidh an trait fhalaichte `{ $supertrait }`, ach tha `{ $supertrait }` poblach, mar sin faodaidh crates leis an t-sruth an dà chuid a chur an gnìomh.
+       *[listed] Tha `{ $name }` air a liostadh ann an `sealed_traits`, ach chan eil dad a’ cumail chrates leis an t-sruth bho a cur an gnìomh, mar sin ’s e atharrachadh briste a th’ ann ma chuirear modh riatanach ris.
+    }
+    .help = Iarr trait-àrd a chaidh a chur an cèill ann am modal prìobhaideach, leithid `private::Sealed`, agus cuir an gnìomh e a-mhàin airson seòrsaichean a’ chrate seo.
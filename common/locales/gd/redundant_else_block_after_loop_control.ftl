@@ -0,0 +1,6 @@
+## Chan eil feum air bacaidhean `else` às dèidh meur `if` a chrìochnaicheas le `continue` no `break`.
+
+redundant_else_block_after_loop_control = `else` gun fheum às dèidh bacadh `if` a chrìochnaicheas le `{ $control }`.
+    .note = Bidh am bacadh `if` an-còmhnaidh a’ crìochnachadh an ath-aithris le `{ $control }`, mar sin chan eil bodhaig an `else` a’ ruith ach nuair a tha an cumha ceàrr, agus chan eil an `else` ach a’ cur neadachadh ris.
+    .help = Thoir air falbh an `else` agus lùghdaich eag a bhodhaig gus am bi an `if` na chlàs-dìona agus gum fuirich an còrr de bhodhaig na lùib aig aon ìre eagachaidh.
+    .suggestion = thoir air falbh an `else` agus lùghdaich eag a bhodhaig
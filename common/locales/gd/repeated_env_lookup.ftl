@@ -0,0 +1,8 @@
+## Bu chòir caochladair àrainneachd a leughadh aon turas seach a lorg a-rithist is a-rithist san aon sgòp.
+
+repeated_env_lookup = Thathar a’ lorg caochladair na h-àrainneachd `{ $key }` a-rithist san aon { $scope ->
+        [impl] bhloca `impl`
+       *[function] fhoincsean
+    }.
+    .note = Thathar a’ lorg `{ $key }` an toiseach an seo.
+    .help = Leugh `{ $key }` aon turas a-steach do chaochladair ionadail no structar rèiteachaidh agus ath-chleachd an luach.
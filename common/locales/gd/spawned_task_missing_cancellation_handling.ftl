@@ -0,0 +1,5 @@
+## Bu chòir do shaothraichean a chaidh a sgaoileadh a bhios a’ lùbadh thar `.await` sùil a chumail air tòcan sguir gus an gabh an dùnadh.
+
+spawned_task_missing_cancellation_handling = Tha an t-saothair a chaidh a sgaoileadh le `{ $callee }` a’ lùbadh thar `.await` gun sùil a chumail air sgur.
+    .note = Chan eil dad san t-saothair a’ leughadh tòcan sguir, mar sin chan urrainn do dhùnadh grinn stad a chur air an lùb; ruithidh an t-saothair gus an leig an àm-ruith às i ann am meadhan ath-thriall.
+    .help = Cuir `CancellationToken` a-steach dhan t-saothair agus cleachd `select!` air `token.cancelled()` còmhla ris an obair ris a bheil an lùb a’ feitheamh, an uairsin bris a-mach às an lùb nuair a thig e.
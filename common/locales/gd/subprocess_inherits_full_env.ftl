@@ -0,0 +1,5 @@
+## Àitheantan ann am mòidealan mothachail a thaobh tèarainteachd air an cur gu dol le àrainneachd shlàn a’ phàrant.
+
+subprocess_inherits_full_env = Ruithidh `{ $method }` àithne a shealbhaicheas àrainneachd shlàn a’ phròiseis seo.
+    .note = Tòisichidh an àithne a chaidh a thogail an-seo le lethbhreac de dh’àrainneachd a’ phàrant, a’ gabhail a-steach tòcain no teisteanasan sam bith a tha innte.
+    .help = Gairm `env_clear()` agus thoir seachad dìreach na caochladairean a tha a dhìth air a’ phàiste le `env` no `envs`, no thoir air falbh caochladairean mothachail le `env_remove`.
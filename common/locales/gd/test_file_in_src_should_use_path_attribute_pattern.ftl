@@ -0,0 +1,10 @@
+## Bu chòir do mhodalan `#[cfg(test)]` mòra a bhith nam faidhle fhèin, air an luchdadh le `#[path]`.
+
+test_file_in_src_should_use_path_attribute_pattern = Tha am modal deuchainn `{ $module }` sgrìobhte am broinn an fhaidhle thar { $lines } { $lines ->
+        [one] loidhne
+        [two] loidhne
+        [few] loidhnichean
+       *[other] loidhne
+    }, os cionn a’ chrìoch de { $limit }.
+    .note = Nì deuchainnean am broinn an fhaidhle faidhlichean toraidh nas fhaide ri leughadh is ri lèirmheas, ach cumaidh faidhle fa leth an còd is na deuchainnean aige air leth.
+    .help = Gluais a’ bhodhaig gu `{ $path }` agus foillsich i le `#[cfg(test)] #[path = "{ $path }"] mod { $module };`.
@@ -0,0 +1,5 @@
+## A’ cur à comas dearbhadh teisteanais no ainm òstair TLS taobh a-muigh deuchainnean.
+
+tls_verification_disabled = Cuiridh `{ $call }` dearbhadh TLS à comas taobh a-muigh deuchainnean.
+    .note = Às aonais sgrùdaidhean air an teisteanas is ainm an òstair, faodaidh duine sam bith air slighe an lìonraidh a bhith na fhrithealaiche meallta agus an trafaig a leughadh no atharrachadh.
+    .help = Thoir air falbh a’ ghairm. Gus earbsa a chur ann an teisteanas prìobhaideach no fèin-shoidhnichte, cuir ris e mar theisteanas freumha an àite sin, no cùm a’ ghairm ri còd deuchainn.
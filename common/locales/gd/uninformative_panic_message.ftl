@@ -0,0 +1,12 @@
+## Bu chòir do theachdaireachdan expect agus panic cunntas a thoirt air a’ chumha a dh’fhàillig.
+
+uninformative_panic_message = { $call ->
+        [expect] Chan eil an teachdaireachd expect seo ag innse dè a chaidh ceàrr.
+       *[panic] Chan eil an teachdaireachd panic seo ag innse dè a chaidh ceàrr.
+    }
+    .note = { $problem ->
+        [empty] Tha an teachdaireachd falamh.
+        [generic] Tha an teachdaireachd "{ $message }" na abairt choitcheann a fhreagras air fàilligeadh sam bith.
+       *[short] Tha an teachdaireachd "{ $message }" nas giorra na { $min_length } caractaran.
+    }
+    .help = Innis an cumha a bu chòir a bhith fìor, mar eisimpleir "config should be validated at start-up".
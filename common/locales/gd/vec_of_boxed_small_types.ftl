@@ -0,0 +1,5 @@
+## Bu chòir do bhectaran luachan beaga le meud suidhichte a stòradh gu dìreach seach gach eileamaid a chur ann am bogsa.
+
+vec_of_boxed_small_types = Tha `Vec<Box<{ $element }>>` a’ cur eileamaidean de dìreach { $size } baidht ann am bogsa.
+    .note = Tha gach eileamaid na riarachadh cuimhne fhèin, mar sin bidh togail a’ bhectair a’ riarachadh aon turas airson gach nì agus leanaidh gach ruigsinn comharraiche; tha e nas saoire luachan le meud suidhichte de { $limit } baidht no nas lugha a stòradh am broinn a’ bhectair.
+    .help = Stòr na h-eileamaidean gu dìreach mar `Vec<{ $element }>`.
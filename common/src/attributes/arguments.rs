@@ -0,0 +1,224 @@
+//! Structured attribute arguments.
+//!
+//! Compiler-facing layers lower the tokens of an unparsed attribute into
+//! [`AttributeArguments`] so lints can ask for a `reason = "..."` value or the
+//! bare paths listed in `#[allow(...)]` without scanning tokens themselves.
+
+use super::AttributePath;
+
+/// A literal appearing in attribute arguments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AttributeLiteral {
+    /// A string literal, with escapes already processed.
+    Str(String),
+    /// `true` or `false`.
+    Bool(bool),
+    /// Any other literal, kept as written (for example `42` or `1.5f32`).
+    Other(String),
+}
+
+impl AttributeLiteral {
+    /// Returns the contents of a string literal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::AttributeLiteral;
+    ///
+    /// assert_eq!(AttributeLiteral::Str("slow".into()).as_str(), Some("slow"));
+    /// assert_eq!(AttributeLiteral::Bool(true).as_str(), None);
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Str(value) => Some(value),
+            Self::Bool(_) | Self::Other(_) => None,
+        }
+    }
+}
+
+/// One argument inside an attribute's parentheses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AttributeArgument {
+    /// A bare path, such as `dead_code` or `clippy::unwrap_used`.
+    Word(AttributePath),
+    /// A `key = literal` pair, such as `reason = "..."`.
+    KeyValue {
+        /// The key path.
+        key: AttributePath,
+        /// The literal value.
+        value: AttributeLiteral,
+    },
+    /// A nested list, such as `not(test)`.
+    List {
+        /// The path before the parentheses.
+        path: AttributePath,
+        /// The nested arguments.
+        arguments: Vec<AttributeArgument>,
+    },
+    /// A bare literal, such as the `"text"` in `#[doc("text")]`.
+    Literal(AttributeLiteral),
+}
+
+impl AttributeArgument {
+    /// Returns the path of a word, key-value pair, or nested list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{AttributeArgument, AttributePath};
+    ///
+    /// let word = AttributeArgument::Word(AttributePath::from("dead_code"));
+    /// assert_eq!(word.path(), Some(&AttributePath::from("dead_code")));
+    /// ```
+    #[must_use]
+    pub fn path(&self) -> Option<&AttributePath> {
+        match self {
+            Self::Word(path) | Self::List { path, .. } => Some(path),
+            Self::KeyValue { key, .. } => Some(key),
+            Self::Literal(_) => None,
+        }
+    }
+
+    /// Returns the arguments of a nested list, or an empty slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{AttributeArgument, AttributePath};
+    ///
+    /// let not_test = AttributeArgument::List {
+    ///     path: AttributePath::from("not"),
+    ///     arguments: vec![AttributeArgument::Word(AttributePath::from("test"))],
+    /// };
+    /// assert_eq!(not_test.nested().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn nested(&self) -> &[AttributeArgument] {
+        match self {
+            Self::List { arguments, .. } => arguments,
+            Self::Word(_) | Self::KeyValue { .. } | Self::Literal(_) => &[],
+        }
+    }
+}
+
+/// The arguments of one attribute.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AttributeArguments {
+    /// No arguments: `#[attr]`.
+    #[default]
+    Empty,
+    /// Parenthesised arguments: `#[attr(a, b = "c", d(e))]`.
+    List(Vec<AttributeArgument>),
+    /// A single value after `=`: `#[attr = "value"]`.
+    Value(AttributeLiteral),
+}
+
+impl AttributeArguments {
+    /// Returns the top-level list arguments, or an empty slice for the other
+    /// forms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{AttributeArguments, AttributeLiteral};
+    ///
+    /// let value = AttributeArguments::Value(AttributeLiteral::Str("why".into()));
+    /// assert!(value.items().is_empty());
+    /// ```
+    #[must_use]
+    pub fn items(&self) -> &[AttributeArgument] {
+        match self {
+            Self::List(arguments) => arguments,
+            Self::Empty | Self::Value(_) => &[],
+        }
+    }
+
+    /// Returns the value of the first top-level `key = literal` pair whose
+    /// key is the single segment `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{
+    ///     AttributeArgument, AttributeArguments, AttributeLiteral, AttributePath,
+    /// };
+    ///
+    /// let arguments = AttributeArguments::List(vec![AttributeArgument::KeyValue {
+    ///     key: AttributePath::from("since"),
+    ///     value: AttributeLiteral::Str("1.0".into()),
+    /// }]);
+    /// assert_eq!(
+    ///     arguments.key_value("since"),
+    ///     Some(&AttributeLiteral::Str("1.0".into()))
+    /// );
+    /// assert_eq!(arguments.key_value("note"), None);
+    /// ```
+    #[must_use]
+    pub fn key_value(&self, key: &str) -> Option<&AttributeLiteral> {
+        self.items().iter().find_map(|argument| match argument {
+            AttributeArgument::KeyValue { key: path, value } if path.matches([key]) => Some(value),
+            _ => None,
+        })
+    }
+
+    /// Returns the reason an attribute gives for itself.
+    ///
+    /// This is the string in `reason = "..."` for list forms such as
+    /// `#[allow(dead_code, reason = "...")]`, or the string after `=` for
+    /// value forms such as `#[ignore = "..."]`. Blank reasons count as
+    /// missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{
+    ///     AttributeArgument, AttributeArguments, AttributeLiteral, AttributePath,
+    /// };
+    ///
+    /// let ignore = AttributeArguments::Value(AttributeLiteral::Str("needs network".into()));
+    /// assert_eq!(ignore.reason(), Some("needs network"));
+    ///
+    /// let allow = AttributeArguments::List(vec![
+    ///     AttributeArgument::Word(AttributePath::from("dead_code")),
+    ///     AttributeArgument::KeyValue {
+    ///         key: AttributePath::from("reason"),
+    ///         value: AttributeLiteral::Str("  ".into()),
+    ///     },
+    /// ]);
+    /// assert_eq!(allow.reason(), None);
+    /// ```
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        let literal = match self {
+            Self::Value(literal) => Some(literal),
+            Self::List(_) => self.key_value("reason"),
+            Self::Empty => None,
+        };
+        literal
+            .and_then(AttributeLiteral::as_str)
+            .filter(|reason| !reason.trim().is_empty())
+    }
+
+    /// Returns the bare paths among the top-level arguments, such as the lint
+    /// names in `#[allow(dead_code, clippy::unwrap_used)]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{AttributeArgument, AttributeArguments, AttributePath};
+    ///
+    /// let arguments = AttributeArguments::List(vec![
+    ///     AttributeArgument::Word(AttributePath::from("dead_code")),
+    ///     AttributeArgument::Word(AttributePath::from("clippy::unwrap_used")),
+    /// ]);
+    /// let words: Vec<String> = arguments.words().map(ToString::to_string).collect();
+    /// assert_eq!(words, ["dead_code", "clippy::unwrap_used"]);
+    /// ```
+    pub fn words(&self) -> impl Iterator<Item = &AttributePath> {
+        self.items().iter().filter_map(|argument| match argument {
+            AttributeArgument::Word(path) => Some(path),
+            _ => None,
+        })
+    }
+}
@@ -76,6 +76,26 @@ impl Attribute {
         Self::with_arguments(path, kind, args.iter().copied())
     }
 
+    /// Creates the projection of a `cfg` attribute whose predicate enables test
+    /// builds, such as `cfg(test)` or `cfg(all(test, unix))`.
+    ///
+    /// Compiler-facing layers evaluate the predicate and record the outcome
+    /// in this normalized form so data-only callers need not parse it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{Attribute, AttributeKind};
+    ///
+    /// let attribute = Attribute::cfg_test(AttributeKind::Outer);
+    /// assert!(attribute.is_cfg_test());
+    /// assert_eq!(attribute.arguments(), &["test"]);
+    /// ```
+    #[must_use]
+    pub fn cfg_test(kind: AttributeKind) -> Self {
+        Self::with_str_arguments(AttributePath::from("cfg"), kind, &["test"])
+    }
+
     /// Returns the underlying attribute path.
     ///
     /// # Examples
@@ -140,6 +160,26 @@ impl Attribute {
         self.path.is_doc()
     }
 
+    /// Indicates whether the attribute is a `cfg` projection that enables test
+    /// builds, as produced by [`Attribute::cfg_test`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::attributes::{Attribute, AttributeKind, AttributePath};
+    ///
+    /// let unix = Attribute::with_str_arguments(
+    ///     AttributePath::from("cfg"),
+    ///     AttributeKind::Outer,
+    ///     &["unix"],
+    /// );
+    /// assert!(!unix.is_cfg_test());
+    /// ```
+    #[must_use]
+    pub fn is_cfg_test(&self) -> bool {
+        self.path.matches(["cfg"]) && self.arguments.iter().any(|argument| argument == "test")
+    }
+
     /// Indicates whether the attribute marks a test-like context.
     ///
     /// Builtin test-like attributes include direct paths such as `test`,
@@ -21,11 +21,13 @@ pub(super) const TEST_LIKE_PATHS: &[&[&str]] = &[
     &["rstest", "case"],
 ];
 
+mod arguments;
 mod attribute;
 mod helpers;
 mod kind;
 mod path;
 
+pub use arguments::{AttributeArgument, AttributeArguments, AttributeLiteral};
 pub use attribute::Attribute;
 pub use helpers::{
     has_test_like_attribute, has_test_like_attribute_with, outer_attributes, split_doc_attributes,
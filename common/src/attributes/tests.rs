@@ -92,3 +92,69 @@ fn finds_outer_attributes() {
 
     assert_eq!(outer_only, vec![&outer]);
 }
+
+fn word(path: &str) -> AttributeArgument {
+    AttributeArgument::Word(AttributePath::from(path))
+}
+
+fn string_pair(key: &str, value: &str) -> AttributeArgument {
+    AttributeArgument::KeyValue {
+        key: AttributePath::from(key),
+        value: AttributeLiteral::Str(value.to_string()),
+    }
+}
+
+#[rstest]
+#[case::listed_reason(
+    AttributeArguments::List(vec![word("dead_code"), string_pair("reason", "kept for FFI")]),
+    Some("kept for FFI")
+)]
+#[case::value_reason(
+    AttributeArguments::Value(AttributeLiteral::Str("slow".into())),
+    Some("slow")
+)]
+#[case::no_reason(AttributeArguments::List(vec![word("dead_code")]), None)]
+#[case::blank_reason(AttributeArguments::List(vec![string_pair("reason", "")]), None)]
+#[case::non_string_value(AttributeArguments::Value(AttributeLiteral::Bool(true)), None)]
+#[case::empty(AttributeArguments::Empty, None)]
+#[case::qualified_key(
+    AttributeArguments::List(vec![string_pair("lint::reason", "why")]),
+    None
+)]
+fn arguments_expose_reason(#[case] arguments: AttributeArguments, #[case] expected: Option<&str>) {
+    assert_eq!(arguments.reason(), expected);
+}
+
+#[rstest]
+fn words_skip_pairs_lists_and_literals() {
+    let arguments = AttributeArguments::List(vec![
+        word("dead_code"),
+        string_pair("reason", "why"),
+        AttributeArgument::List {
+            path: AttributePath::from("not"),
+            arguments: vec![word("test")],
+        },
+        AttributeArgument::Literal(AttributeLiteral::Other("42".into())),
+        word("clippy::unwrap_used"),
+    ]);
+
+    let words: Vec<String> = arguments.words().map(ToString::to_string).collect();
+
+    assert_eq!(words, ["dead_code", "clippy::unwrap_used"]);
+}
+
+#[rstest]
+fn nested_lists_keep_their_arguments() {
+    let argument = AttributeArgument::List {
+        path: AttributePath::from("all"),
+        arguments: vec![word("test"), word("unix")],
+    };
+
+    assert_eq!(argument.path(), Some(&AttributePath::from("all")));
+    assert_eq!(argument.nested(), [word("test"), word("unix")]);
+    assert!(word("test").nested().is_empty());
+    assert_eq!(
+        AttributeArgument::Literal(AttributeLiteral::Bool(false)).path(),
+        None
+    );
+}
@@ -1,9 +1,21 @@
-//! Context tracking utilities for analysing traversal stacks.
+//! Context tracking utilities for analysing traversal stacks, caching the
+//! context each body shares, recognising doctest crates, and describing the
+//! item that encloses a diagnostic or the helper it was redirected from.
 
 use crate::attributes::{
     Attribute, AttributePath, has_test_like_attribute, has_test_like_attribute_with,
 };
 
+mod blame;
+mod cache;
+mod doctest;
+mod enclosing;
+
+pub use blame::BlamedHelper;
+pub use cache::{ContextCache, ContextPrefix};
+pub use doctest::DoctestSignals;
+pub use enclosing::{EnclosingItem, EnclosingItemKind};
+
 /// Categorizes a frame within the traversal stack.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ContextKind {
@@ -15,6 +27,10 @@ pub enum ContextKind {
     Module,
     /// A lexical block (e.g. closure, loop, or bare block).
     Block,
+    /// A statement carrying its own attributes, such as `#[cfg(test)] let`.
+    Statement,
+    /// An expression carrying its own attributes.
+    Expression,
 }
 
 /// Records contextual information for traversal decisions.
@@ -143,6 +159,12 @@ pub fn is_test_fn_with(attrs: &[Attribute], additional: &[AttributePath]) -> boo
 
 /// Returns `true` when any entry in the stack participates in a test-like context.
 ///
+/// An entry participates when it carries a test-like attribute such as
+/// `#[test]`, or a `cfg` projection enabling test builds (see
+/// [`Attribute::cfg_test`]). The latter applies to every entry kind, so
+/// `#[cfg(test)]` on an `impl` block, statement, or expression marks the
+/// context just as it does on a module.
+///
 /// # Examples
 ///
 /// ```
@@ -174,9 +196,10 @@ pub fn in_test_like_context(stack: &[ContextEntry]) -> bool {
 /// ```
 #[must_use]
 pub fn in_test_like_context_with(stack: &[ContextEntry], additional: &[AttributePath]) -> bool {
-    stack
-        .iter()
-        .any(|entry| has_test_like_attribute_with(entry.attributes(), additional))
+    stack.iter().any(|entry| {
+        has_test_like_attribute_with(entry.attributes(), additional)
+            || entry.attributes().iter().any(Attribute::is_cfg_test)
+    })
 }
 
 /// Detects whether the current traversal stack is inside a `main` function.
@@ -204,6 +227,8 @@ pub fn is_in_main_fn(stack: &[ContextEntry]) -> bool {
 mod tests {
     use super::*;
     use crate::attributes::{Attribute, AttributeKind, AttributePath};
+    use crate::i18n::Localizer;
+    use crate::i18n::testing::FailingLookup;
     use rstest::rstest;
 
     fn test_attribute() -> Attribute {
@@ -224,6 +249,39 @@ mod tests {
         assert!(in_test_like_context(&[entry]));
     }
 
+    #[rstest]
+    #[case::impl_block(ContextKind::Impl)]
+    #[case::statement(ContextKind::Statement)]
+    #[case::expression(ContextKind::Expression)]
+    #[case::module(ContextKind::Module)]
+    fn cfg_test_marks_any_entry_kind(#[case] kind: ContextKind) {
+        let stack = vec![
+            ContextEntry::function("helper", Vec::new()),
+            ContextEntry::new(
+                "scope",
+                kind,
+                vec![Attribute::cfg_test(AttributeKind::Outer)],
+            ),
+        ];
+
+        assert!(in_test_like_context(&stack));
+    }
+
+    #[rstest]
+    fn other_cfg_predicates_do_not_mark_context() {
+        let unix = Attribute::with_str_arguments(
+            AttributePath::from("cfg"),
+            AttributeKind::Outer,
+            &["unix"],
+        );
+        let stack = vec![
+            ContextEntry::function("helper", Vec::new()),
+            ContextEntry::new("statement", ContextKind::Statement, vec![unix]),
+        ];
+
+        assert!(!in_test_like_context(&stack));
+    }
+
     #[rstest]
     fn identifies_main() {
         let stack = vec![ContextEntry::function("main", Vec::new())];
@@ -249,4 +307,53 @@ mod tests {
         let entry = ContextEntry::function("demo", attrs);
         assert!(in_test_like_context_with(&[entry], additional.as_slice()));
     }
+
+    #[rstest]
+    #[case::function(EnclosingItemKind::Function, "function `run`")]
+    #[case::method(EnclosingItemKind::Method, "method `run`")]
+    #[case::closure(EnclosingItemKind::Closure, "a closure in `run`")]
+    #[case::constant(EnclosingItemKind::Const, "constant `run`")]
+    #[case::static_item(EnclosingItemKind::Static, "static `run`")]
+    fn describes_enclosing_items(#[case] kind: EnclosingItemKind, #[case] expected: &str) {
+        let item = EnclosingItem::new(kind, "run");
+
+        assert_eq!(item.describe(&Localizer::new(Some("en-GB"))), expected);
+        assert_eq!(item.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::english("en-GB", "the surrounding scope")]
+    #[case::welsh("cy", "y cwmpas o’i amgylch")]
+    #[case::gaelic("gd", "an raon mun cuairt")]
+    fn describes_unnamed_scope(#[case] locale: &str, #[case] expected: &str) {
+        let item = EnclosingItem::scope();
+
+        assert_eq!(item.describe(&Localizer::new(Some(locale))), expected);
+    }
+
+    #[rstest]
+    fn describe_falls_back_to_english() {
+        let item = EnclosingItem::new(EnclosingItemKind::Method, "load");
+        let lookup = FailingLookup::new("common-enclosing-item");
+
+        assert_eq!(item.describe(&lookup), "method `load`");
+    }
+
+    #[rstest]
+    #[case::english("en-GB", "The finding is in helper `fetch`")]
+    #[case::welsh("cy", "Mae’r canfyddiad yn y cynorthwyydd `fetch`")]
+    #[case::gaelic("gd", "Tha an lorg sa ghnìomh-taice `fetch`")]
+    fn describes_blamed_helpers(#[case] locale: &str, #[case] prefix: &str) {
+        let note = BlamedHelper::new("fetch").describe(&Localizer::new(Some(locale)));
+
+        assert!(note.starts_with(prefix), "unexpected note: {note}");
+    }
+
+    #[rstest]
+    fn blamed_helper_falls_back_to_english() {
+        let helper = BlamedHelper::new("fetch");
+        let lookup = FailingLookup::new("common-blamed-helper");
+
+        assert_eq!(helper.describe(&lookup), helper.to_string());
+    }
 }
@@ -0,0 +1,84 @@
+//! Localised notes for findings redirected from a helper to its callers.
+//!
+//! Lints with a `blame_helpers` setting report a finding inside a listed
+//! helper at each call to it. The note left on the original expression names
+//! the helper through the shared `common-blamed-helper` message, so every
+//! lint explains the redirection in the same words.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::i18n::{Arguments, BundleLookup, FluentValue, MessageKey};
+
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("common-blamed-helper");
+
+/// A helper function whose findings are reported at its call sites.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::context::BlamedHelper;
+///
+/// let helper = BlamedHelper::new("util::fetch");
+/// assert_eq!(helper.name(), "util::fetch");
+/// assert!(helper.to_string().contains("`util::fetch`"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlamedHelper {
+    name: String,
+}
+
+impl BlamedHelper {
+    /// Creates a helper description from its path.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+
+    /// Returns the helper's path.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Describes the redirection in the lookup's locale, falling back to
+    /// English when the shared message is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::BlamedHelper;
+    /// use whitaker_common::i18n::Localizer;
+    ///
+    /// let helper = BlamedHelper::new("fetch");
+    /// let note = helper.describe(&Localizer::new(Some("en-GB")));
+    /// assert!(note.starts_with("The finding is in helper `fetch`"));
+    /// ```
+    #[must_use]
+    pub fn describe(&self, lookup: &impl BundleLookup) -> String {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("helper"),
+            FluentValue::from(self.name.clone()),
+        );
+
+        match lookup.message(MESSAGE_KEY, &args) {
+            Ok(mut text) => {
+                text.retain(|ch| !matches!(ch, '\u{2068}' | '\u{2069}'));
+                text
+            }
+            Err(_) => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BlamedHelper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The finding is in helper `{}`, which `blame_helpers` lists, so it is reported \
+             where the helper is called.",
+            self.name
+        )
+    }
+}
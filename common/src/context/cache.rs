@@ -0,0 +1,219 @@
+//! Per-body memoisation of ancestor context.
+//!
+//! Every node inside one body shares the ancestors that lie outside it: the
+//! body's owner, and the impls and modules around that. Lints that summarise
+//! the context of many nodes in the same body can collect that shared prefix
+//! once, then walk only the ancestors between each node and its body.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::ContextEntry;
+
+/// Ancestor context shared by every node inside one body.
+///
+/// Holds the entries from the crate root down to the body's owner, in the
+/// same order a full ancestor walk records them, together with whether any of
+/// those ancestors already established test-only context.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContextPrefix {
+    entries: Vec<ContextEntry>,
+    has_test_ancestry: bool,
+}
+
+impl ContextPrefix {
+    /// Builds a prefix from the entries outside a body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::{ContextEntry, ContextPrefix};
+    ///
+    /// let prefix = ContextPrefix::new(vec![ContextEntry::function("load", Vec::new())], false);
+    /// assert_eq!(prefix.entries().len(), 1);
+    /// ```
+    #[must_use]
+    pub const fn new(entries: Vec<ContextEntry>, has_test_ancestry: bool) -> Self {
+        Self {
+            entries,
+            has_test_ancestry,
+        }
+    }
+
+    /// Returns the entries outside the body, outermost first.
+    #[must_use]
+    pub fn entries(&self) -> &[ContextEntry] {
+        &self.entries
+    }
+
+    /// Returns whether an ancestor outside the body established test context.
+    #[must_use]
+    pub const fn has_test_ancestry(&self) -> bool {
+        self.has_test_ancestry
+    }
+
+    /// Appends the context found inside the body to this prefix.
+    ///
+    /// `inner` holds the entries between the body's owner and the node,
+    /// outermost first. The result matches what a single walk from the node to
+    /// the crate root would have collected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::{ContextEntry, ContextKind, ContextPrefix};
+    ///
+    /// let prefix = ContextPrefix::new(vec![ContextEntry::function("load", Vec::new())], false);
+    /// let block = ContextEntry::new("block", ContextKind::Block, Vec::new());
+    /// let (entries, has_test_ancestry) = prefix.extend(vec![block], true);
+    /// assert_eq!(entries.len(), 2);
+    /// assert!(has_test_ancestry);
+    /// ```
+    #[must_use]
+    pub fn extend(
+        &self,
+        inner: Vec<ContextEntry>,
+        inner_has_test_ancestry: bool,
+    ) -> (Vec<ContextEntry>, bool) {
+        let mut entries = Vec::with_capacity(self.entries.len() + inner.len());
+        entries.extend_from_slice(&self.entries);
+        entries.extend(inner);
+        (entries, self.has_test_ancestry || inner_has_test_ancestry)
+    }
+}
+
+/// Memoises the [`ContextPrefix`] of each body.
+///
+/// `K` identifies a body; lint drivers key the cache by rustc's `BodyId`.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::context::{ContextCache, ContextEntry, ContextPrefix};
+///
+/// let mut cache = ContextCache::new();
+/// let collect = || ContextPrefix::new(vec![ContextEntry::function("load", Vec::new())], false);
+/// let first = cache.prefix_or_insert_with(7_u32, collect).clone();
+/// let second = cache.prefix_or_insert_with(7_u32, || unreachable!("cached"));
+/// assert_eq!(&first, second);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ContextCache<K> {
+    prefixes: HashMap<K, ContextPrefix>,
+}
+
+impl<K> Default for ContextCache<K> {
+    fn default() -> Self {
+        Self {
+            prefixes: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> ContextCache<K> {
+    /// Creates an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the prefix for `body`, running `collect` only on the first
+    /// request for that body.
+    pub fn prefix_or_insert_with(
+        &mut self,
+        body: K,
+        collect: impl FnOnce() -> ContextPrefix,
+    ) -> &ContextPrefix {
+        self.prefixes.entry(body).or_insert_with(collect)
+    }
+
+    /// Returns the number of bodies with a cached prefix.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.prefixes.len()
+    }
+
+    /// Returns `true` when no prefix has been cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.prefixes.is_empty()
+    }
+
+    /// Drops every cached prefix, for example when a new crate is checked.
+    pub fn clear(&mut self) {
+        self.prefixes.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attributes::{Attribute, AttributeKind, AttributePath};
+    use crate::context::{ContextKind, in_test_like_context};
+    use rstest::rstest;
+    use std::cell::Cell;
+
+    fn outer(path: &str) -> Attribute {
+        Attribute::new(AttributePath::from(path), AttributeKind::Outer)
+    }
+
+    /// A crate-to-node stack: module, impl, method, then two in-body blocks.
+    fn full_stack() -> Vec<ContextEntry> {
+        vec![
+            ContextEntry::new("tests", ContextKind::Module, vec![outer("doc")]),
+            ContextEntry::new("impl", ContextKind::Impl, Vec::new()),
+            ContextEntry::function("load", vec![outer("test")]),
+            ContextEntry::new("block", ContextKind::Block, Vec::new()),
+            ContextEntry::new(
+                "statement",
+                ContextKind::Statement,
+                vec![Attribute::cfg_test(AttributeKind::Outer)],
+            ),
+        ]
+    }
+
+    #[rstest]
+    #[case::whole_stack_inside(0)]
+    #[case::module_outside(1)]
+    #[case::owner_outside(3)]
+    #[case::whole_stack_outside(5)]
+    fn split_stacks_summarise_identically(#[case] split: usize) {
+        let stack = full_stack();
+        let (outside, inside) = stack.split_at(split);
+        let prefix = ContextPrefix::new(outside.to_vec(), in_test_like_context(outside));
+
+        let (entries, has_test_ancestry) =
+            prefix.extend(inside.to_vec(), in_test_like_context(inside));
+
+        assert_eq!(entries, stack);
+        assert_eq!(has_test_ancestry, in_test_like_context(&stack));
+    }
+
+    #[rstest]
+    fn prefixes_are_collected_once_per_body() {
+        let mut cache = ContextCache::new();
+        let collections = Cell::new(0);
+        let collect = || {
+            collections.set(collections.get() + 1);
+            ContextPrefix::new(full_stack(), true)
+        };
+
+        for body in [1, 2, 1, 1, 2] {
+            let prefix = cache.prefix_or_insert_with(body, collect);
+            assert_eq!(prefix.entries(), full_stack().as_slice());
+        }
+
+        assert_eq!(collections.get(), 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[rstest]
+    fn clearing_forgets_every_body() {
+        let mut cache = ContextCache::new();
+        let _ = cache.prefix_or_insert_with("body", ContextPrefix::default);
+
+        cache.clear();
+
+        assert!(cache.is_empty());
+    }
+}
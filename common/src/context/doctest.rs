@@ -0,0 +1,211 @@
+//! Doctest detection from several independent compiler signals.
+//!
+//! Rustdoc does not mark doctest crates in their source, so lints have to
+//! infer them from how the crate was compiled. Relying on one signal, such as
+//! the perma-unstable `UNSTABLE_RUSTDOC_TEST_PATH` variable, breaks whenever
+//! rustdoc changes that contract. [`DoctestSignals`] gathers every signal the
+//! driver can observe and [`DoctestSignals::is_doctest`] is the single place
+//! that decides how they combine.
+
+/// Crate name rustdoc gives doctests compiled one at a time.
+const STANDALONE_CRATE_NAME: &str = "rust_out";
+
+/// Crate name prefixes rustdoc gives merged doctests, followed by the edition.
+const MERGED_CRATE_PREFIXES: &[&str] = &["doctest_bundle_", "doctest_runner_"];
+
+/// Observations about the crate being compiled that hint at a doctest.
+///
+/// Lint drivers fill these in from the compiler session; the struct itself
+/// has no compiler dependencies so the decision can be tested directly.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::context::DoctestSignals;
+///
+/// let library = DoctestSignals::new("my_crate").with_source_name("src/lib.rs");
+/// assert!(!library.is_doctest());
+///
+/// let doctest = DoctestSignals::new("rust_out");
+/// assert!(doctest.is_doctest());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DoctestSignals {
+    rustdoc_test_path: bool,
+    crate_name: String,
+    is_test_harness: bool,
+    source_name: Option<String>,
+}
+
+impl DoctestSignals {
+    /// Starts from the crate name with every other signal absent.
+    ///
+    /// The source is treated as synthetic until
+    /// [`with_source_name`](Self::with_source_name) supplies a file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::DoctestSignals;
+    ///
+    /// let signals = DoctestSignals::new("my_crate");
+    /// assert_eq!(signals.crate_name(), "my_crate");
+    /// ```
+    #[must_use]
+    pub fn new(crate_name: impl Into<String>) -> Self {
+        Self {
+            crate_name: crate_name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Records whether rustdoc's `UNSTABLE_RUSTDOC_TEST_PATH` variable is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::DoctestSignals;
+    ///
+    /// let signals = DoctestSignals::new("demo")
+    ///     .with_source_name("src/lib.rs")
+    ///     .with_rustdoc_test_path(true);
+    /// assert!(signals.is_doctest());
+    /// ```
+    #[must_use]
+    pub fn with_rustdoc_test_path(mut self, present: bool) -> Self {
+        self.rustdoc_test_path = present;
+        self
+    }
+
+    /// Records whether the crate is compiled with `--test`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::DoctestSignals;
+    ///
+    /// let signals = DoctestSignals::new("demo").with_test_harness(true);
+    /// assert!(signals.is_doctest());
+    /// ```
+    #[must_use]
+    pub fn with_test_harness(mut self, is_test_harness: bool) -> Self {
+        self.is_test_harness = is_test_harness;
+        self
+    }
+
+    /// Records the name of the source file the crate was compiled from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::DoctestSignals;
+    ///
+    /// let signals = DoctestSignals::new("demo")
+    ///     .with_test_harness(true)
+    ///     .with_source_name("tests/api.rs");
+    /// assert!(!signals.is_doctest());
+    /// ```
+    #[must_use]
+    pub fn with_source_name(mut self, source_name: impl Into<String>) -> Self {
+        self.source_name = Some(source_name.into());
+        self
+    }
+
+    /// Returns the crate name.
+    #[must_use]
+    pub fn crate_name(&self) -> &str {
+        &self.crate_name
+    }
+
+    /// Returns `true` when any signal identifies the crate as a doctest.
+    ///
+    /// A crate is a doctest when rustdoc's environment variable is set, when
+    /// its name matches the names rustdoc generates for standalone or merged
+    /// doctests, or when it is built as a test harness from a synthetic
+    /// source rather than a file on disk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::DoctestSignals;
+    ///
+    /// assert!(DoctestSignals::new("doctest_bundle_2024").is_doctest());
+    /// assert!(
+    ///     DoctestSignals::new("demo")
+    ///         .with_test_harness(true)
+    ///         .with_source_name("<anon>")
+    ///         .is_doctest()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn is_doctest(&self) -> bool {
+        self.rustdoc_test_path
+            || is_doctest_crate_name(&self.crate_name)
+            || (self.is_test_harness && self.has_synthetic_source())
+    }
+
+    fn has_synthetic_source(&self) -> bool {
+        self.source_name
+            .as_deref()
+            .is_none_or(is_synthetic_source_name)
+    }
+}
+
+/// Returns `true` for crate names rustdoc generates for doctests.
+fn is_doctest_crate_name(name: &str) -> bool {
+    name == STANDALONE_CRATE_NAME
+        || MERGED_CRATE_PREFIXES.iter().any(|prefix| {
+            name.strip_prefix(prefix).is_some_and(|edition| {
+                !edition.is_empty() && edition.bytes().all(|byte| byte.is_ascii_digit())
+            })
+        })
+}
+
+/// Returns `true` for compiler placeholder names such as `<anon>`.
+fn is_synthetic_source_name(name: &str) -> bool {
+    name.is_empty() || (name.starts_with('<') && name.ends_with('>'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::standalone("rust_out", true)]
+    #[case::bundle("doctest_bundle_2024", true)]
+    #[case::runner("doctest_runner_2021", true)]
+    #[case::missing_edition("doctest_bundle_", false)]
+    #[case::suffix_not_edition("doctest_runner_tools", false)]
+    #[case::ordinary("rust_output", false)]
+    fn recognises_rustdoc_crate_names(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(is_doctest_crate_name(name), expected);
+    }
+
+    #[rstest]
+    #[case::anonymous("<anon>", true)]
+    #[case::empty("", true)]
+    #[case::library("src/lib.rs", false)]
+    #[case::angle_in_path("src/<gen>.rs", false)]
+    fn recognises_synthetic_source_names(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(is_synthetic_source_name(name), expected);
+    }
+
+    #[rstest]
+    #[case::library(false, Some("src/lib.rs"), false)]
+    #[case::unit_tests(true, Some("src/lib.rs"), false)]
+    #[case::harness_from_string(true, None, true)]
+    #[case::string_without_harness(false, None, false)]
+    fn harness_requires_synthetic_source(
+        #[case] is_test_harness: bool,
+        #[case] source_name: Option<&str>,
+        #[case] expected: bool,
+    ) {
+        let mut signals = DoctestSignals::new("demo").with_test_harness(is_test_harness);
+        if let Some(name) = source_name {
+            signals = signals.with_source_name(name);
+        }
+
+        assert_eq!(signals.is_doctest(), expected);
+    }
+}
@@ -0,0 +1,159 @@
+//! Localised descriptions of the item that encloses a diagnostic site.
+//!
+//! Notes such as "the call originates within function `main`" need the same
+//! wording in every lint. Compiler-facing layers classify the enclosing item
+//! into an [`EnclosingItem`], and [`EnclosingItem::describe`] renders it
+//! through the shared `common-enclosing-item` message so every locale words
+//! it the same way.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::i18n::{Arguments, BundleLookup, FluentValue, MessageKey};
+
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("common-enclosing-item");
+
+/// The kind of item enclosing a diagnostic site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnclosingItemKind {
+    /// A free function.
+    Function,
+    /// A method or associated function in an `impl` or trait.
+    Method,
+    /// A closure; the name is that of the item containing it.
+    Closure,
+    /// A `const` item or associated constant.
+    Const,
+    /// A `static` item.
+    Static,
+    /// Anything else, such as a module or an anonymous constant.
+    Scope,
+}
+
+impl EnclosingItemKind {
+    /// Returns the selector passed to the shared Fluent message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::EnclosingItemKind;
+    ///
+    /// assert_eq!(EnclosingItemKind::Method.as_key(), "method");
+    /// ```
+    #[must_use]
+    pub const fn as_key(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Method => "method",
+            Self::Closure => "closure",
+            Self::Const => "const",
+            Self::Static => "static",
+            Self::Scope => "scope",
+        }
+    }
+}
+
+/// The item enclosing a diagnostic site, ready to be described.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::context::{EnclosingItem, EnclosingItemKind};
+///
+/// let item = EnclosingItem::new(EnclosingItemKind::Method, "load");
+/// assert_eq!(item.to_string(), "method `load`");
+/// assert_eq!(EnclosingItem::scope().to_string(), "the surrounding scope");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnclosingItem {
+    kind: EnclosingItemKind,
+    name: Option<String>,
+}
+
+impl EnclosingItem {
+    /// Creates a description of a named item.
+    #[must_use]
+    pub fn new(kind: EnclosingItemKind, name: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: Some(name.into()),
+        }
+    }
+
+    /// Creates a description of an unnamed surrounding scope.
+    #[must_use]
+    pub const fn scope() -> Self {
+        Self {
+            kind: EnclosingItemKind::Scope,
+            name: None,
+        }
+    }
+
+    /// Returns the item kind.
+    #[must_use]
+    pub const fn kind(&self) -> EnclosingItemKind {
+        self.kind
+    }
+
+    /// Returns the item name, if it has one.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Describes the item in the lookup's locale, falling back to English
+    /// when the shared message is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::context::{EnclosingItem, EnclosingItemKind};
+    /// use whitaker_common::i18n::Localizer;
+    ///
+    /// let item = EnclosingItem::new(EnclosingItemKind::Function, "handler");
+    /// assert_eq!(item.describe(&Localizer::new(Some("en-GB"))), "function `handler`");
+    /// assert_eq!(item.describe(&Localizer::new(Some("cy"))), "ffwythiant `handler`");
+    /// ```
+    #[must_use]
+    pub fn describe(&self, lookup: &impl BundleLookup) -> String {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(Cow::Borrowed("kind"), FluentValue::from(self.key()));
+        args.insert(
+            Cow::Borrowed("name"),
+            FluentValue::from(self.name.clone().unwrap_or_default()),
+        );
+
+        match lookup.message(MESSAGE_KEY, &args) {
+            Ok(mut text) => {
+                text.retain(|ch| !matches!(ch, '\u{2068}' | '\u{2069}'));
+                text
+            }
+            Err(_) => self.to_string(),
+        }
+    }
+
+    /// Unnamed items are described as the surrounding scope whatever their
+    /// kind.
+    fn key(&self) -> &'static str {
+        match self.name {
+            Some(_) => self.kind.as_key(),
+            None => EnclosingItemKind::Scope.as_key(),
+        }
+    }
+}
+
+impl fmt::Display for EnclosingItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(name) = &self.name else {
+            return f.write_str("the surrounding scope");
+        };
+        match self.kind {
+            EnclosingItemKind::Function => write!(f, "function `{name}`"),
+            EnclosingItemKind::Method => write!(f, "method `{name}`"),
+            EnclosingItemKind::Closure => write!(f, "a closure in `{name}`"),
+            EnclosingItemKind::Const => write!(f, "constant `{name}`"),
+            EnclosingItemKind::Static => write!(f, "static `{name}`"),
+            EnclosingItemKind::Scope => f.write_str("the surrounding scope"),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+//! Per-crate lint pass state.
+//!
+//! A lint pass outlives the crate it is checking: the same pass value can see
+//! `check_crate` for one crate and then for another. Anything the pass learnt
+//! about the first crate (its configuration, localizer, doctest flag, or
+//! collected items) must not survive into the second. [`CrateScopedState`]
+//! holds that state as a single value, so `check_crate` replaces all of it at
+//! once rather than resetting fields one by one.
+
+use std::ops::{Deref, DerefMut};
+
+/// State a lint pass rebuilds for every crate it checks.
+///
+/// Group the per-crate fields of a pass into one `T` and call
+/// [`CrateScopedState::reset`] from `check_crate` with a freshly built value.
+/// Because the whole value is replaced, a field added later cannot be
+/// forgotten by the reset. Before the first reset the state holds
+/// `T::default()`. The state dereferences to `T`, so the other pass methods
+/// read it as they would plain fields.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::CrateScopedState;
+///
+/// #[derive(Default)]
+/// struct State {
+///     crate_name: String,
+///     findings: Vec<String>,
+/// }
+///
+/// let mut state = CrateScopedState::<State>::default();
+/// state.reset(State { crate_name: "first".into(), ..State::default() });
+/// state.findings.push("unwrap".into());
+///
+/// state.reset(State { crate_name: "second".into(), ..State::default() });
+/// assert_eq!(state.crate_name, "second");
+/// assert!(state.findings.is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CrateScopedState<T> {
+    current: T,
+    crates: usize,
+}
+
+impl<T> CrateScopedState<T> {
+    /// Replaces the state left by any earlier crate with `state`.
+    ///
+    /// Call this from `check_crate`, before any other pass method reads the
+    /// state.
+    pub fn reset(&mut self, state: T) {
+        self.current = state;
+        self.crates = self.crates.saturating_add(1);
+    }
+
+    /// Returns how many crates have reset the state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::CrateScopedState;
+    ///
+    /// let mut state = CrateScopedState::<u32>::default();
+    /// assert_eq!(state.crates(), 0);
+    /// state.reset(3);
+    /// assert_eq!(state.crates(), 1);
+    /// ```
+    #[must_use]
+    pub const fn crates(&self) -> usize {
+        self.crates
+    }
+}
+
+impl<T> Deref for CrateScopedState<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.current
+    }
+}
+
+impl<T> DerefMut for CrateScopedState<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct State {
+        is_doctest: bool,
+        seen: Vec<&'static str>,
+    }
+
+    #[rstest]
+    fn unreset_state_is_default() {
+        let state = CrateScopedState::<State>::default();
+
+        assert_eq!(*state, State::default());
+        assert_eq!(state.crates(), 0);
+    }
+
+    #[rstest]
+    fn reset_replaces_every_field() {
+        let mut state = CrateScopedState::<State>::default();
+        state.reset(State {
+            is_doctest: true,
+            seen: Vec::new(),
+        });
+        state.seen.push("first");
+
+        state.reset(State::default());
+
+        assert_eq!(*state, State::default());
+        assert_eq!(state.crates(), 2);
+    }
+}
@@ -0,0 +1,126 @@
+//! Grouping of function signatures into data clumps.
+//!
+//! Candidates are the parameter sets that pairs of signatures share. This
+//! module keeps the candidates that recur often enough and drops those a
+//! wider clump already covers; the types it returns live in the parent
+//! module.
+
+use std::collections::BTreeSet;
+
+use super::{DataClump, DataClumpThresholds, FunctionSignature, Parameter};
+
+/// Finds parameter groups shared by enough signatures to count as clumps.
+///
+/// Candidate groups are the parameter sets that pairs of signatures have in
+/// common. Each candidate with at least `min_parameters` members is kept when
+/// `min_occurrences` or more signatures contain all of it. A candidate is
+/// dropped when a larger clump covers exactly the same signatures, so each
+/// group of functions is reported once with the widest shared parameter set.
+///
+/// Results are ordered by first occurrence, then by parameters.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::data_clumps::{
+///     DataClumpThresholds, FunctionSignature, Parameter, find_data_clumps,
+/// };
+///
+/// let signatures = [
+///     FunctionSignature::new("open", [
+///         Parameter::new("host", "&str"),
+///         Parameter::new("port", "u16"),
+///         Parameter::new("timeout", "u64"),
+///     ]),
+///     FunctionSignature::new("probe", [
+///         Parameter::new("host", "&str"),
+///         Parameter::new("port", "u16"),
+///         Parameter::new("timeout", "u64"),
+///         Parameter::new("retries", "u8"),
+///     ]),
+/// ];
+///
+/// let clumps = find_data_clumps(&signatures, DataClumpThresholds::new(3, 2));
+/// assert_eq!(clumps.len(), 1);
+/// assert_eq!(clumps[0].parameters().len(), 3);
+///
+/// assert!(find_data_clumps(&signatures, DataClumpThresholds::default()).is_empty());
+/// ```
+#[must_use]
+pub fn find_data_clumps(
+    signatures: &[FunctionSignature],
+    thresholds: DataClumpThresholds,
+) -> Vec<DataClump> {
+    let eligible: Vec<usize> = signatures
+        .iter()
+        .enumerate()
+        .filter(|(_, signature)| signature.parameters.len() >= thresholds.min_parameters)
+        .map(|(index, _)| index)
+        .collect();
+
+    let clumps: Vec<DataClump> = candidate_groups(signatures, &eligible, thresholds)
+        .into_iter()
+        .map(|parameters| DataClump {
+            occurrences: occurrences_of(&parameters, signatures, &eligible),
+            parameters: parameters.into_iter().collect(),
+        })
+        .filter(|clump| clump.occurrences.len() >= thresholds.min_occurrences)
+        .collect();
+
+    let mut maximal: Vec<DataClump> = clumps
+        .iter()
+        .filter(|clump| !is_subsumed(clump, &clumps))
+        .cloned()
+        .collect();
+    maximal.sort_by(|left, right| {
+        (left.first_occurrence(), &left.parameters)
+            .cmp(&(right.first_occurrence(), &right.parameters))
+    });
+    maximal
+}
+
+/// Collects the pairwise parameter intersections large enough to be clumps.
+fn candidate_groups(
+    signatures: &[FunctionSignature],
+    eligible: &[usize],
+    thresholds: DataClumpThresholds,
+) -> BTreeSet<BTreeSet<Parameter>> {
+    let mut candidates = BTreeSet::new();
+    for (position, &left) in eligible.iter().enumerate() {
+        for &right in &eligible[position + 1..] {
+            let shared: BTreeSet<Parameter> = signatures[left]
+                .parameters
+                .intersection(&signatures[right].parameters)
+                .cloned()
+                .collect();
+            if shared.len() >= thresholds.min_parameters {
+                candidates.insert(shared);
+            }
+        }
+    }
+    candidates
+}
+
+fn occurrences_of(
+    parameters: &BTreeSet<Parameter>,
+    signatures: &[FunctionSignature],
+    eligible: &[usize],
+) -> Vec<usize> {
+    eligible
+        .iter()
+        .copied()
+        .filter(|&index| parameters.is_subset(&signatures[index].parameters))
+        .collect()
+}
+
+/// Returns `true` when a strictly larger clump covers the same signatures.
+fn is_subsumed(clump: &DataClump, clumps: &[DataClump]) -> bool {
+    clumps.iter().any(|other| {
+        other.parameters.len() > clump.parameters.len()
+            && other.occurrences == clump.occurrences
+            && clump
+                .parameters
+                .iter()
+                .all(|parameter| other.parameters.binary_search(parameter).is_ok())
+    })
+}
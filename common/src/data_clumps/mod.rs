@@ -0,0 +1,291 @@
+//! Crate-wide detection of data clumps in function signatures.
+//!
+//! A data clump is a group of parameters that travel together: the same
+//! names with the same types appear in the signatures of several functions.
+//! Such groups usually want to become a struct. Detection needs every
+//! signature in the crate at once, so lint drivers collect
+//! [`FunctionSignature`] summaries during a crate-wide pass and hand them to
+//! [`find_data_clumps`].
+//!
+//! Like the LCOM4 helpers, this module is pure library code. It does not
+//! depend on `rustc_private`; drivers render parameter types to strings
+//! before building signatures.
+
+use std::collections::BTreeSet;
+
+mod grouping;
+
+pub use grouping::find_data_clumps;
+
+/// A named, typed parameter as it appears in a function signature.
+///
+/// Two parameters match only when both the name and the rendered type are
+/// equal, so `id: u32` and `id: u64` belong to different clumps.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::data_clumps::Parameter;
+///
+/// let parameter = Parameter::new("width", "u32");
+/// assert_eq!(parameter.name(), "width");
+/// assert_eq!(parameter.ty(), "u32");
+/// assert_eq!(parameter.to_string(), "width: u32");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Parameter {
+    name: String,
+    ty: String,
+}
+
+impl Parameter {
+    /// Creates a parameter from its name and rendered type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::Parameter;
+    ///
+    /// let parameter = Parameter::new("host", "&str");
+    /// assert_eq!(parameter.ty(), "&str");
+    /// ```
+    #[must_use]
+    pub fn new(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ty: ty.into(),
+        }
+    }
+
+    /// Returns the parameter name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::Parameter;
+    ///
+    /// assert_eq!(Parameter::new("port", "u16").name(), "port");
+    /// ```
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the rendered parameter type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::Parameter;
+    ///
+    /// assert_eq!(Parameter::new("port", "u16").ty(), "u16");
+    /// ```
+    #[must_use]
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+}
+
+impl std::fmt::Display for Parameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.ty)
+    }
+}
+
+/// The parameters of one function, ignoring order and any `self` receiver.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::data_clumps::{FunctionSignature, Parameter};
+///
+/// let signature = FunctionSignature::new(
+///     "connect",
+///     [Parameter::new("host", "&str"), Parameter::new("port", "u16")],
+/// );
+/// assert_eq!(signature.name(), "connect");
+/// assert_eq!(signature.parameters().len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionSignature {
+    name: String,
+    parameters: BTreeSet<Parameter>,
+}
+
+impl FunctionSignature {
+    /// Creates a signature summary from a function name and its parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::{FunctionSignature, Parameter};
+    ///
+    /// let signature = FunctionSignature::new("draw", [Parameter::new("x", "i32")]);
+    /// assert!(signature.parameters().contains(&Parameter::new("x", "i32")));
+    /// ```
+    #[must_use]
+    pub fn new(name: impl Into<String>, parameters: impl IntoIterator<Item = Parameter>) -> Self {
+        Self {
+            name: name.into(),
+            parameters: parameters.into_iter().collect(),
+        }
+    }
+
+    /// Returns the function name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::FunctionSignature;
+    ///
+    /// assert_eq!(FunctionSignature::new("draw", []).name(), "draw");
+    /// ```
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the parameters in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::FunctionSignature;
+    ///
+    /// assert!(FunctionSignature::new("draw", []).parameters().is_empty());
+    /// ```
+    #[must_use]
+    pub fn parameters(&self) -> &BTreeSet<Parameter> {
+        &self.parameters
+    }
+}
+
+/// Limits controlling which parameter groups count as data clumps.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::data_clumps::DataClumpThresholds;
+///
+/// let thresholds = DataClumpThresholds::default();
+/// assert_eq!(thresholds.min_parameters(), 3);
+/// assert_eq!(thresholds.min_occurrences(), 3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DataClumpThresholds {
+    min_parameters: usize,
+    min_occurrences: usize,
+}
+
+impl DataClumpThresholds {
+    /// Creates thresholds from the minimum clump size and the minimum number
+    /// of signatures that must share it.
+    ///
+    /// Both values are raised to at least two, since a lone parameter or a
+    /// single signature cannot form a clump.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::DataClumpThresholds;
+    ///
+    /// let thresholds = DataClumpThresholds::new(4, 1);
+    /// assert_eq!(thresholds.min_parameters(), 4);
+    /// assert_eq!(thresholds.min_occurrences(), 2);
+    /// ```
+    #[must_use]
+    pub fn new(min_parameters: usize, min_occurrences: usize) -> Self {
+        Self {
+            min_parameters: min_parameters.max(2),
+            min_occurrences: min_occurrences.max(2),
+        }
+    }
+
+    /// Returns the minimum number of shared parameters in a clump.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::DataClumpThresholds;
+    ///
+    /// assert_eq!(DataClumpThresholds::new(5, 3).min_parameters(), 5);
+    /// ```
+    #[must_use]
+    pub fn min_parameters(&self) -> usize {
+        self.min_parameters
+    }
+
+    /// Returns the minimum number of signatures that must share a clump.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::data_clumps::DataClumpThresholds;
+    ///
+    /// assert_eq!(DataClumpThresholds::new(3, 4).min_occurrences(), 4);
+    /// ```
+    #[must_use]
+    pub fn min_occurrences(&self) -> usize {
+        self.min_occurrences
+    }
+}
+
+impl Default for DataClumpThresholds {
+    fn default() -> Self {
+        Self::new(3, 3)
+    }
+}
+
+/// A group of parameters shared by several function signatures.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::data_clumps::{
+///     DataClumpThresholds, FunctionSignature, Parameter, find_data_clumps,
+/// };
+///
+/// let point = || [
+///     Parameter::new("x", "f64"),
+///     Parameter::new("y", "f64"),
+///     Parameter::new("z", "f64"),
+/// ];
+/// let signatures = [
+///     FunctionSignature::new("translate", point()),
+///     FunctionSignature::new("scale", point()),
+///     FunctionSignature::new("rotate", point()),
+/// ];
+///
+/// let clumps = find_data_clumps(&signatures, DataClumpThresholds::default());
+/// assert_eq!(clumps[0].occurrences(), &[0, 1, 2]);
+/// assert_eq!(clumps[0].first_occurrence(), 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataClump {
+    parameters: Vec<Parameter>,
+    occurrences: Vec<usize>,
+}
+
+impl DataClump {
+    /// Returns the shared parameters in sorted order.
+    #[must_use]
+    pub fn parameters(&self) -> &[Parameter] {
+        &self.parameters
+    }
+
+    /// Returns the indices of the signatures containing the clump, in the
+    /// order they were supplied.
+    #[must_use]
+    pub fn occurrences(&self) -> &[usize] {
+        &self.occurrences
+    }
+
+    /// Returns the index of the first signature containing the clump.
+    #[must_use]
+    pub fn first_occurrence(&self) -> usize {
+        self.occurrences.first().copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+//! rstest-based unit tests for [`super::find_data_clumps`] and supporting types.
+
+use super::*;
+use rstest::rstest;
+
+fn signature(name: &str, parameters: &[(&str, &str)]) -> FunctionSignature {
+    FunctionSignature::new(
+        name,
+        parameters
+            .iter()
+            .map(|(parameter, ty)| Parameter::new(*parameter, *ty)),
+    )
+}
+
+fn names(clump: &DataClump) -> Vec<&str> {
+    clump.parameters().iter().map(Parameter::name).collect()
+}
+
+const POINT: &[(&str, &str)] = &[("x", "f64"), ("y", "f64"), ("z", "f64")];
+
+#[rstest]
+fn reports_group_shared_by_three_signatures() {
+    let signatures = [
+        signature("translate", POINT),
+        signature("scale", POINT),
+        signature("rotate", POINT),
+    ];
+
+    let clumps = find_data_clumps(&signatures, DataClumpThresholds::default());
+
+    assert_eq!(clumps.len(), 1);
+    assert_eq!(names(&clumps[0]), ["x", "y", "z"]);
+    assert_eq!(clumps[0].occurrences(), &[0, 1, 2]);
+}
+
+#[rstest]
+#[case::default_needs_three_signatures(3, 3, 0)]
+#[case::two_signatures_allowed(3, 2, 1)]
+#[case::larger_group_required(4, 2, 0)]
+fn thresholds_gate_reporting(
+    #[case] min_parameters: usize,
+    #[case] min_occurrences: usize,
+    #[case] expected: usize,
+) {
+    let signatures = [signature("translate", POINT), signature("scale", POINT)];
+    let thresholds = DataClumpThresholds::new(min_parameters, min_occurrences);
+
+    assert_eq!(find_data_clumps(&signatures, thresholds).len(), expected);
+}
+
+#[rstest]
+fn type_mismatch_breaks_the_group() {
+    let signatures = [
+        signature("a", POINT),
+        signature("b", POINT),
+        signature("c", &[("x", "f64"), ("y", "f64"), ("z", "f32")]),
+    ];
+
+    assert!(find_data_clumps(&signatures, DataClumpThresholds::default()).is_empty());
+}
+
+#[rstest]
+fn extra_parameters_do_not_hide_the_group() {
+    let signatures = [
+        signature(
+            "a",
+            &[("x", "f64"), ("y", "f64"), ("z", "f64"), ("w", "u8")],
+        ),
+        signature(
+            "b",
+            &[("scale", "f64"), ("x", "f64"), ("y", "f64"), ("z", "f64")],
+        ),
+        signature("c", POINT),
+    ];
+
+    let clumps = find_data_clumps(&signatures, DataClumpThresholds::default());
+
+    assert_eq!(clumps.len(), 1);
+    assert_eq!(names(&clumps[0]), ["x", "y", "z"]);
+}
+
+#[rstest]
+fn smaller_group_with_same_signatures_is_subsumed() {
+    let wide = &[("a", "u8"), ("b", "u8"), ("c", "u8"), ("d", "u8")];
+    let signatures = [
+        signature("f", wide),
+        signature("g", wide),
+        signature("h", wide),
+    ];
+
+    let clumps = find_data_clumps(&signatures, DataClumpThresholds::default());
+
+    assert_eq!(clumps.len(), 1);
+    assert_eq!(names(&clumps[0]), ["a", "b", "c", "d"]);
+}
+
+#[rstest]
+fn smaller_group_spanning_more_signatures_is_kept() {
+    let wide = &[("w", "f64"), ("x", "f64"), ("y", "f64"), ("z", "f64")];
+    let signatures = [
+        signature("a", wide),
+        signature("b", wide),
+        signature("c", wide),
+        signature("d", POINT),
+    ];
+
+    let clumps = find_data_clumps(&signatures, DataClumpThresholds::default());
+
+    assert_eq!(clumps.len(), 2);
+    assert_eq!(clumps[0].occurrences(), &[0, 1, 2]);
+    assert_eq!(clumps[1].occurrences(), &[0, 1, 2, 3]);
+}
+
+#[rstest]
+fn thresholds_are_raised_to_two() {
+    let thresholds = DataClumpThresholds::new(0, 1);
+
+    assert_eq!(thresholds.min_parameters(), 2);
+    assert_eq!(thresholds.min_occurrences(), 2);
+}
@@ -0,0 +1,212 @@
+//! Stable finding fingerprints that survive line-number churn.
+//!
+//! Baselines, ratchets, and report comparisons need to recognise the same
+//! finding across runs even when unrelated edits move it around a file. A
+//! fingerprint therefore hashes what a finding *is* rather than where it sits:
+//!
+//! - the lint name;
+//! - the repository-relative path, normalised to `/` separators without `.`
+//!   or `..` segments;
+//! - the flagged source snippet, with all whitespace removed so that
+//!   reformatting does not churn the hash; and
+//! - the name of the enclosing item, when known, so identical snippets in
+//!   different functions stay distinct.
+//!
+//! The hash is 64-bit FNV-1a, which is stable across platforms, Rust
+//! releases, and processes, unlike [`std::hash::DefaultHasher`]. Consumers
+//! should store fingerprints under [`FINGERPRINT_KEY`] so a future change to
+//! the scheme can be introduced under a new version.
+
+use std::fmt;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Versioned key under which fingerprints are recorded, such as the SARIF
+/// `partialFingerprints` map or JSON reports.
+pub const FINGERPRINT_KEY: &str = "whitaker/v1";
+
+/// Position-independent context identifying a finding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FingerprintSource<'a> {
+    /// Path of the file containing the finding, relative to the workspace.
+    pub path: &'a str,
+    /// Source text covered by the primary span.
+    pub snippet: &'a str,
+    /// Name of the item enclosing the finding, when known.
+    pub enclosing_item: Option<&'a str>,
+}
+
+/// A stable 64-bit finding fingerprint.
+///
+/// Displays as 16 lowercase hexadecimal digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    /// Returns the raw hash value.
+    #[must_use]
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// Computes the fingerprint of a finding reported by `lint`.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::diagnostics::{FingerprintSource, fingerprint};
+///
+/// let before = FingerprintSource {
+///     path: "src/lib.rs",
+///     snippet: "value.expect(\"present\")",
+///     enclosing_item: Some("load"),
+/// };
+/// let reformatted = FingerprintSource {
+///     path: "./src/lib.rs",
+///     snippet: "value\n    .expect(\"present\")",
+///     ..before
+/// };
+///
+/// assert_eq!(
+///     fingerprint("no_expect_outside_tests", &before),
+///     fingerprint("no_expect_outside_tests", &reformatted),
+/// );
+/// ```
+#[must_use]
+pub fn fingerprint(lint: &str, source: &FingerprintSource<'_>) -> Fingerprint {
+    let path = normalise_path(source.path);
+    let snippet = normalise_snippet(source.snippet);
+    let fields = [
+        lint,
+        path.as_str(),
+        snippet.as_str(),
+        source.enclosing_item.unwrap_or_default(),
+    ];
+    Fingerprint(fields.iter().fold(FNV_OFFSET_BASIS, |hash, field| {
+        field
+            .bytes()
+            .chain(std::iter::once(0))
+            .fold(hash, |acc, byte| {
+                (acc ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }))
+}
+
+/// Normalises a relative path for fingerprinting.
+///
+/// Backslashes become `/`, empty and `.` segments are dropped, and `..`
+/// removes the preceding segment where there is one.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::diagnostics::normalise_path;
+///
+/// assert_eq!(normalise_path(r".\crates\cli\..\core//src/lib.rs"), "crates/core/src/lib.rs");
+/// ```
+#[must_use]
+pub fn normalise_path(path: &str) -> String {
+    let unified = path.replace('\\', "/");
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in unified.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if segments.last().is_some_and(|last| *last != "..") => {
+                segments.pop();
+            }
+            other => segments.push(other),
+        }
+    }
+    segments.join("/")
+}
+
+/// Expresses `path` relative to `root` before normalising it.
+///
+/// Paths outside `root` are normalised unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::diagnostics::relative_path;
+///
+/// assert_eq!(relative_path("/work/repo/src/lib.rs", "/work/repo/"), "src/lib.rs");
+/// assert_eq!(relative_path("/elsewhere/lib.rs", "/work/repo"), "elsewhere/lib.rs");
+/// ```
+#[must_use]
+pub fn relative_path(path: &str, root: &str) -> String {
+    let normalised = normalise_path(path);
+    let prefix = normalise_path(root);
+    normalised
+        .strip_prefix(prefix.as_str())
+        .and_then(|rest| rest.strip_prefix('/'))
+        .map_or_else(|| normalised.clone(), str::to_owned)
+}
+
+fn normalise_snippet(snippet: &str) -> String {
+    snippet
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn source<'a>(path: &'a str, snippet: &'a str, item: Option<&'a str>) -> FingerprintSource<'a> {
+        FingerprintSource {
+            path,
+            snippet,
+            enclosing_item: item,
+        }
+    }
+
+    #[rstest]
+    #[case::lint("other", source("src/lib.rs", "a.expect(x)", Some("load")))]
+    #[case::path("lint", source("src/main.rs", "a.expect(x)", Some("load")))]
+    #[case::snippet("lint", source("src/lib.rs", "b.expect(x)", Some("load")))]
+    #[case::item("lint", source("src/lib.rs", "a.expect(x)", Some("save")))]
+    #[case::no_item("lint", source("src/lib.rs", "a.expect(x)", None))]
+    fn each_component_changes_the_fingerprint(
+        #[case] lint: &str,
+        #[case] variant: FingerprintSource<'_>,
+    ) {
+        let original = fingerprint("lint", &source("src/lib.rs", "a.expect(x)", Some("load")));
+
+        assert_ne!(original, fingerprint(lint, &variant));
+    }
+
+    #[rstest]
+    fn fingerprint_is_stable_across_releases() {
+        let value = fingerprint("lint", &source("src/lib.rs", "a", None));
+
+        assert_eq!(value.to_string(), format!("{:016x}", value.as_u64()));
+        assert_eq!(value.to_string(), "0ada60d7e94915bc");
+    }
+
+    #[rstest]
+    #[case::windows(r"src\lib.rs", "src/lib.rs")]
+    #[case::dot_segments("./src/./lib.rs", "src/lib.rs")]
+    #[case::parent("src/bin/../lib.rs", "src/lib.rs")]
+    #[case::leading_parent("../shared/lib.rs", "../shared/lib.rs")]
+    fn normalises_paths(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(normalise_path(path), expected);
+    }
+
+    #[rstest]
+    fn relative_path_requires_a_segment_boundary() {
+        assert_eq!(
+            relative_path("/repo-other/src/lib.rs", "/repo"),
+            "repo-other/src/lib.rs"
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Per-crate caps on how many findings a lint reports.
+//!
+//! On legacy crates a single lint can report thousands of findings. The
+//! shared `max_findings_per_lint_per_crate` setting caps that number: each lint
+//! keeps a [`FindingBudget`] for the crate being checked, asks it to admit
+//! every finding before emitting it, and reports the findings it held back as
+//! one [`SuppressedFindings`] summary once the crate has been checked.
+
+use std::borrow::Cow;
+use std::cell::Cell;
+
+use crate::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    noop_reporter, safe_resolve_message_set,
+};
+
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("common-findings-suppressed");
+
+/// Counts a lint's findings in one crate against an optional limit.
+///
+/// The budget counts through a [`Cell`], so lints can consult it from the
+/// shared references their emission helpers receive.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::diagnostics::FindingBudget;
+///
+/// let budget = FindingBudget::new(Some(1));
+/// assert!(budget.admit());
+/// assert!(!budget.admit());
+/// assert_eq!(budget.suppressed(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FindingBudget {
+    limit: Option<usize>,
+    seen: Cell<usize>,
+}
+
+impl FindingBudget {
+    /// Creates a budget admitting at most `limit` findings, or every finding
+    /// when `limit` is `None`.
+    #[must_use]
+    pub const fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            seen: Cell::new(0),
+        }
+    }
+
+    /// Returns the configured limit, if any.
+    #[must_use]
+    pub const fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Records a finding and returns `true` when it should be emitted.
+    #[must_use = "findings the budget rejects must not be emitted"]
+    pub fn admit(&self) -> bool {
+        let seen = self.seen.get().saturating_add(1);
+        self.seen.set(seen);
+        self.limit.is_none_or(|limit| seen <= limit)
+    }
+
+    /// Returns how many findings were recorded so far, emitted or not.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.seen.get()
+    }
+
+    /// Returns how many findings were rejected so far.
+    #[must_use]
+    pub fn suppressed(&self) -> usize {
+        self.limit
+            .map_or(0, |limit| self.seen.get().saturating_sub(limit))
+    }
+
+    /// Returns the summary for `lint_name` when findings were rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::diagnostics::FindingBudget;
+    ///
+    /// let budget = FindingBudget::new(Some(0));
+    /// assert!(budget.summary("demo").is_none());
+    /// let _ = budget.admit();
+    /// assert_eq!(budget.summary("demo").map(|summary| summary.remaining()), Some(1));
+    /// ```
+    #[must_use]
+    pub fn summary(&self, lint_name: &str) -> Option<SuppressedFindings> {
+        let limit = self.limit?;
+        let remaining = self.suppressed();
+        (remaining > 0).then(|| SuppressedFindings {
+            lint: lint_name.to_owned(),
+            limit,
+            remaining,
+        })
+    }
+}
+
+/// The findings a lint held back once it reached its limit in a crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuppressedFindings {
+    lint: String,
+    limit: usize,
+    remaining: usize,
+}
+
+impl SuppressedFindings {
+    /// Returns the name of the lint whose findings were held back.
+    #[must_use]
+    pub fn lint(&self) -> &str {
+        &self.lint
+    }
+
+    /// Returns the limit the lint reached.
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns how many findings were held back.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Resolves the summary diagnostic in the localizer's locale, falling
+    /// back to English when the shared message is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::diagnostics::FindingBudget;
+    /// use whitaker_common::i18n::Localizer;
+    ///
+    /// let budget = FindingBudget::new(Some(0));
+    /// let _ = budget.admit();
+    /// let summary = budget.summary("demo").expect("one finding was held back");
+    /// let messages = summary.messages(&Localizer::new(Some("en-GB")));
+    /// assert!(messages.primary().contains("`demo`"));
+    /// ```
+    #[must_use]
+    pub fn messages(&self, localizer: &Localizer) -> DiagnosticMessageSet {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(Cow::Borrowed("lint"), FluentValue::from(self.lint.clone()));
+        args.insert(Cow::Borrowed("limit"), FluentValue::from(self.limit));
+        args.insert(
+            Cow::Borrowed("remaining"),
+            FluentValue::from(self.remaining),
+        );
+
+        let resolution = MessageResolution {
+            lint_name: &self.lint,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+            self.fallback_messages()
+        })
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            lint,
+            limit,
+            remaining,
+        } = self;
+        DiagnosticMessageSet::new(
+            format!(
+                "`{lint}` reached its limit of {limit} findings in this crate; {remaining} more \
+                 were not shown."
+            ),
+            String::from(
+                "`max_findings_per_lint_per_crate` caps the findings each lint reports per \
+                 crate. This diagnostic points at the first finding that was not shown.",
+            ),
+            String::from(
+                "Raise or remove `max_findings_per_lint_per_crate` in `dylint.toml` to list \
+                 every finding in the build log and in the JSON report from `whitaker check`.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn unlimited_budgets_admit_everything() {
+        let budget = FindingBudget::default();
+
+        assert!((0..1_000).all(|_| budget.admit()));
+        assert_eq!(budget.suppressed(), 0);
+        assert!(budget.summary("demo").is_none());
+    }
+
+    #[rstest]
+    #[case(0, 3, 0)]
+    #[case(2, 1, 1)]
+    #[case(2, 2, 2)]
+    #[case(2, 5, 2)]
+    fn limited_budgets_admit_up_to_the_limit(
+        #[case] limit: usize,
+        #[case] findings: usize,
+        #[case] admitted: usize,
+    ) {
+        let budget = FindingBudget::new(Some(limit));
+
+        let emitted = (0..findings).filter(|_| budget.admit()).count();
+
+        assert_eq!(emitted, admitted);
+        assert_eq!(budget.suppressed(), findings - admitted);
+    }
+
+    #[rstest]
+    fn summaries_report_the_remaining_count() {
+        let budget = FindingBudget::new(Some(2));
+        for _ in 0..5 {
+            let _ = budget.admit();
+        }
+
+        let summary = budget
+            .summary("demo")
+            .expect("three findings were held back");
+
+        assert_eq!(summary.lint(), "demo");
+        assert_eq!(summary.limit(), 2);
+        assert_eq!(summary.remaining(), 3);
+    }
+
+    #[rstest]
+    #[case::english("en-GB", "3 more were not shown")]
+    #[case::welsh("cy", "3 arall")]
+    #[case::gaelic("gd", "3 eile")]
+    fn summaries_are_localised(#[case] locale: &str, #[case] expected: &str) {
+        let summary = SuppressedFindings {
+            lint: String::from("demo"),
+            limit: 2,
+            remaining: 3,
+        };
+
+        let messages = summary.messages(&Localizer::new(Some(locale)));
+
+        assert!(
+            messages.primary().contains(expected),
+            "{}",
+            messages.primary()
+        );
+        assert!(messages.help().contains("max_findings_per_lint_per_crate"));
+    }
+
+    #[rstest]
+    fn fallback_messages_point_at_the_json_report() {
+        let summary = SuppressedFindings {
+            lint: String::from("demo"),
+            limit: 2,
+            remaining: 3,
+        };
+
+        let messages = summary.fallback_messages();
+
+        assert!(messages.primary().contains("3 more were not shown"));
+        assert!(messages.help().contains("JSON report"));
+    }
+}
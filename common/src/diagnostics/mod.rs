@@ -1,8 +1,23 @@
 //! Ergonomic builders for lint diagnostics and suggestions.
+//!
+//! The [`fingerprint`] submodule provides position-independent identifiers
+//! for findings, used by reports and SARIF output. The [`limit`] submodule
+//! caps how many findings a lint reports in one crate, and the [`summary`]
+//! submodule totals each lint's findings for the end-of-crate summary.
 #![cfg_attr(test, allow(clippy::expect_used, clippy::unwrap_used))]
 
+mod fingerprint;
+mod limit;
+mod summary;
+
 use crate::span::SourceSpan;
 
+pub use fingerprint::{
+    FINGERPRINT_KEY, Fingerprint, FingerprintSource, fingerprint, normalise_path, relative_path,
+};
+pub use limit::{FindingBudget, SuppressedFindings};
+pub use summary::{CrateSummary, LintTotal};
+
 /// Applicability of a suggestion, mirroring rustc semantics.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Applicability {
@@ -114,6 +129,14 @@ impl Diagnostic {
     pub fn suggestions(&self) -> &[Suggestion] {
         &self.suggestions
     }
+
+    /// Returns the stable fingerprint of this diagnostic in `source`.
+    ///
+    /// See [`fingerprint()`] for the scheme.
+    #[must_use]
+    pub fn fingerprint(&self, source: &FingerprintSource<'_>) -> Fingerprint {
+        fingerprint(&self.code, source)
+    }
 }
 
 /// Builder for [`Diagnostic`] instances.
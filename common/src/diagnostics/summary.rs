@@ -0,0 +1,246 @@
+//! End-of-crate totals for the findings each lint reported.
+//!
+//! CI logs bury the headline numbers under every individual diagnostic. When
+//! the shared `crate_summary` setting is enabled, the suite collects each
+//! lint's total for the crate being checked and reports them as one
+//! [`CrateSummary`] note once every lint has finished.
+
+use std::borrow::Cow;
+
+use crate::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    noop_reporter, safe_resolve_message_set,
+};
+
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("common-crate-summary");
+
+/// The number of findings one lint reported in a crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintTotal {
+    lint: String,
+    findings: usize,
+}
+
+impl LintTotal {
+    /// Creates the total for `lint`.
+    #[must_use]
+    pub fn new(lint: impl Into<String>, findings: usize) -> Self {
+        Self {
+            lint: lint.into(),
+            findings,
+        }
+    }
+
+    /// Returns the lint's name.
+    #[must_use]
+    pub fn lint(&self) -> &str {
+        &self.lint
+    }
+
+    /// Returns how many findings the lint reported, including any that
+    /// `max_findings_per_lint_per_crate` held back.
+    #[must_use]
+    pub const fn findings(&self) -> usize {
+        self.findings
+    }
+}
+
+/// Per-lint finding totals for one crate.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::diagnostics::{CrateSummary, LintTotal};
+///
+/// let summary = CrateSummary::new(
+///     "demo",
+///     vec![LintTotal::new("no_shadowing", 1), LintTotal::new("module_max_lines", 0)],
+/// )
+/// .expect("one lint reported findings");
+/// assert_eq!(summary.total(), 1);
+/// assert_eq!(summary.lines(), ["`no_shadowing`: 1"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrateSummary {
+    crate_name: String,
+    totals: Vec<LintTotal>,
+}
+
+impl CrateSummary {
+    /// Builds the summary for `crate_name`, or `None` when no lint reported a
+    /// finding.
+    ///
+    /// Lints without findings are dropped. The rest are ordered by their
+    /// totals, largest first, with ties broken by lint name.
+    #[must_use]
+    pub fn new(crate_name: impl Into<String>, totals: Vec<LintTotal>) -> Option<Self> {
+        let mut totals: Vec<_> = totals
+            .into_iter()
+            .filter(|total| total.findings > 0)
+            .collect();
+        if totals.is_empty() {
+            return None;
+        }
+        totals.sort_by(|left, right| {
+            right
+                .findings
+                .cmp(&left.findings)
+                .then_with(|| left.lint.cmp(&right.lint))
+        });
+        Some(Self {
+            crate_name: crate_name.into(),
+            totals,
+        })
+    }
+
+    /// Returns the name of the summarised crate.
+    #[must_use]
+    pub fn crate_name(&self) -> &str {
+        &self.crate_name
+    }
+
+    /// Returns the per-lint totals, largest first.
+    #[must_use]
+    pub fn totals(&self) -> &[LintTotal] {
+        &self.totals
+    }
+
+    /// Returns the number of findings across every lint.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.totals.iter().map(LintTotal::findings).sum()
+    }
+
+    /// Returns one line per lint, naming the lint and its total.
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        self.totals
+            .iter()
+            .map(|total| format!("`{}`: {}", total.lint, total.findings))
+            .collect()
+    }
+
+    /// Resolves the summary note in the localizer's locale, falling back to
+    /// English when the shared message is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::diagnostics::{CrateSummary, LintTotal};
+    /// use whitaker_common::i18n::Localizer;
+    ///
+    /// let summary = CrateSummary::new("demo", vec![LintTotal::new("no_shadowing", 2)])
+    ///     .expect("one lint reported findings");
+    /// let messages = summary.messages(&Localizer::new(Some("en-GB")));
+    /// assert!(messages.primary().contains("`demo`"));
+    /// ```
+    #[must_use]
+    pub fn messages(&self, localizer: &Localizer) -> DiagnosticMessageSet {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("crate"),
+            FluentValue::from(self.crate_name.clone()),
+        );
+        args.insert(Cow::Borrowed("total"), FluentValue::from(self.total()));
+        args.insert(Cow::Borrowed("lints"), FluentValue::from(self.totals.len()));
+
+        let resolution = MessageResolution {
+            lint_name: "whitaker",
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+            self.fallback_messages()
+        })
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let crate_name = &self.crate_name;
+        let total = self.total();
+        let lints = self.totals.len();
+        let findings = if total == 1 { "finding" } else { "findings" };
+        let lint_noun = if lints == 1 { "lint" } else { "lints" };
+        DiagnosticMessageSet::new(
+            format!(
+                "Whitaker reported {total} {findings} from {lints} {lint_noun} in crate `{crate_name}`."
+            ),
+            String::from(
+                "Each line gives a lint's total for this crate, including findings that \
+                 `max_findings_per_lint_per_crate` did not show.",
+            ),
+            String::from("Set `crate_summary = false` in `dylint.toml` to turn this summary off."),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn summary() -> CrateSummary {
+        CrateSummary::new(
+            "demo",
+            vec![
+                LintTotal::new("no_shadowing", 2),
+                LintTotal::new("module_max_lines", 0),
+                LintTotal::new("closure_max_lines", 2),
+                LintTotal::new("no_static_mut", 5),
+            ],
+        )
+        .expect("three lints reported findings")
+    }
+
+    #[rstest]
+    fn summaries_need_at_least_one_finding() {
+        let totals = vec![LintTotal::new("no_shadowing", 0)];
+
+        assert!(CrateSummary::new("demo", totals).is_none());
+        assert!(CrateSummary::new("demo", Vec::new()).is_none());
+    }
+
+    #[rstest]
+    fn lines_list_the_largest_totals_first() {
+        let summary = summary();
+
+        assert_eq!(summary.total(), 9);
+        assert_eq!(
+            summary.lines(),
+            [
+                "`no_static_mut`: 5",
+                "`closure_max_lines`: 2",
+                "`no_shadowing`: 2",
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::english("en-GB", "9 findings from 3 lints")]
+    #[case::welsh("cy", "9 canfyddiad")]
+    #[case::gaelic("gd", "9 loirg")]
+    fn summaries_are_localised(#[case] locale: &str, #[case] expected: &str) {
+        let messages = summary().messages(&Localizer::new(Some(locale)));
+
+        assert!(
+            messages.primary().contains(expected),
+            "{}",
+            messages.primary()
+        );
+        assert!(messages.primary().contains("`demo`"));
+        assert!(messages.help().contains("crate_summary"));
+    }
+
+    #[rstest]
+    #[case(1, "1 finding from 1 lint")]
+    #[case(3, "3 findings from 1 lint")]
+    fn english_matches_the_fallback(#[case] findings: usize, #[case] expected: &str) {
+        let summary = CrateSummary::new("demo", vec![LintTotal::new("no_shadowing", findings)])
+            .expect("one lint reported findings");
+
+        let localised = summary.messages(&Localizer::new(Some("en-GB")));
+        let fallback = summary.fallback_messages();
+
+        assert!(fallback.primary().contains(expected));
+        assert_eq!(localised, fallback);
+    }
+}
@@ -0,0 +1,312 @@
+//! Crate-wide detection of near-identical statement sequences.
+//!
+//! Lint drivers lower each block in a function body to a list of
+//! [`NormalizedStatement`]s: token streams in which local names and literal
+//! values have already been erased. [`find_duplicated_blocks`] then slides a
+//! window of `min_statements` statements over every block, buckets windows
+//! whose statements share the same leading tokens, and compares windows from
+//! different functions by token similarity. Each pair of functions is
+//! reported once, at their earliest matching window, extended for as long as
+//! the following statements keep matching.
+//!
+//! The module is pure library code; it does not depend on `rustc_private`.
+
+use std::collections::BTreeMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+mod similarity;
+mod windows;
+
+pub use similarity::token_similarity;
+use windows::{bucket_windows, extend_match, order_ranges, ordered_owners, window_similarity};
+
+/// A normalised token, stored as a stable hash of its text.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::duplicated_blocks::BlockToken;
+///
+/// assert_eq!(BlockToken::from("expr:call"), BlockToken::from("expr:call"));
+/// assert_ne!(BlockToken::from("expr:call"), BlockToken::from("expr:lit"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlockToken(u64);
+
+impl From<&str> for BlockToken {
+    fn from(text: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+/// The normalised tokens of one statement.
+///
+/// The first tokens describe the statement's shape (for example, its kind
+/// and the kind of its top-level expression) and are used to bucket
+/// candidate windows, so drivers should emit them first.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::duplicated_blocks::NormalizedStatement;
+///
+/// let statement = NormalizedStatement::new(["stmt:let", "expr:call", "local"]);
+/// assert_eq!(statement.len(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NormalizedStatement {
+    tokens: Vec<BlockToken>,
+}
+
+impl NormalizedStatement {
+    /// Builds a statement from its token texts in traversal order.
+    #[must_use]
+    pub fn new<'a>(tokens: impl IntoIterator<Item = &'a str>) -> Self {
+        tokens.into_iter().map(BlockToken::from).collect()
+    }
+
+    /// Returns the statement tokens.
+    #[must_use]
+    pub fn tokens(&self) -> &[BlockToken] {
+        &self.tokens
+    }
+
+    /// Returns the number of tokens.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Returns `true` when the statement has no tokens.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    fn skeleton(&self) -> &[BlockToken] {
+        &self.tokens[..self.tokens.len().min(SKELETON_TOKENS)]
+    }
+}
+
+impl FromIterator<BlockToken> for NormalizedStatement {
+    fn from_iter<I: IntoIterator<Item = BlockToken>>(iter: I) -> Self {
+        Self {
+            tokens: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Number of leading statement tokens compared exactly when bucketing.
+const SKELETON_TOKENS: usize = 2;
+
+/// The statements of one block and the function that owns it.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::duplicated_blocks::{NormalizedBlock, NormalizedStatement};
+///
+/// let block = NormalizedBlock::new(0, vec![NormalizedStatement::new(["stmt:semi"])]);
+/// assert_eq!(block.owner(), 0);
+/// assert_eq!(block.statements().len(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NormalizedBlock {
+    owner: usize,
+    statements: Vec<NormalizedStatement>,
+}
+
+impl NormalizedBlock {
+    /// Builds a block owned by the function with index `owner`.
+    #[must_use]
+    pub fn new(owner: usize, statements: Vec<NormalizedStatement>) -> Self {
+        Self { owner, statements }
+    }
+
+    /// Returns the index of the owning function.
+    #[must_use]
+    pub fn owner(&self) -> usize {
+        self.owner
+    }
+
+    /// Returns the block statements in source order.
+    #[must_use]
+    pub fn statements(&self) -> &[NormalizedStatement] {
+        &self.statements
+    }
+}
+
+/// Limits controlling which statement sequences count as duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::duplicated_blocks::DuplicationThresholds;
+///
+/// let thresholds = DuplicationThresholds::new(0, 10, 1.5);
+/// assert_eq!(thresholds.min_statements(), 1);
+/// assert!((thresholds.similarity() - 1.0).abs() < f64::EPSILON);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DuplicationThresholds {
+    min_statements: usize,
+    min_tokens: usize,
+    similarity: f64,
+}
+
+impl DuplicationThresholds {
+    /// Creates thresholds from the minimum window length in statements, the
+    /// minimum number of tokens in a window, and the minimum similarity.
+    ///
+    /// Windows need at least one statement, and similarity is clamped to
+    /// `0.5..=1.0`.
+    #[must_use]
+    pub fn new(min_statements: usize, min_tokens: usize, similarity: f64) -> Self {
+        Self {
+            min_statements: min_statements.max(1),
+            min_tokens,
+            similarity: if similarity.is_nan() {
+                1.0
+            } else {
+                similarity.clamp(0.5, 1.0)
+            },
+        }
+    }
+
+    /// Returns the number of consecutive statements in a window.
+    #[must_use]
+    pub fn min_statements(&self) -> usize {
+        self.min_statements
+    }
+
+    /// Returns the minimum number of tokens in a reported window.
+    #[must_use]
+    pub fn min_tokens(&self) -> usize {
+        self.min_tokens
+    }
+
+    /// Returns the minimum token similarity between duplicate windows.
+    #[must_use]
+    pub fn similarity(&self) -> f64 {
+        self.similarity
+    }
+}
+
+impl Default for DuplicationThresholds {
+    fn default() -> Self {
+        Self::new(4, 30, 0.9)
+    }
+}
+
+/// A run of consecutive statements within one block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StatementRange {
+    /// Index of the block in the input slice.
+    pub block: usize,
+    /// Index of the first statement in the block.
+    pub start: usize,
+    /// Number of statements in the run.
+    pub len: usize,
+}
+
+/// Two near-identical statement runs in different functions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DuplicatedBlock {
+    /// The run in the function that appears first in the input.
+    pub original: StatementRange,
+    /// The run that repeats it.
+    pub duplicate: StatementRange,
+    /// Token similarity between the runs, from `0.0` to `1.0`.
+    pub similarity: f64,
+}
+
+impl DuplicatedBlock {
+    /// Returns the similarity as a whole percentage, rounded down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::duplicated_blocks::{DuplicatedBlock, StatementRange};
+    ///
+    /// let range = StatementRange { block: 0, start: 0, len: 4 };
+    /// let duplicate = DuplicatedBlock {
+    ///     original: range,
+    ///     duplicate: StatementRange { block: 1, ..range },
+    ///     similarity: 0.937,
+    /// };
+    /// assert_eq!(duplicate.similarity_percent(), 93);
+    /// ```
+    #[must_use]
+    pub fn similarity_percent(&self) -> u8 {
+        (self.similarity.clamp(0.0, 1.0) * 100.0).floor() as u8
+    }
+}
+
+/// Finds near-identical statement runs shared by pairs of functions.
+///
+/// Results are ordered by the earlier function's run, then the later one.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::duplicated_blocks::{
+///     DuplicationThresholds, NormalizedBlock, NormalizedStatement, find_duplicated_blocks,
+/// };
+///
+/// let body = || vec![
+///     NormalizedStatement::new(["stmt:let", "expr:call", "path:load", "local"]),
+///     NormalizedStatement::new(["stmt:semi", "expr:method", "method:validate", "local"]),
+///     NormalizedStatement::new(["stmt:semi", "expr:method", "method:save", "local"]),
+/// ];
+/// let blocks = [NormalizedBlock::new(0, body()), NormalizedBlock::new(1, body())];
+/// let thresholds = DuplicationThresholds::new(3, 10, 0.9);
+///
+/// let duplicates = find_duplicated_blocks(&blocks, thresholds);
+/// assert_eq!(duplicates.len(), 1);
+/// assert_eq!(duplicates[0].duplicate.block, 1);
+/// assert_eq!(duplicates[0].duplicate.len, 3);
+/// ```
+#[must_use]
+pub fn find_duplicated_blocks(
+    blocks: &[NormalizedBlock],
+    thresholds: DuplicationThresholds,
+) -> Vec<DuplicatedBlock> {
+    let mut best: BTreeMap<(usize, usize), (StatementRange, StatementRange)> = BTreeMap::new();
+    let buckets = bucket_windows(blocks, thresholds);
+    let pairs = buckets.values().flat_map(|bucket| {
+        bucket.iter().enumerate().flat_map(|(position, &left)| {
+            bucket[position + 1..]
+                .iter()
+                .map(move |&right| (left, right))
+        })
+    });
+
+    for (left, right) in pairs {
+        let Some(owners) = ordered_owners(blocks, left, right) else {
+            continue;
+        };
+        let candidate = order_ranges(blocks, left, right);
+        // Only an earlier window can replace the match already recorded for
+        // this pair of functions, so later ones skip the comparison.
+        if best.get(&owners).is_some_and(|&seen| seen <= candidate) {
+            continue;
+        }
+        if window_similarity(blocks, candidate.0, candidate.1) >= thresholds.similarity {
+            best.insert(owners, candidate);
+        }
+    }
+
+    let mut duplicates: Vec<DuplicatedBlock> = best
+        .into_values()
+        .map(|(original, duplicate)| extend_match(blocks, original, duplicate, thresholds))
+        .collect();
+    duplicates.sort_by(|left, right| {
+        (left.original, left.duplicate).cmp(&(right.original, right.duplicate))
+    });
+    duplicates
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+//! Token-sequence similarity for duplicate block detection.
+
+use super::BlockToken;
+
+/// Returns the Dice coefficient of the longest common subsequence of two
+/// token sequences: `2 * lcs / (left.len() + right.len())`.
+///
+/// Identical sequences score `1.0`; sequences with nothing in common, or two
+/// empty sequences, score `0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::duplicated_blocks::{BlockToken, token_similarity};
+///
+/// let left: Vec<BlockToken> = ["let", "call", "lit"].into_iter().map(BlockToken::from).collect();
+/// let right: Vec<BlockToken> = ["let", "call", "path"].into_iter().map(BlockToken::from).collect();
+///
+/// assert!((token_similarity(&left, &left) - 1.0).abs() < f64::EPSILON);
+/// assert!((token_similarity(&left, &right) - 2.0 / 3.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn token_similarity(left: &[BlockToken], right: &[BlockToken]) -> f64 {
+    let total = left.len() + right.len();
+    if total == 0 {
+        return 0.0;
+    }
+    if left == right {
+        return 1.0;
+    }
+    (2 * longest_common_subsequence(left, right)) as f64 / total as f64
+}
+
+/// Computes the LCS length with a single rolling row.
+fn longest_common_subsequence(left: &[BlockToken], right: &[BlockToken]) -> usize {
+    let mut row = vec![0_usize; right.len() + 1];
+    for token in left {
+        let mut diagonal = 0;
+        for (column, other) in right.iter().enumerate() {
+            let above = row[column + 1];
+            row[column + 1] = if token == other {
+                diagonal + 1
+            } else {
+                above.max(row[column])
+            };
+            diagonal = above;
+        }
+    }
+    row[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn tokens(text: &str) -> Vec<BlockToken> {
+        text.split_whitespace().map(BlockToken::from).collect()
+    }
+
+    #[rstest]
+    #[case::identical("a b c", "a b c", 3)]
+    #[case::disjoint("a b", "c d", 0)]
+    #[case::gap("a b c d", "a c d", 3)]
+    #[case::reordered("a b c", "c b a", 1)]
+    #[case::empty("", "a b", 0)]
+    fn lcs_counts_shared_order(#[case] left: &str, #[case] right: &str, #[case] expected: usize) {
+        assert_eq!(
+            longest_common_subsequence(&tokens(left), &tokens(right)),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn empty_sequences_are_not_similar() {
+        assert!(token_similarity(&[], &[]).abs() < f64::EPSILON);
+    }
+}
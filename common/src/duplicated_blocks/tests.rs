@@ -0,0 +1,133 @@
+//! rstest-based unit tests for [`super::find_duplicated_blocks`].
+
+use super::*;
+use rstest::rstest;
+
+/// Builds a statement from space-separated token texts.
+fn statement(tokens: &str) -> NormalizedStatement {
+    NormalizedStatement::new(tokens.split_whitespace())
+}
+
+fn block(owner: usize, statements: &[&str]) -> NormalizedBlock {
+    NormalizedBlock::new(owner, statements.iter().copied().map(statement).collect())
+}
+
+const BODY: &[&str] = &[
+    "stmt:let expr:call path:open local lit",
+    "stmt:semi expr:method method:read local local",
+    "stmt:semi expr:method method:validate local",
+    "stmt:tail expr:method method:finish local",
+];
+
+fn thresholds() -> DuplicationThresholds {
+    DuplicationThresholds::new(3, 10, 0.9)
+}
+
+#[rstest]
+fn reports_identical_bodies_once() {
+    let blocks = [block(0, BODY), block(1, BODY)];
+
+    let duplicates = find_duplicated_blocks(&blocks, thresholds());
+
+    assert_eq!(duplicates.len(), 1);
+    let duplicate = duplicates[0];
+    assert_eq!(
+        duplicate.original,
+        StatementRange {
+            block: 0,
+            start: 0,
+            len: 4
+        }
+    );
+    assert_eq!(duplicate.duplicate.block, 1);
+    assert!((duplicate.similarity - 1.0).abs() < f64::EPSILON);
+}
+
+#[rstest]
+fn ignores_repetition_within_one_function() {
+    let blocks = [block(0, BODY), block(0, BODY)];
+
+    assert!(find_duplicated_blocks(&blocks, thresholds()).is_empty());
+}
+
+#[rstest]
+fn reports_each_pair_of_functions() {
+    let blocks = [block(0, BODY), block(1, BODY), block(2, BODY)];
+
+    let pairs: Vec<(usize, usize)> = find_duplicated_blocks(&blocks, thresholds())
+        .iter()
+        .map(|duplicate| (duplicate.original.block, duplicate.duplicate.block))
+        .collect();
+
+    assert_eq!(pairs, [(0, 1), (0, 2), (1, 2)]);
+}
+
+#[rstest]
+fn finds_shared_run_at_different_offsets() {
+    let mut shifted = vec!["stmt:semi expr:call path:log lit"];
+    shifted.extend_from_slice(BODY);
+    let blocks = [block(0, BODY), block(1, &shifted)];
+
+    let duplicates = find_duplicated_blocks(&blocks, thresholds());
+
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].duplicate.start, 1);
+    assert_eq!(duplicates[0].duplicate.len, 4);
+}
+
+#[rstest]
+#[case::near_identical(0.9, 1)]
+#[case::exact_only(1.0, 0)]
+fn similarity_threshold_tolerates_small_edits(#[case] similarity: f64, #[case] expected: usize) {
+    let mut edited = BODY.to_vec();
+    edited[1] = "stmt:semi expr:method method:read local lit";
+    let blocks = [block(0, BODY), block(1, &edited)];
+    let thresholds = DuplicationThresholds::new(4, 10, similarity);
+
+    assert_eq!(find_duplicated_blocks(&blocks, thresholds).len(), expected);
+}
+
+#[rstest]
+#[case::short_enough(3, 10, 1)]
+#[case::too_many_statements(5, 10, 0)]
+#[case::too_few_tokens(3, 50, 0)]
+fn size_thresholds_gate_windows(
+    #[case] min_statements: usize,
+    #[case] min_tokens: usize,
+    #[case] expected: usize,
+) {
+    let blocks = [block(0, BODY), block(1, BODY)];
+    let thresholds = DuplicationThresholds::new(min_statements, min_tokens, 0.9);
+
+    assert_eq!(find_duplicated_blocks(&blocks, thresholds).len(), expected);
+}
+
+#[rstest]
+fn different_statement_shapes_do_not_match() {
+    let other = [
+        "stmt:semi expr:call path:open local lit",
+        "stmt:let expr:method method:read local local",
+        "stmt:semi expr:method method:validate local",
+        "stmt:tail expr:method method:finish local",
+    ];
+    let blocks = [block(0, BODY), block(1, &other)];
+
+    assert!(find_duplicated_blocks(&blocks, DuplicationThresholds::new(4, 10, 0.5)).is_empty());
+}
+
+#[rstest]
+#[case::zero_statements(0, 0.9, 1, 0.9)]
+#[case::low_similarity(3, 0.1, 3, 0.5)]
+#[case::high_similarity(3, 2.0, 3, 1.0)]
+#[case::not_a_number(3, f64::NAN, 3, 1.0)]
+fn thresholds_are_clamped(
+    #[case] min_statements: usize,
+    #[case] similarity: f64,
+    #[case] expected_statements: usize,
+    #[case] expected_similarity: f64,
+) {
+    let thresholds = DuplicationThresholds::new(min_statements, 0, similarity);
+
+    assert_eq!(thresholds.min_statements(), expected_statements);
+    assert!((thresholds.similarity() - expected_similarity).abs() < f64::EPSILON);
+}
@@ -0,0 +1,121 @@
+//! Window enumeration, comparison, and extension for duplicate detection.
+
+use std::collections::BTreeMap;
+
+use super::{
+    BlockToken, DuplicatedBlock, DuplicationThresholds, NormalizedBlock, NormalizedStatement,
+    StatementRange, token_similarity,
+};
+
+/// Groups eligible windows by the skeleton of their statements.
+pub(super) fn bucket_windows(
+    blocks: &[NormalizedBlock],
+    thresholds: DuplicationThresholds,
+) -> BTreeMap<Vec<BlockToken>, Vec<StatementRange>> {
+    let len = thresholds.min_statements;
+    let mut buckets: BTreeMap<Vec<BlockToken>, Vec<StatementRange>> = BTreeMap::new();
+    for (index, block) in blocks.iter().enumerate() {
+        let statements = block.statements();
+        for start in 0..=statements.len().saturating_sub(len) {
+            let Some(window) = statements.get(start..start + len) else {
+                continue;
+            };
+            let tokens: usize = window.iter().map(NormalizedStatement::len).sum();
+            if tokens < thresholds.min_tokens {
+                continue;
+            }
+            let key = window
+                .iter()
+                .flat_map(|statement| statement.skeleton().iter().copied())
+                .collect();
+            buckets.entry(key).or_default().push(StatementRange {
+                block: index,
+                start,
+                len,
+            });
+        }
+    }
+    buckets
+}
+
+/// Returns the owning function indices in ascending order, or `None` when
+/// both windows belong to the same function.
+pub(super) fn ordered_owners(
+    blocks: &[NormalizedBlock],
+    left: StatementRange,
+    right: StatementRange,
+) -> Option<(usize, usize)> {
+    let left_owner = blocks[left.block].owner;
+    let right_owner = blocks[right.block].owner;
+    (left_owner != right_owner).then(|| (left_owner.min(right_owner), left_owner.max(right_owner)))
+}
+
+pub(super) fn order_ranges(
+    blocks: &[NormalizedBlock],
+    left: StatementRange,
+    right: StatementRange,
+) -> (StatementRange, StatementRange) {
+    if blocks[left.block].owner < blocks[right.block].owner {
+        (left, right)
+    } else {
+        (right, left)
+    }
+}
+
+fn window_tokens(blocks: &[NormalizedBlock], range: StatementRange) -> Vec<BlockToken> {
+    blocks[range.block].statements[range.start..range.start + range.len]
+        .iter()
+        .flat_map(|statement| statement.tokens.iter().copied())
+        .collect()
+}
+
+pub(super) fn window_similarity(
+    blocks: &[NormalizedBlock],
+    original: StatementRange,
+    duplicate: StatementRange,
+) -> f64 {
+    token_similarity(
+        &window_tokens(blocks, original),
+        &window_tokens(blocks, duplicate),
+    )
+}
+
+/// Grows a matched pair of windows while the following statements still
+/// keep the runs similar enough.
+pub(super) fn extend_match(
+    blocks: &[NormalizedBlock],
+    mut original: StatementRange,
+    mut duplicate: StatementRange,
+    thresholds: DuplicationThresholds,
+) -> DuplicatedBlock {
+    let mut similarity = window_similarity(blocks, original, duplicate);
+    loop {
+        let next_original = StatementRange {
+            len: original.len + 1,
+            ..original
+        };
+        let next_duplicate = StatementRange {
+            len: duplicate.len + 1,
+            ..duplicate
+        };
+        let fits = next_original.start + next_original.len
+            <= blocks[original.block].statements.len()
+            && next_duplicate.start + next_duplicate.len
+                <= blocks[duplicate.block].statements.len();
+        if !fits {
+            break;
+        }
+        let next_similarity = window_similarity(blocks, next_original, next_duplicate);
+        if next_similarity < thresholds.similarity {
+            break;
+        }
+        original = next_original;
+        duplicate = next_duplicate;
+        similarity = next_similarity;
+    }
+    DuplicatedBlock {
+        original,
+        duplicate,
+        similarity,
+    }
+}
@@ -0,0 +1,316 @@
+//! Text rules for removing an `else` after an `if` branch that always exits.
+//!
+//! `no_else_after_return` and `redundant_else_block_after_loop_control` both
+//! rewrite `if c { ...; exit } else { body }` into a guard clause followed by
+//! the dedented body. Their drivers hand the surrounding source text to these
+//! helpers: [`else_keyword`] locates the `else` between the two blocks,
+//! [`reindent_block_body`] rewrites the `else` body at the `if`'s
+//! indentation, and [`ElseRemoval`] decides whether the rewrite can be
+//! applied without review. Source is tokenised with `rustc_lexer`, so the
+//! contents of string literals and comments are never mistaken for code or
+//! re-indented.
+
+use rustc_lexer::{TokenKind, tokenize};
+
+/// How an `if` branch leaves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExitKind {
+    /// A `return` expression.
+    Return,
+    /// A `continue` expression.
+    Continue,
+    /// A `break` expression.
+    Break,
+}
+
+impl ExitKind {
+    /// Returns the keyword diagnostics name.
+    #[must_use]
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Return => "return",
+            Self::Continue => "continue",
+            Self::Break => "break",
+        }
+    }
+
+    /// Returns `true` for `continue` and `break`, which leave the current
+    /// loop iteration rather than the function.
+    #[must_use]
+    pub const fn is_loop_control(self) -> bool {
+        matches!(self, Self::Continue | Self::Break)
+    }
+}
+
+/// What surrounds an `else` whose body is about to be moved out.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ElseRemoval {
+    /// Statements or a trailing expression follow the `if` in its block.
+    pub code_follows: bool,
+    /// The `else` body declares `let` bindings.
+    pub declares_bindings: bool,
+    /// Comments sit between the `if` block and the `else` body.
+    pub comments_before_else: bool,
+}
+
+impl ElseRemoval {
+    /// Returns `true` when the rewrite preserves the program and its
+    /// comments.
+    ///
+    /// Bindings moved out of the `else` stay alive until the end of the
+    /// enclosing block, where they could shadow later names or change when
+    /// values are dropped, so they are only safe when nothing follows the
+    /// `if`. Comments between the blocks would be lost by the rewrite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::else_removal::ElseRemoval;
+    ///
+    /// let removal = ElseRemoval {
+    ///     code_follows: true,
+    ///     declares_bindings: true,
+    ///     ..ElseRemoval::default()
+    /// };
+    /// assert!(!removal.is_machine_applicable());
+    /// assert!(ElseRemoval::default().is_machine_applicable());
+    /// ```
+    #[must_use]
+    pub const fn is_machine_applicable(self) -> bool {
+        !self.comments_before_else && !(self.code_follows && self.declares_bindings)
+    }
+}
+
+/// The `else` keyword found between an `if` block and its `else` body.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ElseKeyword {
+    /// Byte offset of `else` within the searched text.
+    pub offset: usize,
+    /// `true` when only whitespace surrounds the keyword.
+    pub bare: bool,
+}
+
+/// Finds the `else` keyword in the text between an `if` block's closing
+/// brace and its `else` body's opening brace.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::else_removal::else_keyword;
+///
+/// let keyword = else_keyword(" // fallback\n else ").expect("`else` is present");
+/// assert_eq!(keyword.offset, 14);
+/// assert!(!keyword.bare);
+/// ```
+#[must_use]
+pub fn else_keyword(between: &str) -> Option<ElseKeyword> {
+    let mut offset = 0;
+    let mut keyword = None;
+    let mut bare = true;
+    for token in tokenize(between) {
+        let end = offset + token.len;
+        match token.kind {
+            TokenKind::Ident if &between[offset..end] == "else" && keyword.is_none() => {
+                keyword = Some(offset);
+            }
+            TokenKind::Whitespace => {}
+            _ => bare = false,
+        }
+        offset = end;
+    }
+    keyword.map(|offset| ElseKeyword { offset, bare })
+}
+
+/// Rewrites the text inside an `else` body's braces so each line starts at
+/// `indent`, preserving the relative indentation of nested lines.
+///
+/// Blank lines at either end and trailing whitespace are dropped, and lines
+/// that continue a multi-line string literal or block comment are kept
+/// verbatim.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::else_removal::reindent_block_body;
+///
+/// let body = "\n        let value = load();\n        value + 1\n    ";
+/// assert_eq!(
+///     reindent_block_body(body, "    "),
+///     "    let value = load();\n    value + 1"
+/// );
+/// ```
+#[must_use]
+pub fn reindent_block_body(body: &str, indent: &str) -> String {
+    let verbatim = verbatim_ranges(body);
+    let lines = source_lines(body, &verbatim);
+    let first = lines.iter().position(|line| !line.is_blank());
+    let last = lines.iter().rposition(|line| !line.is_blank());
+    let (Some(first), Some(last)) = (first, last) else {
+        return String::new();
+    };
+    let lines = &lines[first..=last];
+
+    // A line sharing the opening brace has no indentation of its own, so it
+    // does not set the common margin.
+    let margin = lines
+        .iter()
+        .filter(|line| line.start > 0 && !line.verbatim && !line.is_blank())
+        .map(|line| line.indentation())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| {
+            if line.verbatim {
+                line.text.to_owned()
+            } else if line.is_blank() {
+                String::new()
+            } else {
+                let strip = if line.start == 0 {
+                    line.indentation()
+                } else {
+                    margin.min(line.indentation())
+                };
+                let text = &line.text[strip..];
+                let text = if line.open { text } else { text.trim_end() };
+                format!("{indent}{text}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// One line of a block body, whether it must be kept verbatim, and whether
+/// a verbatim token is still open at its end.
+struct SourceLine<'a> {
+    start: usize,
+    text: &'a str,
+    verbatim: bool,
+    open: bool,
+}
+
+impl SourceLine<'_> {
+    fn is_blank(&self) -> bool {
+        !self.verbatim && self.text.trim().is_empty()
+    }
+
+    fn indentation(&self) -> usize {
+        self.text.len() - self.text.trim_start_matches([' ', '\t']).len()
+    }
+}
+
+/// Splits `body` into lines, marking those that start inside a verbatim
+/// range.
+fn source_lines<'a>(body: &'a str, verbatim: &[(usize, usize)]) -> Vec<SourceLine<'a>> {
+    let mut start = 0;
+    body.split('\n')
+        .map(|text| {
+            let end = start + text.len();
+            let inside = |offset: usize| {
+                verbatim
+                    .iter()
+                    .any(|&(from, to)| from < offset && offset < to)
+            };
+            let line = SourceLine {
+                start,
+                text: text.strip_suffix('\r').unwrap_or(text),
+                verbatim: inside(start),
+                open: inside(end),
+            };
+            start += text.len() + 1;
+            line
+        })
+        .collect()
+}
+
+/// Returns the byte ranges of tokens other than whitespace that span lines,
+/// such as multi-line string literals and block comments.
+fn verbatim_ranges(body: &str) -> Vec<(usize, usize)> {
+    let mut offset = 0;
+    let mut ranges = Vec::new();
+    for token in tokenize(body) {
+        let end = offset + token.len;
+        if token.kind != TokenKind::Whitespace && body[offset..end].contains('\n') {
+            ranges.push((offset, end));
+        }
+        offset = end;
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(ExitKind::Return, "return", false)]
+    #[case(ExitKind::Continue, "continue", true)]
+    #[case(ExitKind::Break, "break", true)]
+    fn exit_kinds_name_their_keyword(
+        #[case] exit: ExitKind,
+        #[case] keyword: &str,
+        #[case] loop_control: bool,
+    ) {
+        assert_eq!(exit.keyword(), keyword);
+        assert_eq!(exit.is_loop_control(), loop_control);
+    }
+
+    #[rstest]
+    #[case(false, false, false, true)]
+    #[case(true, false, false, true)]
+    #[case(false, true, false, true)]
+    #[case(true, true, false, false)]
+    #[case(false, false, true, false)]
+    fn applicability_guards_bindings_and_comments(
+        #[case] code_follows: bool,
+        #[case] declares_bindings: bool,
+        #[case] comments_before_else: bool,
+        #[case] expected: bool,
+    ) {
+        let removal = ElseRemoval {
+            code_follows,
+            declares_bindings,
+            comments_before_else,
+        };
+
+        assert_eq!(removal.is_machine_applicable(), expected);
+    }
+
+    #[rstest]
+    #[case(" else ", Some(ElseKeyword { offset: 1, bare: true }))]
+    #[case("\n    else ", Some(ElseKeyword { offset: 5, bare: true }))]
+    #[case(" // or else\n else ", Some(ElseKeyword { offset: 13, bare: false }))]
+    #[case(" ", None)]
+    fn else_keyword_skips_comments(#[case] between: &str, #[case] expected: Option<ElseKeyword>) {
+        assert_eq!(else_keyword(between), expected);
+    }
+
+    #[rstest]
+    #[case::nested(
+        "\n        let value = load();\n        if value > 1 {\n            run();\n        }\n    ",
+        "    ",
+        "    let value = load();\n    if value > 1 {\n        run();\n    }"
+    )]
+    #[case::single_line(" value + 1 ", "        ", "        value + 1")]
+    #[case::blank_lines_inside("\n\n    a();\n\n    b();\n\n", "", "a();\n\nb();")]
+    #[case::empty("\n    ", "    ", "")]
+    fn reindent_moves_lines_to_the_new_margin(
+        #[case] body: &str,
+        #[case] indent: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(reindent_block_body(body, indent), expected);
+    }
+
+    #[rstest]
+    fn reindent_keeps_multi_line_literals_verbatim() {
+        let body = "\n        let text = \"first\n            second\";\n        /* note\n           more */\n        text\n    ";
+
+        assert_eq!(
+            reindent_block_body(body, "    "),
+            "    let text = \"first\n            second\";\n    /* note\n           more */\n    text"
+        );
+    }
+}
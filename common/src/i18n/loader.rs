@@ -147,6 +147,13 @@ impl Localizer {
     }
 }
 
+impl Default for Localizer {
+    /// Returns the fallback localizer, as [`Localizer::new`] does for `None`.
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
 fn promote_arguments(
     arguments: &Arguments<'_>,
 ) -> HashMap<Cow<'static, str>, FluentValue<'static>> {
@@ -1,24 +1,32 @@
 //! Shared lint infrastructure providing attribute helpers, context tracking,
-//! path, expression, span, diagnostic, cohesion analysis, and brain
-//! type/trait metric collection utilities for Whitaker lints.
+//! per-crate pass state, path, expression, span, diagnostic, cohesion
+//! analysis, data clump and duplicated block detection, `else` removal, lint
+//! documentation, and brain type/trait metric collection utilities for
+//! Whitaker lints.
 
 pub mod attributes;
 pub mod brain_trait_metrics;
 pub mod brain_type_metrics;
 pub mod complexity_signal;
 pub mod context;
+pub mod crate_state;
+pub mod data_clumps;
 pub mod decomposition_advice;
 pub mod diagnostics;
+pub mod duplicated_blocks;
+pub mod else_removal;
 pub mod expr;
 pub mod i18n;
 pub mod lcom4;
+pub mod lint_docs;
 pub mod path;
 pub mod rstest;
 pub mod span;
 pub mod test_support;
 
 pub use attributes::{
-    Attribute, AttributeKind, AttributePath, PARSED_ATTRIBUTE_PLACEHOLDER, has_test_like_attribute,
+    Attribute, AttributeArgument, AttributeArguments, AttributeKind, AttributeLiteral,
+    AttributePath, PARSED_ATTRIBUTE_PLACEHOLDER, has_test_like_attribute,
     has_test_like_attribute_with, outer_attributes, split_doc_attributes,
 };
 pub use brain_trait_metrics::evaluation::{
@@ -38,14 +46,22 @@ pub use brain_type_metrics::{
     TypeMetricsBuilder, brain_methods, foreign_reach_count, weighted_methods_count,
 };
 pub use context::{
-    ContextEntry, ContextKind, in_test_like_context, in_test_like_context_with, is_in_main_fn,
-    is_test_fn, is_test_fn_with,
+    BlamedHelper, ContextCache, ContextEntry, ContextKind, ContextPrefix, DoctestSignals,
+    EnclosingItem, EnclosingItemKind, in_test_like_context, in_test_like_context_with,
+    is_in_main_fn, is_test_fn, is_test_fn_with,
 };
+pub use crate_state::CrateScopedState;
+pub use data_clumps::{DataClump, DataClumpThresholds, find_data_clumps};
 pub use decomposition_advice::{
     DecompositionContext, DecompositionSuggestion, MethodProfile, MethodProfileBuilder,
     SubjectKind, SuggestedExtractionKind, format_diagnostic_note, suggest_decomposition,
 };
-pub use diagnostics::{Applicability, Diagnostic, DiagnosticBuilder, Suggestion, span_lint};
+pub use diagnostics::{
+    Applicability, Diagnostic, DiagnosticBuilder, FindingBudget, Fingerprint, FingerprintSource,
+    Suggestion, SuppressedFindings, span_lint,
+};
+pub use duplicated_blocks::{DuplicatedBlock, DuplicationThresholds, find_duplicated_blocks};
+pub use else_removal::{ElseKeyword, ElseRemoval, ExitKind, else_keyword, reindent_block_body};
 pub use expr::{Expr, def_id_of_expr_callee, is_path_to, recv_is_option_or_result};
 pub use i18n::{
     Arguments, FALLBACK_LOCALE, I18nError, LocaleSelection, LocaleSource, Localizer,
@@ -53,7 +69,8 @@ pub use i18n::{
     normalise_locale, resolve_localizer, safe_resolve_message_set, supports_locale,
 };
 pub use lcom4::{MethodInfo, MethodInfoBuilder, cohesion_components, collect_method_infos};
-pub use path::SimplePath;
+pub use lint_docs::{ConfigKeyDocs, LintDocs};
+pub use path::{AllowedModules, ModuleGlobs, SimplePath, module_override};
 pub use rstest::{
     ArgAtom, ArgFingerprint, CalleeShape, ExpansionTrace, ExprShape, LocalSlot,
     ParagraphFingerprint, ParagraphNormalizer, ParameterBinding, RstestDetectionOptions,
@@ -61,4 +78,6 @@ pub use rstest::{
     classify_rstest_parameter, fixture_local_names, is_rstest_fixture, is_rstest_fixture_with,
     is_rstest_test, is_rstest_test_with, recover_user_editable_span,
 };
-pub use span::{SourceLocation, SourceSpan, SpanError, span_line_count, span_to_lines};
+pub use span::{
+    LineKind, SourceLocation, SourceSpan, SpanError, classify_lines, span_line_count, span_to_lines,
+};
@@ -0,0 +1,163 @@
+//! Per-lint documentation metadata embedded in lint crates.
+//!
+//! Each lint crate exports a `LINT_DOCS` constant describing what the lint
+//! flags, why, how to fix it, and which `dylint.toml` keys tune it. The suite
+//! collects these constants in `SUITE_LINT_DOCS`, and the installer's lint
+//! listing and the HTML report accept that slice, so both render the same
+//! guidance, versioned with the lint that produced it, instead of relying on a
+//! separately maintained wiki.
+
+/// Documentation for a single configuration key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfigKeyDocs {
+    /// Key name under the lint's `dylint.toml` table.
+    pub key: &'static str,
+    /// Default value, written as TOML.
+    pub default: &'static str,
+    /// One-sentence description of the key's effect.
+    pub description: &'static str,
+}
+
+/// Documentation describing a lint, sourced from the lint crate itself.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+///
+/// const DOCS: LintDocs = LintDocs {
+///     name: "closure_max_lines",
+///     version: "0.2.7",
+///     summary: "Closures should stay within a line budget.",
+///     rationale: "Long closures hide logic that deserves a name.",
+///     bad_example: "let f = |x| { /* 40 lines */ };",
+///     good_example: "let f = summarise;",
+///     config: &[ConfigKeyDocs {
+///         key: "max_lines",
+///         default: "20",
+///         description: "Maximum number of lines in a closure body.",
+///     }],
+/// };
+///
+/// assert_eq!(DOCS.config_key("max_lines").map(|key| key.default), Some("20"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LintDocs {
+    /// Canonical lint name.
+    pub name: &'static str,
+    /// Version of the crate defining the lint; the guidance applies to it.
+    pub version: &'static str,
+    /// One-line description of what the lint flags.
+    pub summary: &'static str,
+    /// Why the flagged pattern is a problem.
+    pub rationale: &'static str,
+    /// Rust code the lint reports.
+    pub bad_example: &'static str,
+    /// Rust code showing the preferred fix.
+    pub good_example: &'static str,
+    /// Configuration keys read from the lint's `dylint.toml` table.
+    pub config: &'static [ConfigKeyDocs],
+}
+
+impl LintDocs {
+    /// Looks up the documentation for configuration key `key`.
+    #[must_use]
+    pub fn config_key(&self, key: &str) -> Option<&'static ConfigKeyDocs> {
+        self.config.iter().find(|entry| entry.key == key)
+    }
+
+    /// Renders the documentation as Markdown.
+    ///
+    /// The layout is shared by every consumer: a level-two heading with the
+    /// lint name and version, the summary and rationale, fenced bad and good
+    /// examples, and a `dylint.toml` snippet listing each key at its default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::lint_docs::LintDocs;
+    ///
+    /// let docs = LintDocs {
+    ///     name: "no_static_mut",
+    ///     version: "0.2.7",
+    ///     summary: "Flags `static mut` items.",
+    ///     rationale: "Every access is unsafe.",
+    ///     bad_example: "static mut COUNT: u32 = 0;",
+    ///     good_example: "static COUNT: AtomicU32 = AtomicU32::new(0);",
+    ///     config: &[],
+    /// };
+    /// let markdown = docs.to_markdown();
+    ///
+    /// assert!(markdown.starts_with("## `no_static_mut` (0.2.7)\n"));
+    /// assert!(!markdown.contains("dylint.toml"));
+    /// ```
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut sections = vec![
+            format!("## `{}` ({})\n\n", self.name, self.version),
+            format!("{}\n\n{}\n\n", self.summary, self.rationale),
+            format!("Flagged:\n\n```rust\n{}\n```\n\n", self.bad_example),
+            format!("Preferred:\n\n```rust\n{}\n```\n", self.good_example),
+        ];
+        if !self.config.is_empty() {
+            sections.push(format!(
+                "\nConfiguration in `dylint.toml`:\n\n```toml\n[{}]\n",
+                self.name
+            ));
+            sections.extend(self.config.iter().map(|entry| {
+                format!(
+                    "# {}\n{} = {}\n",
+                    entry.description, entry.key, entry.default
+                )
+            }));
+            sections.push(String::from("```\n"));
+        }
+        sections.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const DOCS: LintDocs = LintDocs {
+        name: "method_chain_max_length",
+        version: "1.0.0",
+        summary: "Flags long method chains.",
+        rationale: "Long chains hide abstractions.",
+        bad_example: "a.b().c().d()",
+        good_example: "let b = a.b();",
+        config: &[
+            ConfigKeyDocs {
+                key: "max_calls",
+                default: "6",
+                description: "Maximum calls per chain.",
+            },
+            ConfigKeyDocs {
+                key: "builder_types",
+                default: "[]",
+                description: "Types exempt from the limit.",
+            },
+        ],
+    };
+
+    #[rstest]
+    #[case::known("builder_types", Some("[]"))]
+    #[case::unknown("max_lines", None)]
+    fn config_key_lookup(#[case] key: &str, #[case] expected: Option<&str>) {
+        assert_eq!(DOCS.config_key(key).map(|entry| entry.default), expected);
+    }
+
+    #[rstest]
+    fn markdown_lists_config_keys_in_order() {
+        let markdown = DOCS.to_markdown();
+
+        assert!(
+            markdown
+                .contains("[method_chain_max_length]\n# Maximum calls per chain.\nmax_calls = 6\n")
+        );
+        assert!(markdown.ends_with("builder_types = []\n```\n"));
+        assert!(markdown.contains("```rust\na.b().c().d()\n```"));
+    }
+}
@@ -1,7 +1,12 @@
-//! Shared helpers for working with `::`-delimited paths.
+//! Shared helpers for working with `::`-delimited paths, including the module
+//! globs and per-module overrides lints accept in their configuration.
 
+use std::collections::BTreeMap;
 use std::fmt;
 
+use glob::Pattern;
+use log::debug;
+
 /// Represents a syntactic path composed of `::`-separated segments.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SimplePath {
@@ -103,6 +108,153 @@ impl fmt::Display for SimplePath {
     }
 }
 
+/// Module path globs read from a lint's configuration.
+///
+/// A glob matches a module when it matches the module's path relative to the
+/// crate root, or the path of any enclosing module, so `billing` matches
+/// `billing::invoice` as well.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::path::ModuleGlobs;
+///
+/// let modules = ModuleGlobs::new("demo_lint", "modules", &[String::from("billing")]);
+/// assert!(modules.matches("billing::invoice"));
+/// assert!(!modules.matches("billing_export"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ModuleGlobs {
+    patterns: Vec<Pattern>,
+}
+
+impl ModuleGlobs {
+    /// Compiles `globs`, read from the configuration key `key`, skipping any
+    /// that are not valid patterns.
+    ///
+    /// Skipped globs are logged at debug level under `target`, which callers
+    /// set to their lint name.
+    #[must_use]
+    pub fn new(target: &str, key: &str, globs: &[String]) -> Self {
+        let patterns = globs
+            .iter()
+            .filter_map(|glob| match Pattern::new(glob) {
+                Ok(pattern) => Some(pattern),
+                Err(error) => {
+                    debug!(target: target, "ignoring invalid `{key}` glob `{glob}`: {error}");
+                    None
+                }
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    /// Returns `true` when no glob was configured, or none compiled.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Returns `true` when `module_path` or an enclosing module matches.
+    ///
+    /// `module_path` names the module relative to the crate root, with
+    /// segments separated by `::`; the crate root itself is the empty path.
+    #[must_use]
+    pub fn matches(&self, module_path: &str) -> bool {
+        enclosing_paths(module_path)
+            .any(|path| self.patterns.iter().any(|pattern| pattern.matches(path)))
+    }
+}
+
+/// Module path globs under which a lint accepts code it would otherwise
+/// report, read from its `allowed_modules` key.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::path::AllowedModules;
+///
+/// let allowed = AllowedModules::new("demo_lint", &[String::from("metrics")]);
+/// assert!(allowed.allows("metrics::registry"));
+/// assert!(!allowed.allows("metrics_export"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AllowedModules {
+    globs: ModuleGlobs,
+}
+
+impl AllowedModules {
+    /// Compiles `globs`, skipping any that are not valid patterns.
+    ///
+    /// Skipped globs are logged at debug level under `target`, which callers
+    /// set to their lint name.
+    #[must_use]
+    pub fn new(target: &str, globs: &[String]) -> Self {
+        Self {
+            globs: ModuleGlobs::new(target, "allowed_modules", globs),
+        }
+    }
+
+    /// Returns `true` when `module_path` or an enclosing module is allowed.
+    ///
+    /// `module_path` names the module relative to the crate root, with
+    /// segments separated by `::`; the crate root itself is the empty path.
+    #[must_use]
+    pub fn allows(&self, module_path: &str) -> bool {
+        self.globs.matches(module_path)
+    }
+}
+
+/// Returns the override for the module at `module_path`.
+///
+/// Each key of `overrides` names a module, relative to the crate root with an
+/// optional leading `crate::`, and applies to that module and every module
+/// nested inside it. When several keys match, the one naming the deepest
+/// module wins. Returns `None` when no key matches.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use whitaker_common::path::module_override;
+///
+/// let overrides = BTreeMap::from([(String::from("crate::generated"), 2000)]);
+/// assert_eq!(module_override(&overrides, "generated::schema"), Some(2000));
+/// assert_eq!(module_override(&overrides, "handlers"), None);
+/// ```
+#[must_use]
+pub fn module_override<T: Copy>(overrides: &BTreeMap<String, T>, module_path: &str) -> Option<T> {
+    let module = module_segments(module_path);
+    overrides
+        .iter()
+        .filter_map(|(overridden, value)| {
+            let prefix = module_segments(overridden);
+            module
+                .starts_with(&prefix)
+                .then_some((prefix.len(), *value))
+        })
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, value)| value)
+}
+
+/// Splits a module path into its segments, dropping a leading `crate`.
+fn module_segments(path: &str) -> Vec<&str> {
+    path.split("::")
+        .filter(|segment| !segment.is_empty())
+        .skip_while(|segment| *segment == "crate")
+        .collect()
+}
+
+/// Yields `module_path` and the path of each enclosing module, outermost
+/// first, or just the empty path for the crate root.
+fn enclosing_paths(module_path: &str) -> impl Iterator<Item = &str> {
+    let ends = module_path
+        .match_indices("::")
+        .map(|(index, _)| index)
+        .chain(std::iter::once(module_path.len()));
+    ends.map(move |end| &module_path[..end])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +321,65 @@ mod tests {
         let once_path = SimplePath::new(std::iter::once("solo"));
         assert!(once_path.matches(["solo"]));
     }
+
+    #[rstest]
+    #[case::exact("metrics", "metrics", true)]
+    #[case::submodule("metrics", "metrics::registry", true)]
+    #[case::prefix_only("metrics", "metrics_export", false)]
+    #[case::nested_glob("*::logging", "app::logging::sink", true)]
+    #[case::other_module("logging", "app::logging", false)]
+    #[case::crate_root("metrics", "", false)]
+    #[case::wildcard_root("*", "", true)]
+    fn allows_matching_modules_and_their_children(
+        #[case] glob: &str,
+        #[case] module_path: &str,
+        #[case] expected: bool,
+    ) {
+        let allowed = AllowedModules::new("test", &[glob.to_owned()]);
+
+        assert_eq!(allowed.allows(module_path), expected);
+    }
+
+    #[rstest]
+    fn invalid_globs_are_skipped() {
+        let allowed =
+            AllowedModules::new("test", &[String::from("[metrics"), String::from("logging")]);
+
+        assert!(allowed.allows("logging"));
+        assert!(!allowed.allows("metrics"));
+    }
+
+    #[rstest]
+    fn module_globs_without_patterns_match_nothing() {
+        let modules = ModuleGlobs::new("test", "modules", &[String::from("[billing")]);
+
+        assert!(modules.is_empty());
+        assert!(!modules.matches("billing"));
+        assert!(!modules.matches(""));
+    }
+
+    #[rstest]
+    #[case::named("parser", Some(5))]
+    #[case::nested("parser::tokens", Some(5))]
+    #[case::deepest_wins("parser::grammar::rules", Some(8))]
+    #[case::sibling_prefix("parsers", None)]
+    #[case::crate_root("", None)]
+    fn module_override_prefers_the_deepest_match(
+        #[case] module_path: &str,
+        #[case] expected: Option<usize>,
+    ) {
+        let overrides = BTreeMap::from([
+            (String::from("crate::parser"), 5),
+            (String::from("parser::grammar"), 8),
+        ]);
+
+        assert_eq!(module_override(&overrides, module_path), expected);
+    }
+
+    #[rstest]
+    fn enclosing_paths_run_outermost_first() {
+        let paths: Vec<_> = enclosing_paths("a::b::c").collect();
+
+        assert_eq!(paths, ["a", "a::b", "a::b::c"]);
+    }
 }
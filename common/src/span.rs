@@ -3,6 +3,8 @@
 
 use std::ops::RangeInclusive;
 
+use rustc_lexer::{TokenKind, tokenize};
+
 /// Errors produced when constructing spans.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SpanError {
@@ -122,8 +124,67 @@ pub fn span_line_count(span: SourceSpan) -> usize {
     span.end.line() - span.start.line() + 1
 }
 
+/// What a source line holds, as far as line counts are concerned.
+///
+/// Kinds are ordered by precedence: a line holding both a comment and code
+/// is code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LineKind {
+    /// Only whitespace.
+    Blank,
+    /// Comments, including doc comments, and whitespace only.
+    Comment,
+    /// At least one token that is neither a comment nor whitespace.
+    Code,
+}
+
+/// Classifies each line of `source` as blank, comment, or code.
+///
+/// The text is tokenised with `rustc_lexer`, so comment markers inside string
+/// literals are not mistaken for comments. Every line a block comment touches
+/// counts as a comment line, blank lines inside it included, and every line a
+/// multi-line string literal touches counts as code. A trailing newline does
+/// not start another line.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::span::{LineKind, classify_lines};
+///
+/// let source = "//! Docs.\n\nfn main() {} // trailing\n";
+/// assert_eq!(
+///     classify_lines(source),
+///     [LineKind::Comment, LineKind::Blank, LineKind::Code]
+/// );
+/// ```
+#[must_use]
+pub fn classify_lines(source: &str) -> Vec<LineKind> {
+    let line_count = source.lines().count();
+    let mut kinds = vec![LineKind::Blank; line_count];
+    let mut line = 0;
+    let mut offset = 0;
+    for token in tokenize(source) {
+        let text = &source[offset..offset + token.len];
+        offset += token.len;
+        let newlines = text.matches('\n').count();
+        let kind = match token.kind {
+            TokenKind::Whitespace => None,
+            TokenKind::LineComment | TokenKind::BlockComment { .. } => Some(LineKind::Comment),
+            _ => Some(LineKind::Code),
+        };
+        if let Some(kind) = kind {
+            for slot in kinds.iter_mut().skip(line).take(newlines + 1) {
+                *slot = (*slot).max(kind);
+            }
+        }
+        line += newlines;
+    }
+    kinds
+}
+
 #[cfg(test)]
 mod tests {
+    use super::LineKind::{Blank, Code, Comment};
     use super::*;
     use rstest::rstest;
 
@@ -140,4 +201,19 @@ mod tests {
         assert_eq!(span_to_lines(span), 5..=7);
         assert_eq!(span_line_count(span), 3);
     }
+
+    #[rstest]
+    #[case::empty("", &[])]
+    #[case::blank_lines("\n  \n", &[Blank, Blank])]
+    #[case::doc_comments("//! Module.\n/// Item.\nfn f() {}", &[Comment, Comment, Code])]
+    #[case::trailing_comment("let x = 1; // one", &[Code])]
+    #[case::block_comment("/* a\n\n b */\nfn f() {}", &[Comment, Comment, Comment, Code])]
+    #[case::nested_block_comment("/* /* */ x */", &[Comment])]
+    #[case::code_after_block("/* a\n */ fn f() {}", &[Comment, Code])]
+    #[case::comment_marker_in_string("let s = \"/*\";\nlet t = 1;", &[Code, Code])]
+    #[case::multi_line_string("let s = \"\n\n// not a comment\";", &[Code, Code, Code])]
+    #[case::crlf("// a\r\n\r\nx\r\n", &[Comment, Blank, Code])]
+    fn classifies_lines(#[case] source: &str, #[case] expected: &[LineKind]) {
+        assert_eq!(classify_lines(source), expected);
+    }
 }
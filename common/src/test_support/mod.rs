@@ -10,6 +10,8 @@
 //!   support directories) into isolated workspaces for dylint UI harnesses.
 //! - [`decomposition`]: Reusable decomposition-advice fixtures for unit and
 //!   behaviour tests.
+//! - [`mutation`]: Perturbs fixture sources and checks that heuristic
+//!   detectors respond to each perturbation as specified.
 //! - [`env_test_guard`]: Serializes tests that temporarily mutate process-wide
 //!   environment variables.
 //! - [`ui`]: Discovers fixtures, prepares isolated workspaces, and runs dylint
@@ -19,6 +21,7 @@
 
 pub mod decomposition;
 pub mod fixtures;
+pub mod mutation;
 pub mod ui;
 
 pub use fixtures::{copy_directory, copy_fixture};
@@ -0,0 +1,331 @@
+//! Mutation-style self-tests for heuristic detectors.
+//!
+//! Heuristic lints recognise code by its shape: the path of an attribute, the
+//! order of attributes, whether an item was written by hand or produced by a
+//! macro. A [`Mutation`] perturbs a fixture's source along one of those axes,
+//! and [`check_mutations`] reruns a detector over each mutant, asserting that
+//! its outcome changes or stays the same as each [`MutationCase`] specifies.
+//!
+//! Mutations work line by line on rustfmt-formatted sources, with one
+//! attribute per line. A mutation that finds nothing to change is reported as
+//! [`MutationError::NotApplicable`], so a case cannot pass vacuously.
+//! [`scan_items`] gives detectors that run without the compiler a lightweight
+//! view of a fixture's items and the attributes written above them.
+
+use std::fmt;
+
+use thiserror::Error;
+
+mod scan;
+
+pub use scan::{ScannedItem, scan_items};
+
+/// A structural perturbation of fixture source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mutation {
+    /// Renames every outer attribute whose path is `from` to `to`, keeping
+    /// its arguments.
+    RenameAttribute {
+        /// Attribute path to replace, such as `test`.
+        from: String,
+        /// Replacement path, such as `tokio::test`.
+        to: String,
+    },
+    /// Wraps everything after the crate-level header in a `macro_rules!`
+    /// definition named `name` and invokes it once.
+    WrapInMacro {
+        /// Name of the generated macro.
+        name: String,
+    },
+    /// Reverses each run of consecutive outer attributes and doc comments.
+    ReorderAttributes,
+}
+
+impl Mutation {
+    /// Builds a [`Mutation::RenameAttribute`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::test_support::mutation::Mutation;
+    ///
+    /// let mutation = Mutation::rename_attribute("test", "tokio::test");
+    /// let mutant = mutation.apply("#[test]\nfn checks() {}\n")?;
+    /// assert_eq!(mutant, "#[tokio::test]\nfn checks() {}\n");
+    /// # Ok::<(), whitaker_common::test_support::mutation::MutationError>(())
+    /// ```
+    #[must_use]
+    pub fn rename_attribute(from: impl Into<String>, to: impl Into<String>) -> Self {
+        Self::RenameAttribute {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    /// Builds a [`Mutation::WrapInMacro`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::test_support::mutation::Mutation;
+    ///
+    /// let mutant = Mutation::wrap_in_macro("generated").apply("fn helper() {}\n")?;
+    /// assert!(mutant.starts_with("macro_rules! generated {"));
+    /// assert!(mutant.ends_with("generated!();\n"));
+    /// # Ok::<(), whitaker_common::test_support::mutation::MutationError>(())
+    /// ```
+    #[must_use]
+    pub fn wrap_in_macro(name: impl Into<String>) -> Self {
+        Self::WrapInMacro { name: name.into() }
+    }
+
+    /// Applies the mutation to `source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MutationError::NotApplicable`] when the mutation would leave
+    /// `source` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::test_support::mutation::Mutation;
+    ///
+    /// let source = "/// Adds.\n#[inline]\nfn add() {}\n";
+    /// let mutant = Mutation::ReorderAttributes.apply(source)?;
+    /// assert_eq!(mutant, "#[inline]\n/// Adds.\nfn add() {}\n");
+    /// # Ok::<(), whitaker_common::test_support::mutation::MutationError>(())
+    /// ```
+    pub fn apply(&self, source: &str) -> Result<String, MutationError> {
+        let mutant = match self {
+            Self::RenameAttribute { from, to } => rename_attribute(source, from, to),
+            Self::WrapInMacro { name } => wrap_in_macro(source, name),
+            Self::ReorderAttributes => reorder_attributes(source),
+        };
+        if mutant == source {
+            return Err(MutationError::NotApplicable {
+                mutation: self.to_string(),
+            });
+        }
+        Ok(mutant)
+    }
+}
+
+impl fmt::Display for Mutation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RenameAttribute { from, to } => {
+                write!(formatter, "renaming `#[{from}]` to `#[{to}]`")
+            }
+            Self::WrapInMacro { name } => write!(formatter, "wrapping items in `{name}!`"),
+            Self::ReorderAttributes => formatter.write_str("reversing attribute order"),
+        }
+    }
+}
+
+/// How a detector's outcome should respond to a mutation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Expectation {
+    /// The outcome must match the unmutated fixture's.
+    Stable,
+    /// The outcome must differ from the unmutated fixture's.
+    Changes,
+}
+
+impl fmt::Display for Expectation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Stable => "stay the same",
+            Self::Changes => "change",
+        })
+    }
+}
+
+/// A mutation paired with the expected effect on a detector's outcome.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MutationCase {
+    /// The perturbation to apply.
+    pub mutation: Mutation,
+    /// How the outcome should respond.
+    pub expectation: Expectation,
+}
+
+impl MutationCase {
+    /// A case whose outcome must not change under `mutation`.
+    #[must_use]
+    pub const fn stable(mutation: Mutation) -> Self {
+        Self {
+            mutation,
+            expectation: Expectation::Stable,
+        }
+    }
+
+    /// A case whose outcome must change under `mutation`.
+    #[must_use]
+    pub const fn changes(mutation: Mutation) -> Self {
+        Self {
+            mutation,
+            expectation: Expectation::Changes,
+        }
+    }
+}
+
+/// Errors reported by [`Mutation::apply`] and [`check_mutations`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum MutationError {
+    /// The mutation found nothing to change in the fixture.
+    #[error("{mutation} does not change the fixture")]
+    NotApplicable {
+        /// Description of the mutation.
+        mutation: String,
+    },
+    /// The detector's outcome did not respond to the mutation as expected.
+    #[error(
+        "expected the outcome to {expectation} when {mutation}, but it went from {baseline} to {mutated}"
+    )]
+    UnexpectedOutcome {
+        /// Description of the mutation.
+        mutation: String,
+        /// The expected response.
+        expectation: Expectation,
+        /// Debug rendering of the unmutated outcome.
+        baseline: String,
+        /// Debug rendering of the mutant's outcome.
+        mutated: String,
+    },
+}
+
+/// Runs `detect` over `source` and over each case's mutant, checking every
+/// expectation, and returns the number of cases checked.
+///
+/// # Errors
+///
+/// Returns the first [`MutationError`] encountered, in case order.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::test_support::mutation::{
+///     Mutation, MutationCase, check_mutations, scan_items,
+/// };
+///
+/// let fixture = "#[test]\nfn checks() {}\n";
+/// let tests = |source: &str| -> Vec<String> {
+///     scan_items(source)
+///         .into_iter()
+///         .filter(|item| item.attributes().iter().any(|attr| attr.is_test_like()))
+///         .map(|item| item.name().to_owned())
+///         .collect()
+/// };
+/// let cases = [
+///     MutationCase::stable(Mutation::rename_attribute("test", "tokio::test")),
+///     MutationCase::changes(Mutation::rename_attribute("test", "inline")),
+/// ];
+/// assert_eq!(check_mutations(fixture, &cases, tests), Ok(2));
+/// ```
+pub fn check_mutations<O, F>(
+    source: &str,
+    cases: &[MutationCase],
+    detect: F,
+) -> Result<usize, MutationError>
+where
+    O: PartialEq + fmt::Debug,
+    F: Fn(&str) -> O,
+{
+    let baseline = detect(source);
+    for case in cases {
+        let mutated = detect(&case.mutation.apply(source)?);
+        let unchanged = mutated == baseline;
+        if unchanged != (case.expectation == Expectation::Stable) {
+            return Err(MutationError::UnexpectedOutcome {
+                mutation: case.mutation.to_string(),
+                expectation: case.expectation,
+                baseline: format!("{baseline:?}"),
+                mutated: format!("{mutated:?}"),
+            });
+        }
+    }
+    Ok(cases.len())
+}
+
+/// Returns `true` for lines holding an outer attribute or doc comment.
+fn is_outer_attribute_line(trimmed: &str) -> bool {
+    (trimmed.starts_with("#[") || trimmed.starts_with("///")) && !trimmed.starts_with("////")
+}
+
+/// Returns `true` for crate-level lines that must stay outside a macro.
+fn is_header_line(trimmed: &str) -> bool {
+    trimmed.is_empty() || trimmed.starts_with("//!") || trimmed.starts_with("#![")
+}
+
+fn rename_attribute(source: &str, from: &str, to: &str) -> String {
+    let prefix = format!("#[{from}");
+    map_lines(source, |line| {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let Some(rest) = line.trim_start().strip_prefix(prefix.as_str()) else {
+            return line.to_owned();
+        };
+        if rest.starts_with(']') || rest.starts_with('(') || rest.trim_start().starts_with('=') {
+            format!("{indent}#[{to}{rest}")
+        } else {
+            line.to_owned()
+        }
+    })
+}
+
+fn wrap_in_macro(source: &str, name: &str) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let header_len = lines
+        .iter()
+        .take_while(|line| is_header_line(line.trim_start()))
+        .count();
+    let (header, body) = lines.split_at(header_len);
+    if body.is_empty() {
+        return source.to_owned();
+    }
+
+    let mut mutant = String::new();
+    for line in header {
+        mutant.push_str(line);
+        mutant.push('\n');
+    }
+    mutant.push_str(&format!("macro_rules! {name} {{\n    () => {{\n"));
+    for line in body {
+        if !line.is_empty() {
+            mutant.push_str("        ");
+            mutant.push_str(line);
+        }
+        mutant.push('\n');
+    }
+    mutant.push_str(&format!("    }};\n}}\n\n{name}!();\n"));
+    mutant
+}
+
+fn reorder_attributes(source: &str) -> String {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let mut start = 0;
+    while start < lines.len() {
+        let run = lines[start..]
+            .iter()
+            .take_while(|line| is_outer_attribute_line(line.trim_start()))
+            .count();
+        lines[start..start + run].reverse();
+        start += run.max(1);
+    }
+    let mut mutant = lines.join("\n");
+    if source.ends_with('\n') {
+        mutant.push('\n');
+    }
+    mutant
+}
+
+fn map_lines(source: &str, mut map: impl FnMut(&str) -> String) -> String {
+    let mut mutant: String = source.lines().map(&mut map).collect::<Vec<_>>().join("\n");
+    if source.ends_with('\n') {
+        mutant.push('\n');
+    }
+    mutant
+}
+
+#[cfg(test)]
+mod tests;
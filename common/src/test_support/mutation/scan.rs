@@ -0,0 +1,140 @@
+//! A lightweight, line-based view of the items in a fixture.
+//!
+//! The scanner recognises items by keyword and gathers the outer attributes
+//! and doc comments written on the lines above them, in source order. Items
+//! inside `macro_rules!` definitions are skipped, mirroring lints that ignore
+//! macro-generated code. It does not parse Rust: attributes must sit on
+//! their own lines, and braces inside string literals confuse the macro
+//! tracking.
+
+use crate::attributes::{Attribute, AttributeKind, AttributePath};
+
+use super::is_outer_attribute_line;
+
+/// Keywords that introduce an item, in the order they are looked for.
+const ITEM_KEYWORDS: &[&str] = &[
+    "fn", "mod", "struct", "enum", "trait", "type", "static", "const",
+];
+
+/// An item found in fixture source with the attributes written above it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedItem {
+    name: String,
+    line: usize,
+    attributes: Vec<Attribute>,
+}
+
+impl ScannedItem {
+    /// Returns the item's name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the one-based line of the item's declaration.
+    #[must_use]
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the item's outer attributes in source order; doc comments
+    /// appear as `doc` attributes.
+    #[must_use]
+    pub fn attributes(&self) -> &[Attribute] {
+        &self.attributes
+    }
+}
+
+/// Lists the items declared in `source`, outside macro definitions.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::test_support::mutation::scan_items;
+///
+/// let items = scan_items("/// Checks.\n#[test]\nfn checks() {}\n");
+/// assert_eq!(items[0].name(), "checks");
+/// assert!(items[0].attributes()[0].is_doc());
+/// assert!(items[0].attributes()[1].is_test_like());
+/// ```
+#[must_use]
+pub fn scan_items(source: &str) -> Vec<ScannedItem> {
+    let mut items = Vec::new();
+    let mut pending = Vec::new();
+    let mut macro_depth: Option<isize> = None;
+
+    for (index, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if let Some(depth) = macro_depth {
+            let depth = depth + brace_balance(trimmed);
+            macro_depth = (depth > 0).then_some(depth);
+            continue;
+        }
+        if trimmed.starts_with("macro_rules!") {
+            let depth = brace_balance(trimmed);
+            macro_depth = (depth > 0).then_some(depth);
+            pending.clear();
+            continue;
+        }
+        if is_outer_attribute_line(trimmed) {
+            pending.push(parse_attribute(trimmed));
+            continue;
+        }
+        if trimmed.is_empty() || trimmed.starts_with("//") {
+            continue;
+        }
+        if let Some(name) = item_name(trimmed) {
+            items.push(ScannedItem {
+                name,
+                line: index + 1,
+                attributes: std::mem::take(&mut pending),
+            });
+        } else {
+            pending.clear();
+        }
+    }
+    items
+}
+
+fn brace_balance(line: &str) -> isize {
+    line.chars().fold(0, |balance, character| match character {
+        '{' => balance + 1,
+        '}' => balance - 1,
+        _ => balance,
+    })
+}
+
+/// Converts `#[path(args)]`, `#[path = value]`, or `/// text` to an
+/// [`Attribute`].
+fn parse_attribute(trimmed: &str) -> Attribute {
+    if trimmed.starts_with("///") {
+        return Attribute::new(AttributePath::from("doc"), AttributeKind::Outer);
+    }
+    let content = trimmed
+        .trim_start_matches("#[")
+        .trim_end()
+        .trim_end_matches(']');
+    let path_end = content.find(['(', '=']).unwrap_or(content.len());
+    let path = AttributePath::from(content[..path_end].trim());
+    let arguments: Vec<&str> = content[path_end..]
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .map(|inner| inner.split(',').map(str::trim).collect())
+        .unwrap_or_default();
+    Attribute::with_str_arguments(path, AttributeKind::Outer, &arguments)
+}
+
+/// Returns the name following the first item keyword on the line.
+fn item_name(trimmed: &str) -> Option<String> {
+    let tokens: Vec<&str> = trimmed
+        .split(|character: char| !(character.is_alphanumeric() || character == '_'))
+        .filter(|token| !token.is_empty())
+        .collect();
+    ITEM_KEYWORDS.iter().find_map(|keyword| {
+        let position = tokens.iter().position(|token| token == keyword)?;
+        tokens
+            .get(position + 1)
+            .filter(|name| !ITEM_KEYWORDS.contains(name))
+            .map(|name| (*name).to_owned())
+    })
+}
@@ -0,0 +1,129 @@
+//! Unit tests for fixture mutations and the item scanner.
+
+use super::*;
+use rstest::rstest;
+
+const FIXTURE: &str = "\
+//! Fixture.
+#![allow(dead_code)]
+
+/// Checks addition.
+#[test]
+#[should_panic(expected = \"overflow\")]
+fn checks() {}
+
+#[cfg(test)]
+mod tests {}
+";
+
+#[rstest]
+#[case::plain("#[test]\nfn a() {}\n", "#[tokio::test]\nfn a() {}\n")]
+#[case::arguments("#[test(flavor)]\nfn a() {}\n", "#[tokio::test(flavor)]\nfn a() {}\n")]
+#[case::indented(
+    "mod m {\n    #[test]\n    fn a() {}\n}",
+    "mod m {\n    #[tokio::test]\n    fn a() {}\n}"
+)]
+#[case::longer_path_untouched(
+    "#[tested]\n#[test]\nfn a() {}\n",
+    "#[tested]\n#[tokio::test]\nfn a() {}\n"
+)]
+fn rename_replaces_only_the_named_path(#[case] source: &str, #[case] expected: &str) {
+    let mutant = Mutation::rename_attribute("test", "tokio::test").apply(source);
+
+    assert_eq!(mutant.as_deref(), Ok(expected));
+}
+
+#[rstest]
+fn wrap_keeps_the_crate_header_outside_the_macro() {
+    let mutant = Mutation::wrap_in_macro("generated")
+        .apply(FIXTURE)
+        .unwrap_or_default();
+
+    assert!(mutant.starts_with("//! Fixture.\n#![allow(dead_code)]\n\nmacro_rules! generated {"));
+    assert!(mutant.contains("        #[test]\n        #[should_panic"));
+    assert!(mutant.ends_with("    };\n}\n\ngenerated!();\n"));
+}
+
+#[rstest]
+fn reorder_reverses_each_attribute_run() {
+    let mutant = Mutation::ReorderAttributes
+        .apply(FIXTURE)
+        .unwrap_or_default();
+
+    assert!(mutant.contains(
+        "#[should_panic(expected = \"overflow\")]\n#[test]\n/// Checks addition.\nfn checks() {}"
+    ));
+    assert!(mutant.contains("#![allow(dead_code)]"));
+}
+
+#[rstest]
+#[case::rename_missing(Mutation::rename_attribute("rstest", "test"), "fn a() {}\n")]
+#[case::reorder_single(Mutation::ReorderAttributes, "#[test]\nfn a() {}\n")]
+#[case::wrap_header_only(Mutation::wrap_in_macro("generated"), "//! Only docs.\n")]
+fn mutations_that_change_nothing_are_rejected(#[case] mutation: Mutation, #[case] source: &str) {
+    assert_eq!(
+        mutation.apply(source),
+        Err(MutationError::NotApplicable {
+            mutation: mutation.to_string(),
+        })
+    );
+}
+
+#[rstest]
+fn check_mutations_reports_an_unexpected_outcome() {
+    let names = |source: &str| -> Vec<String> {
+        scan_items(source)
+            .iter()
+            .map(|item| item.name().to_owned())
+            .collect()
+    };
+    let cases = [MutationCase::stable(Mutation::wrap_in_macro("generated"))];
+
+    assert_eq!(
+        check_mutations(FIXTURE, &cases, names),
+        Err(MutationError::UnexpectedOutcome {
+            mutation: "wrapping items in `generated!`".to_owned(),
+            expectation: Expectation::Stable,
+            baseline: "[\"checks\", \"tests\"]".to_owned(),
+            mutated: "[]".to_owned(),
+        })
+    );
+}
+
+#[rstest]
+fn scan_collects_attributes_in_source_order() {
+    let items = scan_items(FIXTURE);
+    let paths: Vec<Vec<String>> = items
+        .iter()
+        .map(|item| {
+            item.attributes()
+                .iter()
+                .map(|attribute| attribute.path().to_string())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(
+        items.iter().map(ScannedItem::name).collect::<Vec<_>>(),
+        ["checks", "tests"]
+    );
+    assert_eq!(items[0].line(), 7);
+    assert_eq!(paths, [vec!["doc", "test", "should_panic"], vec!["cfg"]]);
+    assert!(items[1].attributes()[0].is_cfg_test());
+}
+
+#[rstest]
+#[case::statement_clears("#[allow(unused)]\nlet x = 1;\nfn a() {}\n", "a", 0)]
+#[case::qualified("pub(crate) async fn fetch() {}\n", "fetch", 0)]
+#[case::const_fn("#[inline]\nconst fn limit() -> usize { 3 }\n", "limit", 1)]
+fn scan_names_items_after_their_keyword(
+    #[case] source: &str,
+    #[case] name: &str,
+    #[case] attributes: usize,
+) {
+    let items = scan_items(source);
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name(), name);
+    assert_eq!(items[0].attributes().len(), attributes);
+}
@@ -0,0 +1,110 @@
+//! Behaviour-driven coverage for lint pass state across several crates.
+//!
+//! The world drives a stand-in lint pass through `check_crate` calls for
+//! successive crates, as a compiler session that checks more than one crate
+//! would, and asserts that each crate starts from fresh state.
+
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use whitaker_common::CrateScopedState;
+
+/// Per-crate state of the stand-in pass.
+#[derive(Debug, Default)]
+struct CrateState {
+    crate_name: String,
+    is_doctest: bool,
+    findings: Vec<String>,
+}
+
+/// A pass holding its per-crate fields behind [`CrateScopedState`].
+#[derive(Debug, Default)]
+struct Pass {
+    state: CrateScopedState<CrateState>,
+}
+
+impl Pass {
+    fn check_crate(&mut self, crate_name: &str, is_doctest: bool) {
+        self.state.reset(CrateState {
+            crate_name: crate_name.to_owned(),
+            is_doctest,
+            findings: Vec::new(),
+        });
+    }
+
+    fn check_item(&mut self, item: &str) {
+        self.state.findings.push(item.to_owned());
+    }
+}
+
+#[derive(Debug, Default)]
+struct SessionWorld {
+    pass: RefCell<Pass>,
+}
+
+#[fixture]
+fn world() -> SessionWorld {
+    SessionWorld::default()
+}
+
+#[given("a pass that checked the crate {name} and recorded {count} findings")]
+fn given_findings(world: &SessionWorld, name: String, count: usize) {
+    let mut pass = world.pass.borrow_mut();
+    pass.check_crate(&name, false);
+    for index in 0..count {
+        pass.check_item(&format!("item{index}"));
+    }
+}
+
+#[given("a pass that checked the doctest crate {name}")]
+fn given_doctest(world: &SessionWorld, name: String) {
+    world.pass.borrow_mut().check_crate(&name, true);
+}
+
+#[given("a pass that has not checked a crate")]
+fn given_fresh_pass(world: &SessionWorld) {
+    world.pass.replace(Pass::default());
+}
+
+#[when("the pass checks the crate {name}")]
+fn when_next_crate(world: &SessionWorld, name: String) {
+    world.pass.borrow_mut().check_crate(&name, false);
+}
+
+#[then("the pass state names the crate {name}")]
+fn then_crate_name(world: &SessionWorld, name: String) {
+    assert_eq!(world.pass.borrow().state.crate_name, name);
+}
+
+#[then("the pass state holds {count} findings")]
+fn then_findings(world: &SessionWorld, count: usize) {
+    assert_eq!(world.pass.borrow().state.findings.len(), count);
+}
+
+#[then("the pass state is not a doctest")]
+fn then_not_doctest(world: &SessionWorld) {
+    assert!(!world.pass.borrow().state.is_doctest);
+}
+
+#[then("the pass has checked {count} crates")]
+fn then_crates(world: &SessionWorld, count: usize) {
+    assert_eq!(world.pass.borrow().state.crates(), count);
+}
+
+// Scenario indices must match their declaration order in
+// `tests/features/crate_scoped_state.feature`.
+
+#[scenario(path = "tests/features/crate_scoped_state.feature", index = 0)]
+fn scenario_findings_do_not_leak(world: SessionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/crate_scoped_state.feature", index = 1)]
+fn scenario_doctest_flag_does_not_leak(world: SessionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/crate_scoped_state.feature", index = 2)]
+fn scenario_default_before_first_crate(world: SessionWorld) {
+    let _ = world;
+}
@@ -0,0 +1,99 @@
+//! Behaviour-driven coverage for crate-wide data clump detection.
+
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::data_clumps::{
+    DataClump, DataClumpThresholds, FunctionSignature, Parameter, find_data_clumps,
+};
+
+#[derive(Debug, Default)]
+struct ClumpWorld {
+    signatures: RefCell<Vec<FunctionSignature>>,
+    thresholds: Cell<DataClumpThresholds>,
+    clumps: RefCell<Vec<DataClump>>,
+}
+
+#[fixture]
+fn world() -> ClumpWorld {
+    ClumpWorld::default()
+}
+
+/// Parses a comma-separated list of `name: type` pairs.
+fn parse_parameters(parameters: &str) -> Vec<Parameter> {
+    parameters
+        .split(',')
+        .filter_map(|pair| pair.split_once(':'))
+        .map(|(name, ty)| Parameter::new(name.trim(), ty.trim()))
+        .collect()
+}
+
+#[given("a function {name} taking {parameters}")]
+fn given_function(world: &ClumpWorld, name: String, parameters: String) {
+    world
+        .signatures
+        .borrow_mut()
+        .push(FunctionSignature::new(name, parse_parameters(&parameters)));
+}
+
+#[given("clumps need {parameters} parameters in {occurrences} functions")]
+fn given_thresholds(world: &ClumpWorld, parameters: usize, occurrences: usize) {
+    world
+        .thresholds
+        .set(DataClumpThresholds::new(parameters, occurrences));
+}
+
+#[when("data clumps are detected")]
+fn when_detected(world: &ClumpWorld) {
+    let clumps = find_data_clumps(&world.signatures.borrow(), world.thresholds.get());
+    world.clumps.replace(clumps);
+}
+
+#[then("{count} clump is found")]
+fn then_one_clump(world: &ClumpWorld, count: usize) {
+    assert_eq!(world.clumps.borrow().len(), count);
+}
+
+#[then("{count} clumps are found")]
+fn then_clumps(world: &ClumpWorld, count: usize) {
+    assert_eq!(world.clumps.borrow().len(), count);
+}
+
+#[then("the first clump has parameters {names}")]
+fn then_first_clump(world: &ClumpWorld, names: String) {
+    let clumps = world.clumps.borrow();
+    let actual: Vec<&str> = clumps
+        .first()
+        .map(|clump| clump.parameters().iter().map(Parameter::name).collect())
+        .unwrap_or_default();
+    let expected: Vec<&str> = names.split(',').map(str::trim).collect();
+    assert_eq!(actual, expected);
+}
+
+// Scenario indices must match their declaration order in
+// `tests/features/data_clumps.feature`.
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 0)]
+fn scenario_shared_group(world: ClumpWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 1)]
+fn scenario_two_functions(world: ClumpWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 2)]
+fn scenario_type_mismatch(world: ClumpWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 3)]
+fn scenario_too_few_parameters(world: ClumpWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 4)]
+fn scenario_lower_thresholds(world: ClumpWorld) {
+    let _ = world;
+}
@@ -0,0 +1,91 @@
+//! Behaviour-driven coverage for multi-signal doctest detection.
+
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::context::DoctestSignals;
+
+#[derive(Debug, Default)]
+struct DoctestWorld {
+    signals: RefCell<DoctestSignals>,
+    is_doctest: Cell<Option<bool>>,
+}
+
+impl DoctestWorld {
+    fn update(&self, change: impl FnOnce(DoctestSignals) -> DoctestSignals) {
+        let signals = self.signals.take();
+        self.signals.replace(change(signals));
+    }
+}
+
+#[fixture]
+fn world() -> DoctestWorld {
+    DoctestWorld::default()
+}
+
+#[given("a crate named {name} compiled from src/lib.rs")]
+fn given_file_crate(world: &DoctestWorld, name: String) {
+    world
+        .signals
+        .replace(DoctestSignals::new(name).with_source_name("src/lib.rs"));
+}
+
+#[given("a crate named {name} compiled from a string")]
+fn given_string_crate(world: &DoctestWorld, name: String) {
+    world.signals.replace(DoctestSignals::new(name));
+}
+
+#[given("the rustdoc test path variable is set")]
+fn given_rustdoc_variable(world: &DoctestWorld) {
+    world.update(|signals| signals.with_rustdoc_test_path(true));
+}
+
+#[given("the crate is built as a test harness")]
+fn given_test_harness(world: &DoctestWorld) {
+    world.update(|signals| signals.with_test_harness(true));
+}
+
+#[when("doctest signals are evaluated")]
+fn when_evaluated(world: &DoctestWorld) {
+    world
+        .is_doctest
+        .set(Some(world.signals.borrow().is_doctest()));
+}
+
+#[then("the crate is a doctest")]
+fn then_doctest(world: &DoctestWorld) {
+    assert_eq!(world.is_doctest.get(), Some(true));
+}
+
+#[then("the crate is not a doctest")]
+fn then_not_doctest(world: &DoctestWorld) {
+    assert_eq!(world.is_doctest.get(), Some(false));
+}
+
+// Scenario indices must match their declaration order in
+// `tests/features/doctest_detection.feature`.
+
+#[scenario(path = "tests/features/doctest_detection.feature", index = 0)]
+fn scenario_library(world: DoctestWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/doctest_detection.feature", index = 1)]
+fn scenario_environment(world: DoctestWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/doctest_detection.feature", index = 2)]
+fn scenario_merged_name(world: DoctestWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/doctest_detection.feature", index = 3)]
+fn scenario_string_harness(world: DoctestWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/doctest_detection.feature", index = 4)]
+fn scenario_unit_tests(world: DoctestWorld) {
+    let _ = world;
+}
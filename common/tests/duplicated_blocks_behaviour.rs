@@ -0,0 +1,97 @@
+//! Behaviour-driven coverage for crate-wide duplicated block detection.
+
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::duplicated_blocks::{
+    DuplicatedBlock, DuplicationThresholds, NormalizedBlock, NormalizedStatement,
+    find_duplicated_blocks,
+};
+
+#[derive(Debug, Default)]
+struct DuplicationWorld {
+    blocks: RefCell<Vec<NormalizedBlock>>,
+    thresholds: Cell<DuplicationThresholds>,
+    duplicates: RefCell<Vec<DuplicatedBlock>>,
+}
+
+#[fixture]
+fn world() -> DuplicationWorld {
+    DuplicationWorld::default()
+}
+
+/// Parses `;`-separated statements of space-separated tokens.
+fn parse_statements(statements: &str) -> Vec<NormalizedStatement> {
+    statements
+        .split(';')
+        .map(|statement| NormalizedStatement::new(statement.split_whitespace()))
+        .collect()
+}
+
+#[given("function {owner} has a block {statements}")]
+fn given_block(world: &DuplicationWorld, owner: usize, statements: String) {
+    world
+        .blocks
+        .borrow_mut()
+        .push(NormalizedBlock::new(owner, parse_statements(&statements)));
+}
+
+#[given("windows of {statements} statements and {tokens} tokens at {similarity} similarity")]
+fn given_thresholds(world: &DuplicationWorld, statements: usize, tokens: usize, similarity: f64) {
+    world
+        .thresholds
+        .set(DuplicationThresholds::new(statements, tokens, similarity));
+}
+
+#[when("duplicated blocks are detected")]
+fn when_detected(world: &DuplicationWorld) {
+    let duplicates = find_duplicated_blocks(&world.blocks.borrow(), world.thresholds.get());
+    world.duplicates.replace(duplicates);
+}
+
+#[then("{count} duplicate is found")]
+fn then_one_duplicate(world: &DuplicationWorld, count: usize) {
+    assert_eq!(world.duplicates.borrow().len(), count);
+}
+
+#[then("{count} duplicates are found")]
+fn then_duplicates(world: &DuplicationWorld, count: usize) {
+    assert_eq!(world.duplicates.borrow().len(), count);
+}
+
+#[then("the duplicate spans {len} statements")]
+fn then_duplicate_length(world: &DuplicationWorld, len: usize) {
+    let duplicates = world.duplicates.borrow();
+    assert_eq!(
+        duplicates.first().map(|duplicate| duplicate.duplicate.len),
+        Some(len)
+    );
+}
+
+// Scenario indices must match their declaration order in
+// `tests/features/duplicated_blocks.feature`.
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 0)]
+fn scenario_identical_blocks(world: DuplicationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 1)]
+fn scenario_small_edit(world: DuplicationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 2)]
+fn scenario_exact_only(world: DuplicationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 3)]
+fn scenario_short_blocks(world: DuplicationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 4)]
+fn scenario_same_function(world: DuplicationWorld) {
+    let _ = world;
+}
@@ -0,0 +1,110 @@
+//! Behaviour-driven coverage for the shared `else` removal rules.
+
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::else_removal::{ElseRemoval, reindent_block_body};
+
+#[derive(Default)]
+struct RemovalWorld {
+    removal: Cell<ElseRemoval>,
+    applicable: Cell<Option<bool>>,
+    body: RefCell<String>,
+    rewritten: RefCell<String>,
+}
+
+#[fixture]
+fn world() -> RemovalWorld {
+    RemovalWorld::default()
+}
+
+#[given("the if is its block's trailing expression")]
+fn given_trailing(world: &RemovalWorld) {
+    world.removal.set(ElseRemoval {
+        code_follows: false,
+        ..world.removal.get()
+    });
+}
+
+#[given("code follows the if")]
+fn given_code_follows(world: &RemovalWorld) {
+    world.removal.set(ElseRemoval {
+        code_follows: true,
+        ..world.removal.get()
+    });
+}
+
+#[given("the else body declares bindings")]
+fn given_bindings(world: &RemovalWorld) {
+    world.removal.set(ElseRemoval {
+        declares_bindings: true,
+        ..world.removal.get()
+    });
+}
+
+#[given("a comment sits before the else")]
+fn given_comment(world: &RemovalWorld) {
+    world.removal.set(ElseRemoval {
+        comments_before_else: true,
+        ..world.removal.get()
+    });
+}
+
+#[given("an else body indented by {width} spaces")]
+fn given_body(world: &RemovalWorld, width: usize) {
+    let margin = " ".repeat(width);
+    *world.body.borrow_mut() = format!("\n{margin}let value = load();\n{margin}value + 1\n");
+}
+
+#[when("the removal is checked")]
+fn when_checked(world: &RemovalWorld) {
+    world
+        .applicable
+        .set(Some(world.removal.get().is_machine_applicable()));
+}
+
+#[when("the body is re-indented at {width} spaces")]
+fn when_reindented(world: &RemovalWorld, width: usize) {
+    let body = world.body.borrow();
+    *world.rewritten.borrow_mut() = reindent_block_body(&body, &" ".repeat(width));
+}
+
+#[then("the suggestion is machine-applicable")]
+fn then_applicable(world: &RemovalWorld) {
+    assert_eq!(world.applicable.get(), Some(true));
+}
+
+#[then("the suggestion needs review")]
+fn then_needs_review(world: &RemovalWorld) {
+    assert_eq!(world.applicable.get(), Some(false));
+}
+
+#[then("every line starts at {width} spaces")]
+fn then_indented(world: &RemovalWorld, width: usize) {
+    let margin = " ".repeat(width);
+    let rewritten = world.rewritten.borrow();
+    assert!(rewritten.lines().all(|line| {
+        line.strip_prefix(&margin)
+            .is_some_and(|rest| !rest.starts_with(' '))
+    }));
+}
+
+#[scenario(path = "tests/features/else_removal.feature", index = 0)]
+fn scenario_trailing_else(world: RemovalWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/else_removal.feature", index = 1)]
+fn scenario_bindings_before_code(world: RemovalWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/else_removal.feature", index = 2)]
+fn scenario_comments_before_else(world: RemovalWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/else_removal.feature", index = 3)]
+fn scenario_reindent(world: RemovalWorld) {
+    let _ = world;
+}
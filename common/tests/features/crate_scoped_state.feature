@@ -0,0 +1,21 @@
+Feature: Crate-scoped lint pass state
+  A lint pass that checks several crates in one compiler session starts each
+  crate from freshly built state, so nothing it learnt about one crate leaks
+  into the next.
+
+  Scenario: A second crate does not inherit the first crate's findings
+    Given a pass that checked the crate alpha and recorded 2 findings
+    When the pass checks the crate beta
+    Then the pass state names the crate beta
+    And the pass state holds 0 findings
+    And the pass has checked 2 crates
+
+  Scenario: A doctest crate does not mark the next crate as a doctest
+    Given a pass that checked the doctest crate alpha
+    When the pass checks the crate beta
+    Then the pass state is not a doctest
+
+  Scenario: State read before any crate is checked is the default
+    Given a pass that has not checked a crate
+    Then the pass state holds 0 findings
+    And the pass has checked 0 crates
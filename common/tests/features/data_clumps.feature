@@ -0,0 +1,39 @@
+Feature: Data clump detection
+  Groups of parameters that appear together in several function signatures
+  are reported once, with the widest set the signatures share.
+
+  Scenario: A group shared by three functions is a clump
+    Given a function translate taking x: f64, y: f64, z: f64
+    And a function scale taking x: f64, y: f64, z: f64, factor: f64
+    And a function rotate taking x: f64, y: f64, z: f64
+    When data clumps are detected
+    Then 1 clump is found
+    And the first clump has parameters x, y, z
+
+  Scenario: A group shared by two functions is not a clump by default
+    Given a function translate taking x: f64, y: f64, z: f64
+    And a function scale taking x: f64, y: f64, z: f64
+    When data clumps are detected
+    Then 0 clumps are found
+
+  Scenario: Matching names with different types do not clump
+    Given a function translate taking x: f64, y: f64, z: f64
+    And a function scale taking x: f64, y: f64, z: f64
+    And a function rotate taking x: f32, y: f32, z: f32
+    When data clumps are detected
+    Then 0 clumps are found
+
+  Scenario: Two shared parameters are too few
+    Given a function open taking host: String, port: u16
+    And a function close taking host: String, port: u16
+    And a function probe taking host: String, port: u16
+    When data clumps are detected
+    Then 0 clumps are found
+
+  Scenario: Lower thresholds report smaller groups
+    Given a function open taking host: String, port: u16
+    And a function close taking host: String, port: u16
+    And clumps need 2 parameters in 2 functions
+    When data clumps are detected
+    Then 1 clump is found
+    And the first clump has parameters host, port
@@ -0,0 +1,31 @@
+Feature: Doctest detection
+  A crate is treated as a doctest when any compiler signal says so, so lints
+  keep working when rustdoc changes one of them.
+
+  Scenario: A library crate is not a doctest
+    Given a crate named demo compiled from src/lib.rs
+    When doctest signals are evaluated
+    Then the crate is not a doctest
+
+  Scenario: The rustdoc environment variable marks a doctest
+    Given a crate named demo compiled from src/lib.rs
+    And the rustdoc test path variable is set
+    When doctest signals are evaluated
+    Then the crate is a doctest
+
+  Scenario: Merged doctest crate names mark a doctest
+    Given a crate named doctest_bundle_2024 compiled from src/lib.rs
+    When doctest signals are evaluated
+    Then the crate is a doctest
+
+  Scenario: A test harness built from a string marks a doctest
+    Given a crate named demo compiled from a string
+    And the crate is built as a test harness
+    When doctest signals are evaluated
+    Then the crate is a doctest
+
+  Scenario: Unit tests built from a file are not doctests
+    Given a crate named demo compiled from src/lib.rs
+    And the crate is built as a test harness
+    When doctest signals are evaluated
+    Then the crate is not a doctest
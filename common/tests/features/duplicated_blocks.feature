@@ -0,0 +1,39 @@
+Feature: Duplicated block detection
+  Runs of normalised statements that two functions share are reported once
+  per pair of functions, extended as far as the statements keep matching.
+
+  Scenario: Identical blocks in two functions are reported
+    Given function 0 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local; stmt:semi expr:method method:close local
+    And function 1 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local; stmt:semi expr:method method:close local
+    And windows of 3 statements and 10 tokens at 0.9 similarity
+    When duplicated blocks are detected
+    Then 1 duplicate is found
+    And the duplicate spans 3 statements
+
+  Scenario: A small edit still counts as a duplicate
+    Given function 0 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local local local; stmt:semi expr:method method:close local
+    And function 1 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local local lit; stmt:semi expr:method method:close local
+    And windows of 3 statements and 10 tokens at 0.9 similarity
+    When duplicated blocks are detected
+    Then 1 duplicate is found
+
+  Scenario: Exact matching rejects the same edit
+    Given function 0 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local local local; stmt:semi expr:method method:close local
+    And function 1 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local local lit; stmt:semi expr:method method:close local
+    And windows of 3 statements and 10 tokens at 1.0 similarity
+    When duplicated blocks are detected
+    Then 0 duplicates are found
+
+  Scenario: Blocks shorter than the window are ignored
+    Given function 0 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:close local
+    And function 1 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:close local
+    And windows of 3 statements and 5 tokens at 0.9 similarity
+    When duplicated blocks are detected
+    Then 0 duplicates are found
+
+  Scenario: Repetition inside one function is not reported
+    Given function 0 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local; stmt:semi expr:method method:close local
+    And function 0 has a block stmt:let expr:call path:open local lit; stmt:semi expr:method method:read local; stmt:semi expr:method method:close local
+    And windows of 3 statements and 10 tokens at 0.9 similarity
+    When duplicated blocks are detected
+    Then 0 duplicates are found
@@ -0,0 +1,27 @@
+Feature: Removing an else after an early exit
+  The suggestion that removes an `else` after an `if` block ending in an
+  exit is machine-applicable unless moving the body could change the
+  program or drop comments.
+
+  Scenario: A trailing else is removed automatically
+    Given the if is its block's trailing expression
+    And the else body declares bindings
+    When the removal is checked
+    Then the suggestion is machine-applicable
+
+  Scenario: Bindings before following code need review
+    Given code follows the if
+    And the else body declares bindings
+    When the removal is checked
+    Then the suggestion needs review
+
+  Scenario: Comments before the else need review
+    Given the if is its block's trailing expression
+    And a comment sits before the else
+    When the removal is checked
+    Then the suggestion needs review
+
+  Scenario: The else body moves to the if's indentation
+    Given an else body indented by 8 spaces
+    When the body is re-indented at 4 spaces
+    Then every line starts at 4 spaces
@@ -0,0 +1,29 @@
+Feature: Mutation-style self-tests for heuristic detectors
+  Fixture mutations perturb the source a detector sees. Each case states
+  whether the detector's outcome must change or stay the same, so the
+  detector is checked against structural edge cases rather than one
+  hand-written input.
+
+  Scenario: Renaming a test attribute to another test framework is stable
+    Given a fixture with a test function and a helper
+    And the mutation renaming test to tokio::test is expected to be stable
+    When the test-context detector is checked against the mutations
+    Then every mutation case holds
+
+  Scenario: Renaming a test attribute to a non-test attribute changes detection
+    Given a fixture with a test function and a helper
+    And the mutation renaming test to inline is expected to change the outcome
+    When the test-context detector is checked against the mutations
+    Then every mutation case holds
+
+  Scenario: Reordering attributes does not affect test detection
+    Given a fixture with a test function and a helper
+    And attribute reordering is expected to be stable
+    When the test-context detector is checked against the mutations
+    Then every mutation case holds
+
+  Scenario: A mutation that changes nothing is reported
+    Given a fixture with a test function and a helper
+    And the mutation renaming rstest to test is expected to be stable
+    When the test-context detector is checked against the mutations
+    Then the mutation is reported as not applicable
@@ -0,0 +1,116 @@
+//! Behaviour-driven coverage for mutation-style detector self-tests.
+//!
+//! Runs the test-context detector over fixture mutants and checks that its
+//! outcome responds to each perturbation as the scenario specifies.
+
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use whitaker_common::context::is_test_fn;
+use whitaker_common::test_support::mutation::{
+    Mutation, MutationCase, MutationError, check_mutations, scan_items,
+};
+
+const FIXTURE: &str = "\
+/// Adds two numbers.
+fn add(left: u32, right: u32) -> u32 {
+    left + right
+}
+
+/// Checks addition.
+#[test]
+#[should_panic(expected = \"overflow\")]
+fn adds_numbers() {
+    add(u32::MAX, 1);
+}
+";
+
+#[derive(Debug, Default)]
+struct MutationWorld {
+    source: RefCell<String>,
+    cases: RefCell<Vec<MutationCase>>,
+    outcome: RefCell<Option<Result<usize, MutationError>>>,
+}
+
+#[fixture]
+fn world() -> MutationWorld {
+    MutationWorld::default()
+}
+
+/// Names the functions the test-context detector treats as tests.
+fn detect_tests(source: &str) -> Vec<String> {
+    scan_items(source)
+        .into_iter()
+        .filter(|item| is_test_fn(item.attributes()))
+        .map(|item| item.name().to_owned())
+        .collect()
+}
+
+#[given("a fixture with a test function and a helper")]
+fn given_fixture(world: &MutationWorld) {
+    world.source.replace(FIXTURE.to_owned());
+}
+
+#[given("the mutation renaming {from} to {to} is expected to be stable")]
+fn given_stable_rename(world: &MutationWorld, from: String, to: String) {
+    let mutation = Mutation::rename_attribute(from, to);
+    world
+        .cases
+        .borrow_mut()
+        .push(MutationCase::stable(mutation));
+}
+
+#[given("the mutation renaming {from} to {to} is expected to change the outcome")]
+fn given_changing_rename(world: &MutationWorld, from: String, to: String) {
+    let mutation = Mutation::rename_attribute(from, to);
+    world
+        .cases
+        .borrow_mut()
+        .push(MutationCase::changes(mutation));
+}
+
+#[given("attribute reordering is expected to be stable")]
+fn given_stable_reorder(world: &MutationWorld) {
+    let case = MutationCase::stable(Mutation::ReorderAttributes);
+    world.cases.borrow_mut().push(case);
+}
+
+#[when("the test-context detector is checked against the mutations")]
+fn when_checked(world: &MutationWorld) {
+    let outcome = check_mutations(&world.source.borrow(), &world.cases.borrow(), detect_tests);
+    world.outcome.replace(Some(outcome));
+}
+
+#[then("every mutation case holds")]
+fn then_cases_hold(world: &MutationWorld) {
+    let expected = world.cases.borrow().len();
+    assert_eq!(*world.outcome.borrow(), Some(Ok(expected)));
+}
+
+#[then("the mutation is reported as not applicable")]
+fn then_not_applicable(world: &MutationWorld) {
+    assert!(matches!(
+        *world.outcome.borrow(),
+        Some(Err(MutationError::NotApplicable { .. }))
+    ));
+}
+
+#[scenario(path = "tests/features/mutation.feature", index = 0)]
+fn scenario_rename_to_tokio_is_stable(world: MutationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/mutation.feature", index = 1)]
+fn scenario_rename_to_inline_changes(world: MutationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/mutation.feature", index = 2)]
+fn scenario_reorder_is_stable(world: MutationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/mutation.feature", index = 3)]
+fn scenario_inapplicable_mutation_is_reported(world: MutationWorld) {
+    let _ = world;
+}
@@ -0,0 +1,116 @@
+//! Time budgets for the rustc-independent work behind lint passes.
+//!
+//! Each case runs one analysis from `benches/lint_support.rs` over the same
+//! medium workload and fails when its median time exceeds the budget. The
+//! budgets sit well above release-build timings so that only a real
+//! regression, not machine noise, trips them. Timings mean little in debug
+//! builds, so the cases are ignored by default; run them with `make perf`.
+//! One untimed case runs in every build and checks that the per-body context
+//! cache summarises the pathological workload exactly as the uncached path
+//! does.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rstest::rstest;
+use whitaker_common::context::is_test_fn;
+use whitaker_common::data_clumps::{DataClumpThresholds, find_data_clumps};
+use whitaker_common::duplicated_blocks::{DuplicationThresholds, find_duplicated_blocks};
+use whitaker_common::i18n::get_localizer_for_lint;
+use whitaker_common::lcom4::cohesion_components;
+
+#[path = "../benches/support/workload.rs"]
+mod workload;
+
+/// Timed runs per case; the median is compared against the budget.
+const SAMPLES: usize = 15;
+
+/// Returns the median wall-clock time of `SAMPLES` runs of `routine`.
+fn median_time(mut routine: impl FnMut()) -> Duration {
+    routine();
+    let mut timings: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            routine();
+            start.elapsed()
+        })
+        .collect();
+    timings.sort_unstable();
+    timings[SAMPLES / 2]
+}
+
+fn localization() -> Duration {
+    median_time(|| {
+        let localizer = get_localizer_for_lint(workload::DIAGNOSTIC_LINT, None);
+        black_box(workload::resolve_diagnostic(&localizer));
+    })
+}
+
+fn test_context_detection() -> Duration {
+    let attribute_lists = workload::attribute_lists();
+    median_time(|| {
+        black_box(
+            attribute_lists
+                .iter()
+                .filter(|attributes| is_test_fn(attributes))
+                .count(),
+        );
+    })
+}
+
+fn context_summaries() -> Duration {
+    median_time(|| {
+        black_box(workload::summarise_sites_cached());
+    })
+}
+
+fn duplicated_blocks() -> Duration {
+    let blocks = workload::blocks();
+    median_time(|| {
+        black_box(find_duplicated_blocks(
+            &blocks,
+            DuplicationThresholds::default(),
+        ));
+    })
+}
+
+fn data_clumps() -> Duration {
+    let signatures = workload::signatures();
+    median_time(|| {
+        black_box(find_data_clumps(
+            &signatures,
+            DataClumpThresholds::default(),
+        ));
+    })
+}
+
+fn lcom4_components() -> Duration {
+    let methods = workload::methods();
+    median_time(|| {
+        black_box(cohesion_components(&methods));
+    })
+}
+
+#[rstest]
+#[case::localization(localization, Duration::from_millis(1))]
+#[case::test_context_detection(test_context_detection, Duration::from_millis(1))]
+#[case::context_summaries(context_summaries, Duration::from_millis(25))]
+#[case::duplicated_blocks(duplicated_blocks, Duration::from_millis(300))]
+#[case::data_clumps(data_clumps, Duration::from_millis(100))]
+#[case::lcom4_components(lcom4_components, Duration::from_millis(1))]
+#[ignore = "timing budgets only hold for release builds; run with `make perf`"]
+fn analysis_stays_within_budget(#[case] measure: fn() -> Duration, #[case] budget: Duration) {
+    let median = measure();
+    assert!(
+        median <= budget,
+        "median time {median:?} exceeds the {budget:?} budget"
+    );
+}
+
+#[rstest]
+fn cached_context_summaries_match_uncached() {
+    assert_eq!(
+        workload::summarise_sites_cached(),
+        workload::summarise_sites_uncached()
+    );
+}
@@ -0,0 +1,119 @@
+//! Pure decision logic for `allow_must_have_reason`.
+//!
+//! The driver reads the `reason = "..."` argument of each `#[allow(...)]`
+//! attribute and the comment trailing it, and judges them with the same
+//! [`JustificationPolicy`] that [`AllowMustHaveReasonDecision`] wraps.
+//! Findings use the problem's Fluent selector as their reason.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{Justification, JustificationPolicy, MissingJustification};
+
+/// The facts about one `#[allow(...)]` attribute that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllowProjection {
+    /// The value of the `reason = "..."` argument, if present.
+    pub reason: Option<String>,
+    /// The text of a `//` comment trailing the attribute on the same line.
+    pub trailing_comment: Option<String>,
+}
+
+/// Reports `#[allow(...)]` attributes without a non-empty reason.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = AllowMustHaveReasonDecision::default();
+/// let finding = decision.decide(&AllowProjection::default());
+/// assert_eq!(finding.map(|finding| finding.reason()), Some("missing"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllowMustHaveReasonDecision {
+    policy: JustificationPolicy,
+}
+
+impl AllowMustHaveReasonDecision {
+    /// Creates a decision; trailing comments justify the attribute only when
+    /// `accept_comments` is set, as on editions before 2021.
+    #[must_use]
+    pub const fn new(accept_comments: bool) -> Self {
+        Self {
+            policy: JustificationPolicy::new(accept_comments),
+        }
+    }
+
+    /// Returns whether trailing comments are accepted as justification.
+    #[must_use]
+    pub const fn accepts_comments(&self) -> bool {
+        self.policy.accepts_comments()
+    }
+}
+
+impl Default for AllowMustHaveReasonDecision {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl LintDecision for AllowMustHaveReasonDecision {
+    type Projection = AllowProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, projection: &AllowProjection) -> Option<Finding> {
+        let justification = Justification {
+            reason: projection.reason.as_deref(),
+            trailing_comment: projection.trailing_comment.as_deref(),
+        };
+        self.policy
+            .missing(justification)
+            .map(|missing| Finding::new(LINT_NAME, missing.fluent_kind()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<AllowProjection>> {
+        let attribute = |reason: Option<&str>, comment: Option<&str>| AllowProjection {
+            reason: reason.map(str::to_owned),
+            trailing_comment: comment.map(str::to_owned),
+        };
+        let commented = attribute(None, Some("generated bindings"));
+        vec![
+            DecisionFixture::clean("stated reason", attribute(Some("FFI naming"), None)),
+            DecisionFixture::flagged(
+                "no reason",
+                attribute(None, None),
+                MissingJustification::NoReason.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "blank reason",
+                attribute(Some("  "), Some("generated bindings")),
+                MissingJustification::BlankReason.fluent_kind(),
+            ),
+            if self.accepts_comments() {
+                DecisionFixture::clean("trailing comment", commented)
+            } else {
+                DecisionFixture::flagged(
+                    "trailing comment",
+                    commented,
+                    MissingJustification::NoReason.fluent_kind(),
+                )
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::edition_2021(AllowMustHaveReasonDecision::default())]
+    #[case::edition_2018(AllowMustHaveReasonDecision::new(true))]
+    fn decision_conforms(#[case] decision: AllowMustHaveReasonDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
@@ -0,0 +1,17 @@
+//! Embedded documentation for the `allow_must_have_reason` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for `allow_must_have_reason`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "allow_must_have_reason",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `#[allow(...)]` attributes, including `clippy::` and `whitaker::` tool lints, that have no `reason = \"...\"` argument.",
+    rationale: "A suppression without an explanation cannot be audited: reviewers cannot tell a false positive from a shortcut, and nobody knows when the attribute can be removed. Crates on editions before 2021 may justify the attribute with a `//` comment on the same line instead.",
+    bad_example: "#[allow(clippy::too_many_lines)]\nfn render() {}",
+    good_example: "#[allow(clippy::too_many_lines, reason = \"one arm per opcode\")]\nfn render() {}",
+    config: &[],
+};
@@ -0,0 +1,305 @@
+//! Lint pass reporting `#[allow(...)]` attributes without a justification.
+//!
+//! The pass visits every attribute in the crate, including crate-level
+//! `#![allow(...)]` attributes and those naming tool lints such as
+//! `clippy::` or `whitaker::`, and hands the `reason` argument and any
+//! trailing comment to [`JustificationPolicy`]. Attributes produced by macro
+//! expansion are skipped, since their author is not the crate being checked.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::edition::Edition;
+use rustc_span::{Span, sym};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{Justification, JustificationPolicy, MissingJustification, trailing_comment};
+
+pub(crate) const LINT_NAME: &str = "allow_must_have_reason";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub ALLOW_MUST_HAVE_REASON,
+    Warn,
+    "`#[allow(...)]` attributes should give a `reason` so suppressions stay auditable",
+    AllowMustHaveReason::default()
+}
+
+/// Lint pass checking `#[allow(...)]` attributes for a justification.
+#[derive(Default)]
+pub struct AllowMustHaveReason {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Justification policy, finding limit, and localization for the crate being
+/// checked.
+struct CrateState {
+    policy: JustificationPolicy,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl Default for CrateState {
+    fn default() -> Self {
+        Self {
+            policy: JustificationPolicy::new(false),
+            findings: FindingLimit::default(),
+            localizer: Localizer::default(),
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for AllowMustHaveReason {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            policy: JustificationPolicy::new(cx.tcx.sess.edition() < Edition::Edition2021),
+            findings: FindingLimit::new(ALLOW_MUST_HAVE_REASON, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_attribute(&mut self, cx: &LateContext<'tcx>, attr: &'tcx hir::Attribute) {
+        if !attr.has_name(sym::allow) {
+            return;
+        }
+        let span = attr.span();
+        if span.from_expansion() {
+            return;
+        }
+
+        let reason = allow_reason(attr);
+        let rest_of_line = rest_of_line(cx, span);
+        let justification = Justification {
+            reason: reason.as_deref(),
+            trailing_comment: rest_of_line.as_deref().and_then(trailing_comment),
+        };
+        let state = &*self.state;
+        let Some(missing) = state.policy.missing(justification) else {
+            return;
+        };
+
+        let attribute = cx
+            .sess()
+            .source_map()
+            .span_to_snippet(span)
+            .unwrap_or_else(|_| String::from("#[allow(...)]"));
+        debug!(target: LINT_NAME, "`{attribute}` is unjustified: {missing:?}");
+        if !state.findings.admit(cx, cx.last_node_with_lint_attrs, span) {
+            return;
+        }
+        let message = UnjustifiedAllowMessage {
+            attribute: &attribute,
+            missing,
+            accepts_comments: state.policy.accepts_comments(),
+        };
+        emit_diagnostic(cx, span, &message, &state.localizer);
+    }
+}
+
+/// Returns the value of the attribute's `reason = "..."` argument.
+fn allow_reason(attr: &hir::Attribute) -> Option<String> {
+    attr.meta_item_list()?
+        .iter()
+        .find(|item| item.has_name(sym::reason))
+        .map(|item| {
+            item.value_str()
+                .map_or_else(String::new, |value| value.to_string())
+        })
+}
+
+/// Returns the source between the end of `span` and the end of its line.
+fn rest_of_line(cx: &LateContext<'_>, span: Span) -> Option<String> {
+    let source_map = cx.sess().source_map();
+    let line = source_map.span_extend_to_next_char(span, '\n', true);
+    source_map.span_to_snippet(line.with_lo(span.hi())).ok()
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &UnjustifiedAllowMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        ALLOW_MUST_HAVE_REASON,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for an unjustified attribute interpolates.
+#[derive(Clone, Copy, Debug)]
+struct UnjustifiedAllowMessage<'a> {
+    attribute: &'a str,
+    missing: MissingJustification,
+    accepts_comments: bool,
+}
+
+impl UnjustifiedAllowMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("attribute"),
+            FluentValue::from(self.attribute.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("kind"),
+            FluentValue::from(self.missing.fluent_kind()),
+        );
+        args.insert(
+            Cow::Borrowed("comments"),
+            FluentValue::from(if self.accepts_comments {
+                "accepted"
+            } else {
+                "rejected"
+            }),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let attribute = self.attribute;
+        let primary = match self.missing {
+            MissingJustification::NoReason => {
+                format!("`{attribute}` does not say why the lint is allowed.")
+            }
+            MissingJustification::BlankReason => format!("`{attribute}` has an empty `reason`."),
+        };
+        let help = if self.accepts_comments {
+            "Add `reason = \"...\"`, or a `//` comment on the same line, explaining why the lint \
+             does not apply here."
+        } else {
+            "Add `reason = \"...\"` explaining why the lint does not apply here."
+        };
+        DiagnosticMessageSet::new(
+            primary,
+            String::from(
+                "Unexplained suppressions cannot be audited, and nobody can tell when they are \
+                 safe to remove.",
+            ),
+            String::from(help),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(
+        missing: MissingJustification,
+        accepts_comments: bool,
+    ) -> UnjustifiedAllowMessage<'static> {
+        UnjustifiedAllowMessage {
+            attribute: "#[allow(clippy::too_many_lines)]",
+            missing,
+            accepts_comments,
+        }
+    }
+
+    fn resolve(message: &UnjustifiedAllowMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    #[case(
+        MissingJustification::NoReason,
+        "`#[allow(clippy::too_many_lines)]` does not say why the lint is allowed."
+    )]
+    #[case(
+        MissingJustification::BlankReason,
+        "`#[allow(clippy::too_many_lines)]` has an empty `reason`."
+    )]
+    fn fallback_messages_quote_the_attribute(
+        #[case] missing: MissingJustification,
+        #[case] primary: &str,
+    ) {
+        assert_eq!(
+            message(missing, false).fallback_messages().primary(),
+            primary
+        );
+    }
+
+    #[rstest]
+    fn help_mentions_comments_only_when_accepted() {
+        let strict = message(MissingJustification::NoReason, false).fallback_messages();
+        let lenient = message(MissingJustification::NoReason, true).fallback_messages();
+
+        assert!(!strict.help().contains("comment"));
+        assert!(lenient.help().contains("`//` comment"));
+    }
+
+    #[rstest]
+    #[case(MissingJustification::NoReason, false)]
+    #[case(MissingJustification::NoReason, true)]
+    #[case(MissingJustification::BlankReason, false)]
+    fn english_messages_match_the_fallback(
+        #[case] missing: MissingJustification,
+        #[case] accepts_comments: bool,
+    ) {
+        let message = message(missing, accepts_comments);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(MissingJustification::BlankReason, true), locale);
+
+        assert!(
+            messages
+                .primary()
+                .contains("`#[allow(clippy::too_many_lines)]`")
+        );
+        assert!(messages.help().contains("reason"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
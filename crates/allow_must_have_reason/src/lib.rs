@@ -0,0 +1,42 @@
+//! Lint crate requiring `#[allow(...)]` attributes to say why.
+//!
+//! A suppression without an explanation is hard to audit: reviewers cannot
+//! tell whether the allowed lint was a false positive, a deliberate trade-off,
+//! or a shortcut. The lint reports every `#[allow(...)]` attribute, including
+//! tool attributes such as `#[allow(clippy::...)]` and
+//! `#[allow(whitaker::...)]`, that lacks a non-empty `reason = "..."`
+//! argument. Crates on editions before 2021 may instead justify the attribute
+//! with a `//` comment on the same line. The lint is experimental and ships
+//! behind the `experimental-allow-must-have-reason` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{AllowMustHaveReasonDecision, AllowProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn allow_must_have_reason_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
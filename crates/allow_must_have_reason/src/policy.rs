@@ -0,0 +1,145 @@
+//! Decides whether an `#[allow(...)]` attribute is justified.
+//!
+//! The driver extracts the attribute's `reason = "..."` argument and the rest
+//! of the source line that follows it, then asks
+//! [`JustificationPolicy::missing`] whether the suppression is explained. The
+//! decision is kept free of compiler types so it can be tested directly.
+
+/// Why an `#[allow(...)]` attribute is reported.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum MissingJustification {
+    /// The attribute has no `reason` argument and no accepted comment.
+    NoReason,
+    /// The attribute has a `reason` argument containing only whitespace.
+    BlankReason,
+}
+
+impl MissingJustification {
+    /// Returns the selector the Fluent messages use for this finding.
+    #[must_use]
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::NoReason => "missing",
+            Self::BlankReason => "blank",
+        }
+    }
+}
+
+/// The justification found alongside one `#[allow(...)]` attribute.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Justification<'a> {
+    /// The value of the `reason = "..."` argument, if present.
+    pub(crate) reason: Option<&'a str>,
+    /// The text of a `//` comment trailing the attribute on the same line.
+    pub(crate) trailing_comment: Option<&'a str>,
+}
+
+/// Whether trailing comments stand in for a `reason` argument.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct JustificationPolicy {
+    accept_comments: bool,
+}
+
+impl JustificationPolicy {
+    /// Creates a policy. Comments are accepted only on editions before 2021,
+    /// where crates may still target compilers without `reason` support.
+    #[must_use]
+    pub(crate) const fn new(accept_comments: bool) -> Self {
+        Self { accept_comments }
+    }
+
+    /// Returns whether trailing comments are accepted as justification.
+    #[must_use]
+    pub(crate) const fn accepts_comments(self) -> bool {
+        self.accept_comments
+    }
+
+    /// Returns why `justification` is insufficient, or `None` when the
+    /// attribute is explained.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = JustificationPolicy::new(false);
+    /// let justification = Justification { reason: Some("FFI naming"), trailing_comment: None };
+    /// assert_eq!(policy.missing(justification), None);
+    /// ```
+    #[must_use]
+    pub(crate) fn missing(self, justification: Justification<'_>) -> Option<MissingJustification> {
+        match justification.reason {
+            Some(reason) if reason.trim().is_empty() => Some(MissingJustification::BlankReason),
+            Some(_) => None,
+            None if self.accept_comments && justification.trailing_comment.is_some() => None,
+            None => Some(MissingJustification::NoReason),
+        }
+    }
+}
+
+/// Extracts the text of a `//` comment at the start of `rest_of_line`, the
+/// source that follows an attribute on its line.
+///
+/// Doc comments and comments without text are ignored, as they do not
+/// explain the suppression.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(trailing_comment(" // generated bindings"), Some("generated bindings"));
+/// assert_eq!(trailing_comment(" fn demo() {}"), None);
+/// ```
+#[must_use]
+pub(crate) fn trailing_comment(rest_of_line: &str) -> Option<&str> {
+    let comment = rest_of_line.trim_start().strip_prefix("//")?;
+    if comment.starts_with('/') || comment.starts_with('!') {
+        return None;
+    }
+    let text = comment.trim();
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Some("FFI naming"), None, false, None)]
+    #[case(None, None, false, Some(MissingJustification::NoReason))]
+    #[case(Some("  "), None, false, Some(MissingJustification::BlankReason))]
+    #[case(None, Some("FFI naming"), false, Some(MissingJustification::NoReason))]
+    #[case(None, Some("FFI naming"), true, None)]
+    #[case(
+        Some(""),
+        Some("FFI naming"),
+        true,
+        Some(MissingJustification::BlankReason)
+    )]
+    fn missing_classifies_justifications(
+        #[case] reason: Option<&str>,
+        #[case] trailing_comment: Option<&str>,
+        #[case] accept_comments: bool,
+        #[case] expected: Option<MissingJustification>,
+    ) {
+        let justification = Justification {
+            reason,
+            trailing_comment,
+        };
+
+        assert_eq!(
+            JustificationPolicy::new(accept_comments).missing(justification),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(" // generated bindings", Some("generated bindings"))]
+    #[case("//keeps FFI names", Some("keeps FFI names"))]
+    #[case(" //   ", None)]
+    #[case(" /// documentation", None)]
+    #[case(" //! documentation", None)]
+    #[case(" fn demo() {} // too late", None)]
+    #[case("", None)]
+    fn trailing_comment_reads_plain_comments(#[case] rest: &str, #[case] expected: Option<&str>) {
+        assert_eq!(trailing_comment(rest), expected);
+    }
+}
@@ -0,0 +1,101 @@
+//! Behaviour-driven coverage for the allow justification policy.
+
+use crate::policy::{Justification, JustificationPolicy, MissingJustification, trailing_comment};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct AllowWorld {
+    accept_comments: Cell<bool>,
+    reason: RefCell<Option<String>>,
+    rest_of_line: RefCell<String>,
+    result: Cell<Option<Option<MissingJustification>>>,
+}
+
+#[fixture]
+fn world() -> AllowWorld {
+    AllowWorld::default()
+}
+
+#[given("comments are accepted")]
+fn given_comments_accepted(world: &AllowWorld) {
+    world.accept_comments.set(true);
+}
+
+#[given("an allow attribute with reason {reason}")]
+fn given_reason(world: &AllowWorld, reason: String) {
+    world
+        .reason
+        .replace(Some(reason.trim_matches('"').to_owned()));
+}
+
+#[given("an allow attribute without a reason")]
+fn given_no_reason(world: &AllowWorld) {
+    world.reason.replace(None);
+}
+
+#[given("the line continues with {rest}")]
+fn given_rest_of_line(world: &AllowWorld, rest: String) {
+    world
+        .rest_of_line
+        .replace(rest.trim_matches('"').to_owned());
+}
+
+#[when("the attribute is checked")]
+fn when_checked(world: &AllowWorld) {
+    let reason = world.reason.borrow();
+    let rest_of_line = world.rest_of_line.borrow();
+    let justification = Justification {
+        reason: reason.as_deref(),
+        trailing_comment: trailing_comment(&rest_of_line),
+    };
+    let policy = JustificationPolicy::new(world.accept_comments.get());
+    world.result.set(Some(policy.missing(justification)));
+}
+
+#[then("the attribute is accepted")]
+fn then_accepted(world: &AllowWorld) {
+    assert_eq!(world.result.get(), Some(None));
+}
+
+#[then("the attribute is reported as missing a reason")]
+fn then_missing(world: &AllowWorld) {
+    assert_eq!(
+        world.result.get(),
+        Some(Some(MissingJustification::NoReason))
+    );
+}
+
+#[then("the attribute is reported as having a blank reason")]
+fn then_blank(world: &AllowWorld) {
+    assert_eq!(
+        world.result.get(),
+        Some(Some(MissingJustification::BlankReason))
+    );
+}
+
+#[scenario(path = "tests/features/allow_must_have_reason.feature", index = 0)]
+fn scenario_reason_accepted(world: AllowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/allow_must_have_reason.feature", index = 1)]
+fn scenario_missing_reason_reported(world: AllowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/allow_must_have_reason.feature", index = 2)]
+fn scenario_blank_reason_reported(world: AllowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/allow_must_have_reason.feature", index = 3)]
+fn scenario_comment_ignored_on_recent_editions(world: AllowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/allow_must_have_reason.feature", index = 4)]
+fn scenario_comment_accepted_on_older_editions(world: AllowWorld) {
+    let _ = world;
+}
@@ -0,0 +1,32 @@
+Feature: Allow attributes must have a reason
+  `#[allow(...)]` attributes are reported unless they explain the
+  suppression with a `reason` argument, or, on editions before 2021, a
+  trailing comment.
+
+  Scenario: An attribute with a reason is accepted
+    Given an allow attribute with reason "generated bindings"
+    When the attribute is checked
+    Then the attribute is accepted
+
+  Scenario: An attribute without a reason is reported
+    Given an allow attribute without a reason
+    When the attribute is checked
+    Then the attribute is reported as missing a reason
+
+  Scenario: An attribute with a blank reason is reported
+    Given an allow attribute with reason " "
+    When the attribute is checked
+    Then the attribute is reported as having a blank reason
+
+  Scenario: A trailing comment is ignored on recent editions
+    Given an allow attribute without a reason
+    And the line continues with "// generated bindings"
+    When the attribute is checked
+    Then the attribute is reported as missing a reason
+
+  Scenario: A trailing comment justifies the attribute on older editions
+    Given comments are accepted
+    And an allow attribute without a reason
+    And the line continues with "// generated bindings"
+    When the attribute is checked
+    Then the attribute is accepted
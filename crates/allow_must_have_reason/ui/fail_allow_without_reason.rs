@@ -0,0 +1,22 @@
+//! `#[allow(...)]` attributes without a `reason` are reported, whether they
+//! name rustc, `clippy::`, or `whitaker::` lints.
+#![feature(register_tool)]
+#![register_tool(whitaker)]
+#![allow(unused_imports)]
+
+#[allow(dead_code)]
+fn unused() {}
+
+#[allow(clippy::needless_return)]
+fn answer() -> u32 {
+    return 42;
+}
+
+#[allow(whitaker::no_expect_outside_tests, reason = " ")]
+fn configured() -> u32 {
+    answer()
+}
+
+fn main() {
+    println!("{}", configured());
+}
@@ -0,0 +1,19 @@
+//! `#[allow(...)]` attributes with a `reason` are accepted, and other lint
+//! level attributes are not checked.
+#![allow(unused_imports, reason = "fixtures share a prelude")]
+
+#[allow(dead_code, reason = "kept for the FFI surface")]
+fn unused() {}
+
+#[allow(
+    clippy::needless_return,
+    reason = "mirrors the generated bindings"
+)]
+fn answer() -> u32 {
+    return 42;
+}
+
+#[warn(dead_code)]
+fn main() {
+    println!("{}", answer());
+}
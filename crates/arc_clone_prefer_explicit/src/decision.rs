@@ -0,0 +1,121 @@
+//! Pure decision logic for `arc_clone_prefer_explicit`.
+//!
+//! The driver resolves the type each method-call `.clone()` clones and hands
+//! the pieces of source text to [`ArcClonePreferExplicitDecision`], which
+//! reports clones of `Arc` and `Rc` with the [`ExplicitClone`] rewrite the
+//! driver suggests. Findings record whether the rewrite can be applied
+//! without review and carry the replacement as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{ExplicitClone, SharedPointer};
+
+/// Reason key for clones whose rewrite compiles as written.
+const MACHINE_APPLICABLE: &str = "machine_applicable";
+/// Reason key for clones whose rewrite needs the pointer type imported.
+const NEEDS_IMPORT: &str = "needs_import";
+
+/// The facts about one method-call clone that the decision needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneProjection {
+    /// Name of the cloned type as the standard library declares it, such as
+    /// `Arc` or `String`.
+    pub cloned_type: String,
+    /// The name the enclosing module imports the cloned type under, if any.
+    pub imported_as: Option<String>,
+    /// Source text of the method call's receiver.
+    pub receiver: String,
+    /// Whether the receiver is already a reference to the pointer.
+    pub receiver_is_reference: bool,
+}
+
+/// Reports `.clone()` calls on `Arc` and `Rc` values.
+///
+/// # Examples
+///
+/// ```ignore
+/// let clone = CloneProjection {
+///     cloned_type: "Arc".to_owned(),
+///     imported_as: Some("Arc".to_owned()),
+///     receiver: "config".to_owned(),
+///     receiver_is_reference: false,
+/// };
+/// let finding = ArcClonePreferExplicitDecision.decide(&clone).expect("Arc clones are reported");
+/// assert_eq!(finding.subject(), Some("Arc::clone(&config)"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ArcClonePreferExplicitDecision;
+
+impl LintDecision for ArcClonePreferExplicitDecision {
+    type Projection = CloneProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, projection: &CloneProjection) -> Option<Finding> {
+        let pointer = [SharedPointer::Arc, SharedPointer::Rc]
+            .into_iter()
+            .find(|pointer| pointer.name() == projection.cloned_type)?;
+        let clone = ExplicitClone {
+            pointer,
+            imported_as: projection.imported_as.as_deref(),
+            receiver: &projection.receiver,
+            receiver_is_reference: projection.receiver_is_reference,
+        };
+        let reason = if clone.is_machine_applicable() {
+            MACHINE_APPLICABLE
+        } else {
+            NEEDS_IMPORT
+        };
+        Some(Finding::new(LINT_NAME, reason).with_subject(clone.replacement()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<CloneProjection>> {
+        let clone = |cloned_type: &str, imported_as: Option<&str>| CloneProjection {
+            cloned_type: cloned_type.to_owned(),
+            imported_as: imported_as.map(str::to_owned),
+            receiver: "self.config".to_owned(),
+            receiver_is_reference: false,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "imported Arc",
+                clone("Arc", Some("Arc")),
+                MACHINE_APPLICABLE,
+            ),
+            DecisionFixture::flagged("Rc used by path", clone("Rc", None), NEEDS_IMPORT),
+            DecisionFixture::clean("other type", clone("String", None)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::owned_receiver(false, "Rc::clone(&self.config)")]
+    #[case::reference_receiver(true, "Rc::clone(self.config)")]
+    fn findings_name_the_replacement(#[case] receiver_is_reference: bool, #[case] expected: &str) {
+        let projection = CloneProjection {
+            cloned_type: "Rc".to_owned(),
+            imported_as: Some("Rc".to_owned()),
+            receiver: "self.config".to_owned(),
+            receiver_is_reference,
+        };
+        let finding = ArcClonePreferExplicitDecision
+            .decide(&projection)
+            .expect("Rc clones should be reported");
+
+        assert_eq!(finding.subject(), Some(expected));
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&ArcClonePreferExplicitDecision), Ok(3));
+    }
+}
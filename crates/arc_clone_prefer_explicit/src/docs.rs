@@ -0,0 +1,17 @@
+//! Embedded documentation for the `arc_clone_prefer_explicit` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for `arc_clone_prefer_explicit`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "arc_clone_prefer_explicit",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `.clone()` calls on `Arc` and `Rc` values and suggests `Arc::clone(&value)` or `Rc::clone(&value)`. Allow-by-default.",
+    rationale: "The method form reads like a deep copy of the shared value, although it only increments a reference count. The associated-function form makes the cheap pointer copy explicit, so expensive clones stand out in review.",
+    bad_example: "let worker_config = config.clone();",
+    good_example: "let worker_config = Arc::clone(&config);",
+    config: &[],
+};
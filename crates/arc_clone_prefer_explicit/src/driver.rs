@@ -0,0 +1,306 @@
+//! Lint pass reporting `.clone()` calls on `Arc` and `Rc`.
+//!
+//! Each method call resolving to `Clone::clone` whose `Self` type is an `Arc`
+//! or `Rc` is reported, including calls through a reference to the pointer.
+//! Calls produced by macro expansion are skipped. The suggested rewrite calls
+//! `clone` through the name the enclosing module imports the pointer type
+//! under, and is machine-applicable only when such an import exists.
+
+use std::borrow::Cow;
+
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_lint::errors::Applicability;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty;
+use rustc_span::{Span, Symbol, sym};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{ExplicitClone, SharedPointer};
+
+pub(crate) const LINT_NAME: &str = "arc_clone_prefer_explicit";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub ARC_CLONE_PREFER_EXPLICIT,
+    Allow,
+    "`Arc` and `Rc` values should be cloned with `Arc::clone(&value)` rather than `.clone()`",
+    ArcClonePreferExplicit::default()
+}
+
+/// Lint pass that finds method-call clones of reference-counted pointers.
+#[derive(Default)]
+pub struct ArcClonePreferExplicit {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Finding limit and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ArcClonePreferExplicit {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            findings: FindingLimit::new(ARC_CLONE_PREFER_EXPLICIT, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::MethodCall(_, receiver, [], _) = expr.kind else {
+            return;
+        };
+        if expr.span.from_expansion() {
+            return;
+        }
+        let typeck = cx.typeck_results();
+        let Some(method) = typeck.type_dependent_def_id(expr.hir_id) else {
+            return;
+        };
+        if Some(method) != cx.tcx.lang_items().clone_fn() {
+            return;
+        }
+        let Some((pointer, pointer_def_id)) =
+            shared_pointer(cx, typeck.node_args(expr.hir_id).type_at(0))
+        else {
+            return;
+        };
+        let Ok(receiver_text) = cx.sess().source_map().span_to_snippet(receiver.span) else {
+            return;
+        };
+
+        let imported_as = imported_name(cx, expr.hir_id, pointer_def_id);
+        let clone = ExplicitClone {
+            pointer,
+            imported_as: imported_as.as_ref().map(Symbol::as_str),
+            receiver: &receiver_text,
+            receiver_is_reference: typeck.expr_ty(receiver).is_ref(),
+        };
+        let state = &*self.state;
+        if state.findings.admit(cx, expr.hir_id, expr.span) {
+            emit_diagnostic(cx, expr.span, &clone, &state.localizer);
+        }
+    }
+}
+
+/// Returns the pointer kind and definition when `ty` is an `Arc` or `Rc`.
+fn shared_pointer(cx: &LateContext<'_>, ty: ty::Ty<'_>) -> Option<(SharedPointer, DefId)> {
+    let ty::Adt(adt, _) = ty.kind() else {
+        return None;
+    };
+    let did = adt.did();
+    if cx.tcx.is_diagnostic_item(sym::Arc, did) {
+        Some((SharedPointer::Arc, did))
+    } else if cx.tcx.is_diagnostic_item(sym::Rc, did) {
+        Some((SharedPointer::Rc, did))
+    } else {
+        None
+    }
+}
+
+/// Returns the name under which the module enclosing `hir_id` imports
+/// `def_id` with a `use` item of its own.
+fn imported_name(cx: &LateContext<'_>, hir_id: hir::HirId, def_id: DefId) -> Option<Symbol> {
+    let module = cx.tcx.parent_module(hir_id);
+    cx.tcx.hir_module_free_items(module).find_map(|item_id| {
+        if cx.tcx.local_parent(item_id.owner_id.def_id) != module.to_local_def_id() {
+            return None;
+        }
+        match cx.tcx.hir_item(item_id).kind {
+            hir::ItemKind::Use(path, hir::UseKind::Single(ident))
+                if path.res.type_ns == Some(Res::Def(cx.tcx.def_kind(def_id), def_id)) =>
+            {
+                Some(ident.name)
+            }
+            _ => None,
+        }
+    })
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    clone: &ExplicitClone<'_>,
+    localizer: &Localizer,
+) {
+    let replacement = clone.replacement();
+    let message = ExplicitCloneMessage {
+        pointer: clone.pointer.name(),
+        receiver: clone.receiver,
+        replacement: &replacement,
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let suggestion = message.suggestion(localizer);
+    let applicability = if clone.is_machine_applicable() {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+
+    cx.emit_span_lint(
+        ARC_CLONE_PREFER_EXPLICIT,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+            lint.span_suggestion(span, suggestion, replacement, applicability);
+        }),
+    );
+}
+
+/// The values the diagnostic for a method-call clone interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ExplicitCloneMessage<'a> {
+    pointer: &'a str,
+    receiver: &'a str,
+    replacement: &'a str,
+}
+
+impl ExplicitCloneMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("pointer"),
+            FluentValue::from(self.pointer.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("receiver"),
+            FluentValue::from(self.receiver.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("replacement"),
+            FluentValue::from(self.replacement.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            pointer,
+            receiver,
+            replacement,
+        } = *self;
+        DiagnosticMessageSet::new(
+            format!("Clone of an `{pointer}` written as `{receiver}.clone()`."),
+            format!(
+                "`{receiver}.clone()` reads like a copy of the shared value, but it only \
+                 increments the `{pointer}` reference count."
+            ),
+            format!("Write `{replacement}` so the cheap pointer copy is explicit."),
+        )
+    }
+
+    fn fallback_suggestion(&self) -> String {
+        format!("call `{}::clone` explicitly", self.pointer)
+    }
+
+    /// Resolves the label of the suggested rewrite. Fluent may wrap
+    /// arguments in directional isolates, which are stripped to keep
+    /// suggestion text stable.
+    fn suggestion(&self, localizer: &Localizer) -> String {
+        localizer
+            .attribute_with_args(LINT_NAME, "suggestion", &self.args())
+            .unwrap_or_else(|_| self.fallback_suggestion())
+            .chars()
+            .filter(|ch| !matches!(ch, '\u{2068}' | '\u{2069}' | '\u{FFFD}'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message() -> ExplicitCloneMessage<'static> {
+        ExplicitCloneMessage {
+            pointer: "Arc",
+            receiver: "self.config",
+            replacement: "Arc::clone(&self.config)",
+        }
+    }
+
+    fn resolve(message: &ExplicitCloneMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn fallback_messages_show_the_rewrite() {
+        let messages = message().fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Clone of an `Arc` written as `self.config.clone()`."
+        );
+        assert!(messages.help().contains("`Arc::clone(&self.config)`"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        let message = message();
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+        assert_eq!(
+            message.suggestion(&Localizer::new(Some("en-GB"))),
+            message.fallback_suggestion()
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let message = message();
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`self.config.clone()`"));
+        assert!(messages.help().contains("`Arc::clone(&self.config)`"));
+        assert_ne!(
+            message.suggestion(&Localizer::new(Some(locale))),
+            message.fallback_suggestion()
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,42 @@
+//! Lint crate flagging method-call clones of reference-counted pointers.
+//!
+//! `shared.clone()` on an `Arc` or `Rc` reads like a deep copy of the value
+//! behind the pointer, yet it only increments a reference count. Writing
+//! `Arc::clone(&shared)` makes the cheap pointer copy obvious at the call
+//! site and keeps genuine deep copies easy to spot. The lint is
+//! allow-by-default, since many codebases accept the method form; teams
+//! enable it with `warn` while migrating and `deny` once the crate is clean.
+//! It offers a rewrite to the explicit form, is experimental, and ships
+//! behind the `experimental-arc-clone-prefer-explicit` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ArcClonePreferExplicitDecision, CloneProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn arc_clone_prefer_explicit_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,123 @@
+//! Rewrite rules for explicit reference-count clones.
+//!
+//! The driver identifies `.clone()` calls whose `Self` type is an `Arc` or
+//! `Rc` and hands the pieces of source text to [`ExplicitClone`], which
+//! builds the replacement and decides whether it can be applied without
+//! review. The rules are kept free of compiler types so they can be tested
+//! directly.
+
+/// The reference-counted pointer being cloned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SharedPointer {
+    /// `std::sync::Arc`.
+    Arc,
+    /// `std::rc::Rc`.
+    Rc,
+}
+
+impl SharedPointer {
+    /// Returns the type's name as the standard library declares it.
+    #[must_use]
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Arc => "Arc",
+            Self::Rc => "Rc",
+        }
+    }
+}
+
+/// The explicit form of one `.clone()` call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ExplicitClone<'a> {
+    /// The cloned pointer type.
+    pub(crate) pointer: SharedPointer,
+    /// The name the enclosing module imports the pointer type under, if any.
+    pub(crate) imported_as: Option<&'a str>,
+    /// Source text of the method call's receiver.
+    pub(crate) receiver: &'a str,
+    /// `true` when the receiver is already a reference to the pointer.
+    pub(crate) receiver_is_reference: bool,
+}
+
+impl ExplicitClone<'_> {
+    /// Returns the path the rewrite calls `clone` through: the imported name
+    /// when there is one, otherwise the type's own name.
+    #[must_use]
+    pub(crate) fn path(&self) -> &str {
+        self.imported_as.unwrap_or(self.pointer.name())
+    }
+
+    /// Returns the replacement call.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let clone = ExplicitClone {
+    ///     pointer: SharedPointer::Arc,
+    ///     imported_as: Some("Arc"),
+    ///     receiver: "self.config",
+    ///     receiver_is_reference: false,
+    /// };
+    /// assert_eq!(clone.replacement(), "Arc::clone(&self.config)");
+    /// ```
+    #[must_use]
+    pub(crate) fn replacement(&self) -> String {
+        let borrow = if self.receiver_is_reference { "" } else { "&" };
+        format!("{}::clone({borrow}{})", self.path(), self.receiver)
+    }
+
+    /// Returns `true` when the rewrite compiles as written, which requires
+    /// the pointer type to be in scope under the name it uses.
+    #[must_use]
+    pub(crate) const fn is_machine_applicable(&self) -> bool {
+        self.imported_as.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn clone<'a>(imported_as: Option<&'a str>, receiver_is_reference: bool) -> ExplicitClone<'a> {
+        ExplicitClone {
+            pointer: SharedPointer::Arc,
+            imported_as,
+            receiver: "shared",
+            receiver_is_reference,
+        }
+    }
+
+    #[rstest]
+    #[case(Some("Arc"), false, "Arc::clone(&shared)")]
+    #[case(Some("Arc"), true, "Arc::clone(shared)")]
+    #[case(Some("Shared"), false, "Shared::clone(&shared)")]
+    #[case(None, false, "Arc::clone(&shared)")]
+    fn replacement_uses_the_imported_name(
+        #[case] imported_as: Option<&str>,
+        #[case] receiver_is_reference: bool,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            clone(imported_as, receiver_is_reference).replacement(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Some("Arc"), true)]
+    #[case(None, false)]
+    fn rewrite_needs_the_type_in_scope(#[case] imported_as: Option<&str>, #[case] expected: bool) {
+        assert_eq!(clone(imported_as, false).is_machine_applicable(), expected);
+    }
+
+    #[rstest]
+    fn rc_uses_its_own_name() {
+        let clone = ExplicitClone {
+            pointer: SharedPointer::Rc,
+            ..clone(None, false)
+        };
+
+        assert_eq!(clone.replacement(), "Rc::clone(&shared)");
+    }
+}
@@ -0,0 +1,104 @@
+//! Behaviour-driven coverage for the explicit clone rewrite.
+
+use crate::policy::{ExplicitClone, SharedPointer};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct CloneWorld {
+    pointer: Cell<SharedPointer>,
+    receiver: RefCell<String>,
+    imported_as: RefCell<Option<String>>,
+    receiver_is_reference: Cell<bool>,
+    replacement: RefCell<String>,
+    applicable: Cell<Option<bool>>,
+}
+
+impl Default for CloneWorld {
+    fn default() -> Self {
+        Self {
+            pointer: Cell::new(SharedPointer::Arc),
+            receiver: RefCell::default(),
+            imported_as: RefCell::default(),
+            receiver_is_reference: Cell::new(false),
+            replacement: RefCell::default(),
+            applicable: Cell::new(None),
+        }
+    }
+}
+
+#[fixture]
+fn world() -> CloneWorld {
+    CloneWorld::default()
+}
+
+#[given("an Arc receiver named {name}")]
+fn given_arc(world: &CloneWorld, name: String) {
+    world.pointer.set(SharedPointer::Arc);
+    *world.receiver.borrow_mut() = name.trim_matches('"').to_owned();
+}
+
+#[given("an Rc receiver named {name}")]
+fn given_rc(world: &CloneWorld, name: String) {
+    world.pointer.set(SharedPointer::Rc);
+    *world.receiver.borrow_mut() = name.trim_matches('"').to_owned();
+}
+
+#[given("the receiver is already a reference")]
+fn given_reference(world: &CloneWorld) {
+    world.receiver_is_reference.set(true);
+}
+
+#[given("the module imports the type as {name}")]
+fn given_import(world: &CloneWorld, name: String) {
+    *world.imported_as.borrow_mut() = Some(name.trim_matches('"').to_owned());
+}
+
+#[when("the explicit clone is built")]
+fn when_built(world: &CloneWorld) {
+    let receiver = world.receiver.borrow();
+    let imported_as = world.imported_as.borrow();
+    let clone = ExplicitClone {
+        pointer: world.pointer.get(),
+        imported_as: imported_as.as_deref(),
+        receiver: &receiver,
+        receiver_is_reference: world.receiver_is_reference.get(),
+    };
+    *world.replacement.borrow_mut() = clone.replacement();
+    world.applicable.set(Some(clone.is_machine_applicable()));
+}
+
+#[then("the replacement is {expected}")]
+fn then_replacement(world: &CloneWorld, expected: String) {
+    assert_eq!(*world.replacement.borrow(), expected.trim_matches('"'));
+}
+
+#[then("the suggestion is machine-applicable")]
+fn then_applicable(world: &CloneWorld) {
+    assert_eq!(world.applicable.get(), Some(true));
+}
+
+#[then("the suggestion needs review")]
+fn then_needs_review(world: &CloneWorld) {
+    assert_eq!(world.applicable.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/arc_clone_prefer_explicit.feature", index = 0)]
+fn scenario_imported_arc(world: CloneWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/arc_clone_prefer_explicit.feature", index = 1)]
+fn scenario_reference_receiver(world: CloneWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/arc_clone_prefer_explicit.feature", index = 2)]
+fn scenario_renamed_import(world: CloneWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/arc_clone_prefer_explicit.feature", index = 3)]
+fn scenario_missing_import(world: CloneWorld) {
+    let _ = world;
+}
@@ -0,0 +1,30 @@
+Feature: Rewriting reference-count clones explicitly
+  A `.clone()` call on an `Arc` or `Rc` is rewritten to call `clone`
+  through the pointer type, using the name the module imports it under.
+  The rewrite is machine-applicable only when that import exists.
+
+  Scenario: An imported Arc is cloned through its import
+    Given an Arc receiver named "config"
+    And the module imports the type as "Arc"
+    When the explicit clone is built
+    Then the replacement is "Arc::clone(&config)"
+    And the suggestion is machine-applicable
+
+  Scenario: A reference receiver is not borrowed again
+    Given an Arc receiver named "config"
+    And the receiver is already a reference
+    And the module imports the type as "Arc"
+    When the explicit clone is built
+    Then the replacement is "Arc::clone(config)"
+
+  Scenario: A renamed import is used in the rewrite
+    Given an Rc receiver named "node"
+    And the module imports the type as "Shared"
+    When the explicit clone is built
+    Then the replacement is "Shared::clone(&node)"
+
+  Scenario: A type that is not imported needs review
+    Given an Rc receiver named "node"
+    When the explicit clone is built
+    Then the replacement is "Rc::clone(&node)"
+    And the suggestion needs review
@@ -0,0 +1,26 @@
+#![warn(arc_clone_prefer_explicit)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct Service {
+    config: Arc<String>,
+}
+
+impl Service {
+    fn config(&self) -> Arc<String> {
+        self.config.clone()
+    }
+}
+
+fn share(node: &Rc<Vec<u8>>) -> Rc<Vec<u8>> {
+    node.clone()
+}
+
+fn main() {
+    let service = Service {
+        config: Arc::new(String::from("whitaker")),
+    };
+    let _config = service.config();
+    let _node = share(&Rc::new(vec![1, 2, 3]));
+}
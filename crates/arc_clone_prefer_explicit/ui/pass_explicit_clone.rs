@@ -0,0 +1,21 @@
+#![warn(arc_clone_prefer_explicit)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+struct Settings {
+    name: String,
+}
+
+fn main() {
+    let config = Arc::new(Settings {
+        name: String::from("whitaker"),
+    });
+    let _shared = Arc::clone(&config);
+    let _deep = config.as_ref().clone();
+    let _name = config.name.clone();
+
+    let node = Rc::new(vec![1, 2, 3]);
+    let _node = Rc::clone(&node);
+}
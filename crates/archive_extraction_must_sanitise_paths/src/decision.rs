@@ -0,0 +1,96 @@
+//! Pure decision logic for `archive_extraction_must_sanitise_paths`.
+//!
+//! The driver records the entry-path joins and containment checks of each
+//! function body in a [`BodyRecord`].
+//! [`ArchiveExtractionMustSanitisePathsDecision`] replays a body's facts
+//! through the same record and reports the first join left unchecked, naming
+//! it as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::BodyRecord;
+
+/// Reason key for joins in bodies without a containment check.
+const UNCHECKED_JOIN: &str = "unchecked_join";
+
+/// The facts about one function body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BodyProjection {
+    /// Source text of each join of an archive entry path, in body order.
+    pub joins: Vec<String>,
+    /// Whether the body canonicalises a path or checks its containment.
+    pub checked: bool,
+}
+
+/// Reports archive entry paths joined in bodies that never check them.
+///
+/// # Examples
+///
+/// ```ignore
+/// let body = BodyProjection { joins: vec!["out.join(path)".to_owned()], checked: false };
+/// let finding = ArchiveExtractionMustSanitisePathsDecision.decide(&body);
+/// assert!(finding.is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ArchiveExtractionMustSanitisePathsDecision;
+
+impl LintDecision for ArchiveExtractionMustSanitisePathsDecision {
+    type Projection = BodyProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, body: &BodyProjection) -> Option<Finding> {
+        let mut record = BodyRecord::default();
+        for join in &body.joins {
+            record.record_join(join.as_str());
+        }
+        if body.checked {
+            record.record_check();
+        }
+        let join = record.into_unchecked_joins().into_iter().next()?;
+        Some(Finding::new(LINT_NAME, UNCHECKED_JOIN).with_subject(join))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<BodyProjection>> {
+        let body = |checked| BodyProjection {
+            joins: vec!["out.join(entry.path()?)".to_owned()],
+            checked,
+        };
+        vec![
+            DecisionFixture::flagged("join without a check", body(false), UNCHECKED_JOIN),
+            DecisionFixture::clean("join with a containment check", body(true)),
+            DecisionFixture::clean("no joins", BodyProjection::default()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_first_join() {
+        let body = BodyProjection {
+            joins: vec!["out.join(first)".to_owned(), "out.join(second)".to_owned()],
+            checked: false,
+        };
+        let finding = ArchiveExtractionMustSanitisePathsDecision
+            .decide(&body)
+            .expect("unchecked joins should be reported");
+
+        assert_eq!(finding.subject(), Some("out.join(first)"));
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&ArchiveExtractionMustSanitisePathsDecision),
+            Ok(3)
+        );
+    }
+}
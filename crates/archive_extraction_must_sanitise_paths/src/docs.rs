@@ -0,0 +1,35 @@
+//! Embedded documentation for the `archive_extraction_must_sanitise_paths`
+//! lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `archive_extraction_must_sanitise_paths`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "archive_extraction_must_sanitise_paths",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags tar and zip entry paths joined onto a directory in a function that never checks the result for containment. Allow-by-default.",
+    rationale: "An archive decides its own entry names, so an entry called `../../.bashrc` or `/etc/cron.d/job` escapes the output directory when joined onto it. Rejecting `..`, root, and prefix components, or canonicalising the target and checking it `starts_with` the output directory, closes this zip-slip hole.",
+    bad_example: "let target = output_dir.join(entry.path()?);\nentry.unpack(&target)?;",
+    good_example: "let path = entry.path()?.into_owned();\nif path.components().any(|c| !matches!(c, Component::Normal(_))) {\n    return Err(Error::UnsafeEntry(path));\n}\nentry.unpack(output_dir.join(&path))?;",
+    config: &[
+        ConfigKeyDocs {
+            key: "entry_path_methods",
+            default: "[\"tar::Entry::path\", \"tar::Header::path\", \"zip::read::ZipFile::name\"]",
+            description: "Fully qualified methods that return the path stored in an archive entry.",
+        },
+        ConfigKeyDocs {
+            key: "join_methods",
+            default: "[\"std::path::Path::join\", \"std::path::PathBuf::push\", \"camino::Utf8Path::join\", \"camino::Utf8PathBuf::push\"]",
+            description: "Fully qualified methods that place an entry path under an output directory.",
+        },
+        ConfigKeyDocs {
+            key: "checks",
+            default: "[\"std::fs::canonicalize\", \"std::path::Path::canonicalize\", \"std::path::Path::components\", \"std::path::Path::starts_with\", \"camino::Utf8Path::canonicalize\", \"camino::Utf8Path::components\", \"camino::Utf8Path::starts_with\"]",
+            description: "Fully qualified functions or methods whose use in a function counts as a containment check; add project helpers such as `my_crate::extract::validate_entry_path`.",
+        },
+    ],
+};
@@ -0,0 +1,356 @@
+//! Lint pass reporting archive entry paths joined without a containment
+//! check.
+//!
+//! Each function body gets a [`BodyRecord`]. Method calls that resolve to one
+//! of the configured join methods are recorded when their argument traces
+//! back to a configured entry-path method, and calls to any configured check,
+//! such as `canonicalize` or `starts_with`, mark the body as checked. When the
+//! body ends, its joins are reported unless it was checked. Closures share
+//! the record of the function that contains them, so a check made outside an
+//! `entries().for_each(..)` closure covers the joins inside it.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::BodyOwnerKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{Span, Symbol};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::BodyRecord;
+use crate::taint::{callee_path, entry_path_source};
+
+pub(crate) const LINT_NAME: &str = "archive_extraction_must_sanitise_paths";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const DEFAULT_ENTRY_PATH_METHODS: &[&str] = &[
+    "tar::Entry::path",
+    "tar::Header::path",
+    "zip::read::ZipFile::name",
+];
+const DEFAULT_JOIN_METHODS: &[&str] = &[
+    "std::path::Path::join",
+    "std::path::PathBuf::push",
+    "camino::Utf8Path::join",
+    "camino::Utf8PathBuf::push",
+];
+const DEFAULT_CHECKS: &[&str] = &[
+    "std::fs::canonicalize",
+    "std::path::Path::canonicalize",
+    "std::path::Path::components",
+    "std::path::Path::starts_with",
+    "camino::Utf8Path::canonicalize",
+    "camino::Utf8Path::components",
+    "camino::Utf8Path::starts_with",
+];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    entry_path_methods: Vec<String>,
+    join_methods: Vec<String>,
+    checks: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            entry_path_methods: owned(DEFAULT_ENTRY_PATH_METHODS),
+            join_methods: owned(DEFAULT_JOIN_METHODS),
+            checks: owned(DEFAULT_CHECKS),
+        }
+    }
+}
+
+fn owned(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(|&path| path.to_owned()).collect()
+}
+
+/// Lint pass that tracks entry-path joins and containment checks per body.
+#[derive(Default)]
+pub struct ArchiveExtractionMustSanitisePaths {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configuration, open function bodies, finding limit, and localization for
+/// the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    bodies: Vec<BodyRecord<Join>>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// A join whose argument came from an archive entry.
+#[derive(Clone, Copy, Debug)]
+struct Join {
+    hir_id: hir::HirId,
+    span: Span,
+    method: Symbol,
+    source_span: Span,
+    source: Symbol,
+}
+
+dylint_linting::impl_late_lint! {
+    pub ARCHIVE_EXTRACTION_MUST_SANITISE_PATHS,
+    Allow,
+    "archive entry paths joined onto a directory without a containment check",
+    ArchiveExtractionMustSanitisePaths::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for ArchiveExtractionMustSanitisePaths {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            bodies: Vec::new(),
+            findings: FindingLimit::new(ARCHIVE_EXTRACTION_MUST_SANITISE_PATHS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    // Closures are checked together with the function that contains them.
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if !matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            self.state.bodies.push(BodyRecord::default());
+        }
+    }
+
+    fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            return;
+        }
+        let Some(record) = self.state.bodies.pop() else {
+            return;
+        };
+        for join in record.into_unchecked_joins() {
+            if self.state.findings.admit(cx, join.hir_id, join.span) {
+                emit_diagnostic(cx, &join, &self.state.localizer);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let Some(path) = callee_path(cx, expr) else {
+            return;
+        };
+        let state = &mut *self.state;
+        let Some(record) = state.bodies.last_mut() else {
+            return;
+        };
+        if state.config.checks.contains(&path) {
+            debug!(target: LINT_NAME, "`{path}` checks containment");
+            record.record_check();
+            return;
+        }
+        if expr.span.from_expansion() || !state.config.join_methods.contains(&path) {
+            return;
+        }
+        let hir::ExprKind::MethodCall(segment, _, [component], _) = expr.kind else {
+            return;
+        };
+        let Some(source) = entry_path_source(cx, component, &state.config.entry_path_methods)
+        else {
+            return;
+        };
+        let hir::ExprKind::MethodCall(source_segment, ..) = source.kind else {
+            return;
+        };
+
+        debug!(target: LINT_NAME, "`{path}` joins an archive entry path");
+        record.record_join(Join {
+            hir_id: expr.hir_id,
+            span: expr.span,
+            method: segment.ident.name,
+            source_span: source.span,
+            source: source_segment.ident.name,
+        });
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, join: &Join, localizer: &Localizer) {
+    let message = JoinMessage {
+        method: join.method.as_str(),
+        source: join.source.as_str(),
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let source_span = join.source_span;
+
+    cx.emit_span_lint(
+        ARCHIVE_EXTRACTION_MUST_SANITISE_PATHS,
+        join.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(source_span, note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for an unchecked join interpolates.
+#[derive(Clone, Copy, Debug)]
+struct JoinMessage<'a> {
+    method: &'a str,
+    source: &'a str,
+}
+
+impl JoinMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("method"),
+            FluentValue::from(self.method.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("source"),
+            FluentValue::from(self.source.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { method, source } = *self;
+        DiagnosticMessageSet::new(
+            format!("Archive entry path passed to `{method}` without a containment check."),
+            format!(
+                "`{source}` returns the path stored in the archive, which may contain `..` or \
+                 start at the filesystem root, so `{method}` can point outside the output \
+                 directory."
+            ),
+            String::from(
+                "Reject entries whose path has `..`, root, or prefix components, or \
+                 canonicalise the joined path and check that it `starts_with` the output \
+                 directory.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: JoinMessage<'static> = JoinMessage {
+        method: "join",
+        source: "path",
+    };
+
+    fn resolve(message: &JoinMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_covers_tar_zip_std_and_camino() {
+        let config = Config::default();
+
+        assert!(
+            config
+                .entry_path_methods
+                .contains(&"tar::Entry::path".to_owned())
+        );
+        assert!(
+            config
+                .entry_path_methods
+                .contains(&"zip::read::ZipFile::name".to_owned())
+        );
+        assert!(
+            config
+                .join_methods
+                .contains(&"std::path::PathBuf::push".to_owned())
+        );
+        assert!(
+            config
+                .checks
+                .contains(&"camino::Utf8Path::starts_with".to_owned())
+        );
+    }
+
+    #[rstest]
+    fn partial_config_keeps_other_defaults() {
+        let config: Config = toml::from_str("checks = [\"demo::validate\"]")
+            .expect("partial configuration should parse");
+
+        assert_eq!(config.checks, ["demo::validate"]);
+        assert_eq!(config.join_methods, Config::default().join_methods);
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_method_and_source() {
+        let messages = MESSAGE.fallback_messages();
+
+        assert!(messages.primary().contains("`join`"));
+        assert!(messages.note().starts_with("`path` returns"));
+        assert!(messages.help().contains("`starts_with`"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`join`"));
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
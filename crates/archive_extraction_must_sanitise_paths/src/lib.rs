@@ -0,0 +1,45 @@
+//! Lint crate flagging archive entry paths used without a containment check.
+//!
+//! The path stored in a tar or zip entry is chosen by whoever built the
+//! archive. Joining it onto an output directory without checking it lets an
+//! entry named `../../.ssh/authorized_keys` or `/etc/cron.d/job` write outside
+//! that directory, the attack known as zip slip. The lint reports entry paths
+//! joined onto another path in a function that never canonicalises or
+//! otherwise checks the result for containment. The lint is experimental and
+//! ships behind the `experimental-archive-extraction-must-sanitise-paths`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+mod taint;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ArchiveExtractionMustSanitisePathsDecision, BodyProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn archive_extraction_must_sanitise_paths_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
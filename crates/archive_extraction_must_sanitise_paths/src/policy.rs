@@ -0,0 +1,129 @@
+//! Per-function bookkeeping and path normalisation for the lint.
+//!
+//! The driver records every join of an archive entry path and every
+//! containment check it meets while walking a function body, then asks the
+//! [`BodyRecord`] which joins to report once the body is finished. Keeping
+//! the decision here, free of compiler types, lets it be tested directly.
+
+/// The entry-path joins and containment checks seen in one function body.
+#[derive(Debug)]
+pub(crate) struct BodyRecord<J> {
+    joins: Vec<J>,
+    checked: bool,
+}
+
+impl<J> Default for BodyRecord<J> {
+    fn default() -> Self {
+        Self {
+            joins: Vec::new(),
+            checked: false,
+        }
+    }
+}
+
+impl<J> BodyRecord<J> {
+    /// Records a join of an archive entry path onto another path.
+    pub(crate) fn record_join(&mut self, join: J) {
+        self.joins.push(join);
+    }
+
+    /// Records a canonicalisation or containment check.
+    pub(crate) fn record_check(&mut self) {
+        self.checked = true;
+    }
+
+    /// Returns the joins to report: all of them when the body performs no
+    /// check, and none otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut record = BodyRecord::default();
+    /// record.record_join("out.join(path)");
+    /// assert_eq!(record.into_unchecked_joins(), ["out.join(path)"]);
+    /// ```
+    #[must_use]
+    pub(crate) fn into_unchecked_joins(self) -> Vec<J> {
+        if self.checked { Vec::new() } else { self.joins }
+    }
+}
+
+/// Removes generic arguments from a resolved item path, so
+/// `tar::Entry::<'a, R>::path` compares equal to the configured
+/// `tar::Entry::path`.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(strip_generic_args("tar::Entry::<'_, R>::path"), "tar::Entry::path");
+/// ```
+#[must_use]
+pub(crate) fn strip_generic_args(path: &str) -> String {
+    let mut stripped = String::with_capacity(path.len());
+    let mut depth = 0_usize;
+    for character in path.chars() {
+        match character {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => stripped.push(character),
+            _ => {}
+        }
+    }
+    stripped
+        .split("::")
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::plain("tar::Entry::path", "tar::Entry::path")]
+    #[case::turbofish("tar::Entry::<'_, R>::path", "tar::Entry::path")]
+    #[case::nested(
+        "zip::read::ZipFile::<'a, Box<dyn Read>>::name",
+        "zip::read::ZipFile::name"
+    )]
+    #[case::trailing("std::fs::canonicalize::<&Path>", "std::fs::canonicalize")]
+    fn strip_generic_args_keeps_the_item_path(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(strip_generic_args(path), expected);
+    }
+
+    #[rstest]
+    fn unchecked_bodies_report_every_join() {
+        let mut record = BodyRecord::default();
+        record.record_join(1);
+        record.record_join(2);
+
+        assert_eq!(record.into_unchecked_joins(), [1, 2]);
+    }
+
+    #[rstest]
+    #[case::check_first(true)]
+    #[case::check_last(false)]
+    fn a_check_anywhere_in_the_body_suppresses_joins(#[case] check_first: bool) {
+        let mut record = BodyRecord::default();
+        if check_first {
+            record.record_check();
+        }
+        record.record_join(1);
+        if !check_first {
+            record.record_check();
+        }
+
+        assert!(record.into_unchecked_joins().is_empty());
+    }
+
+    #[rstest]
+    fn bodies_without_joins_report_nothing() {
+        assert!(
+            BodyRecord::<u8>::default()
+                .into_unchecked_joins()
+                .is_empty()
+        );
+    }
+}
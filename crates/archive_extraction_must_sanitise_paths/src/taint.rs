@@ -0,0 +1,145 @@
+//! Tracing a joined path back to an archive entry.
+//!
+//! A join argument is traced through borrows, the `?` operator, immutable
+//! `let` bindings, standard `new` and `from` conversions, and methods that
+//! hand their receiver's path back unchanged, such as `into_owned` or
+//! `unwrap`. The trace ends at a call to one of the configured entry-path
+//! methods, or gives up on anything else. Methods that narrow the path, such
+//! as `file_name`, end the trace, because their result cannot climb out of
+//! the output directory.
+
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use crate::policy::strip_generic_args;
+
+/// Bounds how many bindings and conversions an argument is traced through.
+const MAX_TRACE_DEPTH: usize = 8;
+
+/// Methods that return their receiver's path, or a view of it, unchanged.
+const PATH_PRESERVING_METHODS: &[&str] = &[
+    "as_os_str",
+    "as_path",
+    "as_ref",
+    "borrow",
+    "clone",
+    "expect",
+    "into_owned",
+    "to_owned",
+    "to_path_buf",
+    "to_str",
+    "to_string",
+    "to_string_lossy",
+    "unwrap",
+];
+
+/// Returns the path of `def_id` without generic arguments, qualified with
+/// the crate name when the item is local.
+pub(crate) fn item_path(cx: &LateContext<'_>, def_id: DefId) -> String {
+    let path = strip_generic_args(&cx.tcx.def_path_str(def_id));
+    if def_id.is_local() {
+        format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+    } else {
+        path
+    }
+}
+
+/// Returns the path of the function or method `expr` calls.
+pub(crate) fn callee_path(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<String> {
+    let def_id = match expr.kind {
+        hir::ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+        hir::ExprKind::Call(callee, _) => match callee.kind {
+            hir::ExprKind::Path(ref qpath) => cx.qpath_res(qpath, callee.hir_id).opt_def_id(),
+            _ => None,
+        },
+        _ => None,
+    }?;
+    Some(item_path(cx, def_id))
+}
+
+/// Returns the call to one of `sources` that `expr` takes its path from.
+pub(crate) fn entry_path_source<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    sources: &[String],
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    trace(cx, expr, sources, 0)
+}
+
+fn trace<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    sources: &[String],
+    depth: usize,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    if depth > MAX_TRACE_DEPTH {
+        return None;
+    }
+    let next = depth + 1;
+    match expr.kind {
+        hir::ExprKind::AddrOf(_, _, inner) | hir::ExprKind::DropTemps(inner) => {
+            trace(cx, inner, sources, next)
+        }
+        hir::ExprKind::Match(scrutinee, _, hir::MatchSource::TryDesugar(_)) => {
+            match scrutinee.kind {
+                hir::ExprKind::Call(_, [inner]) => trace(cx, inner, sources, next),
+                _ => None,
+            }
+        }
+        hir::ExprKind::MethodCall(segment, receiver, _, _) => {
+            if callee_path(cx, expr).is_some_and(|path| sources.contains(&path)) {
+                Some(expr)
+            } else if PATH_PRESERVING_METHODS.contains(&segment.ident.as_str()) {
+                trace(cx, receiver, sources, next)
+            } else {
+                None
+            }
+        }
+        hir::ExprKind::Call(callee, [arg]) if is_std_conversion(cx, callee) => {
+            trace(cx, arg, sources, next)
+        }
+        hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => match path.res {
+            Res::Local(binding) => {
+                immutable_initializer(cx, binding).and_then(|init| trace(cx, init, sources, next))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Reports whether `callee` is a standard-library `new` or `from`
+/// constructor, such as `Path::new` or `PathBuf::from`.
+fn is_std_conversion(cx: &LateContext<'_>, callee: &hir::Expr<'_>) -> bool {
+    let hir::ExprKind::Path(ref qpath) = callee.kind else {
+        return false;
+    };
+    cx.qpath_res(qpath, callee.hir_id)
+        .opt_def_id()
+        .is_some_and(|def_id| {
+            matches!(
+                cx.tcx.crate_name(def_id.krate),
+                sym::std | sym::core | sym::alloc
+            ) && matches!(cx.tcx.item_name(def_id), sym::new | sym::from)
+        })
+}
+
+/// Returns the initialiser of the immutable `let` binding `binding`.
+fn immutable_initializer<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding: hir::HirId,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    let hir::Node::Pat(pat) = cx.tcx.hir_node(binding) else {
+        return None;
+    };
+    if !matches!(pat.kind, hir::PatKind::Binding(hir::BindingMode::NONE, ..)) {
+        return None;
+    }
+    match cx.tcx.parent_hir_node(binding) {
+        hir::Node::LetStmt(local) => local.init,
+        _ => None,
+    }
+}
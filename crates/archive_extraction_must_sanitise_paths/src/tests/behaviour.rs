@@ -0,0 +1,85 @@
+//! Behaviour-driven coverage for deciding which entry-path joins to report.
+
+use crate::policy::BodyRecord;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct BodyWorld {
+    record: RefCell<BodyRecord<usize>>,
+    reported: Cell<Option<usize>>,
+}
+
+#[fixture]
+fn world() -> BodyWorld {
+    BodyWorld::default()
+}
+
+#[given("a function that joins {count} entry path")]
+fn given_one_join(world: &BodyWorld, count: usize) {
+    given_joins(world, count);
+}
+
+#[given("a function that joins {count} entry paths")]
+fn given_joins(world: &BodyWorld, count: usize) {
+    let mut record = world.record.borrow_mut();
+    for join in 0..count {
+        record.record_join(join);
+    }
+}
+
+#[given("the function checks containment")]
+fn given_check(world: &BodyWorld) {
+    world.record.borrow_mut().record_check();
+}
+
+#[when("the function body ends")]
+fn when_body_ends(world: &BodyWorld) {
+    let record = world.record.take();
+    world
+        .reported
+        .set(Some(record.into_unchecked_joins().len()));
+}
+
+#[then("{count} join is reported")]
+fn then_one_reported(world: &BodyWorld, count: usize) {
+    then_reported(world, count);
+}
+
+#[then("{count} joins are reported")]
+fn then_reported(world: &BodyWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[scenario(
+    path = "tests/features/archive_extraction_must_sanitise_paths.feature",
+    index = 0
+)]
+fn scenario_single_join(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/archive_extraction_must_sanitise_paths.feature",
+    index = 1
+)]
+fn scenario_every_join(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/archive_extraction_must_sanitise_paths.feature",
+    index = 2
+)]
+fn scenario_checked(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/archive_extraction_must_sanitise_paths.feature",
+    index = 3
+)]
+fn scenario_no_joins(world: BodyWorld) {
+    let _ = world;
+}
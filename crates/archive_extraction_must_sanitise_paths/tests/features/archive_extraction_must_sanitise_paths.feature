@@ -0,0 +1,24 @@
+Feature: Archive entry paths joined without a containment check
+  A function that joins an archive entry path onto another path is reported
+  unless it also canonicalises or otherwise checks a path for containment.
+
+  Scenario: A join without a check is reported
+    Given a function that joins 1 entry path
+    When the function body ends
+    Then 1 join is reported
+
+  Scenario: Every unchecked join is reported
+    Given a function that joins 2 entry paths
+    When the function body ends
+    Then 2 joins are reported
+
+  Scenario: A containment check suppresses the joins
+    Given a function that joins 2 entry paths
+    And the function checks containment
+    When the function body ends
+    Then 0 joins are reported
+
+  Scenario: A function without joins is accepted
+    Given a function that joins 0 entry paths
+    When the function body ends
+    Then 0 joins are reported
@@ -0,0 +1,54 @@
+//! UI fixture for archive entry paths joined without a containment check.
+#![warn(archive_extraction_must_sanitise_paths)]
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+mod tar {
+    use std::borrow::Cow;
+    use std::io;
+    use std::path::Path;
+
+    pub struct Entry;
+
+    impl Entry {
+        pub fn path(&self) -> io::Result<Cow<'_, Path>> {
+            Ok(Cow::Borrowed(Path::new("../escape")))
+        }
+    }
+}
+
+mod zip {
+    pub struct ZipFile;
+
+    impl ZipFile {
+        pub fn name(&self) -> &str {
+            "../escape"
+        }
+    }
+}
+
+fn unpack_tar(output: &Path, entry: &tar::Entry) -> io::Result<PathBuf> {
+    let path = entry.path()?.into_owned();
+    Ok(output.join(&path))
+}
+
+fn unpack_zip(output: &Path, file: &zip::ZipFile) -> PathBuf {
+    let mut target = output.to_path_buf();
+    target.push(Path::new(file.name()));
+    target
+}
+
+fn unpack_all(output: &Path, entries: &[tar::Entry]) -> Vec<PathBuf> {
+    entries
+        .iter()
+        .map(|entry| output.join(entry.path().unwrap()))
+        .collect()
+}
+
+fn main() {
+    let output = Path::new("out");
+    let _ = unpack_tar(output, &tar::Entry);
+    let _ = unpack_zip(output, &zip::ZipFile);
+    let _ = unpack_all(output, &[tar::Entry]);
+}
@@ -0,0 +1,66 @@
+//! UI fixture for archive entry paths checked before or after joining.
+#![warn(archive_extraction_must_sanitise_paths)]
+
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+mod tar {
+    use std::borrow::Cow;
+    use std::io;
+    use std::path::Path;
+
+    pub struct Entry;
+
+    impl Entry {
+        pub fn path(&self) -> io::Result<Cow<'_, Path>> {
+            Ok(Cow::Borrowed(Path::new("docs/readme.md")))
+        }
+    }
+}
+
+fn validate_entry_path(path: &Path) -> io::Result<()> {
+    if path.is_absolute() {
+        return Err(io::Error::other("absolute entry path"));
+    }
+    Ok(())
+}
+
+fn unpack_components(output: &Path, entry: &tar::Entry) -> io::Result<PathBuf> {
+    let path = entry.path()?.into_owned();
+    if path
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return Err(io::Error::other("unsafe entry path"));
+    }
+    Ok(output.join(&path))
+}
+
+fn unpack_canonical(output: &Path, entry: &tar::Entry) -> io::Result<PathBuf> {
+    let target = output.join(entry.path()?);
+    let resolved = target.canonicalize()?;
+    if !resolved.starts_with(output) {
+        return Err(io::Error::other("entry escapes the output directory"));
+    }
+    Ok(resolved)
+}
+
+fn unpack_helper(output: &Path, entry: &tar::Entry) -> io::Result<PathBuf> {
+    let path = entry.path()?;
+    validate_entry_path(&path)?;
+    Ok(output.join(path))
+}
+
+fn unpack_file_name(output: &Path, entry: &tar::Entry) -> io::Result<PathBuf> {
+    let path = entry.path()?;
+    Ok(output.join(path.file_name().unwrap_or_default()))
+}
+
+fn main() {
+    let output = Path::new("out");
+    let _ = unpack_components(output, &tar::Entry);
+    let _ = unpack_canonical(output, &tar::Entry);
+    let _ = unpack_helper(output, &tar::Entry);
+    let _ = unpack_file_name(output, &tar::Entry);
+    let _ = output.join("docs");
+}
@@ -0,0 +1,132 @@
+//! Pure decision logic for
+//! `async_fn_returning_boxed_future_prefer_async_trait_or_impl`.
+//!
+//! The driver collects trait methods returning a boxed future and the traits
+//! used as trait objects. The decision reports a method when the crate's
+//! minimum Rust version supports `async fn` in traits and its trait is never
+//! used as `dyn Trait`. Findings use the [`ModernForm`] selector as their
+//! reason.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{ModernForm, RustVersion, supports_async_fn_in_trait};
+
+/// The facts about one boxed-future trait method that the decision needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoxedFutureProjection {
+    /// Whether the boxed future is bounded by `Send`.
+    pub is_send: bool,
+    /// Whether the crate uses the method's trait as a trait object.
+    pub trait_used_as_dyn: bool,
+}
+
+/// Reports trait methods returning boxed futures that can be written as
+/// `async fn` or `impl Future`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision::default();
+/// let method = BoxedFutureProjection { is_send: true, trait_used_as_dyn: false };
+/// let finding = decision.decide(&method).expect("boxed futures are reported");
+/// assert_eq!(finding.reason(), "impl");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision {
+    enabled: bool,
+}
+
+impl AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision {
+    /// Creates a decision for a crate with minimum supported Rust version
+    /// `msrv`, such as `"1.70"`. Missing or unparseable versions are assumed
+    /// to support `async fn` in traits.
+    #[must_use]
+    pub fn new(msrv: Option<&str>) -> Self {
+        Self {
+            enabled: supports_async_fn_in_trait(msrv.and_then(RustVersion::parse)),
+        }
+    }
+
+    /// Returns whether methods are reported at all.
+    #[must_use]
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+impl Default for AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl LintDecision for AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision {
+    type Projection = BoxedFutureProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, method: &BoxedFutureProjection) -> Option<Finding> {
+        (self.enabled && !method.trait_used_as_dyn).then(|| {
+            Finding::new(
+                LINT_NAME,
+                ModernForm::for_future(method.is_send).fluent_kind(),
+            )
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<BoxedFutureProjection>> {
+        let method = |is_send, trait_used_as_dyn| BoxedFutureProjection {
+            is_send,
+            trait_used_as_dyn,
+        };
+        let mut fixtures = vec![DecisionFixture::clean(
+            "trait used as a trait object",
+            method(true, true),
+        )];
+        if self.enabled {
+            fixtures.push(DecisionFixture::flagged(
+                "unbounded future",
+                method(false, false),
+                ModernForm::AsyncFn.fluent_kind(),
+            ));
+            fixtures.push(DecisionFixture::flagged(
+                "Send future",
+                method(true, false),
+                ModernForm::ImplFutureSend.fluent_kind(),
+            ));
+        }
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::undeclared(None, true)]
+    #[case::stabilised(Some("1.75"), true)]
+    #[case::older(Some("1.70.0"), false)]
+    #[case::unparseable(Some("stable"), true)]
+    fn msrv_enables_the_lint(#[case] msrv: Option<&str>, #[case] expected: bool) {
+        assert_eq!(
+            AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision::new(msrv).enabled(),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(
+                &AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision::default()
+            ),
+            Ok(3)
+        );
+    }
+}
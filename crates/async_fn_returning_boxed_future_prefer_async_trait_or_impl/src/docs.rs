@@ -0,0 +1,23 @@
+//! Embedded documentation for the
+//! `async_fn_returning_boxed_future_prefer_async_trait_or_impl` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `async_fn_returning_boxed_future_prefer_async_trait_or_impl`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "async_fn_returning_boxed_future_prefer_async_trait_or_impl",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags trait methods returning `Pin<Box<dyn Future>>` when the crate's minimum supported Rust version allows `async fn` or `impl Future` in traits.",
+    rationale: "Boxing a future allocates on every call and erases its type. Since Rust 1.75, traits can declare `async fn` or return `impl Future` directly, which is cheaper and reads like the rest of the crate. Traits used as `dyn` trait objects are skipped, because the modern forms are not dyn compatible.",
+    bad_example: "trait Store {\n    fn load(&self) -> Pin<Box<dyn Future<Output = Vec<u8>> + '_>>;\n}",
+    good_example: "trait Store {\n    async fn load(&self) -> Vec<u8>;\n}",
+    config: &[ConfigKeyDocs {
+        key: "msrv",
+        default: "unset",
+        description: "Minimum supported Rust version to assume instead of the crate's `rust-version`; below 1.75 the lint reports nothing.",
+    }],
+};
@@ -0,0 +1,417 @@
+//! Lint pass reporting trait methods that return boxed futures.
+//!
+//! Each trait method whose declared return type is
+//! `Pin<Box<dyn Future<Output = T>>>` is recorded as a candidate, and every
+//! `dyn Trait` type written in the crate marks its trait as used as a trait
+//! object. Once the crate has been walked, candidates on traits that are not
+//! used as trait objects are reported, provided the crate's minimum supported
+//! Rust version allows `async fn` in traits. Signatures produced by macro
+//! expansion, such as those `#[async_trait]` writes, are skipped.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::AmbigArg;
+use rustc_hir::def_id::DefId;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{Span, Symbol, sym};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{ModernForm, RustVersion, supports_async_fn_in_trait};
+
+pub(crate) const LINT_NAME: &str = "async_fn_returning_boxed_future_prefer_async_trait_or_impl";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    msrv: Option<String>,
+}
+
+impl Config {
+    /// Returns the configured `msrv`, or the `rust-version` Cargo passes for
+    /// the crate being checked.
+    fn minimum_rust_version(&self) -> Option<RustVersion> {
+        let declared = self
+            .msrv
+            .clone()
+            .or_else(|| std::env::var("CARGO_PKG_RUST_VERSION").ok())
+            .filter(|version| !version.trim().is_empty())?;
+        let version = RustVersion::parse(&declared);
+        if version.is_none() {
+            debug!(
+                target: LINT_NAME,
+                "ignoring unparseable minimum Rust version `{declared}`"
+            );
+        }
+        version
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub ASYNC_FN_RETURNING_BOXED_FUTURE_PREFER_ASYNC_TRAIT_OR_IMPL,
+    Warn,
+    "trait methods should declare `async fn` or return `impl Future` instead of a boxed future when the toolchain allows",
+    AsyncFnReturningBoxedFuturePreferAsyncTraitOrImpl::default()
+}
+
+/// Lint pass collecting boxed-future trait methods and trait object uses.
+#[derive(Default)]
+pub struct AsyncFnReturningBoxedFuturePreferAsyncTraitOrImpl {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Candidates, trait object uses, finding limit, and localization for the
+/// crate being checked.
+#[derive(Default)]
+struct CrateState {
+    enabled: bool,
+    candidates: Vec<BoxedFutureMethod>,
+    dyn_traits: HashSet<DefId>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// A trait method declared to return a boxed future.
+#[derive(Clone, Debug)]
+struct BoxedFutureMethod {
+    trait_def_id: DefId,
+    hir_id: hir::HirId,
+    span: Span,
+    method: Symbol,
+    trait_name: Symbol,
+    return_type: String,
+    output: String,
+    form: ModernForm,
+}
+
+impl<'tcx> LateLintPass<'tcx> for AsyncFnReturningBoxedFuturePreferAsyncTraitOrImpl {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        let msrv = load_configuration().minimum_rust_version();
+        self.state.reset(CrateState {
+            enabled: supports_async_fn_in_trait(msrv),
+            candidates: Vec::new(),
+            dyn_traits: HashSet::new(),
+            findings: FindingLimit::new(
+                ASYNC_FN_RETURNING_BOXED_FUTURE_PREFER_ASYNC_TRAIT_OR_IMPL,
+                &shared_config,
+            ),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        if !self.state.enabled {
+            return;
+        }
+        let hir::TraitItemKind::Fn(signature, _) = item.kind else {
+            return;
+        };
+        let hir::FnRetTy::Return(return_ty) = signature.decl.output else {
+            return;
+        };
+        if item.span.from_expansion() || return_ty.span.from_expansion() {
+            return;
+        }
+        let def_id = item.owner_id.def_id;
+        let output = cx
+            .tcx
+            .fn_sig(def_id)
+            .instantiate_identity()
+            .skip_normalization()
+            .skip_binder()
+            .output();
+        let Some(future) = boxed_future(cx, output) else {
+            return;
+        };
+        let trait_def_id = cx.tcx.local_parent(def_id).to_def_id();
+        let return_type = cx
+            .sess()
+            .source_map()
+            .span_to_snippet(return_ty.span)
+            .unwrap_or_else(|_| output.to_string());
+        self.state.candidates.push(BoxedFutureMethod {
+            trait_def_id,
+            hir_id: item.hir_id(),
+            span: item.ident.span,
+            method: item.ident.name,
+            trait_name: cx.tcx.item_name(trait_def_id),
+            return_type,
+            output: future.output,
+            form: ModernForm::for_future(future.is_send),
+        });
+    }
+
+    fn check_ty(&mut self, _cx: &LateContext<'tcx>, ty: &'tcx hir::Ty<'tcx, AmbigArg>) {
+        if let hir::TyKind::TraitObject(bounds, _) = ty.kind {
+            self.state.dyn_traits.extend(
+                bounds
+                    .iter()
+                    .filter_map(|bound| bound.trait_ref.trait_def_id()),
+            );
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let state = &*self.state;
+        for method in &state.candidates {
+            if state.dyn_traits.contains(&method.trait_def_id) {
+                debug!(
+                    target: LINT_NAME,
+                    "skipping `{}::{}`: the trait is used as a trait object",
+                    method.trait_name,
+                    method.method,
+                );
+                continue;
+            }
+            if state.findings.admit(cx, method.hir_id, method.span) {
+                emit_diagnostic(cx, method, &state.localizer);
+            }
+        }
+        state.findings.summarise(cx, &state.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// The parts of a boxed future the suggestion needs.
+struct BoxedFuture {
+    output: String,
+    is_send: bool,
+}
+
+/// Matches `Pin<Box<dyn Future<Output = T> + ...>>`, returning `T` and
+/// whether the future is bounded by `Send`.
+fn boxed_future<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<BoxedFuture> {
+    let ty::Adt(pin, args) = ty.kind() else {
+        return None;
+    };
+    if Some(pin.did()) != cx.tcx.lang_items().pin_type() {
+        return None;
+    }
+    let boxed = args.type_at(0).boxed_ty()?;
+    let ty::Dynamic(predicates, _) = boxed.kind() else {
+        return None;
+    };
+    if predicates.principal_def_id() != cx.tcx.lang_items().future_trait() {
+        return None;
+    }
+    let future_output = cx.tcx.lang_items().future_output();
+    let output = predicates
+        .projection_bounds()
+        .find(|projection| Some(projection.item_def_id()) == future_output)
+        .and_then(|projection| projection.skip_binder().term.as_type())?;
+    let send = cx.tcx.get_diagnostic_item(sym::Send);
+    Some(BoxedFuture {
+        output: output.to_string(),
+        is_send: predicates.auto_traits().any(|auto| Some(auto) == send),
+    })
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, method: &BoxedFutureMethod, localizer: &Localizer) {
+    let method_name = method.method.to_string();
+    let trait_name = method.trait_name.to_string();
+    let message = BoxedFutureMessage {
+        method: &method_name,
+        trait_name: &trait_name,
+        return_type: &method.return_type,
+        output: &method.output,
+        form: method.form,
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        ASYNC_FN_RETURNING_BOXED_FUTURE_PREFER_ASYNC_TRAIT_OR_IMPL,
+        method.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a boxed-future method interpolates.
+#[derive(Clone, Copy, Debug)]
+struct BoxedFutureMessage<'a> {
+    method: &'a str,
+    trait_name: &'a str,
+    return_type: &'a str,
+    output: &'a str,
+    form: ModernForm,
+}
+
+impl BoxedFutureMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("method"),
+            FluentValue::from(self.method.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("trait"),
+            FluentValue::from(self.trait_name.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("return"),
+            FluentValue::from(self.return_type.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("output"),
+            FluentValue::from(self.output.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("form"),
+            FluentValue::from(self.form.fluent_kind()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            method,
+            trait_name,
+            return_type,
+            output,
+            form,
+        } = *self;
+        let help = match form {
+            ModernForm::AsyncFn => {
+                format!("Declare the method as `async fn {method}(...) -> {output}`.")
+            }
+            ModernForm::ImplFutureSend => format!(
+                "Declare the method as `fn {method}(...) -> impl Future<Output = {output}> + \
+                 Send`, which keeps the `Send` bound."
+            ),
+        };
+        DiagnosticMessageSet::new(
+            format!("Method `{method}` in trait `{trait_name}` returns a boxed future."),
+            format!(
+                "`{return_type}` allocates on every call and erases the future's type, and \
+                 since Rust 1.75 traits can declare asynchronous methods directly."
+            ),
+            help,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(form: ModernForm) -> BoxedFutureMessage<'static> {
+        BoxedFutureMessage {
+            method: "load",
+            trait_name: "Store",
+            return_type: "Pin<Box<dyn Future<Output = Vec<u8>> + Send + '_>>",
+            output: "Vec<u8>",
+            form,
+        }
+    }
+
+    fn resolve(message: &BoxedFutureMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    #[case("1.75", Some(RustVersion::new(1, 75, 0)))]
+    #[case("1.70.1", Some(RustVersion::new(1, 70, 1)))]
+    #[case("nightly", None)]
+    fn configured_msrv_takes_precedence(#[case] msrv: &str, #[case] expected: Option<RustVersion>) {
+        let config = Config {
+            msrv: Some(msrv.to_owned()),
+        };
+
+        assert_eq!(config.minimum_rust_version(), expected);
+    }
+
+    #[rstest]
+    #[case(ModernForm::AsyncFn, "`async fn load(...) -> Vec<u8>`")]
+    #[case(
+        ModernForm::ImplFutureSend,
+        "`fn load(...) -> impl Future<Output = Vec<u8>> + Send`"
+    )]
+    fn fallback_help_suggests_the_modern_form(#[case] form: ModernForm, #[case] expected: &str) {
+        let messages = message(form).fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Method `load` in trait `Store` returns a boxed future."
+        );
+        assert!(messages.help().contains(expected), "{}", messages.help());
+    }
+
+    #[rstest]
+    #[case(ModernForm::AsyncFn)]
+    #[case(ModernForm::ImplFutureSend)]
+    fn english_messages_match_the_fallback(#[case] form: ModernForm) {
+        let message = message(form);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(ModernForm::ImplFutureSend), locale);
+
+        assert!(messages.primary().contains("`load`"));
+        assert!(
+            messages
+                .help()
+                .contains("impl Future<Output = Vec<u8>> + Send")
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,46 @@
+//! Lint crate flagging trait methods that return boxed futures.
+//!
+//! Before Rust 1.75, trait methods could only be asynchronous by returning
+//! `Pin<Box<dyn Future<Output = T>>>`, which allocates on every call and
+//! erases the future's type. Crates whose minimum supported Rust version
+//! allows it can declare `async fn` or return `impl Future` instead. The lint
+//! reports hand-written boxed-future return types on trait methods and
+//! suggests the modern form, skipping traits the crate uses as `dyn` trait
+//! objects, which the modern forms would make dyn-incompatible. The lint is
+//! experimental and ships behind the
+//! `experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{
+    AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision, BoxedFutureProjection,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn async_fn_returning_boxed_future_prefer_async_trait_or_impl_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,127 @@
+//! Toolchain gating and suggestion choice for boxed-future trait methods.
+//!
+//! `async fn` and return-position `impl Trait` in traits were stabilised in
+//! Rust 1.75. [`supports_async_fn_in_trait`] decides from the crate's minimum
+//! supported Rust version whether the modern forms are available, and
+//! [`ModernForm::for_future`] picks the one that preserves the boxed future's
+//! bounds. The rules are kept free of compiler types so they can be tested
+//! directly.
+
+/// A Rust release, as written in `rust-version` or the lint's `msrv` key.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct RustVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl RustVersion {
+    /// Creates a version from its parts.
+    #[must_use]
+    pub(crate) const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parses `1.75` or `1.75.0`, returning `None` for anything else.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// assert_eq!(RustVersion::parse("1.75"), Some(RustVersion::new(1, 75, 0)));
+    /// assert_eq!(RustVersion::parse("stable"), None);
+    /// ```
+    #[must_use]
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next().map_or(Some(0), |patch| patch.parse().ok())?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+/// The first release with `async fn` and `impl Trait` in trait methods.
+pub(crate) const ASYNC_FN_IN_TRAIT: RustVersion = RustVersion::new(1, 75, 0);
+
+/// Returns `true` when a crate with minimum supported version `msrv` may use
+/// `async fn` in traits. Crates without a declared minimum are assumed to
+/// build with the toolchain running the lint, which supports it.
+#[must_use]
+pub(crate) fn supports_async_fn_in_trait(msrv: Option<RustVersion>) -> bool {
+    msrv.is_none_or(|msrv| msrv >= ASYNC_FN_IN_TRAIT)
+}
+
+/// The form a boxed-future method should take instead.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ModernForm {
+    /// `async fn`, when the future carries no auto-trait bounds.
+    AsyncFn,
+    /// `fn ... -> impl Future<Output = T> + Send`, which keeps the `Send`
+    /// bound that an `async fn` signature cannot state.
+    ImplFutureSend,
+}
+
+impl ModernForm {
+    /// Returns the form that preserves a boxed future's bounds.
+    #[must_use]
+    pub(crate) const fn for_future(is_send: bool) -> Self {
+        if is_send {
+            Self::ImplFutureSend
+        } else {
+            Self::AsyncFn
+        }
+    }
+
+    /// Returns the selector the Fluent messages use for this form.
+    #[must_use]
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::AsyncFn => "async",
+            Self::ImplFutureSend => "impl",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1.75", Some(RustVersion::new(1, 75, 0)))]
+    #[case("1.74.1", Some(RustVersion::new(1, 74, 1)))]
+    #[case(" 1.80.0 ", Some(RustVersion::new(1, 80, 0)))]
+    #[case("1", None)]
+    #[case("1.75.0.1", None)]
+    #[case("stable", None)]
+    #[case("", None)]
+    fn parse_reads_release_numbers(#[case] text: &str, #[case] expected: Option<RustVersion>) {
+        assert_eq!(RustVersion::parse(text), expected);
+    }
+
+    #[rstest]
+    #[case(None, true)]
+    #[case(Some(RustVersion::new(1, 74, 1)), false)]
+    #[case(Some(RustVersion::new(1, 75, 0)), true)]
+    #[case(Some(RustVersion::new(1, 85, 0)), true)]
+    fn async_fn_in_trait_needs_rust_1_75(
+        #[case] msrv: Option<RustVersion>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(supports_async_fn_in_trait(msrv), expected);
+    }
+
+    #[rstest]
+    #[case(false, ModernForm::AsyncFn)]
+    #[case(true, ModernForm::ImplFutureSend)]
+    fn send_futures_keep_their_bound(#[case] is_send: bool, #[case] expected: ModernForm) {
+        assert_eq!(ModernForm::for_future(is_send), expected);
+    }
+}
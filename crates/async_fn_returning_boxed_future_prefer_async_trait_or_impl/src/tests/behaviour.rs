@@ -0,0 +1,115 @@
+//! Behaviour-driven coverage for toolchain gating and suggestion choice.
+
+use crate::policy::{ModernForm, RustVersion, supports_async_fn_in_trait};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+#[derive(Default)]
+struct BoxedFutureWorld {
+    msrv: Cell<Option<RustVersion>>,
+    is_send: Cell<bool>,
+    supported: Cell<Option<bool>>,
+    form: Cell<Option<ModernForm>>,
+}
+
+#[fixture]
+fn world() -> BoxedFutureWorld {
+    BoxedFutureWorld::default()
+}
+
+#[given("no minimum supported Rust version")]
+fn given_no_msrv(world: &BoxedFutureWorld) {
+    world.msrv.set(None);
+}
+
+#[given("a minimum supported Rust version of {version}")]
+fn given_msrv(world: &BoxedFutureWorld, version: String) {
+    let parsed = RustVersion::parse(version.trim_matches('"'));
+    assert!(parsed.is_some(), "`{version}` should parse");
+    world.msrv.set(parsed);
+}
+
+#[given("a boxed future without a Send bound")]
+fn given_unbounded_future(world: &BoxedFutureWorld) {
+    world.is_send.set(false);
+}
+
+#[given("a boxed future with a Send bound")]
+fn given_send_future(world: &BoxedFutureWorld) {
+    world.is_send.set(true);
+}
+
+#[when("the toolchain support is evaluated")]
+fn when_support_evaluated(world: &BoxedFutureWorld) {
+    world
+        .supported
+        .set(Some(supports_async_fn_in_trait(world.msrv.get())));
+}
+
+#[when("the replacement is chosen")]
+fn when_replacement_chosen(world: &BoxedFutureWorld) {
+    world
+        .form
+        .set(Some(ModernForm::for_future(world.is_send.get())));
+}
+
+#[then("boxed futures are reported")]
+fn then_reported(world: &BoxedFutureWorld) {
+    assert_eq!(world.supported.get(), Some(true));
+}
+
+#[then("boxed futures are accepted")]
+fn then_accepted(world: &BoxedFutureWorld) {
+    assert_eq!(world.supported.get(), Some(false));
+}
+
+#[then("the suggestion is an async fn")]
+fn then_async_fn(world: &BoxedFutureWorld) {
+    assert_eq!(world.form.get(), Some(ModernForm::AsyncFn));
+}
+
+#[then("the suggestion is an impl Future with Send")]
+fn then_impl_future(world: &BoxedFutureWorld) {
+    assert_eq!(world.form.get(), Some(ModernForm::ImplFutureSend));
+}
+
+#[scenario(
+    path = "tests/features/async_fn_returning_boxed_future_prefer_async_trait_or_impl.feature",
+    index = 0
+)]
+fn scenario_no_msrv(world: BoxedFutureWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/async_fn_returning_boxed_future_prefer_async_trait_or_impl.feature",
+    index = 1
+)]
+fn scenario_old_msrv(world: BoxedFutureWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/async_fn_returning_boxed_future_prefer_async_trait_or_impl.feature",
+    index = 2
+)]
+fn scenario_supported_msrv(world: BoxedFutureWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/async_fn_returning_boxed_future_prefer_async_trait_or_impl.feature",
+    index = 3
+)]
+fn scenario_async_fn(world: BoxedFutureWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/async_fn_returning_boxed_future_prefer_async_trait_or_impl.feature",
+    index = 4
+)]
+fn scenario_impl_future_send(world: BoxedFutureWorld) {
+    let _ = world;
+}
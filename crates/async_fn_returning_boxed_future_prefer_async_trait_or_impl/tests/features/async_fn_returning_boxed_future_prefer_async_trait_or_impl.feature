@@ -0,0 +1,29 @@
+Feature: Boxed futures in trait methods
+  Trait methods returning `Pin<Box<dyn Future>>` are reported when the
+  crate's minimum supported Rust version allows `async fn` in traits, and
+  the suggestion keeps any `Send` bound.
+
+  Scenario: A crate without a declared minimum version is checked
+    Given no minimum supported Rust version
+    When the toolchain support is evaluated
+    Then boxed futures are reported
+
+  Scenario: A crate supporting Rust 1.74 keeps its boxed futures
+    Given a minimum supported Rust version of "1.74"
+    When the toolchain support is evaluated
+    Then boxed futures are accepted
+
+  Scenario: A crate requiring Rust 1.75 is checked
+    Given a minimum supported Rust version of "1.75.0"
+    When the toolchain support is evaluated
+    Then boxed futures are reported
+
+  Scenario: A boxed future without bounds becomes an async fn
+    Given a boxed future without a Send bound
+    When the replacement is chosen
+    Then the suggestion is an async fn
+
+  Scenario: A Send boxed future keeps its bound
+    Given a boxed future with a Send bound
+    When the replacement is chosen
+    Then the suggestion is an impl Future with Send
@@ -0,0 +1,29 @@
+//! Trait methods returning hand-written boxed futures are reported, with a
+//! suggestion that keeps any `Send` bound.
+
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait Store {
+    fn load(&self) -> Pin<Box<dyn Future<Output = u64> + '_>>;
+
+    fn save(&self, data: Vec<u8>) -> Pin<Box<dyn Future<Output = usize> + Send + '_>>;
+}
+
+struct Memory;
+
+impl Store for Memory {
+    fn load(&self) -> Pin<Box<dyn Future<Output = u64> + '_>> {
+        Box::pin(async { 0 })
+    }
+
+    fn save(&self, data: Vec<u8>) -> Pin<Box<dyn Future<Output = usize> + Send + '_>> {
+        Box::pin(async move { data.len() })
+    }
+}
+
+fn main() {
+    let store = Memory;
+    let _ = store.load();
+    let _ = store.save(Vec::new());
+}
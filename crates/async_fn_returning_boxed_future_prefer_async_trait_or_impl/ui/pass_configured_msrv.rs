@@ -0,0 +1,11 @@
+//! With `msrv = "1.70"`, boxed futures are the only way to write
+//! asynchronous trait methods and are accepted.
+
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait Store {
+    fn load(&self) -> Pin<Box<dyn Future<Output = u64> + '_>>;
+}
+
+fn main() {}
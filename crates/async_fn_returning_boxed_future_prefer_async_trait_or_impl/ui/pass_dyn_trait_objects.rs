@@ -0,0 +1,29 @@
+//! Traits used as `dyn` trait objects keep their boxed futures, because
+//! `async fn` would make them dyn-incompatible. Methods returning other
+//! types are not checked.
+
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait Handler {
+    fn handle(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
+
+pub trait Named {
+    fn name(&self) -> Box<dyn Fn() -> String>;
+}
+
+struct Noop;
+
+impl Handler for Noop {
+    fn handle(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async {})
+    }
+}
+
+fn main() {
+    let handlers: Vec<Box<dyn Handler>> = vec![Box::new(Noop)];
+    for handler in &handlers {
+        let _ = handler.handle();
+    }
+}
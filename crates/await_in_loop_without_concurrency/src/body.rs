@@ -0,0 +1,214 @@
+//! Summarises the body of a desugared `for` loop for [`crate::policy`].
+//!
+//! A `for` loop lowers to a `loop` whose single statement matches on
+//! `Iterator::next`; the `Some` arm binds the loop pattern and holds the
+//! body. The visitor walks that body without entering closures or `async`
+//! blocks, whose futures the loop does not await directly, and records the
+//! variables each `.await` operand and `let` initialiser reads, the outer
+//! variables the body assigns or borrows mutably, and any `break` or
+//! `return` that leaves the loop. The `return` a `?` desugars into is not an
+//! early exit, since `try_join_all` stops on the first error too.
+
+use std::collections::HashSet;
+
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_span::Span;
+
+use crate::policy::{Binding, LoopBody};
+
+/// The pattern and body of a `for` loop.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ForLoop<'tcx> {
+    pub(crate) pat: &'tcx hir::Pat<'tcx>,
+    pub(crate) body: &'tcx hir::Expr<'tcx>,
+}
+
+impl<'tcx> ForLoop<'tcx> {
+    /// Recovers the `for` loop `expr` was lowered from, if any.
+    pub(crate) fn from_expr(expr: &'tcx hir::Expr<'tcx>) -> Option<Self> {
+        let hir::ExprKind::Loop(block, _, hir::LoopSource::ForLoop, _) = expr.kind else {
+            return None;
+        };
+        let [stmt] = block.stmts else {
+            return None;
+        };
+        let (hir::StmtKind::Expr(next) | hir::StmtKind::Semi(next)) = stmt.kind else {
+            return None;
+        };
+        let hir::ExprKind::Match(_, arms, hir::MatchSource::ForLoopDesugar) = next.kind else {
+            return None;
+        };
+        arms.iter().find_map(|arm| match arm.pat.kind {
+            hir::PatKind::TupleStruct(_, [pat], _) => Some(Self {
+                pat,
+                body: arm.body,
+            }),
+            _ => None,
+        })
+    }
+}
+
+/// A loop body summary, with the span of each await it lists.
+#[derive(Debug)]
+pub(crate) struct Summary {
+    pub(crate) body: LoopBody<hir::HirId>,
+    pub(crate) await_spans: Vec<Span>,
+}
+
+/// Summarises `for_loop` for [`crate::policy::independent_await`].
+pub(crate) fn summarise<'tcx>(cx: &LateContext<'tcx>, for_loop: ForLoop<'tcx>) -> Summary {
+    let mut visitor = BodyVisitor {
+        cx,
+        declared: HashSet::new(),
+        inner_loops: HashSet::new(),
+        mutated: Vec::new(),
+        summary: Summary {
+            body: LoopBody {
+                item: bindings(for_loop.pat),
+                ..LoopBody::default()
+            },
+            await_spans: Vec::new(),
+        },
+    };
+    visitor.visit_expr(for_loop.body);
+
+    let BodyVisitor {
+        declared,
+        mutated,
+        mut summary,
+        ..
+    } = visitor;
+    summary.body.mutated = mutated
+        .into_iter()
+        .filter(|hir_id| !declared.contains(hir_id))
+        .collect();
+    summary
+}
+
+struct BodyVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    declared: HashSet<hir::HirId>,
+    inner_loops: HashSet<hir::HirId>,
+    mutated: Vec<hir::HirId>,
+    summary: Summary,
+}
+
+impl BodyVisitor<'_, '_> {
+    /// Returns `true` when a method call borrows `receiver` mutably.
+    fn borrows_mutably(&self, receiver: &hir::Expr<'_>) -> bool {
+        self.cx
+            .typeck_results()
+            .expr_adjustments(receiver)
+            .iter()
+            .any(|adjustment| {
+                matches!(
+                    adjustment.kind,
+                    Adjust::Borrow(AutoBorrow::Ref(AutoBorrowMutability::Mut { .. }))
+                )
+            })
+    }
+
+    fn record_mutation(&mut self, place: &hir::Expr<'_>) {
+        if let Some(hir_id) = base_local(place) {
+            self.mutated.push(hir_id);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for BodyVisitor<'_, 'tcx> {
+    fn visit_pat(&mut self, pat: &'tcx hir::Pat<'tcx>) {
+        if let hir::PatKind::Binding(_, hir_id, ..) = pat.kind {
+            self.declared.insert(hir_id);
+        }
+        intravisit::walk_pat(self, pat);
+    }
+
+    fn visit_local(&mut self, local: &'tcx hir::LetStmt<'tcx>) {
+        if let Some(init) = local.init {
+            self.summary.body.lets.push(Binding {
+                names: bindings(local.pat),
+                reads: locals_in(init),
+            });
+        }
+        intravisit::walk_local(self, local);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        match expr.kind {
+            hir::ExprKind::Match(operand, _, hir::MatchSource::AwaitDesugar) => {
+                self.summary.body.awaits.push(locals_in(operand));
+                self.summary.await_spans.push(expr.span);
+            }
+            hir::ExprKind::Loop(..) => {
+                self.inner_loops.insert(expr.hir_id);
+            }
+            hir::ExprKind::Break(destination, _) => {
+                let leaves = destination
+                    .target_id
+                    .map_or(true, |target| !self.inner_loops.contains(&target));
+                self.summary.body.exits_early |= leaves;
+            }
+            hir::ExprKind::Ret(_) if expr.span.desugaring_kind().is_none() => {
+                self.summary.body.exits_early = true;
+            }
+            hir::ExprKind::Assign(place, ..)
+            | hir::ExprKind::AssignOp(_, place, _)
+            | hir::ExprKind::AddrOf(_, hir::Mutability::Mut, place) => {
+                self.record_mutation(place);
+            }
+            hir::ExprKind::MethodCall(_, receiver, ..) if self.borrows_mutably(receiver) => {
+                self.record_mutation(receiver);
+            }
+            _ => {}
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
+
+/// Returns the variables `pat` binds.
+fn bindings(pat: &hir::Pat<'_>) -> Vec<hir::HirId> {
+    let mut names = Vec::new();
+    pat.each_binding(|_, hir_id, _, _| names.push(hir_id));
+    names
+}
+
+/// Returns the variables `expr` reads outside the closures it builds.
+fn locals_in(expr: &hir::Expr<'_>) -> Vec<hir::HirId> {
+    struct Locals(Vec<hir::HirId>);
+
+    impl<'tcx> Visitor<'tcx> for Locals {
+        fn visit_path(&mut self, path: &hir::Path<'tcx>, _: hir::HirId) {
+            if let Res::Local(hir_id) = path.res {
+                self.0.push(hir_id);
+            }
+            intravisit::walk_path(self, path);
+        }
+    }
+
+    let mut locals = Locals(Vec::new());
+    locals.visit_expr(expr);
+    locals.0
+}
+
+/// Returns the variable at the root of the place `expr` names, looking
+/// through fields, indexing, and dereferences.
+fn base_local(mut expr: &hir::Expr<'_>) -> Option<hir::HirId> {
+    loop {
+        match expr.kind {
+            hir::ExprKind::Field(base, _)
+            | hir::ExprKind::Index(base, ..)
+            | hir::ExprKind::Unary(hir::UnOp::Deref, base) => expr = base,
+            hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => {
+                return match path.res {
+                    Res::Local(hir_id) => Some(hir_id),
+                    _ => None,
+                };
+            }
+            _ => return None,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Pure decision logic for `await_in_loop_without_concurrency`.
+//!
+//! The driver summarises each `for` loop body in an `async` context by the
+//! variables its pattern, mutations, `let` bindings, and awaited operands
+//! touch. [`AwaitInLoopWithoutConcurrencyDecision`] converts that summary into
+//! the [`LoopBody`] the driver judges and reports the loop when one of its
+//! awaits is independent of earlier iterations.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{Binding, LoopBody, independent_await};
+
+/// Reason key for loops awaiting futures that could run concurrently.
+const INDEPENDENT_AWAIT: &str = "independent_await";
+
+/// A `let` binding inside the loop body.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LetProjection {
+    /// The variables the pattern binds.
+    pub names: Vec<String>,
+    /// The variables the initialiser reads.
+    pub reads: Vec<String>,
+}
+
+/// The facts about one `for` loop body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoopProjection {
+    /// The variables bound by the loop pattern.
+    pub item: Vec<String>,
+    /// Variables declared outside the loop that the body assigns or borrows
+    /// mutably.
+    pub mutated: Vec<String>,
+    /// The `let` bindings of the body, in source order.
+    pub lets: Vec<LetProjection>,
+    /// The variables each awaited operand reads, in source order.
+    pub awaits: Vec<Vec<String>>,
+    /// Whether a `break` or `return` can end the loop early.
+    pub exits_early: bool,
+}
+
+/// Reports loops that await, one iteration at a time, futures derived only
+/// from the loop item.
+///
+/// # Examples
+///
+/// ```ignore
+/// let body = LoopProjection {
+///     item: vec!["url".to_owned()],
+///     awaits: vec![vec!["url".to_owned()]],
+///     ..LoopProjection::default()
+/// };
+/// assert!(AwaitInLoopWithoutConcurrencyDecision.decide(&body).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AwaitInLoopWithoutConcurrencyDecision;
+
+impl LintDecision for AwaitInLoopWithoutConcurrencyDecision {
+    type Projection = LoopProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, projection: &LoopProjection) -> Option<Finding> {
+        let body = LoopBody {
+            item: projection.item.clone(),
+            mutated: projection.mutated.clone(),
+            lets: projection
+                .lets
+                .iter()
+                .map(|binding| Binding {
+                    names: binding.names.clone(),
+                    reads: binding.reads.clone(),
+                })
+                .collect(),
+            awaits: projection.awaits.clone(),
+            exits_early: projection.exits_early,
+        };
+        independent_await(&body).map(|_| Finding::new(LINT_NAME, INDEPENDENT_AWAIT))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<LoopProjection>> {
+        let names = |names: &[&str]| names.iter().map(|&name| name.to_owned()).collect();
+        let fetch = LoopProjection {
+            item: names(&["url"]),
+            lets: vec![LetProjection {
+                names: names(&["request"]),
+                reads: names(&["url"]),
+            }],
+            awaits: vec![names(&["client", "request"])],
+            ..LoopProjection::default()
+        };
+        let accumulating = LoopProjection {
+            mutated: names(&["cursor"]),
+            awaits: vec![names(&["url", "cursor"])],
+            ..fetch.clone()
+        };
+        let stopping = LoopProjection {
+            exits_early: true,
+            ..fetch.clone()
+        };
+        vec![
+            DecisionFixture::flagged("await derived from the item", fetch, INDEPENDENT_AWAIT),
+            DecisionFixture::clean("await reading mutated state", accumulating),
+            DecisionFixture::clean("loop with an early exit", stopping),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&AwaitInLoopWithoutConcurrencyDecision),
+            Ok(3)
+        );
+    }
+}
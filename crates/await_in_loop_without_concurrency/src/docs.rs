@@ -0,0 +1,17 @@
+//! Embedded documentation for the `await_in_loop_without_concurrency` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for `await_in_loop_without_concurrency`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "await_in_loop_without_concurrency",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `for` loops that await, one item at a time, futures built from the loop item that do not depend on earlier iterations.",
+    rationale: "Awaiting inside a loop finishes each future before the next one starts, so ten independent requests take ten round trips. When no iteration depends on another, running the futures together with `join_all`, or through a stream with `buffer_unordered` to bound how many are in flight, takes about as long as the slowest request.",
+    bad_example: "for id in ids {\n    let user = client.fetch(id).await?;\n    users.push(user);\n}",
+    good_example: "let users = futures::future::try_join_all(ids.into_iter().map(|id| client.fetch(id))).await?;",
+    config: &[],
+};
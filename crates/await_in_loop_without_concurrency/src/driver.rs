@@ -0,0 +1,217 @@
+//! Lint pass reporting `for` loops that await independent futures in turn.
+//!
+//! Each `for` loop is recovered from its desugaring by [`ForLoop`] and its
+//! body summarised by [`summarise`]. [`independent_await`] then picks the
+//! first `.await` whose operand is built from the loop item and from nothing
+//! an earlier iteration changed; that await is reported. The check is a
+//! heuristic: assignments, `&mut` borrows, and mutable method receivers
+//! count as changes, but interior mutability and writes through other
+//! references do not. Loops produced by macro expansion, loops in test code,
+//! and awaits already reported for an enclosing loop are skipped.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::body::{ForLoop, summarise};
+use crate::policy::independent_await;
+
+pub(crate) const LINT_NAME: &str = "await_in_loop_without_concurrency";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub AWAIT_IN_LOOP_WITHOUT_CONCURRENCY,
+    Warn,
+    "`for` loops awaiting futures that do not depend on earlier iterations should run them concurrently",
+    AwaitInLoopWithoutConcurrency::default()
+}
+
+/// Lint pass that finds sequential awaits over independent loop items.
+#[derive(Default)]
+pub struct AwaitInLoopWithoutConcurrency {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Test detection, reported awaits, finding limit, and localization for the
+/// crate being checked.
+#[derive(Default)]
+struct CrateState {
+    test_context: TestContext,
+    reported: HashSet<Span>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for AwaitInLoopWithoutConcurrency {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            test_context: TestContext::for_crate(cx, Vec::new()),
+            reported: HashSet::new(),
+            findings: FindingLimit::new(AWAIT_IN_LOOP_WITHOUT_CONCURRENCY, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let Some(for_loop) = ForLoop::from_expr(expr) else {
+            return;
+        };
+        if for_loop.body.span.from_expansion() || self.state.test_context.is_test(cx, expr.hir_id) {
+            return;
+        }
+
+        let summary = summarise(cx, for_loop);
+        let Some(index) = independent_await(&summary.body) else {
+            return;
+        };
+        let span = summary.await_spans[index];
+        if !self.state.reported.insert(span) {
+            return;
+        }
+
+        let item = cx
+            .sess()
+            .source_map()
+            .span_to_snippet(for_loop.pat.span)
+            .unwrap_or_else(|_| String::from("the item"));
+        debug!(target: LINT_NAME, "`for {item}` awaits an independent future in turn");
+        let state = &*self.state;
+        if state.findings.admit(cx, expr.hir_id, span) {
+            emit_diagnostic(
+                cx,
+                span,
+                &SequentialAwaitMessage { item: &item },
+                &state.localizer,
+            );
+        }
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &SequentialAwaitMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        AWAIT_IN_LOOP_WITHOUT_CONCURRENCY,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one sequential await interpolates.
+#[derive(Clone, Copy, Debug)]
+struct SequentialAwaitMessage<'a> {
+    item: &'a str,
+}
+
+impl SequentialAwaitMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("item"),
+            FluentValue::from(self.item.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let item = self.item;
+        DiagnosticMessageSet::new(
+            format!(
+                "Each iteration awaits a future built from `{item}` before the next iteration \
+                 starts."
+            ),
+            String::from(
+                "The future does not depend on earlier iterations, so awaiting the futures one at \
+                 a time leaves only one of them making progress.",
+            ),
+            String::from(
+                "Collect the futures and await them together with `futures::future::join_all`, \
+                 or turn the items into a stream and use `buffer_unordered` to bound how many run \
+                 at once.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: SequentialAwaitMessage<'static> = SequentialAwaitMessage { item: "id" };
+
+    fn resolve(message: &SequentialAwaitMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn fallback_primary_names_the_item() {
+        assert_eq!(
+            MESSAGE.fallback_messages().primary(),
+            "Each iteration awaits a future built from `id` before the next iteration starts."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`id`"));
+        assert!(messages.help().contains("`buffer_unordered`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
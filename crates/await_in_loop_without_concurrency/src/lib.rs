@@ -0,0 +1,45 @@
+//! Lint crate flagging `for` loops that await independent futures in turn.
+//!
+//! A loop that awaits a request for each item waits for every response
+//! before sending the next request, even when the requests do not depend on
+//! each other. Running the futures concurrently with `join_all`, or with
+//! `buffer_unordered` to bound the number in flight, finishes in roughly the
+//! time of the slowest one. The lint reports `.await` expressions in `for`
+//! loop bodies whose future is built from the loop item and from no state an
+//! earlier iteration changed. The lint is experimental and ships behind the
+//! `experimental-await-in-loop-without-concurrency` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod body;
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{AwaitInLoopWithoutConcurrencyDecision, LetProjection, LoopProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn await_in_loop_without_concurrency_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,158 @@
+//! Rules deciding whether a `for` loop awaits independent futures in turn.
+//!
+//! The driver summarises a loop body as a [`LoopBody`]: the bindings the
+//! loop pattern introduces, the variables from outside the loop the body
+//! mutates, the `let` bindings the body declares, the variables each awaited
+//! operand reads, and whether the body can leave the loop early. A variable
+//! is derived from a root when a chain of `let` initialisers leads back to
+//! it. An await is independent when its operand is derived from the loop
+//! item but from nothing an earlier iteration may have changed. The rules
+//! are generic over the variable key so they can be tested without compiler
+//! types.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// A `let` binding inside the loop body.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Binding<K> {
+    /// The variables the pattern binds.
+    pub(crate) names: Vec<K>,
+    /// The variables the initialiser reads.
+    pub(crate) reads: Vec<K>,
+}
+
+/// What the lint needs to know about one `for` loop body.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LoopBody<K> {
+    /// The variables bound by the loop pattern.
+    pub(crate) item: Vec<K>,
+    /// Variables declared outside the loop that the body assigns or borrows
+    /// mutably.
+    pub(crate) mutated: Vec<K>,
+    /// The `let` bindings of the body, in source order.
+    pub(crate) lets: Vec<Binding<K>>,
+    /// The variables each awaited operand reads, in source order.
+    pub(crate) awaits: Vec<Vec<K>>,
+    /// Whether a `break` or `return` can end the loop early.
+    pub(crate) exits_early: bool,
+}
+
+impl<K> Default for LoopBody<K> {
+    fn default() -> Self {
+        Self {
+            item: Vec::new(),
+            mutated: Vec::new(),
+            lets: Vec::new(),
+            awaits: Vec::new(),
+            exits_early: false,
+        }
+    }
+}
+
+/// Returns the index of the first await in `body` whose future could run
+/// concurrently with those of other iterations.
+///
+/// Loops that can exit early are never reported, because later iterations
+/// depend on earlier ones not stopping the loop.
+#[must_use]
+pub(crate) fn independent_await<K: Clone + Eq + Hash>(body: &LoopBody<K>) -> Option<usize> {
+    if body.exits_early {
+        return None;
+    }
+    let from_item = derived(&body.item, &body.lets);
+    let from_state = derived(&body.mutated, &body.lets);
+    body.awaits.iter().position(|reads| {
+        reads.iter().any(|read| from_item.contains(read))
+            && !reads.iter().any(|read| from_state.contains(read))
+    })
+}
+
+/// Returns `roots` and every variable a chain of `lets` derives from them.
+fn derived<K: Clone + Eq + Hash>(roots: &[K], lets: &[Binding<K>]) -> HashSet<K> {
+    let mut found: HashSet<K> = roots.iter().cloned().collect();
+    loop {
+        let before = found.len();
+        for binding in lets {
+            if binding.reads.iter().any(|read| found.contains(read)) {
+                found.extend(binding.names.iter().cloned());
+            }
+        }
+        if found.len() == before {
+            return found;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn binding(names: &[&'static str], reads: &[&'static str]) -> Binding<&'static str> {
+        Binding {
+            names: names.to_vec(),
+            reads: reads.to_vec(),
+        }
+    }
+
+    fn body(awaits: Vec<Vec<&'static str>>) -> LoopBody<&'static str> {
+        LoopBody {
+            item: vec!["id"],
+            awaits,
+            ..LoopBody::default()
+        }
+    }
+
+    #[rstest]
+    #[case::reads_item(vec![vec!["client", "id"]], Some(0))]
+    #[case::ignores_unrelated(vec![vec!["client"]], None)]
+    #[case::finds_later_await(vec![vec!["client"], vec!["id"]], Some(1))]
+    fn reports_awaits_on_the_item(
+        #[case] awaits: Vec<Vec<&'static str>>,
+        #[case] expected: Option<usize>,
+    ) {
+        assert_eq!(independent_await(&body(awaits)), expected);
+    }
+
+    #[rstest]
+    fn follows_let_bindings_from_the_item() {
+        let loop_body = LoopBody {
+            lets: vec![binding(&["path"], &["id"]), binding(&["url"], &["path"])],
+            ..body(vec![vec!["url"]])
+        };
+
+        assert_eq!(independent_await(&loop_body), Some(0));
+    }
+
+    #[rstest]
+    fn rejects_futures_reading_mutated_state() {
+        let loop_body = LoopBody {
+            mutated: vec!["cursor"],
+            lets: vec![binding(&["token"], &["cursor"])],
+            ..body(vec![vec!["id", "token"]])
+        };
+
+        assert_eq!(independent_await(&loop_body), None);
+    }
+
+    #[rstest]
+    fn accepts_mutation_the_future_does_not_read() {
+        let loop_body = LoopBody {
+            mutated: vec!["results"],
+            ..body(vec![vec!["id"]])
+        };
+
+        assert_eq!(independent_await(&loop_body), Some(0));
+    }
+
+    #[rstest]
+    fn rejects_loops_that_exit_early() {
+        let loop_body = LoopBody {
+            exits_early: true,
+            ..body(vec![vec!["id"]])
+        };
+
+        assert_eq!(independent_await(&loop_body), None);
+    }
+}
@@ -0,0 +1,100 @@
+//! Behaviour-driven coverage for the sequential await policy.
+
+use crate::policy::{Binding, LoopBody, independent_await};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct AwaitWorld {
+    body: RefCell<LoopBody<String>>,
+    reported: RefCell<Option<Option<usize>>>,
+}
+
+#[fixture]
+fn world() -> AwaitWorld {
+    AwaitWorld::default()
+}
+
+fn names(value: &str) -> Vec<String> {
+    value
+        .trim_matches('"')
+        .split(',')
+        .map(|name| name.trim().to_owned())
+        .collect()
+}
+
+#[given("a loop over {item}")]
+fn given_loop(world: &AwaitWorld, item: String) {
+    world.body.borrow_mut().item = names(&item);
+}
+
+#[given("the body binds {name} from {source}")]
+fn given_binding(world: &AwaitWorld, name: String, source: String) {
+    world.body.borrow_mut().lets.push(Binding {
+        names: names(&name),
+        reads: names(&source),
+    });
+}
+
+#[given("the body mutates {name}")]
+fn given_mutation(world: &AwaitWorld, name: String) {
+    world.body.borrow_mut().mutated.extend(names(&name));
+}
+
+#[given("the loop awaits a future reading {reads}")]
+fn given_await(world: &AwaitWorld, reads: String) {
+    world.body.borrow_mut().awaits.push(names(&reads));
+}
+
+#[given("the body can break out of the loop")]
+fn given_break(world: &AwaitWorld) {
+    world.body.borrow_mut().exits_early = true;
+}
+
+#[when("the loop is checked")]
+fn when_checked(world: &AwaitWorld) {
+    *world.reported.borrow_mut() = Some(independent_await(&world.body.borrow()));
+}
+
+#[then("the await is reported")]
+fn then_reported(world: &AwaitWorld) {
+    assert_eq!(*world.reported.borrow(), Some(Some(0)));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &AwaitWorld) {
+    assert_eq!(*world.reported.borrow(), Some(None));
+}
+
+#[scenario(
+    path = "tests/features/await_in_loop_without_concurrency.feature",
+    index = 0
+)]
+fn scenario_item_await(world: AwaitWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/await_in_loop_without_concurrency.feature",
+    index = 1
+)]
+fn scenario_derived_await(world: AwaitWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/await_in_loop_without_concurrency.feature",
+    index = 2
+)]
+fn scenario_mutated_state(world: AwaitWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/await_in_loop_without_concurrency.feature",
+    index = 3
+)]
+fn scenario_early_exit(world: AwaitWorld) {
+    let _ = world;
+}
@@ -0,0 +1,31 @@
+Feature: Sequential awaits in loops
+  A `for` loop is reported when it awaits a future built from the loop item
+  and from no state an earlier iteration changed, and it cannot leave the
+  loop early.
+
+  Scenario: An await on the loop item is reported
+    Given a loop over "id"
+    And the loop awaits a future reading "client, id"
+    When the loop is checked
+    Then the await is reported
+
+  Scenario: An await on a value derived from the item is reported
+    Given a loop over "id"
+    And the body binds "url" from "id"
+    And the loop awaits a future reading "client, url"
+    When the loop is checked
+    Then the await is reported
+
+  Scenario: An await reading state the loop mutates is accepted
+    Given a loop over "id"
+    And the body mutates "cursor"
+    And the loop awaits a future reading "id, cursor"
+    When the loop is checked
+    Then nothing is reported
+
+  Scenario: A loop that can break is accepted
+    Given a loop over "id"
+    And the loop awaits a future reading "id"
+    And the body can break out of the loop
+    When the loop is checked
+    Then nothing is reported
@@ -0,0 +1,26 @@
+//! Negative UI fixture: loops awaiting independent futures one at a time.
+#![allow(dead_code)]
+
+async fn fetch(id: u32) -> u32 {
+    id * 2
+}
+
+async fn fetch_all(ids: Vec<u32>) -> Vec<u32> {
+    let mut results = Vec::new();
+    for id in ids {
+        results.push(fetch(id).await);
+    }
+    results
+}
+
+async fn fetch_labelled(ids: &[u32]) -> Vec<String> {
+    let mut labels = Vec::new();
+    for &id in ids {
+        let doubled = id + id;
+        let value = fetch(doubled).await;
+        labels.push(format!("{id}: {value}"));
+    }
+    labels
+}
+
+fn main() {}
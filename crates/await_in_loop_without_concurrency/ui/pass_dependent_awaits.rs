@@ -0,0 +1,33 @@
+//! Positive UI fixture: loops whose awaits depend on earlier iterations.
+#![allow(dead_code)]
+
+async fn fetch(id: u32) -> u32 {
+    id * 2
+}
+
+async fn running_total(ids: Vec<u32>) -> u32 {
+    let mut total = 0;
+    for id in ids {
+        total = fetch(id + total).await;
+    }
+    total
+}
+
+async fn first_large(ids: Vec<u32>) -> Option<u32> {
+    for id in ids {
+        if fetch(id).await > 10 {
+            return Some(id);
+        }
+    }
+    None
+}
+
+async fn repeated(ids: Vec<u32>) -> u32 {
+    let mut count = 0;
+    for _ in ids {
+        count += fetch(1).await;
+    }
+    count
+}
+
+fn main() {}
@@ -3,7 +3,16 @@
 //! The lint pass uses these helpers after constructing and smoothing the
 //! per-line complexity signal. Keeping this logic independent from `rustc_*`
 //! APIs allows unit and behavioural testing without compiling the compiler
-//! driver.
+//! driver, and lets tools outside the compiler score functions through
+//! [`analyse_signal`] and [`analyse_segments`].
+
+use std::ops::RangeInclusive;
+
+mod offline;
+
+pub use offline::{
+    Bump, SignalAnalysis, SignalParseError, analyse_segments, analyse_signal, parse_signal,
+};
 
 /// Default smoothed-signal threshold at which a bump is considered active.
 ///
@@ -43,10 +52,16 @@ pub struct Settings {
     pub window: usize,
     /// Minimum number of contiguous lines required to keep a bump.
     pub min_bump_lines: usize,
+    /// Minimum number of lines below the threshold that keep two bumps
+    /// apart; closer bumps are merged into one.
+    pub min_bump_separation: usize,
     /// Segment weights.
     pub weights: Weights,
     /// Whether closure bodies are inspected as additional function-like scopes.
     pub include_closures: bool,
+    /// Whether code expanded from macros counts towards the function that
+    /// invokes them, attributed to the lines of each invocation.
+    pub include_macro_expansions: bool,
 }
 
 impl Default for Settings {
@@ -55,8 +70,10 @@ impl Default for Settings {
             threshold: DEFAULT_THRESHOLD,
             window: 3,
             min_bump_lines: 2,
+            min_bump_separation: 1,
             weights: Weights::default(),
             include_closures: false,
+            include_macro_expansions: false,
         }
     }
 }
@@ -115,6 +132,7 @@ pub fn normalise_settings(settings: Settings) -> Settings {
     };
 
     let min_bump_lines = settings.min_bump_lines.max(1);
+    let min_bump_separation = settings.min_bump_separation.max(1);
     let weights = Weights {
         depth: normalise_weight(settings.weights.depth, defaults.weights.depth),
         predicate: normalise_weight(settings.weights.predicate, defaults.weights.predicate),
@@ -125,8 +143,10 @@ pub fn normalise_settings(settings: Settings) -> Settings {
         threshold,
         window,
         min_bump_lines,
+        min_bump_separation,
         weights,
         include_closures: settings.include_closures,
+        include_macro_expansions: settings.include_macro_expansions,
     }
 }
 
@@ -167,6 +187,27 @@ impl BumpInterval {
         self.start_index > self.end_index
     }
 
+    /// Source lines covered by the bump when the signal starts at
+    /// `first_line`.
+    ///
+    /// The driver rasterizes one sample per source line, so this maps the
+    /// interval back onto the function body for diagnostics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bumpy_road_function::analysis::detect_bumps;
+    ///
+    /// let signal = [0.0, 3.0, 3.2, 0.0];
+    /// let bumps = detect_bumps(&signal, 3.0, 2);
+    ///
+    /// assert_eq!(bumps[0].lines(40), 41..=42);
+    /// ```
+    #[must_use]
+    pub const fn lines(self, first_line: usize) -> RangeInclusive<usize> {
+        (first_line + self.start_index)..=(first_line + self.end_index)
+    }
+
     /// Area above the threshold used for ranking bumps.
     #[must_use]
     pub const fn area_above_threshold(self) -> f64 {
@@ -278,6 +319,56 @@ fn finalize_bump(
     }
 }
 
+/// Merges bumps separated by fewer than `min_separation` lines below the
+/// threshold.
+///
+/// A merged bump spans both intervals and the gap between them, and its area
+/// is the sum of theirs. A separation of one, the default, keeps every bump
+/// [`detect_bumps`] reports apart.
+///
+/// The intervals need not be sorted or disjoint: they are ordered by start
+/// index before merging, and an interval that overlaps or touches the one
+/// before it counts as zero lines away, so it merges whenever
+/// `min_separation` is positive.
+///
+/// # Examples
+///
+/// ```
+/// use bumpy_road_function::analysis::{detect_bumps, merge_close_bumps};
+///
+/// let signal = [0.0, 3.0, 3.2, 0.0, 3.1, 3.0, 0.0, 0.0, 0.0, 3.0, 3.0];
+/// let bumps = merge_close_bumps(detect_bumps(&signal, 3.0, 2), 2);
+///
+/// assert_eq!(bumps.len(), 2);
+/// assert_eq!((bumps[0].start_index(), bumps[0].end_index()), (1, 5));
+/// assert_eq!((bumps[1].start_index(), bumps[1].end_index()), (9, 10));
+/// ```
+#[must_use]
+pub fn merge_close_bumps(
+    mut intervals: Vec<BumpInterval>,
+    min_separation: usize,
+) -> Vec<BumpInterval> {
+    intervals.sort_by_key(|interval| interval.start_index);
+    let mut merged: Vec<BumpInterval> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(previous) if lines_between(*previous, interval) < min_separation => {
+                previous.end_index = previous.end_index.max(interval.end_index);
+                previous.area_above_threshold += interval.area_above_threshold;
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
+
+/// Counts the lines strictly between `previous` and `next`, which starts no
+/// earlier than `previous`; overlapping or adjacent intervals are zero apart.
+const fn lines_between(previous: BumpInterval, next: BumpInterval) -> usize {
+    next.start_index
+        .saturating_sub(previous.end_index.saturating_add(1))
+}
+
 /// Returns the two most severe bumps by area (breaking ties by longest interval, then earliest).
 ///
 /// # Examples
@@ -0,0 +1,247 @@
+//! Whole-signal analysis that runs without the compiler.
+//!
+//! The lint driver builds weighted line segments from HIR, but everything
+//! after that point only needs numbers. [`analyse_segments`] and
+//! [`analyse_signal`] run the same smoothing, detection, and merging steps on
+//! segments or a raw per-line signal and report [`Bump`]s on source lines, so
+//! CI tools and editors can score changed functions without loading `rustc`.
+//! [`parse_signal`] reads a signal written as plain text, one sample per line.
+//! Enable the `serde` feature to serialize the results.
+
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+use whitaker_common::complexity_signal::{
+    LineSegment, SignalBuildError, rasterize_signal, smooth_moving_average,
+};
+
+use super::{BumpInterval, Settings, detect_bumps, merge_close_bumps, normalise_settings};
+
+/// A bump located on the source lines it covers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bump {
+    start_line: usize,
+    end_line: usize,
+    peak: f64,
+    area_above_threshold: f64,
+}
+
+impl Bump {
+    /// First source line covered by the bump (inclusive).
+    #[must_use]
+    pub const fn start_line(self) -> usize {
+        self.start_line
+    }
+
+    /// Last source line covered by the bump (inclusive).
+    #[must_use]
+    pub const fn end_line(self) -> usize {
+        self.end_line
+    }
+
+    /// Source lines covered by the bump.
+    #[must_use]
+    pub const fn lines(self) -> RangeInclusive<usize> {
+        self.start_line..=self.end_line
+    }
+
+    /// Highest smoothed value inside the bump.
+    #[must_use]
+    pub const fn peak(self) -> f64 {
+        self.peak
+    }
+
+    /// Area of the smoothed signal above the threshold inside the bump.
+    #[must_use]
+    pub const fn area_above_threshold(self) -> f64 {
+        self.area_above_threshold
+    }
+}
+
+/// The smoothed signal of one function and the bumps found in it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalAnalysis {
+    first_line: usize,
+    smoothed: Vec<f64>,
+    bumps: Vec<Bump>,
+}
+
+impl SignalAnalysis {
+    /// Source line of the first sample.
+    #[must_use]
+    pub const fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    /// Smoothed per-line signal, starting at [`Self::first_line`].
+    #[must_use]
+    pub fn smoothed(&self) -> &[f64] {
+        &self.smoothed
+    }
+
+    /// Bumps in source order, after merging those closer than the
+    /// configured separation.
+    #[must_use]
+    pub fn bumps(&self) -> &[Bump] {
+        &self.bumps
+    }
+
+    /// Returns `true` when the lint would report the function: it has two or
+    /// more separated bumps.
+    #[must_use]
+    pub fn is_bumpy(&self) -> bool {
+        self.bumps.len() >= 2
+    }
+
+    /// Total area above the threshold across all bumps, for ranking
+    /// functions by how much clustered complexity they carry.
+    #[must_use]
+    pub fn score(&self) -> f64 {
+        self.bumps
+            .iter()
+            .map(|bump| bump.area_above_threshold)
+            .sum()
+    }
+}
+
+/// Analyses a raw per-line signal whose first sample describes
+/// `first_line`.
+///
+/// Settings are normalised first, so invalid values fall back to defaults as
+/// they do in the lint.
+///
+/// # Examples
+///
+/// ```
+/// use bumpy_road_function::analysis::{Settings, analyse_signal};
+///
+/// let signal = [0.0, 3.0, 3.0, 3.0, 3.0, 0.0, 0.0, 0.0, 3.0, 3.0, 3.0, 3.0, 0.0];
+/// let analysis = analyse_signal(&signal, 10, Settings::default());
+///
+/// assert!(analysis.is_bumpy());
+/// assert_eq!(analysis.bumps()[0].lines(), 12..=13);
+/// assert_eq!(analysis.bumps()[1].lines(), 19..=20);
+/// ```
+#[must_use]
+pub fn analyse_signal(signal: &[f64], first_line: usize, settings: Settings) -> SignalAnalysis {
+    let settings = normalise_settings(settings);
+    // Normalised windows are always odd and positive, so smoothing succeeds.
+    let smoothed =
+        smooth_moving_average(signal, settings.window).unwrap_or_else(|_| signal.to_vec());
+    let intervals = merge_close_bumps(
+        detect_bumps(&smoothed, settings.threshold, settings.min_bump_lines),
+        settings.min_bump_separation,
+    );
+    let bumps = intervals
+        .into_iter()
+        .map(|interval| locate(interval, &smoothed, first_line))
+        .collect();
+
+    SignalAnalysis {
+        first_line,
+        smoothed,
+        bumps,
+    }
+}
+
+/// Rasterizes weighted line segments over `lines` and analyses the
+/// resulting signal.
+///
+/// # Errors
+///
+/// Returns [`SignalBuildError`] when `lines` is not one-based or a segment
+/// falls outside it.
+///
+/// # Examples
+///
+/// ```
+/// use bumpy_road_function::analysis::{Settings, analyse_segments};
+/// use whitaker_common::complexity_signal::LineSegment;
+///
+/// let segments = [
+///     LineSegment::new(2, 5, 3.0).expect("segment should be valid"),
+///     LineSegment::new(8, 11, 3.0).expect("segment should be valid"),
+/// ];
+/// let analysis = analyse_segments(1..=12, &segments, Settings::default())
+///     .expect("segments should lie inside the function");
+///
+/// assert_eq!(analysis.bumps().len(), 2);
+/// ```
+pub fn analyse_segments(
+    lines: RangeInclusive<usize>,
+    segments: &[LineSegment],
+    settings: Settings,
+) -> Result<SignalAnalysis, SignalBuildError> {
+    let first_line = *lines.start();
+    let signal = rasterize_signal(lines, segments)?;
+    Ok(analyse_signal(&signal, first_line, settings))
+}
+
+/// Errors raised while reading a per-line signal from text.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum SignalParseError {
+    /// A line held something other than a finite, non-negative number.
+    #[error("line {line}: `{text}` is not a finite, non-negative complexity value")]
+    InvalidSample {
+        /// One-based line of the text on which the sample appears.
+        line: usize,
+        /// The offending text, trimmed.
+        text: String,
+    },
+}
+
+/// Reads a per-line signal written as plain text, one sample per line.
+///
+/// Surrounding whitespace is ignored and blank lines read as zero, so a
+/// signal can be produced by tools that emit nothing for simple lines.
+///
+/// # Errors
+///
+/// Returns [`SignalParseError::InvalidSample`] for the first line that is
+/// neither blank nor a finite, non-negative number.
+///
+/// # Examples
+///
+/// ```
+/// use bumpy_road_function::analysis::parse_signal;
+///
+/// assert_eq!(parse_signal("0\n1.5\n\n 2 "), Ok(vec![0.0, 1.5, 0.0, 2.0]));
+/// assert!(parse_signal("1\nhigh").is_err());
+/// ```
+pub fn parse_signal(text: &str) -> Result<Vec<f64>, SignalParseError> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                return Ok(0.0);
+            }
+            trimmed
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite() && *value >= 0.0)
+                .ok_or_else(|| SignalParseError::InvalidSample {
+                    line: index + 1,
+                    text: trimmed.to_owned(),
+                })
+        })
+        .collect()
+}
+
+fn locate(interval: BumpInterval, smoothed: &[f64], first_line: usize) -> Bump {
+    let lines = interval.lines(first_line);
+    let peak = smoothed
+        .get(interval.start_index()..=interval.end_index())
+        .unwrap_or_default()
+        .iter()
+        .copied()
+        .fold(0.0, f64::max);
+    Bump {
+        start_line: *lines.start(),
+        end_line: *lines.end(),
+        peak,
+        area_above_threshold: interval.area_above_threshold(),
+    }
+}
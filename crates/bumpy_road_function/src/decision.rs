@@ -0,0 +1,129 @@
+//! Pure decision logic for `bumpy_road_function`.
+//!
+//! The driver turns each function body into weighted line segments and
+//! rasterises them into a per-line complexity signal.
+//! [`BumpyRoadFunctionDecision`] runs the same [`analyse_signal`] smoothing,
+//! detection, and merging and reports functions the analysis finds bumpy,
+//! naming the number of bumps as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::analysis::{Settings, analyse_signal, normalise_settings};
+use crate::driver::LINT_NAME;
+
+/// Reason key for functions with two or more separated bumps.
+const BUMPY_ROAD: &str = "bumpy_road";
+
+/// The facts about one function that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FunctionSignalProjection {
+    /// The unsmoothed complexity of each line of the function, in order.
+    pub signal: Vec<f64>,
+}
+
+/// Reports functions whose smoothed complexity signal has two or more
+/// separated bumps.
+///
+/// # Examples
+///
+/// ```ignore
+/// let function = FunctionSignalProjection {
+///     signal: vec![0.0, 3.0, 3.0, 3.0, 3.0, 0.0, 0.0, 0.0, 3.0, 3.0, 3.0, 3.0, 0.0],
+/// };
+/// let decision = BumpyRoadFunctionDecision::default();
+/// assert!(decision.decide(&function).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BumpyRoadFunctionDecision {
+    settings: Settings,
+}
+
+impl BumpyRoadFunctionDecision {
+    /// Creates a decision from `settings`, falling back to the defaults for
+    /// invalid values as the lint does.
+    #[must_use]
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            settings: normalise_settings(settings),
+        }
+    }
+}
+
+impl LintDecision for BumpyRoadFunctionDecision {
+    type Projection = FunctionSignalProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, function: &FunctionSignalProjection) -> Option<Finding> {
+        let analysis = analyse_signal(&function.signal, 1, self.settings);
+        analysis.is_bumpy().then(|| {
+            Finding::new(LINT_NAME, BUMPY_ROAD).with_subject(analysis.bumps().len().to_string())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<FunctionSignalProjection>> {
+        let Settings {
+            threshold,
+            window,
+            min_bump_lines,
+            min_bump_separation,
+            ..
+        } = self.settings;
+        // Smoothing blurs the edges of each run, so runs and gaps are padded
+        // by the window to survive it.
+        let peak = 2.0 * threshold + 1.0;
+        let run = vec![peak; min_bump_lines + window];
+        let gap = vec![0.0; min_bump_separation + 2 * window];
+        let function = |parts: &[&[f64]]| FunctionSignalProjection {
+            signal: parts.concat(),
+        };
+        vec![
+            DecisionFixture::flagged(
+                "two separated bumps",
+                function(&[&gap, &run, &gap, &run, &gap]),
+                BUMPY_ROAD,
+            ),
+            DecisionFixture::clean("one bump", function(&[&gap, &run, &gap])),
+            DecisionFixture::clean("flat signal", function(&[&gap, &gap])),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_count_the_bumps() {
+        let function = FunctionSignalProjection {
+            signal: vec![
+                0.0, 3.0, 3.0, 3.0, 3.0, 0.0, 0.0, 0.0, 3.0, 3.0, 3.0, 3.0, 0.0,
+            ],
+        };
+        let finding = BumpyRoadFunctionDecision::default()
+            .decide(&function)
+            .expect("the function should be reported");
+
+        assert_eq!(finding.subject(), Some("2"));
+    }
+
+    #[rstest]
+    #[case::defaults(Settings::default())]
+    #[case::wide_window(Settings {
+        threshold: 4.0,
+        window: 5,
+        min_bump_lines: 3,
+        min_bump_separation: 2,
+        ..Settings::default()
+    })]
+    fn decision_conforms(#[case] settings: Settings) {
+        assert_eq!(
+            check_conformance(&BumpyRoadFunctionDecision::new(settings)),
+            Ok(3)
+        );
+    }
+}
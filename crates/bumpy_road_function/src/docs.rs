@@ -0,0 +1,65 @@
+//! Embedded documentation for the `bumpy_road_function` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `bumpy_road_function`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "bumpy_road_function",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags functions with two or more separate clusters of complex conditional logic.",
+    rationale: "Each bump of nested or compound conditions is usually a responsibility of its own; extracting them shortens the function and names the steps.",
+    bad_example: concat!(
+        "fn process(input: &Input) {\n",
+        "    if a && (b || c) { /* ... */ }\n",
+        "    // unrelated work\n",
+        "    if d && (e || f) { /* ... */ }\n",
+        "}",
+    ),
+    good_example: concat!(
+        "fn process(input: &Input) {\n",
+        "    validate(input);\n",
+        "    // unrelated work\n",
+        "    dispatch(input);\n",
+        "}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "threshold",
+            default: "2.5",
+            description: "Smoothed complexity at which a line counts as part of a bump.",
+        },
+        ConfigKeyDocs {
+            key: "window",
+            default: "3",
+            description: "Size of the centred moving-average window.",
+        },
+        ConfigKeyDocs {
+            key: "min_bump_lines",
+            default: "2",
+            description: "Minimum number of lines a bump must span.",
+        },
+        ConfigKeyDocs {
+            key: "min_bump_separation",
+            default: "1",
+            description: "Minimum number of lines below the threshold between two bumps; closer bumps count as one.",
+        },
+        ConfigKeyDocs {
+            key: "include_closures",
+            default: "false",
+            description: "Analyse closure bodies as well as functions.",
+        },
+        ConfigKeyDocs {
+            key: "include_macro_expansions",
+            default: "false",
+            description: "Count code expanded from macros towards the invoking function, on the lines of each invocation.",
+        },
+        ConfigKeyDocs {
+            key: "weights",
+            default: "{ depth = 1.0, predicate = 0.5, flow = 0.5 }",
+            description: "Weights applied to nesting depth, predicate branches, and control flow.",
+        },
+    ],
+};
@@ -2,10 +2,10 @@
 //!
 //! The detector converts nested control-flow and predicate complexity into a
 //! per-line signal, applies moving-average smoothing, then identifies two or
-//! more separated bumps above a configurable threshold. The warning highlights
-//! the two largest bump intervals with labelled spans.
+//! more separated bumps above a configurable threshold. The warning carries a
+//! note on each bump, spanning the lines it covers.
 
-use crate::analysis::{Settings, detect_bumps, normalise_settings};
+use crate::analysis::{Settings, analyse_segments, normalise_settings};
 use rustc_hir as hir;
 use rustc_hir::ExprKind;
 use rustc_lint::{LateContext, LateLintPass};
@@ -13,20 +13,20 @@ use rustc_span::Ident;
 use rustc_span::Span;
 use rustc_span::symbol::Symbol;
 use whitaker::SharedConfig;
-use whitaker_common::complexity_signal::{rasterize_signal, smooth_moving_average};
+use whitaker::hir::FindingLimit;
 use whitaker_common::i18n::MessageKey;
-use whitaker_common::{Localizer, get_localizer_for_lint};
+use whitaker_common::{CrateScopedState, Localizer, get_localizer_for_lint};
 
-const LINT_NAME: &str = "bumpy_road_function";
+pub(crate) const LINT_NAME: &str = "bumpy_road_function";
 const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
 
 mod config;
 mod diagnostic;
 mod segment_builder;
 
-use self::config::load_configuration;
+use self::config::{Config, load_configuration};
 use self::diagnostic::{DiagnosticInput, emit_diagnostic};
-use self::segment_builder::{SegmentBuilder, span_line_range};
+use self::segment_builder::{EARLY_EXIT_SIGNALS, SegmentBuilder, span_line_range};
 
 dylint_linting::impl_late_lint! {
     pub BUMPY_ROAD_FUNCTION,
@@ -36,25 +36,36 @@ dylint_linting::impl_late_lint! {
 }
 
 /// Lint pass that caches configuration and localization for a crate.
+#[derive(Default)]
 pub struct BumpyRoadFunction {
-    settings: Settings,
-    localizer: Localizer,
+    state: CrateScopedState<CrateState>,
 }
 
-impl Default for BumpyRoadFunction {
-    fn default() -> Self {
-        Self {
-            settings: Settings::default(),
-            localizer: Localizer::new(None),
-        }
-    }
+/// Configuration, behaviour gates, finding limit, and localization loaded for
+/// the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    early_exits: bool,
+    findings: FindingLimit,
+    localizer: Localizer,
 }
 
 impl<'tcx> LateLintPass<'tcx> for BumpyRoadFunction {
     fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
-        self.settings = normalise_settings(load_configuration().into_settings());
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            early_exits: shared_config
+                .behaviour_version(LINT_NAME)
+                .enables(EARLY_EXIT_SIGNALS),
+            findings: FindingLimit::new(BUMPY_ROAD_FUNCTION, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
@@ -82,13 +93,12 @@ impl<'tcx> LateLintPass<'tcx> for BumpyRoadFunction {
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
-        if !self.settings.include_closures {
-            return;
-        }
-
         let Some(target) = extract_expr_target(expr) else {
             return;
         };
+        if !settings_at(cx, &self.state, expr.span).include_closures {
+            return;
+        }
 
         self.analyse_if_not_expanded(cx, expr.span, target);
     }
@@ -100,7 +110,7 @@ impl BumpyRoadFunction {
             return;
         }
 
-        analyse_body(cx, target, &self.settings, &self.localizer);
+        analyse_body(cx, target, &self.state);
     }
 }
 
@@ -170,12 +180,19 @@ struct AnalysisTarget {
     body_id: hir::BodyId,
 }
 
-fn analyse_body(
-    cx: &LateContext<'_>,
-    target: AnalysisTarget,
-    settings: &Settings,
-    localizer: &Localizer,
-) {
+/// Returns the settings for a body at `span`, with the directory overrides
+/// above its file applied.
+fn settings_at(cx: &LateContext<'_>, state: &CrateState, span: Span) -> Settings {
+    normalise_settings(
+        state
+            .findings
+            .config_at(cx, span, &state.config)
+            .into_settings(),
+    )
+}
+
+fn analyse_body(cx: &LateContext<'_>, target: AnalysisTarget, state: &CrateState) {
+    let settings = &settings_at(cx, state, target.primary_span);
     let body = cx.tcx.hir_body(target.body_id);
     let body_span = body.value.span;
     if body_span.from_expansion() {
@@ -188,11 +205,12 @@ fn analyse_body(
     };
 
     let mut segments = Vec::new();
-    let mut builder = SegmentBuilder::new(cx, settings, function_lines.clone(), &mut segments);
+    let mut builder = SegmentBuilder::new(cx, settings, function_lines.clone(), &mut segments)
+        .counting_early_exits(state.early_exits);
     builder.visit_expr(body.value);
 
-    let signal = match rasterize_signal(function_lines.clone(), &segments) {
-        Ok(signal) => signal,
+    let analysis = match analyse_segments(function_lines.clone(), &segments, *settings) {
+        Ok(analysis) => analysis,
         Err(error) => {
             cx.tcx.sess.dcx().span_delayed_bug(
                 body_span,
@@ -202,19 +220,11 @@ fn analyse_body(
         }
     };
 
-    let smoothed = match smooth_moving_average(&signal, settings.window) {
-        Ok(signal) => signal,
-        Err(error) => {
-            cx.tcx.sess.dcx().span_delayed_bug(
-                body_span,
-                format!("bumpy-road signal smoothing failed: {error}"),
-            );
-            return;
-        }
-    };
-
-    let bumps = detect_bumps(&smoothed, settings.threshold, settings.min_bump_lines);
-    if bumps.len() < 2 {
+    if !analysis.is_bumpy()
+        || !state
+            .findings
+            .admit(cx, cx.last_node_with_lint_attrs, target.primary_span)
+    {
         return;
     }
 
@@ -225,9 +235,9 @@ fn analyse_body(
             primary_span: target.primary_span,
             body_span,
             function_lines,
-            bumps,
+            bumps: analysis.bumps().to_vec(),
             settings,
         },
-        localizer,
+        &state.localizer,
     );
 }
@@ -1,25 +1,29 @@
 //! Configuration parsing and loading for the bumpy road lint.
 //!
 //! The lint reads optional configuration from `dylint.toml`, applies defaults,
-//! and relies on `analysis::normalise_settings` to clamp invalid values.
+//! and relies on `analysis::normalise_settings` to clamp invalid values. The
+//! driver lays nested `whitaker.toml` overrides over the loaded value before
+//! converting it, so overridden values are clamped the same way.
 
 use crate::analysis::{Settings, Weights};
 use log::debug;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::LINT_NAME;
 
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub(super) struct Config {
     threshold: f64,
     window: usize,
     min_bump_lines: usize,
+    min_bump_separation: usize,
     include_closures: bool,
+    include_macro_expansions: bool,
     weights: WeightsConfig,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 struct WeightsConfig {
     depth: f64,
@@ -45,7 +49,9 @@ impl Default for Config {
             threshold: defaults.threshold,
             window: defaults.window,
             min_bump_lines: defaults.min_bump_lines,
+            min_bump_separation: defaults.min_bump_separation,
             include_closures: defaults.include_closures,
+            include_macro_expansions: defaults.include_macro_expansions,
             weights: WeightsConfig::default(),
         }
     }
@@ -57,7 +63,9 @@ impl Config {
             threshold: self.threshold,
             window: self.window,
             min_bump_lines: self.min_bump_lines,
+            min_bump_separation: self.min_bump_separation,
             include_closures: self.include_closures,
+            include_macro_expansions: self.include_macro_expansions,
             weights: Weights {
                 depth: self.weights.depth,
                 predicate: self.weights.predicate,
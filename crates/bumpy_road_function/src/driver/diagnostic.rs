@@ -1,12 +1,13 @@
 //! Diagnostic emission for the bumpy road lint.
 //!
 //! The lint warns when it detects two or more separated bump intervals in the
-//! smoothed signal and highlights the two most severe bumps.
+//! smoothed signal and attaches a note to each bump, in source order, pointing
+//! at the lines it covers.
 
 use std::borrow::Cow;
 use std::ops::RangeInclusive;
 
-use crate::analysis::{BumpInterval, Settings, top_two_bumps};
+use crate::analysis::{Bump, Settings};
 use fluent_templates::fluent_bundle::FluentValue;
 use rustc_lint::{LateContext, LintContext};
 use rustc_span::{BytePos, Span};
@@ -23,7 +24,7 @@ pub(super) struct DiagnosticInput<'a> {
     pub(super) primary_span: Span,
     pub(super) body_span: Span,
     pub(super) function_lines: RangeInclusive<usize>,
-    pub(super) bumps: Vec<BumpInterval>,
+    pub(super) bumps: Vec<Bump>,
     pub(super) settings: &'a Settings,
 }
 
@@ -52,23 +53,23 @@ pub(super) fn emit_diagnostic(
         fallback_messages(input.name, input.bumps.len(), input.settings.threshold)
     });
 
-    let highlighted = top_two_bumps(input.bumps);
-    let bump_spans = build_bump_spans(cx, input.body_span, &input.function_lines, &highlighted);
+    let bump_spans = build_bump_spans(cx, input.body_span, &input.function_lines, &input.bumps);
 
     cx.emit_span_lint(
         BUMPY_ROAD_FUNCTION,
         input.primary_span,
         rustc_lint::errors::DiagDecorator(|lint| {
             lint.primary_message(messages.primary().to_string());
-            lint.span_note(input.primary_span, messages.note().to_string());
+            lint.note(messages.note().to_string());
 
-            for (ordinal, interval) in highlighted.iter().enumerate() {
+            for (ordinal, bump) in input.bumps.iter().enumerate() {
                 let Some(span) = bump_spans.get(ordinal).copied().flatten() else {
                     continue;
                 };
-                let label =
-                    resolve_bump_label(localizer, (ordinal + 1) as i64, interval.len() as i64);
-                lint.span_label(span, label);
+                lint.span_note(
+                    span,
+                    resolve_bump_note(localizer, ordinal + 1, bump.lines()),
+                );
             }
 
             lint.help(messages.help().to_string());
@@ -80,24 +81,19 @@ fn build_bump_spans(
     cx: &LateContext<'_>,
     body_span: Span,
     function_lines: &RangeInclusive<usize>,
-    highlighted: &[BumpInterval],
+    bumps: &[Bump],
 ) -> Vec<Option<Span>> {
     let source_map = cx.tcx.sess.source_map();
     let Ok(snippet) = source_map.span_to_snippet(body_span) else {
-        return vec![None; highlighted.len()];
+        return vec![None; bumps.len()];
     };
 
-    let line_starts = line_start_offsets(&snippet);
     let body_start_line = *function_lines.start();
-    let mapper = LineSpanMapper::new(body_span, snippet.len(), body_start_line, line_starts);
+    let mapper = LineSpanMapper::new(body_span, &snippet, body_start_line);
 
-    highlighted
+    bumps
         .iter()
-        .map(|interval| {
-            let start_line = body_start_line + interval.start_index();
-            let end_line = body_start_line + interval.end_index();
-            mapper.span_for_range(start_line, end_line)
-        })
+        .map(|bump| mapper.span_for_range(bump.start_line(), bump.end_line()))
         .collect()
 }
 
@@ -113,41 +109,51 @@ fn line_start_offsets(snippet: &str) -> Vec<usize> {
 
 /// Translates 1-based source line ranges into byte-offset `Span`s.
 ///
+/// Spans run from the first non-blank character of the first line to the last
+/// non-blank character of the last line, so notes underline code rather than
+/// indentation.
+///
 /// The span mapper operates on in-memory snippets, so byte offsets are bounded
 /// by the file size. `rustc_span::BytePos` uses `u32`, which is sufficient for
 /// typical source files used with this lint (and matches the compiler's span
 /// representation).
-struct LineSpanMapper {
+struct LineSpanMapper<'a> {
     base_span: Span,
-    snippet_len: usize,
+    snippet: &'a str,
     base_line: usize,
     line_starts: Vec<usize>,
 }
 
-impl LineSpanMapper {
-    fn new(base_span: Span, snippet_len: usize, base_line: usize, line_starts: Vec<usize>) -> Self {
+impl<'a> LineSpanMapper<'a> {
+    fn new(base_span: Span, snippet: &'a str, base_line: usize) -> Self {
         Self {
             base_span,
-            snippet_len,
+            snippet,
             base_line,
-            line_starts,
+            line_starts: line_start_offsets(snippet),
         }
     }
 
+    /// Returns the text of the line at `index` without its line terminator,
+    /// together with its starting byte offset.
+    fn line(&self, index: usize) -> Option<(usize, &'a str)> {
+        let start = *self.line_starts.get(index)?;
+        let end = self
+            .line_starts
+            .get(index + 1)
+            .map_or(self.snippet.len(), |next| next - 1);
+        Some((start, self.snippet.get(start..end)?))
+    }
+
     fn span_for_range(&self, start_line: usize, end_line: usize) -> Option<Span> {
         if start_line < self.base_line || end_line < start_line {
             return None;
         }
 
-        let start_index = start_line - self.base_line;
-        let end_index = end_line - self.base_line;
-
-        let start_offset = *self.line_starts.get(start_index)?;
-        let end_offset = self
-            .line_starts
-            .get(end_index + 1)
-            .copied()
-            .unwrap_or(self.snippet_len);
+        let (first_start, first) = self.line(start_line - self.base_line)?;
+        let (last_start, last) = self.line(end_line - self.base_line)?;
+        let start_offset = first_start + (first.len() - first.trim_start().len());
+        let end_offset = last_start + last.trim_end().len();
 
         let base = self.base_span.shrink_to_lo();
         // `BytePos` is `u32`-backed; the snippet length is expected to fit in
@@ -161,22 +167,34 @@ impl LineSpanMapper {
     }
 }
 
-fn resolve_bump_label(localizer: &Localizer, index: i64, lines: i64) -> String {
+fn resolve_bump_note(localizer: &Localizer, index: usize, lines: RangeInclusive<usize>) -> String {
+    let (start, end) = (*lines.start(), *lines.end());
+    let count = end - start + 1;
     let mut args: Arguments<'_> = Arguments::default();
-    args.insert(Cow::Borrowed("index"), FluentValue::from(index));
-    args.insert(Cow::Borrowed("lines"), FluentValue::from(lines));
+    args.insert(Cow::Borrowed("index"), FluentValue::from(index as i64));
+    args.insert(Cow::Borrowed("lines"), FluentValue::from(count as i64));
+    args.insert(Cow::Borrowed("start"), FluentValue::from(start as i64));
+    args.insert(Cow::Borrowed("end"), FluentValue::from(end as i64));
 
     // Fluent may inject bidi-safe directional isolates. We strip the resulting
     // control characters (plus replacement characters) to keep output stable in
     // diagnostics and UI golden files.
     localizer
-        .attribute_with_args(LINT_NAME, "label", &args)
-        .unwrap_or_else(|_| format!("Complexity bump {index} spans {lines} lines."))
+        .attribute_with_args(LINT_NAME, "bump", &args)
+        .unwrap_or_else(|_| fallback_bump_note(index, start, end))
         .chars()
         .filter(|ch| !matches!(ch, '\u{2068}' | '\u{2069}' | '\u{FFFD}'))
         .collect()
 }
 
+fn fallback_bump_note(index: usize, start: usize, end: usize) -> String {
+    if start == end {
+        format!("Complexity bump {index} covers line {start}.")
+    } else {
+        format!("Complexity bump {index} covers lines {start}–{end}.")
+    }
+}
+
 fn fallback_messages(name: &str, count: usize, threshold: f64) -> DiagnosticMessageSet {
     DiagnosticMessageSet::new(
         format!("Multiple clusters of nested conditional logic in `{name}`."),
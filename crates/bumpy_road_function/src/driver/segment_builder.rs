@@ -1,7 +1,19 @@
 //! Build per-line complexity segments for bump detection.
 //!
 //! The lint converts nested control-flow and predicate branching into weighted
-//! line segments which are then rasterized into a per-line signal.
+//! line segments which are then rasterized into a per-line signal. Early-exit
+//! idioms contribute too: a `let ... else` block counts like a nested branch,
+//! and a `matches!` invocation outside a condition counts as a predicate at its
+//! call site. Those signals, and `if let` match guards, count only at behaviour
+//! version [`EARLY_EXIT_SIGNALS`] or later, so upgrading does not surface new
+//! findings until a workspace opts in.
+//!
+//! Code expanded from macros is skipped, so a large `match` generated by a
+//! macro does not count against the function that invokes it. With
+//! `include_macro_expansions` set, macro output is walked like written code
+//! and each of its segments is attributed to the lines of the outermost
+//! invocation. Compiler desugarings outside macro output stay skipped either
+//! way.
 
 use std::ops::RangeInclusive;
 
@@ -10,14 +22,19 @@ use rustc_hir as hir;
 use rustc_hir::{BinOpKind, ExprKind, LoopSource, UnOp};
 use rustc_lint::LateContext;
 use rustc_span::source_map::SourceMap;
-use rustc_span::{DesugaringKind, Span};
+use rustc_span::{DesugaringKind, ExpnKind, MacroKind, Span, sym};
 use whitaker_common::complexity_signal::LineSegment;
 
+/// Behaviour version that starts counting `let ... else` blocks, `matches!`
+/// invocations, and `if let` match guards.
+pub(super) const EARLY_EXIT_SIGNALS: u32 = 2;
+
 pub(super) struct SegmentBuilder<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     settings: &'a Settings,
     function_lines: RangeInclusive<usize>,
     segments: &'a mut Vec<LineSegment>,
+    early_exits: bool,
 }
 
 impl<'a, 'tcx> SegmentBuilder<'a, 'tcx> {
@@ -32,12 +49,27 @@ impl<'a, 'tcx> SegmentBuilder<'a, 'tcx> {
             settings,
             function_lines,
             segments,
+            early_exits: false,
         }
     }
 
+    /// Counts early-exit idioms when `enabled`; see [`EARLY_EXIT_SIGNALS`].
+    pub(super) const fn counting_early_exits(mut self, enabled: bool) -> Self {
+        self.early_exits = enabled;
+        self
+    }
+
     pub(super) fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
         if expr.span.from_expansion() {
-            return;
+            if self.early_exits
+                && let Some((call_site, arms)) = matches_macro_call(self.cx, expr)
+            {
+                self.visit_matches_macro(call_site, expr, arms);
+                return;
+            }
+            if !self.counts_macro_output(expr.span) {
+                return;
+            }
         }
 
         match expr.kind {
@@ -99,7 +131,11 @@ impl<'a, 'tcx> SegmentBuilder<'a, 'tcx> {
 
     fn visit_match_arm(&mut self, arm: &'tcx hir::Arm<'tcx>) {
         if let Some(guard) = arm.guard {
-            self.push_predicate_segment(guard);
+            if self.early_exits {
+                self.push_guard_segment(guard);
+            } else {
+                self.push_predicate_segment(guard);
+            }
         }
 
         self.push_depth_segment(arm.body.span);
@@ -107,6 +143,34 @@ impl<'a, 'tcx> SegmentBuilder<'a, 'tcx> {
         self.visit_expr(arm.body);
     }
 
+    /// Counts a `matches!` invocation as one predicate branch plus the
+    /// branches of its guard, spanning the invocation.
+    fn visit_matches_macro(
+        &mut self,
+        call_site: Span,
+        expr: &'tcx hir::Expr<'tcx>,
+        arms: &'tcx [hir::Arm<'tcx>],
+    ) {
+        let guard = arms.first().and_then(|arm| arm.guard);
+        let branches = 1 + guard.map_or(0, count_branches);
+        self.push_segment(call_site, branches as f64 * self.settings.weights.predicate);
+
+        if let ExprKind::Match(scrutinee, ..) = expr.kind {
+            self.visit_expr(scrutinee);
+        }
+        if let Some(guard) = guard {
+            self.visit_expr(guard);
+        }
+    }
+
+    /// Treats the `else` block of a `let ... else` as a nested branch that
+    /// leaves the function early.
+    fn visit_let_else(&mut self, els: &'tcx hir::Block<'tcx>) {
+        self.push_depth_segment(els.span);
+        self.push_flow_segment(els.span);
+        self.visit_block(els);
+    }
+
     fn visit_block(&mut self, block: &'tcx hir::Block<'tcx>) {
         for stmt in block.stmts {
             rustc_hir::intravisit::walk_stmt(self, stmt);
@@ -144,13 +208,45 @@ impl<'a, 'tcx> SegmentBuilder<'a, 'tcx> {
         self.push_segment(expr.span, value);
     }
 
+    /// Match guards count even when they bind with `if let`, since the guard
+    /// still decides whether the arm runs.
+    fn push_guard_segment(&mut self, guard: &'tcx hir::Expr<'tcx>) {
+        let value = count_branches(guard) as f64 * self.settings.weights.predicate;
+        self.push_segment(guard.span, value);
+    }
+
+    /// Reports whether `span` comes from a macro whose output is counted.
+    fn counts_macro_output(&self, span: Span) -> bool {
+        self.settings.include_macro_expansions && is_macro_output(span)
+    }
+
     fn push_segment(&mut self, span: Span, value: f64) {
-        // Skip spans from macro expansions; their line numbers can point to
-        // locations outside the function (e.g., the macro definition site).
-        if span.from_expansion() {
-            return;
+        // Spans from expansions carry line numbers that can point outside the
+        // function (e.g., the macro definition site), so counted macro output
+        // moves to its invocation and everything else is skipped.
+        if !span.from_expansion() {
+            self.push_source_segment(span, value);
+        } else if self.counts_macro_output(span) {
+            self.push_call_site_segment(span.source_callsite(), value);
         }
+    }
+
+    /// Attributes a segment of macro output to the invocation lines.
+    ///
+    /// Output of an attribute macro on the function can resolve to the
+    /// attribute itself, which lies before the body; such segments have no
+    /// line in the function and are dropped.
+    fn push_call_site_segment(&mut self, call_site: Span, value: f64) {
+        let source_map = self.cx.tcx.sess.source_map();
+        let within_function = span_line_range(source_map, call_site).is_some_and(|lines| {
+            self.function_lines.contains(lines.start()) && self.function_lines.contains(lines.end())
+        });
+        if within_function {
+            self.push_source_segment(call_site, value);
+        }
+    }
 
+    fn push_source_segment(&mut self, span: Span, value: f64) {
         let source_map = self.cx.tcx.sess.source_map();
         let Some(lines) = span_line_range(source_map, span) else {
             return;
@@ -193,6 +289,49 @@ impl<'a, 'tcx> rustc_hir::intravisit::Visitor<'tcx> for SegmentBuilder<'a, 'tcx>
     fn visit_block(&mut self, block: &'tcx hir::Block<'tcx>) {
         Self::visit_block(self, block);
     }
+
+    fn visit_local(&mut self, local: &'tcx hir::LetStmt<'tcx>) {
+        let counted = self.early_exits && !local.span.from_expansion();
+        let Some(els) = local.els.filter(|_| counted) else {
+            rustc_hir::intravisit::walk_local(self, local);
+            return;
+        };
+
+        if let Some(init) = local.init {
+            self.visit_expr(init);
+        }
+        self.visit_let_else(els);
+    }
+}
+
+/// Returns the call site and arms of a `match` expanded from a `matches!`
+/// invocation written directly in the analysed body.
+fn matches_macro_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+) -> Option<(Span, &'tcx [hir::Arm<'tcx>])> {
+    let ExprKind::Match(_, arms, _) = expr.kind else {
+        return None;
+    };
+    let expn = expr.span.ctxt().outer_expn_data();
+    let ExpnKind::Macro(MacroKind::Bang, name) = expn.kind else {
+        return None;
+    };
+    let from_core = expn
+        .macro_def_id
+        .is_some_and(|def_id| cx.tcx.crate_name(def_id.krate) == sym::core);
+    if name.as_str() != "matches" || !from_core || expn.call_site.from_expansion() {
+        return None;
+    }
+
+    Some((expn.call_site, arms))
+}
+
+/// Reports whether `span` was produced by a macro, directly or through a
+/// desugaring of code a macro emitted.
+fn is_macro_output(span: Span) -> bool {
+    span.macro_backtrace()
+        .any(|expn| matches!(expn.kind, ExpnKind::Macro(..)))
 }
 
 fn extract_while_components<'hir>(
@@ -8,12 +8,14 @@
 extern crate rustc_driver;
 
 use bumpy_road_function::analysis::{
-    DEFAULT_THRESHOLD, Settings, Weights, detect_bumps, normalise_settings, top_two_bumps,
+    DEFAULT_THRESHOLD, Settings, SignalParseError, Weights, analyse_segments, analyse_signal,
+    detect_bumps, merge_close_bumps, normalise_settings, parse_signal,
 };
 use rstest::fixture;
 use rstest::rstest;
 use rstest_bdd_macros::{given, scenario, then, when};
 use std::cell::RefCell;
+use whitaker_common::complexity_signal::{LineSegment, SignalBuildError};
 
 #[rstest]
 #[case::even_window(
@@ -47,6 +49,13 @@ use std::cell::RefCell;
         ..Settings::default()
     },
 )]
+#[case::min_bump_separation_clamped(
+    Settings {
+        min_bump_separation: 0,
+        ..Settings::default()
+    },
+    Settings::default(),
+)]
 #[case::negative_weights(
     Settings {
         weights: Weights {
@@ -67,32 +76,105 @@ fn normalise_settings_falls_back_to_defaults(
 }
 
 #[rstest]
-fn detect_bumps_reports_two_intervals() {
-    let smoothed = vec![0.0, 3.0, 3.0, 0.0, 3.1, 3.0];
-    let bumps = detect_bumps(&smoothed, 3.0, 2);
+fn analyse_signal_reports_bumps_on_source_lines() {
+    let signal = [
+        0.0, 3.0, 3.0, 3.0, 3.0, 0.0, 0.0, 0.0, 3.0, 4.5, 4.5, 3.0, 0.0,
+    ];
+    let analysis = analyse_signal(&signal, 20, Settings::default());
+
+    let lines: Vec<_> = analysis.bumps().iter().map(|bump| bump.lines()).collect();
+    assert_eq!(lines, [22..=23, 28..=31]);
+    assert_eq!(analysis.bumps()[1].peak(), 4.0);
+    assert_eq!(analysis.first_line(), 20);
+    assert_eq!(analysis.smoothed().len(), signal.len());
+    assert!(analysis.is_bumpy());
+    assert_eq!(analysis.score(), 4.0);
+}
+
+#[rstest]
+fn analyse_signal_normalises_settings() {
+    let signal = [0.0, 3.0, 3.0, 3.0, 3.0, 0.0];
+    let settings = Settings {
+        window: 4,
+        min_bump_lines: 0,
+        ..Settings::default()
+    };
+
+    let analysis = analyse_signal(&signal, 1, settings);
+
+    assert_eq!(analysis, analyse_signal(&signal, 1, Settings::default()));
+    assert!(!analysis.is_bumpy());
+}
+
+#[rstest]
+fn analyse_segments_matches_the_rasterized_signal() {
+    let segments = [
+        LineSegment::new(2, 5, 3.0).expect("segment should be valid"),
+        LineSegment::new(8, 11, 3.0).expect("segment should be valid"),
+    ];
+
+    let analysis = analyse_segments(1..=12, &segments, Settings::default())
+        .expect("segments should lie inside the function");
+    let signal = [0.0, 3.0, 3.0, 3.0, 3.0, 0.0, 0.0, 3.0, 3.0, 3.0, 3.0, 0.0];
 
-    assert_eq!(bumps.len(), 2);
-    assert_eq!((bumps[0].start_index(), bumps[0].end_index()), (1, 2));
-    assert_eq!((bumps[1].start_index(), bumps[1].end_index()), (4, 5));
+    assert_eq!(analysis, analyse_signal(&signal, 1, Settings::default()));
 }
 
 #[rstest]
-fn detect_bumps_ignores_short_spikes() {
-    let smoothed = vec![0.0, 4.0, 0.0];
-    let bumps = detect_bumps(&smoothed, 3.0, 2);
+fn analyse_segments_rejects_segments_outside_the_function() {
+    let segments = [LineSegment::new(7, 9, 1.0).expect("segment should be valid")];
 
-    assert!(bumps.is_empty());
+    let result = analyse_segments(1..=5, &segments, Settings::default());
+
+    assert!(matches!(
+        result,
+        Err(SignalBuildError::SegmentOutsideFunctionRange { .. })
+    ));
+}
+
+#[rstest]
+#[case::values("0\n1.5\n2", &[0.0, 1.5, 2.0])]
+#[case::blank_lines_read_as_zero("3\n\n  \n1", &[3.0, 0.0, 0.0, 1.0])]
+#[case::surrounding_whitespace(" 2.5 \r\n\t4", &[2.5, 4.0])]
+#[case::empty("", &[])]
+fn parse_signal_reads_one_sample_per_line(#[case] text: &str, #[case] expected: &[f64]) {
+    assert_eq!(parse_signal(text).as_deref(), Ok(expected));
 }
 
 #[rstest]
-fn top_two_bumps_prefers_area_then_length() {
-    let smoothed = vec![0.0, 4.0, 4.0, 0.0, 10.0, 0.0, 4.0, 4.0, 4.0];
-    let bumps = detect_bumps(&smoothed, 3.0, 2);
-    let top = top_two_bumps(bumps);
+#[case::word("1\nhigh", 2, "high")]
+#[case::negative("-1", 1, "-1")]
+#[case::not_finite("0\n0\nNaN", 3, "NaN")]
+#[case::two_values("1 2", 1, "1 2")]
+fn parse_signal_rejects_invalid_samples(
+    #[case] text: &str,
+    #[case] line: usize,
+    #[case] sample: &str,
+) {
+    assert_eq!(
+        parse_signal(text),
+        Err(SignalParseError::InvalidSample {
+            line,
+            text: sample.to_owned(),
+        })
+    );
+}
 
-    assert_eq!(top.len(), 2);
-    assert_eq!((top[0].start_index(), top[0].end_index()), (6, 8));
-    assert_eq!((top[1].start_index(), top[1].end_index()), (1, 2));
+#[cfg(feature = "serde")]
+#[rstest]
+fn analysis_round_trips_through_json() {
+    let signal = [
+        0.0, 3.0, 3.0, 3.0, 3.0, 0.0, 0.0, 0.0, 3.0, 3.0, 3.0, 3.0, 0.0,
+    ];
+    let analysis = analyse_signal(&signal, 5, Settings::default());
+
+    let json = serde_json::to_value(&analysis).expect("analysis should serialize");
+    assert_eq!(json["bumps"][0]["start_line"], 7);
+    assert_eq!(json["bumps"][1]["end_line"], 15);
+
+    let decoded: bumpy_road_function::analysis::SignalAnalysis =
+        serde_json::from_value(json).expect("analysis should deserialize");
+    assert_eq!(decoded, analysis);
 }
 
 #[derive(Default)]
@@ -100,6 +182,7 @@ struct World {
     signal: RefCell<Vec<f64>>,
     threshold: RefCell<f64>,
     min_bump_lines: RefCell<usize>,
+    min_bump_separation: RefCell<usize>,
     bumps: RefCell<Vec<bumpy_road_function::analysis::BumpInterval>>,
     settings: RefCell<Settings>,
     normalised: RefCell<Option<Settings>>,
@@ -137,12 +220,18 @@ fn given_min_bump_lines(world: &World, min_lines: usize) {
     world.min_bump_lines.replace(min_lines);
 }
 
+#[given("the minimum bump separation is {min_separation}")]
+fn given_min_bump_separation(world: &World, min_separation: usize) {
+    world.min_bump_separation.replace(min_separation);
+}
+
 #[when("I detect bumps")]
 fn when_detect(world: &World) {
     let signal = world.signal.borrow();
     let threshold = *world.threshold.borrow();
     let min_lines = *world.min_bump_lines.borrow();
-    let bumps = detect_bumps(&signal, threshold, min_lines);
+    let min_separation = *world.min_bump_separation.borrow();
+    let bumps = merge_close_bumps(detect_bumps(&signal, threshold, min_lines), min_separation);
     world.bumps.replace(bumps);
 }
 
@@ -226,6 +315,13 @@ fn scenario_negative_threshold(world: World) {
     let _ = world;
 }
 
+#[scenario(path = "tests/features/bumpy_road.feature", index = 5)]
+fn scenario_close_bumps_merge(world: World) {
+    // `rstest-bdd` binds the fixture through this argument, even though the
+    // step functions perform the assertions.
+    let _ = world;
+}
+
 /// Guards against threshold drift between the canonical constant and the UI
 /// test configuration. The `ui/dylint.toml` threshold must equal
 /// [`DEFAULT_THRESHOLD`] so fixtures exercise the production default.
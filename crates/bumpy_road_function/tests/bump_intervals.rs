@@ -0,0 +1,103 @@
+//! Unit coverage for bumpy road interval detection, merging, and ranking.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+// When the lint crate is built with `dylint-driver` enabled (for example, under
+// `cargo test --all-features`), this test crate must opt into `rustc_private`
+// so the transitive `rustc_*` dependencies can link successfully.
+#[cfg(feature = "dylint-driver")]
+extern crate rustc_driver;
+
+use bumpy_road_function::analysis::{detect_bumps, merge_close_bumps, top_two_bumps};
+use rstest::rstest;
+
+#[rstest]
+fn detect_bumps_reports_two_intervals() {
+    let smoothed = vec![0.0, 3.0, 3.0, 0.0, 3.1, 3.0];
+    let bumps = detect_bumps(&smoothed, 3.0, 2);
+
+    assert_eq!(bumps.len(), 2);
+    assert_eq!((bumps[0].start_index(), bumps[0].end_index()), (1, 2));
+    assert_eq!((bumps[1].start_index(), bumps[1].end_index()), (4, 5));
+}
+
+#[rstest]
+#[case::first_line(1, [2..=3, 5..=6])]
+#[case::nested_function(37, [38..=39, 41..=42])]
+fn bump_lines_offset_from_the_first_body_line(
+    #[case] first_line: usize,
+    #[case] expected: [std::ops::RangeInclusive<usize>; 2],
+) {
+    let smoothed = vec![0.0, 3.0, 3.0, 0.0, 3.1, 3.0];
+    let bumps = detect_bumps(&smoothed, 3.0, 2);
+
+    let lines: Vec<_> = bumps.iter().map(|bump| bump.lines(first_line)).collect();
+    assert_eq!(lines, expected);
+}
+
+#[rstest]
+fn detect_bumps_ignores_short_spikes() {
+    let smoothed = vec![0.0, 4.0, 0.0];
+    let bumps = detect_bumps(&smoothed, 3.0, 2);
+
+    assert!(bumps.is_empty());
+}
+
+#[rstest]
+#[case::default_separation(1, &[(1, 2), (4, 5), (8, 9)])]
+#[case::merges_single_line_gaps(2, &[(1, 5), (8, 9)])]
+#[case::merges_wider_gaps(3, &[(1, 9)])]
+fn merge_close_bumps_joins_nearby_intervals(
+    #[case] min_separation: usize,
+    #[case] expected: &[(usize, usize)],
+) {
+    let smoothed = vec![0.0, 3.0, 3.0, 0.0, 3.1, 3.0, 0.0, 0.0, 3.2, 3.0];
+    let bumps = merge_close_bumps(detect_bumps(&smoothed, 3.0, 2), min_separation);
+    let intervals: Vec<_> = bumps
+        .iter()
+        .map(|bump| (bump.start_index(), bump.end_index()))
+        .collect();
+
+    assert_eq!(intervals, expected);
+}
+
+#[rstest]
+fn merged_bumps_sum_their_areas() {
+    let smoothed = vec![4.0, 4.0, 0.0, 5.0, 5.0];
+    let bumps = merge_close_bumps(detect_bumps(&smoothed, 3.0, 2), 2);
+
+    assert_eq!(bumps.len(), 1);
+    assert_eq!(bumps[0].area_above_threshold(), 6.0);
+}
+
+#[rstest]
+#[case::unsorted(&[0.0, 0.0, 0.0, 0.0, 0.0, 4.0, 4.0], &[0.0, 4.0, 4.0], 1, &[(1, 2), (5, 6)])]
+#[case::unsorted_and_close(&[0.0, 0.0, 0.0, 0.0, 4.0, 4.0], &[0.0, 4.0, 4.0], 2, &[(1, 5)])]
+#[case::overlapping(&[0.0, 0.0, 4.0, 4.0, 4.0], &[0.0, 4.0, 4.0, 4.0], 1, &[(1, 4)])]
+#[case::contained(&[0.0, 4.0, 4.0, 4.0, 4.0], &[0.0, 0.0, 4.0, 4.0], 1, &[(1, 4)])]
+fn merge_close_bumps_accepts_unsorted_and_overlapping_intervals(
+    #[case] later: &[f64],
+    #[case] earlier: &[f64],
+    #[case] min_separation: usize,
+    #[case] expected: &[(usize, usize)],
+) {
+    let mut intervals = detect_bumps(later, 3.0, 2);
+    intervals.extend(detect_bumps(earlier, 3.0, 2));
+    let bumps = merge_close_bumps(intervals, min_separation);
+    let merged: Vec<_> = bumps
+        .iter()
+        .map(|bump| (bump.start_index(), bump.end_index()))
+        .collect();
+
+    assert_eq!(merged, expected);
+}
+
+#[rstest]
+fn top_two_bumps_prefers_area_then_length() {
+    let smoothed = vec![0.0, 4.0, 4.0, 0.0, 10.0, 0.0, 4.0, 4.0, 4.0];
+    let bumps = detect_bumps(&smoothed, 3.0, 2);
+    let top = top_two_bumps(bumps);
+
+    assert_eq!(top.len(), 2);
+    assert_eq!((top[0].start_index(), top[0].end_index()), (6, 8));
+    assert_eq!((top[1].start_index(), top[1].end_index()), (1, 2));
+}
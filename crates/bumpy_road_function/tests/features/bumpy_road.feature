@@ -33,3 +33,11 @@ Feature: Detect bumpy road intervals
     And I normalise the settings
     Then the threshold becomes 2.5
 
+
+  Scenario: Bumps closer than the minimum separation merge
+    Given a smoothed signal with two bumps
+    And the threshold is 3.0
+    And the minimum bump length is 2
+    And the minimum bump separation is 2
+    When I detect bumps
+    Then 1 bumps are reported
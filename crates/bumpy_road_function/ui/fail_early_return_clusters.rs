@@ -0,0 +1,35 @@
+//! UI fixture that should trigger the bumpy road lint at behaviour version 2.
+//!
+//! Neither cluster nests an `if`: the complexity comes from `let ... else`
+//! blocks, match guards (including an `if let` guard), and `matches!`.
+//! `pass_early_return_clusters_default.rs` holds the same function at the
+//! default behaviour version.
+#![expect(dead_code, reason = "UI test fixture; functions are analysed but not invoked")]
+
+/// Decides whether a `key=value` port setting names a trusted port.
+///
+/// ```ignore
+/// assert_eq!(is_trusted_port("root=22", None), Ok(true));
+/// ```
+pub fn is_trusted_port(line: &str, limit: Option<u16>) -> Result<bool, String> {
+    let Some((key, value)) = line.split_once('=') else {
+        let Some(limit) = limit else {
+            return Err(format!("missing `=` in {line}"));
+        };
+        return Ok(limit < 1024);
+    };
+    let label = key.trim().to_uppercase();
+    let port = value.trim().parse::<u16>().ok();
+
+    match port {
+        Some(port) if port < 1024 && label != "ROOT" => Ok(matches!(limit, Some(0..=1023))),
+        Some(port) if port > 49151 && label != "TEST" => Ok(matches!(limit, Some(49152..))),
+        Some(port) if let Some(limit) = limit => Ok(port <= limit && matches!(key, "user")),
+        Some(port) => Ok(matches!(port, 1024..=8080)),
+        None => Err(label),
+    }
+}
+
+fn dead_code_fixture_marker() {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+//! UI fixture whose nested `whitaker.toml` raises the bump threshold.
+#![expect(dead_code, reason = "UI test fixture; functions are analysed but not invoked")]
+
+// `legacy/whitaker.toml` raises the threshold above both clusters.
+#[path = "pass_directory_override/legacy/checks.rs"]
+mod legacy;
+
+fn main() {}
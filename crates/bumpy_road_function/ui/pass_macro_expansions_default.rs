@@ -0,0 +1,40 @@
+//! UI fixture that must not trigger the bumpy road lint by default.
+//!
+//! Both clusters come from `tier!` expansions. Macro output is skipped unless
+//! `include_macro_expansions` is set, so the function has no bumps;
+//! `fail_macro_expansions.rs` holds the same function with the option on.
+#![expect(dead_code, reason = "UI test fixture; functions are analysed but not invoked")]
+
+const LIMIT: u32 = 42;
+
+/// Expands to two nested conditional tiers.
+macro_rules! tier {
+    () => {
+        if LIMIT > 0 && LIMIT < 100 && LIMIT != 5 && LIMIT != 7 {
+            if LIMIT % 2 == 0 { 1 } else { 2 }
+        } else if LIMIT > 1000 && LIMIT < 2000 {
+            3
+        } else {
+            4
+        }
+    };
+}
+
+/// Adds two macro-generated tiers separated by plain arithmetic.
+///
+/// ```ignore
+/// assert_eq!(tiers(), 8);
+/// ```
+pub fn tiers() -> u32 {
+    let mut total = 0;
+    total += tier!();
+    total += 1;
+    total += 2;
+    total += 3;
+    total += tier!();
+    total
+}
+
+fn dead_code_fixture_marker() {}
+
+fn main() {}
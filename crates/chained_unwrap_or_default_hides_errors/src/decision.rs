@@ -0,0 +1,85 @@
+//! Pure decision logic for `chained_unwrap_or_default_hides_errors`.
+//!
+//! The driver describes each `Result::unwrap_or_default` call by what it
+//! learnt about the error type's `Display` implementation and whether the
+//! call sits in test code. [`ChainedUnwrapOrDefaultHidesErrorsDecision`]
+//! judges that description with [`should_flag`], as the driver does.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{DefaultedResult, ErrorDisplay, should_flag};
+
+/// Reason key for calls that discard a displayable error.
+const DISCARDS_ERROR: &str = "discards_error";
+
+/// The facts about one `unwrap_or_default` call that the decision needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DefaultedResultProjection {
+    /// Whether the error type implements `Display`, or `None` when the type
+    /// could not be resolved.
+    pub error_is_display: Option<bool>,
+    /// Whether the call sits in test code.
+    pub in_test: bool,
+}
+
+/// Reports `unwrap_or_default` calls that discard a displayable error
+/// outside test code.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = DefaultedResultProjection { error_is_display: Some(true), in_test: false };
+/// assert!(ChainedUnwrapOrDefaultHidesErrorsDecision.decide(&call).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChainedUnwrapOrDefaultHidesErrorsDecision;
+
+impl LintDecision for ChainedUnwrapOrDefaultHidesErrorsDecision {
+    type Projection = DefaultedResultProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, projection: &DefaultedResultProjection) -> Option<Finding> {
+        let error = match projection.error_is_display {
+            Some(true) => ErrorDisplay::Reportable,
+            Some(false) => ErrorDisplay::Silent,
+            None => ErrorDisplay::Unknown,
+        };
+        let call = DefaultedResult {
+            error,
+            in_test: projection.in_test,
+        };
+        should_flag(&call).then(|| Finding::new(LINT_NAME, DISCARDS_ERROR))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<DefaultedResultProjection>> {
+        let call = |error_is_display, in_test| DefaultedResultProjection {
+            error_is_display,
+            in_test,
+        };
+        vec![
+            DecisionFixture::flagged("displayable error", call(Some(true), false), DISCARDS_ERROR),
+            DecisionFixture::clean("displayable error in a test", call(Some(true), true)),
+            DecisionFixture::clean("error without Display", call(Some(false), false)),
+            DecisionFixture::clean("unresolved error type", call(None, false)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&ChainedUnwrapOrDefaultHidesErrorsDecision),
+            Ok(4)
+        );
+    }
+}
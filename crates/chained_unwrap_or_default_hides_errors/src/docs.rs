@@ -0,0 +1,23 @@
+//! Embedded documentation for the `chained_unwrap_or_default_hides_errors`
+//! lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `chained_unwrap_or_default_hides_errors`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "chained_unwrap_or_default_hides_errors",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `unwrap_or_default` on `Result` values whose error type implements `Display`, outside test code.",
+    rationale: "`unwrap_or_default` discards the error and returns zeroed data, so a failure looks exactly like an empty success. An error that implements `Display` was meant to be reported, and dropping it silently hides problems that should be logged or propagated.",
+    bad_example: "let port: u16 = text.parse().unwrap_or_default();",
+    good_example: "let port: u16 = text.parse()?;",
+    config: &[ConfigKeyDocs {
+        key: "additional_test_attributes",
+        default: "[]",
+        description: "Extra attribute paths that mark a function as a test.",
+    }],
+};
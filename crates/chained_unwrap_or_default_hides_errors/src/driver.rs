@@ -0,0 +1,322 @@
+//! Lint pass reporting `unwrap_or_default` calls that discard errors.
+//!
+//! Each `unwrap_or_default` method call is resolved, and calls whose method
+//! belongs to the inherent `impl` of the standard library `Result` are
+//! inspected. The trait solver decides whether the error type implements
+//! `Display` in the caller's environment, so blanket implementations and
+//! bounds on generic parameters count, and [`should_flag`] decides whether
+//! the call is reported. Test code is recognized through
+//! [`whitaker::hir::TestContext`], and calls produced by macro expansion are
+//! skipped. The finding spans the `unwrap_or_default` call.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_span::def_id::DefId;
+use rustc_span::{Span, sym};
+use rustc_trait_selection::infer::{InferCtxtExt, TyCtxtInferExt};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext, describe_enclosing_item};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::{AttributePath, CrateScopedState};
+
+use crate::policy::{DefaultedResult, ErrorDisplay, should_flag};
+
+pub(crate) const LINT_NAME: &str = "chained_unwrap_or_default_hides_errors";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    additional_test_attributes: Vec<String>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub CHAINED_UNWRAP_OR_DEFAULT_HIDES_ERRORS,
+    Warn,
+    "`unwrap_or_default` on a `Result` with a displayable error silently discards the failure",
+    ChainedUnwrapOrDefaultHidesErrors::default()
+}
+
+/// Lint pass that finds `unwrap_or_default` on results with reportable
+/// errors.
+#[derive(Default)]
+pub struct ChainedUnwrapOrDefaultHidesErrors {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Test context, the `Display` trait, finding limit, and localization for
+/// the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    test_context: TestContext,
+    display: Option<DefId>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ChainedUnwrapOrDefaultHidesErrors {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let additional = load_configuration()
+            .additional_test_attributes
+            .iter()
+            .map(|path| AttributePath::from(path.as_str()))
+            .collect();
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            test_context: TestContext::for_crate(cx, additional),
+            display: cx.tcx.get_diagnostic_item(sym::Display),
+            findings: FindingLimit::new(CHAINED_UNWRAP_OR_DEFAULT_HIDES_ERRORS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::MethodCall(segment, receiver, ..) = expr.kind else {
+            return;
+        };
+        if segment.ident.as_str() != "unwrap_or_default" || expr.span.from_expansion() {
+            return;
+        }
+        let is_result_method = cx
+            .typeck_results()
+            .type_dependent_def_id(expr.hir_id)
+            .is_some_and(|method| is_result_method(cx, method));
+        if !is_result_method {
+            return;
+        }
+        let Some(error) = result_error_type(cx, cx.typeck_results().expr_ty(receiver)) else {
+            return;
+        };
+
+        let state = &*self.state;
+        let call = DefaultedResult {
+            error: error_display(cx, state.display, error),
+            in_test: state.test_context.is_test(cx, expr.hir_id),
+        };
+        if !should_flag(&call) {
+            return;
+        }
+
+        let error = error.to_string();
+        let context = describe_enclosing_item(cx, expr.hir_id).describe(&state.localizer);
+        debug!(
+            target: LINT_NAME,
+            "`unwrap_or_default` discards `{error}` in {context}"
+        );
+        let span = expr.span.with_lo(segment.ident.span.lo());
+        if state.findings.admit(cx, expr.hir_id, span) {
+            let message = DefaultedResultMessage {
+                error: &error,
+                context: &context,
+            };
+            emit_diagnostic(cx, span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns `true` when `method` is defined by the inherent `impl` of
+/// `Result`.
+fn is_result_method(cx: &LateContext<'_>, method: DefId) -> bool {
+    cx.tcx
+        .inherent_impl_of_assoc(method)
+        .is_some_and(|impl_id| {
+            cx.tcx
+                .type_of(impl_id)
+                .instantiate_identity()
+                .skip_normalization()
+                .ty_adt_def()
+                .is_some_and(|adt| cx.tcx.is_diagnostic_item(sym::Result, adt.did()))
+        })
+}
+
+/// Returns the type `ty` carries in its `Err` position, when `ty` is a
+/// `Result`.
+fn result_error_type<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    let ty::Adt(result, args) = ty.kind() else {
+        return None;
+    };
+    cx.tcx
+        .is_diagnostic_item(sym::Result, result.did())
+        .then(|| args.type_at(1))
+}
+
+/// Asks the trait solver whether `error` implements `Display` in the
+/// environment of the item being checked, so `E: Display` bounds and blanket
+/// implementations such as `impl<T: Display> Display for Box<T>` are seen.
+fn error_display<'tcx>(
+    cx: &LateContext<'tcx>,
+    display: Option<DefId>,
+    error: Ty<'tcx>,
+) -> ErrorDisplay {
+    let Some(display) = display else {
+        return ErrorDisplay::Unknown;
+    };
+    if error.references_error() || error.has_infer() {
+        return ErrorDisplay::Unknown;
+    }
+    let infcx = cx.tcx.infer_ctxt().build(cx.typing_mode());
+    if infcx
+        .type_implements_trait(display, [error], cx.param_env)
+        .must_apply_modulo_regions()
+    {
+        ErrorDisplay::Reportable
+    } else {
+        ErrorDisplay::Silent
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &DefaultedResultMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        CHAINED_UNWRAP_OR_DEFAULT_HIDES_ERRORS,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for a discarded error interpolates.
+#[derive(Clone, Copy, Debug)]
+struct DefaultedResultMessage<'a> {
+    error: &'a str,
+    context: &'a str,
+}
+
+impl DefaultedResultMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("error"),
+            FluentValue::from(self.error.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(self.context.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { error, context } = *self;
+        DiagnosticMessageSet::new(
+            format!("`.unwrap_or_default()` discards a `{error}` error in {context}."),
+            format!(
+                "`{error}` implements `Display`, so it was meant to be reported, but \
+                 `.unwrap_or_default()` replaces the failure with default data that looks like \
+                 an empty success."
+            ),
+            String::from(
+                "Propagate the error with `?`, or use `.unwrap_or_else(|error| ...)` to log it \
+                 before falling back to a default.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message() -> DefaultedResultMessage<'static> {
+        DefaultedResultMessage {
+            error: "ParseIntError",
+            context: "function `port`",
+        }
+    }
+
+    fn resolve(message: &DefaultedResultMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_adds_no_test_attributes() {
+        assert!(Config::default().additional_test_attributes.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_error_type() {
+        let messages = message().fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "`.unwrap_or_default()` discards a `ParseIntError` error in function `port`."
+        );
+        assert!(messages.help().contains("unwrap_or_else"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        let message = message();
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(), locale);
+
+        assert!(messages.primary().contains("`ParseIntError`"));
+        assert!(messages.help().contains("`?`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
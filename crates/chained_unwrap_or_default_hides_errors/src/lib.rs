@@ -0,0 +1,42 @@
+//! Lint crate flagging `unwrap_or_default` on fallible results.
+//!
+//! `Result::unwrap_or_default` discards the error and hands back zeroed data,
+//! so a failed parse, read, or lookup looks exactly like an empty success.
+//! When the error type implements `Display` it was written to be reported,
+//! and silently dropping it hides failures that should have been logged or
+//! propagated. Calls on such results are reported outside test code, and the
+//! help points at `unwrap_or_else` with logging or the `?` operator. The lint
+//! is experimental and ships behind the
+//! `experimental-chained-unwrap-or-default-hides-errors` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ChainedUnwrapOrDefaultHidesErrorsDecision, DefaultedResultProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn chained_unwrap_or_default_hides_errors_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
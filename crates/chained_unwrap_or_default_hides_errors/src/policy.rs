@@ -0,0 +1,64 @@
+//! Rules deciding which defaulted results are reported.
+//!
+//! The driver describes each `Result::unwrap_or_default` call by whether its
+//! error type implements `Display` and whether it sits in test code, and this
+//! module decides whether the call is reported. The rules are kept free of
+//! compiler types so they can be tested directly.
+
+/// How much the driver could learn about a result's error type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ErrorDisplay {
+    /// The error type implements `Display`, so it was meant to be reported.
+    Reportable,
+    /// The error type does not implement `Display`, such as `()` or a
+    /// generic parameter without a `Display` bound.
+    Silent,
+    /// The error type could not be resolved, so nothing is known about it.
+    Unknown,
+}
+
+/// An `unwrap_or_default` call on a `Result`, described without compiler
+/// types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct DefaultedResult {
+    /// What is known about the error type's `Display` implementation.
+    pub(crate) error: ErrorDisplay,
+    /// Whether the call sits in test code.
+    pub(crate) in_test: bool,
+}
+
+/// Returns `true` when `call` discards a displayable error outside test
+/// code.
+///
+/// Unresolved error types are not reported, since the lint cannot tell
+/// whether they were meant to be displayed.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = DefaultedResult { error: ErrorDisplay::Reportable, in_test: false };
+/// assert!(should_flag(&call));
+/// ```
+#[must_use]
+pub(crate) fn should_flag(call: &DefaultedResult) -> bool {
+    !call.in_test && call.error == ErrorDisplay::Reportable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(ErrorDisplay::Reportable, false, true)]
+    #[case(ErrorDisplay::Reportable, true, false)]
+    #[case(ErrorDisplay::Silent, false, false)]
+    #[case(ErrorDisplay::Unknown, false, false)]
+    fn displayable_errors_outside_tests_are_flagged(
+        #[case] error: ErrorDisplay,
+        #[case] in_test: bool,
+        #[case] flagged: bool,
+    ) {
+        assert_eq!(should_flag(&DefaultedResult { error, in_test }), flagged);
+    }
+}
@@ -0,0 +1,98 @@
+//! Behaviour-driven coverage for the defaulted result policy.
+
+use crate::policy::{DefaultedResult, ErrorDisplay, should_flag};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+struct DefaultWorld {
+    error: Cell<ErrorDisplay>,
+    in_test: Cell<bool>,
+    flagged: Cell<Option<bool>>,
+}
+
+impl Default for DefaultWorld {
+    fn default() -> Self {
+        Self {
+            error: Cell::new(ErrorDisplay::Unknown),
+            in_test: Cell::new(false),
+            flagged: Cell::new(None),
+        }
+    }
+}
+
+#[fixture]
+fn world() -> DefaultWorld {
+    DefaultWorld::default()
+}
+
+#[given("a result whose error implements Display")]
+fn given_reportable(world: &DefaultWorld) {
+    world.error.set(ErrorDisplay::Reportable);
+}
+
+#[given("a result whose error does not implement Display")]
+fn given_silent(world: &DefaultWorld) {
+    world.error.set(ErrorDisplay::Silent);
+}
+
+#[given("a result whose error type is unresolved")]
+fn given_unknown(world: &DefaultWorld) {
+    world.error.set(ErrorDisplay::Unknown);
+}
+
+#[given("the call sits in test code")]
+fn given_test_code(world: &DefaultWorld) {
+    world.in_test.set(true);
+}
+
+#[when("the call is checked")]
+fn when_checked(world: &DefaultWorld) {
+    let call = DefaultedResult {
+        error: world.error.get(),
+        in_test: world.in_test.get(),
+    };
+    world.flagged.set(Some(should_flag(&call)));
+}
+
+#[then("the call is reported")]
+fn then_reported(world: &DefaultWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the call is allowed")]
+fn then_allowed(world: &DefaultWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/chained_unwrap_or_default_hides_errors.feature",
+    index = 0
+)]
+fn scenario_displayable_error(world: DefaultWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/chained_unwrap_or_default_hides_errors.feature",
+    index = 1
+)]
+fn scenario_silent_error(world: DefaultWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/chained_unwrap_or_default_hides_errors.feature",
+    index = 2
+)]
+fn scenario_unresolved_error(world: DefaultWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/chained_unwrap_or_default_hides_errors.feature",
+    index = 3
+)]
+fn scenario_test_code(world: DefaultWorld) {
+    let _ = world;
+}
@@ -0,0 +1,24 @@
+Feature: Defaulted results with reportable errors
+  `unwrap_or_default` on a `Result` whose error type implements `Display` is
+  reported outside test code.
+
+  Scenario: Defaulting a displayable error is reported
+    Given a result whose error implements Display
+    When the call is checked
+    Then the call is reported
+
+  Scenario: Errors without Display are ignored
+    Given a result whose error does not implement Display
+    When the call is checked
+    Then the call is allowed
+
+  Scenario: Unresolved errors are ignored
+    Given a result whose error type is unresolved
+    When the call is checked
+    Then the call is allowed
+
+  Scenario: Test code is exempt
+    Given a result whose error implements Display
+    And the call sits in test code
+    When the call is checked
+    Then the call is allowed
@@ -0,0 +1,42 @@
+//! Displayable errors discarded with `unwrap_or_default` outside test code.
+
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::num::ParseIntError;
+
+fn port(text: &str) -> u16 {
+    text.parse().unwrap_or_default()
+}
+
+fn config() -> String {
+    fs::read_to_string("app.toml").unwrap_or_default()
+}
+
+fn parse(text: &str) -> Result<u32, ParseIntError> {
+    text.trim().parse()
+}
+
+fn total(lines: &[&str]) -> u32 {
+    lines.iter().map(|line| parse(line).unwrap_or_default()).sum()
+}
+
+fn load(path: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(fs::read(path)?)
+}
+
+fn bytes() -> Vec<u8> {
+    load("cache.bin").unwrap_or_default()
+}
+
+fn or_zero<T: Default, E: Display>(result: Result<T, E>) -> T {
+    result.unwrap_or_default()
+}
+
+fn main() {
+    let _ = port("8080");
+    let _ = config();
+    let _ = total(&["1", "2"]);
+    let _ = bytes();
+    let _ = or_zero("1".parse::<u32>());
+}
@@ -0,0 +1,44 @@
+//! Errors handled explicitly, errors without `Display`, generic errors with
+//! no `Display` bound, and test code are accepted.
+
+use std::num::ParseIntError;
+
+fn port(text: &str) -> Result<u16, ParseIntError> {
+    let port = text.parse()?;
+    Ok(port)
+}
+
+fn logged_port(text: &str) -> u16 {
+    text.parse().unwrap_or_else(|error| {
+        eprintln!("invalid port `{text}`: {error}");
+        0
+    })
+}
+
+fn lookup(key: &str) -> Result<u32, ()> {
+    if key.is_empty() { Err(()) } else { Ok(1) }
+}
+
+fn or_zero<T: Default, E>(result: Result<T, E>) -> T {
+    result.unwrap_or_default()
+}
+
+fn first(values: &[u32]) -> u32 {
+    values.first().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn defaults_in_tests() {
+        assert_eq!("x".parse::<u16>().unwrap_or_default(), 0);
+    }
+}
+
+fn main() {
+    let _ = port("8080");
+    let _ = logged_port("8080");
+    let _ = lookup("key").unwrap_or_default();
+    let _ = or_zero("1".parse::<u32>());
+    let _ = first(&[1]);
+}
@@ -9,6 +9,7 @@
 
 pub mod macros {
     use rustc_hir as hir;
+    use rustc_hir::def_id::{DefId, LOCAL_CRATE};
     use rustc_lint::LateContext;
 
     // Panic entry points mirrored from the main lint panic detector to avoid
@@ -33,6 +34,29 @@ pub mod macros {
     /// Best-effort panic detection mirroring Clippy's helper.
     #[must_use]
     pub fn is_panic(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+        is_panic_with(cx, expr, &[])
+    }
+
+    /// Panic detection that also treats `additional` functions as panics.
+    ///
+    /// Each entry names a function by its fully qualified path, including the
+    /// crate name, such as `my_crate::errors::fatal`. This lets wrappers
+    /// around `panic!` count as panics rather than evading detection.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let additional = [String::from("my_crate::errors::fatal")];
+    /// if clippy_utils::macros::is_panic_with(cx, expr, &additional) {
+    ///     // `expr` panics directly or through `fatal`.
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_panic_with(
+        cx: &LateContext<'_>,
+        expr: &hir::Expr<'_>,
+        additional: &[String],
+    ) -> bool {
         let hir::ExprKind::Call(callee, _) = expr.kind else {
             return false;
         };
@@ -49,8 +73,22 @@ pub mod macros {
             return false;
         };
 
-        let path = cx.tcx.def_path_str(def_id);
+        let path = qualified_path(cx, def_id);
         let path_str = path.as_str();
         PANIC_PATHS.contains(&path_str)
+            || additional
+                .iter()
+                .any(|candidate| candidate.trim_start_matches("::") == path_str)
+    }
+
+    /// Names `def_id` by its path, prefixing the crate name for local items
+    /// so that they read the same as when seen from a dependent crate.
+    fn qualified_path(cx: &LateContext<'_>, def_id: DefId) -> String {
+        let path = cx.tcx.def_path_str(def_id);
+        if def_id.is_local() {
+            format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+        } else {
+            path
+        }
     }
 }
@@ -0,0 +1,113 @@
+//! Pure decision logic for `closure_max_lines`.
+//!
+//! The driver measures each closure body and records whether it sits in test
+//! code; [`ClosureMaxLinesDecision`] reports production closures longer than
+//! the line budget.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+
+const EXCEEDS_LIMIT: &str = "exceeds_limit";
+
+/// The facts about one closure that the decision needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClosureProjection {
+    /// Source lines spanned by the closure body.
+    pub lines: usize,
+    /// Whether the closure is inside test code.
+    pub in_test: bool,
+}
+
+/// Reports closures outside test code whose body exceeds `max_lines`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = ClosureMaxLinesDecision::new(20);
+/// let closure = ClosureProjection { lines: 21, in_test: false };
+/// assert!(decision.decide(&closure).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClosureMaxLinesDecision {
+    max_lines: usize,
+}
+
+impl ClosureMaxLinesDecision {
+    /// Creates a decision with the given line budget; budgets below one line
+    /// are raised to one.
+    #[must_use]
+    pub const fn new(max_lines: usize) -> Self {
+        Self {
+            max_lines: if max_lines == 0 { 1 } else { max_lines },
+        }
+    }
+
+    /// Returns the line budget.
+    #[must_use]
+    pub const fn max_lines(&self) -> usize {
+        self.max_lines
+    }
+}
+
+impl Default for ClosureMaxLinesDecision {
+    fn default() -> Self {
+        Self::new(crate::driver::DEFAULT_MAX_LINES)
+    }
+}
+
+impl LintDecision for ClosureMaxLinesDecision {
+    type Projection = ClosureProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, closure: &ClosureProjection) -> Option<Finding> {
+        (exceeds_limit(closure.lines, self.max_lines) && !closure.in_test)
+            .then(|| Finding::new(LINT_NAME, EXCEEDS_LIMIT))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ClosureProjection>> {
+        let closure = |lines, in_test| ClosureProjection { lines, in_test };
+        vec![
+            DecisionFixture::clean("one-line closure", closure(1, false)),
+            DecisionFixture::clean("exactly at the budget", closure(self.max_lines, false)),
+            DecisionFixture::flagged(
+                "one line over the budget",
+                closure(self.max_lines + 1, false),
+                EXCEEDS_LIMIT,
+            ),
+            DecisionFixture::clean(
+                "test closure over the budget",
+                closure(self.max_lines + 1, true),
+            ),
+        ]
+    }
+}
+
+pub(crate) const fn exceeds_limit(lines: usize, limit: usize) -> bool {
+    lines > limit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::below(19, 20, false)]
+    #[case::at(20, 20, false)]
+    #[case::above(21, 20, true)]
+    fn exceeds_limit_is_strict(#[case] lines: usize, #[case] limit: usize, #[case] expected: bool) {
+        assert_eq!(exceeds_limit(lines, limit), expected);
+    }
+
+    #[rstest]
+    #[case::default_budget(ClosureMaxLinesDecision::default())]
+    #[case::zero_budget(ClosureMaxLinesDecision::new(0))]
+    fn decision_conforms(#[case] decision: ClosureMaxLinesDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
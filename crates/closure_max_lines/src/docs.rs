@@ -0,0 +1,28 @@
+//! Embedded documentation for the `closure_max_lines` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `closure_max_lines`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "closure_max_lines",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags closures whose bodies exceed the configured line budget.",
+    rationale: "A long closure is a function without a name, documentation, or tests of its own; test code is exempt.",
+    bad_example: "let reports = entries.iter().map(|entry| { /* 25 lines */ }).collect::<Vec<_>>();",
+    good_example: "let reports = entries.iter().map(summarise_entry).collect::<Vec<_>>();",
+    config: &[
+        ConfigKeyDocs {
+            key: "max_lines",
+            default: "20",
+            description: "Maximum number of lines in a closure body.",
+        },
+        ConfigKeyDocs {
+            key: "additional_test_attributes",
+            default: "[]",
+            description: "Extra attribute paths that mark a function as a test.",
+        },
+    ],
+};
@@ -0,0 +1,237 @@
+//! Detect closures whose bodies exceed the configured line budget.
+//!
+//! The lint measures the source lines spanned by each closure body and warns
+//! when the count exceeds `max_lines`. Closures produced by macro expansion
+//! and the closures that desugar `async` blocks are ignored. Test code is
+//! exempt through [`whitaker::hir::TestContext`], so closures inside
+//! `#[test]` functions, `#[rstest]` fixtures, `#[cfg(test)]` modules, and
+//! integration test crates are never reported.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::{ClosureKind, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use rustc_span::source_map::SourceMap;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext};
+use whitaker::testing::decision::LintDecision;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::{AttributePath, CrateScopedState};
+
+pub(crate) const LINT_NAME: &str = "closure_max_lines";
+pub(crate) const DEFAULT_MAX_LINES: usize = 20;
+use crate::decision::{ClosureMaxLinesDecision, ClosureProjection};
+
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    #[serde(default = "Config::default_max_lines")]
+    max_lines: usize,
+    additional_test_attributes: Vec<String>,
+}
+
+impl Config {
+    const fn default_max_lines() -> usize {
+        DEFAULT_MAX_LINES
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_lines: Self::default_max_lines(),
+            additional_test_attributes: Vec::new(),
+        }
+    }
+}
+
+/// Lint pass enforcing closure body line budgets.
+#[derive(Default)]
+pub struct ClosureMaxLines {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Budget, test context, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    test_context: TestContext,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub CLOSURE_MAX_LINES,
+    Warn,
+    "closures should stay within the configured maximum line count",
+    ClosureMaxLines::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for ClosureMaxLines {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let config = load_configuration();
+        let additional = config
+            .additional_test_attributes
+            .iter()
+            .map(|path| AttributePath::from(path.as_str()))
+            .collect();
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            test_context: TestContext::for_crate(cx, additional),
+            findings: FindingLimit::new(CLOSURE_MAX_LINES, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+            config,
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ExprKind::Closure(closure) = expr.kind else {
+            return;
+        };
+        if closure.kind != ClosureKind::Closure || expr.span.from_expansion() {
+            return;
+        }
+
+        let body = cx.tcx.hir_body(closure.body);
+        let Some(lines) = count_lines(cx.sess().source_map(), body.value.span) else {
+            return;
+        };
+        let projection = ClosureProjection {
+            lines,
+            in_test: self.state.test_context.is_test(cx, expr.hir_id),
+        };
+        let config = self
+            .state
+            .findings
+            .config_at(cx, expr.span, &self.state.config);
+        let decision = ClosureMaxLinesDecision::new(config.max_lines);
+        if decision.decide(&projection).is_none() {
+            return;
+        }
+        debug!(
+            target: LINT_NAME,
+            "closure body spans {lines} lines (limit {limit})",
+            limit = decision.max_lines(),
+        );
+
+        let info = ClosureInfo {
+            span: closure.fn_decl_span,
+            lines,
+            limit: decision.max_lines(),
+        };
+        if self.state.findings.admit(cx, expr.hir_id, info.span) {
+            emit_diagnostic(cx, &info, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+fn count_lines(source_map: &SourceMap, span: Span) -> Option<usize> {
+    if span.is_dummy() {
+        return None;
+    }
+    let first = source_map.lookup_char_pos(span.lo()).line;
+    let last = source_map.lookup_char_pos(span.hi()).line;
+    last.checked_sub(first).map(|extra| extra + 1)
+}
+
+/// A closure whose body exceeds the configured line budget.
+#[derive(Clone, Copy, Debug)]
+struct ClosureInfo {
+    span: Span,
+    lines: usize,
+    limit: usize,
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, info: &ClosureInfo, localizer: &Localizer) {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("lines"), FluentValue::from(info.lines as i64));
+    args.insert(Cow::Borrowed("limit"), FluentValue::from(info.limit as i64));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(info.lines, info.limit)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        CLOSURE_MAX_LINES,
+        info.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(lines: usize, limit: usize) -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        format!("Closure body spans {lines} lines, exceeding the limit of {limit}."),
+        String::from("Long closures hide logic that deserves a name and tests of its own."),
+        String::from(
+            "Extract the closure body into a named function and pass it by path or call it \
+             from a short closure.",
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_allows_twenty_lines() {
+        let config = Config::default();
+
+        assert_eq!(config.max_lines, 20);
+        assert!(config.additional_test_attributes.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_messages_mention_counts() {
+        let messages = fallback_messages(27, 20);
+
+        assert!(messages.primary().contains("27 lines"));
+        assert!(messages.primary().contains("20"));
+        assert!(messages.help().contains("named function"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
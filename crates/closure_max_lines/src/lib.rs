@@ -0,0 +1,36 @@
+//! Lint crate limiting the number of lines in a closure body.
+//!
+//! Closures that grow beyond a few lines hide logic that deserves a name,
+//! documentation, and tests of its own. The lint reports closures whose
+//! bodies exceed a configurable line budget and suggests extracting a named
+//! function. Closures in test code, including `#[rstest]` fixtures and
+//! closures passed to test frameworks, are exempt.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ClosureMaxLinesDecision, ClosureProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn closure_max_lines_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
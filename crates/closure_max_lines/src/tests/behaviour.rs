@@ -0,0 +1,68 @@
+//! Behaviour-driven coverage for closure line budgets.
+
+use super::{Config, fallback_messages};
+use crate::decision::exceeds_limit;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::i18n::DiagnosticMessageSet;
+
+struct ClosureWorld {
+    lines: Cell<usize>,
+    config: RefCell<Config>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> ClosureWorld {
+    ClosureWorld {
+        lines: Cell::new(0),
+        config: RefCell::new(Config::default()),
+        messages: RefCell::new(None),
+    }
+}
+
+#[given("a closure body spanning {lines} lines")]
+fn given_closure(world: &ClosureWorld, lines: usize) {
+    world.lines.set(lines);
+}
+
+#[given("the budget is {limit}")]
+fn given_budget(world: &ClosureWorld, limit: usize) {
+    world.config.borrow_mut().max_lines = limit;
+}
+
+#[when("the closure is checked")]
+fn when_checked(world: &ClosureWorld) {
+    let limit = world.config.borrow().max_lines;
+    let lines = world.lines.get();
+    let messages = exceeds_limit(lines, limit).then(|| fallback_messages(lines, limit));
+    *world.messages.borrow_mut() = messages;
+}
+
+#[then("the closure is accepted")]
+fn then_accepted(world: &ClosureWorld) {
+    assert!(world.messages.borrow().is_none());
+}
+
+#[then("the closure is reported with {lines} lines")]
+fn then_reported(world: &ClosureWorld, lines: usize) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert!(messages.primary().contains(&format!("{lines} lines")));
+}
+
+#[scenario(path = "tests/features/closure_max_lines.feature", index = 0)]
+fn scenario_within_budget(world: ClosureWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/closure_max_lines.feature", index = 1)]
+fn scenario_beyond_budget(world: ClosureWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/closure_max_lines.feature", index = 2)]
+fn scenario_configured_budget(world: ClosureWorld) {
+    let _ = world;
+}
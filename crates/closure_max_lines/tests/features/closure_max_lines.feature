@@ -0,0 +1,19 @@
+Feature: Closure line budget
+  Closures whose bodies span more lines than the configured budget are
+  reported so their logic can be extracted into named functions.
+
+  Scenario: A closure within the default budget is accepted
+    Given a closure body spanning 20 lines
+    When the closure is checked
+    Then the closure is accepted
+
+  Scenario: A closure beyond the default budget is reported
+    Given a closure body spanning 21 lines
+    When the closure is checked
+    Then the closure is reported with 21 lines
+
+  Scenario: A configured budget lowers the threshold
+    Given a closure body spanning 6 lines
+    And the budget is 5
+    When the closure is checked
+    Then the closure is reported with 6 lines
@@ -0,0 +1,8 @@
+fn main() {
+    let greet = |name: &str| {
+        let trimmed = name.trim();
+        let upper = trimmed.to_uppercase();
+        format!("Hello, {upper}!")
+    };
+    println!("{}", greet(" whitaker "));
+}
@@ -0,0 +1,24 @@
+pub fn describe(input: u32) -> String {
+    let describe = |value: u32| {
+        let doubled = value * 2;
+        let tripled = value * 3;
+        let squared = value * value;
+        let halved = value / 2;
+        let sum = doubled + tripled + squared + halved;
+        let text = format!("{value}: {sum}");
+        let trimmed = text.trim().to_owned();
+        let upper = trimmed.to_uppercase();
+        let lower = trimmed.to_lowercase();
+        let joined = format!("{upper}/{lower}");
+        let length = joined.len();
+        let padded = format!("{joined:>40}");
+        let first = padded.chars().next();
+        let last = padded.chars().last();
+        let marker = first.zip(last);
+        let counted = padded.matches('/').count();
+        let spaces = padded.matches(' ').count();
+        let summary = format!("{length} {counted} {spaces} {marker:?}");
+        summary
+    };
+    describe(input)
+}
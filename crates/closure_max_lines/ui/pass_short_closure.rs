@@ -0,0 +1,11 @@
+#![warn(closure_max_lines)]
+
+fn main() {
+    let values: Vec<u32> = (1..=5)
+        .map(|value| {
+            let doubled = value * 2;
+            doubled + 1
+        })
+        .collect();
+    println!("{values:?}");
+}
@@ -0,0 +1,33 @@
+#![warn(closure_max_lines)]
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn long_setup_closure_is_exempt() {
+        let setup = |seed: u32| {
+            let a = seed + 1;
+            let b = a + 1;
+            let c = b + 1;
+            let d = c + 1;
+            let e = d + 1;
+            let f = e + 1;
+            let g = f + 1;
+            let h = g + 1;
+            let i = h + 1;
+            let j = i + 1;
+            let k = j + 1;
+            let l = k + 1;
+            let m = l + 1;
+            let n = m + 1;
+            let o = n + 1;
+            let p = o + 1;
+            let q = p + 1;
+            let r = q + 1;
+            let s = r + 1;
+            s + 1
+        };
+        assert_eq!(setup(0), 20);
+    }
+}
@@ -0,0 +1,220 @@
+//! Extraction of plain (non-doc) comment blocks from Rust source text.
+//!
+//! The source is tokenised with `rustc_lexer`, so comment markers inside
+//! string literals are never mistaken for comments. Consecutive `//` comments
+//! on adjacent lines form one block; each `/* */` comment is a block of its
+//! own. Doc comments (`///`, `//!`, `/** */`, and `/*! */`) are skipped
+//! entirely.
+
+use rustc_lexer::{TokenKind, strip_shebang, tokenize};
+
+/// A run of plain comments and the text inside their markers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CommentBlock {
+    /// Byte offset of the first comment marker.
+    pub(crate) start: usize,
+    /// Byte offset just past the last comment.
+    pub(crate) end: usize,
+    /// The comment text with markers removed, one source line per line.
+    pub(crate) text: String,
+}
+
+impl CommentBlock {
+    /// Returns the number of source lines the block spans.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let blocks = plain_comment_blocks("// let a = 1;\n// let b = 2;\n");
+    /// assert_eq!(blocks[0].line_count(), 2);
+    /// ```
+    pub(crate) fn line_count(&self) -> usize {
+        self.text.lines().count().max(1)
+    }
+}
+
+/// Returns the plain comment blocks of `source` in source order.
+///
+/// # Examples
+///
+/// ```ignore
+/// let source = "/// Documented.\nfn main() {\n    // run();\n}\n";
+/// let blocks = plain_comment_blocks(source);
+/// assert_eq!(blocks.len(), 1);
+/// assert_eq!(blocks[0].text, "run();");
+/// ```
+pub(crate) fn plain_comment_blocks(source: &str) -> Vec<CommentBlock> {
+    let mut scanner = Scanner::default();
+    let mut offset = strip_shebang(source).unwrap_or(0);
+
+    for token in tokenize(&source[offset..]) {
+        let end = offset + token.len;
+        let text = &source[offset..end];
+        match token.kind {
+            TokenKind::LineComment => scanner.line_comment(offset, end, text),
+            TokenKind::BlockComment { .. } => scanner.block_comment(offset, end, text),
+            TokenKind::Whitespace => scanner.whitespace(text),
+            _ => scanner.flush(),
+        }
+        offset = end;
+    }
+
+    scanner.flush();
+    scanner.blocks
+}
+
+#[derive(Default)]
+struct Scanner {
+    blocks: Vec<CommentBlock>,
+    pending: Option<CommentBlock>,
+    newlines_since_comment: usize,
+}
+
+impl Scanner {
+    fn line_comment(&mut self, start: usize, end: usize, text: &str) {
+        if is_doc_line_comment(text) {
+            self.flush();
+            return;
+        }
+
+        let body = strip_line_marker(text);
+        match self.pending.as_mut() {
+            Some(block) if self.newlines_since_comment <= 1 => {
+                block.end = end;
+                block.text.push('\n');
+                block.text.push_str(body);
+            }
+            _ => {
+                self.flush();
+                self.pending = Some(CommentBlock {
+                    start,
+                    end,
+                    text: body.to_owned(),
+                });
+            }
+        }
+        self.newlines_since_comment = 0;
+    }
+
+    fn block_comment(&mut self, start: usize, end: usize, text: &str) {
+        self.flush();
+        if !is_doc_block_comment(text) {
+            self.blocks.push(CommentBlock {
+                start,
+                end,
+                text: strip_block_markers(text),
+            });
+        }
+    }
+
+    fn whitespace(&mut self, text: &str) {
+        self.newlines_since_comment += text.matches('\n').count();
+    }
+
+    fn flush(&mut self) {
+        self.blocks.extend(self.pending.take());
+    }
+}
+
+fn is_doc_line_comment(text: &str) -> bool {
+    text.starts_with("//!") || (text.starts_with("///") && !text.starts_with("////"))
+}
+
+fn is_doc_block_comment(text: &str) -> bool {
+    text.starts_with("/*!")
+        || (text.starts_with("/**") && !text.starts_with("/***") && text != "/**/")
+}
+
+fn strip_line_marker(text: &str) -> &str {
+    let body = text.trim_start_matches('/');
+    body.strip_prefix(' ').unwrap_or(body).trim_end()
+}
+
+/// Removes `/*`, `*/`, and the leading ` * ` decoration of each line.
+fn strip_block_markers(text: &str) -> String {
+    let inner = text
+        .strip_prefix("/*")
+        .unwrap_or(text)
+        .trim_start_matches('*');
+    let inner = inner
+        .strip_suffix("*/")
+        .unwrap_or(inner)
+        .trim_end_matches('*');
+    inner
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix('*').unwrap_or(line);
+            line.strip_prefix(' ').unwrap_or(line).trim_end()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn texts(source: &str) -> Vec<String> {
+        plain_comment_blocks(source)
+            .into_iter()
+            .map(|block| block.text)
+            .collect()
+    }
+
+    #[rstest]
+    fn groups_adjacent_line_comments() {
+        let source = "fn main() {\n    // let a = 1;\n    // let b = 2;\n\n    // later\n}\n";
+
+        assert_eq!(texts(source), ["let a = 1;\nlet b = 2;", "later"]);
+    }
+
+    #[rstest]
+    #[case::outer_line("/// let a = 1;\nfn f() {}\n")]
+    #[case::inner_line("//! let a = 1;\n")]
+    #[case::outer_block("/** let a = 1; */\nfn f() {}\n")]
+    #[case::inner_block("/*! let a = 1; */\n")]
+    fn skips_doc_comments(#[case] source: &str) {
+        assert!(texts(source).is_empty());
+    }
+
+    #[rstest]
+    #[case::four_slashes("//// let a = 1;\n", "let a = 1;")]
+    #[case::three_stars("/*** let a = 1; */\n", "let a = 1;")]
+    fn treats_extra_markers_as_plain(#[case] source: &str, #[case] expected: &str) {
+        assert_eq!(texts(source), [expected]);
+    }
+
+    #[rstest]
+    fn doc_comment_splits_line_comment_runs() {
+        let source = "// first\n/// doc\n// second\nfn f() {}\n";
+
+        assert_eq!(texts(source), ["first", "second"]);
+    }
+
+    #[rstest]
+    fn strips_block_comment_decoration() {
+        let source = "/*\n * let a = 1;\n * run(a);\n */\nfn f() {}\n";
+
+        assert_eq!(texts(source), ["let a = 1;\nrun(a);"]);
+    }
+
+    #[rstest]
+    fn ignores_comment_markers_in_strings() {
+        let source = "const URL: &str = \"https://example.com\";\n";
+
+        assert!(texts(source).is_empty());
+    }
+
+    #[rstest]
+    fn records_byte_offsets() {
+        let source = "fn f() {}\n// g();\n";
+        let blocks = plain_comment_blocks(source);
+
+        assert_eq!(&source[blocks[0].start..blocks[0].end], "// g();");
+        assert_eq!(blocks[0].line_count(), 1);
+    }
+}
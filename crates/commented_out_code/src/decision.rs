@@ -0,0 +1,102 @@
+//! Pure decision logic for `commented_out_code`.
+//!
+//! The driver hands the text of every local source file to
+//! [`plain_comment_blocks`]. [`CommentedOutCodeDecision`] does the same for
+//! one file and reports its first block that [`looks_like_code`], naming the
+//! block's text as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::comments::plain_comment_blocks;
+use crate::driver::LINT_NAME;
+use crate::heuristics::looks_like_code;
+
+/// Reason key for comment blocks holding Rust code.
+const COMMENTED_CODE: &str = "commented_code";
+
+/// The facts about one source file that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SourceFileProjection {
+    /// The file's source text.
+    pub source: String,
+}
+
+impl SourceFileProjection {
+    /// Creates a projection for the file text `source`.
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+/// Reports plain comment blocks that read as commented-out Rust code.
+///
+/// # Examples
+///
+/// ```ignore
+/// let file = SourceFileProjection::new("fn main() {\n    // retry(3);\n}\n");
+/// assert!(CommentedOutCodeDecision.decide(&file).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CommentedOutCodeDecision;
+
+impl LintDecision for CommentedOutCodeDecision {
+    type Projection = SourceFileProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, file: &SourceFileProjection) -> Option<Finding> {
+        let block = plain_comment_blocks(&file.source)
+            .into_iter()
+            .find(|block| looks_like_code(&block.text))?;
+        Some(Finding::new(LINT_NAME, COMMENTED_CODE).with_subject(block.text))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<SourceFileProjection>> {
+        vec![
+            DecisionFixture::flagged(
+                "commented-out statement",
+                SourceFileProjection::new("fn main() {\n    // retry(3);\n}\n"),
+                COMMENTED_CODE,
+            ),
+            DecisionFixture::clean(
+                "prose comment",
+                SourceFileProjection::new("// Retry once; the server may be warming up.\n"),
+            ),
+            DecisionFixture::clean(
+                "doc comment example",
+                SourceFileProjection::new("/// let total = compute(items);\nfn total() {}\n"),
+            ),
+            DecisionFixture::clean(
+                "comment marker in a string",
+                SourceFileProjection::new("const URL: &str = \"http://example.com/a;\";\n"),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_commented_code() {
+        let file = SourceFileProjection::new("// let a = 1;\n// let b = 2;\n");
+        let finding = CommentedOutCodeDecision
+            .decide(&file)
+            .expect("the block should be reported");
+
+        assert_eq!(finding.subject(), Some("let a = 1;\nlet b = 2;"));
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&CommentedOutCodeDecision), Ok(4));
+    }
+}
@@ -0,0 +1,27 @@
+//! Embedded documentation for the `commented_out_code` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, examples, and configuration for `commented_out_code`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "commented_out_code",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags plain comments whose text is commented-out Rust code.",
+    rationale: "Code kept in comments stops compiling, drifts from the code around it, and leaves readers guessing whether it still matters. Version control already records deleted code, so the comment adds noise without preserving anything. Doc comments and fenced examples are never reported.",
+    bad_example: concat!(
+        "fn total(items: &[Item]) -> u64 {\n",
+        "    // let discounted = apply_discount(items);\n",
+        "    // log::debug!(\"{discounted:?}\");\n",
+        "    items.iter().map(Item::price).sum()\n",
+        "}",
+    ),
+    good_example: concat!(
+        "fn total(items: &[Item]) -> u64 {\n",
+        "    items.iter().map(Item::price).sum()\n",
+        "}",
+    ),
+    config: &[],
+};
@@ -0,0 +1,183 @@
+//! Detect comment blocks that contain commented-out Rust code.
+//!
+//! The lint runs once per crate. During `check_crate` it scans the source of
+//! every file belonging to the local crate for plain comments (see
+//! [`crate::comments`]) and reports each block that the heuristics in
+//! [`crate::heuristics`] judge to be code. Doc comments are never scanned,
+//! and fenced examples inside plain comments are ignored.
+//!
+//! Diagnostics attach to the innermost item whose span contains the comment,
+//! so `#[allow(commented_out_code)]` on an item or module silences the
+//! comments inside it.
+
+use std::sync::Arc;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{BytePos, FileName, SourceFile, Span};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::comments::{CommentBlock, plain_comment_blocks};
+use crate::heuristics::looks_like_code;
+
+pub(crate) const LINT_NAME: &str = "commented_out_code";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+/// Lint pass reporting comments that contain commented-out code.
+#[derive(Default)]
+pub struct CommentedOutCode {
+    localizer: CrateScopedState<Localizer>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub COMMENTED_OUT_CODE,
+    Warn,
+    "commented-out code should be deleted rather than kept in comments",
+    CommentedOutCode::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for CommentedOutCode {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        let findings = FindingLimit::new(COMMENTED_OUT_CODE, &shared_config);
+
+        let mut spans = Vec::new();
+        for file in local_source_files(cx) {
+            let Some(source) = file.src.as_deref() else {
+                continue;
+            };
+            let blocks = plain_comment_blocks(source)
+                .into_iter()
+                .filter(|block| looks_like_code(&block.text));
+            for block in blocks {
+                debug!(
+                    target: LINT_NAME,
+                    "{} commented-out lines in {:?}",
+                    block.line_count(),
+                    file.name,
+                );
+                spans.push(block_span(&file, &block));
+            }
+        }
+
+        let owners = OwnerSpans::collect(cx);
+        for span in spans {
+            let owner = owners.innermost(span);
+            if findings.admit(cx, owner, span) {
+                emit_diagnostic(cx, owner, span, &self.localizer);
+            }
+        }
+        findings.summarise(cx, &self.localizer);
+    }
+}
+
+/// Returns the hand-written source files of the crate being linted.
+fn local_source_files(cx: &LateContext<'_>) -> Vec<Arc<SourceFile>> {
+    cx.sess()
+        .source_map()
+        .files()
+        .iter()
+        .filter(|file| file.cnum == LOCAL_CRATE && matches!(file.name, FileName::Real(_)))
+        .cloned()
+        .collect()
+}
+
+fn block_span(file: &SourceFile, block: &CommentBlock) -> Span {
+    let position = |offset: usize| file.start_pos + BytePos(offset as u32);
+    Span::with_root_ctxt(position(block.start), position(block.end))
+}
+
+/// The spans of every HIR owner, used to choose where lint levels apply.
+struct OwnerSpans {
+    owners: Vec<(hir::HirId, Span)>,
+}
+
+impl OwnerSpans {
+    fn collect(cx: &LateContext<'_>) -> Self {
+        let owners = cx
+            .tcx
+            .hir_crate_items(())
+            .owners()
+            .map(|owner| {
+                let hir_id = hir::HirId::make_owner(owner.def_id);
+                (hir_id, cx.tcx.hir_span_with_body(hir_id))
+            })
+            .filter(|(_, span)| !span.from_expansion())
+            .collect();
+        Self { owners }
+    }
+
+    /// Returns the smallest owner whose span contains `span`, falling back to
+    /// the crate root.
+    fn innermost(&self, span: Span) -> hir::HirId {
+        self.owners
+            .iter()
+            .filter(|(_, owner_span)| owner_span.contains(span))
+            .min_by_key(|(_, owner_span)| owner_span.hi() - owner_span.lo())
+            .map_or(hir::CRATE_HIR_ID, |(hir_id, _)| *hir_id)
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, hir_id: hir::HirId, span: Span, localizer: &Localizer) {
+    let args: Arguments<'static> = Arguments::default();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages =
+        safe_resolve_message_set(localizer, resolution, noop_reporter, fallback_messages);
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.tcx.emit_node_span_lint(
+        COMMENTED_OUT_CODE,
+        hir_id,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages() -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        "This comment contains commented-out code.".to_string(),
+        "Version control already keeps the history of deleted code.".to_string(),
+        "Delete the commented-out code and recover it from version control if it is needed again."
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn fallback_messages_point_to_version_control() {
+        let messages = fallback_messages();
+
+        assert!(messages.primary().contains("commented-out code"));
+        assert!(messages.note().contains("Version control"));
+        assert!(messages.help().starts_with("Delete"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
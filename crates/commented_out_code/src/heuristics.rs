@@ -0,0 +1,210 @@
+//! Pure heuristics deciding whether comment text is commented-out Rust code.
+//!
+//! The check is deliberately conservative. A comment counts as code only
+//! when its brackets balance, at least one line carries a strong signal (a
+//! statement ending in `;`, a `let` binding, or a `fn` signature), and
+//! code-shaped lines make up at least half of the non-blank lines. Fenced
+//! code examples are removed before any line is classified, so illustrative
+//! snippets never count.
+
+/// Returns `true` when comment text reads as commented-out Rust code.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert!(looks_like_code("let total = compute(items);"));
+/// assert!(looks_like_code("fn legacy() {\n    retry();\n}"));
+///
+/// assert!(!looks_like_code("Retry once; the server may be warming up."));
+/// assert!(!looks_like_code("For example:\n```\nlet x = 1;\n```"));
+/// ```
+#[must_use]
+pub(crate) fn looks_like_code(text: &str) -> bool {
+    let lines = unfenced_lines(text);
+    if lines.is_empty() || !brackets_balance(&lines) {
+        return false;
+    }
+
+    let kinds: Vec<LineKind> = lines.iter().map(|line| classify(line)).collect();
+    let strong = kinds
+        .iter()
+        .filter(|kind| **kind == LineKind::Strong)
+        .count();
+    let code = kinds
+        .iter()
+        .filter(|kind| **kind != LineKind::Prose)
+        .count();
+    strong > 0 && code * 2 >= kinds.len()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineKind {
+    /// A statement, binding, or signature.
+    Strong,
+    /// Structural code such as a lone brace or an attribute.
+    Structural,
+    Prose,
+}
+
+/// Returns the trimmed, non-blank lines outside fenced code blocks.
+fn unfenced_lines(text: &str) -> Vec<&str> {
+    let mut in_fence = false;
+    text.lines()
+        .map(str::trim)
+        .filter(|line| {
+            if line.starts_with("```") || line.starts_with("~~~") {
+                in_fence = !in_fence;
+                return false;
+            }
+            !in_fence && !line.is_empty()
+        })
+        .collect()
+}
+
+fn classify(line: &str) -> LineKind {
+    if is_labelled_note(line) {
+        return LineKind::Prose;
+    }
+    if is_statement(line) || is_let_binding(line) || is_fn_signature(line) {
+        return LineKind::Strong;
+    }
+    if is_structural(line) {
+        return LineKind::Structural;
+    }
+    LineKind::Prose
+}
+
+/// Matches notes such as `TODO: call flush();` whose first word is a
+/// capitalised label.
+fn is_labelled_note(line: &str) -> bool {
+    line.split_whitespace().next().is_some_and(|word| {
+        word.starts_with(|ch: char| ch.is_ascii_uppercase())
+            && word.ends_with(':')
+            && !word.contains("::")
+    })
+}
+
+/// Matches lines ending in `;` that also contain code punctuation, so prose
+/// such as "first this; then that;" is not mistaken for a statement.
+fn is_statement(line: &str) -> bool {
+    line.ends_with(';') && has_code_punctuation(line)
+}
+
+fn has_code_punctuation(line: &str) -> bool {
+    line.contains(['(', '=', '[', '!'])
+        || line.contains("::")
+        || line.as_bytes().windows(3).any(|window| {
+            is_ident_byte(window[0]) && window[1] == b'.' && is_ident_start(window[2])
+        })
+}
+
+fn is_let_binding(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix("let ") else {
+        return false;
+    };
+    let rest = rest.strip_prefix("mut ").unwrap_or(rest);
+    rest.starts_with(|ch: char| ch.is_ascii_alphabetic() || matches!(ch, '_' | '(' | '['))
+        && (rest.contains('=') || rest.ends_with(';'))
+}
+
+fn is_fn_signature(line: &str) -> bool {
+    let line = line.strip_prefix("pub ").unwrap_or(line);
+    let line = line.strip_prefix("async ").unwrap_or(line);
+    let Some(rest) = line.strip_prefix("fn ") else {
+        return false;
+    };
+    let name_len = rest.bytes().take_while(|byte| is_ident_byte(*byte)).count();
+    name_len > 0 && rest[name_len..].starts_with(['(', '<'])
+}
+
+fn is_structural(line: &str) -> bool {
+    matches!(line, "}" | "};" | "}," | ")" | ");" | "]" | "];")
+        || line.ends_with('{')
+        || (line.starts_with("#[") && line.ends_with(']'))
+}
+
+/// Checks that `()`, `[]`, and `{}` nest correctly outside string literals.
+fn brackets_balance(lines: &[&str]) -> bool {
+    let mut stack = Vec::new();
+    let nested = lines
+        .iter()
+        .flat_map(|line| outside_strings(line))
+        .all(|ch| match ch {
+            '(' | '[' | '{' => {
+                stack.push(ch);
+                true
+            }
+            ')' => stack.pop() == Some('('),
+            ']' => stack.pop() == Some('['),
+            '}' => stack.pop() == Some('{'),
+            _ => true,
+        });
+    nested && stack.is_empty()
+}
+
+/// Yields the characters of a line that fall outside `"..."` literals.
+fn outside_strings(line: &str) -> impl Iterator<Item = char> + '_ {
+    let mut in_string = false;
+    let mut escaped = false;
+    line.chars().filter(move |&ch| {
+        if in_string {
+            match (escaped, ch) {
+                (false, '\\') => escaped = true,
+                (false, '"') => in_string = false,
+                _ => escaped = false,
+            }
+            return false;
+        }
+        in_string = ch == '"';
+        !in_string
+    })
+}
+
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+fn is_ident_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::statement("flush(&mut writer);")]
+    #[case::method_call("cache.clear();")]
+    #[case::let_binding("let retries = 3")]
+    #[case::signature("fn legacy_path(input: &str) -> String {\n    input.to_owned()\n}")]
+    #[case::block("if ready {\n    start();\n}")]
+    #[case::attribute("#[inline]\npub fn fast() {}")]
+    fn detects_code(#[case] text: &str) {
+        assert!(looks_like_code(text), "{text:?}");
+    }
+
+    #[rstest]
+    #[case::prose("This retries the request once.")]
+    #[case::semicolon_prose("Retry once; the server may be warming up;")]
+    #[case::labelled_note("TODO: call flush();")]
+    #[case::unbalanced("run(first;")]
+    #[case::lone_brace("}")]
+    #[case::fenced("For example:\n```\nlet x = 1;\n```")]
+    #[case::mostly_prose("The cache is rebuilt lazily.\nCallers must hold the lock.\nSee clear();")]
+    fn ignores_prose(#[case] text: &str) {
+        assert!(!looks_like_code(text), "{text:?}");
+    }
+
+    #[rstest]
+    fn fence_with_surrounding_code_still_counts() {
+        let text = "let x = build();\n```\nexample\n```";
+
+        assert!(looks_like_code(text));
+    }
+
+    #[rstest]
+    fn brackets_inside_strings_are_ignored() {
+        assert!(looks_like_code("log(\"(unbalanced\");"));
+    }
+}
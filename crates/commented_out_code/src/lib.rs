@@ -0,0 +1,42 @@
+//! Lint crate flagging comment blocks that contain commented-out code.
+//!
+//! Code left behind in comments rots: it stops compiling, misleads readers,
+//! and duplicates what version control already remembers. The lint scans the
+//! plain comments of every source file in the crate and reports blocks whose
+//! text reads as Rust statements or items. Doc comments and fenced examples
+//! are never reported.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod comments;
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod heuristics;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{CommentedOutCodeDecision, SourceFileProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn commented_out_code_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
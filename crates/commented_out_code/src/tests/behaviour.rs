@@ -0,0 +1,71 @@
+//! Behaviour-driven coverage for commented-out code detection.
+
+use crate::comments::plain_comment_blocks;
+use crate::heuristics::looks_like_code;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct SourceWorld {
+    lines: RefCell<Vec<String>>,
+    reported: Cell<Option<usize>>,
+}
+
+#[fixture]
+fn world() -> SourceWorld {
+    SourceWorld::default()
+}
+
+#[given("the source line {line}")]
+fn given_source_line(world: &SourceWorld, line: String) {
+    world
+        .lines
+        .borrow_mut()
+        .push(line.trim_matches('"').to_string());
+}
+
+#[when("the source is scanned")]
+fn when_scanned(world: &SourceWorld) {
+    let source = world.lines.borrow().join("\n");
+    let reported = plain_comment_blocks(&source)
+        .iter()
+        .filter(|block| looks_like_code(&block.text))
+        .count();
+    world.reported.set(Some(reported));
+}
+
+#[then("{count} comment block is reported")]
+fn then_block_reported(world: &SourceWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[then("{count} comment blocks are reported")]
+fn then_blocks_reported(world: &SourceWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[scenario(path = "tests/features/commented_out_code.feature", index = 0)]
+fn scenario_reports_statement(world: SourceWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/commented_out_code.feature", index = 1)]
+fn scenario_groups_adjacent_lines(world: SourceWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/commented_out_code.feature", index = 2)]
+fn scenario_ignores_doc_comments(world: SourceWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/commented_out_code.feature", index = 3)]
+fn scenario_ignores_fenced_examples(world: SourceWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/commented_out_code.feature", index = 4)]
+fn scenario_ignores_prose(world: SourceWorld) {
+    let _ = world;
+}
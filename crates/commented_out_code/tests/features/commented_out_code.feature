@@ -0,0 +1,34 @@
+Feature: Commented-out code detection
+  Plain comments whose text reads as Rust code are reported, while doc
+  comments, fenced examples, and prose are left alone.
+
+  Scenario: A commented-out statement is reported
+    Given the source line "// let total = compute(items);"
+    When the source is scanned
+    Then 1 comment block is reported
+
+  Scenario: Adjacent commented-out lines form one block
+    Given the source line "// fn legacy() {"
+    And the source line "//     retry();"
+    And the source line "// }"
+    When the source is scanned
+    Then 1 comment block is reported
+
+  Scenario: Doc comments are never reported
+    Given the source line "/// let total = compute(items);"
+    And the source line "fn documented() {}"
+    When the source is scanned
+    Then 0 comment blocks are reported
+
+  Scenario: Fenced examples are never reported
+    Given the source line "// For example:"
+    And the source line "// ```"
+    And the source line "// let total = compute(items);"
+    And the source line "// ```"
+    When the source is scanned
+    Then 0 comment blocks are reported
+
+  Scenario: Prose comments are not reported
+    Given the source line "// Retry once; the server may still be warming up."
+    When the source is scanned
+    Then 0 comment blocks are reported
@@ -0,0 +1,16 @@
+//! A block comment holding a whole function is reported.
+
+/*
+fn legacy_total(values: &[u64]) -> u64 {
+    let mut total = 0;
+    for value in values {
+        total += value;
+    }
+    total
+}
+*/
+
+fn main() {
+    let values = [1_u64, 2, 3];
+    let _ = values.iter().sum::<u64>();
+}
@@ -0,0 +1,11 @@
+//! Commented-out statements inside a function body are reported.
+
+fn total(values: &[u64]) -> u64 {
+    // let doubled: Vec<u64> = values.iter().map(|value| value * 2).collect();
+    // println!("{doubled:?}");
+    values.iter().sum()
+}
+
+fn main() {
+    let _ = total(&[1, 2, 3]);
+}
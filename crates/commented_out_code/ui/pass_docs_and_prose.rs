@@ -0,0 +1,34 @@
+//! Doc comments, fenced examples, and prose comments are not reported.
+//!
+//! ```
+//! let total = compute(&[1, 2, 3]);
+//! ```
+
+/// Sums the values.
+///
+/// let total = compute(&[1, 2, 3]);
+fn compute(values: &[u64]) -> u64 {
+    // Sum in one pass; the slice is usually short.
+    values.iter().sum()
+}
+
+/** let unused = compute(&[]); */
+fn describe() -> &'static str {
+    // For example:
+    // ```
+    // let total = compute(&[1, 2, 3]);
+    // ```
+    "sums values"
+}
+
+#[allow(commented_out_code)]
+fn allowed() {
+    // compute(&[4, 5, 6]);
+}
+
+fn main() {
+    // TODO: call describe() once the output format settles;
+    let _ = compute(&[1, 2, 3]);
+    let _ = describe();
+    allowed();
+}
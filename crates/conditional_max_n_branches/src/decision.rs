@@ -0,0 +1,132 @@
+//! Pure decision logic for `conditional_max_n_branches`.
+//!
+//! The driver counts the short-circuit branches in each `if`, `while`, and
+//! match-guard predicate and hands the count to
+//! [`ConditionalMaxNBranchesDecision`]. A lone `if let` or `while let` is a
+//! pattern match rather than a boolean predicate and is never reported; in a
+//! let-chain, each `let` counts as one branch.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_BRANCHES, LINT_NAME};
+
+/// Reason key for predicates with more branches than the limit.
+const EXCEEDS_LIMIT: &str = "exceeds_limit";
+
+/// The facts about one predicate that the decision needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConditionProjection {
+    /// Boolean branches joined by `&&` and `||` in the predicate.
+    pub branches: usize,
+    /// Whether the predicate is a single `let` pattern match rather than a
+    /// let-chain.
+    pub is_let: bool,
+}
+
+/// Reports predicates with more than `max_branches` branches.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = ConditionalMaxNBranchesDecision::new(2);
+/// let condition = ConditionProjection { branches: 3, is_let: false };
+/// assert!(decision.decide(&condition).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConditionalMaxNBranchesDecision {
+    max_branches: usize,
+}
+
+impl ConditionalMaxNBranchesDecision {
+    /// Creates a decision with the given branch limit; a limit of zero is
+    /// treated as one.
+    #[must_use]
+    pub fn new(max_branches: usize) -> Self {
+        Self {
+            max_branches: max_branches.max(1),
+        }
+    }
+
+    /// Returns the branch limit.
+    #[must_use]
+    pub const fn max_branches(&self) -> usize {
+        self.max_branches
+    }
+}
+
+impl Default for ConditionalMaxNBranchesDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BRANCHES)
+    }
+}
+
+impl LintDecision for ConditionalMaxNBranchesDecision {
+    type Projection = ConditionProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, condition: &ConditionProjection) -> Option<Finding> {
+        let disposition = evaluate_condition(condition.branches, self.max_branches);
+        (!condition.is_let && disposition == ConditionDisposition::ExceedsLimit)
+            .then(|| Finding::new(LINT_NAME, EXCEEDS_LIMIT))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ConditionProjection>> {
+        let condition = |branches, is_let| ConditionProjection { branches, is_let };
+        vec![
+            DecisionFixture::clean("single branch", condition(1, false)),
+            DecisionFixture::clean("exactly at the limit", condition(self.max_branches, false)),
+            DecisionFixture::flagged(
+                "one branch over the limit",
+                condition(self.max_branches + 1, false),
+                EXCEEDS_LIMIT,
+            ),
+            DecisionFixture::clean(
+                "let pattern over the limit",
+                condition(self.max_branches + 1, true),
+            ),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ConditionDisposition {
+    WithinLimit,
+    ExceedsLimit,
+}
+
+pub(crate) const fn evaluate_condition(branches: usize, limit: usize) -> ConditionDisposition {
+    if branches > limit {
+        ConditionDisposition::ExceedsLimit
+    } else {
+        ConditionDisposition::WithinLimit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case(1, 2, ConditionDisposition::WithinLimit)]
+    #[case(2, 2, ConditionDisposition::WithinLimit)]
+    #[case(3, 2, ConditionDisposition::ExceedsLimit)]
+    fn evaluate_condition_respects_limit(
+        #[case] branches: usize,
+        #[case] limit: usize,
+        #[case] expected: ConditionDisposition,
+    ) {
+        assert_eq!(evaluate_condition(branches, limit), expected);
+    }
+
+    #[rstest]
+    #[case::default_limit(ConditionalMaxNBranchesDecision::default())]
+    #[case::zero_limit(ConditionalMaxNBranchesDecision::new(0))]
+    fn decision_conforms(#[case] decision: ConditionalMaxNBranchesDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
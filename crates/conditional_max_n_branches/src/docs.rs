@@ -0,0 +1,37 @@
+//! Embedded documentation for the `conditional_max_n_branches` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `conditional_max_n_branches`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "conditional_max_n_branches",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags conditionals whose predicates combine more branches than the configured limit.",
+    rationale: "Predicates built from many boolean branches are hard to read and test; naming the condition documents the intent.",
+    bad_example: concat!(
+        "if ready && !paused && retries < limit {\n",
+        "    run();\n",
+        "}",
+    ),
+    good_example: concat!(
+        "let can_run = ready && !paused && retries < limit;\n",
+        "if can_run {\n",
+        "    run();\n",
+        "}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "max_branches",
+            default: "2",
+            description: "Maximum number of predicate branches in a single conditional. From behaviour version 2 the branches of a `matches!` guard count towards the conditional containing it.",
+        },
+        ConfigKeyDocs {
+            key: "overrides",
+            default: "{}",
+            description: "Branch limits for named modules, such as `\"crate::parser\" = 4`; each covers the module and those nested in it, and the deepest match wins.",
+        },
+    ],
+};
@@ -2,38 +2,63 @@
 //!
 //! The lint counts boolean branches within `if`, `while`, and `match` guard
 //! predicates, flagging expressions that involve more than the configured
-//! number of short-circuit branches. Diagnostics are localized through the
-//! shared Fluent bundles so helper text stays consistent with other lints.
+//! number of short-circuit branches. Each `let` in a let-chain counts as one
+//! branch, and from behaviour version 2 a `matches!` invocation counts as one
+//! branch plus those of its guard. Diagnostics are localized through the shared Fluent bundles so
+//! helper text stays consistent with other lints. A
+//! `[conditional_max_n_branches]` table in a nested `whitaker.toml` replaces
+//! the limit for conditionals in files beneath that directory, and
+//! `overrides` sets the limit for named modules and those nested in them.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 
 use fluent_templates::fluent_bundle::FluentValue;
 use log::debug;
 use rustc_hir as hir;
-use rustc_hir::{BinOpKind, ExprKind, LoopSource, UnOp};
+use rustc_hir::{ExprKind, LoopSource};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_span::{DesugaringKind, Span};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker::testing::decision::LintDecision;
 use whitaker_common::i18n::{DiagnosticMessageSet, MessageKey};
+use whitaker_common::path::module_override;
 use whitaker_common::{
-    Arguments, FALLBACK_LOCALE, Localizer, MessageResolution, branch_phrase,
+    Arguments, CrateScopedState, FALLBACK_LOCALE, Localizer, MessageResolution, branch_phrase,
     get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
 };
 
-const LINT_NAME: &str = "conditional_max_n_branches";
+use crate::decision::{ConditionProjection, ConditionalMaxNBranchesDecision};
+
+mod branches;
+
+use self::branches::{MATCHES_GUARD_BRANCHES, count_branches, is_in_predicate, matches_macro_call};
+
+pub(crate) const LINT_NAME: &str = "conditional_max_n_branches";
+pub(crate) const DEFAULT_MAX_BRANCHES: usize = 2;
 const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 struct Config {
     #[serde(default = "Config::default_max_branches")]
     max_branches: usize,
+    /// Branch limits for particular modules, keyed by module path such as
+    /// `crate::parser`.
+    overrides: BTreeMap<String, usize>,
 }
 
 impl Config {
     const fn default_max_branches() -> usize {
-        2
+        DEFAULT_MAX_BRANCHES
+    }
+
+    /// Returns the branch limit for conditionals in the module at `path`,
+    /// taken from the override naming the deepest enclosing module, if any.
+    fn max_branches_for(&self, path: &str) -> usize {
+        module_override(&self.overrides, path).unwrap_or(self.max_branches)
     }
 }
 
@@ -41,23 +66,24 @@ impl Default for Config {
     fn default() -> Self {
         Self {
             max_branches: Self::default_max_branches(),
+            overrides: BTreeMap::new(),
         }
     }
 }
 
 /// Lint pass enforcing predicate branch limits.
+#[derive(Default)]
 pub struct ConditionalMaxNBranches {
-    max_branches: usize,
-    localizer: Localizer,
+    state: CrateScopedState<CrateState>,
 }
 
-impl Default for ConditionalMaxNBranches {
-    fn default() -> Self {
-        Self {
-            max_branches: Config::default().max_branches,
-            localizer: Localizer::new(None),
-        }
-    }
+/// Branch limits and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+    matches_guards: bool,
 }
 
 dylint_linting::impl_late_lint! {
@@ -69,9 +95,19 @@ dylint_linting::impl_late_lint! {
 
 impl<'tcx> LateLintPass<'tcx> for ConditionalMaxNBranches {
     fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
-        self.max_branches = load_configuration().max_branches.max(1);
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(CONDITIONAL_MAX_N_BRANCHES, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+            matches_guards: shared_config
+                .behaviour_version(LINT_NAME)
+                .enables(MATCHES_GUARD_BRANCHES),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
@@ -87,7 +123,10 @@ impl<'tcx> LateLintPass<'tcx> for ConditionalMaxNBranches {
                     self.inspect_condition(cx, ConditionKind::While, cond);
                 }
             }
-            ExprKind::Match(_, arms, _) => self.inspect_match_guards(cx, arms),
+            // A `matches!` guard inside a predicate counts towards it instead.
+            ExprKind::Match(_, arms, _) if !self.counted_in_predicate(cx, expr) => {
+                self.inspect_match_guards(cx, arms);
+            }
             _ => {}
         }
     }
@@ -109,21 +148,52 @@ fn load_configuration() -> Config {
 
 impl ConditionalMaxNBranches {
     fn inspect_condition(&self, cx: &LateContext<'_>, kind: ConditionKind, expr: &hir::Expr<'_>) {
-        if matches!(expr.kind, ExprKind::Let(..)) {
-            return;
-        }
-
-        let branches = count_branches(expr);
-        if evaluate_condition(branches, self.max_branches) == ConditionDisposition::WithinLimit {
+        let condition = ConditionProjection {
+            branches: count_branches(cx, expr, self.state.matches_guards),
+            is_let: matches!(expr.kind, ExprKind::Let(..)),
+        };
+        // A lone `matches!` is reported at its invocation, not its expansion.
+        let span = matches_macro_call(cx, expr.peel_drop_temps())
+            .filter(|_| self.state.matches_guards)
+            .map_or(expr.span, |(call_site, _)| call_site);
+        let decision = self.decision_at(cx, span, expr.hir_id);
+        if decision.decide(&condition).is_none() {
             return;
         }
 
         let metadata = ConditionMetadata {
             kind,
-            span: expr.span,
-            branches,
+            span,
+            branches: condition.branches,
         };
-        emit_diagnostic(cx, &metadata, self.max_branches, &self.localizer);
+        if self.state.findings.admit(cx, expr.hir_id, metadata.span) {
+            emit_diagnostic(
+                cx,
+                &metadata,
+                decision.max_branches(),
+                &self.state.localizer,
+            );
+        }
+    }
+
+    /// Returns the decision for a conditional at `span`, with the directory
+    /// overrides above its file applied and the limit of its module chosen.
+    fn decision_at(
+        &self,
+        cx: &LateContext<'_>,
+        span: Span,
+        hir_id: hir::HirId,
+    ) -> ConditionalMaxNBranchesDecision {
+        let config = self.state.findings.config_at(cx, span, &self.state.config);
+        ConditionalMaxNBranchesDecision::new(config.max_branches_for(&module_path(cx, hir_id)))
+    }
+
+    /// Reports whether `expr` is a `matches!` whose guard is counted by an
+    /// enclosing predicate rather than checked on its own.
+    fn counted_in_predicate(&self, cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+        self.state.matches_guards
+            && matches_macro_call(cx, expr).is_some()
+            && is_in_predicate(cx, expr)
     }
 
     fn inspect_match_guards(&self, cx: &LateContext<'_>, arms: &[hir::Arm<'_>]) {
@@ -144,20 +214,6 @@ fn extract_while_condition<'hir>(block: &'hir hir::Block<'hir>) -> Option<&'hir
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum ConditionDisposition {
-    WithinLimit,
-    ExceedsLimit,
-}
-
-const fn evaluate_condition(branches: usize, limit: usize) -> ConditionDisposition {
-    if branches > limit {
-        ConditionDisposition::ExceedsLimit
-    } else {
-        ConditionDisposition::WithinLimit
-    }
-}
-
 #[derive(Clone, Copy, Debug)]
 struct ConditionMetadata {
     kind: ConditionKind,
@@ -182,22 +238,6 @@ impl ConditionKind {
     }
 }
 
-fn count_branches(expr: &hir::Expr<'_>) -> usize {
-    match expr.kind {
-        ExprKind::Binary(op, lhs, rhs) if matches!(op.node, BinOpKind::And | BinOpKind::Or) => {
-            count_branches(lhs) + count_branches(rhs)
-        }
-        ExprKind::Unary(UnOp::Not, inner) => count_branches(inner),
-        ExprKind::DropTemps(inner) => count_branches(inner),
-        ExprKind::Block(block, _) => match block.expr {
-            Some(inner) => count_branches(inner),
-            None => 1,
-        },
-        ExprKind::If(cond, ..) => count_branches(cond),
-        _ => 1,
-    }
-}
-
 fn emit_diagnostic(
     cx: &LateContext<'_>,
     metadata: &ConditionMetadata,
@@ -285,24 +325,6 @@ fn fallback_messages(kind: ConditionKind, branches: usize, limit: usize) -> Diag
     DiagnosticMessageSet::new(primary, note, help)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::rstest;
-
-    #[rstest]
-    #[case(1, 2, ConditionDisposition::WithinLimit)]
-    #[case(2, 2, ConditionDisposition::WithinLimit)]
-    #[case(3, 2, ConditionDisposition::ExceedsLimit)]
-    fn evaluate_condition_respects_limit(
-        #[case] branches: usize,
-        #[case] limit: usize,
-        #[case] expected: ConditionDisposition,
-    ) {
-        assert_eq!(evaluate_condition(branches, limit), expected);
-    }
-}
-
 #[cfg(test)]
 #[path = "tests/behaviour.rs"]
 mod behaviour;
@@ -0,0 +1,86 @@
+//! Branch counting for `if`, `while`, and match-guard predicates.
+//!
+//! Operands of `&&` and `||` count separately, so each `let` in a let-chain is
+//! one branch. From behaviour version [`MATCHES_GUARD_BRANCHES`], a `matches!`
+//! invocation also counts the branches of its guard; earlier versions count it
+//! as a single branch and check its guard as a separate match guard, so
+//! upgrading does not surface new findings.
+
+use rustc_hir as hir;
+use rustc_hir::{BinOpKind, ExprKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_span::{ExpnKind, MacroKind, Span, sym};
+
+/// Behaviour version that counts `matches!` guards towards the enclosing
+/// predicate.
+pub(super) const MATCHES_GUARD_BRANCHES: u32 = 2;
+
+/// Counts the boolean branches in a predicate.
+///
+/// When `matches_guards` is set, a `matches!` invocation is one branch plus
+/// the branches of its guard; see [`MATCHES_GUARD_BRANCHES`].
+pub(super) fn count_branches(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    matches_guards: bool,
+) -> usize {
+    if let Some((_, arms)) = matches_macro_call(cx, expr).filter(|_| matches_guards) {
+        let guard = arms.first().and_then(|arm| arm.guard);
+        return 1 + guard.map_or(0, |guard| count_branches(cx, guard, matches_guards));
+    }
+    let count = |inner| count_branches(cx, inner, matches_guards);
+    match expr.kind {
+        ExprKind::Binary(op, lhs, rhs) if matches!(op.node, BinOpKind::And | BinOpKind::Or) => {
+            count(lhs) + count(rhs)
+        }
+        ExprKind::Unary(UnOp::Not, inner) => count(inner),
+        ExprKind::DropTemps(inner) => count(inner),
+        ExprKind::Block(block, _) => block.expr.map_or(1, count),
+        ExprKind::If(cond, ..) => count(cond),
+        _ => 1,
+    }
+}
+
+/// Reports whether `expr` is counted towards an enclosing `if`, `while`, or
+/// match-guard predicate.
+pub(super) fn is_in_predicate(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    let mut child = expr.hir_id;
+    for (id, node) in cx.tcx.hir_parent_iter(expr.hir_id) {
+        match node {
+            hir::Node::Expr(parent) => match parent.kind {
+                ExprKind::If(cond, ..) => return cond.hir_id == child,
+                ExprKind::Binary(op, ..) if matches!(op.node, BinOpKind::And | BinOpKind::Or) => {}
+                ExprKind::Unary(UnOp::Not, _) | ExprKind::DropTemps(_) | ExprKind::Block(..) => {}
+                _ => return false,
+            },
+            hir::Node::Block(_) => {}
+            hir::Node::Arm(arm) => return arm.guard.is_some_and(|guard| guard.hir_id == child),
+            _ => return false,
+        }
+        child = id;
+    }
+    false
+}
+
+/// Returns the call site and arms of a `match` expanded from a `matches!`
+/// invocation written directly in the analysed code.
+pub(super) fn matches_macro_call<'hir>(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'hir>,
+) -> Option<(Span, &'hir [hir::Arm<'hir>])> {
+    let ExprKind::Match(_, arms, _) = expr.kind else {
+        return None;
+    };
+    let expn = expr.span.ctxt().outer_expn_data();
+    let ExpnKind::Macro(MacroKind::Bang, name) = expn.kind else {
+        return None;
+    };
+    let from_core = expn
+        .macro_def_id
+        .is_some_and(|def_id| cx.tcx.crate_name(def_id.krate) == sym::core);
+    if name.as_str() != "matches" || !from_core || expn.call_site.from_expansion() {
+        return None;
+    }
+
+    Some((expn.call_site, arms))
+}
@@ -1,6 +1,6 @@
 //! Behaviour-driven coverage for predicate branch evaluation logic.
 
-use super::{ConditionDisposition, evaluate_condition};
+use crate::decision::{ConditionDisposition, evaluate_condition};
 use rstest::fixture;
 use rstest_bdd_macros::{given, scenario, then, when};
 use std::cell::Cell;
@@ -0,0 +1,33 @@
+//! UI test fixture showing that let-chains and, at behaviour version 2,
+//! `matches!` guards count towards the branch limit.
+//! `pass_let_chain_and_matches_default.rs` holds the `matches!` conditions at
+//! the default behaviour version.
+#![warn(conditional_max_n_branches)]
+
+#[derive(Clone, Copy)]
+enum Kind {
+    File,
+    Link,
+}
+
+fn lookup(key: &str) -> Option<Kind> {
+    (!key.is_empty()).then_some(Kind::File)
+}
+
+fn enabled() -> bool { true }
+fn quiet() -> bool { false }
+
+fn main() {
+    if let Some(kind) = lookup("entry") && enabled() && matches!(kind, Kind::File) {
+        println!("let-chain matched");
+    }
+
+    let kind = Kind::Link;
+    if matches!(kind, Kind::Link if enabled() && !quiet() && lookup("link").is_some()) {
+        println!("guarded match");
+    }
+
+    if enabled() && matches!(kind, Kind::Link if !quiet()) {
+        println!("guard counted in the condition");
+    }
+}
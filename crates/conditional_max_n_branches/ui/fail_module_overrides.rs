@@ -0,0 +1,31 @@
+fn ready() -> bool { true }
+fn approved() -> bool { true }
+fn enabled() -> bool { true }
+
+mod parser {
+    pub fn ident_start(c: char) -> Option<char> {
+        if c.is_alphabetic() || c == '_' || c == '$' {
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    pub mod tokens {
+        pub fn separator(c: char) -> Option<char> {
+            if c == ',' || c == ';' || c == ':' || c.is_whitespace() {
+                Some(c)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(parser::ident_start('a'), Some('a'));
+    assert_eq!(parser::tokens::separator(','), Some(','));
+    if ready() && approved() && enabled() {
+        println!("outside the override, the default limit applies");
+    }
+}
@@ -0,0 +1,11 @@
+#![warn(conditional_max_n_branches)]
+
+// `legacy/whitaker.toml` raises the limit for the three-branch condition.
+#[path = "pass_directory_override/legacy/checks.rs"]
+mod legacy;
+
+fn main() {
+    if legacy::all_conditions_satisfied() {
+        println!("nested override allows three branches");
+    }
+}
@@ -0,0 +1,10 @@
+fn alpha() -> bool { true }
+fn beta() -> bool { true }
+fn gamma() -> bool { true }
+
+pub fn all_conditions_satisfied() -> bool {
+    if alpha() && beta() && gamma() {
+        return true;
+    }
+    false
+}
@@ -0,0 +1,29 @@
+//! UI test fixture showing let-chains and `matches!` invocations within the
+//! branch limit.
+#![deny(conditional_max_n_branches)]
+
+#[derive(Clone, Copy)]
+enum Kind {
+    File,
+    Link,
+}
+
+fn lookup(key: &str) -> Option<Kind> {
+    (!key.is_empty()).then_some(Kind::File)
+}
+
+fn enabled() -> bool { true }
+
+fn main() {
+    if let Some(kind) = lookup("entry") && matches!(kind, Kind::File) {
+        println!("let-chain matched");
+    }
+
+    let kind = Kind::Link;
+    if matches!(kind, Kind::File | Kind::Link) {
+        println!("either kind");
+    }
+
+    let linked = matches!(kind, Kind::Link if enabled() && lookup("link").is_some());
+    println!("linked: {linked}");
+}
@@ -0,0 +1,27 @@
+//! UI test fixture showing `matches!` conditions that stay within the branch
+//! limit at the default behaviour version.
+//!
+//! Below behaviour version 2, a `matches!` invocation counts as one branch and
+//! its guard is checked as a separate match guard, so neither condition here is
+//! reported. Both are reported once `conditional_max_n_branches = 2` is set.
+#![deny(conditional_max_n_branches)]
+
+#[derive(Clone, Copy)]
+enum Kind {
+    File,
+    Link,
+}
+
+fn enabled() -> bool { true }
+fn quiet() -> bool { false }
+
+fn main() {
+    let kind = Kind::Link;
+    if matches!(kind, Kind::Link if enabled() && !quiet()) {
+        println!("guarded match");
+    }
+
+    if enabled() && matches!(kind, Kind::File | Kind::Link if !quiet()) {
+        println!("guard checked on its own");
+    }
+}
@@ -0,0 +1,127 @@
+//! Pure decision logic for `config_struct_fields_must_have_defaults_documented`.
+//!
+//! The driver reads, for each field of a struct, whether serde fills it with
+//! a default through the field's or the struct's `#[serde(...)]` attribute,
+//! and joins its doc comments.
+//! [`ConfigStructFieldsMustHaveDefaultsDocumentedDecision`] matches the struct
+//! name against the [`TypePatterns`] and judges the field with
+//! [`lacks_documented_default`], as the driver does.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_TYPE_PATTERNS, LINT_NAME};
+use crate::policy::{TypePatterns, lacks_documented_default};
+
+/// Reason key for defaulted fields whose docs do not state the default.
+const UNDOCUMENTED_DEFAULT: &str = "undocumented_default";
+
+/// The facts about one struct field that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigFieldProjection {
+    /// Name of the struct declaring the field.
+    pub struct_name: String,
+    /// Whether the struct's `#[serde(...)]` attribute requests a default.
+    pub struct_default: bool,
+    /// Whether the field's `#[serde(...)]` attribute requests a default.
+    pub field_default: bool,
+    /// The field's doc comments joined into one string.
+    pub doc: String,
+}
+
+/// Reports defaulted fields of configuration structs whose documentation
+/// does not state the default.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = ConfigStructFieldsMustHaveDefaultsDocumentedDecision::default();
+/// let field = ConfigFieldProjection {
+///     struct_name: "ServerConfig".to_owned(),
+///     field_default: true,
+///     doc: "Port to listen on.".to_owned(),
+///     ..ConfigFieldProjection::default()
+/// };
+/// assert!(decision.decide(&field).is_some());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ConfigStructFieldsMustHaveDefaultsDocumentedDecision {
+    type_patterns: TypePatterns,
+}
+
+impl ConfigStructFieldsMustHaveDefaultsDocumentedDecision {
+    /// Creates a decision treating structs whose names match `type_patterns`
+    /// as configuration. Invalid globs are skipped.
+    #[must_use]
+    pub fn new(type_patterns: &[String]) -> Self {
+        Self {
+            type_patterns: TypePatterns::new(LINT_NAME, type_patterns),
+        }
+    }
+}
+
+impl Default for ConfigStructFieldsMustHaveDefaultsDocumentedDecision {
+    fn default() -> Self {
+        let type_patterns: Vec<String> = DEFAULT_TYPE_PATTERNS
+            .iter()
+            .map(|&pattern| pattern.to_owned())
+            .collect();
+        Self::new(&type_patterns)
+    }
+}
+
+impl LintDecision for ConfigStructFieldsMustHaveDefaultsDocumentedDecision {
+    type Projection = ConfigFieldProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, field: &ConfigFieldProjection) -> Option<Finding> {
+        (self.type_patterns.matches(&field.struct_name)
+            && lacks_documented_default(field.struct_default, field.field_default, &field.doc))
+        .then(|| Finding::new(LINT_NAME, UNDOCUMENTED_DEFAULT).with_subject(&field.struct_name))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ConfigFieldProjection>> {
+        let field = |struct_name: &str, struct_default, doc: &str| ConfigFieldProjection {
+            struct_name: struct_name.to_owned(),
+            struct_default,
+            field_default: false,
+            doc: doc.to_owned(),
+        };
+        vec![
+            DecisionFixture::flagged(
+                "defaulted field without a stated default",
+                field("ServerConfig", true, "Port to listen on."),
+                UNDOCUMENTED_DEFAULT,
+            ),
+            DecisionFixture::clean(
+                "defaulted field stating its default",
+                field("ServerConfig", true, "Port to listen on. Defaults to 8080."),
+            ),
+            DecisionFixture::clean(
+                "field without a default",
+                field("ServerConfig", false, "Port to listen on."),
+            ),
+            DecisionFixture::clean(
+                "struct outside the type patterns",
+                field("Server", true, "Port to listen on."),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&ConfigStructFieldsMustHaveDefaultsDocumentedDecision::default()),
+            Ok(4)
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! Embedded documentation for the
+//! `config_struct_fields_must_have_defaults_documented` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `config_struct_fields_must_have_defaults_documented`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "config_struct_fields_must_have_defaults_documented",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `#[serde(default)]` fields of configuration structs whose doc comment does not state the default value.",
+    rationale: "A field filled by `#[serde(default)]` takes a value its readers cannot see without finding the `Default` impl or helper behind it. Stating the default in the field's documentation keeps the documented and actual behaviour aligned.",
+    bad_example: "#[derive(Deserialize)]\n#[serde(default)]\nstruct Config {\n    /// Maximum number of retries.\n    retries: u32,\n}",
+    good_example: "#[derive(Deserialize)]\n#[serde(default)]\nstruct Config {\n    /// Maximum number of retries. Defaults to 3.\n    retries: u32,\n}",
+    config: &[ConfigKeyDocs {
+        key: "type_patterns",
+        default: "[\"*Config\", \"*Settings\"]",
+        description: "Glob patterns matched against struct names to decide which structs are configuration.",
+    }],
+};
@@ -0,0 +1,281 @@
+//! Lint pass reporting serde-defaulted configuration fields whose docs do
+//! not state the default.
+//!
+//! Every struct with named fields whose name matches the configured
+//! [`TypePatterns`] is checked. A field is defaulted when its own
+//! `#[serde(...)]` attribute, or the struct's, contains `default` or
+//! `default = "path"`; the derive helper attributes stay on the HIR, so no
+//! trait lookup is needed. Each defaulted field whose doc comments do not
+//! mention a default is reported at the field. Structs produced by macro
+//! expansion are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{Span, Symbol};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, attribute_arguments};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::{AttributeArguments, CrateScopedState};
+
+use crate::policy::{TypePatterns, lacks_documented_default, requests_default};
+
+pub(crate) const LINT_NAME: &str = "config_struct_fields_must_have_defaults_documented";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_TYPE_PATTERNS: &[&str] = &["*Config", "*Settings"];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    type_patterns: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            type_patterns: DEFAULT_TYPE_PATTERNS
+                .iter()
+                .map(|&pattern| pattern.to_owned())
+                .collect(),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub CONFIG_STRUCT_FIELDS_MUST_HAVE_DEFAULTS_DOCUMENTED,
+    Warn,
+    "`#[serde(default)]` fields of configuration structs should state their default in their documentation",
+    ConfigStructFieldsMustHaveDefaultsDocumented::default()
+}
+
+/// Lint pass that checks configuration structs for undocumented defaults.
+#[derive(Default)]
+pub struct ConfigStructFieldsMustHaveDefaultsDocumented {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Type patterns, finding limit, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    type_patterns: TypePatterns,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ConfigStructFieldsMustHaveDefaultsDocumented {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            type_patterns: TypePatterns::new(LINT_NAME, &load_configuration().type_patterns),
+            findings: FindingLimit::new(
+                CONFIG_STRUCT_FIELDS_MUST_HAVE_DEFAULTS_DOCUMENTED,
+                &shared_config,
+            ),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let hir::ItemKind::Struct(ident, _, hir::VariantData::Struct { fields, .. }) = item.kind
+        else {
+            return;
+        };
+        let state = &*self.state;
+        if item.span.from_expansion() || !state.type_patterns.matches(ident.as_str()) {
+            return;
+        }
+
+        let struct_default = requests_default(&serde_attributes(cx, item.hir_id()));
+        for field in fields {
+            let field_default = requests_default(&serde_attributes(cx, field.hir_id));
+            if !lacks_documented_default(struct_default, field_default, &doc_text(cx, field)) {
+                continue;
+            }
+            debug!(
+                target: LINT_NAME,
+                "field `{}` of `{ident}` does not document its default",
+                field.ident,
+            );
+            if state.findings.admit(cx, field.hir_id, field.span) {
+                let message = UndocumentedDefaultMessage {
+                    field: field.ident.as_str(),
+                    ty: ident.as_str(),
+                };
+                emit_diagnostic(cx, field.span, &message, &state.localizer);
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the arguments of each `#[serde(...)]` attribute on `hir_id`.
+fn serde_attributes(cx: &LateContext<'_>, hir_id: hir::HirId) -> Vec<AttributeArguments> {
+    let serde = Symbol::intern("serde");
+    cx.tcx
+        .hir_attrs(hir_id)
+        .iter()
+        .filter(|attr| attr.has_name(serde))
+        .filter_map(attribute_arguments)
+        .collect()
+}
+
+/// Joins the doc comments on `field` into one string.
+fn doc_text(cx: &LateContext<'_>, field: &hir::FieldDef<'_>) -> String {
+    cx.tcx
+        .hir_attrs(field.hir_id)
+        .iter()
+        .filter_map(hir::Attribute::doc_str)
+        .map(|doc| doc.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &UndocumentedDefaultMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        CONFIG_STRUCT_FIELDS_MUST_HAVE_DEFAULTS_DOCUMENTED,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one undocumented default interpolates.
+#[derive(Clone, Copy, Debug)]
+struct UndocumentedDefaultMessage<'a> {
+    field: &'a str,
+    ty: &'a str,
+}
+
+impl UndocumentedDefaultMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("field"),
+            FluentValue::from(self.field.to_owned()),
+        );
+        args.insert(Cow::Borrowed("type"), FluentValue::from(self.ty.to_owned()));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { field, ty } = self;
+        DiagnosticMessageSet::new(
+            format!(
+                "Field `{field}` of `{ty}` has a serde default its documentation does not state."
+            ),
+            format!(
+                "Readers of `{ty}` cannot tell which value `{field}` takes when the configuration \
+                 omits it."
+            ),
+            String::from(
+                "State the default in the field's doc comment, for example \"Defaults to 3.\".",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn message() -> UndocumentedDefaultMessage<'static> {
+        UndocumentedDefaultMessage {
+            field: "retries",
+            ty: "ClientConfig",
+        }
+    }
+
+    fn resolve(message: &UndocumentedDefaultMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_matches_config_and_settings() {
+        assert_eq!(Config::default().type_patterns, ["*Config", "*Settings"]);
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_field(message: UndocumentedDefaultMessage<'static>) {
+        let messages = message.fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Field `retries` of `ClientConfig` has a serde default its documentation does not \
+             state."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback(message: UndocumentedDefaultMessage<'static>) {
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str, message: UndocumentedDefaultMessage<'static>) {
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`retries`"));
+        assert!(messages.note().contains("`ClientConfig`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
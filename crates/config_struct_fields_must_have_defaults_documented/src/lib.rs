@@ -0,0 +1,44 @@
+//! Lint crate requiring serde-defaulted configuration fields to document
+//! their default.
+//!
+//! A configuration struct that fills omitted fields through
+//! `#[serde(default)]` behaves in a way its readers cannot see: the value a
+//! field takes when a file leaves it out lives in a `Default` impl or a
+//! helper function. The lint reports each such field on a struct whose name
+//! matches the configured type patterns when its doc comment does not state
+//! the default, keeping the documentation and the behaviour aligned. The lint
+//! is experimental and ships behind the
+//! `experimental-config-struct-fields-must-have-defaults-documented` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ConfigFieldProjection, ConfigStructFieldsMustHaveDefaultsDocumentedDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn config_struct_fields_must_have_defaults_documented_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,153 @@
+//! Rules deciding which defaulted configuration fields are reported.
+//!
+//! The driver lowers each `#[serde(...)]` attribute into
+//! [`AttributeArguments`] and joins a field's doc comments into one string,
+//! and this module decides whether the struct is configuration and whether
+//! the field documents its default. The rules are kept free of compiler types
+//! so they can be tested directly.
+
+use glob::Pattern;
+use log::debug;
+use whitaker_common::AttributeArguments;
+
+/// Struct name globs selecting the types treated as configuration.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TypePatterns {
+    patterns: Vec<Pattern>,
+}
+
+impl TypePatterns {
+    /// Compiles `globs`, skipping any that are not valid patterns.
+    ///
+    /// Skipped globs are logged at debug level under `target`, which the
+    /// driver sets to the lint name.
+    pub(crate) fn new(target: &str, globs: &[String]) -> Self {
+        let patterns = globs
+            .iter()
+            .filter_map(|glob| match Pattern::new(glob) {
+                Ok(pattern) => Some(pattern),
+                Err(error) => {
+                    debug!(target: target, "ignoring invalid `type_patterns` glob `{glob}`: {error}");
+                    None
+                }
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    /// Returns `true` when the struct called `name` is configuration.
+    pub(crate) fn matches(&self, name: &str) -> bool {
+        self.patterns.iter().any(|pattern| pattern.matches(name))
+    }
+}
+
+/// Returns `true` when one of the `#[serde(...)]` attributes asks serde to
+/// fill omitted values, with either `default` or `default = "path"`.
+pub(crate) fn requests_default<'a>(
+    serde_attributes: impl IntoIterator<Item = &'a AttributeArguments>,
+) -> bool {
+    serde_attributes
+        .into_iter()
+        .flat_map(AttributeArguments::items)
+        .filter_map(|argument| argument.path())
+        .any(|path| path.matches(["default"]))
+}
+
+/// Returns `true` when `doc` states what a field defaults to.
+///
+/// Any mention of a default counts, such as "Defaults to 3." or
+/// "(default: `info`)", since the value itself cannot be checked against
+/// the `Default` impl.
+pub(crate) fn states_default(doc: &str) -> bool {
+    doc.to_lowercase().contains("default")
+}
+
+/// Returns `true` when a field filled by serde, through its own attribute or
+/// the struct's, has documentation that does not state the default.
+pub(crate) fn lacks_documented_default(
+    struct_default: bool,
+    field_default: bool,
+    doc: &str,
+) -> bool {
+    (struct_default || field_default) && !states_default(doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker_common::{AttributeArgument, AttributeLiteral, AttributePath};
+
+    fn serde(arguments: Vec<AttributeArgument>) -> AttributeArguments {
+        AttributeArguments::List(arguments)
+    }
+
+    #[rstest]
+    #[case::suffix("ServerConfig", true)]
+    #[case::exact("Config", true)]
+    #[case::settings("LintSettings", true)]
+    #[case::other("Server", false)]
+    fn default_patterns_select_configuration(#[case] name: &str, #[case] expected: bool) {
+        let patterns = TypePatterns::new("test", &["*Config".into(), "*Settings".into()]);
+
+        assert_eq!(patterns.matches(name), expected);
+    }
+
+    #[rstest]
+    fn invalid_patterns_are_skipped() {
+        let patterns = TypePatterns::new("test", &["[Config".into(), "Options".into()]);
+
+        assert!(patterns.matches("Options"));
+        assert!(!patterns.matches("Config"));
+    }
+
+    #[rstest]
+    #[case::word(AttributeArgument::Word(AttributePath::from("default")), true)]
+    #[case::path(
+        AttributeArgument::KeyValue {
+            key: AttributePath::from("default"),
+            value: AttributeLiteral::Str("default_retries".into()),
+        },
+        true
+    )]
+    #[case::rename(
+        AttributeArgument::KeyValue {
+            key: AttributePath::from("rename"),
+            value: AttributeLiteral::Str("retry_count".into()),
+        },
+        false
+    )]
+    #[case::deny(
+        AttributeArgument::Word(AttributePath::from("deny_unknown_fields")),
+        false
+    )]
+    fn recognises_default_requests(#[case] argument: AttributeArgument, #[case] expected: bool) {
+        assert_eq!(requests_default([&serde(vec![argument])]), expected);
+    }
+
+    #[rstest]
+    #[case::sentence("Maximum retries. Defaults to 3.", true)]
+    #[case::parenthesised("Log level (default: `info`).", true)]
+    #[case::silent("Maximum retries.", false)]
+    #[case::empty("", false)]
+    fn recognises_stated_defaults(#[case] doc: &str, #[case] expected: bool) {
+        assert_eq!(states_default(doc), expected);
+    }
+
+    #[rstest]
+    #[case::struct_default(true, false, "Maximum retries.", true)]
+    #[case::field_default(false, true, "", true)]
+    #[case::documented(true, true, "Defaults to 3.", false)]
+    #[case::required(false, false, "Maximum retries.", false)]
+    fn reports_undocumented_defaults(
+        #[case] struct_default: bool,
+        #[case] field_default: bool,
+        #[case] doc: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            lacks_documented_default(struct_default, field_default, doc),
+            expected
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Behaviour-driven coverage for the documented-default policy.
+
+use crate::policy::{TypePatterns, lacks_documented_default, requests_default};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::{AttributeArgument, AttributeArguments, AttributePath};
+
+struct ConfigWorld {
+    name: RefCell<String>,
+    struct_serde: RefCell<Vec<AttributeArguments>>,
+    field_serde: RefCell<Vec<AttributeArguments>>,
+    doc: RefCell<String>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> ConfigWorld {
+    ConfigWorld {
+        name: RefCell::new(String::new()),
+        struct_serde: RefCell::new(Vec::new()),
+        field_serde: RefCell::new(Vec::new()),
+        doc: RefCell::new(String::new()),
+        reported: Cell::new(None),
+    }
+}
+
+fn serde_default() -> AttributeArguments {
+    AttributeArguments::List(vec![AttributeArgument::Word(AttributePath::from(
+        "default",
+    ))])
+}
+
+#[given("a struct named {name} with serde default")]
+fn given_defaulted_struct(world: &ConfigWorld, name: String) {
+    *world.name.borrow_mut() = name;
+    world.struct_serde.borrow_mut().push(serde_default());
+}
+
+#[given("a struct named {name} without serde default")]
+fn given_struct(world: &ConfigWorld, name: String) {
+    *world.name.borrow_mut() = name;
+}
+
+#[given("a field documented as {doc}")]
+fn given_field(world: &ConfigWorld, doc: String) {
+    *world.doc.borrow_mut() = doc;
+}
+
+#[given("the field has serde default")]
+fn given_field_default(world: &ConfigWorld) {
+    world.field_serde.borrow_mut().push(serde_default());
+}
+
+#[when("the struct is checked")]
+fn when_checked(world: &ConfigWorld) {
+    let patterns = TypePatterns::new("test", &["*Config".into(), "*Settings".into()]);
+    let reported = patterns.matches(&world.name.borrow())
+        && lacks_documented_default(
+            requests_default(world.struct_serde.borrow().iter()),
+            requests_default(world.field_serde.borrow().iter()),
+            &world.doc.borrow(),
+        );
+    world.reported.set(Some(reported));
+}
+
+#[then("the field is reported")]
+fn then_reported(world: &ConfigWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("the field is accepted")]
+fn then_accepted(world: &ConfigWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/config_struct_fields_must_have_defaults_documented.feature",
+    index = 0
+)]
+fn scenario_struct_default(world: ConfigWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/config_struct_fields_must_have_defaults_documented.feature",
+    index = 1
+)]
+fn scenario_documented(world: ConfigWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/config_struct_fields_must_have_defaults_documented.feature",
+    index = 2
+)]
+fn scenario_field_default(world: ConfigWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/config_struct_fields_must_have_defaults_documented.feature",
+    index = 3
+)]
+fn scenario_outside_patterns(world: ConfigWorld) {
+    let _ = world;
+}
@@ -0,0 +1,28 @@
+Feature: Config struct fields must have defaults documented
+  Fields serde fills with a default are reported on configuration structs
+  unless their documentation states the default.
+
+  Scenario: An undocumented struct-level default is reported
+    Given a struct named ClientConfig with serde default
+    And a field documented as Maximum retries.
+    When the struct is checked
+    Then the field is reported
+
+  Scenario: A documented default is accepted
+    Given a struct named ClientConfig with serde default
+    And a field documented as Maximum retries. Defaults to 3.
+    When the struct is checked
+    Then the field is accepted
+
+  Scenario: A field-level default is reported
+    Given a struct named ClientConfig without serde default
+    And a field documented as Maximum retries.
+    And the field has serde default
+    When the struct is checked
+    Then the field is reported
+
+  Scenario: Structs outside the type patterns are accepted
+    Given a struct named Client with serde default
+    And a field documented as Maximum retries.
+    When the struct is checked
+    Then the field is accepted
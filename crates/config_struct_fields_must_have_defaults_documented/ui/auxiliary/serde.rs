@@ -0,0 +1,15 @@
+// force-host
+// no-prefer-dynamic
+//! Minimal `serde`-style derive macro used by UI fixtures.
+#![crate_type = "proc-macro"]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+/// Accepts `#[serde(...)]` helper attributes and generates nothing, which is
+/// all the lint needs from the real derive.
+#[proc_macro_derive(Deserialize, attributes(serde))]
+pub fn derive_deserialize(_item: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
@@ -0,0 +1,31 @@
+// aux-build: serde.rs
+//! Negative UI fixture: configuration defaults missing from the docs.
+#![crate_type = "lib"]
+#![warn(config_struct_fields_must_have_defaults_documented)]
+
+extern crate serde;
+
+/// Client settings read from `client.toml`.
+#[derive(serde::Deserialize)]
+#[serde(default)]
+pub struct ClientConfig {
+    /// Maximum number of retries.
+    pub retries: u32,
+    /// Request timeout in seconds. Defaults to 30.
+    pub timeout: u64,
+}
+
+/// Server settings.
+#[derive(serde::Deserialize)]
+pub struct ServerSettings {
+    /// Address to bind.
+    pub address: String,
+    /// Worker threads.
+    #[serde(default = "default_workers")]
+    pub workers: usize,
+}
+
+/// Returns the worker count used when none is configured.
+pub fn default_workers() -> usize {
+    4
+}
@@ -0,0 +1,40 @@
+// aux-build: serde.rs
+//! Positive UI fixture: configuration defaults stated in the docs, required
+//! fields, and structs outside the type patterns.
+#![crate_type = "lib"]
+#![warn(config_struct_fields_must_have_defaults_documented)]
+
+extern crate serde;
+
+/// Client settings read from `client.toml`.
+#[derive(serde::Deserialize)]
+#[serde(default)]
+pub struct ClientConfig {
+    /// Maximum number of retries. Defaults to 3.
+    pub retries: u32,
+    /// Log level (default: `info`).
+    pub log_level: String,
+}
+
+/// Server settings.
+#[derive(serde::Deserialize)]
+pub struct ServerSettings {
+    /// Address to bind.
+    pub address: String,
+    /// Worker threads; the default is one per core.
+    #[serde(default = "default_workers")]
+    pub workers: usize,
+}
+
+/// Returns the worker count used when none is configured.
+pub fn default_workers() -> usize {
+    4
+}
+
+/// Not configuration, so its defaults are not checked.
+#[derive(serde::Deserialize)]
+#[serde(default)]
+pub struct Client {
+    /// Maximum number of retries.
+    pub retries: u32,
+}
@@ -0,0 +1,142 @@
+//! Pure decision logic for `constructor_max_arguments`.
+//!
+//! The driver describes each associated function of an inherent impl by
+//! name, argument count, and whether it takes `self`;
+//! [`ConstructorMaxArgumentsDecision`] reports constructors, meaning `new`
+//! and `from_*` functions without a receiver, that take more arguments than
+//! the limit.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_ARGUMENTS, LINT_NAME};
+
+const TOO_MANY_ARGUMENTS: &str = "too_many_arguments";
+
+/// The facts about one associated function that the decision needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstructorProjection {
+    /// The function's name.
+    pub name: String,
+    /// Number of arguments, excluding any receiver.
+    pub arguments: usize,
+    /// Whether the function takes `self` in any form.
+    pub has_receiver: bool,
+}
+
+/// Reports constructors taking more than `max_arguments` arguments.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = ConstructorMaxArgumentsDecision::new(5);
+/// let constructor = ConstructorProjection {
+///     name: "new".to_owned(),
+///     arguments: 6,
+///     has_receiver: false,
+/// };
+/// assert!(decision.decide(&constructor).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConstructorMaxArgumentsDecision {
+    max_arguments: usize,
+}
+
+impl ConstructorMaxArgumentsDecision {
+    /// Creates a decision with the given argument limit.
+    #[must_use]
+    pub const fn new(max_arguments: usize) -> Self {
+        Self { max_arguments }
+    }
+
+    /// Returns the argument limit.
+    #[must_use]
+    pub const fn max_arguments(&self) -> usize {
+        self.max_arguments
+    }
+}
+
+impl Default for ConstructorMaxArgumentsDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ARGUMENTS)
+    }
+}
+
+impl LintDecision for ConstructorMaxArgumentsDecision {
+    type Projection = ConstructorProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, function: &ConstructorProjection) -> Option<Finding> {
+        let flagged = !function.has_receiver
+            && is_constructor_name(&function.name)
+            && function.arguments > self.max_arguments;
+        flagged.then(|| {
+            Finding::new(LINT_NAME, TOO_MANY_ARGUMENTS).with_subject(function.name.as_str())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ConstructorProjection>> {
+        let over = self.max_arguments + 1;
+        let function = |name: &str, arguments, has_receiver| ConstructorProjection {
+            name: name.to_owned(),
+            arguments,
+            has_receiver,
+        };
+        vec![
+            DecisionFixture::clean(
+                "`new` at the limit",
+                function("new", self.max_arguments, false),
+            ),
+            DecisionFixture::flagged(
+                "`new` over the limit",
+                function("new", over, false),
+                TOO_MANY_ARGUMENTS,
+            ),
+            DecisionFixture::flagged(
+                "`from_*` over the limit",
+                function("from_parts", over, false),
+                TOO_MANY_ARGUMENTS,
+            ),
+            DecisionFixture::clean(
+                "other function over the limit",
+                function("build", over, false),
+            ),
+            DecisionFixture::clean("method over the limit", function("new", over, true)),
+        ]
+    }
+}
+
+/// Returns `true` for `new` and for `from_` followed by a name.
+pub(crate) fn is_constructor_name(name: &str) -> bool {
+    name == "new"
+        || name
+            .strip_prefix("from_")
+            .is_some_and(|rest| !rest.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::new("new", true)]
+    #[case::from_parts("from_parts", true)]
+    #[case::bare_from("from", false)]
+    #[case::bare_prefix("from_", false)]
+    #[case::new_prefix("new_with_capacity", false)]
+    #[case::other("build", false)]
+    fn constructor_names_are_new_or_from(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(is_constructor_name(name), expected);
+    }
+
+    #[rstest]
+    #[case::default_limit(ConstructorMaxArgumentsDecision::default())]
+    #[case::zero_limit(ConstructorMaxArgumentsDecision::new(0))]
+    fn decision_conforms(#[case] decision: ConstructorMaxArgumentsDecision) {
+        assert_eq!(check_conformance(&decision), Ok(5));
+    }
+}
@@ -0,0 +1,34 @@
+//! Embedded documentation for the `constructor_max_arguments` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `constructor_max_arguments`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "constructor_max_arguments",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `new` and `from_*` constructors taking more arguments than the limit and suggests a builder.",
+    rationale: "Every caller of a long constructor must supply each value in order, and arguments of the same type are easily swapped without a compiler error. A builder or configuration struct names each value and lets optional ones take defaults.",
+    bad_example: concat!(
+        "impl Server {\n",
+        "    pub fn new(host: String, port: u16, workers: usize, timeout: Duration, tls: bool, log: bool) -> Self {\n",
+        "        /* ... */\n",
+        "    }\n",
+        "}",
+    ),
+    good_example: concat!(
+        "let server = Server::builder()\n",
+        "    .host(host)\n",
+        "    .port(8080)\n",
+        "    .workers(4)\n",
+        "    .build();",
+    ),
+    config: &[ConfigKeyDocs {
+        key: "max_arguments",
+        default: "5",
+        description: "Maximum number of arguments a constructor may take.",
+    }],
+};
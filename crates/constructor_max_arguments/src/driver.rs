@@ -0,0 +1,266 @@
+//! Detect constructors whose argument lists call for a builder.
+//!
+//! The lint inspects the associated functions of inherent impls. A function
+//! named `new` or `from_*` that takes no receiver is a constructor; when it
+//! takes more than `max_arguments` arguments, the lint reports its name and
+//! suggests a builder or a configuration struct for the impl's type. Trait
+//! impls are skipped because their signatures are fixed by the trait, and
+//! macro-generated constructors are ignored.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker::testing::decision::LintDecision;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::decision::{ConstructorMaxArgumentsDecision, ConstructorProjection};
+
+pub(crate) const LINT_NAME: &str = "constructor_max_arguments";
+pub(crate) const DEFAULT_MAX_ARGUMENTS: usize = 5;
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_arguments: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_arguments: DEFAULT_MAX_ARGUMENTS,
+        }
+    }
+}
+
+/// Lint pass suggesting builders for constructors with many arguments.
+#[derive(Default)]
+pub struct ConstructorMaxArguments {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Argument limit and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub CONSTRUCTOR_MAX_ARGUMENTS,
+    Warn,
+    "constructors taking many arguments should use a builder or a configuration struct",
+    ConstructorMaxArguments::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for ConstructorMaxArguments {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(CONSTRUCTOR_MAX_ARGUMENTS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        let hir::ImplItemKind::Fn(sig, _) = item.kind else {
+            return;
+        };
+        if !matches!(item.impl_kind, hir::ImplItemImplKind::Inherent { .. })
+            || item.span.from_expansion()
+        {
+            return;
+        }
+
+        let has_receiver = cx.tcx.associated_item(item.owner_id).is_method();
+        let projection = ConstructorProjection {
+            name: item.ident.name.to_string(),
+            arguments: sig
+                .decl
+                .inputs
+                .len()
+                .saturating_sub(usize::from(has_receiver)),
+            has_receiver,
+        };
+        let config = self
+            .state
+            .findings
+            .config_at(cx, item.span, &self.state.config);
+        let decision = ConstructorMaxArgumentsDecision::new(config.max_arguments);
+        if decision.decide(&projection).is_none() {
+            return;
+        }
+        debug!(
+            target: LINT_NAME,
+            "`{}` takes {} arguments (limit {})",
+            projection.name,
+            projection.arguments,
+            decision.max_arguments(),
+        );
+
+        let constructor = Constructor {
+            type_name: self_type_name(cx, item),
+            name: projection.name,
+            arguments: projection.arguments,
+            limit: decision.max_arguments(),
+        };
+        if self
+            .state
+            .findings
+            .admit(cx, item.hir_id(), item.ident.span)
+        {
+            emit_diagnostic(cx, item.ident.span, &constructor, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the impl's self type as written, or `Self` when it cannot be read.
+fn self_type_name(cx: &LateContext<'_>, item: &hir::ImplItem<'_>) -> String {
+    let parent = cx.tcx.local_parent(item.owner_id.def_id);
+    let hir::ItemKind::Impl(impl_block) = cx.tcx.hir_expect_item(parent).kind else {
+        return String::from("Self");
+    };
+    cx.sess()
+        .source_map()
+        .span_to_snippet(impl_block.self_ty.span)
+        .unwrap_or_else(|_| String::from("Self"))
+}
+
+/// A constructor taking more arguments than the limit.
+#[derive(Clone, Debug)]
+struct Constructor {
+    type_name: String,
+    name: String,
+    arguments: usize,
+    limit: usize,
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: rustc_span::Span,
+    constructor: &Constructor,
+    localizer: &Localizer,
+) {
+    let mut args: Arguments<'static> = Arguments::default();
+    let function = format!("{}::{}", constructor.type_name, constructor.name);
+    args.insert(Cow::Borrowed("constructor"), FluentValue::from(function));
+    args.insert(
+        Cow::Borrowed("type"),
+        FluentValue::from(constructor.type_name.clone()),
+    );
+    args.insert(
+        Cow::Borrowed("arguments"),
+        FluentValue::from(constructor.arguments as i64),
+    );
+    args.insert(
+        Cow::Borrowed("limit"),
+        FluentValue::from(constructor.limit as i64),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(constructor)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        CONSTRUCTOR_MAX_ARGUMENTS,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(constructor: &Constructor) -> DiagnosticMessageSet {
+    let Constructor {
+        type_name,
+        name,
+        arguments,
+        limit,
+    } = constructor;
+    DiagnosticMessageSet::new(
+        format!(
+            "`{type_name}::{name}` takes {arguments} arguments, exceeding the limit of {limit}."
+        ),
+        String::from(
+            "Callers must supply every argument in order, and arguments of the same type are \
+             easily swapped.",
+        ),
+        format!(
+            "Add a builder for `{type_name}`, or group the arguments into a configuration struct."
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_allows_five_arguments() {
+        assert_eq!(Config::default().max_arguments, 5);
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_constructor_and_remedy() {
+        let messages = fallback_messages(&Constructor {
+            type_name: String::from("Server"),
+            name: String::from("new"),
+            arguments: 7,
+            limit: 5,
+        });
+
+        assert!(
+            messages
+                .primary()
+                .contains("`Server::new` takes 7 arguments")
+        );
+        assert!(messages.primary().contains("limit of 5"));
+        assert!(messages.help().contains("builder for `Server`"));
+        assert!(messages.help().contains("configuration struct"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,39 @@
+//! Lint crate suggesting builders for constructors that take many arguments.
+//!
+//! A constructor with a long argument list forces every caller to supply
+//! each value in order, and arguments of the same type are easily swapped.
+//! The lint reports `new` and `from_*` associated functions in inherent
+//! impls that take more than `max_arguments` arguments and suggests a
+//! builder or a configuration struct. Clippy's `too_many_arguments` covers
+//! all functions with a generic remedy; this lint targets constructors,
+//! where the builder pattern is the idiomatic fix. It is experimental and
+//! ships behind the `experimental-constructor-max-arguments` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ConstructorMaxArgumentsDecision, ConstructorProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn constructor_max_arguments_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
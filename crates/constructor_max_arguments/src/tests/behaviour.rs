@@ -0,0 +1,104 @@
+//! Behaviour-driven coverage for constructor argument limits.
+
+use super::{Config, Constructor, fallback_messages};
+use crate::decision::{ConstructorMaxArgumentsDecision, ConstructorProjection};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use whitaker::testing::decision::LintDecision;
+use whitaker_common::i18n::DiagnosticMessageSet;
+
+struct ConstructorWorld {
+    function: RefCell<ConstructorProjection>,
+    config: RefCell<Config>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> ConstructorWorld {
+    ConstructorWorld {
+        function: RefCell::new(ConstructorProjection {
+            name: String::from("new"),
+            arguments: 0,
+            has_receiver: false,
+        }),
+        config: RefCell::new(Config::default()),
+        messages: RefCell::new(None),
+    }
+}
+
+#[given("a constructor named {name} taking {arguments} arguments")]
+fn given_constructor(world: &ConstructorWorld, name: String, arguments: usize) {
+    let mut function = world.function.borrow_mut();
+    function.name = name;
+    function.arguments = arguments;
+}
+
+#[given("the limit is {limit}")]
+fn given_limit(world: &ConstructorWorld, limit: usize) {
+    world.config.borrow_mut().max_arguments = limit;
+}
+
+#[when("the function is checked")]
+fn when_checked(world: &ConstructorWorld) {
+    let limit = world.config.borrow().max_arguments;
+    let function = world.function.borrow();
+    let decision = ConstructorMaxArgumentsDecision::new(limit);
+    let messages = decision.decide(&function).map(|_| {
+        fallback_messages(&Constructor {
+            type_name: String::from("Server"),
+            name: function.name.clone(),
+            arguments: function.arguments,
+            limit,
+        })
+    });
+    *world.messages.borrow_mut() = messages;
+}
+
+#[then("the function is accepted")]
+fn then_accepted(world: &ConstructorWorld) {
+    assert!(world.messages.borrow().is_none());
+}
+
+#[then("the function is reported with {arguments} arguments")]
+fn then_reported(world: &ConstructorWorld, arguments: usize) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert!(
+        messages
+            .primary()
+            .contains(&format!("takes {arguments} arguments"))
+    );
+}
+
+#[then("the help suggests a builder")]
+fn then_suggests_builder(world: &ConstructorWorld) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert!(messages.help().contains("builder for `Server`"));
+}
+
+#[scenario(path = "tests/features/constructor_max_arguments.feature", index = 0)]
+fn scenario_at_limit(world: ConstructorWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/constructor_max_arguments.feature", index = 1)]
+fn scenario_beyond_limit(world: ConstructorWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/constructor_max_arguments.feature", index = 2)]
+fn scenario_from_constructor(world: ConstructorWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/constructor_max_arguments.feature", index = 3)]
+fn scenario_other_function(world: ConstructorWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/constructor_max_arguments.feature", index = 4)]
+fn scenario_configured_limit(world: ConstructorWorld) {
+    let _ = world;
+}
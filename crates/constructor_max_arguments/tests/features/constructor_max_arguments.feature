@@ -0,0 +1,31 @@
+Feature: Constructor argument limit
+  Constructors named `new` or `from_*` that take more arguments than the
+  configured limit are reported with a suggestion to introduce a builder or
+  a configuration struct.
+
+  Scenario: A constructor at the default limit is accepted
+    Given a constructor named new taking 5 arguments
+    When the function is checked
+    Then the function is accepted
+
+  Scenario: A constructor beyond the default limit is reported
+    Given a constructor named new taking 6 arguments
+    When the function is checked
+    Then the function is reported with 6 arguments
+    And the help suggests a builder
+
+  Scenario: A from constructor beyond the limit is reported
+    Given a constructor named from_parts taking 7 arguments
+    When the function is checked
+    Then the function is reported with 7 arguments
+
+  Scenario: Other associated functions are not constructors
+    Given a constructor named assemble taking 9 arguments
+    When the function is checked
+    Then the function is accepted
+
+  Scenario: A configured limit lowers the threshold
+    Given a constructor named new taking 3 arguments
+    And the limit is 2
+    When the function is checked
+    Then the function is reported with 3 arguments
@@ -0,0 +1,16 @@
+pub struct Point<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+fn main() {
+    let point = Point::new(1, 2, 3);
+    println!("{} {} {}", point.x, point.y, point.z);
+}
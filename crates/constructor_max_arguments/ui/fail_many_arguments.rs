@@ -0,0 +1,51 @@
+#![warn(constructor_max_arguments)]
+
+use std::time::Duration;
+
+pub struct Server {
+    host: String,
+    port: u16,
+    workers: usize,
+    timeout: Duration,
+    tls: bool,
+    verbose: bool,
+}
+
+impl Server {
+    pub fn new(
+        host: String,
+        port: u16,
+        workers: usize,
+        timeout: Duration,
+        tls: bool,
+        verbose: bool,
+    ) -> Self {
+        Self { host, port, workers, timeout, tls, verbose }
+    }
+
+    pub fn from_parts(
+        host: &str,
+        port: u16,
+        workers: usize,
+        timeout_secs: u64,
+        tls: bool,
+        verbose: bool,
+    ) -> Self {
+        Self::new(
+            host.to_owned(),
+            port,
+            workers,
+            Duration::from_secs(timeout_secs),
+            tls,
+            verbose,
+        )
+    }
+}
+
+fn main() {
+    let server = Server::from_parts("localhost", 8080, 4, 30, false, true);
+    println!(
+        "{}:{} {} {:?} {} {}",
+        server.host, server.port, server.workers, server.timeout, server.tls, server.verbose
+    );
+}
@@ -0,0 +1,55 @@
+#![warn(constructor_max_arguments)]
+
+pub struct Rect {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+pub trait FromCorners {
+    fn from_corners(left: i32, top: i32, right: i32, bottom: i32, scale: u32, snap: bool) -> Self;
+}
+
+impl Rect {
+    pub fn new(x: i32, y: i32, width: u32, height: u32, _scale: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn assemble(x: i32, y: i32, width: u32, height: u32, _scale: u32, _snap: bool) -> Self {
+        Self::new(x, y, width, height, 1)
+    }
+
+    pub fn from_(x: i32, y: i32, width: u32, height: u32, _scale: u32, _snap: bool) -> Self {
+        Self::new(x, y, width, height, 1)
+    }
+
+    pub fn from_offsets(&self, dx: i32, dy: i32, dw: u32, dh: u32, _scale: u32) -> Self {
+        Self::new(self.x + dx, self.y + dy, self.width + dw, self.height + dh, 1)
+    }
+}
+
+impl FromCorners for Rect {
+    fn from_corners(left: i32, top: i32, right: i32, bottom: i32, _scale: u32, _snap: bool) -> Self {
+        Self::new(left, top, (right - left) as u32, (bottom - top) as u32, 1)
+    }
+}
+
+#[allow(constructor_max_arguments)]
+impl Rect {
+    pub fn from_everything(x: i32, y: i32, width: u32, height: u32, _scale: u32, _snap: bool) -> Self {
+        Self::new(x, y, width, height, 1)
+    }
+}
+
+fn main() {
+    let rect = Rect::assemble(0, 0, 2, 3, 1, false);
+    let moved = rect.from_offsets(1, 1, 0, 0, 1);
+    let cornered = Rect::from_corners(0, 0, 4, 4, 1, true);
+    let everything = Rect::from_everything(0, 0, 1, 1, 1, false);
+    let bare = Rect::from_(0, 0, 1, 1, 1, false);
+    println!(
+        "{} {} {} {} {}",
+        moved.x, cornered.width, everything.height, bare.y, rect.height
+    );
+}
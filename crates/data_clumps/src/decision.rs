@@ -0,0 +1,112 @@
+//! Pure decision logic for `data_clumps`.
+//!
+//! The driver summarises the named parameters of every eligible function in
+//! the crate as a [`FunctionSignature`]. [`DataClumpsDecision`] hands the
+//! same signatures to [`find_data_clumps`] and reports the first clump,
+//! naming the function that first declares it as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::data_clumps::{
+    DataClumpThresholds, FunctionSignature, Parameter, find_data_clumps,
+};
+
+use crate::driver::LINT_NAME;
+
+/// Reason key for parameter groups shared by several signatures.
+const DATA_CLUMP: &str = "data_clump";
+
+/// The facts about one crate that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CrateSignaturesProjection {
+    /// The signatures of the crate's eligible functions, in source order.
+    pub signatures: Vec<FunctionSignature>,
+}
+
+/// Reports groups of parameters shared by several function signatures.
+///
+/// # Examples
+///
+/// ```ignore
+/// let point = || ["x", "y", "z"].map(|name| Parameter::new(name, "f64"));
+/// let signatures = ["translate", "scale", "rotate"]
+///     .map(|name| FunctionSignature::new(name, point()))
+///     .to_vec();
+/// let krate = CrateSignaturesProjection { signatures };
+/// assert!(DataClumpsDecision::default().decide(&krate).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DataClumpsDecision {
+    thresholds: DataClumpThresholds,
+}
+
+impl DataClumpsDecision {
+    /// Creates a decision reporting groups of at least `min_parameters`
+    /// parameters shared by at least `min_occurrences` signatures.
+    #[must_use]
+    pub fn new(min_parameters: usize, min_occurrences: usize) -> Self {
+        Self {
+            thresholds: DataClumpThresholds::new(min_parameters, min_occurrences),
+        }
+    }
+}
+
+impl LintDecision for DataClumpsDecision {
+    type Projection = CrateSignaturesProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, krate: &CrateSignaturesProjection) -> Option<Finding> {
+        let clump = find_data_clumps(&krate.signatures, self.thresholds)
+            .into_iter()
+            .next()?;
+        let first = krate.signatures.get(clump.first_occurrence())?;
+        Some(Finding::new(LINT_NAME, DATA_CLUMP).with_subject(first.name()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<CrateSignaturesProjection>> {
+        let min_parameters = self.thresholds.min_parameters();
+        let min_occurrences = self.thresholds.min_occurrences();
+        let krate = |parameters: usize, functions: usize| CrateSignaturesProjection {
+            signatures: (0..functions)
+                .map(|function| {
+                    FunctionSignature::new(
+                        format!("handler_{function}"),
+                        (0..parameters).map(|index| Parameter::new(format!("arg_{index}"), "u32")),
+                    )
+                })
+                .collect(),
+        };
+        vec![
+            DecisionFixture::flagged(
+                "shared by enough signatures",
+                krate(min_parameters, min_occurrences),
+                DATA_CLUMP,
+            ),
+            DecisionFixture::clean(
+                "too few signatures",
+                krate(min_parameters, min_occurrences - 1),
+            ),
+            DecisionFixture::clean(
+                "too few parameters",
+                krate(min_parameters - 1, min_occurrences),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::defaults(DataClumpsDecision::default())]
+    #[case::pairs(DataClumpsDecision::new(2, 2))]
+    #[case::large(DataClumpsDecision::new(5, 4))]
+    fn decision_conforms(#[case] decision: DataClumpsDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
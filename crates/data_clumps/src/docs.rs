@@ -0,0 +1,42 @@
+//! Embedded documentation for the `data_clumps` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `data_clumps`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "data_clumps",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags groups of parameters that appear together in several function signatures.",
+    rationale: "Values that always travel together form a concept the code has not named yet; a struct gives the group one place for validation and documentation and shortens every signature that passes it.",
+    bad_example: concat!(
+        "fn connect(host: &str, port: u16, timeout: u64) {}\n",
+        "fn reconnect(host: &str, port: u16, timeout: u64) {}\n",
+        "fn probe(host: &str, port: u16, timeout: u64) {}",
+    ),
+    good_example: concat!(
+        "struct Endpoint<'a> {\n",
+        "    host: &'a str,\n",
+        "    port: u16,\n",
+        "    timeout: u64,\n",
+        "}\n",
+        "\n",
+        "fn connect(endpoint: &Endpoint<'_>) {}\n",
+        "fn reconnect(endpoint: &Endpoint<'_>) {}\n",
+        "fn probe(endpoint: &Endpoint<'_>) {}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "min_parameters",
+            default: "3",
+            description: "Minimum number of shared parameters that form a clump.",
+        },
+        ConfigKeyDocs {
+            key: "min_occurrences",
+            default: "3",
+            description: "Minimum number of signatures that must share a group before it is reported.",
+        },
+    ],
+};
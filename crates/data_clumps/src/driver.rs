@@ -0,0 +1,280 @@
+//! Detect groups of parameters repeated across function signatures.
+//!
+//! The lint runs once per crate. During `check_crate` it collects the named
+//! parameters of every free function, inherent method, and trait method
+//! declaration, then asks [`whitaker_common::data_clumps`] for groups of at
+//! least `min_parameters` parameters, matched by name and type, that appear
+//! together in at least `min_occurrences` signatures. Each group is reported
+//! once, at the first function that declares it, with a note pointing at the
+//! others.
+//!
+//! `self` receivers, unnamed parameters, trait implementations (whose
+//! signatures the trait dictates), macro-generated functions, and test code
+//! are not collected.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def_id::LocalDefId;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::{Ident, Span, kw};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker::hir::TestContext;
+use whitaker_common::CrateScopedState;
+use whitaker_common::data_clumps::{
+    DataClump, DataClumpThresholds, FunctionSignature, Parameter, find_data_clumps,
+};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+pub(crate) const LINT_NAME: &str = "data_clumps";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    min_parameters: usize,
+    min_occurrences: usize,
+}
+
+impl Config {
+    fn thresholds(&self) -> DataClumpThresholds {
+        DataClumpThresholds::new(self.min_parameters, self.min_occurrences)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_parameters: 3,
+            min_occurrences: 3,
+        }
+    }
+}
+
+/// Lint pass reporting parameter groups shared by several signatures.
+#[derive(Default)]
+pub struct DataClumps {
+    localizer: CrateScopedState<Localizer>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub DATA_CLUMPS,
+    Warn,
+    "groups of parameters that travel together should become a struct",
+    DataClumps::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for DataClumps {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let thresholds = load_configuration().thresholds();
+        let shared_config = SharedConfig::load();
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        let findings = FindingLimit::new(DATA_CLUMPS, &shared_config);
+
+        let (sites, signatures): (Vec<Site>, Vec<FunctionSignature>) =
+            collect_signatures(cx).into_iter().unzip();
+        for clump in find_data_clumps(&signatures, thresholds) {
+            debug!(
+                target: LINT_NAME,
+                "{} parameters shared by {} functions",
+                clump.parameters().len(),
+                clump.occurrences().len(),
+            );
+            let site = sites[clump.first_occurrence()];
+            if findings.admit(cx, site.hir_id, site.ident.span) {
+                emit_diagnostic(cx, &clump, &sites, &self.localizer);
+            }
+        }
+        findings.summarise(cx, &self.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Where a collected signature was declared.
+#[derive(Clone, Copy, Debug)]
+struct Site {
+    hir_id: hir::HirId,
+    ident: Ident,
+}
+
+/// Collects a signature summary for every eligible function in the crate.
+fn collect_signatures(cx: &LateContext<'_>) -> Vec<(Site, FunctionSignature)> {
+    let test_context = TestContext::for_crate(cx, Vec::new());
+    let items = cx.tcx.hir_crate_items(());
+
+    let free = items.free_items().filter_map(|id| {
+        let item = cx.tcx.hir_item(id);
+        match item.kind {
+            hir::ItemKind::Fn { ident, .. } => Some((item.owner_id.def_id, ident, item.span)),
+            _ => None,
+        }
+    });
+    let inherent = items.impl_items().filter_map(|id| {
+        let item = cx.tcx.hir_impl_item(id);
+        let is_inherent = matches!(item.impl_kind, hir::ImplItemImplKind::Inherent { .. });
+        (is_inherent && matches!(item.kind, hir::ImplItemKind::Fn(..))).then_some((
+            item.owner_id.def_id,
+            item.ident,
+            item.span,
+        ))
+    });
+    let declared = items.trait_items().filter_map(|id| {
+        let item = cx.tcx.hir_trait_item(id);
+        matches!(item.kind, hir::TraitItemKind::Fn(..)).then_some((
+            item.owner_id.def_id,
+            item.ident,
+            item.span,
+        ))
+    });
+
+    free.chain(inherent)
+        .chain(declared)
+        .filter(|(_, _, span)| !span.from_expansion())
+        .filter_map(|(def_id, ident, _)| {
+            let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+            (!test_context.is_test(cx, hir_id))
+                .then(|| (Site { hir_id, ident }, signature_of(cx, def_id, ident)))
+        })
+        .collect()
+}
+
+/// Summarises the named, non-`self` parameters of a function.
+fn signature_of(cx: &LateContext<'_>, def_id: LocalDefId, ident: Ident) -> FunctionSignature {
+    let sig = cx.tcx.instantiate_bound_regions_with_erased(
+        cx.tcx
+            .fn_sig(def_id)
+            .instantiate_identity()
+            .skip_normalization(),
+    );
+    let names = cx.tcx.fn_arg_idents(def_id.to_def_id());
+    let parameters = names.iter().zip(sig.inputs()).filter_map(|(name, ty)| {
+        let name = (*name)?;
+        (!matches!(name.name, kw::SelfLower | kw::Underscore))
+            .then(|| Parameter::new(name.as_str(), ty.to_string()))
+    });
+    FunctionSignature::new(ident.as_str(), parameters)
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, clump: &DataClump, sites: &[Site], localizer: &Localizer) {
+    let site = sites[clump.first_occurrence()];
+    let others: Vec<Site> = clump
+        .occurrences()
+        .iter()
+        .skip(1)
+        .map(|&index| sites[index])
+        .collect();
+    let parameters = format_names(clump.parameters().iter().map(Parameter::name));
+    let functions = format_names(others.iter().map(|other| other.ident.as_str()));
+    let count = clump.occurrences().len();
+
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("parameters"),
+        FluentValue::from(parameters.clone()),
+    );
+    args.insert(
+        Cow::Borrowed("functions"),
+        FluentValue::from(functions.clone()),
+    );
+    args.insert(Cow::Borrowed("count"), FluentValue::from(count));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(&parameters, &functions, count)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let other_spans: Vec<Span> = others.iter().map(|other| other.ident.span).collect();
+
+    cx.tcx.emit_node_span_lint(
+        DATA_CLUMPS,
+        site.hir_id,
+        site.ident.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(other_spans, note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// Formats names as a comma-separated list of code spans.
+fn format_names<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    names
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn fallback_messages(parameters: &str, functions: &str, count: usize) -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        format!("Parameters {parameters} appear together in {count} function signatures."),
+        format!("The same parameters are also taken by {functions}."),
+        format!("Group {parameters} into a struct and pass that instead."),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_matches_common_defaults() {
+        assert_eq!(
+            Config::default().thresholds(),
+            DataClumpThresholds::default()
+        );
+    }
+
+    #[rstest]
+    #[case::empty(&[], "")]
+    #[case::single(&["host"], "`host`")]
+    #[case::several(&["host", "port", "timeout"], "`host`, `port`, `timeout`")]
+    fn format_names_wraps_each_name(#[case] names: &[&str], #[case] expected: &str) {
+        assert_eq!(format_names(names.iter().copied()), expected);
+    }
+
+    #[rstest]
+    fn fallback_messages_name_parameters_and_functions() {
+        let messages = fallback_messages("`x`, `y`, `z`", "`scale`, `rotate`", 3);
+
+        assert!(messages.primary().contains("appear together in 3 function"));
+        assert!(messages.note().contains("`scale`, `rotate`"));
+        assert!(
+            messages
+                .help()
+                .contains("Group `x`, `y`, `z` into a struct")
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
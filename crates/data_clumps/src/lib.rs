@@ -0,0 +1,37 @@
+//! Lint crate flagging groups of parameters repeated across signatures.
+//!
+//! A data clump is a set of values that always travel together, such as
+//! `host`, `port`, and `timeout`. When the same names and types recur in
+//! several function signatures they usually want to become a struct. The lint
+//! collects every signature in the crate and reports each repeated group once.
+//! It is experimental and ships behind the `experimental-data-clumps` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{CrateSignaturesProjection, DataClumpsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn data_clumps_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,94 @@
+//! Behaviour-driven coverage for the data clump configuration.
+
+use super::Config;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::data_clumps::{FunctionSignature, Parameter, find_data_clumps};
+
+struct ClumpWorld {
+    signatures: RefCell<Vec<FunctionSignature>>,
+    config: RefCell<Config>,
+    reported: Cell<Option<usize>>,
+}
+
+#[fixture]
+fn world() -> ClumpWorld {
+    ClumpWorld {
+        signatures: RefCell::new(Vec::new()),
+        config: RefCell::new(Config::default()),
+        reported: Cell::new(None),
+    }
+}
+
+fn connection(name: &str) -> FunctionSignature {
+    FunctionSignature::new(
+        name,
+        [
+            Parameter::new("host", "&str"),
+            Parameter::new("port", "u16"),
+            Parameter::new("timeout", "u64"),
+        ],
+    )
+}
+
+#[given("functions connect, reconnect, and probe taking host, port, and timeout")]
+fn given_three_functions(world: &ClumpWorld) {
+    world
+        .signatures
+        .replace(["connect", "reconnect", "probe"].map(connection).to_vec());
+}
+
+#[given("functions connect and reconnect taking host, port, and timeout")]
+fn given_two_functions(world: &ClumpWorld) {
+    world
+        .signatures
+        .replace(["connect", "reconnect"].map(connection).to_vec());
+}
+
+#[given("min_occurrences is {value}")]
+fn given_min_occurrences(world: &ClumpWorld, value: usize) {
+    world.config.borrow_mut().min_occurrences = value;
+}
+
+#[given("min_parameters is {value}")]
+fn given_min_parameters(world: &ClumpWorld, value: usize) {
+    world.config.borrow_mut().min_parameters = value;
+}
+
+#[when("data clumps are detected")]
+fn when_detected(world: &ClumpWorld) {
+    let thresholds = world.config.borrow().thresholds();
+    let clumps = find_data_clumps(&world.signatures.borrow(), thresholds);
+    world.reported.set(Some(clumps.len()));
+}
+
+#[then("{count} clump is reported")]
+fn then_clump_reported(world: &ClumpWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[then("{count} clumps are reported")]
+fn then_clumps_reported(world: &ClumpWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 0)]
+fn scenario_default_reports_three(world: ClumpWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 1)]
+fn scenario_default_ignores_two(world: ClumpWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 2)]
+fn scenario_lower_occurrences(world: ClumpWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/data_clumps.feature", index = 3)]
+fn scenario_higher_parameters(world: ClumpWorld) {
+    let _ = world;
+}
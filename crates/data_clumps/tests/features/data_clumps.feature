@@ -0,0 +1,25 @@
+Feature: Data clump configuration
+  The lint's configuration controls how large a parameter group must be and
+  how many signatures must share it before it is reported.
+
+  Scenario: Default settings report a group shared by three functions
+    Given functions connect, reconnect, and probe taking host, port, and timeout
+    When data clumps are detected
+    Then 1 clump is reported
+
+  Scenario: Default settings ignore a group shared by two functions
+    Given functions connect and reconnect taking host, port, and timeout
+    When data clumps are detected
+    Then 0 clumps are reported
+
+  Scenario: Lowering the occurrence threshold reports two functions
+    Given functions connect and reconnect taking host, port, and timeout
+    And min_occurrences is 2
+    When data clumps are detected
+    Then 1 clump is reported
+
+  Scenario: Raising the parameter threshold ignores small groups
+    Given functions connect, reconnect, and probe taking host, port, and timeout
+    And min_parameters is 4
+    When data clumps are detected
+    Then 0 clumps are reported
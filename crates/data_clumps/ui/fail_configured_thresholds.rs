@@ -0,0 +1,13 @@
+//! Lowered thresholds report a pair of parameters shared by two functions.
+
+fn area(width: u32, height: u32) -> u32 {
+    width * height
+}
+
+fn perimeter(width: u32, height: u32) -> u32 {
+    2 * (width + height)
+}
+
+fn main() {
+    let _ = area(2, 3) + perimeter(2, 3);
+}
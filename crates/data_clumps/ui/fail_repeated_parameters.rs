@@ -0,0 +1,30 @@
+//! Free functions, inherent methods, and trait declarations sharing a group
+//! of parameters are reported once, at the first signature. Trait
+//! implementations repeat the declaration and are not counted.
+
+fn connect(host: &str, port: u16, timeout: u64) -> bool {
+    !host.is_empty() && port > 0 && timeout > 0
+}
+
+struct Pool;
+
+impl Pool {
+    fn reconnect(&self, port: u16, host: &str, timeout: u64, retries: u8) -> bool {
+        retries > 0 && connect(host, port, timeout)
+    }
+}
+
+trait Probe {
+    fn probe(&self, host: &str, port: u16, timeout: u64) -> bool;
+}
+
+impl Probe for Pool {
+    fn probe(&self, host: &str, port: u16, timeout: u64) -> bool {
+        connect(host, port, timeout)
+    }
+}
+
+fn main() {
+    let _ = connect("localhost", 80, 5);
+    let _ = Pool.reconnect(80, "localhost", 5, 3) || Pool.probe("localhost", 80, 5);
+}
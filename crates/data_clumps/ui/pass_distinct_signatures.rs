@@ -0,0 +1,53 @@
+//! Groups shared by too few signatures, with differing types, or only through
+//! trait implementations are not reported.
+
+fn connect(host: &str, port: u16, timeout: u64) -> bool {
+    !host.is_empty() && port > 0 && timeout > 0
+}
+
+fn reconnect(host: &str, port: u16, timeout: u64) -> bool {
+    connect(host, port, timeout)
+}
+
+fn probe(host: String, port: u32, timeout: u64) -> bool {
+    !host.is_empty() && port > 0 && timeout > 0
+}
+
+trait Resize {
+    fn resize(&mut self, width: u32, height: u32, depth: u32);
+}
+
+struct Image;
+struct Volume;
+
+impl Resize for Image {
+    fn resize(&mut self, width: u32, height: u32, depth: u32) {
+        let _ = (width, height, depth);
+    }
+}
+
+impl Resize for Volume {
+    fn resize(&mut self, width: u32, height: u32, depth: u32) {
+        let _ = (width, height, depth);
+    }
+}
+
+#[allow(data_clumps)]
+fn scale(x: f64, y: f64, z: f64) -> f64 {
+    x * y * z
+}
+
+fn translate(x: f64, y: f64, z: f64) -> f64 {
+    x + y + z
+}
+
+fn rotate(x: f64, y: f64, z: f64) -> f64 {
+    x - y - z
+}
+
+fn main() {
+    let _ = reconnect("localhost", 80, 5) && probe(String::new(), 80, 5);
+    Image.resize(1, 2, 3);
+    Volume.resize(1, 2, 3);
+    let _ = scale(1.0, 2.0, 3.0) + translate(1.0, 2.0, 3.0) + rotate(1.0, 2.0, 3.0);
+}
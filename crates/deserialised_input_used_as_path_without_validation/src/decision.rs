@@ -0,0 +1,117 @@
+//! Pure decision logic for `deserialised_input_used_as_path_without_validation`.
+//!
+//! The driver records, for each function body, the filesystem calls reached
+//! by deserialised input with the places the value was read through, and the
+//! places passed to validation functions.
+//! [`DeserialisedInputUsedAsPathWithoutValidationDecision`] replays those
+//! facts through the same [`BodyRecord`] and reports the first call whose
+//! value was never validated, naming the call as the finding's subject.
+//! Places are written as a binding followed by fields, such as
+//! `request.path`.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{BodyRecord, Place};
+
+/// Reason key for filesystem calls on unvalidated input.
+const UNVALIDATED_PATH: &str = "unvalidated_path";
+
+/// A filesystem call whose path argument came from deserialised input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SinkProjection {
+    /// The called function, such as `std::fs::File::open`.
+    pub call: String,
+    /// The places the value was read through, such as `request.path`.
+    pub read_through: Vec<String>,
+}
+
+/// The facts about one function body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathBodyProjection {
+    /// The filesystem calls reached by deserialised input, in body order.
+    pub sinks: Vec<SinkProjection>,
+    /// The places passed to a validation function.
+    pub validated: Vec<String>,
+}
+
+/// Reports filesystem calls whose path comes from deserialised input that
+/// the body never validates.
+///
+/// # Examples
+///
+/// ```ignore
+/// let body = PathBodyProjection {
+///     sinks: vec![SinkProjection {
+///         call: "std::fs::File::open".to_owned(),
+///         read_through: vec!["request.path".to_owned()],
+///     }],
+///     validated: vec!["request".to_owned()],
+/// };
+/// assert!(DeserialisedInputUsedAsPathWithoutValidationDecision.decide(&body).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DeserialisedInputUsedAsPathWithoutValidationDecision;
+
+impl LintDecision for DeserialisedInputUsedAsPathWithoutValidationDecision {
+    type Projection = PathBodyProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, body: &PathBodyProjection) -> Option<Finding> {
+        let mut record = BodyRecord::default();
+        for sink in &body.sinks {
+            let places = sink.read_through.iter().map(|place| place_of(place));
+            record.record_sink(places.collect(), sink.call.as_str());
+        }
+        for place in &body.validated {
+            record.record_validation(place_of(place));
+        }
+        let call = record.into_unvalidated_sinks().into_iter().next()?;
+        Some(Finding::new(LINT_NAME, UNVALIDATED_PATH).with_subject(call))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<PathBodyProjection>> {
+        let body = |validated: &[&str]| PathBodyProjection {
+            sinks: vec![SinkProjection {
+                call: "std::fs::File::open".to_owned(),
+                read_through: vec!["request".to_owned(), "request.path".to_owned()],
+            }],
+            validated: validated.iter().map(|&place| place.to_owned()).collect(),
+        };
+        vec![
+            DecisionFixture::flagged("no validation", body(&[]), UNVALIDATED_PATH),
+            DecisionFixture::flagged(
+                "sibling field validated",
+                body(&["request.name"]),
+                UNVALIDATED_PATH,
+            ),
+            DecisionFixture::clean("field validated", body(&["request.path"])),
+            DecisionFixture::clean("whole value validated", body(&["request"])),
+        ]
+    }
+}
+
+/// Splits a dotted place such as `request.path` into its binding and fields.
+fn place_of(place: &str) -> Place<&str> {
+    let mut segments = place.split('.');
+    let binding = segments.next().unwrap_or_default();
+    Place::new(binding, segments.map(str::to_owned).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&DeserialisedInputUsedAsPathWithoutValidationDecision),
+            Ok(4)
+        );
+    }
+}
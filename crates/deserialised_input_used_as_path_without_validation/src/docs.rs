@@ -0,0 +1,35 @@
+//! Embedded documentation for the
+//! `deserialised_input_used_as_path_without_validation` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `deserialised_input_used_as_path_without_validation`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "deserialised_input_used_as_path_without_validation",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags filesystem calls such as `File::open` and `fs::remove_file` whose path comes from serde-deserialised input that was not passed to a validation function in the same function.",
+    rationale: "Deserialised data is chosen by whoever produced it, so a path field holding `../../etc/passwd` or `/var/lib/app` reads or deletes files outside the directory the program meant to expose. Checking the path before it reaches the filesystem closes this traversal hole.",
+    bad_example: "let request: Request = serde_json::from_str(body)?;\nfs::remove_file(&request.path)?;",
+    good_example: "let request: Request = serde_json::from_str(body)?;\nvalidate_upload_path(&request.path)?;\nfs::remove_file(&request.path)?;",
+    config: &[
+        ConfigKeyDocs {
+            key: "deserialisers",
+            default: "[\"serde::Deserialize::deserialize\", \"serde_json::from_reader\", \"serde_json::from_slice\", \"serde_json::from_str\", \"serde_json::from_value\", \"serde_yaml::from_reader\", \"serde_yaml::from_slice\", \"serde_yaml::from_str\", \"toml::from_str\"]",
+            description: "Fully qualified functions whose results are treated as untrusted input.",
+        },
+        ConfigKeyDocs {
+            key: "sinks",
+            default: "[\"std::fs::File::open\", \"std::fs::remove_dir\", \"std::fs::remove_dir_all\", \"std::fs::remove_file\", \"tokio::fs::File::open\", \"tokio::fs::remove_dir\", \"tokio::fs::remove_dir_all\", \"tokio::fs::remove_file\"]",
+            description: "Fully qualified functions whose first argument is a path that must not come from unvalidated input.",
+        },
+        ConfigKeyDocs {
+            key: "validators",
+            default: "[]",
+            description: "Fully qualified functions that validate the values passed to them, such as `my_crate::paths::validate_upload_path`; passing a binding or field to one covers every use of it in the function.",
+        },
+    ],
+};
@@ -0,0 +1,380 @@
+//! Lint pass reporting deserialised input used as a filesystem path without
+//! validation.
+//!
+//! Each function body gets a [`BodyRecord`]. Calls that resolve to one of
+//! the configured sinks, such as `File::open` or `fs::remove_file`, are
+//! recorded when their path argument traces back to a configured
+//! deserialisation function, along with the bindings and fields the value was
+//! read through. Calls to a configured validation function record the
+//! binding or field they are given. When the body ends, the sinks whose value
+//! was never read from a validated place are reported. Closures share the
+//! record of the function that contains them.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::BodyOwnerKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::BodyRecord;
+use crate::taint::{call_operands, callee_path, deserialised_source, first_argument, place_of};
+
+pub(crate) const LINT_NAME: &str = "deserialised_input_used_as_path_without_validation";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const DEFAULT_DESERIALISERS: &[&str] = &[
+    "serde::Deserialize::deserialize",
+    "serde_json::from_reader",
+    "serde_json::from_slice",
+    "serde_json::from_str",
+    "serde_json::from_value",
+    "serde_yaml::from_reader",
+    "serde_yaml::from_slice",
+    "serde_yaml::from_str",
+    "toml::from_str",
+];
+const DEFAULT_SINKS: &[&str] = &[
+    "std::fs::File::open",
+    "std::fs::remove_dir",
+    "std::fs::remove_dir_all",
+    "std::fs::remove_file",
+    "tokio::fs::File::open",
+    "tokio::fs::remove_dir",
+    "tokio::fs::remove_dir_all",
+    "tokio::fs::remove_file",
+];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    deserialisers: Vec<String>,
+    sinks: Vec<String>,
+    validators: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            deserialisers: owned(DEFAULT_DESERIALISERS),
+            sinks: owned(DEFAULT_SINKS),
+            validators: Vec::new(),
+        }
+    }
+}
+
+fn owned(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(|&path| path.to_owned()).collect()
+}
+
+/// Lint pass that tracks tainted filesystem calls and validations per body.
+#[derive(Default)]
+pub struct DeserialisedInputUsedAsPathWithoutValidation {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configuration, open function bodies, finding limit, and localization for
+/// the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    bodies: Vec<BodyRecord<hir::HirId, Sink>>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// A filesystem call whose path came from deserialised input.
+#[derive(Clone, Debug)]
+struct Sink {
+    hir_id: hir::HirId,
+    span: Span,
+    sink: String,
+    source_span: Span,
+    source: String,
+}
+
+dylint_linting::impl_late_lint! {
+    pub DESERIALISED_INPUT_USED_AS_PATH_WITHOUT_VALIDATION,
+    Warn,
+    "deserialised input passed to a filesystem call without validation",
+    DeserialisedInputUsedAsPathWithoutValidation::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for DeserialisedInputUsedAsPathWithoutValidation {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            bodies: Vec::new(),
+            findings: FindingLimit::new(
+                DESERIALISED_INPUT_USED_AS_PATH_WITHOUT_VALIDATION,
+                &shared_config,
+            ),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    // Closures are checked together with the function that contains them.
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if !matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            self.state.bodies.push(BodyRecord::default());
+        }
+    }
+
+    fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            return;
+        }
+        let Some(record) = self.state.bodies.pop() else {
+            return;
+        };
+        for sink in record.into_unvalidated_sinks() {
+            if self.state.findings.admit(cx, sink.hir_id, sink.span) {
+                emit_diagnostic(cx, &sink, &self.state.localizer);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let Some(path) = callee_path(cx, expr) else {
+            return;
+        };
+        let state = &mut *self.state;
+        let Some(record) = state.bodies.last_mut() else {
+            return;
+        };
+        if state.config.validators.contains(&path) {
+            debug!(target: LINT_NAME, "`{path}` validates its arguments");
+            for place in call_operands(expr)
+                .into_iter()
+                .filter_map(|operand| place_of(cx, operand))
+            {
+                record.record_validation(place);
+            }
+            return;
+        }
+        if expr.span.from_expansion() || !state.config.sinks.contains(&path) {
+            return;
+        }
+        let Some(taint) = first_argument(expr)
+            .and_then(|argument| deserialised_source(cx, argument, &state.config.deserialisers))
+        else {
+            return;
+        };
+        let source = callee_path(cx, taint.source).unwrap_or_default();
+
+        debug!(target: LINT_NAME, "`{path}` receives a value from `{source}`");
+        record.record_sink(
+            taint.places,
+            Sink {
+                hir_id: expr.hir_id,
+                span: expr.span,
+                sink: short_path(&path),
+                source_span: taint.source.span,
+                source: short_path(&source),
+            },
+        );
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Keeps the last two segments of an item path, so `std::fs::File::open`
+/// reads as `File::open` and `std::fs::remove_file` as `fs::remove_file`.
+fn short_path(path: &str) -> String {
+    let mut segments = path.rsplitn(3, "::");
+    let last = segments.next().unwrap_or_default();
+    match segments.next() {
+        Some(parent) => format!("{parent}::{last}"),
+        None => last.to_owned(),
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, sink: &Sink, localizer: &Localizer) {
+    let message = SinkMessage {
+        sink: &sink.sink,
+        source: &sink.source,
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let source_span = sink.source_span;
+
+    cx.emit_span_lint(
+        DESERIALISED_INPUT_USED_AS_PATH_WITHOUT_VALIDATION,
+        sink.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(source_span, note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for an unvalidated filesystem call
+/// interpolates.
+#[derive(Clone, Copy, Debug)]
+struct SinkMessage<'a> {
+    sink: &'a str,
+    source: &'a str,
+}
+
+impl SinkMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("sink"),
+            FluentValue::from(self.sink.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("source"),
+            FluentValue::from(self.source.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { sink, source } = *self;
+        DiagnosticMessageSet::new(
+            format!("Deserialised input reaches `{sink}` without validation."),
+            format!(
+                "`{source}` decodes data chosen by whoever produced it, so the path may contain \
+                 `..` or start at the filesystem root."
+            ),
+            String::from(
+                "Reject paths with `..`, root, or prefix components before using them, and list \
+                 the checking function under `validators`.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: SinkMessage<'static> = SinkMessage {
+        sink: "File::open",
+        source: "serde_json::from_str",
+    };
+
+    fn resolve(message: &SinkMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_covers_serde_formats_and_std_and_tokio_sinks() {
+        let config = Config::default();
+
+        assert!(
+            config
+                .deserialisers
+                .contains(&"serde_json::from_str".to_owned())
+        );
+        assert!(config.deserialisers.contains(&"toml::from_str".to_owned()));
+        assert!(config.sinks.contains(&"std::fs::File::open".to_owned()));
+        assert!(
+            config
+                .sinks
+                .contains(&"tokio::fs::remove_dir_all".to_owned())
+        );
+        assert!(config.validators.is_empty());
+    }
+
+    #[rstest]
+    fn partial_config_keeps_other_defaults() {
+        let config: Config = toml::from_str("validators = [\"demo::validate_path\"]")
+            .expect("partial configuration should parse");
+
+        assert_eq!(config.validators, ["demo::validate_path"]);
+        assert_eq!(config.sinks, Config::default().sinks);
+    }
+
+    #[rstest]
+    #[case::method("std::fs::File::open", "File::open")]
+    #[case::function("std::fs::remove_file", "fs::remove_file")]
+    #[case::single("open", "open")]
+    fn short_path_keeps_two_segments(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(short_path(path), expected);
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_sink_and_source() {
+        let messages = MESSAGE.fallback_messages();
+
+        assert!(messages.primary().contains("`File::open`"));
+        assert!(
+            messages
+                .note()
+                .starts_with("`serde_json::from_str` decodes")
+        );
+        assert!(messages.help().contains("`validators`"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`File::open`"));
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,51 @@
+//! Lint crate flagging deserialised input used as a filesystem path without
+//! validation.
+//!
+//! A request body, configuration file, or message decoded with serde holds
+//! whatever its sender chose to put there. When a string from it reaches
+//! `File::open` or `fs::remove_file` unchecked, a value such as
+//! `../../etc/passwd` or an absolute path reads or deletes files outside the
+//! directory the program meant to expose. The lint follows values produced by
+//! a configured deserialisation function to filesystem calls in the same
+//! function and reports the calls that are reached without the value passing
+//! through a configured validation function. The lint is experimental and
+//! ships behind the
+//! `experimental-deserialised-input-used-as-path-without-validation` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+mod taint;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{
+    DeserialisedInputUsedAsPathWithoutValidationDecision, PathBodyProjection, SinkProjection,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn deserialised_input_used_as_path_without_validation_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,206 @@
+//! Per-function bookkeeping and path normalisation for the lint.
+//!
+//! The driver records every filesystem call reached by deserialised input,
+//! together with the places the value was read from on the way, and every
+//! place passed to a validation function while walking a function body. Once
+//! the body is finished it asks the [`BodyRecord`] which calls to report.
+//! Keeping the decision here, free of compiler types, lets it be tested
+//! directly.
+
+/// A local binding, or a field reached from it, such as `request.path`.
+///
+/// `B` identifies the binding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Place<B> {
+    binding: B,
+    fields: Vec<String>,
+}
+
+impl<B: PartialEq> Place<B> {
+    /// Creates the place reached by reading `fields`, outermost first, from
+    /// `binding`.
+    pub(crate) fn new(binding: B, fields: Vec<String>) -> Self {
+        Self { binding, fields }
+    }
+
+    /// Reports whether validating `self` also validates `other`, because
+    /// `other` is `self` or a field nested inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let request = Place::new("request", vec![]);
+    /// let path = Place::new("request", vec!["path".to_owned()]);
+    /// assert!(request.covers(&path));
+    /// assert!(!path.covers(&request));
+    /// ```
+    #[must_use]
+    pub(crate) fn covers(&self, other: &Self) -> bool {
+        self.binding == other.binding && other.fields.starts_with(&self.fields)
+    }
+}
+
+/// The filesystem calls and validations seen in one function body.
+///
+/// `B` identifies a local binding and `S` describes a filesystem call.
+#[derive(Debug)]
+pub(crate) struct BodyRecord<B, S> {
+    sinks: Vec<(Vec<Place<B>>, S)>,
+    validated: Vec<Place<B>>,
+}
+
+impl<B, S> Default for BodyRecord<B, S> {
+    fn default() -> Self {
+        Self {
+            sinks: Vec::new(),
+            validated: Vec::new(),
+        }
+    }
+}
+
+impl<B: PartialEq, S> BodyRecord<B, S> {
+    /// Records a filesystem call whose path argument came from deserialised
+    /// input, read through `places` on the way.
+    pub(crate) fn record_sink(&mut self, places: Vec<Place<B>>, sink: S) {
+        self.sinks.push((places, sink));
+    }
+
+    /// Records a place passed to a validation function.
+    pub(crate) fn record_validation(&mut self, place: Place<B>) {
+        self.validated.push(place);
+    }
+
+    /// Returns the calls to report: those whose value was never read from a
+    /// place validated anywhere in the body.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut record = BodyRecord::default();
+    /// record.record_sink(vec![Place::new("request", vec![])], "File::open");
+    /// record.record_validation(Place::new("request", vec![]));
+    /// assert!(record.into_unvalidated_sinks().is_empty());
+    /// ```
+    #[must_use]
+    pub(crate) fn into_unvalidated_sinks(self) -> Vec<S> {
+        let Self { sinks, validated } = self;
+        sinks
+            .into_iter()
+            .filter(|(places, _)| {
+                !places
+                    .iter()
+                    .any(|place| validated.iter().any(|valid| valid.covers(place)))
+            })
+            .map(|(_, sink)| sink)
+            .collect()
+    }
+}
+
+/// Removes generic arguments from a resolved item path, so
+/// `serde_json::from_str::<Request>` compares equal to the configured
+/// `serde_json::from_str`.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(strip_generic_args("serde_json::from_str::<'_, T>"), "serde_json::from_str");
+/// ```
+#[must_use]
+pub(crate) fn strip_generic_args(path: &str) -> String {
+    let mut stripped = String::with_capacity(path.len());
+    let mut depth = 0_usize;
+    for character in path.chars() {
+        match character {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => stripped.push(character),
+            _ => {}
+        }
+    }
+    stripped
+        .split("::")
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn place(binding: &'static str, fields: &[&str]) -> Place<&'static str> {
+        Place::new(
+            binding,
+            fields.iter().map(|&field| field.to_owned()).collect(),
+        )
+    }
+
+    #[rstest]
+    #[case::plain("serde_json::from_str", "serde_json::from_str")]
+    #[case::turbofish("serde_json::from_str::<'_, Request>", "serde_json::from_str")]
+    #[case::trait_method(
+        "serde::Deserialize::<'de>::deserialize",
+        "serde::Deserialize::deserialize"
+    )]
+    fn strip_generic_args_keeps_the_item_path(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(strip_generic_args(path), expected);
+    }
+
+    #[rstest]
+    #[case::same_place(&[], &[], true)]
+    #[case::nested_field(&[], &["path"], true)]
+    #[case::deeper_field(&["paths"], &["paths", "input"], true)]
+    #[case::sibling_field(&["name"], &["path"], false)]
+    #[case::enclosing_value(&["path"], &[], false)]
+    fn places_cover_their_fields(
+        #[case] validated: &[&str],
+        #[case] used: &[&str],
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            place("request", validated).covers(&place("request", used)),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn places_of_other_bindings_are_not_covered() {
+        assert!(!place("request", &[]).covers(&place("reply", &[])));
+    }
+
+    #[rstest]
+    fn sinks_without_validation_are_reported() {
+        let mut record = BodyRecord::default();
+        record.record_sink(vec![place("request", &["path"])], 1);
+        record.record_sink(Vec::new(), 2);
+
+        assert_eq!(record.into_unvalidated_sinks(), [1, 2]);
+    }
+
+    #[rstest]
+    #[case::validation_first(true)]
+    #[case::validation_last(false)]
+    fn a_validation_anywhere_covers_the_place(#[case] validate_first: bool) {
+        let mut record = BodyRecord::default();
+        if validate_first {
+            record.record_validation(place("request", &[]));
+        }
+        record.record_sink(vec![place("path", &[]), place("request", &["path"])], 1);
+        if !validate_first {
+            record.record_validation(place("request", &[]));
+        }
+
+        assert!(record.into_unvalidated_sinks().is_empty());
+    }
+
+    #[rstest]
+    fn a_validation_covers_only_its_own_place() {
+        let mut record = BodyRecord::default();
+        record.record_validation(place("request", &["name"]));
+        record.record_sink(vec![place("request", &["name"])], 1);
+        record.record_sink(vec![place("request", &["path"])], 2);
+
+        assert_eq!(record.into_unvalidated_sinks(), [2]);
+    }
+}
@@ -0,0 +1,278 @@
+//! Tracing a filesystem call's path back to deserialised input.
+//!
+//! A path argument is traced through borrows, dereferences, the `?` operator,
+//! field reads, immutable `let` bindings, including struct, `Ok(..)`, and
+//! `Some(..)` patterns and `if let`, standard `new` and `from` conversions,
+//! methods that hand their receiver's value back unchanged, such as `as_str`,
+//! `map_err`, or `unwrap`, and `join`, whose result is tainted by either side.
+//! The trace ends at a call to one of the configured deserialisation functions,
+//! or gives up on anything else, so a value returned by a validation helper is
+//! not followed. Every binding and field read along the way is kept as a
+//! [`Place`], so validating any of them covers the call.
+
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use crate::policy::{Place, strip_generic_args};
+
+/// Bounds how many bindings, fields, and conversions a value is traced
+/// through.
+const MAX_TRACE_DEPTH: usize = 16;
+
+/// Methods that return their receiver's value, or a view of it, unchanged.
+const VALUE_PRESERVING_METHODS: &[&str] = &[
+    "as_deref",
+    "as_os_str",
+    "as_path",
+    "as_ref",
+    "as_str",
+    "borrow",
+    "clone",
+    "expect",
+    "into",
+    "into_owned",
+    "map_err",
+    "ok",
+    "to_owned",
+    "to_path_buf",
+    "to_string",
+    "unwrap",
+];
+
+/// Methods whose result is tainted by their receiver or their argument.
+const COMBINING_METHODS: &[&str] = &["join"];
+
+/// Where a traced value came from.
+#[derive(Debug)]
+pub(crate) struct Taint<'tcx> {
+    /// The deserialisation call that produced the value.
+    pub(crate) source: &'tcx hir::Expr<'tcx>,
+    /// The bindings and fields the value was read through.
+    pub(crate) places: Vec<Place<hir::HirId>>,
+}
+
+/// Returns the path of `def_id` without generic arguments, qualified with
+/// the crate name when the item is local.
+pub(crate) fn item_path(cx: &LateContext<'_>, def_id: DefId) -> String {
+    let path = strip_generic_args(&cx.tcx.def_path_str(def_id));
+    if def_id.is_local() {
+        format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+    } else {
+        path
+    }
+}
+
+/// Returns the path of the function or method `expr` calls.
+pub(crate) fn callee_path(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<String> {
+    let def_id = match expr.kind {
+        hir::ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+        hir::ExprKind::Call(callee, _) => match callee.kind {
+            hir::ExprKind::Path(ref qpath) => cx.qpath_res(qpath, callee.hir_id).opt_def_id(),
+            _ => None,
+        },
+        _ => None,
+    }?;
+    Some(item_path(cx, def_id))
+}
+
+/// Returns the values a call passes, receiver first for method calls.
+pub(crate) fn call_operands<'tcx>(expr: &'tcx hir::Expr<'tcx>) -> Vec<&'tcx hir::Expr<'tcx>> {
+    match expr.kind {
+        hir::ExprKind::Call(_, args) => args.iter().collect(),
+        hir::ExprKind::MethodCall(_, receiver, args, _) => {
+            std::iter::once(receiver).chain(args).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the first argument of a call, not counting a method's receiver.
+pub(crate) fn first_argument<'tcx>(expr: &'tcx hir::Expr<'tcx>) -> Option<&'tcx hir::Expr<'tcx>> {
+    match expr.kind {
+        hir::ExprKind::Call(_, args) | hir::ExprKind::MethodCall(_, _, args, _) => args.first(),
+        _ => None,
+    }
+}
+
+/// Traces `expr` back to a call to one of `deserialisers`.
+pub(crate) fn deserialised_source<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    deserialisers: &[String],
+) -> Option<Taint<'tcx>> {
+    trace(cx, expr, deserialisers, Trail::default())
+}
+
+/// Returns the binding or field `expr` reads, looking through borrows,
+/// conversions, and value-preserving methods.
+pub(crate) fn place_of(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<Place<hir::HirId>> {
+    let mut fields = Vec::new();
+    let mut current = expr;
+    for _ in 0..MAX_TRACE_DEPTH {
+        current = match current.kind {
+            hir::ExprKind::AddrOf(_, _, inner)
+            | hir::ExprKind::DropTemps(inner)
+            | hir::ExprKind::Unary(hir::UnOp::Deref, inner) => inner,
+            hir::ExprKind::Field(base, ident) => {
+                fields.push(ident.to_string());
+                base
+            }
+            hir::ExprKind::MethodCall(segment, receiver, _, _)
+                if VALUE_PRESERVING_METHODS.contains(&segment.ident.as_str()) =>
+            {
+                receiver
+            }
+            hir::ExprKind::Call(callee, [arg]) if is_std_conversion(cx, callee) => arg,
+            hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => {
+                let Res::Local(binding) = path.res else {
+                    return None;
+                };
+                fields.reverse();
+                return Some(Place::new(binding, fields));
+            }
+            _ => return None,
+        };
+    }
+    None
+}
+
+/// What a trace has passed through on its way back from the sink.
+#[derive(Clone, Debug, Default)]
+struct Trail {
+    /// Fields the sink reads from the value being traced, innermost first.
+    fields: Vec<String>,
+    /// Bindings and fields the value was read through so far.
+    places: Vec<Place<hir::HirId>>,
+    /// Expressions followed so far.
+    depth: usize,
+}
+
+impl Trail {
+    /// Returns the trail one expression further on.
+    fn step(mut self) -> Option<Self> {
+        self.depth += 1;
+        (self.depth <= MAX_TRACE_DEPTH).then_some(self)
+    }
+}
+
+/// Follows `expr` towards the deserialisation call that produced it.
+fn trace<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    deserialisers: &[String],
+    trail: Trail,
+) -> Option<Taint<'tcx>> {
+    let mut trail = trail.step()?;
+    if callee_path(cx, expr).is_some_and(|path| deserialisers.contains(&path)) {
+        return Some(Taint {
+            source: expr,
+            places: trail.places,
+        });
+    }
+    match expr.kind {
+        hir::ExprKind::AddrOf(_, _, inner)
+        | hir::ExprKind::DropTemps(inner)
+        | hir::ExprKind::Unary(hir::UnOp::Deref, inner) => trace(cx, inner, deserialisers, trail),
+        hir::ExprKind::Field(base, ident) => {
+            trail.fields.push(ident.to_string());
+            trace(cx, base, deserialisers, trail)
+        }
+        hir::ExprKind::Match(scrutinee, _, hir::MatchSource::TryDesugar(_)) => {
+            match scrutinee.kind {
+                hir::ExprKind::Call(_, [inner]) => trace(cx, inner, deserialisers, trail),
+                _ => None,
+            }
+        }
+        hir::ExprKind::MethodCall(segment, receiver, args, _) => {
+            let method = segment.ident.as_str();
+            if VALUE_PRESERVING_METHODS.contains(&method) {
+                trace(cx, receiver, deserialisers, trail)
+            } else if COMBINING_METHODS.contains(&method) {
+                // A joined path reads no field of either side.
+                trail.fields.clear();
+                args.iter()
+                    .chain([receiver])
+                    .find_map(|operand| trace(cx, operand, deserialisers, trail.clone()))
+            } else {
+                None
+            }
+        }
+        hir::ExprKind::Call(callee, [arg]) if is_std_conversion(cx, callee) => {
+            trace(cx, arg, deserialisers, trail)
+        }
+        hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => match path.res {
+            Res::Local(binding) => trace_binding(cx, binding, deserialisers, trail),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Records `binding` as a place the value was read through and follows its
+/// initialiser.
+fn trace_binding<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding: hir::HirId,
+    deserialisers: &[String],
+    mut trail: Trail,
+) -> Option<Taint<'tcx>> {
+    let fields = trail.fields.iter().rev().cloned().collect();
+    trail.places.push(Place::new(binding, fields));
+    let (init, pattern_fields) = binding_initializer(cx, binding)?;
+    trail.fields.extend(pattern_fields);
+    trace(cx, init, deserialisers, trail)
+}
+
+/// Reports whether `callee` is a standard-library `new` or `from`
+/// constructor, such as `Path::new` or `PathBuf::from`.
+fn is_std_conversion(cx: &LateContext<'_>, callee: &hir::Expr<'_>) -> bool {
+    let hir::ExprKind::Path(ref qpath) = callee.kind else {
+        return false;
+    };
+    cx.qpath_res(qpath, callee.hir_id)
+        .opt_def_id()
+        .is_some_and(|def_id| {
+            matches!(
+                cx.tcx.crate_name(def_id.krate),
+                sym::std | sym::core | sym::alloc
+            ) && matches!(cx.tcx.item_name(def_id), sym::new | sym::from)
+        })
+}
+
+/// Returns the initialiser of the immutable binding `binding`, together with
+/// the fields its pattern reads from that initialiser, innermost first.
+///
+/// The binding may sit inside struct patterns and single-field tuple-struct
+/// patterns such as `Ok(..)` and `Some(..)`, in a `let` statement or an
+/// `if let` or `while let` condition.
+fn binding_initializer<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding: hir::HirId,
+) -> Option<(&'tcx hir::Expr<'tcx>, Vec<String>)> {
+    let hir::Node::Pat(pat) = cx.tcx.hir_node(binding) else {
+        return None;
+    };
+    if !matches!(pat.kind, hir::PatKind::Binding(hir::BindingMode::NONE, ..)) {
+        return None;
+    }
+    let mut fields = Vec::new();
+    for (_, node) in cx.tcx.hir_parent_iter(binding) {
+        match node {
+            hir::Node::PatField(field) => fields.push(field.ident.to_string()),
+            hir::Node::Pat(parent) => match parent.kind {
+                hir::PatKind::Struct(..) | hir::PatKind::TupleStruct(_, [_], _) => {}
+                _ => return None,
+            },
+            hir::Node::LetStmt(local) => return local.init.map(|init| (init, fields)),
+            hir::Node::Expr(hir::Expr {
+                kind: hir::ExprKind::Let(let_expr),
+                ..
+            }) => return Some((let_expr.init, fields)),
+            _ => return None,
+        }
+    }
+    None
+}
@@ -0,0 +1,97 @@
+//! Behaviour-driven coverage for deciding which filesystem calls to report.
+
+use crate::policy::{BodyRecord, Place};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+const REQUEST: &str = "request";
+
+#[derive(Default)]
+struct BodyWorld {
+    record: RefCell<BodyRecord<&'static str, usize>>,
+    reported: Cell<Option<usize>>,
+}
+
+#[fixture]
+fn world() -> BodyWorld {
+    BodyWorld::default()
+}
+
+fn request_field(field: &str) -> Place<&'static str> {
+    Place::new(REQUEST, vec![field.to_owned()])
+}
+
+#[given("a filesystem call reads the request field {field}")]
+fn given_sink(world: &BodyWorld, field: String) {
+    world
+        .record
+        .borrow_mut()
+        .record_sink(vec![request_field(field.trim_matches('"'))], 0);
+}
+
+#[given("the request field {field} is validated")]
+fn given_field_validated(world: &BodyWorld, field: String) {
+    world
+        .record
+        .borrow_mut()
+        .record_validation(request_field(field.trim_matches('"')));
+}
+
+#[given("the whole request is validated")]
+fn given_request_validated(world: &BodyWorld) {
+    world
+        .record
+        .borrow_mut()
+        .record_validation(Place::new(REQUEST, Vec::new()));
+}
+
+#[when("the function body ends")]
+fn when_body_ends(world: &BodyWorld) {
+    let record = world.record.take();
+    world
+        .reported
+        .set(Some(record.into_unvalidated_sinks().len()));
+}
+
+#[then("{count} call is reported")]
+fn then_one_reported(world: &BodyWorld, count: usize) {
+    then_reported(world, count);
+}
+
+#[then("{count} calls are reported")]
+fn then_reported(world: &BodyWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[scenario(
+    path = "tests/features/deserialised_input_used_as_path_without_validation.feature",
+    index = 0
+)]
+fn scenario_unvalidated(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/deserialised_input_used_as_path_without_validation.feature",
+    index = 1
+)]
+fn scenario_field_validated(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/deserialised_input_used_as_path_without_validation.feature",
+    index = 2
+)]
+fn scenario_request_validated(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/deserialised_input_used_as_path_without_validation.feature",
+    index = 3
+)]
+fn scenario_other_field_validated(world: BodyWorld) {
+    let _ = world;
+}
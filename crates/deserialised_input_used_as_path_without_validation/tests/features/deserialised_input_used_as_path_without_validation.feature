@@ -0,0 +1,27 @@
+Feature: Deserialised input used as a path without validation
+  A filesystem call whose path was read from deserialised input is reported
+  unless the binding or field it was read from is passed to a validation
+  function in the same function.
+
+  Scenario: An unvalidated field is reported
+    Given a filesystem call reads the request field "path"
+    When the function body ends
+    Then 1 call is reported
+
+  Scenario: Validating the field suppresses the call
+    Given a filesystem call reads the request field "path"
+    And the request field "path" is validated
+    When the function body ends
+    Then 0 calls are reported
+
+  Scenario: Validating the whole request suppresses the call
+    Given a filesystem call reads the request field "path"
+    And the whole request is validated
+    When the function body ends
+    Then 0 calls are reported
+
+  Scenario: Validating a different field leaves the call reported
+    Given a filesystem call reads the request field "path"
+    And the request field "name" is validated
+    When the function body ends
+    Then 1 call is reported
@@ -0,0 +1,52 @@
+//! UI fixture for deserialised input used as a path without validation.
+#![warn(deserialised_input_used_as_path_without_validation)]
+
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+mod wire {
+    pub struct Request {
+        pub path: String,
+        pub name: String,
+    }
+
+    pub fn from_str(_body: &str) -> Result<Request, String> {
+        Ok(Request {
+            path: String::from("../escape"),
+            name: String::from("../escape"),
+        })
+    }
+}
+
+fn validate_name(name: &str) -> io::Result<()> {
+    if name.contains("..") {
+        return Err(io::Error::other("unsafe name"));
+    }
+    Ok(())
+}
+
+fn open_field(body: &str) -> io::Result<File> {
+    let request = wire::from_str(body).map_err(io::Error::other)?;
+    validate_name(&request.name)?;
+    File::open(&request.path)
+}
+
+fn remove_destructured(body: &str) -> io::Result<()> {
+    let wire::Request { path, .. } = wire::from_str(body).map_err(io::Error::other)?;
+    fs::remove_file(path)
+}
+
+fn remove_joined(body: &str) -> io::Result<()> {
+    if let Ok(request) = wire::from_str(body) {
+        let target = Path::new("uploads").join(&request.name);
+        fs::remove_dir_all(target)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    let _ = open_field("{}");
+    let _ = remove_destructured("{}");
+    let _ = remove_joined("{}");
+}
@@ -0,0 +1,67 @@
+//! UI fixture for deserialised input validated before filesystem use.
+#![warn(deserialised_input_used_as_path_without_validation)]
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+mod wire {
+    pub struct Request {
+        pub path: String,
+    }
+
+    pub fn from_str(_body: &str) -> Result<Request, String> {
+        Ok(Request {
+            path: String::from("reports/latest.txt"),
+        })
+    }
+}
+
+fn validate_path(path: &str) -> io::Result<()> {
+    if Path::new(path)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        Ok(())
+    } else {
+        Err(io::Error::other("unsafe path"))
+    }
+}
+
+fn validate_request(request: &wire::Request) -> io::Result<()> {
+    validate_path(&request.path)
+}
+
+fn checked(path: &str) -> io::Result<PathBuf> {
+    validate_path(path)?;
+    Ok(Path::new("uploads").join(path))
+}
+
+fn open_validated_field(body: &str) -> io::Result<File> {
+    let request = wire::from_str(body).map_err(io::Error::other)?;
+    validate_path(&request.path)?;
+    File::open(&request.path)
+}
+
+fn remove_validated_request(body: &str) -> io::Result<()> {
+    let request = wire::from_str(body).map_err(io::Error::other)?;
+    validate_request(&request)?;
+    fs::remove_file(&request.path)
+}
+
+fn open_checked_path(body: &str) -> io::Result<File> {
+    let request = wire::from_str(body).map_err(io::Error::other)?;
+    let path = checked(&request.path)?;
+    File::open(path)
+}
+
+fn open_fixed_path() -> io::Result<File> {
+    File::open("config.toml")
+}
+
+fn main() {
+    let _ = open_validated_field("{}");
+    let _ = remove_validated_request("{}");
+    let _ = open_checked_path("{}");
+    let _ = open_fixed_path();
+}
@@ -0,0 +1,97 @@
+//! Pure decision logic for `doc_ignore_must_have_reason`.
+//!
+//! The driver feeds the documentation of each item to a [`FenceScanner`] and
+//! reports every Rust example marked `ignore` without a parenthesised reason.
+//! [`DocIgnoreMustHaveReasonDecision`] scans an item's documentation the same
+//! way and reports its first such example, naming the fence's info string as
+//! the finding's subject. The crate-wide share of ignored examples is left
+//! to the driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::FenceScanner;
+
+/// Reason key for ignored examples without a reason.
+const MISSING_REASON: &str = "missing_reason";
+
+/// The facts about one item's documentation that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DocProjection {
+    /// The item's documentation, one line per entry.
+    pub lines: Vec<String>,
+}
+
+impl DocProjection {
+    /// Creates a projection from documentation text.
+    #[must_use]
+    pub fn new(doc: &str) -> Self {
+        Self {
+            lines: doc.lines().map(str::to_owned).collect(),
+        }
+    }
+}
+
+/// Reports documentation holding an `ignore` example without a reason.
+///
+/// # Examples
+///
+/// ```ignore
+/// let doc = DocProjection::new("```ignore\nrun();\n```");
+/// let finding = DocIgnoreMustHaveReasonDecision.decide(&doc).expect("no reason is given");
+/// assert_eq!(finding.subject(), Some("ignore"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DocIgnoreMustHaveReasonDecision;
+
+impl LintDecision for DocIgnoreMustHaveReasonDecision {
+    type Projection = DocProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, doc: &DocProjection) -> Option<Finding> {
+        let mut scanner = FenceScanner::default();
+        let fence = doc
+            .lines
+            .iter()
+            .filter_map(|line| scanner.scan(line))
+            .find(|fence| fence.example.lacks_reason())?;
+        Some(Finding::new(LINT_NAME, MISSING_REASON).with_subject(fence.info))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<DocProjection>> {
+        vec![
+            DecisionFixture::flagged(
+                "ignored without a reason",
+                DocProjection::new("```ignore\nrun();\n```"),
+                MISSING_REASON,
+            ),
+            DecisionFixture::clean(
+                "ignored with a reason",
+                DocProjection::new("```ignore (needs a network)\nrun();\n```"),
+            ),
+            DecisionFixture::clean(
+                "other language",
+                DocProjection::new("```toml,ignore\nkey = 1\n```"),
+            ),
+            DecisionFixture::clean(
+                "ignore inside an open fence",
+                DocProjection::new("````text\n```ignore\n````"),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&DocIgnoreMustHaveReasonDecision), Ok(4));
+    }
+}
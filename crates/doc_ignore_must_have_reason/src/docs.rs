@@ -0,0 +1,22 @@
+//! Embedded documentation for the `doc_ignore_must_have_reason` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `doc_ignore_must_have_reason`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "doc_ignore_must_have_reason",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags Rust doc examples marked `ignore` whose fence line gives no reason in parentheses, and optionally crates that ignore too many of their examples.",
+    rationale: "Rustdoc never compiles or runs an `ignore` example, so it drifts from the code it documents without warning. A reason on the fence line tells readers and reviewers whether skipping it is still justified.",
+    bad_example: "/// ```ignore\n/// let pool = connect(DATABASE_URL)?;\n/// ```",
+    good_example: "/// ```ignore (needs a running database)\n/// let pool = connect(DATABASE_URL)?;\n/// ```",
+    config: &[ConfigKeyDocs {
+        key: "max_ignored_percent",
+        default: "unset",
+        description: "When set, also report the crate once if more than this percentage of its Rust doc examples are ignored, with or without a reason.",
+    }],
+};
@@ -0,0 +1,337 @@
+//! Lint pass reporting `ignore` doc examples that give no reason.
+//!
+//! The documentation attributes of every node, and of the crate root, are
+//! read line by line through a [`FenceScanner`]. Each Rust example whose
+//! info string contains `ignore` without a parenthesised reason is reported
+//! at the documentation line that opens it. Every Rust example is also
+//! tallied in an [`IgnoredShare`], and when `max_ignored_percent` is set and
+//! the crate ignores a larger share, one crate-level finding is emitted at
+//! the first ignored example. Documentation produced by macro expansion is
+//! skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{FenceScanner, IgnoredShare, OpenedFence};
+
+pub(crate) const LINT_NAME: &str = "doc_ignore_must_have_reason";
+const EXAMPLE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const CRATE_KEY: MessageKey<'static> = MessageKey::new("doc_ignore_must_have_reason-crate");
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_ignored_percent: Option<u8>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub DOC_IGNORE_MUST_HAVE_REASON,
+    Warn,
+    "`ignore` doc examples should say why on the fence line",
+    DocIgnoreMustHaveReason::default()
+}
+
+/// Lint pass checking `ignore` doc examples for a reason.
+#[derive(Default)]
+pub struct DocIgnoreMustHaveReason {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configured limit, example tally, finding limit, and localization for the
+/// crate being checked.
+#[derive(Default)]
+struct CrateState {
+    max_ignored_percent: Option<u8>,
+    share: IgnoredShare,
+    first_ignored: Option<Span>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for DocIgnoreMustHaveReason {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            max_ignored_percent: load_configuration().max_ignored_percent,
+            findings: FindingLimit::new(DOC_IGNORE_MUST_HAVE_REASON, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+            ..CrateState::default()
+        });
+        // The crate root's attributes are visited before `check_crate` runs.
+        self.check_docs(cx, cx.tcx.hir_attrs(hir::CRATE_HIR_ID));
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let state = &*self.state;
+        if let (Some(limit), Some(span)) = (state.max_ignored_percent, state.first_ignored)
+            && state.share.exceeds(limit)
+            && state.findings.admit(cx, hir::CRATE_HIR_ID, span)
+        {
+            let finding = Finding::Crate {
+                share: state.share,
+                limit,
+            };
+            emit_diagnostic(cx, span, &finding, &state.localizer);
+        }
+        state.findings.summarise(cx, &state.localizer);
+    }
+
+    fn check_attributes(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [hir::Attribute]) {
+        if cx.last_node_with_lint_attrs != hir::CRATE_HIR_ID {
+            self.check_docs(cx, attrs);
+        }
+    }
+}
+
+impl DocIgnoreMustHaveReason {
+    fn check_docs(&mut self, cx: &LateContext<'_>, attrs: &[hir::Attribute]) {
+        for (span, fence) in doc_fences(attrs) {
+            let state = &mut *self.state;
+            state.share.record(fence.example);
+            if fence.example.rust && fence.example.ignored {
+                state.first_ignored.get_or_insert(span);
+            }
+            if !fence.example.lacks_reason() {
+                continue;
+            }
+            debug!(target: LINT_NAME, "doc example `{}` is ignored without a reason", fence.info);
+            if state.findings.admit(cx, cx.last_node_with_lint_attrs, span) {
+                let finding = Finding::Example { info: fence.info };
+                emit_diagnostic(cx, span, &finding, &state.localizer);
+            }
+        }
+    }
+}
+
+/// Returns each fence opened in the documentation among `attrs`, with the
+/// span of the attribute holding its opening line.
+fn doc_fences(attrs: &[hir::Attribute]) -> Vec<(Span, OpenedFence)> {
+    let mut scanner = FenceScanner::default();
+    let mut fences = Vec::new();
+    for attr in attrs {
+        let Some(doc) = attr.doc_str() else {
+            continue;
+        };
+        let span = attr.span();
+        if span.from_expansion() {
+            continue;
+        }
+        fences.extend(
+            doc.as_str()
+                .lines()
+                .filter_map(|line| scanner.scan(line))
+                .map(|fence| (span, fence)),
+        );
+    }
+    fences
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, span: Span, finding: &Finding, localizer: &Localizer) {
+    let args = finding.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: finding.message_key(),
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        finding.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        DOC_IGNORE_MUST_HAVE_REASON,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// What the lint reports.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Finding {
+    /// One ignored example without a reason.
+    Example {
+        /// The info string of its opening fence.
+        info: String,
+    },
+    /// A crate ignoring more of its examples than the configured share.
+    Crate {
+        /// The crate's example tally.
+        share: IgnoredShare,
+        /// The configured limit, as a percentage.
+        limit: u8,
+    },
+}
+
+impl Finding {
+    const fn message_key(&self) -> MessageKey<'static> {
+        match self {
+            Self::Example { .. } => EXAMPLE_KEY,
+            Self::Crate { .. } => CRATE_KEY,
+        }
+    }
+
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        match self {
+            Self::Example { info } => {
+                args.insert(Cow::Borrowed("info"), FluentValue::from(info.clone()));
+            }
+            Self::Crate { share, limit } => {
+                args.insert(Cow::Borrowed("ignored"), FluentValue::from(share.ignored));
+                args.insert(Cow::Borrowed("total"), FluentValue::from(share.total));
+                args.insert(Cow::Borrowed("percent"), FluentValue::from(share.percent()));
+                args.insert(Cow::Borrowed("limit"), FluentValue::from(*limit));
+            }
+        }
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        match self {
+            Self::Example { info } => DiagnosticMessageSet::new(
+                format!("Doc example fenced with `{info}` is ignored without a reason."),
+                String::from(
+                    "An `ignore` example is never compiled or run, so it can drift from the code \
+                     it documents, and readers cannot tell whether skipping it was deliberate.",
+                ),
+                format!(
+                    "Give the reason in parentheses on the fence line, as in `{info} (needs a \
+                     running database)`, or use `no_run` if the example still compiles."
+                ),
+            ),
+            Self::Crate { share, limit } => DiagnosticMessageSet::new(
+                format!(
+                    "{} of the {} doc examples in this crate are ignored ({}%), above the limit \
+                     of {limit}%.",
+                    share.ignored,
+                    share.total,
+                    share.percent(),
+                ),
+                String::from(
+                    "Ignored examples are never compiled, so that share of this crate's \
+                     documentation goes unchecked.",
+                ),
+                String::from(
+                    "Make examples compile with hidden setup lines starting with `# `, or mark \
+                     them `no_run` when they must not execute.",
+                ),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn example() -> Finding {
+        Finding::Example {
+            info: "rust,ignore".to_owned(),
+        }
+    }
+
+    fn crate_share() -> Finding {
+        Finding::Crate {
+            share: IgnoredShare {
+                ignored: 7,
+                total: 10,
+            },
+            limit: 50,
+        }
+    }
+
+    fn resolve(finding: &Finding, locale: &str) -> DiagnosticMessageSet {
+        let args = finding.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: finding.message_key(),
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || finding.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_disables_the_crate_limit() {
+        assert_eq!(Config::default().max_ignored_percent, None);
+    }
+
+    #[rstest]
+    fn fallback_messages_quote_the_info_string() {
+        let messages = example().fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Doc example fenced with `rust,ignore` is ignored without a reason."
+        );
+        assert!(
+            messages
+                .help()
+                .contains("`rust,ignore (needs a running database)`")
+        );
+    }
+
+    #[rstest]
+    fn crate_message_reports_the_share() {
+        assert_eq!(
+            crate_share().fallback_messages().primary(),
+            "7 of the 10 doc examples in this crate are ignored (70%), above the limit of 50%."
+        );
+    }
+
+    #[rstest]
+    #[case::example(example())]
+    #[case::crate_share(crate_share())]
+    fn english_messages_match_the_fallback(#[case] finding: Finding) {
+        assert_eq!(resolve(&finding, "en-GB"), finding.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let example = resolve(&example(), locale);
+        let crate_share = resolve(&crate_share(), locale);
+
+        assert!(example.primary().contains("`rust,ignore`"));
+        assert!(crate_share.primary().contains("70"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,41 @@
+//! Lint crate requiring a reason on ignored doc examples.
+//!
+//! A doc example marked `ignore` is never compiled or run, so nothing warns
+//! when it stops matching the code it documents. The lint reports `ignore`
+//! examples whose fence line gives no reason in parentheses, such as
+//! `ignore (needs a running database)`, and can also report a crate that
+//! ignores more than `max_ignored_percent` of its examples. The lint is
+//! experimental and ships behind the `experimental-doc-ignore-must-have-reason`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{DocIgnoreMustHaveReasonDecision, DocProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn doc_ignore_must_have_reason_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
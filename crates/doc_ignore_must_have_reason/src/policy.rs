@@ -0,0 +1,290 @@
+//! Rules recognizing doc examples and deciding which ones are reported.
+//!
+//! The driver feeds documentation to a [`FenceScanner`] one line at a time.
+//! Each opening fence is described as a [`DocExample`] from its info string,
+//! the text after the backticks or tildes. Rustdoc treats a block as Rust
+//! unless the info string names another language, skips it when the string
+//! contains `ignore`, and reads parenthesised text as a comment, which is
+//! where the reason belongs. [`IgnoredShare`] keeps the crate-wide tally. The
+//! rules are kept free of compiler types so they can be tested directly.
+
+/// Info string tokens rustdoc understands on a Rust example.
+const RUSTDOC_TOKENS: &[&str] = &[
+    "rust",
+    "ignore",
+    "should_panic",
+    "no_run",
+    "compile_fail",
+    "test_harness",
+    "standalone_crate",
+    "edition2015",
+    "edition2018",
+    "edition2021",
+    "edition2024",
+];
+
+/// One fenced code block, described by the info string on its opening line.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct DocExample {
+    /// Rustdoc treats the block as Rust code.
+    pub(crate) rust: bool,
+    /// The info string contains `ignore`.
+    pub(crate) ignored: bool,
+    /// The info string carries a non-empty parenthesised comment.
+    pub(crate) reason: bool,
+}
+
+impl DocExample {
+    /// Describes the block opened with `info` after the fence marker.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let example = DocExample::from_info("rust,ignore (needs a database)");
+    /// assert!(example.rust && example.ignored && example.reason);
+    /// assert!(!DocExample::from_info("text").rust);
+    /// ```
+    #[must_use]
+    pub(crate) fn from_info(info: &str) -> Self {
+        let mut words = String::with_capacity(info.len());
+        let mut reason = false;
+        let mut chars = info.chars();
+        while let Some(current) = chars.next() {
+            match current {
+                '(' => {
+                    let comment: String =
+                        chars.by_ref().take_while(|&inner| inner != ')').collect();
+                    reason |= !comment.trim().is_empty();
+                    words.push(' ');
+                }
+                '{' => {
+                    chars
+                        .by_ref()
+                        .take_while(|&inner| inner != '}')
+                        .for_each(drop);
+                    words.push(' ');
+                }
+                ',' => words.push(' '),
+                _ => words.push(current),
+            }
+        }
+
+        let tokens: Vec<&str> = words.split_whitespace().collect();
+        let names_rust = tokens.contains(&"rust");
+        let foreign = tokens.iter().any(|token| !is_rustdoc_token(token));
+        Self {
+            rust: names_rust || !foreign,
+            ignored: tokens.contains(&"ignore"),
+            reason,
+        }
+    }
+
+    /// Returns `true` when the block is an ignored Rust example without a
+    /// reason.
+    #[must_use]
+    pub(crate) const fn lacks_reason(self) -> bool {
+        self.rust && self.ignored && !self.reason
+    }
+}
+
+fn is_rustdoc_token(token: &str) -> bool {
+    RUSTDOC_TOKENS.contains(&token)
+        || token.starts_with("ignore-")
+        || (token.starts_with('E')
+            && token.len() > 1
+            && token[1..].bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// The marker that opened the current fence.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Fence {
+    marker: char,
+    length: usize,
+}
+
+/// Tracks fenced code blocks across the lines of one item's documentation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct FenceScanner {
+    open: Option<Fence>,
+}
+
+/// A fence opened by [`FenceScanner::scan`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct OpenedFence {
+    /// The info string as written, trimmed.
+    pub(crate) info: String,
+    /// What the info string says about the block.
+    pub(crate) example: DocExample,
+}
+
+impl FenceScanner {
+    /// Reads the next documentation line and returns the fence it opens, if
+    /// any. Lines inside a block and closing fences return `None`.
+    pub(crate) fn scan(&mut self, line: &str) -> Option<OpenedFence> {
+        let trimmed = line.trim_start();
+        let marker = trimmed
+            .chars()
+            .next()
+            .filter(|&first| matches!(first, '`' | '~'))?;
+        let length = trimmed
+            .chars()
+            .take_while(|&current| current == marker)
+            .count();
+        if length < 3 {
+            return None;
+        }
+        let info = trimmed[length * marker.len_utf8()..].trim();
+
+        match self.open {
+            Some(fence) => {
+                if fence.marker == marker && length >= fence.length && info.is_empty() {
+                    self.open = None;
+                }
+                None
+            }
+            None => {
+                // Rustdoc rejects backticks in a backtick fence's info string.
+                if marker == '`' && info.contains('`') {
+                    return None;
+                }
+                self.open = Some(Fence { marker, length });
+                Some(OpenedFence {
+                    info: info.to_owned(),
+                    example: DocExample::from_info(info),
+                })
+            }
+        }
+    }
+}
+
+/// The crate-wide count of Rust doc examples and how many are ignored.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct IgnoredShare {
+    /// Ignored Rust examples, with or without a reason.
+    pub(crate) ignored: usize,
+    /// All Rust examples.
+    pub(crate) total: usize,
+}
+
+impl IgnoredShare {
+    /// Counts `example` when it is Rust code.
+    pub(crate) fn record(&mut self, example: DocExample) {
+        if example.rust {
+            self.total += 1;
+            self.ignored += usize::from(example.ignored);
+        }
+    }
+
+    /// The ignored share as a whole percentage, rounded down.
+    #[must_use]
+    pub(crate) fn percent(self) -> usize {
+        (self.ignored * 100).checked_div(self.total).unwrap_or(0)
+    }
+
+    /// Returns `true` when more than `max_percent` of the examples are
+    /// ignored.
+    #[must_use]
+    pub(crate) fn exceeds(self, max_percent: u8) -> bool {
+        self.ignored * 100 > usize::from(max_percent) * self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", true, false, false)]
+    #[case("ignore", true, true, false)]
+    #[case("rust,ignore", true, true, false)]
+    #[case("ignore (needs a database)", true, true, true)]
+    #[case("ignore ()", true, true, false)]
+    #[case("ignore,edition2021 (pseudo-code)", true, true, true)]
+    #[case("compile_fail,E0277", true, false, false)]
+    #[case("ignore-windows", true, false, false)]
+    #[case("{.rust} ignore", true, true, false)]
+    #[case("text", false, false, false)]
+    #[case("toml,ignore", false, true, false)]
+    fn info_strings_describe_the_example(
+        #[case] info: &str,
+        #[case] rust: bool,
+        #[case] ignored: bool,
+        #[case] reason: bool,
+    ) {
+        assert_eq!(
+            DocExample::from_info(info),
+            DocExample {
+                rust,
+                ignored,
+                reason
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("ignore", true)]
+    #[case("ignore (illustrative)", false)]
+    #[case("no_run", false)]
+    #[case("sh,ignore", false)]
+    fn only_unexplained_rust_ignores_lack_a_reason(#[case] info: &str, #[case] lacks: bool) {
+        assert_eq!(DocExample::from_info(info).lacks_reason(), lacks);
+    }
+
+    #[rstest]
+    fn scanner_reports_opening_fences_only() {
+        let mut scanner = FenceScanner::default();
+        let opened: Vec<String> = [
+            " Loads the file.",
+            " ```ignore",
+            " let config = load()?;",
+            " ```",
+            " ~~~~text",
+            " ```ignore",
+            " ~~~~",
+        ]
+        .into_iter()
+        .filter_map(|line| scanner.scan(line))
+        .map(|fence| fence.info)
+        .collect();
+
+        assert_eq!(opened, ["ignore", "text"]);
+    }
+
+    #[rstest]
+    fn inline_code_does_not_open_a_fence() {
+        let mut scanner = FenceScanner::default();
+
+        assert_eq!(scanner.scan(" ```ignore` inline"), None);
+        assert_eq!(scanner.scan(" ``not a fence``"), None);
+    }
+
+    #[rstest]
+    #[case(IgnoredShare { ignored: 3, total: 10 }, 30, false)]
+    #[case(IgnoredShare { ignored: 4, total: 10 }, 30, true)]
+    #[case(IgnoredShare { ignored: 0, total: 0 }, 0, false)]
+    fn share_exceeds_the_limit_strictly(
+        #[case] share: IgnoredShare,
+        #[case] limit: u8,
+        #[case] exceeds: bool,
+    ) {
+        assert_eq!(share.exceeds(limit), exceeds);
+    }
+
+    #[rstest]
+    fn share_counts_rust_examples_only() {
+        let mut share = IgnoredShare::default();
+        for info in ["ignore", "ignore (reason)", "", "text", "toml,ignore"] {
+            share.record(DocExample::from_info(info));
+        }
+
+        assert_eq!(
+            share,
+            IgnoredShare {
+                ignored: 2,
+                total: 3
+            }
+        );
+        assert_eq!(share.percent(), 66);
+    }
+}
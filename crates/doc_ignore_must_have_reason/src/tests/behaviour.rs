@@ -0,0 +1,86 @@
+//! Behaviour-driven coverage for the ignored doc example policy.
+
+use crate::policy::{FenceScanner, IgnoredShare};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct DocWorld {
+    lines: RefCell<Vec<String>>,
+    limit: Cell<Option<u8>>,
+    unexplained: Cell<usize>,
+    share: Cell<IgnoredShare>,
+}
+
+#[fixture]
+fn world() -> DocWorld {
+    DocWorld::default()
+}
+
+#[given("a doc example fenced with {info}")]
+fn given_example(world: &DocWorld, info: String) {
+    let mut lines = world.lines.borrow_mut();
+    lines.push(format!(" ```{}", info.trim_matches('"')));
+    lines.push(String::from(" let value = 1;"));
+    lines.push(String::from(" ```"));
+}
+
+#[given("a limit of {limit} percent")]
+fn given_limit(world: &DocWorld, limit: u8) {
+    world.limit.set(Some(limit));
+}
+
+#[when("the documentation is scanned")]
+fn when_scanned(world: &DocWorld) {
+    let mut scanner = FenceScanner::default();
+    let mut share = IgnoredShare::default();
+    let mut unexplained = 0;
+    for fence in world
+        .lines
+        .borrow()
+        .iter()
+        .filter_map(|line| scanner.scan(line))
+    {
+        share.record(fence.example);
+        unexplained += usize::from(fence.example.lacks_reason());
+    }
+    world.share.set(share);
+    world.unexplained.set(unexplained);
+}
+
+#[then("the example is reported")]
+fn then_reported(world: &DocWorld) {
+    assert_eq!(world.unexplained.get(), 1);
+}
+
+#[then("the example is allowed")]
+fn then_allowed(world: &DocWorld) {
+    assert_eq!(world.unexplained.get(), 0);
+}
+
+#[then("the crate is reported")]
+fn then_crate_reported(world: &DocWorld) {
+    let limit = world.limit.get().expect("scenario should set a limit");
+    assert!(world.share.get().exceeds(limit));
+}
+
+#[scenario(path = "tests/features/doc_ignore_must_have_reason.feature", index = 0)]
+fn scenario_missing_reason(world: DocWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/doc_ignore_must_have_reason.feature", index = 1)]
+fn scenario_reason_given(world: DocWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/doc_ignore_must_have_reason.feature", index = 2)]
+fn scenario_other_language(world: DocWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/doc_ignore_must_have_reason.feature", index = 3)]
+fn scenario_crate_share(world: DocWorld) {
+    let _ = world;
+}
@@ -0,0 +1,25 @@
+Feature: Ignored doc examples need a reason
+  Rust doc examples marked `ignore` are reported unless the fence line gives
+  a reason in parentheses, and crates can cap the share of ignored examples.
+
+  Scenario: An ignored example without a reason is reported
+    Given a doc example fenced with "ignore"
+    When the documentation is scanned
+    Then the example is reported
+
+  Scenario: An ignored example with a reason is allowed
+    Given a doc example fenced with "ignore (needs a running database)"
+    When the documentation is scanned
+    Then the example is allowed
+
+  Scenario: Examples in other languages are allowed
+    Given a doc example fenced with "toml,ignore"
+    When the documentation is scanned
+    Then the example is allowed
+
+  Scenario: A crate ignoring too many examples is reported
+    Given a doc example fenced with "ignore (illustrative)"
+    And a doc example fenced with "no_run"
+    And a limit of 40 percent
+    When the documentation is scanned
+    Then the crate is reported
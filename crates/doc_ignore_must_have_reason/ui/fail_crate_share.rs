@@ -0,0 +1,30 @@
+//! A crate ignoring more of its doc examples than the configured share.
+
+/// Opens the ledger.
+///
+/// ```ignore (needs a running database)
+/// let ledger = open("postgres://localhost/ledger")?;
+/// ```
+fn open() {}
+
+/// Closes the ledger.
+///
+/// ```ignore (needs a running database)
+/// close(ledger)?;
+/// ```
+fn close() {}
+
+/// Counts entries.
+///
+/// ```
+/// assert_eq!(2 + 2, 4);
+/// ```
+fn count() -> usize {
+    0
+}
+
+fn main() {
+    open();
+    close();
+    let _ = count();
+}
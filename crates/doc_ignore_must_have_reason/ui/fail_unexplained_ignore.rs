@@ -0,0 +1,36 @@
+//! Ignored doc examples whose fence line gives no reason.
+//!
+//! ```ignore
+//! let policy = Policy::new(3);
+//! ```
+
+/// Returns `true` when `depth` exceeds the limit.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert!(exceeds(4, 3));
+/// ```
+fn exceeds(depth: usize, limit: usize) -> bool {
+    depth > limit
+}
+
+struct Scanner;
+
+impl Scanner {
+    /// Scans one line.
+    ///
+    /// ```rust,ignore
+    /// let mut scanner = Scanner;
+    /// scanner.scan("```ignore");
+    /// ```
+    fn scan(&mut self, line: &str) -> bool {
+        line.starts_with("```")
+    }
+}
+
+fn main() {
+    let mut scanner = Scanner;
+    let _ = scanner.scan("text");
+    let _ = exceeds(4, 3);
+}
@@ -0,0 +1,35 @@
+//! Ignored doc examples that say why, and examples that are not ignored.
+//!
+//! ```ignore (needs the compiler's private crates)
+//! let ty = cx.typeck_results().expr_ty(expr);
+//! ```
+
+/// Returns `true` when `depth` exceeds the limit.
+///
+/// ```
+/// assert!(4 > 3);
+/// ```
+///
+/// ```ignore,edition2021 (illustrative)
+/// assert!(exceeds(4, 3));
+/// ```
+///
+/// ```no_run
+/// std::process::exit(0);
+/// ```
+///
+/// ```toml,ignore
+/// [my_lint]
+/// max_depth = 3
+/// ```
+///
+/// ```text
+/// ```ignore
+/// ```
+fn exceeds(depth: usize, limit: usize) -> bool {
+    depth > limit
+}
+
+fn main() {
+    let _ = exceeds(4, 3);
+}
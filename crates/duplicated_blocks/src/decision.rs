@@ -0,0 +1,132 @@
+//! Pure decision logic for `duplicated_blocks`.
+//!
+//! The driver lowers every block of every eligible function body in the
+//! crate to a [`NormalizedBlock`] owned by its function.
+//! [`DuplicatedBlocksDecision`] hands the same blocks to
+//! [`find_duplicated_blocks`] and reports the first repeated run, naming its
+//! similarity as a percentage as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::duplicated_blocks::{
+    DuplicationThresholds, NormalizedBlock, NormalizedStatement, find_duplicated_blocks,
+};
+
+use crate::driver::LINT_NAME;
+
+/// Reason key for runs of statements repeated in another function.
+const DUPLICATED_BLOCK: &str = "duplicated_block";
+
+/// The facts about one crate that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CrateBlocksProjection {
+    /// The normalised blocks of the crate's eligible functions, with owners
+    /// numbered in collection order.
+    pub blocks: Vec<NormalizedBlock>,
+}
+
+/// Reports runs of statements that two functions repeat.
+///
+/// # Examples
+///
+/// ```ignore
+/// let body = || vec![NormalizedStatement::new(["stmt:semi", "expr:call"]); 4];
+/// let krate = CrateBlocksProjection {
+///     blocks: vec![NormalizedBlock::new(0, body()), NormalizedBlock::new(1, body())],
+/// };
+/// let decision = DuplicatedBlocksDecision::new(4, 8, 0.9);
+/// assert!(decision.decide(&krate).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DuplicatedBlocksDecision {
+    thresholds: DuplicationThresholds,
+}
+
+impl DuplicatedBlocksDecision {
+    /// Creates a decision reporting runs of at least `min_statements`
+    /// statements and `min_tokens` tokens that are at least `similarity`
+    /// alike.
+    #[must_use]
+    pub fn new(min_statements: usize, min_tokens: usize, similarity: f64) -> Self {
+        Self {
+            thresholds: DuplicationThresholds::new(min_statements, min_tokens, similarity),
+        }
+    }
+}
+
+impl LintDecision for DuplicatedBlocksDecision {
+    type Projection = CrateBlocksProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, krate: &CrateBlocksProjection) -> Option<Finding> {
+        let duplicate = find_duplicated_blocks(&krate.blocks, self.thresholds)
+            .into_iter()
+            .next()?;
+        let percent = format!("{}%", duplicate.similarity_percent());
+        Some(Finding::new(LINT_NAME, DUPLICATED_BLOCK).with_subject(percent))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<CrateBlocksProjection>> {
+        let statements = self.thresholds.min_statements();
+        let per_statement = self.thresholds.min_tokens().div_ceil(statements).max(4);
+        let body = |statements: usize, method: &str| -> Vec<NormalizedStatement> {
+            (0..statements)
+                .map(|index| {
+                    let call = format!("method:{method}{index}");
+                    let local = format!("local:{method}");
+                    let mut tokens = vec!["stmt:semi", call.as_str()];
+                    tokens.resize(per_statement, local.as_str());
+                    NormalizedStatement::new(tokens)
+                })
+                .collect()
+        };
+        let krate = |blocks| CrateBlocksProjection { blocks };
+        vec![
+            DecisionFixture::flagged(
+                "copied between functions",
+                krate(vec![
+                    NormalizedBlock::new(0, body(statements, "save")),
+                    NormalizedBlock::new(1, body(statements, "save")),
+                ]),
+                DUPLICATED_BLOCK,
+            ),
+            DecisionFixture::clean(
+                "repeated within one function",
+                krate(vec![
+                    NormalizedBlock::new(0, body(statements, "save")),
+                    NormalizedBlock::new(0, body(statements, "save")),
+                ]),
+            ),
+            DecisionFixture::clean(
+                "different calls",
+                krate(vec![
+                    NormalizedBlock::new(0, body(statements, "save")),
+                    NormalizedBlock::new(1, body(statements, "load")),
+                ]),
+            ),
+            DecisionFixture::clean(
+                "run too short",
+                krate(vec![
+                    NormalizedBlock::new(0, body(statements - 1, "save")),
+                    NormalizedBlock::new(1, body(statements - 1, "save")),
+                ]),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::defaults(DuplicatedBlocksDecision::default())]
+    #[case::short_runs(DuplicatedBlocksDecision::new(2, 10, 0.8))]
+    fn decision_conforms(#[case] decision: DuplicatedBlocksDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
@@ -0,0 +1,54 @@
+//! Embedded documentation for the `duplicated_blocks` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `duplicated_blocks`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "duplicated_blocks",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags pairs of functions that contain near-identical runs of statements.",
+    rationale: "Copied blocks drift apart: a fix lands in one copy and not the other. Statements are compared after local names and literal values are erased, so renaming a variable or changing a constant does not hide a copy; extracting the shared steps into one function keeps them in step.",
+    bad_example: concat!(
+        "fn export_csv(rows: &[Row], path: &Path) -> io::Result<()> {\n",
+        "    let mut file = File::create(path)?;\n",
+        "    let header = rows.first().map(Row::header);\n",
+        "    file.write_all(render(header, rows, \",\").as_bytes())?;\n",
+        "    file.sync_all()\n",
+        "}\n",
+        "\n",
+        "fn export_tsv(rows: &[Row], target: &Path) -> io::Result<()> {\n",
+        "    let mut out = File::create(target)?;\n",
+        "    let header = rows.first().map(Row::header);\n",
+        "    out.write_all(render(header, rows, \"\\t\").as_bytes())?;\n",
+        "    out.sync_all()\n",
+        "}",
+    ),
+    good_example: concat!(
+        "fn export(rows: &[Row], path: &Path, separator: &str) -> io::Result<()> {\n",
+        "    let mut file = File::create(path)?;\n",
+        "    let header = rows.first().map(Row::header);\n",
+        "    file.write_all(render(header, rows, separator).as_bytes())?;\n",
+        "    file.sync_all()\n",
+        "}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "min_statements",
+            default: "4",
+            description: "Number of consecutive statements in a compared block.",
+        },
+        ConfigKeyDocs {
+            key: "min_tokens",
+            default: "30",
+            description: "Minimum number of normalised tokens a block must contain.",
+        },
+        ConfigKeyDocs {
+            key: "similarity",
+            default: "0.9",
+            description: "Minimum token similarity, from 0.5 to 1.0, for two blocks to count as duplicates.",
+        },
+    ],
+};
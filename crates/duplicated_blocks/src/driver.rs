@@ -0,0 +1,300 @@
+//! Detect near-identical blocks of statements shared by two functions.
+//!
+//! The lint runs once per crate. During `check_crate` it lowers every block
+//! in the bodies of free functions, methods, and default trait methods to
+//! normalised statement tokens (see [`crate::normalize`]), then asks
+//! [`whitaker_common::duplicated_blocks`] for runs of at least
+//! `min_statements` statements and `min_tokens` tokens whose token similarity
+//! reaches `similarity`. Each pair of functions is reported once, at the
+//! repeated run in the later function, with a note pointing at the earlier
+//! copy.
+//!
+//! Macro-generated functions and test code are not collected.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def_id::LocalDefId;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::{Ident, Span};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker::hir::TestContext;
+use whitaker_common::CrateScopedState;
+use whitaker_common::duplicated_blocks::{
+    DuplicatedBlock, DuplicationThresholds, NormalizedBlock, StatementRange, find_duplicated_blocks,
+};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::normalize::lower_body;
+
+pub(crate) const LINT_NAME: &str = "duplicated_blocks";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    min_statements: usize,
+    min_tokens: usize,
+    similarity: f64,
+}
+
+impl Config {
+    fn thresholds(&self) -> DuplicationThresholds {
+        DuplicationThresholds::new(self.min_statements, self.min_tokens, self.similarity)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_statements: 4,
+            min_tokens: 30,
+            similarity: 0.9,
+        }
+    }
+}
+
+/// Lint pass reporting functions that repeat each other's statements.
+#[derive(Default)]
+pub struct DuplicatedBlocks {
+    localizer: CrateScopedState<Localizer>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub DUPLICATED_BLOCKS,
+    Warn,
+    "near-identical blocks of statements should be extracted into a shared function",
+    DuplicatedBlocks::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for DuplicatedBlocks {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let thresholds = load_configuration().thresholds();
+        let shared_config = SharedConfig::load();
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        let findings = FindingLimit::new(DUPLICATED_BLOCKS, &shared_config);
+
+        let (sources, blocks): (Vec<BlockSource>, Vec<NormalizedBlock>) =
+            collect_blocks(cx).into_iter().unzip();
+        for duplicate in find_duplicated_blocks(&blocks, thresholds) {
+            debug!(
+                target: LINT_NAME,
+                "{} statements repeated at {:.2} similarity",
+                duplicate.duplicate.len,
+                duplicate.similarity,
+            );
+            let site = sources[duplicate.duplicate.block].site;
+            let span = range_span(&sources, duplicate.duplicate);
+            if findings.admit(cx, site.hir_id, span) {
+                emit_diagnostic(cx, &duplicate, &sources, &self.localizer);
+            }
+        }
+        findings.summarise(cx, &self.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Where a collected function was declared.
+#[derive(Clone, Copy, Debug)]
+struct Site {
+    hir_id: hir::HirId,
+    ident: Ident,
+}
+
+/// The function owning a lowered block and the span of each statement.
+struct BlockSource {
+    site: Site,
+    spans: Vec<Span>,
+}
+
+/// Lowers the blocks of every eligible function body in the crate.
+///
+/// Owners are numbered in collection order so the common detector can tell
+/// blocks of the same function apart from blocks of different ones.
+fn collect_blocks(cx: &LateContext<'_>) -> Vec<(BlockSource, NormalizedBlock)> {
+    let test_context = TestContext::for_crate(cx, Vec::new());
+    let items = cx.tcx.hir_crate_items(());
+
+    let free = items.free_items().filter_map(|id| {
+        let item = cx.tcx.hir_item(id);
+        match item.kind {
+            hir::ItemKind::Fn { ident, .. } => Some((item.owner_id.def_id, ident, item.span)),
+            _ => None,
+        }
+    });
+    let methods = items.impl_items().filter_map(|id| {
+        let item = cx.tcx.hir_impl_item(id);
+        matches!(item.kind, hir::ImplItemKind::Fn(..)).then_some((
+            item.owner_id.def_id,
+            item.ident,
+            item.span,
+        ))
+    });
+    let provided = items.trait_items().filter_map(|id| {
+        let item = cx.tcx.hir_trait_item(id);
+        matches!(
+            item.kind,
+            hir::TraitItemKind::Fn(_, hir::TraitFn::Provided(_))
+        )
+        .then_some((item.owner_id.def_id, item.ident, item.span))
+    });
+
+    let functions = free
+        .chain(methods)
+        .chain(provided)
+        .filter(|(_, _, span)| !span.from_expansion())
+        .filter_map(|(def_id, ident, _)| {
+            let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+            (!test_context.is_test(cx, hir_id)).then_some((def_id, Site { hir_id, ident }))
+        });
+
+    functions
+        .enumerate()
+        .flat_map(|(owner, (def_id, site))| lower_function(cx, owner, def_id, site))
+        .collect()
+}
+
+fn lower_function(
+    cx: &LateContext<'_>,
+    owner: usize,
+    def_id: LocalDefId,
+    site: Site,
+) -> Vec<(BlockSource, NormalizedBlock)> {
+    let Some(body) = cx.tcx.hir_maybe_body_owned_by(def_id) else {
+        return Vec::new();
+    };
+    lower_body(cx, body.id())
+        .into_iter()
+        .map(|block| {
+            let source = BlockSource {
+                site,
+                spans: block.spans,
+            };
+            (source, NormalizedBlock::new(owner, block.statements))
+        })
+        .collect()
+}
+
+/// Returns the span covering a run of statements.
+fn range_span(sources: &[BlockSource], range: StatementRange) -> Span {
+    let spans = &sources[range.block].spans[range.start..range.start + range.len];
+    match (spans.first(), spans.last()) {
+        (Some(first), Some(last)) => first.to(*last),
+        _ => sources[range.block].site.ident.span,
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    duplicate: &DuplicatedBlock,
+    sources: &[BlockSource],
+    localizer: &Localizer,
+) {
+    let site = sources[duplicate.duplicate.block].site;
+    let original_site = sources[duplicate.original.block].site;
+    let function = site.ident.to_string();
+    let original = original_site.ident.to_string();
+    let statements = duplicate.duplicate.len;
+    let similarity = duplicate.similarity_percent();
+
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("function"),
+        FluentValue::from(function.clone()),
+    );
+    args.insert(
+        Cow::Borrowed("original"),
+        FluentValue::from(original.clone()),
+    );
+    args.insert(Cow::Borrowed("statements"), FluentValue::from(statements));
+    args.insert(Cow::Borrowed("similarity"), FluentValue::from(similarity));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(&function, &original, statements, similarity)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let original_span = range_span(sources, duplicate.original);
+
+    cx.tcx.emit_node_span_lint(
+        DUPLICATED_BLOCKS,
+        site.hir_id,
+        range_span(sources, duplicate.duplicate),
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(original_span, note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(
+    function: &str,
+    original: &str,
+    statements: usize,
+    similarity: u8,
+) -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        format!("`{function}` repeats {statements} statements from `{original}`."),
+        format!("The block in `{original}` is {similarity}% similar."),
+        "Extract the shared statements into a helper function that both can call.".to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_matches_common_defaults() {
+        assert_eq!(
+            Config::default().thresholds(),
+            DuplicationThresholds::default()
+        );
+    }
+
+    #[rstest]
+    fn fallback_messages_name_both_functions() {
+        let messages = fallback_messages("export_csv", "export_json", 5, 94);
+
+        assert!(
+            messages
+                .primary()
+                .contains("`export_csv` repeats 5 statements from `export_json`")
+        );
+        assert!(messages.note().contains("94% similar"));
+        assert!(messages.help().contains("helper function"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
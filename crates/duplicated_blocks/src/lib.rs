@@ -0,0 +1,40 @@
+//! Lint crate flagging near-identical blocks of statements in different
+//! functions.
+//!
+//! Copy-pasted code drifts: a fix lands in one copy and not the other. The
+//! lint normalises the statements of every function body in the crate,
+//! erasing local names and literal values, and reports each pair of functions
+//! that share a run of near-identical statements. It is experimental and
+//! ships behind the `experimental-duplicated-blocks` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod normalize;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{CrateBlocksProjection, DuplicatedBlocksDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn duplicated_blocks_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
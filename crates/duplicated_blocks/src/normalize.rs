@@ -0,0 +1,215 @@
+//! Lowering of HIR function bodies to normalised statement tokens.
+//!
+//! Every block in a body, including blocks nested in expressions and
+//! closures, becomes a list of [`NormalizedStatement`]s. Tokens record the
+//! kinds of statements, expressions, and patterns in traversal order, along
+//! with method names, field names, operators, and the definitions that paths
+//! resolve to. Local variables collapse to a single `local` token and
+//! literals to `lit`, so renaming a binding or changing a constant does not
+//! hide a copy. Types are not tokenised.
+
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+use whitaker_common::duplicated_blocks::{BlockToken, NormalizedStatement};
+
+/// A block's normalised statements and the source span of each.
+pub(crate) struct LoweredBlock {
+    pub(crate) statements: Vec<NormalizedStatement>,
+    pub(crate) spans: Vec<Span>,
+}
+
+/// Lowers every block in a body that was written by hand.
+pub(crate) fn lower_body(cx: &LateContext<'_>, body_id: hir::BodyId) -> Vec<LoweredBlock> {
+    let mut collector = BlockCollector {
+        cx,
+        blocks: Vec::new(),
+    };
+    collector.visit_body(cx.tcx.hir_body(body_id));
+    collector.blocks
+}
+
+struct BlockCollector<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    blocks: Vec<LoweredBlock>,
+}
+
+impl<'tcx> Visitor<'tcx> for BlockCollector<'_, 'tcx> {
+    fn visit_nested_body(&mut self, body_id: hir::BodyId) {
+        visit_closure_body(self, self.cx, body_id);
+    }
+
+    fn visit_block(&mut self, block: &'tcx hir::Block<'tcx>) {
+        // Desugared and macro-generated blocks are not code the user can
+        // deduplicate.
+        if !block.span.from_expansion() {
+            self.blocks.push(lower_block(self.cx, block));
+        }
+        intravisit::walk_block(self, block);
+    }
+}
+
+fn lower_block<'tcx>(cx: &LateContext<'tcx>, block: &'tcx hir::Block<'tcx>) -> LoweredBlock {
+    let mut lowered = LoweredBlock {
+        statements: Vec::with_capacity(block.stmts.len() + 1),
+        spans: Vec::with_capacity(block.stmts.len() + 1),
+    };
+    for stmt in block.stmts {
+        let mut tokenizer = Tokenizer::new(cx);
+        tokenizer.visit_stmt(stmt);
+        lowered.statements.push(tokenizer.finish());
+        lowered.spans.push(stmt.span.source_callsite());
+    }
+    if let Some(tail) = block.expr {
+        let mut tokenizer = Tokenizer::new(cx);
+        tokenizer.push("stmt:tail");
+        tokenizer.visit_expr(tail);
+        lowered.statements.push(tokenizer.finish());
+        lowered.spans.push(tail.span.source_callsite());
+    }
+    lowered
+}
+
+/// Walks closure bodies, which belong to the enclosing function; other
+/// nested bodies, such as array length constants, are skipped.
+fn visit_closure_body<'tcx, V: Visitor<'tcx>>(
+    visitor: &mut V,
+    cx: &LateContext<'tcx>,
+    body_id: hir::BodyId,
+) {
+    let owner = cx.tcx.hir_body_owner_def_id(body_id);
+    if cx.tcx.is_closure_like(owner.to_def_id()) {
+        visitor.visit_body(cx.tcx.hir_body(body_id));
+    }
+}
+
+struct Tokenizer<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    tokens: Vec<BlockToken>,
+}
+
+impl<'a, 'tcx> Tokenizer<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>) -> Self {
+        Self {
+            cx,
+            tokens: Vec::new(),
+        }
+    }
+
+    fn finish(self) -> NormalizedStatement {
+        self.tokens.into_iter().collect()
+    }
+
+    fn push(&mut self, text: &str) {
+        self.tokens.push(BlockToken::from(text));
+    }
+
+    fn push_qpath(&mut self, qpath: &hir::QPath<'_>) {
+        match qpath {
+            hir::QPath::Resolved(_, path) => self.push_res(path.res),
+            hir::QPath::TypeRelative(_, segment) => {
+                self.push(&format!("path:<_>::{}", segment.ident));
+            }
+        }
+    }
+
+    fn push_res(&mut self, res: Res) {
+        match res {
+            Res::Local(_) => self.push("local"),
+            Res::Def(_, def_id) => {
+                let path = self.cx.tcx.def_path_str(def_id);
+                self.push(&format!("path:{path}"));
+            }
+            _ => self.push("path"),
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for Tokenizer<'_, 'tcx> {
+    fn visit_nested_body(&mut self, body_id: hir::BodyId) {
+        visit_closure_body(self, self.cx, body_id);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'tcx hir::Stmt<'tcx>) {
+        self.push(match stmt.kind {
+            hir::StmtKind::Let(_) => "stmt:let",
+            hir::StmtKind::Item(_) => "stmt:item",
+            hir::StmtKind::Expr(_) => "stmt:expr",
+            hir::StmtKind::Semi(_) => "stmt:semi",
+        });
+        intravisit::walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        match expr.kind {
+            hir::ExprKind::Path(ref qpath) => self.push_qpath(qpath),
+            hir::ExprKind::Lit(_) => self.push("lit"),
+            hir::ExprKind::MethodCall(segment, ..) => {
+                self.push("expr:method");
+                self.push(&format!("method:{}", segment.ident));
+            }
+            hir::ExprKind::Field(_, ident) => {
+                self.push("expr:field");
+                self.push(&format!("field:{ident}"));
+            }
+            hir::ExprKind::Binary(op, ..) => {
+                self.push("expr:binary");
+                self.push(op.node.as_str());
+            }
+            hir::ExprKind::AssignOp(op, ..) => {
+                self.push("expr:assign_op");
+                self.push(op.node.as_str());
+            }
+            hir::ExprKind::Unary(op, _) => {
+                self.push("expr:unary");
+                self.push(op.as_str());
+            }
+            hir::ExprKind::Struct(qpath, ..) => {
+                self.push("expr:struct");
+                self.push_qpath(qpath);
+            }
+            ref kind => self.push(expr_kind_name(kind)),
+        }
+        intravisit::walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx hir::Pat<'tcx>) {
+        match pat.kind {
+            hir::PatKind::Struct(ref qpath, ..) | hir::PatKind::TupleStruct(ref qpath, ..) => {
+                self.push("pat:struct");
+                self.push_qpath(qpath);
+            }
+            hir::PatKind::Binding(..) => self.push("pat:binding"),
+            hir::PatKind::Wild => self.push("pat:wild"),
+            hir::PatKind::Tuple(..) => self.push("pat:tuple"),
+            hir::PatKind::Ref(..) => self.push("pat:ref"),
+            _ => self.push("pat:other"),
+        }
+        intravisit::walk_pat(self, pat);
+    }
+}
+
+/// Names the expression kinds that carry no further token of their own.
+fn expr_kind_name(kind: &hir::ExprKind<'_>) -> &'static str {
+    match kind {
+        hir::ExprKind::Call(..) => "expr:call",
+        hir::ExprKind::Cast(..) => "expr:cast",
+        hir::ExprKind::If(..) => "expr:if",
+        hir::ExprKind::Loop(..) => "expr:loop",
+        hir::ExprKind::Match(..) => "expr:match",
+        hir::ExprKind::Closure(..) => "expr:closure",
+        hir::ExprKind::Block(..) => "expr:block",
+        hir::ExprKind::Assign(..) => "expr:assign",
+        hir::ExprKind::Index(..) => "expr:index",
+        hir::ExprKind::AddrOf(..) => "expr:ref",
+        hir::ExprKind::Break(..) => "expr:break",
+        hir::ExprKind::Continue(..) => "expr:continue",
+        hir::ExprKind::Ret(..) => "expr:return",
+        hir::ExprKind::Array(..) => "expr:array",
+        hir::ExprKind::Tup(..) => "expr:tuple",
+        hir::ExprKind::Let(..) => "expr:let",
+        _ => "expr:other",
+    }
+}
@@ -0,0 +1,87 @@
+//! Behaviour-driven coverage for the duplicated block configuration.
+
+use super::Config;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::duplicated_blocks::{
+    NormalizedBlock, NormalizedStatement, find_duplicated_blocks,
+};
+
+struct DuplicationWorld {
+    blocks: RefCell<Vec<NormalizedBlock>>,
+    config: RefCell<Config>,
+    reported: Cell<Option<usize>>,
+}
+
+#[fixture]
+fn world() -> DuplicationWorld {
+    DuplicationWorld {
+        blocks: RefCell::new(Vec::new()),
+        config: RefCell::new(Config::default()),
+        reported: Cell::new(None),
+    }
+}
+
+/// A four-statement export routine of 30 tokens.
+fn export_body() -> Vec<NormalizedStatement> {
+    [
+        "stmt:let expr:call path:std::fs::File::create local expr:ref local pat:binding",
+        "stmt:let expr:method method:serialize local local pat:binding lit lit",
+        "stmt:semi expr:method method:write_all local expr:method method:as_bytes local",
+        "stmt:tail expr:call path:std::result::Result::Ok expr:tuple lit lit lit lit",
+    ]
+    .into_iter()
+    .map(|statement| NormalizedStatement::new(statement.split_whitespace()))
+    .collect()
+}
+
+#[given("two functions with the same four-statement body")]
+fn given_two_functions(world: &DuplicationWorld) {
+    world.blocks.replace(vec![
+        NormalizedBlock::new(0, export_body()),
+        NormalizedBlock::new(1, export_body()),
+    ]);
+}
+
+#[given("min_statements is {value}")]
+fn given_min_statements(world: &DuplicationWorld, value: usize) {
+    world.config.borrow_mut().min_statements = value;
+}
+
+#[given("min_tokens is {value}")]
+fn given_min_tokens(world: &DuplicationWorld, value: usize) {
+    world.config.borrow_mut().min_tokens = value;
+}
+
+#[when("duplicated blocks are detected")]
+fn when_detected(world: &DuplicationWorld) {
+    let thresholds = world.config.borrow().thresholds();
+    let duplicates = find_duplicated_blocks(&world.blocks.borrow(), thresholds);
+    world.reported.set(Some(duplicates.len()));
+}
+
+#[then("{count} duplicate is reported")]
+fn then_duplicate_reported(world: &DuplicationWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[then("{count} duplicates are reported")]
+fn then_duplicates_reported(world: &DuplicationWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 0)]
+fn scenario_default_reports_copy(world: DuplicationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 1)]
+fn scenario_longer_window(world: DuplicationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/duplicated_blocks.feature", index = 2)]
+fn scenario_more_tokens(world: DuplicationWorld) {
+    let _ = world;
+}
@@ -0,0 +1,20 @@
+Feature: Duplicated block configuration
+  The lint's configuration controls how many statements and tokens a
+  repeated block must span before it is reported.
+
+  Scenario: Default settings report a copied four-statement body
+    Given two functions with the same four-statement body
+    When duplicated blocks are detected
+    Then 1 duplicate is reported
+
+  Scenario: Raising the statement threshold ignores shorter copies
+    Given two functions with the same four-statement body
+    And min_statements is 5
+    When duplicated blocks are detected
+    Then 0 duplicates are reported
+
+  Scenario: Raising the token threshold ignores smaller copies
+    Given two functions with the same four-statement body
+    And min_tokens is 40
+    When duplicated blocks are detected
+    Then 0 duplicates are reported
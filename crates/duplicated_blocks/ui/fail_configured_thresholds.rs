@@ -0,0 +1,18 @@
+//! Three-statement copies fall below the default window of four statements
+//! and are only reported once the thresholds are lowered.
+
+fn load(path: &str) -> usize {
+    let trimmed = path.trim();
+    let name = trimmed.to_lowercase();
+    name.len()
+}
+
+fn load_backup(backup: &str) -> usize {
+    let cleaned = backup.trim();
+    let lowered = cleaned.to_lowercase();
+    lowered.len()
+}
+
+fn main() {
+    let _ = load("a") + load_backup("b");
+}
@@ -0,0 +1,39 @@
+//! A report routine copied into a second function with renamed locals and
+//! different literals is reported once, at the copy.
+
+struct Report {
+    lines: Vec<String>,
+    total: u32,
+}
+
+fn summarise(values: &[u32]) -> Report {
+    let mut report = Report {
+        lines: Vec::new(),
+        total: 0,
+    };
+    for value in values {
+        report.total += value;
+        report.lines.push(format!("value: {value}"));
+    }
+    let average = report.total / values.len().max(1) as u32;
+    report.lines.push(format!("average: {average}"));
+    report
+}
+
+fn summarise_scaled(samples: &[u32]) -> Report {
+    let mut scaled = Report {
+        lines: Vec::new(),
+        total: 10,
+    };
+    for sample in samples {
+        scaled.total += sample;
+        scaled.lines.push(format!("sample: {sample}"));
+    }
+    let mean = scaled.total / samples.len().max(1) as u32;
+    scaled.lines.push(format!("mean: {mean}"));
+    scaled
+}
+
+fn main() {
+    let _ = summarise(&[1, 2, 3]).lines.len() + summarise_scaled(&[4, 5]).lines.len();
+}
@@ -0,0 +1,32 @@
+//! Functions with different statements, and repetition within a single
+//! function, are not reported.
+
+fn parse(input: &str) -> Vec<u32> {
+    let trimmed = input.trim();
+    let parts = trimmed.split(',');
+    let numbers = parts.filter_map(|part| part.parse().ok());
+    numbers.collect()
+}
+
+fn render(values: &[u32]) -> String {
+    if values.is_empty() {
+        return String::new();
+    }
+    let strings: Vec<String> = values.iter().map(u32::to_string).collect();
+    let joined = strings.join(", ");
+    format!("[{joined}]")
+}
+
+fn twice(input: &str) -> usize {
+    let first = input.trim();
+    let parts = first.split(',');
+    let count = parts.count();
+    let second = input.trim();
+    let parts = second.split(',');
+    let again = parts.count();
+    count + again
+}
+
+fn main() {
+    let _ = render(&parse("1, 2")).len() + twice("a,b");
+}
@@ -0,0 +1,93 @@
+//! Pure decision logic for `error_context_missing_on_io_operations`.
+//!
+//! The driver finds each `?` whose operand is a call returning an I/O error
+//! and resolves the error type the `?` converts into.
+//! [`ErrorContextMissingOnIoOperationsDecision`] reports the `?` when that
+//! type is one of the [`ContextErrorTypes`], naming the operation as the
+//! finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::ContextErrorTypes;
+
+/// Reason key for `?` converting an I/O error without context.
+const MISSING_CONTEXT: &str = "missing_context";
+
+/// The facts about one `?` on an I/O call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IoTryProjection {
+    /// The called function, such as `std::fs::read_to_string`.
+    pub operation: String,
+    /// The fully qualified error type the `?` converts into.
+    pub error: String,
+}
+
+/// Reports `?` that converts an I/O error into a context-carrying error type
+/// without adding context.
+///
+/// # Examples
+///
+/// ```ignore
+/// let operator = IoTryProjection {
+///     operation: "std::fs::read_to_string".to_owned(),
+///     error: "anyhow::Error".to_owned(),
+/// };
+/// assert!(ErrorContextMissingOnIoOperationsDecision::default().decide(&operator).is_some());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContextMissingOnIoOperationsDecision {
+    error_types: ContextErrorTypes,
+}
+
+impl ErrorContextMissingOnIoOperationsDecision {
+    /// Creates a decision checking conversions into `error_types`, given by
+    /// fully qualified path.
+    #[must_use]
+    pub fn new(error_types: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            error_types: ContextErrorTypes::new(error_types),
+        }
+    }
+}
+
+impl LintDecision for ErrorContextMissingOnIoOperationsDecision {
+    type Projection = IoTryProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, operator: &IoTryProjection) -> Option<Finding> {
+        self.error_types.contains(&operator.error).then(|| {
+            Finding::new(LINT_NAME, MISSING_CONTEXT).with_subject(operator.operation.as_str())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<IoTryProjection>> {
+        let operator = |error: &str| IoTryProjection {
+            operation: "std::fs::read_to_string".to_owned(),
+            error: error.to_owned(),
+        };
+        vec![
+            DecisionFixture::flagged("into anyhow", operator("anyhow::Error"), MISSING_CONTEXT),
+            DecisionFixture::flagged("into eyre", operator("eyre::Report"), MISSING_CONTEXT),
+            DecisionFixture::clean("into the I/O error", operator("std::io::Error")),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&ErrorContextMissingOnIoOperationsDecision::default()),
+            Ok(3)
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Embedded documentation for the `error_context_missing_on_io_operations` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `error_context_missing_on_io_operations`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "error_context_missing_on_io_operations",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `?` applied directly to a call returning `std::io::Result` when the enclosing function returns a rich error type such as `anyhow::Error`.",
+    rationale: "A `std::io::Error` carries the operating system's message but not the path or the operation that failed, so the report reads \"No such file or directory\" with no hint of which file. A rich error type can record that detail if the code attaches it before `?`.",
+    bad_example: "let text = fs::read_to_string(&path)?;",
+    good_example: "let text = fs::read_to_string(&path)\n    .with_context(|| format!(\"reading {}\", path.display()))?;",
+    config: &[ConfigKeyDocs {
+        key: "error_types",
+        default: "[\"anyhow::Error\", \"eyre::Report\"]",
+        description: "Fully qualified paths of error types that offer context methods; `?` converting an I/O error into one of them is reported.",
+    }],
+};
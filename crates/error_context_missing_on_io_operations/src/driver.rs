@@ -0,0 +1,325 @@
+//! Lint pass reporting `?` that propagates I/O errors without context.
+//!
+//! The pass looks at each `?` desugaring. Its operand must be a function or
+//! method call whose type is `Result<_, std::io::Error>`, and the value the
+//! desugaring returns on error must be a `Result` whose error type is one of
+//! [`ContextErrorTypes`]. Reading the error type from the desugared `return`
+//! covers functions, closures, and async blocks alike. Calls already wrapped
+//! in `.with_context(...)` or `.map_err(...)` no longer return an I/O error,
+//! so they are accepted. Code written by macros is skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{Span, sym};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::ContextErrorTypes;
+
+pub(crate) const LINT_NAME: &str = "error_context_missing_on_io_operations";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const IO_ERROR: &str = "std::io::Error";
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    error_types: Option<Vec<String>>,
+}
+
+impl Config {
+    fn error_types(self) -> ContextErrorTypes {
+        self.error_types
+            .map_or_else(ContextErrorTypes::default, ContextErrorTypes::new)
+    }
+}
+
+/// Lint pass that checks `?` on I/O calls for missing context.
+#[derive(Default)]
+pub struct ErrorContextMissingOnIoOperations {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Context-capable error types, finding limit, and localization for the
+/// crate being checked.
+#[derive(Default)]
+struct CrateState {
+    error_types: ContextErrorTypes,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub ERROR_CONTEXT_MISSING_ON_IO_OPERATIONS,
+    Warn,
+    "I/O errors should gain context before `?` converts them into a rich error type",
+    ErrorContextMissingOnIoOperations::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for ErrorContextMissingOnIoOperations {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            error_types: load_configuration().error_types(),
+            findings: FindingLimit::new(ERROR_CONTEXT_MISSING_ON_IO_OPERATIONS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::Match(scrutinee, arms, hir::MatchSource::TryDesugar(_)) = expr.kind
+        else {
+            return;
+        };
+        if is_macro_output(expr.span) {
+            return;
+        }
+        let hir::ExprKind::Call(_, [operand]) = scrutinee.kind else {
+            return;
+        };
+        let Some(operation) = io_operation(cx, operand) else {
+            return;
+        };
+        let Some(error) = residual_error(cx, arms) else {
+            return;
+        };
+        let error = qualified_path(cx, error);
+        if !self.state.error_types.contains(&error) {
+            return;
+        }
+
+        debug!(target: LINT_NAME, "`?` converts an I/O error from `{operation}` into `{error}`");
+        if self.state.findings.admit(cx, expr.hir_id, expr.span) {
+            let message = ContextMessage {
+                operation: &operation,
+                error: &error,
+            };
+            emit_diagnostic(cx, expr.span, &message, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Reports whether `span` was produced by a macro rather than written by hand.
+fn is_macro_output(span: Span) -> bool {
+    span.macro_backtrace()
+        .any(|expn| matches!(expn.kind, rustc_span::ExpnKind::Macro(..)))
+}
+
+/// Returns the path of the function or method `operand` calls when the call
+/// returns `Result<_, std::io::Error>`.
+fn io_operation<'tcx>(cx: &LateContext<'tcx>, operand: &'tcx hir::Expr<'tcx>) -> Option<String> {
+    let def_id = match operand.kind {
+        hir::ExprKind::Call(callee, _) => match callee.kind {
+            hir::ExprKind::Path(ref qpath) => cx.qpath_res(qpath, callee.hir_id).opt_def_id()?,
+            _ => return None,
+        },
+        hir::ExprKind::MethodCall(..) => {
+            cx.typeck_results().type_dependent_def_id(operand.hir_id)?
+        }
+        _ => return None,
+    };
+    let error = result_error(cx, cx.typeck_results().expr_ty(operand))?;
+    let is_io_error =
+        matches!(error.kind(), ty::Adt(adt, _) if cx.tcx.def_path_str(adt.did()) == IO_ERROR);
+    is_io_error.then(|| cx.tcx.def_path_str(def_id))
+}
+
+/// Returns the error type of the `Result` the `?` desugaring returns on
+/// failure, read from the `return` or `break` in its residual arm.
+fn residual_error<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [hir::Arm<'tcx>]) -> Option<DefId> {
+    arms.iter().find_map(|arm| {
+        let (hir::ExprKind::Ret(Some(residual)) | hir::ExprKind::Break(_, Some(residual))) =
+            arm.body.kind
+        else {
+            return None;
+        };
+        let error = result_error(cx, cx.typeck_results().expr_ty(residual))?;
+        match error.kind() {
+            ty::Adt(adt, _) => Some(adt.did()),
+            _ => None,
+        }
+    })
+}
+
+/// Returns `E` when `ty` is `Result<T, E>`.
+fn result_error<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    let ty::Adt(adt, args) = ty.kind() else {
+        return None;
+    };
+    cx.tcx
+        .is_diagnostic_item(sym::Result, adt.did())
+        .then(|| args.type_at(1))
+}
+
+/// Names `def_id` by its path, prefixing the crate name for local items so
+/// they match the fully qualified paths given in `error_types`.
+fn qualified_path(cx: &LateContext<'_>, def_id: DefId) -> String {
+    let path = cx.tcx.def_path_str(def_id);
+    if def_id.is_local() {
+        format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+    } else {
+        path
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &ContextMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        ERROR_CONTEXT_MISSING_ON_IO_OPERATIONS,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a context-free `?` interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ContextMessage<'a> {
+    operation: &'a str,
+    error: &'a str,
+}
+
+impl ContextMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("operation"),
+            FluentValue::from(self.operation.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("error"),
+            FluentValue::from(self.error.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { operation, error } = *self;
+        DiagnosticMessageSet::new(
+            format!("`?` passes the I/O error from `{operation}` on without context."),
+            format!(
+                "A `std::io::Error` names neither the operation nor the path that failed, and \
+                 `{error}` can record both."
+            ),
+            String::from(
+                "Attach context before `?`, for example with `.with_context(|| ...)`, naming the \
+                 path or operation.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: ContextMessage<'static> = ContextMessage {
+        operation: "std::fs::read_to_string",
+        error: "anyhow::Error",
+    };
+
+    fn resolve(message: &ContextMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_checks_anyhow_and_eyre() {
+        assert_eq!(
+            Config::default().error_types(),
+            ContextErrorTypes::default()
+        );
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_operation_and_error_type() {
+        let messages = MESSAGE.fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "`?` passes the I/O error from `std::fs::read_to_string` on without context."
+        );
+        assert!(messages.note().contains("`anyhow::Error`"));
+        assert!(messages.help().contains(".with_context"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`std::fs::read_to_string`"));
+        assert!(messages.note().contains("`anyhow::Error`"));
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
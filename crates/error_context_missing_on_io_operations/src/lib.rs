@@ -0,0 +1,43 @@
+//! Lint crate flagging `?` that propagates I/O errors without context.
+//!
+//! A `std::io::Error` carries the operating system's message, such as "No
+//! such file or directory", but not the path or the operation that failed.
+//! Functions returning a rich error type such as `anyhow::Error` can attach
+//! that detail with `.with_context(...)`, yet a bare `?` on an I/O call
+//! converts the error without it. The lint reports `?` applied directly to a
+//! call returning `std::io::Result` when the enclosing function, closure, or
+//! async block returns one of the configured error types. The lint is
+//! experimental and ships behind the
+//! `experimental-error-context-missing-on-io-operations` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ErrorContextMissingOnIoOperationsDecision, IoTryProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn error_context_missing_on_io_operations_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
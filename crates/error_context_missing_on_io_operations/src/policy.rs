@@ -0,0 +1,75 @@
+//! Error types that can carry context.
+//!
+//! The driver resolves the error type a `?` converts into and names it by its
+//! fully qualified path. [`ContextErrorTypes::contains`] then decides whether
+//! that type offers context methods, keeping the list free of compiler types
+//! so it can be tested directly.
+
+use whitaker_common::SimplePath;
+
+/// Error types checked when the configuration names none.
+pub(crate) const DEFAULT_ERROR_TYPES: &[&str] = &["anyhow::Error", "eyre::Report"];
+
+/// Error types whose functions should attach context to I/O errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ContextErrorTypes {
+    paths: Vec<SimplePath>,
+}
+
+impl Default for ContextErrorTypes {
+    fn default() -> Self {
+        Self::new(DEFAULT_ERROR_TYPES.iter().map(|path| (*path).to_owned()))
+    }
+}
+
+impl ContextErrorTypes {
+    /// Builds the list from configured paths; a leading `::` is ignored.
+    #[must_use]
+    pub(crate) fn new(paths: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            paths: paths.into_iter().map(SimplePath::from).collect(),
+        }
+    }
+
+    /// Returns `true` when `error`, a fully qualified type path, names one
+    /// of the configured error types.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let types = ContextErrorTypes::default();
+    /// assert!(types.contains("anyhow::Error"));
+    /// assert!(!types.contains("std::io::Error"));
+    /// ```
+    #[must_use]
+    pub(crate) fn contains(&self, error: &str) -> bool {
+        let error = SimplePath::from(error);
+        self.paths.contains(&error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::anyhow("anyhow::Error", true)]
+    #[case::eyre("eyre::Report", true)]
+    #[case::io("std::io::Error", false)]
+    #[case::boxed("std::boxed::Box", false)]
+    fn default_error_types(#[case] error: &str, #[case] expected: bool) {
+        assert_eq!(ContextErrorTypes::default().contains(error), expected);
+    }
+
+    #[rstest]
+    #[case::configured("app::errors::AppError", true)]
+    #[case::leading_separator("::app::errors::AppError", true)]
+    #[case::unqualified("errors::AppError", false)]
+    #[case::default_replaced("anyhow::Error", false)]
+    fn configured_error_types_replace_the_defaults(#[case] error: &str, #[case] expected: bool) {
+        let types = ContextErrorTypes::new([String::from("::app::errors::AppError")]);
+
+        assert_eq!(types.contains(error), expected);
+    }
+}
@@ -0,0 +1,86 @@
+//! Behaviour-driven coverage for the context-capable error type list.
+
+use crate::policy::ContextErrorTypes;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct ErrorWorld {
+    error: RefCell<String>,
+    configured: RefCell<Option<Vec<String>>>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> ErrorWorld {
+    ErrorWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+#[given("`?` converts an I/O error into {error}")]
+fn given_conversion(world: &ErrorWorld, error: String) {
+    *world.error.borrow_mut() = unquote(&error);
+}
+
+#[given("the configured error types are {error}")]
+fn given_configured(world: &ErrorWorld, error: String) {
+    *world.configured.borrow_mut() = Some(vec![unquote(&error)]);
+}
+
+#[when("the conversion is checked")]
+fn when_checked(world: &ErrorWorld) {
+    let types = world
+        .configured
+        .borrow()
+        .clone()
+        .map_or_else(ContextErrorTypes::default, ContextErrorTypes::new);
+    world
+        .reported
+        .set(Some(types.contains(&world.error.borrow())));
+}
+
+#[then("the `?` is reported")]
+fn then_reported(world: &ErrorWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("the `?` is accepted")]
+fn then_accepted(world: &ErrorWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/error_context_missing_on_io_operations.feature",
+    index = 0
+)]
+fn scenario_anyhow(world: ErrorWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/error_context_missing_on_io_operations.feature",
+    index = 1
+)]
+fn scenario_eyre(world: ErrorWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/error_context_missing_on_io_operations.feature",
+    index = 2
+)]
+fn scenario_unconfigured_custom_error(world: ErrorWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/error_context_missing_on_io_operations.feature",
+    index = 3
+)]
+fn scenario_configured_custom_error(world: ErrorWorld) {
+    let _ = world;
+}
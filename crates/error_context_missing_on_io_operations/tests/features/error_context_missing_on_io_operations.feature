@@ -0,0 +1,24 @@
+Feature: Context for I/O errors
+  A `?` on an I/O call is reported when it converts the error into a type
+  that could have recorded which path or operation failed.
+
+  Scenario: Conversion into anyhow is reported
+    Given `?` converts an I/O error into "anyhow::Error"
+    When the conversion is checked
+    Then the `?` is reported
+
+  Scenario: Conversion into eyre is reported
+    Given `?` converts an I/O error into "eyre::Report"
+    When the conversion is checked
+    Then the `?` is reported
+
+  Scenario: Conversion into an unlisted custom error is accepted
+    Given `?` converts an I/O error into "app::errors::AppError"
+    When the conversion is checked
+    Then the `?` is accepted
+
+  Scenario: Conversion into a configured custom error is reported
+    Given `?` converts an I/O error into "app::errors::AppError"
+    And the configured error types are "app::errors::AppError"
+    When the conversion is checked
+    Then the `?` is reported
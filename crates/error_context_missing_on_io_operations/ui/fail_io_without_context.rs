@@ -0,0 +1,28 @@
+//! UI test: `?` on I/O calls that convert into a context-capable error.
+#![warn(error_context_missing_on_io_operations)]
+
+use std::fs::File;
+use std::io::Read;
+
+mod errors {
+    pub struct Report;
+
+    impl From<std::io::Error> for Report {
+        fn from(_: std::io::Error) -> Self {
+            Self
+        }
+    }
+}
+
+fn read_config(path: &str) -> Result<String, errors::Report> {
+    let mut text = String::new();
+    let mut file = File::open(path)?;
+    file.read_to_string(&mut text)?;
+    Ok(text)
+}
+
+fn main() {
+    let load = |path: &str| -> Result<Vec<u8>, errors::Report> { Ok(std::fs::read(path)?) };
+    let _ = read_config("app.toml");
+    let _ = load("app.toml");
+}
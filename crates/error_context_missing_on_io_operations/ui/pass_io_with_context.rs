@@ -0,0 +1,49 @@
+//! UI test: I/O errors with context, or not converted, are accepted.
+#![warn(error_context_missing_on_io_operations)]
+
+use std::fs::File;
+use std::io::{self, Read};
+
+mod errors {
+    pub struct Report;
+
+    impl Report {
+        pub fn new(_: &str, _: std::io::Error) -> Self {
+            Self
+        }
+    }
+
+    impl From<std::num::ParseIntError> for Report {
+        fn from(_: std::num::ParseIntError) -> Self {
+            Self
+        }
+    }
+}
+
+fn read_config(path: &str) -> Result<String, errors::Report> {
+    let mut text = String::new();
+    let mut file = File::open(path).map_err(|error| errors::Report::new(path, error))?;
+    file.read_to_string(&mut text)
+        .map_err(|error| errors::Report::new(path, error))?;
+    Ok(text)
+}
+
+fn read_port(text: &str) -> Result<u16, errors::Report> {
+    Ok(text.trim().parse::<u16>()?)
+}
+
+fn read_raw(path: &str) -> io::Result<Vec<u8>> {
+    let bytes = std::fs::read(path)?;
+    Ok(bytes)
+}
+
+fn read_boxed(path: &str) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(std::fs::read_to_string(path)?)
+}
+
+fn main() {
+    let _ = read_config("app.toml");
+    let _ = read_port("8080");
+    let _ = read_raw("app.toml");
+    let _ = read_boxed("app.toml");
+}
@@ -0,0 +1,97 @@
+//! Pure decision logic for `error_types_implement_error`.
+//!
+//! The driver describes each exported type by its name, whether a public
+//! function returns it as an error, and which of `Display` and
+//! `std::error::Error` it implements. [`ErrorTypesImplementErrorDecision`]
+//! classifies it with [`error_role`] and [`missing_impls`], as the driver
+//! does. Findings use the missing impls' Fluent selector as their reason and
+//! the type name as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{MissingImpls, error_role, missing_impls};
+
+/// The facts about one exported type that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorTypeProjection {
+    /// The type's name.
+    pub name: String,
+    /// Whether a public function returns the type in the `Err` position.
+    pub returned: bool,
+    /// Whether the type implements `Display`.
+    pub has_display: bool,
+    /// Whether the type implements `std::error::Error`.
+    pub has_error: bool,
+}
+
+/// Reports error types that lack a `Display` or `std::error::Error` impl.
+///
+/// # Examples
+///
+/// ```ignore
+/// let parse_error = ErrorTypeProjection {
+///     name: "ParseError".to_owned(),
+///     has_display: true,
+///     ..ErrorTypeProjection::default()
+/// };
+/// let finding = ErrorTypesImplementErrorDecision.decide(&parse_error);
+/// assert_eq!(finding.map(|finding| finding.reason()), Some("error"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ErrorTypesImplementErrorDecision;
+
+impl LintDecision for ErrorTypesImplementErrorDecision {
+    type Projection = ErrorTypeProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, error_type: &ErrorTypeProjection) -> Option<Finding> {
+        error_role(&error_type.name, error_type.returned)?;
+        let missing = missing_impls(error_type.has_display, error_type.has_error)?;
+        Some(Finding::new(LINT_NAME, missing.fluent_kind()).with_subject(error_type.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ErrorTypeProjection>> {
+        let error_type = |name: &str, returned, has_display, has_error| ErrorTypeProjection {
+            name: name.to_owned(),
+            returned,
+            has_display,
+            has_error,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "named error with Display only",
+                error_type("ParseError", false, true, false),
+                MissingImpls::Error.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "returned error without impls",
+                error_type("Failure", true, false, false),
+                MissingImpls::DisplayAndError.fluent_kind(),
+            ),
+            DecisionFixture::clean(
+                "error implementing Error",
+                error_type("ParseError", true, true, true),
+            ),
+            DecisionFixture::clean(
+                "type that is not an error",
+                error_type("Parser", false, false, false),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&ErrorTypesImplementErrorDecision), Ok(4));
+    }
+}
@@ -0,0 +1,29 @@
+//! Embedded documentation for the `error_types_implement_error` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, examples, and configuration for
+/// `error_types_implement_error`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "error_types_implement_error",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags public error types, named `*Error` or returned as the error of a public function, that do not implement `Display` and `std::error::Error`.",
+    rationale: "Callers expect an error type to print a message and to work with `?`, `Box<dyn Error>`, and source chains. A type missing either impl forces every caller to wrap or format it by hand.",
+    bad_example: concat!(
+        "#[derive(Debug)]\n",
+        "pub enum ParseError {\n",
+        "    Empty,\n",
+        "}",
+    ),
+    good_example: concat!(
+        "#[derive(Debug, thiserror::Error)]\n",
+        "pub enum ParseError {\n",
+        "    #[error(\"the input was empty\")]\n",
+        "    Empty,\n",
+        "}",
+    ),
+    config: &[],
+};
@@ -0,0 +1,388 @@
+//! Detect public error types that do not implement `Display` and `Error`.
+//!
+//! Before the crate is walked, the lint records every local type the crate
+//! implements `Display` or `std::error::Error` for, and the local types that
+//! exported functions return in the `Err` position of a `Result`. Each
+//! exported enum or struct is then classified by [`crate::policy`]. Impls are
+//! matched whether they are written by hand or generated by a derive macro
+//! such as `thiserror::Error`, while error types that are themselves produced
+//! by macro expansion are left to the macro's author.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty;
+use rustc_span::{Ident, Span, Symbol, sym};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{ErrorRole, MissingImpls, error_role, missing_impls};
+
+pub(crate) const LINT_NAME: &str = "error_types_implement_error";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub ERROR_TYPES_IMPLEMENT_ERROR,
+    Warn,
+    "public error types should implement `Display` and `std::error::Error` so callers can report and propagate them",
+    ErrorTypesImplementError::default()
+}
+
+/// Lint pass reporting public error types with missing impls.
+#[derive(Default)]
+pub struct ErrorTypesImplementError {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Implemented traits, returned error types, and localization for the crate
+/// being checked.
+#[derive(Default)]
+struct CrateState {
+    display_types: HashSet<DefId>,
+    error_types: HashSet<DefId>,
+    returned_by: HashMap<LocalDefId, Symbol>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ErrorTypesImplementError {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            display_types: implementing_types(cx, sym::Display),
+            error_types: implementing_types(cx, Symbol::intern("Error")),
+            returned_by: returned_error_types(cx),
+            findings: FindingLimit::new(ERROR_TYPES_IMPLEMENT_ERROR, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let (hir::ItemKind::Enum(ident, ..) | hir::ItemKind::Struct(ident, ..)) = item.kind else {
+            return;
+        };
+        let def_id = item.owner_id.def_id;
+        if item.span.from_expansion() || !cx.effective_visibilities.is_exported(def_id) {
+            return;
+        }
+
+        let state = &*self.state;
+        let function = state.returned_by.get(&def_id).copied();
+        let Some(role) = error_role(ident.as_str(), function.is_some()) else {
+            return;
+        };
+        let Some(missing) = missing_impls(
+            state.display_types.contains(&def_id.to_def_id()),
+            state.error_types.contains(&def_id.to_def_id()),
+        ) else {
+            return;
+        };
+
+        debug!(
+            target: LINT_NAME,
+            "`{ident}` is an error type missing {missing:?} ({role:?})"
+        );
+        let finding = IncompleteErrorType {
+            ident,
+            role,
+            function,
+            missing,
+        };
+        if state.findings.admit(cx, item.hir_id(), ident.span) {
+            emit_diagnostic(cx, &finding, &state.localizer);
+        }
+    }
+}
+
+/// Lists the types the local crate implements the diagnostic trait `name`
+/// for, whether the impl is hand-written or derived.
+fn implementing_types(cx: &LateContext<'_>, name: Symbol) -> HashSet<DefId> {
+    let Some(trait_def_id) = cx.tcx.get_diagnostic_item(name) else {
+        return HashSet::new();
+    };
+    cx.tcx
+        .all_local_trait_impls(())
+        .get(&trait_def_id)
+        .into_iter()
+        .flatten()
+        .filter_map(|impl_def_id| {
+            match cx
+                .tcx
+                .type_of(*impl_def_id)
+                .instantiate_identity()
+                .skip_normalization()
+                .kind()
+            {
+                ty::Adt(adt, _) => Some(adt.did()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Maps each local type an exported function returns as its `Err` type to
+/// the first such function.
+fn returned_error_types(cx: &LateContext<'_>) -> HashMap<LocalDefId, Symbol> {
+    let mut returned = HashMap::new();
+    for def_id in cx.tcx.hir_crate_items(()).definitions() {
+        if !matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || !cx.effective_visibilities.is_exported(def_id)
+        {
+            continue;
+        }
+        let output = cx
+            .tcx
+            .fn_sig(def_id)
+            .instantiate_identity()
+            .skip_normalization()
+            .skip_binder()
+            .output();
+        if let Some(error) = result_error_type(cx, output) {
+            returned
+                .entry(error)
+                .or_insert_with(|| cx.tcx.item_name(def_id.to_def_id()));
+        }
+    }
+    returned
+}
+
+/// Returns the local type `ty` carries in its `Err` position, when `ty` is a
+/// `Result`.
+fn result_error_type<'tcx>(cx: &LateContext<'tcx>, ty: ty::Ty<'tcx>) -> Option<LocalDefId> {
+    let ty::Adt(result, args) = ty.kind() else {
+        return None;
+    };
+    if !cx.tcx.is_diagnostic_item(sym::Result, result.did()) {
+        return None;
+    }
+    match args.type_at(1).kind() {
+        ty::Adt(error, _) => error.did().as_local(),
+        _ => None,
+    }
+}
+
+/// An exported error type that lacks `Display` or `std::error::Error`.
+#[derive(Clone, Copy, Debug)]
+struct IncompleteErrorType {
+    ident: Ident,
+    role: ErrorRole,
+    function: Option<Symbol>,
+    missing: MissingImpls,
+}
+
+impl IncompleteErrorType {
+    fn span(&self) -> Span {
+        self.ident.span
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, finding: &IncompleteErrorType, localizer: &Localizer) {
+    let type_name = finding.ident.to_string();
+    let function = finding
+        .function
+        .map(|function| function.to_string())
+        .unwrap_or_default();
+    let message = ErrorTypeMessage {
+        type_name: &type_name,
+        function: &function,
+        role: finding.role,
+        missing: finding.missing,
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        ERROR_TYPES_IMPLEMENT_ERROR,
+        finding.span(),
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for an incomplete error type interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ErrorTypeMessage<'a> {
+    type_name: &'a str,
+    function: &'a str,
+    role: ErrorRole,
+    missing: MissingImpls,
+}
+
+impl ErrorTypeMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("type"),
+            FluentValue::from(self.type_name.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("function"),
+            FluentValue::from(self.function.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("role"),
+            FluentValue::from(self.role.fluent_kind()),
+        );
+        args.insert(
+            Cow::Borrowed("missing"),
+            FluentValue::from(self.missing.fluent_kind()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            type_name,
+            function,
+            role,
+            missing,
+        } = *self;
+        let (absent, impls) = match missing {
+            MissingImpls::Error => (
+                String::from("`std::error::Error`"),
+                format!("`impl std::error::Error for {type_name}`"),
+            ),
+            MissingImpls::DisplayAndError => (
+                String::from("`Display` or `std::error::Error`"),
+                format!(
+                    "`impl std::fmt::Display for {type_name}` and `impl std::error::Error for \
+                     {type_name}`"
+                ),
+            ),
+        };
+        let reason = match role {
+            ErrorRole::Returned => {
+                format!("`{type_name}` is the error type of public function `{function}`")
+            }
+            ErrorRole::Named => format!("`{type_name}` is public and named as an error type"),
+        };
+        DiagnosticMessageSet::new(
+            format!("Error type `{type_name}` does not implement {absent}."),
+            format!("{reason}; it is missing {impls}."),
+            String::from(
+                "Derive the impls with `#[derive(thiserror::Error)]` and an `#[error(\"...\")]` \
+                 message, or write them by hand, so callers can print the error and use it with \
+                 `?`, `Box<dyn Error>`, and source chains.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn resolve(message: &ErrorTypeMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    #[case(
+        ErrorRole::Named,
+        MissingImpls::DisplayAndError,
+        "Error type `ParseError` does not implement `Display` or `std::error::Error`.",
+        "`ParseError` is public and named as an error type; it is missing `impl std::fmt::Display for ParseError` and `impl std::error::Error for ParseError`."
+    )]
+    #[case(
+        ErrorRole::Returned,
+        MissingImpls::Error,
+        "Error type `ParseError` does not implement `std::error::Error`.",
+        "`ParseError` is the error type of public function `parse`; it is missing `impl std::error::Error for ParseError`."
+    )]
+    fn fallback_messages_name_the_missing_impl(
+        #[case] role: ErrorRole,
+        #[case] missing: MissingImpls,
+        #[case] primary: &str,
+        #[case] note: &str,
+    ) {
+        let message = ErrorTypeMessage {
+            type_name: "ParseError",
+            function: "parse",
+            role,
+            missing,
+        };
+
+        let messages = message.fallback_messages();
+
+        assert_eq!(messages.primary(), primary);
+        assert_eq!(messages.note(), note);
+        assert!(messages.help().contains("thiserror"));
+    }
+
+    #[rstest]
+    #[case(ErrorRole::Named, MissingImpls::DisplayAndError)]
+    #[case(ErrorRole::Returned, MissingImpls::Error)]
+    fn english_messages_match_the_fallback(#[case] role: ErrorRole, #[case] missing: MissingImpls) {
+        let message = ErrorTypeMessage {
+            type_name: "ParseError",
+            function: "parse",
+            role,
+            missing,
+        };
+
+        let messages = resolve(&message, "en-GB");
+
+        assert_eq!(messages, message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let message = ErrorTypeMessage {
+            type_name: "ParseError",
+            function: "parse",
+            role: ErrorRole::Returned,
+            missing: MissingImpls::DisplayAndError,
+        };
+
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("ParseError"));
+        assert!(messages.note().contains("parse"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
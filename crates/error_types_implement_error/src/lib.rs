@@ -0,0 +1,42 @@
+//! Lint crate flagging public error types that cannot be used as errors.
+//!
+//! A public type named like an error (`ParseError`, `Error`) or returned in
+//! the `Err` position of a public function is expected to implement both
+//! `Display` and `std::error::Error`. Without them callers cannot box it as
+//! `dyn Error`, wrap it as a source, or print it for users. The lint reports
+//! such types with a note naming the missing impl. Impls generated by derive
+//! macros such as `thiserror::Error` count like hand-written ones. The lint
+//! is experimental and ships behind the
+//! `experimental-error-types-implement-error` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ErrorTypeProjection, ErrorTypesImplementErrorDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn error_types_implement_error_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
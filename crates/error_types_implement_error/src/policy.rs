@@ -0,0 +1,120 @@
+//! Classification of error types that lack `Display` or `Error` impls.
+//!
+//! A type is treated as an error type when a public function returns it in
+//! the `Err` position of a `Result`, or when it is public and its name ends in
+//! `Error`. [`missing_impls`] then reports which of the two impls callers
+//! expect are absent. `std::error::Error` requires `Display`, so a type with
+//! an `Error` impl is always complete.
+
+/// Why a type is treated as an error type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ErrorRole {
+    /// A public function returns the type as its error.
+    Returned,
+    /// The type is public and named like an error.
+    Named,
+}
+
+impl ErrorRole {
+    /// Returns the selector the Fluent messages use for this role.
+    #[must_use]
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Returned => "returned",
+            Self::Named => "named",
+        }
+    }
+}
+
+/// The impls an error type lacks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum MissingImpls {
+    /// `Display` is implemented but `std::error::Error` is not.
+    Error,
+    /// Neither `Display` nor `std::error::Error` is implemented.
+    DisplayAndError,
+}
+
+impl MissingImpls {
+    /// Returns the selector the Fluent messages use for this gap.
+    #[must_use]
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::DisplayAndError => "both",
+        }
+    }
+}
+
+/// Returns why a type is an error type, or `None` when it is not one.
+///
+/// Being returned by a public function takes precedence, because the
+/// diagnostic can then name that function.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(error_role("ParseError", false), Some(ErrorRole::Named));
+/// assert_eq!(error_role("Failure", true), Some(ErrorRole::Returned));
+/// assert_eq!(error_role("Config", false), None);
+/// ```
+#[must_use]
+pub(crate) fn error_role(name: &str, returned: bool) -> Option<ErrorRole> {
+    if returned {
+        Some(ErrorRole::Returned)
+    } else if name.ends_with("Error") {
+        Some(ErrorRole::Named)
+    } else {
+        None
+    }
+}
+
+/// Returns the impls an error type lacks, or `None` when it is complete.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(missing_impls(true, false), Some(MissingImpls::Error));
+/// assert_eq!(missing_impls(true, true), None);
+/// ```
+#[must_use]
+pub(crate) const fn missing_impls(has_display: bool, has_error: bool) -> Option<MissingImpls> {
+    match (has_display, has_error) {
+        (_, true) => None,
+        (true, false) => Some(MissingImpls::Error),
+        (false, false) => Some(MissingImpls::DisplayAndError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::suffix("ParseError", false, Some(ErrorRole::Named))]
+    #[case::bare("Error", false, Some(ErrorRole::Named))]
+    #[case::returned("Failure", true, Some(ErrorRole::Returned))]
+    #[case::returned_and_named("LoadError", true, Some(ErrorRole::Returned))]
+    #[case::prefix("ErrorKind", false, None)]
+    #[case::unrelated("Config", false, None)]
+    fn classifies_error_types(
+        #[case] name: &str,
+        #[case] returned: bool,
+        #[case] expected: Option<ErrorRole>,
+    ) {
+        assert_eq!(error_role(name, returned), expected);
+    }
+
+    #[rstest]
+    #[case::complete(true, true, None)]
+    #[case::display_only(true, false, Some(MissingImpls::Error))]
+    #[case::neither(false, false, Some(MissingImpls::DisplayAndError))]
+    fn reports_missing_impls(
+        #[case] has_display: bool,
+        #[case] has_error: bool,
+        #[case] expected: Option<MissingImpls>,
+    ) {
+        assert_eq!(missing_impls(has_display, has_error), expected);
+    }
+}
@@ -0,0 +1,78 @@
+//! Behaviour-driven coverage for error type classification.
+
+use crate::policy::{MissingImpls, error_role, missing_impls};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct ErrorTypeWorld {
+    name: RefCell<String>,
+    returned: Cell<bool>,
+    has_display: Cell<bool>,
+    has_error: Cell<bool>,
+    missing: Cell<Option<Option<MissingImpls>>>,
+}
+
+#[fixture]
+fn world() -> ErrorTypeWorld {
+    ErrorTypeWorld::default()
+}
+
+#[given("a public type named {name}")]
+fn given_type(world: &ErrorTypeWorld, name: String) {
+    *world.name.borrow_mut() = name;
+}
+
+#[given("a public function returns it as an error")]
+fn given_returned(world: &ErrorTypeWorld) {
+    world.returned.set(true);
+}
+
+#[given("it implements Display")]
+fn given_display(world: &ErrorTypeWorld) {
+    world.has_display.set(true);
+}
+
+#[given("it implements Error")]
+fn given_error(world: &ErrorTypeWorld) {
+    world.has_error.set(true);
+}
+
+#[when("the type is checked")]
+fn when_checked(world: &ErrorTypeWorld) {
+    let missing = error_role(&world.name.borrow(), world.returned.get())
+        .and_then(|_| missing_impls(world.has_display.get(), world.has_error.get()));
+    world.missing.set(Some(missing));
+}
+
+#[then("the type is reported as missing {kind}")]
+fn then_reported(world: &ErrorTypeWorld, kind: String) {
+    let missing = world.missing.get().flatten().expect("the type is reported");
+    assert_eq!(missing.fluent_kind(), kind);
+}
+
+#[then("the type is accepted")]
+fn then_accepted(world: &ErrorTypeWorld) {
+    assert_eq!(world.missing.get(), Some(None));
+}
+
+#[scenario(path = "tests/features/error_types_implement_error.feature", index = 0)]
+fn scenario_named_error_without_impls(world: ErrorTypeWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/error_types_implement_error.feature", index = 1)]
+fn scenario_returned_error_with_display(world: ErrorTypeWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/error_types_implement_error.feature", index = 2)]
+fn scenario_complete_error_type(world: ErrorTypeWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/error_types_implement_error.feature", index = 3)]
+fn scenario_not_an_error_type(world: ErrorTypeWorld) {
+    let _ = world;
+}
@@ -0,0 +1,27 @@
+Feature: Error types implement Error
+  Public error types are reported when they lack `Display` or
+  `std::error::Error`, and accepted once both are implemented.
+
+  Scenario: A named error type without impls is reported
+    Given a public type named ParseError
+    When the type is checked
+    Then the type is reported as missing both
+
+  Scenario: A returned error type with only Display is reported
+    Given a public type named Failure
+    And a public function returns it as an error
+    And it implements Display
+    When the type is checked
+    Then the type is reported as missing error
+
+  Scenario: A complete error type is accepted
+    Given a public type named LoadError
+    And it implements Display
+    And it implements Error
+    When the type is checked
+    Then the type is accepted
+
+  Scenario: A type that is not an error type is accepted
+    Given a public type named Config
+    When the type is checked
+    Then the type is accepted
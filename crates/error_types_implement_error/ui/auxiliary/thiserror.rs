@@ -0,0 +1,37 @@
+// force-host
+// no-prefer-dynamic
+//! Minimal `thiserror`-style derive macro used by UI fixtures.
+#![crate_type = "proc-macro"]
+
+extern crate proc_macro;
+
+use proc_macro::{TokenStream, TokenTree};
+
+/// Implements `Display` and `std::error::Error` for a non-generic type,
+/// mirroring the impls `thiserror::Error` generates.
+#[proc_macro_derive(Error, attributes(error))]
+pub fn derive_error(item: TokenStream) -> TokenStream {
+    let name = type_name(item).expect("derive input names a struct or enum");
+    format!(
+        "impl ::std::fmt::Display for {name} {{\n\
+             fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{\n\
+                 f.write_str(\"{name}\")\n\
+             }}\n\
+         }}\n\
+         impl ::std::error::Error for {name} {{}}"
+    )
+    .parse()
+    .expect("generated impls parse")
+}
+
+fn type_name(item: TokenStream) -> Option<String> {
+    let mut tokens = item.into_iter();
+    while let Some(token) = tokens.next() {
+        if let TokenTree::Ident(ident) = token {
+            if matches!(ident.to_string().as_str(), "struct" | "enum") {
+                return tokens.next().map(|name| name.to_string());
+            }
+        }
+    }
+    None
+}
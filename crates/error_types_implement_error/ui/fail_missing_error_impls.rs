@@ -0,0 +1,42 @@
+//! Negative UI fixture: public error types without `Display` and
+//! `std::error::Error` impls are reported.
+#![warn(error_types_implement_error)]
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ParseError {
+    Empty,
+}
+
+#[derive(Debug)]
+pub struct LoadError {
+    pub path: String,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not load {}", self.path)
+    }
+}
+
+#[derive(Debug)]
+pub enum Failure {
+    Timeout,
+}
+
+pub fn parse(input: &str) -> Result<u32, ParseError> {
+    input.parse().map_err(|_| ParseError::Empty)
+}
+
+pub fn fetch(ready: bool) -> Result<(), Failure> {
+    if ready { Ok(()) } else { Err(Failure::Timeout) }
+}
+
+fn main() {
+    let _ = parse("1");
+    let _ = fetch(true);
+    let _ = LoadError {
+        path: String::from("config.toml"),
+    };
+}
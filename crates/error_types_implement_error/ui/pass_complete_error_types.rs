@@ -0,0 +1,62 @@
+// aux-build: thiserror.rs
+//! UI fixture: complete, private, and non-error types are accepted.
+#![warn(error_types_implement_error)]
+
+extern crate thiserror;
+
+use std::fmt;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DerivedError {
+    #[error("the input was empty")]
+    Empty,
+}
+
+#[derive(Debug)]
+pub struct ManualError;
+
+impl fmt::Display for ManualError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("manual failure")
+    }
+}
+
+impl std::error::Error for ManualError {}
+
+#[derive(Debug)]
+enum InternalError {
+    Busy,
+}
+
+#[derive(Debug)]
+pub struct Config {
+    pub retries: u8,
+}
+
+pub fn derived(input: &str) -> Result<u32, DerivedError> {
+    input.parse().map_err(|_| DerivedError::Empty)
+}
+
+pub fn manual(ready: bool) -> Result<(), ManualError> {
+    if ready { Ok(()) } else { Err(ManualError) }
+}
+
+pub fn described(input: &str) -> Result<u32, String> {
+    input.parse().map_err(|_| String::from("not a number"))
+}
+
+fn internal(ready: bool) -> Result<(), InternalError> {
+    if ready { Ok(()) } else { Err(InternalError::Busy) }
+}
+
+pub fn config() -> Config {
+    Config { retries: 3 }
+}
+
+fn main() {
+    let _ = derived("1");
+    let _ = manual(true);
+    let _ = described("2");
+    let _ = internal(true);
+    let _ = config().retries;
+}
@@ -0,0 +1,145 @@
+//! Pure decision logic for `feature_envy`.
+//!
+//! The driver counts, for each method taking `self`, the accesses to `self`
+//! and the accesses to every other local type. [`FeatureEnvyDecision`]
+//! replays the same counts through [`AccessCounts`] and applies the same
+//! configured thresholds, naming the envied type as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{AccessCounts, Config, LINT_NAME};
+
+/// Reason key for methods that use another type more than their own.
+const ENVIOUS: &str = "envious";
+
+/// The accesses one method makes to one other type.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ForeignAccessProjection {
+    /// The name of the accessed type.
+    pub type_name: String,
+    /// How many field reads and method calls reach the type.
+    pub count: u32,
+}
+
+/// The facts about one method that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MethodAccessesProjection {
+    /// How many field reads and method calls reach `self`.
+    pub own: u32,
+    /// The accesses to other types, in first-seen order.
+    pub foreign: Vec<ForeignAccessProjection>,
+}
+
+/// Reports methods whose most-used foreign type outweighs `self`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let access = ForeignAccessProjection { type_name: "Order".to_owned(), count: 6 };
+/// let method = MethodAccessesProjection { own: 1, foreign: vec![access] };
+/// assert!(FeatureEnvyDecision::default().decide(&method).is_some());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeatureEnvyDecision {
+    config: Config,
+}
+
+impl FeatureEnvyDecision {
+    /// Creates a decision reporting at least `min_foreign_accesses` accesses
+    /// to one type that are at least `ratio` times the accesses to `self`.
+    #[must_use]
+    pub const fn new(ratio: f64, min_foreign_accesses: u32) -> Self {
+        Self {
+            config: Config {
+                ratio,
+                min_foreign_accesses,
+            },
+        }
+    }
+}
+
+impl LintDecision for FeatureEnvyDecision {
+    type Projection = MethodAccessesProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, method: &MethodAccessesProjection) -> Option<Finding> {
+        let mut counts = AccessCounts::default();
+        for _ in 0..method.own {
+            counts.record_own();
+        }
+        for (index, access) in method.foreign.iter().enumerate() {
+            for _ in 0..access.count {
+                counts.record_foreign(index);
+            }
+        }
+        let (target, foreign) = counts.most_envied()?;
+        let envied = method.foreign.get(target)?;
+        self.config
+            .is_envious(method.own, foreign)
+            .then(|| Finding::new(LINT_NAME, ENVIOUS).with_subject(envied.type_name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<MethodAccessesProjection>> {
+        let minimum = self.config.min_foreign_accesses.max(1);
+        let access = |type_name: &str, count| ForeignAccessProjection {
+            type_name: type_name.to_owned(),
+            count,
+        };
+        let method = |own, foreign| MethodAccessesProjection { own, foreign };
+        vec![
+            DecisionFixture::flagged(
+                "never touches self",
+                method(0, vec![access("Order", minimum)]),
+                ENVIOUS,
+            ),
+            DecisionFixture::clean(
+                "too few foreign accesses",
+                method(0, vec![access("Order", minimum - 1)]),
+            ),
+            DecisionFixture::clean("uses self mostly", method(minimum, Vec::new())),
+            DecisionFixture::clean(
+                "self outweighs the foreign type",
+                method(minimum.saturating_mul(4), vec![access("Order", minimum)]),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_most_envied_type() {
+        let method = MethodAccessesProjection {
+            own: 0,
+            foreign: vec![
+                ForeignAccessProjection {
+                    type_name: "Customer".to_owned(),
+                    count: 4,
+                },
+                ForeignAccessProjection {
+                    type_name: "Order".to_owned(),
+                    count: 5,
+                },
+            ],
+        };
+        let finding = FeatureEnvyDecision::default()
+            .decide(&method)
+            .expect("the method should be reported");
+
+        assert_eq!(finding.subject(), Some("Order"));
+    }
+
+    #[rstest]
+    #[case::defaults(FeatureEnvyDecision::default())]
+    #[case::strict(FeatureEnvyDecision::new(1.5, 2))]
+    fn decision_conforms(#[case] decision: FeatureEnvyDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
@@ -0,0 +1,40 @@
+//! Embedded documentation for the `feature_envy` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `feature_envy`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "feature_envy",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags methods that use another type's fields and methods more than their own.",
+    rationale: "A method that mostly reads another type's data is coupled to that type's internals and usually belongs on it; moving it keeps behaviour next to the data it depends on.",
+    bad_example: concat!(
+        "impl Report {\n",
+        "    fn total(&self, invoice: &Invoice) -> u64 {\n",
+        "        invoice.net + invoice.tax - invoice.discount + invoice.shipping()\n",
+        "    }\n",
+        "}",
+    ),
+    good_example: concat!(
+        "impl Invoice {\n",
+        "    fn total(&self) -> u64 {\n",
+        "        self.net + self.tax - self.discount + self.shipping()\n",
+        "    }\n",
+        "}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "ratio",
+            default: "2.0",
+            description: "How many times more often the envied type must be used than `Self`.",
+        },
+        ConfigKeyDocs {
+            key: "min_foreign_accesses",
+            default: "4",
+            description: "Minimum accesses to one foreign type before a method is reported.",
+        },
+    ],
+};
@@ -0,0 +1,375 @@
+//! Detect methods that use another type more than their own `self`.
+//!
+//! For each method with a `self` receiver the lint walks the body, including
+//! closures, and classifies every field access and method call by the type of
+//! its receiver:
+//!
+//! - receivers of the implementing type, whether `self` or another value of
+//!   type `Self`, count as *own* accesses; and
+//! - receivers of any other type defined in the current crate count as
+//!   *foreign* accesses to that type.
+//!
+//! Types from other crates, such as `String` or `Vec`, are ignored because
+//! methods cannot move onto them. The most-used foreign type is reported when
+//! its access count reaches `min_foreign_accesses` and at least `ratio` times
+//! the own count. Code produced by macro expansion is not counted.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::TypeckResults;
+use rustc_span::{Span, Symbol};
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+pub(crate) const LINT_NAME: &str = "feature_envy";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Config {
+    pub(crate) ratio: f64,
+    pub(crate) min_foreign_accesses: u32,
+}
+
+impl Config {
+    /// Raises `ratio` to at least 1, so a method is never reported for using
+    /// another type less than it uses `self`.
+    fn normalised(self) -> Self {
+        Self {
+            ratio: self.ratio.max(1.0),
+            ..self
+        }
+    }
+
+    /// Returns `true` when `foreign` accesses to one type outweigh `own`
+    /// accesses to `self` enough to report the method.
+    pub(crate) fn is_envious(&self, own: u32, foreign: u32) -> bool {
+        foreign >= self.min_foreign_accesses && f64::from(foreign) >= self.ratio * f64::from(own)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            ratio: 2.0,
+            min_foreign_accesses: 4,
+        }
+    }
+}
+
+/// Access counts gathered from one method body.
+///
+/// Foreign counts keep first-seen order so ties resolve to the type the
+/// method touches first.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct AccessCounts<K> {
+    own: u32,
+    foreign: Vec<(K, u32)>,
+}
+
+impl<K> Default for AccessCounts<K> {
+    fn default() -> Self {
+        Self {
+            own: 0,
+            foreign: Vec::new(),
+        }
+    }
+}
+
+impl<K: Copy + PartialEq> AccessCounts<K> {
+    pub(crate) const fn record_own(&mut self) {
+        self.own = self.own.saturating_add(1);
+    }
+
+    pub(crate) fn record_foreign(&mut self, key: K) {
+        match self.foreign.iter_mut().find(|(seen, _)| *seen == key) {
+            Some((_, count)) => *count = count.saturating_add(1),
+            None => self.foreign.push((key, 1)),
+        }
+    }
+
+    /// Returns the foreign type with the most accesses, if any.
+    pub(crate) fn most_envied(&self) -> Option<(K, u32)> {
+        self.foreign
+            .iter()
+            .copied()
+            .reduce(|best, entry| if entry.1 > best.1 { entry } else { best })
+    }
+}
+
+/// Lint pass reporting feature-envious methods.
+#[derive(Default)]
+pub struct FeatureEnvy {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Thresholds and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub FEATURE_ENVY,
+    Warn,
+    "methods should not use another type's fields and methods more than their own",
+    FeatureEnvy::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for FeatureEnvy {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(FEATURE_ENVY, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        let hir::ImplItemKind::Fn(sig, body_id) = item.kind else {
+            return;
+        };
+        if item.span.from_expansion() || !sig.decl.implicit_self().has_implicit_self() {
+            return;
+        }
+        let Some(counts) = count_accesses(cx, body_id) else {
+            return;
+        };
+        let Some((target, foreign)) = counts.most_envied() else {
+            return;
+        };
+        let config = self
+            .state
+            .findings
+            .config_at(cx, item.span, &self.state.config)
+            .normalised();
+        if !config.is_envious(counts.own, foreign) {
+            return;
+        }
+        debug!(
+            target: LINT_NAME,
+            "`{}` uses `{}` {foreign} times and `self` {} times",
+            item.ident,
+            cx.tcx.item_name(target),
+            counts.own,
+        );
+
+        let envy = Envy {
+            span: item.ident.span,
+            method: item.ident.name,
+            target: cx.tcx.item_name(target),
+            foreign,
+            own: counts.own,
+        };
+        if self.state.findings.admit(cx, item.hir_id(), envy.span) {
+            emit_diagnostic(cx, &envy, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Counts own and foreign accesses in the body of a method taking `self`.
+///
+/// Returns `None` when the implementing type is not a struct, enum, or union.
+fn count_accesses(cx: &LateContext<'_>, body_id: hir::BodyId) -> Option<AccessCounts<DefId>> {
+    let body = cx.tcx.hir_body(body_id);
+    let self_param = body.params.first()?;
+    let typeck = cx.tcx.typeck_body(body_id);
+    let self_adt = typeck
+        .node_type(self_param.pat.hir_id)
+        .peel_refs()
+        .ty_adt_def()?;
+
+    let mut counter = AccessCounter {
+        cx,
+        typeck,
+        self_type: self_adt.did(),
+        counts: AccessCounts::default(),
+    };
+    counter.visit_expr(body.value);
+    Some(counter.counts)
+}
+
+struct AccessCounter<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    self_type: DefId,
+    counts: AccessCounts<DefId>,
+}
+
+impl AccessCounter<'_, '_> {
+    fn record(&mut self, receiver: &hir::Expr<'_>) {
+        let Some(adt) = self.typeck.expr_ty(receiver).peel_refs().ty_adt_def() else {
+            return;
+        };
+        let did = adt.did();
+        if did == self.self_type {
+            self.counts.record_own();
+        } else if did.is_local() {
+            self.counts.record_foreign(did);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for AccessCounter<'_, 'tcx> {
+    fn visit_nested_body(&mut self, body_id: hir::BodyId) {
+        // Closures share the typeck results of the enclosing method; other
+        // nested bodies, such as array length constants, do not.
+        let owner = self.cx.tcx.hir_body_owner_def_id(body_id);
+        if self.cx.tcx.is_closure_like(owner.to_def_id()) {
+            self.visit_body(self.cx.tcx.hir_body(body_id));
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if !expr.span.from_expansion() {
+            match expr.kind {
+                ExprKind::Field(receiver, _) | ExprKind::MethodCall(_, receiver, _, _) => {
+                    self.record(receiver);
+                }
+                _ => {}
+            }
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
+
+/// A reported feature-envious method.
+#[derive(Clone, Copy, Debug)]
+struct Envy {
+    span: Span,
+    method: Symbol,
+    target: Symbol,
+    foreign: u32,
+    own: u32,
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, envy: &Envy, localizer: &Localizer) {
+    let method = envy.method.to_string();
+    let target = envy.target.to_string();
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("method"), FluentValue::from(method.clone()));
+    args.insert(Cow::Borrowed("target"), FluentValue::from(target.clone()));
+    args.insert(Cow::Borrowed("foreign"), FluentValue::from(envy.foreign));
+    args.insert(Cow::Borrowed("own"), FluentValue::from(envy.own));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(&method, &target, envy.foreign, envy.own)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        FEATURE_ENVY,
+        envy.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(method: &str, target: &str, foreign: u32, own: u32) -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        format!(
+            "`{method}` uses `{target}` more than its own type ({foreign} accesses against \
+             {own})."
+        ),
+        String::from(
+            "Methods that mostly work with another type's data usually belong on that type.",
+        ),
+        format!("Move `{method}`, or the part that works with `{target}`, onto `{target}`."),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::no_self_use(0, 4, true)]
+    #[case::below_minimum(0, 3, false)]
+    #[case::at_ratio(3, 6, true)]
+    #[case::below_ratio(3, 5, false)]
+    fn default_config_thresholds(#[case] own: u32, #[case] foreign: u32, #[case] expected: bool) {
+        assert_eq!(Config::default().is_envious(own, foreign), expected);
+    }
+
+    #[rstest]
+    fn most_envied_prefers_highest_count_then_first_seen() {
+        let mut counts = AccessCounts::default();
+        for key in ["order", "invoice", "invoice", "order", "customer"] {
+            counts.record_foreign(key);
+        }
+        counts.record_own();
+
+        assert_eq!(counts.own, 1);
+        assert_eq!(counts.most_envied(), Some(("order", 2)));
+    }
+
+    #[rstest]
+    fn most_envied_is_none_without_foreign_accesses() {
+        let mut counts = AccessCounts::<&str>::default();
+        counts.record_own();
+
+        assert_eq!(counts.most_envied(), None);
+    }
+
+    #[rstest]
+    fn fallback_messages_name_both_types() {
+        let messages = fallback_messages("total", "Invoice", 6, 1);
+
+        assert!(
+            messages
+                .primary()
+                .contains("`Invoice` more than its own type")
+        );
+        assert!(messages.primary().contains("(6 accesses against 1)"));
+        assert!(messages.help().contains("onto `Invoice`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,37 @@
+//! Lint crate flagging methods that use another type more than their own.
+//!
+//! Feature envy is the smell of a method that reads another type's fields and
+//! calls its methods far more often than it touches `self`. Such logic usually
+//! belongs on the type it envies. The lint counts accesses per receiver type
+//! and reports methods whose most-used foreign type outweighs `self` by a
+//! configurable ratio. It is experimental and ships behind the
+//! `experimental-feature-envy` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{FeatureEnvyDecision, ForeignAccessProjection, MethodAccessesProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn feature_envy_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
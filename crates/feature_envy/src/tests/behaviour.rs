@@ -0,0 +1,82 @@
+//! Behaviour-driven coverage for the feature-envy thresholds.
+
+use super::{AccessCounts, Config};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct EnvyWorld {
+    counts: RefCell<AccessCounts<&'static str>>,
+    config: RefCell<Config>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> EnvyWorld {
+    EnvyWorld {
+        counts: RefCell::new(AccessCounts::default()),
+        config: RefCell::new(Config::default()),
+        reported: Cell::new(None),
+    }
+}
+
+#[given("a method with {own} own accesses")]
+fn given_own_accesses(world: &EnvyWorld, own: u32) {
+    let mut counts = world.counts.borrow_mut();
+    for _ in 0..own {
+        counts.record_own();
+    }
+}
+
+#[given("{foreign} accesses to another type")]
+fn given_foreign_accesses(world: &EnvyWorld, foreign: u32) {
+    let mut counts = world.counts.borrow_mut();
+    for _ in 0..foreign {
+        counts.record_foreign("Invoice");
+    }
+}
+
+#[given("the ratio is {ratio}")]
+fn given_ratio(world: &EnvyWorld, ratio: f64) {
+    world.config.borrow_mut().ratio = ratio;
+}
+
+#[when("the method is checked")]
+fn when_checked(world: &EnvyWorld) {
+    let counts = world.counts.borrow();
+    let config = world.config.borrow();
+    let reported = counts
+        .most_envied()
+        .is_some_and(|(_, foreign)| config.is_envious(counts.own, foreign));
+    world.reported.set(Some(reported));
+}
+
+#[then("the method is reported")]
+fn then_reported(world: &EnvyWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("the method is allowed")]
+fn then_allowed(world: &EnvyWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/feature_envy.feature", index = 0)]
+fn scenario_only_foreign_reported(world: EnvyWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/feature_envy.feature", index = 1)]
+fn scenario_balanced_allowed(world: EnvyWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/feature_envy.feature", index = 2)]
+fn scenario_few_foreign_allowed(world: EnvyWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/feature_envy.feature", index = 3)]
+fn scenario_lower_ratio_reported(world: EnvyWorld) {
+    let _ = world;
+}
@@ -0,0 +1,28 @@
+Feature: Feature envy detection
+  Methods are reported when their most-used foreign type outweighs their own
+  type by the configured ratio and reaches the minimum access count.
+
+  Scenario: A method using only another type is reported
+    Given a method with 0 own accesses
+    And 5 accesses to another type
+    When the method is checked
+    Then the method is reported
+
+  Scenario: A method balancing both types is allowed
+    Given a method with 3 own accesses
+    And 5 accesses to another type
+    When the method is checked
+    Then the method is allowed
+
+  Scenario: Few foreign accesses are allowed
+    Given a method with 0 own accesses
+    And 3 accesses to another type
+    When the method is checked
+    Then the method is allowed
+
+  Scenario: A lower ratio reports a balanced method
+    Given a method with 3 own accesses
+    And 5 accesses to another type
+    And the ratio is 1.5
+    When the method is checked
+    Then the method is reported
@@ -0,0 +1,20 @@
+struct Invoice {
+    net: u64,
+    tax: u64,
+}
+
+struct Ledger {
+    opening: u64,
+}
+
+impl Ledger {
+    fn closing(&self, invoice: &Invoice) -> u64 {
+        self.opening + invoice.net + invoice.tax
+    }
+}
+
+fn main() {
+    let ledger = Ledger { opening: 10 };
+    let invoice = Invoice { net: 5, tax: 1 };
+    println!("{}", ledger.closing(&invoice));
+}
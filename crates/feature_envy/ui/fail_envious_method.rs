@@ -0,0 +1,37 @@
+struct Invoice {
+    net: u64,
+    tax: u64,
+    discount: u64,
+}
+
+impl Invoice {
+    fn shipping(&self) -> u64 {
+        self.net / 10
+    }
+}
+
+struct Report {
+    label: String,
+}
+
+impl Report {
+    fn total(&self, invoice: &Invoice) -> u64 {
+        invoice.net + invoice.tax - invoice.discount + invoice.shipping()
+    }
+
+    fn heading(&self) -> &str {
+        &self.label
+    }
+}
+
+fn main() {
+    let invoice = Invoice {
+        net: 100,
+        tax: 20,
+        discount: 5,
+    };
+    let report = Report {
+        label: String::from("Q3"),
+    };
+    println!("{}: {}", report.heading(), report.total(&invoice));
+}
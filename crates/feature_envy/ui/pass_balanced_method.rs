@@ -0,0 +1,33 @@
+struct Invoice {
+    net: u64,
+    tax: u64,
+}
+
+struct Ledger {
+    opening: u64,
+    adjustment: u64,
+    fees: u64,
+}
+
+impl Ledger {
+    fn closing(&self, invoice: &Invoice) -> u64 {
+        // Two foreign accesses against three own accesses.
+        self.opening + self.adjustment - self.fees + invoice.net + invoice.tax
+    }
+
+    fn summary(&self, label: &str) -> String {
+        // Types from other crates never count as envied.
+        let name = label.trim().to_uppercase().replace(' ', "_");
+        format!("{name}: {}", self.opening.to_string().len())
+    }
+}
+
+fn main() {
+    let ledger = Ledger {
+        opening: 10,
+        adjustment: 2,
+        fees: 1,
+    };
+    let invoice = Invoice { net: 5, tax: 1 };
+    println!("{} {}", ledger.closing(&invoice), ledger.summary("q3 totals"));
+}
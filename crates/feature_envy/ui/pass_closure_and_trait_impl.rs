@@ -0,0 +1,35 @@
+use std::fmt;
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+struct Canvas {
+    origin: Point,
+    scale: i32,
+}
+
+impl Canvas {
+    fn project(&self, points: &[Point]) -> Vec<i32> {
+        // Accesses inside closures count: two own, two foreign.
+        points
+            .iter()
+            .map(|point| point.x * self.scale + point.y * self.origin.x)
+            .collect()
+    }
+}
+
+impl fmt::Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}x{}", self.origin.x, self.origin.y)
+    }
+}
+
+fn main() {
+    let canvas = Canvas {
+        origin: Point { x: 1, y: 2 },
+        scale: 3,
+    };
+    println!("{canvas} {:?}", canvas.project(&[Point { x: 4, y: 5 }]));
+}
@@ -0,0 +1,140 @@
+//! Pure decision logic for `feature_gated_pub_use_must_have_cfg_doc`.
+//!
+//! The driver hands the source text of every module body to
+//! [`undocumented_reexports`]. [`FeatureGatedPubUseMustHaveCfgDocDecision`]
+//! does the same for one module body and reports its first feature-gated
+//! re-export without `doc(cfg(...))`, naming the attribute
+//! [`doc_cfg_attribute`] suggests as the finding's subject. Skipping binary
+//! crates and crates that enable `doc_auto_cfg` is left to the driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_DOCS_CFG, LINT_NAME};
+use crate::policy::{doc_cfg_attribute, undocumented_reexports};
+
+/// Reason key for feature-gated re-exports without `doc(cfg(...))`.
+const UNDOCUMENTED_GATE: &str = "undocumented_gate";
+
+/// The facts about one module body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleSourceProjection {
+    /// The source text of the module body.
+    pub source: String,
+}
+
+impl ModuleSourceProjection {
+    /// Creates a projection for the module body `source`.
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+/// Reports feature-gated `pub use` items that rustdoc cannot label.
+///
+/// # Examples
+///
+/// ```ignore
+/// let module = ModuleSourceProjection::new(
+///     "#[cfg(feature = \"serde\")]\npub use serde::Serialize;",
+/// );
+/// let decision = FeatureGatedPubUseMustHaveCfgDocDecision::default();
+/// assert!(decision.decide(&module).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeatureGatedPubUseMustHaveCfgDocDecision {
+    docs_cfg: String,
+}
+
+impl FeatureGatedPubUseMustHaveCfgDocDecision {
+    /// Creates a decision for crates that build their documentation with the
+    /// `docs_cfg` configuration flag, such as `docsrs`.
+    #[must_use]
+    pub fn new(docs_cfg: impl Into<String>) -> Self {
+        Self {
+            docs_cfg: docs_cfg.into(),
+        }
+    }
+}
+
+impl Default for FeatureGatedPubUseMustHaveCfgDocDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_DOCS_CFG)
+    }
+}
+
+impl LintDecision for FeatureGatedPubUseMustHaveCfgDocDecision {
+    type Projection = ModuleSourceProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, module: &ModuleSourceProjection) -> Option<Finding> {
+        let reexport = undocumented_reexports(&module.source, &self.docs_cfg)
+            .into_iter()
+            .next()?;
+        Some(
+            Finding::new(LINT_NAME, UNDOCUMENTED_GATE)
+                .with_subject(doc_cfg_attribute(&self.docs_cfg, &reexport.predicate)),
+        )
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ModuleSourceProjection>> {
+        let documented = format!(
+            "#[cfg(feature = \"serde\")]\n#[cfg_attr({}, doc(cfg(feature = \"serde\")))]\n\
+             pub use serde::Serialize;",
+            self.docs_cfg
+        );
+        vec![
+            DecisionFixture::flagged(
+                "gated re-export",
+                ModuleSourceProjection::new(
+                    "#[cfg(feature = \"serde\")]\npub use serde::Serialize;",
+                ),
+                UNDOCUMENTED_GATE,
+            ),
+            DecisionFixture::clean("documented gate", ModuleSourceProjection::new(documented)),
+            DecisionFixture::clean(
+                "gate without a feature",
+                ModuleSourceProjection::new(
+                    "#[cfg(unix)]\npub use std::os::unix::fs::PermissionsExt;",
+                ),
+            ),
+            DecisionFixture::clean(
+                "private import",
+                ModuleSourceProjection::new("#[cfg(feature = \"serde\")]\nuse serde::Serialize;"),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_suggested_attribute() {
+        let module =
+            ModuleSourceProjection::new("#[cfg(feature = \"serde\")]\npub use serde::Serialize;");
+        let finding = FeatureGatedPubUseMustHaveCfgDocDecision::default()
+            .decide(&module)
+            .expect("the gate should be reported");
+
+        assert_eq!(
+            finding.subject(),
+            Some("#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]")
+        );
+    }
+
+    #[rstest]
+    #[case::docsrs(FeatureGatedPubUseMustHaveCfgDocDecision::default())]
+    #[case::custom_flag(FeatureGatedPubUseMustHaveCfgDocDecision::new("doc_cfg"))]
+    fn decision_conforms(#[case] decision: FeatureGatedPubUseMustHaveCfgDocDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
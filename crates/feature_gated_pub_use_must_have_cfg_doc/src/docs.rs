@@ -0,0 +1,23 @@
+//! Embedded documentation for the `feature_gated_pub_use_must_have_cfg_doc`
+//! lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `feature_gated_pub_use_must_have_cfg_doc`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "feature_gated_pub_use_must_have_cfg_doc",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `#[cfg(feature = ...)] pub use` re-exports in library crates that lack a matching `#[cfg_attr(docsrs, doc(cfg(...)))]` attribute.",
+    rationale: "Documentation is normally built with every feature enabled, so a feature-gated re-export looks unconditionally available unless rustdoc is told about the gate. The `doc(cfg(...))` attribute labels the item with the features it needs, sparing users a build failure when they reach for it.",
+    bad_example: "#[cfg(feature = \"serde\")]\npub use crate::serde_support::Serializer;",
+    good_example: "#[cfg(feature = \"serde\")]\n#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]\npub use crate::serde_support::Serializer;",
+    config: &[ConfigKeyDocs {
+        key: "docs_cfg",
+        default: "\"docsrs\"",
+        description: "The `cfg` name under which the `doc(cfg(...))` attribute is applied, as in `#[cfg_attr(docsrs, doc(cfg(...)))]`.",
+    }],
+};
@@ -0,0 +1,292 @@
+//! Lint pass reporting feature-gated re-exports without a `doc(cfg(...))`.
+//!
+//! The source text of every module body, the crate root included, is handed
+//! to [`undocumented_reexports`], because re-exports gated on disabled
+//! features never reach HIR and `cfg_attr` attributes are expanded before the
+//! pass runs. Each undocumented re-export is reported at its `cfg` attribute
+//! with the attribute [`doc_cfg_attribute`] builds. Binary crates have no
+//! documented API and are skipped, as are crates whose root enables
+//! `doc_auto_cfg` and module bodies produced by macro expansion.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::attrs::CrateType;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{BytePos, Pos, Span};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{doc_cfg_attribute, enables_doc_auto_cfg, undocumented_reexports};
+
+pub(crate) const LINT_NAME: &str = "feature_gated_pub_use_must_have_cfg_doc";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_DOCS_CFG: &str = "docsrs";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    docs_cfg: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            docs_cfg: DEFAULT_DOCS_CFG.to_owned(),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub FEATURE_GATED_PUB_USE_MUST_HAVE_CFG_DOC,
+    Warn,
+    "feature-gated `pub use` re-exports should carry `#[cfg_attr(docsrs, doc(cfg(...)))]` so documentation shows the gate",
+    FeatureGatedPubUseMustHaveCfgDoc::default()
+}
+
+/// Lint pass that finds feature-gated re-exports missing `doc(cfg(...))`.
+#[derive(Default)]
+pub struct FeatureGatedPubUseMustHaveCfgDoc {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Docs `cfg` name, whether the crate is checked at all, finding limit, and
+/// localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    docs_cfg: String,
+    enabled: bool,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for FeatureGatedPubUseMustHaveCfgDoc {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        let is_library = cx
+            .tcx
+            .crate_types()
+            .iter()
+            .any(|crate_type| matches!(crate_type, CrateType::Rlib | CrateType::Dylib));
+        let root = cx.tcx.hir_root_module().spans.inner_span;
+        let auto_cfg = cx
+            .sess()
+            .source_map()
+            .span_to_snippet(root)
+            .is_ok_and(|source| enables_doc_auto_cfg(&source));
+        self.state.reset(CrateState {
+            docs_cfg: load_configuration().docs_cfg,
+            enabled: is_library && !auto_cfg,
+            findings: FindingLimit::new(FEATURE_GATED_PUB_USE_MUST_HAVE_CFG_DOC, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_mod(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        module: &'tcx hir::Mod<'tcx>,
+        hir_id: hir::HirId,
+    ) {
+        let state = &*self.state;
+        let body = module.spans.inner_span;
+        if !state.enabled || body.is_dummy() || body.from_expansion() {
+            return;
+        }
+        let Ok(source) = cx.sess().source_map().span_to_snippet(body) else {
+            return;
+        };
+
+        for reexport in undocumented_reexports(&source, &state.docs_cfg) {
+            debug!(
+                target: LINT_NAME,
+                "re-export gated on `{}` lacks `doc(cfg(...))`",
+                reexport.predicate,
+            );
+            let offset = |position: usize| body.lo() + BytePos::from_usize(position);
+            let span = body
+                .with_lo(offset(reexport.gate.start))
+                .with_hi(offset(reexport.gate.end));
+            let node = item_at(cx, module, offset(reexport.item_start)).unwrap_or(hir_id);
+            if state.findings.admit(cx, node, span) {
+                let message = UndocumentedGateMessage {
+                    predicate: &reexport.predicate,
+                    attribute: doc_cfg_attribute(&state.docs_cfg, &reexport.predicate),
+                };
+                emit_diagnostic(cx, span, &message, &state.localizer);
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Finds the item of `module` starting at `start`, which exists only when
+/// its features are enabled, so its lint levels apply.
+fn item_at(cx: &LateContext<'_>, module: &hir::Mod<'_>, start: BytePos) -> Option<hir::HirId> {
+    module
+        .item_ids
+        .iter()
+        .map(|&item_id| cx.tcx.hir_item(item_id))
+        .find(|item| item.span.lo() == start)
+        .map(hir::Item::hir_id)
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &UndocumentedGateMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        FEATURE_GATED_PUB_USE_MUST_HAVE_CFG_DOC,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one undocumented gate interpolates.
+#[derive(Clone, Debug)]
+struct UndocumentedGateMessage<'a> {
+    predicate: &'a str,
+    attribute: String,
+}
+
+impl UndocumentedGateMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("predicate"),
+            FluentValue::from(self.predicate.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("attribute"),
+            FluentValue::from(self.attribute.clone()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            predicate,
+            attribute,
+        } = self;
+        DiagnosticMessageSet::new(
+            format!("Re-export gated on `{predicate}` has no `doc(cfg(...))` attribute."),
+            format!(
+                "Documentation built with every feature shows the re-export without saying it \
+                 needs `{predicate}`."
+            ),
+            format!("Add `{attribute}` so rustdoc labels the re-export with its gate."),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn message() -> UndocumentedGateMessage<'static> {
+        UndocumentedGateMessage {
+            predicate: "feature = \"serde\"",
+            attribute: doc_cfg_attribute("docsrs", "feature = \"serde\""),
+        }
+    }
+
+    fn resolve(message: &UndocumentedGateMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_uses_docsrs() {
+        assert_eq!(Config::default().docs_cfg, "docsrs");
+    }
+
+    #[rstest]
+    fn fallback_messages_give_the_attribute(message: UndocumentedGateMessage<'static>) {
+        let messages = message.fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Re-export gated on `feature = \"serde\"` has no `doc(cfg(...))` attribute."
+        );
+        assert_eq!(
+            messages.help(),
+            "Add `#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]` so rustdoc labels the \
+             re-export with its gate."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback(message: UndocumentedGateMessage<'static>) {
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str, message: UndocumentedGateMessage<'static>) {
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`feature = \"serde\"`"));
+        assert!(
+            messages
+                .help()
+                .contains("`#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]`")
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
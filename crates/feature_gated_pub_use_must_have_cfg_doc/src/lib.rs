@@ -0,0 +1,42 @@
+//! Lint crate requiring feature-gated re-exports to document their gate.
+//!
+//! Documentation is usually built with every feature enabled, so a
+//! `#[cfg(feature = "serde")] pub use` re-export appears there as if it were
+//! always available. The lint reports such re-exports unless they also carry
+//! `#[cfg_attr(docsrs, doc(cfg(...)))]` naming the same features, which makes
+//! rustdoc label the item. The `cfg` guarding the `doc(cfg(...))` attribute is
+//! configurable, and crates enabling `doc_auto_cfg` are exempt. The lint is
+//! experimental and ships behind the
+//! `experimental-feature-gated-pub-use-must-have-cfg-doc` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{FeatureGatedPubUseMustHaveCfgDocDecision, ModuleSourceProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn feature_gated_pub_use_must_have_cfg_doc_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,316 @@
+//! Rules finding feature-gated re-exports that rustdoc cannot label.
+//!
+//! The driver hands each module body's source text to
+//! [`undocumented_reexports`], which finds the `pub use` items among the
+//! body's own items whose `cfg` attribute names a feature, and keeps those
+//! without a `doc(cfg(...))` attribute naming the same features. Source is
+//! tokenised with `rustc_lexer` because re-exports gated on disabled features
+//! are removed before HIR is built, and `cfg_attr` attributes are expanded
+//! away. The rules are kept free of compiler types so they can be tested
+//! directly.
+
+use std::ops::Range;
+
+use rustc_lexer::{TokenKind, tokenize};
+
+/// A `pub use` item gated on a feature without a matching `doc(cfg(...))`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct GatedReexport {
+    /// Byte range of the `cfg` attribute naming the feature.
+    pub(crate) gate: Range<usize>,
+    /// Byte offset of the `pub` keyword starting the item.
+    pub(crate) item_start: usize,
+    /// The `cfg` predicate as written, such as `feature = "serde"`.
+    pub(crate) predicate: String,
+}
+
+/// A token other than whitespace or a comment, with its offset.
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    start: usize,
+}
+
+/// An outer attribute: its byte range and the tokens between its brackets.
+#[derive(Clone, Debug)]
+struct Attribute<'t, 'a> {
+    span: Range<usize>,
+    contents: &'t [Token<'a>],
+}
+
+/// Returns the feature-gated `pub use` items `source`, the text of a module
+/// body, declares at its top level without documenting the gate.
+///
+/// A re-export is documented when one of its attributes is
+/// `cfg_attr(<docs_cfg>, doc(cfg(...)))`, or `doc(cfg(...))` itself, naming
+/// every feature its `cfg` attribute names. Restricted visibilities such as
+/// `pub(crate)` are ignored.
+///
+/// # Examples
+///
+/// ```ignore
+/// let source = "#[cfg(feature = \"serde\")]\npub use crate::ser::Serializer;\n";
+/// let found = undocumented_reexports(source, "docsrs");
+/// assert_eq!(found[0].predicate, "feature = \"serde\"");
+/// ```
+#[must_use]
+pub(crate) fn undocumented_reexports(source: &str, docs_cfg: &str) -> Vec<GatedReexport> {
+    let tokens = significant_tokens(source);
+    let mut found = Vec::new();
+    let mut attributes = Vec::new();
+    let mut depth = 0_usize;
+    let mut index = 0;
+    while let Some(&token) = tokens.get(index) {
+        match token.kind {
+            TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseBrace => depth = depth.saturating_sub(1),
+            _ if depth > 0 => {}
+            TokenKind::Pound if is_kind(tokens.get(index + 1), TokenKind::OpenBracket) => {
+                let end = closing_bracket(&tokens, index + 1);
+                attributes.push(Attribute {
+                    span: token.start..tokens[end].start + 1,
+                    contents: &tokens[(index + 2).min(end)..end],
+                });
+                index = end + 1;
+                continue;
+            }
+            TokenKind::Ident if token.text == "pub" && is_use(tokens.get(index + 1)) => {
+                found.extend(undocumented(source, &attributes, token.start, docs_cfg));
+            }
+            _ => {}
+        }
+        attributes.clear();
+        index += 1;
+    }
+    found
+}
+
+/// Returns `true` when `source`, the text of a crate root, enables
+/// `doc_auto_cfg`, which labels feature-gated items without attributes.
+#[must_use]
+pub(crate) fn enables_doc_auto_cfg(source: &str) -> bool {
+    let tokens = significant_tokens(source);
+    tokens.iter().enumerate().any(|(index, token)| {
+        token.kind == TokenKind::Pound
+            && is_kind(tokens.get(index + 1), TokenKind::Not)
+            && is_kind(tokens.get(index + 2), TokenKind::OpenBracket)
+            && tokens[index + 2..=closing_bracket(&tokens, index + 2)]
+                .iter()
+                .any(|token| token.text == "doc_auto_cfg")
+    })
+}
+
+/// Returns the attribute documenting a re-export gated on `predicate`.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(
+///     doc_cfg_attribute("docsrs", "feature = \"serde\""),
+///     "#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]",
+/// );
+/// ```
+#[must_use]
+pub(crate) fn doc_cfg_attribute(docs_cfg: &str, predicate: &str) -> String {
+    format!("#[cfg_attr({docs_cfg}, doc(cfg({predicate})))]")
+}
+
+/// Checks the `attributes` of the `pub use` item starting at `item_start`.
+fn undocumented(
+    source: &str,
+    attributes: &[Attribute<'_, '_>],
+    item_start: usize,
+    docs_cfg: &str,
+) -> Option<GatedReexport> {
+    let gate = attributes.iter().find(|attribute| {
+        is_named(attribute.contents, "cfg") && !features(attribute.contents).is_empty()
+    })?;
+    let gated = features(gate.contents);
+    if attributes
+        .iter()
+        .any(|attribute| documents(attribute.contents, docs_cfg, &gated))
+    {
+        return None;
+    }
+    let predicate = gate
+        .contents
+        .get(2..gate.contents.len().saturating_sub(1))?;
+    let (first, last) = (predicate.first()?, predicate.last()?);
+    Some(GatedReexport {
+        gate: gate.span.clone(),
+        item_start,
+        predicate: source[first.start..last.start + last.text.len()].to_owned(),
+    })
+}
+
+/// Returns `true` when an attribute's `contents` apply `doc(cfg(...))`,
+/// directly or under `cfg_attr(<docs_cfg>, ...)`, naming every feature in
+/// `gated`.
+fn documents(contents: &[Token<'_>], docs_cfg: &str, gated: &[&str]) -> bool {
+    let applied = if is_named(contents, "cfg_attr") {
+        match contents.get(2..4) {
+            Some([name, comma]) if name.text == docs_cfg && comma.kind == TokenKind::Comma => {
+                &contents[4..]
+            }
+            _ => return false,
+        }
+    } else {
+        contents
+    };
+    let has_doc_cfg = applied.windows(3).any(|window| {
+        window[0].text == "doc" && window[1].kind == TokenKind::OpenParen && window[2].text == "cfg"
+    });
+    let named = features(applied);
+    has_doc_cfg && gated.iter().all(|feature| named.contains(feature))
+}
+
+/// Returns `true` when an attribute's `contents` start with `name(`.
+fn is_named(contents: &[Token<'_>], name: &str) -> bool {
+    matches!(
+        contents,
+        [first, open, ..] if first.text == name && open.kind == TokenKind::OpenParen
+    )
+}
+
+/// Returns the names in the `feature = "..."` predicates of `contents`.
+fn features<'a>(contents: &[Token<'a>]) -> Vec<&'a str> {
+    contents
+        .windows(3)
+        .filter(|window| window[0].text == "feature" && window[1].kind == TokenKind::Eq)
+        .map(|window| window[2].text.trim_matches('"'))
+        .collect()
+}
+
+fn is_use(token: Option<&Token<'_>>) -> bool {
+    token.is_some_and(|token| token.kind == TokenKind::Ident && token.text == "use")
+}
+
+fn is_kind(token: Option<&Token<'_>>, kind: TokenKind) -> bool {
+    token.is_some_and(|token| token.kind == kind)
+}
+
+/// Tokens other than whitespace and comments, with their text and offset.
+fn significant_tokens(source: &str) -> Vec<Token<'_>> {
+    let mut offset = 0;
+    let mut tokens = Vec::new();
+    for token in tokenize(source) {
+        let end = offset + token.len;
+        if !matches!(
+            token.kind,
+            TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment { .. }
+        ) {
+            tokens.push(Token {
+                kind: token.kind,
+                text: &source[offset..end],
+                start: offset,
+            });
+        }
+        offset = end;
+    }
+    tokens
+}
+
+/// Returns the index of the bracket closing the one at `open`, or the last
+/// index when the attribute is unterminated.
+fn closing_bracket(tokens: &[Token<'_>], open: usize) -> usize {
+    let mut depth = 0_usize;
+    for (index, token) in tokens.iter().enumerate().skip(open) {
+        match token.kind {
+            TokenKind::OpenBracket => depth += 1,
+            TokenKind::CloseBracket => {
+                depth -= 1;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len().saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const ITEM: &str = "pub use crate::ser::Serializer;\n";
+
+    fn predicates(source: &str) -> Vec<String> {
+        undocumented_reexports(source, "docsrs")
+            .into_iter()
+            .map(|reexport| reexport.predicate)
+            .collect()
+    }
+
+    #[rstest]
+    #[case::plain("#[cfg(feature = \"serde\")]\n", "feature = \"serde\"")]
+    #[case::combined(
+        "#[cfg(all(feature = \"serde\", unix))]\n",
+        "all(feature = \"serde\", unix)"
+    )]
+    #[case::wrong_docs_cfg(
+        "#[cfg(feature = \"serde\")]\n#[cfg_attr(doc, doc(cfg(feature = \"serde\")))]\n",
+        "feature = \"serde\""
+    )]
+    #[case::other_feature_documented(
+        "#[cfg(feature = \"serde\")]\n#[cfg_attr(docsrs, doc(cfg(feature = \"json\")))]\n",
+        "feature = \"serde\""
+    )]
+    fn undocumented_gates_are_reported(#[case] attributes: &str, #[case] predicate: &str) {
+        assert_eq!(predicates(&format!("{attributes}{ITEM}")), [predicate]);
+    }
+
+    #[rstest]
+    #[case::cfg_attr(
+        "#[cfg(feature = \"serde\")]\n#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]\n"
+    )]
+    #[case::doc_first(
+        "#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]\n/// Serializer.\n#[cfg(feature = \"serde\")]\n"
+    )]
+    #[case::direct("#[cfg(feature = \"serde\")]\n#[doc(cfg(feature = \"serde\"))]\n")]
+    #[case::not_feature("#[cfg(unix)]\n")]
+    #[case::ungated("")]
+    fn documented_or_ungated_reexports_are_accepted(#[case] attributes: &str) {
+        assert!(predicates(&format!("{attributes}{ITEM}")).is_empty());
+    }
+
+    #[rstest]
+    #[case::restricted("#[cfg(feature = \"serde\")]\npub(crate) use crate::ser::Serializer;\n")]
+    #[case::private("#[cfg(feature = \"serde\")]\nuse crate::ser::Serializer;\n")]
+    #[case::not_a_use("#[cfg(feature = \"serde\")]\npub fn serialize() {}\n")]
+    #[case::nested(
+        "mod ser {\n    #[cfg(feature = \"serde\")]\n    pub use super::Serializer;\n}\n"
+    )]
+    fn other_items_are_ignored(#[case] source: &str) {
+        assert!(predicates(source).is_empty());
+    }
+
+    #[rstest]
+    fn reexports_report_their_gate_and_item() {
+        let gate = "#[cfg(feature = \"serde\")]";
+        let source = format!("/// Serializer.\n{gate}\n{ITEM}");
+        let found = undocumented_reexports(&source, "docsrs");
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(&source[found[0].gate.clone()], gate);
+        assert!(source[found[0].item_start..].starts_with("pub use"));
+    }
+
+    #[rstest]
+    #[case::enabled("#![cfg_attr(docsrs, feature(doc_auto_cfg))]\npub mod api;\n", true)]
+    #[case::doc_cfg_only("#![cfg_attr(docsrs, feature(doc_cfg))]\npub mod api;\n", false)]
+    #[case::outer_attribute("#[doc_auto_cfg]\npub mod api;\n", false)]
+    fn doc_auto_cfg_is_detected(#[case] source: &str, #[case] enabled: bool) {
+        assert_eq!(enables_doc_auto_cfg(source), enabled);
+    }
+
+    #[rstest]
+    fn attributes_wrap_the_predicate() {
+        assert_eq!(
+            doc_cfg_attribute("docsrs", "all(feature = \"serde\", unix)"),
+            "#[cfg_attr(docsrs, doc(cfg(all(feature = \"serde\", unix))))]"
+        );
+    }
+}
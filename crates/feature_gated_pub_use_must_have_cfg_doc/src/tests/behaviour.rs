@@ -0,0 +1,94 @@
+//! Behaviour-driven coverage for the feature-gated re-export policy.
+
+use crate::policy::undocumented_reexports;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct ReexportWorld {
+    attributes: RefCell<Vec<String>>,
+    docs_cfg: RefCell<String>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> ReexportWorld {
+    ReexportWorld {
+        attributes: RefCell::new(Vec::new()),
+        docs_cfg: RefCell::new(String::from("docsrs")),
+        reported: Cell::new(None),
+    }
+}
+
+#[given("a re-export gated on feature {feature}")]
+fn given_gated(world: &ReexportWorld, feature: String) {
+    world
+        .attributes
+        .borrow_mut()
+        .push(format!("#[cfg(feature = \"{feature}\")]"));
+}
+
+#[given("the re-export is documented under {cfg}")]
+fn given_documented(world: &ReexportWorld, cfg: String) {
+    world
+        .attributes
+        .borrow_mut()
+        .push(format!("#[cfg_attr({cfg}, doc(cfg(feature = \"serde\")))]"));
+}
+
+#[given("the docs cfg is {cfg}")]
+fn given_docs_cfg(world: &ReexportWorld, cfg: String) {
+    *world.docs_cfg.borrow_mut() = cfg;
+}
+
+#[when("the module is checked")]
+fn when_checked(world: &ReexportWorld) {
+    let source = format!(
+        "{}\npub use crate::ser::Serializer;\n",
+        world.attributes.borrow().join("\n")
+    );
+    let found = undocumented_reexports(&source, &world.docs_cfg.borrow());
+    world.reported.set(Some(!found.is_empty()));
+}
+
+#[then("the re-export is reported")]
+fn then_reported(world: &ReexportWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("the re-export is accepted")]
+fn then_accepted(world: &ReexportWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/feature_gated_pub_use_must_have_cfg_doc.feature",
+    index = 0
+)]
+fn scenario_undocumented(world: ReexportWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/feature_gated_pub_use_must_have_cfg_doc.feature",
+    index = 1
+)]
+fn scenario_documented(world: ReexportWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/feature_gated_pub_use_must_have_cfg_doc.feature",
+    index = 2
+)]
+fn scenario_other_cfg(world: ReexportWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/feature_gated_pub_use_must_have_cfg_doc.feature",
+    index = 3
+)]
+fn scenario_configured_cfg(world: ReexportWorld) {
+    let _ = world;
+}
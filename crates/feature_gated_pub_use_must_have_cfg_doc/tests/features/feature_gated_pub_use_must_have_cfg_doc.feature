@@ -0,0 +1,27 @@
+Feature: Feature-gated pub use must have cfg doc
+  Re-exports gated on a feature are reported unless a doc(cfg) attribute
+  under the docs cfg names the same features.
+
+  Scenario: An undocumented gated re-export is reported
+    Given a re-export gated on feature serde
+    When the module is checked
+    Then the re-export is reported
+
+  Scenario: A documented gated re-export is accepted
+    Given a re-export gated on feature serde
+    And the re-export is documented under docsrs
+    When the module is checked
+    Then the re-export is accepted
+
+  Scenario: Documentation under another cfg is reported
+    Given a re-export gated on feature serde
+    And the re-export is documented under doc
+    When the module is checked
+    Then the re-export is reported
+
+  Scenario: A configured docs cfg is honoured
+    Given a re-export gated on feature serde
+    And the re-export is documented under doc
+    And the docs cfg is doc
+    When the module is checked
+    Then the re-export is accepted
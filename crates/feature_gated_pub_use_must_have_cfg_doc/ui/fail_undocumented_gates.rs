@@ -0,0 +1,14 @@
+//! Negative UI fixture: feature-gated re-exports without `doc(cfg(...))`.
+#![crate_type = "lib"]
+#![warn(feature_gated_pub_use_must_have_cfg_doc)]
+
+pub mod ser {
+    pub struct Serializer;
+}
+
+#[cfg(feature = "serde")]
+pub use crate::ser::Serializer;
+
+/// JSON serializer, on Unix.
+#[cfg(all(feature = "json", unix))]
+pub use crate::ser::Serializer as JsonSerializer;
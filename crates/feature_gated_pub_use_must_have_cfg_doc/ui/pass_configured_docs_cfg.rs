@@ -0,0 +1,11 @@
+//! Positive UI fixture: the configured docs `cfg` guards `doc(cfg(...))`.
+#![crate_type = "lib"]
+#![warn(feature_gated_pub_use_must_have_cfg_doc)]
+
+pub mod ser {
+    pub struct Serializer;
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc, doc(cfg(feature = "serde")))]
+pub use crate::ser::Serializer;
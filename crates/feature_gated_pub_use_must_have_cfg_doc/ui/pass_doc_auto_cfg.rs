@@ -0,0 +1,11 @@
+//! Positive UI fixture: crates enabling `doc_auto_cfg` are exempt.
+#![crate_type = "lib"]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![warn(feature_gated_pub_use_must_have_cfg_doc)]
+
+pub mod ser {
+    pub struct Serializer;
+}
+
+#[cfg(feature = "serde")]
+pub use crate::ser::Serializer;
@@ -0,0 +1,22 @@
+//! Positive UI fixture: documented gates, restricted re-exports, and
+//! ungated re-exports are accepted.
+#![crate_type = "lib"]
+#![warn(feature_gated_pub_use_must_have_cfg_doc)]
+
+pub mod ser {
+    pub struct Serializer;
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use crate::ser::Serializer;
+
+/// JSON serializer, on Unix.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "json", unix))))]
+#[cfg(all(feature = "json", unix))]
+pub use crate::ser::Serializer as JsonSerializer;
+
+#[cfg(feature = "serde")]
+pub(crate) use crate::ser::Serializer as InternalSerializer;
+
+pub use crate::ser::Serializer as DefaultSerializer;
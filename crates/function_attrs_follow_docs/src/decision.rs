@@ -0,0 +1,109 @@
+//! Pure decision logic for `function_attrs_follow_docs`.
+//!
+//! The driver collects the attributes on each function, method and trait
+//! method in source order. [`FunctionAttrsFollowDocsDecision`] hands the
+//! same sequence to [`detect_misordered_doc`] and names the attribute the
+//! doc comment follows as the finding's subject.
+
+use rustc_span::{DUMMY_SP, Span};
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::driver::attributes::{OrderedAttribute, detect_misordered_doc};
+
+/// Reason key for doc comments that follow another outer attribute.
+const DOC_AFTER_ATTRIBUTE: &str = "doc_after_attribute";
+
+/// One attribute on a function, as written in the source.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AttributeProjection {
+    /// The attribute's path, such as `inline`, or `doc` for doc comments.
+    pub name: String,
+    /// Whether the attribute is an outer attribute.
+    pub outer: bool,
+}
+
+impl OrderedAttribute for AttributeProjection {
+    fn is_outer(&self) -> bool {
+        self.outer
+    }
+
+    fn is_doc(&self) -> bool {
+        self.name == "doc"
+    }
+
+    fn span(&self) -> Span {
+        DUMMY_SP
+    }
+}
+
+/// The facts about one function that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FunctionAttributesProjection {
+    /// The function's attributes in source order.
+    pub attributes: Vec<AttributeProjection>,
+}
+
+/// Reports functions whose doc comments follow other outer attributes.
+///
+/// # Examples
+///
+/// ```ignore
+/// let outer = |name: &str| AttributeProjection { name: name.to_owned(), outer: true };
+/// let function = FunctionAttributesProjection {
+///     attributes: vec![outer("inline"), outer("doc")],
+/// };
+/// assert!(FunctionAttrsFollowDocsDecision.decide(&function).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FunctionAttrsFollowDocsDecision;
+
+impl LintDecision for FunctionAttrsFollowDocsDecision {
+    type Projection = FunctionAttributesProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, function: &FunctionAttributesProjection) -> Option<Finding> {
+        let (_, offending) = detect_misordered_doc(&function.attributes)?;
+        let attribute = function.attributes.get(offending)?;
+        Some(Finding::new(LINT_NAME, DOC_AFTER_ATTRIBUTE).with_subject(attribute.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<FunctionAttributesProjection>> {
+        let attribute = |name: &str, outer| AttributeProjection {
+            name: name.to_owned(),
+            outer,
+        };
+        let function = |attributes| FunctionAttributesProjection { attributes };
+        vec![
+            DecisionFixture::flagged(
+                "doc after inline",
+                function(vec![attribute("inline", true), attribute("doc", true)]),
+                DOC_AFTER_ATTRIBUTE,
+            ),
+            DecisionFixture::clean(
+                "doc first",
+                function(vec![attribute("doc", true), attribute("inline", true)]),
+            ),
+            DecisionFixture::clean(
+                "inner doc after outer attribute",
+                function(vec![attribute("inline", true), attribute("doc", false)]),
+            ),
+            DecisionFixture::clean("undocumented", function(vec![attribute("inline", true)])),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&FunctionAttrsFollowDocsDecision), Ok(4));
+    }
+}
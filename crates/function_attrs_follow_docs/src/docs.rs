@@ -0,0 +1,25 @@
+//! Embedded documentation for the `function_attrs_follow_docs` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, examples, and configuration for `function_attrs_follow_docs`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "function_attrs_follow_docs",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Doc comments on functions must precede other outer attributes.",
+    rationale: "Keeping documentation first makes the purpose of a function visible before its configuration, and keeps rustdoc output and review diffs consistent.",
+    bad_example: concat!(
+        "#[inline]\n",
+        "/// Returns the answer.\n",
+        "fn answer() -> u32 { 42 }",
+    ),
+    good_example: concat!(
+        "/// Returns the answer.\n",
+        "#[inline]\n",
+        "fn answer() -> u32 { 42 }",
+    ),
+    config: &[],
+};
@@ -0,0 +1,127 @@
+//! Source edits that move misplaced doc comments above outer attributes.
+//!
+//! [`plan_reorder`] works on plain source text so the edit can be tested
+//! without the compiler: it inserts every doc comment that follows the first
+//! non-doc outer attribute directly above that attribute, and removes the
+//! lines the doc comments used to occupy. [`reorder_suggestion`] maps the
+//! plan back onto spans for a multipart suggestion.
+
+use std::ops::Range;
+
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::{BytePos, Span};
+
+/// Byte-offset edits that move doc comments above an attribute.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct Reorder {
+    /// Offset where the moved doc comments are inserted.
+    pub(super) insert_at: usize,
+    /// The doc comments, each followed by a newline and the attribute's
+    /// indentation.
+    pub(super) insertion: String,
+    /// The lines the doc comments occupied, including their line breaks.
+    pub(super) removals: Vec<Range<usize>>,
+}
+
+/// Plans moving the `docs` ranges of `source` above the `anchor` attribute.
+///
+/// Returns `None` unless the anchor starts its line and every doc comment
+/// follows it on lines of its own, so the edit never joins an attribute or an
+/// item onto a `///` comment.
+///
+/// # Examples
+///
+/// ```ignore
+/// let source = "#[inline]\n/// Docs.\nfn demo() {}\n";
+/// let plan = plan_reorder(source, 0..9, &[10..19]).expect("lines are separate");
+/// assert_eq!(plan.insertion, "/// Docs.\n");
+/// assert_eq!(plan.removals, [10..20]);
+/// ```
+#[must_use]
+pub(super) fn plan_reorder(
+    source: &str,
+    anchor: Range<usize>,
+    docs: &[Range<usize>],
+) -> Option<Reorder> {
+    let indent = leading_blank(source, anchor.start)?;
+    let mut insertion = String::new();
+    let mut removals = Vec::with_capacity(docs.len());
+    for doc in docs {
+        if doc.start < anchor.end {
+            return None;
+        }
+        let prefix = leading_blank(source, doc.start)?;
+        let rest = source.get(doc.end..)?;
+        let line_break = rest.find('\n').unwrap_or(rest.len());
+        if !is_blank(&rest[..line_break]) {
+            return None;
+        }
+        let end = (doc.end + line_break + 1).min(source.len());
+        removals.push(doc.start - prefix.len()..end);
+        insertion.push_str(source.get(doc.clone())?);
+        insertion.push('\n');
+        insertion.push_str(indent);
+    }
+    Some(Reorder {
+        insert_at: anchor.start,
+        insertion,
+        removals,
+    })
+}
+
+/// Returns the text between the start of the line and `offset` when it is
+/// only whitespace.
+fn leading_blank(source: &str, offset: usize) -> Option<&str> {
+    let before = source.get(..offset)?;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let prefix = &before[line_start..];
+    is_blank(prefix).then_some(prefix)
+}
+
+fn is_blank(text: &str) -> bool {
+    text.chars().all(|ch| ch != '\n' && ch.is_whitespace())
+}
+
+/// Builds the multipart suggestion moving `docs` above the `anchor`
+/// attribute, or `None` when the source is unavailable or the layout cannot
+/// be rewritten safely.
+pub(super) fn reorder_suggestion(
+    cx: &LateContext<'_>,
+    anchor: Span,
+    docs: &[Span],
+) -> Option<Vec<(Span, String)>> {
+    let source_map = cx.sess().source_map();
+    let last = docs.last()?;
+    let start = source_map.lookup_line(anchor.lo()).ok()?;
+    let end = source_map.lookup_line(last.hi()).ok()?;
+    if start.sf.start_pos != end.sf.start_pos {
+        return None;
+    }
+    let region = anchor
+        .with_lo(start.sf.line_bounds(start.line).start)
+        .with_hi(end.sf.line_bounds(end.line).end);
+    let source = source_map.span_to_snippet(region).ok()?;
+
+    let base = region.lo().0;
+    let offsets = |span: Span| -> Option<Range<usize>> {
+        let lo = span.lo().0.checked_sub(base)?;
+        let hi = span.hi().0.checked_sub(base)?;
+        Some(usize::try_from(lo).ok()?..usize::try_from(hi).ok()?)
+    };
+    let doc_ranges = docs
+        .iter()
+        .map(|&doc| offsets(doc))
+        .collect::<Option<Vec<_>>>()?;
+    let plan = plan_reorder(&source, offsets(anchor)?, &doc_ranges)?;
+
+    let span_of = |range: Range<usize>| -> Option<Span> {
+        let lo = base.checked_add(u32::try_from(range.start).ok()?)?;
+        let hi = base.checked_add(u32::try_from(range.end).ok()?)?;
+        Some(region.with_lo(BytePos(lo)).with_hi(BytePos(hi)))
+    };
+    let mut parts = vec![(span_of(plan.insert_at..plan.insert_at)?, plan.insertion)];
+    for removal in plan.removals {
+        parts.push((span_of(removal)?, String::new()));
+    }
+    Some(parts)
+}
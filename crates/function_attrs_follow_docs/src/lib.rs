@@ -4,7 +4,8 @@
 //! The `driver` module holds the lint pass and the ordering logic,
 //! including the recovery of user-written spans from parsed
 //! `AttributeKind` variants and the item-boundary check that tolerates
-//! outer attributes sitting immediately before the item span. Unit and
+//! outer attributes sitting immediately before the item span, and the
+//! machine-applicable suggestion that moves misplaced doc comments. Unit and
 //! behavioural tests live alongside the driver under `tests`. When the
 //! `dylint-driver` feature is disabled, the crate retains only a tiny
 //! internal stub so the package still builds cleanly in non-driver
@@ -15,9 +16,23 @@
 #[cfg(feature = "dylint-driver")]
 mod driver;
 
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
 
+#[cfg(feature = "dylint-driver")]
+pub use decision::{
+    AttributeProjection, FunctionAttributesProjection, FunctionAttrsFollowDocsDecision,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
 #[cfg(not(feature = "dylint-driver"))]
 mod stub {
     #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
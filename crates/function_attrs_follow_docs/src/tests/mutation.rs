@@ -0,0 +1,43 @@
+//! Mutation-style self-tests for the attribute ordering detector.
+//!
+//! Perturbs fixture sources and checks that `detect_misordered_doc` follows
+//! attribute order, ignores which attribute follows the docs, and stops
+//! reporting items once they are generated by a macro.
+
+use super::detect_misordered_doc;
+use rstest::rstest;
+use whitaker_common::test_support::mutation::{
+    Mutation, MutationCase, check_mutations, scan_items,
+};
+
+const FIXTURE: &str = "\
+/// Documented before its attributes.
+#[inline]
+fn ordered() {}
+
+#[inline]
+/// Documented after its attributes.
+fn misordered() {}
+";
+
+/// Names the items whose doc comments follow another outer attribute.
+fn misordered_items(source: &str) -> Vec<String> {
+    scan_items(source)
+        .into_iter()
+        .filter(|item| detect_misordered_doc(item.attributes()).is_some())
+        .map(|item| item.name().to_owned())
+        .collect()
+}
+
+#[rstest]
+fn baseline_reports_only_the_misordered_item() {
+    assert_eq!(misordered_items(FIXTURE), ["misordered"]);
+}
+
+#[rstest]
+#[case::reorder(MutationCase::changes(Mutation::ReorderAttributes))]
+#[case::rename(MutationCase::stable(Mutation::rename_attribute("inline", "cold")))]
+#[case::macro_generated(MutationCase::changes(Mutation::wrap_in_macro("generated")))]
+fn detector_responds_to_mutations(#[case] case: MutationCase) {
+    assert_eq!(check_mutations(FIXTURE, &[case], misordered_items), Ok(1));
+}
@@ -0,0 +1,121 @@
+//! Unit tests for the doc comment reorder suggestion.
+//!
+//! Covers the source edits `plan_reorder` produces for common layouts, the
+//! layouts it refuses to rewrite, and the localized suggestion label.
+
+use super::reorder::{Reorder, plan_reorder};
+use super::{Localizer, fallback_suggestion, suggestion_label};
+use rstest::rstest;
+use std::borrow::Cow;
+use whitaker_common::i18n::{Arguments, FluentValue};
+
+/// Returns the byte range of the first occurrence of `needle` in `source`.
+fn range_of(source: &str, needle: &str) -> std::ops::Range<usize> {
+    let start = source.find(needle).expect("needle should occur in source");
+    start..start + needle.len()
+}
+
+/// Applies `plan` to `source`, as `cargo fix` would.
+fn apply(source: &str, plan: &Reorder) -> String {
+    let mut edits: Vec<(std::ops::Range<usize>, &str)> = plan
+        .removals
+        .iter()
+        .map(|removal| (removal.clone(), ""))
+        .collect();
+    edits.push((plan.insert_at..plan.insert_at, plan.insertion.as_str()));
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+
+    let mut fixed = source.to_owned();
+    for (range, replacement) in edits {
+        fixed.replace_range(range, replacement);
+    }
+    fixed
+}
+
+fn fix(source: &str, anchor: &str, docs: &[&str]) -> Option<String> {
+    let doc_ranges: Vec<_> = docs.iter().map(|doc| range_of(source, doc)).collect();
+    plan_reorder(source, range_of(source, anchor), &doc_ranges).map(|plan| apply(source, &plan))
+}
+
+#[rstest]
+#[case::single_doc(
+    "#[inline]\n/// Docs.\nfn demo() {}\n",
+    &["/// Docs."],
+    "/// Docs.\n#[inline]\nfn demo() {}\n"
+)]
+#[case::indented_method(
+    "    #[inline]\n    /// First.\n    /// Second.\n    fn demo() {}\n",
+    &["/// First.", "/// Second."],
+    "    /// First.\n    /// Second.\n    #[inline]\n    fn demo() {}\n"
+)]
+#[case::interleaved_attributes(
+    "#[inline]\n/// First.\n#[must_use]\n/// Second.\nfn demo() {}\n",
+    &["/// First.", "/// Second."],
+    "/// First.\n/// Second.\n#[inline]\n#[must_use]\nfn demo() {}\n"
+)]
+#[case::doc_attribute(
+    "#[inline]\n#[doc = \"Docs.\"]\nfn demo() {}\n",
+    &["#[doc = \"Docs.\"]"],
+    "#[doc = \"Docs.\"]\n#[inline]\nfn demo() {}\n"
+)]
+#[case::crlf_line_endings(
+    "#[inline]\r\n/// Docs.\r\nfn demo() {}\r\n",
+    &["/// Docs."],
+    "/// Docs.\n#[inline]\r\nfn demo() {}\r\n"
+)]
+fn misplaced_docs_move_above_the_attribute(
+    #[case] source: &str,
+    #[case] docs: &[&str],
+    #[case] expected: &str,
+) {
+    assert_eq!(fix(source, "#[inline]", docs).as_deref(), Some(expected));
+}
+
+#[rstest]
+#[case::attribute_after_code("fn a() {} #[inline]\n/// Docs.\nfn demo() {}\n", "/// Docs.")]
+#[case::doc_shares_a_line("#[inline]\n#[doc = \"Docs.\"] fn demo() {}\n", "#[doc = \"Docs.\"]")]
+#[case::doc_after_attribute_on_one_line("#[inline] /// Docs.\nfn demo() {}\n", "/// Docs.")]
+fn shared_lines_are_not_rewritten(#[case] source: &str, #[case] doc: &str) {
+    assert_eq!(fix(source, "#[inline]", &[doc]), None);
+}
+
+#[rstest]
+fn docs_overlapping_the_attribute_are_not_rewritten() {
+    let source = "#[inline]\nfn demo() {}\n";
+    let anchor = range_of(source, "#[inline]");
+
+    assert_eq!(plan_reorder(source, anchor.clone(), &[anchor]), None);
+}
+
+fn label_args(attribute: &str) -> Arguments<'static> {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("attribute"),
+        FluentValue::from(attribute.to_owned()),
+    );
+    args
+}
+
+#[rstest]
+fn english_suggestion_label_matches_the_fallback() {
+    let localizer = Localizer::new(Some("en-GB"));
+
+    assert_eq!(
+        suggestion_label(&localizer, &label_args("#[inline]"), "#[inline]"),
+        fallback_suggestion("#[inline]")
+    );
+}
+
+#[rstest]
+#[case::welsh("cy")]
+#[case::gaelic("gd")]
+fn translated_suggestion_labels_name_the_attribute(#[case] locale: &str) {
+    let label = suggestion_label(
+        &Localizer::new(Some(locale)),
+        &label_args("#[inline]"),
+        "#[inline]",
+    );
+
+    assert!(label.ends_with("#[inline]"));
+    assert_ne!(label, fallback_suggestion("#[inline]"));
+}
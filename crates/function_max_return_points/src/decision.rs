@@ -0,0 +1,144 @@
+//! Pure decision logic for `function_max_return_points`.
+//!
+//! The driver records every explicit `return` and `?` in a function body
+//! with the index of the top-level statement it sits in.
+//! [`FunctionMaxReturnPointsDecision`] counts them with the same
+//! [`ReturnPointPolicy`], skipping the guard-clause prologue, and reports
+//! functions with more exits than the limit.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_GUARD_CLAUSE_STATEMENTS, DEFAULT_MAX_RETURN_POINTS, LINT_NAME};
+use crate::policy::{ExitKind, ExitPoint, ReturnPointPolicy};
+
+/// Reason key for functions with too many exits.
+const EXCEEDS_LIMIT: &str = "exceeds_limit";
+
+/// One exit from a function body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExitProjection {
+    /// Whether the exit is a `?` rather than an explicit `return`.
+    pub question: bool,
+    /// The index of the top-level statement holding the exit.
+    pub statement: usize,
+}
+
+/// The facts about one function body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FunctionExitsProjection {
+    /// The body's exits, in source order.
+    pub exits: Vec<ExitProjection>,
+}
+
+/// Reports functions with more exits than `max_return_points`, not counting
+/// exits in the first `guard_clause_statements` statements.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = FunctionMaxReturnPointsDecision::new(1, 0);
+/// let exit = ExitProjection { question: true, statement: 0 };
+/// let body = FunctionExitsProjection { exits: vec![exit, exit] };
+/// assert!(decision.decide(&body).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FunctionMaxReturnPointsDecision {
+    policy: ReturnPointPolicy,
+}
+
+impl FunctionMaxReturnPointsDecision {
+    /// Creates a decision allowing `max_return_points` exits after the first
+    /// `guard_clause_statements` statements.
+    #[must_use]
+    pub const fn new(max_return_points: usize, guard_clause_statements: usize) -> Self {
+        Self {
+            policy: ReturnPointPolicy::new(max_return_points, guard_clause_statements),
+        }
+    }
+
+    /// Returns the exit limit.
+    #[must_use]
+    pub const fn max_return_points(&self) -> usize {
+        self.policy.max_return_points()
+    }
+}
+
+impl Default for FunctionMaxReturnPointsDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_RETURN_POINTS, DEFAULT_GUARD_CLAUSE_STATEMENTS)
+    }
+}
+
+impl LintDecision for FunctionMaxReturnPointsDecision {
+    type Projection = FunctionExitsProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, body: &FunctionExitsProjection) -> Option<Finding> {
+        let exits: Vec<ExitPoint> = body
+            .exits
+            .iter()
+            .map(|exit| ExitPoint {
+                kind: if exit.question {
+                    ExitKind::Question
+                } else {
+                    ExitKind::Return
+                },
+                statement: exit.statement,
+            })
+            .collect();
+        self.policy
+            .excess(&exits)
+            .map(|_| Finding::new(LINT_NAME, EXCEEDS_LIMIT))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<FunctionExitsProjection>> {
+        let body = |count: usize, statement: usize| FunctionExitsProjection {
+            exits: (0..count)
+                .map(|index| ExitProjection {
+                    question: index % 2 == 0,
+                    statement,
+                })
+                .collect(),
+        };
+        let after_guards = usize::MAX;
+        let mut fixtures = vec![
+            DecisionFixture::clean("no exits", body(0, after_guards)),
+            DecisionFixture::clean(
+                "exactly at the limit",
+                body(self.max_return_points(), after_guards),
+            ),
+            DecisionFixture::flagged(
+                "one exit over the limit",
+                body(self.max_return_points() + 1, after_guards),
+                EXCEEDS_LIMIT,
+            ),
+        ];
+        if *self != Self::new(self.max_return_points(), 0) {
+            fixtures.push(DecisionFixture::clean(
+                "extra exits in the guard clauses",
+                body(self.max_return_points() + 1, 0),
+            ));
+        }
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_policy(FunctionMaxReturnPointsDecision::default(), 4)]
+    #[case::no_guard_clauses(FunctionMaxReturnPointsDecision::new(1, 0), 3)]
+    fn decision_conforms(
+        #[case] decision: FunctionMaxReturnPointsDecision,
+        #[case] fixtures: usize,
+    ) {
+        assert_eq!(check_conformance(&decision), Ok(fixtures));
+    }
+}
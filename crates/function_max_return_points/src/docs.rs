@@ -0,0 +1,29 @@
+//! Embedded documentation for the `function_max_return_points` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `function_max_return_points`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "function_max_return_points",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags functions and methods whose explicit `return` expressions and `?` operators exceed the configured number of exits.",
+    rationale: "Every early exit is another path out of the function, and readers must trace each one to know what state the function leaves behind. Many exits signal a function doing several jobs, even when its nesting stays shallow.",
+    bad_example: "fn load(path: &Path) -> Result<Config> {\n    let text = read(path)?;\n    let raw = parse(&text)?;\n    let merged = merge(raw)?;\n    if merged.is_legacy() { return upgrade(merged); }\n    let checked = validate(merged)?;\n    if checked.is_empty() { return Ok(Config::default()); }\n    Ok(finish(checked)?)\n}",
+    good_example: "fn load(path: &Path) -> Result<Config> {\n    let raw = parse(&read(path)?)?;\n    resolve(merge(raw)?)\n}",
+    config: &[
+        ConfigKeyDocs {
+            key: "max_return_points",
+            default: "4",
+            description: "Largest number of `return` expressions and `?` operators a function may contain.",
+        },
+        ConfigKeyDocs {
+            key: "guard_clause_statements",
+            default: "2",
+            description: "Number of leading statements whose exits are not counted, so guard clauses are not penalised.",
+        },
+    ],
+};
@@ -0,0 +1,378 @@
+//! Lint pass counting the early exits of functions and methods.
+//!
+//! The pass walks the top-level block of each function body, recording every
+//! explicit `return` and every `?` operator together with the statement it
+//! belongs to, and hands the exits to [`ReturnPointPolicy`]. Closures and
+//! nested items are separate bodies and are not walked, while `async fn`
+//! bodies are unwrapped from their desugared coroutine so they are counted
+//! like any other function. Functions produced by macro expansion are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::intravisit::{self, FnKind, Visitor};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use rustc_span::def_id::LocalDefId;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, describe_enclosing_item};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{ExitKind, ExitPoint, ReturnPointCount, ReturnPointPolicy};
+
+pub(crate) const LINT_NAME: &str = "function_max_return_points";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_RETURN_POINTS: usize = 4;
+pub(crate) const DEFAULT_GUARD_CLAUSE_STATEMENTS: usize = 2;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_return_points: usize,
+    guard_clause_statements: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_return_points: DEFAULT_MAX_RETURN_POINTS,
+            guard_clause_statements: DEFAULT_GUARD_CLAUSE_STATEMENTS,
+        }
+    }
+}
+
+impl Config {
+    const fn policy(&self) -> ReturnPointPolicy {
+        ReturnPointPolicy::new(self.max_return_points, self.guard_clause_statements)
+    }
+}
+
+/// Lint pass that counts function exits.
+#[derive(Default)]
+pub struct FunctionMaxReturnPoints {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Exit limits, finding limit, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub FUNCTION_MAX_RETURN_POINTS,
+    Warn,
+    "functions should not have more `return` and `?` exits than the configured limit",
+    FunctionMaxReturnPoints::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for FunctionMaxReturnPoints {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(FUNCTION_MAX_RETURN_POINTS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _decl: &'tcx hir::FnDecl<'tcx>,
+        body: &'tcx hir::Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure) || span.from_expansion() {
+            return;
+        }
+        let Some(block) = user_block(cx, body) else {
+            return;
+        };
+        let state = &*self.state;
+        let policy = state.findings.config_at(cx, span, &state.config).policy();
+        let Some(count) = policy.excess(&collect_exits(block)) else {
+            return;
+        };
+
+        let enclosing = describe_enclosing_item(cx, body.value.hir_id);
+        let context = enclosing.describe(&state.localizer);
+        debug!(
+            target: LINT_NAME,
+            "{context} has {} return points (limit {})",
+            count.total(),
+            policy.max_return_points(),
+        );
+        let span = enclosing.span.unwrap_or(span);
+        if !state
+            .findings
+            .admit(cx, cx.tcx.local_def_id_to_hir_id(def_id), span)
+        {
+            return;
+        }
+        let message = ReturnPointsMessage {
+            context: &context,
+            count,
+            limit: policy.max_return_points(),
+        };
+        emit_diagnostic(cx, span, &message, &state.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the block the author wrote as the function body, unwrapping the
+/// coroutine an `async fn` is lowered to.
+fn user_block<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx hir::Body<'tcx>,
+) -> Option<&'tcx hir::Block<'tcx>> {
+    match body.value.kind {
+        hir::ExprKind::Block(block, _) => Some(block),
+        hir::ExprKind::Closure(closure)
+            if matches!(
+                closure.kind,
+                hir::ClosureKind::Coroutine(hir::CoroutineKind::Desugared(
+                    _,
+                    hir::CoroutineSource::Fn
+                ))
+            ) =>
+        {
+            // The coroutine body is `{ let param = param; ... drop-temps { <user body> } }`.
+            let hir::ExprKind::Block(wrapper, _) = cx.tcx.hir_body(closure.body).value.kind else {
+                return None;
+            };
+            let hir::ExprKind::DropTemps(user_body) = wrapper.expr?.kind else {
+                return None;
+            };
+            match user_body.kind {
+                hir::ExprKind::Block(block, _) => Some(block),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Records the exits in `block`, tagging each with its top-level statement.
+fn collect_exits(block: &hir::Block<'_>) -> Vec<ExitPoint> {
+    let mut collector = ExitCollector::default();
+    for (index, stmt) in block.stmts.iter().enumerate() {
+        collector.statement = index;
+        collector.visit_stmt(stmt);
+    }
+    if let Some(expr) = block.expr {
+        collector.statement = block.stmts.len();
+        collector.visit_expr(expr);
+    }
+    collector.exits
+}
+
+/// Visitor collecting `return` expressions and `?` operators. Nested bodies,
+/// such as closures, are not entered.
+#[derive(Default)]
+struct ExitCollector {
+    statement: usize,
+    exits: Vec<ExitPoint>,
+}
+
+impl<'tcx> Visitor<'tcx> for ExitCollector {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        let kind = match expr.kind {
+            // The `return` inside a `?` desugaring is counted as the `?`.
+            hir::ExprKind::Ret(_) if expr.span.desugaring_kind().is_none() => {
+                Some(ExitKind::Return)
+            }
+            hir::ExprKind::Match(_, _, hir::MatchSource::TryDesugar(_)) => Some(ExitKind::Question),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            self.exits.push(ExitPoint {
+                kind,
+                statement: self.statement,
+            });
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &ReturnPointsMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        FUNCTION_MAX_RETURN_POINTS,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a function with too many exits
+/// interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ReturnPointsMessage<'a> {
+    context: &'a str,
+    count: ReturnPointCount,
+    limit: usize,
+}
+
+impl ReturnPointsMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(self.context.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("total"),
+            FluentValue::from(self.count.total()),
+        );
+        args.insert(
+            Cow::Borrowed("returns"),
+            FluentValue::from(self.count.returns),
+        );
+        args.insert(
+            Cow::Borrowed("questions"),
+            FluentValue::from(self.count.questions),
+        );
+        args.insert(Cow::Borrowed("limit"), FluentValue::from(self.limit));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            context,
+            count,
+            limit,
+        } = *self;
+        let total = count.total();
+        let returns = count.returns;
+        let questions = count.questions;
+        DiagnosticMessageSet::new(
+            format!("Found {total} return points in {context}, above the limit of {limit}."),
+            format!(
+                "Outside its guard clauses the function has {returns} `return` and {questions} \
+                 `?`, and each is another path readers must trace."
+            ),
+            String::from(
+                "Extract the steps between exits into helper functions, or combine related \
+                 checks so the function has fewer ways out.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message() -> ReturnPointsMessage<'static> {
+        ReturnPointsMessage {
+            context: "function `load`",
+            count: ReturnPointCount {
+                returns: 2,
+                questions: 4,
+            },
+            limit: 4,
+        }
+    }
+
+    fn resolve(message: &ReturnPointsMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_allows_four_exits_after_two_guards() {
+        assert_eq!(Config::default().policy(), ReturnPointPolicy::new(4, 2));
+    }
+
+    #[rstest]
+    fn fallback_messages_give_the_total_and_breakdown() {
+        let messages = message().fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Found 6 return points in function `load`, above the limit of 4."
+        );
+        assert!(messages.note().contains("2 `return` and 4 `?`"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        let message = message();
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(), locale);
+
+        assert!(messages.primary().contains("`load`"));
+        assert!(messages.primary().contains('6'));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,42 @@
+//! Lint crate flagging functions with too many early exits.
+//!
+//! Each explicit `return` and each `?` operator adds a path out of a
+//! function, and readers must keep every one in mind to know what state the
+//! function leaves behind. The lint counts these exits per function and
+//! method and reports those above `max_return_points`, a complexity signal
+//! independent of nesting depth. Exits in the first
+//! `guard_clause_statements` statements are ignored so that a prologue of
+//! guard clauses is not penalised. The lint is experimental and ships behind
+//! the `experimental-function-max-return-points` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ExitProjection, FunctionExitsProjection, FunctionMaxReturnPointsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn function_max_return_points_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
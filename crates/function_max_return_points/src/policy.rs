@@ -0,0 +1,160 @@
+//! Counting rules for the exits of a function.
+//!
+//! The driver records every explicit `return` and every `?` in a function
+//! body as an [`ExitPoint`], tagged with the index of the top-level statement
+//! it sits in. [`ReturnPointPolicy`] then drops the exits in the guard-clause
+//! prologue and compares the rest with the configured limit, keeping the
+//! rules free of compiler types so they can be tested directly.
+
+/// How a function exits early.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ExitKind {
+    /// An explicit `return` expression.
+    Return,
+    /// A `?` operator, which returns on the error or `None` path.
+    Question,
+}
+
+/// One early exit and where it sits in the function body.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct ExitPoint {
+    /// How the function exits.
+    pub(crate) kind: ExitKind,
+    /// Index of the top-level statement containing the exit. Exits in the
+    /// body's trailing expression use the number of statements.
+    pub(crate) statement: usize,
+}
+
+/// The exits counted against the limit.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct ReturnPointCount {
+    /// Counted `return` expressions.
+    pub(crate) returns: usize,
+    /// Counted `?` operators.
+    pub(crate) questions: usize,
+}
+
+impl ReturnPointCount {
+    /// Returns the number of counted exits.
+    #[must_use]
+    pub(crate) const fn total(self) -> usize {
+        self.returns + self.questions
+    }
+}
+
+/// The configured limit and guard-clause allowance.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct ReturnPointPolicy {
+    max_return_points: usize,
+    guard_clause_statements: usize,
+}
+
+impl ReturnPointPolicy {
+    /// Creates a policy allowing `max_return_points` exits and ignoring those
+    /// in the first `guard_clause_statements` statements.
+    #[must_use]
+    pub(crate) const fn new(max_return_points: usize, guard_clause_statements: usize) -> Self {
+        Self {
+            max_return_points,
+            guard_clause_statements,
+        }
+    }
+
+    /// Returns the configured limit.
+    #[must_use]
+    pub(crate) const fn max_return_points(self) -> usize {
+        self.max_return_points
+    }
+
+    /// Counts the exits outside the guard-clause prologue.
+    #[must_use]
+    pub(crate) fn count(self, exits: &[ExitPoint]) -> ReturnPointCount {
+        exits
+            .iter()
+            .filter(|exit| exit.statement >= self.guard_clause_statements)
+            .fold(ReturnPointCount::default(), |count, exit| match exit.kind {
+                ExitKind::Return => ReturnPointCount {
+                    returns: count.returns + 1,
+                    ..count
+                },
+                ExitKind::Question => ReturnPointCount {
+                    questions: count.questions + 1,
+                    ..count
+                },
+            })
+    }
+
+    /// Returns the counted exits when they exceed the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = ReturnPointPolicy::new(1, 0);
+    /// let exit = ExitPoint { kind: ExitKind::Return, statement: 0 };
+    /// assert_eq!(policy.excess(&[exit, exit]).map(ReturnPointCount::total), Some(2));
+    /// ```
+    #[must_use]
+    pub(crate) fn excess(self, exits: &[ExitPoint]) -> Option<ReturnPointCount> {
+        let count = self.count(exits);
+        (count.total() > self.max_return_points).then_some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn exit(kind: ExitKind, statement: usize) -> ExitPoint {
+        ExitPoint { kind, statement }
+    }
+
+    #[rstest]
+    fn count_separates_returns_from_questions() {
+        let exits = [
+            exit(ExitKind::Return, 0),
+            exit(ExitKind::Question, 1),
+            exit(ExitKind::Question, 1),
+        ];
+
+        assert_eq!(
+            ReturnPointPolicy::new(4, 0).count(&exits),
+            ReturnPointCount {
+                returns: 1,
+                questions: 2,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(0, 3)]
+    #[case(1, 2)]
+    #[case(2, 1)]
+    #[case(5, 0)]
+    fn guard_clause_statements_are_ignored(#[case] guard: usize, #[case] expected: usize) {
+        let exits = [
+            exit(ExitKind::Return, 0),
+            exit(ExitKind::Return, 1),
+            exit(ExitKind::Question, 3),
+        ];
+
+        assert_eq!(
+            ReturnPointPolicy::new(4, guard).count(&exits).total(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(2, None)]
+    #[case(3, None)]
+    #[case(4, Some(4))]
+    fn excess_reports_counts_above_the_limit(
+        #[case] exits: usize,
+        #[case] expected: Option<usize>,
+    ) {
+        let exits = vec![exit(ExitKind::Return, 2); exits];
+        let policy = ReturnPointPolicy::new(3, 0);
+
+        assert_eq!(policy.excess(&exits).map(ReturnPointCount::total), expected);
+    }
+}
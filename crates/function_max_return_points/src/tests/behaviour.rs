@@ -0,0 +1,102 @@
+//! Behaviour-driven coverage for the return point policy.
+
+use crate::policy::{ExitKind, ExitPoint, ReturnPointCount, ReturnPointPolicy};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct ReturnPointWorld {
+    max_return_points: Cell<usize>,
+    guard_clause_statements: Cell<usize>,
+    guard_clauses: Cell<usize>,
+    exits: RefCell<Vec<ExitKind>>,
+    result: Cell<Option<Option<ReturnPointCount>>>,
+}
+
+#[fixture]
+fn world() -> ReturnPointWorld {
+    ReturnPointWorld::default()
+}
+
+#[given("a limit of {limit} return points")]
+fn given_limit(world: &ReturnPointWorld, limit: usize) {
+    world.max_return_points.set(limit);
+}
+
+#[given("{statements} guard clause statements")]
+fn given_guard_statements(world: &ReturnPointWorld, statements: usize) {
+    world.guard_clause_statements.set(statements);
+}
+
+#[given("{clauses} guard clauses returning early")]
+fn given_guard_clauses(world: &ReturnPointWorld, clauses: usize) {
+    world.guard_clauses.set(clauses);
+}
+
+#[given("{returns} returns and {questions} question mark after the prologue")]
+fn given_exit(world: &ReturnPointWorld, returns: usize, questions: usize) {
+    given_exits(world, returns, questions);
+}
+
+#[given("{returns} returns and {questions} question marks after the prologue")]
+fn given_exits(world: &ReturnPointWorld, returns: usize, questions: usize) {
+    let mut exits = world.exits.borrow_mut();
+    exits.extend(std::iter::repeat_n(ExitKind::Return, returns));
+    exits.extend(std::iter::repeat_n(ExitKind::Question, questions));
+}
+
+#[when("the function is checked")]
+fn when_checked(world: &ReturnPointWorld) {
+    let guards = world.guard_clauses.get();
+    let guard_exits = (0..guards).map(|statement| ExitPoint {
+        kind: ExitKind::Return,
+        statement,
+    });
+    let body = world.exits.borrow();
+    let body_exits = body.iter().map(|&kind| ExitPoint {
+        kind,
+        statement: guards,
+    });
+    let exits: Vec<_> = guard_exits.chain(body_exits).collect();
+    let policy = ReturnPointPolicy::new(
+        world.max_return_points.get(),
+        world.guard_clause_statements.get(),
+    );
+    world.result.set(Some(policy.excess(&exits)));
+}
+
+#[then("the function is accepted")]
+fn then_accepted(world: &ReturnPointWorld) {
+    assert_eq!(world.result.get(), Some(None));
+}
+
+#[then("the function is reported with {total} return points")]
+fn then_reported(world: &ReturnPointWorld, total: usize) {
+    let count = world
+        .result
+        .get()
+        .flatten()
+        .unwrap_or_else(|| panic!("expected the function to be reported"));
+    assert_eq!(count.total(), total);
+}
+
+#[scenario(path = "tests/features/function_max_return_points.feature", index = 0)]
+fn scenario_within_limit(world: ReturnPointWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/function_max_return_points.feature", index = 1)]
+fn scenario_above_limit(world: ReturnPointWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/function_max_return_points.feature", index = 2)]
+fn scenario_guard_clauses_ignored(world: ReturnPointWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/function_max_return_points.feature", index = 3)]
+fn scenario_guard_clauses_counted(world: ReturnPointWorld) {
+    let _ = world;
+}
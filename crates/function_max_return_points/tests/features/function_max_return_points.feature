@@ -0,0 +1,31 @@
+Feature: Function return points
+  Functions are reported when their `return` expressions and `?` operators
+  outside the guard-clause prologue exceed the configured limit.
+
+  Scenario: A function within the limit is accepted
+    Given a limit of 3 return points
+    And 2 returns and 1 question mark after the prologue
+    When the function is checked
+    Then the function is accepted
+
+  Scenario: A function above the limit is reported
+    Given a limit of 3 return points
+    And 2 returns and 2 question marks after the prologue
+    When the function is checked
+    Then the function is reported with 4 return points
+
+  Scenario: Guard clauses in the prologue are not counted
+    Given a limit of 3 return points
+    And 2 guard clause statements
+    And 2 guard clauses returning early
+    And 2 returns and 1 question mark after the prologue
+    When the function is checked
+    Then the function is accepted
+
+  Scenario: Guard clauses count when the prologue is disabled
+    Given a limit of 3 return points
+    And 0 guard clause statements
+    And 2 guard clauses returning early
+    And 2 returns and 1 question mark after the prologue
+    When the function is checked
+    Then the function is reported with 5 return points
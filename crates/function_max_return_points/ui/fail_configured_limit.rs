@@ -0,0 +1,13 @@
+//! With `max_return_points = 1` and `guard_clause_statements = 0`, a guard
+//! clause followed by a `?` is reported.
+
+fn first_digit(text: &str) -> Option<u32> {
+    if text.is_empty() {
+        return None;
+    }
+    text.chars().next()?.to_digit(10)
+}
+
+fn main() {
+    let _ = first_digit("42");
+}
@@ -0,0 +1,49 @@
+//! Functions and methods with more than four `return` and `?` exits after
+//! their first two statements are reported, including `async fn`s.
+
+fn parse(text: &str) -> Option<u32> {
+    let trimmed = text.trim();
+    let first = trimmed.chars().next()?;
+    let digit = first.to_digit(10)?;
+    if digit == 0 {
+        return None;
+    }
+    let rest = trimmed.get(1..)?;
+    if rest.is_empty() {
+        return Some(digit);
+    }
+    let value: u32 = rest.parse().ok()?;
+    Some(digit * value)
+}
+
+struct Reader;
+
+impl Reader {
+    fn read(&self, text: &str) -> Result<u32, std::num::ParseIntError> {
+        let _ = self;
+        let _ = text;
+        let a: u32 = text.parse()?;
+        let b: u32 = text.parse()?;
+        let c: u32 = text.parse()?;
+        let d: u32 = text.parse()?;
+        let e: u32 = text.parse()?;
+        Ok(a + b + c + d + e)
+    }
+}
+
+async fn fetch(text: &str) -> Option<u32> {
+    let _ = text;
+    let _ = text;
+    let a = text.len().checked_sub(1)?;
+    let b = a.checked_sub(1)?;
+    let c = b.checked_sub(1)?;
+    let d = c.checked_sub(1)?;
+    let e = d.checked_sub(1)?;
+    u32::try_from(e).ok()
+}
+
+fn main() {
+    let _ = parse("42");
+    let _ = Reader.read("42");
+    let _ = fetch("42");
+}
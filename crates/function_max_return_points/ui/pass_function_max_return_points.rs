@@ -0,0 +1,38 @@
+//! Guard clauses in the first two statements, exits inside closures, and
+//! functions within the limit are accepted.
+
+fn guarded(text: &str) -> Option<u32> {
+    if text.is_empty() {
+        return None;
+    }
+    let first = text.chars().next()?;
+    let digit = first.to_digit(10)?;
+    let rest = text.get(1..)?;
+    let value: u32 = rest.parse().ok()?;
+    if value == 0 {
+        return Some(digit);
+    }
+    Some(digit * value)
+}
+
+fn with_closures(items: &[&str]) -> Vec<u32> {
+    let _ = items;
+    let _ = items;
+    items
+        .iter()
+        .filter_map(|item| {
+            let first = item.chars().next()?;
+            let digit = first.to_digit(10)?;
+            if digit == 0 {
+                return None;
+            }
+            let rest = item.get(1..)?;
+            rest.parse().ok()
+        })
+        .collect()
+}
+
+fn main() {
+    let _ = guarded("42");
+    let _ = with_closures(&["42"]);
+}
@@ -0,0 +1,120 @@
+//! Pure decision logic for `generic_function_monomorphisation_bloat`.
+//!
+//! The driver measures each local generic function's body and renders the
+//! generic arguments of every call site that uses it.
+//! [`GenericFunctionMonomorphisationBloatDecision`] tallies the distinct
+//! argument sets in the same [`Instantiations`] and applies the same
+//! [`Thresholds`], naming the function as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{Instantiations, Thresholds};
+
+/// Reason key for large functions with many instantiations.
+const BLOATED: &str = "bloated";
+
+/// The facts about one generic function that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GenericFunctionProjection {
+    /// The function's name.
+    pub name: String,
+    /// The lines its body spans.
+    pub lines: usize,
+    /// The rendered generic arguments of each call site, repeats included.
+    pub call_sites: Vec<String>,
+}
+
+/// Reports generic functions longer than `max_lines` that are used with at
+/// least `min_instantiations` distinct sets of generic arguments.
+///
+/// # Examples
+///
+/// ```ignore
+/// let function = GenericFunctionProjection {
+///     name: "parse".to_owned(),
+///     lines: 41,
+///     call_sites: ["u8", "u16", "u32", "u64"].map(str::to_owned).to_vec(),
+/// };
+/// let decision = GenericFunctionMonomorphisationBloatDecision::default();
+/// assert!(decision.decide(&function).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GenericFunctionMonomorphisationBloatDecision {
+    thresholds: Thresholds,
+}
+
+impl GenericFunctionMonomorphisationBloatDecision {
+    /// Creates a decision reporting bodies longer than `max_lines` with at
+    /// least `min_instantiations` instantiations.
+    #[must_use]
+    pub const fn new(max_lines: usize, min_instantiations: usize) -> Self {
+        Self {
+            thresholds: Thresholds {
+                max_lines,
+                min_instantiations,
+            },
+        }
+    }
+}
+
+impl LintDecision for GenericFunctionMonomorphisationBloatDecision {
+    type Projection = GenericFunctionProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, function: &GenericFunctionProjection) -> Option<Finding> {
+        let mut instantiations = Instantiations::default();
+        for arguments in &function.call_sites {
+            instantiations.record((), arguments.clone());
+        }
+        self.thresholds
+            .is_bloated(function.lines, instantiations.count(&()))
+            .then(|| Finding::new(LINT_NAME, BLOATED).with_subject(function.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<GenericFunctionProjection>> {
+        let Thresholds {
+            max_lines,
+            min_instantiations,
+        } = self.thresholds;
+        let function = |lines, distinct: usize, repeats: usize| GenericFunctionProjection {
+            name: "parse".to_owned(),
+            lines,
+            call_sites: (0..distinct)
+                .flat_map(|index| std::iter::repeat_n(format!("<T{index}>"), repeats))
+                .collect(),
+        };
+        vec![
+            DecisionFixture::flagged(
+                "large and common",
+                function(max_lines + 1, min_instantiations, 1),
+                BLOATED,
+            ),
+            DecisionFixture::clean(
+                "at the line limit",
+                function(max_lines, min_instantiations, 1),
+            ),
+            DecisionFixture::clean(
+                "repeated arguments",
+                function(max_lines + 1, min_instantiations - 1, 2),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_thresholds(GenericFunctionMonomorphisationBloatDecision::default())]
+    #[case::strict_thresholds(GenericFunctionMonomorphisationBloatDecision::new(10, 2))]
+    fn decision_conforms(#[case] decision: GenericFunctionMonomorphisationBloatDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
@@ -0,0 +1,30 @@
+//! Embedded documentation for the `generic_function_monomorphisation_bloat`
+//! lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `generic_function_monomorphisation_bloat`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "generic_function_monomorphisation_bloat",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags long generic functions that the crate instantiates with many distinct sets of generic arguments.",
+    rationale: "The compiler emits a separate copy of a generic function for every set of generic arguments it is used with. When only the first lines depend on the generic types, each copy repeats the rest of the body for nothing, adding to build time and binary size. Converting the arguments in a thin generic wrapper and delegating to a non-generic inner function compiles the body once.",
+    bad_example: "pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Config> {\n    let text = fs::read_to_string(path.as_ref())?;\n    // ... fifty lines of parsing ...\n}",
+    good_example: "pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Config> {\n    fn inner(path: &Path) -> io::Result<Config> {\n        let text = fs::read_to_string(path)?;\n        // ... fifty lines of parsing ...\n    }\n    inner(path.as_ref())\n}",
+    config: &[
+        ConfigKeyDocs {
+            key: "max_lines",
+            default: "40",
+            description: "Lines a generic function body may span before its instantiations are counted.",
+        },
+        ConfigKeyDocs {
+            key: "min_instantiations",
+            default: "4",
+            description: "Distinct sets of generic arguments, seen at call sites in the crate, at which a long generic function is reported.",
+        },
+    ],
+};
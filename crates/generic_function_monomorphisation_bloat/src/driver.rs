@@ -0,0 +1,372 @@
+//! Lint pass reporting large generic functions with many instantiations.
+//!
+//! Each free function or inherent method whose generics need
+//! monomorphisation is recorded with the number of lines its body spans, not
+//! counting items declared inside it, such as the non-generic inner function
+//! the lint recommends. Every place a local function is named, whether called
+//! by path, called as a method, or passed as a function value, contributes
+//! the generic arguments the type checker resolved there to an
+//! [`Instantiations`] tally. Arguments that still mention the caller's own
+//! generic parameters are skipped, as their concrete types depend on how the
+//! caller is instantiated. Once the crate has been walked, functions the
+//! [`Thresholds`] consider bloated are reported. Trait methods are skipped
+//! because calls resolve to the trait rather than the implementation, and
+//! code produced by macro expansion is skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_span::Span;
+use rustc_span::source_map::SourceMap;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{Instantiations, Thresholds};
+
+pub(crate) const LINT_NAME: &str = "generic_function_monomorphisation_bloat";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_lines: usize,
+    min_instantiations: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let Thresholds {
+            max_lines,
+            min_instantiations,
+        } = Thresholds::default();
+        Self {
+            max_lines,
+            min_instantiations,
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub GENERIC_FUNCTION_MONOMORPHISATION_BLOAT,
+    Warn,
+    "large generic functions instantiated with many distinct generic arguments should delegate to an inner non-generic function",
+    GenericFunctionMonomorphisationBloat::default()
+}
+
+/// Lint pass collecting generic functions and the arguments they are used
+/// with.
+#[derive(Default)]
+pub struct GenericFunctionMonomorphisationBloat {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Thresholds, candidates, instantiations, finding limit, and localization
+/// for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    thresholds: Thresholds,
+    candidates: Vec<GenericFunction>,
+    instantiations: Instantiations<DefId>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// A local function the compiler copies for each set of generic arguments.
+#[derive(Clone, Debug)]
+struct GenericFunction {
+    def_id: DefId,
+    hir_id: hir::HirId,
+    span: Span,
+    name: String,
+    lines: usize,
+}
+
+impl<'tcx> LateLintPass<'tcx> for GenericFunctionMonomorphisationBloat {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        let config = load_configuration();
+        self.state.reset(CrateState {
+            thresholds: Thresholds {
+                max_lines: config.max_lines,
+                min_instantiations: config.min_instantiations,
+            },
+            candidates: Vec::new(),
+            instantiations: Instantiations::default(),
+            findings: FindingLimit::new(GENERIC_FUNCTION_MONOMORPHISATION_BLOAT, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _decl: &'tcx hir::FnDecl<'tcx>,
+        body: &'tcx hir::Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        let ident = match kind {
+            FnKind::ItemFn(ident, ..) => ident,
+            FnKind::Method(ident, _)
+                if cx.tcx.inherent_impl_of_assoc(def_id.to_def_id()).is_some() =>
+            {
+                ident
+            }
+            _ => return,
+        };
+        if span.from_expansion() || !cx.tcx.generics_of(def_id).requires_monomorphization(cx.tcx) {
+            return;
+        }
+        let Some(lines) = own_lines(cx, body) else {
+            return;
+        };
+
+        self.state.candidates.push(GenericFunction {
+            def_id: def_id.to_def_id(),
+            hir_id: cx.tcx.local_def_id_to_hir_id(def_id),
+            span: ident.span,
+            name: ident.to_string(),
+            lines,
+        });
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let typeck = cx.typeck_results();
+        let callee = match expr.kind {
+            hir::ExprKind::Path(ref qpath) => match typeck.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => def_id,
+                _ => return,
+            },
+            hir::ExprKind::MethodCall(..) => match typeck.type_dependent_def_id(expr.hir_id) {
+                Some(def_id) => def_id,
+                None => return,
+            },
+            _ => return,
+        };
+        if !callee.is_local() || cx.tcx.trait_of_assoc(callee).is_some() {
+            return;
+        }
+        let arguments = cx
+            .tcx
+            .erase_and_anonymize_regions(typeck.node_args(expr.hir_id));
+        if arguments.is_empty() || arguments.has_param() {
+            return;
+        }
+        self.state
+            .instantiations
+            .record(callee, format!("{arguments:?}"));
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let state = &*self.state;
+        for function in &state.candidates {
+            let count = state.instantiations.count(&function.def_id);
+            if !state.thresholds.is_bloated(function.lines, count) {
+                continue;
+            }
+            debug!(
+                target: LINT_NAME,
+                "generic function `{}` spans {} lines with {count} instantiations",
+                function.name,
+                function.lines,
+            );
+            if state.findings.admit(cx, function.hir_id, function.span) {
+                let message = BloatMessage {
+                    name: &function.name,
+                    lines: function.lines,
+                    count,
+                };
+                emit_diagnostic(cx, function.span, &message, &state.localizer);
+            }
+        }
+        state.findings.summarise(cx, &state.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the lines `body` spans, less those of items declared directly in
+/// it, so a wrapper holding its non-generic inner function counts as short.
+fn own_lines(cx: &LateContext<'_>, body: &hir::Body<'_>) -> Option<usize> {
+    let source_map = cx.sess().source_map();
+    let total = count_lines(source_map, body.value.span)?;
+    let hir::ExprKind::Block(block, _) = body.value.kind else {
+        return Some(total);
+    };
+    let nested: usize = block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            hir::StmtKind::Item(item_id) => count_lines(source_map, cx.tcx.hir_item(item_id).span),
+            _ => None,
+        })
+        .sum();
+    Some(total.saturating_sub(nested))
+}
+
+/// Returns the number of source lines `span` covers.
+fn count_lines(source_map: &SourceMap, span: Span) -> Option<usize> {
+    if span.is_dummy() {
+        return None;
+    }
+    let first = source_map.lookup_char_pos(span.lo()).line;
+    let last = source_map.lookup_char_pos(span.hi()).line;
+    last.checked_sub(first).map(|extra| extra + 1)
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &BloatMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        GENERIC_FUNCTION_MONOMORPHISATION_BLOAT,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one bloated generic function interpolates.
+#[derive(Clone, Copy, Debug)]
+struct BloatMessage<'a> {
+    name: &'a str,
+    lines: usize,
+    count: usize,
+}
+
+impl BloatMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("name"),
+            FluentValue::from(self.name.to_owned()),
+        );
+        args.insert(Cow::Borrowed("lines"), FluentValue::from(self.lines));
+        args.insert(Cow::Borrowed("count"), FluentValue::from(self.count));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { name, lines, count } = self;
+        DiagnosticMessageSet::new(
+            format!(
+                "Generic function `{name}` spans {lines} lines and is instantiated with {count} \
+                 distinct sets of generic arguments."
+            ),
+            String::from(
+                "The compiler emits a copy of the whole body for each set of generic arguments, \
+                 adding to build time and binary size.",
+            ),
+            String::from(
+                "Move the body into an inner non-generic function, and keep only the conversion \
+                 of the arguments, such as `.as_ref()` or `.into()`, in the generic wrapper.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn message() -> BloatMessage<'static> {
+        BloatMessage {
+            name: "load",
+            lines: 52,
+            count: 6,
+        }
+    }
+
+    fn resolve(message: &BloatMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_allows_forty_lines_below_four_instantiations() {
+        let config = Config::default();
+
+        assert_eq!(config.max_lines, 40);
+        assert_eq!(config.min_instantiations, 4);
+    }
+
+    #[rstest]
+    fn fallback_messages_state_the_size_and_count(message: BloatMessage<'static>) {
+        assert_eq!(
+            message.fallback_messages().primary(),
+            "Generic function `load` spans 52 lines and is instantiated with 6 distinct sets of \
+             generic arguments."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback(message: BloatMessage<'static>) {
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str, message: BloatMessage<'static>) {
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`load`"));
+        assert!(messages.primary().contains("52"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,42 @@
+//! Lint crate flagging large generic functions instantiated many times.
+//!
+//! The compiler emits a separate copy of a generic function for every
+//! distinct set of generic arguments it is used with. A long body copied a
+//! dozen times costs build time and binary size, even when only a few lines
+//! depend on the generic types. The lint estimates how many copies each local
+//! generic function needs from the concrete arguments at its call sites, and
+//! reports bodies longer than `max_lines` with at least `min_instantiations`
+//! of them. The lint is experimental and ships behind the
+//! `experimental-generic-function-monomorphisation-bloat` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{GenericFunctionMonomorphisationBloatDecision, GenericFunctionProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn generic_function_monomorphisation_bloat_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
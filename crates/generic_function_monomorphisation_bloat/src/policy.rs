@@ -0,0 +1,103 @@
+//! Rules deciding which generic functions are reported as bloated.
+//!
+//! The driver records the concrete generic arguments seen at each call site
+//! of a local generic function in an [`Instantiations`] tally, keyed by the
+//! function. A function is reported once its body is longer than the line
+//! threshold and the tally reaches the instantiation threshold. The rules are
+//! kept free of compiler types so they can be tested directly.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The limits a generic function must exceed to be reported.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Thresholds {
+    /// Body lines a generic function may span before it counts as large.
+    pub(crate) max_lines: usize,
+    /// Distinct instantiations at which a large function is reported.
+    pub(crate) min_instantiations: usize,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            max_lines: 40,
+            min_instantiations: 4,
+        }
+    }
+}
+
+impl Thresholds {
+    /// Returns `true` when a body spanning `lines` lines, copied
+    /// `instantiations` times, should be reported.
+    #[must_use]
+    pub(crate) fn is_bloated(self, lines: usize, instantiations: usize) -> bool {
+        lines > self.max_lines && instantiations >= self.min_instantiations
+    }
+}
+
+/// The distinct generic arguments each function is used with.
+#[derive(Clone, Debug)]
+pub(crate) struct Instantiations<K> {
+    seen: HashMap<K, HashSet<String>>,
+}
+
+impl<K> Default for Instantiations<K> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> Instantiations<K> {
+    /// Records that `function` is used with the rendered generic `arguments`.
+    pub(crate) fn record(&mut self, function: K, arguments: String) {
+        self.seen.entry(function).or_default().insert(arguments);
+    }
+
+    /// Returns how many distinct argument sets `function` is used with.
+    #[must_use]
+    pub(crate) fn count(&self, function: &K) -> usize {
+        self.seen.get(function).map_or(0, HashSet::len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::large_and_common(41, 4, true)]
+    #[case::at_line_limit(40, 10, false)]
+    #[case::few_instantiations(100, 3, false)]
+    #[case::small_and_rare(10, 1, false)]
+    fn applies_both_thresholds(
+        #[case] lines: usize,
+        #[case] instantiations: usize,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            Thresholds::default().is_bloated(lines, instantiations),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn counts_distinct_arguments() {
+        let mut tally = Instantiations::default();
+        tally.record("parse", "<u8>".to_owned());
+        tally.record("parse", "<u16>".to_owned());
+        tally.record("parse", "<u8>".to_owned());
+        tally.record("render", "<String>".to_owned());
+
+        assert_eq!(tally.count(&"parse"), 2);
+        assert_eq!(tally.count(&"render"), 1);
+    }
+
+    #[rstest]
+    fn unseen_functions_have_no_instantiations() {
+        assert_eq!(Instantiations::<&str>::default().count(&"parse"), 0);
+    }
+}
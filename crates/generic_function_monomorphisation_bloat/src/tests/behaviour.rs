@@ -0,0 +1,78 @@
+//! Behaviour-driven coverage for the monomorphisation bloat policy.
+
+use crate::policy::{Instantiations, Thresholds};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+const FUNCTION: &str = "load";
+
+#[derive(Default)]
+struct BloatWorld {
+    lines: Cell<usize>,
+    instantiations: RefCell<Instantiations<&'static str>>,
+    reported: RefCell<Option<Option<usize>>>,
+}
+
+#[fixture]
+fn world() -> BloatWorld {
+    BloatWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+#[given("a generic function spanning {lines} lines")]
+fn given_function(world: &BloatWorld, lines: usize) {
+    world.lines.set(lines);
+}
+
+#[given("it is called with {arguments}")]
+fn given_calls(world: &BloatWorld, arguments: String) {
+    let mut instantiations = world.instantiations.borrow_mut();
+    for argument in unquote(&arguments).split(',') {
+        instantiations.record(FUNCTION, argument.trim().to_owned());
+    }
+}
+
+#[when("the function is checked")]
+fn when_checked(world: &BloatWorld) {
+    let count = world.instantiations.borrow().count(&FUNCTION);
+    let bloated = Thresholds::default().is_bloated(world.lines.get(), count);
+    *world.reported.borrow_mut() = Some(bloated.then_some(count));
+}
+
+#[then("it is reported with {count} instantiations")]
+fn then_reported(world: &BloatWorld, count: usize) {
+    assert_eq!(*world.reported.borrow(), Some(Some(count)));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &BloatWorld) {
+    assert_eq!(*world.reported.borrow(), Some(None));
+}
+
+#[scenario(
+    path = "tests/features/generic_function_monomorphisation_bloat.feature",
+    index = 0
+)]
+fn scenario_many_instantiations(world: BloatWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/generic_function_monomorphisation_bloat.feature",
+    index = 1
+)]
+fn scenario_repeated_arguments(world: BloatWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/generic_function_monomorphisation_bloat.feature",
+    index = 2
+)]
+fn scenario_short_function(world: BloatWorld) {
+    let _ = world;
+}
@@ -0,0 +1,22 @@
+Feature: Generic function monomorphisation bloat
+  Generic functions whose bodies span more than the line threshold are
+  reported once the crate uses them with at least the configured number of
+  distinct sets of generic arguments.
+
+  Scenario: A long function with many instantiations is reported
+    Given a generic function spanning 60 lines
+    And it is called with "u8, u16, u32, u64"
+    When the function is checked
+    Then it is reported with 4 instantiations
+
+  Scenario: Repeated arguments count once
+    Given a generic function spanning 60 lines
+    And it is called with "u8, u8, u16, u16"
+    When the function is checked
+    Then nothing is reported
+
+  Scenario: A short function is not reported
+    Given a generic function spanning 12 lines
+    And it is called with "u8, u16, u32, u64"
+    When the function is checked
+    Then nothing is reported
@@ -0,0 +1,31 @@
+//! Negative UI fixture: long generic functions used with many argument types.
+
+use std::fmt::Display;
+
+fn describe<T: Display>(value: T) -> String {
+    let text = value.to_string();
+    let trimmed = text.trim();
+    let upper = trimmed.to_uppercase();
+    format!("<{upper}>")
+}
+
+struct Report;
+
+impl Report {
+    fn render<T: Display>(&self, value: T) -> String {
+        let text = value.to_string();
+        let padded = format!("{text:>8}");
+        let line = padded.replace(' ', ".");
+        format!("[{line}]")
+    }
+}
+
+fn main() {
+    let report = Report;
+    let _ = describe(1_u8);
+    let _ = describe("two");
+    let _ = describe(3.0_f64);
+    let _ = report.render(1_u8);
+    let _ = report.render('x');
+    let _ = report.render(String::from("three"));
+}
@@ -0,0 +1,34 @@
+//! Positive UI fixture: thin generic wrappers and rarely instantiated bodies.
+
+use std::fmt::Display;
+
+fn describe<T: Display>(value: T) -> String {
+    fn inner(text: &str) -> String {
+        let trimmed = text.trim();
+        let upper = trimmed.to_uppercase();
+        format!("<{upper}>")
+    }
+    inner(&value.to_string())
+}
+
+fn render<T: Display>(value: T) -> String {
+    let text = value.to_string();
+    let padded = format!("{text:>8}");
+    let line = padded.replace(' ', ".");
+    format!("[{line}]")
+}
+
+fn render_twice<T: Display + Copy>(value: T) -> String {
+    render(value) + &render(value)
+}
+
+fn main() {
+    let _ = describe(1_u8);
+    let _ = describe("two");
+    let _ = describe(3.0_f64);
+    let _ = describe('x');
+    let _ = render(1_u8);
+    let _ = render('x');
+    let _ = render_twice(2_u16);
+    let _ = render_twice(3_u32);
+}
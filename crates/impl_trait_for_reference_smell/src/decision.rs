@@ -0,0 +1,112 @@
+//! Pure decision logic for `impl_trait_for_reference_smell`.
+//!
+//! The driver considers implementations of local traits on `&T` or
+//! `&mut T` and reduces the `Self` type of every other implementation of the
+//! trait to a comparable key. [`ImplTraitForReferenceSmellDecision`] looks
+//! for the implementation on the referent with the same [`owned_counterpart`]
+//! and names the referent as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{SelfType, owned_counterpart};
+
+/// Reason key for reference implementations duplicating an owned one.
+const DUPLICATES_OWNED: &str = "duplicates_owned";
+
+/// The `Self` type of one other implementation of the trait.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OtherImplProjection {
+    /// The implementing type, without any reference.
+    pub self_type: String,
+    /// Whether the implementation is on a reference to `self_type`.
+    pub borrowed: bool,
+}
+
+/// The facts about one implementation on a reference that the decision
+/// needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReferenceImplProjection {
+    /// The type the implementing reference points to.
+    pub referent: String,
+    /// The trait's other implementations.
+    pub others: Vec<OtherImplProjection>,
+}
+
+/// Reports trait implementations on `&T` when the trait is also implemented
+/// on `T`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let owned = OtherImplProjection { self_type: "Config".to_owned(), borrowed: false };
+/// let on_ref = ReferenceImplProjection { referent: "Config".to_owned(), others: vec![owned] };
+/// assert!(ImplTraitForReferenceSmellDecision.decide(&on_ref).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImplTraitForReferenceSmellDecision;
+
+impl LintDecision for ImplTraitForReferenceSmellDecision {
+    type Projection = ReferenceImplProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, reference: &ReferenceImplProjection) -> Option<Finding> {
+        let others = reference.others.iter().map(|other| {
+            let key = other.self_type.as_str();
+            let self_type = if other.borrowed {
+                SelfType::Borrowed(key)
+            } else {
+                SelfType::Owned(key)
+            };
+            ((), self_type)
+        });
+        owned_counterpart(&reference.referent.as_str(), others).map(|()| {
+            Finding::new(LINT_NAME, DUPLICATES_OWNED).with_subject(reference.referent.as_str())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ReferenceImplProjection>> {
+        let other = |self_type: &str, borrowed| OtherImplProjection {
+            self_type: self_type.to_owned(),
+            borrowed,
+        };
+        let reference = |others| ReferenceImplProjection {
+            referent: "Config".to_owned(),
+            others,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "owned implementation exists",
+                reference(vec![other("Settings", false), other("Config", false)]),
+                DUPLICATES_OWNED,
+            ),
+            DecisionFixture::clean("only implementation", reference(Vec::new())),
+            DecisionFixture::clean(
+                "other reference implementation",
+                reference(vec![other("Config", true)]),
+            ),
+            DecisionFixture::clean(
+                "unrelated owned implementation",
+                reference(vec![other("Settings", false)]),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&ImplTraitForReferenceSmellDecision),
+            Ok(4)
+        );
+    }
+}
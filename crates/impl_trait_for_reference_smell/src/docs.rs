@@ -0,0 +1,17 @@
+//! Embedded documentation for the `impl_trait_for_reference_smell` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for `impl_trait_for_reference_smell`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "impl_trait_for_reference_smell",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags implementations of a local trait for `&T` or `&mut T` when the trait is also implemented for `T`. Allow-by-default.",
+    rationale: "Method resolution chooses between the two implementations by auto-referencing, so the same call reaches different code depending on whether the receiver is a value, a reference, or a reference to a reference. Keeping a single implementation makes the behaviour independent of how the receiver was borrowed.",
+    bad_example: "impl Describe for Config {\n    fn describe(&self) -> String {\n        self.name.clone()\n    }\n}\n\nimpl Describe for &Config {\n    fn describe(&self) -> String {\n        format!(\"borrowed {}\", self.name)\n    }\n}",
+    good_example: "impl Describe for Config {\n    fn describe(&self) -> String {\n        self.name.clone()\n    }\n}",
+    config: &[],
+};
@@ -0,0 +1,288 @@
+//! Lint pass reporting trait implementations on references that shadow an
+//! implementation on the referent.
+//!
+//! Each trait `impl` whose written `Self` type is `&T` or `&mut T` is
+//! inspected when the trait is defined in the crate being checked, since
+//! only then can every implementation of it be seen. The `Self` types of the
+//! trait's other implementations are reduced with rustc's fast-reject
+//! simplification, so `Config` and `Wrapper<T>` match whatever arguments an
+//! implementation names, and [`owned_counterpart`] finds the one on `T`.
+//! Implementations produced by macro expansion and those on a bare type
+//! parameter are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::fast_reject::{SimplifiedType, TreatParams, simplify_type};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+use rustc_span::def_id::DefId;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{SelfType, owned_counterpart};
+
+pub(crate) const LINT_NAME: &str = "impl_trait_for_reference_smell";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub IMPL_TRAIT_FOR_REFERENCE_SMELL,
+    Allow,
+    "local traits should not be implemented for both `T` and `&T` or `&mut T`",
+    ImplTraitForReferenceSmell::default()
+}
+
+/// Lint pass that finds trait implementations duplicated on references.
+#[derive(Default)]
+pub struct ImplTraitForReferenceSmell {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Finding limit and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ImplTraitForReferenceSmell {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            findings: FindingLimit::new(IMPL_TRAIT_FOR_REFERENCE_SMELL, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let hir::ItemKind::Impl(impl_block) = item.kind else {
+            return;
+        };
+        let hir::TyKind::Ref(_, written_referent) = impl_block.self_ty.kind else {
+            return;
+        };
+        if impl_block.of_trait.is_none() || item.span.from_expansion() {
+            return;
+        }
+        let impl_id = item.owner_id.to_def_id();
+        let trait_id = cx.tcx.impl_trait_id(impl_id);
+        if !trait_id.is_local() {
+            return;
+        }
+        let Some(SelfType::Borrowed(referent)) = self_type(cx, impl_id) else {
+            return;
+        };
+        let others = cx
+            .tcx
+            .all_impls(trait_id)
+            .filter(|&other| other != impl_id)
+            .filter_map(|other| Some((other, self_type(cx, other)?)));
+        let Some(counterpart) = owned_counterpart(&referent, others) else {
+            return;
+        };
+
+        let source_map = cx.sess().source_map();
+        let (Ok(self_text), Ok(referent_text)) = (
+            source_map.span_to_snippet(impl_block.self_ty.span),
+            source_map.span_to_snippet(written_referent.ty.span),
+        ) else {
+            return;
+        };
+        let trait_name = cx.tcx.item_name(trait_id);
+        debug!(
+            target: LINT_NAME,
+            "`{trait_name}` is implemented for both `{self_text}` and `{referent_text}`"
+        );
+        let state = &*self.state;
+        let span = impl_block.self_ty.span;
+        if state.findings.admit(cx, item.hir_id(), span) {
+            let message = ReferenceImplMessage {
+                trait_name: trait_name.as_str(),
+                self_ty: &self_text,
+                referent: &referent_text,
+            };
+            let spans = ImplSpans {
+                reference: span,
+                counterpart: cx.tcx.def_span(counterpart),
+            };
+            emit_diagnostic(cx, spans, &message, &state.localizer);
+        }
+    }
+}
+
+/// Reduces the `Self` type of `impl_id` to a comparable key. Returns `None`
+/// when the type, or the type a reference points to, is a bare parameter.
+fn self_type<'tcx>(cx: &LateContext<'tcx>, impl_id: DefId) -> Option<SelfType<SimplifiedType>> {
+    let self_ty = cx
+        .tcx
+        .type_of(impl_id)
+        .instantiate_identity()
+        .skip_normalization();
+    let simplify = |ty: Ty<'tcx>| simplify_type(cx.tcx, ty, TreatParams::InstantiateWithInfer);
+    match self_ty.kind() {
+        ty::Ref(_, referent, _) => simplify(*referent).map(SelfType::Borrowed),
+        _ => simplify(self_ty).map(SelfType::Owned),
+    }
+}
+
+/// Where the reported implementation and its counterpart are written.
+#[derive(Clone, Copy, Debug)]
+struct ImplSpans {
+    reference: Span,
+    counterpart: Span,
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    spans: ImplSpans,
+    message: &ReferenceImplMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        IMPL_TRAIT_FOR_REFERENCE_SMELL,
+        spans.reference,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(spans.counterpart, note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a duplicated implementation interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ReferenceImplMessage<'a> {
+    trait_name: &'a str,
+    self_ty: &'a str,
+    referent: &'a str,
+}
+
+impl ReferenceImplMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("trait"),
+            FluentValue::from(self.trait_name.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("self_ty"),
+            FluentValue::from(self.self_ty.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("referent"),
+            FluentValue::from(self.referent.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            trait_name,
+            self_ty,
+            referent,
+        } = *self;
+        DiagnosticMessageSet::new(
+            format!("`{trait_name}` is implemented for `{self_ty}` as well as for `{referent}`."),
+            format!(
+                "The implementation for `{referent}` is here. Method calls choose between the \
+                 two by auto-referencing, so the same call can reach either one depending on how \
+                 the receiver was borrowed."
+            ),
+            format!(
+                "Keep only the implementation for `{referent}` and let callers reach it through \
+                 the reference, or make both behave identically."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message() -> ReferenceImplMessage<'static> {
+        ReferenceImplMessage {
+            trait_name: "Describe",
+            self_ty: "&mut Config",
+            referent: "Config",
+        }
+    }
+
+    fn resolve(message: &ReferenceImplMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn fallback_messages_name_both_types() {
+        let messages = message().fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "`Describe` is implemented for `&mut Config` as well as for `Config`."
+        );
+        assert!(
+            messages
+                .note()
+                .starts_with("The implementation for `Config` is here.")
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        let message = message();
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(), locale);
+
+        assert!(messages.primary().contains("`Describe`"));
+        assert!(messages.primary().contains("`&mut Config`"));
+        assert!(messages.note().contains("`Config`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,46 @@
+//! Lint crate flagging trait implementations on references that shadow an
+//! implementation on the referent.
+//!
+//! When a crate implements one of its own traits for both `T` and `&T` (or
+//! `&mut T`), method resolution picks between them by auto-referencing, so
+//! the same call can reach different code depending on whether the receiver
+//! is a value, a reference, or a reference to a reference. The lint reports
+//! the implementation on the reference and points at the one on the
+//! referent. It is allow-by-default, since some crates keep both on purpose;
+//! teams choose its severity with the usual lint level attributes. The lint
+//! is experimental and ships behind the
+//! `experimental-impl-trait-for-reference-smell` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{
+    ImplTraitForReferenceSmellDecision, OtherImplProjection, ReferenceImplProjection,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn impl_trait_for_reference_smell_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
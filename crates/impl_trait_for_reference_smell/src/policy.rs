@@ -0,0 +1,74 @@
+//! Matching implementations on references with implementations on their
+//! referents.
+//!
+//! The driver reduces the `Self` type of every implementation of a trait to a
+//! comparable key, noting whether it is a reference, and asks
+//! [`owned_counterpart`] for the implementation on the referent. Keys are
+//! generic so the matching can be tested without compiler types.
+
+/// The `Self` type of one implementation, reduced to a comparable key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SelfType<K> {
+    /// An implementation on `T`.
+    Owned(K),
+    /// An implementation on `&T` or `&mut T`, keyed by `T`.
+    Borrowed(K),
+}
+
+/// Returns the first of `impls` implemented on `referent` itself.
+///
+/// # Examples
+///
+/// ```ignore
+/// let impls = [("on_ref", SelfType::Borrowed("Config")), ("on_value", SelfType::Owned("Config"))];
+/// assert_eq!(owned_counterpart(&"Config", impls), Some("on_value"));
+/// ```
+#[must_use]
+pub(crate) fn owned_counterpart<K, I>(
+    referent: &K,
+    impls: impl IntoIterator<Item = (I, SelfType<K>)>,
+) -> Option<I>
+where
+    K: PartialEq,
+{
+    impls
+        .into_iter()
+        .find_map(|(id, self_type)| match self_type {
+            SelfType::Owned(key) if key == *referent => Some(id),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn finds_the_implementation_on_the_referent() {
+        let impls = [
+            (0, SelfType::Borrowed("Config")),
+            (1, SelfType::Owned("Settings")),
+            (2, SelfType::Owned("Config")),
+        ];
+
+        assert_eq!(owned_counterpart(&"Config", impls), Some(2));
+    }
+
+    #[rstest]
+    fn other_references_are_not_counterparts() {
+        let impls = [
+            (0, SelfType::Borrowed("Config")),
+            (1, SelfType::Borrowed("Config")),
+        ];
+
+        assert_eq!(owned_counterpart(&"Config", impls), None);
+    }
+
+    #[rstest]
+    fn unrelated_types_are_not_counterparts() {
+        let impls = [(0, SelfType::Owned("Settings"))];
+
+        assert_eq!(owned_counterpart(&"Config", impls), None);
+    }
+}
@@ -0,0 +1,81 @@
+//! Behaviour-driven coverage for matching implementations on references.
+
+use crate::policy::{SelfType, owned_counterpart};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct ImplWorld {
+    impls: RefCell<Vec<SelfType<String>>>,
+    counterpart: RefCell<Option<Option<String>>>,
+}
+
+#[fixture]
+fn world() -> ImplWorld {
+    ImplWorld::default()
+}
+
+#[given("an implementation for a reference to {name}")]
+fn given_borrowed(world: &ImplWorld, name: String) {
+    let name = name.trim_matches('"').to_owned();
+    world.impls.borrow_mut().push(SelfType::Borrowed(name));
+}
+
+#[given("an implementation for {name}")]
+fn given_owned(world: &ImplWorld, name: String) {
+    let name = name.trim_matches('"').to_owned();
+    world.impls.borrow_mut().push(SelfType::Owned(name));
+}
+
+#[when("the counterpart is looked up")]
+fn when_looked_up(world: &ImplWorld) {
+    let impls = world.impls.borrow();
+    let Some(SelfType::Borrowed(referent)) = impls.first() else {
+        panic!("the first implementation should be on a reference");
+    };
+    let others = impls.iter().skip(1).map(|self_type| {
+        let name = match self_type {
+            SelfType::Owned(name) | SelfType::Borrowed(name) => name.clone(),
+        };
+        (name, self_type.clone())
+    });
+    *world.counterpart.borrow_mut() = Some(owned_counterpart(referent, others));
+}
+
+#[then("the implementation for {name} is found")]
+fn then_found(world: &ImplWorld, name: String) {
+    assert_eq!(
+        *world.counterpart.borrow(),
+        Some(Some(name.trim_matches('"').to_owned()))
+    );
+}
+
+#[then("no counterpart is found")]
+fn then_not_found(world: &ImplWorld) {
+    assert_eq!(*world.counterpart.borrow(), Some(None));
+}
+
+#[scenario(
+    path = "tests/features/impl_trait_for_reference_smell.feature",
+    index = 0
+)]
+fn scenario_counterpart_found(world: ImplWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/impl_trait_for_reference_smell.feature",
+    index = 1
+)]
+fn scenario_other_reference(world: ImplWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/impl_trait_for_reference_smell.feature",
+    index = 2
+)]
+fn scenario_unrelated_type(world: ImplWorld) {
+    let _ = world;
+}
@@ -0,0 +1,21 @@
+Feature: Trait implementations on references beside the referent
+  An implementation of a local trait for `&T` or `&mut T` is reported when
+  the trait is also implemented for `T`.
+
+  Scenario: An implementation on the referent is found
+    Given an implementation for a reference to "Config"
+    And an implementation for "Config"
+    When the counterpart is looked up
+    Then the implementation for "Config" is found
+
+  Scenario: An implementation on another reference is not a counterpart
+    Given an implementation for a reference to "Config"
+    And an implementation for a reference to "Config"
+    When the counterpart is looked up
+    Then no counterpart is found
+
+  Scenario: Implementations on other types are ignored
+    Given an implementation for a reference to "Config"
+    And an implementation for "Settings"
+    When the counterpart is looked up
+    Then no counterpart is found
@@ -0,0 +1,35 @@
+#![warn(impl_trait_for_reference_smell)]
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+pub struct Config {
+    name: String,
+}
+
+impl Describe for Config {
+    fn describe(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Describe for &Config {
+    fn describe(&self) -> String {
+        format!("borrowed {}", self.name)
+    }
+}
+
+impl<'a> Describe for &'a mut Config {
+    fn describe(&self) -> String {
+        format!("mutably borrowed {}", self.name)
+    }
+}
+
+fn main() {
+    let mut config = Config {
+        name: String::from("primary"),
+    };
+    println!("{}", (&config).describe());
+    println!("{}", (&mut config).describe());
+}
@@ -0,0 +1,46 @@
+#![warn(impl_trait_for_reference_smell)]
+
+use std::fmt;
+
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+pub struct Config {
+    name: String,
+}
+
+pub struct Settings;
+
+impl Describe for Config {
+    fn describe(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl Describe for &Settings {
+    fn describe(&self) -> String {
+        String::from("settings")
+    }
+}
+
+impl<T: Describe> Describe for Box<T> {
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+}
+
+impl fmt::Display for Config {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.name)
+    }
+}
+
+fn main() {
+    let config = Config {
+        name: String::from("primary"),
+    };
+    println!("{} {}", config, config.describe());
+    println!("{}", (&Settings).describe());
+    println!("{}", Box::new(config).describe());
+}
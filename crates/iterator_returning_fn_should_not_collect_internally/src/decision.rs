@@ -0,0 +1,125 @@
+//! Pure decision logic for `iterator_returning_fn_should_not_collect_internally`.
+//!
+//! The driver keeps local functions that collect into the `Vec` they return
+//! and classifies every place each one is named.
+//! [`IteratorReturningFnShouldNotCollectInternallyDecision`] classifies the
+//! same uses as [`CallerUse`] values and reports the helper when
+//! [`iterating_callers`] finds that every caller only iterates, naming the
+//! helper as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{CallerUse, iterating_callers};
+
+/// Reason key for helpers whose callers only iterate the vector.
+const ONLY_ITERATED: &str = "only_iterated";
+
+/// How one call site consumes the returned vector.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallerProjection {
+    /// Whether the call is the head of a `for` loop.
+    pub for_loop: bool,
+    /// Whether the call's result is indexed.
+    pub indexed: bool,
+    /// The method called on the result, if any.
+    pub method: Option<String>,
+}
+
+impl CallerProjection {
+    fn caller_use(&self) -> CallerUse {
+        match (self.for_loop, self.indexed, self.method.as_deref()) {
+            (true, _, _) => CallerUse::ForLoop,
+            (false, true, _) => CallerUse::Indexed,
+            (false, false, Some(method)) => CallerUse::for_receiver_method(method),
+            (false, false, None) => CallerUse::Other,
+        }
+    }
+}
+
+/// The facts about one collecting helper that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectingHelperProjection {
+    /// The helper's name.
+    pub helper: String,
+    /// Every place the helper is named.
+    pub callers: Vec<CallerProjection>,
+}
+
+/// Reports helpers that collect into a `Vec` only for every caller to
+/// iterate it.
+///
+/// # Examples
+///
+/// ```ignore
+/// let helper = CollectingHelperProjection {
+///     helper: "names".to_owned(),
+///     callers: vec![CallerProjection { for_loop: true, ..CallerProjection::default() }],
+/// };
+/// let decision = IteratorReturningFnShouldNotCollectInternallyDecision;
+/// assert!(decision.decide(&helper).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IteratorReturningFnShouldNotCollectInternallyDecision;
+
+impl LintDecision for IteratorReturningFnShouldNotCollectInternallyDecision {
+    type Projection = CollectingHelperProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, helper: &CollectingHelperProjection) -> Option<Finding> {
+        let uses: Vec<CallerUse> = helper
+            .callers
+            .iter()
+            .map(CallerProjection::caller_use)
+            .collect();
+        iterating_callers(&uses)?;
+        Some(Finding::new(LINT_NAME, ONLY_ITERATED).with_subject(helper.helper.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<CollectingHelperProjection>> {
+        let for_loop = CallerProjection {
+            for_loop: true,
+            ..CallerProjection::default()
+        };
+        let method = |name: &str| CallerProjection {
+            method: Some(name.to_owned()),
+            ..CallerProjection::default()
+        };
+        let indexed = CallerProjection {
+            indexed: true,
+            ..CallerProjection::default()
+        };
+        let helper = |callers| CollectingHelperProjection {
+            helper: "names".to_owned(),
+            callers,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "every caller iterates",
+                helper(vec![for_loop.clone(), method("iter")]),
+                ONLY_ITERATED,
+            ),
+            DecisionFixture::clean("a caller indexes", helper(vec![for_loop.clone(), indexed])),
+            DecisionFixture::clean("a caller takes the length", helper(vec![method("len")])),
+            DecisionFixture::clean("never called", helper(Vec::new())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&IteratorReturningFnShouldNotCollectInternallyDecision),
+            Ok(4)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Embedded documentation for the
+//! `iterator_returning_fn_should_not_collect_internally` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for
+/// `iterator_returning_fn_should_not_collect_internally`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "iterator_returning_fn_should_not_collect_internally",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags private functions that end by collecting into a `Vec` which every caller in the crate immediately iterates without indexing.",
+    rationale: "Collecting allocates a vector and copies every item into it. When each caller only walks the result once, returning `impl Iterator` lets the items flow straight to the caller and removes the allocation. Functions visible outside the crate are skipped, because their callers cannot all be seen.",
+    bad_example: "fn active(users: &[User]) -> Vec<&User> {\n    users.iter().filter(|user| user.active).collect()\n}\n\nfor user in active(&users) {\n    notify(user);\n}",
+    good_example: "fn active(users: &[User]) -> impl Iterator<Item = &User> {\n    users.iter().filter(|user| user.active)\n}\n\nfor user in active(&users) {\n    notify(user);\n}",
+    config: &[],
+};
@@ -0,0 +1,405 @@
+//! Lint pass reporting private helpers whose collected `Vec` every caller
+//! only iterates.
+//!
+//! Each crate-private function or inherent method returning `Vec<T>` whose
+//! body ends in `Iterator::collect` is recorded as a candidate. Every place a
+//! local function is named is classified as a [`CallerUse`]: the head of a
+//! `for` loop, the receiver of an iterator method, an index, or anything
+//! else, including being passed around as a function value. Once the crate
+//! has been walked, candidates whose callers all iterate are reported. Trait
+//! methods are skipped because their signatures are shared with other
+//! implementations, and code produced by macro expansion is skipped.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{Span, sym};
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, describe_enclosing_item};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{CallerUse, iterating_callers};
+
+pub(crate) const LINT_NAME: &str = "iterator_returning_fn_should_not_collect_internally";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub ITERATOR_RETURNING_FN_SHOULD_NOT_COLLECT_INTERNALLY,
+    Warn,
+    "private helpers should return `impl Iterator` when every caller only iterates the collected `Vec`",
+    IteratorReturningFnShouldNotCollectInternally::default()
+}
+
+/// Lint pass collecting `Vec`-returning helpers and how callers use them.
+#[derive(Default)]
+pub struct IteratorReturningFnShouldNotCollectInternally {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Candidates, caller uses, finding limit, and localization for the crate
+/// being checked.
+#[derive(Default)]
+struct CrateState {
+    candidates: Vec<CollectingHelper>,
+    uses: HashMap<DefId, Vec<CallerUse>>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// A private function whose body ends by collecting into a `Vec`.
+#[derive(Clone, Debug)]
+struct CollectingHelper {
+    def_id: DefId,
+    hir_id: hir::HirId,
+    span: Span,
+    context: String,
+    item: String,
+}
+
+impl<'tcx> LateLintPass<'tcx> for IteratorReturningFnShouldNotCollectInternally {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            candidates: Vec::new(),
+            uses: HashMap::new(),
+            findings: FindingLimit::new(
+                ITERATOR_RETURNING_FN_SHOULD_NOT_COLLECT_INTERNALLY,
+                &shared_config,
+            ),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _decl: &'tcx hir::FnDecl<'tcx>,
+        body: &'tcx hir::Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        let ident = match kind {
+            FnKind::ItemFn(ident, ..) => ident,
+            FnKind::Method(ident, _) if is_inherent_method(cx, def_id) => ident,
+            _ => return,
+        };
+        if span.from_expansion() || cx.effective_visibilities.is_exported(def_id) {
+            return;
+        }
+        let output = cx
+            .tcx
+            .fn_sig(def_id)
+            .instantiate_identity()
+            .skip_normalization()
+            .skip_binder()
+            .output();
+        let Some(item) = vec_item(cx, output) else {
+            return;
+        };
+        if !ends_with_collect(cx, body) {
+            return;
+        }
+
+        let context =
+            describe_enclosing_item(cx, body.value.hir_id).describe(&self.state.localizer);
+        self.state.candidates.push(CollectingHelper {
+            def_id: def_id.to_def_id(),
+            hir_id: cx.tcx.local_def_id_to_hir_id(def_id),
+            span: ident.span,
+            context,
+            item: item.to_string(),
+        });
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let use_of = match expr.kind {
+            hir::ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) if def_id.is_local() => {
+                    Some((def_id, path_use(cx, expr)))
+                }
+                _ => None,
+            },
+            hir::ExprKind::MethodCall(..) => cx
+                .typeck_results()
+                .type_dependent_def_id(expr.hir_id)
+                .filter(|def_id| def_id.is_local())
+                .map(|def_id| (def_id, caller_use(cx, expr))),
+            _ => None,
+        };
+        if let Some((def_id, caller)) = use_of {
+            self.state.uses.entry(def_id).or_default().push(caller);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let state = &*self.state;
+        for helper in &state.candidates {
+            let uses = state
+                .uses
+                .get(&helper.def_id)
+                .map_or(&[][..], Vec::as_slice);
+            let Some(calls) = iterating_callers(uses) else {
+                debug!(
+                    target: LINT_NAME,
+                    "skipping {}: callers use the vector as {uses:?}",
+                    helper.context,
+                );
+                continue;
+            };
+            if state.findings.admit(cx, helper.hir_id, helper.span) {
+                emit_diagnostic(cx, helper, calls, &state.localizer);
+            }
+        }
+        state.findings.summarise(cx, &state.localizer);
+    }
+}
+
+/// Returns `true` for methods in inherent `impl` blocks, whose signatures
+/// are not fixed by a trait.
+fn is_inherent_method(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    matches!(
+        cx.tcx.hir_node_by_def_id(def_id),
+        hir::Node::ImplItem(hir::ImplItem {
+            impl_kind: hir::ImplItemImplKind::Inherent { .. },
+            ..
+        })
+    )
+}
+
+/// Returns `T` when `ty` is `Vec<T>`.
+fn vec_item<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    match ty.kind() {
+        ty::Adt(adt, args) if cx.tcx.is_diagnostic_item(sym::Vec, adt.did()) => {
+            Some(args.type_at(0))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` when the body's trailing expression calls
+/// `Iterator::collect`.
+fn ends_with_collect(cx: &LateContext<'_>, body: &hir::Body<'_>) -> bool {
+    let hir::ExprKind::Block(block, _) = body.value.kind else {
+        return false;
+    };
+    let Some(tail) = block.expr else {
+        return false;
+    };
+    if tail.span.from_expansion() {
+        return false;
+    }
+    matches!(tail.kind, hir::ExprKind::MethodCall(..))
+        && cx
+            .typeck_results()
+            .type_dependent_def_id(tail.hir_id)
+            .is_some_and(|def_id| cx.tcx.is_diagnostic_item(sym::iterator_collect_fn, def_id))
+}
+
+/// Classifies a path naming a local function: as the callee of a call it is
+/// classified by how the call's result is used, and anywhere else the
+/// function escapes as a value.
+fn path_use<'tcx>(cx: &LateContext<'tcx>, path: &'tcx hir::Expr<'tcx>) -> CallerUse {
+    match cx.tcx.parent_hir_node(path.hir_id) {
+        hir::Node::Expr(
+            call @ hir::Expr {
+                kind: hir::ExprKind::Call(callee, _),
+                ..
+            },
+        ) if callee.hir_id == path.hir_id => caller_use(cx, call),
+        _ => CallerUse::Other,
+    }
+}
+
+/// Classifies how the result of `call` is consumed.
+fn caller_use<'tcx>(cx: &LateContext<'tcx>, call: &'tcx hir::Expr<'tcx>) -> CallerUse {
+    if call.span.from_expansion() {
+        return CallerUse::Other;
+    }
+    let hir::Node::Expr(parent) = cx.tcx.parent_hir_node(call.hir_id) else {
+        return CallerUse::Other;
+    };
+    match parent.kind {
+        hir::ExprKind::MethodCall(segment, receiver, ..) if receiver.hir_id == call.hir_id => {
+            CallerUse::for_receiver_method(segment.ident.as_str())
+        }
+        hir::ExprKind::Index(base, ..) if base.hir_id == call.hir_id => CallerUse::Indexed,
+        hir::ExprKind::Call(_, [head])
+            if head.hir_id == call.hir_id && is_for_loop_head(cx, parent) =>
+        {
+            CallerUse::ForLoop
+        }
+        _ => CallerUse::Other,
+    }
+}
+
+/// Returns `true` when `into_iter` is the `IntoIterator::into_iter` call a
+/// `for` loop desugars its head into.
+fn is_for_loop_head(cx: &LateContext<'_>, into_iter: &hir::Expr<'_>) -> bool {
+    matches!(
+        cx.tcx.parent_hir_node(into_iter.hir_id),
+        hir::Node::Expr(hir::Expr {
+            kind: hir::ExprKind::Match(_, _, hir::MatchSource::ForLoopDesugar),
+            ..
+        })
+    )
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    helper: &CollectingHelper,
+    calls: usize,
+    localizer: &Localizer,
+) {
+    let message = CollectingHelperMessage {
+        context: &helper.context,
+        item: &helper.item,
+        calls,
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        ITERATOR_RETURNING_FN_SHOULD_NOT_COLLECT_INTERNALLY,
+        helper.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a collecting helper interpolates.
+#[derive(Clone, Copy, Debug)]
+struct CollectingHelperMessage<'a> {
+    context: &'a str,
+    item: &'a str,
+    calls: usize,
+}
+
+impl CollectingHelperMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(self.context.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("item"),
+            FluentValue::from(self.item.to_owned()),
+        );
+        args.insert(Cow::Borrowed("calls"), FluentValue::from(self.calls));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            context,
+            item,
+            calls,
+        } = *self;
+        let times = if calls == 1 { "time" } else { "times" };
+        DiagnosticMessageSet::new(
+            format!("Every caller of {context} only iterates the `Vec<{item}>` it collects."),
+            format!(
+                "The crate calls it {calls} {times}, and each call walks the result once without \
+                 indexing or keeping it, so the vector is allocated only to be consumed."
+            ),
+            format!(
+                "Return `impl Iterator<Item = {item}>` and drop the final `collect()` so items \
+                 flow straight to the callers."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message() -> CollectingHelperMessage<'static> {
+        message_with_calls(2)
+    }
+
+    fn message_with_calls(calls: usize) -> CollectingHelperMessage<'static> {
+        CollectingHelperMessage {
+            context: "function `active_names`",
+            item: "String",
+            calls,
+        }
+    }
+
+    fn resolve(message: &CollectingHelperMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_item_type() {
+        let messages = message().fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Every caller of function `active_names` only iterates the `Vec<String>` it collects."
+        );
+        assert!(messages.note().contains("calls it 2 times"));
+        assert!(messages.help().contains("`impl Iterator<Item = String>`"));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn english_messages_match_the_fallback(#[case] calls: usize) {
+        let message = message_with_calls(calls);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(), locale);
+
+        assert!(messages.primary().contains("`active_names`"));
+        assert!(messages.help().contains("impl Iterator<Item = String>"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,48 @@
+//! Lint crate flagging private helpers that collect into a `Vec` only for
+//! their callers to iterate it.
+//!
+//! A helper ending in `.collect()` allocates a vector on every call. When
+//! every caller in the crate immediately walks the result with a `for` loop,
+//! `iter`, or `into_iter`, and never indexes or stores it, the helper could
+//! return `impl Iterator` and skip the intermediate allocation. The lint
+//! records how each local function's result is used across the crate and
+//! reports helpers whose callers all iterate. Functions visible outside the
+//! crate are skipped, because their callers cannot all be seen. The lint is
+//! experimental and ships behind the
+//! `experimental-iterator-returning-fn-should-not-collect-internally` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{
+    CallerProjection, CollectingHelperProjection,
+    IteratorReturningFnShouldNotCollectInternallyDecision,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn iterator_returning_fn_should_not_collect_internally_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
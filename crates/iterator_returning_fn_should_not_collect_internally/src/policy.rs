@@ -0,0 +1,82 @@
+//! Caller-usage rules for helpers that collect into a `Vec`.
+//!
+//! The driver classifies every place a local function is named as a
+//! [`CallerUse`]. [`iterating_callers`] then decides whether all of those
+//! uses only iterate the returned vector, keeping the rules free of compiler
+//! types so they can be tested directly.
+
+/// How one call site consumes a helper's returned vector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum CallerUse {
+    /// The call is the head of a `for` loop.
+    ForLoop,
+    /// The call is the receiver of `iter`, `iter_mut`, or `into_iter`.
+    IteratorMethod,
+    /// The result is indexed, as in `helper()[0]`.
+    Indexed,
+    /// The result is stored, passed on, or otherwise kept as a vector, or the
+    /// function is named without being called.
+    Other,
+}
+
+/// Methods that turn a vector straight into an iterator.
+const ITERATOR_METHODS: [&str; 3] = ["iter", "iter_mut", "into_iter"];
+
+impl CallerUse {
+    /// Classifies a call whose result is the receiver of method `name`.
+    #[must_use]
+    pub(crate) fn for_receiver_method(name: &str) -> Self {
+        if ITERATOR_METHODS.contains(&name) {
+            Self::IteratorMethod
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Returns `true` when the use only walks the vector once.
+    #[must_use]
+    pub(crate) const fn iterates(self) -> bool {
+        matches!(self, Self::ForLoop | Self::IteratorMethod)
+    }
+}
+
+/// Returns the number of call sites when there is at least one and every
+/// one of them only iterates the result.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(iterating_callers(&[CallerUse::ForLoop]), Some(1));
+/// assert_eq!(iterating_callers(&[CallerUse::ForLoop, CallerUse::Indexed]), None);
+/// assert_eq!(iterating_callers(&[]), None);
+/// ```
+#[must_use]
+pub(crate) fn iterating_callers(uses: &[CallerUse]) -> Option<usize> {
+    (!uses.is_empty() && uses.iter().all(|caller| caller.iterates())).then_some(uses.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("iter", CallerUse::IteratorMethod)]
+    #[case("iter_mut", CallerUse::IteratorMethod)]
+    #[case("into_iter", CallerUse::IteratorMethod)]
+    #[case("len", CallerUse::Other)]
+    #[case("first", CallerUse::Other)]
+    fn receiver_methods_are_classified(#[case] name: &str, #[case] expected: CallerUse) {
+        assert_eq!(CallerUse::for_receiver_method(name), expected);
+    }
+
+    #[rstest]
+    #[case(&[CallerUse::ForLoop], Some(1))]
+    #[case(&[CallerUse::ForLoop, CallerUse::IteratorMethod], Some(2))]
+    #[case(&[CallerUse::ForLoop, CallerUse::Indexed], None)]
+    #[case(&[CallerUse::IteratorMethod, CallerUse::Other], None)]
+    #[case(&[], None)]
+    fn every_caller_must_iterate(#[case] uses: &[CallerUse], #[case] expected: Option<usize>) {
+        assert_eq!(iterating_callers(uses), expected);
+    }
+}
@@ -0,0 +1,75 @@
+//! Behaviour-driven coverage for the caller-usage policy.
+
+use crate::policy::{CallerUse, iterating_callers};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct CallerWorld {
+    uses: RefCell<Vec<CallerUse>>,
+    result: Cell<Option<Option<usize>>>,
+}
+
+#[fixture]
+fn world() -> CallerWorld {
+    CallerWorld::default()
+}
+
+#[given("a call site used as {usage}")]
+fn given_call_site(world: &CallerWorld, usage: String) {
+    let caller = match usage.trim_matches('"') {
+        "for-loop" => CallerUse::ForLoop,
+        "index" => CallerUse::Indexed,
+        method => CallerUse::for_receiver_method(method),
+    };
+    world.uses.borrow_mut().push(caller);
+}
+
+#[when("the helper is checked")]
+fn when_checked(world: &CallerWorld) {
+    let uses = world.uses.borrow();
+    world.result.set(Some(iterating_callers(&uses)));
+}
+
+#[then("the helper is accepted")]
+fn then_accepted(world: &CallerWorld) {
+    assert_eq!(world.result.get(), Some(None));
+}
+
+#[then("the helper is reported with {calls} call sites")]
+fn then_reported(world: &CallerWorld, calls: usize) {
+    assert_eq!(world.result.get(), Some(Some(calls)));
+}
+
+#[scenario(
+    path = "tests/features/iterator_returning_fn_should_not_collect_internally.feature",
+    index = 0
+)]
+fn scenario_all_callers_iterate(world: CallerWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/iterator_returning_fn_should_not_collect_internally.feature",
+    index = 1
+)]
+fn scenario_indexed_result(world: CallerWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/iterator_returning_fn_should_not_collect_internally.feature",
+    index = 2
+)]
+fn scenario_stored_result(world: CallerWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/iterator_returning_fn_should_not_collect_internally.feature",
+    index = 3
+)]
+fn scenario_without_callers(world: CallerWorld) {
+    let _ = world;
+}
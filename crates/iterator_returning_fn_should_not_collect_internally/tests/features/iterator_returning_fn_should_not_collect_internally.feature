@@ -0,0 +1,26 @@
+Feature: Helpers collecting into a vector their callers only iterate
+  A private helper ending in `collect()` is reported when it has callers
+  and every one of them iterates the returned vector without indexing or
+  keeping it.
+
+  Scenario: A helper whose callers all iterate is reported
+    Given a call site used as "for-loop"
+    And a call site used as "into_iter"
+    When the helper is checked
+    Then the helper is reported with 2 call sites
+
+  Scenario: An indexed result keeps the vector
+    Given a call site used as "for-loop"
+    And a call site used as "index"
+    When the helper is checked
+    Then the helper is accepted
+
+  Scenario: A stored result keeps the vector
+    Given a call site used as "iter"
+    And a call site used as "len"
+    When the helper is checked
+    Then the helper is accepted
+
+  Scenario: A helper without callers is accepted
+    When the helper is checked
+    Then the helper is accepted
@@ -0,0 +1,31 @@
+//! Private helpers whose collected vectors are only ever iterated.
+
+fn active_names(names: &[&str]) -> Vec<String> {
+    names
+        .iter()
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_uppercase())
+        .collect()
+}
+
+struct Ledger {
+    entries: Vec<i64>,
+}
+
+impl Ledger {
+    fn credits(&self) -> Vec<i64> {
+        self.entries.iter().copied().filter(|entry| *entry > 0).collect()
+    }
+}
+
+fn main() {
+    for name in active_names(&["a", ""]) {
+        let _ = name;
+    }
+    let _ = active_names(&["b"]).into_iter().count();
+
+    let ledger = Ledger {
+        entries: vec![3, -1],
+    };
+    let _: i64 = ledger.credits().iter().sum();
+}
@@ -0,0 +1,58 @@
+//! Helpers whose vectors are indexed, stored, or passed around, exported
+//! helpers, and trait methods are accepted.
+
+fn indexed(values: &[u32]) -> Vec<u32> {
+    values.iter().map(|value| value * 2).collect()
+}
+
+fn stored(values: &[u32]) -> Vec<u32> {
+    values.iter().map(|value| value + 1).collect()
+}
+
+fn passed_as_value(values: &[u32]) -> Vec<u32> {
+    values.iter().rev().copied().collect()
+}
+
+fn uncalled(values: &[u32]) -> Vec<u32> {
+    values.to_vec().into_iter().collect()
+}
+
+pub fn exported(values: &[u32]) -> Vec<u32> {
+    values.iter().copied().collect()
+}
+
+trait Source {
+    fn items(&self) -> Vec<u32>;
+}
+
+struct Numbers;
+
+impl Source for Numbers {
+    fn items(&self) -> Vec<u32> {
+        (0..3).collect()
+    }
+}
+
+fn main() {
+    let _ = indexed(&[1, 2])[0];
+    for value in indexed(&[3]) {
+        let _ = value;
+    }
+
+    let kept = stored(&[1]);
+    let _ = kept.len();
+
+    let lists: Vec<Vec<u32>> = [[1_u32].as_slice()].into_iter().map(passed_as_value).collect();
+    let _ = lists;
+    for value in passed_as_value(&[2]) {
+        let _ = value;
+    }
+
+    let _ = uncalled;
+    for value in exported(&[4]) {
+        let _ = value;
+    }
+    for value in Numbers.items() {
+        let _ = value;
+    }
+}
@@ -0,0 +1,147 @@
+//! Pure decision logic for `large_stack_values`.
+//!
+//! The driver measures each local variable and argument and notes whether it
+//! is an array in a const context or a `#[repr(C)]` type.
+//! [`LargeStackValuesDecision`] applies the same [`StackValuePolicy`] and
+//! reports values over the limit. Findings use the binding kind's Fluent
+//! selector as their reason and the binding's name as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_BYTES, LINT_NAME};
+use crate::policy::{BindingKind, StackValue, StackValuePolicy};
+
+/// The facts about one binding that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StackBindingProjection {
+    /// The binding's name.
+    pub name: String,
+    /// Whether the binding is a function argument rather than a local.
+    pub argument: bool,
+    /// The size of the binding's type in bytes.
+    pub size: u64,
+    /// Whether the type is an array.
+    pub is_array: bool,
+    /// Whether the binding is in a `const` or `static` initialiser.
+    pub in_const_context: bool,
+    /// Whether the type is `#[repr(C)]`.
+    pub is_repr_c: bool,
+}
+
+/// Reports locals and arguments larger than `max_bytes`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let buffer = StackBindingProjection {
+///     name: "buffer".to_owned(),
+///     size: 8192,
+///     ..StackBindingProjection::default()
+/// };
+/// let finding = LargeStackValuesDecision::default().decide(&buffer);
+/// assert_eq!(finding.map(|finding| finding.reason()), Some("local"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LargeStackValuesDecision {
+    policy: StackValuePolicy,
+}
+
+impl LargeStackValuesDecision {
+    /// Creates a decision reporting values over `max_bytes`, optionally
+    /// exempting arrays in const contexts and `#[repr(C)]` types.
+    #[must_use]
+    pub const fn new(max_bytes: u64, exempt_const_arrays: bool, exempt_repr_c: bool) -> Self {
+        Self {
+            policy: StackValuePolicy::new(max_bytes, exempt_const_arrays, exempt_repr_c),
+        }
+    }
+}
+
+impl Default for LargeStackValuesDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BYTES, true, true)
+    }
+}
+
+impl LintDecision for LargeStackValuesDecision {
+    type Projection = StackBindingProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, binding: &StackBindingProjection) -> Option<Finding> {
+        let value = StackValue {
+            size: binding.size,
+            is_array: binding.is_array,
+            in_const_context: binding.in_const_context,
+            is_repr_c: binding.is_repr_c,
+        };
+        let kind = if binding.argument {
+            BindingKind::Argument
+        } else {
+            BindingKind::Local
+        };
+        self.policy.exceeds_limit(value).then(|| {
+            Finding::new(LINT_NAME, kind.fluent_kind()).with_subject(binding.name.as_str())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<StackBindingProjection>> {
+        let limit = self.policy.max_bytes();
+        let binding = |size, argument| StackBindingProjection {
+            name: "buffer".to_owned(),
+            argument,
+            size,
+            ..StackBindingProjection::default()
+        };
+        vec![
+            DecisionFixture::flagged(
+                "local over the limit",
+                binding(limit + 1, false),
+                BindingKind::Local.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "argument over the limit",
+                binding(limit + 1, true),
+                BindingKind::Argument.fluent_kind(),
+            ),
+            DecisionFixture::clean("at the limit", binding(limit, false)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn exemptions_follow_the_configuration() {
+        let array = StackBindingProjection {
+            size: 64,
+            is_array: true,
+            in_const_context: true,
+            ..StackBindingProjection::default()
+        };
+
+        assert!(
+            LargeStackValuesDecision::new(16, true, false)
+                .decide(&array)
+                .is_none()
+        );
+        assert!(
+            LargeStackValuesDecision::new(16, false, false)
+                .decide(&array)
+                .is_some()
+        );
+    }
+
+    #[rstest]
+    #[case::default_policy(LargeStackValuesDecision::default())]
+    #[case::small_limit(LargeStackValuesDecision::new(16, false, false))]
+    fn decision_conforms(#[case] decision: LargeStackValuesDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
@@ -0,0 +1,33 @@
+//! Embedded documentation for the `large_stack_values` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `large_stack_values`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "large_stack_values",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags local variables and function arguments whose types are larger than the configured number of bytes.",
+    rationale: "Large values held on the stack can overflow it, particularly on threads with small stacks or in recursive code, and every move copies them byte by byte. Boxing keeps the value on the heap and makes moves cheap.",
+    bad_example: "let buffer = [0_u8; 65_536];",
+    good_example: "let buffer = vec![0_u8; 65_536].into_boxed_slice();",
+    config: &[
+        ConfigKeyDocs {
+            key: "max_bytes",
+            default: "4096",
+            description: "Largest size, in bytes, a local variable or argument may have.",
+        },
+        ConfigKeyDocs {
+            key: "exempt_const_arrays",
+            default: "true",
+            description: "Skip arrays declared inside `const` items and `const fn` bodies, where values cannot be boxed.",
+        },
+        ConfigKeyDocs {
+            key: "exempt_repr_c",
+            default: "true",
+            description: "Skip `#[repr(C)]` types, whose layout foreign code usually dictates.",
+        },
+    ],
+};
@@ -0,0 +1,379 @@
+//! Lint pass reporting local variables and arguments with oversized types.
+//!
+//! The pass visits every `let` binding and every parameter of a function or
+//! method, computes the size of the bound type with `layout_of`, and hands the
+//! result to [`StackValuePolicy`]. Types whose layout depends on generic
+//! parameters cannot be measured and are skipped, as are bindings created by
+//! macro expansion or desugaring.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::intravisit::FnKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+use rustc_span::def_id::LocalDefId;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, describe_enclosing_item};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{BindingKind, StackValue, StackValuePolicy};
+
+pub(crate) const LINT_NAME: &str = "large_stack_values";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_BYTES: u64 = 4096;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_bytes: u64,
+    exempt_const_arrays: bool,
+    exempt_repr_c: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_BYTES,
+            exempt_const_arrays: true,
+            exempt_repr_c: true,
+        }
+    }
+}
+
+impl Config {
+    const fn policy(&self) -> StackValuePolicy {
+        StackValuePolicy::new(self.max_bytes, self.exempt_const_arrays, self.exempt_repr_c)
+    }
+}
+
+/// Lint pass that measures local variables and arguments.
+#[derive(Default)]
+pub struct LargeStackValues {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Size limits, finding limit, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub LARGE_STACK_VALUES,
+    Warn,
+    "local variables and arguments should not hold values larger than the configured size",
+    LargeStackValues::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for LargeStackValues {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(LARGE_STACK_VALUES, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx hir::LetStmt<'tcx>) {
+        if !matches!(local.source, hir::LocalSource::Normal) || local.span.from_expansion() {
+            return;
+        }
+        self.check_bindings(cx, local.pat, BindingKind::Local);
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _decl: &'tcx hir::FnDecl<'tcx>,
+        body: &'tcx hir::Body<'tcx>,
+        span: Span,
+        _def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure) || span.from_expansion() {
+            return;
+        }
+        for param in body.params {
+            self.check_bindings(cx, param.pat, BindingKind::Argument);
+        }
+    }
+}
+
+impl LargeStackValues {
+    fn check_bindings<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        pat: &'tcx hir::Pat<'tcx>,
+        kind: BindingKind,
+    ) {
+        let owner = cx.tcx.hir_enclosing_body_owner(pat.hir_id);
+        let typeck_results = cx.tcx.typeck(owner);
+        let in_const_context = cx.tcx.hir_body_const_context(owner).is_some();
+        pat.each_binding(|_, hir_id, span, ident| {
+            if span.from_expansion() {
+                return;
+            }
+            let ty = typeck_results.node_type(hir_id);
+            let Some(value) = measure(cx, ty, in_const_context) else {
+                return;
+            };
+            let policy = self
+                .state
+                .findings
+                .config_at(cx, span, &self.state.config)
+                .policy();
+            if !policy.exceeds_limit(value) {
+                return;
+            }
+            debug!(
+                target: LINT_NAME,
+                "`{ident}` has type `{ty}` of {} bytes (limit {})",
+                value.size,
+                policy.max_bytes(),
+            );
+            if !self.state.findings.admit(cx, hir_id, span) {
+                return;
+            }
+            let context = describe_enclosing_item(cx, hir_id).describe(&self.state.localizer);
+            let message = StackValueMessage {
+                name: ident.name.as_str(),
+                type_name: &ty.to_string(),
+                context: &context,
+                kind,
+                size: value.size,
+                limit: policy.max_bytes(),
+            };
+            emit_diagnostic(cx, span, &message, &self.state.localizer);
+        });
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Describes `ty` for the policy, or returns `None` when its layout is not
+/// known, as for types that depend on generic parameters.
+fn measure<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    in_const_context: bool,
+) -> Option<StackValue> {
+    let layout = cx.tcx.layout_of(cx.typing_env().as_query_input(ty)).ok()?;
+    Some(StackValue {
+        size: layout.size.bytes(),
+        is_array: matches!(ty.kind(), ty::Array(..)),
+        in_const_context,
+        is_repr_c: ty.ty_adt_def().is_some_and(|adt| adt.repr().c()),
+    })
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &StackValueMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        LARGE_STACK_VALUES,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for an oversized binding interpolates.
+#[derive(Clone, Copy, Debug)]
+struct StackValueMessage<'a> {
+    name: &'a str,
+    type_name: &'a str,
+    context: &'a str,
+    kind: BindingKind,
+    size: u64,
+    limit: u64,
+}
+
+impl StackValueMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("name"),
+            FluentValue::from(self.name.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("type"),
+            FluentValue::from(self.type_name.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(self.context.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("kind"),
+            FluentValue::from(self.kind.fluent_kind()),
+        );
+        args.insert(Cow::Borrowed("size"), FluentValue::from(self.size));
+        args.insert(Cow::Borrowed("limit"), FluentValue::from(self.limit));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            name,
+            type_name,
+            context,
+            kind,
+            size,
+            limit,
+        } = *self;
+        let (binding, help) = match kind {
+            BindingKind::Local => (
+                "Local variable",
+                format!(
+                    "Allocate the value on the heap, for example as a `Box<{type_name}>` or a \
+                     `Vec`, so only a pointer lives on the stack."
+                ),
+            ),
+            BindingKind::Argument => (
+                "Argument",
+                format!("Take the argument by reference or as a `Box<{type_name}>`."),
+            ),
+        };
+        DiagnosticMessageSet::new(
+            format!("{binding} `{name}` in {context} is {size} bytes, above the limit of {limit}."),
+            format!(
+                "`{type_name}` occupies the stack while `{name}` is live, and every move copies \
+                 all {size} bytes."
+            ),
+            help,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(kind: BindingKind) -> StackValueMessage<'static> {
+        StackValueMessage {
+            name: "buffer",
+            type_name: "[u8; 65536]",
+            context: "function `load`",
+            kind,
+            size: 65_536,
+            limit: 4096,
+        }
+    }
+
+    fn resolve(message: &StackValueMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_exempts_const_arrays_and_repr_c() {
+        assert_eq!(
+            Config::default().policy(),
+            StackValuePolicy::new(4096, true, true)
+        );
+    }
+
+    #[rstest]
+    #[case(
+        BindingKind::Local,
+        "Local variable `buffer` in function `load` is 65536 bytes, above the limit of 4096.",
+        "Box<[u8; 65536]>"
+    )]
+    #[case(
+        BindingKind::Argument,
+        "Argument `buffer` in function `load` is 65536 bytes, above the limit of 4096.",
+        "by reference"
+    )]
+    fn fallback_messages_name_the_binding_and_size(
+        #[case] kind: BindingKind,
+        #[case] primary: &str,
+        #[case] help: &str,
+    ) {
+        let messages = message(kind).fallback_messages();
+
+        assert_eq!(messages.primary(), primary);
+        assert!(messages.note().contains("`[u8; 65536]`"));
+        assert!(messages.help().contains(help), "{}", messages.help());
+    }
+
+    #[rstest]
+    #[case(BindingKind::Local)]
+    #[case(BindingKind::Argument)]
+    fn english_messages_match_the_fallback(#[case] kind: BindingKind) {
+        let message = message(kind);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(BindingKind::Argument), locale);
+
+        assert!(messages.primary().contains("`buffer`"));
+        assert!(messages.primary().contains("65536"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,42 @@
+//! Lint crate flagging local variables and arguments too large for the stack.
+//!
+//! Every local variable and by-value argument occupies stack space for as
+//! long as it lives, and moving it copies every byte. The lint computes the
+//! size of each binding's type with `layout_of` and reports those above
+//! `max_bytes`, suggesting a `Box` or a reference. Arrays inside `const`
+//! contexts, where boxing is unavailable, and `#[repr(C)]` types, whose layout
+//! is usually dictated by foreign code, are exempt by default. The lint is
+//! experimental and ships behind the `experimental-large-stack-values` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{LargeStackValuesDecision, StackBindingProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn large_stack_values_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,148 @@
+//! Size thresholds and exemptions for stack-allocated values.
+//!
+//! The driver measures each local variable and argument and describes it as a
+//! [`StackValue`]. [`StackValuePolicy::exceeds_limit`] then decides whether
+//! the value is reported, keeping the configured exemptions free of compiler
+//! types so they can be tested directly.
+
+/// Where a measured value is bound.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum BindingKind {
+    /// A variable bound by `let`.
+    Local,
+    /// A function or method parameter.
+    Argument,
+}
+
+impl BindingKind {
+    /// Returns the selector the Fluent messages use for this binding.
+    #[must_use]
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Argument => "argument",
+        }
+    }
+}
+
+/// The facts about a binding's type that the policy needs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct StackValue {
+    /// Size of the type in bytes.
+    pub(crate) size: u64,
+    /// Whether the type is an array.
+    pub(crate) is_array: bool,
+    /// Whether the binding is inside a `const` item or `const fn` body.
+    pub(crate) in_const_context: bool,
+    /// Whether the type is an ADT declared `#[repr(C)]`.
+    pub(crate) is_repr_c: bool,
+}
+
+/// The configured size limit and exemptions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct StackValuePolicy {
+    max_bytes: u64,
+    exempt_const_arrays: bool,
+    exempt_repr_c: bool,
+}
+
+impl StackValuePolicy {
+    /// Creates a policy reporting values above `max_bytes`.
+    #[must_use]
+    pub(crate) const fn new(
+        max_bytes: u64,
+        exempt_const_arrays: bool,
+        exempt_repr_c: bool,
+    ) -> Self {
+        Self {
+            max_bytes,
+            exempt_const_arrays,
+            exempt_repr_c,
+        }
+    }
+
+    /// Returns the configured size limit in bytes.
+    #[must_use]
+    pub(crate) const fn max_bytes(self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Returns `true` when `value` is larger than the limit and no exemption
+    /// applies.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = StackValuePolicy::new(4096, true, true);
+    /// let value = StackValue { size: 8192, is_array: true, in_const_context: false, is_repr_c: false };
+    /// assert!(policy.exceeds_limit(value));
+    /// ```
+    #[must_use]
+    pub(crate) const fn exceeds_limit(self, value: StackValue) -> bool {
+        if value.size <= self.max_bytes {
+            return false;
+        }
+        let const_array = self.exempt_const_arrays && value.is_array && value.in_const_context;
+        let repr_c = self.exempt_repr_c && value.is_repr_c;
+        !const_array && !repr_c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const fn value(size: u64) -> StackValue {
+        StackValue {
+            size,
+            is_array: false,
+            in_const_context: false,
+            is_repr_c: false,
+        }
+    }
+
+    #[rstest]
+    #[case::below(4095, false)]
+    #[case::at_limit(4096, false)]
+    #[case::above(4097, true)]
+    fn compares_sizes_with_the_limit(#[case] size: u64, #[case] expected: bool) {
+        let policy = StackValuePolicy::new(4096, true, true);
+
+        assert_eq!(policy.exceeds_limit(value(size)), expected);
+    }
+
+    #[rstest]
+    #[case::exempt_const_array(true, true, true, false)]
+    #[case::reported_const_array(false, true, true, true)]
+    #[case::runtime_array(true, true, false, true)]
+    #[case::const_struct(true, false, true, true)]
+    fn exempts_arrays_in_const_contexts(
+        #[case] exempt: bool,
+        #[case] is_array: bool,
+        #[case] in_const_context: bool,
+        #[case] expected: bool,
+    ) {
+        let policy = StackValuePolicy::new(16, exempt, false);
+        let value = StackValue {
+            is_array,
+            in_const_context,
+            ..value(64)
+        };
+
+        assert_eq!(policy.exceeds_limit(value), expected);
+    }
+
+    #[rstest]
+    #[case::exempt(true, false)]
+    #[case::reported(false, true)]
+    fn exempts_repr_c_types(#[case] exempt: bool, #[case] expected: bool) {
+        let policy = StackValuePolicy::new(16, false, exempt);
+        let value = StackValue {
+            is_repr_c: true,
+            ..value(64)
+        };
+
+        assert_eq!(policy.exceeds_limit(value), expected);
+    }
+}
@@ -0,0 +1,112 @@
+//! Behaviour-driven coverage for the stack value size policy.
+
+use crate::policy::{StackValue, StackValuePolicy};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+struct StackValueWorld {
+    max_bytes: Cell<u64>,
+    exempt_repr_c: Cell<bool>,
+    value: Cell<StackValue>,
+    reported: Cell<Option<bool>>,
+}
+
+impl Default for StackValueWorld {
+    fn default() -> Self {
+        Self {
+            max_bytes: Cell::new(0),
+            exempt_repr_c: Cell::new(true),
+            value: Cell::new(StackValue {
+                size: 0,
+                is_array: false,
+                in_const_context: false,
+                is_repr_c: false,
+            }),
+            reported: Cell::new(None),
+        }
+    }
+}
+
+#[fixture]
+fn world() -> StackValueWorld {
+    StackValueWorld::default()
+}
+
+#[given("a limit of {bytes} bytes")]
+fn given_limit(world: &StackValueWorld, bytes: u64) {
+    world.max_bytes.set(bytes);
+}
+
+#[given("repr(C) types are not exempt")]
+fn given_repr_c_not_exempt(world: &StackValueWorld) {
+    world.exempt_repr_c.set(false);
+}
+
+#[given("a value of {bytes} bytes")]
+fn given_value(world: &StackValueWorld, bytes: u64) {
+    world.value.set(StackValue {
+        size: bytes,
+        ..world.value.get()
+    });
+}
+
+#[given("the value is an array in a const context")]
+fn given_const_array(world: &StackValueWorld) {
+    world.value.set(StackValue {
+        is_array: true,
+        in_const_context: true,
+        ..world.value.get()
+    });
+}
+
+#[given("the value has a repr(C) type")]
+fn given_repr_c(world: &StackValueWorld) {
+    world.value.set(StackValue {
+        is_repr_c: true,
+        ..world.value.get()
+    });
+}
+
+#[when("the value is checked")]
+fn when_checked(world: &StackValueWorld) {
+    let policy = StackValuePolicy::new(world.max_bytes.get(), true, world.exempt_repr_c.get());
+    world
+        .reported
+        .set(Some(policy.exceeds_limit(world.value.get())));
+}
+
+#[then("the value is reported")]
+fn then_reported(world: &StackValueWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("the value is accepted")]
+fn then_accepted(world: &StackValueWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/large_stack_values.feature", index = 0)]
+fn scenario_value_above_limit(world: StackValueWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/large_stack_values.feature", index = 1)]
+fn scenario_value_at_limit(world: StackValueWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/large_stack_values.feature", index = 2)]
+fn scenario_const_array_exempt(world: StackValueWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/large_stack_values.feature", index = 3)]
+fn scenario_repr_c_exempt(world: StackValueWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/large_stack_values.feature", index = 4)]
+fn scenario_repr_c_reported(world: StackValueWorld) {
+    let _ = world;
+}
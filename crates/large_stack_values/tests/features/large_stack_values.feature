@@ -0,0 +1,37 @@
+Feature: Large stack values
+  Local variables and arguments are reported when their types are larger
+  than the configured limit, unless an exemption applies.
+
+  Scenario: A value above the limit is reported
+    Given a limit of 4096 bytes
+    And a value of 8192 bytes
+    When the value is checked
+    Then the value is reported
+
+  Scenario: A value at the limit is accepted
+    Given a limit of 4096 bytes
+    And a value of 4096 bytes
+    When the value is checked
+    Then the value is accepted
+
+  Scenario: An array in a const context is exempt
+    Given a limit of 4096 bytes
+    And a value of 8192 bytes
+    And the value is an array in a const context
+    When the value is checked
+    Then the value is accepted
+
+  Scenario: A repr(C) type is exempt
+    Given a limit of 4096 bytes
+    And a value of 8192 bytes
+    And the value has a repr(C) type
+    When the value is checked
+    Then the value is accepted
+
+  Scenario: A repr(C) type is reported when the exemption is disabled
+    Given a limit of 4096 bytes
+    And repr(C) types are not exempt
+    And a value of 8192 bytes
+    And the value has a repr(C) type
+    When the value is checked
+    Then the value is reported
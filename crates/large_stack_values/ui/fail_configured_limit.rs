@@ -0,0 +1,12 @@
+//! With `max_bytes = 64` and `exempt_repr_c = false`, a 128-byte `#[repr(C)]`
+//! local is reported.
+
+#[repr(C)]
+pub struct Header {
+    fields: [u32; 32],
+}
+
+fn main() {
+    let header = Header { fields: [0; 32] };
+    println!("{}", header.fields.len());
+}
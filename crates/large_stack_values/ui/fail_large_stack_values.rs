@@ -0,0 +1,15 @@
+//! Locals and arguments above the default limit of 4096 bytes are reported.
+
+pub struct Frame {
+    pixels: [u32; 2048],
+}
+
+pub fn checksum(frame: Frame) -> u32 {
+    frame.pixels.iter().sum()
+}
+
+fn main() {
+    let buffer = [0_u8; 8192];
+    let frame = Frame { pixels: [1; 2048] };
+    println!("{} {}", buffer.len(), checksum(frame));
+}
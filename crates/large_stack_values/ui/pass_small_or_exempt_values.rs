@@ -0,0 +1,26 @@
+//! Small values, generic values, and exempt bindings are not reported.
+
+#[repr(C)]
+pub struct FfiBuffer {
+    bytes: [u8; 8192],
+}
+
+pub fn read(buffer: FfiBuffer) -> u8 {
+    buffer.bytes[0]
+}
+
+pub const fn table() -> u8 {
+    let table = [7_u8; 8192];
+    table[0]
+}
+
+pub fn first<T: Copy>(values: [T; 8192]) -> T {
+    values[0]
+}
+
+fn main() {
+    let small = [0_u8; 64];
+    let boxed = Box::new(FfiBuffer { bytes: [0; 8192] });
+    println!("{} {} {}", small.len(), read(*boxed), table());
+    let _ = first::<u8>;
+}
@@ -0,0 +1,136 @@
+//! Pure decision logic for `lint_crate_missing_stub_for_non_driver_builds`.
+//!
+//! The driver hands the source text of the crate root to
+//! [`FeatureGating::scan`]. [`LintCrateMissingStubForNonDriverBuildsDecision`]
+//! scans the same text and reports crates that gate items on the driver
+//! feature without a stub module, naming the first gating attribute as the
+//! finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_FEATURE, LINT_NAME};
+use crate::policy::FeatureGating;
+
+/// Reason key for lint crates without a stub module.
+const MISSING_STUB: &str = "missing_stub";
+
+/// The facts about one crate root that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CrateRootProjection {
+    /// The source text of the crate root.
+    pub source: String,
+}
+
+impl CrateRootProjection {
+    /// Creates a projection for the crate root `source`.
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+/// Reports lint crates that gate items on `feature` without a module that
+/// builds when the feature is off.
+///
+/// # Examples
+///
+/// ```ignore
+/// let root = CrateRootProjection::new(
+///     "#[cfg(feature = \"dylint-driver\")]\nmod driver;",
+/// );
+/// let decision = LintCrateMissingStubForNonDriverBuildsDecision::default();
+/// assert!(decision.decide(&root).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LintCrateMissingStubForNonDriverBuildsDecision {
+    feature: String,
+}
+
+impl LintCrateMissingStubForNonDriverBuildsDecision {
+    /// Creates a decision for crates gating their driver on `feature`.
+    #[must_use]
+    pub fn new(feature: impl Into<String>) -> Self {
+        Self {
+            feature: feature.into(),
+        }
+    }
+}
+
+impl Default for LintCrateMissingStubForNonDriverBuildsDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_FEATURE)
+    }
+}
+
+impl LintDecision for LintCrateMissingStubForNonDriverBuildsDecision {
+    type Projection = CrateRootProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, root: &CrateRootProjection) -> Option<Finding> {
+        let gating = FeatureGating::scan(&root.source, &self.feature);
+        if !gating.lacks_stub() {
+            return None;
+        }
+        let gate = root.source.get(gating.first_driver_gate?)?;
+        Some(Finding::new(LINT_NAME, MISSING_STUB).with_subject(gate))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<CrateRootProjection>> {
+        let gated = format!("#[cfg(feature = \"{}\")]\nmod driver;\n", self.feature);
+        let stubbed = format!(
+            "{gated}#[cfg(not(feature = \"{}\"))]\nmod stub {{}}\n",
+            self.feature
+        );
+        vec![
+            DecisionFixture::flagged(
+                "driver gate without a stub",
+                CrateRootProjection::new(gated.as_str()),
+                MISSING_STUB,
+            ),
+            DecisionFixture::clean(
+                "stub gated on the feature's absence",
+                CrateRootProjection::new(stubbed),
+            ),
+            DecisionFixture::clean(
+                "ungated module",
+                CrateRootProjection::new(format!("{gated}mod common;\n")),
+            ),
+            DecisionFixture::clean(
+                "crate without a driver",
+                CrateRootProjection::new("mod common;\n"),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_first_gate() {
+        let root = CrateRootProjection::new("#[cfg(feature = \"dylint-driver\")]\nmod driver;");
+        let finding = LintCrateMissingStubForNonDriverBuildsDecision::default()
+            .decide(&root)
+            .expect("the crate should be reported");
+
+        assert_eq!(
+            finding.subject(),
+            Some("#[cfg(feature = \"dylint-driver\")]")
+        );
+    }
+
+    #[rstest]
+    #[case::default_feature(LintCrateMissingStubForNonDriverBuildsDecision::default())]
+    #[case::custom_feature(LintCrateMissingStubForNonDriverBuildsDecision::new("rustc"))]
+    fn decision_conforms(#[case] decision: LintCrateMissingStubForNonDriverBuildsDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
@@ -0,0 +1,23 @@
+//! Embedded documentation for the
+//! `lint_crate_missing_stub_for_non_driver_builds` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `lint_crate_missing_stub_for_non_driver_builds`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "lint_crate_missing_stub_for_non_driver_builds",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags lint crates that gate items on the `dylint-driver` feature without declaring a `#[cfg(not(feature = \"dylint-driver\"))]` stub module.",
+    rationale: "Without the stub, a lint crate built with default features compiles none of its code. The breakage surfaces only when someone builds the crate without the driver feature, long after the change that caused it.",
+    bad_example: "#[cfg(feature = \"dylint-driver\")]\nmod driver;\n\n#[cfg(feature = \"dylint-driver\")]\npub use driver::*;",
+    good_example: "#[cfg(feature = \"dylint-driver\")]\nmod driver;\n\n#[cfg(feature = \"dylint-driver\")]\npub use driver::*;\n\n#[cfg(not(feature = \"dylint-driver\"))]\nmod stub {\n    #[expect(dead_code, reason = \"stub when dylint-driver is disabled\")]\n    pub fn my_lint_disabled_stub() {}\n}",
+    config: &[ConfigKeyDocs {
+        key: "feature",
+        default: "\"dylint-driver\"",
+        description: "Name of the feature lint crates gate their driver code on.",
+    }],
+};
@@ -0,0 +1,258 @@
+//! Lint pass reporting lint crates without a stub for non-driver builds.
+//!
+//! The crate root's source text is scanned through [`FeatureGating::scan`],
+//! since the stub module is gated out of the build being checked. A crate
+//! that gates top-level items on the configured feature but declares no
+//! module that builds without it is reported once, at the first attribute
+//! gating an item on the feature.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{BytePos, Pos, Span};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::FeatureGating;
+
+pub(crate) const LINT_NAME: &str = "lint_crate_missing_stub_for_non_driver_builds";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_FEATURE: &str = "dylint-driver";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    feature: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            feature: DEFAULT_FEATURE.to_owned(),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub LINT_CRATE_MISSING_STUB_FOR_NON_DRIVER_BUILDS,
+    Warn,
+    "lint crates gating items on `dylint-driver` should declare a stub module for builds without it",
+    LintCrateMissingStubForNonDriverBuilds::default()
+}
+
+/// Lint pass that checks lint crate roots for a non-driver stub module.
+#[derive(Default)]
+pub struct LintCrateMissingStubForNonDriverBuilds {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configured feature, finding limit, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    feature: String,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for LintCrateMissingStubForNonDriverBuilds {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            feature: load_configuration().feature,
+            findings: FindingLimit::new(
+                LINT_CRATE_MISSING_STUB_FOR_NON_DRIVER_BUILDS,
+                &shared_config,
+            ),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+
+        let state = &*self.state;
+        let root = cx.tcx.hir_root_module().spans.inner_span;
+        let Ok(source) = cx.sess().source_map().span_to_snippet(root) else {
+            return;
+        };
+        let gating = FeatureGating::scan(&source, &state.feature);
+        if !gating.lacks_stub() {
+            return;
+        }
+        let Some(gate) = gating.first_driver_gate else {
+            return;
+        };
+
+        debug!(
+            target: LINT_NAME,
+            "crate gates items on `{}` without a stub module",
+            state.feature,
+        );
+        let offset = |position: usize| root.lo() + BytePos::from_usize(position);
+        let span = root.with_lo(offset(gate.start)).with_hi(offset(gate.end));
+        if state.findings.admit(cx, hir::CRATE_HIR_ID, span) {
+            let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+            let message = MissingStubMessage {
+                feature: &state.feature,
+                crate_name: crate_name.as_str(),
+            };
+            emit_diagnostic(cx, span, &message, &state.localizer);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &MissingStubMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        LINT_CRATE_MISSING_STUB_FOR_NON_DRIVER_BUILDS,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for a missing stub interpolates.
+#[derive(Clone, Copy, Debug)]
+struct MissingStubMessage<'a> {
+    feature: &'a str,
+    crate_name: &'a str,
+}
+
+impl MissingStubMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("feature"),
+            FluentValue::from(self.feature.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("crate"),
+            FluentValue::from(self.crate_name.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            feature,
+            crate_name,
+        } = *self;
+        DiagnosticMessageSet::new(
+            format!(
+                "Lint crate `{crate_name}` gates its items on `{feature}` but has no stub module \
+                 for builds without it."
+            ),
+            format!(
+                "Builds with default features compile none of the lint's code, and the breakage \
+                 only shows when someone builds `{crate_name}` without `{feature}`."
+            ),
+            format!(
+                "Add `#[cfg(not(feature = \"{feature}\"))] mod stub {{ pub fn \
+                 {crate_name}_disabled_stub() {{}} }}` to the crate root."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn message() -> MissingStubMessage<'static> {
+        MissingStubMessage {
+            feature: "dylint-driver",
+            crate_name: "no_recursion",
+        }
+    }
+
+    fn resolve(message: &MissingStubMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_checks_the_driver_feature() {
+        assert_eq!(Config::default().feature, "dylint-driver");
+    }
+
+    #[rstest]
+    fn fallback_help_gives_the_stub(message: MissingStubMessage<'static>) {
+        assert_eq!(
+            message.fallback_messages().help(),
+            "Add `#[cfg(not(feature = \"dylint-driver\"))] mod stub { pub fn \
+             no_recursion_disabled_stub() {} }` to the crate root."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback(message: MissingStubMessage<'static>) {
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str, message: MissingStubMessage<'static>) {
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`no_recursion`"));
+        assert!(messages.help().contains("no_recursion_disabled_stub"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,43 @@
+//! Lint crate checking that Whitaker-style lint crates build without their
+//! driver feature.
+//!
+//! Lint crates gate their driver code behind the `dylint-driver` feature
+//! and declare a `#[cfg(not(feature = "dylint-driver"))]` stub module so the
+//! crate still has content when the feature is off. A crate that forgets the
+//! stub only fails when someone builds it with default features. The lint
+//! reports crate roots that gate items on the feature, named by `feature`,
+//! without declaring such a module. The lint is experimental and ships
+//! behind the `experimental-lint-crate-missing-stub-for-non-driver-builds`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{CrateRootProjection, LintCrateMissingStubForNonDriverBuildsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn lint_crate_missing_stub_for_non_driver_builds_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,274 @@
+//! Rules recognizing lint crates and their stub modules.
+//!
+//! The driver hands over the source text of the crate root, and
+//! [`FeatureGating::scan`] sorts its top-level `cfg` attributes by how they
+//! use the driver feature. A crate gating items on the feature is a lint
+//! crate, and it needs a module that builds without it: one gated on the
+//! feature's absence, or one with no `cfg` attribute at all. Source is
+//! tokenised with `rustc_lexer` because the compiler drops the stub from the
+//! crate being checked, which enables the feature.
+//! The rules are kept free of compiler types so they can be tested directly.
+
+use std::ops::Range;
+
+use rustc_lexer::{TokenKind, tokenize};
+
+/// Keywords that may appear in a visibility between attributes and `mod`.
+const VISIBILITY_WORDS: [&str; 5] = ["pub", "crate", "super", "self", "in"];
+
+/// How a crate root's top-level items depend on the driver feature.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct FeatureGating {
+    /// Byte range of the first attribute gating an item on the feature.
+    pub(crate) first_driver_gate: Option<Range<usize>>,
+    /// A module builds without the feature: it is gated on the feature being
+    /// disabled, or carries no `cfg` attribute at all.
+    pub(crate) has_stub: bool,
+}
+
+impl FeatureGating {
+    /// Scans `source`, the text of a crate root, for top-level items gated
+    /// on `feature`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let source = "#[cfg(feature = \"dylint-driver\")]\nmod driver;\n";
+    /// let gating = FeatureGating::scan(source, "dylint-driver");
+    /// assert_eq!(gating.first_driver_gate, Some(0..33));
+    /// assert!(!gating.has_stub);
+    /// ```
+    #[must_use]
+    pub(crate) fn scan(source: &str, feature: &str) -> Self {
+        let tokens = significant_tokens(source);
+        let mut gating = Self::default();
+        let mut depth = 0_usize;
+        let mut disabled = false;
+        let mut configured = false;
+        let mut index = 0;
+        while let Some(token) = tokens.get(index) {
+            match token.kind {
+                TokenKind::OpenBrace => depth += 1,
+                TokenKind::CloseBrace => depth = depth.saturating_sub(1),
+                _ if depth > 0 => {}
+                TokenKind::Pound if is_open_bracket(tokens.get(index + 1)) => {
+                    let end = closing_bracket(&tokens, index + 1);
+                    let span = token.start..tokens[end].start + 1;
+                    let attribute = &tokens[index + 2..end];
+                    configured |= is_cfg(attribute);
+                    disabled |= gating.record(attribute, span, feature);
+                    index = end + 1;
+                    continue;
+                }
+                TokenKind::Ident if token.text == "mod" => {
+                    gating.has_stub |= disabled || !configured;
+                }
+                TokenKind::Ident if VISIBILITY_WORDS.contains(&token.text) => {
+                    index += 1;
+                    continue;
+                }
+                TokenKind::OpenParen | TokenKind::CloseParen | TokenKind::Colon => {
+                    index += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            disabled = false;
+            configured = false;
+            index += 1;
+        }
+        gating
+    }
+
+    /// Records the attribute at `span`, returning `true` when it gates the
+    /// next item on the feature being disabled.
+    fn record(&mut self, attribute: &[Token<'_>], span: Range<usize>, feature: &str) -> bool {
+        match feature_use(attribute, feature) {
+            Some(FeatureUse::Enabled) => {
+                self.first_driver_gate.get_or_insert(span);
+                false
+            }
+            Some(FeatureUse::Disabled) => true,
+            None => false,
+        }
+    }
+
+    /// Returns `true` when the crate gates items on the feature without a
+    /// stub module for builds that disable it.
+    #[must_use]
+    pub(crate) const fn lacks_stub(&self) -> bool {
+        self.first_driver_gate.is_some() && !self.has_stub
+    }
+}
+
+/// How a `cfg` attribute uses the feature.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FeatureUse {
+    /// The item is compiled when the feature is enabled.
+    Enabled,
+    /// The item is compiled when the feature is disabled.
+    Disabled,
+}
+
+/// A token other than whitespace or a comment, with its offset.
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    start: usize,
+}
+
+fn is_cfg(attribute: &[Token<'_>]) -> bool {
+    attribute.first().is_some_and(|token| token.text == "cfg")
+}
+
+/// Classifies the contents of an attribute by the first `feature = "..."`
+/// predicate naming `feature` in a `cfg`, counting the `not(...)` groups
+/// around it.
+fn feature_use(attribute: &[Token<'_>], feature: &str) -> Option<FeatureUse> {
+    if !is_cfg(attribute) {
+        return None;
+    }
+    let mut groups: Vec<bool> = Vec::new();
+    for (index, token) in attribute.iter().enumerate() {
+        match token.kind {
+            TokenKind::OpenParen => {
+                let negating = index
+                    .checked_sub(1)
+                    .is_some_and(|previous| attribute[previous].text == "not");
+                groups.push(negating);
+            }
+            TokenKind::CloseParen => {
+                groups.pop();
+            }
+            TokenKind::Ident
+                if token.text == "feature" && names_feature(attribute, index, feature) =>
+            {
+                let negations = groups.iter().filter(|&&negating| negating).count();
+                return Some(if negations % 2 == 0 {
+                    FeatureUse::Enabled
+                } else {
+                    FeatureUse::Disabled
+                });
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns `true` when `feature = "<feature>"` starts at `index`.
+fn names_feature(attribute: &[Token<'_>], index: usize, feature: &str) -> bool {
+    matches!(
+        attribute.get(index + 1..index + 3),
+        Some([equals, value])
+            if equals.kind == TokenKind::Eq && value.text.trim_matches('"') == feature
+    )
+}
+
+/// Tokens other than whitespace and comments, with their text and offset.
+fn significant_tokens(source: &str) -> Vec<Token<'_>> {
+    let mut offset = 0;
+    let mut tokens = Vec::new();
+    for token in tokenize(source) {
+        let end = offset + token.len;
+        if !matches!(
+            token.kind,
+            TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment { .. }
+        ) {
+            tokens.push(Token {
+                kind: token.kind,
+                text: &source[offset..end],
+                start: offset,
+            });
+        }
+        offset = end;
+    }
+    tokens
+}
+
+fn is_open_bracket(token: Option<&Token<'_>>) -> bool {
+    token.is_some_and(|token| token.kind == TokenKind::OpenBracket)
+}
+
+/// Returns the index of the bracket closing the one at `open`, or the last
+/// index when the attribute is unterminated.
+fn closing_bracket(tokens: &[Token<'_>], open: usize) -> usize {
+    let mut depth = 0_usize;
+    for (index, token) in tokens.iter().enumerate().skip(open) {
+        match token.kind {
+            TokenKind::OpenBracket => depth += 1,
+            TokenKind::CloseBracket => {
+                depth -= 1;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len().saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const FEATURE: &str = "dylint-driver";
+
+    #[rstest]
+    #[case::stubbed(
+        "#[cfg(feature = \"dylint-driver\")]\nmod driver;\n\n#[cfg(not(feature = \"dylint-driver\"))]\nmod stub {\n    pub fn stub() {}\n}\n",
+        true
+    )]
+    #[case::stub_with_visibility(
+        "#[cfg(feature = \"dylint-driver\")]\npub use driver::*;\n#[cfg(not(feature = \"dylint-driver\"))]\n/// Stub.\npub(crate) mod stub {}\n",
+        true
+    )]
+    #[case::missing("#[cfg(feature = \"dylint-driver\")]\nmod driver;\n", false)]
+    #[case::stub_function(
+        "#[cfg(feature = \"dylint-driver\")]\nmod driver;\n#[cfg(not(feature = \"dylint-driver\"))]\npub fn stub() {}\n",
+        false
+    )]
+    #[case::nested_stub(
+        "#[cfg(feature = \"dylint-driver\")]\nmod driver {\n    #[cfg(not(feature = \"dylint-driver\"))]\n    mod stub {}\n}\n",
+        false
+    )]
+    #[case::ungated_module("mod lints;\n#[cfg(feature = \"dylint-driver\")]\nmod driver;\n", true)]
+    #[case::test_module(
+        "#[cfg(feature = \"dylint-driver\")]\nmod driver;\n#[cfg(test)]\nmod tests;\n",
+        false
+    )]
+    fn stubs_are_found_at_the_top_level(#[case] source: &str, #[case] has_stub: bool) {
+        let gating = FeatureGating::scan(source, FEATURE);
+
+        assert!(gating.first_driver_gate.is_some());
+        assert_eq!(gating.has_stub, has_stub);
+        assert_eq!(gating.lacks_stub(), !has_stub);
+    }
+
+    #[rstest]
+    #[case::plain("#[cfg(feature = \"dylint-driver\")]", true)]
+    #[case::combined("#[cfg(all(test, feature = \"dylint-driver\"))]", true)]
+    #[case::double_negation("#[cfg(not(not(feature = \"dylint-driver\")))]", true)]
+    #[case::negated_sibling("#[cfg(all(not(test), feature = \"dylint-driver\"))]", true)]
+    #[case::other_feature("#[cfg(feature = \"serde\")]", false)]
+    #[case::cfg_attr("#[cfg_attr(feature = \"dylint-driver\", allow(dead_code))]", false)]
+    fn driver_gates_are_recognised(#[case] attribute: &str, #[case] gated: bool) {
+        let source = format!("{attribute}\nmod driver;\n");
+
+        assert_eq!(
+            FeatureGating::scan(&source, FEATURE).first_driver_gate,
+            gated.then_some(0..attribute.len())
+        );
+    }
+
+    #[rstest]
+    #[case::no_feature("pub fn run() {}\n")]
+    #[case::comment("// #[cfg(feature = \"dylint-driver\")]\nmod driver;\n")]
+    #[case::crate_attribute("#![cfg_attr(feature = \"dylint-driver\", feature(rustc_private))]\n")]
+    fn other_crates_are_not_lint_crates(#[case] source: &str) {
+        assert!(!FeatureGating::scan(source, FEATURE).lacks_stub());
+    }
+}
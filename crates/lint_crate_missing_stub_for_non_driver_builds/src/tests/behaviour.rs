@@ -0,0 +1,77 @@
+//! Behaviour-driven coverage for recognizing missing stub modules.
+
+use crate::policy::FeatureGating;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+const FEATURE: &str = "dylint-driver";
+
+#[derive(Default)]
+struct StubWorld {
+    source: RefCell<String>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> StubWorld {
+    StubWorld::default()
+}
+
+#[given("a crate root gating {item} on the driver feature")]
+fn given_gated_item(world: &StubWorld, item: String) {
+    let item = item.trim_matches('"');
+    *world.source.borrow_mut() = format!("#[cfg(feature = \"{FEATURE}\")]\n{item}\n");
+}
+
+#[given("a crate root declaring {item}")]
+fn given_item(world: &StubWorld, item: String) {
+    *world.source.borrow_mut() = format!("{}\n", item.trim_matches('"'));
+}
+
+#[given("a stub module for builds without the driver feature")]
+fn given_stub(world: &StubWorld) {
+    world.source.borrow_mut().push_str(&format!(
+        "\n#[cfg(not(feature = \"{FEATURE}\"))]\nmod stub {{\n    pub fn stub() {{}}\n}}\n"
+    ));
+}
+
+#[when("the crate root is checked")]
+fn when_checked(world: &StubWorld) {
+    let gating = FeatureGating::scan(&world.source.borrow(), FEATURE);
+    world.reported.set(Some(gating.lacks_stub()));
+}
+
+#[then("the crate is reported")]
+fn then_reported(world: &StubWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &StubWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/lint_crate_missing_stub_for_non_driver_builds.feature",
+    index = 0
+)]
+fn scenario_missing_stub(world: StubWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/lint_crate_missing_stub_for_non_driver_builds.feature",
+    index = 1
+)]
+fn scenario_stubbed(world: StubWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/lint_crate_missing_stub_for_non_driver_builds.feature",
+    index = 2
+)]
+fn scenario_not_a_lint_crate(world: StubWorld) {
+    let _ = world;
+}
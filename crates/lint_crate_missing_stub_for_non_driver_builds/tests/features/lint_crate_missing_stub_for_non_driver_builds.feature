@@ -0,0 +1,19 @@
+Feature: Stub modules for non-driver builds
+  Lint crates gate their code on the driver feature and must declare a stub
+  module for builds with the feature disabled.
+
+  Scenario: A lint crate without a stub is reported
+    Given a crate root gating "mod driver;" on the driver feature
+    When the crate root is checked
+    Then the crate is reported
+
+  Scenario: A lint crate with a stub is accepted
+    Given a crate root gating "mod driver;" on the driver feature
+    And a stub module for builds without the driver feature
+    When the crate root is checked
+    Then nothing is reported
+
+  Scenario: A crate without the driver feature is not a lint crate
+    Given a crate root declaring "mod driver;"
+    When the crate root is checked
+    Then nothing is reported
@@ -0,0 +1,9 @@
+//! Negative UI fixture: a crate gating items on a configured feature.
+
+#[cfg(feature = "lint-driver")]
+mod driver {}
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+//! Negative UI fixture: a lint crate root without a non-driver stub.
+
+#[cfg(feature = "dylint-driver")]
+mod driver {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! Positive UI fixture: a lint crate root with a non-driver stub.
+
+#[cfg(feature = "dylint-driver")]
+mod driver {}
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    pub fn pass_stubbed_crate_disabled_stub() {}
+}
+
+fn main() {
+    stub::pass_stubbed_crate_disabled_stub();
+}
@@ -0,0 +1,115 @@
+//! Pure decision logic for `method_chain_max_length`.
+//!
+//! The driver walks the receivers of the outermost call of each chain
+//! written outside macro expansions and resolves each receiver's type.
+//! [`MethodChainMaxLengthDecision`] counts the same calls and reports chains
+//! over the limit unless a receiver is one of the configured builder types.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_CALLS, LINT_NAME, exceeds_limit, is_builder_type};
+
+/// Reason key for chains longer than the configured limit.
+const EXCEEDS_LIMIT: &str = "exceeds_limit";
+
+/// The facts about one method chain that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MethodChainProjection {
+    /// The path of each call's receiver type, from the outermost call
+    /// inwards, so its length is the number of chained calls.
+    pub receiver_types: Vec<String>,
+}
+
+/// Reports method chains of more than `max_calls` calls.
+///
+/// # Examples
+///
+/// ```ignore
+/// let chain = MethodChainProjection {
+///     receiver_types: vec!["std::vec::Vec".to_owned(); 7],
+/// };
+/// let decision = MethodChainMaxLengthDecision::default();
+/// assert!(decision.decide(&chain).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodChainMaxLengthDecision {
+    max_calls: usize,
+    builder_types: Vec<String>,
+}
+
+impl MethodChainMaxLengthDecision {
+    /// Creates a decision allowing `max_calls` calls, raised to at least
+    /// one, and exempting chains on the `builder_types`.
+    #[must_use]
+    pub fn new(max_calls: usize, builder_types: Vec<String>) -> Self {
+        Self {
+            max_calls: max_calls.max(1),
+            builder_types,
+        }
+    }
+}
+
+impl Default for MethodChainMaxLengthDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CALLS, Vec::new())
+    }
+}
+
+impl LintDecision for MethodChainMaxLengthDecision {
+    type Projection = MethodChainProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, chain: &MethodChainProjection) -> Option<Finding> {
+        let on_builder = chain
+            .receiver_types
+            .iter()
+            .any(|path| is_builder_type(path, &self.builder_types));
+        (exceeds_limit(chain.receiver_types.len(), self.max_calls) && !on_builder)
+            .then(|| Finding::new(LINT_NAME, EXCEEDS_LIMIT))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<MethodChainProjection>> {
+        let chain = |calls: usize, receiver: &str| MethodChainProjection {
+            receiver_types: vec![receiver.to_owned(); calls],
+        };
+        let mut fixtures = vec![
+            DecisionFixture::clean(
+                "exactly at the limit",
+                chain(self.max_calls, "std::vec::Vec"),
+            ),
+            DecisionFixture::flagged(
+                "one call over the limit",
+                chain(self.max_calls + 1, "std::vec::Vec"),
+                EXCEEDS_LIMIT,
+            ),
+        ];
+        if let Some(builder) = self.builder_types.first() {
+            fixtures.push(DecisionFixture::clean(
+                "builder chain over the limit",
+                chain(self.max_calls + 1, builder),
+            ));
+        }
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_limit(MethodChainMaxLengthDecision::default(), 2)]
+    #[case::zero_limit(MethodChainMaxLengthDecision::new(0, Vec::new()), 2)]
+    #[case::builders(
+        MethodChainMaxLengthDecision::new(3, vec!["std::process::Command".to_owned()]),
+        3
+    )]
+    fn decision_conforms(#[case] decision: MethodChainMaxLengthDecision, #[case] count: usize) {
+        assert_eq!(check_conformance(&decision), Ok(count));
+    }
+}
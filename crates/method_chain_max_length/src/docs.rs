@@ -0,0 +1,31 @@
+//! Embedded documentation for the `method_chain_max_length` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `method_chain_max_length`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "method_chain_max_length",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags method chains with more calls than the configured limit.",
+    rationale: "Long chains on a single receiver often hide a missing intermediate abstraction; named bindings make each step easier to read, debug, and test.",
+    bad_example: "let total: usize = values.into_iter().map(f).filter(g).map(h).rev().skip(1).take(5).sum();",
+    good_example: concat!(
+        "let adjusted = values.into_iter().map(f).filter(g).map(h);\n",
+        "let total: usize = adjusted.rev().skip(1).take(5).sum();",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "max_calls",
+            default: "6",
+            description: "Maximum number of calls in a chain.",
+        },
+        ConfigKeyDocs {
+            key: "builder_types",
+            default: "[]",
+            description: "Types whose chains are exempt, by full path or bare name.",
+        },
+    ],
+};
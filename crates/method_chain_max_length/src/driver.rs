@@ -0,0 +1,299 @@
+//! Detect method chains that exceed the configured number of calls.
+//!
+//! The lint inspects the outermost call of each chain, walking receivers for
+//! as long as they are themselves method calls. Chains produced by macro
+//! expansion are ignored, as are chains in which any receiver is one of the
+//! configured `builder_types`, so fluent builders such as
+//! `std::process::Command` can opt out of the limit.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::{ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+pub(crate) const LINT_NAME: &str = "method_chain_max_length";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_CALLS: usize = 6;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    #[serde(default = "Config::default_max_calls")]
+    max_calls: usize,
+    builder_types: Vec<String>,
+}
+
+impl Config {
+    const fn default_max_calls() -> usize {
+        DEFAULT_MAX_CALLS
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_calls: Self::default_max_calls(),
+            builder_types: Vec::new(),
+        }
+    }
+}
+
+/// Lint pass enforcing method chain length limits.
+#[derive(Default)]
+pub struct MethodChainMaxLength {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Chain limit, builder exemptions, and localization for the crate being checked.
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl Default for CrateState {
+    fn default() -> Self {
+        Self {
+            config: Config::default(),
+            findings: FindingLimit::default(),
+            localizer: Localizer::new(None),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub METHOD_CHAIN_MAX_LENGTH,
+    Warn,
+    "long method chains on a single receiver should be broken up",
+    MethodChainMaxLength::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for MethodChainMaxLength {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(METHOD_CHAIN_MAX_LENGTH, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if !matches!(expr.kind, ExprKind::MethodCall(..))
+            || expr.span.from_expansion()
+            || continues_chain(cx, expr)
+        {
+            return;
+        }
+
+        let receivers = chain_receivers(expr);
+        let calls = receivers.len();
+        let config = self
+            .state
+            .findings
+            .config_at(cx, expr.span, &self.state.config);
+        let max_calls = config.max_calls.max(1);
+        if !exceeds_limit(calls, max_calls) {
+            return;
+        }
+        if let Some(builder) = receivers
+            .iter()
+            .find_map(|receiver| builder_type_of(cx, receiver, &config.builder_types))
+        {
+            debug!(target: LINT_NAME, "exempting chain of {calls} calls on builder `{builder}`");
+            return;
+        }
+
+        let chain = Chain {
+            span: expr.span,
+            calls,
+            limit: max_calls,
+        };
+        if self.state.findings.admit(cx, expr.hir_id, chain.span) {
+            emit_diagnostic(cx, &chain, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the path of `receiver`'s type when it is one of `builder_types`.
+fn builder_type_of(
+    cx: &LateContext<'_>,
+    receiver: &hir::Expr<'_>,
+    builder_types: &[String],
+) -> Option<String> {
+    if builder_types.is_empty() {
+        return None;
+    }
+    let ty = cx.typeck_results().expr_ty(receiver).peel_refs();
+    let path = cx.tcx.def_path_str(ty.ty_adt_def()?.did());
+    is_builder_type(&path, builder_types).then_some(path)
+}
+
+/// Returns `true` when `expr` is the receiver of an enclosing method call, so
+/// that only the outermost call of a chain is reported.
+fn continues_chain(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    match cx.tcx.parent_hir_node(expr.hir_id) {
+        Node::Expr(parent) => matches!(
+            parent.kind,
+            ExprKind::MethodCall(_, receiver, ..) if receiver.hir_id == expr.hir_id
+        ),
+        _ => false,
+    }
+}
+
+/// Collects the receiver of every call in the chain ending at `expr`, from the
+/// outermost call inwards. The length equals the number of chained calls.
+fn chain_receivers<'tcx>(expr: &'tcx hir::Expr<'tcx>) -> Vec<&'tcx hir::Expr<'tcx>> {
+    let mut receivers = Vec::new();
+    let mut current = expr;
+    while let ExprKind::MethodCall(_, receiver, ..) = current.kind {
+        receivers.push(receiver);
+        current = receiver;
+    }
+    receivers
+}
+
+pub(crate) const fn exceeds_limit(calls: usize, limit: usize) -> bool {
+    calls > limit
+}
+
+/// Matches a type path against the configured builder list.
+///
+/// Entries containing `::` must match the full path; bare names match the
+/// final path segment, so `Command` exempts `std::process::Command`.
+pub(crate) fn is_builder_type(path: &str, builder_types: &[String]) -> bool {
+    let name = path.rsplit("::").next().unwrap_or(path);
+    builder_types.iter().any(|builder| {
+        if builder.contains("::") {
+            builder == path
+        } else {
+            builder == name
+        }
+    })
+}
+
+/// A method chain that exceeds the configured limit.
+#[derive(Clone, Copy, Debug)]
+struct Chain {
+    span: Span,
+    calls: usize,
+    limit: usize,
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, chain: &Chain, localizer: &Localizer) {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("calls"),
+        FluentValue::from(chain.calls as i64),
+    );
+    args.insert(
+        Cow::Borrowed("limit"),
+        FluentValue::from(chain.limit as i64),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(chain.calls, chain.limit)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        METHOD_CHAIN_MAX_LENGTH,
+        chain.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(calls: usize, limit: usize) -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        format!("Method chain of {calls} calls exceeds the limit of {limit}."),
+        String::from(
+            "Long chains on a single receiver often hide a missing intermediate abstraction.",
+        ),
+        String::from(
+            "Bind intermediate results to named variables or extract a helper; list builder \
+             types under `builder_types` to exempt them.",
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::full_path("std::process::Command", true)]
+    #[case::bare_name("Command", true)]
+    #[case::other_module("tokio::process::Command", false)]
+    #[case::unrelated("Vec", false)]
+    fn builder_types_match_paths_and_names(#[case] entry: &str, #[case] expected: bool) {
+        let builder_types = vec![String::from(entry)];
+
+        assert_eq!(
+            is_builder_type("std::process::Command", &builder_types),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn default_config_allows_six_calls() {
+        let config = Config::default();
+
+        assert_eq!(config.max_calls, 6);
+        assert!(config.builder_types.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_messages_mention_counts() {
+        let messages = fallback_messages(8, 6);
+
+        assert!(messages.primary().contains('8'));
+        assert!(messages.primary().contains('6'));
+        assert!(messages.help().contains("builder_types"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
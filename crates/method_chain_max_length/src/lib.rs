@@ -0,0 +1,35 @@
+//! Lint crate limiting the number of calls in a single method chain.
+//!
+//! Long chains on one receiver often indicate a missing intermediate
+//! abstraction: a named binding or helper would make the steps easier to
+//! read and test. Builder types, whose fluent APIs are designed for long
+//! chains, can be exempted through configuration.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{MethodChainMaxLengthDecision, MethodChainProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn method_chain_max_length_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
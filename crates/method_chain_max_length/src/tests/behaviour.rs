@@ -0,0 +1,82 @@
+//! Behaviour-driven coverage for method chain length limits.
+
+use super::{Config, exceeds_limit, fallback_messages, is_builder_type};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::i18n::DiagnosticMessageSet;
+
+struct ChainWorld {
+    calls: Cell<usize>,
+    receiver: RefCell<String>,
+    config: RefCell<Config>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> ChainWorld {
+    ChainWorld {
+        calls: Cell::new(0),
+        receiver: RefCell::new(String::new()),
+        config: RefCell::new(Config::default()),
+        messages: RefCell::new(None),
+    }
+}
+
+#[given("a chain of {calls} calls on {receiver}")]
+fn given_chain(world: &ChainWorld, calls: usize, receiver: String) {
+    world.calls.set(calls);
+    *world.receiver.borrow_mut() = receiver;
+}
+
+#[given("the limit is {limit}")]
+fn given_limit(world: &ChainWorld, limit: usize) {
+    world.config.borrow_mut().max_calls = limit;
+}
+
+#[given("builder types include {builder}")]
+fn given_builder(world: &ChainWorld, builder: String) {
+    world.config.borrow_mut().builder_types.push(builder);
+}
+
+#[when("the chain is checked")]
+fn when_checked(world: &ChainWorld) {
+    let config = world.config.borrow();
+    let calls = world.calls.get();
+    let exempt = is_builder_type(&world.receiver.borrow(), &config.builder_types);
+    let messages = (exceeds_limit(calls, config.max_calls) && !exempt)
+        .then(|| fallback_messages(calls, config.max_calls));
+    *world.messages.borrow_mut() = messages;
+}
+
+#[then("the chain is accepted")]
+fn then_accepted(world: &ChainWorld) {
+    assert!(world.messages.borrow().is_none());
+}
+
+#[then("the chain is reported with {calls} calls")]
+fn then_reported(world: &ChainWorld, calls: usize) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert!(messages.primary().contains(&format!("{calls} calls")));
+}
+
+#[scenario(path = "tests/features/method_chain_max_length.feature", index = 0)]
+fn scenario_within_limit(world: ChainWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/method_chain_max_length.feature", index = 1)]
+fn scenario_beyond_limit(world: ChainWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/method_chain_max_length.feature", index = 2)]
+fn scenario_configured_limit(world: ChainWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/method_chain_max_length.feature", index = 3)]
+fn scenario_builder_exempt(world: ChainWorld) {
+    let _ = world;
+}
@@ -0,0 +1,25 @@
+Feature: Method chain length limit
+  Method chains longer than the configured number of calls are reported
+  unless a receiver in the chain is a configured builder type.
+
+  Scenario: A chain within the default limit is accepted
+    Given a chain of 6 calls on alloc::vec::Vec
+    When the chain is checked
+    Then the chain is accepted
+
+  Scenario: A chain beyond the default limit is reported
+    Given a chain of 7 calls on alloc::vec::Vec
+    When the chain is checked
+    Then the chain is reported with 7 calls
+
+  Scenario: A configured limit lowers the threshold
+    Given a chain of 4 calls on alloc::vec::Vec
+    And the limit is 3
+    When the chain is checked
+    Then the chain is reported with 4 calls
+
+  Scenario: A builder chain is exempt
+    Given a chain of 9 calls on std::process::Command
+    And builder types include Command
+    When the chain is checked
+    Then the chain is accepted
@@ -0,0 +1,4 @@
+fn main() {
+    let name = String::from(" Whitaker ").trim().to_lowercase().len();
+    println!("{name}");
+}
@@ -0,0 +1,14 @@
+#![warn(method_chain_max_length)]
+
+fn main() {
+    let total: usize = vec![1_usize, 2, 3]
+        .into_iter()
+        .map(|value| value + 1)
+        .filter(|value| value % 2 == 0)
+        .map(|value| value * 3)
+        .rev()
+        .skip(1)
+        .take(5)
+        .sum();
+    println!("{total}");
+}
@@ -0,0 +1,15 @@
+#![warn(method_chain_max_length)]
+
+use std::process::Command;
+
+fn main() {
+    let mut command = Command::new("cargo");
+    command
+        .arg("build")
+        .arg("--workspace")
+        .arg("--all-targets")
+        .env("CARGO_TERM_COLOR", "never")
+        .env_remove("RUSTFLAGS")
+        .current_dir(".")
+        .arg("--locked");
+}
@@ -0,0 +1,9 @@
+#![warn(method_chain_max_length)]
+
+// `legacy/whitaker.toml` raises the limit for the long chain.
+#[path = "pass_directory_override/legacy/totals.rs"]
+mod legacy;
+
+fn main() {
+    println!("{}", legacy::total());
+}
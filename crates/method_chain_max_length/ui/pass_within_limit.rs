@@ -0,0 +1,10 @@
+#![warn(method_chain_max_length)]
+
+fn main() {
+    let doubled: Vec<usize> = vec![1_usize, 2, 3]
+        .into_iter()
+        .map(|value| value * 2)
+        .filter(|value| *value > 2)
+        .collect();
+    println!("{doubled:?}");
+}
@@ -0,0 +1,141 @@
+//! Finds configured macro calls in the body of a desugared `for` loop.
+//!
+//! A `for` loop lowers to a `loop` whose single statement matches on
+//! `Iterator::next`; the `Some` arm holds the body. The visitor walks that
+//! body without entering closures, notes any loop written inside it, and
+//! records each hand-written invocation of a configured macro along with
+//! whether an `if` or `match` written in the body guards it. Branches that
+//! macros and desugarings expand into, such as the level check inside
+//! `log::info!`, do not count as guards.
+
+use std::collections::HashSet;
+
+use rustc_hir as hir;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_lint::LateContext;
+use rustc_span::{ExpnKind, MacroKind, Span, Symbol};
+
+use crate::policy::{LoopCall, MacroPaths};
+
+/// Returns the body of the `for` loop `expr` was lowered from, if any.
+pub(crate) fn for_loop_body<'tcx>(expr: &'tcx hir::Expr<'tcx>) -> Option<&'tcx hir::Expr<'tcx>> {
+    let hir::ExprKind::Loop(block, _, hir::LoopSource::ForLoop, _) = expr.kind else {
+        return None;
+    };
+    let [stmt] = block.stmts else {
+        return None;
+    };
+    let (hir::StmtKind::Expr(next) | hir::StmtKind::Semi(next)) = stmt.kind else {
+        return None;
+    };
+    let hir::ExprKind::Match(_, arms, hir::MatchSource::ForLoopDesugar) = next.kind else {
+        return None;
+    };
+    arms.iter().find_map(|arm| match arm.pat.kind {
+        hir::PatKind::TupleStruct(_, [_], _) => Some(arm.body),
+        _ => None,
+    })
+}
+
+/// A configured macro invoked in a loop body.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct MacroCall {
+    /// The span of the invocation as written.
+    pub(crate) span: Span,
+    /// The name the macro was invoked by.
+    pub(crate) name: Symbol,
+    /// Where the invocation sits relative to the loop.
+    pub(crate) placement: LoopCall,
+}
+
+/// Returns the configured macro calls in the loop `body`, in source order.
+pub(crate) fn macro_calls<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx hir::Expr<'tcx>,
+    macros: &MacroPaths,
+) -> Vec<MacroCall> {
+    let mut visitor = CallVisitor {
+        cx,
+        macros,
+        guards: 0,
+        has_inner_loop: false,
+        seen: HashSet::new(),
+        calls: Vec::new(),
+    };
+    visitor.visit_expr(body);
+
+    let CallVisitor {
+        has_inner_loop,
+        mut calls,
+        ..
+    } = visitor;
+    for call in &mut calls {
+        call.placement.loop_has_inner_loop = has_inner_loop;
+    }
+    calls
+}
+
+struct CallVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    macros: &'a MacroPaths,
+    guards: usize,
+    has_inner_loop: bool,
+    seen: HashSet<Span>,
+    calls: Vec<MacroCall>,
+}
+
+impl CallVisitor<'_, '_> {
+    /// Records the hand-written configured macro invocation that produced
+    /// `span`, if any.
+    fn record(&mut self, span: Span) {
+        let Some((call_site, name)) = span.macro_backtrace().find_map(|expn| {
+            let ExpnKind::Macro(MacroKind::Bang, name) = expn.kind else {
+                return None;
+            };
+            let def_id = expn.macro_def_id?;
+            (!is_macro_output(expn.call_site)
+                && self.macros.matches(&self.cx.tcx.def_path_str(def_id)))
+            .then_some((expn.call_site, name))
+        }) else {
+            return;
+        };
+        if self.seen.insert(call_site) {
+            self.calls.push(MacroCall {
+                span: call_site,
+                name,
+                placement: LoopCall {
+                    loop_has_inner_loop: false,
+                    conditional: self.guards > 0,
+                },
+            });
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for CallVisitor<'_, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        let from_macro = is_macro_output(expr.span);
+        if from_macro {
+            self.record(expr.span);
+        }
+        let guard = !from_macro
+            && matches!(
+                expr.kind,
+                hir::ExprKind::If(..) | hir::ExprKind::Match(_, _, hir::MatchSource::Normal)
+            );
+        if matches!(expr.kind, hir::ExprKind::Loop(..)) && !from_macro {
+            self.has_inner_loop = true;
+        }
+
+        self.guards += usize::from(guard);
+        intravisit::walk_expr(self, expr);
+        self.guards -= usize::from(guard);
+    }
+}
+
+/// Reports whether `span` was produced by a macro, directly or through a
+/// desugaring of code a macro emitted.
+pub(crate) fn is_macro_output(span: Span) -> bool {
+    span.macro_backtrace()
+        .any(|expn| matches!(expn.kind, ExpnKind::Macro(..)))
+}
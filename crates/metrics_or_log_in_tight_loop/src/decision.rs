@@ -0,0 +1,111 @@
+//! Pure decision logic for `metrics_or_log_in_tight_loop`.
+//!
+//! The driver names each macro invocation in a `for` loop body by its path
+//! and describes where it sits as a [`LoopCall`].
+//! [`MetricsOrLogInTightLoopDecision`] matches the path against the same
+//! [`MacroPaths`] and reports calls that run on every iteration of an
+//! innermost loop, naming the macro as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{DEFAULT_MACROS, LoopCall, MacroPaths};
+
+/// Reason key for macros called on every iteration.
+const PER_ITERATION: &str = "per_iteration";
+
+/// The facts about one macro call in a loop that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoopMacroCallProjection {
+    /// The macro's path, such as `tracing::info`.
+    pub path: String,
+    /// Whether the enclosing loop contains another loop.
+    pub loop_has_inner_loop: bool,
+    /// Whether the call sits under a condition inside the loop body.
+    pub conditional: bool,
+}
+
+/// Reports logging and metrics macros called on every iteration of an
+/// innermost loop.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = LoopMacroCallProjection {
+///     path: "tracing::info".to_owned(),
+///     ..LoopMacroCallProjection::default()
+/// };
+/// let decision = MetricsOrLogInTightLoopDecision::default();
+/// assert!(decision.decide(&call).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetricsOrLogInTightLoopDecision {
+    macros: MacroPaths,
+}
+
+impl MetricsOrLogInTightLoopDecision {
+    /// Creates a decision reporting the macros named by `macros`, matched in
+    /// full or by trailing path segments.
+    #[must_use]
+    pub fn new(macros: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            macros: MacroPaths::new(macros),
+        }
+    }
+}
+
+impl Default for MetricsOrLogInTightLoopDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MACROS.iter().map(|path| (*path).to_owned()))
+    }
+}
+
+impl LintDecision for MetricsOrLogInTightLoopDecision {
+    type Projection = LoopMacroCallProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, call: &LoopMacroCallProjection) -> Option<Finding> {
+        let placement = LoopCall {
+            loop_has_inner_loop: call.loop_has_inner_loop,
+            conditional: call.conditional,
+        };
+        (self.macros.matches(&call.path) && placement.runs_every_iteration())
+            .then(|| Finding::new(LINT_NAME, PER_ITERATION).with_subject(call.path.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<LoopMacroCallProjection>> {
+        let call = |path: &str, loop_has_inner_loop, conditional| LoopMacroCallProjection {
+            path: path.to_owned(),
+            loop_has_inner_loop,
+            conditional,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "logging in an innermost loop",
+                call("tracing::info", false, false),
+                PER_ITERATION,
+            ),
+            DecisionFixture::clean("conditional logging", call("tracing::info", false, true)),
+            DecisionFixture::clean("loop with an inner loop", call("log::debug", true, false)),
+            DecisionFixture::clean("unrelated macro", call("std::println", false, false)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&MetricsOrLogInTightLoopDecision::default()),
+            Ok(4)
+        );
+    }
+}
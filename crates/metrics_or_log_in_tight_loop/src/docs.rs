@@ -0,0 +1,22 @@
+//! Embedded documentation for the `metrics_or_log_in_tight_loop` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `metrics_or_log_in_tight_loop`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "metrics_or_log_in_tight_loop",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags logging and metrics macros called unconditionally on every iteration of an innermost `for` loop.",
+    rationale: "A log record or metric update per item costs a formatting pass, a subscriber lock or atomic update, and often I/O. Over a large collection that overhead can outweigh the loop's own work, while a count or total emitted once after the loop carries the same information.",
+    bad_example: "for order in &orders {\n    total += order.amount;\n    tracing::debug!(id = order.id, \"summed order\");\n}",
+    good_example: "for order in &orders {\n    total += order.amount;\n}\ntracing::debug!(count = orders.len(), total, \"summed orders\");",
+    config: &[ConfigKeyDocs {
+        key: "macros",
+        default: "[\"log::trace\", \"log::debug\", \"log::info\", \"log::warn\", \"log::error\", \"log::log\", \"tracing::trace\", \"tracing::debug\", \"tracing::info\", \"tracing::warn\", \"tracing::error\", \"tracing::event\", \"metrics::counter\", \"metrics::gauge\", \"metrics::histogram\"]",
+        description: "Paths, or trailing path segments, of the logging and metrics macros to report.",
+    }],
+};
@@ -0,0 +1,241 @@
+//! Lint pass reporting logging and metrics macros in innermost loops.
+//!
+//! Each `for` loop is recovered from its desugaring by [`for_loop_body`],
+//! and [`macro_calls`] lists the invocations of the configured `macros` in
+//! its body. A call is reported when the loop holds no other loop and no
+//! `if` or `match` in the body guards the call, so it runs once per item.
+//! Calls in closures are not examined, `while` and `loop` loops are left
+//! alone because they usually drive event processing rather than iterate
+//! over a collection, and loops produced by macro expansion and loops in
+//! test code are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::calls::{for_loop_body, is_macro_output, macro_calls};
+use crate::policy::{DEFAULT_MACROS, MacroPaths};
+
+pub(crate) const LINT_NAME: &str = "metrics_or_log_in_tight_loop";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    macros: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            macros: DEFAULT_MACROS
+                .iter()
+                .map(|path| (*path).to_owned())
+                .collect(),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub METRICS_OR_LOG_IN_TIGHT_LOOP,
+    Warn,
+    "logging and metrics macros called on every iteration of an innermost loop should be aggregated outside it",
+    MetricsOrLogInTightLoop::default()
+}
+
+/// Lint pass that finds per-item logging and metrics calls in loops.
+#[derive(Default)]
+pub struct MetricsOrLogInTightLoop {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configured macros, test detection, finding limit, and localization for
+/// the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    macros: MacroPaths,
+    test_context: TestContext,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for MetricsOrLogInTightLoop {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            macros: MacroPaths::new(load_configuration().macros),
+            test_context: TestContext::for_crate(cx, Vec::new()),
+            findings: FindingLimit::new(METRICS_OR_LOG_IN_TIGHT_LOOP, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let Some(body) = for_loop_body(expr) else {
+            return;
+        };
+        if is_macro_output(expr.span) || self.state.test_context.is_test(cx, expr.hir_id) {
+            return;
+        }
+
+        let state = &*self.state;
+        for call in macro_calls(cx, body, &state.macros) {
+            if !call.placement.runs_every_iteration() {
+                continue;
+            }
+            let name = call.name.to_string();
+            debug!(target: LINT_NAME, "`{name}!` runs on every iteration of a loop");
+            if state.findings.admit(cx, expr.hir_id, call.span) {
+                emit_diagnostic(
+                    cx,
+                    call.span,
+                    &PerIterationMessage { name: &name },
+                    &state.localizer,
+                );
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &PerIterationMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        METRICS_OR_LOG_IN_TIGHT_LOOP,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one per-iteration call interpolates.
+#[derive(Clone, Copy, Debug)]
+struct PerIterationMessage<'a> {
+    name: &'a str,
+}
+
+impl PerIterationMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("name"),
+            FluentValue::from(self.name.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let name = self.name;
+        DiagnosticMessageSet::new(
+            format!("`{name}!` runs on every iteration of this loop."),
+            String::from(
+                "Each call formats a record or updates a metric, which can cost more than the \
+                 work the loop does for one item when the collection is large.",
+            ),
+            String::from(
+                "Aggregate inside the loop, such as a count, a total, or a few sample items, and \
+                 log or record the result once after the loop.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: PerIterationMessage<'static> = PerIterationMessage { name: "info" };
+
+    fn resolve(message: &PerIterationMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_lists_the_default_macros() {
+        assert_eq!(Config::default().macros.len(), DEFAULT_MACROS.len());
+    }
+
+    #[rstest]
+    fn fallback_primary_names_the_macro() {
+        assert_eq!(
+            MESSAGE.fallback_messages().primary(),
+            "`info!` runs on every iteration of this loop."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`info!`"));
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,46 @@
+//! Lint crate flagging logging and metrics macros called on every iteration
+//! of an innermost loop.
+//!
+//! A `for` loop over a large collection that logs or records a metric for
+//! each item spends most of its time formatting records, taking subscriber
+//! locks, and updating atomics rather than doing its work. Aggregating inside
+//! the loop and emitting once afterwards keeps the information at a fraction
+//! of the cost. The lint reports invocations of the configured macros that
+//! run unconditionally in the body of a `for` loop containing no other loop.
+//! The lint is experimental and ships behind the
+//! `experimental-metrics-or-log-in-tight-loop` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod calls;
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{LoopMacroCallProjection, MetricsOrLogInTightLoopDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn metrics_or_log_in_tight_loop_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,123 @@
+//! Rules deciding whether a macro call in a loop runs on every iteration.
+//!
+//! The driver names each macro invocation by the path of the macro, such as
+//! `tracing::info`. An entry in `macros` matches that path in full or as a
+//! trailing run of segments, so `info` matches both `log::info` and
+//! `tracing::info`. A matching call is reported when it sits directly in the
+//! body of an innermost loop, as described by [`LoopCall`].
+
+/// Macros checked when `macros` is not set.
+pub(crate) const DEFAULT_MACROS: &[&str] = &[
+    "log::trace",
+    "log::debug",
+    "log::info",
+    "log::warn",
+    "log::error",
+    "log::log",
+    "tracing::trace",
+    "tracing::debug",
+    "tracing::info",
+    "tracing::warn",
+    "tracing::error",
+    "tracing::event",
+    "metrics::counter",
+    "metrics::gauge",
+    "metrics::histogram",
+];
+
+/// The configured logging and metrics macros.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct MacroPaths {
+    paths: Vec<String>,
+}
+
+impl MacroPaths {
+    /// Builds the list from configured paths.
+    pub(crate) fn new(paths: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            paths: paths.into_iter().collect(),
+        }
+    }
+
+    /// Returns `true` when an entry matches the macro at `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let macros = MacroPaths::new([String::from("info")]);
+    /// assert!(macros.matches("tracing::info"));
+    /// ```
+    pub(crate) fn matches(&self, path: &str) -> bool {
+        self.paths.iter().any(|pattern| {
+            path == pattern.as_str()
+                || path
+                    .strip_suffix(pattern.as_str())
+                    .is_some_and(|prefix| prefix.ends_with("::"))
+        })
+    }
+}
+
+/// Where a configured macro call sits relative to its enclosing `for` loop.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct LoopCall {
+    /// Whether the loop body holds another loop, making the call part of an
+    /// outer loop.
+    pub(crate) loop_has_inner_loop: bool,
+    /// Whether an `if` or `match` in the loop body guards the call.
+    pub(crate) conditional: bool,
+}
+
+impl LoopCall {
+    /// Returns `true` when the call runs on every iteration of an innermost
+    /// loop.
+    #[must_use]
+    pub(crate) const fn runs_every_iteration(self) -> bool {
+        !self.loop_has_inner_loop && !self.conditional
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn defaults() -> MacroPaths {
+        MacroPaths::new(DEFAULT_MACROS.iter().map(|path| (*path).to_owned()))
+    }
+
+    #[rstest]
+    #[case::log("log::info", true)]
+    #[case::tracing("tracing::event", true)]
+    #[case::metrics("metrics::counter", true)]
+    #[case::partial_segment("my_log::info", false)]
+    #[case::println("std::println", false)]
+    fn matches_default_macros(#[case] path: &str, #[case] expected: bool) {
+        assert_eq!(defaults().matches(path), expected);
+    }
+
+    #[rstest]
+    fn trailing_segments_match_any_crate() {
+        let macros = MacroPaths::new([String::from("info")]);
+
+        assert!(macros.matches("tracing::info"));
+        assert!(macros.matches("info"));
+        assert!(!macros.matches("tracing::info_span"));
+    }
+
+    #[rstest]
+    #[case::innermost(false, false, true)]
+    #[case::outer_loop(true, false, false)]
+    #[case::guarded(false, true, false)]
+    fn reports_unconditional_calls_in_innermost_loops(
+        #[case] loop_has_inner_loop: bool,
+        #[case] conditional: bool,
+        #[case] expected: bool,
+    ) {
+        let call = LoopCall {
+            loop_has_inner_loop,
+            conditional,
+        };
+
+        assert_eq!(call.runs_every_iteration(), expected);
+    }
+}
@@ -0,0 +1,94 @@
+//! Behaviour-driven coverage for the tight loop logging policy.
+
+use crate::policy::{DEFAULT_MACROS, LoopCall, MacroPaths};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct LoopWorld {
+    path: RefCell<String>,
+    call: Cell<LoopCall>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> LoopWorld {
+    LoopWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+#[given("a call to {path}")]
+fn given_call(world: &LoopWorld, path: String) {
+    *world.path.borrow_mut() = unquote(&path);
+}
+
+#[given("the loop holds another loop")]
+fn given_inner_loop(world: &LoopWorld) {
+    world.call.set(LoopCall {
+        loop_has_inner_loop: true,
+        ..world.call.get()
+    });
+}
+
+#[given("an if guards the call")]
+fn given_guard(world: &LoopWorld) {
+    world.call.set(LoopCall {
+        conditional: true,
+        ..world.call.get()
+    });
+}
+
+#[when("the call is checked")]
+fn when_checked(world: &LoopWorld) {
+    let macros = MacroPaths::new(DEFAULT_MACROS.iter().map(|path| (*path).to_owned()));
+    let configured = macros.matches(&world.path.borrow());
+    world
+        .reported
+        .set(Some(configured && world.call.get().runs_every_iteration()));
+}
+
+#[then("the call is reported")]
+fn then_reported(world: &LoopWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &LoopWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/metrics_or_log_in_tight_loop.feature",
+    index = 0
+)]
+fn scenario_innermost_loop(world: LoopWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/metrics_or_log_in_tight_loop.feature",
+    index = 1
+)]
+fn scenario_outer_loop(world: LoopWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/metrics_or_log_in_tight_loop.feature",
+    index = 2
+)]
+fn scenario_guarded_call(world: LoopWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/metrics_or_log_in_tight_loop.feature",
+    index = 3
+)]
+fn scenario_other_macro(world: LoopWorld) {
+    let _ = world;
+}
@@ -0,0 +1,25 @@
+Feature: Logging and metrics in tight loops
+  A configured macro is reported when it runs unconditionally in the body of
+  a `for` loop that holds no other loop.
+
+  Scenario: A log call in an innermost loop is reported
+    Given a call to "tracing::info"
+    When the call is checked
+    Then the call is reported
+
+  Scenario: A call in an outer loop is accepted
+    Given a call to "tracing::info"
+    And the loop holds another loop
+    When the call is checked
+    Then nothing is reported
+
+  Scenario: A guarded call is accepted
+    Given a call to "log::warn"
+    And an if guards the call
+    When the call is checked
+    Then nothing is reported
+
+  Scenario: Other macros are ignored
+    Given a call to "std::println"
+    When the call is checked
+    Then nothing is reported
@@ -0,0 +1,31 @@
+//! Negative UI fixture: logging and metrics macros called on every
+//! iteration of an innermost loop.
+#![warn(metrics_or_log_in_tight_loop)]
+
+macro_rules! info {
+    ($message:expr) => {
+        $crate::log_line($message)
+    };
+}
+
+macro_rules! counter {
+    ($name:expr) => {
+        $crate::bump($name)
+    };
+}
+
+fn log_line(_message: &str) {}
+
+fn bump(_name: &str) {}
+
+fn process(items: &[u32]) {
+    for item in items {
+        info!("processing item");
+        counter!("items_processed");
+        let _ = item;
+    }
+}
+
+fn main() {
+    process(&[1, 2, 3]);
+}
@@ -0,0 +1,59 @@
+//! Positive UI fixture: logging and metrics macros that run once per loop,
+//! under a guard, or in an outer loop.
+#![warn(metrics_or_log_in_tight_loop)]
+
+macro_rules! info {
+    ($message:expr) => {
+        $crate::log_line($message)
+    };
+}
+
+macro_rules! counter {
+    ($name:expr) => {
+        $crate::bump($name)
+    };
+}
+
+fn log_line(_message: &str) {}
+
+fn bump(_name: &str) {}
+
+fn aggregated(items: &[u32]) {
+    let mut processed = 0;
+    for item in items {
+        processed += item;
+    }
+    let _ = processed;
+    info!("processed items");
+    counter!("items_processed");
+}
+
+fn guarded(items: &[u32]) {
+    for item in items {
+        if *item == 0 {
+            info!("skipping empty item");
+        }
+    }
+}
+
+fn outer(batches: &[Vec<u32>]) {
+    for batch in batches {
+        info!("processing batch");
+        for item in batch {
+            let _ = item;
+        }
+    }
+}
+
+fn unconfigured(items: &[u32]) {
+    for item in items {
+        println!("{item}");
+    }
+}
+
+fn main() {
+    aggregated(&[1, 2, 3]);
+    guarded(&[0, 1]);
+    outer(&[vec![1, 2], vec![3]]);
+    unconfigured(&[1]);
+}
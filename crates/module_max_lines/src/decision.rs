@@ -0,0 +1,133 @@
+//! Pure decision logic for `module_max_lines`.
+//!
+//! The driver measures each module and hands the line count to
+//! [`ModuleMaxLinesDecision`], which reports modules longer than the limit.
+//! Modules produced by macro expansion are never reported because their
+//! source is not the author's to split.
+
+use whitaker::ModuleMaxLinesConfig;
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+
+/// Reason key for modules longer than the configured limit.
+const EXCEEDS_LIMIT: &str = "exceeds_limit";
+
+/// The facts about one module that the decision needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModuleProjection {
+    /// Source lines spanned by the module body.
+    pub lines: usize,
+    /// Whether the module comes from a macro expansion.
+    pub from_macro: bool,
+}
+
+/// Reports modules whose body exceeds `max_lines`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = ModuleMaxLinesDecision::new(400);
+/// let module = ModuleProjection { lines: 401, from_macro: false };
+/// assert!(decision.decide(&module).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModuleMaxLinesDecision {
+    max_lines: usize,
+}
+
+impl ModuleMaxLinesDecision {
+    /// Creates a decision with the given line limit.
+    #[must_use]
+    pub const fn new(max_lines: usize) -> Self {
+        Self { max_lines }
+    }
+
+    /// Returns the line limit.
+    #[must_use]
+    pub const fn max_lines(&self) -> usize {
+        self.max_lines
+    }
+}
+
+impl Default for ModuleMaxLinesDecision {
+    fn default() -> Self {
+        Self::new(ModuleMaxLinesConfig::default().max_lines)
+    }
+}
+
+impl LintDecision for ModuleMaxLinesDecision {
+    type Projection = ModuleProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, module: &ModuleProjection) -> Option<Finding> {
+        let disposition = evaluate_module(module.lines, self.max_lines, module.from_macro);
+        (disposition == ModuleDisposition::ExceedsLimit)
+            .then(|| Finding::new(LINT_NAME, EXCEEDS_LIMIT))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ModuleProjection>> {
+        let module = |lines, from_macro| ModuleProjection { lines, from_macro };
+        vec![
+            DecisionFixture::clean("within the limit", module(self.max_lines / 2, false)),
+            DecisionFixture::clean("exactly at the limit", module(self.max_lines, false)),
+            DecisionFixture::flagged(
+                "one line over the limit",
+                module(self.max_lines + 1, false),
+                EXCEEDS_LIMIT,
+            ),
+            DecisionFixture::clean(
+                "macro-generated module over the limit",
+                module(self.max_lines + 1, true),
+            ),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ModuleDisposition {
+    Ignore,
+    WithinLimit,
+    ExceedsLimit,
+}
+
+pub(crate) fn evaluate_module(lines: usize, limit: usize, from_macro: bool) -> ModuleDisposition {
+    if from_macro {
+        ModuleDisposition::Ignore
+    } else if lines > limit {
+        ModuleDisposition::ExceedsLimit
+    } else {
+        ModuleDisposition::WithinLimit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case(4, 5, false, ModuleDisposition::WithinLimit)]
+    #[case(6, 5, false, ModuleDisposition::ExceedsLimit)]
+    #[case(5, 5, false, ModuleDisposition::WithinLimit)]
+    #[case(10, 1, true, ModuleDisposition::Ignore)]
+    fn evaluate_module_behaviour(
+        #[case] lines: usize,
+        #[case] limit: usize,
+        #[case] from_macro: bool,
+        #[case] expected: ModuleDisposition,
+    ) {
+        assert_eq!(evaluate_module(lines, limit, from_macro), expected);
+    }
+
+    #[rstest]
+    #[case::default_limit(ModuleMaxLinesDecision::default())]
+    #[case::small_limit(ModuleMaxLinesDecision::new(1))]
+    fn decision_conforms(#[case] decision: ModuleMaxLinesDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
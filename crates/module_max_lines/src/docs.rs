@@ -0,0 +1,42 @@
+//! Embedded documentation for the `module_max_lines` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `module_max_lines`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "module_max_lines",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags modules longer than the configured number of lines.",
+    rationale: "Very long modules usually mix responsibilities; splitting them keeps each file reviewable and focused.",
+    bad_example: concat!(
+        "mod handlers {\n",
+        "    // 450 lines of unrelated handlers\n",
+        "}",
+    ),
+    good_example: concat!(
+        "mod handlers {\n",
+        "    mod auth;\n",
+        "    mod billing;\n",
+        "}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "max_lines",
+            default: "400",
+            description: "Maximum number of lines in a module.",
+        },
+        ConfigKeyDocs {
+            key: "count_mode",
+            default: "\"physical\"",
+            description: "Which lines count towards `max_lines`: `physical` counts every line, `non_blank` skips blank lines, and `code_only` also skips comment-only lines.",
+        },
+        ConfigKeyDocs {
+            key: "overrides",
+            default: "{}",
+            description: "Line budgets for named modules, such as `\"crate::generated\" = 2000`; each covers the module and those nested in it, and the deepest match wins.",
+        },
+    ],
+};
@@ -4,27 +4,31 @@
 //! and warns when the count exceeds the configurable `max_lines` threshold.
 //! The lint uses localization data sourced from the shared Whitaker
 //! infrastructure so diagnostics match the suite's tone across locales.
+//! `count_mode` chooses which lines count: every physical line, lines
+//! holding code, or non-blank lines, so documentation-heavy modules need not
+//! be split. A `[module_max_lines]` table in a nested `whitaker.toml`
+//! replaces the settings for modules whose body lives beneath that directory,
+//! and `overrides` sets the budget of named modules and those nested in them.
 use log::debug;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_span::Span;
 use rustc_span::source_map::SourceMap;
 use rustc_span::symbol::Ident;
+use whitaker::config::LineCountMode;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker::testing::decision::LintDecision;
 use whitaker::{ModuleMaxLinesConfig, SharedConfig, module_body_span, module_header_span};
+use whitaker_common::CrateScopedState;
 use whitaker_common::i18n::{
     Arguments, DiagnosticMessageSet, Localizer, MessageKey, MessageResolution,
     get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
 };
 
-const LINT_NAME: &str = "module_max_lines";
-const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("module_max_lines");
+use crate::decision::{ModuleMaxLinesDecision, ModuleProjection};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum ModuleDisposition {
-    Ignore,
-    WithinLimit,
-    ExceedsLimit,
-}
+pub(crate) const LINT_NAME: &str = "module_max_lines";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("module_max_lines");
 
 dylint_linting::impl_late_lint! {
     pub MODULE_MAX_LINES,
@@ -34,25 +38,31 @@ dylint_linting::impl_late_lint! {
 }
 
 /// Lint pass that tracks configuration and localization state while checking modules.
+#[derive(Default)]
 pub struct ModuleMaxLines {
-    max_lines: usize,
-    localizer: Localizer,
+    state: CrateScopedState<CrateState>,
 }
 
-impl Default for ModuleMaxLines {
-    fn default() -> Self {
-        Self {
-            max_lines: ModuleMaxLinesConfig::default().max_lines,
-            localizer: Localizer::new(None),
-        }
-    }
+/// Line limit, count mode, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: ModuleMaxLinesConfig,
+    findings: FindingLimit,
+    localizer: Localizer,
 }
 
 impl<'tcx> LateLintPass<'tcx> for ModuleMaxLines {
     fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
-        self.max_lines = load_configuration();
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(MODULE_MAX_LINES, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
@@ -62,7 +72,9 @@ impl<'tcx> LateLintPass<'tcx> for ModuleMaxLines {
         };
 
         let span = module_body_span(cx, item, module);
-        let Some(lines) = count_lines(cx.sess().source_map(), span) else {
+        let config = config_at(cx, &self.state, span);
+        let decision = ModuleMaxLinesDecision::new(config.max_lines_for(&path_of(cx, item, ident)));
+        let Some(lines) = count_lines(cx.sess().source_map(), span, config.count_mode) else {
             debug!(
                 target: LINT_NAME,
                 "unable to determine line span for module `{}`; skipping",
@@ -72,14 +84,18 @@ impl<'tcx> LateLintPass<'tcx> for ModuleMaxLines {
         };
         debug!(
             target: LINT_NAME,
-            "module `{}` spans {lines} lines (limit {limit}, from_macro: {from_macro})",
+            "module `{}` counts {lines} lines (limit {limit}, mode {mode:?}, from_macro: {from_macro})",
             ident.name,
-            limit = self.max_lines,
+            limit = decision.max_lines(),
+            mode = config.count_mode,
             from_macro = item.span.from_expansion(),
         );
 
-        let disposition = evaluate_module(lines, self.max_lines, item.span.from_expansion());
-        if disposition != ModuleDisposition::ExceedsLimit {
+        let module = ModuleProjection {
+            lines,
+            from_macro: item.span.from_expansion(),
+        };
+        if decision.decide(&module).is_none() {
             return;
         }
 
@@ -87,38 +103,62 @@ impl<'tcx> LateLintPass<'tcx> for ModuleMaxLines {
             ident,
             item_span: item.span,
             lines,
-            limit: self.max_lines,
+            limit: decision.max_lines(),
+            mode: config.count_mode,
         };
-        emit_diagnostic(cx, &info, &self.localizer);
-    }
-}
-
-fn evaluate_module(lines: usize, limit: usize, from_macro: bool) -> ModuleDisposition {
-    if from_macro {
-        ModuleDisposition::Ignore
-    } else if lines > limit {
-        ModuleDisposition::ExceedsLimit
-    } else {
-        ModuleDisposition::WithinLimit
+        if self.state.findings.admit(cx, item.hir_id(), ident.span) {
+            emit_diagnostic(cx, &info, &self.state.localizer);
+        }
     }
 }
 
-fn load_configuration() -> usize {
+fn load_configuration() -> ModuleMaxLinesConfig {
     match dylint_linting::config::<ModuleMaxLinesConfig>(LINT_NAME) {
-        Ok(Some(config)) => config.max_lines,
-        Ok(None) => ModuleMaxLinesConfig::default().max_lines,
+        Ok(Some(config)) => config,
+        Ok(None) => ModuleMaxLinesConfig::default(),
         Err(error) => {
             debug!(
                 target: LINT_NAME,
                 "failed to parse `{}` configuration: {error}; using defaults",
                 LINT_NAME
             );
-            ModuleMaxLinesConfig::default().max_lines
+            ModuleMaxLinesConfig::default()
+        }
+    }
+}
+
+/// Returns the settings for a module whose body is at `span`, with the
+/// directory overrides above the body's file applied.
+fn config_at(cx: &LateContext<'_>, state: &CrateState, span: Span) -> ModuleMaxLinesConfig {
+    state.findings.config_at(cx, span, &state.config)
+}
+
+/// Returns the path of the module `item` declares, relative to the crate
+/// root, for matching against `overrides`.
+fn path_of(cx: &LateContext<'_>, item: &hir::Item<'_>, ident: Ident) -> String {
+    let parent = module_path(cx, item.hir_id());
+    if parent.is_empty() {
+        ident.to_string()
+    } else {
+        format!("{parent}::{ident}")
+    }
+}
+
+/// Counts the lines of `span` that `mode` includes.
+///
+/// Physical lines come from the source map's line table; the other modes
+/// classify the lines of the span's snippet.
+fn count_lines(source_map: &SourceMap, span: Span, mode: LineCountMode) -> Option<usize> {
+    match mode {
+        LineCountMode::Physical => count_physical_lines(source_map, span),
+        LineCountMode::CodeOnly | LineCountMode::NonBlank => {
+            let snippet = source_map.span_to_snippet(span).ok()?;
+            Some(mode.count(&snippet))
         }
     }
 }
 
-fn count_lines(source_map: &SourceMap, span: Span) -> Option<usize> {
+fn count_physical_lines(source_map: &SourceMap, span: Span) -> Option<usize> {
     let info = source_map.span_to_lines(span).ok()?;
     let first = info.lines.first()?;
     let last = info.lines.last()?;
@@ -144,6 +184,7 @@ struct ModuleDiagnosticInfo {
     item_span: Span,
     lines: usize,
     limit: usize,
+    mode: LineCountMode,
 }
 
 fn emit_diagnostic(cx: &LateContext<'_>, info: &ModuleDiagnosticInfo, localizer: &Localizer) {
@@ -155,6 +196,10 @@ fn emit_diagnostic(cx: &LateContext<'_>, info: &ModuleDiagnosticInfo, localizer:
     args.insert(Cow::Borrowed("module"), FluentValue::from(module_name));
     args.insert(Cow::Borrowed("lines"), FluentValue::from(info.lines as i64));
     args.insert(Cow::Borrowed("limit"), FluentValue::from(info.limit as i64));
+    args.insert(
+        Cow::Borrowed("mode"),
+        FluentValue::from(mode_key(info.mode)),
+    );
 
     let resolution = MessageResolution {
         lint_name: LINT_NAME,
@@ -162,7 +207,7 @@ fn emit_diagnostic(cx: &LateContext<'_>, info: &ModuleDiagnosticInfo, localizer:
         args: &args,
     };
     let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
-        fallback_messages(module_name, info.lines, info.limit)
+        fallback_messages(module_name, info.lines, info.limit, info.mode)
     });
 
     cx.emit_span_lint(
@@ -179,9 +224,28 @@ fn emit_diagnostic(cx: &LateContext<'_>, info: &ModuleDiagnosticInfo, localizer:
     );
 }
 
-fn fallback_messages(module: &str, lines: usize, limit: usize) -> DiagnosticMessageSet {
+/// Returns the Fluent selector naming `mode`.
+const fn mode_key(mode: LineCountMode) -> &'static str {
+    match mode {
+        LineCountMode::Physical => "physical",
+        LineCountMode::CodeOnly => "code_only",
+        LineCountMode::NonBlank => "non_blank",
+    }
+}
+
+fn fallback_messages(
+    module: &str,
+    lines: usize,
+    limit: usize,
+    mode: LineCountMode,
+) -> DiagnosticMessageSet {
+    let measure = match mode {
+        LineCountMode::Physical => format!("spans {lines} lines"),
+        LineCountMode::CodeOnly => format!("has {lines} lines of code"),
+        LineCountMode::NonBlank => format!("has {lines} non-blank lines"),
+    };
     DiagnosticMessageSet::new(
-        format!("Module {module} spans {lines} lines, exceeding the allowed {limit}."),
+        format!("Module {module} {measure}, exceeding the allowed {limit}."),
         String::from("Large modules are harder to navigate and review."),
         format!("Split {module} into smaller modules or reduce its responsibilities."),
     )
@@ -190,126 +254,51 @@ fn fallback_messages(module: &str, lines: usize, limit: usize) -> DiagnosticMess
 #[cfg(test)]
 mod tests {
     use super::*;
+    use fluent_templates::fluent_bundle::FluentValue;
     use rstest::rstest;
+    use std::borrow::Cow;
 
     #[rstest]
-    #[case(4, 5, false, ModuleDisposition::WithinLimit)]
-    #[case(6, 5, false, ModuleDisposition::ExceedsLimit)]
-    #[case(5, 5, false, ModuleDisposition::WithinLimit)]
-    #[case(10, 1, true, ModuleDisposition::Ignore)]
-    fn evaluate_module_behaviour(
-        #[case] lines: usize,
-        #[case] limit: usize,
-        #[case] from_macro: bool,
-        #[case] expected: ModuleDisposition,
-    ) {
-        assert_eq!(evaluate_module(lines, limit, from_macro), expected);
+    #[case::physical(
+        LineCountMode::Physical,
+        "Module handlers spans 420 lines, exceeding the allowed 400."
+    )]
+    #[case::code_only(
+        LineCountMode::CodeOnly,
+        "Module handlers has 420 lines of code, exceeding the allowed 400."
+    )]
+    #[case::non_blank(
+        LineCountMode::NonBlank,
+        "Module handlers has 420 non-blank lines, exceeding the allowed 400."
+    )]
+    fn primary_message_names_the_count_mode(#[case] mode: LineCountMode, #[case] expected: &str) {
+        let fallback = fallback_messages("handlers", 420, 400, mode);
+
+        let mut args: Arguments<'_> = Arguments::default();
+        args.insert(Cow::Borrowed("module"), FluentValue::from("handlers"));
+        args.insert(Cow::Borrowed("lines"), FluentValue::from(420_i64));
+        args.insert(Cow::Borrowed("limit"), FluentValue::from(400_i64));
+        args.insert(Cow::Borrowed("mode"), FluentValue::from(mode_key(mode)));
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        let english = safe_resolve_message_set(
+            &Localizer::new(Some("en-GB")),
+            resolution,
+            |error| panic!("`en-GB` message should resolve: {error}"),
+            || fallback.clone(),
+        );
+
+        assert_eq!(fallback.primary(), expected);
+        assert_eq!(english, fallback);
     }
 }
 
 #[cfg(test)]
-mod behaviour {
-    use super::{ModuleDisposition, evaluate_module};
-    use rstest::fixture;
-    use rstest_bdd_macros::{given, scenario, then, when};
-    use std::cell::RefCell;
-
-    #[derive(Default)]
-    struct ModuleWorld {
-        lines: RefCell<usize>,
-        limit: RefCell<usize>,
-        from_macro: RefCell<bool>,
-        disposition: RefCell<Option<ModuleDisposition>>,
-    }
-
-    impl ModuleWorld {
-        fn set_lines(&self, value: usize) {
-            *self.lines.borrow_mut() = value;
-        }
-
-        fn set_limit(&self, value: usize) {
-            *self.limit.borrow_mut() = value;
-        }
-
-        fn mark_macro(&self) {
-            *self.from_macro.borrow_mut() = true;
-        }
-
-        fn evaluate(&self) {
-            let lines = *self.lines.borrow();
-            let limit = *self.limit.borrow();
-            let from_macro = *self.from_macro.borrow();
-            let result = evaluate_module(lines, limit, from_macro);
-            self.disposition.borrow_mut().replace(result);
-        }
-
-        fn disposition(&self) -> ModuleDisposition {
-            self.disposition
-                .borrow()
-                .expect("module disposition should be recorded")
-        }
-    }
-
-    #[fixture]
-    fn world() -> ModuleWorld {
-        ModuleWorld::default()
-    }
-
-    #[given("the maximum module length is {limit}")]
-    fn given_limit(world: &ModuleWorld, limit: usize) {
-        world.set_limit(limit);
-    }
-
-    #[given("a module spans {lines} lines")]
-    fn given_lines(world: &ModuleWorld, lines: usize) {
-        world.set_lines(lines);
-    }
-
-    #[given("the module originates from a macro expansion")]
-    fn given_macro(world: &ModuleWorld) {
-        world.mark_macro();
-    }
-
-    #[when("I evaluate the module length")]
-    fn when_evaluate(world: &ModuleWorld) {
-        world.evaluate();
-    }
-
-    #[then("the module is accepted")]
-    fn then_accepted(world: &ModuleWorld) {
-        assert_eq!(world.disposition(), ModuleDisposition::WithinLimit);
-    }
-
-    #[then("the module is rejected")]
-    fn then_rejected(world: &ModuleWorld) {
-        assert_eq!(world.disposition(), ModuleDisposition::ExceedsLimit);
-    }
-
-    #[then("the module evaluation is ignored")]
-    fn then_ignored(world: &ModuleWorld) {
-        assert_eq!(world.disposition(), ModuleDisposition::Ignore);
-    }
-
-    #[scenario(path = "tests/features/module_length.feature", index = 0)]
-    fn scenario_within_limit(world: ModuleWorld) {
-        let _ = world;
-    }
-
-    #[scenario(path = "tests/features/module_length.feature", index = 1)]
-    fn scenario_exceeds_limit(world: ModuleWorld) {
-        let _ = world;
-    }
-
-    #[scenario(path = "tests/features/module_length.feature", index = 2)]
-    fn scenario_exact_limit(world: ModuleWorld) {
-        let _ = world;
-    }
-
-    #[scenario(path = "tests/features/module_length.feature", index = 3)]
-    fn scenario_macro(world: ModuleWorld) {
-        let _ = world;
-    }
-}
+#[path = "tests/behaviour.rs"]
+mod behaviour;
 
 #[cfg(test)]
 #[path = "lib_ui_tests.rs"]
@@ -3,9 +3,21 @@
 #[cfg(feature = "dylint-driver")]
 mod driver;
 
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
 
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ModuleMaxLinesDecision, ModuleProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
 #[cfg(not(feature = "dylint-driver"))]
 mod stub {
     #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
@@ -0,0 +1,102 @@
+//! Behaviour-driven coverage for the module length decision.
+
+use crate::decision::{ModuleDisposition, evaluate_module};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct ModuleWorld {
+    lines: RefCell<usize>,
+    limit: RefCell<usize>,
+    from_macro: RefCell<bool>,
+    disposition: RefCell<Option<ModuleDisposition>>,
+}
+
+impl ModuleWorld {
+    fn set_lines(&self, value: usize) {
+        *self.lines.borrow_mut() = value;
+    }
+
+    fn set_limit(&self, value: usize) {
+        *self.limit.borrow_mut() = value;
+    }
+
+    fn mark_macro(&self) {
+        *self.from_macro.borrow_mut() = true;
+    }
+
+    fn evaluate(&self) {
+        let lines = *self.lines.borrow();
+        let limit = *self.limit.borrow();
+        let from_macro = *self.from_macro.borrow();
+        let result = evaluate_module(lines, limit, from_macro);
+        self.disposition.borrow_mut().replace(result);
+    }
+
+    fn disposition(&self) -> ModuleDisposition {
+        self.disposition
+            .borrow()
+            .expect("module disposition should be recorded")
+    }
+}
+
+#[fixture]
+fn world() -> ModuleWorld {
+    ModuleWorld::default()
+}
+
+#[given("the maximum module length is {limit}")]
+fn given_limit(world: &ModuleWorld, limit: usize) {
+    world.set_limit(limit);
+}
+
+#[given("a module spans {lines} lines")]
+fn given_lines(world: &ModuleWorld, lines: usize) {
+    world.set_lines(lines);
+}
+
+#[given("the module originates from a macro expansion")]
+fn given_macro(world: &ModuleWorld) {
+    world.mark_macro();
+}
+
+#[when("I evaluate the module length")]
+fn when_evaluate(world: &ModuleWorld) {
+    world.evaluate();
+}
+
+#[then("the module is accepted")]
+fn then_accepted(world: &ModuleWorld) {
+    assert_eq!(world.disposition(), ModuleDisposition::WithinLimit);
+}
+
+#[then("the module is rejected")]
+fn then_rejected(world: &ModuleWorld) {
+    assert_eq!(world.disposition(), ModuleDisposition::ExceedsLimit);
+}
+
+#[then("the module evaluation is ignored")]
+fn then_ignored(world: &ModuleWorld) {
+    assert_eq!(world.disposition(), ModuleDisposition::Ignore);
+}
+
+#[scenario(path = "tests/features/module_length.feature", index = 0)]
+fn scenario_within_limit(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_length.feature", index = 1)]
+fn scenario_exceeds_limit(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_length.feature", index = 2)]
+fn scenario_exact_limit(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_length.feature", index = 3)]
+fn scenario_macro(world: ModuleWorld) {
+    let _ = world;
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+
+mod generated {
+    pub fn first() {}
+    pub fn second() {}
+    pub fn third() {}
+    pub fn fourth() {}
+
+    mod schema {
+        pub fn first() {}
+        pub fn second() {}
+        pub fn third() {}
+        pub fn fourth() {}
+    }
+}
+
+mod handwritten {
+    pub fn first() {}
+    pub fn second() {}
+    pub fn third() {}
+    pub fn fourth() {}
+}
+
+fn main() {}
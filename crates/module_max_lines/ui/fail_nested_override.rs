@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+
+// `legacy/whitaker.toml` raises the limit, so only `current` is reported.
+#[path = "fail_nested_override/legacy/module.module"]
+mod legacy;
+
+#[path = "fail_nested_override/current/module.module"]
+mod current;
+
+fn main() {}
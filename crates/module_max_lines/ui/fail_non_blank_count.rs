@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+
+mod documented {
+    /// First.
+    pub fn first() {}
+
+    /// Second.
+    pub fn second() {}
+
+    /// Third.
+    pub fn third() {}
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+
+mod documented {
+    //! Handlers whose documentation outweighs their code.
+
+    /// Returns the first value.
+    ///
+    /// Only lines holding code count towards the limit.
+    pub fn first() {}
+
+    /// Returns the second value.
+    ///
+    /// Blank and comment lines are skipped.
+    pub fn second() {}
+
+    // A plain comment counts no more than a doc comment.
+    pub fn third() {}
+}
+
+fn main() {}
@@ -0,0 +1,111 @@
+//! Pure decision logic for `module_must_have_inner_docs`.
+//!
+//! The driver reads the source text of each module body that does not come
+//! from a macro expansion. [`ModuleMustHaveInnerDocsDecision`] hands the
+//! same text to [`classify_leading_content`] and, for misordered modules,
+//! names the attribute that precedes the doc comment as the finding's
+//! subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::SourceSnippet;
+use crate::driver::{LINT_NAME, LeadingContent, classify_leading_content};
+
+/// Reason key for modules without a leading inner doc comment.
+const MISSING_DOCS: &str = "missing_docs";
+
+/// Reason key for modules whose doc comment follows another attribute.
+const MISORDERED: &str = "misordered";
+
+/// The facts about one module body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleBodyProjection {
+    /// The source text of the module body.
+    pub source: String,
+}
+
+impl ModuleBodyProjection {
+    /// Creates a projection for the module body `source`.
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+}
+
+/// Reports modules that do not begin with an inner doc comment.
+///
+/// # Examples
+///
+/// ```ignore
+/// let module = ModuleBodyProjection::new("pub fn value() {}");
+/// assert!(ModuleMustHaveInnerDocsDecision.decide(&module).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ModuleMustHaveInnerDocsDecision;
+
+impl LintDecision for ModuleMustHaveInnerDocsDecision {
+    type Projection = ModuleBodyProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, module: &ModuleBodyProjection) -> Option<Finding> {
+        let source = module.source.as_str();
+        match classify_leading_content(SourceSnippet::from(source)) {
+            LeadingContent::Doc => None,
+            LeadingContent::Missing => Some(Finding::new(LINT_NAME, MISSING_DOCS)),
+            LeadingContent::Misordered { offset, len } => {
+                let attribute = source.get(offset..offset + len).unwrap_or_default();
+                Some(Finding::new(LINT_NAME, MISORDERED).with_subject(attribute))
+            }
+        }
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ModuleBodyProjection>> {
+        vec![
+            DecisionFixture::flagged(
+                "undocumented",
+                ModuleBodyProjection::new("pub fn value() {}"),
+                MISSING_DOCS,
+            ),
+            DecisionFixture::flagged(
+                "attribute before the docs",
+                ModuleBodyProjection::new("#![allow(dead_code)]\n//! Values.\npub fn value() {}"),
+                MISORDERED,
+            ),
+            DecisionFixture::clean(
+                "doc comment first",
+                ModuleBodyProjection::new("\n    //! Values.\n    pub fn value() {}"),
+            ),
+            DecisionFixture::clean(
+                "doc attribute first",
+                ModuleBodyProjection::new("#![doc = \"Values.\"]\npub fn value() {}"),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn misordered_findings_name_the_leading_attribute() {
+        let module = ModuleBodyProjection::new("#![allow(dead_code)]\n//! Values.");
+        let finding = ModuleMustHaveInnerDocsDecision
+            .decide(&module)
+            .expect("the module should be reported");
+
+        assert_eq!(finding.subject(), Some("#![allow(dead_code)]"));
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&ModuleMustHaveInnerDocsDecision), Ok(4));
+    }
+}
@@ -0,0 +1,22 @@
+//! Embedded documentation for the `module_must_have_inner_docs` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, examples, and configuration for `module_must_have_inner_docs`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "module_must_have_inner_docs",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Modules must begin with an inner `//!` doc comment.",
+    rationale: "A module-level summary tells readers what the module is for before they read its items, and keeps generated documentation navigable.",
+    bad_example: concat!("mod parser {\n", "    pub fn parse() {}\n", "}",),
+    good_example: concat!(
+        "mod parser {\n",
+        "    //! Parses configuration files.\n",
+        "    pub fn parse() {}\n",
+        "}",
+    ),
+    config: &[],
+};
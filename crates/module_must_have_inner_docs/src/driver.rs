@@ -17,7 +17,9 @@ use rustc_span::DUMMY_SP;
 use rustc_span::source_map::SourceMap;
 use rustc_span::symbol::Ident;
 use rustc_span::{BytePos, Span};
+use whitaker::hir::FindingLimit;
 use whitaker::{SharedConfig, module_body_span, module_header_span};
+use whitaker_common::CrateScopedState;
 use whitaker_common::i18n::{
     Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
     get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
@@ -48,7 +50,7 @@ impl<'a> ParseInput<'a> {
     }
 }
 
-const LINT_NAME: &str = "module_must_have_inner_docs";
+pub(crate) const LINT_NAME: &str = "module_must_have_inner_docs";
 const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
 
 dylint_linting::impl_late_lint! {
@@ -59,22 +61,21 @@ dylint_linting::impl_late_lint! {
 }
 
 /// Lint pass enforcing leading inner doc comments on modules.
+#[derive(Default)]
 pub struct ModuleMustHaveInnerDocs {
-    localizer: Localizer,
-}
-
-impl Default for ModuleMustHaveInnerDocs {
-    fn default() -> Self {
-        Self {
-            localizer: Localizer::new(None),
-        }
-    }
+    localizer: CrateScopedState<Localizer>,
+    findings: CrateScopedState<FindingLimit>,
 }
 
 impl<'tcx> LateLintPass<'tcx> for ModuleMustHaveInnerDocs {
     fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        self.findings.reset(FindingLimit::new(
+            MODULE_MUST_HAVE_INNER_DOCS,
+            &shared_config,
+        ));
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
@@ -104,7 +105,13 @@ impl<'tcx> LateLintPass<'tcx> for ModuleMustHaveInnerDocs {
             header_span,
         };
 
-        emit_diagnostic(cx, &context, &self.localizer);
+        if self.findings.admit(cx, item.hir_id(), primary_span) {
+            emit_diagnostic(cx, &context, &self.localizer);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.findings.summarise(cx, &self.localizer);
     }
 }
 
@@ -124,13 +131,13 @@ pub(crate) enum ModuleDocDisposition {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum LeadingContent {
+pub(crate) enum LeadingContent {
     Doc,
     Missing,
     Misordered { offset: usize, len: usize },
 }
 
-fn classify_leading_content(snippet: SourceSnippet<'_>) -> LeadingContent {
+pub(crate) fn classify_leading_content(snippet: SourceSnippet<'_>) -> LeadingContent {
     let (offset, rest) = parser::skip_leading_whitespace(ParseInput::from(*snippet));
     if rest.is_empty() {
         return LeadingContent::Missing;
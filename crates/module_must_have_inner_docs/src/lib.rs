@@ -26,5 +26,23 @@
 #[cfg(feature = "dylint-driver")]
 mod driver;
 
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ModuleBodyProjection, ModuleMustHaveInnerDocsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn module_must_have_inner_docs_disabled_stub() {}
+}
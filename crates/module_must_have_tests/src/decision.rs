@@ -0,0 +1,119 @@
+//! Pure decision logic for `module_must_have_tests`.
+//!
+//! The driver measures each module, hands its body to
+//! [`declares_test_module`], and looks for a `*_tests.rs` companion file.
+//! [`ModuleMustHaveTestsDecision`] applies the same [`SizeThreshold`] and
+//! reports sizeable modules with neither, naming the module as the finding's
+//! subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MIN_ITEMS, DEFAULT_MIN_LINES, LINT_NAME};
+use crate::policy::{ModuleSize, SizeThreshold, declares_test_module};
+
+/// Reason key for sizeable modules without tests.
+const UNTESTED: &str = "untested";
+
+/// The facts about one module that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleProjection {
+    /// The module's name.
+    pub name: String,
+    /// The items the module declares, counting associated items.
+    pub items: usize,
+    /// The lines the module body spans.
+    pub lines: usize,
+    /// The source text of the module body.
+    pub source: String,
+    /// Whether a `*_tests.rs` companion file exists.
+    pub has_companion: bool,
+}
+
+/// Reports modules with at least `min_items` items or `min_lines` lines
+/// that have no tests.
+///
+/// # Examples
+///
+/// ```ignore
+/// let module = ModuleProjection {
+///     name: "parser".to_owned(),
+///     items: 5,
+///     ..ModuleProjection::default()
+/// };
+/// assert!(ModuleMustHaveTestsDecision::default().decide(&module).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModuleMustHaveTestsDecision {
+    threshold: SizeThreshold,
+    min_items: usize,
+}
+
+impl ModuleMustHaveTestsDecision {
+    /// Creates a decision for modules reaching `min_items` items or
+    /// `min_lines` lines.
+    #[must_use]
+    pub const fn new(min_items: usize, min_lines: usize) -> Self {
+        Self {
+            threshold: SizeThreshold::new(min_items, min_lines),
+            min_items,
+        }
+    }
+}
+
+impl Default for ModuleMustHaveTestsDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_ITEMS, DEFAULT_MIN_LINES)
+    }
+}
+
+impl LintDecision for ModuleMustHaveTestsDecision {
+    type Projection = ModuleProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, module: &ModuleProjection) -> Option<Finding> {
+        let size = ModuleSize {
+            items: module.items,
+            lines: module.lines,
+        };
+        let tested = module.has_companion || declares_test_module(&module.source);
+        (self.threshold.is_reached(size) && !tested)
+            .then(|| Finding::new(LINT_NAME, UNTESTED).with_subject(module.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ModuleProjection>> {
+        let module = |items: usize, source: &str, has_companion| ModuleProjection {
+            name: "parser".to_owned(),
+            items,
+            lines: 1,
+            source: source.to_owned(),
+            has_companion,
+        };
+        let items = self.min_items;
+        vec![
+            DecisionFixture::flagged("sizeable and untested", module(items, "", false), UNTESTED),
+            DecisionFixture::clean(
+                "inline test module",
+                module(items, "#[cfg(test)]\nmod tests {}", false),
+            ),
+            DecisionFixture::clean("companion test file", module(items, "", true)),
+            DecisionFixture::clean("small module", module(items.saturating_sub(1), "", false)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_threshold(ModuleMustHaveTestsDecision::default())]
+    #[case::small_threshold(ModuleMustHaveTestsDecision::new(2, 50))]
+    fn decision_conforms(#[case] decision: ModuleMustHaveTestsDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
@@ -0,0 +1,29 @@
+//! Embedded documentation for the `module_must_have_tests` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `module_must_have_tests`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "module_must_have_tests",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags modules above an item or line threshold that contain no `#[cfg(test)]` module and have no `*_tests.rs` companion file. Allow-by-default.",
+    rationale: "A sizeable module with no tests beside it leaves its behaviour unchecked, and the gap is cheapest to close while the code is fresh. Keeping tests next to the module also makes the coverage visible to reviewers.",
+    bad_example: "// src/parser.rs, with no `mod tests` and no src/parser_tests.rs\npub fn parse(input: &str) -> Ast { ... }\nfn tokenise(input: &str) -> Vec<Token> { ... }\n// ...",
+    good_example: "// src/parser.rs\npub fn parse(input: &str) -> Ast { ... }\n\n#[cfg(test)]\n#[path = \"parser_tests.rs\"]\nmod tests;",
+    config: &[
+        ConfigKeyDocs {
+            key: "min_items",
+            default: "5",
+            description: "Number of items, counting each method, from which a module needs tests.",
+        },
+        ConfigKeyDocs {
+            key: "min_lines",
+            default: "100",
+            description: "Number of lines from which a module needs tests.",
+        },
+    ],
+};
@@ -0,0 +1,333 @@
+//! Lint pass reporting non-trivial modules without tests.
+//!
+//! Each `mod` item written by the user is measured by the items it declares,
+//! counting the associated items of its `impl` blocks, and by the lines its
+//! body spans. Modules reaching either limit are then searched for tests:
+//! the body's source text for a `#[cfg(test)]` module, which ordinary builds
+//! strip before HIR exists, and the file system for the `<module>_tests.rs`
+//! companion beside a file-backed module. Modules that are themselves test
+//! code, and modules produced by macro expansion, are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use rustc_span::source_map::SourceMap;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::{
+    FindingLimit, TestContext, companion_test_file_for, is_cfg_test_attribute,
+    is_cfg_trace_test_attribute, module_source_path,
+};
+use whitaker::{module_body_span, module_header_span};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{ModuleSize, SizeThreshold, declares_test_module};
+
+pub(crate) const LINT_NAME: &str = "module_must_have_tests";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MIN_ITEMS: usize = 5;
+pub(crate) const DEFAULT_MIN_LINES: usize = 100;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    min_items: usize,
+    min_lines: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_items: DEFAULT_MIN_ITEMS,
+            min_lines: DEFAULT_MIN_LINES,
+        }
+    }
+}
+
+impl Config {
+    const fn threshold(self) -> SizeThreshold {
+        SizeThreshold::new(self.min_items, self.min_lines)
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub MODULE_MUST_HAVE_TESTS,
+    Allow,
+    "non-trivial modules should contain a `#[cfg(test)]` module or have a `*_tests.rs` companion",
+    ModuleMustHaveTests::default()
+}
+
+/// Lint pass that finds sizeable modules without tests.
+#[derive(Default)]
+pub struct ModuleMustHaveTests {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Size threshold, test context, finding limit, and localization for the
+/// crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    test_context: TestContext,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ModuleMustHaveTests {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            test_context: TestContext::for_crate(cx, Vec::new()),
+            findings: FindingLimit::new(MODULE_MUST_HAVE_TESTS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let hir::ItemKind::Mod(ident, module) = item.kind else {
+            return;
+        };
+        if item.span.from_expansion() || is_test_module(cx, &self.state.test_context, item) {
+            return;
+        }
+
+        let source_map = cx.sess().source_map();
+        let body = module_body_span(cx, item, module);
+        let Some(lines) = count_lines(source_map, body) else {
+            return;
+        };
+        let size = ModuleSize {
+            items: count_items(cx, module),
+            lines,
+        };
+        let state = &*self.state;
+        let threshold = state
+            .findings
+            .config_at(cx, body, &state.config)
+            .threshold();
+        if !threshold.is_reached(size) {
+            return;
+        }
+        let Ok(source) = source_map.span_to_snippet(body) else {
+            return;
+        };
+        if declares_test_module(&source) {
+            return;
+        }
+        let companion =
+            module_source_path(cx, item, module).and_then(|path| companion_test_file_for(&path));
+        if companion.as_deref().is_some_and(|path| path.is_file()) {
+            return;
+        }
+
+        debug!(
+            target: LINT_NAME,
+            "module `{ident}` has {} items over {} lines and no tests",
+            size.items,
+            size.lines,
+        );
+        let span = module_header_span(item.span, ident.span);
+        if state.findings.admit(cx, item.hir_id(), span) {
+            let message = UntestedModuleMessage {
+                module: ident.name.as_str(),
+                size,
+            };
+            emit_diagnostic(cx, span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns `true` when `item` is a test module itself or sits inside test
+/// code.
+fn is_test_module(cx: &LateContext<'_>, test_context: &TestContext, item: &hir::Item<'_>) -> bool {
+    cx.tcx
+        .hir_attrs(item.hir_id())
+        .iter()
+        .any(|attr| is_cfg_test_attribute(attr) || is_cfg_trace_test_attribute(attr))
+        || test_context.is_test(cx, item.hir_id())
+}
+
+/// Counts the items a module declares. Imports, nested modules, and macro
+/// definitions are not counted, and each `impl` block counts its associated
+/// items rather than itself.
+fn count_items(cx: &LateContext<'_>, module: &hir::Mod<'_>) -> usize {
+    module
+        .item_ids
+        .iter()
+        .map(|&item_id| cx.tcx.hir_item(item_id))
+        .filter(|item| !item.span.from_expansion())
+        .map(|item| match item.kind {
+            hir::ItemKind::Impl(impl_block) => impl_block.items.len(),
+            hir::ItemKind::Use(..)
+            | hir::ItemKind::ExternCrate(..)
+            | hir::ItemKind::Mod(..)
+            | hir::ItemKind::Macro(..)
+            | hir::ItemKind::ForeignMod { .. }
+            | hir::ItemKind::GlobalAsm { .. } => 0,
+            _ => 1,
+        })
+        .sum()
+}
+
+fn count_lines(source_map: &SourceMap, span: Span) -> Option<usize> {
+    if span.is_dummy() {
+        return None;
+    }
+    let first = source_map.lookup_char_pos(span.lo()).line;
+    let last = source_map.lookup_char_pos(span.hi()).line;
+    last.checked_sub(first).map(|extra| extra + 1)
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &UntestedModuleMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        MODULE_MUST_HAVE_TESTS,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for an untested module interpolates.
+#[derive(Clone, Copy, Debug)]
+struct UntestedModuleMessage<'a> {
+    module: &'a str,
+    size: ModuleSize,
+}
+
+impl UntestedModuleMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("module"),
+            FluentValue::from(self.module.to_owned()),
+        );
+        args.insert(Cow::Borrowed("items"), FluentValue::from(self.size.items));
+        args.insert(Cow::Borrowed("lines"), FluentValue::from(self.size.lines));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { module, size } = *self;
+        let items = if size.items == 1 { "item" } else { "items" };
+        let lines = if size.lines == 1 { "line" } else { "lines" };
+        DiagnosticMessageSet::new(
+            format!("Module `{module}` has no tests."),
+            format!(
+                "`{module}` declares {} {items} over {} {lines}, but contains no `#[cfg(test)]` \
+                 module and has no `{module}_tests.rs` companion file.",
+                size.items, size.lines
+            ),
+            format!(
+                "Add a `#[cfg(test)]` module covering `{module}`, inline or loaded from a \
+                 `{module}_tests.rs` file beside it."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(items: usize, lines: usize) -> UntestedModuleMessage<'static> {
+        UntestedModuleMessage {
+            module: "parser",
+            size: ModuleSize { items, lines },
+        }
+    }
+
+    fn resolve(message: &UntestedModuleMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_needs_five_items_or_a_hundred_lines() {
+        assert_eq!(Config::default().threshold(), SizeThreshold::new(5, 100));
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(6, 120)]
+    fn english_messages_match_the_fallback(#[case] items: usize, #[case] lines: usize) {
+        let message = message(items, lines);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    fn fallback_note_gives_the_size() {
+        let messages = message(6, 120).fallback_messages();
+
+        assert!(messages.note().contains("declares 6 items over 120 lines"));
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(6, 120), locale);
+
+        assert!(messages.primary().contains("`parser`"));
+        assert!(messages.note().contains("120"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
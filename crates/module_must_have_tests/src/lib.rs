@@ -0,0 +1,42 @@
+//! Lint crate flagging non-trivial modules without tests.
+//!
+//! A module with many items or many lines and no tests anywhere near it
+//! leaves its behaviour unchecked, and gaps like this are easiest to close
+//! while the module is still fresh. The lint reports modules that reach
+//! `min_items` items or `min_lines` lines yet contain no `#[cfg(test)]`
+//! module and have no `<module>_tests.rs` companion file beside them. The
+//! lint is allow-by-default, since many crates keep their tests elsewhere;
+//! teams opt in per crate. It is experimental and ships behind the
+//! `experimental-module-must-have-tests` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ModuleMustHaveTestsDecision, ModuleProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn module_must_have_tests_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
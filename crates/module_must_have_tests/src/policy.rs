@@ -0,0 +1,191 @@
+//! Rules deciding which modules need tests.
+//!
+//! The driver measures each module and hands its source text to
+//! [`declares_test_module`], which looks for a `#[cfg(test)]` module among
+//! the module's own items. Source is tokenised with `rustc_lexer` because
+//! `#[cfg(test)]` items are removed before HIR is built in ordinary builds.
+//! [`SizeThreshold`] then decides whether the module is large enough to
+//! report. The rules are kept free of compiler types so they can be tested
+//! directly.
+
+use rustc_lexer::{TokenKind, tokenize};
+
+/// Keywords that may appear in a visibility between attributes and `mod`.
+const VISIBILITY_WORDS: [&str; 5] = ["pub", "crate", "super", "self", "in"];
+
+/// How much a module contains.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct ModuleSize {
+    /// Items declared in the module, counting each associated item of its
+    /// `impl` blocks.
+    pub(crate) items: usize,
+    /// Source lines the module body spans.
+    pub(crate) lines: usize,
+}
+
+/// The size from which a module is expected to have tests.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct SizeThreshold {
+    min_items: usize,
+    min_lines: usize,
+}
+
+impl SizeThreshold {
+    /// Creates a threshold reached by `min_items` items or `min_lines`
+    /// lines.
+    #[must_use]
+    pub(crate) const fn new(min_items: usize, min_lines: usize) -> Self {
+        Self {
+            min_items,
+            min_lines,
+        }
+    }
+
+    /// Returns `true` when `size` reaches either limit.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let threshold = SizeThreshold::new(5, 100);
+    /// assert!(threshold.is_reached(ModuleSize { items: 2, lines: 120 }));
+    /// assert!(!threshold.is_reached(ModuleSize { items: 2, lines: 40 }));
+    /// ```
+    #[must_use]
+    pub(crate) const fn is_reached(self, size: ModuleSize) -> bool {
+        size.items >= self.min_items || size.lines >= self.min_lines
+    }
+}
+
+/// Returns `true` when `source`, the text of a module body, declares a
+/// module gated by `#[cfg(test)]` at its top level.
+///
+/// Attributes, comments, and a visibility may sit between the `cfg`
+/// attribute and `mod`. Modules nested inside other items are not the
+/// module's own tests and are ignored, as are `cfg` predicates that negate
+/// `test`.
+#[must_use]
+pub(crate) fn declares_test_module(source: &str) -> bool {
+    let tokens = significant_tokens(source);
+    let mut depth = 0_usize;
+    let mut gated = false;
+    let mut index = 0;
+    while let Some(&(kind, text)) = tokens.get(index) {
+        match kind {
+            TokenKind::OpenBrace => {
+                depth += 1;
+                gated = false;
+            }
+            TokenKind::CloseBrace => {
+                depth = depth.saturating_sub(1);
+                gated = false;
+            }
+            _ if depth > 0 => {}
+            TokenKind::Pound
+                if tokens
+                    .get(index + 1)
+                    .is_some_and(|(next, _)| *next == TokenKind::OpenBracket) =>
+            {
+                let end = closing_bracket(&tokens, index + 1);
+                gated |= is_cfg_test(&tokens[index + 2..end]);
+                index = end;
+            }
+            TokenKind::Ident if text == "mod" => {
+                if gated {
+                    return true;
+                }
+            }
+            TokenKind::Ident if VISIBILITY_WORDS.contains(&text) => {}
+            TokenKind::OpenParen | TokenKind::CloseParen | TokenKind::Colon if gated => {}
+            _ => gated = false,
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Tokens other than whitespace and comments, with their text.
+fn significant_tokens(source: &str) -> Vec<(TokenKind, &str)> {
+    let mut offset = 0;
+    let mut tokens = Vec::new();
+    for token in tokenize(source) {
+        let end = offset + token.len;
+        if !matches!(
+            token.kind,
+            TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment { .. }
+        ) {
+            tokens.push((token.kind, &source[offset..end]));
+        }
+        offset = end;
+    }
+    tokens
+}
+
+/// Returns the index of the bracket closing the one at `open`, or the last
+/// index when the attribute is unterminated.
+fn closing_bracket(tokens: &[(TokenKind, &str)], open: usize) -> usize {
+    let mut depth = 0_usize;
+    for (index, (kind, _)) in tokens.iter().enumerate().skip(open) {
+        match kind {
+            TokenKind::OpenBracket => depth += 1,
+            TokenKind::CloseBracket => {
+                depth -= 1;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len().saturating_sub(1)
+}
+
+/// Returns `true` for the contents of a `cfg` attribute whose predicate
+/// mentions `test` without negating it.
+fn is_cfg_test(attribute: &[(TokenKind, &str)]) -> bool {
+    let mut idents = attribute
+        .iter()
+        .filter(|(kind, _)| *kind == TokenKind::Ident)
+        .map(|&(_, text)| text);
+    idents.next() == Some("cfg")
+        && attribute
+            .iter()
+            .any(|&(kind, text)| kind == TokenKind::Ident && text == "test")
+        && !idents.any(|text| text == "not")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::plain("fn run() {}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn runs() {}\n}\n")]
+    #[case::companion("#[cfg(test)]\n#[path = \"parser_tests.rs\"]\nmod tests;\n")]
+    #[case::documented("#[cfg(test)]\n/// Unit tests.\npub(crate) mod tests {}\n")]
+    #[case::combined("#[cfg(all(test, feature = \"serde\"))]\nmod serde_tests;\n")]
+    #[case::inline_body("fn run() {}\n#[cfg(test)] mod tests {}\n}")]
+    fn finds_test_modules(#[case] source: &str) {
+        assert!(declares_test_module(source));
+    }
+
+    #[rstest]
+    #[case::none("fn run() {}\n")]
+    #[case::ungated("mod tests {}\n")]
+    #[case::negated("#[cfg(not(test))]\nmod fallback;\n")]
+    #[case::other_cfg("#[cfg(unix)]\nmod unix;\n")]
+    #[case::gated_function("#[cfg(test)]\nfn helper() {}\nmod parser;\n")]
+    #[case::nested("mod outer {\n    #[cfg(test)]\n    mod tests {}\n}\n")]
+    #[case::comment("// #[cfg(test)]\n// mod tests;\nfn run() {}\n")]
+    #[case::string("const DOC: &str = \"#[cfg(test)] mod tests\";\n")]
+    fn ignores_other_modules(#[case] source: &str) {
+        assert!(!declares_test_module(source));
+    }
+
+    #[rstest]
+    #[case(ModuleSize { items: 5, lines: 10 }, true)]
+    #[case(ModuleSize { items: 1, lines: 100 }, true)]
+    #[case(ModuleSize { items: 4, lines: 99 }, false)]
+    fn either_limit_makes_a_module_non_trivial(#[case] size: ModuleSize, #[case] expected: bool) {
+        assert_eq!(SizeThreshold::new(5, 100).is_reached(size), expected);
+    }
+}
@@ -0,0 +1,114 @@
+//! Behaviour-driven coverage for finding tests in module source.
+
+use crate::policy::{ModuleSize, SizeThreshold, declares_test_module};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct ModuleWorld {
+    source: RefCell<String>,
+    size: Cell<ModuleSize>,
+    has_tests: Cell<Option<bool>>,
+    needs_tests: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> ModuleWorld {
+    ModuleWorld::default()
+}
+
+#[given("a module body declaring a cfg test module")]
+fn given_inline_tests(world: &ModuleWorld) {
+    *world.source.borrow_mut() =
+        "pub fn parse() {}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn parses() {}\n}\n"
+            .to_owned();
+}
+
+#[given("a module body loading tests from {file}")]
+fn given_companion(world: &ModuleWorld, file: String) {
+    *world.source.borrow_mut() = format!(
+        "pub fn parse() {{}}\n\n#[cfg(test)]\n#[path = \"{}\"]\nmod tests;\n",
+        file.trim_matches('"')
+    );
+}
+
+#[given("a module body with a cfg test module inside an inner module")]
+fn given_nested_tests(world: &ModuleWorld) {
+    *world.source.borrow_mut() = "mod inner {\n    #[cfg(test)]\n    mod tests {}\n}\n".to_owned();
+}
+
+#[given("a module body declaring a cfg not test module")]
+fn given_negated(world: &ModuleWorld) {
+    *world.source.borrow_mut() = "#[cfg(not(test))]\nmod release;\n".to_owned();
+}
+
+#[given("a module with {items} items over {lines} lines")]
+fn given_size(world: &ModuleWorld, items: usize, lines: usize) {
+    world.size.set(ModuleSize { items, lines });
+}
+
+#[when("the module source is scanned")]
+fn when_scanned(world: &ModuleWorld) {
+    world
+        .has_tests
+        .set(Some(declares_test_module(&world.source.borrow())));
+}
+
+#[when("the size is checked against {items} items or {lines} lines")]
+fn when_size_checked(world: &ModuleWorld, items: usize, lines: usize) {
+    let threshold = SizeThreshold::new(items, lines);
+    world
+        .needs_tests
+        .set(Some(threshold.is_reached(world.size.get())));
+}
+
+#[then("the module has tests")]
+fn then_has_tests(world: &ModuleWorld) {
+    assert_eq!(world.has_tests.get(), Some(true));
+}
+
+#[then("the module has no tests")]
+fn then_has_no_tests(world: &ModuleWorld) {
+    assert_eq!(world.has_tests.get(), Some(false));
+}
+
+#[then("the module needs tests")]
+fn then_needs_tests(world: &ModuleWorld) {
+    assert_eq!(world.needs_tests.get(), Some(true));
+}
+
+#[then("the module does not need tests")]
+fn then_does_not_need_tests(world: &ModuleWorld) {
+    assert_eq!(world.needs_tests.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/module_must_have_tests.feature", index = 0)]
+fn scenario_inline_tests(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_must_have_tests.feature", index = 1)]
+fn scenario_companion(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_must_have_tests.feature", index = 2)]
+fn scenario_nested_tests(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_must_have_tests.feature", index = 3)]
+fn scenario_negated(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_must_have_tests.feature", index = 4)]
+fn scenario_large_module(world: ModuleWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_must_have_tests.feature", index = 5)]
+fn scenario_small_module(world: ModuleWorld) {
+    let _ = world;
+}
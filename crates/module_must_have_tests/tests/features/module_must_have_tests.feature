@@ -0,0 +1,34 @@
+Feature: Finding tests for non-trivial modules
+  A module reaching the item or line threshold needs a `#[cfg(test)]`
+  module among its own items. Test modules nested in other items do not
+  count, and `cfg` predicates negating `test` do not gate tests.
+
+  Scenario: A module with an inline test module has tests
+    Given a module body declaring a cfg test module
+    When the module source is scanned
+    Then the module has tests
+
+  Scenario: A module loading its tests from a companion file has tests
+    Given a module body loading tests from "parser_tests.rs"
+    When the module source is scanned
+    Then the module has tests
+
+  Scenario: A module whose test module is nested has no tests
+    Given a module body with a cfg test module inside an inner module
+    When the module source is scanned
+    Then the module has no tests
+
+  Scenario: A negated cfg does not count as tests
+    Given a module body declaring a cfg not test module
+    When the module source is scanned
+    Then the module has no tests
+
+  Scenario: A large module needs tests
+    Given a module with 2 items over 150 lines
+    When the size is checked against 5 items or 100 lines
+    Then the module needs tests
+
+  Scenario: A small module does not need tests
+    Given a module with 2 items over 30 lines
+    When the size is checked against 5 items or 100 lines
+    Then the module does not need tests
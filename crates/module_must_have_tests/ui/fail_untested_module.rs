@@ -0,0 +1,16 @@
+//! Negative UI fixture: a module above the item threshold without tests.
+#![warn(module_must_have_tests)]
+
+mod parser {
+    pub fn parse(input: &str) -> usize {
+        input.len()
+    }
+
+    pub fn tokenise(input: &str) -> Vec<&str> {
+        input.split_whitespace().collect()
+    }
+}
+
+fn main() {
+    let _ = (parser::parse("a b"), parser::tokenise("a b"));
+}
@@ -0,0 +1,33 @@
+//! Positive UI fixture: modules with inline or companion tests, and small
+//! modules, are not reported.
+#![warn(module_must_have_tests)]
+
+mod parser {
+    pub fn parse(input: &str) -> usize {
+        input.len()
+    }
+
+    pub fn tokenise(input: &str) -> Vec<&str> {
+        input.split_whitespace().collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        #[test]
+        fn parses() {
+            assert_eq!(super::parse("ab"), 2);
+        }
+    }
+}
+
+#[path = "support/lexer.rs"]
+mod lexer;
+
+mod constants {
+    pub const LIMIT: usize = 3;
+}
+
+fn main() {
+    let _ = (parser::parse("a b"), parser::tokenise("a b"));
+    let _ = (lexer::lex("a b"), lexer::count("a b"), constants::LIMIT);
+}
@@ -0,0 +1,9 @@
+//! Support module whose tests live in `lexer_tests.rs` beside it.
+
+pub fn lex(input: &str) -> Vec<char> {
+    input.chars().collect()
+}
+
+pub fn count(input: &str) -> usize {
+    lex(input).len()
+}
@@ -0,0 +1,6 @@
+//! Companion tests for the support lexer.
+
+#[test]
+fn lexes() {
+    assert_eq!(super::lex("ab"), ['a', 'b']);
+}
@@ -0,0 +1,172 @@
+//! Pure decision logic for `module_named_after_parent`.
+//!
+//! The driver hands over each module and type with the path of the module
+//! declaring it. [`ModuleNamedAfterParentDecision`] applies the same
+//! [`module_repeats_parent`] and [`item_rename`] rules under the configured
+//! [`StutterMode`]. Findings use `module` or `item` as their reason and the
+//! suggested name, when there is one, as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{StutterMode, item_rename, module_rename, module_repeats_parent};
+
+/// Reason key for modules repeating their parent's name.
+const MODULE: &str = "module";
+/// Reason key for types repeating their module's name.
+const ITEM: &str = "item";
+
+/// The facts about one module or type that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NamedItemProjection {
+    /// The path of the module declaring the item, empty at the crate root.
+    pub parent_path: String,
+    /// The item's name.
+    pub name: String,
+    /// Whether the item is a module rather than a type.
+    pub is_module: bool,
+    /// For modules, the names of the types declared inside them.
+    pub types: Vec<String>,
+}
+
+/// Reports modules named after their parent and types repeating the name of
+/// their module.
+///
+/// # Examples
+///
+/// ```ignore
+/// let loader = NamedItemProjection {
+///     parent_path: "crate::config".to_owned(),
+///     name: "ConfigLoader".to_owned(),
+///     ..NamedItemProjection::default()
+/// };
+/// let finding = ModuleNamedAfterParentDecision::default().decide(&loader);
+/// assert_eq!(finding.as_ref().and_then(Finding::subject), Some("Loader"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ModuleNamedAfterParentDecision {
+    mode: StutterMode,
+}
+
+impl ModuleNamedAfterParentDecision {
+    /// Creates a decision for the configured `mode`: `modules`, `items`, or
+    /// `both`. Returns `None` for any other mode.
+    #[must_use]
+    pub fn new(mode: &str) -> Option<Self> {
+        let mode = match mode {
+            "modules" => StutterMode::Modules,
+            "items" => StutterMode::Items,
+            "both" => StutterMode::Both,
+            _ => return None,
+        };
+        Some(Self { mode })
+    }
+
+    fn module_finding(item: &NamedItemProjection) -> Option<Finding> {
+        if !module_repeats_parent(&item.parent_path, &item.name) {
+            return None;
+        }
+        let finding = Finding::new(LINT_NAME, MODULE);
+        Some(
+            match module_rename(&item.name, item.types.iter().map(String::as_str)) {
+                Some(suggestion) => finding.with_subject(suggestion),
+                None => finding,
+            },
+        )
+    }
+
+    fn item_finding(item: &NamedItemProjection) -> Option<Finding> {
+        let module = item
+            .parent_path
+            .rsplit("::")
+            .next()
+            .filter(|name| !name.is_empty())?;
+        let suggestion = item_rename(module, &item.name)?;
+        Some(Finding::new(LINT_NAME, ITEM).with_subject(suggestion))
+    }
+}
+
+impl LintDecision for ModuleNamedAfterParentDecision {
+    type Projection = NamedItemProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, item: &NamedItemProjection) -> Option<Finding> {
+        if item.is_module {
+            self.mode
+                .checks_modules()
+                .then(|| Self::module_finding(item))?
+        } else {
+            self.mode.checks_items().then(|| Self::item_finding(item))?
+        }
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<NamedItemProjection>> {
+        let module = |name: &str| NamedItemProjection {
+            parent_path: "crate::config".to_owned(),
+            name: name.to_owned(),
+            is_module: true,
+            types: vec!["ConfigLoader".to_owned()],
+        };
+        let item = |name: &str| NamedItemProjection {
+            parent_path: "crate::config".to_owned(),
+            name: name.to_owned(),
+            ..NamedItemProjection::default()
+        };
+        let mut fixtures = vec![
+            DecisionFixture::clean("distinct module name", module("loader")),
+            DecisionFixture::clean("type named after its module", item("Config")),
+            DecisionFixture::clean("longer word", item("Configuration")),
+        ];
+        fixtures.push(if self.mode.checks_modules() {
+            DecisionFixture::flagged("module repeating its parent", module("config"), MODULE)
+        } else {
+            DecisionFixture::clean("module stutter not checked", module("config"))
+        });
+        fixtures.push(if self.mode.checks_items() {
+            DecisionFixture::flagged("type repeating its module", item("ConfigLoader"), ITEM)
+        } else {
+            DecisionFixture::clean("type stutter not checked", item("ConfigLoader"))
+        });
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_suggest_a_new_name() {
+        let module = NamedItemProjection {
+            parent_path: "crate::config".to_owned(),
+            name: "config".to_owned(),
+            is_module: true,
+            types: vec!["ConfigLoader".to_owned()],
+        };
+        let finding = ModuleNamedAfterParentDecision::default()
+            .decide(&module)
+            .expect("the module should be reported");
+
+        assert_eq!(finding.subject(), Some("loader"));
+    }
+
+    #[rstest]
+    fn unknown_modes_are_rejected() {
+        assert_eq!(ModuleNamedAfterParentDecision::new("types"), None);
+    }
+
+    #[rstest]
+    #[case::both("both")]
+    #[case::modules("modules")]
+    #[case::items("items")]
+    fn decision_conforms(#[case] mode: &str) {
+        let decision = ModuleNamedAfterParentDecision::new(mode).expect("mode should parse");
+
+        assert_eq!(check_conformance(&decision), Ok(5));
+    }
+}
@@ -0,0 +1,22 @@
+//! Embedded documentation for the `module_named_after_parent` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `module_named_after_parent`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "module_named_after_parent",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags modules named after their parent module, and types whose name starts or ends with the name of their module.",
+    rationale: "A path such as `config::config::ConfigLoader` repeats one word three times. Each repetition lengthens every use site without telling the reader anything the path has not already said.",
+    bad_example: "mod config {\n    pub mod config {\n        pub struct ConfigLoader;\n    }\n}",
+    good_example: "mod config {\n    pub mod loader {\n        pub struct Loader;\n    }\n}",
+    config: &[ConfigKeyDocs {
+        key: "mode",
+        default: "\"both\"",
+        description: "Which stutter to report: `\"modules\"` for modules named after their parent, `\"items\"` for types repeating their module name, or `\"both\"`.",
+    }],
+};
@@ -0,0 +1,386 @@
+//! Lint pass reporting modules and types that repeat the name of the module
+//! containing them.
+//!
+//! Each module item is compared with the module it is declared in, rendered
+//! with [`whitaker::hir::module_path`], and reported through
+//! [`module_repeats_parent`]; the types it declares supply a rename through
+//! [`module_rename`]. Each struct, enum, union, trait, and type alias is
+//! compared with its own module through [`item_rename`]. The `mode` setting
+//! selects which of the two checks run. Items produced by macro expansion are
+//! skipped, and the crate root has no name to repeat.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{Ident, Span, Symbol};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{StutterMode, item_rename, module_rename, module_repeats_parent};
+
+pub(crate) const LINT_NAME: &str = "module_named_after_parent";
+const MODULE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const ITEM_KEY: MessageKey<'static> = MessageKey::new("module_named_after_parent-item");
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    mode: StutterMode,
+}
+
+dylint_linting::impl_late_lint! {
+    pub MODULE_NAMED_AFTER_PARENT,
+    Warn,
+    "modules should not repeat their parent's name, nor types the name of their module",
+    ModuleNamedAfterParent::default()
+}
+
+/// Lint pass that finds module and type names stuttering along a path.
+#[derive(Default)]
+pub struct ModuleNamedAfterParent {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Mode, finding limit, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    mode: StutterMode,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ModuleNamedAfterParent {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            mode: load_configuration().mode,
+            findings: FindingLimit::new(MODULE_NAMED_AFTER_PARENT, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if item.span.from_expansion() {
+            return;
+        }
+        let state = &*self.state;
+        let stutter = match item.kind {
+            hir::ItemKind::Mod(ident, module) if state.mode.checks_modules() => {
+                module_stutter(cx, item, ident, module)
+            }
+            _ if state.mode.checks_items() && declares_type(&item.kind) => item
+                .kind
+                .ident()
+                .and_then(|ident| type_stutter(cx, item, ident)),
+            _ => None,
+        };
+        let Some((span, stutter)) = stutter else {
+            return;
+        };
+
+        debug!(target: LINT_NAME, "{stutter:?}");
+        if state.findings.admit(cx, item.hir_id(), span) {
+            emit_diagnostic(cx, span, &stutter, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns `true` for the items whose names appear in type position.
+const fn declares_type(kind: &hir::ItemKind<'_>) -> bool {
+    matches!(
+        kind,
+        hir::ItemKind::Struct(..)
+            | hir::ItemKind::Enum(..)
+            | hir::ItemKind::Union(..)
+            | hir::ItemKind::Trait { .. }
+            | hir::ItemKind::TraitAlias(..)
+            | hir::ItemKind::TyAlias(..)
+    )
+}
+
+/// Describes the module `item` when its name repeats its parent's.
+fn module_stutter(
+    cx: &LateContext<'_>,
+    item: &hir::Item<'_>,
+    ident: Ident,
+    module: &hir::Mod<'_>,
+) -> Option<(Span, Stutter)> {
+    let parent = module_path(cx, item.hir_id());
+    let name = ident.name.as_str();
+    if !module_repeats_parent(&parent, name) {
+        return None;
+    }
+    let types: Vec<Symbol> = module
+        .item_ids
+        .iter()
+        .map(|&item_id| cx.tcx.hir_item(item_id))
+        .filter(|inner| !inner.span.from_expansion() && declares_type(&inner.kind))
+        .filter_map(|inner| inner.kind.ident())
+        .map(|inner| inner.name)
+        .collect();
+    let suggestion = module_rename(name, types.iter().map(Symbol::as_str));
+    let stutter = Stutter::Module {
+        path: format!("{parent}::{name}"),
+        parent,
+        suggestion,
+    };
+    Some((ident.span, stutter))
+}
+
+/// Describes the type `item` when its name repeats its module's.
+fn type_stutter(
+    cx: &LateContext<'_>,
+    item: &hir::Item<'_>,
+    ident: Ident,
+) -> Option<(Span, Stutter)> {
+    let module = module_path(cx, item.hir_id());
+    let module_name = module.rsplit("::").next().filter(|name| !name.is_empty())?;
+    let suggestion = item_rename(module_name, ident.name.as_str())?;
+    let stutter = Stutter::Item {
+        item: ident.name.to_string(),
+        module,
+        suggestion,
+    };
+    Some((ident.span, stutter))
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, span: Span, stutter: &Stutter, localizer: &Localizer) {
+    let args = stutter.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: stutter.message_key(),
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        stutter.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        MODULE_NAMED_AFTER_PARENT,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// A name that repeats the module containing it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Stutter {
+    /// A module named after its parent.
+    Module {
+        /// The full path of the reported module.
+        path: String,
+        /// The path of the module it is declared in.
+        parent: String,
+        /// A name taken from the types it declares, if any repeat it.
+        suggestion: Option<String>,
+    },
+    /// A type whose name starts or ends with its module's name.
+    Item {
+        /// The type name as written.
+        item: String,
+        /// The path of the module it is declared in.
+        module: String,
+        /// The type name without the repeated module name.
+        suggestion: String,
+    },
+}
+
+impl Stutter {
+    const fn message_key(&self) -> MessageKey<'static> {
+        match self {
+            Self::Module { .. } => MODULE_KEY,
+            Self::Item { .. } => ITEM_KEY,
+        }
+    }
+
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        match self {
+            Self::Module {
+                path,
+                parent,
+                suggestion,
+            } => {
+                let fix = if suggestion.is_some() {
+                    "rename"
+                } else {
+                    "flatten"
+                };
+                args.insert(Cow::Borrowed("path"), FluentValue::from(path.clone()));
+                args.insert(Cow::Borrowed("parent"), FluentValue::from(parent.clone()));
+                args.insert(Cow::Borrowed("fix"), FluentValue::from(fix));
+                args.insert(
+                    Cow::Borrowed("suggestion"),
+                    FluentValue::from(suggestion.clone().unwrap_or_default()),
+                );
+            }
+            Self::Item {
+                item,
+                module,
+                suggestion,
+            } => {
+                args.insert(Cow::Borrowed("item"), FluentValue::from(item.clone()));
+                args.insert(Cow::Borrowed("module"), FluentValue::from(module.clone()));
+                args.insert(
+                    Cow::Borrowed("suggestion"),
+                    FluentValue::from(suggestion.clone()),
+                );
+            }
+        }
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        match self {
+            Self::Module {
+                path,
+                parent,
+                suggestion,
+            } => DiagnosticMessageSet::new(
+                format!("Module `{path}` repeats the name of its parent module."),
+                format!(
+                    "Paths through it read `{path}`, naming the same module twice without saying \
+                     what the inner one adds."
+                ),
+                match suggestion {
+                    Some(suggestion) => format!(
+                        "Rename it after what it holds, such as `{parent}::{suggestion}`, or move \
+                         its items up into `{parent}`."
+                    ),
+                    None => format!(
+                        "Move its items up into `{parent}`, or rename it after what it adds to \
+                         `{parent}`."
+                    ),
+                },
+            ),
+            Self::Item {
+                item,
+                module,
+                suggestion,
+            } => DiagnosticMessageSet::new(
+                format!("`{item}` repeats the name of its module `{module}`."),
+                format!(
+                    "Callers already write `{module}::{item}`, so the module name appears twice \
+                     in every path."
+                ),
+                format!("Rename it to `{suggestion}` so the path reads `{module}::{suggestion}`."),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    fn module(suggestion: Option<&str>) -> Stutter {
+        Stutter::Module {
+            path: "app::config::config".to_owned(),
+            parent: "app::config".to_owned(),
+            suggestion: suggestion.map(str::to_owned),
+        }
+    }
+
+    #[fixture]
+    fn item() -> Stutter {
+        Stutter::Item {
+            item: "ConfigLoader".to_owned(),
+            module: "app::config".to_owned(),
+            suggestion: "Loader".to_owned(),
+        }
+    }
+
+    fn resolve(stutter: &Stutter, locale: &str) -> DiagnosticMessageSet {
+        let args = stutter.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: stutter.message_key(),
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || stutter.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_reports_both_kinds() {
+        assert_eq!(Config::default().mode, StutterMode::Both);
+    }
+
+    #[rstest]
+    fn module_help_names_the_suggested_module() {
+        let messages = module(Some("loader")).fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Module `app::config::config` repeats the name of its parent module."
+        );
+        assert!(messages.help().contains("`app::config::loader`"));
+    }
+
+    #[rstest]
+    fn item_help_names_the_shorter_path(item: Stutter) {
+        assert_eq!(
+            item.fallback_messages().help(),
+            "Rename it to `Loader` so the path reads `app::config::Loader`."
+        );
+    }
+
+    #[rstest]
+    #[case::renamed_module(module(Some("loader")))]
+    #[case::flattened_module(module(None))]
+    #[case::item(item())]
+    fn english_messages_match_the_fallback(#[case] stutter: Stutter) {
+        assert_eq!(resolve(&stutter, "en-GB"), stutter.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str, item: Stutter) {
+        let module_messages = resolve(&module(Some("loader")), locale);
+        let item_messages = resolve(&item, locale);
+
+        assert!(module_messages.primary().contains("`app::config::config`"));
+        assert!(module_messages.help().contains("`app::config::loader`"));
+        assert!(item_messages.help().contains("`app::config::Loader`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,41 @@
+//! Lint crate flagging names that stutter along a module path.
+//!
+//! A path such as `config::config::ConfigLoader` names the same idea three
+//! times. The lint reports modules whose name repeats the module they are
+//! declared in, and structs, enums, unions, traits, and type aliases whose
+//! name starts or ends with the name of their module. The help text suggests
+//! a rename with the repetition dropped. The `mode` setting selects whether
+//! modules, types, or both are checked. The lint is experimental and ships
+//! behind the `experimental-module-named-after-parent` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ModuleNamedAfterParentDecision, NamedItemProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn module_named_after_parent_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
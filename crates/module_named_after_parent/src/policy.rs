@@ -0,0 +1,186 @@
+//! Rules deciding which names stutter and how they could be renamed.
+//!
+//! The driver hands over module paths, module names, and type names as
+//! strings. A module stutters when its name repeats the module containing it,
+//! as in `config::config`, and a type stutters when its name starts or ends
+//! with the name of its module, as in `config::ConfigLoader`. The rules are
+//! kept free of compiler types so they can be tested directly.
+
+use serde::Deserialize;
+
+/// Which kinds of stutter the lint reports.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StutterMode {
+    /// Report only modules named after their parent module.
+    Modules,
+    /// Report only types repeating the name of their module.
+    Items,
+    /// Report both.
+    #[default]
+    Both,
+}
+
+impl StutterMode {
+    /// Returns `true` when modules named after their parent are reported.
+    #[must_use]
+    pub(crate) const fn checks_modules(self) -> bool {
+        matches!(self, Self::Modules | Self::Both)
+    }
+
+    /// Returns `true` when types repeating their module name are reported.
+    #[must_use]
+    pub(crate) const fn checks_items(self) -> bool {
+        matches!(self, Self::Items | Self::Both)
+    }
+}
+
+/// Returns `true` when a module called `name`, declared in the module at
+/// `parent_path`, repeats its parent's name. The crate root, whose path is
+/// empty, has no name to repeat.
+#[must_use]
+pub(crate) fn module_repeats_parent(parent_path: &str, name: &str) -> bool {
+    !parent_path.is_empty() && parent_path.rsplit("::").next() == Some(name)
+}
+
+/// Returns the name a type called `item` in module `module` could take once
+/// the module name is dropped, or `None` when it does not repeat the module
+/// name.
+///
+/// The module name is compared in `UpperCamelCase` at a word boundary, so
+/// `ConfigLoader` and `LoaderConfig` repeat `config` while `Configuration`
+/// and `Config` itself do not.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(item_rename("config", "ConfigLoader"), Some("Loader".to_owned()));
+/// assert_eq!(item_rename("http_client", "RetryingHttpClient"), Some("Retrying".to_owned()));
+/// assert_eq!(item_rename("config", "Config"), None);
+/// ```
+#[must_use]
+pub(crate) fn item_rename(module: &str, item: &str) -> Option<String> {
+    let stem = upper_camel_case(module);
+    if stem.is_empty() {
+        return None;
+    }
+    if let Some(rest) = item.strip_prefix(&stem)
+        && rest.starts_with(|first: char| first.is_ascii_uppercase())
+    {
+        return Some(rest.to_owned());
+    }
+    item.strip_suffix(&stem)
+        .filter(|rest| !rest.is_empty())
+        .map(str::to_owned)
+}
+
+/// Suggests a name for a module called `module` that repeats its parent,
+/// taken from the first of its `types` that also repeats the name.
+///
+/// `config::config::ConfigLoader` suggests `loader`, giving
+/// `config::loader::ConfigLoader`.
+#[must_use]
+pub(crate) fn module_rename<'a>(
+    module: &str,
+    types: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    types
+        .into_iter()
+        .find_map(|item| item_rename(module, item))
+        .map(|rest| snake_case(&rest))
+}
+
+fn upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, &current) in chars.iter().enumerate() {
+        if current.is_ascii_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lower = chars
+                .get(index + 1)
+                .is_some_and(|next| next.is_ascii_lowercase());
+            if !previous.is_ascii_uppercase() || next_is_lower {
+                snake.push('_');
+            }
+        }
+        snake.push(current.to_ascii_lowercase());
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(StutterMode::Modules, true, false)]
+    #[case(StutterMode::Items, false, true)]
+    #[case(StutterMode::Both, true, true)]
+    fn modes_select_what_is_checked(
+        #[case] mode: StutterMode,
+        #[case] modules: bool,
+        #[case] items: bool,
+    ) {
+        assert_eq!(mode.checks_modules(), modules);
+        assert_eq!(mode.checks_items(), items);
+    }
+
+    #[rstest]
+    #[case("config", "config", true)]
+    #[case("app::config", "config", true)]
+    #[case("config", "loader", false)]
+    #[case("config_store", "config", false)]
+    #[case("", "config", false)]
+    fn modules_repeating_their_parent_are_detected(
+        #[case] parent: &str,
+        #[case] name: &str,
+        #[case] repeats: bool,
+    ) {
+        assert_eq!(module_repeats_parent(parent, name), repeats);
+    }
+
+    #[rstest]
+    #[case("config", "ConfigLoader", Some("Loader"))]
+    #[case("config", "LoaderConfig", Some("Loader"))]
+    #[case("http_client", "HttpClientPool", Some("Pool"))]
+    #[case("http_client", "RetryingHttpClient", Some("Retrying"))]
+    #[case("config", "Config", None)]
+    #[case("config", "Configuration", None)]
+    #[case("config", "Loader", None)]
+    fn types_repeating_their_module_are_renamed(
+        #[case] module: &str,
+        #[case] item: &str,
+        #[case] rename: Option<&str>,
+    ) {
+        assert_eq!(item_rename(module, item).as_deref(), rename);
+    }
+
+    #[rstest]
+    #[case(&["Settings", "ConfigLoader"], Some("loader"))]
+    #[case(&["ConfigHTTPSource"], Some("http_source"))]
+    #[case(&["Settings"], None)]
+    fn module_renames_come_from_stuttering_types(
+        #[case] types: &[&str],
+        #[case] rename: Option<&str>,
+    ) {
+        assert_eq!(
+            module_rename("config", types.iter().copied()).as_deref(),
+            rename
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Behaviour-driven coverage for the stutter policy.
+
+use crate::policy::{StutterMode, item_rename, module_rename, module_repeats_parent};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+/// What a scenario reports: the kind of name and its suggested rename.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Finding {
+    Module(Option<String>),
+    Type(String),
+}
+
+#[derive(Default)]
+enum Subject {
+    #[default]
+    Unset,
+    Module {
+        name: String,
+        parent: String,
+        types: Vec<String>,
+    },
+    Type {
+        name: String,
+        module: String,
+    },
+}
+
+#[derive(Default)]
+struct StutterWorld {
+    mode: Cell<StutterMode>,
+    subject: RefCell<Subject>,
+    finding: RefCell<Option<Option<Finding>>>,
+}
+
+#[fixture]
+fn world() -> StutterWorld {
+    StutterWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+#[given("the mode {mode}")]
+fn given_mode(world: &StutterWorld, mode: String) {
+    let mode = match unquote(&mode).as_str() {
+        "modules" => StutterMode::Modules,
+        "items" => StutterMode::Items,
+        _ => StutterMode::Both,
+    };
+    world.mode.set(mode);
+}
+
+#[given("a module {name} in {parent} declaring {item}")]
+fn given_module(world: &StutterWorld, name: String, parent: String, item: String) {
+    *world.subject.borrow_mut() = Subject::Module {
+        name: unquote(&name),
+        parent: unquote(&parent),
+        types: vec![unquote(&item)],
+    };
+}
+
+#[given("a type {name} in module {module}")]
+fn given_type(world: &StutterWorld, name: String, module: String) {
+    *world.subject.borrow_mut() = Subject::Type {
+        name: unquote(&name),
+        module: unquote(&module),
+    };
+}
+
+#[when("the names are checked")]
+fn when_checked(world: &StutterWorld) {
+    let mode = world.mode.get();
+    let finding = match &*world.subject.borrow() {
+        Subject::Module {
+            name,
+            parent,
+            types,
+        } if mode.checks_modules() && module_repeats_parent(parent, name) => Some(Finding::Module(
+            module_rename(name, types.iter().map(String::as_str)),
+        )),
+        Subject::Type { name, module } if mode.checks_items() => {
+            item_rename(module, name).map(Finding::Type)
+        }
+        _ => None,
+    };
+    *world.finding.borrow_mut() = Some(finding);
+}
+
+#[then("the module is reported with the rename {rename}")]
+fn then_module_reported(world: &StutterWorld, rename: String) {
+    assert_eq!(
+        *world.finding.borrow(),
+        Some(Some(Finding::Module(Some(unquote(&rename)))))
+    );
+}
+
+#[then("the type is reported with the rename {rename}")]
+fn then_type_reported(world: &StutterWorld, rename: String) {
+    assert_eq!(
+        *world.finding.borrow(),
+        Some(Some(Finding::Type(unquote(&rename))))
+    );
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &StutterWorld) {
+    assert_eq!(*world.finding.borrow(), Some(None));
+}
+
+#[scenario(path = "tests/features/module_named_after_parent.feature", index = 0)]
+fn scenario_module_stutter(world: StutterWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_named_after_parent.feature", index = 1)]
+fn scenario_type_stutter(world: StutterWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_named_after_parent.feature", index = 2)]
+fn scenario_items_mode(world: StutterWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/module_named_after_parent.feature", index = 3)]
+fn scenario_modules_mode(world: StutterWorld) {
+    let _ = world;
+}
@@ -0,0 +1,27 @@
+Feature: Names repeating their module
+  Modules named after their parent, and types repeating the name of their
+  module, are reported according to the configured mode.
+
+  Scenario: A module repeating its parent is reported with a rename
+    Given the mode "both"
+    And a module "config" in "app::config" declaring "ConfigLoader"
+    When the names are checked
+    Then the module is reported with the rename "loader"
+
+  Scenario: A type repeating its module is reported with a rename
+    Given the mode "both"
+    And a type "ConfigLoader" in module "config"
+    When the names are checked
+    Then the type is reported with the rename "Loader"
+
+  Scenario: Items mode leaves modules alone
+    Given the mode "items"
+    And a module "config" in "app::config" declaring "Settings"
+    When the names are checked
+    Then nothing is reported
+
+  Scenario: Modules mode leaves types alone
+    Given the mode "modules"
+    And a type "ConfigLoader" in module "config"
+    When the names are checked
+    Then nothing is reported
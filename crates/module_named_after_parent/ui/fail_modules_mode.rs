@@ -0,0 +1,15 @@
+//! With `mode = "modules"`, only modules named after their parent are
+//! reported.
+
+mod config {
+    pub mod config {
+        pub struct Settings;
+    }
+
+    pub struct ConfigError;
+}
+
+fn main() {
+    let _ = config::config::Settings;
+    let _ = config::ConfigError;
+}
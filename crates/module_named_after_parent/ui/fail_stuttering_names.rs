@@ -0,0 +1,32 @@
+//! Modules and types repeating the name of the module containing them.
+
+mod config {
+    pub mod config {
+        pub struct ConfigLoader;
+    }
+
+    pub struct ConfigError;
+
+    pub struct Config;
+}
+
+mod storage {
+    pub trait BackendStorage {
+        fn name(&self) -> &'static str;
+    }
+
+    pub struct Disk;
+
+    impl BackendStorage for Disk {
+        fn name(&self) -> &'static str {
+            "disk"
+        }
+    }
+}
+
+fn main() {
+    let _ = config::config::ConfigLoader;
+    let _ = config::ConfigError;
+    let _ = config::Config;
+    let _ = storage::BackendStorage::name(&storage::Disk);
+}
@@ -0,0 +1,30 @@
+//! Names that do not repeat the module containing them.
+
+mod config {
+    pub mod loader {
+        pub struct Loader;
+    }
+
+    pub struct Config;
+
+    pub struct Configuration;
+
+    pub enum Error {
+        Missing,
+    }
+}
+
+mod config_store {
+    pub struct Snapshot;
+}
+
+struct MainConfig;
+
+fn main() {
+    let _ = config::loader::Loader;
+    let _ = config::Config;
+    let _ = config::Configuration;
+    let _ = config::Error::Missing;
+    let _ = config_store::Snapshot;
+    let _ = MainConfig;
+}
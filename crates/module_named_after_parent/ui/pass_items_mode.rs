@@ -0,0 +1,11 @@
+//! With `mode = "items"`, modules named after their parent are not reported.
+
+mod config {
+    pub mod config {
+        pub struct Settings;
+    }
+}
+
+fn main() {
+    let _ = config::config::Settings;
+}
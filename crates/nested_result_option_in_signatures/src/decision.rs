@@ -0,0 +1,142 @@
+//! Pure decision logic for `nested_result_option_in_signatures`.
+//!
+//! The driver peels the `Result` and `Option` layers wrapped directly around
+//! one another in each signature type, noting the error type of every
+//! `Result`. [`NestedResultOptionInSignaturesDecision`] applies the same
+//! [`NestingPolicy`] and uses the Fluent selector of the layer
+//! [`likely_collapse`] picks as the finding's reason.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_DEPTH, LINT_NAME};
+use crate::policy::{Collapse, Layer, NestingPolicy, likely_collapse};
+
+/// The facts about one signature type that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignatureTypeProjection {
+    /// The nested layers, outermost first: the error type of each `Result`,
+    /// or `None` for an `Option`.
+    pub layers: Vec<Option<String>>,
+}
+
+/// Reports signature types nesting more than `max_depth` `Result` and
+/// `Option` layers.
+///
+/// # Examples
+///
+/// ```ignore
+/// let ty = SignatureTypeProjection {
+///     layers: vec![Some("io::Error".to_owned()), None, None],
+/// };
+/// let finding = NestedResultOptionInSignaturesDecision::default().decide(&ty);
+/// assert_eq!(finding.map(|finding| finding.reason()), Some("repeated-option"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NestedResultOptionInSignaturesDecision {
+    policy: NestingPolicy,
+}
+
+impl NestedResultOptionInSignaturesDecision {
+    /// Creates a decision accepting at most `max_depth` nested layers.
+    #[must_use]
+    pub const fn new(max_depth: usize) -> Self {
+        Self {
+            policy: NestingPolicy::new(max_depth),
+        }
+    }
+}
+
+impl Default for NestedResultOptionInSignaturesDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_DEPTH)
+    }
+}
+
+impl LintDecision for NestedResultOptionInSignaturesDecision {
+    type Projection = SignatureTypeProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, ty: &SignatureTypeProjection) -> Option<Finding> {
+        if !self.policy.flags(ty.layers.len()) {
+            return None;
+        }
+        let layers: Vec<Layer> = ty
+            .layers
+            .iter()
+            .map(|error| match error {
+                Some(error) => Layer::Result {
+                    error: error.clone(),
+                },
+                None => Layer::Option,
+            })
+            .collect();
+        Some(Finding::new(LINT_NAME, likely_collapse(&layers).key()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<SignatureTypeProjection>> {
+        let deeper = self.policy.max_depth() + 1;
+        let ty = |layer: fn(usize) -> Option<String>, depth: usize| SignatureTypeProjection {
+            layers: (0..depth).map(layer).collect(),
+        };
+        let options = |_| None;
+        let same_error = |_| Some("StoreError".to_owned());
+        let distinct_errors = |index| Some(format!("Error{index}"));
+        vec![
+            DecisionFixture::flagged(
+                "repeated options",
+                ty(options, deeper.max(2)),
+                Collapse::RepeatedOption { layer: 2 }.key(),
+            ),
+            DecisionFixture::flagged(
+                "repeated error type",
+                ty(same_error, deeper.max(2)),
+                Collapse::SameError {
+                    layer: 2,
+                    error: String::new(),
+                }
+                .key(),
+            ),
+            DecisionFixture::flagged(
+                "distinct error types",
+                ty(distinct_errors, deeper.max(2)),
+                Collapse::DistinctErrors {
+                    layer: 2,
+                    error: String::new(),
+                    outer_error: String::new(),
+                }
+                .key(),
+            ),
+            DecisionFixture::clean("at the limit", ty(options, self.policy.max_depth())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn a_single_layer_over_the_limit_needs_an_enum() {
+        let ty = SignatureTypeProjection {
+            layers: vec![Some("io::Error".to_owned())],
+        };
+        let finding = NestedResultOptionInSignaturesDecision::new(0).decide(&ty);
+
+        assert_eq!(
+            finding.map(|finding| finding.reason().to_owned()),
+            Some(Collapse::DedicatedEnum.key().to_owned())
+        );
+    }
+
+    #[rstest]
+    #[case::default_depth(NestedResultOptionInSignaturesDecision::default())]
+    #[case::deeper_limit(NestedResultOptionInSignaturesDecision::new(3))]
+    fn decision_conforms(#[case] decision: NestedResultOptionInSignaturesDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
@@ -0,0 +1,22 @@
+//! Embedded documentation for the `nested_result_option_in_signatures` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `nested_result_option_in_signatures`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "nested_result_option_in_signatures",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags public signatures that nest `Result` and `Option` more than `max_depth` layers deep.",
+    rationale: "Each layer of `Result<Option<Result<..>>>` is another check every caller must make in the right order. Deep nesting usually hides a simpler shape: an error type repeated so `?` could fold it away, an `Option` inside an `Option`, or distinct outcomes that a dedicated enum would name.",
+    bad_example: "pub fn lookup(key: &str) -> Result<Option<Result<Record, StoreError>>, StoreError> {\n    todo!()\n}",
+    good_example: "pub fn lookup(key: &str) -> Result<Option<Record>, StoreError> {\n    todo!()\n}",
+    config: &[ConfigKeyDocs {
+        key: "max_depth",
+        default: "2",
+        description: "Most `Result` and `Option` layers a parameter or return type may nest.",
+    }],
+};
@@ -0,0 +1,473 @@
+//! Lint pass reporting deeply nested `Result` and `Option` signature types.
+//!
+//! The parameters and return types of exported functions, inherent methods,
+//! and trait methods are read from their semantic signatures, so type
+//! aliases such as `io::Result<T>` count as the `Result` they expand to. The
+//! output of an `async fn` is taken from the `Future::Output` bound of its
+//! opaque return type. Each type is peeled from the outside in for as long as
+//! it is a `Result` or `Option`, following the success type, and types nested
+//! deeper than the configured limit are reported at the written type.
+//! Signatures produced by macro expansion are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{Span, Symbol, sym};
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, describe_enclosing_item};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{Collapse, Layer, NestingPolicy, likely_collapse};
+
+pub(crate) const LINT_NAME: &str = "nested_result_option_in_signatures";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_DEPTH: usize = 2;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_depth: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+impl Config {
+    const fn policy(self) -> NestingPolicy {
+        NestingPolicy::new(self.max_depth)
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub NESTED_RESULT_OPTION_IN_SIGNATURES,
+    Warn,
+    "public signatures should not nest `Result` and `Option` beyond a configurable depth",
+    NestedResultOptionInSignatures::default()
+}
+
+/// Lint pass that finds deeply nested `Result` and `Option` signature types.
+#[derive(Default)]
+pub struct NestedResultOptionInSignatures {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Nesting limit, finding limit, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NestedResultOptionInSignatures {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(NESTED_RESULT_OPTION_IN_SIGNATURES, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Fn { sig, .. } = item.kind {
+            self.check_signature(cx, item.owner_id.def_id, &sig);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        if let hir::ImplItemKind::Fn(sig, _) = item.kind
+            && matches!(item.impl_kind, hir::ImplItemImplKind::Inherent { .. })
+        {
+            self.check_signature(cx, item.owner_id.def_id, &sig);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        if let hir::TraitItemKind::Fn(sig, _) = item.kind {
+            self.check_signature(cx, item.owner_id.def_id, &sig);
+        }
+    }
+}
+
+impl NestedResultOptionInSignatures {
+    fn check_signature<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        def_id: LocalDefId,
+        sig: &hir::FnSig<'tcx>,
+    ) {
+        if sig.span.from_expansion() || !cx.effective_visibilities.is_exported(def_id) {
+            return;
+        }
+        let fn_sig = cx.tcx.instantiate_bound_regions_with_erased(
+            cx.tcx
+                .fn_sig(def_id)
+                .instantiate_identity()
+                .skip_normalization(),
+        );
+        let names = cx.tcx.fn_arg_idents(def_id.to_def_id());
+
+        for (index, (hir_ty, &ty)) in sig.decl.inputs.iter().zip(fn_sig.inputs()).enumerate() {
+            let name = names.get(index).copied().flatten().map(|ident| ident.name);
+            let position = TypePosition::Parameter(name.as_ref().map_or("_", Symbol::as_str));
+            self.check_type(cx, SignatureType { hir_ty, position }, ty);
+        }
+        if let hir::FnRetTy::Return(hir_ty) = sig.decl.output {
+            let output = if sig.header.is_async() {
+                async_output(cx, hir_ty)
+            } else {
+                Some(fn_sig.output())
+            };
+            if let Some(ty) = output {
+                let site = SignatureType {
+                    hir_ty,
+                    position: TypePosition::Return,
+                };
+                self.check_type(cx, site, ty);
+            }
+        }
+    }
+
+    fn check_type<'tcx>(&self, cx: &LateContext<'tcx>, site: SignatureType<'_, '_>, ty: Ty<'tcx>) {
+        let SignatureType { hir_ty, position } = site;
+        if hir_ty.span.from_expansion() {
+            return;
+        }
+        let errors = peel_layers(cx, ty);
+        let state = &*self.state;
+        let policy = state
+            .findings
+            .config_at(cx, hir_ty.span, &state.config)
+            .policy();
+        if !policy.flags(errors.len()) {
+            return;
+        }
+
+        let layers: Vec<Layer> = errors
+            .iter()
+            .map(|error| match error {
+                Some(error) => Layer::Result {
+                    error: error.to_string(),
+                },
+                None => Layer::Option,
+            })
+            .collect();
+        let collapse = likely_collapse(&layers);
+        let context = describe_enclosing_item(cx, hir_ty.hir_id).describe(&state.localizer);
+        debug!(
+            target: LINT_NAME,
+            "{} layers nested in a signature type of {context}",
+            layers.len(),
+        );
+        if state.findings.admit(cx, hir_ty.hir_id, hir_ty.span) {
+            let message = NestingMessage {
+                position,
+                context: &context,
+                depth: layers.len(),
+                limit: policy.max_depth(),
+                collapse: &collapse,
+            };
+            emit_diagnostic(cx, hir_ty.span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// A written parameter or return type and where it sits in the signature.
+#[derive(Clone, Copy, Debug)]
+struct SignatureType<'a, 'hir> {
+    hir_ty: &'a hir::Ty<'hir>,
+    position: TypePosition<'a>,
+}
+
+/// Whether a type is the return type or a named parameter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TypePosition<'a> {
+    Return,
+    Parameter(&'a str),
+}
+
+/// Returns the `Future::Output` type of an `async fn` whose lowered return
+/// type is `hir_ty`.
+fn async_output<'tcx>(cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'_>) -> Option<Ty<'tcx>> {
+    let hir::TyKind::OpaqueDef(opaque) = hir_ty.kind else {
+        return None;
+    };
+    cx.tcx
+        .explicit_item_bounds(opaque.def_id.to_def_id())
+        .skip_binder()
+        .iter()
+        .find_map(|(clause, _)| clause.as_projection_clause()?.skip_binder().term.as_type())
+}
+
+/// Peels the `Result` and `Option` layers wrapped around one another at the
+/// top of `ty`, outermost first, following each success type. A layer is
+/// `Some(error)` for a `Result` and `None` for an `Option`.
+fn peel_layers<'tcx>(cx: &LateContext<'tcx>, mut ty: Ty<'tcx>) -> Vec<Option<Ty<'tcx>>> {
+    let mut layers = Vec::new();
+    while let ty::Adt(adt, args) = ty.kind() {
+        if cx.tcx.is_diagnostic_item(sym::Option, adt.did()) {
+            layers.push(None);
+        } else if cx.tcx.is_diagnostic_item(sym::Result, adt.did()) {
+            layers.push(Some(args.type_at(1)));
+        } else {
+            break;
+        }
+        ty = args.type_at(0);
+    }
+    layers
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &NestingMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        NESTED_RESULT_OPTION_IN_SIGNATURES,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one nested type interpolates.
+#[derive(Clone, Copy, Debug)]
+struct NestingMessage<'a> {
+    position: TypePosition<'a>,
+    context: &'a str,
+    depth: usize,
+    limit: usize,
+    collapse: &'a Collapse,
+}
+
+impl NestingMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let (position, parameter) = match self.position {
+            TypePosition::Return => ("return", ""),
+            TypePosition::Parameter(name) => ("parameter", name),
+        };
+        let (layer, error, outer_error) = match self.collapse {
+            Collapse::SameError { layer, error } => (*layer, error.as_str(), ""),
+            Collapse::RepeatedOption { layer } => (*layer, "", ""),
+            Collapse::DistinctErrors {
+                layer,
+                error,
+                outer_error,
+            } => (*layer, error.as_str(), outer_error.as_str()),
+            Collapse::DedicatedEnum => (0, "", ""),
+        };
+
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(Cow::Borrowed("position"), FluentValue::from(position));
+        args.insert(
+            Cow::Borrowed("parameter"),
+            FluentValue::from(parameter.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(self.context.to_owned()),
+        );
+        args.insert(Cow::Borrowed("depth"), FluentValue::from(self.depth));
+        args.insert(Cow::Borrowed("limit"), FluentValue::from(self.limit));
+        args.insert(
+            Cow::Borrowed("collapse"),
+            FluentValue::from(self.collapse.key()),
+        );
+        args.insert(Cow::Borrowed("layer"), FluentValue::from(layer));
+        args.insert(Cow::Borrowed("error"), FluentValue::from(error.to_owned()));
+        args.insert(
+            Cow::Borrowed("outer_error"),
+            FluentValue::from(outer_error.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            context,
+            depth,
+            limit,
+            ..
+        } = *self;
+        let subject = match self.position {
+            TypePosition::Return => "The return type".to_owned(),
+            TypePosition::Parameter(name) => format!("Parameter `{name}`"),
+        };
+        let note = match self.collapse {
+            Collapse::SameError { layer, error } => format!(
+                "Layer {layer} repeats the error type `{error}` of an outer `Result`, so `?` \
+                 can likely fold it into that layer."
+            ),
+            Collapse::RepeatedOption { layer } => format!(
+                "Layer {layer} is an `Option` directly inside another `Option`, so \
+                 `Option::flatten` can likely fold the two together."
+            ),
+            Collapse::DistinctErrors {
+                layer,
+                error,
+                outer_error,
+            } => format!(
+                "Layer {layer} fails with `{error}` inside a `Result` that fails with \
+                 `{outer_error}`, so an error enum covering both would let the two layers \
+                 collapse."
+            ),
+            Collapse::DedicatedEnum => "No layer repeats another, so each encodes a separate \
+                                        outcome that callers must unpick in order."
+                .to_owned(),
+        };
+        DiagnosticMessageSet::new(
+            format!(
+                "{subject} of {context} nests {depth} `Result` and `Option` layers, above the \
+                 limit of {limit}."
+            ),
+            note,
+            "Flatten the type with `?`, `Option::flatten`, or an error enum, or return a \
+             dedicated enum that names each outcome."
+                .to_owned(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message<'a>(position: TypePosition<'a>, collapse: &'a Collapse) -> NestingMessage<'a> {
+        NestingMessage {
+            position,
+            context: "function `lookup`",
+            depth: 3,
+            limit: 2,
+            collapse,
+        }
+    }
+
+    fn collapses() -> [Collapse; 4] {
+        [
+            Collapse::SameError {
+                layer: 3,
+                error: "StoreError".to_owned(),
+            },
+            Collapse::RepeatedOption { layer: 2 },
+            Collapse::DistinctErrors {
+                layer: 3,
+                error: "ParseError".to_owned(),
+                outer_error: "io::Error".to_owned(),
+            },
+            Collapse::DedicatedEnum,
+        ]
+    }
+
+    fn resolve(message: &NestingMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_allows_two_layers() {
+        assert_eq!(Config::default().policy(), NestingPolicy::new(2));
+    }
+
+    #[rstest]
+    #[case(TypePosition::Return)]
+    #[case(TypePosition::Parameter("reply"))]
+    fn english_messages_match_the_fallback(#[case] position: TypePosition<'static>) {
+        for collapse in &collapses() {
+            let message = message(position, collapse);
+
+            assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+        }
+    }
+
+    #[rstest]
+    fn fallback_note_names_the_repeated_error() {
+        let collapse = &collapses()[0];
+        let messages = message(TypePosition::Return, collapse).fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "The return type of function `lookup` nests 3 `Result` and `Option` layers, above \
+             the limit of 2."
+        );
+        assert!(
+            messages
+                .note()
+                .starts_with("Layer 3 repeats the error type `StoreError`")
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let collapse = &collapses()[2];
+        let messages = resolve(&message(TypePosition::Parameter("reply"), collapse), locale);
+
+        assert!(messages.primary().contains("`reply`"));
+        assert!(messages.note().contains("`ParseError`"));
+        assert!(messages.note().contains("`io::Error`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
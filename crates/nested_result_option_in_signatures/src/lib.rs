@@ -0,0 +1,44 @@
+//! Lint crate flagging deeply nested `Result` and `Option` types in public
+//! signatures.
+//!
+//! A return type such as `Result<Option<Result<T, E>>, E>` makes every
+//! caller unpick the layers in order, and usually hides a simpler shape: a
+//! repeated error type that `?` would fold away, an `Option` inside an
+//! `Option`, or a set of outcomes that deserves its own enum. The lint counts
+//! the `Result` and `Option` layers wrapped directly around one another in
+//! the parameters and return types of exported functions and methods, and
+//! reports any type nested deeper than `max_depth`. The lint is experimental
+//! and ships behind the `experimental-nested-result-option-in-signatures`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{NestedResultOptionInSignaturesDecision, SignatureTypeProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn nested_result_option_in_signatures_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,216 @@
+//! Rules deciding which nested types are reported and which layer likely
+//! collapses.
+//!
+//! The driver peels the `Result` and `Option` layers wrapped directly around
+//! one another in a signature type, outermost first, and describes each as a
+//! [`Layer`]. [`NestingPolicy`] decides whether the nesting is too deep, and
+//! [`likely_collapse`] picks the layer the diagnostic points at. The rules
+//! are kept free of compiler types so they can be tested directly.
+
+/// One `Result` or `Option` layer of a signature type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Layer {
+    /// An `Option<T>`.
+    Option,
+    /// A `Result<T, E>` failing with `error`.
+    Result {
+        /// The error type as rendered in the diagnostic.
+        error: String,
+    },
+}
+
+impl Layer {
+    fn error(&self) -> Option<&str> {
+        match self {
+            Self::Option => None,
+            Self::Result { error } => Some(error),
+        }
+    }
+}
+
+/// How deep `Result` and `Option` layers may nest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct NestingPolicy {
+    max_depth: usize,
+}
+
+impl NestingPolicy {
+    /// Creates a policy accepting at most `max_depth` nested layers.
+    #[must_use]
+    pub(crate) const fn new(max_depth: usize) -> Self {
+        Self { max_depth }
+    }
+
+    /// Returns `true` when `depth` layers exceed the limit.
+    #[must_use]
+    pub(crate) const fn flags(self, depth: usize) -> bool {
+        depth > self.max_depth
+    }
+
+    /// The configured limit.
+    #[must_use]
+    pub(crate) const fn max_depth(self) -> usize {
+        self.max_depth
+    }
+}
+
+/// The layer a reported type most likely sheds, and why. Layers are numbered
+/// from one, outermost first.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Collapse {
+    /// A `Result` repeats the error type of an outer `Result`.
+    SameError {
+        /// The inner of the two layers.
+        layer: usize,
+        /// The shared error type.
+        error: String,
+    },
+    /// An `Option` sits directly inside another `Option`.
+    RepeatedOption {
+        /// The inner of the two layers.
+        layer: usize,
+    },
+    /// A `Result` sits inside a `Result` with a different error type.
+    DistinctErrors {
+        /// The inner of the two layers.
+        layer: usize,
+        /// The error type of the inner layer.
+        error: String,
+        /// The error type of the outer layer.
+        outer_error: String,
+    },
+    /// No two layers overlap, so the type is best replaced by an enum.
+    DedicatedEnum,
+}
+
+impl Collapse {
+    /// The Fluent selector naming this case.
+    #[must_use]
+    pub(crate) const fn key(&self) -> &'static str {
+        match self {
+            Self::SameError { .. } => "same-error",
+            Self::RepeatedOption { .. } => "repeated-option",
+            Self::DistinctErrors { .. } => "distinct-errors",
+            Self::DedicatedEnum => "dedicated-enum",
+        }
+    }
+}
+
+/// Picks the layer of `layers`, outermost first, that most likely collapses.
+///
+/// A repeated error type wins, because `?` folds it away without a new type;
+/// adjacent `Option`s come next, then `Result`s whose error types differ.
+///
+/// # Examples
+///
+/// ```ignore
+/// let error = || Layer::Result { error: "StoreError".into() };
+/// let collapse = likely_collapse(&[error(), Layer::Option, error()]);
+/// assert_eq!(collapse, Collapse::SameError { layer: 3, error: "StoreError".into() });
+/// ```
+#[must_use]
+pub(crate) fn likely_collapse(layers: &[Layer]) -> Collapse {
+    let errors: Vec<(usize, &str)> = layers
+        .iter()
+        .enumerate()
+        .filter_map(|(index, layer)| layer.error().map(|error| (index + 1, error)))
+        .collect();
+
+    for (position, &(layer, error)) in errors.iter().enumerate() {
+        if errors[..position].iter().any(|&(_, outer)| outer == error) {
+            return Collapse::SameError {
+                layer,
+                error: error.to_owned(),
+            };
+        }
+    }
+    if let Some(index) = layers
+        .windows(2)
+        .position(|pair| matches!(pair, [Layer::Option, Layer::Option]))
+    {
+        return Collapse::RepeatedOption { layer: index + 2 };
+    }
+    match errors.as_slice() {
+        [(_, outer_error), (layer, error), ..] => Collapse::DistinctErrors {
+            layer: *layer,
+            error: (*error).to_owned(),
+            outer_error: (*outer_error).to_owned(),
+        },
+        _ => Collapse::DedicatedEnum,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn result(error: &str) -> Layer {
+        Layer::Result {
+            error: error.to_owned(),
+        }
+    }
+
+    #[rstest]
+    #[case(2, false)]
+    #[case(3, true)]
+    fn policy_flags_depth_above_the_limit(#[case] depth: usize, #[case] flagged: bool) {
+        assert_eq!(NestingPolicy::new(2).flags(depth), flagged);
+    }
+
+    #[rstest]
+    fn repeated_error_collapses_the_inner_result() {
+        let layers = [result("StoreError"), Layer::Option, result("StoreError")];
+
+        assert_eq!(
+            likely_collapse(&layers),
+            Collapse::SameError {
+                layer: 3,
+                error: "StoreError".to_owned(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn repeated_error_wins_over_adjacent_options() {
+        let layers = [
+            result("io::Error"),
+            Layer::Option,
+            Layer::Option,
+            result("io::Error"),
+        ];
+
+        assert_eq!(likely_collapse(&layers).key(), "same-error");
+    }
+
+    #[rstest]
+    fn adjacent_options_collapse_the_inner_option() {
+        let layers = [result("io::Error"), Layer::Option, Layer::Option];
+
+        assert_eq!(
+            likely_collapse(&layers),
+            Collapse::RepeatedOption { layer: 3 }
+        );
+    }
+
+    #[rstest]
+    fn distinct_errors_name_both_error_types() {
+        let layers = [result("io::Error"), Layer::Option, result("ParseError")];
+
+        assert_eq!(
+            likely_collapse(&layers),
+            Collapse::DistinctErrors {
+                layer: 3,
+                error: "ParseError".to_owned(),
+                outer_error: "io::Error".to_owned(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn alternating_layers_suggest_a_dedicated_enum() {
+        let layers = [Layer::Option, result("io::Error"), Layer::Option];
+
+        assert_eq!(likely_collapse(&layers), Collapse::DedicatedEnum);
+    }
+}
@@ -0,0 +1,111 @@
+//! Behaviour-driven coverage for the nesting policy and collapse hints.
+
+use crate::policy::{Collapse, Layer, NestingPolicy, likely_collapse};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct NestingWorld {
+    layers: RefCell<Vec<Layer>>,
+    flagged: Cell<Option<bool>>,
+    collapse: RefCell<Option<Collapse>>,
+}
+
+#[fixture]
+fn world() -> NestingWorld {
+    NestingWorld::default()
+}
+
+#[given("a Result layer failing with {error}")]
+fn given_result(world: &NestingWorld, error: String) {
+    world.layers.borrow_mut().push(Layer::Result {
+        error: error.trim_matches('"').to_owned(),
+    });
+}
+
+#[given("an Option layer")]
+fn given_option(world: &NestingWorld) {
+    world.layers.borrow_mut().push(Layer::Option);
+}
+
+#[when("the type is checked")]
+fn when_checked(world: &NestingWorld) {
+    let layers = world.layers.borrow();
+    world
+        .flagged
+        .set(Some(NestingPolicy::new(2).flags(layers.len())));
+    *world.collapse.borrow_mut() = Some(likely_collapse(&layers));
+}
+
+#[then("the type is reported")]
+fn then_reported(world: &NestingWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the type is allowed")]
+fn then_allowed(world: &NestingWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[then("layer {layer} likely collapses as {key}")]
+fn then_layer_collapses(world: &NestingWorld, layer: usize, key: String) {
+    let collapse = world.collapse.borrow();
+    let collapse = collapse
+        .as_ref()
+        .expect("the type should have been checked");
+    let found = match collapse {
+        Collapse::SameError { layer, .. }
+        | Collapse::RepeatedOption { layer }
+        | Collapse::DistinctErrors { layer, .. } => Some(*layer),
+        Collapse::DedicatedEnum => None,
+    };
+
+    assert_eq!(collapse.key(), key.trim_matches('"'));
+    assert_eq!(found, Some(layer));
+}
+
+#[then("no single layer likely collapses")]
+fn then_dedicated_enum(world: &NestingWorld) {
+    assert_eq!(*world.collapse.borrow(), Some(Collapse::DedicatedEnum));
+}
+
+#[scenario(
+    path = "tests/features/nested_result_option_in_signatures.feature",
+    index = 0
+)]
+fn scenario_within_limit(world: NestingWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/nested_result_option_in_signatures.feature",
+    index = 1
+)]
+fn scenario_repeated_error(world: NestingWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/nested_result_option_in_signatures.feature",
+    index = 2
+)]
+fn scenario_adjacent_options(world: NestingWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/nested_result_option_in_signatures.feature",
+    index = 3
+)]
+fn scenario_distinct_errors(world: NestingWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/nested_result_option_in_signatures.feature",
+    index = 4
+)]
+fn scenario_dedicated_enum(world: NestingWorld) {
+    let _ = world;
+}
@@ -0,0 +1,42 @@
+Feature: Nested Result and Option layers in public signatures
+  Signature types nesting more `Result` and `Option` layers than
+  `max_depth` are reported, and the diagnostic points at the layer that
+  most likely collapses.
+
+  Scenario: Two layers are within the default limit
+    Given a Result layer failing with "StoreError"
+    And an Option layer
+    When the type is checked
+    Then the type is allowed
+
+  Scenario: A repeated error type collapses into the outer Result
+    Given a Result layer failing with "StoreError"
+    And an Option layer
+    And a Result layer failing with "StoreError"
+    When the type is checked
+    Then the type is reported
+    And layer 3 likely collapses as "same-error"
+
+  Scenario: Adjacent Options collapse into one
+    Given a Result layer failing with "StoreError"
+    And an Option layer
+    And an Option layer
+    When the type is checked
+    Then the type is reported
+    And layer 3 likely collapses as "repeated-option"
+
+  Scenario: Distinct error types call for an error enum
+    Given a Result layer failing with "io::Error"
+    And an Option layer
+    And a Result layer failing with "ParseError"
+    When the type is checked
+    Then the type is reported
+    And layer 3 likely collapses as "distinct-errors"
+
+  Scenario: Alternating layers call for a dedicated enum
+    Given an Option layer
+    And a Result layer failing with "StoreError"
+    And an Option layer
+    When the type is checked
+    Then the type is reported
+    And no single layer likely collapses
@@ -0,0 +1,20 @@
+//! Public signatures nesting more than two `Result` and `Option` layers.
+
+pub struct Record;
+
+pub struct StoreError;
+
+pub fn lookup(key: &str) -> Result<Option<Result<Record, StoreError>>, StoreError> {
+    let _ = key;
+    Ok(None)
+}
+
+pub struct Cache;
+
+impl Cache {
+    pub fn prime(&self, records: Option<Option<Option<Record>>>) -> bool {
+        records.is_some()
+    }
+}
+
+fn main() {}
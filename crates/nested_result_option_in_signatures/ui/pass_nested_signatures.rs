@@ -0,0 +1,31 @@
+//! Shallow public signatures and deeply nested private ones are accepted.
+
+use std::io;
+
+pub struct Record;
+
+pub fn load(path: &str) -> io::Result<Option<Record>> {
+    let _ = path;
+    Ok(None)
+}
+
+pub fn first(records: Vec<Option<Result<Record, io::Error>>>) -> Option<Record> {
+    records.into_iter().flatten().flatten().next()
+}
+
+fn probe() -> Result<Option<Option<Record>>, io::Error> {
+    Ok(None)
+}
+
+struct Scratch;
+
+impl Scratch {
+    pub fn peek(&self) -> Option<Option<Option<Record>>> {
+        None
+    }
+}
+
+fn main() {
+    let _ = probe();
+    let _ = Scratch.peek();
+}
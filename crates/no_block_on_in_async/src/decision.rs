@@ -0,0 +1,111 @@
+//! Pure decision logic for `no_block_on_in_async`.
+//!
+//! The driver names each callee by its path and finds the async function,
+//! block, or closure enclosing the call. [`NoBlockOnInAsyncDecision`]
+//! matches the path against the same [`BlockingFunctions`] and reports calls
+//! inside async code. Findings use the kind of async code as their reason
+//! and the matching entry as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::BlockingFunctions;
+
+/// The kinds of async code a blocking call can sit in, which double as the
+/// findings' reasons.
+const ASYNC_CONTEXTS: [&str; 3] = ["function", "block", "closure"];
+
+/// The facts about one call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallProjection {
+    /// The callee's path, such as `tokio::runtime::Runtime::block_on`.
+    pub callee: String,
+    /// The async code enclosing the call, `function`, `block`, or
+    /// `closure`, or `None` outside async code.
+    pub context: Option<String>,
+}
+
+/// Reports blocking entry points called from async code.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = CallProjection {
+///     callee: "futures::executor::block_on".to_owned(),
+///     context: Some("function".to_owned()),
+/// };
+/// assert!(NoBlockOnInAsyncDecision::default().decide(&call).is_some());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NoBlockOnInAsyncDecision {
+    blocking: BlockingFunctions,
+}
+
+impl NoBlockOnInAsyncDecision {
+    /// Creates a decision reporting the entry points named by `paths`,
+    /// matched in full or by trailing path segments.
+    #[must_use]
+    pub fn new(paths: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            blocking: BlockingFunctions::new(paths),
+        }
+    }
+}
+
+impl LintDecision for NoBlockOnInAsyncDecision {
+    type Projection = CallProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, call: &CallProjection) -> Option<Finding> {
+        let entry = self.blocking.matching(&call.callee)?;
+        let context = ASYNC_CONTEXTS
+            .into_iter()
+            .find(|context| call.context.as_deref() == Some(*context))?;
+        Some(Finding::new(LINT_NAME, context).with_subject(entry))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<CallProjection>> {
+        let call = |callee: &str, context: Option<&str>| CallProjection {
+            callee: callee.to_owned(),
+            context: context.map(str::to_owned),
+        };
+        vec![
+            DecisionFixture::flagged(
+                "block_on in an async function",
+                call("futures::executor::block_on", Some("function")),
+                "function",
+            ),
+            DecisionFixture::flagged(
+                "runtime method in an async block",
+                call("tokio::runtime::Runtime::block_on", Some("block")),
+                "block",
+            ),
+            DecisionFixture::clean(
+                "block_on in synchronous code",
+                call("futures::executor::block_on", None),
+            ),
+            DecisionFixture::clean(
+                "unrelated call in an async closure",
+                call("std::mem::drop", Some("closure")),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&NoBlockOnInAsyncDecision::default()),
+            Ok(4)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Embedded documentation for the `no_block_on_in_async` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_block_on_in_async`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_block_on_in_async",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `block_on` and other configured blocking entry points called from async functions, blocks, and closures.",
+    rationale: "Blocking on a future parks the thread that should be driving the runtime. A single-threaded executor can then never run the awaited work and deadlocks, while a multi-threaded one stalls its other tasks or panics.",
+    bad_example: concat!(
+        "async fn refresh(handle: Handle) -> Config {\n",
+        "    handle.block_on(fetch_config())\n",
+        "}",
+    ),
+    good_example: concat!(
+        "async fn refresh() -> Config {\n",
+        "    fetch_config().await\n",
+        "}",
+    ),
+    config: &[ConfigKeyDocs {
+        key: "blocking_functions",
+        default: "[\"futures::executor::block_on\", \"futures_executor::block_on\", \"Runtime::block_on\", \"Handle::block_on\"]",
+        description: "Paths of blocking entry points, matched in full or by their trailing segments. Setting the list replaces the defaults.",
+    }],
+};
@@ -0,0 +1,311 @@
+//! Detect blocking executor calls made from async code.
+//!
+//! The lint resolves each function and method call to its path and checks it
+//! against `blocking_functions`. A matching call is reported when the nearest
+//! enclosing closure is an async function body, async block, or async
+//! closure. A plain closure ends the search, so handing a blocking call to
+//! `spawn_blocking` or a thread from inside async code is accepted, as is any
+//! call in a nested item. Calls produced by macro expansion are skipped,
+//! which keeps the `block_on` that `#[tokio::main]` wraps around `main` out of
+//! reach.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{ClosureKind, CoroutineDesugaring, CoroutineKind, CoroutineSource, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use rustc_span::def_id::DefId;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{BlockingFunctions, DEFAULT_BLOCKING_FUNCTIONS};
+
+pub(crate) const LINT_NAME: &str = "no_block_on_in_async";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    blocking_functions: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            blocking_functions: DEFAULT_BLOCKING_FUNCTIONS
+                .iter()
+                .map(|path| (*path).to_owned())
+                .collect(),
+        }
+    }
+}
+
+/// Lint pass reporting blocking executor calls inside async code.
+#[derive(Default)]
+pub struct NoBlockOnInAsync {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Blocking functions and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    blocking: BlockingFunctions,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_BLOCK_ON_IN_ASYNC,
+    Warn,
+    "blocking on a future inside async code stalls the runtime and deadlocks single-threaded executors",
+    NoBlockOnInAsync::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoBlockOnInAsync {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            blocking: BlockingFunctions::new(load_configuration().blocking_functions),
+            findings: FindingLimit::new(NO_BLOCK_ON_IN_ASYNC, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(def_id) = callee_def_id(cx, expr) else {
+            return;
+        };
+        let callee = cx.tcx.def_path_str(def_id);
+        if self.state.blocking.matching(&callee).is_none() {
+            return;
+        }
+        let Some(context) = async_context(cx, expr.hir_id) else {
+            return;
+        };
+
+        debug!(target: LINT_NAME, "`{callee}` called inside an async {context:?}");
+        if self.state.findings.admit(cx, expr.hir_id, expr.span) {
+            emit_diagnostic(
+                cx,
+                expr.span,
+                &BlockingCall { callee, context },
+                &self.state.localizer,
+            );
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the function or method `expr` calls, if it is a call.
+fn callee_def_id(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<DefId> {
+    match expr.kind {
+        ExprKind::Call(callee, _) => {
+            let ExprKind::Path(ref qpath) = callee.kind else {
+                return None;
+            };
+            match cx.qpath_res(qpath, callee.hir_id) {
+                Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => Some(def_id),
+                _ => None,
+            }
+        }
+        ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+        _ => None,
+    }
+}
+
+/// The kind of async code a blocking call sits in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum AsyncContext {
+    Function,
+    Block,
+    Closure,
+}
+
+impl AsyncContext {
+    const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Block => "block",
+            Self::Closure => "closure",
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Function => "an async function",
+            Self::Block => "an async block",
+            Self::Closure => "an async closure",
+        }
+    }
+}
+
+/// Returns the async code enclosing `hir_id`, stopping at the nearest closure
+/// or item.
+fn async_context(cx: &LateContext<'_>, hir_id: hir::HirId) -> Option<AsyncContext> {
+    for (_, node) in cx.tcx.hir_parent_iter(hir_id) {
+        match node {
+            Node::Expr(hir::Expr {
+                kind: ExprKind::Closure(closure),
+                ..
+            }) => return closure_async_context(closure.kind),
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::ForeignItem(_) => {
+                return None;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+const fn closure_async_context(kind: ClosureKind) -> Option<AsyncContext> {
+    match kind {
+        ClosureKind::Coroutine(CoroutineKind::Desugared(
+            CoroutineDesugaring::Async | CoroutineDesugaring::AsyncGen,
+            source,
+        )) => Some(match source {
+            CoroutineSource::Fn => AsyncContext::Function,
+            CoroutineSource::Block => AsyncContext::Block,
+            CoroutineSource::Closure => AsyncContext::Closure,
+        }),
+        ClosureKind::CoroutineClosure(
+            CoroutineDesugaring::Async | CoroutineDesugaring::AsyncGen,
+        ) => Some(AsyncContext::Closure),
+        _ => None,
+    }
+}
+
+/// A blocking call found inside async code.
+#[derive(Clone, Debug)]
+struct BlockingCall {
+    callee: String,
+    context: AsyncContext,
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, span: Span, call: &BlockingCall, localizer: &Localizer) {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("callee"),
+        FluentValue::from(call.callee.clone()),
+    );
+    args.insert(
+        Cow::Borrowed("context"),
+        FluentValue::from(call.context.fluent_kind()),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(call)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        NO_BLOCK_ON_IN_ASYNC,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(call: &BlockingCall) -> DiagnosticMessageSet {
+    let BlockingCall { callee, context } = call;
+    DiagnosticMessageSet::new(
+        format!(
+            "`{callee}` blocks the thread inside {}.",
+            context.description()
+        ),
+        String::from(
+            "The blocked thread cannot drive the runtime, so a single-threaded executor \
+             deadlocks and a multi-threaded one stalls its other tasks.",
+        ),
+        String::from(
+            "`.await` the future instead, or move the blocking work into `spawn_blocking` or a \
+             dedicated thread.",
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_lists_the_known_entry_points() {
+        assert_eq!(
+            Config::default().blocking_functions,
+            DEFAULT_BLOCKING_FUNCTIONS
+        );
+    }
+
+    #[rstest]
+    #[case::function(CoroutineSource::Fn, AsyncContext::Function)]
+    #[case::block(CoroutineSource::Block, AsyncContext::Block)]
+    #[case::closure(CoroutineSource::Closure, AsyncContext::Closure)]
+    fn async_coroutines_are_async_contexts(
+        #[case] source: CoroutineSource,
+        #[case] expected: AsyncContext,
+    ) {
+        let kind =
+            ClosureKind::Coroutine(CoroutineKind::Desugared(CoroutineDesugaring::Async, source));
+
+        assert_eq!(closure_async_context(kind), Some(expected));
+    }
+
+    #[rstest]
+    fn plain_closures_end_the_async_context() {
+        assert_eq!(closure_async_context(ClosureKind::Closure), None);
+    }
+
+    #[rstest]
+    fn gen_blocks_are_not_async_contexts() {
+        let kind = ClosureKind::Coroutine(CoroutineKind::Desugared(
+            CoroutineDesugaring::Gen,
+            CoroutineSource::Block,
+        ));
+
+        assert_eq!(closure_async_context(kind), None);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,42 @@
+//! Lint crate flagging blocking executor calls inside async code.
+//!
+//! Calling `futures::executor::block_on`, `Runtime::block_on`, or
+//! `Handle::block_on` from an async function or block parks the thread that
+//! should be driving the runtime. On a single-threaded runtime the awaited
+//! work can never run, so the call deadlocks; on a multi-threaded one it
+//! starves other tasks or panics. The lint reports such calls and suggests
+//! awaiting the future or moving the blocking work to `spawn_blocking`. The
+//! list of blocking entry points is configurable. It is experimental and
+//! ships behind the `experimental-no-block-on-in-async` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{CallProjection, NoBlockOnInAsyncDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_block_on_in_async_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,94 @@
+//! Matching callees against the configured blocking entry points.
+//!
+//! The driver names each callee by its path, such as
+//! `tokio::runtime::Runtime::block_on`. An entry in `blocking_functions`
+//! matches that path in full or as a trailing run of segments, so
+//! `Runtime::block_on` matches Tokio's method without naming its module.
+
+/// Blocking entry points reported when `blocking_functions` is not set.
+pub(crate) const DEFAULT_BLOCKING_FUNCTIONS: &[&str] = &[
+    "futures::executor::block_on",
+    "futures_executor::block_on",
+    "Runtime::block_on",
+    "Handle::block_on",
+];
+
+/// The configured blocking entry points.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct BlockingFunctions {
+    paths: Vec<String>,
+}
+
+impl Default for BlockingFunctions {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_BLOCKING_FUNCTIONS
+                .iter()
+                .map(|path| (*path).to_owned()),
+        )
+    }
+}
+
+impl BlockingFunctions {
+    /// Builds the list from configured paths.
+    pub(crate) fn new(paths: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            paths: paths.into_iter().collect(),
+        }
+    }
+
+    /// Returns the entry matching `callee`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let blocking = BlockingFunctions::default();
+    /// assert_eq!(
+    ///     blocking.matching("tokio::runtime::Runtime::block_on"),
+    ///     Some("Runtime::block_on"),
+    /// );
+    /// ```
+    pub(crate) fn matching(&self, callee: &str) -> Option<&str> {
+        self.paths
+            .iter()
+            .find(|path| {
+                callee == path.as_str()
+                    || callee
+                        .strip_suffix(path.as_str())
+                        .is_some_and(|prefix| prefix.ends_with("::"))
+            })
+            .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::futures("futures::executor::block_on", Some("futures::executor::block_on"))]
+    #[case::futures_executor("futures_executor::block_on", Some("futures_executor::block_on"))]
+    #[case::tokio_runtime("tokio::runtime::Runtime::block_on", Some("Runtime::block_on"))]
+    #[case::tokio_handle("tokio::runtime::Handle::block_on", Some("Handle::block_on"))]
+    #[case::partial_segment("tokio::runtime::MyRuntime::block_on", None)]
+    #[case::other_method("tokio::runtime::Runtime::spawn", None)]
+    #[case::bare_name("block_on", None)]
+    fn default_list_matches_known_entry_points(
+        #[case] callee: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(BlockingFunctions::default().matching(callee), expected);
+    }
+
+    #[rstest]
+    fn configured_list_replaces_the_defaults() {
+        let blocking = BlockingFunctions::new([String::from("pollster::block_on")]);
+
+        assert_eq!(
+            blocking.matching("pollster::block_on"),
+            Some("pollster::block_on")
+        );
+        assert_eq!(blocking.matching("tokio::runtime::Runtime::block_on"), None);
+    }
+}
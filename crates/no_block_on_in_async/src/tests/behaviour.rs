@@ -0,0 +1,113 @@
+//! Behaviour-driven coverage for blocking calls inside async code.
+
+use super::{BlockingCall, closure_async_context, fallback_messages};
+use crate::policy::BlockingFunctions;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use rustc_hir::{ClosureKind, CoroutineDesugaring, CoroutineKind, CoroutineSource};
+use std::cell::RefCell;
+use whitaker_common::i18n::DiagnosticMessageSet;
+
+struct BlockOnWorld {
+    callee: RefCell<String>,
+    enclosing: RefCell<ClosureKind>,
+    blocking: RefCell<BlockingFunctions>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> BlockOnWorld {
+    BlockOnWorld {
+        callee: RefCell::new(String::new()),
+        enclosing: RefCell::new(ClosureKind::Closure),
+        blocking: RefCell::new(BlockingFunctions::default()),
+        messages: RefCell::new(None),
+    }
+}
+
+const fn async_coroutine(source: CoroutineSource) -> ClosureKind {
+    ClosureKind::Coroutine(CoroutineKind::Desugared(CoroutineDesugaring::Async, source))
+}
+
+#[given("a call to {callee}")]
+fn given_call(world: &BlockOnWorld, callee: String) {
+    *world.callee.borrow_mut() = callee;
+}
+
+#[given("the call sits in an async function")]
+fn given_async_function(world: &BlockOnWorld) {
+    *world.enclosing.borrow_mut() = async_coroutine(CoroutineSource::Fn);
+}
+
+#[given("the call sits in an async block")]
+fn given_async_block(world: &BlockOnWorld) {
+    *world.enclosing.borrow_mut() = async_coroutine(CoroutineSource::Block);
+}
+
+#[given("the call sits in an async closure")]
+fn given_async_closure(world: &BlockOnWorld) {
+    *world.enclosing.borrow_mut() = async_coroutine(CoroutineSource::Closure);
+}
+
+#[given("the call sits in a plain closure")]
+fn given_plain_closure(world: &BlockOnWorld) {
+    *world.enclosing.borrow_mut() = ClosureKind::Closure;
+}
+
+#[given("the blocking functions are {path}")]
+fn given_blocking_functions(world: &BlockOnWorld, path: String) {
+    *world.blocking.borrow_mut() = BlockingFunctions::new([path]);
+}
+
+#[when("the call is checked")]
+fn when_checked(world: &BlockOnWorld) {
+    let callee = world.callee.borrow().clone();
+    let listed = world.blocking.borrow().matching(&callee).is_some();
+    let messages = closure_async_context(*world.enclosing.borrow())
+        .filter(|_| listed)
+        .map(|context| fallback_messages(&BlockingCall { callee, context }));
+    *world.messages.borrow_mut() = messages;
+}
+
+#[then("the call is accepted")]
+fn then_accepted(world: &BlockOnWorld) {
+    assert!(world.messages.borrow().is_none());
+}
+
+#[then("the call is reported inside {context}")]
+fn then_reported(world: &BlockOnWorld, context: String) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    let callee = world.callee.borrow();
+    assert!(
+        messages
+            .primary()
+            .starts_with(&format!("`{callee}` blocks the thread"))
+    );
+    assert!(messages.primary().contains(&format!("inside {context}.")));
+}
+
+#[scenario(path = "tests/features/no_block_on_in_async.feature", index = 0)]
+fn scenario_runtime_in_async_function(world: BlockOnWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_block_on_in_async.feature", index = 1)]
+fn scenario_futures_in_async_block(world: BlockOnWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_block_on_in_async.feature", index = 2)]
+fn scenario_plain_closure(world: BlockOnWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_block_on_in_async.feature", index = 3)]
+fn scenario_unlisted_function(world: BlockOnWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_block_on_in_async.feature", index = 4)]
+fn scenario_configured_list(world: BlockOnWorld) {
+    let _ = world;
+}
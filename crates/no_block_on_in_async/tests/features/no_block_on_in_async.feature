@@ -0,0 +1,34 @@
+Feature: Blocking calls inside async code
+  Calls to a configured blocking entry point are reported when the nearest
+  enclosing closure is async code. A plain closure ends the async context.
+
+  Scenario: Runtime::block_on inside an async function is reported
+    Given a call to tokio::runtime::Runtime::block_on
+    And the call sits in an async function
+    When the call is checked
+    Then the call is reported inside an async function
+
+  Scenario: futures block_on inside an async block is reported
+    Given a call to futures::executor::block_on
+    And the call sits in an async block
+    When the call is checked
+    Then the call is reported inside an async block
+
+  Scenario: A plain closure inside async code is accepted
+    Given a call to tokio::runtime::Handle::block_on
+    And the call sits in a plain closure
+    When the call is checked
+    Then the call is accepted
+
+  Scenario: Unlisted functions are accepted
+    Given a call to tokio::task::spawn
+    And the call sits in an async function
+    When the call is checked
+    Then the call is accepted
+
+  Scenario: A configured list replaces the defaults
+    Given a call to pollster::block_on
+    And the blocking functions are pollster::block_on
+    And the call sits in an async closure
+    When the call is checked
+    Then the call is reported inside an async closure
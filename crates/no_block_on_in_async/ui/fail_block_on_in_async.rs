@@ -0,0 +1,54 @@
+//! Negative UI fixture: blocking entry points called from async code.
+#![warn(no_block_on_in_async)]
+
+use std::future::Future;
+
+mod futures {
+    pub mod executor {
+        pub fn block_on<F: std::future::Future>(_future: F) -> F::Output {
+            unimplemented!()
+        }
+    }
+}
+
+mod runtime {
+    pub struct Runtime;
+
+    impl Runtime {
+        pub fn block_on<F: std::future::Future>(&self, _future: F) -> F::Output {
+            unimplemented!()
+        }
+    }
+
+    pub struct Handle;
+
+    impl Handle {
+        pub fn block_on<F: std::future::Future>(&self, _future: F) -> F::Output {
+            unimplemented!()
+        }
+    }
+}
+
+async fn fetch() -> u32 {
+    7
+}
+
+async fn in_async_fn(runtime: &runtime::Runtime) -> u32 {
+    runtime.block_on(fetch())
+}
+
+fn in_async_block(handle: runtime::Handle) -> impl Future<Output = u32> {
+    async move { handle.block_on(fetch()) }
+}
+
+fn in_async_closure() -> u32 {
+    let load = async || futures::executor::block_on(fetch());
+    let _ = load;
+    0
+}
+
+fn main() {
+    let _ = in_async_fn(&runtime::Runtime);
+    let _ = in_async_block(runtime::Handle);
+    let _ = in_async_closure();
+}
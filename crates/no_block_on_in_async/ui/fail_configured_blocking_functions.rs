@@ -0,0 +1,33 @@
+//! Negative UI fixture: a configured list replaces the default entry points.
+#![warn(no_block_on_in_async)]
+
+mod futures {
+    pub mod executor {
+        pub fn block_on<F: std::future::Future>(_future: F) -> F::Output {
+            unimplemented!()
+        }
+    }
+}
+
+mod pollster {
+    pub fn block_on<F: std::future::Future>(_future: F) -> F::Output {
+        unimplemented!()
+    }
+}
+
+async fn fetch() -> u32 {
+    7
+}
+
+async fn configured() -> u32 {
+    pollster::block_on(fetch())
+}
+
+async fn no_longer_listed() -> u32 {
+    futures::executor::block_on(fetch())
+}
+
+fn main() {
+    let _ = configured();
+    let _ = no_longer_listed();
+}
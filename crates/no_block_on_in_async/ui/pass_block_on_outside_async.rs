@@ -0,0 +1,45 @@
+//! Positive UI fixture: blocking calls outside async code are accepted.
+#![warn(no_block_on_in_async)]
+
+mod futures {
+    pub mod executor {
+        pub fn block_on<F: std::future::Future>(_future: F) -> F::Output {
+            unimplemented!()
+        }
+    }
+}
+
+fn spawn_blocking<T>(work: impl FnOnce() -> T) -> T {
+    work()
+}
+
+async fn fetch() -> u32 {
+    7
+}
+
+fn sync_entry_point() -> u32 {
+    futures::executor::block_on(fetch())
+}
+
+async fn hands_off_to_a_blocking_closure() -> u32 {
+    spawn_blocking(|| futures::executor::block_on(fetch()))
+}
+
+async fn nested_item() -> u32 {
+    fn helper() -> u32 {
+        futures::executor::block_on(fetch())
+    }
+    helper()
+}
+
+#[allow(no_block_on_in_async)]
+async fn allowed() -> u32 {
+    futures::executor::block_on(fetch())
+}
+
+fn main() {
+    let _ = sync_entry_point();
+    let _ = hands_off_to_a_blocking_closure();
+    let _ = nested_item();
+    let _ = allowed();
+}
@@ -0,0 +1,140 @@
+//! Pure decision logic for `no_detached_threads`.
+//!
+//! The driver finds statements discarding the result of a call, peels any
+//! `Result` or `Option` around the discarded type, and names the module the
+//! statement sits in. [`NoDetachedThreadsDecision`] reports the discard when
+//! [`is_join_handle`] recognises the type and the module is not allowed.
+//! Findings use the [`Discard`] Fluent selector as their reason and the
+//! module as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::path::AllowedModules;
+
+use crate::driver::LINT_NAME;
+use crate::policy::{Discard, is_join_handle};
+
+/// The ways a statement can discard a value.
+const DISCARDS: [Discard; 3] = [Discard::Binding, Discard::Statement, Discard::Drop];
+
+/// The facts about one discarding statement that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiscardProjection {
+    /// How the value is discarded: `binding`, `statement`, or `drop`.
+    pub discard: String,
+    /// Whether the discarded value comes from a call.
+    pub from_call: bool,
+    /// The path of the discarded type, inside any `Result` or `Option`.
+    pub type_path: String,
+    /// The module the statement sits in, relative to the crate root.
+    pub module: String,
+}
+
+/// Reports spawned threads whose `JoinHandle` is discarded outside the
+/// allowed modules.
+///
+/// # Examples
+///
+/// ```ignore
+/// let spawn = DiscardProjection {
+///     discard: "binding".to_owned(),
+///     from_call: true,
+///     type_path: "std::thread::JoinHandle".to_owned(),
+///     module: "worker".to_owned(),
+/// };
+/// assert!(NoDetachedThreadsDecision::default().decide(&spawn).is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NoDetachedThreadsDecision {
+    allowed_modules: AllowedModules,
+}
+
+impl NoDetachedThreadsDecision {
+    /// Creates a decision accepting detached threads in modules matching
+    /// `allowed_modules`.
+    #[must_use]
+    pub fn new(allowed_modules: &[String]) -> Self {
+        Self {
+            allowed_modules: AllowedModules::new(LINT_NAME, allowed_modules),
+        }
+    }
+}
+
+impl LintDecision for NoDetachedThreadsDecision {
+    type Projection = DiscardProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, statement: &DiscardProjection) -> Option<Finding> {
+        let discard = DISCARDS
+            .into_iter()
+            .find(|discard| discard.fluent_kind() == statement.discard)?;
+        if !statement.from_call
+            || !is_join_handle(&statement.type_path)
+            || self.allowed_modules.allows(&statement.module)
+        {
+            return None;
+        }
+        Some(Finding::new(LINT_NAME, discard.fluent_kind()).with_subject(statement.module.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<DiscardProjection>> {
+        let statement = |discard: Discard, from_call, type_path: &str| DiscardProjection {
+            discard: discard.fluent_kind().to_owned(),
+            from_call,
+            type_path: type_path.to_owned(),
+            module: "worker".to_owned(),
+        };
+        let handle = "std::thread::JoinHandle";
+        let mut fixtures: Vec<_> = DISCARDS
+            .into_iter()
+            .map(|discard| {
+                DecisionFixture::flagged(
+                    discard.description(),
+                    statement(discard, true, handle),
+                    discard.fluent_kind(),
+                )
+            })
+            .collect();
+        fixtures.extend([
+            DecisionFixture::clean(
+                "scoped handle",
+                statement(Discard::Binding, true, "std::thread::ScopedJoinHandle"),
+            ),
+            DecisionFixture::clean(
+                "handle not from a call",
+                statement(Discard::Drop, false, handle),
+            ),
+        ]);
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn allowed_modules_accept_detached_threads() {
+        let decision = NoDetachedThreadsDecision::new(&["worker".to_owned()]);
+        let spawn = DiscardProjection {
+            discard: "statement".to_owned(),
+            from_call: true,
+            type_path: "std::thread::JoinHandle".to_owned(),
+            module: "worker::pool".to_owned(),
+        };
+
+        assert_eq!(decision.decide(&spawn), None);
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&NoDetachedThreadsDecision::default()),
+            Ok(5)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Embedded documentation for the `no_detached_threads` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_detached_threads`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_detached_threads",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `std::thread::spawn` calls whose `JoinHandle` is bound to `_`, dropped at the end of a statement, or passed to `drop`.",
+    rationale: "Discarding the handle detaches the thread. Nothing can join it, so a panic inside it goes unobserved, and it can keep running after the test or caller that started it has finished.",
+    bad_example: concat!(
+        "fn start(queue: Queue) {\n",
+        "    let _ = std::thread::spawn(move || queue.drain());\n",
+        "}",
+    ),
+    good_example: concat!(
+        "fn start(queue: Queue) -> std::thread::JoinHandle<()> {\n",
+        "    std::thread::spawn(move || queue.drain())\n",
+        "}",
+    ),
+    config: &[ConfigKeyDocs {
+        key: "allowed_modules",
+        default: "[]",
+        description: "Module path globs, relative to the crate root, whose code may start fire-and-forget threads. A glob also accepts every module inside the modules it matches.",
+    }],
+};
@@ -0,0 +1,241 @@
+//! Detect spawned threads whose `JoinHandle` is thrown away.
+//!
+//! The lint inspects statements that discard a value: `let _ = value;`, a bare
+//! `value;` statement, and `drop(value);`. When the discarded value comes from
+//! a call and its type is `std::thread::JoinHandle`, or a `Result` or `Option`
+//! holding one as `thread::Builder::spawn` returns, the thread is detached and
+//! the lint reports the call. Statements produced by macro expansion are
+//! skipped, as are statements in modules matching `allowed_modules`.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::{ExprKind, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::path::AllowedModules;
+
+use crate::policy::{Discard, is_join_handle};
+
+pub(crate) const LINT_NAME: &str = "no_detached_threads";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_modules: Vec<String>,
+}
+
+/// Lint pass reporting spawned threads whose handle is discarded.
+#[derive(Default)]
+pub struct NoDetachedThreads {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Allowed modules and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    allowed_modules: AllowedModules,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_DETACHED_THREADS,
+    Warn,
+    "discarding a thread's `JoinHandle` detaches it, hiding its panics and letting it outlive its caller",
+    NoDetachedThreads::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoDetachedThreads {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            allowed_modules: AllowedModules::new(LINT_NAME, &load_configuration().allowed_modules),
+            findings: FindingLimit::new(NO_DETACHED_THREADS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx hir::Stmt<'tcx>) {
+        if stmt.span.from_expansion() {
+            return;
+        }
+        let Some((value, discard)) = discarded_value(cx, stmt) else {
+            return;
+        };
+        if !matches!(value.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            || !holds_join_handle(cx, cx.typeck_results().expr_ty(value))
+        {
+            return;
+        }
+
+        let module = module_path(cx, stmt.hir_id);
+        if self.state.allowed_modules.allows(&module) {
+            debug!(
+                target: LINT_NAME,
+                "accepting a detached thread in allowed module `{module}`"
+            );
+            return;
+        }
+        if self.state.findings.admit(cx, stmt.hir_id, value.span) {
+            emit_diagnostic(cx, value, discard, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the value `stmt` throws away and how it does so.
+fn discarded_value<'tcx>(
+    cx: &LateContext<'tcx>,
+    stmt: &'tcx hir::Stmt<'tcx>,
+) -> Option<(&'tcx hir::Expr<'tcx>, Discard)> {
+    match stmt.kind {
+        StmtKind::Let(local) if matches!(local.pat.kind, PatKind::Wild) => {
+            local.init.map(|init| (init, Discard::Binding))
+        }
+        StmtKind::Semi(expr) => Some(
+            dropped_argument(cx, expr)
+                .map_or((expr, Discard::Statement), |arg| (arg, Discard::Drop)),
+        ),
+        _ => None,
+    }
+}
+
+/// Returns the argument of a `drop(..)` call.
+fn dropped_argument<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    let ExprKind::Call(callee, [arg]) = expr.kind else {
+        return None;
+    };
+    let ExprKind::Path(ref qpath) = callee.kind else {
+        return None;
+    };
+    let def_id = cx.qpath_res(qpath, callee.hir_id).opt_def_id()?;
+    cx.tcx
+        .is_diagnostic_item(sym::mem_drop, def_id)
+        .then_some(arg)
+}
+
+/// Returns `true` when `ty` is a `JoinHandle`, or a `Result` or `Option`
+/// holding one.
+fn holds_join_handle<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    let ty::Adt(adt, args) = ty.kind() else {
+        return false;
+    };
+    let did = adt.did();
+    if cx.tcx.is_diagnostic_item(sym::Result, did) || cx.tcx.is_diagnostic_item(sym::Option, did) {
+        return args
+            .types()
+            .next()
+            .is_some_and(|inner| holds_join_handle(cx, inner));
+    }
+    is_join_handle(&cx.tcx.def_path_str(did))
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    value: &hir::Expr<'_>,
+    discard: Discard,
+    localizer: &Localizer,
+) {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("discard"),
+        FluentValue::from(discard.fluent_kind()),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(discard)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        NO_DETACHED_THREADS,
+        value.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(discard: Discard) -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        format!(
+            "This thread is detached because its `JoinHandle` is {}.",
+            discard.description()
+        ),
+        String::from(
+            "Nothing can join a detached thread, so its panics go unobserved and it can \
+             outlive the test or caller that started it.",
+        ),
+        String::from(
+            "Keep the handle and `join` it, use `std::thread::scope`, or list this module in \
+             `allowed_modules` if the thread is meant to run in the background.",
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_allows_no_modules() {
+        assert!(Config::default().allowed_modules.is_empty());
+    }
+
+    #[rstest]
+    #[case::binding(Discard::Binding, "bound to `_`.")]
+    #[case::statement(Discard::Statement, "dropped at the end of the statement.")]
+    #[case::drop(Discard::Drop, "passed to `drop`.")]
+    fn fallback_names_the_discard(#[case] discard: Discard, #[case] ending: &str) {
+        let messages = fallback_messages(discard);
+
+        assert!(messages.primary().ends_with(ending));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
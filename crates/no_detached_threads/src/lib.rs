@@ -0,0 +1,41 @@
+//! Lint crate flagging spawned threads whose `JoinHandle` is discarded.
+//!
+//! `let _ = std::thread::spawn(..)`, a bare `std::thread::spawn(..);`
+//! statement, or `drop(std::thread::spawn(..))` throws away the only handle to
+//! the new thread. Nothing can join it, so a panic in the thread goes
+//! unobserved and the thread may outlive the test or caller that started it.
+//! Modules meant to run fire-and-forget threads can be listed in
+//! `allowed_modules`. The lint is experimental and ships behind the
+//! `experimental-no-detached-threads` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{DiscardProjection, NoDetachedThreadsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_detached_threads_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,77 @@
+//! Recognition of thread join handles and the ways they are discarded.
+//!
+//! The driver resolves the type a discarded expression produces and passes
+//! its path here, so the decision can be tested without the compiler.
+
+/// How a statement throws away the value it computes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Discard {
+    /// `let _ = value;`
+    Binding,
+    /// `value;`
+    Statement,
+    /// `drop(value);`
+    Drop,
+}
+
+impl Discard {
+    /// Returns the Fluent selector describing the discard.
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Binding => "binding",
+            Self::Statement => "statement",
+            Self::Drop => "drop",
+        }
+    }
+
+    /// Returns the English description used by the fallback message.
+    pub(crate) const fn description(self) -> &'static str {
+        match self {
+            Self::Binding => "bound to `_`",
+            Self::Statement => "dropped at the end of the statement",
+            Self::Drop => "passed to `drop`",
+        }
+    }
+}
+
+/// Returns `true` when `type_path` names `std::thread::JoinHandle`.
+///
+/// The path may be the public re-export or the defining module inside
+/// `std::thread`. Scoped thread handles are joined when their scope ends, so
+/// `ScopedJoinHandle` does not count.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert!(is_join_handle("std::thread::JoinHandle"));
+/// assert!(!is_join_handle("std::thread::ScopedJoinHandle"));
+/// ```
+pub(crate) fn is_join_handle(type_path: &str) -> bool {
+    type_path
+        .strip_prefix("std::thread::")
+        .is_some_and(|rest| rest.rsplit("::").next() == Some("JoinHandle"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::reexport("std::thread::JoinHandle", true)]
+    #[case::defining_module("std::thread::join_handle::JoinHandle", true)]
+    #[case::scoped("std::thread::ScopedJoinHandle", false)]
+    #[case::tokio("tokio::task::JoinHandle", false)]
+    #[case::local("JoinHandle", false)]
+    fn recognises_std_join_handles(#[case] path: &str, #[case] expected: bool) {
+        assert_eq!(is_join_handle(path), expected);
+    }
+
+    #[rstest]
+    #[case::binding(Discard::Binding, "binding")]
+    #[case::statement(Discard::Statement, "statement")]
+    #[case::drop(Discard::Drop, "drop")]
+    fn discards_have_fluent_selectors(#[case] discard: Discard, #[case] expected: &str) {
+        assert_eq!(discard.fluent_kind(), expected);
+    }
+}
@@ -0,0 +1,93 @@
+//! Behaviour-driven coverage for detached thread detection.
+
+use super::{LINT_NAME, fallback_messages};
+use crate::policy::{Discard, is_join_handle};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use whitaker_common::i18n::DiagnosticMessageSet;
+use whitaker_common::path::AllowedModules;
+
+struct DetachedWorld {
+    type_path: RefCell<String>,
+    discard: RefCell<Discard>,
+    allowed: RefCell<Vec<String>>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> DetachedWorld {
+    DetachedWorld {
+        type_path: RefCell::new(String::new()),
+        discard: RefCell::new(Discard::Statement),
+        allowed: RefCell::new(Vec::new()),
+        messages: RefCell::new(None),
+    }
+}
+
+#[given("a value of type {path}")]
+fn given_type(world: &DetachedWorld, path: String) {
+    *world.type_path.borrow_mut() = path;
+}
+
+#[given("the value is bound to the wildcard pattern")]
+fn given_binding(world: &DetachedWorld) {
+    *world.discard.borrow_mut() = Discard::Binding;
+}
+
+#[given("the value is passed to drop")]
+fn given_drop(world: &DetachedWorld) {
+    *world.discard.borrow_mut() = Discard::Drop;
+}
+
+#[given("the value is discarded by a statement")]
+fn given_statement(world: &DetachedWorld) {
+    *world.discard.borrow_mut() = Discard::Statement;
+}
+
+#[given("the allowed modules are {glob}")]
+fn given_allowed(world: &DetachedWorld, glob: String) {
+    world.allowed.borrow_mut().push(glob);
+}
+
+#[when("the statement is checked in module {module}")]
+fn when_checked(world: &DetachedWorld, module: String) {
+    let allowed = AllowedModules::new(LINT_NAME, &world.allowed.borrow());
+    let detached = is_join_handle(&world.type_path.borrow()) && !allowed.allows(&module);
+    *world.messages.borrow_mut() = detached.then(|| fallback_messages(*world.discard.borrow()));
+}
+
+#[then("the thread is accepted")]
+fn then_accepted(world: &DetachedWorld) {
+    assert!(world.messages.borrow().is_none());
+}
+
+#[then("the thread is reported as {description}")]
+fn then_reported(world: &DetachedWorld, description: String) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert_eq!(
+        messages.primary(),
+        format!("This thread is detached because its `JoinHandle` is {description}.")
+    );
+}
+
+#[scenario(path = "tests/features/no_detached_threads.feature", index = 0)]
+fn scenario_wildcard_binding(world: DetachedWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_detached_threads.feature", index = 1)]
+fn scenario_drop(world: DetachedWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_detached_threads.feature", index = 2)]
+fn scenario_scoped_handles(world: DetachedWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_detached_threads.feature", index = 3)]
+fn scenario_allowed_modules(world: DetachedWorld) {
+    let _ = world;
+}
@@ -0,0 +1,28 @@
+Feature: Detached threads
+  A spawned thread whose JoinHandle is discarded is reported unless the
+  statement sits in an allowed module.
+
+  Scenario: A handle bound to the wildcard pattern is reported
+    Given a value of type std::thread::JoinHandle
+    And the value is bound to the wildcard pattern
+    When the statement is checked in module worker
+    Then the thread is reported as bound to `_`
+
+  Scenario: A handle passed to drop is reported
+    Given a value of type std::thread::JoinHandle
+    And the value is passed to drop
+    When the statement is checked in module worker
+    Then the thread is reported as passed to `drop`
+
+  Scenario: Scoped thread handles are accepted
+    Given a value of type std::thread::ScopedJoinHandle
+    And the value is discarded by a statement
+    When the statement is checked in module worker
+    Then the thread is accepted
+
+  Scenario: Allowed modules may detach threads
+    Given a value of type std::thread::JoinHandle
+    And the value is discarded by a statement
+    And the allowed modules are telemetry
+    When the statement is checked in module telemetry::flush
+    Then the thread is accepted
@@ -0,0 +1,35 @@
+//! Negative UI fixture: spawned threads whose handle is discarded.
+#![warn(no_detached_threads)]
+
+use std::thread;
+
+fn bound_to_wildcard() {
+    let _ = thread::spawn(|| println!("working"));
+}
+
+fn dropped_statement() {
+    thread::spawn(|| println!("working"));
+}
+
+fn passed_to_drop() {
+    drop(std::thread::spawn(|| println!("working")));
+}
+
+fn builder_result_discarded() {
+    let _ = thread::Builder::new().spawn(|| println!("working"));
+}
+
+fn builder_handle_discarded() {
+    thread::Builder::new()
+        .name(String::from("worker"))
+        .spawn(|| println!("working"))
+        .unwrap();
+}
+
+fn main() {
+    bound_to_wildcard();
+    dropped_statement();
+    passed_to_drop();
+    builder_result_discarded();
+    builder_handle_discarded();
+}
@@ -0,0 +1,14 @@
+//! Positive UI fixture: configured modules may start fire-and-forget threads.
+#![warn(no_detached_threads)]
+
+mod telemetry {
+    pub mod flush {
+        pub fn start() {
+            std::thread::spawn(|| println!("flushing"));
+        }
+    }
+}
+
+fn main() {
+    telemetry::flush::start();
+}
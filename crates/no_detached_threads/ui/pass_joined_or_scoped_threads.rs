@@ -0,0 +1,41 @@
+//! Positive UI fixture: joined, returned, scoped, and allowed threads.
+#![warn(no_detached_threads)]
+
+use std::thread::{self, JoinHandle};
+
+fn joined() {
+    let handle = thread::spawn(|| println!("working"));
+    handle.join().unwrap();
+}
+
+fn returned() -> JoinHandle<()> {
+    thread::spawn(|| println!("working"))
+}
+
+fn stored(handles: &mut Vec<JoinHandle<()>>) {
+    handles.push(thread::spawn(|| println!("working")));
+}
+
+fn scoped() {
+    thread::scope(|scope| {
+        scope.spawn(|| println!("working"));
+    });
+}
+
+fn named_binding_is_kept() {
+    let _worker = thread::spawn(|| println!("working"));
+}
+
+#[allow(no_detached_threads)]
+fn allowed() {
+    thread::spawn(|| println!("working"));
+}
+
+fn main() {
+    joined();
+    returned().join().unwrap();
+    stored(&mut Vec::new());
+    scoped();
+    named_binding_is_kept();
+    allowed();
+}
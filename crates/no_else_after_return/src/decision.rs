@@ -0,0 +1,88 @@
+//! Pure decision logic for `no_else_after_return`.
+//!
+//! The driver finds each `if` with a plain `else` block and notes how its
+//! `if` block ends and the source text between the two blocks.
+//! [`NoElseAfterReturnDecision`] reports the `else` when the block ends in an
+//! [`ExitKind`] and [`else_keyword`] finds the keyword, using the exit's
+//! keyword as the finding's reason.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::else_removal::{ExitKind, else_keyword};
+
+use crate::driver::LINT_NAME;
+
+/// The facts about one `if`/`else` that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IfElseProjection {
+    /// The keyword of the expression ending the `if` block, such as
+    /// `return`, or the kind of any other final expression.
+    pub then_ends_with: String,
+    /// Whether the `else` branch is a block rather than another `if`.
+    pub else_is_block: bool,
+    /// The source text between the `if` block and the `else` body.
+    pub between: String,
+}
+
+/// Reports `else` blocks after an `if` block that always exits.
+///
+/// # Examples
+///
+/// ```ignore
+/// let branch = IfElseProjection {
+///     then_ends_with: "return".to_owned(),
+///     else_is_block: true,
+///     between: " else ".to_owned(),
+/// };
+/// assert!(NoElseAfterReturnDecision.decide(&branch).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoElseAfterReturnDecision;
+
+impl LintDecision for NoElseAfterReturnDecision {
+    type Projection = IfElseProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, branch: &IfElseProjection) -> Option<Finding> {
+        if !branch.else_is_block {
+            return None;
+        }
+        let exit = match branch.then_ends_with.as_str() {
+            "return" => ExitKind::Return,
+            "continue" => ExitKind::Continue,
+            "break" => ExitKind::Break,
+            _ => return None,
+        };
+        else_keyword(&branch.between)?;
+        Some(Finding::new(LINT_NAME, exit.keyword()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<IfElseProjection>> {
+        let branch = |then_ends_with: &str, else_is_block| IfElseProjection {
+            then_ends_with: then_ends_with.to_owned(),
+            else_is_block,
+            between: " else ".to_owned(),
+        };
+        vec![
+            DecisionFixture::flagged("after return", branch("return", true), "return"),
+            DecisionFixture::flagged("after continue", branch("continue", true), "continue"),
+            DecisionFixture::flagged("after break", branch("break", true), "break"),
+            DecisionFixture::clean("if block falls through", branch("call", true)),
+            DecisionFixture::clean("else if chain", branch("return", false)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&NoElseAfterReturnDecision), Ok(5));
+    }
+}
@@ -0,0 +1,17 @@
+//! Embedded documentation for the `no_else_after_return` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for `no_else_after_return`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_else_after_return",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `else` blocks following an `if` branch that always ends in `return`, `continue`, or `break`, and suggests removing the `else`.",
+    rationale: "When the `if` branch always leaves, the `else` body already runs exactly when the branch is not taken, so the `else` only adds nesting. Writing the `if` as a guard clause keeps the main path at the outer indentation level and reduces the clustered nesting that `bumpy_road_function` reports.",
+    bad_example: "fn parse(text: &str) -> Option<u32> {\n    if text.is_empty() {\n        return None;\n    } else {\n        text.parse().ok()\n    }\n}",
+    good_example: "fn parse(text: &str) -> Option<u32> {\n    if text.is_empty() {\n        return None;\n    }\n    text.parse().ok()\n}",
+    config: &[],
+};
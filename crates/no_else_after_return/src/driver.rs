@@ -0,0 +1,220 @@
+//! Lint pass reporting `else` blocks after branches that always exit.
+//!
+//! Each `if` whose block ends in `return`, `continue`, or `break` and is
+//! followed by a plain `else` block is found by
+//! [`whitaker::hir::ElseAfterExit`], which also builds the suggestion that
+//! removes the `else` and dedents its body. The `else` keyword is reported.
+//! `continue` and `break` are also reported by the experimental
+//! `redundant_else_block_after_loop_control` lint.
+
+use std::borrow::Cow;
+
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use whitaker::SharedConfig;
+use whitaker::hir::{ElseAfterExit, FindingLimit};
+use whitaker_common::CrateScopedState;
+use whitaker_common::else_removal::ExitKind;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+pub(crate) const LINT_NAME: &str = "no_else_after_return";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub NO_ELSE_AFTER_RETURN,
+    Warn,
+    "`else` blocks after an `if` branch ending in `return`, `continue`, or `break` should be removed",
+    NoElseAfterReturn::default()
+}
+
+/// Lint pass that finds `else` blocks following an early exit.
+#[derive(Default)]
+pub struct NoElseAfterReturn {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Finding limit and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoElseAfterReturn {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            findings: FindingLimit::new(NO_ELSE_AFTER_RETURN, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let Some(finding) = ElseAfterExit::find(cx, expr, |_| true) else {
+            return;
+        };
+        let state = &*self.state;
+        if state.findings.admit(cx, expr.hir_id, finding.else_span()) {
+            emit_diagnostic(cx, &finding, &state.localizer);
+        }
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, finding: &ElseAfterExit<'_>, localizer: &Localizer) {
+    let message = ElseAfterExitMessage {
+        exit: finding.exit(),
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let suggestion = message.suggestion(localizer);
+    let rewrite = finding.rewrite(cx);
+
+    cx.emit_span_lint(
+        NO_ELSE_AFTER_RETURN,
+        finding.else_span(),
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+            if let Some(rewrite) = rewrite {
+                lint.span_suggestion(
+                    rewrite.span,
+                    suggestion,
+                    rewrite.replacement,
+                    rewrite.applicability,
+                );
+            }
+        }),
+    );
+}
+
+/// The values the diagnostic for an `else` after an exit interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ElseAfterExitMessage {
+    exit: ExitKind,
+}
+
+impl ElseAfterExitMessage {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("exit"),
+            FluentValue::from(self.exit.keyword()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let exit = self.exit.keyword();
+        DiagnosticMessageSet::new(
+            format!("Unnecessary `else` after an `if` block that ends with `{exit}`."),
+            format!(
+                "The `if` block always leaves with `{exit}`, so the `else` body already runs \
+                 only when the condition is false and the `else` just adds nesting."
+            ),
+            String::from(
+                "Remove the `else` and dedent its body so the `if` reads as a guard clause; \
+                 flatter branches also lower the nesting `bumpy_road_function` measures.",
+            ),
+        )
+    }
+
+    fn fallback_suggestion(&self) -> String {
+        String::from("remove the `else` and dedent its body")
+    }
+
+    /// Resolves the label of the suggested rewrite. Fluent may wrap
+    /// arguments in directional isolates, which are stripped to keep
+    /// suggestion text stable.
+    fn suggestion(&self, localizer: &Localizer) -> String {
+        localizer
+            .attribute_with_args(LINT_NAME, "suggestion", &self.args())
+            .unwrap_or_else(|_| self.fallback_suggestion())
+            .chars()
+            .filter(|ch| !matches!(ch, '\u{2068}' | '\u{2069}' | '\u{FFFD}'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn resolve(message: &ElseAfterExitMessage, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    #[case(ExitKind::Return)]
+    #[case(ExitKind::Continue)]
+    #[case(ExitKind::Break)]
+    fn english_messages_match_the_fallback(#[case] exit: ExitKind) {
+        let message = ElseAfterExitMessage { exit };
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+        assert_eq!(
+            message.suggestion(&Localizer::new(Some("en-GB"))),
+            message.fallback_suggestion()
+        );
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_exit() {
+        let messages = ElseAfterExitMessage {
+            exit: ExitKind::Continue,
+        }
+        .fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Unnecessary `else` after an `if` block that ends with `continue`."
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let message = ElseAfterExitMessage {
+            exit: ExitKind::Break,
+        };
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`break`"));
+        assert!(messages.primary().contains("`else`"));
+        assert_ne!(
+            message.suggestion(&Localizer::new(Some(locale))),
+            message.fallback_suggestion()
+        );
+    }
+}
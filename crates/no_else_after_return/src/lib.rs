@@ -0,0 +1,39 @@
+//! Lint crate flagging `else` blocks that follow an early exit.
+//!
+//! When an `if` branch always ends in `return`, `continue`, or `break`, the
+//! `else` that follows it only adds a level of nesting: its body already runs
+//! exactly when the branch is not taken. The lint reports these `else` blocks
+//! and suggests removing the `else` and dedenting its body, turning the `if`
+//! into a guard clause. Flattening the branches this way also removes the
+//! nested conditional logic `bumpy_road_function` measures. The lint is
+//! experimental and ships behind the `experimental-no-else-after-return`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{IfElseProjection, NoElseAfterReturnDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_else_after_return_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
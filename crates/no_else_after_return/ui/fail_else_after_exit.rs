@@ -0,0 +1,42 @@
+//! `else` blocks after `if` blocks ending in `return`, `continue`, and
+//! `break` are reported.
+
+fn parse(text: &str) -> Option<u32> {
+    if text.is_empty() {
+        return None;
+    } else {
+        text.parse().ok()
+    }
+}
+
+fn total(values: &[i32]) -> i32 {
+    let mut sum = 0;
+    for value in values {
+        if *value < 0 {
+            continue;
+        } else {
+            sum += value;
+        }
+        sum += 1;
+    }
+    sum
+}
+
+fn first_even(values: &[u32]) -> Option<u32> {
+    let mut found = None;
+    for value in values {
+        if value % 2 == 0 {
+            found = Some(*value);
+            break;
+        } else {
+            let _ = value;
+        }
+    }
+    found
+}
+
+fn main() {
+    let _ = parse("7");
+    let _ = total(&[1, -2]);
+    let _ = first_even(&[1, 2]);
+}
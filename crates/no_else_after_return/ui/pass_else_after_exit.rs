@@ -0,0 +1,51 @@
+//! `else if` chains, `if` expressions whose value is used, branches that do
+//! not always exit, and `while` loops are accepted.
+
+fn classify(value: i32) -> &'static str {
+    if value < 0 {
+        return "negative";
+    } else if value == 0 {
+        "zero"
+    } else {
+        "positive"
+    }
+}
+
+fn doubled(value: Option<u32>) -> u32 {
+    let inner = if let Some(inner) = value {
+        inner
+    } else {
+        return 0;
+    };
+    inner * 2
+}
+
+fn scaled(value: u32) -> u32 {
+    let factor = if value > 10 { return value } else { 2 };
+    value * factor
+}
+
+fn labelled(value: u32) -> u32 {
+    if value > 1 {
+        let _ = value;
+    } else {
+        return 1;
+    }
+    value
+}
+
+fn last(values: &mut Vec<u32>) -> u32 {
+    while let Some(value) = values.pop() {
+        let _ = value;
+        return value;
+    }
+    0
+}
+
+fn main() {
+    let _ = classify(3);
+    let _ = doubled(Some(2));
+    let _ = scaled(4);
+    let _ = labelled(5);
+    let _ = last(&mut vec![1, 0]);
+}
@@ -0,0 +1,183 @@
+//! Places where teams tolerate `.expect(..)` outside tests.
+//!
+//! Three configuration keys feed the [`AllowList`]: `allowed_files` holds
+//! globs matched against a source file's path relative to the package
+//! directory, such as `build.rs` or `examples/**`; `allowed_modules` holds
+//! module globs, as other lints accept them; and `allowed_functions` holds
+//! function paths such as `crate::telemetry::init`, whose closures are
+//! covered too. A leading `crate::` on a function path is optional.
+
+use std::path::{Path, PathBuf};
+
+use glob::{MatchOptions, Pattern};
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::{FileName, Span};
+use whitaker::hir::module_path;
+use whitaker_common::path::AllowedModules;
+
+const LINT_NAME: &str = "no_expect_outside_tests";
+
+/// Files, modules, and functions in which expectations are accepted.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AllowList {
+    files: Vec<Pattern>,
+    modules: AllowedModules,
+    functions: Vec<String>,
+}
+
+impl AllowList {
+    /// Compiles the configured globs and paths, skipping file globs that are
+    /// not valid patterns.
+    pub(crate) fn new(files: &[String], modules: &[String], functions: &[String]) -> Self {
+        let files = files
+            .iter()
+            .filter_map(|glob| match Pattern::new(glob) {
+                Ok(pattern) => Some(pattern),
+                Err(error) => {
+                    debug!(target: LINT_NAME, "ignoring invalid `allowed_files` glob `{glob}`: {error}");
+                    None
+                }
+            })
+            .collect();
+        Self {
+            files,
+            modules: AllowedModules::new(LINT_NAME, modules),
+            functions: functions
+                .iter()
+                .map(|path| without_crate_prefix(path).to_owned())
+                .collect(),
+        }
+    }
+
+    /// Returns `true` when a glob matches `file`, a `/`-separated path
+    /// relative to the package directory.
+    ///
+    /// `*` stays within one path segment, while `**` spans any number.
+    #[must_use]
+    pub(crate) fn allows_file(&self, file: &str) -> bool {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        self.files
+            .iter()
+            .any(|pattern| pattern.matches_with(file, options))
+    }
+
+    /// Returns `true` when `module_path`, relative to the crate root, or an
+    /// enclosing module is allowed.
+    #[must_use]
+    pub(crate) fn allows_module(&self, module_path: &str) -> bool {
+        self.modules.allows(module_path)
+    }
+
+    /// Returns `true` when the function at `function_path` is allowed.
+    #[must_use]
+    pub(crate) fn allows_function(&self, function_path: &str) -> bool {
+        let path = without_crate_prefix(function_path);
+        self.functions.iter().any(|allowed| allowed == path)
+    }
+
+    /// Returns `true` when the expression at `hir_id`, reported at `span`,
+    /// lies in an allowed file, module, or function.
+    pub(crate) fn allows(&self, cx: &LateContext<'_>, hir_id: hir::HirId, span: Span) -> bool {
+        if !self.files.is_empty()
+            && package_relative_file(cx, span).is_some_and(|file| self.allows_file(&file))
+        {
+            return true;
+        }
+        if self.allows_module(&module_path(cx, hir_id)) {
+            return true;
+        }
+        if self.functions.is_empty() {
+            return false;
+        }
+        let owner = cx.tcx.hir_enclosing_body_owner(hir_id);
+        let function = cx.tcx.typeck_root_def_id(owner.to_def_id());
+        self.allows_function(&cx.tcx.def_path_str(function))
+    }
+}
+
+fn without_crate_prefix(path: &str) -> &str {
+    path.strip_prefix("crate::").unwrap_or(path)
+}
+
+/// Returns the path of the file containing `span` relative to the package
+/// directory, which Cargo names in `CARGO_MANIFEST_DIR`, or to the working
+/// directory when that is unset.
+fn package_relative_file(cx: &LateContext<'_>, span: Span) -> Option<String> {
+    let source_map = cx.sess().source_map();
+    let FileName::Real(name) = source_map.span_to_filename(span) else {
+        return None;
+    };
+    let working_dir = source_map.working_dir().local_path()?;
+    let file = working_dir.join(name.local_path()?);
+    let package = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map_or_else(|| working_dir.to_path_buf(), PathBuf::from);
+    let relative = file.strip_prefix(&package).ok()?;
+    Some(forward_slashes(relative))
+}
+
+fn forward_slashes(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn allow_list(files: &[&str], modules: &[&str], functions: &[&str]) -> AllowList {
+        let owned = |values: &[&str]| -> Vec<String> {
+            values.iter().map(|value| (*value).to_owned()).collect()
+        };
+        AllowList::new(&owned(files), &owned(modules), &owned(functions))
+    }
+
+    #[rstest]
+    #[case::build_script("build.rs", "build.rs", true)]
+    #[case::nested_build_script("build.rs", "src/build.rs", false)]
+    #[case::examples("examples/**", "examples/demo/main.rs", true)]
+    #[case::single_segment("examples/*.rs", "examples/demo/main.rs", false)]
+    #[case::other_file("examples/**", "src/main.rs", false)]
+    fn matches_file_globs(#[case] glob: &str, #[case] file: &str, #[case] expected: bool) {
+        assert_eq!(allow_list(&[glob], &[], &[]).allows_file(file), expected);
+    }
+
+    #[rstest]
+    fn skips_invalid_file_globs() {
+        let allowed = allow_list(&["[", "build.rs"], &[], &[]);
+
+        assert!(allowed.allows_file("build.rs"));
+    }
+
+    #[rstest]
+    #[case::submodule("telemetry::exporter", true)]
+    #[case::sibling("telemetry_export", false)]
+    fn matches_module_globs(#[case] module_path: &str, #[case] expected: bool) {
+        let allowed = allow_list(&[], &["telemetry"], &[]);
+
+        assert_eq!(allowed.allows_module(module_path), expected);
+    }
+
+    #[rstest]
+    #[case::prefixed("crate::telemetry::init", "telemetry::init", true)]
+    #[case::unprefixed("telemetry::init", "telemetry::init", true)]
+    #[case::both_prefixed("crate::telemetry::init", "crate::telemetry::init", true)]
+    #[case::other_function("crate::telemetry::init", "telemetry::shutdown", false)]
+    #[case::nested_function("crate::telemetry", "telemetry::init", false)]
+    fn matches_function_paths(
+        #[case] configured: &str,
+        #[case] function_path: &str,
+        #[case] expected: bool,
+    ) {
+        let allowed = allow_list(&[], &[], &[configured]);
+
+        assert_eq!(allowed.allows_function(function_path), expected);
+    }
+}
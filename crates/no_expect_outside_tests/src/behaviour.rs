@@ -130,17 +130,6 @@ fn then_test(world: &ContextWorld) {
     assert!(world.summary_ref().is_test);
 }
 
-#[then("the function name is {expected}")]
-fn then_function(world: &ContextWorld, expected: String) {
-    let summary = world.summary_ref();
-    assert_eq!(summary.function_name.as_deref(), Some(expected.as_str()));
-}
-
-#[then("no function name is recorded")]
-fn then_no_function(world: &ContextWorld) {
-    assert!(world.summary_ref().function_name.is_none());
-}
-
 #[then("the lint is skipped")]
 fn then_lint_skipped(world: &ContextWorld) {
     assert!(world.should_skip_lint());
@@ -152,7 +141,6 @@ fn scenario_production(world: ContextWorld) {
     world.evaluate();
     let summary = world.summary_ref();
     assert!(!summary.is_test);
-    assert_eq!(summary.function_name.as_deref(), Some("handler"));
 }
 
 #[scenario(path = "tests/features/context_summary.feature", index = 1)]
@@ -161,7 +149,6 @@ fn scenario_test(world: ContextWorld) {
     world.evaluate();
     let summary = world.summary_ref();
     assert!(summary.is_test);
-    assert_eq!(summary.function_name.as_deref(), Some("works"));
 }
 
 #[scenario(path = "tests/features/context_summary.feature", index = 2)]
@@ -171,7 +158,6 @@ fn scenario_cfg_test(world: ContextWorld) {
     world.evaluate();
     let summary = world.summary_ref();
     assert!(summary.is_test);
-    assert!(summary.function_name.is_none());
 }
 
 #[scenario(path = "tests/features/context_summary.feature", index = 3)]
@@ -187,7 +173,6 @@ fn scenario_additional_attribute(world: ContextWorld) {
     world.evaluate();
     let summary = world.summary_ref();
     assert!(summary.is_test);
-    assert_eq!(summary.function_name.as_deref(), Some("custom"));
 }
 
 #[scenario(path = "tests/features/context_summary.feature", index = 4)]
@@ -196,8 +181,4 @@ fn scenario_doctest(world: ContextWorld) {
     world.mark_doctest();
     world.evaluate();
     assert!(world.should_skip_lint());
-    assert_eq!(
-        world.summary_ref().function_name.as_deref(),
-        Some("handler")
-    );
 }
@@ -2,23 +2,20 @@
 //! guards (for example, `cfg(test)`), supporting the lint's context
 //! summarization.
 
-use rustc_ast::AttrStyle;
 use rustc_ast::ast::{MetaItem, MetaItemInner};
 use rustc_hir as hir;
 use rustc_hir::Node;
-use rustc_hir::attrs::AttributeKind as HirAttributeKind;
 use rustc_lint::LateContext;
 use rustc_span::sym;
-use whitaker::hir::has_test_like_hir_attributes;
+use whitaker::hir::{attribute_kind, has_test_like_hir_attributes, is_cfg_trace_test_attribute};
 use whitaker_common::{
-    Attribute, AttributeKind, AttributePath, ContextEntry, ContextKind,
+    Attribute, AttributePath, ContextCache, ContextEntry, ContextKind, ContextPrefix,
     PARSED_ATTRIBUTE_PLACEHOLDER, in_test_like_context_with,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub(crate) struct ContextSummary {
     pub(crate) is_test: bool,
-    pub(crate) function_name: Option<String>,
 }
 
 /// Collects simplified context entries for the ancestors of a HIR node.
@@ -27,12 +24,19 @@ pub(crate) struct ContextSummary {
 /// `ContextEntry` values, and tracks whether any ancestor establishes test
 /// context through `cfg(test)` or a recognized test attribute.
 ///
+/// Ancestors from the enclosing body's owner up to the crate root are the same
+/// for every node in that body, so they are collected once per body and kept
+/// in `cache`. Each call then walks only the ancestors inside the body, which
+/// keeps functions with many `expect` calls from re-walking the full chain for
+/// each one.
+///
 /// # Parameters
 ///
 /// - `cx`: Lint context used to walk the HIR and inspect ancestor attributes.
 /// - `hir_id`: The HIR node whose ancestor chain should be summarized.
 /// - `additional_test_attributes`: Extra user-configured attribute paths that
 ///   should be treated as test markers alongside Whitaker's built-in list.
+/// - `cache`: Per-body ancestor context, keyed by the enclosing body.
 ///
 /// # Returns
 ///
@@ -44,18 +48,49 @@ pub(crate) struct ContextSummary {
 ///
 /// ```ignore
 /// let (entries, has_test_context_ancestry) =
-///     collect_context(cx, expr.hir_id, additional_test_attributes);
+///     collect_context(cx, expr.hir_id, additional_test_attributes, &mut self.context_cache);
 /// assert!(!entries.is_empty() || !has_test_context_ancestry);
 /// ```
 pub(crate) fn collect_context<'tcx>(
     cx: &LateContext<'tcx>,
     hir_id: hir::HirId,
     additional_test_attributes: &[AttributePath],
+    cache: &mut ContextCache<hir::BodyId>,
+) -> (Vec<ContextEntry>, bool) {
+    let Some(body) = cx.enclosing_body else {
+        let ancestors: Vec<_> = cx.tcx.hir_parent_iter(hir_id).collect();
+        return summarise_ancestors(cx, ancestors, additional_test_attributes);
+    };
+
+    let owner = cx.tcx.hir_body_owner(body);
+    let inner: Vec<_> = cx
+        .tcx
+        .hir_parent_iter(hir_id)
+        .take_while(|(ancestor_id, _)| *ancestor_id != owner)
+        .collect();
+    let prefix = cache.prefix_or_insert_with(body, || {
+        let outer: Vec<_> = std::iter::once((owner, cx.tcx.hir_node(owner)))
+            .chain(cx.tcx.hir_parent_iter(owner))
+            .collect();
+        let (entries, has_test_context_ancestry) =
+            summarise_ancestors(cx, outer, additional_test_attributes);
+        ContextPrefix::new(entries, has_test_context_ancestry)
+    });
+
+    let (entries, has_test_context_ancestry) =
+        summarise_ancestors(cx, inner, additional_test_attributes);
+    prefix.extend(entries, has_test_context_ancestry)
+}
+
+/// Converts ancestors, innermost first, into entries ordered outermost first.
+fn summarise_ancestors<'tcx>(
+    cx: &LateContext<'tcx>,
+    mut ancestors: Vec<(hir::HirId, Node<'tcx>)>,
+    additional_test_attributes: &[AttributePath],
 ) -> (Vec<ContextEntry>, bool) {
     let mut entries = Vec::new();
     let mut has_test_context_ancestry = false;
 
-    let mut ancestors: Vec<_> = cx.tcx.hir_parent_iter(hir_id).collect();
     ancestors.reverse();
 
     for (ancestor_id, node) in ancestors {
@@ -103,8 +138,7 @@ fn has_test_ancestry(
 ///
 /// # Returns
 ///
-/// Returns a `ContextSummary` describing the derived test-context status and
-/// the innermost enclosing function name, if one was found.
+/// Returns a `ContextSummary` describing the derived test-context status.
 ///
 /// # Examples
 ///
@@ -125,17 +159,8 @@ pub(crate) fn summarise_context(
 ) -> ContextSummary {
     let is_test =
         has_test_context_ancestry || in_test_like_context_with(entries, additional_test_attributes);
-    let function_name = entries.iter().rev().find_map(|entry| {
-        entry
-            .kind()
-            .matches_function()
-            .then(|| entry.name().to_string())
-    });
 
-    ContextSummary {
-        is_test,
-        function_name,
-    }
+    ContextSummary { is_test }
 }
 
 fn context_entry_for(node: Node<'_>, attrs: &[hir::Attribute]) -> Option<ContextEntry> {
@@ -173,6 +198,19 @@ fn context_entry_for(node: Node<'_>, attrs: &[hir::Attribute]) -> Option<Context
             ContextKind::Block,
             convert_attributes(attrs),
         )),
+        // Statements and expressions only matter when they carry attributes
+        // such as `#[cfg(test)]`; recording every ancestor would bloat the
+        // stack without changing the summary.
+        Node::Stmt(_) | Node::LetStmt(_) if !attrs.is_empty() => Some(ContextEntry::new(
+            "statement".to_string(),
+            ContextKind::Statement,
+            convert_attributes(attrs),
+        )),
+        Node::Expr(_) if !attrs.is_empty() => Some(ContextEntry::new(
+            "expression".to_string(),
+            ContextKind::Expression,
+            convert_attributes(attrs),
+        )),
         _ => None,
     }
 }
@@ -182,10 +220,10 @@ fn convert_attributes(attrs: &[hir::Attribute]) -> Vec<Attribute> {
 }
 
 fn convert_attribute(attr: &hir::Attribute) -> Attribute {
-    let kind = match attribute_style(attr) {
-        AttrStyle::Inner => AttributeKind::Inner,
-        AttrStyle::Outer => AttributeKind::Outer,
-    };
+    let kind = attribute_kind(attr);
+    if is_cfg_test_attribute(attr) {
+        return Attribute::cfg_test(kind);
+    }
     let path = if attr.doc_str().is_some() {
         AttributePath::from("doc")
     } else {
@@ -227,7 +265,9 @@ where
 /// Returns whether a HIR attribute enables `cfg(test)` semantics.
 ///
 /// Recognizes both direct `cfg(test)` attributes and `cfg_attr(.., cfg(test))`
-/// forms so callers can treat such ancestors as test-only context.
+/// forms so callers can treat such ancestors as test-only context. A
+/// satisfied `cfg` reaches HIR as a parsed `cfg_trace` attribute, which is
+/// recognized as well.
 ///
 /// # Parameters
 ///
@@ -246,10 +286,10 @@ where
 /// }
 /// ```
 pub(crate) fn is_cfg_test_attribute(attr: &hir::Attribute) -> bool {
-    // Parsed attributes (like #[must_use]) are not cfg-related; skip them to
-    // avoid panics when calling path() on arbitrary parsed attributes.
+    // Other parsed attributes (like #[must_use]) are not cfg-related; skip
+    // them to avoid panics when calling path() on arbitrary parsed attributes.
     let hir::Attribute::Unparsed(_) = attr else {
-        return false;
+        return is_cfg_trace_test_attribute(attr);
     };
 
     let path = attr.path();
@@ -332,14 +372,6 @@ fn item_name(item: &hir::Item<'_>) -> Option<String> {
     item.kind.ident().map(|ident| ident.name.to_string())
 }
 
-fn attribute_style(attr: &hir::Attribute) -> AttrStyle {
-    match attr {
-        hir::Attribute::Unparsed(item) => item.style,
-        hir::Attribute::Parsed(HirAttributeKind::DocComment { style, .. }) => *style,
-        _ => AttrStyle::Outer,
-    }
-}
-
 fn path_is_ident(path: &rustc_ast::Path, symbol: rustc_span::Symbol) -> bool {
     path.segments.len() == 1 && path.segments[0].ident.name == symbol
 }
@@ -462,6 +462,25 @@ fn convert_attribute_handles_parsed_must_use() {
     assert_eq!(attribute.kind(), AttributeKind::Outer);
 }
 
+/// Verify that `convert_attribute` projects a `cfg_trace(test)` attribute, the
+/// form a satisfied `#[cfg(test)]` takes in HIR, to the normalized `cfg` form.
+#[cfg(feature = "dylint-driver")]
+#[test]
+fn convert_attribute_projects_cfg_trace_test() {
+    create_default_session_globals_then(|| {
+        let entry = rustc_hir::attrs::CfgEntry::NameValue {
+            name: rustc_span::sym::test,
+            value: None,
+            span: DUMMY_SP,
+        };
+        let parsed_attr =
+            hir::Attribute::Parsed(HirAttributeKind::CfgTrace(vec![(entry, DUMMY_SP)].into()));
+
+        assert!(is_cfg_test_attribute(&parsed_attr));
+        assert!(convert_attribute(&parsed_attr).is_cfg_test());
+    });
+}
+
 /// Verify that `is_cfg_test_attribute` handles parsed attributes without panicking.
 #[cfg(feature = "dylint-driver")]
 #[test]
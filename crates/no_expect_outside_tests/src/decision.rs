@@ -0,0 +1,207 @@
+//! Pure decision logic for `no_expect_outside_tests`.
+//!
+//! The driver describes each call to a panicking accessor by the method, the
+//! receiver's type, the context entries enclosing the call, and the file,
+//! module, and function it sits in. [`NoExpectOutsideTestsDecision`] applies
+//! the same [`ExpectMethod`] behaviour gate, the same test detection through
+//! [`summarise_context`], and the same [`AllowList`]. Findings use the method
+//! as their reason and name the enclosing function as their subject.
+//! Doctest crates, harness fallbacks, and blamed helpers are left to the
+//! driver.
+
+use whitaker::config::BehaviourVersion;
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::{Attribute, AttributeKind, AttributePath, ContextEntry};
+
+use crate::allow_list::AllowList;
+use crate::context::summarise_context;
+use crate::diagnostics::{ExpectMethod, RESULT_ERR_ACCESSORS};
+use crate::driver::LINT_NAME;
+
+/// The facts about one accessor call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExpectCallProjection {
+    /// The method called, such as `expect`.
+    pub method: String,
+    /// The receiver's type behind any references: `Option`, `Result`, or
+    /// the name of any other type.
+    pub receiver: String,
+    /// The context entries enclosing the call, outermost first.
+    pub contexts: Vec<ContextEntry>,
+    /// The file holding the call, relative to the package directory.
+    pub file: String,
+    /// The module holding the call, relative to the crate root.
+    pub module: String,
+    /// The path of the function holding the call.
+    pub function: String,
+}
+
+/// Reports panicking accessors on `Option` and `Result` outside test code.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = ExpectCallProjection {
+///     method: "expect".to_owned(),
+///     receiver: "Result".to_owned(),
+///     function: "crate::load".to_owned(),
+///     ..ExpectCallProjection::default()
+/// };
+/// assert!(NoExpectOutsideTestsDecision::default().decide(&call).is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NoExpectOutsideTestsDecision {
+    behaviour: BehaviourVersion,
+    additional_test_attributes: Vec<AttributePath>,
+    allow_list: AllowList,
+}
+
+impl NoExpectOutsideTestsDecision {
+    /// Creates a decision running at `behaviour` that also treats
+    /// `additional_test_attributes` as test markers.
+    #[must_use]
+    pub fn new(behaviour: BehaviourVersion, additional_test_attributes: &[String]) -> Self {
+        Self {
+            behaviour,
+            additional_test_attributes: additional_test_attributes
+                .iter()
+                .map(|path| AttributePath::from(path.as_str()))
+                .collect(),
+            allow_list: AllowList::default(),
+        }
+    }
+
+    /// Accepts calls in the given file globs, modules, and functions.
+    #[must_use]
+    pub fn allowing(mut self, files: &[String], modules: &[String], functions: &[String]) -> Self {
+        self.allow_list = AllowList::new(files, modules, functions);
+        self
+    }
+
+    fn reports(&self, method: ExpectMethod, receiver: &str) -> bool {
+        if method.panics_on_ok() && !self.behaviour.enables(RESULT_ERR_ACCESSORS) {
+            return false;
+        }
+        match receiver {
+            "Result" => true,
+            "Option" => !method.panics_on_ok(),
+            _ => false,
+        }
+    }
+
+    fn allows(&self, call: &ExpectCallProjection) -> bool {
+        self.allow_list.allows_file(&call.file)
+            || self.allow_list.allows_module(&call.module)
+            || self.allow_list.allows_function(&call.function)
+    }
+}
+
+impl LintDecision for NoExpectOutsideTestsDecision {
+    type Projection = ExpectCallProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, call: &ExpectCallProjection) -> Option<Finding> {
+        let method = ExpectMethod::from_name(&call.method)?;
+        if !self.reports(method, &call.receiver) {
+            return None;
+        }
+        let summary = summarise_context(&call.contexts, false, &self.additional_test_attributes);
+        if summary.is_test || self.allows(call) {
+            return None;
+        }
+        Some(Finding::new(LINT_NAME, method.name()).with_subject(call.function.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ExpectCallProjection>> {
+        let call = |method: &str, receiver: &str, attribute: Option<&AttributePath>| {
+            let attributes = attribute
+                .map(|path| Attribute::new(path.clone(), AttributeKind::Outer))
+                .into_iter()
+                .collect();
+            ExpectCallProjection {
+                method: method.to_owned(),
+                receiver: receiver.to_owned(),
+                contexts: vec![ContextEntry::function("load", attributes)],
+                file: "src/config.rs".to_owned(),
+                module: "config".to_owned(),
+                function: "crate::config::load".to_owned(),
+            }
+        };
+        let test = AttributePath::from("test");
+        let mut fixtures = vec![
+            DecisionFixture::flagged(
+                "expect on a result",
+                call("expect", "Result", None),
+                "expect",
+            ),
+            DecisionFixture::flagged(
+                "expect on an option",
+                call("expect", "Option", None),
+                "expect",
+            ),
+            DecisionFixture::clean("inside a test", call("expect", "Result", Some(&test))),
+            DecisionFixture::clean("expect on another type", call("expect", "Sender", None)),
+            DecisionFixture::clean(
+                "unwrap_err on an option",
+                call("unwrap_err", "Option", None),
+            ),
+        ];
+        let unwrap_err = call("unwrap_err", "Result", None);
+        fixtures.push(if self.behaviour.enables(RESULT_ERR_ACCESSORS) {
+            DecisionFixture::flagged("unwrap_err on a result", unwrap_err, "unwrap_err")
+        } else {
+            DecisionFixture::clean("unwrap_err before version 2", unwrap_err)
+        });
+        fixtures.extend(self.additional_test_attributes.first().map(|marker| {
+            DecisionFixture::clean(
+                "inside a custom test",
+                call("expect", "Result", Some(marker)),
+            )
+        }));
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn allowed_functions_are_accepted() {
+        let call = ExpectCallProjection {
+            method: "expect".to_owned(),
+            receiver: "Result".to_owned(),
+            function: "crate::telemetry::init".to_owned(),
+            ..ExpectCallProjection::default()
+        };
+        let decision = NoExpectOutsideTestsDecision::default().allowing(
+            &[],
+            &[],
+            &["telemetry::init".to_owned()],
+        );
+
+        assert_eq!(decision.decide(&call), None);
+    }
+
+    #[rstest]
+    #[case::defaults(NoExpectOutsideTestsDecision::default(), 6)]
+    #[case::result_err_accessors(
+        NoExpectOutsideTestsDecision::new(BehaviourVersion::new(2), &[]),
+        6
+    )]
+    #[case::custom_test_attribute(
+        NoExpectOutsideTestsDecision::new(
+            BehaviourVersion::DEFAULT,
+            &["tokio::test".to_owned()],
+        ),
+        7
+    )]
+    fn decision_conforms(#[case] decision: NoExpectOutsideTestsDecision, #[case] fixtures: usize) {
+        assert_eq!(check_conformance(&decision), Ok(fixtures));
+    }
+}
@@ -3,19 +3,21 @@
 //! test-context evidence.
 
 use crate::NO_EXPECT_OUTSIDE_TESTS;
-use crate::context::ContextSummary;
+use crate::suggestion::QuestionMarkFix;
 use rustc_hir as hir;
 use rustc_lint::{DiagDecorator, LateContext, LintContext};
 use rustc_middle::ty;
 use rustc_span::sym;
 use std::borrow::Cow;
 use std::fmt;
+use whitaker::hir::{EnclosingItemDescription, FindingSite};
+use whitaker_common::EnclosingItem;
 use whitaker_common::i18n::{
-    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
-    noop_reporter, safe_resolve_message_set,
+    Arguments, BundleLookup, DiagnosticMessageSet, FluentValue, Localizer, MessageKey,
+    MessageResolution, noop_reporter, safe_resolve_message_set,
 };
 #[cfg(test)]
-use whitaker_common::i18n::{BundleLookup, I18nError, resolve_message_set};
+use whitaker_common::i18n::{I18nError, resolve_message_set};
 
 /// A formatted label for the receiver type (e.g., "`Result<T, E>`").
 #[derive(Debug, Clone)]
@@ -45,10 +47,52 @@ impl fmt::Display for ReceiverLabel {
     }
 }
 
+/// Behaviour version that starts reporting `expect_err` and `unwrap_err`.
+pub(crate) const RESULT_ERR_ACCESSORS: u32 = 2;
+
+/// The panicking accessors the lint reports.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum ExpectMethod {
+    #[default]
+    Expect,
+    ExpectErr,
+    UnwrapErr,
+}
+
+impl ExpectMethod {
+    /// Returns the accessor called `name`, if the lint reports it.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "expect" => Some(Self::Expect),
+            "expect_err" => Some(Self::ExpectErr),
+            "unwrap_err" => Some(Self::UnwrapErr),
+            _ => None,
+        }
+    }
+
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Expect => "expect",
+            Self::ExpectErr => "expect_err",
+            Self::UnwrapErr => "unwrap_err",
+        }
+    }
+
+    /// Returns `true` for the `Result`-only accessors that panic on `Ok`.
+    /// They are reported only from behaviour version
+    /// [`RESULT_ERR_ACCESSORS`].
+    pub(crate) const fn panics_on_ok(self) -> bool {
+        matches!(self, Self::ExpectErr | Self::UnwrapErr)
+    }
+}
+
+/// The variant a reported call panics on, which the help asks callers to
+/// handle.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum ReceiverCategory {
     Option,
     Result,
+    ResultOk,
     Other,
 }
 
@@ -81,10 +125,20 @@ impl ReceiverCategory {
         }
     }
 
+    /// Narrows a `Result` receiver to its `Ok` variant when `method` panics
+    /// on success.
+    fn for_method(self, method: ExpectMethod) -> Self {
+        match self {
+            Self::Result if method.panics_on_ok() => Self::ResultOk,
+            category => category,
+        }
+    }
+
     fn as_key(self) -> &'static str {
         match self {
             Self::Option => "option",
             Self::Result => "result",
+            Self::ResultOk => "ok",
             Self::Other => "other",
         }
     }
@@ -97,6 +151,9 @@ impl ReceiverCategory {
             Self::Result => {
                 format!("Handle the `Err` variant of {receiver} or move the code into a test.")
             }
+            Self::ResultOk => {
+                format!("Handle the `Ok` variant of {receiver} or move the code into a test.")
+            }
             Self::Other => {
                 format!("Handle the error path for {receiver} or move the code into a test.")
             }
@@ -104,7 +161,7 @@ impl ReceiverCategory {
     }
 }
 
-/// A formatted label for the call context (e.g., "function `handler`" or "the surrounding scope").
+/// A localised label for the call context (e.g., "function `handler`" or "the surrounding scope").
 #[derive(Debug, Clone)]
 pub(crate) struct ContextLabel(String);
 
@@ -126,42 +183,86 @@ impl fmt::Display for ContextLabel {
     }
 }
 
+/// The reported call: the accessor invoked and the variant it panics on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct ExpectCall {
+    method: ExpectMethod,
+    category: ReceiverCategory,
+}
+
+impl ExpectCall {
+    fn new(method: ExpectMethod, receiver: ReceiverCategory) -> Self {
+        Self {
+            method,
+            category: receiver.for_method(method),
+        }
+    }
+
+    fn args(self, receiver: &ReceiverLabel, context: &ContextLabel) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("method"),
+            FluentValue::from(self.method.name()),
+        );
+        args.insert(
+            Cow::Borrowed("receiver"),
+            FluentValue::from(receiver.as_ref().to_string()),
+        );
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(context.as_ref().to_string()),
+        );
+        args.insert(
+            Cow::Borrowed("handling"),
+            FluentValue::from(self.category.as_key().to_string()),
+        );
+        args
+    }
+}
+
 pub(crate) struct DiagnosticContext<'a> {
-    pub(crate) summary: &'a ContextSummary,
+    pub(crate) enclosing: &'a EnclosingItemDescription,
+    pub(crate) method: ExpectMethod,
     pub(crate) localizer: &'a Localizer,
+    pub(crate) fix: Option<QuestionMarkFix>,
 }
 
 impl<'a> DiagnosticContext<'a> {
-    pub(crate) fn new(summary: &'a ContextSummary, localizer: &'a Localizer) -> Self {
-        Self { summary, localizer }
+    pub(crate) fn new(
+        enclosing: &'a EnclosingItemDescription,
+        method: ExpectMethod,
+        localizer: &'a Localizer,
+    ) -> Self {
+        Self {
+            enclosing,
+            method,
+            localizer,
+            fix: None,
+        }
+    }
+
+    /// Attaches the `?` rewrite offered alongside the diagnostic.
+    pub(crate) fn with_fix(mut self, fix: Option<QuestionMarkFix>) -> Self {
+        self.fix = fix;
+        self
     }
 }
 
 pub(crate) fn emit_diagnostic(
     cx: &LateContext<'_>,
-    expr: &hir::Expr<'_>,
+    site: &FindingSite,
     receiver: &hir::Expr<'_>,
     context: &DiagnosticContext<'_>,
 ) {
     let receiver_ty = cx.typeck_results().expr_ty(receiver).peel_refs();
     let receiver_label = ReceiverLabel::new(format!("`{}`", receiver_ty));
-    let call_context = context_label(context.summary);
-
-    let category = ReceiverCategory::classify_ty(cx, receiver_ty);
+    let call_context = context_label(&context.enclosing.item, context.localizer);
 
-    let mut args: Arguments<'static> = Arguments::default();
-    args.insert(
-        Cow::Borrowed("receiver"),
-        FluentValue::from(receiver_label.as_ref().to_string()),
-    );
-    args.insert(
-        Cow::Borrowed("context"),
-        FluentValue::from(call_context.as_ref().to_string()),
-    );
-    args.insert(
-        Cow::Borrowed("handling"),
-        FluentValue::from(category.as_key().to_string()),
+    let call = ExpectCall::new(
+        context.method,
+        ReceiverCategory::classify_ty(cx, receiver_ty),
     );
+    let args = call.args(&receiver_label, &call_context);
 
     let fallback_receiver = receiver_label.clone();
     let fallback_context = call_context.clone();
@@ -172,20 +273,30 @@ pub(crate) fn emit_diagnostic(
     };
     let messages =
         safe_resolve_message_set(context.localizer, resolution, noop_reporter, move || {
-            fallback_messages(&fallback_receiver, &fallback_context, category)
+            fallback_messages(&fallback_receiver, &fallback_context, call)
         });
 
     let primary = messages.primary().to_string();
     let note = messages.note().to_string();
     let help = messages.help().to_string();
+    let fix = context
+        .fix
+        .clone()
+        .map(|fix| (fix.label(context.localizer), fix));
 
     cx.emit_span_lint(
         NO_EXPECT_OUTSIDE_TESTS,
-        expr.span,
+        site.span,
         DiagDecorator(move |lint| {
             lint.primary_message(primary);
             lint.note(note);
+            if let Some(origin) = &site.origin {
+                lint.span_note(origin.span, origin.note.clone());
+            }
             lint.help(help);
+            if let Some((label, fix)) = fix {
+                lint.span_suggestion(fix.span, label, fix.replacement, fix.applicability);
+            }
         }),
     );
 }
@@ -199,21 +310,9 @@ fn localised_messages(
     lookup: &impl BundleLookup,
     receiver: &ReceiverLabel,
     context: &ContextLabel,
-    category: ReceiverCategory,
+    call: ExpectCall,
 ) -> Result<NoExpectMessages, I18nError> {
-    let mut args: Arguments<'static> = Arguments::default();
-    args.insert(
-        Cow::Borrowed("receiver"),
-        FluentValue::from(receiver.as_ref().to_string()),
-    );
-    args.insert(
-        Cow::Borrowed("context"),
-        FluentValue::from(context.as_ref().to_string()),
-    );
-    args.insert(
-        Cow::Borrowed("handling"),
-        FluentValue::from(category.as_key().to_string()),
-    );
+    let args = call.args(receiver, context);
 
     resolve_message_set(lookup, MESSAGE_KEY, &args)
 }
@@ -221,23 +320,18 @@ fn localised_messages(
 fn fallback_messages(
     receiver: &ReceiverLabel,
     context: &ContextLabel,
-    category: ReceiverCategory,
+    call: ExpectCall,
 ) -> NoExpectMessages {
-    let primary = format!("Avoid calling expect on {receiver} outside test-only code.");
+    let method = call.method.name();
+    let primary = format!("Avoid calling {method} on {receiver} outside test-only code.");
     let note = format!("The call originates within {context} which is not recognised as a test.",);
-    let help = category.fallback_help(receiver);
+    let help = call.category.fallback_help(receiver);
 
     NoExpectMessages::new(primary, note, help)
 }
 
-fn context_label(summary: &ContextSummary) -> ContextLabel {
-    let label = summary
-        .function_name
-        .as_ref()
-        .map(|name| format!("function `{name}`"))
-        .unwrap_or_else(|| "the surrounding scope".to_string());
-
-    ContextLabel::new(label)
+fn context_label(enclosing: &EnclosingItem, lookup: &impl BundleLookup) -> ContextLabel {
+    ContextLabel::new(enclosing.describe(lookup))
 }
 
 #[cfg(test)]
@@ -0,0 +1,43 @@
+//! Embedded documentation for the `no_expect_outside_tests` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_expect_outside_tests`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_expect_outside_tests",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Forbids `.expect(...)` on `Option` and `Result` outside test code; from behaviour version 2 it also forbids `.expect_err(...)` and `.unwrap_err()` on `Result`.",
+    rationale: "An expectation in production code turns a recoverable condition into a panic; tests, doctests, and recognised test frameworks may still use it.",
+    bad_example: "let port = env::var(\"PORT\").expect(\"PORT must be set\");",
+    good_example: "let port = env::var(\"PORT\").map_err(ConfigError::MissingPort)?;",
+    config: &[
+        ConfigKeyDocs {
+            key: "additional_test_attributes",
+            default: "[]",
+            description: "Extra attribute paths that mark a function as a test.",
+        },
+        ConfigKeyDocs {
+            key: "blame_helpers",
+            default: "[]",
+            description: "Fully qualified paths of helper functions whose findings are reported at each call to them instead of inside the helper.",
+        },
+        ConfigKeyDocs {
+            key: "allowed_files",
+            default: "[]",
+            description: "Globs, relative to the package directory, of source files where `.expect(...)` is tolerated, such as `build.rs` or `examples/**`.",
+        },
+        ConfigKeyDocs {
+            key: "allowed_modules",
+            default: "[]",
+            description: "Module globs, relative to the crate root, where `.expect(...)` is tolerated; a glob also covers the modules nested in the ones it matches.",
+        },
+        ConfigKeyDocs {
+            key: "allowed_functions",
+            default: "[]",
+            description: "Paths of functions, such as `crate::telemetry::init`, where `.expect(...)` is tolerated, including in their closures.",
+        },
+    ],
+};
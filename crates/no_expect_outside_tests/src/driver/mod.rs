@@ -1,13 +1,19 @@
 //! Lint crate forbidding `.expect(..)` outside test and doctest contexts.
 //!
-//! The lint inspects method calls named `expect`, verifies that the receiver
-//! is an `Option` or `Result`, and checks the surrounding traversal context for
-//! test-like attributes or `cfg(test)` guards. Doctest harnesses are skipped via
-//! `Crate::is_doctest`, ensuring documentation examples remain ergonomic. When
+//! The lint inspects method calls named `expect` on an `Option` or `Result`,
+//! and `expect_err` or `unwrap_err` on a `Result`, then checks the
+//! surrounding traversal context for test-like attributes or `cfg(test)`
+//! guards. Doctest crates are skipped via
+//! `whitaker::hir::is_doctest_crate`, ensuring documentation examples remain ergonomic. When
 //! no test context is present, the lint emits a denial with a note describing
-//! the enclosing function and the receiver type to guide remediation. Teams can
+//! the enclosing item (via `whitaker::hir::describe_enclosing_item`) and the
+//! receiver type to guide remediation. `expect_err` and `unwrap_err` are
+//! reported only from behaviour version 2. Teams can
 //! extend the recognized test attributes through `dylint.toml` when bespoke
-//! macros are in play.
+//! macros are in play, list `blame_helpers` whose calls should carry the
+//! findings inside them, and tolerate expectations in the files, modules, and
+//! functions named by `allowed_files`, `allowed_modules`, and
+//! `allowed_functions`.
 
 use std::collections::HashSet;
 use std::ffi::OsStr;
@@ -17,14 +23,23 @@ use log::debug;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, Ty};
-use rustc_span::{RemapPathScopeComponents, sym};
+use rustc_span::{RemapPathScopeComponents, Symbol, sym};
 use serde::Deserialize;
 use whitaker::SharedConfig;
-use whitaker::hir::has_test_like_hir_attributes;
-use whitaker_common::{AttributePath, Localizer, get_localizer_for_lint};
-
+use whitaker::hir::{
+    FindingLimit, HelperCallSites, describe_enclosing_item, has_test_like_hir_attributes,
+    is_doctest_crate,
+};
+use whitaker_common::{
+    AttributePath, ContextCache, CrateScopedState, Localizer, get_localizer_for_lint,
+};
+
+use crate::allow_list::AllowList;
 use crate::context::{collect_context, is_cfg_test_attribute, summarise_context};
-use crate::diagnostics::{DiagnosticContext, emit_diagnostic};
+use crate::diagnostics::{DiagnosticContext, ExpectMethod, RESULT_ERR_ACCESSORS, emit_diagnostic};
+use crate::suggestion::question_mark_fix;
+
+pub(crate) const LINT_NAME: &str = "no_expect_outside_tests";
 
 dylint_linting::impl_late_lint! {
     pub NO_EXPECT_OUTSIDE_TESTS,
@@ -37,44 +52,49 @@ dylint_linting::impl_late_lint! {
 struct Config {
     #[serde(default)]
     additional_test_attributes: Vec<String>,
+    #[serde(default)]
+    blame_helpers: Vec<String>,
+    #[serde(default)]
+    allowed_files: Vec<String>,
+    #[serde(default)]
+    allowed_modules: Vec<String>,
+    #[serde(default)]
+    allowed_functions: Vec<String>,
 }
 
 /// Lint pass that tracks contexts while checking method calls.
+#[derive(Default)]
 pub struct NoExpectOutsideTests {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Test detection, behaviour gates, context cache, helper call sites, and
+/// localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
     is_doctest: bool,
+    result_err_accessors: bool,
     is_test_harness: bool,
     additional_test_attributes: Vec<AttributePath>,
     harness_marked_test_functions: HashSet<hir::HirId>,
+    context_cache: ContextCache<hir::BodyId>,
+    helper_calls: HelperCallSites,
+    allow_list: AllowList,
+    findings: FindingLimit,
     localizer: Localizer,
 }
 
-impl Default for NoExpectOutsideTests {
-    fn default() -> Self {
-        Self {
-            is_doctest: false,
-            is_test_harness: false,
-            additional_test_attributes: Vec::new(),
-            harness_marked_test_functions: HashSet::new(),
-            localizer: Localizer::new(None),
-        }
-    }
-}
-
 impl<'tcx> LateLintPass<'tcx> for NoExpectOutsideTests {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
-        self.is_doctest = cx
-            .tcx
-            .env_var_os("UNSTABLE_RUSTDOC_TEST_PATH".as_ref())
-            .is_some();
-        self.is_test_harness = cx.tcx.sess.opts.test;
-        self.harness_marked_test_functions = if self.is_test_harness {
+        let is_test_harness = cx.tcx.sess.opts.test;
+        let harness_marked_test_functions = if is_test_harness {
             let mut marked = whitaker::hir::collect_harness_test_functions(cx);
             marked.extend(whitaker::hir::collect_rstest_companion_test_functions(cx));
             marked
         } else {
             HashSet::new()
         };
-        let config_name = "no_expect_outside_tests";
+        let config_name = LINT_NAME;
         let config = match dylint_linting::config::<Config>(config_name) {
             Ok(Some(config)) => config,
             Ok(None) => {
@@ -93,98 +113,158 @@ impl<'tcx> LateLintPass<'tcx> for NoExpectOutsideTests {
             }
         };
 
-        self.additional_test_attributes = config
-            .additional_test_attributes
-            .iter()
-            .map(|path| AttributePath::from(path.as_str()))
-            .collect();
-
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint("no_expect_outside_tests", shared_config.locale());
+        self.state.reset(CrateState {
+            is_doctest: is_doctest_crate(cx),
+            result_err_accessors: shared_config
+                .behaviour_version(config_name)
+                .enables(RESULT_ERR_ACCESSORS),
+            is_test_harness,
+            additional_test_attributes: config
+                .additional_test_attributes
+                .iter()
+                .map(|path| AttributePath::from(path.as_str()))
+                .collect(),
+            harness_marked_test_functions,
+            context_cache: ContextCache::new(),
+            helper_calls: HelperCallSites::for_crate(cx, &config.blame_helpers),
+            allow_list: AllowList::new(
+                &config.allowed_files,
+                &config.allowed_modules,
+                &config.allowed_functions,
+            ),
+            findings: FindingLimit::new(NO_EXPECT_OUTSIDE_TESTS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
-        if self.is_doctest {
+        let state = &mut *self.state;
+        if state.is_doctest {
             return;
         }
 
-        let hir::ExprKind::MethodCall(segment, receiver, ..) = expr.kind else {
+        let hir::ExprKind::MethodCall(segment, receiver, args, _) = expr.kind else {
+            return;
+        };
+
+        let Some(method) = ExpectMethod::from_name(segment.ident.name.as_str())
+            .filter(|method| !method.panics_on_ok() || state.result_err_accessors)
+        else {
             return;
         };
 
-        if segment.ident.name != sym::expect {
+        if !receiver_supports(cx, receiver, method) {
             return;
         }
 
-        if !receiver_is_option_or_result(cx, receiver) {
+        if state.is_test_context(cx, expr.hir_id) {
             return;
         }
 
+        // A call to a blamed helper is judged by its own context, so calls
+        // from tests stay exempt.
+        for site in state.helper_calls.finding_sites(cx, expr, &state.localizer) {
+            if site.is_redirected() && state.is_test_context(cx, site.hir_id) {
+                continue;
+            }
+            if state.allow_list.allows(cx, site.hir_id, site.span) {
+                debug!(target: LINT_NAME, "accepting `expect` in an allowed location");
+                continue;
+            }
+            if !state.findings.admit(cx, site.hir_id, site.span) {
+                continue;
+            }
+            let enclosing = describe_enclosing_item(cx, site.hir_id);
+            let fix = match args {
+                [message] if method == ExpectMethod::Expect && !site.is_redirected() => {
+                    question_mark_fix(cx, expr, receiver, message)
+                }
+                _ => None,
+            };
+            let diagnostic_context =
+                DiagnosticContext::new(&enclosing, method, &state.localizer).with_fix(fix);
+            emit_diagnostic(cx, &site, receiver, &diagnostic_context);
+        }
+    }
+}
+
+impl CrateState {
+    /// Reports whether `hir_id` sits in test-only code.
+    fn is_test_context(&mut self, cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
         let additional = self.additional_test_attributes.as_slice();
-        let (entries, has_test_context_ancestry) = collect_context(cx, expr.hir_id, additional);
+        let (entries, has_test_context_ancestry) =
+            collect_context(cx, hir_id, additional, &mut self.context_cache);
         let summary = summarise_context(entries.as_slice(), has_test_context_ancestry, additional);
 
-        if summary.is_test {
-            return;
-        }
-
         // Fallback: when compiled with --test (integration test crates), functions
         // with #[test] may not be detected via attributes if the test framework
         // processes them differently. Allow expect() in functions that appear to
         // be tests based on the harness context.
-        if self.is_test_harness
-            && is_likely_test_function(cx, expr, &self.harness_marked_test_functions, additional)
-        {
-            return;
-        }
-
-        let diagnostic_context = DiagnosticContext::new(&summary, &self.localizer);
-        emit_diagnostic(cx, expr, receiver, &diagnostic_context);
+        summary.is_test
+            || (self.is_test_harness
+                && is_likely_test_function(
+                    cx,
+                    hir_id,
+                    &self.harness_marked_test_functions,
+                    additional,
+                ))
     }
 }
 
-fn receiver_is_option_or_result<'tcx>(
+/// Reports whether `receiver` is a `Result`, or an `Option` when `method`
+/// also exists on `Option`.
+fn receiver_supports<'tcx>(
     cx: &LateContext<'tcx>,
     receiver: &'tcx hir::Expr<'tcx>,
+    method: ExpectMethod,
 ) -> bool {
     let ty = cx.typeck_results().expr_ty(receiver);
 
-    ty_is_option_or_result(cx, ty)
+    match panicking_receiver(cx, ty) {
+        Some(sym::Result) => true,
+        Some(_) => !method.panics_on_ok(),
+        None => false,
+    }
 }
 
-fn ty_is_option_or_result<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+/// Returns `sym::Option` or `sym::Result` when `ty`, behind any references,
+/// is one of them.
+fn panicking_receiver<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Symbol> {
     let ty = cx
         .tcx
         .normalize_erasing_regions(cx.typing_env(), ty::Unnormalized::new_wip(ty))
         .peel_refs();
 
-    let Some(adt) = ty.ty_adt_def() else {
-        return false;
-    };
-
-    let def_id = adt.did();
-    cx.tcx.is_diagnostic_item(sym::Option, def_id) || cx.tcx.is_diagnostic_item(sym::Result, def_id)
+    let def_id = ty.ty_adt_def()?.did();
+    [sym::Option, sym::Result]
+        .into_iter()
+        .find(|name| cx.tcx.is_diagnostic_item(*name, def_id))
 }
 
-fn is_owner_test_function<'tcx>(
-    cx: &LateContext<'tcx>,
-    expr: &hir::Expr<'tcx>,
+fn is_owner_test_function(
+    cx: &LateContext<'_>,
+    hir_id: hir::HirId,
     harness_marked_test_functions: &HashSet<hir::HirId>,
     additional_test_attributes: &[AttributePath],
 ) -> bool {
-    let owner_hir_id: hir::HirId = expr.hir_id.owner.into();
+    let owner_hir_id: hir::HirId = hir_id.owner.into();
     has_test_like_hir_attributes(cx.tcx.hir_attrs(owner_hir_id), additional_test_attributes)
         || is_harness_marked_test_function(owner_hir_id, harness_marked_test_functions)
 }
 
-fn ancestor_function_is_test<'tcx>(
-    cx: &LateContext<'tcx>,
-    expr: &hir::Expr<'tcx>,
+fn ancestor_function_is_test(
+    cx: &LateContext<'_>,
+    hir_id: hir::HirId,
     harness_marked_test_functions: &HashSet<hir::HirId>,
     additional_test_attributes: &[AttributePath],
 ) -> bool {
     cx.tcx
-        .hir_parent_iter(expr.hir_id)
+        .hir_parent_iter(hir_id)
         .filter_map(|(_, node)| extract_function_item(node))
         .any(|item| {
             let attrs = cx.tcx.hir_attrs(item.hir_id());
@@ -213,23 +293,23 @@ fn is_integration_test_crate_root(crate_root: &Path) -> bool {
 
     is_direct_test || is_multi_file_test
 }
-fn is_likely_test_function<'tcx>(
-    cx: &LateContext<'tcx>,
-    expr: &hir::Expr<'tcx>,
+fn is_likely_test_function(
+    cx: &LateContext<'_>,
+    hir_id: hir::HirId,
     harness_marked_test_functions: &HashSet<hir::HirId>,
     additional_test_attributes: &[AttributePath],
 ) -> bool {
     is_owner_test_function(
         cx,
-        expr,
+        hir_id,
         harness_marked_test_functions,
         additional_test_attributes,
     ) || ancestor_function_is_test(
         cx,
-        expr,
+        hir_id,
         harness_marked_test_functions,
         additional_test_attributes,
-    ) || is_in_cfg_test_module(cx, expr.hir_id)
+    ) || is_in_cfg_test_module(cx, hir_id)
         || is_in_tests_directory(cx)
 }
 
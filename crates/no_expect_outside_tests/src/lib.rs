@@ -8,16 +8,22 @@
 
 #![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
 
+#[cfg(feature = "dylint-driver")]
+mod allow_list;
 #[cfg(all(feature = "dylint-driver", test))]
 mod behaviour;
 #[cfg(feature = "dylint-driver")]
 mod context;
 #[cfg(feature = "dylint-driver")]
+mod decision;
+#[cfg(feature = "dylint-driver")]
 mod diagnostics;
 #[cfg(feature = "dylint-driver")]
 mod driver;
 #[cfg(all(feature = "dylint-driver", test))]
 mod lib_ui_tests;
+#[cfg(feature = "dylint-driver")]
+mod suggestion;
 #[cfg(all(feature = "dylint-driver", test))]
 mod tests;
 #[cfg(all(feature = "dylint-driver", test))]
@@ -25,9 +31,18 @@ mod ui {
     whitaker::declare_ui_tests!("ui");
 }
 
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ExpectCallProjection, NoExpectOutsideTestsDecision};
+
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
 
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
 #[cfg(not(feature = "dylint-driver"))]
 mod stub {
     #[expect(
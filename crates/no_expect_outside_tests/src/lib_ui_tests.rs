@@ -317,6 +317,12 @@ fn example_compiles_under_test_harness(#[case] name: &str, #[case] label: &str)
     "File-backed cfg(test)",
     &[],
 )]
+#[case(
+    "ui",
+    "pass_expect_in_cfg_test_impl_and_statement",
+    "cfg(test) impl block and statements",
+    &[],
+)]
 fn fixture_compiles_under_test_harness(
     #[case] directory: &str,
     #[case] fixture_name: &str,
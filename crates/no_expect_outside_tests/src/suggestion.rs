@@ -0,0 +1,310 @@
+//! Builds the `?` suggestion offered with `no_expect_outside_tests`.
+//!
+//! When the function around an `.expect(..)` call returns an `Option` or a
+//! `Result` the unwrapped value can propagate into, the `.expect(..)` can be
+//! replaced by `?`. A `Result` propagates when its error type is the
+//! function's or converts into it through `From`, which covers `thiserror`
+//! `#[from]` variants. When the function returns `anyhow::Error`, the
+//! suggestion keeps the expectation message as `.context(..)?` instead; it is
+//! machine-applicable only when `anyhow::Context` is already in scope and
+//! the message is a literal.
+//! Calls in closures, `async` bodies, and `const fn`s are left without a
+//! suggestion, as `?` there would not return from the function the author
+//! sees.
+
+use std::borrow::Cow;
+
+use rustc_hir as hir;
+use rustc_hir::def::DefKind;
+use rustc_lint::errors::Applicability;
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{Span, sym};
+use rustc_trait_selection::infer::{InferCtxtExt, TyCtxtInferExt};
+use whitaker_common::i18n::{Arguments, FluentValue, Localizer};
+
+/// The container `.expect(..)` is called on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Receiver {
+    Option,
+    Result,
+}
+
+/// What the enclosing function returns, as far as `?` is concerned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Returns {
+    Option,
+    /// A `Result` whose error type `?` converts the receiver's error into.
+    Result,
+    /// An `anyhow::Result`, noting whether `anyhow::Context` is in scope.
+    Anyhow {
+        context_in_scope: bool,
+    },
+    /// Anything the receiver cannot propagate into.
+    Other,
+}
+
+/// The rewrite offered for an `.expect(..)` call.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Fix {
+    /// Replace `.expect(..)` with `?`.
+    Propagate,
+    /// Replace `.expect(message)` with `.context(message)?`.
+    Context { machine_applicable: bool },
+}
+
+impl Fix {
+    /// The Fluent selector naming this fix.
+    pub(crate) const fn as_key(self) -> &'static str {
+        match self {
+            Self::Propagate => "propagate",
+            Self::Context { .. } => "context",
+        }
+    }
+}
+
+/// Picks the rewrite for an `.expect(..)` on `receiver` in a function that
+/// returns `returns`.
+#[must_use]
+pub(crate) fn choose_fix(receiver: Receiver, returns: Returns) -> Option<Fix> {
+    match (receiver, returns) {
+        (Receiver::Option, Returns::Option) | (Receiver::Result, Returns::Result) => {
+            Some(Fix::Propagate)
+        }
+        (_, Returns::Anyhow { context_in_scope }) => Some(Fix::Context {
+            machine_applicable: context_in_scope,
+        }),
+        _ => None,
+    }
+}
+
+/// A suggestion ready to attach to the diagnostic.
+#[derive(Clone, Debug)]
+pub(crate) struct QuestionMarkFix {
+    pub(crate) fix: Fix,
+    pub(crate) span: Span,
+    pub(crate) replacement: String,
+    pub(crate) applicability: Applicability,
+}
+
+impl QuestionMarkFix {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("fix"),
+            FluentValue::from(self.fix.as_key().to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("replacement"),
+            FluentValue::from(self.replacement.clone()),
+        );
+        args
+    }
+
+    pub(crate) fn fallback_label(&self) -> String {
+        match self.fix {
+            Fix::Propagate => String::from("propagate the failure with `?`"),
+            Fix::Context { .. } => format!("attach the message with `{}`", self.replacement),
+        }
+    }
+
+    /// Resolves the label of the suggested rewrite, stripping the
+    /// directional isolates Fluent wraps arguments in.
+    pub(crate) fn label(&self, localizer: &Localizer) -> String {
+        localizer
+            .attribute_with_args("no_expect_outside_tests", "suggestion", &self.args())
+            .unwrap_or_else(|_| self.fallback_label())
+            .chars()
+            .filter(|ch| !matches!(ch, '\u{2068}' | '\u{2069}' | '\u{FFFD}'))
+            .collect()
+    }
+}
+
+/// Returns the `?` suggestion for the `.expect(message)` call `expr` on
+/// `receiver`, when the enclosing function can take one.
+pub(crate) fn question_mark_fix<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    receiver: &'tcx hir::Expr<'tcx>,
+    message: &'tcx hir::Expr<'tcx>,
+) -> Option<QuestionMarkFix> {
+    if expr.span.from_expansion() || !receiver.span.eq_ctxt(expr.span) {
+        return None;
+    }
+    let owner = cx.tcx.hir_enclosing_body_owner(expr.hir_id);
+    if !matches!(cx.tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn)
+        || cx.tcx.is_const_fn(owner.to_def_id())
+    {
+        return None;
+    }
+
+    let receiver_ty = cx.typeck_results().expr_ty(receiver);
+    let (receiver_kind, receiver_error) = classify(cx, receiver_ty)?;
+    let output = cx
+        .tcx
+        .fn_sig(owner)
+        .instantiate_identity()
+        .skip_normalization()
+        .skip_binder()
+        .output();
+    let returns = match (classify(cx, output), receiver_error) {
+        (Some((Receiver::Option, _)), _) => Returns::Option,
+        (Some((Receiver::Result, Some(error))), None) if is_anyhow_error(cx, error) => {
+            Returns::Anyhow {
+                context_in_scope: context_in_scope(cx, expr.hir_id),
+            }
+        }
+        (Some((Receiver::Result, Some(error))), Some(from)) => {
+            if !converts(cx, from, error) {
+                Returns::Other
+            } else if is_anyhow_error(cx, error) {
+                Returns::Anyhow {
+                    context_in_scope: context_in_scope(cx, expr.hir_id),
+                }
+            } else {
+                Returns::Result
+            }
+        }
+        _ => Returns::Other,
+    };
+
+    let fix = choose_fix(receiver_kind, returns)?;
+    let replacement = match fix {
+        Fix::Propagate => String::from("?"),
+        Fix::Context { .. } => {
+            let message = cx.sess().source_map().span_to_snippet(message.span).ok()?;
+            format!(".context({message})?")
+        }
+    };
+    // `Context::context` needs a `'static` message, which only a literal
+    // is known to be.
+    let applicability = match fix {
+        Fix::Propagate => Applicability::MachineApplicable,
+        Fix::Context {
+            machine_applicable: true,
+        } if matches!(message.kind, hir::ExprKind::Lit(_)) => Applicability::MachineApplicable,
+        Fix::Context { .. } => Applicability::MaybeIncorrect,
+    };
+    Some(QuestionMarkFix {
+        fix,
+        span: receiver.span.shrink_to_hi().to(expr.span.shrink_to_hi()),
+        replacement,
+        applicability,
+    })
+}
+
+/// Classifies `ty` as an `Option` or a `Result`, with the `Result`'s error
+/// type.
+fn classify<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<(Receiver, Option<Ty<'tcx>>)> {
+    let ty::Adt(adt, args) = ty.kind() else {
+        return None;
+    };
+    if cx.tcx.is_diagnostic_item(sym::Option, adt.did()) {
+        Some((Receiver::Option, None))
+    } else if cx.tcx.is_diagnostic_item(sym::Result, adt.did()) {
+        Some((Receiver::Result, Some(args.type_at(1))))
+    } else {
+        None
+    }
+}
+
+/// Asks the trait solver whether `?` converts a `from` error into `into`.
+fn converts<'tcx>(cx: &LateContext<'tcx>, from: Ty<'tcx>, into: Ty<'tcx>) -> bool {
+    let (from, into) = (
+        cx.tcx.erase_and_anonymize_regions(from),
+        cx.tcx.erase_and_anonymize_regions(into),
+    );
+    if from == into {
+        return true;
+    }
+    let Some(from_trait) = cx.tcx.get_diagnostic_item(sym::From) else {
+        return false;
+    };
+    cx.tcx
+        .infer_ctxt()
+        .build(cx.typing_mode())
+        .type_implements_trait(from_trait, [into, from], cx.param_env)
+        .must_apply_modulo_regions()
+}
+
+fn is_anyhow_error(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Adt(adt, _) if cx.tcx.def_path_str(adt.did()) == "anyhow::Error")
+}
+
+/// Returns `true` when `anyhow::Context` is in scope for method calls at
+/// `hir_id`.
+fn context_in_scope(cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
+    cx.tcx.in_scope_traits(hir_id).is_some_and(|candidates| {
+        candidates
+            .iter()
+            .any(|candidate| cx.tcx.def_path_str(candidate.def_id) == "anyhow::Context")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::option(Receiver::Option, Returns::Option, Some(Fix::Propagate))]
+    #[case::result(Receiver::Result, Returns::Result, Some(Fix::Propagate))]
+    #[case::anyhow_in_scope(
+        Receiver::Result,
+        Returns::Anyhow { context_in_scope: true },
+        Some(Fix::Context { machine_applicable: true })
+    )]
+    #[case::anyhow_option(
+        Receiver::Option,
+        Returns::Anyhow { context_in_scope: false },
+        Some(Fix::Context { machine_applicable: false })
+    )]
+    #[case::option_into_result(Receiver::Option, Returns::Result, None)]
+    #[case::result_into_option(Receiver::Result, Returns::Option, None)]
+    #[case::unit(Receiver::Result, Returns::Other, None)]
+    fn chooses_fixes(
+        #[case] receiver: Receiver,
+        #[case] returns: Returns,
+        #[case] expected: Option<Fix>,
+    ) {
+        assert_eq!(choose_fix(receiver, returns), expected);
+    }
+
+    fn suggestion(fix: Fix, replacement: &str) -> QuestionMarkFix {
+        QuestionMarkFix {
+            fix,
+            span: rustc_span::DUMMY_SP,
+            replacement: replacement.to_owned(),
+            applicability: Applicability::MachineApplicable,
+        }
+    }
+
+    #[rstest]
+    #[case::propagate(suggestion(Fix::Propagate, "?"))]
+    #[case::context(suggestion(
+        Fix::Context { machine_applicable: true },
+        ".context(\"config should load\")?"
+    ))]
+    fn english_labels_match_the_fallback(#[case] fix: QuestionMarkFix) {
+        assert_eq!(
+            fix.label(&Localizer::new(Some("en-GB"))),
+            fix.fallback_label()
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translated_labels_keep_the_replacement(#[case] locale: &str) {
+        let fix = suggestion(
+            Fix::Context {
+                machine_applicable: false,
+            },
+            ".context(\"x\")?",
+        );
+        let label = fix.label(&Localizer::new(Some(locale)));
+
+        assert!(label.contains("`.context(\"x\")?`"), "{label}");
+        assert_ne!(label, fix.fallback_label());
+    }
+}
@@ -6,15 +6,15 @@
 //! `FailingLookup` test double.
 
 use super::{
-    I18nError, Localizer, MESSAGE_KEY, NoExpectMessages, ReceiverCategory, ReceiverLabel,
-    context_label, fallback_messages, localised_messages,
+    ExpectCall, ExpectMethod, I18nError, Localizer, MESSAGE_KEY, NoExpectMessages,
+    ReceiverCategory, ReceiverLabel, context_label, fallback_messages, localised_messages,
 };
-use crate::context::ContextSummary;
 use rstest::fixture;
 use rstest_bdd_macros::{given, scenario, then, when};
 use std::cell::{Cell, Ref, RefCell};
 use whitaker_common::i18n::BundleLookup;
 use whitaker_common::i18n::testing::FailingLookup;
+use whitaker_common::{EnclosingItem, EnclosingItemKind};
 
 fn unquote(value: &str) -> &str {
     value
@@ -43,7 +43,8 @@ fn normalize_for_assertion(value: &str) -> String {
 struct LocalizationWorld {
     localizer: RefCell<Option<Localizer>>,
     receiver: RefCell<ReceiverLabel>,
-    summary: RefCell<ContextSummary>,
+    method: Cell<ExpectMethod>,
+    enclosing: RefCell<Option<EnclosingItem>>,
     failing: Cell<bool>,
     result: RefCell<Option<Result<NoExpectMessages, I18nError>>>,
 }
@@ -63,9 +64,20 @@ impl LocalizationWorld {
         *self.receiver.borrow_mut() = ReceiverLabel::new(format_receiver(receiver));
     }
 
-    fn set_function(&self, name: Option<&str>) {
-        let mut summary = self.summary.borrow_mut();
-        summary.function_name = name.map(ToString::to_string);
+    fn call(&self) -> ExpectCall {
+        let receiver = self.receiver.borrow();
+        ExpectCall::new(self.method.get(), ReceiverCategory::for_label(&receiver))
+    }
+
+    fn set_enclosing(&self, kind: EnclosingItemKind, name: Option<&str>) {
+        *self.enclosing.borrow_mut() = name.map(|name| EnclosingItem::new(kind, name));
+    }
+
+    fn enclosing(&self) -> EnclosingItem {
+        self.enclosing
+            .borrow()
+            .clone()
+            .unwrap_or_else(EnclosingItem::scope)
     }
 
     fn record_result(&self, value: Result<NoExpectMessages, I18nError>) {
@@ -106,11 +118,22 @@ fn given_receiver(world: &LocalizationWorld, receiver: String) {
     world.set_receiver_type(unquote(&receiver));
 }
 
+#[given("the method called is {name}")]
+fn given_method_called(world: &LocalizationWorld, name: String) {
+    let method = ExpectMethod::from_name(unquote(&name)).expect("a reported method");
+    world.method.set(method);
+}
+
 #[given("the function context is {name}")]
 fn given_function(world: &LocalizationWorld, name: String) {
     let name = unquote(&name);
     let value = if name.is_empty() { None } else { Some(name) };
-    world.set_function(value);
+    world.set_enclosing(EnclosingItemKind::Function, value);
+}
+
+#[given("the method context is {name}")]
+fn given_method(world: &LocalizationWorld, name: String) {
+    world.set_enclosing(EnclosingItemKind::Method, Some(unquote(&name)));
 }
 
 #[given("the receiver type is empty")]
@@ -130,7 +153,7 @@ fn given_receiver_type_unexpected(world: &LocalizationWorld) {
 
 #[given("the call occurs outside any function")]
 fn given_no_function(world: &LocalizationWorld) {
-    world.set_function(None);
+    world.set_enclosing(EnclosingItemKind::Function, None);
 }
 
 #[given("localization fails")]
@@ -141,13 +164,16 @@ fn given_failure(world: &LocalizationWorld) {
 #[when("I localise the expect diagnostic")]
 fn when_localize(world: &LocalizationWorld) {
     let receiver = world.receiver.borrow().clone();
-    let summary = world.summary.borrow().clone();
+    let enclosing = world.enclosing();
+    let call = world.call();
 
     let result = if world.failing.get() {
         let lookup = failing_lookup();
-        execute_localization(&lookup, &receiver, &summary)
+        execute_localization(&lookup, &receiver, &enclosing, call)
     } else {
-        world.with_localizer(|localizer| execute_localization(localizer, &receiver, &summary))
+        world.with_localizer(|localizer| {
+            execute_localization(localizer, &receiver, &enclosing, call)
+        })
     };
 
     world.record_result(result);
@@ -236,14 +262,23 @@ fn scenario_failure(world: LocalizationWorld) {
     let _ = world;
 }
 
+#[scenario(path = "tests/features/localization.feature", index = 7)]
+fn scenario_method_context(world: LocalizationWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/localization.feature", index = 8)]
+fn scenario_panics_on_ok(world: LocalizationWorld) {
+    let _ = world;
+}
+
 #[then("the fallback help mentions {snippet}")]
 fn then_fallback(world: &LocalizationWorld, snippet: String) {
     let snippet = normalize_for_assertion(unquote(&snippet));
-    let summary = world.summary.borrow().clone();
-    let context = context_label(&summary);
+    let enclosing = world.enclosing();
+    let context = world.with_localizer(|localizer| context_label(&enclosing, localizer));
     let receiver = world.receiver.borrow().clone();
-    let category = ReceiverCategory::for_label(&receiver);
-    let fallback = fallback_messages(&receiver, &context, category);
+    let fallback = fallback_messages(&receiver, &context, world.call());
     let help = normalize_for_assertion(fallback.help());
     assert!(
         help.contains(&snippet),
@@ -254,11 +289,11 @@ fn then_fallback(world: &LocalizationWorld, snippet: String) {
 fn execute_localization(
     lookup: &impl BundleLookup,
     receiver: &ReceiverLabel,
-    summary: &ContextSummary,
+    enclosing: &EnclosingItem,
+    call: ExpectCall,
 ) -> Result<NoExpectMessages, I18nError> {
-    let context = context_label(summary);
-    let category = ReceiverCategory::for_label(receiver);
-    localised_messages(lookup, receiver, &context, category)
+    let context = context_label(enclosing, lookup);
+    localised_messages(lookup, receiver, &context, call)
 }
 
 fn failing_lookup() -> FailingLookup {
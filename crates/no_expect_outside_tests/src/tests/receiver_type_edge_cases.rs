@@ -1,7 +1,8 @@
 //! Edge-case localization tests covering unusual receiver labels.
 
 use super::{
-    ContextLabel, Localizer, NoExpectMessages, ReceiverCategory, ReceiverLabel, localised_messages,
+    ContextLabel, ExpectCall, ExpectMethod, Localizer, NoExpectMessages, ReceiverCategory,
+    ReceiverLabel, localised_messages,
 };
 use rstest::rstest;
 
@@ -25,8 +26,11 @@ fn handles_receiver_type_edge_cases(
     let lookup = Localizer::new(Some("en-GB"));
     let receiver_label = ReceiverLabel::new(receiver);
     let context_label = ContextLabel::new(context);
-    let category = ReceiverCategory::for_label(&receiver_label);
-    let messages = localised_messages(&lookup, &receiver_label, &context_label, category)
+    let call = ExpectCall::new(
+        ExpectMethod::Expect,
+        ReceiverCategory::for_label(&receiver_label),
+    );
+    let messages = localised_messages(&lookup, &receiver_label, &context_label, call)
         .expect("localization succeeds");
     assert!(
         assertion(&messages),
@@ -16,6 +16,7 @@ Feature: Localised diagnostics for expect usage
     When I localise the expect diagnostic
     Then the diagnostic mentions "Peidiwch"
     And the note references "Daw’r galwad"
+    And the note references "y cwmpas o’i amgylch"
 
   Scenario: Unsupported locale falls back to English
     Given the locale "zz" is selected
@@ -53,3 +54,20 @@ Feature: Localised diagnostics for expect usage
     And the function context is "worker"
     When I localise the expect diagnostic
     Then localization fails for "no_expect_outside_tests"
+
+  Scenario: Method context is described as a method
+    Given the locale "en-GB" is selected
+    And the receiver type is "Option<u8>"
+    And the method context is "load"
+    When I localise the expect diagnostic
+    Then the note references "method `load`"
+
+  Scenario: Result accessors that panic on Ok name the Ok variant
+    Given the locale "en-GB" is selected
+    And the receiver type is "Result<u16, ParseIntError>"
+    And the method called is "unwrap_err"
+    And the function context is "handler"
+    When I localise the expect diagnostic
+    Then the diagnostic mentions "calling unwrap_err on `Result<u16, ParseIntError>`"
+    And the help references "`Ok` variant"
+    And the fallback help mentions "`Ok` variant"
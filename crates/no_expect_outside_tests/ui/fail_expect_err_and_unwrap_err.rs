@@ -0,0 +1,20 @@
+//! Negative UI fixture: `.expect_err(...)` and `.unwrap_err()` outside tests
+//! must be denied at behaviour version 2.
+//! `pass_expect_err_and_unwrap_err_default.rs` holds the same calls at the
+//! default behaviour version.
+#![deny(no_expect_outside_tests)]
+
+fn parse_failure() {
+    let result: Result<u8, &'static str> = Err("boom");
+    let _error = result.expect_err("parsing should fail");
+}
+
+fn rejected() {
+    let result: Result<u8, &'static str> = Err("boom");
+    let _error = result.unwrap_err();
+}
+
+fn main() {
+    parse_failure();
+    rejected();
+}
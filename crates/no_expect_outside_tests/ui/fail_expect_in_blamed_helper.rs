@@ -0,0 +1,25 @@
+//! Negative UI fixture: `.expect(...)` in a blamed helper is reported where
+//! the helper is called, while other helpers keep their findings.
+#![deny(no_expect_outside_tests)]
+
+mod config {
+    pub fn port(raw: &str) -> u16 {
+        raw.parse::<u16>().expect("port should be a number")
+    }
+
+    pub fn host(raw: Option<&str>) -> &str {
+        raw.expect("host should be configured")
+    }
+}
+
+fn listen_port() -> u16 {
+    config::port("8080")
+}
+
+fn admin_port() -> u16 {
+    config::port("9090")
+}
+
+fn main() {
+    let _ = (listen_port(), admin_port(), config::host(Some("localhost")));
+}
@@ -0,0 +1,22 @@
+//! Negative UI fixture: notes name methods and closures as the call context.
+#![deny(no_expect_outside_tests)]
+
+struct Loader;
+
+impl Loader {
+    fn load(&self) -> u8 {
+        Some(1).expect("value should exist")
+    }
+}
+
+fn total(values: &[Option<u8>]) -> u8 {
+    values
+        .iter()
+        .map(|value| value.expect("value should exist"))
+        .sum()
+}
+
+fn main() {
+    let _ = Loader.load();
+    let _ = total(&[Some(1)]);
+}
@@ -0,0 +1,27 @@
+//! Negative UI fixture: `.expect(...)` in a function returning `Option` or a
+//! compatible `Result` is offered a `?` rewrite.
+#![deny(no_expect_outside_tests)]
+
+#[derive(Debug)]
+struct ConfigError;
+
+impl From<std::num::ParseIntError> for ConfigError {
+    fn from(_: std::num::ParseIntError) -> Self {
+        Self
+    }
+}
+
+fn first(values: &[i32]) -> Option<i32> {
+    let value = values.first().copied().expect("values should not be empty");
+    Some(value)
+}
+
+fn port(raw: &str) -> Result<u16, ConfigError> {
+    let port = raw.parse::<u16>().expect("port should be numeric");
+    Ok(port)
+}
+
+fn main() {
+    let _ = first(&[1]);
+    let _ = port("80");
+}
@@ -0,0 +1,14 @@
+//! Negative UI fixture: a nested `whitaker.toml` that ignores the lint
+//! silences only the files beneath its directory.
+//!
+//! The `legacy` module's `expect` is not reported, while `main` is.
+#![deny(no_expect_outside_tests)]
+
+#[path = "fail_expect_with_directory_ignore/legacy/loader.rs"]
+mod legacy;
+
+fn main() {
+    let _ = legacy::load();
+    let value = Some("value");
+    let _ = value.expect("outside the legacy directory");
+}
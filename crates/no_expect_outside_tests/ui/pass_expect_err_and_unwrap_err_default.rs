@@ -0,0 +1,21 @@
+//! Positive UI fixture: `.expect_err(...)` and `.unwrap_err()` outside tests
+//! are accepted at the default behaviour version.
+//!
+//! The calls match `fail_expect_err_and_unwrap_err.rs`, which opts in to
+//! behaviour version 2.
+#![deny(no_expect_outside_tests)]
+
+fn parse_failure() {
+    let result: Result<u8, &'static str> = Err("boom");
+    let _error = result.expect_err("parsing should fail");
+}
+
+fn rejected() {
+    let result: Result<u8, &'static str> = Err("boom");
+    let _error = result.unwrap_err();
+}
+
+fn main() {
+    parse_failure();
+    rejected();
+}
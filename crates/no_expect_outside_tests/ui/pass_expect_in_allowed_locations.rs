@@ -0,0 +1,24 @@
+//! Positive UI fixture: `.expect(...)` in allowed modules and functions is
+//! accepted.
+#![deny(no_expect_outside_tests)]
+
+mod telemetry {
+    pub fn init() -> u16 {
+        let port = Some(4317);
+        let configure = || port.expect("telemetry port is compiled in");
+        configure()
+    }
+}
+
+mod scripts {
+    pub mod seed {
+        pub fn run() -> &'static str {
+            Some("seed").expect("seed data is compiled in")
+        }
+    }
+}
+
+fn main() {
+    let _ = telemetry::init();
+    let _ = scripts::seed::run();
+}
@@ -0,0 +1,25 @@
+//! Positive UI fixture: allow `.expect(...)` under `#[cfg(test)]` applied to
+//! an `impl` block, a statement, and an expression.
+#![deny(no_expect_outside_tests)]
+
+struct Widget;
+
+#[cfg(test)]
+impl Widget {
+    fn fixture_value() -> i32 {
+        Some(1).expect("cfg(test) impl block permits expect")
+    }
+}
+
+fn main() {
+    let _widget = Widget;
+
+    #[cfg(test)]
+    let _value = Some(2).expect("cfg(test) statement permits expect");
+
+    #[cfg(test)]
+    {
+        let _ = Widget::fixture_value();
+        let _ = Some(3).expect("cfg(test) block expression permits expect");
+    }
+}
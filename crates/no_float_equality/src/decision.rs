@@ -0,0 +1,125 @@
+//! Pure decision logic for `no_float_equality`.
+//!
+//! The driver finds each `==` or `!=` between floating-point operands and
+//! records the macros it was expanded through and whether it sits in test
+//! code. [`NoFloatEqualityDecision`] classifies the comparison with
+//! [`Origin::classify`] over the same [`ApproxMacros`] and reports it when
+//! [`should_flag`] does, naming the float type as the finding's subject.
+//! Redirecting findings to the callers of test helpers is left to the driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{ApproxMacros, Comparison, Origin, should_flag};
+
+/// Reason key for exact floating-point comparisons.
+const EXACT_COMPARISON: &str = "exact_comparison";
+
+/// The facts about one floating-point comparison that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FloatComparisonProjection {
+    /// The compared type, `f32` or `f64`.
+    pub float_type: String,
+    /// The macros the comparison was expanded through, innermost first.
+    pub backtrace: Vec<String>,
+    /// Whether the comparison was expanded from another crate's macro.
+    pub external: bool,
+    /// Whether the comparison sits in test code.
+    pub in_test: bool,
+}
+
+/// Reports exact floating-point comparisons written outside tests and
+/// approximate-comparison macros.
+///
+/// # Examples
+///
+/// ```ignore
+/// let comparison = FloatComparisonProjection {
+///     float_type: "f64".to_owned(),
+///     ..FloatComparisonProjection::default()
+/// };
+/// assert!(NoFloatEqualityDecision::default().decide(&comparison).is_some());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NoFloatEqualityDecision {
+    approx_macros: ApproxMacros,
+}
+
+impl NoFloatEqualityDecision {
+    /// Creates a decision accepting comparisons made by the macros named by
+    /// `approx_macros`.
+    #[must_use]
+    pub fn new(approx_macros: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            approx_macros: ApproxMacros::new(approx_macros),
+        }
+    }
+}
+
+impl LintDecision for NoFloatEqualityDecision {
+    type Projection = FloatComparisonProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, comparison: &FloatComparisonProjection) -> Option<Finding> {
+        let origin = Origin::classify(
+            &self.approx_macros,
+            comparison.backtrace.iter().map(String::as_str),
+            comparison.external,
+        );
+        let comparison_facts = Comparison {
+            origin,
+            in_test: comparison.in_test,
+        };
+        should_flag(&comparison_facts).then(|| {
+            Finding::new(LINT_NAME, EXACT_COMPARISON).with_subject(comparison.float_type.as_str())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<FloatComparisonProjection>> {
+        let comparison = |backtrace: &[&str], external, in_test| FloatComparisonProjection {
+            float_type: "f64".to_owned(),
+            backtrace: backtrace.iter().map(|name| (*name).to_owned()).collect(),
+            external,
+            in_test,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "comparison in source",
+                comparison(&[], false, false),
+                EXACT_COMPARISON,
+            ),
+            DecisionFixture::flagged(
+                "comparison in a local macro",
+                comparison(&["same_value"], false, false),
+                EXACT_COMPARISON,
+            ),
+            DecisionFixture::clean("comparison in a test", comparison(&[], false, true)),
+            DecisionFixture::clean(
+                "comparison from a derive",
+                comparison(&["PartialEq"], true, false),
+            ),
+            DecisionFixture::clean(
+                "approximate comparison",
+                comparison(&["relative_eq"], true, false),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&NoFloatEqualityDecision::default()),
+            Ok(5)
+        );
+    }
+}
@@ -0,0 +1,33 @@
+//! Embedded documentation for the `no_float_equality` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_float_equality`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_float_equality",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `==` and `!=` between floating-point values outside test code.",
+    rationale: "Rounding leaves results that should be equal differing in their last bits, so an exact comparison fails or succeeds by accident. A tolerance states how close is close enough, and `total_cmp` makes an intended exact comparison explicit.",
+    bad_example: "if measured == expected {\n    // ...\n}",
+    good_example: "if (measured - expected).abs() <= TOLERANCE {\n    // ...\n}",
+    config: &[
+        ConfigKeyDocs {
+            key: "approx_macros",
+            default: "[\"abs_diff_eq\", \"abs_diff_ne\", \"relative_eq\", \"relative_ne\", \"ulps_eq\", \"ulps_ne\", \"assert_abs_diff_eq\", \"assert_abs_diff_ne\", \"assert_relative_eq\", \"assert_relative_ne\", \"assert_ulps_eq\", \"assert_ulps_ne\", \"approx_eq\", \"assert_approx_eq\", \"float_eq\", \"float_ne\", \"assert_float_eq\", \"assert_float_ne\"]",
+            description: "Names of macros whose comparisons account for rounding and are skipped. Setting the list replaces the defaults.",
+        },
+        ConfigKeyDocs {
+            key: "additional_test_attributes",
+            default: "[]",
+            description: "Extra attribute paths that mark a function as a test.",
+        },
+        ConfigKeyDocs {
+            key: "blame_helpers",
+            default: "[]",
+            description: "Fully qualified paths of helper functions whose findings are reported at each call to them instead of inside the helper.",
+        },
+    ],
+};
@@ -0,0 +1,261 @@
+//! Detect `==` and `!=` between floating-point values.
+//!
+//! The lint inspects every equality comparison whose left operand, once
+//! references are peeled, is an `f16`, `f32`, `f64`, or `f128`. The
+//! comparison's origin is then classified: a comparison whose macro
+//! backtrace includes one of `approx_macros` comes from an approximate
+//! comparison, one expanded from another crate's macro or derive is
+//! external, and one expanded from a macro defined in the crate is local.
+//! Test code is exempt through [`whitaker::hir::TestContext`]. Findings inside
+//! `blame_helpers` are reported at each non-test call through
+//! [`whitaker::hir::HelperCallSites`].
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::{BinOpKind, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty;
+use rustc_span::{ExpnKind, Span};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, FindingSite, HelperCallSites, TestContext};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::{AttributePath, CrateScopedState};
+
+use crate::policy::{ApproxMacros, Comparison, DEFAULT_APPROX_MACROS, Origin, should_flag};
+
+pub(crate) const LINT_NAME: &str = "no_float_equality";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    approx_macros: Vec<String>,
+    additional_test_attributes: Vec<String>,
+    blame_helpers: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            approx_macros: DEFAULT_APPROX_MACROS
+                .iter()
+                .map(|name| (*name).to_owned())
+                .collect(),
+            additional_test_attributes: Vec::new(),
+            blame_helpers: Vec::new(),
+        }
+    }
+}
+
+/// Lint pass reporting exact floating-point equality comparisons.
+#[derive(Default)]
+pub struct NoFloatEquality {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Approximate-comparison macros, test context, helper call sites, and
+/// localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    approx_macros: ApproxMacros,
+    test_context: TestContext,
+    helper_calls: HelperCallSites,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_FLOAT_EQUALITY,
+    Warn,
+    "comparing floating-point values with `==` or `!=` depends on rounding; compare against a tolerance or use `total_cmp`",
+    NoFloatEquality::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoFloatEquality {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let config = load_configuration();
+        let additional = config
+            .additional_test_attributes
+            .iter()
+            .map(|path| AttributePath::from(path.as_str()))
+            .collect();
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            approx_macros: ApproxMacros::new(config.approx_macros),
+            test_context: TestContext::for_crate(cx, additional),
+            helper_calls: HelperCallSites::for_crate(cx, &config.blame_helpers),
+            findings: FindingLimit::new(NO_FLOAT_EQUALITY, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ExprKind::Binary(op, left, _) = expr.kind else {
+            return;
+        };
+        if !matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) {
+            return;
+        }
+        let ty = cx.typeck_results().expr_ty(left).peel_refs();
+        let ty::Float(float) = ty.kind() else {
+            return;
+        };
+
+        let comparison = Comparison {
+            origin: origin(cx, expr.span, &self.state.approx_macros),
+            in_test: self.state.test_context.is_test(cx, expr.hir_id),
+        };
+        if !should_flag(&comparison) {
+            return;
+        }
+
+        let info = FloatComparison {
+            operator: op.node.as_str(),
+            ty: float.name_str(),
+        };
+        debug!(target: LINT_NAME, "`{}` between `{}` values", info.operator, info.ty);
+        let state = &*self.state;
+        for site in state.helper_calls.finding_sites(cx, expr, &state.localizer) {
+            if site.is_redirected() && state.test_context.is_test(cx, site.hir_id) {
+                continue;
+            }
+            if state.findings.admit(cx, site.hir_id, site.span) {
+                emit_diagnostic(cx, &site, &info, &state.localizer);
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Classifies where the comparison spanning `span` was written from the
+/// names of the macros it was expanded through.
+fn origin(cx: &LateContext<'_>, span: Span, approx_macros: &ApproxMacros) -> Origin {
+    let names: Vec<_> = span
+        .macro_backtrace()
+        .filter_map(|expn| match expn.kind {
+            ExpnKind::Macro(_, name) => Some(name),
+            _ => None,
+        })
+        .collect();
+    Origin::classify(
+        approx_macros,
+        names.iter().map(|name| name.as_str()),
+        span.in_external_macro(cx.sess().source_map()),
+    )
+}
+
+/// An exact comparison between floating-point values.
+#[derive(Clone, Copy, Debug)]
+struct FloatComparison {
+    operator: &'static str,
+    ty: &'static str,
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    site: &FindingSite,
+    info: &FloatComparison,
+    localizer: &Localizer,
+) {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("operator"), FluentValue::from(info.operator));
+    args.insert(Cow::Borrowed("ty"), FluentValue::from(info.ty));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(info)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let origin = site.origin.clone();
+
+    cx.emit_span_lint(
+        NO_FLOAT_EQUALITY,
+        site.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            if let Some(origin) = origin {
+                lint.span_note(origin.span, origin.note);
+            }
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(info: &FloatComparison) -> DiagnosticMessageSet {
+    let FloatComparison { operator, ty } = info;
+    DiagnosticMessageSet::new(
+        format!("Comparing `{ty}` values with `{operator}` tests for exact equality."),
+        String::from(
+            "Rounding leaves results that should be equal differing in their last bits, so an \
+             exact comparison can fail or succeed by accident.",
+        ),
+        format!(
+            "Compare the difference against a tolerance, such as `(a - b).abs() <= \
+             {ty}::EPSILON`, or use `{ty}::total_cmp` when an exact comparison is intended."
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_lists_the_known_approx_macros() {
+        let config = Config::default();
+
+        assert_eq!(config.approx_macros, DEFAULT_APPROX_MACROS);
+        assert!(config.additional_test_attributes.is_empty());
+        assert!(config.blame_helpers.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_type_and_operator() {
+        let messages = fallback_messages(&FloatComparison {
+            operator: "!=",
+            ty: "f32",
+        });
+
+        assert_eq!(
+            messages.primary(),
+            "Comparing `f32` values with `!=` tests for exact equality."
+        );
+        assert!(messages.help().contains("f32::total_cmp"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
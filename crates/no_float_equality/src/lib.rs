@@ -0,0 +1,43 @@
+//! Lint crate flagging exact equality comparisons between floating-point
+//! values.
+//!
+//! Rounding means two computations that should give the same `f64` often
+//! differ in their last bits, so `a == b` quietly turns false and `a != b`
+//! quietly turns true. The lint reports `==` and `!=` between floating-point
+//! operands outside test code and suggests comparing against a tolerance or
+//! using `total_cmp`. Comparisons produced by `approx`-style macros, which
+//! already account for rounding, are recognized and skipped. The lint is
+//! experimental and ships behind the `experimental-no-float-equality` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{FloatComparisonProjection, NoFloatEqualityDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_float_equality_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
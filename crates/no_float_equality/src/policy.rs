@@ -0,0 +1,195 @@
+//! Deciding whether a floating-point equality comparison is reported.
+//!
+//! The driver describes each `==` or `!=` between floating-point operands by
+//! where the comparison came from and whether it sits in test code. This
+//! module decides whether it is reported and recognizes `approx`-style
+//! macros by name, so the decision can be tested without the compiler.
+
+/// Approximate-comparison macros recognized when `approx_macros` is not set.
+///
+/// The list covers the `approx`, `float_cmp`, `float_eq`, and
+/// `assert_approx_eq` crates.
+pub(crate) const DEFAULT_APPROX_MACROS: &[&str] = &[
+    "abs_diff_eq",
+    "abs_diff_ne",
+    "relative_eq",
+    "relative_ne",
+    "ulps_eq",
+    "ulps_ne",
+    "assert_abs_diff_eq",
+    "assert_abs_diff_ne",
+    "assert_relative_eq",
+    "assert_relative_ne",
+    "assert_ulps_eq",
+    "assert_ulps_ne",
+    "approx_eq",
+    "assert_approx_eq",
+    "float_eq",
+    "float_ne",
+    "assert_float_eq",
+    "assert_float_ne",
+];
+
+/// The configured approximate-comparison macros.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ApproxMacros {
+    names: Vec<String>,
+}
+
+impl Default for ApproxMacros {
+    fn default() -> Self {
+        Self::new(DEFAULT_APPROX_MACROS.iter().map(|name| (*name).to_owned()))
+    }
+}
+
+impl ApproxMacros {
+    /// Builds the list from configured macro names or paths.
+    ///
+    /// Only the final segment of a path such as `approx::relative_eq` is
+    /// kept, because macros are recognized by name.
+    pub(crate) fn new(names: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            names: names
+                .into_iter()
+                .map(|name| match name.rsplit_once("::") {
+                    Some((_, last)) => last.to_owned(),
+                    None => name,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns `true` when `name` is one of the configured macros.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let macros = ApproxMacros::default();
+    /// assert!(macros.recognizes("assert_relative_eq"));
+    /// assert!(!macros.recognizes("assert_eq"));
+    /// ```
+    pub(crate) fn recognizes(&self, name: &str) -> bool {
+        self.names.iter().any(|candidate| candidate == name)
+    }
+}
+
+/// Where a comparison's `==` or `!=` was written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Origin {
+    /// Written directly in the crate's source.
+    Source,
+    /// Produced by a macro defined in the crate being checked.
+    LocalMacro,
+    /// Produced by a macro or derive from another crate.
+    ExternalMacro,
+    /// Produced by, or written inside, an approximate-comparison macro.
+    ApproxMacro,
+}
+
+impl Origin {
+    /// Classifies a comparison from the macros it was expanded through.
+    ///
+    /// `backtrace` names each macro from the innermost expansion outwards and
+    /// is empty for a comparison written in source. `external` records
+    /// whether the comparison was expanded from another crate's macro.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let macros = ApproxMacros::default();
+    /// assert_eq!(Origin::classify(&macros, ["assert_relative_eq"], true), Origin::ApproxMacro);
+    /// assert_eq!(Origin::classify(&macros, [], false), Origin::Source);
+    /// ```
+    pub(crate) fn classify<'a>(
+        approx_macros: &ApproxMacros,
+        backtrace: impl IntoIterator<Item = &'a str>,
+        external: bool,
+    ) -> Self {
+        let mut backtrace = backtrace.into_iter().peekable();
+        if backtrace.peek().is_none() {
+            Self::Source
+        } else if backtrace.any(|name| approx_macros.recognizes(name)) {
+            Self::ApproxMacro
+        } else if external {
+            Self::ExternalMacro
+        } else {
+            Self::LocalMacro
+        }
+    }
+}
+
+/// A floating-point equality comparison as seen by the policy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Comparison {
+    pub(crate) origin: Origin,
+    pub(crate) in_test: bool,
+}
+
+/// Returns `true` when `comparison` should be reported.
+///
+/// Comparisons written in the crate, directly or through its own macros, are
+/// reported outside test code. Those produced by other crates' macros and
+/// derives, or by approximate-comparison macros, are accepted.
+///
+/// # Examples
+///
+/// ```ignore
+/// let comparison = Comparison { origin: Origin::Source, in_test: false };
+/// assert!(should_flag(&comparison));
+/// ```
+#[must_use]
+pub(crate) fn should_flag(comparison: &Comparison) -> bool {
+    !comparison.in_test && matches!(comparison.origin, Origin::Source | Origin::LocalMacro)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::source(Origin::Source, false, true)]
+    #[case::local_macro(Origin::LocalMacro, false, true)]
+    #[case::external_macro(Origin::ExternalMacro, false, false)]
+    #[case::approx_macro(Origin::ApproxMacro, false, false)]
+    #[case::source_in_test(Origin::Source, true, false)]
+    fn decision(#[case] origin: Origin, #[case] in_test: bool, #[case] expected: bool) {
+        assert_eq!(should_flag(&Comparison { origin, in_test }), expected);
+    }
+
+    #[rstest]
+    #[case::source(&[], false, Origin::Source)]
+    #[case::nested_approx(&["assert", "assert_relative_eq"], true, Origin::ApproxMacro)]
+    #[case::external(&["assert_eq"], true, Origin::ExternalMacro)]
+    #[case::local(&["check_close"], false, Origin::LocalMacro)]
+    fn classification(
+        #[case] backtrace: &[&str],
+        #[case] external: bool,
+        #[case] expected: Origin,
+    ) {
+        let origin = Origin::classify(
+            &ApproxMacros::default(),
+            backtrace.iter().copied(),
+            external,
+        );
+
+        assert_eq!(origin, expected);
+    }
+
+    #[rstest]
+    #[case::approx("assert_relative_eq", true)]
+    #[case::float_cmp("approx_eq", true)]
+    #[case::float_eq("assert_float_eq", true)]
+    #[case::std("assert_eq", false)]
+    fn default_macros(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(ApproxMacros::default().recognizes(name), expected);
+    }
+
+    #[rstest]
+    fn configured_paths_match_by_name_and_replace_the_defaults() {
+        let macros = ApproxMacros::new([String::from("crate::util::close_enough")]);
+
+        assert!(macros.recognizes("close_enough"));
+        assert!(!macros.recognizes("relative_eq"));
+    }
+}
@@ -0,0 +1,100 @@
+//! Behaviour-driven coverage for the float equality policy.
+
+use crate::policy::{ApproxMacros, Comparison, Origin, should_flag};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct ComparisonWorld {
+    macro_name: RefCell<Option<String>>,
+    local_macro: Cell<bool>,
+    approx_macros: RefCell<ApproxMacros>,
+    in_test: Cell<bool>,
+    flagged: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> ComparisonWorld {
+    ComparisonWorld {
+        macro_name: RefCell::new(None),
+        local_macro: Cell::new(false),
+        approx_macros: RefCell::new(ApproxMacros::default()),
+        in_test: Cell::new(false),
+        flagged: Cell::new(None),
+    }
+}
+
+#[given("a comparison written in source")]
+fn given_source(world: &ComparisonWorld) {
+    *world.macro_name.borrow_mut() = None;
+}
+
+#[given("a comparison expanded from a macro named {name}")]
+fn given_macro(world: &ComparisonWorld, name: String) {
+    *world.macro_name.borrow_mut() = Some(name);
+}
+
+#[given("the macro is defined in the crate")]
+fn given_local_macro(world: &ComparisonWorld) {
+    world.local_macro.set(true);
+}
+
+#[given("the approx macros are {path}")]
+fn given_approx_macros(world: &ComparisonWorld, path: String) {
+    *world.approx_macros.borrow_mut() = ApproxMacros::new([path]);
+}
+
+#[given("the comparison is in test code")]
+fn given_in_test(world: &ComparisonWorld) {
+    world.in_test.set(true);
+}
+
+#[when("the comparison is checked")]
+fn when_checked(world: &ComparisonWorld) {
+    let macro_name = world.macro_name.borrow();
+    let origin = Origin::classify(
+        &world.approx_macros.borrow(),
+        macro_name.as_deref(),
+        !world.local_macro.get(),
+    );
+    let comparison = Comparison {
+        origin,
+        in_test: world.in_test.get(),
+    };
+    world.flagged.set(Some(should_flag(&comparison)));
+}
+
+#[then("the comparison is reported")]
+fn then_reported(world: &ComparisonWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the comparison is accepted")]
+fn then_accepted(world: &ComparisonWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/no_float_equality.feature", index = 0)]
+fn scenario_source_comparison(world: ComparisonWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_float_equality.feature", index = 1)]
+fn scenario_local_macro_comparison(world: ComparisonWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_float_equality.feature", index = 2)]
+fn scenario_approx_macro_comparison(world: ComparisonWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_float_equality.feature", index = 3)]
+fn scenario_configured_approx_macro(world: ComparisonWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_float_equality.feature", index = 4)]
+fn scenario_comparison_in_tests(world: ComparisonWorld) {
+    let _ = world;
+}
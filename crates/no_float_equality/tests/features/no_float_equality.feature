@@ -0,0 +1,32 @@
+Feature: No float equality
+  Exact equality comparisons between floating-point values are reported
+  outside test code, unless an approximate-comparison macro produced them.
+
+  Scenario: A comparison written in source is reported
+    Given a comparison written in source
+    When the comparison is checked
+    Then the comparison is reported
+
+  Scenario: A comparison from a crate-local macro is reported
+    Given a comparison expanded from a macro named check_close
+    And the macro is defined in the crate
+    When the comparison is checked
+    Then the comparison is reported
+
+  Scenario: A comparison from an approx macro is accepted
+    Given a comparison expanded from a macro named assert_relative_eq
+    When the comparison is checked
+    Then the comparison is accepted
+
+  Scenario: A configured approx macro is accepted
+    Given a comparison expanded from a macro named close_enough
+    And the macro is defined in the crate
+    And the approx macros are util::close_enough
+    When the comparison is checked
+    Then the comparison is accepted
+
+  Scenario: A comparison in test code is accepted
+    Given a comparison written in source
+    And the comparison is in test code
+    When the comparison is checked
+    Then the comparison is accepted
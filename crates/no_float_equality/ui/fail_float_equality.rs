@@ -0,0 +1,26 @@
+//! Negative UI fixture: exact comparisons between floating-point values.
+#![warn(no_float_equality)]
+
+macro_rules! same {
+    ($left:expr, $right:expr) => {
+        $left == $right
+    };
+}
+
+fn is_origin(x: f64, y: f64) -> bool {
+    x == 0.0 && y == 0.0
+}
+
+fn changed(previous: &f32, current: &f32) -> bool {
+    previous != current
+}
+
+fn matches_target(reading: f64, target: f64) -> bool {
+    same!(reading, target)
+}
+
+fn main() {
+    let _ = is_origin(0.0, 0.0);
+    let _ = changed(&1.0, &2.0);
+    let _ = matches_target(1.0, 1.0);
+}
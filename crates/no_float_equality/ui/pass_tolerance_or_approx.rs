@@ -0,0 +1,49 @@
+//! Positive UI fixture: tolerances, `total_cmp`, approx-style macros,
+//! derives, integers, and test code are accepted.
+#![warn(no_float_equality)]
+
+const TOLERANCE: f64 = 1e-9;
+
+macro_rules! approx_eq {
+    ($left:expr, $right:expr) => {
+        ($left - $right).abs() <= TOLERANCE || $left == $right
+    };
+}
+
+#[derive(PartialEq)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() <= TOLERANCE
+}
+
+fn identical(a: f64, b: f64) -> bool {
+    a.total_cmp(&b).is_eq()
+}
+
+fn settled(a: f64, b: f64) -> bool {
+    approx_eq!(a, b)
+}
+
+fn same_count(a: u32, b: u32) -> bool {
+    a == b
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn exact_results_in_tests() {
+        assert!(0.5_f64 + 0.25 == 0.75);
+    }
+}
+
+fn main() {
+    let _ = close(1.0, 1.0);
+    let _ = identical(1.0, 1.0);
+    let _ = settled(1.0, 1.0);
+    let _ = same_count(1, 1);
+    let _ = Point { x: 0.0, y: 0.0 } == Point { x: 0.0, y: 0.0 };
+}
@@ -0,0 +1,145 @@
+//! Pure decision logic for `no_glob_reexports`.
+//!
+//! The driver finds exported `pub use path::*;` items in library crates and
+//! hands over the module each is written in, the path it imports from, and
+//! the names it makes public. [`NoGlobReexportsDecision`] exempts preludes
+//! with the same [`Preludes`] and names the [`explicit_reexport`] replacing
+//! the glob, when there is one, as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::path::ModuleGlobs;
+
+use crate::driver::{DEFAULT_PRELUDE_MODULES, LINT_NAME};
+use crate::policy::{Preludes, explicit_reexport};
+
+/// Reason key for glob re-exports outside preludes.
+const GLOB_REEXPORT: &str = "glob_reexport";
+
+/// The facts about one glob re-export that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GlobReexportProjection {
+    /// The module the re-export is written in, relative to the crate root.
+    pub module: String,
+    /// The path the glob imports from, as written.
+    pub source: String,
+    /// The names the glob makes public.
+    pub names: Vec<String>,
+}
+
+/// Reports glob re-exports in a library's public API outside preludes.
+///
+/// # Examples
+///
+/// ```ignore
+/// let glob = GlobReexportProjection {
+///     module: "api".to_owned(),
+///     source: "crate::types".to_owned(),
+///     names: vec!["Config".to_owned()],
+/// };
+/// let finding = NoGlobReexportsDecision::default().decide(&glob);
+/// assert_eq!(
+///     finding.as_ref().and_then(Finding::subject),
+///     Some("pub use crate::types::Config;"),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct NoGlobReexportsDecision {
+    preludes: Preludes,
+}
+
+impl NoGlobReexportsDecision {
+    /// Creates a decision exempting the modules matching `prelude_modules`.
+    #[must_use]
+    pub fn new(prelude_modules: &[String]) -> Self {
+        Self {
+            preludes: Preludes::new(ModuleGlobs::new(
+                LINT_NAME,
+                "prelude_modules",
+                prelude_modules,
+            )),
+        }
+    }
+}
+
+impl Default for NoGlobReexportsDecision {
+    fn default() -> Self {
+        let globs: Vec<String> = DEFAULT_PRELUDE_MODULES
+            .iter()
+            .map(|glob| (*glob).to_owned())
+            .collect();
+        Self::new(&globs)
+    }
+}
+
+impl LintDecision for NoGlobReexportsDecision {
+    type Projection = GlobReexportProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, glob: &GlobReexportProjection) -> Option<Finding> {
+        if self.preludes.exempts(&glob.module, &glob.source) {
+            return None;
+        }
+        let finding = Finding::new(LINT_NAME, GLOB_REEXPORT);
+        Some(match explicit_reexport(&glob.source, &glob.names) {
+            Some(replacement) => finding.with_subject(replacement),
+            None => finding,
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<GlobReexportProjection>> {
+        let glob = |module: &str, source: &str| GlobReexportProjection {
+            module: module.to_owned(),
+            source: source.to_owned(),
+            names: vec!["Config".to_owned(), "load".to_owned()],
+        };
+        vec![
+            DecisionFixture::flagged(
+                "glob in the API",
+                glob("api", "crate::types"),
+                GLOB_REEXPORT,
+            ),
+            DecisionFixture::flagged(
+                "glob at the crate root",
+                glob("", "crate::types"),
+                GLOB_REEXPORT,
+            ),
+            DecisionFixture::clean("glob inside a prelude", glob("prelude", "crate::types")),
+            DecisionFixture::clean("forwarded prelude", glob("", "crate::io::prelude")),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_suggest_the_explicit_reexport() {
+        let glob = GlobReexportProjection {
+            module: "api".to_owned(),
+            source: "crate::types".to_owned(),
+            names: vec!["load".to_owned(), "Config".to_owned()],
+        };
+        let finding = NoGlobReexportsDecision::default()
+            .decide(&glob)
+            .expect("the glob should be reported");
+
+        assert_eq!(
+            finding.subject(),
+            Some("pub use crate::types::{Config, load};")
+        );
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&NoGlobReexportsDecision::default()),
+            Ok(4)
+        );
+    }
+}
@@ -0,0 +1,21 @@
+//! Embedded documentation for the `no_glob_reexports` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_glob_reexports`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_glob_reexports",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `pub use path::*;` re-exports in the public API of library crates, outside prelude modules.",
+    rationale: "A glob re-export publishes whatever its source module exports, so adding, renaming, or removing an item there changes the crate's API without any change where the API is declared. Listing the names keeps the public surface explicit and reviewable.",
+    bad_example: "pub use crate::parser::*;",
+    good_example: "pub use crate::parser::{Parser, parse};",
+    config: &[ConfigKeyDocs {
+        key: "prelude_modules",
+        default: "[\"prelude\", \"*::prelude\"]",
+        description: "Module path globs naming prelude modules. Glob re-exports written in or importing from a matching module are exempt.",
+    }],
+};
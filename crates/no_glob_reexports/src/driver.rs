@@ -0,0 +1,303 @@
+//! Lint pass reporting glob re-exports in the public API of library crates.
+//!
+//! Each `use path::*;` item that the crate exports, according to the
+//! compiler's effective visibilities, is a glob re-export. The module it is
+//! written in is rendered with [`whitaker::hir::module_path`] and, with the
+//! path as written, checked against the configured [`Preludes`]. Reported
+//! re-exports suggest the names the glob makes public, read from the
+//! module's children, through [`explicit_reexport`]. Binary crates have no
+//! public API and are skipped, as are items produced by macro expansion.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::attrs::CrateType;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::metadata::Reexport;
+use rustc_span::Span;
+use rustc_span::symbol::kw;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::path::ModuleGlobs;
+
+use crate::policy::{Preludes, explicit_reexport};
+
+pub(crate) const LINT_NAME: &str = "no_glob_reexports";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_PRELUDE_MODULES: &[&str] = &["prelude", "*::prelude"];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    prelude_modules: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            prelude_modules: DEFAULT_PRELUDE_MODULES
+                .iter()
+                .map(|glob| (*glob).to_owned())
+                .collect(),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_GLOB_REEXPORTS,
+    Warn,
+    "library crates should re-export items by name rather than with `pub use path::*`",
+    NoGlobReexports::default()
+}
+
+/// Lint pass that finds glob re-exports in a library's public API.
+#[derive(Default)]
+pub struct NoGlobReexports {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Prelude globs, crate kind, finding limit, and localization for the crate
+/// being checked.
+#[derive(Default)]
+struct CrateState {
+    preludes: Preludes,
+    is_library: bool,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoGlobReexports {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        let globs = load_configuration().prelude_modules;
+        self.state.reset(CrateState {
+            preludes: Preludes::new(ModuleGlobs::new(LINT_NAME, "prelude_modules", &globs)),
+            is_library: cx
+                .tcx
+                .crate_types()
+                .iter()
+                .any(|crate_type| matches!(crate_type, CrateType::Rlib | CrateType::Dylib)),
+            findings: FindingLimit::new(NO_GLOB_REEXPORTS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let hir::ItemKind::Use(path, hir::UseKind::Glob) = item.kind else {
+            return;
+        };
+        let state = &*self.state;
+        if !state.is_library
+            || item.span.from_expansion()
+            || !cx.effective_visibilities.is_exported(item.owner_id.def_id)
+        {
+            return;
+        }
+
+        let module = module_path(cx, item.hir_id());
+        let source = path
+            .segments
+            .iter()
+            .filter(|segment| segment.ident.name != kw::PathRoot)
+            .map(|segment| segment.ident.name.as_str())
+            .collect::<Vec<_>>()
+            .join("::");
+        if state.preludes.exempts(&module, &source) {
+            return;
+        }
+
+        debug!(target: LINT_NAME, "glob re-export of `{source}` in module `{module}`");
+        if state.findings.admit(cx, item.hir_id(), item.span) {
+            let names = reexported_names(cx, item);
+            let message = GlobReexportMessage {
+                source: &source,
+                replacement: explicit_reexport(&source, &names),
+            };
+            emit_diagnostic(cx, item.span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the public names the glob `item` brings into its module.
+fn reexported_names(cx: &LateContext<'_>, item: &hir::Item<'_>) -> Vec<String> {
+    let glob = item.owner_id.to_def_id();
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    cx.tcx
+        .module_children_local(module.to_local_def_id())
+        .iter()
+        .filter(|child| {
+            child.vis.is_public()
+                && matches!(child.reexport_chain.first(), Some(Reexport::Glob(id)) if *id == glob)
+        })
+        .map(|child| child.ident.to_string())
+        .collect()
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &GlobReexportMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        NO_GLOB_REEXPORTS,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one glob re-export interpolates.
+#[derive(Clone, Debug)]
+struct GlobReexportMessage<'a> {
+    source: &'a str,
+    replacement: Option<String>,
+}
+
+impl GlobReexportMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("source"),
+            FluentValue::from(self.source.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("fix"),
+            FluentValue::from(if self.replacement.is_some() {
+                "list"
+            } else {
+                "remove"
+            }),
+        );
+        args.insert(
+            Cow::Borrowed("replacement"),
+            FluentValue::from(self.replacement.clone().unwrap_or_default()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let source = self.source;
+        DiagnosticMessageSet::new(
+            format!("Glob re-export of `{source}` in the public API."),
+            format!(
+                "Every public item added to or removed from `{source}` changes this crate's API \
+                 without the change showing here."
+            ),
+            match &self.replacement {
+                Some(replacement) => {
+                    format!("Re-export the items by name: `{replacement}`.")
+                }
+                None => String::from("The glob makes nothing public; remove it."),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(replacement: Option<&str>) -> GlobReexportMessage<'static> {
+        GlobReexportMessage {
+            source: "crate::parser",
+            replacement: replacement.map(str::to_owned),
+        }
+    }
+
+    fn resolve(message: &GlobReexportMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_exempts_preludes() {
+        assert_eq!(Config::default().prelude_modules, ["prelude", "*::prelude"]);
+    }
+
+    #[rstest]
+    fn fallback_help_gives_the_replacement() {
+        let messages = message(Some("pub use crate::parser::{Parser, parse};")).fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Glob re-export of `crate::parser` in the public API."
+        );
+        assert_eq!(
+            messages.help(),
+            "Re-export the items by name: `pub use crate::parser::{Parser, parse};`."
+        );
+    }
+
+    #[rstest]
+    #[case::listed(Some("pub use crate::parser::Parser;"))]
+    #[case::empty(None)]
+    fn english_messages_match_the_fallback(#[case] replacement: Option<&str>) {
+        let message = message(replacement);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(Some("pub use crate::parser::Parser;")), locale);
+
+        assert!(messages.primary().contains("`crate::parser`"));
+        assert!(messages.help().contains("`pub use crate::parser::Parser;`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
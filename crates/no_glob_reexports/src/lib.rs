@@ -0,0 +1,41 @@
+//! Lint crate flagging glob re-exports in library crates.
+//!
+//! A `pub use path::*;` in a library makes its public API whatever `path`
+//! happens to export: adding an item there publishes it, and renaming one
+//! breaks callers, with nothing in the re-exporting module to review. The
+//! lint reports glob re-exports the crate exports and suggests the names to
+//! list instead. Re-exports inside, or forwarding, a prelude module matched
+//! by `prelude_modules` are exempt. The lint is experimental and ships behind
+//! the `experimental-no-glob-reexports` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{GlobReexportProjection, NoGlobReexportsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_glob_reexports_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,141 @@
+//! Rules deciding which glob re-exports are reported and what replaces them.
+//!
+//! The driver hands over the module a `pub use path::*;` is written in and
+//! the path it imports from, both as strings. A re-export is exempt when
+//! either names a prelude, matched by the configured globs. The names the
+//! glob brings into the public API become the explicit list suggested in its
+//! place. The rules are kept free of compiler types so they can be tested
+//! directly.
+
+use whitaker_common::path::ModuleGlobs;
+
+/// Names listed in a suggested replacement before the rest are counted.
+const MAX_LISTED_NAMES: usize = 6;
+
+/// Leading path segments that locate a path rather than name a module.
+const PATH_ANCHORS: [&str; 4] = ["{{root}}", "crate", "self", "super"];
+
+/// Module path globs naming prelude modules.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Preludes {
+    globs: ModuleGlobs,
+}
+
+impl Preludes {
+    /// Wraps the compiled `prelude_modules` globs.
+    #[must_use]
+    pub(crate) const fn new(globs: ModuleGlobs) -> Self {
+        Self { globs }
+    }
+
+    /// Returns `true` when a glob re-export written in `module`, importing
+    /// from `source`, is exempt.
+    ///
+    /// `source` is the path as written, so `crate::`, `self::`, and `super::`
+    /// prefixes are dropped before matching. The re-export is exempt when it
+    /// sits inside a prelude, as in `prelude { pub use crate::types::*; }`,
+    /// or forwards one, as in `pub use crate::prelude::*;`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let preludes = Preludes::new(ModuleGlobs::new("demo", "prelude_modules", &["*prelude".into()]));
+    /// assert!(preludes.exempts("prelude", "crate::types"));
+    /// assert!(preludes.exempts("", "self::prelude"));
+    /// assert!(!preludes.exempts("api", "crate::types"));
+    /// ```
+    #[must_use]
+    pub(crate) fn exempts(&self, module: &str, source: &str) -> bool {
+        (!module.is_empty() && self.globs.matches(module))
+            || self.globs.matches(&unanchored(source))
+    }
+}
+
+/// Drops the leading `crate`, `self`, `super`, and root segments of `path`.
+fn unanchored(path: &str) -> String {
+    path.split("::")
+        .skip_while(|segment| segment.is_empty() || PATH_ANCHORS.contains(segment))
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Returns the explicit re-export that could replace `pub use {source}::*;`,
+/// given the `names` the glob makes public, or `None` when it makes nothing
+/// public.
+///
+/// Names are sorted, and beyond six the rest are counted rather than listed.
+///
+/// # Examples
+///
+/// ```ignore
+/// let names = ["Parser".to_owned(), "parse".to_owned()];
+/// assert_eq!(
+///     explicit_reexport("crate::parser", &names).as_deref(),
+///     Some("pub use crate::parser::{Parser, parse};"),
+/// );
+/// ```
+#[must_use]
+pub(crate) fn explicit_reexport(source: &str, names: &[String]) -> Option<String> {
+    let mut names: Vec<&str> = names.iter().map(String::as_str).collect();
+    names.sort_unstable();
+    names.dedup();
+    match names.as_slice() {
+        [] => None,
+        [name] => Some(format!("pub use {source}::{name};")),
+        _ if names.len() > MAX_LISTED_NAMES => {
+            let listed = names[..MAX_LISTED_NAMES].join(", ");
+            let rest = names.len() - MAX_LISTED_NAMES;
+            Some(format!(
+                "pub use {source}::{{{listed}, ...}}; // and {rest} more"
+            ))
+        }
+        _ => Some(format!("pub use {source}::{{{}}};", names.join(", "))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn preludes() -> Preludes {
+        let globs = ["prelude".to_owned(), "*::prelude".to_owned()];
+        Preludes::new(ModuleGlobs::new("test", "prelude_modules", &globs))
+    }
+
+    #[rstest]
+    #[case::inside_prelude("prelude", "crate::types", true)]
+    #[case::inside_nested_prelude("io::prelude", "super::reader", true)]
+    #[case::forwarding_prelude("", "self::prelude", true)]
+    #[case::forwarding_external_prelude("", "tokio::prelude", true)]
+    #[case::plain("", "crate::types", false)]
+    #[case::prelude_named_item("api", "crate::preludes", false)]
+    fn preludes_are_exempt(
+        preludes: Preludes,
+        #[case] module: &str,
+        #[case] source: &str,
+        #[case] exempt: bool,
+    ) {
+        assert_eq!(preludes.exempts(module, source), exempt);
+    }
+
+    #[rstest]
+    fn nothing_is_exempt_without_globs() {
+        assert!(!Preludes::default().exempts("prelude", "crate::prelude"));
+    }
+
+    #[rstest]
+    #[case::none(&[], None)]
+    #[case::one(&["Parser"], Some("pub use parser::Parser;"))]
+    #[case::sorted(&["parse", "Parser", "Error"], Some("pub use parser::{Error, Parser, parse};"))]
+    #[case::many(
+        &["A", "B", "C", "D", "E", "F", "G", "H"],
+        Some("pub use parser::{A, B, C, D, E, F, ...}; // and 2 more")
+    )]
+    fn replacements_list_the_names(#[case] names: &[&str], #[case] expected: Option<&str>) {
+        let names: Vec<String> = names.iter().map(|&name| name.to_owned()).collect();
+
+        assert_eq!(explicit_reexport("parser", &names).as_deref(), expected);
+    }
+}
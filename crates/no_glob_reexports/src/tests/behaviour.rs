@@ -0,0 +1,87 @@
+//! Behaviour-driven coverage for the glob re-export policy.
+
+use super::Config;
+use crate::policy::{Preludes, explicit_reexport};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use whitaker_common::path::ModuleGlobs;
+
+#[derive(Default)]
+struct GlobWorld {
+    preludes: RefCell<Option<Vec<String>>>,
+    module: RefCell<String>,
+    source: RefCell<String>,
+    names: RefCell<Vec<String>>,
+    reported: RefCell<Option<Option<String>>>,
+}
+
+#[fixture]
+fn world() -> GlobWorld {
+    GlobWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+#[given("the prelude modules {glob}")]
+fn given_preludes(world: &GlobWorld, glob: String) {
+    *world.preludes.borrow_mut() = Some(vec![unquote(&glob)]);
+}
+
+#[given("a glob re-export of {source} in module {module}")]
+fn given_glob(world: &GlobWorld, source: String, module: String) {
+    *world.source.borrow_mut() = unquote(&source);
+    *world.module.borrow_mut() = unquote(&module);
+}
+
+#[given("the glob makes {first} and {second} public")]
+fn given_names(world: &GlobWorld, first: String, second: String) {
+    *world.names.borrow_mut() = vec![unquote(&first), unquote(&second)];
+}
+
+#[when("the re-export is checked")]
+fn when_checked(world: &GlobWorld) {
+    let globs = world
+        .preludes
+        .borrow()
+        .clone()
+        .unwrap_or_else(|| Config::default().prelude_modules);
+    let preludes = Preludes::new(ModuleGlobs::new("test", "prelude_modules", &globs));
+    let source = world.source.borrow();
+    let reported = (!preludes.exempts(&world.module.borrow(), &source))
+        .then(|| explicit_reexport(&source, &world.names.borrow()))
+        .flatten();
+    *world.reported.borrow_mut() = Some(reported);
+}
+
+#[then("it is reported with the replacement {replacement}")]
+fn then_reported(world: &GlobWorld, replacement: String) {
+    assert_eq!(*world.reported.borrow(), Some(Some(unquote(&replacement))));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &GlobWorld) {
+    assert_eq!(*world.reported.borrow(), Some(None));
+}
+
+#[scenario(path = "tests/features/no_glob_reexports.feature", index = 0)]
+fn scenario_reported(world: GlobWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_glob_reexports.feature", index = 1)]
+fn scenario_inside_prelude(world: GlobWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_glob_reexports.feature", index = 2)]
+fn scenario_forwarding_prelude(world: GlobWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_glob_reexports.feature", index = 3)]
+fn scenario_configured_preludes(world: GlobWorld) {
+    let _ = world;
+}
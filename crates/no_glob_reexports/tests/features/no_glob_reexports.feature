@@ -0,0 +1,26 @@
+Feature: Glob re-exports
+  Glob re-exports in a library's public API are reported with the names
+  they make public, unless they belong to a prelude.
+
+  Scenario: A glob re-export is reported with its names
+    Given a glob re-export of "crate::parser" in module "api"
+    And the glob makes "Parser" and "parse" public
+    When the re-export is checked
+    Then it is reported with the replacement "pub use crate::parser::{Parser, parse};"
+
+  Scenario: A glob inside a prelude is exempt
+    Given a glob re-export of "crate::parser" in module "prelude"
+    When the re-export is checked
+    Then nothing is reported
+
+  Scenario: A glob forwarding a prelude is exempt
+    Given a glob re-export of "crate::io::prelude" in module "api"
+    When the re-export is checked
+    Then nothing is reported
+
+  Scenario: Configured prelude globs replace the defaults
+    Given the prelude modules "exports"
+    And a glob re-export of "crate::prelude" in module "api"
+    And the glob makes "Parser" and "parse" public
+    When the re-export is checked
+    Then it is reported with the replacement "pub use crate::prelude::{Parser, parse};"
@@ -0,0 +1,24 @@
+//! Negative UI fixture: glob re-exports in a library's public API.
+#![crate_type = "lib"]
+
+pub mod parser {
+    pub struct Parser;
+
+    pub fn parse() -> Parser {
+        Parser
+    }
+}
+
+pub mod shapes {
+    pub enum Shape {
+        Circle,
+        Square,
+    }
+}
+
+pub mod prelude {
+    pub use crate::parser::*;
+}
+
+pub use crate::parser::*;
+pub use crate::shapes::Shape::*;
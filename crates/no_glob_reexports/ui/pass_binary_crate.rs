@@ -0,0 +1,13 @@
+//! Positive UI fixture: a binary crate has no public API to protect.
+
+pub mod parser {
+    pub fn parse() -> usize {
+        0
+    }
+}
+
+pub use crate::parser::*;
+
+fn main() {
+    let _ = parse();
+}
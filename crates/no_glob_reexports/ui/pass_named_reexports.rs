@@ -0,0 +1,27 @@
+//! Positive UI fixture: named re-exports, preludes, and globs that stay
+//! private.
+#![crate_type = "lib"]
+
+pub mod parser {
+    pub struct Parser;
+
+    pub fn parse() -> Parser {
+        Parser
+    }
+}
+
+pub mod prelude {
+    pub use crate::parser::*;
+}
+
+mod internal {
+    pub use crate::parser::*;
+}
+
+pub use crate::parser::{Parser, parse};
+pub use crate::prelude::*;
+
+pub fn build() -> Parser {
+    use crate::internal::*;
+    parse()
+}
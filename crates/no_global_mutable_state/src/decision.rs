@@ -0,0 +1,123 @@
+//! Pure decision logic for `no_global_mutable_state`.
+//!
+//! The driver describes each static by whether it is `static mut`, the chain
+//! of type names reached through each type's first generic argument, and the
+//! module declaring it. [`NoGlobalMutableStateDecision`] finds the lock with
+//! the same [`locked_interior`] and skips the allowed modules. Findings use
+//! `static_mut` or `locked` as their reason, and name the lock as their
+//! subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::path::AllowedModules;
+
+use crate::driver::LINT_NAME;
+use crate::policy::locked_interior;
+
+/// Reason key for `static mut` items.
+const STATIC_MUT: &str = "static_mut";
+/// Reason key for shared statics holding a lock.
+const LOCKED: &str = "locked";
+
+/// The facts about one static that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StaticProjection {
+    /// Whether the item is `static mut`.
+    pub mutable: bool,
+    /// The static's type name followed by those of its first generic
+    /// arguments, outermost first.
+    pub type_chain: Vec<String>,
+    /// The module declaring the static, relative to the crate root.
+    pub module: String,
+}
+
+/// Reports `static mut` items and statics holding a lock behind lazy cells,
+/// outside the allowed modules.
+///
+/// # Examples
+///
+/// ```ignore
+/// let registry = StaticProjection {
+///     type_chain: vec!["OnceLock".to_owned(), "Mutex".to_owned()],
+///     ..StaticProjection::default()
+/// };
+/// let finding = NoGlobalMutableStateDecision::default().decide(&registry);
+/// assert_eq!(finding.as_ref().and_then(Finding::subject), Some("Mutex"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NoGlobalMutableStateDecision {
+    allowed_modules: AllowedModules,
+}
+
+impl NoGlobalMutableStateDecision {
+    /// Creates a decision accepting globals in modules matching
+    /// `allowed_modules`.
+    #[must_use]
+    pub fn new(allowed_modules: &[String]) -> Self {
+        Self {
+            allowed_modules: AllowedModules::new(LINT_NAME, allowed_modules),
+        }
+    }
+}
+
+impl LintDecision for NoGlobalMutableStateDecision {
+    type Projection = StaticProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, item: &StaticProjection) -> Option<Finding> {
+        let finding = if item.mutable {
+            Finding::new(LINT_NAME, STATIC_MUT)
+        } else {
+            let names: Vec<&str> = item.type_chain.iter().map(String::as_str).collect();
+            Finding::new(LINT_NAME, LOCKED).with_subject(locked_interior(&names)?)
+        };
+        (!self.allowed_modules.allows(&item.module)).then_some(finding)
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<StaticProjection>> {
+        let item = |mutable, type_chain: &[&str]| StaticProjection {
+            mutable,
+            type_chain: type_chain.iter().map(|name| (*name).to_owned()).collect(),
+            module: "registry".to_owned(),
+        };
+        vec![
+            DecisionFixture::flagged("static mut", item(true, &["Vec", "String"]), STATIC_MUT),
+            DecisionFixture::flagged(
+                "lock behind a lazy cell",
+                item(false, &["LazyLock", "Mutex", "Vec"]),
+                LOCKED,
+            ),
+            DecisionFixture::clean("lazy value", item(false, &["OnceLock", "String"])),
+            DecisionFixture::clean("lock inside a collection", item(false, &["Vec", "Mutex"])),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn allowed_modules_keep_their_globals() {
+        let decision = NoGlobalMutableStateDecision::new(&["registry".to_owned()]);
+        let item = StaticProjection {
+            mutable: true,
+            module: "registry::cache".to_owned(),
+            ..StaticProjection::default()
+        };
+
+        assert_eq!(decision.decide(&item), None);
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&NoGlobalMutableStateDecision::default()),
+            Ok(4)
+        );
+    }
+}
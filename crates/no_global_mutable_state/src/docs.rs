@@ -0,0 +1,38 @@
+//! Embedded documentation for the `no_global_mutable_state` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_global_mutable_state`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_global_mutable_state",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags statics holding a `Mutex`, `RwLock`, or `RefCell`, including through lazy cells and `lazy_static!`, and every `static mut`.",
+    rationale: "Global mutable state can be reached from every module, so functions depend on values they never receive, and tests that share the global interfere with one another. Passing the state explicitly keeps dependencies visible and lets each test build its own.",
+    bad_example: concat!(
+        "static REGISTRY: LazyLock<Mutex<Vec<Handler>>> = LazyLock::new(Default::default);\n",
+        "\n",
+        "fn register(handler: Handler) {\n",
+        "    REGISTRY.lock().expect(\"registry lock poisoned\").push(handler);\n",
+        "}",
+    ),
+    good_example: concat!(
+        "struct Registry {\n",
+        "    handlers: Vec<Handler>,\n",
+        "}\n",
+        "\n",
+        "impl Registry {\n",
+        "    fn register(&mut self, handler: Handler) {\n",
+        "        self.handlers.push(handler);\n",
+        "    }\n",
+        "}",
+    ),
+    config: &[ConfigKeyDocs {
+        key: "allowed_modules",
+        default: "[]",
+        description: "Module path globs, relative to the crate root, whose modules and submodules may declare global mutable state.",
+    }],
+};
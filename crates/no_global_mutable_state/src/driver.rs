@@ -0,0 +1,273 @@
+//! Detect statics holding global mutable state.
+//!
+//! Every `static mut` is reported. A shared static is reported when its type,
+//! after any lazy cells such as `LazyLock` or `OnceLock`, is a `Mutex`,
+//! `RwLock`, or `RefCell`. Statics declared through `lazy_static!` have a
+//! macro-generated type that dereferences to the declared one, so for
+//! macro-generated statics with a user-written name the lint inspects the
+//! `Deref` target instead. Statics in modules matching an `allowed_modules`
+//! glob are accepted.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::{ItemKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty, TyCtxt, Unnormalized};
+use rustc_span::Ident;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::path::AllowedModules;
+
+use crate::policy::locked_interior;
+
+pub(crate) const LINT_NAME: &str = "no_global_mutable_state";
+const LOCKED_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const STATIC_MUT_KEY: MessageKey<'static> = MessageKey::new("no_global_mutable_state-static-mut");
+
+/// Generic arguments followed when naming a static's type.
+const MAX_TYPE_DEPTH: usize = 4;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed_modules: Vec<String>,
+}
+
+/// Lint pass reporting crate-level mutable singletons.
+#[derive(Default)]
+pub struct NoGlobalMutableState {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Allowed modules and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    allowed_modules: AllowedModules,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_GLOBAL_MUTABLE_STATE,
+    Warn,
+    "crate-level mutable singletons hide shared state; pass the state explicitly",
+    NoGlobalMutableState::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoGlobalMutableState {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            allowed_modules: AllowedModules::new(LINT_NAME, &load_configuration().allowed_modules),
+            findings: FindingLimit::new(NO_GLOBAL_MUTABLE_STATE, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let ItemKind::Static(mutability, ident, _, _) = item.kind else {
+            return;
+        };
+        if ident.span.from_expansion() {
+            return;
+        }
+        let Some(state) = classify(cx, item, mutability) else {
+            return;
+        };
+
+        let module_path = module_path(cx, item.hir_id());
+        if self.state.allowed_modules.allows(&module_path) {
+            debug!(
+                target: LINT_NAME,
+                "accepting `{ident}` in allowed module `{module_path}`"
+            );
+            return;
+        }
+        if self.state.findings.admit(cx, item.hir_id(), ident.span) {
+            emit_diagnostic(cx, ident, &state, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// The kind of global mutable state a static holds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum GlobalState {
+    /// A `static mut` item.
+    StaticMut,
+    /// A shared static holding the named lock.
+    Locked(String),
+}
+
+fn classify<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &hir::Item<'tcx>,
+    mutability: Mutability,
+) -> Option<GlobalState> {
+    if mutability == Mutability::Mut {
+        return Some(GlobalState::StaticMut);
+    }
+
+    let ty = cx
+        .tcx
+        .type_of(item.owner_id)
+        .instantiate_identity()
+        .skip_normalization();
+    held_lock(cx.tcx, ty)
+        .or_else(|| {
+            item.span
+                .from_expansion()
+                .then(|| deref_target(cx, ty))
+                .flatten()
+                .and_then(|target| held_lock(cx.tcx, target))
+        })
+        .map(GlobalState::Locked)
+}
+
+/// Returns the lock `ty` holds behind any lazy cells.
+fn held_lock<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<String> {
+    let names = type_chain(tcx, ty);
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    locked_interior(&names).map(str::to_owned)
+}
+
+/// Names `ty` and the types reached through each first generic argument.
+fn type_chain<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current = Some(ty);
+    while let Some(ty::Adt(adt, args)) = current.map(|ty| ty.kind()) {
+        if names.len() == MAX_TYPE_DEPTH {
+            break;
+        }
+        names.push(tcx.item_name(adt.did()).to_string());
+        current = args.types().next();
+    }
+    names
+}
+
+/// Resolves `<ty as Deref>::Target`, as generated by `lazy_static!`.
+fn deref_target<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    let target = cx.tcx.lang_items().deref_target()?;
+    let projection = Ty::new_projection(cx.tcx, target, [ty]);
+    cx.tcx
+        .try_normalize_erasing_regions(cx.typing_env(), Unnormalized::new_wip(projection))
+        .ok()
+}
+
+/// Returns the path of the module declaring `item`, relative to the crate
+/// root.
+fn emit_diagnostic(cx: &LateContext<'_>, ident: Ident, state: &GlobalState, localizer: &Localizer) {
+    let name = ident.name.to_string();
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("name"), FluentValue::from(name.clone()));
+    let key = match state {
+        GlobalState::StaticMut => STATIC_MUT_KEY,
+        GlobalState::Locked(lock) => {
+            args.insert(Cow::Borrowed("lock"), FluentValue::from(lock.clone()));
+            LOCKED_KEY
+        }
+    };
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(&name, state)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        NO_GLOBAL_MUTABLE_STATE,
+        ident.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(name: &str, state: &GlobalState) -> DiagnosticMessageSet {
+    let primary = match state {
+        GlobalState::StaticMut => format!("`static mut` item `{name}` is global mutable state."),
+        GlobalState::Locked(lock) => {
+            format!("`{name}` is a global mutable singleton guarded by a `{lock}`.")
+        }
+    };
+    DiagnosticMessageSet::new(
+        primary,
+        String::from(
+            "Every module can reach global state, so callers depend on values they never \
+             receive and tests interfere with one another.",
+        ),
+        format!(
+            "Pass the state behind `{name}` explicitly, or list its module in \
+             `allowed_modules` if the global is deliberate."
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_allows_no_modules() {
+        assert!(Config::default().allowed_modules.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_lock() {
+        let messages = fallback_messages("REGISTRY", &GlobalState::Locked(String::from("Mutex")));
+
+        assert!(messages.primary().contains("`REGISTRY`"));
+        assert!(messages.primary().contains("`Mutex`"));
+        assert!(messages.help().contains("allowed_modules"));
+    }
+
+    #[rstest]
+    fn fallback_messages_describe_static_mut() {
+        let messages = fallback_messages("COUNTER", &GlobalState::StaticMut);
+
+        assert!(
+            messages
+                .primary()
+                .starts_with("`static mut` item `COUNTER`")
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,42 @@
+//! Lint crate flagging crate-level mutable singletons.
+//!
+//! A global `Mutex`, `RwLock`, or `RefCell` hides shared state behind a name
+//! every module can reach, so callers depend on state they never receive and
+//! tests interfere with one another. The lint reports statics holding such a
+//! lock, directly or inside a lazy cell such as `LazyLock`, `OnceLock`, or
+//! `once_cell::sync::Lazy`, those declared through `lazy_static!`, and every
+//! `static mut`. Modules matching an `allowed_modules` glob, such as
+//! `metrics` or `logging`, may keep their globals. It is experimental and
+//! ships behind the `experimental-no-global-mutable-state` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{NoGlobalMutableStateDecision, StaticProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_global_mutable_state_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,52 @@
+//! Classification of global state.
+//!
+//! The driver describes a static by the chain of type names reached by
+//! following each type's first generic argument, so `OnceLock<Mutex<T>>`
+//! becomes `["OnceLock", "Mutex", ...]`. [`locked_interior`] decides whether
+//! that chain is a lock behind any number of lazy cells. Whether the declaring
+//! module may keep its globals is decided by
+//! `whitaker_common::path::AllowedModules`.
+
+/// Cells that initialise their contents on first use.
+const LAZY_CELLS: &[&str] = &["Lazy", "LazyCell", "LazyLock", "OnceCell", "OnceLock"];
+
+/// Types granting mutable access through a shared reference.
+const LOCKS: &[&str] = &["Mutex", "RefCell", "RwLock"];
+
+/// Returns the lock at the heart of `type_names`, if any.
+///
+/// Leading lazy cells are skipped; the first remaining type must be a lock.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(locked_interior(&["OnceLock", "Mutex", "Vec"]), Some("Mutex"));
+/// assert_eq!(locked_interior(&["OnceLock", "String"]), None);
+/// ```
+pub(crate) fn locked_interior<'a>(type_names: &[&'a str]) -> Option<&'a str> {
+    type_names
+        .iter()
+        .copied()
+        .find(|name| !LAZY_CELLS.contains(name))
+        .filter(|name| LOCKS.contains(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::once_lock_mutex(&["OnceLock", "Mutex", "Vec"], Some("Mutex"))]
+    #[case::lazy_lock_rwlock(&["LazyLock", "RwLock", "HashMap"], Some("RwLock"))]
+    #[case::once_cell_lazy_refcell(&["Lazy", "RefCell", "String"], Some("RefCell"))]
+    #[case::bare_mutex(&["Mutex", "u32"], Some("Mutex"))]
+    #[case::nested_cells(&["OnceLock", "LazyLock", "Mutex"], Some("Mutex"))]
+    #[case::plain_value(&["OnceLock", "String"], None)]
+    #[case::atomic(&["AtomicU32"], None)]
+    #[case::lock_behind_other_type(&["Vec", "Mutex"], None)]
+    #[case::empty(&[], None)]
+    fn classifies_lock_chains(#[case] names: &[&str], #[case] expected: Option<&str>) {
+        assert_eq!(locked_interior(names), expected);
+    }
+}
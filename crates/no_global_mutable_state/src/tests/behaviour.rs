@@ -0,0 +1,113 @@
+//! Behaviour-driven coverage for global mutable state detection.
+
+use super::{GlobalState, LINT_NAME, fallback_messages};
+use crate::policy::locked_interior;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use whitaker_common::i18n::DiagnosticMessageSet;
+use whitaker_common::path::AllowedModules;
+
+#[derive(Default)]
+struct GlobalWorld {
+    type_names: RefCell<Vec<String>>,
+    is_mut: RefCell<bool>,
+    module_path: RefCell<String>,
+    allowed: RefCell<Vec<String>>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> GlobalWorld {
+    GlobalWorld::default()
+}
+
+/// Splits a written type such as `OnceLock<Mutex<u8>>` into its chain of
+/// names.
+fn type_names(written: &str) -> Vec<String> {
+    written
+        .split('<')
+        .map(|name| name.trim_end_matches('>').to_owned())
+        .collect()
+}
+
+#[given("a static of type {written} in module {module}")]
+fn given_static(world: &GlobalWorld, written: String, module: String) {
+    *world.type_names.borrow_mut() = type_names(&written);
+    *world.module_path.borrow_mut() = module;
+}
+
+#[given("a static mut of type {written} in module {module}")]
+fn given_static_mut(world: &GlobalWorld, written: String, module: String) {
+    given_static(world, written, module);
+    *world.is_mut.borrow_mut() = true;
+}
+
+#[given("allowed modules matching {glob}")]
+fn given_allowed(world: &GlobalWorld, glob: String) {
+    world.allowed.borrow_mut().push(glob);
+}
+
+#[when("the static is checked")]
+fn when_checked(world: &GlobalWorld) {
+    let names = world.type_names.borrow();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let state = if *world.is_mut.borrow() {
+        Some(GlobalState::StaticMut)
+    } else {
+        locked_interior(&names).map(|lock| GlobalState::Locked(lock.to_owned()))
+    };
+    let allowed = AllowedModules::new(LINT_NAME, &world.allowed.borrow());
+    let messages = state
+        .filter(|_| !allowed.allows(&world.module_path.borrow()))
+        .map(|state| fallback_messages("STATE", &state));
+    *world.messages.borrow_mut() = messages;
+}
+
+#[then("the static is accepted")]
+fn then_accepted(world: &GlobalWorld) {
+    assert!(world.messages.borrow().is_none());
+}
+
+#[then("the static is reported as guarded by a {lock}")]
+fn then_reported_lock(world: &GlobalWorld, lock: String) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert!(
+        messages
+            .primary()
+            .contains(&format!("guarded by a `{lock}`"))
+    );
+}
+
+#[then("the static is reported as static mut")]
+fn then_reported_static_mut(world: &GlobalWorld) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert!(messages.primary().starts_with("`static mut` item `STATE`"));
+}
+
+#[scenario(path = "tests/features/no_global_mutable_state.feature", index = 0)]
+fn scenario_lazy_mutex(world: GlobalWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_global_mutable_state.feature", index = 1)]
+fn scenario_lazy_plain_value(world: GlobalWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_global_mutable_state.feature", index = 2)]
+fn scenario_static_mut(world: GlobalWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_global_mutable_state.feature", index = 3)]
+fn scenario_allowed_module(world: GlobalWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_global_mutable_state.feature", index = 4)]
+fn scenario_unrelated_module(world: GlobalWorld) {
+    let _ = world;
+}
@@ -0,0 +1,30 @@
+Feature: Global mutable state
+  Statics holding a lock behind any lazy cells, and every `static mut`, are
+  reported unless their module matches an `allowed_modules` glob.
+
+  Scenario: A lazily initialised mutex is reported
+    Given a static of type OnceLock<Mutex<Vec<u8>>> in module app::state
+    When the static is checked
+    Then the static is reported as guarded by a Mutex
+
+  Scenario: A lazily initialised plain value is accepted
+    Given a static of type OnceLock<String> in module app::state
+    When the static is checked
+    Then the static is accepted
+
+  Scenario: A static mut is reported whatever its type
+    Given a static mut of type u32 in module app::state
+    When the static is checked
+    Then the static is reported as static mut
+
+  Scenario: An allowed module keeps its globals
+    Given a static of type LazyLock<RwLock<Vec<u8>>> in module metrics::registry
+    And allowed modules matching metrics
+    When the static is checked
+    Then the static is accepted
+
+  Scenario: Allowed modules do not cover unrelated modules
+    Given a static of type LazyLock<RwLock<Vec<u8>>> in module app::metrics
+    And allowed modules matching metrics
+    When the static is checked
+    Then the static is reported as guarded by a RwLock
@@ -0,0 +1,31 @@
+//! Negative UI fixture: statics holding locks and `static mut` are reported.
+#![warn(no_global_mutable_state)]
+#![allow(static_mut_refs)]
+
+use std::cell::RefCell;
+use std::sync::{LazyLock, Mutex, OnceLock, RwLock};
+
+static REGISTRY: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+static SETTINGS: LazyLock<RwLock<Vec<u32>>> = LazyLock::new(|| RwLock::new(Vec::new()));
+static COUNTER: Mutex<u64> = Mutex::new(0);
+static mut LAST_ID: u32 = 0;
+
+fn record(name: &str) {
+    static SEEN: LazyLock<Mutex<Vec<String>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+    SEEN.lock().expect("seen lock poisoned").push(name.to_owned());
+}
+
+thread_local! {
+    static SCRATCH: RefCell<String> = RefCell::new(String::new());
+}
+
+fn main() {
+    let _ = REGISTRY.get_or_init(|| Mutex::new(Vec::new()));
+    drop(SETTINGS.read());
+    drop(COUNTER.lock());
+    unsafe {
+        LAST_ID += 1;
+    }
+    record("main");
+    SCRATCH.with(|scratch| scratch.borrow_mut().push('x'));
+}
@@ -0,0 +1,36 @@
+//! Negative UI fixture: locks declared through a `lazy_static!` expansion are
+//! reported at the declared name.
+#![warn(no_global_mutable_state)]
+
+use std::ops::Deref;
+use std::sync::{Mutex, OnceLock};
+
+/// Mirrors the shape of the `lazy_static` crate's expansion: a unit struct
+/// named after the static that dereferences to the declared type.
+macro_rules! lazy_static {
+    (static ref $name:ident : $ty:ty = $init:expr ;) => {
+        #[allow(non_camel_case_types)]
+        struct $name {
+            __private_field: (),
+        }
+
+        static $name: $name = $name { __private_field: () };
+
+        impl Deref for $name {
+            type Target = $ty;
+
+            fn deref(&self) -> &$ty {
+                static LAZY: OnceLock<$ty> = OnceLock::new();
+                LAZY.get_or_init(|| $init)
+            }
+        }
+    };
+}
+
+lazy_static! {
+    static ref SESSIONS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+}
+
+fn main() {
+    SESSIONS.lock().expect("sessions lock poisoned").push(1);
+}
@@ -0,0 +1,37 @@
+//! Positive UI fixture: plain globals and allowed modules are accepted.
+#![deny(no_global_mutable_state)]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+
+static REQUESTS: AtomicU64 = AtomicU64::new(0);
+static GREETING: OnceLock<String> = OnceLock::new();
+static NAMES: LazyLock<Vec<&str>> = LazyLock::new(|| vec!["a", "b"]);
+
+mod metrics {
+    pub mod registry {
+        use std::sync::{LazyLock, Mutex};
+
+        pub static COUNTERS: LazyLock<Mutex<Vec<u64>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+    }
+}
+
+mod logging {
+    use std::sync::Mutex;
+
+    pub static SINK: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+#[allow(no_global_mutable_state)]
+static ACCEPTED: OnceLock<Mutex<u8>> = OnceLock::new();
+
+static _UNUSED: LazyLock<u8> = LazyLock::new(|| 1);
+
+fn main() {
+    REQUESTS.fetch_add(1, Ordering::Relaxed);
+    let _ = GREETING.get_or_init(|| "hello".to_owned());
+    let _ = NAMES.len();
+    drop(metrics::registry::COUNTERS.lock());
+    drop(logging::SINK.lock());
+    let _ = ACCEPTED.get_or_init(|| Mutex::new(0));
+}
@@ -0,0 +1,114 @@
+//! Pure decision logic for `no_include_str_of_large_files`.
+//!
+//! The driver hands over the source text of each `include_str!` or
+//! `include_bytes!` invocation with the size of the file it embeds.
+//! [`NoIncludeStrOfLargeFilesDecision`] reads the argument with the same
+//! [`include_argument`] and reports files over the limit, naming the file as
+//! the finding's subject. Resolving and measuring the file is left to the
+//! driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_BYTES, LINT_NAME};
+use crate::policy::{include_argument, literal_path};
+
+/// Reason key for embedded files over the limit.
+const LARGE_INCLUDE: &str = "large_include";
+
+/// The facts about one include that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IncludeProjection {
+    /// The source text of the invocation, such as `include_str!("data.json")`.
+    pub call: String,
+    /// The size of the embedded file in bytes.
+    pub size: u64,
+}
+
+/// Reports `include_str!` and `include_bytes!` embedding files larger than
+/// `max_bytes`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let include = IncludeProjection {
+///     call: "include_str!(\"data.json\")".to_owned(),
+///     size: 2 * 1024 * 1024,
+/// };
+/// let finding = NoIncludeStrOfLargeFilesDecision::default().decide(&include);
+/// assert_eq!(finding.as_ref().and_then(Finding::subject), Some("data.json"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoIncludeStrOfLargeFilesDecision {
+    max_bytes: u64,
+}
+
+impl NoIncludeStrOfLargeFilesDecision {
+    /// Creates a decision reporting files over `max_bytes`.
+    #[must_use]
+    pub const fn new(max_bytes: u64) -> Self {
+        Self { max_bytes }
+    }
+}
+
+impl Default for NoIncludeStrOfLargeFilesDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BYTES)
+    }
+}
+
+impl LintDecision for NoIncludeStrOfLargeFilesDecision {
+    type Projection = IncludeProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, include: &IncludeProjection) -> Option<Finding> {
+        let argument = include_argument(&include.call)?;
+        (include.size > self.max_bytes).then(|| {
+            Finding::new(LINT_NAME, LARGE_INCLUDE)
+                .with_subject(literal_path(argument).unwrap_or(argument))
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<IncludeProjection>> {
+        let include = |call: &str, size| IncludeProjection {
+            call: call.to_owned(),
+            size,
+        };
+        let over = self.max_bytes + 1;
+        vec![
+            DecisionFixture::flagged(
+                "large text file",
+                include("include_str!(\"data.json\")", over),
+                LARGE_INCLUDE,
+            ),
+            DecisionFixture::flagged(
+                "large generated file",
+                include(
+                    "include_bytes!(concat!(env!(\"OUT_DIR\"), \"/blob\"))",
+                    over,
+                ),
+                LARGE_INCLUDE,
+            ),
+            DecisionFixture::clean(
+                "file at the limit",
+                include("include_str!(\"data.json\")", self.max_bytes),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_limit(NoIncludeStrOfLargeFilesDecision::default())]
+    #[case::small_limit(NoIncludeStrOfLargeFilesDecision::new(64))]
+    fn decision_conforms(#[case] decision: NoIncludeStrOfLargeFilesDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
@@ -0,0 +1,22 @@
+//! Embedded documentation for the `no_include_str_of_large_files` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_include_str_of_large_files`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_include_str_of_large_files",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `include_str!` and `include_bytes!` of files larger than the configured size.",
+    rationale: "Embedded files are copied into every binary that links the crate. A multi-megabyte embed bloats the artefact and slows each rebuild without showing up in the code, so large assets are better loaded at run time or shipped alongside the binary.",
+    bad_example: "static DATASET: &str = include_str!(\"../data/dataset.json\"); // 40 MiB",
+    good_example: "let dataset = std::fs::read_to_string(data_dir.join(\"dataset.json\"))?;",
+    config: &[ConfigKeyDocs {
+        key: "max_bytes",
+        default: "1048576",
+        description: "Largest file, in bytes, that may be embedded. Larger files are reported.",
+    }],
+};
@@ -0,0 +1,303 @@
+//! Lint pass reporting `include_str!` and `include_bytes!` of large files.
+//!
+//! Both macros expand to a literal holding the file's contents, so the pass
+//! inspects literals whose expansion comes from either macro. The argument is
+//! read from the invocation's source with [`include_argument`] and, when it
+//! is a plain string literal, resolved against the invoking file with
+//! [`resolve_include_path`]; the size is then read from the file system.
+//! Arguments built by other macros, such as `concat!`, fall back to the size
+//! of the embedded literal. Files above `max_bytes` are reported at the
+//! outermost invocation written in source.
+
+use std::borrow::Cow;
+use std::fs;
+
+use log::debug;
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{ExpnKind, MacroKind, Span, sym};
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{format_size, include_argument, literal_path, resolve_include_path};
+
+pub(crate) const LINT_NAME: &str = "no_include_str_of_large_files";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_BYTES: u64 = 1024 * 1024;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_bytes: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_INCLUDE_STR_OF_LARGE_FILES,
+    Warn,
+    "`include_str!` and `include_bytes!` should not embed files above the configured size",
+    NoIncludeStrOfLargeFiles::default()
+}
+
+/// Lint pass that finds large files embedded with `include_str!` or
+/// `include_bytes!`.
+#[derive(Default)]
+pub struct NoIncludeStrOfLargeFiles {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Size limit, finding limit, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoIncludeStrOfLargeFiles {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(NO_INCLUDE_STR_OF_LARGE_FILES, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ExprKind::Lit(literal) = expr.kind else {
+            return;
+        };
+        let expn = expr.span.ctxt().outer_expn_data();
+        let ExpnKind::Macro(MacroKind::Bang, name) = expn.kind else {
+            return;
+        };
+        if !matches!(name, sym::include_str | sym::include_bytes) {
+            return;
+        }
+        let Ok(call) = cx.sess().source_map().span_to_snippet(expn.call_site) else {
+            return;
+        };
+        let Some(argument) = include_argument(&call) else {
+            return;
+        };
+
+        let span = expn.call_site.source_callsite();
+        let state = &*self.state;
+        let size = included_size(cx, span, argument, &literal.node);
+        let max_bytes = state.findings.config_at(cx, span, &state.config).max_bytes;
+        if size <= max_bytes {
+            return;
+        }
+
+        debug!(target: LINT_NAME, "`{name}!` embeds {argument} of {size} bytes");
+        if state.findings.admit(cx, expr.hir_id, span) {
+            let message = LargeIncludeMessage {
+                macro_name: name.as_str(),
+                file: literal_path(argument).unwrap_or(argument),
+                size: format_size(size),
+                limit: format_size(max_bytes),
+            };
+            emit_diagnostic(cx, span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the size of the file `argument` names, resolved against the file
+/// holding `call`, or the size of the embedded `literal` when the path cannot
+/// be resolved or read.
+fn included_size(cx: &LateContext<'_>, call: Span, argument: &str, literal: &LitKind) -> u64 {
+    let invoking_file = cx
+        .sess()
+        .source_map()
+        .span_to_filename(call)
+        .into_local_path();
+    literal_path(argument)
+        .zip(invoking_file)
+        .and_then(|(path, file)| fs::metadata(resolve_include_path(&file, path)).ok())
+        .map_or_else(|| embedded_size(literal), |metadata| metadata.len())
+}
+
+fn embedded_size(literal: &LitKind) -> u64 {
+    let len = match literal {
+        LitKind::Str(symbol, _) => symbol.as_str().len(),
+        LitKind::ByteStr(bytes, _) => bytes.as_byte_str().len(),
+        _ => 0,
+    };
+    u64::try_from(len).unwrap_or(u64::MAX)
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &LargeIncludeMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        NO_INCLUDE_STR_OF_LARGE_FILES,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one large include interpolates.
+#[derive(Clone, Debug)]
+struct LargeIncludeMessage<'a> {
+    macro_name: &'a str,
+    file: &'a str,
+    size: String,
+    limit: String,
+}
+
+impl LargeIncludeMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("macro"),
+            FluentValue::from(self.macro_name.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("file"),
+            FluentValue::from(self.file.to_owned()),
+        );
+        args.insert(Cow::Borrowed("size"), FluentValue::from(self.size.clone()));
+        args.insert(
+            Cow::Borrowed("limit"),
+            FluentValue::from(self.limit.clone()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            macro_name,
+            file,
+            size,
+            limit,
+        } = self;
+        DiagnosticMessageSet::new(
+            format!(
+                "`{macro_name}!` embeds `{file}`, which is {size}, above the limit of {limit}."
+            ),
+            String::from(
+                "Embedded files are copied into every binary that links this crate, bloating it \
+                 and slowing each rebuild.",
+            ),
+            String::from(
+                "Load the file at run time, or ship it alongside the binary, instead of embedding \
+                 it.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn message() -> LargeIncludeMessage<'static> {
+        LargeIncludeMessage {
+            macro_name: "include_str",
+            file: "../data/dataset.json",
+            size: String::from("3.5 MiB"),
+            limit: String::from("1.0 MiB"),
+        }
+    }
+
+    fn resolve(message: &LargeIncludeMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_allows_one_mebibyte() {
+        assert_eq!(Config::default().max_bytes, 1_048_576);
+    }
+
+    #[rstest]
+    fn fallback_primary_names_the_file_and_sizes(message: LargeIncludeMessage<'static>) {
+        assert_eq!(
+            message.fallback_messages().primary(),
+            "`include_str!` embeds `../data/dataset.json`, which is 3.5 MiB, above the limit of \
+             1.0 MiB."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback(message: LargeIncludeMessage<'static>) {
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str, message: LargeIncludeMessage<'static>) {
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`../data/dataset.json`"));
+        assert!(messages.primary().contains("3.5 MiB"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,40 @@
+//! Lint crate flagging `include_str!` and `include_bytes!` of large files.
+//!
+//! Both macros copy the named file into the compiled artefact, so a
+//! multi-megabyte embed quietly bloats every binary that links the crate and
+//! slows the builds that rebuild it. The lint resolves each included path the
+//! way the compiler does, relative to the file invoking the macro, and reports
+//! files larger than `max_bytes`. The lint is experimental and ships behind the
+//! `experimental-no-include-str-of-large-files` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{IncludeProjection, NoIncludeStrOfLargeFilesDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_include_str_of_large_files_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
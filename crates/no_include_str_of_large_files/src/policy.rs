@@ -0,0 +1,142 @@
+//! Rules locating included files and describing their size.
+//!
+//! The driver hands over the source text of each `include_str!` or
+//! `include_bytes!` invocation and the file it appears in. The argument is
+//! read from the invocation with [`include_argument`] and, when it is a
+//! plain string literal, resolved with [`resolve_include_path`] exactly as
+//! the compiler resolves it. Sizes are rendered for diagnostics with
+//! [`format_size`]. The rules are kept free of compiler types so they can be
+//! tested directly.
+
+use std::path::{Path, PathBuf};
+
+const KIB: u64 = 1024;
+const MIB: u64 = KIB * 1024;
+
+/// Returns the argument of the macro invocation `call`, as written.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(include_argument("include_str!(\"data.json\")"), Some("\"data.json\""));
+/// assert_eq!(include_argument("include_bytes![ \"logo.png\", ]"), Some("\"logo.png\""));
+/// ```
+#[must_use]
+pub(crate) fn include_argument(call: &str) -> Option<&str> {
+    let (_, rest) = call.split_once('!')?;
+    let rest = rest.trim();
+    let inner = rest.get(1..rest.len().checked_sub(1)?)?;
+    let argument = inner.trim().trim_end_matches(',').trim_end();
+    (!argument.is_empty()).then_some(argument)
+}
+
+/// Returns the path a plain string literal `argument` names, or `None` for
+/// raw strings, escapes, and macro calls such as `concat!`.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(literal_path("\"../data.json\""), Some("../data.json"));
+/// assert_eq!(literal_path("concat!(env!(\"OUT_DIR\"), \"/data.json\")"), None);
+/// ```
+#[must_use]
+pub(crate) fn literal_path(argument: &str) -> Option<&str> {
+    let path = argument.strip_prefix('"')?.strip_suffix('"')?;
+    (!path.contains(['"', '\\'])).then_some(path)
+}
+
+/// Resolves `path` the way `include_str!` does: absolute paths stand alone,
+/// and relative paths start from the directory of `invoking_file`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let resolved = resolve_include_path(Path::new("src/lib.rs"), "../data.json");
+/// assert_eq!(resolved, Path::new("src/../data.json"));
+/// ```
+#[must_use]
+pub(crate) fn resolve_include_path(invoking_file: &Path, path: &str) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    invoking_file
+        .parent()
+        .map_or_else(|| path.to_path_buf(), |directory| directory.join(path))
+}
+
+/// Renders `bytes` in bytes, KiB, or MiB, with one decimal place for the
+/// larger units.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(format_size(512), "512 bytes");
+/// assert_eq!(format_size(3 * 1024 * 1024 + 512 * 1024), "3.5 MiB");
+/// ```
+#[must_use]
+pub(crate) fn format_size(bytes: u64) -> String {
+    if bytes >= MIB {
+        format!("{}.{} MiB", bytes / MIB, bytes % MIB * 10 / MIB)
+    } else if bytes >= KIB {
+        format!("{}.{} KiB", bytes / KIB, bytes % KIB * 10 / KIB)
+    } else {
+        format!("{bytes} bytes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::parentheses("include_str!(\"data.json\")", Some("\"data.json\""))]
+    #[case::brackets("include_bytes![\"logo.png\"]", Some("\"logo.png\""))]
+    #[case::trailing_comma("include_str!( \"data.json\", )", Some("\"data.json\""))]
+    #[case::qualified("std::include_str!(\"data.json\")", Some("\"data.json\""))]
+    #[case::nested(
+        "include_str!(concat!(env!(\"OUT_DIR\"), \"/data.json\"))",
+        Some("concat!(env!(\"OUT_DIR\"), \"/data.json\")")
+    )]
+    #[case::empty("include_str!()", None)]
+    #[case::not_a_call("DATA", None)]
+    fn arguments_are_read_from_the_call(#[case] call: &str, #[case] expected: Option<&str>) {
+        assert_eq!(include_argument(call), expected);
+    }
+
+    #[rstest]
+    #[case::plain("\"data.json\"", Some("data.json"))]
+    #[case::relative("\"../assets/logo.png\"", Some("../assets/logo.png"))]
+    #[case::escaped("\"data\\\\file.json\"", None)]
+    #[case::raw("r\"data.json\"", None)]
+    #[case::macro_call("concat!(\"data\", \".json\")", None)]
+    fn only_plain_literals_name_paths(#[case] argument: &str, #[case] expected: Option<&str>) {
+        assert_eq!(literal_path(argument), expected);
+    }
+
+    #[rstest]
+    #[case::sibling("src/lib.rs", "data.json", "src/data.json")]
+    #[case::parent("src/parser/mod.rs", "../../data.json", "src/parser/../../data.json")]
+    #[case::absolute("src/lib.rs", "/srv/data.json", "/srv/data.json")]
+    #[case::bare_file("lib.rs", "data.json", "data.json")]
+    fn paths_resolve_from_the_invoking_file(
+        #[case] invoking_file: &str,
+        #[case] path: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            resolve_include_path(Path::new(invoking_file), path),
+            Path::new(expected)
+        );
+    }
+
+    #[rstest]
+    #[case::bytes(1023, "1023 bytes")]
+    #[case::kibibytes(1536, "1.5 KiB")]
+    #[case::mebibyte(MIB, "1.0 MiB")]
+    #[case::truncated(2 * MIB - 1, "1.9 MiB")]
+    fn sizes_use_binary_units(#[case] bytes: u64, #[case] expected: &str) {
+        assert_eq!(format_size(bytes), expected);
+    }
+}
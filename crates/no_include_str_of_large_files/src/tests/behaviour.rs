@@ -0,0 +1,71 @@
+//! Behaviour-driven coverage for resolving included paths.
+
+use crate::policy::{include_argument, literal_path, resolve_include_path};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+
+struct IncludeWorld {
+    call: RefCell<String>,
+    invoking_file: RefCell<String>,
+    resolved: RefCell<Option<PathBuf>>,
+}
+
+#[fixture]
+fn world() -> IncludeWorld {
+    IncludeWorld {
+        call: RefCell::new(String::new()),
+        invoking_file: RefCell::new(String::new()),
+        resolved: RefCell::new(None),
+    }
+}
+
+#[given("an include of {argument} from {file}")]
+fn given_include(world: &IncludeWorld, argument: String, file: String) {
+    *world.call.borrow_mut() = format!("include_str!({argument})");
+    *world.invoking_file.borrow_mut() = file;
+}
+
+#[when("the include is resolved")]
+fn when_resolved(world: &IncludeWorld) {
+    let call = world.call.borrow();
+    let invoking_file = world.invoking_file.borrow();
+    *world.resolved.borrow_mut() = include_argument(&call)
+        .and_then(literal_path)
+        .map(|path| resolve_include_path(Path::new(invoking_file.as_str()), path));
+}
+
+#[then("the resolved path is {path}")]
+fn then_resolved(world: &IncludeWorld, path: String) {
+    assert_eq!(world.resolved.borrow().as_deref(), Some(Path::new(&path)));
+}
+
+#[then("no path is resolved")]
+fn then_unresolved(world: &IncludeWorld) {
+    assert_eq!(*world.resolved.borrow(), None);
+}
+
+#[scenario(
+    path = "tests/features/no_include_str_of_large_files.feature",
+    index = 0
+)]
+fn scenario_relative_path(world: IncludeWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_include_str_of_large_files.feature",
+    index = 1
+)]
+fn scenario_absolute_path(world: IncludeWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_include_str_of_large_files.feature",
+    index = 2
+)]
+fn scenario_computed_path(world: IncludeWorld) {
+    let _ = world;
+}
@@ -0,0 +1,18 @@
+Feature: No include_str of large files
+  Included paths resolve against the invoking file, and only plain string
+  literals name a path that can be checked on disk.
+
+  Scenario: A relative path resolves beside the invoking file
+    Given an include of "../data/dataset.json" from src/lib.rs
+    When the include is resolved
+    Then the resolved path is src/../data/dataset.json
+
+  Scenario: An absolute path stands alone
+    Given an include of "/srv/dataset.json" from src/lib.rs
+    When the include is resolved
+    Then the resolved path is /srv/dataset.json
+
+  Scenario: A computed path is not resolved
+    Given an include of concat!(env!("OUT_DIR"), "/dataset.json") from src/lib.rs
+    When the include is resolved
+    Then no path is resolved
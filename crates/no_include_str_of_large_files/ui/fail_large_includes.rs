@@ -0,0 +1,7 @@
+//! Negative UI fixture: embedded files above the configured size.
+#![crate_type = "lib"]
+#![warn(no_include_str_of_large_files)]
+
+pub static NOTES: &str = include_str!("fail_large_includes/notes.txt");
+
+pub static LOGO: &[u8] = include_bytes!("fail_large_includes/logo.bin");
@@ -0,0 +1,7 @@
+//! Positive UI fixture: embedded files within the default size.
+#![crate_type = "lib"]
+#![warn(no_include_str_of_large_files)]
+
+pub static NOTES: &str = include_str!("pass_small_includes/notes.txt");
+
+pub static BANNER: &[u8] = include_bytes!("pass_small_includes/notes.txt");
@@ -0,0 +1,127 @@
+//! Pure decision logic for `no_indexing_outside_tests`.
+//!
+//! The driver describes each `base[index]` expression by the container it
+//! reads from, what is known about the index, and whether it sits in test
+//! code. [`NoIndexingOutsideTestsDecision`] rebuilds the same
+//! [`IndexAccess`] and reports it when [`should_flag`] does. Findings use
+//! the container's Fluent selector as their reason. Redirecting findings to
+//! the callers of test helpers is left to the driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{Container, IndexAccess, IndexValue, should_flag};
+
+/// The facts about one indexing expression that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IndexProjection {
+    /// The container indexed: `slice`, `vec`, or `array`.
+    pub container: String,
+    /// The array's length, when the container is an array of known length.
+    pub array_len: Option<u128>,
+    /// Whether the index is a constant, or a range of constants.
+    pub constant: bool,
+    /// One past the highest element a constant index reads, when known.
+    pub constant_end: Option<u128>,
+    /// Whether the expression sits in test code.
+    pub in_test: bool,
+}
+
+impl IndexProjection {
+    fn container(&self) -> Option<Container> {
+        [
+            Container::Slice,
+            Container::Vec,
+            Container::Array {
+                len: self.array_len,
+            },
+        ]
+        .into_iter()
+        .find(|container| container.fluent_kind() == self.container)
+    }
+}
+
+/// Reports indexing that may panic outside test code.
+///
+/// # Examples
+///
+/// ```ignore
+/// let access = IndexProjection {
+///     container: "vec".to_owned(),
+///     ..IndexProjection::default()
+/// };
+/// assert!(NoIndexingOutsideTestsDecision.decide(&access).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoIndexingOutsideTestsDecision;
+
+impl LintDecision for NoIndexingOutsideTestsDecision {
+    type Projection = IndexProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, access: &IndexProjection) -> Option<Finding> {
+        let container = access.container()?;
+        let index = if access.constant {
+            IndexValue::Constant {
+                end: access.constant_end,
+            }
+        } else {
+            IndexValue::Dynamic
+        };
+        let facts = IndexAccess {
+            container,
+            index,
+            in_test: access.in_test,
+        };
+        should_flag(&facts).then(|| Finding::new(LINT_NAME, container.fluent_kind()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<IndexProjection>> {
+        let dynamic = |container: &str, in_test| IndexProjection {
+            container: container.to_owned(),
+            in_test,
+            ..IndexProjection::default()
+        };
+        let constant = |end| IndexProjection {
+            container: "array".to_owned(),
+            array_len: Some(4),
+            constant: true,
+            constant_end: Some(end),
+            in_test: false,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "dynamic index into a vec",
+                dynamic("vec", false),
+                Container::Vec.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "dynamic index into a slice",
+                dynamic("slice", false),
+                Container::Slice.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "constant past the end of an array",
+                constant(5),
+                Container::Array { len: None }.fluent_kind(),
+            ),
+            DecisionFixture::clean("constant inside an array", constant(4)),
+            DecisionFixture::clean("dynamic index in a test", dynamic("vec", true)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&NoIndexingOutsideTestsDecision), Ok(5));
+    }
+}
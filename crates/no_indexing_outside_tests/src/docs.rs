@@ -0,0 +1,29 @@
+//! Embedded documentation for the `no_indexing_outside_tests` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_indexing_outside_tests`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_indexing_outside_tests",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags indexing into slices, `Vec`s, and arrays with indices computed at run time outside test code.",
+    rationale: "`values[index]` panics when the index is out of bounds, turning a missing element the caller could handle into an abort. `get` and `get_mut` return an `Option` that makes the missing case explicit.",
+    bad_example: "let first = items[position];",
+    good_example: "let Some(first) = items.get(position) else {\n    return Err(Error::MissingItem(position));\n};",
+    config: &[
+        ConfigKeyDocs {
+            key: "additional_test_attributes",
+            default: "[]",
+            description: "Extra attribute paths that mark a function as a test.",
+        },
+        ConfigKeyDocs {
+            key: "blame_helpers",
+            default: "[]",
+            description: "Fully qualified paths of helper functions whose findings are reported at each call to them instead of inside the helper.",
+        },
+    ],
+};
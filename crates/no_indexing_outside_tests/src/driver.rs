@@ -0,0 +1,381 @@
+//! Detect slice, `Vec`, and array indexing that may panic outside tests.
+//!
+//! The lint inspects every `base[index]` expression whose base, once
+//! references are peeled, is a slice, a `Vec`, or an array. The index is a
+//! constant when it is an integer literal, a path to a constant, arithmetic
+//! on constants, or a range whose bounds are all constants. The policy then
+//! reports dynamic indices, and constant indices known to fall outside an
+//! array. Test code is exempt through [`whitaker::hir::TestContext`], and
+//! macro-generated expressions and constant contexts are skipped. Pattern
+//! destructuring such as `let [first, ..] = values;` is not an index
+//! expression and is never seen. Findings inside `blame_helpers` are reported
+//! at each non-test call through [`whitaker::hir::HelperCallSites`].
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, FindingSite, HelperCallSites, TestContext};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::{AttributePath, CrateScopedState};
+
+use crate::policy::{Container, IndexAccess, IndexValue, should_flag};
+
+pub(crate) const LINT_NAME: &str = "no_indexing_outside_tests";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    additional_test_attributes: Vec<String>,
+    blame_helpers: Vec<String>,
+}
+
+/// Lint pass reporting panicking indexing outside test code.
+#[derive(Default)]
+pub struct NoIndexingOutsideTests {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Test context, helper call sites, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    test_context: TestContext,
+    helper_calls: HelperCallSites,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_INDEXING_OUTSIDE_TESTS,
+    Warn,
+    "indexing a slice, `Vec`, or array with a run-time index panics when it is out of bounds; use `get` outside tests",
+    NoIndexingOutsideTests::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoIndexingOutsideTests {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let config = load_configuration();
+        let additional = config
+            .additional_test_attributes
+            .iter()
+            .map(|path| AttributePath::from(path.as_str()))
+            .collect();
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            test_context: TestContext::for_crate(cx, additional),
+            helper_calls: HelperCallSites::for_crate(cx, &config.blame_helpers),
+            findings: FindingLimit::new(NO_INDEXING_OUTSIDE_TESTS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ExprKind::Index(base, index, _) = expr.kind else {
+            return;
+        };
+        if expr.span.from_expansion() || cx.tcx.hir_is_inside_const_context(expr.hir_id) {
+            return;
+        }
+        let Some(container) = container_of(cx, cx.typeck_results().expr_ty(base)) else {
+            return;
+        };
+
+        let access = IndexAccess {
+            container,
+            index: index_value(cx, index),
+            in_test: self.state.test_context.is_test(cx, expr.hir_id),
+        };
+        if !should_flag(&access) {
+            return;
+        }
+
+        let index = cx
+            .sess()
+            .source_map()
+            .span_to_snippet(index.span)
+            .unwrap_or_else(|_| String::from(".."));
+        debug!(target: LINT_NAME, "indexing a {container:?} with `{index}`");
+        let info = IndexInfo { container, index };
+        let state = &*self.state;
+        for site in state.helper_calls.finding_sites(cx, expr, &state.localizer) {
+            if site.is_redirected() && state.test_context.is_test(cx, site.hir_id) {
+                continue;
+            }
+            if state.findings.admit(cx, site.hir_id, site.span) {
+                emit_diagnostic(cx, &site, &info, &state.localizer);
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Names the container `ty` refers to once references are peeled.
+fn container_of<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Container> {
+    match ty.peel_refs().kind() {
+        ty::Slice(_) => Some(Container::Slice),
+        ty::Array(_, len) => Some(Container::Array {
+            len: len.try_to_target_usize(cx.tcx).map(u128::from),
+        }),
+        ty::Adt(adt, _) if cx.tcx.is_diagnostic_item(sym::Vec, adt.did()) => Some(Container::Vec),
+        _ => None,
+    }
+}
+
+/// Classifies `index` as dynamic or constant, recording how far a constant
+/// index reaches when its value is a literal.
+fn index_value<'tcx>(cx: &LateContext<'tcx>, index: &hir::Expr<'tcx>) -> IndexValue {
+    if let Some(bounds) = range_bounds(cx, index) {
+        return range_value(cx, &bounds);
+    }
+    if !is_constant(cx, index) {
+        return IndexValue::Dynamic;
+    }
+    IndexValue::Constant {
+        end: literal(index).and_then(|value| value.checked_add(1)),
+    }
+}
+
+/// The bounds of a range index such as `start..end` or `..=last`.
+struct RangeBounds<'a, 'tcx> {
+    start: Option<&'a hir::Expr<'tcx>>,
+    end: Option<&'a hir::Expr<'tcx>>,
+    inclusive: bool,
+}
+
+/// Returns the bounds of `index` when it is a range expression.
+fn range_bounds<'a, 'tcx>(
+    cx: &LateContext<'tcx>,
+    index: &'a hir::Expr<'tcx>,
+) -> Option<RangeBounds<'a, 'tcx>> {
+    let ty::Adt(adt, _) = cx.typeck_results().expr_ty(index).kind() else {
+        return None;
+    };
+    let is = |item| cx.tcx.is_lang_item(adt.did(), item);
+    let inclusive = is(LangItem::RangeInclusiveStruct) || is(LangItem::RangeToInclusive);
+    let full = is(LangItem::RangeFull);
+    if !(inclusive
+        || full
+        || is(LangItem::Range)
+        || is(LangItem::RangeFrom)
+        || is(LangItem::RangeTo))
+    {
+        return None;
+    }
+
+    let mut bounds = RangeBounds {
+        start: None,
+        end: None,
+        inclusive,
+    };
+    match index.kind {
+        ExprKind::Struct(_, fields, _) => {
+            for field in fields {
+                match field.ident.as_str() {
+                    "start" => bounds.start = Some(field.expr),
+                    "end" => bounds.end = Some(field.expr),
+                    _ => {}
+                }
+            }
+        }
+        ExprKind::Call(callee, [start, end]) if is_range_inclusive_new(cx, callee) => {
+            bounds.start = Some(start);
+            bounds.end = Some(end);
+        }
+        ExprKind::Path(_) if full => {}
+        _ => return None,
+    }
+    Some(bounds)
+}
+
+/// Returns `true` when `callee` is `RangeInclusive::new`, which `start..=end`
+/// desugars to.
+fn is_range_inclusive_new(cx: &LateContext<'_>, callee: &hir::Expr<'_>) -> bool {
+    let ExprKind::Path(ref qpath) = callee.kind else {
+        return false;
+    };
+    cx.qpath_res(qpath, callee.hir_id)
+        .opt_def_id()
+        .is_some_and(|def_id| cx.tcx.is_lang_item(def_id, LangItem::RangeInclusiveNew))
+}
+
+/// Classifies a range index: constant when every bound is constant, reaching
+/// its end bound, or its start bound when it has no end.
+fn range_value(cx: &LateContext<'_>, bounds: &RangeBounds<'_, '_>) -> IndexValue {
+    let mut present = bounds.start.into_iter().chain(bounds.end);
+    if !present.all(|bound| is_constant(cx, bound)) {
+        return IndexValue::Dynamic;
+    }
+    let end = match (bounds.start, bounds.end) {
+        (_, Some(end)) => literal(end).and_then(|value| {
+            if bounds.inclusive {
+                value.checked_add(1)
+            } else {
+                Some(value)
+            }
+        }),
+        (Some(start), None) => literal(start),
+        (None, None) => Some(0),
+    };
+    IndexValue::Constant { end }
+}
+
+/// Returns `true` when `expr` is evaluated at compile time.
+fn is_constant(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(ref qpath) => matches!(
+            cx.qpath_res(qpath, expr.hir_id),
+            Res::Def(
+                DefKind::Const { .. } | DefKind::AssocConst { .. } | DefKind::ConstParam,
+                _
+            )
+        ),
+        ExprKind::Binary(_, left, right) => is_constant(cx, left) && is_constant(cx, right),
+        ExprKind::Unary(hir::UnOp::Neg, operand) => is_constant(cx, operand),
+        _ => false,
+    }
+}
+
+/// Returns the value of an integer literal.
+fn literal(expr: &hir::Expr<'_>) -> Option<u128> {
+    match expr.kind {
+        ExprKind::Lit(lit) => match lit.node {
+            rustc_ast::LitKind::Int(value, _) => Some(value.get()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// An indexing expression that may panic.
+#[derive(Clone, Debug)]
+struct IndexInfo {
+    container: Container,
+    index: String,
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    site: &FindingSite,
+    info: &IndexInfo,
+    localizer: &Localizer,
+) {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("container"),
+        FluentValue::from(info.container.fluent_kind()),
+    );
+    args.insert(
+        Cow::Borrowed("index"),
+        FluentValue::from(info.index.clone()),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(info)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let origin = site.origin.clone();
+
+    cx.emit_span_lint(
+        NO_INDEXING_OUTSIDE_TESTS,
+        site.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            if let Some(origin) = origin {
+                lint.span_note(origin.span, origin.note);
+            }
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(info: &IndexInfo) -> DiagnosticMessageSet {
+    let container = match info.container {
+        Container::Slice => "this slice",
+        Container::Vec => "this `Vec`",
+        Container::Array { .. } => "this array",
+    };
+    let index = &info.index;
+    DiagnosticMessageSet::new(
+        format!("Indexing {container} with `{index}` panics when the index is out of bounds."),
+        String::from(
+            "Outside tests, an out-of-bounds index aborts the caller instead of letting it \
+             handle the missing element.",
+        ),
+        format!("Use `.get({index})` or `.get_mut({index})` and handle the `None` case."),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_adds_no_test_attributes_or_helpers() {
+        let config = Config::default();
+
+        assert!(config.additional_test_attributes.is_empty());
+        assert!(config.blame_helpers.is_empty());
+    }
+
+    #[rstest]
+    #[case::slice(Container::Slice, "Indexing this slice with `i` panics")]
+    #[case::vec(Container::Vec, "Indexing this `Vec` with `i` panics")]
+    #[case::array(Container::Array { len: Some(3) }, "Indexing this array with `i` panics")]
+    fn fallback_messages_name_the_container(#[case] container: Container, #[case] prefix: &str) {
+        let info = IndexInfo {
+            container,
+            index: String::from("i"),
+        };
+        let messages = fallback_messages(&info);
+
+        assert!(messages.primary().starts_with(prefix));
+        assert_eq!(
+            messages.help(),
+            "Use `.get(i)` or `.get_mut(i)` and handle the `None` case."
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
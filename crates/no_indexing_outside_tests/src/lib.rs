@@ -0,0 +1,41 @@
+//! Lint crate flagging slice, `Vec`, and array indexing outside tests.
+//!
+//! `values[index]` panics when `index` is out of bounds, so production code
+//! that indexes with a value computed at run time trades a recoverable
+//! `None` for an abort. The lint reports such indexing and suggests `get` or
+//! `get_mut` with explicit handling. Constant indices known to be in bounds,
+//! pattern destructuring, and test code are accepted. The lint is
+//! experimental and ships behind the `experimental-no-indexing-outside-tests`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{IndexProjection, NoIndexingOutsideTestsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_indexing_outside_tests_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
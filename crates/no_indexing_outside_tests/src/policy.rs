@@ -0,0 +1,128 @@
+//! Deciding whether an indexing expression may panic outside tests.
+//!
+//! The driver describes each `base[index]` expression by the kind of
+//! container being indexed, whether the index is a constant, and whether the
+//! expression sits in test code. This module decides whether that access is
+//! reported, so the decision can be tested without the compiler.
+
+/// The container an index expression reads from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Container {
+    /// A slice, reached directly or through a reference.
+    Slice,
+    /// A `Vec`, reached directly or through a reference.
+    Vec,
+    /// A fixed-size array, with its length when the compiler knows it.
+    Array {
+        /// The number of elements in the array.
+        len: Option<u128>,
+    },
+}
+
+impl Container {
+    /// Returns the Fluent selector naming the container.
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Slice => "slice",
+            Self::Vec => "vec",
+            Self::Array { .. } => "array",
+        }
+    }
+}
+
+/// What is known about the index when the crate is compiled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum IndexValue {
+    /// The index is computed at run time.
+    Dynamic,
+    /// The index is a constant, or a range whose bounds are all constants.
+    Constant {
+        /// One past the highest element the access reads, when it is known.
+        end: Option<u128>,
+    },
+}
+
+/// An indexing expression as seen by the policy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct IndexAccess {
+    pub(crate) container: Container,
+    pub(crate) index: IndexValue,
+    pub(crate) in_test: bool,
+}
+
+/// Returns `true` when `access` should be reported.
+///
+/// Dynamic indices outside test code are reported. A constant index is
+/// reported only when it is known to fall outside an array, since its value
+/// cannot change at run time.
+///
+/// # Examples
+///
+/// ```ignore
+/// let access = IndexAccess {
+///     container: Container::Vec,
+///     index: IndexValue::Dynamic,
+///     in_test: false,
+/// };
+/// assert!(should_flag(&access));
+/// ```
+#[must_use]
+pub(crate) fn should_flag(access: &IndexAccess) -> bool {
+    if access.in_test {
+        return false;
+    }
+    match (access.index, access.container) {
+        (IndexValue::Dynamic, _) => true,
+        (IndexValue::Constant { end: Some(end) }, Container::Array { len: Some(len) }) => end > len,
+        (IndexValue::Constant { .. }, _) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const ARRAY: Container = Container::Array { len: Some(4) };
+
+    #[rstest]
+    #[case::dynamic_slice(Container::Slice, IndexValue::Dynamic, true)]
+    #[case::dynamic_vec(Container::Vec, IndexValue::Dynamic, true)]
+    #[case::dynamic_array(ARRAY, IndexValue::Dynamic, true)]
+    #[case::constant_in_bounds(ARRAY, IndexValue::Constant { end: Some(4) }, false)]
+    #[case::constant_out_of_bounds(ARRAY, IndexValue::Constant { end: Some(5) }, true)]
+    #[case::constant_unknown_value(ARRAY, IndexValue::Constant { end: None }, false)]
+    #[case::constant_into_vec(Container::Vec, IndexValue::Constant { end: Some(9) }, false)]
+    fn outside_tests(
+        #[case] container: Container,
+        #[case] index: IndexValue,
+        #[case] expected: bool,
+    ) {
+        let access = IndexAccess {
+            container,
+            index,
+            in_test: false,
+        };
+
+        assert_eq!(should_flag(&access), expected);
+    }
+
+    #[rstest]
+    fn test_code_is_exempt() {
+        let access = IndexAccess {
+            container: Container::Slice,
+            index: IndexValue::Dynamic,
+            in_test: true,
+        };
+
+        assert!(!should_flag(&access));
+    }
+
+    #[rstest]
+    #[case::slice(Container::Slice, "slice")]
+    #[case::vec(Container::Vec, "vec")]
+    #[case::array(ARRAY, "array")]
+    fn containers_have_fluent_selectors(#[case] container: Container, #[case] expected: &str) {
+        assert_eq!(container.fluent_kind(), expected);
+    }
+}
@@ -0,0 +1,100 @@
+//! Behaviour-driven coverage for the indexing policy.
+
+use crate::policy::{Container, IndexAccess, IndexValue, should_flag};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+struct IndexWorld {
+    container: Cell<Container>,
+    index: Cell<IndexValue>,
+    in_test: Cell<bool>,
+    flagged: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> IndexWorld {
+    IndexWorld {
+        container: Cell::new(Container::Slice),
+        index: Cell::new(IndexValue::Dynamic),
+        in_test: Cell::new(false),
+        flagged: Cell::new(None),
+    }
+}
+
+#[given("an index into a slice")]
+fn given_slice(world: &IndexWorld) {
+    world.container.set(Container::Slice);
+}
+
+#[given("an index into a Vec")]
+fn given_vec(world: &IndexWorld) {
+    world.container.set(Container::Vec);
+}
+
+#[given("an index into an array of length {len}")]
+fn given_array(world: &IndexWorld, len: u128) {
+    world.container.set(Container::Array { len: Some(len) });
+}
+
+#[given("the index is computed at run time")]
+fn given_dynamic(world: &IndexWorld) {
+    world.index.set(IndexValue::Dynamic);
+}
+
+#[given("the index is the constant {value}")]
+fn given_constant(world: &IndexWorld, value: u128) {
+    world.index.set(IndexValue::Constant {
+        end: Some(value + 1),
+    });
+}
+
+#[given("the access is in test code")]
+fn given_in_test(world: &IndexWorld) {
+    world.in_test.set(true);
+}
+
+#[when("the access is checked")]
+fn when_checked(world: &IndexWorld) {
+    let access = IndexAccess {
+        container: world.container.get(),
+        index: world.index.get(),
+        in_test: world.in_test.get(),
+    };
+    world.flagged.set(Some(should_flag(&access)));
+}
+
+#[then("the access is reported")]
+fn then_reported(world: &IndexWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the access is accepted")]
+fn then_accepted(world: &IndexWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/no_indexing_outside_tests.feature", index = 0)]
+fn scenario_dynamic_slice_index(world: IndexWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_indexing_outside_tests.feature", index = 1)]
+fn scenario_dynamic_index_in_tests(world: IndexWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_indexing_outside_tests.feature", index = 2)]
+fn scenario_constant_in_bounds(world: IndexWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_indexing_outside_tests.feature", index = 3)]
+fn scenario_constant_out_of_bounds(world: IndexWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_indexing_outside_tests.feature", index = 4)]
+fn scenario_constant_into_vec(world: IndexWorld) {
+    let _ = world;
+}
@@ -0,0 +1,35 @@
+Feature: No indexing outside tests
+  Indexing a slice, Vec, or array with an index computed at run time is
+  reported outside test code. Constant indices are accepted unless they are
+  known to fall outside an array.
+
+  Scenario: A dynamic index into a slice is reported
+    Given an index into a slice
+    And the index is computed at run time
+    When the access is checked
+    Then the access is reported
+
+  Scenario: A dynamic index in test code is accepted
+    Given an index into a Vec
+    And the index is computed at run time
+    And the access is in test code
+    When the access is checked
+    Then the access is accepted
+
+  Scenario: A constant index inside an array is accepted
+    Given an index into an array of length 3
+    And the index is the constant 2
+    When the access is checked
+    Then the access is accepted
+
+  Scenario: A constant index past the end of an array is reported
+    Given an index into an array of length 3
+    And the index is the constant 3
+    When the access is checked
+    Then the access is reported
+
+  Scenario: A constant index into a Vec is accepted
+    Given an index into a Vec
+    And the index is the constant 0
+    When the access is checked
+    Then the access is accepted
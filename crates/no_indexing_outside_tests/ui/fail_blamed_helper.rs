@@ -0,0 +1,27 @@
+//! Negative UI fixture: indexing in a blamed helper is reported where the
+//! helper is called, while other helpers keep their findings.
+#![warn(no_indexing_outside_tests)]
+
+struct Table {
+    rows: Vec<u32>,
+}
+
+impl Table {
+    fn row(&self, index: usize) -> u32 {
+        self.rows[index]
+    }
+}
+
+fn column(values: &[u32], index: usize) -> u32 {
+    values[index]
+}
+
+fn total(table: &Table, first: usize, second: usize) -> u32 {
+    table.row(first) + table.row(second)
+}
+
+fn main() {
+    let table = Table { rows: vec![1, 2, 3] };
+    let _ = total(&table, 0, 1);
+    let _ = column(&table.rows, 2);
+}
@@ -0,0 +1,47 @@
+//! Negative UI fixture: run-time indices into slices, `Vec`s, and arrays.
+#![warn(no_indexing_outside_tests)]
+
+fn first_of(values: &[u32], position: usize) -> u32 {
+    values[position]
+}
+
+fn bump(values: &mut Vec<u32>, position: usize) {
+    values[position] += 1;
+}
+
+fn window(values: &[u32], start: usize, len: usize) -> &[u32] {
+    &values[start..start + len]
+}
+
+fn tail(values: &Vec<u8>, skip: usize) -> &[u8] {
+    &values[skip..]
+}
+
+fn upto(values: &[u8], last: usize) -> &[u8] {
+    &values[..=last]
+}
+
+fn by_range(values: &[u8], range: std::ops::Range<usize>) -> &[u8] {
+    &values[range]
+}
+
+fn weekday(days: [&str; 7], offset: usize) -> &str {
+    days[offset % 7]
+}
+
+fn past_the_end(days: [&str; 7]) -> &str {
+    days[7]
+}
+
+fn main() {
+    let values = vec![1, 2, 3];
+    let _ = first_of(&values, 1);
+    let mut copy = values.clone();
+    bump(&mut copy, 0);
+    let _ = window(&values, 0, 2);
+    let _ = tail(&vec![1, 2], 1);
+    let _ = upto(&[1, 2], 1);
+    let _ = by_range(&[1, 2], 0..1);
+    let _ = weekday(["mon"; 7], 3);
+    let _ = past_the_end(["mon"; 7]);
+}
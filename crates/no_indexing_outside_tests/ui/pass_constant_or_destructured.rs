@@ -0,0 +1,61 @@
+//! Positive UI fixture: constant indices, destructuring, `get`, other
+//! containers, and test code are accepted.
+#![warn(no_indexing_outside_tests)]
+
+use std::collections::HashMap;
+
+const LAST: usize = 2;
+
+fn corners(grid: [u8; 3]) -> (u8, u8) {
+    (grid[0], grid[LAST])
+}
+
+fn prefix(grid: [u8; 3]) -> &'static [u8] {
+    let _ = &grid[..2];
+    &[]
+}
+
+fn head(values: &[u32]) -> u32 {
+    values[0]
+}
+
+fn whole(values: &[u32]) -> &[u32] {
+    &values[..]
+}
+
+fn split(values: [u32; 3]) -> u32 {
+    let [first, .., last] = values;
+    first + last
+}
+
+fn checked(values: &[u32], position: usize) -> Option<u32> {
+    values.get(position).copied()
+}
+
+fn lookup(table: &HashMap<&str, u32>, key: &str) -> u32 {
+    table[key]
+}
+
+const TABLE: [u8; 4] = [1, 2, 3, 4];
+const PICKED: u8 = TABLE[LAST + 1];
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn indexing_in_tests_is_accepted() {
+        let values = vec![1, 2, 3];
+        let position = values.len() - 1;
+        assert_eq!(values[position], 3);
+    }
+}
+
+fn main() {
+    let _ = corners([1, 2, 3]);
+    let _ = prefix([1, 2, 3]);
+    let _ = head(&[1]);
+    let _ = whole(&[1]);
+    let _ = split([1, 2, 3]);
+    let _ = checked(&[1], 0);
+    let _ = lookup(&HashMap::from([("a", 1)]), "a");
+    let _ = PICKED;
+}
@@ -0,0 +1,110 @@
+//! Pure decision logic for `no_lock_unwrap`.
+//!
+//! The driver describes each `unwrap` or `expect` whose receiver calls a
+//! method on a standard library `Mutex` or `RwLock`.
+//! [`NoLockUnwrapDecision`] rebuilds the same [`LockUnwrap`] and reports it
+//! when [`should_flag`] does. Findings use the panicking method as their
+//! reason and the lock method, such as `Mutex::lock`, as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{LockKind, LockUnwrap, Panicking, should_flag};
+
+/// The facts about one panicking call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LockUnwrapProjection {
+    /// The lock type, `Mutex` or `RwLock`.
+    pub lock: String,
+    /// The lock method whose result is unwrapped, such as `lock`.
+    pub method: String,
+    /// The panicking method, `unwrap` or `expect`.
+    pub panicking: String,
+    /// Whether the call sits in test code.
+    pub in_test: bool,
+}
+
+/// Reports `unwrap` and `expect` on lock acquisitions outside test code.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = LockUnwrapProjection {
+///     lock: "Mutex".to_owned(),
+///     method: "lock".to_owned(),
+///     panicking: "unwrap".to_owned(),
+///     in_test: false,
+/// };
+/// assert!(NoLockUnwrapDecision.decide(&call).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoLockUnwrapDecision;
+
+impl LintDecision for NoLockUnwrapDecision {
+    type Projection = LockUnwrapProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, call: &LockUnwrapProjection) -> Option<Finding> {
+        let lock = [LockKind::Mutex, LockKind::RwLock]
+            .into_iter()
+            .find(|lock| lock.name() == call.lock)?;
+        let panicking = Panicking::from_name(&call.panicking)?;
+        let facts = LockUnwrap {
+            lock,
+            method: &call.method,
+            in_test: call.in_test,
+        };
+        should_flag(&facts).then(|| {
+            Finding::new(LINT_NAME, panicking.name()).with_subject(format!(
+                "{}::{}",
+                lock.name(),
+                call.method
+            ))
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<LockUnwrapProjection>> {
+        let call =
+            |lock: LockKind, method: &str, panicking: Panicking, in_test| LockUnwrapProjection {
+                lock: lock.name().to_owned(),
+                method: method.to_owned(),
+                panicking: panicking.name().to_owned(),
+                in_test,
+            };
+        vec![
+            DecisionFixture::flagged(
+                "unwrapped mutex lock",
+                call(LockKind::Mutex, "lock", Panicking::Unwrap, false),
+                Panicking::Unwrap.name(),
+            ),
+            DecisionFixture::flagged(
+                "expected write lock",
+                call(LockKind::RwLock, "write", Panicking::Expect, false),
+                Panicking::Expect.name(),
+            ),
+            DecisionFixture::clean(
+                "non-blocking acquisition",
+                call(LockKind::Mutex, "try_lock", Panicking::Unwrap, false),
+            ),
+            DecisionFixture::clean(
+                "lock in a test",
+                call(LockKind::RwLock, "read", Panicking::Unwrap, true),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&NoLockUnwrapDecision), Ok(4));
+    }
+}
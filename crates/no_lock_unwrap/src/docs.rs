@@ -0,0 +1,21 @@
+//! Embedded documentation for the `no_lock_unwrap` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_lock_unwrap`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_lock_unwrap",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `unwrap` and `expect` on the results of `Mutex::lock`, `RwLock::read`, and `RwLock::write` outside test code.",
+    rationale: "Acquiring a standard library lock fails only when another thread panicked while holding it. Unwrapping the result turns that poisoning into a second panic without deciding whether the protected data is still usable, so the choice should be explicit.",
+    bad_example: "let mut queue = self.queue.lock().unwrap();",
+    good_example: "let mut queue = self.queue.lock().unwrap_or_else(PoisonError::into_inner);",
+    config: &[ConfigKeyDocs {
+        key: "additional_test_attributes",
+        default: "[]",
+        description: "Extra attribute paths that mark a function as a test.",
+    }],
+};
@@ -0,0 +1,309 @@
+//! Lint pass reporting unwrapped lock acquisitions.
+//!
+//! Each `unwrap` or `expect` method call whose receiver is itself a method
+//! call is inspected. The receiver's method is resolved, and when it belongs
+//! to an inherent `impl` of the standard library `Mutex` or `RwLock`,
+//! [`should_flag`] decides whether it acquires the lock and whether the call
+//! sits outside test code. Test code is recognized through
+//! [`whitaker::hir::TestContext`], and calls produced by macro expansion are
+//! skipped. The finding spans the lock method and the panicking call.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::def_id::DefId;
+use rustc_span::{Span, sym};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext, describe_enclosing_item};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::{AttributePath, CrateScopedState};
+
+use crate::policy::{LockKind, LockUnwrap, Panicking, should_flag};
+
+pub(crate) const LINT_NAME: &str = "no_lock_unwrap";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    additional_test_attributes: Vec<String>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_LOCK_UNWRAP,
+    Warn,
+    "lock poisoning should be handled explicitly rather than with `.lock().unwrap()`",
+    NoLockUnwrap::default()
+}
+
+/// Lint pass that finds `unwrap` and `expect` on lock acquisitions.
+#[derive(Default)]
+pub struct NoLockUnwrap {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Test context, finding limit, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    test_context: TestContext,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoLockUnwrap {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let additional = load_configuration()
+            .additional_test_attributes
+            .iter()
+            .map(|path| AttributePath::from(path.as_str()))
+            .collect();
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            test_context: TestContext::for_crate(cx, additional),
+            findings: FindingLimit::new(NO_LOCK_UNWRAP, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::MethodCall(segment, receiver, ..) = expr.kind else {
+            return;
+        };
+        let Some(panicking) = Panicking::from_name(segment.ident.as_str()) else {
+            return;
+        };
+        let hir::ExprKind::MethodCall(lock_segment, ..) = receiver.kind else {
+            return;
+        };
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(lock) = cx
+            .typeck_results()
+            .type_dependent_def_id(receiver.hir_id)
+            .and_then(|method| lock_kind(cx, method))
+        else {
+            return;
+        };
+
+        let state = &*self.state;
+        let call = LockUnwrap {
+            lock,
+            method: lock_segment.ident.as_str(),
+            in_test: state.test_context.is_test(cx, expr.hir_id),
+        };
+        if !should_flag(&call) {
+            return;
+        }
+
+        let context = describe_enclosing_item(cx, expr.hir_id).describe(&state.localizer);
+        debug!(
+            target: LINT_NAME,
+            "`{}::{}` unwrapped with `{}` in {context}",
+            lock.name(),
+            call.method,
+            panicking.name(),
+        );
+        let span = expr.span.with_lo(lock_segment.ident.span.lo());
+        if state.findings.admit(cx, expr.hir_id, span) {
+            let message = LockUnwrapMessage {
+                lock: lock.name(),
+                method: call.method,
+                call: panicking.name(),
+                context: &context,
+            };
+            emit_diagnostic(cx, span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the lock type whose inherent `impl` defines `method`.
+fn lock_kind(cx: &LateContext<'_>, method: DefId) -> Option<LockKind> {
+    let impl_id = cx.tcx.inherent_impl_of_assoc(method)?;
+    let self_ty = cx
+        .tcx
+        .type_of(impl_id)
+        .instantiate_identity()
+        .skip_normalization();
+    let did = self_ty.ty_adt_def()?.did();
+    if cx.tcx.is_diagnostic_item(sym::Mutex, did) {
+        Some(LockKind::Mutex)
+    } else if cx.tcx.is_diagnostic_item(sym::RwLock, did) {
+        Some(LockKind::RwLock)
+    } else {
+        None
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &LockUnwrapMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        NO_LOCK_UNWRAP,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for an unwrapped lock acquisition interpolates.
+#[derive(Clone, Copy, Debug)]
+struct LockUnwrapMessage<'a> {
+    lock: &'a str,
+    method: &'a str,
+    call: &'a str,
+    context: &'a str,
+}
+
+impl LockUnwrapMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("lock"),
+            FluentValue::from(self.lock.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("method"),
+            FluentValue::from(self.method.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("call"),
+            FluentValue::from(self.call.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(self.context.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            lock,
+            method,
+            call,
+            context,
+        } = *self;
+        DiagnosticMessageSet::new(
+            format!("`{lock}::{method}` is followed by `.{call}()` in {context}."),
+            format!(
+                "`{lock}::{method}` fails only when another thread panicked while holding the \
+                 lock, and `.{call}()` turns that poisoning into a second panic without deciding \
+                 whether the protected data is still usable."
+            ),
+            String::from(
+                "Handle the `PoisonError` explicitly, such as with \
+                 `.unwrap_or_else(PoisonError::into_inner)` when the data stays valid, or use \
+                 `parking_lot`, whose locks do not poison.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message() -> LockUnwrapMessage<'static> {
+        LockUnwrapMessage {
+            lock: "RwLock",
+            method: "write",
+            call: "expect",
+            context: "method `record`",
+        }
+    }
+
+    fn resolve(message: &LockUnwrapMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_adds_no_test_attributes() {
+        assert!(Config::default().additional_test_attributes.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_lock_method() {
+        let messages = message().fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "`RwLock::write` is followed by `.expect()` in method `record`."
+        );
+        assert!(messages.help().contains("parking_lot"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        let message = message();
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(), locale);
+
+        assert!(messages.primary().contains("`RwLock::write`"));
+        assert!(messages.help().contains("`parking_lot`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,43 @@
+//! Lint crate flagging `.lock().unwrap()` on standard library locks.
+//!
+//! `Mutex::lock`, `RwLock::read`, and `RwLock::write` fail only when another
+//! thread panicked while holding the lock. Unwrapping the result turns that
+//! poisoning into a second panic without deciding whether the protected data
+//! is still usable. Generic unwrap lints rarely single this case out, so the
+//! lint gives teams a separate rule they can allow on its own. Calls to
+//! `unwrap` and `expect` on those results are reported outside test code,
+//! and the help points at explicit poisoning handling or `parking_lot`. The
+//! lint is experimental and ships behind the `experimental-no-lock-unwrap`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{LockUnwrapProjection, NoLockUnwrapDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_lock_unwrap_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
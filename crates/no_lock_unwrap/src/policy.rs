@@ -0,0 +1,133 @@
+//! Rules deciding which unwrapped lock acquisitions are reported.
+//!
+//! The driver describes each `unwrap` or `expect` whose receiver calls a
+//! method on a standard library `Mutex` or `RwLock`, and this module decides
+//! whether that method acquires the lock and whether the call is reported.
+//! The rules are kept free of compiler types so they can be tested directly.
+
+/// A standard library lock type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum LockKind {
+    /// `std::sync::Mutex`.
+    Mutex,
+    /// `std::sync::RwLock`.
+    RwLock,
+}
+
+impl LockKind {
+    /// The type name shown in diagnostics.
+    #[must_use]
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Mutex => "Mutex",
+            Self::RwLock => "RwLock",
+        }
+    }
+
+    /// Returns `true` when `method` blocks to acquire this lock and fails
+    /// only when the lock is poisoned.
+    #[must_use]
+    pub(crate) fn acquires(self, method: &str) -> bool {
+        match self {
+            Self::Mutex => method == "lock",
+            Self::RwLock => matches!(method, "read" | "write"),
+        }
+    }
+}
+
+/// A method that panics when its `Result` holds an error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Panicking {
+    /// `Result::unwrap`.
+    Unwrap,
+    /// `Result::expect`.
+    Expect,
+}
+
+impl Panicking {
+    /// Recognizes `unwrap` and `expect` by name.
+    #[must_use]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "unwrap" => Some(Self::Unwrap),
+            "expect" => Some(Self::Expect),
+            _ => None,
+        }
+    }
+
+    /// The method name shown in diagnostics.
+    #[must_use]
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Unwrap => "unwrap",
+            Self::Expect => "expect",
+        }
+    }
+}
+
+/// A panicking call on the result of a lock method, described without
+/// compiler types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct LockUnwrap<'a> {
+    /// The lock the method belongs to.
+    pub(crate) lock: LockKind,
+    /// The lock method whose result is unwrapped.
+    pub(crate) method: &'a str,
+    /// Whether the call sits in test code.
+    pub(crate) in_test: bool,
+}
+
+/// Returns `true` when `call` unwraps a lock acquisition outside test code.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = LockUnwrap { lock: LockKind::RwLock, method: "write", in_test: false };
+/// assert!(should_flag(&call));
+/// ```
+#[must_use]
+pub(crate) fn should_flag(call: &LockUnwrap<'_>) -> bool {
+    !call.in_test && call.lock.acquires(call.method)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(LockKind::Mutex, "lock", true)]
+    #[case(LockKind::Mutex, "try_lock", false)]
+    #[case(LockKind::Mutex, "get_mut", false)]
+    #[case(LockKind::RwLock, "read", true)]
+    #[case(LockKind::RwLock, "write", true)]
+    #[case(LockKind::RwLock, "lock", false)]
+    fn acquisitions_are_recognized(
+        #[case] lock: LockKind,
+        #[case] method: &str,
+        #[case] acquires: bool,
+    ) {
+        assert_eq!(lock.acquires(method), acquires);
+    }
+
+    #[rstest]
+    #[case("unwrap", Some(Panicking::Unwrap))]
+    #[case("expect", Some(Panicking::Expect))]
+    #[case("unwrap_or_else", None)]
+    fn panicking_methods_are_recognized(#[case] name: &str, #[case] expected: Option<Panicking>) {
+        assert_eq!(Panicking::from_name(name), expected);
+    }
+
+    #[rstest]
+    #[case(false, true)]
+    #[case(true, false)]
+    fn test_code_is_exempt(#[case] in_test: bool, #[case] flagged: bool) {
+        let call = LockUnwrap {
+            lock: LockKind::Mutex,
+            method: "lock",
+            in_test,
+        };
+
+        assert_eq!(should_flag(&call), flagged);
+    }
+}
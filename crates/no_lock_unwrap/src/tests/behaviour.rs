@@ -0,0 +1,90 @@
+//! Behaviour-driven coverage for the unwrapped lock policy.
+
+use crate::policy::{LockKind, LockUnwrap, should_flag};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct LockWorld {
+    lock: Cell<LockKind>,
+    method: RefCell<String>,
+    in_test: Cell<bool>,
+    flagged: Cell<Option<bool>>,
+}
+
+impl Default for LockWorld {
+    fn default() -> Self {
+        Self {
+            lock: Cell::new(LockKind::Mutex),
+            method: RefCell::default(),
+            in_test: Cell::new(false),
+            flagged: Cell::new(None),
+        }
+    }
+}
+
+#[fixture]
+fn world() -> LockWorld {
+    LockWorld::default()
+}
+
+fn set_call(world: &LockWorld, method: &str, lock: LockKind) {
+    *world.method.borrow_mut() = method.trim_matches('"').to_owned();
+    world.lock.set(lock);
+}
+
+#[given("a call to {method} on a Mutex")]
+fn given_mutex(world: &LockWorld, method: String) {
+    set_call(world, &method, LockKind::Mutex);
+}
+
+#[given("a call to {method} on a RwLock")]
+fn given_rwlock(world: &LockWorld, method: String) {
+    set_call(world, &method, LockKind::RwLock);
+}
+
+#[given("the call sits in test code")]
+fn given_test_code(world: &LockWorld) {
+    world.in_test.set(true);
+}
+
+#[when("the unwrap is checked")]
+fn when_checked(world: &LockWorld) {
+    let method = world.method.borrow();
+    let call = LockUnwrap {
+        lock: world.lock.get(),
+        method: &method,
+        in_test: world.in_test.get(),
+    };
+    world.flagged.set(Some(should_flag(&call)));
+}
+
+#[then("the call is reported")]
+fn then_reported(world: &LockWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the call is allowed")]
+fn then_allowed(world: &LockWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/no_lock_unwrap.feature", index = 0)]
+fn scenario_mutex_lock(world: LockWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lock_unwrap.feature", index = 1)]
+fn scenario_rwlock_write(world: LockWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lock_unwrap.feature", index = 2)]
+fn scenario_non_acquiring_method(world: LockWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lock_unwrap.feature", index = 3)]
+fn scenario_test_code(world: LockWorld) {
+    let _ = world;
+}
@@ -0,0 +1,24 @@
+Feature: Unwrapped lock acquisitions
+  `unwrap` and `expect` on the result of `Mutex::lock`, `RwLock::read`, or
+  `RwLock::write` are reported outside test code.
+
+  Scenario: Unwrapping a mutex lock is reported
+    Given a call to "lock" on a Mutex
+    When the unwrap is checked
+    Then the call is reported
+
+  Scenario: Unwrapping a write lock is reported
+    Given a call to "write" on a RwLock
+    When the unwrap is checked
+    Then the call is reported
+
+  Scenario: Methods that do not acquire the lock are ignored
+    Given a call to "get_mut" on a Mutex
+    When the unwrap is checked
+    Then the call is allowed
+
+  Scenario: Test code is exempt
+    Given a call to "lock" on a Mutex
+    And the call sits in test code
+    When the unwrap is checked
+    Then the call is allowed
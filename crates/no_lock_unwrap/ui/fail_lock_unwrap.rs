@@ -0,0 +1,28 @@
+//! Lock acquisitions unwrapped outside test code.
+
+use std::sync::{Arc, Mutex, RwLock};
+
+struct Ledger {
+    entries: Mutex<Vec<i64>>,
+    totals: Arc<RwLock<i64>>,
+}
+
+impl Ledger {
+    fn record(&self, amount: i64) {
+        self.entries.lock().unwrap().push(amount);
+        *self.totals.write().expect("totals lock poisoned") += amount;
+    }
+
+    fn total(&self) -> i64 {
+        *self.totals.read().unwrap()
+    }
+}
+
+fn main() {
+    let ledger = Ledger {
+        entries: Mutex::new(Vec::new()),
+        totals: Arc::new(RwLock::new(0)),
+    };
+    ledger.record(5);
+    let _ = ledger.total();
+}
@@ -0,0 +1,43 @@
+//! Poisoning handled explicitly, non-acquiring lock methods, and test code
+//! are accepted.
+
+use std::sync::{Mutex, PoisonError, RwLock, TryLockError};
+
+fn push(entries: &Mutex<Vec<i64>>, amount: i64) {
+    entries
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(amount);
+}
+
+fn total(totals: &RwLock<i64>) -> Option<i64> {
+    totals.read().ok().map(|total| *total)
+}
+
+fn drain(mut entries: Mutex<Vec<i64>>) -> Vec<i64> {
+    let _ = entries.get_mut().map(Vec::clear);
+    entries.into_inner().unwrap_or_default()
+}
+
+fn poll(entries: &Mutex<Vec<i64>>) -> bool {
+    !matches!(entries.try_lock(), Err(TryLockError::WouldBlock))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    #[test]
+    fn unwraps_in_tests() {
+        let entries = Mutex::new(vec![1]);
+        assert_eq!(entries.lock().unwrap().len(), 1);
+    }
+}
+
+fn main() {
+    let entries = Mutex::new(Vec::new());
+    push(&entries, 5);
+    let _ = poll(&entries);
+    let _ = drain(entries);
+    let _ = total(&RwLock::new(0));
+}
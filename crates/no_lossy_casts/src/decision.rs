@@ -0,0 +1,133 @@
+//! Pure decision logic for `no_lossy_casts`.
+//!
+//! The driver names the source and target types of each `as` cast and reads
+//! the operand when it is a numeric literal. [`NoLossyCastsDecision`]
+//! classifies the cast with the same [`cast_loss`] and accepts integer
+//! literals the target holds exactly and float literals cast to a float.
+//! Findings use the [`CastLoss`] Fluent selector as their reason and the cast,
+//! such as `u64 as u32`, as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{CastLoss, NumericType, cast_loss};
+
+/// The facts about one `as` cast that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CastProjection {
+    /// The operand's type, such as `u64` or `f64`.
+    pub from: String,
+    /// The target type, such as `u32` or `f32`.
+    pub to: String,
+    /// The operand's magnitude, when it is an integer literal.
+    pub literal: Option<u128>,
+    /// Whether the literal operand is negated.
+    pub negative: bool,
+    /// Whether the operand is a float literal, optionally negated.
+    pub float_literal: bool,
+}
+
+/// Reports `as` casts that can wrap, truncate, or round the value they
+/// convert.
+///
+/// # Examples
+///
+/// ```ignore
+/// let cast = CastProjection {
+///     from: "u64".to_owned(),
+///     to: "u32".to_owned(),
+///     ..CastProjection::default()
+/// };
+/// let finding = NoLossyCastsDecision.decide(&cast);
+/// assert_eq!(finding.map(|finding| finding.reason()), Some("range"));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoLossyCastsDecision;
+
+impl LintDecision for NoLossyCastsDecision {
+    type Projection = CastProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, cast: &CastProjection) -> Option<Finding> {
+        let from = NumericType::from_name(&cast.from)?;
+        let to = NumericType::from_name(&cast.to)?;
+        let loss = cast_loss(from, to)?;
+        let holds_literal = match cast.literal {
+            Some(value) => to.holds_literal(value, cast.negative),
+            None => cast.float_literal && matches!(to, NumericType::Float(_)),
+        };
+        if holds_literal {
+            return None;
+        }
+        Some(
+            Finding::new(LINT_NAME, loss.fluent_kind())
+                .with_subject(format!("{} as {}", cast.from, cast.to)),
+        )
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<CastProjection>> {
+        let cast = |from: &str, to: &str, literal, negative| CastProjection {
+            from: from.to_owned(),
+            to: to.to_owned(),
+            literal,
+            negative,
+            float_literal: false,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "narrowing",
+                cast("u64", "u32", None, false),
+                CastLoss::Range.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "signed to unsigned",
+                cast("i32", "u32", None, false),
+                CastLoss::Sign.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "signed and narrowing",
+                cast("isize", "u16", None, false),
+                CastLoss::SignAndRange.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "negative literal to unsigned",
+                cast("i32", "u8", Some(1), true),
+                CastLoss::SignAndRange.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "float to integer",
+                cast("f64", "i32", None, false),
+                CastLoss::Fraction.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "float narrowing",
+                cast("f64", "f32", None, false),
+                CastLoss::Precision.fluent_kind(),
+            ),
+            DecisionFixture::clean("widening", cast("u32", "u64", None, false)),
+            DecisionFixture::clean("literal that fits", cast("i32", "u8", Some(255), false)),
+            DecisionFixture::clean(
+                "float literal to float",
+                CastProjection {
+                    float_literal: true,
+                    ..cast("f64", "f32", None, false)
+                },
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&NoLossyCastsDecision), Ok(9));
+    }
+}
@@ -0,0 +1,17 @@
+//! Embedded documentation for the `no_lossy_casts` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, examples, and configuration for `no_lossy_casts`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_lossy_casts",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags numeric `as` casts that can truncate values, round them, or change their sign.",
+    rationale: "An `as` cast never fails, so a value the target type cannot hold silently becomes a different number. `try_from` and `try_into` report integer overflow and make the caller decide what it means; float casts need explicit rounding and range checks.",
+    bad_example: "let len = total as u32;",
+    good_example: "let len = u32::try_from(total)?;",
+    config: &[],
+};
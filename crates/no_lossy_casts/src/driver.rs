@@ -0,0 +1,272 @@
+//! Detect numeric `as` casts that can truncate, round, or change sign.
+//!
+//! The lint inspects every `as` cast whose operand and result are both
+//! primitive integer or float types and asks [`crate::policy::cast_loss`]
+//! whether the target represents every value of the source exactly. Casts of
+//! integer literals, optionally negated, are skipped when the target holds the
+//! literal exactly, as are float literals cast to a float type. Casts produced
+//! by macro expansion are left to the macro's author.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_hir::{ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{CastLoss, NumericType, cast_loss};
+
+pub(crate) const LINT_NAME: &str = "no_lossy_casts";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub NO_LOSSY_CASTS,
+    Warn,
+    "numeric `as` casts that can truncate, round, or change sign; convert explicitly and handle the loss",
+    NoLossyCasts::default()
+}
+
+/// Lint pass reporting numeric `as` casts that can lose information.
+#[derive(Default)]
+pub struct NoLossyCasts {
+    localizer: CrateScopedState<Localizer>,
+    findings: CrateScopedState<FindingLimit>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoLossyCasts {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        self.findings
+            .reset(FindingLimit::new(NO_LOSSY_CASTS, &shared_config));
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.findings.summarise(cx, &self.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ExprKind::Cast(operand, _) = expr.kind else {
+            return;
+        };
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        let typeck = cx.typeck_results();
+        let (Some((from, from_name)), Some((to, to_name))) = (
+            numeric_type(typeck.expr_ty(operand)),
+            numeric_type(typeck.expr_ty(expr)),
+        ) else {
+            return;
+        };
+        let Some(loss) = cast_loss(from, to) else {
+            return;
+        };
+        let holds_literal = match literal(operand) {
+            Some(Literal::Int(value, negative)) => to.holds_literal(value, negative),
+            Some(Literal::Float) => matches!(to, NumericType::Float(_)),
+            None => false,
+        };
+        if holds_literal {
+            return;
+        }
+
+        let cast = LossyCast {
+            from: from_name,
+            to: to_name,
+            loss,
+        };
+        debug!(target: LINT_NAME, "`{}` as `{}` can lose information", cast.from, cast.to);
+        if self.findings.admit(cx, expr.hir_id, expr.span) {
+            emit_diagnostic(cx, expr.span, &cast, &self.localizer);
+        }
+    }
+}
+
+/// Returns the numeric type `ty` names, together with its name.
+fn numeric_type(ty: Ty<'_>) -> Option<(NumericType, &'static str)> {
+    let name = match ty.kind() {
+        ty::Int(int) => int.name_str(),
+        ty::Uint(uint) => uint.name_str(),
+        ty::Float(float) => float.name_str(),
+        _ => return None,
+    };
+    NumericType::from_name(name).map(|numeric| (numeric, name))
+}
+
+/// A numeric literal operand, optionally negated.
+#[derive(Clone, Copy, Debug)]
+enum Literal {
+    /// An integer literal's magnitude and whether it is negated.
+    Int(u128, bool),
+    /// A float literal, whose exact value the lint does not inspect.
+    Float,
+}
+
+/// Returns the numeric literal `expr` consists of, if any.
+fn literal(expr: &hir::Expr<'_>) -> Option<Literal> {
+    let (inner, negative) = match expr.kind {
+        ExprKind::Unary(UnOp::Neg, inner) => (inner, true),
+        _ => (expr, false),
+    };
+    match inner.kind {
+        ExprKind::Lit(lit) => match lit.node {
+            LitKind::Int(value, _) => Some(Literal::Int(value.get(), negative)),
+            LitKind::Float(..) => Some(Literal::Float),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// A numeric `as` cast that can lose information.
+#[derive(Clone, Copy, Debug)]
+struct LossyCast {
+    from: &'static str,
+    to: &'static str,
+    loss: CastLoss,
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, span: Span, cast: &LossyCast, localizer: &Localizer) {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("from"), FluentValue::from(cast.from));
+    args.insert(Cow::Borrowed("to"), FluentValue::from(cast.to));
+    args.insert(
+        Cow::Borrowed("loss"),
+        FluentValue::from(cast.loss.fluent_kind()),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(cast)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        NO_LOSSY_CASTS,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(cast: &LossyCast) -> DiagnosticMessageSet {
+    let LossyCast { from, to, loss } = *cast;
+    let effect = match loss {
+        CastLoss::Sign => String::from("wraps negative values"),
+        CastLoss::Range => format!("truncates values outside the range of `{to}`"),
+        CastLoss::SignAndRange => {
+            format!("wraps negative values and truncates values outside the range of `{to}`")
+        }
+        CastLoss::Fraction => {
+            format!("drops the fractional part and saturates values outside the range of `{to}`")
+        }
+        CastLoss::Precision => format!("rounds values that `{to}` cannot represent exactly"),
+    };
+    let help = match loss {
+        CastLoss::Fraction => format!(
+            "Round explicitly with `trunc`, `round`, `floor`, or `ceil`, and check that the \
+             value is finite and within the range of `{to}` before casting."
+        ),
+        CastLoss::Precision => String::from(
+            "Keep the value in a type that represents it exactly, or allow the lint where the \
+             rounding is intended.",
+        ),
+        CastLoss::Sign | CastLoss::Range | CastLoss::SignAndRange => format!(
+            "Use `{to}::try_from(value)` or `value.try_into()` and handle the error, or change \
+             the types so that the cast widens."
+        ),
+    };
+    DiagnosticMessageSet::new(
+        format!("Casting `{from}` to `{to}` with `as` {effect}."),
+        format!(
+            "`as` never fails, so a value `{to}` cannot hold silently becomes a different number."
+        ),
+        help,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        CastLoss::Sign,
+        "Casting `i64` to `u32` with `as` wraps negative values."
+    )]
+    #[case(
+        CastLoss::Range,
+        "Casting `i64` to `u32` with `as` truncates values outside the range of `u32`."
+    )]
+    #[case(
+        CastLoss::SignAndRange,
+        "Casting `i64` to `u32` with `as` wraps negative values and truncates values outside the range of `u32`."
+    )]
+    fn fallback_messages_describe_the_loss(#[case] loss: CastLoss, #[case] expected: &str) {
+        let messages = fallback_messages(&LossyCast {
+            from: "i64",
+            to: "u32",
+            loss,
+        });
+
+        assert_eq!(messages.primary(), expected);
+        assert!(messages.help().contains("u32::try_from"));
+    }
+
+    #[rstest]
+    #[case(
+        "i32",
+        CastLoss::Fraction,
+        "Casting `f64` to `i32` with `as` drops the fractional part and saturates values outside the range of `i32`.",
+        "trunc"
+    )]
+    #[case(
+        "f32",
+        CastLoss::Precision,
+        "Casting `f64` to `f32` with `as` rounds values that `f32` cannot represent exactly.",
+        "represents it exactly"
+    )]
+    fn fallback_messages_describe_float_losses(
+        #[case] to: &'static str,
+        #[case] loss: CastLoss,
+        #[case] expected: &str,
+        #[case] help: &str,
+    ) {
+        let messages = fallback_messages(&LossyCast {
+            from: "f64",
+            to,
+            loss,
+        });
+
+        assert_eq!(messages.primary(), expected);
+        assert!(messages.help().contains(help));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,41 @@
+//! Lint crate flagging integer `as` casts that can truncate or change sign.
+//!
+//! An `as` cast between integer types never fails: `u64 as u32` keeps only
+//! the low bits and `-1_i64 as usize` becomes `usize::MAX`. The lint reports
+//! casts whose target cannot hold every value of the source type and suggests
+//! `try_from` or `try_into` with error handling. Widening casts, casts between
+//! types of the same width and sign, and literals that fit their target are
+//! never reported. The lint is experimental and ships behind the
+//! `experimental-no-lossy-casts` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{CastProjection, NoLossyCastsDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_lossy_casts_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
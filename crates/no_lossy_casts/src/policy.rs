@@ -0,0 +1,328 @@
+//! Classification of numeric `as` casts that can lose information.
+//!
+//! An `as` cast between numeric types never fails. Between integer types, a
+//! value the target type cannot hold is wrapped into its range: [`cast_loss`]
+//! compares the ranges of the source and target types and reports whether
+//! negative values wrap, values beyond the target's range wrap, or both.
+//! `usize` and `isize` are taken to be between 32 and 64 bits wide, so a cast
+//! is reported when it can lose information on any supported target.
+//!
+//! A float cast to an integer drops the fractional part and saturates at the
+//! target's bounds, with `NaN` becoming zero. A float cast to a narrower float,
+//! and an integer cast to a float whose significand is narrower than the
+//! integer, round values the target cannot represent exactly.
+
+/// The width of an integer type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Width {
+    /// A fixed number of bits, as for `u32` or `i128`.
+    Bits(u32),
+    /// The pointer width of `usize` and `isize`.
+    Pointer,
+}
+
+impl Width {
+    const fn min_bits(self) -> u32 {
+        match self {
+            Self::Bits(bits) => bits,
+            Self::Pointer => 32,
+        }
+    }
+
+    const fn max_bits(self) -> u32 {
+        match self {
+            Self::Bits(bits) => bits,
+            Self::Pointer => 64,
+        }
+    }
+}
+
+/// A primitive integer type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct IntType {
+    signed: bool,
+    width: Width,
+}
+
+impl IntType {
+    /// Returns the integer type called `name`, such as `u64` or `isize`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// assert!(IntType::from_name("i16").is_some());
+    /// assert!(IntType::from_name("f32").is_none());
+    /// ```
+    #[must_use]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let (signed, width) = name
+            .strip_prefix('i')
+            .map(|width| (true, width))
+            .or_else(|| name.strip_prefix('u').map(|width| (false, width)))?;
+        let width = match width {
+            "size" => Width::Pointer,
+            "8" | "16" | "32" | "64" | "128" => Width::Bits(width.parse().ok()?),
+            _ => return None,
+        };
+        Some(Self { signed, width })
+    }
+
+    /// Returns `true` when the integer literal `value`, negated when
+    /// `negative` is set, fits this type on every supported target.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let byte = IntType::from_name("u8").unwrap();
+    /// assert!(byte.holds_literal(255, false));
+    /// assert!(!byte.holds_literal(1, true));
+    /// ```
+    #[must_use]
+    pub(crate) fn holds_literal(self, value: u128, negative: bool) -> bool {
+        let bits = self.width.min_bits() - u32::from(self.signed);
+        if negative {
+            value == 0 || (self.signed && value <= 1_u128 << bits)
+        } else {
+            value.checked_shr(bits).unwrap_or(0) == 0
+        }
+    }
+}
+
+/// A primitive floating-point type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct FloatType {
+    bits: u32,
+}
+
+impl FloatType {
+    /// Returns the float type called `name`, such as `f32`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// assert!(FloatType::from_name("f64").is_some());
+    /// assert!(FloatType::from_name("u64").is_none());
+    /// ```
+    #[must_use]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let bits = match name.strip_prefix('f')? {
+            "16" => 16,
+            "32" => 32,
+            "64" => 64,
+            "128" => 128,
+            _ => return None,
+        };
+        Some(Self { bits })
+    }
+
+    /// Returns the number of significant bits, including the implicit one,
+    /// so every integer up to `2^significand_bits` is represented exactly.
+    const fn significand_bits(self) -> u32 {
+        match self.bits {
+            16 => 11,
+            32 => 24,
+            64 => 53,
+            _ => 113,
+        }
+    }
+}
+
+/// A primitive numeric type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum NumericType {
+    /// An integer type, such as `u32`.
+    Int(IntType),
+    /// A floating-point type, such as `f64`.
+    Float(FloatType),
+}
+
+impl NumericType {
+    /// Returns the numeric type called `name`, such as `i64` or `f32`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// assert!(NumericType::from_name("f32").is_some());
+    /// assert!(NumericType::from_name("char").is_none());
+    /// ```
+    #[must_use]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        IntType::from_name(name)
+            .map(Self::Int)
+            .or_else(|| FloatType::from_name(name).map(Self::Float))
+    }
+
+    /// Returns `true` when the integer literal `value`, negated when
+    /// `negative` is set, is represented exactly by this type on every
+    /// supported target.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let single = NumericType::from_name("f32").unwrap();
+    /// assert!(single.holds_literal(1 << 24, true));
+    /// assert!(!single.holds_literal((1 << 24) + 1, false));
+    /// ```
+    #[must_use]
+    pub(crate) fn holds_literal(self, value: u128, negative: bool) -> bool {
+        match self {
+            Self::Int(int) => int.holds_literal(value, negative),
+            Self::Float(float) => value <= 1 << float.significand_bits(),
+        }
+    }
+}
+
+/// How an `as` cast can change the value it converts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum CastLoss {
+    /// Negative values wrap to large unsigned ones.
+    Sign,
+    /// Values beyond the target's range wrap.
+    Range,
+    /// Both negative values and values beyond the target's range wrap.
+    SignAndRange,
+    /// A float loses its fractional part and saturates at the target's bounds.
+    Fraction,
+    /// Values the target cannot represent exactly are rounded.
+    Precision,
+}
+
+impl CastLoss {
+    /// Returns the selector passed to the Fluent message.
+    #[must_use]
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Sign => "sign",
+            Self::Range => "range",
+            Self::SignAndRange => "both",
+            Self::Fraction => "fraction",
+            Self::Precision => "precision",
+        }
+    }
+}
+
+/// Returns how casting `from` to `to` with `as` can lose information, or
+/// `None` when every value of `from` is represented exactly by `to`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let from = NumericType::from_name("u64").unwrap();
+/// let to = NumericType::from_name("u32").unwrap();
+/// assert_eq!(cast_loss(from, to), Some(CastLoss::Range));
+/// assert_eq!(cast_loss(to, from), None);
+/// ```
+#[must_use]
+pub(crate) fn cast_loss(from: NumericType, to: NumericType) -> Option<CastLoss> {
+    match (from, to) {
+        (NumericType::Int(from), NumericType::Int(to)) => int_cast_loss(from, to),
+        (NumericType::Float(_), NumericType::Int(_)) => Some(CastLoss::Fraction),
+        (NumericType::Float(from), NumericType::Float(to)) => {
+            (from.bits > to.bits).then_some(CastLoss::Precision)
+        }
+        (NumericType::Int(from), NumericType::Float(to)) => {
+            let value_bits = from.width.max_bits() - u32::from(from.signed);
+            (value_bits > to.significand_bits()).then_some(CastLoss::Precision)
+        }
+    }
+}
+
+fn int_cast_loss(from: IntType, to: IntType) -> Option<CastLoss> {
+    // Both pointer-width types share the same width on any one target.
+    let (from_bits, to_bits) = match (from.width, to.width) {
+        (Width::Pointer, Width::Pointer) => (64, 64),
+        _ => (from.width.max_bits(), to.width.min_bits()),
+    };
+    let sign = from.signed && !to.signed;
+    let range = from_bits - u32::from(from.signed) > to_bits - u32::from(to.signed);
+    match (sign, range) {
+        (true, true) => Some(CastLoss::SignAndRange),
+        (true, false) => Some(CastLoss::Sign),
+        (false, true) => Some(CastLoss::Range),
+        (false, false) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn numeric(name: &str) -> NumericType {
+        NumericType::from_name(name).expect("test type names are numeric")
+    }
+
+    #[rstest]
+    #[case::narrowing("u64", "u32", Some(CastLoss::Range))]
+    #[case::signed_narrowing("i32", "i8", Some(CastLoss::Range))]
+    #[case::same_width_to_signed("u32", "i32", Some(CastLoss::Range))]
+    #[case::to_unsigned("i8", "u8", Some(CastLoss::Sign))]
+    #[case::widening_to_unsigned("i32", "u64", Some(CastLoss::Sign))]
+    #[case::narrowing_to_unsigned("i64", "usize", Some(CastLoss::SignAndRange))]
+    #[case::to_pointer_width("u64", "usize", Some(CastLoss::Range))]
+    #[case::from_pointer_width("usize", "u32", Some(CastLoss::Range))]
+    #[case::pointer_width_to_signed("usize", "isize", Some(CastLoss::Range))]
+    #[case::float_to_int("f64", "i32", Some(CastLoss::Fraction))]
+    #[case::float_to_wide_int("f32", "u128", Some(CastLoss::Fraction))]
+    #[case::float_narrowing("f64", "f32", Some(CastLoss::Precision))]
+    #[case::int_beyond_significand("i64", "f64", Some(CastLoss::Precision))]
+    #[case::pointer_width_to_float("usize", "f64", Some(CastLoss::Precision))]
+    #[case::int_to_single("u32", "f32", Some(CastLoss::Precision))]
+    fn reports_lossy_casts(#[case] from: &str, #[case] to: &str, #[case] loss: Option<CastLoss>) {
+        assert_eq!(cast_loss(numeric(from), numeric(to)), loss);
+    }
+
+    #[rstest]
+    #[case::widening("u32", "u64")]
+    #[case::signed_widening("i8", "i64")]
+    #[case::unsigned_to_wider_signed("u32", "i64")]
+    #[case::same_type("u16", "u16")]
+    #[case::same_pointer_width("usize", "usize")]
+    #[case::into_pointer_width("u32", "usize")]
+    #[case::out_of_pointer_width("isize", "i64")]
+    #[case::float_widening("f32", "f64")]
+    #[case::same_float("f64", "f64")]
+    #[case::int_within_significand("i32", "f64")]
+    #[case::small_int_to_single("u16", "f32")]
+    fn accepts_lossless_casts(#[case] from: &str, #[case] to: &str) {
+        assert_eq!(cast_loss(numeric(from), numeric(to)), None);
+    }
+
+    #[rstest]
+    #[case::float("f64")]
+    #[case::character("char")]
+    #[case::odd_width("u7")]
+    fn rejects_non_integer_names(#[case] name: &str) {
+        assert!(IntType::from_name(name).is_none());
+    }
+
+    #[rstest]
+    #[case::character("char")]
+    #[case::boolean("bool")]
+    #[case::odd_float("f8")]
+    fn rejects_non_numeric_names(#[case] name: &str) {
+        assert!(NumericType::from_name(name).is_none());
+    }
+
+    #[rstest]
+    #[case::byte_max("u8", 255, false, true)]
+    #[case::byte_overflow("u8", 256, false, false)]
+    #[case::negative_unsigned("u8", 1, true, false)]
+    #[case::negative_zero("u8", 0, true, true)]
+    #[case::signed_min("i8", 128, true, true)]
+    #[case::signed_max("i8", 128, false, false)]
+    #[case::widest("u128", u128::MAX, false, true)]
+    #[case::pointer_width("usize", u128::from(u32::MAX), false, true)]
+    #[case::beyond_pointer_width("usize", 1 << 32, false, false)]
+    #[case::single_significand("f32", 1 << 24, true, true)]
+    #[case::beyond_single_significand("f32", (1 << 24) + 1, false, false)]
+    #[case::double_significand("f64", 1 << 53, false, true)]
+    fn checks_literals(
+        #[case] ty: &str,
+        #[case] value: u128,
+        #[case] negative: bool,
+        #[case] holds: bool,
+    ) {
+        assert_eq!(numeric(ty).holds_literal(value, negative), holds);
+    }
+}
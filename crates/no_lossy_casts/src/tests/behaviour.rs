@@ -0,0 +1,80 @@
+//! Behaviour-driven coverage for the lossy cast policy.
+
+use crate::policy::{CastLoss, NumericType, cast_loss};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct CastWorld {
+    types: RefCell<Option<(NumericType, NumericType)>>,
+    loss: Cell<Option<Option<CastLoss>>>,
+}
+
+#[fixture]
+fn world() -> CastWorld {
+    CastWorld::default()
+}
+
+#[given("a cast from {from} to {to}")]
+fn given_cast(world: &CastWorld, from: String, to: String) {
+    let numeric = |name: &str| NumericType::from_name(name).expect("scenario names numeric types");
+    *world.types.borrow_mut() = Some((numeric(&from), numeric(&to)));
+}
+
+#[when("the cast is checked")]
+fn when_checked(world: &CastWorld) {
+    let (from, to) = world.types.borrow().expect("a cast is given");
+    world.loss.set(Some(cast_loss(from, to)));
+}
+
+#[then("the cast is reported as {kind}")]
+fn then_reported(world: &CastWorld, kind: String) {
+    let loss = world.loss.get().flatten().expect("the cast is reported");
+    assert_eq!(loss.fluent_kind(), kind);
+}
+
+#[then("the cast is accepted")]
+fn then_accepted(world: &CastWorld) {
+    assert_eq!(world.loss.get(), Some(None));
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 0)]
+fn scenario_narrowing_cast(world: CastWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 1)]
+fn scenario_signed_to_unsigned_cast(world: CastWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 2)]
+fn scenario_signed_narrowing_to_unsigned(world: CastWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 3)]
+fn scenario_widening_cast(world: CastWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 4)]
+fn scenario_same_width_cast(world: CastWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 5)]
+fn scenario_float_to_integer_cast(world: CastWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 6)]
+fn scenario_float_narrowing_cast(world: CastWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_lossy_casts.feature", index = 7)]
+fn scenario_float_widening_cast(world: CastWorld) {
+    let _ = world;
+}
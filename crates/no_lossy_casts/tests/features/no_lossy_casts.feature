@@ -0,0 +1,43 @@
+Feature: No lossy casts
+  Numeric `as` casts are reported when the target type cannot represent
+  every value of the source type exactly, and accepted when the cast widens.
+
+  Scenario: A narrowing cast is reported as truncating
+    Given a cast from u64 to u32
+    When the cast is checked
+    Then the cast is reported as range
+
+  Scenario: A signed to unsigned cast is reported as wrapping negatives
+    Given a cast from i8 to u8
+    When the cast is checked
+    Then the cast is reported as sign
+
+  Scenario: A signed cast to a narrower unsigned type loses both
+    Given a cast from i64 to usize
+    When the cast is checked
+    Then the cast is reported as both
+
+  Scenario: A widening cast is accepted
+    Given a cast from u32 to u64
+    When the cast is checked
+    Then the cast is accepted
+
+  Scenario: A same-width same-sign cast is accepted
+    Given a cast from isize to isize
+    When the cast is checked
+    Then the cast is accepted
+
+  Scenario: A float to integer cast is reported as dropping the fraction
+    Given a cast from f64 to i32
+    When the cast is checked
+    Then the cast is reported as fraction
+
+  Scenario: A narrowing float cast is reported as rounding
+    Given a cast from f64 to f32
+    When the cast is checked
+    Then the cast is reported as precision
+
+  Scenario: A widening float cast is accepted
+    Given a cast from f32 to f64
+    When the cast is checked
+    Then the cast is accepted
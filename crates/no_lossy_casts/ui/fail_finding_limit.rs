@@ -0,0 +1,17 @@
+//! Negative UI fixture: findings beyond `max_findings_per_lint_per_crate`
+//! are summarised in one diagnostic.
+#![warn(no_lossy_casts)]
+
+fn narrow(total: u64, delta: i32, len: usize, offset: i64) -> (u32, u32, u16, u8) {
+    (total as u32, delta as u32, len as u16, offset as u8)
+}
+
+#[allow(no_lossy_casts)]
+fn silenced(total: u64) -> u8 {
+    total as u8
+}
+
+fn main() {
+    let _ = narrow(1, 2, 3, 4);
+    let _ = silenced(5);
+}
@@ -0,0 +1,36 @@
+//! Negative UI fixture: narrowing, sign-changing, and rounding numeric casts
+//! are reported.
+#![warn(no_lossy_casts)]
+
+fn narrow(total: u64) -> u32 {
+    total as u32
+}
+
+fn unsign(delta: i8) -> u8 {
+    delta as u8
+}
+
+fn index(offset: i64) -> usize {
+    offset as usize
+}
+
+fn pointer_width(len: usize) -> (u32, isize) {
+    (len as u32, len as isize)
+}
+
+fn oversized_literal() -> u8 {
+    300_u16 as u8
+}
+
+fn floats(value: f64, total: i64) -> (i32, f32, f64) {
+    (value as i32, value as f32, total as f64)
+}
+
+fn main() {
+    let _ = narrow(1);
+    let _ = unsign(-1);
+    let _ = index(2);
+    let _ = pointer_width(3);
+    let _ = oversized_literal();
+    let _ = floats(1.5, 2);
+}
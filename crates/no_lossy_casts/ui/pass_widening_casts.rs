@@ -0,0 +1,49 @@
+//! Positive UI fixture: widening casts, same-width same-sign casts, fitting
+//! literals, exact float casts, and `char` casts are accepted.
+#![warn(no_lossy_casts)]
+
+type Count = u32;
+
+fn widen(small: u32, signed: i8, byte: u8) -> (u64, i64, i16) {
+    (small as u64, signed as i64, byte as i16)
+}
+
+fn same(count: Count, len: usize) -> (u32, usize) {
+    (count as u32, len as usize)
+}
+
+fn into_pointer_width(small: u32) -> usize {
+    small as usize
+}
+
+fn literals() -> (u8, i8, u16) {
+    (255 as u8, -128 as i8, 0 as u16)
+}
+
+fn floats(value: f32, count: i32) -> (f64, f64, f32) {
+    (value as f64, count as f64, 0.5 as f32)
+}
+
+fn characters(letter: char) -> u32 {
+    letter as u32
+}
+
+macro_rules! truncate {
+    ($value:expr) => {
+        $value as u8
+    };
+}
+
+fn expanded(value: u64) -> u8 {
+    truncate!(value)
+}
+
+fn main() {
+    let _ = widen(1, -1, 2);
+    let _ = same(3, 4);
+    let _ = into_pointer_width(5);
+    let _ = literals();
+    let _ = floats(1.5, 7);
+    let _ = characters('a');
+    let _ = expanded(6);
+}
@@ -0,0 +1,149 @@
+//! Collection of the crate's functions and the local functions they call.
+//!
+//! Free functions, methods, and default trait methods are collected by
+//! definition path. Each body, including the closures inside it, is walked
+//! for calls and for paths naming a function, so passing `walk` to
+//! `Iterator::map` counts as a call. Trait method calls are resolved to the
+//! implementation when the receiver type is known; calls through a generic
+//! parameter stay on the trait's own method. Macro-generated functions and
+//! test code are not collected.
+
+use std::collections::BTreeMap;
+
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, TypeckResults};
+use rustc_span::{Ident, Span};
+use whitaker::hir::TestContext;
+
+/// A collected function and the first call it makes to each local function.
+pub(crate) struct Function {
+    pub(crate) name: String,
+    pub(crate) hir_id: hir::HirId,
+    pub(crate) ident: Ident,
+    pub(crate) calls: BTreeMap<String, Span>,
+}
+
+/// Collects every eligible function in the crate with its local calls.
+pub(crate) fn collect_functions(cx: &LateContext<'_>) -> Vec<Function> {
+    let test_context = TestContext::for_crate(cx, Vec::new());
+    let items = cx.tcx.hir_crate_items(());
+
+    let free = items.free_items().filter_map(|id| {
+        let item = cx.tcx.hir_item(id);
+        match item.kind {
+            hir::ItemKind::Fn { ident, .. } => Some((item.owner_id.def_id, ident, item.span)),
+            _ => None,
+        }
+    });
+    let methods = items.impl_items().filter_map(|id| {
+        let item = cx.tcx.hir_impl_item(id);
+        matches!(item.kind, hir::ImplItemKind::Fn(..)).then_some((
+            item.owner_id.def_id,
+            item.ident,
+            item.span,
+        ))
+    });
+    let provided = items.trait_items().filter_map(|id| {
+        let item = cx.tcx.hir_trait_item(id);
+        matches!(
+            item.kind,
+            hir::TraitItemKind::Fn(_, hir::TraitFn::Provided(_))
+        )
+        .then_some((item.owner_id.def_id, item.ident, item.span))
+    });
+
+    free.chain(methods)
+        .chain(provided)
+        .filter(|(_, _, span)| !span.from_expansion())
+        .filter_map(|(def_id, ident, _)| {
+            let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
+            if test_context.is_test(cx, hir_id) {
+                return None;
+            }
+            Some(Function {
+                name: cx.tcx.def_path_str(def_id),
+                hir_id,
+                ident,
+                calls: collect_calls(cx, def_id),
+            })
+        })
+        .collect()
+}
+
+fn collect_calls(cx: &LateContext<'_>, def_id: LocalDefId) -> BTreeMap<String, Span> {
+    let Some(body) = cx.tcx.hir_maybe_body_owned_by(def_id) else {
+        return BTreeMap::new();
+    };
+    let mut collector = CallCollector {
+        cx,
+        typeck: cx.tcx.typeck_body(body.id()),
+        typing_env: ty::TypingEnv::non_body_analysis(cx.tcx, def_id),
+        calls: BTreeMap::new(),
+    };
+    collector.visit_body(body);
+    collector.calls
+}
+
+struct CallCollector<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    typing_env: ty::TypingEnv<'tcx>,
+    calls: BTreeMap<String, Span>,
+}
+
+impl<'tcx> CallCollector<'_, 'tcx> {
+    /// Returns the function an expression calls or names, if any.
+    fn callee(&self, expr: &hir::Expr<'tcx>) -> Option<DefId> {
+        let def_id = match expr.kind {
+            hir::ExprKind::Path(ref qpath) => match self.typeck.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => def_id,
+                _ => return None,
+            },
+            hir::ExprKind::MethodCall(..) => self.typeck.type_dependent_def_id(expr.hir_id)?,
+            _ => return None,
+        };
+        Some(self.resolve(def_id, expr.hir_id))
+    }
+
+    /// Resolves a trait method to its implementation when the types allow.
+    fn resolve(&self, def_id: DefId, hir_id: hir::HirId) -> DefId {
+        let tcx = self.cx.tcx;
+        if tcx.trait_of_assoc(def_id).is_none() {
+            return def_id;
+        }
+        // Desugared calls, such as the `Iterator::next` of a `for` loop, can
+        // carry no arguments, and resolving them would panic inside rustc.
+        let args = self.typeck.node_args(hir_id);
+        if args.len() != tcx.generics_of(def_id).count() {
+            return def_id;
+        }
+        match ty::Instance::try_resolve(tcx, self.typing_env, def_id, args) {
+            Ok(Some(instance)) => instance.def_id(),
+            _ => def_id,
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for CallCollector<'_, 'tcx> {
+    fn visit_nested_body(&mut self, body_id: hir::BodyId) {
+        // Closures share the typeck results of the enclosing function; other
+        // nested bodies, such as array length constants, do not.
+        let owner = self.cx.tcx.hir_body_owner_def_id(body_id);
+        if self.cx.tcx.is_closure_like(owner.to_def_id()) {
+            self.visit_body(self.cx.tcx.hir_body(body_id));
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let Some(local) = self.callee(expr).and_then(DefId::as_local) {
+            self.calls
+                .entry(self.cx.tcx.def_path_str(local))
+                .or_insert_with(|| expr.span.source_callsite());
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
@@ -0,0 +1,292 @@
+//! Pure decision logic for `no_recursion`.
+//!
+//! The driver records which local functions each function calls in a
+//! [`CallGraph`] keyed by path, then asks [`NoRecursionDecision`] whether a
+//! function lies on a call cycle no longer than `max_cycle_length`. A cycle
+//! of length one is direct recursion; longer cycles are mutual recursion.
+//! Functions listed in `allowed_functions` are neither reported nor followed,
+//! so listing one member of a cycle accepts the whole cycle.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_CYCLE_LENGTH, LINT_NAME};
+
+/// Reason key for a function that calls itself.
+const DIRECT: &str = "direct";
+/// Reason key for a function on a longer call cycle.
+const MUTUAL: &str = "mutual";
+
+/// Calls between the functions of one crate, keyed by function path.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallGraph {
+    calls: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl CallGraph {
+    /// Records that `caller` calls or refers to `callee`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut graph = CallGraph::default();
+    /// graph.add_call("parse", "parse_group");
+    /// graph.add_call("parse_group", "parse");
+    /// ```
+    pub fn add_call(&mut self, caller: impl Into<String>, callee: impl Into<String>) {
+        self.calls
+            .entry(caller.into())
+            .or_default()
+            .insert(callee.into());
+    }
+
+    fn callees(&self, caller: &str) -> impl Iterator<Item = &String> {
+        self.calls.get(caller).into_iter().flatten()
+    }
+}
+
+/// A function and the call graph it belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecursionProjection {
+    /// Calls between the crate's functions.
+    pub graph: CallGraph,
+    /// The path of the function to judge.
+    pub function: String,
+}
+
+/// Reports functions on call cycles of at most `max_cycle_length` calls.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut graph = CallGraph::default();
+/// graph.add_call("walk", "walk");
+/// let decision = NoRecursionDecision::new(1, &[]);
+/// assert_eq!(decision.cycle_through(&graph, "walk"), Some(vec!["walk".to_owned()]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoRecursionDecision {
+    max_cycle_length: usize,
+    allowed_functions: Vec<String>,
+}
+
+impl NoRecursionDecision {
+    /// Creates a decision that follows cycles of up to `max_cycle_length`
+    /// calls, treated as at least one, and exempts `allowed_functions`.
+    #[must_use]
+    pub fn new(max_cycle_length: usize, allowed_functions: &[String]) -> Self {
+        Self {
+            max_cycle_length: max_cycle_length.max(1),
+            allowed_functions: allowed_functions.to_vec(),
+        }
+    }
+
+    /// Returns the longest cycle that is reported.
+    #[must_use]
+    pub const fn max_cycle_length(&self) -> usize {
+        self.max_cycle_length
+    }
+
+    /// Returns `true` when `function` matches an `allowed_functions` entry,
+    /// either as its full path or as a trailing run of path segments.
+    #[must_use]
+    pub fn is_allowed(&self, function: &str) -> bool {
+        self.allowed_functions.iter().any(|allowed| {
+            function == allowed
+                || function
+                    .strip_suffix(allowed.as_str())
+                    .is_some_and(|prefix| prefix.ends_with("::"))
+        })
+    }
+
+    /// Returns the shortest cycle through `function`, starting with it, or
+    /// `None` when it is allowed or on no cycle within the limit.
+    #[must_use]
+    pub fn cycle_through<'a>(
+        &self,
+        graph: &'a CallGraph,
+        function: &'a str,
+    ) -> Option<Vec<String>> {
+        if self.is_allowed(function) {
+            return None;
+        }
+
+        // Breadth-first search from `function`, recording each function's
+        // caller so the first cycle found, which is the shortest, can be
+        // traced back.
+        let mut callers = BTreeMap::from([(function, function)]);
+        let mut frontier = vec![function];
+        for _ in 0..self.max_cycle_length {
+            if let Some(last) = frontier
+                .iter()
+                .copied()
+                .find(|caller| graph.callees(caller).any(|callee| callee == function))
+            {
+                return Some(trace(&callers, function, last));
+            }
+            frontier = self.expand(graph, &frontier, &mut callers);
+        }
+        None
+    }
+
+    /// Returns the functions first reached by calls from `frontier`.
+    fn expand<'a>(
+        &self,
+        graph: &'a CallGraph,
+        frontier: &[&'a str],
+        callers: &mut BTreeMap<&'a str, &'a str>,
+    ) -> Vec<&'a str> {
+        let calls = frontier.iter().flat_map(|caller| {
+            graph
+                .callees(caller)
+                .map(move |callee| (*caller, callee.as_str()))
+        });
+        let mut reached = Vec::new();
+        for (caller, callee) in calls {
+            if self.is_allowed(callee) || callers.contains_key(callee) {
+                continue;
+            }
+            callers.insert(callee, caller);
+            reached.push(callee);
+        }
+        reached
+    }
+}
+
+/// Follows recorded callers from `last` back to `start`.
+fn trace(callers: &BTreeMap<&str, &str>, start: &str, last: &str) -> Vec<String> {
+    let mut cycle = vec![last.to_owned()];
+    let mut current = last;
+    while current != start {
+        current = callers.get(current).copied().unwrap_or(start);
+        cycle.push(current.to_owned());
+    }
+    cycle.reverse();
+    cycle
+}
+
+impl Default for NoRecursionDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CYCLE_LENGTH, &[])
+    }
+}
+
+impl LintDecision for NoRecursionDecision {
+    type Projection = RecursionProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, projection: &RecursionProjection) -> Option<Finding> {
+        let cycle = self.cycle_through(&projection.graph, &projection.function)?;
+        let reason = if cycle.len() == 1 { DIRECT } else { MUTUAL };
+        Some(Finding::new(LINT_NAME, reason).with_subject(projection.function.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<RecursionProjection>> {
+        let ring = ring_of(self.max_cycle_length);
+        let too_long = ring_of(self.max_cycle_length + 1);
+        let mut fixtures = vec![
+            DecisionFixture::flagged("function calling itself", ring_of(1), DIRECT),
+            DecisionFixture::clean("cycle one call too long", too_long),
+            DecisionFixture::clean("acyclic calls", acyclic()),
+        ];
+        if self.max_cycle_length > 1 {
+            fixtures.push(DecisionFixture::flagged("cycle at the limit", ring, MUTUAL));
+        }
+        fixtures
+    }
+}
+
+/// A cycle `f0 -> f1 -> ... -> f0` of `length` calls, judged at `f0`.
+fn ring_of(length: usize) -> RecursionProjection {
+    let mut graph = CallGraph::default();
+    for index in 0..length {
+        let next = if index + 1 == length { 0 } else { index + 1 };
+        graph.add_call(format!("f{index}"), format!("f{next}"));
+    }
+    RecursionProjection {
+        graph,
+        function: "f0".to_owned(),
+    }
+}
+
+fn acyclic() -> RecursionProjection {
+    let mut graph = CallGraph::default();
+    graph.add_call("run", "parse");
+    graph.add_call("run", "render");
+    graph.add_call("parse", "render");
+    RecursionProjection {
+        graph,
+        function: "run".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    fn graph(calls: &[(&str, &str)]) -> CallGraph {
+        let mut graph = CallGraph::default();
+        for (caller, callee) in calls {
+            graph.add_call(*caller, *callee);
+        }
+        graph
+    }
+
+    #[rstest]
+    #[case::direct(&[("walk", "walk")], "walk", 1, Some(&["walk"][..]))]
+    #[case::mutual(&[("even", "odd"), ("odd", "even")], "odd", 2, Some(&["odd", "even"][..]))]
+    #[case::too_long(&[("a", "b"), ("b", "c"), ("c", "a")], "a", 2, None)]
+    #[case::shortest_wins(
+        &[("a", "b"), ("b", "c"), ("c", "a"), ("b", "a")],
+        "a",
+        3,
+        Some(&["a", "b"][..])
+    )]
+    #[case::outside_cycle(&[("main", "walk"), ("walk", "walk")], "main", 3, None)]
+    fn cycle_through_finds_the_shortest_cycle(
+        #[case] calls: &[(&str, &str)],
+        #[case] function: &str,
+        #[case] max_cycle_length: usize,
+        #[case] expected: Option<&[&str]>,
+    ) {
+        let decision = NoRecursionDecision::new(max_cycle_length, &[]);
+        let expected = expected.map(|cycle| cycle.iter().map(|name| (*name).to_owned()).collect());
+
+        assert_eq!(decision.cycle_through(&graph(calls), function), expected);
+    }
+
+    #[rstest]
+    #[case::full_path("tree::Node::walk", true)]
+    #[case::trailing_segments("Node::walk", true)]
+    #[case::name_only("walk", true)]
+    #[case::partial_segment("alk", false)]
+    #[case::other_function("tree::Node::visit", false)]
+    fn allowed_functions_match_trailing_segments(#[case] allowed: &str, #[case] expected: bool) {
+        let decision = NoRecursionDecision::new(1, &[allowed.to_owned()]);
+
+        assert_eq!(decision.is_allowed("tree::Node::walk"), expected);
+    }
+
+    #[rstest]
+    fn allowing_one_member_accepts_the_cycle() {
+        let calls = graph(&[("even", "odd"), ("odd", "even")]);
+        let decision = NoRecursionDecision::new(2, &["odd".to_owned()]);
+
+        assert_eq!(decision.cycle_through(&calls, "even"), None);
+        assert_eq!(decision.cycle_through(&calls, "odd"), None);
+    }
+
+    #[rstest]
+    #[case::default_limit(NoRecursionDecision::default(), 4)]
+    #[case::direct_only(NoRecursionDecision::new(1, &[]), 3)]
+    #[case::zero_limit(NoRecursionDecision::new(0, &[]), 3)]
+    fn decision_conforms(#[case] decision: NoRecursionDecision, #[case] fixtures: usize) {
+        assert_eq!(check_conformance(&decision), Ok(fixtures));
+    }
+}
@@ -0,0 +1,42 @@
+//! Embedded documentation for the `no_recursion` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_recursion`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_recursion",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags functions that call themselves, directly or through a short cycle of other functions.",
+    rationale: "Recursion depth follows the input, so a deep enough tree or a cyclic structure overflows the stack. Small embedded stacks make that fatal, and on servers the crash is hard to trace back to a request. A loop over an explicit stack keeps the depth on the heap, where it can be bounded and reported.",
+    bad_example: concat!(
+        "fn depth(node: &Node) -> usize {\n",
+        "    1 + node.children.iter().map(depth).max().unwrap_or(0)\n",
+        "}",
+    ),
+    good_example: concat!(
+        "fn depth(root: &Node) -> usize {\n",
+        "    let mut deepest = 0;\n",
+        "    let mut pending = vec![(root, 1)];\n",
+        "    while let Some((node, level)) = pending.pop() {\n",
+        "        deepest = deepest.max(level);\n",
+        "        pending.extend(node.children.iter().map(|child| (child, level + 1)));\n",
+        "    }\n",
+        "    deepest\n",
+        "}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "max_cycle_length",
+            default: "3",
+            description: "Longest call cycle reported, counted in calls; 1 reports only functions that call themselves.",
+        },
+        ConfigKeyDocs {
+            key: "allowed_functions",
+            default: "[]",
+            description: "Functions whose recursion is accepted, by path or trailing path segments such as `Node::walk`.",
+        },
+    ],
+};
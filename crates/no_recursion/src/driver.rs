@@ -0,0 +1,225 @@
+//! Detect functions that call themselves, directly or through a call cycle.
+//!
+//! The lint runs once per crate. During `check_crate` it records the local
+//! functions each function calls (see [`crate::calls`]) in a
+//! [`CallGraph`], then reports every function that
+//! [`NoRecursionDecision`] finds on a cycle of at most `max_cycle_length`
+//! calls. The diagnostic sits on the function's name, with a note at the
+//! call that starts the cycle. `#[allow(no_recursion)]` on a function or an
+//! `allowed_functions` entry accepts recursion whose depth is known to be
+//! bounded.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_lint::{LateContext, LateLintPass};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::calls::{Function, collect_functions};
+use crate::decision::{CallGraph, NoRecursionDecision};
+
+pub(crate) const LINT_NAME: &str = "no_recursion";
+pub(crate) const DEFAULT_MAX_CYCLE_LENGTH: usize = 3;
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_cycle_length: usize,
+    allowed_functions: Vec<String>,
+}
+
+impl Config {
+    fn decision(&self) -> NoRecursionDecision {
+        NoRecursionDecision::new(self.max_cycle_length, &self.allowed_functions)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_cycle_length: DEFAULT_MAX_CYCLE_LENGTH,
+            allowed_functions: Vec::new(),
+        }
+    }
+}
+
+/// Lint pass reporting recursive functions.
+#[derive(Default)]
+pub struct NoRecursion {
+    localizer: CrateScopedState<Localizer>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_RECURSION,
+    Warn,
+    "functions should not recurse, directly or through other functions",
+    NoRecursion::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoRecursion {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let decision = load_configuration().decision();
+        let shared_config = SharedConfig::load();
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        let findings = FindingLimit::new(NO_RECURSION, &shared_config);
+
+        let functions = collect_functions(cx);
+        let mut graph = CallGraph::default();
+        for function in &functions {
+            for callee in function.calls.keys() {
+                graph.add_call(function.name.as_str(), callee.as_str());
+            }
+        }
+
+        for function in &functions {
+            let Some(cycle) = decision.cycle_through(&graph, &function.name) else {
+                continue;
+            };
+            debug!(target: LINT_NAME, "`{}` is on the cycle {cycle:?}", function.name);
+            if findings.admit(cx, function.hir_id, function.ident.span) {
+                emit_diagnostic(cx, function, &cycle, &self.localizer);
+            }
+        }
+        findings.summarise(cx, &self.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Renders a cycle as `a -> b -> a`.
+fn render_cycle(cycle: &[String]) -> String {
+    cycle
+        .iter()
+        .chain(cycle.first())
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+const fn cycle_kind(cycle: &[String]) -> &'static str {
+    if cycle.len() == 1 { "direct" } else { "mutual" }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    function: &Function,
+    cycle: &[String],
+    localizer: &Localizer,
+) {
+    let name = function.ident.to_string();
+    let rendered = render_cycle(cycle);
+    let kind = cycle_kind(cycle);
+
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("function"), FluentValue::from(name.clone()));
+    args.insert(Cow::Borrowed("cycle"), FluentValue::from(rendered.clone()));
+    args.insert(Cow::Borrowed("kind"), FluentValue::from(kind));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(&name, &rendered, kind)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let next = cycle.get(1).unwrap_or(&function.name);
+    let call_span = function.calls.get(next).copied();
+
+    cx.tcx.emit_node_span_lint(
+        NO_RECURSION,
+        function.hir_id,
+        function.ident.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            match call_span {
+                Some(span) => lint.span_note(span, note),
+                None => lint.note(note),
+            };
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(function: &str, cycle: &str, kind: &str) -> DiagnosticMessageSet {
+    let primary = if kind == "direct" {
+        format!("`{function}` calls itself.")
+    } else {
+        format!("`{function}` is part of a recursive call cycle.")
+    };
+    DiagnosticMessageSet::new(
+        primary,
+        format!("This call starts the cycle `{cycle}`."),
+        "Rewrite the recursion as a loop over an explicit stack, or allow the function if its depth is bounded.".to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn cycle(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| (*name).to_owned()).collect()
+    }
+
+    #[rstest]
+    #[case::direct(&["walk"], "walk -> walk", "direct")]
+    #[case::mutual(&["is_even", "is_odd"], "is_even -> is_odd -> is_even", "mutual")]
+    fn cycles_render_back_to_their_start(
+        #[case] names: &[&str],
+        #[case] rendered: &str,
+        #[case] kind: &str,
+    ) {
+        assert_eq!(render_cycle(&cycle(names)), rendered);
+        assert_eq!(cycle_kind(&cycle(names)), kind);
+    }
+
+    #[rstest]
+    fn default_config_follows_short_cycles() {
+        assert_eq!(
+            Config::default().decision().max_cycle_length(),
+            DEFAULT_MAX_CYCLE_LENGTH
+        );
+    }
+
+    #[rstest]
+    #[case::direct("direct", "`walk` calls itself.")]
+    #[case::mutual("mutual", "`walk` is part of a recursive call cycle.")]
+    fn fallback_messages_describe_the_kind(#[case] kind: &str, #[case] primary: &str) {
+        let messages = fallback_messages("walk", "walk -> walk", kind);
+
+        assert_eq!(messages.primary(), primary);
+        assert!(messages.note().contains("walk -> walk"));
+        assert!(messages.help().contains("explicit stack"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
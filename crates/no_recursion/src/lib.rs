@@ -0,0 +1,43 @@
+//! Lint crate flagging functions that recurse, directly or through a cycle
+//! of calls.
+//!
+//! Unbounded recursion turns deep input into a stack overflow, which is
+//! fatal on small embedded stacks and hard to attribute on servers. The lint
+//! builds the crate's call graph and reports every function on a cycle of at
+//! most `max_cycle_length` calls; a length of one restricts it to functions
+//! that call themselves. Recursion known to be bounded can be accepted with
+//! `#[allow(no_recursion)]` or the `allowed_functions` list. It is
+//! experimental and ships behind the `experimental-no-recursion` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod calls;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{CallGraph, NoRecursionDecision, RecursionProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_recursion_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
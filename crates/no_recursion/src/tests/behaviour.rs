@@ -0,0 +1,99 @@
+//! Behaviour-driven coverage for the recursion detection configuration.
+
+use super::Config;
+use crate::decision::CallGraph;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+
+struct RecursionWorld {
+    graph: RefCell<CallGraph>,
+    config: RefCell<Config>,
+    cycle: RefCell<Option<Option<Vec<String>>>>,
+}
+
+#[fixture]
+fn world() -> RecursionWorld {
+    RecursionWorld {
+        graph: RefCell::new(CallGraph::default()),
+        config: RefCell::new(Config::default()),
+        cycle: RefCell::new(None),
+    }
+}
+
+fn reported_cycle(world: &RecursionWorld) -> Option<Vec<String>> {
+    world
+        .cycle
+        .borrow()
+        .clone()
+        .expect("recursion should be checked before asserting on it")
+}
+
+#[given("a function {name} that calls itself")]
+fn given_self_call(world: &RecursionWorld, name: String) {
+    world
+        .graph
+        .borrow_mut()
+        .add_call(name.as_str(), name.as_str());
+}
+
+#[given("a call cycle {cycle}")]
+fn given_cycle(world: &RecursionWorld, cycle: String) {
+    let names: Vec<&str> = cycle.split(" -> ").collect();
+    let mut graph = world.graph.borrow_mut();
+    for pair in names.windows(2) {
+        graph.add_call(pair[0], pair[1]);
+    }
+}
+
+#[given("max_cycle_length is {value}")]
+fn given_max_cycle_length(world: &RecursionWorld, value: usize) {
+    world.config.borrow_mut().max_cycle_length = value;
+}
+
+#[given("allowed_functions lists {name}")]
+fn given_allowed(world: &RecursionWorld, name: String) {
+    world.config.borrow_mut().allowed_functions.push(name);
+}
+
+#[when("recursion is checked for {name}")]
+fn when_checked(world: &RecursionWorld, name: String) {
+    let decision = world.config.borrow().decision();
+    let cycle = decision.cycle_through(&world.graph.borrow(), &name);
+    world.cycle.replace(Some(cycle));
+}
+
+#[then("a direct cycle is reported")]
+fn then_direct(world: &RecursionWorld) {
+    assert_eq!(reported_cycle(world).map(|cycle| cycle.len()), Some(1));
+}
+
+#[then("a mutual cycle is reported")]
+fn then_mutual(world: &RecursionWorld) {
+    assert!(reported_cycle(world).is_some_and(|cycle| cycle.len() > 1));
+}
+
+#[then("no recursion is reported")]
+fn then_none(world: &RecursionWorld) {
+    assert_eq!(reported_cycle(world), None);
+}
+
+#[scenario(path = "tests/features/no_recursion.feature", index = 0)]
+fn scenario_direct_recursion(world: RecursionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_recursion.feature", index = 1)]
+fn scenario_mutual_recursion(world: RecursionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_recursion.feature", index = 2)]
+fn scenario_direct_only(world: RecursionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_recursion.feature", index = 3)]
+fn scenario_allowed_cycle(world: RecursionWorld) {
+    let _ = world;
+}
@@ -0,0 +1,25 @@
+Feature: Recursion detection configuration
+  The lint's configuration controls how long a call cycle may be before it
+  is ignored and which functions may recurse.
+
+  Scenario: Default settings report a function that calls itself
+    Given a function walk that calls itself
+    When recursion is checked for walk
+    Then a direct cycle is reported
+
+  Scenario: Default settings report a three-function cycle
+    Given a call cycle parse -> parse_group -> parse_item -> parse
+    When recursion is checked for parse
+    Then a mutual cycle is reported
+
+  Scenario: A cycle length of one ignores mutual recursion
+    Given a call cycle is_even -> is_odd -> is_even
+    And max_cycle_length is 1
+    When recursion is checked for is_even
+    Then no recursion is reported
+
+  Scenario: Allowing one member of a cycle accepts the whole cycle
+    Given a call cycle is_even -> is_odd -> is_even
+    And allowed_functions lists is_odd
+    When recursion is checked for is_even
+    Then no recursion is reported
@@ -0,0 +1,18 @@
+//! With `max_cycle_length = 1` only functions that call themselves are
+//! reported; the mutual recursion below is accepted.
+
+fn walk(depth: u32) -> u32 {
+    if depth == 0 { 0 } else { walk(depth - 1) + 1 }
+}
+
+fn ping(n: u32) -> u32 {
+    if n == 0 { 0 } else { pong(n - 1) }
+}
+
+fn pong(n: u32) -> u32 {
+    ping(n)
+}
+
+fn main() {
+    let _ = walk(3) + ping(3);
+}
@@ -0,0 +1,21 @@
+//! Functions and methods that call themselves are reported, including when
+//! the recursive call passes the function by name.
+
+struct Node {
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn count(&self) -> usize {
+        1 + self.children.iter().map(Node::count).sum::<usize>()
+    }
+}
+
+fn factorial(n: u64) -> u64 {
+    if n == 0 { 1 } else { n * factorial(n - 1) }
+}
+
+fn main() {
+    let tree = Node { children: Vec::new() };
+    let _ = tree.count() + factorial(5) as usize;
+}
@@ -0,0 +1,40 @@
+//! Every function on a call cycle of up to three calls is reported,
+//! including cycles through trait implementations.
+
+fn is_even(n: u32) -> bool {
+    n == 0 || is_odd(n - 1)
+}
+
+fn is_odd(n: u32) -> bool {
+    n != 0 && is_even(n - 1)
+}
+
+trait Render {
+    fn render(&self) -> String;
+}
+
+struct List(Vec<Item>);
+
+enum Item {
+    Leaf(String),
+    Nested(List),
+}
+
+impl Render for List {
+    fn render(&self) -> String {
+        self.0.iter().map(render_item).collect()
+    }
+}
+
+fn render_item(item: &Item) -> String {
+    match item {
+        Item::Leaf(text) => text.clone(),
+        Item::Nested(list) => list.render(),
+    }
+}
+
+fn main() {
+    let _ = is_even(4);
+    let nested = Item::Nested(List(Vec::new()));
+    let _ = List(vec![Item::Leaf(String::new()), nested]).render();
+}
@@ -0,0 +1,37 @@
+//! Iterative code, cycles longer than the default limit, and recursion that
+//! is explicitly allowed are not reported.
+
+fn depth(levels: &[Vec<u32>]) -> usize {
+    let mut deepest = 0;
+    for (index, level) in levels.iter().enumerate() {
+        if !level.is_empty() {
+            deepest = index + 1;
+        }
+    }
+    deepest
+}
+
+fn first(n: u32) -> u32 {
+    if n == 0 { 0 } else { second(n - 1) }
+}
+
+fn second(n: u32) -> u32 {
+    third(n)
+}
+
+fn third(n: u32) -> u32 {
+    fourth(n)
+}
+
+fn fourth(n: u32) -> u32 {
+    first(n)
+}
+
+#[allow(no_recursion)]
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn main() {
+    let _ = depth(&[vec![1]]) + first(2) as usize + gcd(12, 8) as usize;
+}
@@ -0,0 +1,135 @@
+//! Pure decision logic for `no_shadowing`.
+//!
+//! The driver finds the earlier binding each new binding shadows and notes
+//! whether the new initialiser reads it and whether both bindings share a
+//! scope. [`NoShadowingDecision`] classifies the shadow the same way and
+//! applies the same configuration, using the placement as the finding's
+//! reason and naming the binding as its subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{Config, LINT_NAME, Placement, ScopeMode, ShadowKind};
+
+/// Reason key for shadows within the binding's own scope.
+const SAME_SCOPE: &str = "same_scope";
+
+/// Reason key for shadows of a binding from an enclosing scope.
+const NESTED_SCOPE: &str = "nested_scope";
+
+/// The facts about one shadowing binding that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ShadowProjection {
+    /// The binding's name.
+    pub name: String,
+    /// Whether the new binding's initialiser reads the shadowed value.
+    pub reads_shadowed: bool,
+    /// Whether both bindings belong to the same block, match arm, or
+    /// closure.
+    pub same_scope: bool,
+}
+
+/// Reports bindings that shadow an earlier binding of the same name.
+///
+/// # Examples
+///
+/// ```ignore
+/// let shadow = ShadowProjection {
+///     name: "total".to_owned(),
+///     same_scope: true,
+///     ..ShadowProjection::default()
+/// };
+/// assert!(NoShadowingDecision::default().decide(&shadow).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoShadowingDecision {
+    config: Config,
+}
+
+impl NoShadowingDecision {
+    /// Creates a decision that exempts reuse when `allow_reuse` is set and
+    /// reports the placements `scope` selects: `same`, `nested`, or any
+    /// other value for both.
+    #[must_use]
+    pub fn new(allow_reuse: bool, scope: &str) -> Self {
+        let scope = match scope {
+            "same" => ScopeMode::Same,
+            "nested" => ScopeMode::Nested,
+            _ => ScopeMode::Any,
+        };
+        Self {
+            config: Config { allow_reuse, scope },
+        }
+    }
+}
+
+impl LintDecision for NoShadowingDecision {
+    type Projection = ShadowProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, shadow: &ShadowProjection) -> Option<Finding> {
+        let kind = if shadow.reads_shadowed {
+            ShadowKind::Reuse
+        } else {
+            ShadowKind::Unrelated
+        };
+        let (placement, reason) = if shadow.same_scope {
+            (Placement::SameScope, SAME_SCOPE)
+        } else {
+            (Placement::NestedScope, NESTED_SCOPE)
+        };
+        self.config
+            .flags(kind, placement)
+            .then(|| Finding::new(LINT_NAME, reason).with_subject(shadow.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ShadowProjection>> {
+        let shadow = |reads_shadowed, same_scope| ShadowProjection {
+            name: "total".to_owned(),
+            reads_shadowed,
+            same_scope,
+        };
+        let Config { allow_reuse, scope } = self.config;
+        let placement = |same_scope, name, reason| {
+            let covered = match scope {
+                ScopeMode::Any => true,
+                ScopeMode::Same => same_scope,
+                ScopeMode::Nested => !same_scope,
+            };
+            if covered {
+                DecisionFixture::flagged(name, shadow(false, same_scope), reason)
+            } else {
+                DecisionFixture::clean(name, shadow(false, same_scope))
+            }
+        };
+        let reuse = shadow(true, scope != ScopeMode::Nested);
+        vec![
+            placement(true, "unrelated, same scope", SAME_SCOPE),
+            placement(false, "unrelated, nested scope", NESTED_SCOPE),
+            if allow_reuse {
+                DecisionFixture::clean("reuse", reuse)
+            } else if scope == ScopeMode::Nested {
+                DecisionFixture::flagged("reuse", reuse, NESTED_SCOPE)
+            } else {
+                DecisionFixture::flagged("reuse", reuse, SAME_SCOPE)
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::defaults(NoShadowingDecision::default())]
+    #[case::same_scope_only(NoShadowingDecision::new(true, "same"))]
+    #[case::nested_without_reuse(NoShadowingDecision::new(false, "nested"))]
+    fn decision_conforms(#[case] decision: NoShadowingDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
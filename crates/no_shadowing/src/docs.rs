@@ -0,0 +1,34 @@
+//! Embedded documentation for the `no_shadowing` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_shadowing`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_shadowing",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags bindings that shadow an unrelated earlier binding of the same name.",
+    rationale: "Reusing a name for a different value forces readers to track which binding is live; rebinding a transformed version of the same value, such as `let x = x.clone();`, is allowed by default.",
+    bad_example: concat!(
+        "let path = config.path();\n",
+        "let path = Url::parse(&endpoint)?;",
+    ),
+    good_example: concat!(
+        "let path = config.path();\n",
+        "let url = Url::parse(&endpoint)?;",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "allow_reuse",
+            default: "true",
+            description: "Allow bindings whose initialiser reads the value they shadow.",
+        },
+        ConfigKeyDocs {
+            key: "scope",
+            default: "\"any\"",
+            description: "Which shadows to report: `\"any\"`, `\"same\"` for same-scope only, or `\"nested\"` for enclosing-scope only.",
+        },
+    ],
+};
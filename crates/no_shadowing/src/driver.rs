@@ -0,0 +1,279 @@
+//! Detect bindings that shadow an earlier binding of the same name.
+//!
+//! Bindings are recorded per body as patterns are visited. When a new binding
+//! reuses a name, the region scope tree decides whether the earlier binding
+//! is still in scope, and therefore shadowed. Each shadow is then classified
+//! twice:
+//!
+//! - as *reuse* when the new binding's initialiser reads the shadowed value,
+//!   as in `let x = x.clone();`, and as *unrelated* otherwise; and
+//! - as *same-scope* when both bindings belong to the same block, match arm,
+//!   or closure, and as *nested-scope* otherwise.
+//!
+//! Reuse is allowed by default. `allow_reuse` and `scope` select which
+//! combinations are reported.
+//!
+//! The scope queries live in `scope`, and diagnostic emission in
+//! `diagnostics`.
+
+use std::collections::HashMap;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{BodyOwnerKind, ItemLocalId, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_span::Symbol;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{Localizer, get_localizer_for_lint};
+
+mod diagnostics;
+mod scope;
+
+use diagnostics::{Shadow, emit_diagnostic};
+use scope::{binding_init, binding_scope, find_shadowed, references_local};
+
+pub(crate) const LINT_NAME: &str = "no_shadowing";
+
+/// Which shadowing placements the lint reports.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ScopeMode {
+    /// Report shadowing wherever it occurs.
+    #[default]
+    Any,
+    /// Report only bindings shadowed within the same scope.
+    Same,
+    /// Report only bindings that shadow one from an enclosing scope.
+    Nested,
+}
+
+impl ScopeMode {
+    const fn covers(self, placement: Placement) -> bool {
+        matches!(
+            (self, placement),
+            (Self::Any, _)
+                | (Self::Same, Placement::SameScope)
+                | (Self::Nested, Placement::NestedScope)
+        )
+    }
+}
+
+/// Whether the new binding is derived from the value it shadows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ShadowKind {
+    Reuse,
+    Unrelated,
+}
+
+/// Where the new binding sits relative to the one it shadows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Placement {
+    SameScope,
+    NestedScope,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct Config {
+    #[serde(default = "Config::default_allow_reuse")]
+    pub(crate) allow_reuse: bool,
+    pub(crate) scope: ScopeMode,
+}
+
+impl Config {
+    const fn default_allow_reuse() -> bool {
+        true
+    }
+
+    /// Returns `true` when a shadow of `kind` at `placement` is reported.
+    pub(crate) const fn flags(self, kind: ShadowKind, placement: Placement) -> bool {
+        let exempt_reuse = self.allow_reuse && matches!(kind, ShadowKind::Reuse);
+        !exempt_reuse && self.scope.covers(placement)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            allow_reuse: Self::default_allow_reuse(),
+            scope: ScopeMode::default(),
+        }
+    }
+}
+
+/// Bindings seen so far in one body, keyed by name.
+struct BodyBindings {
+    owner: LocalDefId,
+    names: HashMap<Symbol, Vec<ItemLocalId>>,
+}
+
+/// Lint pass reporting shadowed bindings.
+#[derive(Default)]
+pub struct NoShadowing {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configuration, open bodies, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    bodies: Vec<BodyBindings>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_SHADOWING,
+    Warn,
+    "bindings should not shadow an unrelated earlier binding of the same name",
+    NoShadowing::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoShadowing {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            bodies: Vec::new(),
+            findings: FindingLimit::new(NO_SHADOWING, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    // Closures share their parent's scope tree, so only other bodies start a
+    // fresh set of bindings.
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if !matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            self.state.bodies.push(BodyBindings {
+                owner,
+                names: HashMap::new(),
+            });
+        }
+    }
+
+    fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if !matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            self.state.bodies.pop();
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx hir::Pat<'tcx>) {
+        let PatKind::Binding(_, hir_id, ident, _) = pat.kind else {
+            return;
+        };
+        if pat.span.from_expansion() || pat.span.desugaring_kind().is_some() {
+            return;
+        }
+        let Some(init) = binding_init(cx, hir_id) else {
+            return;
+        };
+        let Some(body) = self.state.bodies.last_mut() else {
+            return;
+        };
+
+        let previous = body.names.entry(ident.name).or_default();
+        let shadowed = find_shadowed(cx, body.owner, previous, hir_id.local_id);
+        previous.push(hir_id.local_id);
+        let Some(shadowed) = shadowed else {
+            return;
+        };
+
+        let shadowed_id = hir::HirId {
+            owner: hir_id.owner,
+            local_id: shadowed,
+        };
+        let kind = match init {
+            Some(expr) if references_local(cx, expr, shadowed_id) => ShadowKind::Reuse,
+            _ => ShadowKind::Unrelated,
+        };
+        let placement = if binding_scope(cx, hir_id) == binding_scope(cx, shadowed_id) {
+            Placement::SameScope
+        } else {
+            Placement::NestedScope
+        };
+        if !self.state.config.flags(kind, placement) {
+            debug!(target: LINT_NAME, "allowing {kind:?} shadow of `{ident}` ({placement:?})");
+            return;
+        }
+
+        let shadow = Shadow {
+            span: ident.span,
+            shadowed_span: cx.tcx.hir_span(shadowed_id),
+            name: ident.name.as_str(),
+            placement,
+        };
+        if self.state.findings.admit(cx, hir_id, shadow.span) {
+            emit_diagnostic(cx, &shadow, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::reuse_same(ShadowKind::Reuse, Placement::SameScope, false)]
+    #[case::reuse_nested(ShadowKind::Reuse, Placement::NestedScope, false)]
+    #[case::unrelated_same(ShadowKind::Unrelated, Placement::SameScope, true)]
+    #[case::unrelated_nested(ShadowKind::Unrelated, Placement::NestedScope, true)]
+    fn default_config_flags_only_unrelated_shadows(
+        #[case] kind: ShadowKind,
+        #[case] placement: Placement,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(Config::default().flags(kind, placement), expected);
+    }
+
+    #[rstest]
+    #[case::any(ScopeMode::Any, true, true)]
+    #[case::same(ScopeMode::Same, true, false)]
+    #[case::nested(ScopeMode::Nested, false, true)]
+    fn scope_mode_selects_placements(
+        #[case] scope: ScopeMode,
+        #[case] same: bool,
+        #[case] nested: bool,
+    ) {
+        assert_eq!(scope.covers(Placement::SameScope), same);
+        assert_eq!(scope.covers(Placement::NestedScope), nested);
+    }
+
+    #[rstest]
+    #[case(Placement::SameScope, "no_shadowing")]
+    #[case(Placement::NestedScope, "no_shadowing-nested")]
+    fn placements_select_distinct_message_keys(
+        #[case] placement: Placement,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(placement.message_key().as_ref(), expected);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
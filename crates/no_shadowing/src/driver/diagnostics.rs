@@ -0,0 +1,85 @@
+//! Localised diagnostics for shadowed bindings.
+//!
+//! Same-scope and nested-scope shadows resolve distinct Fluent messages, and
+//! each diagnostic carries a note on the binding it shadows.
+
+use std::borrow::Cow;
+
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::Span;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    noop_reporter, safe_resolve_message_set,
+};
+
+use super::{LINT_NAME, NO_SHADOWING, Placement};
+
+const SAME_SCOPE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const NESTED_SCOPE_KEY: MessageKey<'static> = MessageKey::new("no_shadowing-nested");
+
+impl Placement {
+    pub(super) const fn message_key(self) -> MessageKey<'static> {
+        match self {
+            Self::SameScope => SAME_SCOPE_KEY,
+            Self::NestedScope => NESTED_SCOPE_KEY,
+        }
+    }
+}
+
+/// A reported shadowing binding.
+pub(super) struct Shadow<'a> {
+    pub(super) span: Span,
+    pub(super) shadowed_span: Span,
+    pub(super) name: &'a str,
+    pub(super) placement: Placement,
+}
+
+pub(super) fn emit_diagnostic(cx: &LateContext<'_>, shadow: &Shadow<'_>, localizer: &Localizer) {
+    let Shadow {
+        span,
+        shadowed_span,
+        name,
+        placement,
+    } = *shadow;
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("name"), FluentValue::from(name.to_string()));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: placement.message_key(),
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(placement, name)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        NO_SHADOWING,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(shadowed_span, note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(placement: Placement, name: &str) -> DiagnosticMessageSet {
+    let note = format!("The shadowed binding `{name}` is declared here.");
+    match placement {
+        Placement::SameScope => DiagnosticMessageSet::new(
+            format!("`{name}` shadows an earlier binding in the same scope."),
+            note,
+            String::from("Give the new value its own name so each name refers to one value."),
+        ),
+        Placement::NestedScope => DiagnosticMessageSet::new(
+            format!("`{name}` shadows a binding from an enclosing scope."),
+            note,
+            format!("Rename the inner binding so the outer `{name}` stays visible to readers."),
+        ),
+    }
+}
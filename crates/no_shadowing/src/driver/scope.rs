@@ -0,0 +1,111 @@
+//! Scope queries that decide whether a binding shadows another.
+//!
+//! The region scope tree answers whether an earlier binding is still visible,
+//! the HIR parents give each binding its initialiser and owning scope, and a
+//! visitor checks whether that initialiser reads the shadowed value.
+
+use std::ops::ControlFlow;
+
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_hir::{ExprKind, ItemLocalId, LocalSource, Node, QPath};
+use rustc_lint::LateContext;
+
+/// Returns the most recent earlier binding still in scope at `binding`.
+pub(super) fn find_shadowed(
+    cx: &LateContext<'_>,
+    owner: LocalDefId,
+    previous: &[ItemLocalId],
+    binding: ItemLocalId,
+) -> Option<ItemLocalId> {
+    let scope_tree = cx.tcx.region_scope_tree(owner);
+    let binding_scope = scope_tree.var_scope(binding)?;
+    previous
+        .iter()
+        .rev()
+        // A repeated binding in an or-pattern is the same variable.
+        .take_while(|earlier| **earlier != binding)
+        .copied()
+        .find(|earlier| {
+            scope_tree
+                .var_scope(*earlier)
+                .is_some_and(|scope| scope_tree.is_subscope_of(binding_scope, scope))
+        })
+}
+
+/// Finds the expression a binding is initialised from.
+///
+/// Returns `None` for compiler-generated bindings, such as the argument
+/// copies in `async fn` bodies, which are never reported. Returns
+/// `Some(None)` for bindings without an initialiser, such as parameters.
+pub(super) fn binding_init<'tcx>(
+    cx: &LateContext<'tcx>,
+    hir_id: hir::HirId,
+) -> Option<Option<&'tcx hir::Expr<'tcx>>> {
+    for (_, node) in cx.tcx.hir_parent_iter(hir_id) {
+        match node {
+            Node::Pat(_) | Node::PatField(_) => {}
+            Node::LetStmt(local) if matches!(local.source, LocalSource::Normal) => {
+                return Some(local.init);
+            }
+            Node::LetStmt(_) => return None,
+            Node::Expr(expr) => {
+                return Some(match expr.kind {
+                    ExprKind::Let(let_expr) => Some(let_expr.init),
+                    ExprKind::Match(scrutinee, ..) => Some(scrutinee),
+                    _ => None,
+                });
+            }
+            Node::Arm(_) => {}
+            _ => return Some(None),
+        }
+    }
+    Some(None)
+}
+
+/// Identifies the scope that owns a binding: the nearest block, match arm,
+/// closure, or `let` expression.
+pub(super) fn binding_scope(cx: &LateContext<'_>, hir_id: hir::HirId) -> Option<hir::HirId> {
+    cx.tcx
+        .hir_parent_iter(hir_id)
+        .find(|(_, node)| match node {
+            Node::Block(_) | Node::Arm(_) => true,
+            Node::Expr(expr) => matches!(expr.kind, ExprKind::Closure(..) | ExprKind::Let(..)),
+            _ => false,
+        })
+        .map(|(scope, _)| scope)
+}
+
+pub(super) fn references_local<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    target: hir::HirId,
+) -> bool {
+    let mut visitor = LocalReferenceVisitor { cx, target };
+    visitor.visit_expr(expr).is_break()
+}
+
+/// Searches an expression, including closure bodies, for a path to `target`.
+struct LocalReferenceVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    target: hir::HirId,
+}
+
+impl<'tcx> Visitor<'tcx> for LocalReferenceVisitor<'_, 'tcx> {
+    type Result = ControlFlow<()>;
+
+    fn visit_nested_body(&mut self, body_id: hir::BodyId) -> Self::Result {
+        self.visit_body(self.cx.tcx.hir_body(body_id))
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Self::Result {
+        if let ExprKind::Path(QPath::Resolved(None, path)) = expr.kind
+            && path.res == Res::Local(self.target)
+        {
+            return ControlFlow::Break(());
+        }
+        intravisit::walk_expr(self, expr)
+    }
+}
@@ -0,0 +1,36 @@
+//! Lint crate flagging `let` bindings that shadow an unrelated earlier binding.
+//!
+//! Rebinding a name to a transformed version of itself, such as
+//! `let x = x.clone();` before moving into an `async` block, is idiomatic and
+//! allowed by default. Reusing a name for an unrelated value makes code harder
+//! to follow, so the lint reports it. Configuration selects whether reuse is
+//! also flagged and whether same-scope or nested-scope shadowing is checked.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{NoShadowingDecision, ShadowProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_shadowing_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,107 @@
+//! Behaviour-driven coverage for the shadowing policy.
+
+use super::{Config, Placement, ScopeMode, ShadowKind};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct ShadowWorld {
+    kind: Cell<ShadowKind>,
+    placement: Cell<Placement>,
+    config: RefCell<Config>,
+    flagged: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> ShadowWorld {
+    ShadowWorld {
+        kind: Cell::new(ShadowKind::Unrelated),
+        placement: Cell::new(Placement::SameScope),
+        config: RefCell::new(Config::default()),
+        flagged: Cell::new(None),
+    }
+}
+
+fn parse_kind(kind: &str) -> ShadowKind {
+    match kind {
+        "reuse" => ShadowKind::Reuse,
+        "unrelated" => ShadowKind::Unrelated,
+        other => panic!("unknown shadow kind `{other}`"),
+    }
+}
+
+fn parse_placement(placement: &str) -> Placement {
+    match placement {
+        "the same" => Placement::SameScope,
+        "a nested" => Placement::NestedScope,
+        other => panic!("unknown placement `{other}`"),
+    }
+}
+
+#[given("a {kind} shadow in {placement} scope")]
+fn given_reuse_shadow(world: &ShadowWorld, kind: String, placement: String) {
+    world.kind.set(parse_kind(&kind));
+    world.placement.set(parse_placement(&placement));
+}
+
+#[given("an {kind} shadow in {placement} scope")]
+fn given_unrelated_shadow(world: &ShadowWorld, kind: String, placement: String) {
+    given_reuse_shadow(world, kind, placement);
+}
+
+#[given("reuse is disallowed")]
+fn given_reuse_disallowed(world: &ShadowWorld) {
+    world.config.borrow_mut().allow_reuse = false;
+}
+
+#[given("the scope mode is {mode}")]
+fn given_scope_mode(world: &ShadowWorld, mode: String) {
+    world.config.borrow_mut().scope = match mode.as_str() {
+        "same" => ScopeMode::Same,
+        "nested" => ScopeMode::Nested,
+        _ => ScopeMode::Any,
+    };
+}
+
+#[when("the shadow is checked")]
+fn when_checked(world: &ShadowWorld) {
+    let config = *world.config.borrow();
+    world
+        .flagged
+        .set(Some(config.flags(world.kind.get(), world.placement.get())));
+}
+
+#[then("the shadow is allowed")]
+fn then_allowed(world: &ShadowWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[then("the shadow is reported")]
+fn then_reported(world: &ShadowWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[scenario(path = "tests/features/no_shadowing.feature", index = 0)]
+fn scenario_reuse_allowed(world: ShadowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_shadowing.feature", index = 1)]
+fn scenario_unrelated_reported(world: ShadowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_shadowing.feature", index = 2)]
+fn scenario_reuse_disallowed(world: ShadowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_shadowing.feature", index = 3)]
+fn scenario_same_scope_mode(world: ShadowWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_shadowing.feature", index = 4)]
+fn scenario_nested_scope_mode(world: ShadowWorld) {
+    let _ = world;
+}
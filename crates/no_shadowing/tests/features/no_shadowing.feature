@@ -0,0 +1,31 @@
+Feature: Variable shadowing policy
+  Bindings that shadow an earlier binding of the same name are reported
+  according to whether they reuse the shadowed value and where they sit.
+
+  Scenario: Reuse shadowing is allowed by default
+    Given a reuse shadow in the same scope
+    When the shadow is checked
+    Then the shadow is allowed
+
+  Scenario: Unrelated shadowing is reported by default
+    Given an unrelated shadow in a nested scope
+    When the shadow is checked
+    Then the shadow is reported
+
+  Scenario: Reuse shadowing is reported when reuse is disallowed
+    Given a reuse shadow in the same scope
+    And reuse is disallowed
+    When the shadow is checked
+    Then the shadow is reported
+
+  Scenario: Same-scope mode ignores nested shadowing
+    Given an unrelated shadow in a nested scope
+    And the scope mode is same
+    When the shadow is checked
+    Then the shadow is allowed
+
+  Scenario: Nested-scope mode ignores same-scope shadowing
+    Given an unrelated shadow in the same scope
+    And the scope mode is nested
+    When the shadow is checked
+    Then the shadow is allowed
@@ -0,0 +1,5 @@
+fn main() {
+    let count = 1;
+    let count = count + 1;
+    println!("{count}");
+}
@@ -0,0 +1,13 @@
+#![warn(no_shadowing)]
+
+fn main() {
+    let value = 10;
+    println!("{value}");
+    let value = "ten";
+    println!("{value}");
+    let total = 3;
+    if total > 1 {
+        let total = "many";
+        println!("{total}");
+    }
+}
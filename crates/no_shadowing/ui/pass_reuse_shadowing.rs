@@ -0,0 +1,23 @@
+#![warn(no_shadowing)]
+
+async fn send(message: String) -> usize {
+    message.len()
+}
+
+fn main() {
+    let name = String::from(" whitaker ");
+    let name = name.trim();
+    let count = 1;
+    let count = count + 1;
+    let message = String::from("hello");
+    let task = {
+        let message = message.clone();
+        async move { send(message).await }
+    };
+    drop(task);
+    let maybe = Some(count);
+    if let Some(maybe) = maybe {
+        println!("{maybe}");
+    }
+    println!("{name} {count} {message}");
+}
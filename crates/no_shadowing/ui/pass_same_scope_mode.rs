@@ -0,0 +1,10 @@
+#![warn(no_shadowing)]
+
+fn main() {
+    let label = 7;
+    for index in 0..2 {
+        let label = format!("item {index}");
+        println!("{label}");
+    }
+    println!("{label}");
+}
@@ -0,0 +1,173 @@
+//! Pure decision logic for `no_single_letter_identifiers`.
+//!
+//! The driver classifies each single-letter binding by where it is
+//! introduced and whether the enclosing function does generic arithmetic.
+//! [`NoSingleLetterIdentifiersDecision`] asks the same [`NamePolicy`]
+//! whether the name is acceptable there. Findings use `parameter` or
+//! `binding` as their reason and the name as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_ALLOWED, DEFAULT_MAX_CLOSURE_LINES, LINT_NAME};
+use crate::policy::{Binding, NamePolicy, Position};
+
+/// Reason key for single-letter parameters.
+const PARAMETER: &str = "parameter";
+/// Reason key for other single-letter bindings.
+const BINDING: &str = "binding";
+
+/// The facts about one binding that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BindingProjection {
+    /// The bound name.
+    pub name: String,
+    /// Where the binding is introduced: `for_loop`, `closure_param`,
+    /// `fn_param`, or `local`.
+    pub position: String,
+    /// For closure parameters, the lines the closure spans.
+    pub closure_lines: usize,
+    /// Whether the enclosing function bounds a type parameter by an
+    /// arithmetic operator trait.
+    pub in_generic_math: bool,
+}
+
+impl BindingProjection {
+    fn position(&self) -> Option<Position> {
+        match self.position.as_str() {
+            "for_loop" => Some(Position::ForLoop),
+            "closure_param" => Some(Position::ClosureParam {
+                lines: self.closure_lines,
+            }),
+            "fn_param" => Some(Position::FnParam),
+            "local" => Some(Position::Local),
+            _ => None,
+        }
+    }
+}
+
+/// Reports single-letter bindings outside `for` loops, short closures, and
+/// generic arithmetic, unless the name is allowed.
+///
+/// # Examples
+///
+/// ```ignore
+/// let binding = BindingProjection {
+///     name: "d".to_owned(),
+///     position: "fn_param".to_owned(),
+///     ..BindingProjection::default()
+/// };
+/// let decision = NoSingleLetterIdentifiersDecision::default();
+/// assert!(decision.decide(&binding).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoSingleLetterIdentifiersDecision {
+    policy: NamePolicy,
+    max_closure_lines: usize,
+}
+
+impl NoSingleLetterIdentifiersDecision {
+    /// Creates a decision accepting `allowed` everywhere and any single
+    /// letter as the parameter of a closure no longer than
+    /// `max_closure_lines`.
+    #[must_use]
+    pub const fn new(allowed: Vec<String>, max_closure_lines: usize) -> Self {
+        Self {
+            policy: NamePolicy::new(allowed, max_closure_lines),
+            max_closure_lines,
+        }
+    }
+}
+
+impl Default for NoSingleLetterIdentifiersDecision {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_ALLOWED.map(str::to_owned).to_vec(),
+            DEFAULT_MAX_CLOSURE_LINES,
+        )
+    }
+}
+
+impl LintDecision for NoSingleLetterIdentifiersDecision {
+    type Projection = BindingProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, binding: &BindingProjection) -> Option<Finding> {
+        let position = binding.position()?;
+        let facts = Binding {
+            name: &binding.name,
+            position,
+            in_generic_math: binding.in_generic_math,
+        };
+        let reason = if position.is_parameter() {
+            PARAMETER
+        } else {
+            BINDING
+        };
+        self.policy
+            .flags(facts)
+            .then(|| Finding::new(LINT_NAME, reason).with_subject(binding.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<BindingProjection>> {
+        let binding =
+            |name: &str, position: &str, closure_lines, in_generic_math| BindingProjection {
+                name: name.to_owned(),
+                position: position.to_owned(),
+                closure_lines,
+                in_generic_math,
+            };
+        let long_closure = self.max_closure_lines + 1;
+        vec![
+            DecisionFixture::flagged(
+                "function parameter",
+                binding("d", "fn_param", 0, false),
+                PARAMETER,
+            ),
+            DecisionFixture::flagged("local", binding("q", "local", 0, false), BINDING),
+            DecisionFixture::flagged(
+                "parameter of a long closure",
+                binding("e", "closure_param", long_closure, false),
+                PARAMETER,
+            ),
+            DecisionFixture::clean(
+                "parameter of a short closure",
+                binding("e", "closure_param", self.max_closure_lines, false),
+            ),
+            DecisionFixture::clean("loop variable", binding("k", "for_loop", 0, false)),
+            DecisionFixture::clean("generic arithmetic", binding("a", "fn_param", 0, true)),
+            DecisionFixture::clean("descriptive name", binding("depth", "local", 0, false)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn allowed_names_are_accepted() {
+        let binding = BindingProjection {
+            name: "i".to_owned(),
+            position: "local".to_owned(),
+            ..BindingProjection::default()
+        };
+
+        assert_eq!(
+            NoSingleLetterIdentifiersDecision::default().decide(&binding),
+            None
+        );
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&NoSingleLetterIdentifiersDecision::default()),
+            Ok(7)
+        );
+    }
+}
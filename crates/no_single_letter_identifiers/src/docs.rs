@@ -0,0 +1,29 @@
+//! Embedded documentation for the `no_single_letter_identifiers` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_single_letter_identifiers`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_single_letter_identifiers",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags single-letter bindings and parameters outside `for` loops, short closures, and generic arithmetic.",
+    rationale: "A one-letter name says nothing about the value it holds, so readers must trace it back to where it was bound. Loop counters, short closure parameters, and the variables of generic maths are exempt because there the convention is familiar and the binding is never far from its uses.",
+    bad_example: "fn publish(d: &Document, s: &mut Sink) {\n    let h = d.header();\n    s.write(h);\n}",
+    good_example: "fn publish(document: &Document, sink: &mut Sink) {\n    let header = document.header();\n    sink.write(header);\n}",
+    config: &[
+        ConfigKeyDocs {
+            key: "allowed",
+            default: "[\"i\", \"j\", \"n\", \"x\"]",
+            description: "Single-letter names that are never reported.",
+        },
+        ConfigKeyDocs {
+            key: "max_closure_lines",
+            default: "3",
+            description: "Longest closure, in lines, whose single-letter parameters are allowed.",
+        },
+    ],
+};
@@ -0,0 +1,373 @@
+//! Lint pass reporting single-letter bindings and parameters.
+//!
+//! Every binding pattern written by the user is classified by the node that
+//! owns its outermost pattern: a closure or function parameter, the `Some`
+//! arm of a desugared `for` loop, or anything else. Closure parameters carry
+//! the number of lines the closure spans, and each binding records whether
+//! the function around it bounds a type parameter by an arithmetic operator
+//! trait. [`NamePolicy`] then decides whether the name is reported.
+//! Shorthand struct field patterns such as `Point { x, y }` take their names
+//! from the fields and are skipped, as are bindings produced by macro
+//! expansion and the copies of `async fn` parameters the compiler inserts.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::{LangItem, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty;
+use rustc_span::Span;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::source_map::SourceMap;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, describe_enclosing_item};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{Binding, NamePolicy, Position, is_single_letter};
+
+pub(crate) const LINT_NAME: &str = "no_single_letter_identifiers";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_ALLOWED: [&str; 4] = ["i", "j", "n", "x"];
+pub(crate) const DEFAULT_MAX_CLOSURE_LINES: usize = 3;
+
+/// Operator traits whose bounds mark a function as generic arithmetic.
+const ARITHMETIC_TRAITS: [LangItem; 11] = [
+    LangItem::Add,
+    LangItem::Sub,
+    LangItem::Mul,
+    LangItem::Div,
+    LangItem::Rem,
+    LangItem::Neg,
+    LangItem::AddAssign,
+    LangItem::SubAssign,
+    LangItem::MulAssign,
+    LangItem::DivAssign,
+    LangItem::RemAssign,
+];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    allowed: Vec<String>,
+    max_closure_lines: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            allowed: DEFAULT_ALLOWED.map(String::from).to_vec(),
+            max_closure_lines: DEFAULT_MAX_CLOSURE_LINES,
+        }
+    }
+}
+
+impl Config {
+    fn policy(self) -> NamePolicy {
+        NamePolicy::new(self.allowed, self.max_closure_lines)
+    }
+}
+
+/// Lint pass that finds single-letter bindings.
+#[derive(Default)]
+pub struct NoSingleLetterIdentifiers {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Name settings, finding limit, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_SINGLE_LETTER_IDENTIFIERS,
+    Warn,
+    "bindings and parameters should not have single-letter names outside loops, short closures, and generic arithmetic",
+    NoSingleLetterIdentifiers::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoSingleLetterIdentifiers {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(NO_SINGLE_LETTER_IDENTIFIERS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx hir::Pat<'tcx>) {
+        let hir::PatKind::Binding(_, _, ident, _) = pat.kind else {
+            return;
+        };
+        if ident.span.from_expansion() || !is_single_letter(ident.name.as_str()) {
+            return;
+        }
+        let Some(position) = binding_position(cx, pat) else {
+            return;
+        };
+        let owner = cx.tcx.hir_enclosing_body_owner(pat.hir_id);
+        let binding = Binding {
+            name: ident.name.as_str(),
+            position,
+            in_generic_math: in_generic_math(cx, owner),
+        };
+        let state = &*self.state;
+        let policy = state
+            .findings
+            .config_at(cx, ident.span, &state.config)
+            .policy();
+        if !policy.flags(binding) {
+            return;
+        }
+
+        let context = describe_enclosing_item(cx, pat.hir_id).describe(&state.localizer);
+        debug!(target: LINT_NAME, "single-letter binding `{ident}` in {context}");
+        if state.findings.admit(cx, pat.hir_id, ident.span) {
+            let message = SingleLetterMessage {
+                name: ident.name.as_str(),
+                parameter: position.is_parameter(),
+                context: &context,
+            };
+            emit_diagnostic(cx, ident.span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Classifies a binding by the node owning its outermost pattern. Returns
+/// `None` for bindings whose name the user did not choose freely.
+fn binding_position(cx: &LateContext<'_>, pat: &hir::Pat<'_>) -> Option<Position> {
+    let mut parents = cx.tcx.hir_parent_iter(pat.hir_id).map(|(_, node)| node);
+    let mut owner = parents.next()?;
+    if let Node::PatField(field) = owner
+        && field.is_shorthand
+    {
+        return None;
+    }
+    while matches!(owner, Node::Pat(_) | Node::PatField(_)) {
+        owner = parents.next()?;
+    }
+
+    let position = match (owner, parents.next()) {
+        (
+            Node::Param(_),
+            Some(Node::Expr(
+                closure @ hir::Expr {
+                    kind: hir::ExprKind::Closure(_),
+                    ..
+                },
+            )),
+        ) => Position::ClosureParam {
+            lines: count_lines(cx.sess().source_map(), closure.span)?,
+        },
+        (Node::Param(_), _) => Position::FnParam,
+        (
+            Node::Arm(_),
+            Some(Node::Expr(hir::Expr {
+                kind: hir::ExprKind::Match(_, _, hir::MatchSource::ForLoopDesugar),
+                ..
+            })),
+        ) => Position::ForLoop,
+        (
+            Node::LetStmt(hir::LetStmt {
+                source: hir::LocalSource::AsyncFn,
+                ..
+            }),
+            _,
+        ) => return None,
+        _ => Position::Local,
+    };
+    Some(position)
+}
+
+fn count_lines(source_map: &SourceMap, span: Span) -> Option<usize> {
+    if span.is_dummy() {
+        return None;
+    }
+    let first = source_map.lookup_char_pos(span.lo()).line;
+    let last = source_map.lookup_char_pos(span.hi()).line;
+    last.checked_sub(first).map(|extra| extra + 1)
+}
+
+/// Returns `true` when the function around `owner`, or its `impl`, bounds a
+/// type parameter by an arithmetic operator trait. Closures are judged by
+/// the function they are written in.
+fn in_generic_math(cx: &LateContext<'_>, owner: LocalDefId) -> bool {
+    let root = cx.tcx.typeck_root_def_id_local(owner);
+    cx.tcx
+        .predicates_of(root)
+        .instantiate_identity(cx.tcx)
+        .predicates
+        .iter()
+        .filter_map(|clause| clause.as_trait_clause())
+        .any(|bound| {
+            matches!(bound.self_ty().skip_binder().kind(), ty::Param(_))
+                && cx
+                    .tcx
+                    .as_lang_item(bound.def_id())
+                    .is_some_and(|item| ARITHMETIC_TRAITS.contains(&item))
+        })
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &SingleLetterMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        NO_SINGLE_LETTER_IDENTIFIERS,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one binding interpolates.
+#[derive(Clone, Copy, Debug)]
+struct SingleLetterMessage<'a> {
+    name: &'a str,
+    parameter: bool,
+    context: &'a str,
+}
+
+impl SingleLetterMessage<'_> {
+    const fn kind(&self) -> &'static str {
+        if self.parameter {
+            "parameter"
+        } else {
+            "binding"
+        }
+    }
+
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("name"),
+            FluentValue::from(self.name.to_owned()),
+        );
+        args.insert(Cow::Borrowed("kind"), FluentValue::from(self.kind()));
+        args.insert(
+            Cow::Borrowed("context"),
+            FluentValue::from(self.context.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { name, context, .. } = *self;
+        let kind = self.kind();
+        DiagnosticMessageSet::new(
+            format!("Single-letter {kind} `{name}` in {context}."),
+            format!(
+                "`{name}` says nothing about the value it holds, so readers must trace it back \
+                 to where it is bound."
+            ),
+            format!(
+                "Rename `{name}` after what it holds, or add it to `allowed` if the name is a \
+                 convention here."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(parameter: bool) -> SingleLetterMessage<'static> {
+        SingleLetterMessage {
+            name: "d",
+            parameter,
+            context: "function `publish`",
+        }
+    }
+
+    fn resolve(message: &SingleLetterMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_allows_loop_names() {
+        let config = Config::default();
+
+        assert_eq!(config.allowed, ["i", "j", "n", "x"]);
+        assert_eq!(config.max_closure_lines, 3);
+    }
+
+    #[rstest]
+    #[case(true, "Single-letter parameter `d` in function `publish`.")]
+    #[case(false, "Single-letter binding `d` in function `publish`.")]
+    fn english_messages_match_the_fallback(#[case] parameter: bool, #[case] primary: &str) {
+        let message = message(parameter);
+        let messages = resolve(&message, "en-GB");
+
+        assert_eq!(messages, message.fallback_messages());
+        assert_eq!(messages.primary(), primary);
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(true), locale);
+
+        assert!(messages.primary().contains("`d`"));
+        assert!(messages.primary().contains("`publish`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
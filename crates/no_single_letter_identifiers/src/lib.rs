@@ -0,0 +1,42 @@
+//! Lint crate flagging single-letter bindings and parameters.
+//!
+//! A name such as `d` or `s` tells readers nothing about the value it holds,
+//! and the cost grows with the distance between the binding and its uses.
+//! The lint reports single-letter `let` bindings, function parameters, and
+//! pattern bindings, but leaves the positions where short names are
+//! idiomatic alone: `for` loop bindings, parameters of closures no longer
+//! than `max_closure_lines`, and functions that do generic arithmetic. Names
+//! listed in `allowed` are never reported. The lint is experimental and ships
+//! behind the `experimental-no-single-letter-identifiers` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{BindingProjection, NoSingleLetterIdentifiersDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_single_letter_identifiers_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
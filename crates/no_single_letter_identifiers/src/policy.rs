@@ -0,0 +1,159 @@
+//! Rules deciding which single-letter names are reported.
+//!
+//! The driver classifies each binding by where it appears and whether the
+//! enclosing function does generic arithmetic, then asks [`NamePolicy`]
+//! whether the name is acceptable there. The rules are kept free of compiler
+//! types so they can be tested directly.
+
+/// Where a binding is introduced.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Position {
+    /// The pattern of a `for` loop.
+    ForLoop,
+    /// A parameter of a closure spanning `lines` source lines.
+    ClosureParam {
+        /// Number of lines the closure spans.
+        lines: usize,
+    },
+    /// A function or method parameter.
+    FnParam,
+    /// A `let` binding or any other pattern binding.
+    Local,
+}
+
+impl Position {
+    /// Returns `true` for parameters of functions, methods, and closures.
+    #[must_use]
+    pub(crate) const fn is_parameter(self) -> bool {
+        matches!(self, Self::ClosureParam { .. } | Self::FnParam)
+    }
+}
+
+/// A binding the driver found, described without compiler types.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Binding<'a> {
+    /// The bound name.
+    pub(crate) name: &'a str,
+    /// Where the binding is introduced.
+    pub(crate) position: Position,
+    /// The enclosing function bounds a type parameter by an arithmetic
+    /// operator trait such as `Add` or `Mul`.
+    pub(crate) in_generic_math: bool,
+}
+
+/// Accepted single-letter names and the closure length they are tolerated in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct NamePolicy {
+    allowed: Vec<String>,
+    max_closure_lines: usize,
+}
+
+impl NamePolicy {
+    /// Creates a policy accepting `allowed` everywhere and any single letter
+    /// as the parameter of a closure no longer than `max_closure_lines`.
+    #[must_use]
+    pub(crate) const fn new(allowed: Vec<String>, max_closure_lines: usize) -> Self {
+        Self {
+            allowed,
+            max_closure_lines,
+        }
+    }
+
+    /// Returns `true` when `binding` should be reported.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = NamePolicy::new(vec!["i".into()], 3);
+    /// let binding = Binding { name: "d", position: Position::FnParam, in_generic_math: false };
+    /// assert!(policy.flags(binding));
+    /// ```
+    #[must_use]
+    pub(crate) fn flags(&self, binding: Binding<'_>) -> bool {
+        if !is_single_letter(binding.name) || binding.in_generic_math {
+            return false;
+        }
+        if self.allowed.iter().any(|allowed| allowed == binding.name) {
+            return false;
+        }
+        match binding.position {
+            Position::ForLoop => false,
+            Position::ClosureParam { lines } => lines > self.max_closure_lines,
+            Position::FnParam | Position::Local => true,
+        }
+    }
+}
+
+/// Returns `true` when `name` is a single letter. Leading underscores mark
+/// intentionally unused bindings and make the name longer, so `_x` is not
+/// reported.
+#[must_use]
+pub(crate) fn is_single_letter(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!((chars.next(), chars.next()), (Some(ch), None) if ch.is_alphabetic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn policy() -> NamePolicy {
+        NamePolicy::new(vec!["i".into(), "j".into(), "n".into(), "x".into()], 3)
+    }
+
+    fn binding(name: &str, position: Position) -> Binding<'_> {
+        Binding {
+            name,
+            position,
+            in_generic_math: false,
+        }
+    }
+
+    #[rstest]
+    #[case("d", true)]
+    #[case("é", true)]
+    #[case("_", false)]
+    #[case("_d", false)]
+    #[case("id", false)]
+    #[case("", false)]
+    fn single_letters_are_alphabetic(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(is_single_letter(name), expected);
+    }
+
+    #[rstest]
+    #[case(Position::Local, true)]
+    #[case(Position::FnParam, true)]
+    #[case(Position::ForLoop, false)]
+    #[case(Position::ClosureParam { lines: 3 }, false)]
+    #[case(Position::ClosureParam { lines: 4 }, true)]
+    fn position_decides_single_letters(
+        policy: NamePolicy,
+        #[case] position: Position,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(policy.flags(binding("d", position)), expected);
+    }
+
+    #[rstest]
+    fn allowed_names_pass_anywhere(policy: NamePolicy) {
+        assert!(!policy.flags(binding("n", Position::FnParam)));
+        assert!(!policy.flags(binding("x", Position::ClosureParam { lines: 20 })));
+    }
+
+    #[rstest]
+    fn generic_math_is_exempt(policy: NamePolicy) {
+        let binding = Binding {
+            in_generic_math: true,
+            ..binding("a", Position::FnParam)
+        };
+
+        assert!(!policy.flags(binding));
+    }
+
+    #[rstest]
+    fn longer_names_are_never_reported(policy: NamePolicy) {
+        assert!(!policy.flags(binding("sink", Position::Local)));
+    }
+}
@@ -0,0 +1,129 @@
+//! Behaviour-driven coverage for the single-letter name policy.
+
+use crate::policy::{Binding, NamePolicy, Position};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct NameWorld {
+    name: RefCell<String>,
+    position: Cell<Position>,
+    in_generic_math: Cell<bool>,
+    flagged: Cell<Option<bool>>,
+}
+
+impl Default for NameWorld {
+    fn default() -> Self {
+        Self {
+            name: RefCell::default(),
+            position: Cell::new(Position::Local),
+            in_generic_math: Cell::new(false),
+            flagged: Cell::new(None),
+        }
+    }
+}
+
+#[fixture]
+fn world() -> NameWorld {
+    NameWorld::default()
+}
+
+fn set_binding(world: &NameWorld, name: &str, position: Position) {
+    *world.name.borrow_mut() = name.trim_matches('"').to_owned();
+    world.position.set(position);
+}
+
+#[given("a binding named {name} in a let statement")]
+fn given_local(world: &NameWorld, name: String) {
+    set_binding(world, &name, Position::Local);
+}
+
+#[given("a binding named {name} in a for loop")]
+fn given_for_loop(world: &NameWorld, name: String) {
+    set_binding(world, &name, Position::ForLoop);
+}
+
+#[given("a binding named {name} in a function parameter")]
+fn given_fn_param(world: &NameWorld, name: String) {
+    set_binding(world, &name, Position::FnParam);
+}
+
+#[given("a binding named {name} in a closure spanning {lines} lines")]
+fn given_closure_param(world: &NameWorld, name: String, lines: usize) {
+    set_binding(world, &name, Position::ClosureParam { lines });
+}
+
+#[given("the function does generic arithmetic")]
+fn given_generic_math(world: &NameWorld) {
+    world.in_generic_math.set(true);
+}
+
+#[when("the name is checked")]
+fn when_checked(world: &NameWorld) {
+    let policy = NamePolicy::new(["i", "j", "n", "x"].map(String::from).to_vec(), 3);
+    let name = world.name.borrow();
+    let binding = Binding {
+        name: &name,
+        position: world.position.get(),
+        in_generic_math: world.in_generic_math.get(),
+    };
+    world.flagged.set(Some(policy.flags(binding)));
+}
+
+#[then("the name is reported")]
+fn then_reported(world: &NameWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the name is allowed")]
+fn then_allowed(world: &NameWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/no_single_letter_identifiers.feature",
+    index = 0
+)]
+fn scenario_local_binding(world: NameWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_single_letter_identifiers.feature",
+    index = 1
+)]
+fn scenario_for_loop(world: NameWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_single_letter_identifiers.feature",
+    index = 2
+)]
+fn scenario_long_closure(world: NameWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_single_letter_identifiers.feature",
+    index = 3
+)]
+fn scenario_short_closure(world: NameWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_single_letter_identifiers.feature",
+    index = 4
+)]
+fn scenario_generic_math(world: NameWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_single_letter_identifiers.feature",
+    index = 5
+)]
+fn scenario_allowed_name(world: NameWorld) {
+    let _ = world;
+}
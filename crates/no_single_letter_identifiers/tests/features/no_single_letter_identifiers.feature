@@ -0,0 +1,35 @@
+Feature: Single-letter names outside idiomatic positions
+  Single-letter bindings and parameters are reported unless they are
+  `for` loop bindings, parameters of short closures, names in generic
+  arithmetic, or listed in `allowed`.
+
+  Scenario: A single-letter local binding is reported
+    Given a binding named "d" in a let statement
+    When the name is checked
+    Then the name is reported
+
+  Scenario: A for loop binding is allowed
+    Given a binding named "k" in a for loop
+    When the name is checked
+    Then the name is allowed
+
+  Scenario: A parameter of a long closure is reported
+    Given a binding named "s" in a closure spanning 6 lines
+    When the name is checked
+    Then the name is reported
+
+  Scenario: A parameter of a short closure is allowed
+    Given a binding named "s" in a closure spanning 1 lines
+    When the name is checked
+    Then the name is allowed
+
+  Scenario: Generic arithmetic is exempt
+    Given a binding named "a" in a function parameter
+    And the function does generic arithmetic
+    When the name is checked
+    Then the name is allowed
+
+  Scenario: Allowed names pass anywhere
+    Given a binding named "n" in a function parameter
+    When the name is checked
+    Then the name is allowed
@@ -0,0 +1,22 @@
+struct Document {
+    title: String,
+}
+
+fn publish(d: &Document) -> usize {
+    let t = d.title.trim();
+    t.len()
+}
+
+fn main() {
+    let document = Document {
+        title: String::from(" whitaker "),
+    };
+    let total: i32 = [1, 2, 3]
+        .iter()
+        .map(|v| {
+            let doubled = v * 2;
+            doubled + 1
+        })
+        .sum();
+    let _ = (publish(&document), total);
+}
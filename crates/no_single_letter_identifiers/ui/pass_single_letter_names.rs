@@ -0,0 +1,37 @@
+use std::ops::{Add, Mul};
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn lerp<T: Add<Output = T> + Mul<Output = T> + Copy>(a: T, b: T, t: T) -> T {
+    a * t + b * t
+}
+
+fn sum_grid(grid: &[Vec<i32>]) -> i32 {
+    let mut total = 0;
+    for (r, row) in grid.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            total += cell * (r + c) as i32;
+        }
+    }
+    total
+}
+
+fn norm(point: &Point) -> i32 {
+    let Point { x, y } = point;
+    x * x + y * y
+}
+
+async fn scale(factor: i32, n: i32) -> i32 {
+    factor * n
+}
+
+fn main() {
+    let values = [1, 2, 3];
+    let doubled: Vec<i32> = values.iter().map(|v| v * 2).collect();
+    let _ = (doubled, lerp(1, 2, 3), sum_grid(&[vec![1]]), norm(&Point { x: 1, y: 2 }));
+    let _future = scale(2, 3);
+    for _ in 0..1 {}
+}
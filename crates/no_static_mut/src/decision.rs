@@ -0,0 +1,94 @@
+//! Pure decision logic for `no_static_mut`.
+//!
+//! The driver visits every `static` declaration and every path expression
+//! resolving to a `static`, noting its mutability and whether it comes from
+//! a macro expansion. [`NoStaticMutDecision`] reports the mutable ones
+//! written by hand, using the declaration or access as the finding's reason
+//! and naming the item as its subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+
+/// Reason key for `static mut` declarations.
+const DECLARATION: &str = "declaration";
+
+/// Reason key for reads and writes of a `static mut` item.
+const ACCESS: &str = "access";
+
+/// The facts about one declaration of, or access to, a `static` that the
+/// decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StaticProjection {
+    /// The item's name.
+    pub name: String,
+    /// Whether the item is declared `static mut`.
+    pub mutable: bool,
+    /// Whether this is the declaration rather than an access.
+    pub declaration: bool,
+    /// Whether the declaration or access comes from a macro expansion.
+    pub from_expansion: bool,
+}
+
+/// Reports `static mut` declarations and every access to them.
+///
+/// # Examples
+///
+/// ```ignore
+/// let counter = StaticProjection {
+///     name: "COUNTER".to_owned(),
+///     mutable: true,
+///     declaration: true,
+///     from_expansion: false,
+/// };
+/// assert!(NoStaticMutDecision.decide(&counter).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoStaticMutDecision;
+
+impl LintDecision for NoStaticMutDecision {
+    type Projection = StaticProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, item: &StaticProjection) -> Option<Finding> {
+        if !item.mutable || item.from_expansion {
+            return None;
+        }
+        let reason = if item.declaration {
+            DECLARATION
+        } else {
+            ACCESS
+        };
+        Some(Finding::new(LINT_NAME, reason).with_subject(item.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<StaticProjection>> {
+        let item = |mutable, declaration, from_expansion| StaticProjection {
+            name: "COUNTER".to_owned(),
+            mutable,
+            declaration,
+            from_expansion,
+        };
+        vec![
+            DecisionFixture::flagged("declaration", item(true, true, false), DECLARATION),
+            DecisionFixture::flagged("access", item(true, false, false), ACCESS),
+            DecisionFixture::clean("immutable static", item(false, true, false)),
+            DecisionFixture::clean("macro expansion", item(true, false, true)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&NoStaticMutDecision), Ok(4));
+    }
+}
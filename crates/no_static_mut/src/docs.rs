@@ -0,0 +1,17 @@
+//! Embedded documentation for the `no_static_mut` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, examples, and configuration for `no_static_mut`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_static_mut",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Forbids `static mut` items.",
+    rationale: "`static mut` permits unsynchronised global mutation and every access needs `unsafe`; `OnceLock`, `Mutex`, or atomics provide safe alternatives.",
+    bad_example: "static mut COUNTER: u32 = 0;",
+    good_example: "static COUNTER: AtomicU32 = AtomicU32::new(0);",
+    config: &[],
+};
@@ -0,0 +1,207 @@
+//! Forbid `static mut` declarations and every access to them.
+//!
+//! The lint reports each `static mut` item where it is declared and each path
+//! expression that resolves to one, including accesses to `static mut` items
+//! declared in `extern` blocks or other crates. Diagnostics steer authors
+//! towards `OnceLock`, `Mutex`, or atomic types, which provide the same global
+//! state without unsynchronised mutation.
+
+use std::borrow::Cow;
+
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{ExprKind, ItemKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+pub(crate) const LINT_NAME: &str = "no_static_mut";
+const DECLARATION_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const ACCESS_KEY: MessageKey<'static> = MessageKey::new("no_static_mut-access");
+
+dylint_linting::impl_late_lint! {
+    pub NO_STATIC_MUT,
+    Deny,
+    "`static mut` permits unsynchronised global mutation; use `OnceLock`, `Mutex`, or atomics",
+    NoStaticMut::default()
+}
+
+/// Lint pass that reports `static mut` declarations and accesses.
+#[derive(Default)]
+pub struct NoStaticMut {
+    localizer: CrateScopedState<Localizer>,
+    findings: CrateScopedState<FindingLimit>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoStaticMut {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        self.findings
+            .reset(FindingLimit::new(NO_STATIC_MUT, &shared_config));
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.findings.summarise(cx, &self.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if item.span.from_expansion() || !matches!(item.kind, ItemKind::Static(..)) {
+            return;
+        }
+
+        let def_id = item.owner_id.to_def_id();
+        if cx.tcx.static_mutability(def_id) != Some(Mutability::Mut) {
+            return;
+        }
+
+        let Some(ident) = item.kind.ident() else {
+            return;
+        };
+        let finding = Finding {
+            span: item.span,
+            usage: StaticMutUsage::Declaration,
+            name: ident.name.as_str(),
+        };
+        if self.findings.admit(cx, item.hir_id(), finding.span) {
+            emit_diagnostic(cx, &finding, &self.localizer);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        let ExprKind::Path(ref qpath) = expr.kind else {
+            return;
+        };
+
+        let Res::Def(
+            DefKind::Static {
+                mutability: Mutability::Mut,
+                ..
+            },
+            def_id,
+        ) = cx.qpath_res(qpath, expr.hir_id)
+        else {
+            return;
+        };
+
+        let name = cx.tcx.item_name(def_id);
+        let finding = Finding {
+            span: expr.span,
+            usage: StaticMutUsage::Access,
+            name: name.as_str(),
+        };
+        if self.findings.admit(cx, expr.hir_id, finding.span) {
+            emit_diagnostic(cx, &finding, &self.localizer);
+        }
+    }
+}
+
+/// Distinguishes the declaration of a `static mut` from a use site.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum StaticMutUsage {
+    Declaration,
+    Access,
+}
+
+impl StaticMutUsage {
+    const fn message_key(self) -> MessageKey<'static> {
+        match self {
+            Self::Declaration => DECLARATION_KEY,
+            Self::Access => ACCESS_KEY,
+        }
+    }
+}
+
+/// A single `static mut` declaration or access awaiting a diagnostic.
+struct Finding<'a> {
+    span: Span,
+    usage: StaticMutUsage,
+    name: &'a str,
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, finding: &Finding<'_>, localizer: &Localizer) {
+    let Finding { span, usage, name } = *finding;
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(Cow::Borrowed("name"), FluentValue::from(name.to_string()));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: usage.message_key(),
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(usage, name)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        NO_STATIC_MUT,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(usage: StaticMutUsage, name: &str) -> DiagnosticMessageSet {
+    match usage {
+        StaticMutUsage::Declaration => DiagnosticMessageSet::new(
+            format!("`static mut` item `{name}` permits unsynchronised global mutation."),
+            "References to `static mut` items are deprecated and every access requires `unsafe`."
+                .to_string(),
+            format!("Replace `{name}` with a `std::sync::OnceLock`, a `Mutex`, or an atomic type."),
+        ),
+        StaticMutUsage::Access => DiagnosticMessageSet::new(
+            format!("Access to `static mut` item `{name}` is unsynchronised."),
+            format!("Reading or writing `{name}` races with any other thread that touches it."),
+            format!(
+                "Migrate `{name}` to a `std::sync::OnceLock`, a `Mutex`, or an atomic type, then remove the `unsafe` block."
+            ),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(StaticMutUsage::Declaration, "no_static_mut")]
+    #[case(StaticMutUsage::Access, "no_static_mut-access")]
+    fn usages_select_distinct_message_keys(#[case] usage: StaticMutUsage, #[case] expected: &str) {
+        assert_eq!(usage.message_key().as_ref(), expected);
+    }
+
+    #[rstest]
+    #[case(StaticMutUsage::Declaration)]
+    #[case(StaticMutUsage::Access)]
+    fn fallback_messages_name_the_item_and_alternatives(#[case] usage: StaticMutUsage) {
+        let messages = fallback_messages(usage, "COUNTER");
+
+        assert!(messages.primary().contains("`COUNTER`"));
+        for alternative in ["OnceLock", "Mutex", "atomic"] {
+            assert!(messages.help().contains(alternative), "{alternative}");
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
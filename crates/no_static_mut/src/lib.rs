@@ -0,0 +1,36 @@
+//! Lint crate forbidding `static mut` declarations and accesses.
+//!
+//! `static mut` items permit unsynchronised mutation of global state, and
+//! references to them are deprecated upstream. The lint denies both the
+//! declaration and every access so teams migrate to `OnceLock`, `Mutex`, or
+//! atomic types.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{NoStaticMutDecision, StaticProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_static_mut_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+mod ui {
+    whitaker::declare_ui_tests!("ui");
+}
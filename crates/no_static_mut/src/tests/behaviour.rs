@@ -0,0 +1,67 @@
+//! Behaviour-driven coverage for `static mut` diagnostic selection.
+
+use super::{StaticMutUsage, fallback_messages};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::i18n::DiagnosticMessageSet;
+
+#[derive(Default)]
+struct StaticMutWorld {
+    usage: Cell<Option<StaticMutUsage>>,
+    name: RefCell<String>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+impl StaticMutWorld {
+    fn messages(&self) -> DiagnosticMessageSet {
+        self.messages
+            .borrow()
+            .clone()
+            .expect("diagnostic messages must be rendered")
+    }
+}
+
+#[fixture]
+fn world() -> StaticMutWorld {
+    StaticMutWorld::default()
+}
+
+#[given("a `static mut` declaration named {name}")]
+fn given_declaration(world: &StaticMutWorld, name: String) {
+    world.usage.set(Some(StaticMutUsage::Declaration));
+    *world.name.borrow_mut() = name;
+}
+
+#[given("an access to a `static mut` named {name}")]
+fn given_access(world: &StaticMutWorld, name: String) {
+    world.usage.set(Some(StaticMutUsage::Access));
+    *world.name.borrow_mut() = name;
+}
+
+#[when("the fallback diagnostic is rendered")]
+fn when_rendered(world: &StaticMutWorld) {
+    let usage = world.usage.get().expect("usage must be configured");
+    let messages = fallback_messages(usage, world.name.borrow().as_str());
+    *world.messages.borrow_mut() = Some(messages);
+}
+
+#[then("the primary message mentions {text}")]
+fn then_primary_mentions(world: &StaticMutWorld, text: String) {
+    assert!(world.messages().primary().contains(text.as_str()));
+}
+
+#[then("the help suggests {alternative}")]
+fn then_help_suggests(world: &StaticMutWorld, alternative: String) {
+    assert!(world.messages().help().contains(alternative.as_str()));
+}
+
+#[scenario(path = "tests/features/no_static_mut.feature", index = 0)]
+fn scenario_declaration(world: StaticMutWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_static_mut.feature", index = 1)]
+fn scenario_access(world: StaticMutWorld) {
+    let _ = world;
+}
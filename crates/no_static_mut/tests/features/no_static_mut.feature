@@ -0,0 +1,15 @@
+Feature: Static mut diagnostics
+  `static mut` declarations and accesses steer authors towards synchronised
+  alternatives.
+
+  Scenario: Declarations suggest synchronised replacements
+    Given a `static mut` declaration named COUNTER
+    When the fallback diagnostic is rendered
+    Then the primary message mentions `COUNTER`
+    And the help suggests OnceLock
+
+  Scenario: Accesses recommend removing the unsafe block
+    Given an access to a `static mut` named CACHE
+    When the fallback diagnostic is rendered
+    Then the primary message mentions unsynchronised
+    And the help suggests unsafe
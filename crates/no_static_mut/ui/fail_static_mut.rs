@@ -0,0 +1,15 @@
+//! Negative UI fixture: `static mut` declarations and accesses are denied.
+#![deny(no_static_mut)]
+
+static mut COUNTER: u32 = 0;
+
+fn increment() -> u32 {
+    unsafe {
+        COUNTER += 1;
+        COUNTER
+    }
+}
+
+fn main() {
+    let _ = increment();
+}
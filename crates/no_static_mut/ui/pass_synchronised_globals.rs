@@ -0,0 +1,21 @@
+//! Positive UI fixture: synchronised globals remain accepted.
+#![deny(no_static_mut)]
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static COUNTER: AtomicU32 = AtomicU32::new(0);
+static GREETING: OnceLock<String> = OnceLock::new();
+
+fn increment() -> u32 {
+    COUNTER.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+fn greeting() -> &'static str {
+    GREETING.get_or_init(|| "hello".to_owned())
+}
+
+fn main() {
+    let _ = increment();
+    let _ = greeting();
+}
@@ -0,0 +1,341 @@
+//! Sanctioned `std::fs` call sites configured in `dylint.toml`.
+//!
+//! Three escape hatches sit below whole-crate exclusion. A `std::fs` value
+//! handed straight to a function of an allowed wrapper crate, or produced by
+//! one of its macros, is accepted because the wrapper owns the capability
+//! boundary. A call whose every path parameter provably lives under an
+//! allowed root is accepted too: the argument must trace back, through
+//! borrows, immutable `let` bindings, path conversions, and `join` with a
+//! relative literal, to a value of a configured root type or to a string
+//! literal under a configured prefix.
+
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
+
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{Span, Symbol, sym};
+
+use crate::config::NoStdFsConfig;
+
+/// Bounds how many bindings and conversions a path argument is traced through.
+const MAX_TRACE_DEPTH: usize = 8;
+
+/// Methods that return the path of their receiver unchanged.
+const PATH_PRESERVING_METHODS: &[&str] = &[
+    "as_path",
+    "as_ref",
+    "borrow",
+    "clone",
+    "into_path",
+    "keep",
+    "path",
+    "to_owned",
+    "to_path_buf",
+];
+
+/// The call sites a crate's configuration sanctions.
+#[derive(Debug, Default)]
+pub(crate) struct Allowances {
+    crates: HashSet<String>,
+    path_prefixes: Vec<PathBuf>,
+    path_roots: HashSet<String>,
+}
+
+impl Allowances {
+    /// Collects the allowances configured in `config`.
+    pub(crate) fn new(config: &NoStdFsConfig) -> Self {
+        Self {
+            crates: config.allowed_crates.clone(),
+            path_prefixes: config
+                .allowed_path_prefixes
+                .iter()
+                .map(PathBuf::from)
+                .collect(),
+            path_roots: config.allowed_path_roots.iter().cloned().collect(),
+        }
+    }
+
+    /// Reports whether `crate_name` is a sanctioned wrapper crate.
+    pub(crate) fn allows_crate(&self, crate_name: &str) -> bool {
+        self.crates.contains(crate_name)
+    }
+
+    /// Reports whether the literal `path` lies under an allowed prefix
+    /// without climbing out of it through `..`.
+    pub(crate) fn allows_literal_path(&self, path: &str) -> bool {
+        let path = Path::new(path);
+        !path
+            .components()
+            .any(|component| component == Component::ParentDir)
+            && self
+                .path_prefixes
+                .iter()
+                .any(|prefix| path.starts_with(prefix))
+    }
+
+    /// Reports whether values of the type at `type_path` are allowed roots.
+    pub(crate) fn allows_root_type(&self, type_path: &str) -> bool {
+        self.path_roots.contains(type_path)
+    }
+
+    /// Reports whether `span` was expanded from a macro defined in an
+    /// allowed crate.
+    pub(crate) fn allows_expansion(&self, cx: &LateContext<'_>, span: Span) -> bool {
+        !self.crates.is_empty()
+            && span.macro_backtrace().any(|expn| {
+                expn.macro_def_id
+                    .is_some_and(|def_id| self.allows_def_crate(cx, def_id))
+            })
+    }
+
+    /// Reports whether the `std::fs` expression `expr` is sanctioned, either
+    /// because it is passed to an allowed crate or because it is the callee
+    /// of a call whose paths stay under allowed roots.
+    pub(crate) fn allows_expr<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx hir::Expr<'tcx>,
+    ) -> bool {
+        self.passed_to_allowed_crate(cx, expr) || self.targets_allowed_paths(cx, expr)
+    }
+
+    fn allows_def_crate(&self, cx: &LateContext<'_>, def_id: DefId) -> bool {
+        self.allows_crate(cx.tcx.crate_name(def_id.krate).as_str())
+    }
+
+    fn passed_to_allowed_crate(&self, cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+        if self.crates.is_empty() {
+            return false;
+        }
+        let mut child = expr.hir_id;
+        for (parent_id, node) in cx.tcx.hir_parent_iter(expr.hir_id) {
+            let hir::Node::Expr(parent) = node else {
+                return false;
+            };
+            let callee = match parent.kind {
+                hir::ExprKind::Call(callee, _) if callee.hir_id != child => match callee.kind {
+                    hir::ExprKind::Path(ref qpath) => {
+                        cx.qpath_res(qpath, callee.hir_id).opt_def_id()
+                    }
+                    _ => None,
+                },
+                hir::ExprKind::MethodCall(..) => {
+                    cx.typeck_results().type_dependent_def_id(parent.hir_id)
+                }
+                hir::ExprKind::Closure(..) => return false,
+                _ => None,
+            };
+            if callee.is_some_and(|def_id| self.allows_def_crate(cx, def_id)) {
+                return true;
+            }
+            child = parent_id;
+        }
+        false
+    }
+
+    fn targets_allowed_paths<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx hir::Expr<'tcx>,
+    ) -> bool {
+        if self.path_prefixes.is_empty() && self.path_roots.is_empty() {
+            return false;
+        }
+        let Some(call) = PathCall::of(cx, expr) else {
+            return false;
+        };
+        let parameters = path_parameters(cx, call.def_id);
+        !parameters.is_empty()
+            && parameters.iter().all(|&index| {
+                index
+                    .checked_sub(call.receiver_offset)
+                    .and_then(|index| call.args.get(index))
+                    .is_some_and(|arg| self.contained_path(cx, arg, 0))
+            })
+    }
+
+    fn contained_path(&self, cx: &LateContext<'_>, expr: &hir::Expr<'_>, depth: usize) -> bool {
+        if depth > MAX_TRACE_DEPTH {
+            return false;
+        }
+        if self.is_root_value(cx, cx.typeck_results().expr_ty(expr)) {
+            return true;
+        }
+        let next = depth + 1;
+        match expr.kind {
+            hir::ExprKind::Lit(lit) => match lit.node {
+                LitKind::Str(path, _) => self.allows_literal_path(path.as_str()),
+                _ => false,
+            },
+            hir::ExprKind::AddrOf(_, _, inner) => self.contained_path(cx, inner, next),
+            hir::ExprKind::MethodCall(segment, receiver, args, _) => {
+                match (segment.ident.as_str(), args) {
+                    ("join", [component]) => {
+                        literal_str(component)
+                            .is_some_and(|value| is_contained_component(value.as_str()))
+                            && self.contained_path(cx, receiver, next)
+                    }
+                    (method, []) if PATH_PRESERVING_METHODS.contains(&method) => {
+                        self.contained_path(cx, receiver, next)
+                    }
+                    _ => false,
+                }
+            }
+            hir::ExprKind::Call(callee, [arg]) if is_std_conversion(cx, callee) => {
+                self.contained_path(cx, arg, next)
+            }
+            hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => match path.res {
+                Res::Local(binding) => immutable_initializer(cx, binding)
+                    .is_some_and(|init| self.contained_path(cx, init, next)),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn is_root_value(&self, cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+        let ty::Adt(adt, _) = ty.peel_refs().kind() else {
+            return false;
+        };
+        let def_id = adt.did();
+        let path = cx.tcx.def_path_str(def_id);
+        let qualified = if def_id.is_local() {
+            format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+        } else {
+            path
+        };
+        self.allows_root_type(&qualified)
+    }
+}
+
+/// Reports whether joining `component` onto a path keeps the result inside
+/// that path.
+pub(crate) fn is_contained_component(component: &str) -> bool {
+    Path::new(component)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+}
+
+/// A call to a function or method, seen from its callee expression.
+struct PathCall<'tcx> {
+    def_id: DefId,
+    args: &'tcx [hir::Expr<'tcx>],
+    /// Parameters before the first explicit argument, one for `self`.
+    receiver_offset: usize,
+}
+
+impl<'tcx> PathCall<'tcx> {
+    fn of(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) -> Option<Self> {
+        match expr.kind {
+            hir::ExprKind::MethodCall(_, _, args, _) => Some(Self {
+                def_id: cx.typeck_results().type_dependent_def_id(expr.hir_id)?,
+                args,
+                receiver_offset: 1,
+            }),
+            hir::ExprKind::Path(ref qpath) => {
+                let hir::Node::Expr(call) = cx.tcx.parent_hir_node(expr.hir_id) else {
+                    return None;
+                };
+                let hir::ExprKind::Call(callee, args) = call.kind else {
+                    return None;
+                };
+                (callee.hir_id == expr.hir_id).then_some(())?;
+                Some(Self {
+                    def_id: cx.qpath_res(qpath, expr.hir_id).opt_def_id()?,
+                    args,
+                    receiver_offset: 0,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Returns the positions of the parameters of `def_id` that take a path.
+fn path_parameters(cx: &LateContext<'_>, def_id: DefId) -> Vec<usize> {
+    if !matches!(
+        cx.tcx.def_kind(def_id),
+        hir::def::DefKind::Fn | hir::def::DefKind::AssocFn
+    ) {
+        return Vec::new();
+    }
+    let sig = cx
+        .tcx
+        .fn_sig(def_id)
+        .instantiate_identity()
+        .skip_normalization()
+        .skip_binder();
+    let bounds = cx.tcx.param_env(def_id).caller_bounds();
+    sig.inputs()
+        .iter()
+        .enumerate()
+        .filter(|(_, input)| {
+            is_path_ty(cx, input.peel_refs())
+                || matches!(input.kind(), ty::Param(_))
+                    && bounds.iter().any(|clause| {
+                        clause.as_trait_clause().is_some_and(|predicate| {
+                            let predicate = predicate.skip_binder();
+                            predicate.self_ty() == **input
+                                && cx.tcx.is_diagnostic_item(sym::AsRef, predicate.def_id())
+                                && is_path_ty(cx, predicate.trait_ref.args.type_at(1))
+                        })
+                    })
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn is_path_ty(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Adt(adt, _) if cx.tcx.def_path_str(adt.did()) == "std::path::Path")
+}
+
+fn literal_str(expr: &hir::Expr<'_>) -> Option<Symbol> {
+    let hir::ExprKind::Lit(lit) = expr.kind else {
+        return None;
+    };
+    match lit.node {
+        LitKind::Str(value, _) => Some(value),
+        _ => None,
+    }
+}
+
+/// Reports whether `callee` is a standard-library `new` or `from`
+/// constructor, such as `Path::new` or `PathBuf::from`.
+fn is_std_conversion(cx: &LateContext<'_>, callee: &hir::Expr<'_>) -> bool {
+    let hir::ExprKind::Path(ref qpath) = callee.kind else {
+        return false;
+    };
+    cx.qpath_res(qpath, callee.hir_id)
+        .opt_def_id()
+        .is_some_and(|def_id| {
+            matches!(
+                cx.tcx.crate_name(def_id.krate),
+                sym::std | sym::core | sym::alloc
+            ) && matches!(cx.tcx.item_name(def_id), sym::new | sym::from)
+        })
+}
+
+/// Returns the initialiser of the immutable `let` binding `binding`.
+fn immutable_initializer<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding: hir::HirId,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    let hir::Node::Pat(pat) = cx.tcx.hir_node(binding) else {
+        return None;
+    };
+    if !matches!(pat.kind, hir::PatKind::Binding(hir::BindingMode::NONE, ..)) {
+        return None;
+    }
+    match cx.tcx.parent_hir_node(binding) {
+        hir::Node::LetStmt(local) => local.init,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
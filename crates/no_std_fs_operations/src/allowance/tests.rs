@@ -0,0 +1,74 @@
+//! Tests for matching configured wrapper crates, path prefixes, and roots.
+use super::{Allowances, is_contained_component};
+use crate::config::NoStdFsConfig;
+use rstest::{fixture, rstest};
+
+#[fixture]
+fn allowances() -> Allowances {
+    Allowances::new(&NoStdFsConfig {
+        allowed_crates: ["cap_std".to_owned()].into(),
+        allowed_path_prefixes: vec!["/tmp".to_owned(), "target/scratch".to_owned()],
+        allowed_path_roots: vec!["tempfile::TempDir".to_owned()],
+        ..NoStdFsConfig::default()
+    })
+}
+
+#[rstest]
+#[case("/tmp", true)]
+#[case("/tmp/whitaker/cache", true)]
+#[case("target/scratch/out.txt", true)]
+#[case("/tmpfiles/out.txt", false)]
+#[case("/tmp/../etc/passwd", false)]
+#[case("target/scratch/../../secret", false)]
+#[case("/var/tmp/out.txt", false)]
+#[case("tmp/out.txt", false)]
+fn literal_paths_must_stay_under_a_prefix(
+    allowances: Allowances,
+    #[case] path: &str,
+    #[case] expected: bool,
+) {
+    assert_eq!(allowances.allows_literal_path(path), expected);
+}
+
+#[rstest]
+#[case("cap_std", true)]
+#[case("cap-std", false)]
+#[case("camino", false)]
+fn wrapper_crates_match_by_crate_name(
+    allowances: Allowances,
+    #[case] name: &str,
+    #[case] expected: bool,
+) {
+    assert_eq!(allowances.allows_crate(name), expected);
+}
+
+#[rstest]
+#[case("tempfile::TempDir", true)]
+#[case("tempfile::NamedTempFile", false)]
+#[case("TempDir", false)]
+fn root_types_match_by_full_path(
+    allowances: Allowances,
+    #[case] path: &str,
+    #[case] expected: bool,
+) {
+    assert_eq!(allowances.allows_root_type(path), expected);
+}
+
+#[rstest]
+#[case("notes.txt", true)]
+#[case("cache/notes.txt", true)]
+#[case("./notes.txt", true)]
+#[case("../notes.txt", false)]
+#[case("cache/../../notes.txt", false)]
+#[case("/etc/passwd", false)]
+fn joined_components_must_stay_inside(#[case] component: &str, #[case] expected: bool) {
+    assert_eq!(is_contained_component(component), expected);
+}
+
+#[test]
+fn default_configuration_allows_nothing() {
+    let allowances = Allowances::new(&NoStdFsConfig::default());
+    assert!(!allowances.allows_crate("cap_std"));
+    assert!(!allowances.allows_literal_path("/tmp/out.txt"));
+    assert!(!allowances.allows_root_type("tempfile::TempDir"));
+}
@@ -11,6 +11,7 @@ use whitaker_common::i18n::{I18nError, Localizer};
 struct LocalizationWorld {
     localizer: Option<Localizer>,
     operation: String,
+    module: String,
     failing: bool,
     result: Option<Result<StdFsMessages, I18nError>>,
 }
@@ -24,17 +25,22 @@ impl LocalizationWorld {
         self.operation = operation.to_owned();
     }
 
+    fn set_module(&mut self, module: &str) {
+        self.module = module.to_owned();
+    }
+
     fn mark_failure(&mut self) {
         self.failing = true;
     }
 
     fn resolve(&mut self) {
         let op = self.operation.clone();
+        let module = self.module.clone();
         let result = if self.failing {
-            localised_messages(&FailingLookup::new("no_std_fs_operations"), &op)
+            localised_messages(&FailingLookup::new("no_std_fs_operations"), &op, &module)
         } else {
             let localizer = self.localizer.as_ref().expect("a locale must be selected");
-            localised_messages(localizer, &op)
+            localised_messages(localizer, &op, &module)
         };
         self.result = Some(result);
     }
@@ -62,6 +68,7 @@ type WorldCell = RefCell<LocalizationWorld>;
 fn world() -> WorldCell {
     RefCell::new(LocalizationWorld {
         operation: String::from("std::fs::read"),
+        module: String::from("std::fs"),
         ..LocalizationWorld::default()
     })
 }
@@ -78,6 +85,11 @@ fn given_operation(world: &WorldCell, operation: String) {
         .set_operation(operation.trim_matches('"'));
 }
 
+#[given("the module is {module}")]
+fn given_module(world: &WorldCell, module: String) {
+    world.borrow_mut().set_module(module.trim_matches('"'));
+}
+
 #[given("localization fails")]
 fn given_failure(world: &WorldCell) {
     world.borrow_mut().mark_failure();
@@ -143,3 +155,8 @@ fn scenario_fallback(world: WorldCell) {
 fn scenario_failure(world: WorldCell) {
     let _ = world;
 }
+
+#[scenario(path = "tests/features/localization.feature", index = 5)]
+fn scenario_async_module(world: WorldCell) {
+    let _ = world;
+}
@@ -0,0 +1,236 @@
+//! Configuration for the `no_std_fs_operations` lint, read from
+//! `dylint.toml`.
+
+use crate::usage::FilesystemModules;
+use log::warn;
+use serde::Deserialize;
+use std::collections::HashSet;
+
+const LINT_NAME: &str = "no_std_fs_operations";
+
+/// Receiver named in suggestions that route calls through an injected
+/// filesystem trait.
+const DEFAULT_FILESYSTEM_RECEIVER: &str = "fs";
+
+/// Configuration for the `no_std_fs_operations` lint.
+///
+/// # TOML Configuration
+///
+/// In `dylint.toml` at your workspace root:
+///
+/// ```toml
+/// [no_std_fs_operations]
+/// excluded_crates = ["my_cli_app", "test_utilities"]
+/// allowed_crates = ["cap_std"]
+/// allowed_path_prefixes = ["/tmp"]
+/// allowed_path_roots = ["tempfile::TempDir"]
+/// modules = ["std::fs", "tokio::fs", "async_std::fs"]
+/// filesystem_trait = "my_app::fs::Filesystem"
+/// filesystem_receiver = "fs"
+/// ```
+///
+/// Use Rust crate names (underscores), not Cargo package names (hyphens).
+///
+/// # Strict Validation
+///
+/// This configuration uses `deny_unknown_fields`, meaning any unrecognized
+/// key (such as a typo like `excluded_crate` instead of `excluded_crates`)
+/// will cause configuration parsing to fail. When parsing fails, the lint
+/// falls back to defaults and logs a warning. If exclusions don't work as
+/// expected, check the logs for parse errors.
+///
+/// # Examples
+///
+/// ```rust
+/// # use no_std_fs_operations::NoStdFsConfig;
+/// # use std::collections::HashSet;
+/// let config = NoStdFsConfig {
+///     excluded_crates: HashSet::from(["my_cli_app".to_owned()]),
+///     ..NoStdFsConfig::default()
+/// };
+/// assert!(config.is_excluded("my_cli_app"));
+/// assert!(!config.is_excluded("other_crate"));
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NoStdFsConfig {
+    /// Crate names excluded from the lint. These crates are allowed to use
+    /// `std::fs` operations without triggering diagnostics.
+    #[serde(deserialize_with = "deserialize_crate_names")]
+    pub excluded_crates: HashSet<String>,
+    /// Wrapper crates whose functions may receive `std::fs` values and whose
+    /// macros may expand to `std::fs` operations.
+    #[serde(deserialize_with = "deserialize_crate_names")]
+    pub allowed_crates: HashSet<String>,
+    /// Path prefixes under which string-literal paths may be passed to
+    /// `std::fs` operations.
+    pub allowed_path_prefixes: Vec<String>,
+    /// Fully qualified types, such as `tempfile::TempDir`, whose values are
+    /// directories `std::fs` operations may target.
+    pub allowed_path_roots: Vec<String>,
+    /// Modules whose items count as ambient filesystem access. Setting this
+    /// replaces the default `std::fs`, which behaviour version 2 extends with
+    /// `tokio::fs` and `async_std::fs`.
+    pub modules: Option<Vec<String>>,
+    /// Fully qualified path of the team's injected filesystem trait. When
+    /// set, diagnostics suggest routing the operation through it.
+    pub filesystem_trait: Option<String>,
+    /// Name of the trait object or generic value that suggested calls are
+    /// made on.
+    pub filesystem_receiver: String,
+}
+
+impl Default for NoStdFsConfig {
+    fn default() -> Self {
+        Self {
+            excluded_crates: HashSet::new(),
+            allowed_crates: HashSet::new(),
+            allowed_path_prefixes: Vec::new(),
+            allowed_path_roots: Vec::new(),
+            modules: None,
+            filesystem_trait: None,
+            filesystem_receiver: DEFAULT_FILESYSTEM_RECEIVER.to_owned(),
+        }
+    }
+}
+
+fn deserialize_crate_names<'de, D>(deserializer: D) -> Result<HashSet<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let vec: Vec<String> = Vec::deserialize(deserializer)?;
+    Ok(vec.into_iter().collect())
+}
+
+impl NoStdFsConfig {
+    /// Check if the given crate name is excluded from the lint.
+    ///
+    /// Returns `true` if `crate_name` appears in the `excluded_crates` set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use no_std_fs_operations::NoStdFsConfig;
+    /// # use std::collections::HashSet;
+    /// let config = NoStdFsConfig {
+    ///     excluded_crates: HashSet::from(["my_cli".to_owned(), "test_utils".to_owned()]),
+    ///     ..NoStdFsConfig::default()
+    /// };
+    ///
+    /// assert!(config.is_excluded("my_cli"));
+    /// assert!(!config.is_excluded("other_crate"));
+    /// ```
+    #[must_use]
+    pub fn is_excluded(&self, crate_name: &str) -> bool {
+        self.excluded_crates.contains(crate_name)
+    }
+
+    /// Returns the configured filesystem modules, or the defaults when none
+    /// are configured. `async_modules` adds `tokio::fs` and `async_std::fs`
+    /// to the defaults.
+    #[must_use]
+    pub(crate) fn filesystem_modules(&self, async_modules: bool) -> FilesystemModules {
+        self.modules.as_deref().map_or_else(
+            || FilesystemModules::defaults(async_modules),
+            FilesystemModules::new,
+        )
+    }
+}
+
+/// Trait for loading lint configuration, enabling dependency injection for tests.
+///
+/// # Return Values
+///
+/// - `Ok(Some(config))` - Configuration section found and parsed successfully
+/// - `Ok(None)` - No configuration file or section present
+/// - `Err(...)` - Configuration file exists but parsing failed
+///
+/// # Example Usage (in tests)
+///
+/// ```text
+/// let mut mock = MockConfigReader::new();
+/// mock.expect_read_config()
+///     .returning(|_| Ok(Some(NoStdFsConfig {
+///         excluded_crates: vec!["my_crate".to_owned()],
+///     })));
+///
+/// let config = load_configuration_with_reader(&mock);
+/// assert!(config.is_excluded("my_crate"));
+/// ```
+#[cfg_attr(test, mockall::automock)]
+pub(crate) trait ConfigReader {
+    /// Read configuration for the given lint name.
+    ///
+    /// Returns `Ok(Some(config))` if found, `Ok(None)` if not present, or
+    /// `Err` if parsing fails.
+    fn read_config(
+        &self,
+        lint_name: &str,
+    ) -> Result<Option<NoStdFsConfig>, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// Production implementation that reads from `dylint.toml` via `dylint_linting::config`.
+///
+/// This reader delegates to `dylint_linting::config` to locate and parse
+/// the `dylint.toml` configuration file in the workspace root.
+pub(crate) struct DylintConfigReader;
+
+impl ConfigReader for DylintConfigReader {
+    fn read_config(
+        &self,
+        lint_name: &str,
+    ) -> Result<Option<NoStdFsConfig>, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+        dylint_linting::config::<NoStdFsConfig>(lint_name).map_err(|e| -> BoxError { Box::new(e) })
+    }
+}
+
+/// Load lint configuration using the provided reader.
+///
+/// Returns the default configuration when:
+/// - No configuration file exists
+/// - No `[no_std_fs_operations]` section is present
+/// - The configuration fails to parse (logged at `warn` level)
+///
+/// # Example Return Cases
+///
+/// ```text
+/// // Case 1: Configuration present
+/// reader.read_config("lint") => Ok(Some(config))
+/// load_configuration_with_reader(&reader) => config
+///
+/// // Case 2: No configuration section
+/// reader.read_config("lint") => Ok(None)
+/// load_configuration_with_reader(&reader) => NoStdFsConfig::default()
+///
+/// // Case 3: Parse error
+/// reader.read_config("lint") => Err(...)
+/// load_configuration_with_reader(&reader) => NoStdFsConfig::default() + logs warning
+/// ```
+fn load_configuration_with_reader(reader: &dyn ConfigReader) -> NoStdFsConfig {
+    match reader.read_config(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => NoStdFsConfig::default(),
+        Err(error) => {
+            warn!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            NoStdFsConfig::default()
+        }
+    }
+}
+
+/// Load lint configuration from `dylint.toml`.
+///
+/// Returns the default configuration when:
+/// - No configuration file exists
+/// - No `[no_std_fs_operations]` section is present
+/// - The configuration fails to parse (logged at `warn` level)
+pub(crate) fn load_configuration() -> NoStdFsConfig {
+    load_configuration_with_reader(&DylintConfigReader)
+}
+
+#[cfg(test)]
+#[path = "config_tests.rs"]
+mod tests;
@@ -34,6 +34,55 @@ fn config_default_has_empty_excluded_crates() {
     assert!(NoStdFsConfig::default().excluded_crates.is_empty());
 }
 
+#[rstest]
+#[case::legacy(false, None)]
+#[case::behaviour_version_2(true, Some("tokio::fs"))]
+fn config_default_adds_async_modules_from_behaviour_version_2(
+    #[case] async_modules: bool,
+    #[case] expected: Option<&str>,
+) {
+    let modules = NoStdFsConfig::default().filesystem_modules(async_modules);
+
+    assert!(modules.module_for("std::fs::read").is_some());
+    assert_eq!(
+        modules
+            .module_for("tokio::fs::read")
+            .map(ToString::to_string)
+            .as_deref(),
+        expected
+    );
+}
+
+#[rstest]
+#[case::legacy(false)]
+#[case::behaviour_version_2(true)]
+fn config_modules_replace_the_defaults(#[case] async_modules: bool) {
+    let config: NoStdFsConfig =
+        toml::from_str(r#"modules = ["std::fs", "my_crate::vfs"]"#).expect("valid TOML");
+    let modules = config.filesystem_modules(async_modules);
+
+    assert!(modules.module_for("my_crate::vfs::read").is_some());
+    assert!(modules.module_for("tokio::fs::read").is_none());
+}
+
+#[rstest]
+#[case::default_receiver(r#"filesystem_trait = "my_app::fs::Filesystem""#, "fs")]
+#[case::named_receiver(
+    r#"
+    filesystem_trait = "my_app::fs::Filesystem"
+    filesystem_receiver = "self.fs"
+    "#,
+    "self.fs"
+)]
+fn config_deserializes_the_injected_filesystem(#[case] toml: &str, #[case] receiver: &str) {
+    let config: NoStdFsConfig = toml::from_str(toml).expect("valid TOML");
+    assert_eq!(
+        config.filesystem_trait.as_deref(),
+        Some("my_app::fs::Filesystem")
+    );
+    assert_eq!(config.filesystem_receiver, receiver);
+}
+
 #[rstest]
 #[case::empty_config(r#""#, &[])]
 #[case::empty_excluded(r#"excluded_crates = []"#, &[])]
@@ -50,8 +99,29 @@ fn config_deserializes_excluded_crates(#[case] toml: &str, #[case] expected: &[&
     );
 }
 
+#[test]
+fn config_deserializes_allowances() {
+    let config: NoStdFsConfig = toml::from_str(
+        r#"
+        allowed_crates = ["cap_std", "camino"]
+        allowed_path_prefixes = ["/tmp"]
+        allowed_path_roots = ["tempfile::TempDir"]
+        "#,
+    )
+    .expect("valid TOML");
+    assert_eq!(
+        config.allowed_crates,
+        HashSet::from(["cap_std".to_owned(), "camino".to_owned()])
+    );
+    assert_eq!(config.allowed_path_prefixes, ["/tmp"]);
+    assert_eq!(config.allowed_path_roots, ["tempfile::TempDir"]);
+    assert!(config.excluded_crates.is_empty());
+}
+
 #[rstest]
 #[case::unknown_field(r#"unknown_field = true"#)]
+#[case::wrong_allowed_crates_type(r#"allowed_crates = "cap_std""#)]
+#[case::wrong_prefix_element_type(r#"allowed_path_prefixes = [1]"#)]
 #[case::wrong_type(r#"excluded_crates = "not_an_array""#)]
 #[case::wrong_element_type(r#"excluded_crates = [1, 2, 3]"#)]
 fn config_rejects_invalid_toml(#[case] toml: &str) {
@@ -85,6 +155,7 @@ fn is_excluded_matches_correctly(
 ) {
     let config = NoStdFsConfig {
         excluded_crates: excluded.iter().map(|s| (*s).to_owned()).collect(),
+        ..NoStdFsConfig::default()
     };
     assert_eq!(config.is_excluded(query), expected);
 }
@@ -93,6 +164,7 @@ fn is_excluded_matches_correctly(
 fn load_configuration_returns_config_when_present() {
     let config = NoStdFsConfig {
         excluded_crates: HashSet::from(["my_crate".to_owned()]),
+        ..NoStdFsConfig::default()
     };
     let mut mock = MockConfigReader::new();
     mock.expect_read_config()
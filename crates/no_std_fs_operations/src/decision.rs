@@ -0,0 +1,200 @@
+//! Pure decision logic for `no_std_fs_operations`.
+//!
+//! The driver describes each import, type, or call resolving to an item by
+//! the item's fully qualified path, the crate defining it, how it is used,
+//! and any string-literal path the call receives. [`NoStdFsOperationsDecision`]
+//! matches the item against the same [`FilesystemModules`] and applies the
+//! same crate exclusions and literal-path [`Allowances`]. Findings use the
+//! usage category as their reason and name the item as their subject.
+//! Allowed wrapper crates and root types are left to the driver.
+
+use std::collections::HashSet;
+
+use whitaker::config::BehaviourVersion;
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::NoStdFsConfig;
+use crate::allowance::Allowances;
+use crate::driver::LINT_NAME;
+use crate::usage::{ASYNC_FILESYSTEM_MODULES, FilesystemModules, UsageCategory};
+
+/// The facts about one filesystem usage that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FsUsageProjection {
+    /// The name of the crate being checked.
+    pub crate_name: String,
+    /// The item's fully qualified path, such as `std::fs::read`.
+    pub item: String,
+    /// The name of the crate defining the item.
+    pub defining_crate: String,
+    /// How the item is used: `import`, `type`, or `call`.
+    pub category: String,
+    /// The string-literal path passed to the call, if any.
+    pub literal_path: Option<String>,
+}
+
+impl FsUsageProjection {
+    fn category(&self) -> Option<UsageCategory> {
+        [
+            UsageCategory::Import,
+            UsageCategory::Type,
+            UsageCategory::Call,
+        ]
+        .into_iter()
+        .find(|category| category.as_str() == self.category)
+    }
+}
+
+/// Reports ambient filesystem access outside the team's sanctioned
+/// wrappers.
+///
+/// # Examples
+///
+/// ```ignore
+/// let usage = FsUsageProjection {
+///     crate_name: "app".to_owned(),
+///     item: "std::fs::read".to_owned(),
+///     defining_crate: "std".to_owned(),
+///     category: "call".to_owned(),
+///     literal_path: None,
+/// };
+/// assert!(NoStdFsOperationsDecision::default().decide(&usage).is_some());
+/// ```
+#[derive(Debug, Default)]
+pub struct NoStdFsOperationsDecision {
+    excluded_crates: HashSet<String>,
+    modules: FilesystemModules,
+    allowances: Allowances,
+}
+
+impl NoStdFsOperationsDecision {
+    /// Creates a decision applying `config` at `behaviour`.
+    #[must_use]
+    pub fn new(config: &NoStdFsConfig, behaviour: BehaviourVersion) -> Self {
+        Self {
+            excluded_crates: config.excluded_crates.clone(),
+            modules: config.filesystem_modules(behaviour.enables(ASYNC_FILESYSTEM_MODULES)),
+            allowances: Allowances::new(config),
+        }
+    }
+}
+
+impl LintDecision for NoStdFsOperationsDecision {
+    type Projection = FsUsageProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, usage: &FsUsageProjection) -> Option<Finding> {
+        if self.excluded_crates.contains(&usage.crate_name) {
+            return None;
+        }
+        let category = usage.category()?;
+        let module = self.modules.module_for(&usage.item)?;
+        if module.segments().first() != Some(&usage.defining_crate) {
+            return None;
+        }
+        if usage
+            .literal_path
+            .as_deref()
+            .is_some_and(|path| self.allowances.allows_literal_path(path))
+        {
+            return None;
+        }
+        Some(Finding::new(LINT_NAME, category.as_str()).with_subject(usage.item.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<FsUsageProjection>> {
+        let usage = |item: &str, defining_crate: &str, category: &str| FsUsageProjection {
+            crate_name: "app".to_owned(),
+            item: item.to_owned(),
+            defining_crate: defining_crate.to_owned(),
+            category: category.to_owned(),
+            literal_path: None,
+        };
+        let excluded = self.excluded_crates.iter().next().map(|name| {
+            let mut read = usage("std::fs::read", "std", "call");
+            read.crate_name.clone_from(name);
+            DecisionFixture::clean("excluded crate", read)
+        });
+        let mut fixtures = vec![
+            DecisionFixture::flagged("import", usage("std::fs::File", "std", "import"), "import"),
+            DecisionFixture::flagged("call", usage("std::fs::read", "std", "call"), "call"),
+            DecisionFixture::clean(
+                "capability API",
+                usage("cap_std::fs::Dir", "cap_std", "type"),
+            ),
+            DecisionFixture::clean(
+                "local module named std",
+                usage("std::fs::read", "app", "call"),
+            ),
+        ];
+        fixtures.extend(excluded);
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    fn read(literal_path: &str) -> FsUsageProjection {
+        FsUsageProjection {
+            crate_name: "app".to_owned(),
+            item: "std::fs::read".to_owned(),
+            defining_crate: "std".to_owned(),
+            category: "call".to_owned(),
+            literal_path: Some(literal_path.to_owned()),
+        }
+    }
+
+    #[rstest]
+    #[case::under_prefix("fixtures/input.txt", false)]
+    #[case::climbing_out("fixtures/../secrets.txt", true)]
+    #[case::elsewhere("/etc/passwd", true)]
+    fn literal_paths_honour_allowed_prefixes(#[case] path: &str, #[case] flagged: bool) {
+        let config = NoStdFsConfig {
+            allowed_path_prefixes: vec!["fixtures".to_owned()],
+            ..NoStdFsConfig::default()
+        };
+        let decision = NoStdFsOperationsDecision::new(&config, BehaviourVersion::DEFAULT);
+
+        assert_eq!(decision.decide(&read(path)).is_some(), flagged);
+    }
+
+    #[rstest]
+    fn async_modules_follow_the_behaviour_version() {
+        let open = FsUsageProjection {
+            crate_name: "app".to_owned(),
+            item: "tokio::fs::File".to_owned(),
+            defining_crate: "tokio".to_owned(),
+            category: "type".to_owned(),
+            literal_path: None,
+        };
+        let config = NoStdFsConfig::default();
+
+        assert_eq!(NoStdFsOperationsDecision::default().decide(&open), None);
+        assert!(
+            NoStdFsOperationsDecision::new(&config, BehaviourVersion::new(2))
+                .decide(&open)
+                .is_some()
+        );
+    }
+
+    #[rstest]
+    #[case::defaults(NoStdFsConfig::default(), 4)]
+    #[case::excluded_crate(
+        NoStdFsConfig {
+            excluded_crates: HashSet::from(["cli".to_owned()]),
+            ..NoStdFsConfig::default()
+        },
+        5
+    )]
+    fn decision_conforms(#[case] config: NoStdFsConfig, #[case] fixtures: usize) {
+        let decision = NoStdFsOperationsDecision::new(&config, BehaviourVersion::DEFAULT);
+        assert_eq!(check_conformance(&decision), Ok(fixtures));
+    }
+}
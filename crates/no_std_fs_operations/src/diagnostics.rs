@@ -1,7 +1,9 @@
 //! Localized diagnostics for the `no_std_fs_operations` lint.
 
 use crate::NO_STD_FS_OPERATIONS;
+use crate::injected::InjectedHint;
 use crate::usage::StdFsUsage;
+use rustc_lint::errors::Applicability;
 use rustc_lint::{LateContext, LintContext};
 use rustc_span::Span;
 use std::borrow::Cow;
@@ -12,29 +14,34 @@ use whitaker_common::i18n::{
 #[cfg(test)]
 use whitaker_common::i18n::{BundleLookup, I18nError, resolve_message_set};
 
-/// Emit a diagnostic for a detected `std::fs` usage.
-pub(crate) fn emit_diagnostic(
-    cx: &LateContext<'_>,
-    span: Span,
-    usage: StdFsUsage,
-    localizer: &Localizer,
-) {
-    let mut args: Arguments<'static> = Arguments::default();
-    args.insert(
-        Cow::Borrowed("operation"),
-        FluentValue::from(usage.operation().to_string()),
-    );
+/// A detected filesystem usage and, when configured, the injected trait to
+/// point at instead.
+pub(crate) struct Finding<'a> {
+    pub(crate) span: Span,
+    pub(crate) usage: StdFsUsage,
+    pub(crate) injected: Option<InjectedHint<'a>>,
+}
 
-    let fallback_operation = usage.operation().to_string();
+/// Emit a diagnostic for a detected filesystem usage.
+pub(crate) fn emit_diagnostic(cx: &LateContext<'_>, finding: &Finding<'_>, localizer: &Localizer) {
+    let Finding {
+        span,
+        ref usage,
+        ref injected,
+    } = *finding;
+    let args = message_args(usage.operation(), usage.module());
     let resolution = MessageResolution {
         lint_name: "no_std_fs_operations",
         key: MESSAGE_KEY,
         args: &args,
     };
 
-    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, move || {
-        fallback_messages(&fallback_operation)
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(usage.operation(), usage.module())
     });
+    let injected = injected
+        .as_ref()
+        .map(|hint| injected_guidance(localizer, usage, hint));
 
     cx.emit_span_lint(
         NO_STD_FS_OPERATIONS,
@@ -43,23 +50,92 @@ pub(crate) fn emit_diagnostic(
             lint.primary_message(sanitize_message(messages.primary().to_string()));
             lint.note(sanitize_message(messages.note().to_string()));
             lint.help(sanitize_message(messages.help().to_string()));
+            match injected {
+                Some(InjectedGuidance::Suggestion {
+                    span,
+                    label,
+                    replacement,
+                }) => {
+                    lint.span_suggestion(span, label, replacement, Applicability::MaybeIncorrect);
+                }
+                Some(InjectedGuidance::Help(help)) => {
+                    lint.help(help);
+                }
+                None => {}
+            }
         }),
     );
 }
 
+/// How a diagnostic points at the injected filesystem trait.
+enum InjectedGuidance {
+    /// A rewrite of the call through the trait.
+    Suggestion {
+        span: Span,
+        label: String,
+        replacement: String,
+    },
+    /// A help message naming the trait, for usages without a rewrite.
+    Help(String),
+}
+
+fn injected_guidance(
+    localizer: &Localizer,
+    usage: &StdFsUsage,
+    hint: &InjectedHint<'_>,
+) -> InjectedGuidance {
+    let function = hint
+        .rewrite
+        .as_ref()
+        .map_or("", |rewrite| rewrite.function.as_str());
+    let args = injected_args(usage.module(), hint.trait_path, function);
+    let (attribute, fallback) = match hint.rewrite {
+        Some(_) => ("suggestion", fallback_suggestion(hint.trait_path, function)),
+        None => (
+            "trait_help",
+            fallback_trait_help(usage.module(), hint.trait_path),
+        ),
+    };
+    let text = sanitize_message(
+        localizer
+            .attribute_with_args("no_std_fs_operations", attribute, &args)
+            .unwrap_or(fallback),
+    );
+    match &hint.rewrite {
+        Some(rewrite) => InjectedGuidance::Suggestion {
+            span: rewrite.span,
+            label: text,
+            replacement: rewrite.replacement.clone(),
+        },
+        None => InjectedGuidance::Help(text),
+    }
+}
+
 const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("no_std_fs_operations");
 
 pub(crate) type StdFsMessages = DiagnosticMessageSet;
 
-fn fallback_messages(operation: &str) -> StdFsMessages {
+fn message_args(operation: &str, module: &str) -> Arguments<'static> {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("operation"),
+        FluentValue::from(operation.to_string()),
+    );
+    args.insert(
+        Cow::Borrowed("module"),
+        FluentValue::from(module.to_string()),
+    );
+    args
+}
+
+fn fallback_messages(operation: &str, module: &str) -> StdFsMessages {
     let primary = format!(
-        "Avoid using std::fs operation `{operation}`; require capability-bearing handles instead."
+        "Avoid using {module} operation `{operation}`; require capability-bearing handles instead."
+    );
+    let note = format!(
+        "{module} reads the ambient working directory, so it bypasses the capability model \
+         enforced by cap-std and camino."
     );
-    let note = concat!(
-        "std::fs reads the ambient working directory, ",
-        "so it bypasses the capability model enforced by cap-std and camino."
-    )
-    .to_string();
     let help = concat!(
         "Pass `cap_std::fs::Dir` handles and camino::Utf8Path/Utf8PathBuf arguments down to the call ",
         "so only explicit capabilities touch the filesystem."
@@ -68,6 +144,34 @@ fn fallback_messages(operation: &str) -> StdFsMessages {
     DiagnosticMessageSet::new(primary, note, help)
 }
 
+fn injected_args(module: &str, filesystem_trait: &str, function: &str) -> Arguments<'static> {
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("module"),
+        FluentValue::from(module.to_string()),
+    );
+    args.insert(
+        Cow::Borrowed("filesystem_trait"),
+        FluentValue::from(filesystem_trait.to_string()),
+    );
+    args.insert(
+        Cow::Borrowed("function"),
+        FluentValue::from(function.to_string()),
+    );
+    args
+}
+
+fn fallback_suggestion(filesystem_trait: &str, function: &str) -> String {
+    format!("call `{function}` through the injected `{filesystem_trait}`")
+}
+
+fn fallback_trait_help(module: &str, filesystem_trait: &str) -> String {
+    format!(
+        "Accept an implementation of `{filesystem_trait}` and perform this {module} operation \
+         through it."
+    )
+}
+
 fn sanitize_message(text: String) -> String {
     text.chars()
         .filter(|ch| !matches!(ch, '\u{2068}' | '\u{2069}'))
@@ -78,18 +182,55 @@ fn sanitize_message(text: String) -> String {
 pub(crate) fn localised_messages(
     lookup: &impl BundleLookup,
     operation: &str,
+    module: &str,
 ) -> Result<StdFsMessages, I18nError> {
-    let mut args: Arguments<'static> = Arguments::default();
-    args.insert(
-        Cow::Borrowed("operation"),
-        FluentValue::from(operation.to_string()),
-    );
+    let args = message_args(operation, module);
     resolve_message_set(lookup, MESSAGE_KEY, &args)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::sanitize_message;
+    use super::{fallback_suggestion, fallback_trait_help, injected_args, sanitize_message};
+    use rstest::rstest;
+    use whitaker_common::i18n::Localizer;
+
+    const TRAIT: &str = "my_app::fs::Filesystem";
+
+    fn attribute(locale: &str, attribute: &str) -> String {
+        let args = injected_args("std::fs", TRAIT, "read_to_string");
+        sanitize_message(
+            Localizer::new(Some(locale))
+                .attribute_with_args("no_std_fs_operations", attribute, &args)
+                .unwrap_or_else(|error| panic!("`{locale}` {attribute} should resolve: {error}")),
+        )
+    }
+
+    #[rstest]
+    fn english_injected_guidance_matches_the_fallback() {
+        assert_eq!(
+            attribute("en-GB", "suggestion"),
+            fallback_suggestion(TRAIT, "read_to_string")
+        );
+        assert_eq!(
+            attribute("en-GB", "trait_help"),
+            fallback_trait_help("std::fs", TRAIT)
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn injected_guidance_translations_name_the_trait(#[case] locale: &str) {
+        for name in ["suggestion", "trait_help"] {
+            let text = attribute(locale, name);
+
+            assert!(text.contains(TRAIT), "{locale} {name}: {text}");
+        }
+        assert_ne!(
+            attribute(locale, "trait_help"),
+            fallback_trait_help("std::fs", TRAIT)
+        );
+    }
 
     #[test]
     fn removes_isolation_marks() {
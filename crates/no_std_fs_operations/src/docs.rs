@@ -0,0 +1,53 @@
+//! Embedded documentation for the `no_std_fs_operations` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_std_fs_operations`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_std_fs_operations",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Forbids ambient `std::fs` operations, and from behaviour version 2 their `tokio::fs` and `async_std::fs` counterparts.",
+    rationale: "Direct filesystem access bypasses capability-based handles, making code harder to sandbox and test; pass a `cap_std` directory instead.",
+    bad_example: "let text = std::fs::read_to_string(\"config.toml\")?;",
+    good_example: "let text = dir.read_to_string(\"config.toml\")?;",
+    config: &[
+        ConfigKeyDocs {
+            key: "excluded_crates",
+            default: "[]",
+            description: "Crates allowed to use `std::fs` without diagnostics.",
+        },
+        ConfigKeyDocs {
+            key: "allowed_crates",
+            default: "[]",
+            description: "Wrapper crates whose functions may receive `std::fs` values and whose macros may expand to `std::fs` calls.",
+        },
+        ConfigKeyDocs {
+            key: "allowed_path_prefixes",
+            default: "[]",
+            description: "Prefixes under which string-literal paths may be passed to `std::fs` calls.",
+        },
+        ConfigKeyDocs {
+            key: "allowed_path_roots",
+            default: "[]",
+            description: "Fully qualified types, such as `tempfile::TempDir`, whose values `std::fs` calls may target.",
+        },
+        ConfigKeyDocs {
+            key: "modules",
+            default: "[\"std::fs\"]",
+            description: "Modules whose items count as ambient filesystem access; setting it replaces the defaults. Behaviour version 2 adds `tokio::fs` and `async_std::fs` to the default.",
+        },
+        ConfigKeyDocs {
+            key: "filesystem_trait",
+            default: "unset",
+            description: "Fully qualified injected filesystem trait that diagnostics point at, with a rewrite for module function calls.",
+        },
+        ConfigKeyDocs {
+            key: "filesystem_receiver",
+            default: "\"fs\"",
+            description: "Value that rewritten calls are made on, such as `self.fs`.",
+        },
+    ],
+};
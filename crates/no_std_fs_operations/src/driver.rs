@@ -1,108 +1,43 @@
 //! Lint crate enforcing capability-based filesystem access by forbidding
-//! `std::fs` operations.
+//! `std::fs` operations and their async counterparts in `tokio::fs` and
+//! `async_std::fs`.
 
-use crate::diagnostics::emit_diagnostic;
+use crate::allowance::Allowances;
+use crate::config::load_configuration;
+use crate::diagnostics::{Finding, emit_diagnostic};
+use crate::injected::InjectedFilesystem;
 use crate::usage::{
-    StdFsUsage, UsageCategory, classify_def_id, classify_qpath, classify_res, label_is_std_fs,
+    ASYNC_FILESYSTEM_MODULES, Classifier, FilesystemModules, StdFsUsage, UsageCategory,
 };
-use log::{info, warn};
+use log::info;
 use rustc_hir as hir;
 use rustc_hir::AmbigArg;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
-use rustc_span::{Span, sym};
-use serde::Deserialize;
-use std::collections::HashSet;
+use rustc_span::Span;
 use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
 use whitaker_common::i18n::Localizer;
 use whitaker_common::i18n::get_localizer_for_lint;
 
-const LINT_NAME: &str = "no_std_fs_operations";
+pub(crate) const LINT_NAME: &str = "no_std_fs_operations";
 
-/// Configuration for the `no_std_fs_operations` lint.
-///
-/// # TOML Configuration
-///
-/// In `dylint.toml` at your workspace root:
-///
-/// ```toml
-/// [no_std_fs_operations]
-/// excluded_crates = ["my_cli_app", "test_utilities"]
-/// ```
-///
-/// Use Rust crate names (underscores), not Cargo package names (hyphens).
-///
-/// # Strict Validation
-///
-/// This configuration uses `deny_unknown_fields`, meaning any unrecognized
-/// key (such as a typo like `excluded_crate` instead of `excluded_crates`)
-/// will cause configuration parsing to fail. When parsing fails, the lint
-/// falls back to defaults and logs a warning. If exclusions don't work as
-/// expected, check the logs for parse errors.
-///
-/// # Examples
-///
-/// ```rust
-/// # use no_std_fs_operations::driver::NoStdFsConfig;
-/// # use std::collections::HashSet;
-/// let config = NoStdFsConfig {
-///     excluded_crates: HashSet::from(["my_cli_app".to_owned()]),
-/// };
-/// assert!(config.is_excluded("my_cli_app"));
-/// assert!(!config.is_excluded("other_crate"));
-/// ```
-#[derive(Clone, Debug, Default, Deserialize)]
-#[serde(default, deny_unknown_fields)]
-pub struct NoStdFsConfig {
-    /// Crate names excluded from the lint. These crates are allowed to use
-    /// `std::fs` operations without triggering diagnostics.
-    #[serde(deserialize_with = "deserialize_excluded_crates")]
-    pub excluded_crates: HashSet<String>,
-}
-
-fn deserialize_excluded_crates<'de, D>(deserializer: D) -> Result<HashSet<String>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let vec: Vec<String> = Vec::deserialize(deserializer)?;
-    Ok(vec.into_iter().collect())
-}
-
-impl NoStdFsConfig {
-    /// Check if the given crate name is excluded from the lint.
-    ///
-    /// Returns `true` if `crate_name` appears in the `excluded_crates` set.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # use no_std_fs_operations::driver::NoStdFsConfig;
-    /// # use std::collections::HashSet;
-    /// let config = NoStdFsConfig {
-    ///     excluded_crates: HashSet::from(["my_cli".to_owned(), "test_utils".to_owned()]),
-    /// };
-    ///
-    /// assert!(config.is_excluded("my_cli"));
-    /// assert!(!config.is_excluded("other_crate"));
-    /// ```
-    #[must_use]
-    pub fn is_excluded(&self, crate_name: &str) -> bool {
-        self.excluded_crates.contains(crate_name)
-    }
+#[derive(Default)]
+pub struct NoStdFsOperations {
+    state: CrateScopedState<CrateState>,
 }
 
-pub struct NoStdFsOperations {
+/// Localization, exclusion status, filesystem modules, allowances, and the
+/// injected filesystem trait for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    findings: FindingLimit,
     localizer: Localizer,
     excluded: bool,
-}
-
-impl Default for NoStdFsOperations {
-    fn default() -> Self {
-        Self {
-            localizer: Localizer::new(None),
-            excluded: false,
-        }
-    }
+    modules: FilesystemModules,
+    allowances: Allowances,
+    injected: Option<InjectedFilesystem>,
 }
 
 dylint_linting::impl_late_lint! {
@@ -115,15 +50,24 @@ dylint_linting::impl_late_lint! {
 impl<'tcx> LateLintPass<'tcx> for NoStdFsOperations {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
-
         let config = load_configuration();
         let crate_name_sym = cx.tcx.crate_name(rustc_hir::def_id::LOCAL_CRATE);
         let crate_name = crate_name_sym.as_str();
 
-        self.excluded = config.is_excluded(crate_name);
-
-        if self.excluded {
+        self.state.reset(CrateState {
+            findings: FindingLimit::new(NO_STD_FS_OPERATIONS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+            excluded: config.is_excluded(crate_name),
+            modules: config.filesystem_modules(
+                shared_config
+                    .behaviour_version(LINT_NAME)
+                    .enables(ASYNC_FILESYSTEM_MODULES),
+            ),
+            allowances: Allowances::new(&config),
+            injected: InjectedFilesystem::new(&config),
+        });
+
+        if self.state.excluded {
             info!(
                 target: LINT_NAME,
                 "crate `{crate_name}` is excluded from no_std_fs_operations lint"
@@ -131,14 +75,18 @@ impl<'tcx> LateLintPass<'tcx> for NoStdFsOperations {
         }
     }
 
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
         if self.should_skip() {
             return;
         }
         if let hir::ItemKind::Use(path, ..) = item.kind {
             for res in path.res.present_items() {
-                let usage = classify_res(cx, res, UsageCategory::Import);
-                self.emit_optional(cx, path.span, usage);
+                let usage = self.classifier(cx).res(res, UsageCategory::Import);
+                self.emit_optional(cx, Site::span(path.span), usage);
             }
         }
     }
@@ -147,29 +95,26 @@ impl<'tcx> LateLintPass<'tcx> for NoStdFsOperations {
         if self.should_skip() {
             return;
         }
-        match &expr.kind {
+        let usage = match &expr.kind {
             hir::ExprKind::Path(qpath) => {
-                let usage = classify_qpath(cx, qpath, expr.hir_id, UsageCategory::Call);
-                self.emit_optional(cx, expr.span, usage);
+                self.classifier(cx)
+                    .qpath(qpath, expr.hir_id, UsageCategory::Call)
             }
             hir::ExprKind::Struct(qpath, ..) => {
-                let usage = classify_qpath(cx, qpath, expr.hir_id, UsageCategory::Call);
-                self.emit_optional(cx, expr.span, usage);
-            }
-            hir::ExprKind::MethodCall(segment, receiver, ..) => {
-                let mut usage = cx
-                    .typeck_results()
-                    .type_dependent_def_id(expr.hir_id)
-                    .and_then(|def_id| classify_def_id(cx, def_id, UsageCategory::Call));
-
-                if usage.is_none() {
-                    usage = self.receiver_usage_for_method(cx, receiver, segment.ident.as_str());
-                }
-
-                self.emit_optional(cx, expr.span, usage);
+                self.classifier(cx)
+                    .qpath(qpath, expr.hir_id, UsageCategory::Call)
             }
-            _ => {}
+            hir::ExprKind::MethodCall(segment, receiver, ..) => cx
+                .typeck_results()
+                .type_dependent_def_id(expr.hir_id)
+                .and_then(|def_id| self.classifier(cx).def_id(def_id, UsageCategory::Call))
+                .or_else(|| self.receiver_usage_for_method(cx, receiver, segment.ident.as_str())),
+            _ => None,
+        };
+        if usage.is_some() && self.state.allowances.allows_expr(cx, expr) {
+            return;
         }
+        self.emit_optional(cx, Site::expr(expr), usage);
     }
 
     fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx hir::Ty<'tcx, AmbigArg>) {
@@ -177,8 +122,38 @@ impl<'tcx> LateLintPass<'tcx> for NoStdFsOperations {
             return;
         }
         if let hir::TyKind::Path(qpath) = &ty.kind {
-            let usage = classify_qpath(cx, qpath, ty.hir_id, UsageCategory::Type);
-            self.emit_optional(cx, ty.span, usage);
+            let usage = self
+                .classifier(cx)
+                .qpath(qpath, ty.hir_id, UsageCategory::Type);
+            // A type segment such as `File` in `File::open(path)` shares the
+            // allowance of the call it names.
+            if usage.is_some()
+                && let hir::Node::Expr(site) = cx.tcx.parent_hir_node(ty.hir_id)
+                && self.state.allowances.allows_expr(cx, site)
+            {
+                return;
+            }
+            self.emit_optional(cx, Site::span(ty.span), usage);
+        }
+    }
+}
+
+/// Where a usage was found, and the expression there when it is one.
+#[derive(Clone, Copy)]
+struct Site<'hir> {
+    span: Span,
+    expr: Option<&'hir hir::Expr<'hir>>,
+}
+
+impl<'hir> Site<'hir> {
+    fn span(span: Span) -> Self {
+        Self { span, expr: None }
+    }
+
+    fn expr(expr: &'hir hir::Expr<'hir>) -> Self {
+        Self {
+            span: expr.span,
+            expr: Some(expr),
         }
     }
 }
@@ -187,20 +162,39 @@ impl NoStdFsOperations {
     /// Centralizes exclusion logic for all lint pass methods.
     #[inline]
     fn should_skip(&self) -> bool {
-        self.excluded
+        self.state.excluded
     }
 
-    fn emit_optional(&self, cx: &LateContext<'_>, span: Span, usage: Option<StdFsUsage>) {
-        if self.should_skip() {
+    fn emit_optional(&self, cx: &LateContext<'_>, site: Site<'_>, usage: Option<StdFsUsage>) {
+        if self.should_skip() || self.state.allowances.allows_expansion(cx, site.span) {
             return;
         }
         if let Some(usage) = usage {
-            self.emit(cx, span, usage);
+            self.emit(cx, site, usage);
+        }
+    }
+
+    fn emit(&self, cx: &LateContext<'_>, site: Site<'_>, usage: StdFsUsage) {
+        let state = &*self.state;
+        if state
+            .findings
+            .admit(cx, cx.last_node_with_lint_attrs, site.span)
+        {
+            let injected = state
+                .injected
+                .as_ref()
+                .map(|filesystem| filesystem.hint(cx, site.expr, &usage));
+            let finding = Finding {
+                span: site.span,
+                usage,
+                injected,
+            };
+            emit_diagnostic(cx, &finding, &state.localizer);
         }
     }
 
-    fn emit(&self, cx: &LateContext<'_>, span: Span, usage: StdFsUsage) {
-        emit_diagnostic(cx, span, usage, &self.localizer);
+    fn classifier<'a, 'tcx>(&'a self, cx: &'a LateContext<'tcx>) -> Classifier<'a, 'tcx> {
+        Classifier::new(cx, &self.state.modules)
     }
 
     fn receiver_usage_for_method(
@@ -215,115 +209,12 @@ impl NoStdFsOperations {
             return None;
         };
 
-        let def_id = adt.did();
-        if cx.tcx.crate_name(def_id.krate) != sym::std {
-            return None;
-        }
-
-        let label = cx.tcx.def_path_str(def_id);
-        if !label_is_std_fs(&label) {
-            return None;
-        }
-
-        let operation = format!("{label}::{method}");
-        Some(StdFsUsage::new(operation, UsageCategory::Call))
+        let usage = self.classifier(cx).def_id(adt.did(), UsageCategory::Call)?;
+        let operation = format!("{}::{method}", usage.operation());
+        Some(StdFsUsage::new(
+            operation,
+            usage.module().to_owned(),
+            UsageCategory::Call,
+        ))
     }
 }
-
-/// Trait for loading lint configuration, enabling dependency injection for tests.
-///
-/// # Return Values
-///
-/// - `Ok(Some(config))` - Configuration section found and parsed successfully
-/// - `Ok(None)` - No configuration file or section present
-/// - `Err(...)` - Configuration file exists but parsing failed
-///
-/// # Example Usage (in tests)
-///
-/// ```text
-/// let mut mock = MockConfigReader::new();
-/// mock.expect_read_config()
-///     .returning(|_| Ok(Some(NoStdFsConfig {
-///         excluded_crates: vec!["my_crate".to_owned()],
-///     })));
-///
-/// let config = load_configuration_with_reader(&mock);
-/// assert!(config.is_excluded("my_crate"));
-/// ```
-#[cfg_attr(test, mockall::automock)]
-pub(crate) trait ConfigReader {
-    /// Read configuration for the given lint name.
-    ///
-    /// Returns `Ok(Some(config))` if found, `Ok(None)` if not present, or
-    /// `Err` if parsing fails.
-    fn read_config(
-        &self,
-        lint_name: &str,
-    ) -> Result<Option<NoStdFsConfig>, Box<dyn std::error::Error + Send + Sync + 'static>>;
-}
-
-/// Production implementation that reads from `dylint.toml` via `dylint_linting::config`.
-///
-/// This reader delegates to `dylint_linting::config` to locate and parse
-/// the `dylint.toml` configuration file in the workspace root.
-pub(crate) struct DylintConfigReader;
-
-impl ConfigReader for DylintConfigReader {
-    fn read_config(
-        &self,
-        lint_name: &str,
-    ) -> Result<Option<NoStdFsConfig>, Box<dyn std::error::Error + Send + Sync + 'static>> {
-        type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
-        dylint_linting::config::<NoStdFsConfig>(lint_name).map_err(|e| -> BoxError { Box::new(e) })
-    }
-}
-
-/// Load lint configuration using the provided reader.
-///
-/// Returns the default configuration when:
-/// - No configuration file exists
-/// - No `[no_std_fs_operations]` section is present
-/// - The configuration fails to parse (logged at `warn` level)
-///
-/// # Example Return Cases
-///
-/// ```text
-/// // Case 1: Configuration present
-/// reader.read_config("lint") => Ok(Some(config))
-/// load_configuration_with_reader(&reader) => config
-///
-/// // Case 2: No configuration section
-/// reader.read_config("lint") => Ok(None)
-/// load_configuration_with_reader(&reader) => NoStdFsConfig::default()
-///
-/// // Case 3: Parse error
-/// reader.read_config("lint") => Err(...)
-/// load_configuration_with_reader(&reader) => NoStdFsConfig::default() + logs warning
-/// ```
-fn load_configuration_with_reader(reader: &dyn ConfigReader) -> NoStdFsConfig {
-    match reader.read_config(LINT_NAME) {
-        Ok(Some(config)) => config,
-        Ok(None) => NoStdFsConfig::default(),
-        Err(error) => {
-            warn!(
-                target: LINT_NAME,
-                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
-            );
-            NoStdFsConfig::default()
-        }
-    }
-}
-
-/// Load lint configuration from `dylint.toml`.
-///
-/// Returns the default configuration when:
-/// - No configuration file exists
-/// - No `[no_std_fs_operations]` section is present
-/// - The configuration fails to parse (logged at `warn` level)
-fn load_configuration() -> NoStdFsConfig {
-    load_configuration_with_reader(&DylintConfigReader)
-}
-
-#[cfg(test)]
-#[path = "driver_tests.rs"]
-mod tests;
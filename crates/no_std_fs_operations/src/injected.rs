@@ -0,0 +1,119 @@
+//! Suggestions that route flagged operations through an injected filesystem
+//! trait.
+//!
+//! Teams that inject a filesystem abstraction can name its trait in
+//! `filesystem_trait`. Every diagnostic then points at the trait, and a call
+//! to a free function of a filesystem module, such as
+//! `std::fs::read_to_string(path)`, gets a suggestion that rewrites it as a
+//! method call on the configured receiver: `fs.read_to_string(path)`. Other
+//! usages, such as `File::open` or imports, have no one-to-one rewrite and get
+//! the trait named in a help message instead.
+
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::Span;
+
+use crate::config::NoStdFsConfig;
+use crate::usage::StdFsUsage;
+
+/// The injected filesystem trait a crate's configuration names.
+#[derive(Clone, Debug)]
+pub(crate) struct InjectedFilesystem {
+    trait_path: String,
+    receiver: String,
+}
+
+impl InjectedFilesystem {
+    /// Returns the configured trait, or `None` when `filesystem_trait` is
+    /// unset or empty.
+    #[must_use]
+    pub(crate) fn new(config: &NoStdFsConfig) -> Option<Self> {
+        let trait_path = config.filesystem_trait.as_deref()?.trim();
+        (!trait_path.is_empty()).then(|| Self {
+            trait_path: trait_path.to_owned(),
+            receiver: config.filesystem_receiver.clone(),
+        })
+    }
+
+    /// Returns the hint for `usage`, rewriting the call `site` is the callee
+    /// of when the usage is a free function of its module.
+    pub(crate) fn hint<'a>(
+        &'a self,
+        cx: &LateContext<'_>,
+        site: Option<&hir::Expr<'_>>,
+        usage: &StdFsUsage,
+    ) -> InjectedHint<'a> {
+        InjectedHint {
+            trait_path: &self.trait_path,
+            rewrite: site.and_then(|callee| self.call_rewrite(cx, callee, usage)),
+        }
+    }
+
+    fn call_rewrite(
+        &self,
+        cx: &LateContext<'_>,
+        callee: &hir::Expr<'_>,
+        usage: &StdFsUsage,
+    ) -> Option<CallRewrite> {
+        let function = module_function(usage.operation(), usage.module())?;
+        let hir::Node::Expr(call) = cx.tcx.parent_hir_node(callee.hir_id) else {
+            return None;
+        };
+        let hir::ExprKind::Call(target, args) = call.kind else {
+            return None;
+        };
+        if target.hir_id != callee.hir_id || call.span.from_expansion() {
+            return None;
+        }
+        let source_map = cx.sess().source_map();
+        let args = args
+            .iter()
+            .map(|arg| source_map.span_to_snippet(arg.span).ok())
+            .collect::<Option<Vec<_>>>()?;
+        Some(CallRewrite {
+            span: call.span,
+            replacement: rewrite_call(&self.receiver, function, &args),
+            function: function.to_owned(),
+        })
+    }
+}
+
+/// The injected trait to name in one diagnostic.
+#[derive(Clone, Debug)]
+pub(crate) struct InjectedHint<'a> {
+    pub(crate) trait_path: &'a str,
+    pub(crate) rewrite: Option<CallRewrite>,
+}
+
+/// A call rewritten as a method call through the injected trait.
+#[derive(Clone, Debug)]
+pub(crate) struct CallRewrite {
+    pub(crate) span: Span,
+    pub(crate) function: String,
+    pub(crate) replacement: String,
+}
+
+/// Returns the function name when `operation` is a free function declared
+/// directly in `module`, such as `read` in `std::fs::read`.
+#[must_use]
+pub(crate) fn module_function<'a>(operation: &'a str, module: &str) -> Option<&'a str> {
+    let function = operation.strip_prefix(module)?.strip_prefix("::")?;
+    let is_function = function.starts_with(|first: char| first.is_lowercase() || first == '_')
+        && !function.contains("::");
+    is_function.then_some(function)
+}
+
+/// Writes `function(args)` as a method call on `receiver`.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(rewrite_call("fs", "read", &["path".into()]), "fs.read(path)");
+/// ```
+#[must_use]
+pub(crate) fn rewrite_call(receiver: &str, function: &str, args: &[String]) -> String {
+    format!("{receiver}.{function}({})", args.join(", "))
+}
+
+#[cfg(test)]
+mod tests;
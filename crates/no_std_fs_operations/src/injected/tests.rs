@@ -0,0 +1,42 @@
+//! Tests for configuring the injected trait and rewriting module functions.
+use super::{InjectedFilesystem, module_function, rewrite_call};
+use crate::config::NoStdFsConfig;
+use rstest::rstest;
+
+#[rstest]
+#[case("std::fs::read_to_string", "std::fs", Some("read_to_string"))]
+#[case("tokio::fs::create_dir_all", "tokio::fs", Some("create_dir_all"))]
+#[case("std::fs::File::open", "std::fs", None)]
+#[case("std::fs::File", "std::fs", None)]
+#[case("std::fs::read", "tokio::fs", None)]
+#[case("std::fsx::read", "std::fs", None)]
+fn module_functions_are_free_functions_of_the_module(
+    #[case] operation: &str,
+    #[case] module: &str,
+    #[case] expected: Option<&str>,
+) {
+    assert_eq!(module_function(operation, module), expected);
+}
+
+#[rstest]
+#[case::no_args(&[], "fs.read_dir()")]
+#[case::one_arg(&["\"config.toml\""], "fs.read_dir(\"config.toml\")")]
+#[case::two_args(&["&path", "contents"], "fs.read_dir(&path, contents)")]
+fn rewritten_calls_keep_their_arguments(#[case] args: &[&str], #[case] expected: &str) {
+    let args: Vec<String> = args.iter().map(|&arg| arg.to_owned()).collect();
+
+    assert_eq!(rewrite_call("fs", "read_dir", &args), expected);
+}
+
+#[rstest]
+#[case::unset(None, false)]
+#[case::blank(Some("  "), false)]
+#[case::set(Some("my_app::fs::Filesystem"), true)]
+fn only_a_named_trait_is_injected(#[case] filesystem_trait: Option<&str>, #[case] expected: bool) {
+    let config = NoStdFsConfig {
+        filesystem_trait: filesystem_trait.map(str::to_owned),
+        ..NoStdFsConfig::default()
+    };
+
+    assert_eq!(InjectedFilesystem::new(&config).is_some(), expected);
+}
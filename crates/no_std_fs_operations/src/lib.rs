@@ -1,20 +1,40 @@
-//! Dylint crate implementing the `no_std_fs_operations` lint, which is only
-//! available when compiled with the `dylint-driver` feature enabled.
+//! Dylint crate implementing the `no_std_fs_operations` lint, which forbids
+//! ambient `std::fs` operations and, from behaviour version 2, their
+//! `tokio::fs` and `async_std::fs` counterparts. The lint is only available
+//! when compiled with the `dylint-driver` feature enabled.
 #![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
 
+#[cfg(feature = "dylint-driver")]
+mod allowance;
 #[cfg(all(feature = "dylint-driver", test))]
 mod behaviour;
 #[cfg(feature = "dylint-driver")]
+mod config;
+#[cfg(feature = "dylint-driver")]
+mod decision;
+#[cfg(feature = "dylint-driver")]
 mod diagnostics;
 #[cfg(feature = "dylint-driver")]
+mod docs;
+#[cfg(feature = "dylint-driver")]
 mod driver;
+#[cfg(feature = "dylint-driver")]
+mod injected;
 #[cfg(all(feature = "dylint-driver", test))]
 mod tests;
 #[cfg(feature = "dylint-driver")]
 mod usage;
+
+#[cfg(feature = "dylint-driver")]
+pub use config::NoStdFsConfig;
+#[cfg(feature = "dylint-driver")]
+pub use decision::{FsUsageProjection, NoStdFsOperationsDecision};
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
 
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
 #[cfg(not(feature = "dylint-driver"))]
 mod stub {
     #[expect(
@@ -1,18 +1,33 @@
-//! Classifies `std::fs` usages encountered by the lint into diagnostic inputs.
+//! Classifies filesystem usages encountered by the lint into diagnostic inputs.
+//!
+//! A usage is any item whose fully qualified path lies within one of the
+//! configured filesystem modules: `std::fs` by default, joined by `tokio::fs`
+//! and `async_std::fs` from behaviour version [`ASYNC_FILESYSTEM_MODULES`].
 
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::DefId;
 use rustc_lint::LateContext;
-use rustc_span::sym;
 use whitaker_common::SimplePath;
 
-/// Category describing how the `std::fs` item is being used.
+/// Behaviour version that adds the async runtimes' filesystem modules to the
+/// defaults.
+pub(crate) const ASYNC_FILESYSTEM_MODULES: u32 = 2;
+
+/// Modules whose items count as ambient filesystem access by default, before
+/// behaviour version [`ASYNC_FILESYSTEM_MODULES`].
+pub(crate) const LEGACY_MODULES: &[&str] = &["std::fs"];
+
+/// Modules whose items count as ambient filesystem access by default, from
+/// behaviour version [`ASYNC_FILESYSTEM_MODULES`].
+pub(crate) const DEFAULT_MODULES: &[&str] = &["std::fs", "tokio::fs", "async_std::fs"];
+
+/// Category describing how the filesystem item is being used.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum UsageCategory {
     /// `use std::fs::{..}` imports.
     Import,
-    /// Type positions referencing `std::fs` types (structs, aliases).
+    /// Type positions referencing filesystem types (structs, aliases).
     Type,
     /// Value-level calls, struct literals, or method invocations.
     Call,
@@ -20,7 +35,6 @@ pub enum UsageCategory {
 
 impl UsageCategory {
     /// Returns a stable &str identifier for use in tests and localization.
-    #[cfg(test)]
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -31,10 +45,11 @@ impl UsageCategory {
     }
 }
 
-/// Normalized view of a `std::fs` operation for diagnostics and tests.
+/// Normalized view of a filesystem operation for diagnostics and tests.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StdFsUsage {
     operation: String,
+    module: String,
     category: UsageCategory,
 }
 
@@ -46,12 +61,17 @@ impl StdFsUsage {
     ///
     /// ```ignore
     /// # use crate::usage::{StdFsUsage, UsageCategory};
-    /// let usage = StdFsUsage::new(String::from("std::fs::read"), UsageCategory::Call);
+    /// let usage = StdFsUsage::new(
+    ///     String::from("std::fs::read"),
+    ///     String::from("std::fs"),
+    ///     UsageCategory::Call,
+    /// );
     /// assert_eq!(usage.operation(), "std::fs::read");
     /// ```
-    pub fn new(operation: String, category: UsageCategory) -> Self {
+    pub fn new(operation: String, module: String, category: UsageCategory) -> Self {
         Self {
             operation,
+            module,
             category,
         }
     }
@@ -63,13 +83,24 @@ impl StdFsUsage {
     ///
     /// ```ignore
     /// # use crate::usage::{StdFsUsage, UsageCategory};
-    /// let usage = StdFsUsage::new(String::from("std::fs::remove_file"), UsageCategory::Call);
+    /// let usage = StdFsUsage::new(
+    ///     String::from("std::fs::remove_file"),
+    ///     String::from("std::fs"),
+    ///     UsageCategory::Call,
+    /// );
     /// assert_eq!(usage.operation(), "std::fs::remove_file");
     /// ```
     pub fn operation(&self) -> &str {
         &self.operation
     }
 
+    /// Returns the configured module the operation belongs to (e.g.,
+    /// `tokio::fs`).
+    #[must_use]
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
     /// Returns the usage category.
     #[cfg(test)]
     #[must_use]
@@ -78,108 +109,148 @@ impl StdFsUsage {
     }
 }
 
-/// Classify a resolved path (expression, type, import) into a usage record.
-#[must_use]
-///
-/// # Examples
-///
-/// ```ignore
-/// # use rustc_hir as hir;
-/// # use rustc_lint::LateContext;
-/// # use crate::usage::{classify_qpath, UsageCategory};
-/// # fn example<'tcx>(cx: &LateContext<'tcx>, qpath: &hir::QPath<'tcx>, hir_id: hir::HirId) {
-/// let _ = classify_qpath(cx, qpath, hir_id, UsageCategory::Call);
-/// # }
-/// ```
-pub fn classify_qpath(
-    cx: &LateContext<'_>,
-    qpath: &hir::QPath<'_>,
-    hir_id: hir::HirId,
-    category: UsageCategory,
-) -> Option<StdFsUsage> {
-    let res = cx.qpath_res(qpath, hir_id);
-    classify_res(cx, res, category)
-}
-
-/// Classify using a `Res` obtained from HIR traversal.
-#[must_use]
-///
-/// # Examples
-///
-/// ```ignore
-/// # use rustc_hir::def::Res;
-/// # use rustc_lint::LateContext;
-/// # use crate::usage::{classify_res, UsageCategory};
-/// # fn example<'tcx>(cx: &LateContext<'tcx>, res: Res) {
-/// let _ = classify_res(cx, res, UsageCategory::Type);
-/// # }
-/// ```
-pub fn classify_res(cx: &LateContext<'_>, res: Res, category: UsageCategory) -> Option<StdFsUsage> {
-    res.opt_def_id()
-        .and_then(|def_id| classify_def_id(cx, def_id, category))
+/// The configured modules whose items count as ambient filesystem access.
+#[derive(Clone, Debug)]
+pub(crate) struct FilesystemModules {
+    modules: Vec<SimplePath>,
 }
 
-/// Classify a `DefId` by inspecting its fully qualified path.
-#[must_use]
-///
-/// # Examples
-///
-/// ```ignore
-/// # use rustc_hir::def_id::DefId;
-/// # use rustc_lint::LateContext;
-/// # use crate::usage::{classify_def_id, UsageCategory};
-/// # fn example<'tcx>(cx: &LateContext<'tcx>, def_id: DefId) {
-/// let _ = classify_def_id(cx, def_id, UsageCategory::Call);
-/// # }
-/// ```
-pub fn classify_def_id(
-    cx: &LateContext<'_>,
-    def_id: DefId,
-    category: UsageCategory,
-) -> Option<StdFsUsage> {
-    if cx.tcx.crate_name(def_id.krate) != sym::std {
-        return None;
+impl FilesystemModules {
+    /// Parses the configured module `paths`, skipping empty entries.
+    #[must_use]
+    pub(crate) fn new(paths: &[String]) -> Self {
+        Self {
+            modules: paths
+                .iter()
+                .map(|path| SimplePath::parse(path))
+                .filter(|path| !path.segments().is_empty())
+                .collect(),
+        }
     }
 
-    let label = cx.tcx.def_path_str(def_id);
+    /// Returns the configured module containing the item at `label`, a fully
+    /// qualified path such as `tokio::fs::read`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let modules = FilesystemModules::default();
+    /// assert!(modules.module_for("tokio::fs::File").is_some());
+    /// assert!(modules.module_for("cap_std::fs::Dir").is_none());
+    /// ```
+    #[must_use]
+    pub(crate) fn module_for(&self, label: &str) -> Option<&SimplePath> {
+        if label != label.trim() || label.is_empty() || label.chars().any(is_invalid_label_char) {
+            return None;
+        }
+        self.modules
+            .iter()
+            .find(|module| label_is_within(label, module))
+    }
+}
 
-    label_is_std_fs(&label).then(|| StdFsUsage::new(label, category))
+impl Default for FilesystemModules {
+    fn default() -> Self {
+        Self::defaults(false)
+    }
 }
 
-fn is_std_fs_path(path: &SimplePath) -> bool {
-    let segments = path.segments();
-    segments.len() >= 2 && segments[0] == "std" && segments[1] == "fs"
+impl FilesystemModules {
+    /// Returns the modules checked when the configuration names none,
+    /// including `tokio::fs` and `async_std::fs` when `async_modules` is set.
+    #[must_use]
+    pub(crate) fn defaults(async_modules: bool) -> Self {
+        let paths = if async_modules {
+            DEFAULT_MODULES
+        } else {
+            LEGACY_MODULES
+        };
+        Self {
+            modules: paths.iter().map(|&path| SimplePath::parse(path)).collect(),
+        }
+    }
 }
 
-/// Returns true if the character should be rejected in a valid std::fs label.
+/// Returns true if the character should be rejected in a valid item label.
 fn is_invalid_label_char(ch: char) -> bool {
     ch.is_whitespace() || matches!(ch, '(' | ')')
 }
 
-pub(crate) fn label_is_std_fs(label: &str) -> bool {
-    if label != label.trim() {
+fn label_is_within(label: &str, module: &SimplePath) -> bool {
+    let Some(remainder) = label.strip_prefix(module.to_string().as_str()) else {
         return false;
+    };
+    if remainder.is_empty() {
+        return true;
     }
-
-    if label.is_empty() || label.chars().any(is_invalid_label_char) {
+    if !remainder.starts_with("::") {
         return false;
     }
+    SimplePath::parse(label)
+        .segments()
+        .starts_with(module.segments())
+}
 
-    if !label.starts_with("std::fs") {
-        return false;
+/// Classifies resolved items against the configured filesystem modules.
+pub(crate) struct Classifier<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    modules: &'a FilesystemModules,
+}
+
+impl<'a, 'tcx> Classifier<'a, 'tcx> {
+    /// Creates a classifier for the crate `cx` is checking.
+    #[must_use]
+    pub(crate) fn new(cx: &'a LateContext<'tcx>, modules: &'a FilesystemModules) -> Self {
+        Self { cx, modules }
     }
 
-    let remainder = &label["std::fs".len()..];
-    if remainder.is_empty() {
-        return true;
+    /// Classify a resolved path (expression, type, import) into a usage record.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let classifier = Classifier::new(cx, &modules);
+    /// let _ = classifier.qpath(qpath, hir_id, UsageCategory::Call);
+    /// ```
+    #[must_use]
+    pub(crate) fn qpath(
+        &self,
+        qpath: &hir::QPath<'_>,
+        hir_id: hir::HirId,
+        category: UsageCategory,
+    ) -> Option<StdFsUsage> {
+        let res = self.cx.qpath_res(qpath, hir_id);
+        self.res(res, category)
     }
 
-    if !remainder.starts_with("::") {
-        return false;
+    /// Classify using a `Res` obtained from HIR traversal.
+    #[must_use]
+    pub(crate) fn res(&self, res: Res, category: UsageCategory) -> Option<StdFsUsage> {
+        res.opt_def_id()
+            .and_then(|def_id| self.def_id(def_id, category))
     }
 
-    let path = SimplePath::parse(label);
-    is_std_fs_path(&path)
+    /// Classify a `DefId` by inspecting its fully qualified path.
+    ///
+    /// Local items are qualified with the crate name, so a configured module
+    /// such as `my_crate::vfs` matches them. The module's first segment must
+    /// name the crate defining the item, which keeps a local `mod std` from
+    /// posing as the standard library.
+    #[must_use]
+    pub(crate) fn def_id(&self, def_id: DefId, category: UsageCategory) -> Option<StdFsUsage> {
+        let tcx = self.cx.tcx;
+        let crate_name = tcx.crate_name(def_id.krate);
+        let path = tcx.def_path_str(def_id);
+        let label = if def_id.is_local() {
+            format!("{crate_name}::{path}")
+        } else {
+            path
+        };
+
+        let module = self.modules.module_for(&label)?;
+        (module.segments().first().map(String::as_str) == Some(crate_name.as_str()))
+            .then(|| StdFsUsage::new(label, module.to_string(), category))
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
-//! Tests for classifying std::fs usage and its reporting metadata.
-use super::{StdFsUsage, UsageCategory, label_is_std_fs};
+//! Tests for classifying filesystem usage and its reporting metadata.
+use super::{FilesystemModules, StdFsUsage, UsageCategory};
 use rstest::rstest;
 
 #[rstest]
@@ -22,7 +22,43 @@ use rstest::rstest;
 #[case("std::", false)]
 #[case("std::filesystem", false)]
 fn recognises_std_fs_paths(#[case] path: &str, #[case] expected: bool) {
-    assert_eq!(label_is_std_fs(path), expected);
+    assert_eq!(
+        FilesystemModules::default().module_for(path).is_some(),
+        expected
+    );
+}
+
+#[rstest]
+#[case("tokio::fs", Some("tokio::fs"))]
+#[case("tokio::fs::read_to_string", Some("tokio::fs"))]
+#[case("tokio::fs::File::open", Some("tokio::fs"))]
+#[case("async_std::fs::File", Some("async_std::fs"))]
+#[case("std::fs::File", Some("std::fs"))]
+#[case("tokio::fsx::read", None)]
+#[case("tokio::io::AsyncReadExt", None)]
+#[case("async_std::path::Path", None)]
+fn recognises_async_filesystem_modules(#[case] path: &str, #[case] expected: Option<&str>) {
+    let modules = FilesystemModules::defaults(true);
+    assert_eq!(
+        modules.module_for(path).map(ToString::to_string).as_deref(),
+        expected
+    );
+}
+
+#[rstest]
+#[case::tokio("tokio::fs::read_to_string")]
+#[case::async_std("async_std::fs::File")]
+fn async_modules_are_not_defaults_before_behaviour_version_2(#[case] path: &str) {
+    assert!(FilesystemModules::default().module_for(path).is_none());
+}
+
+#[rstest]
+fn configured_modules_replace_the_defaults() {
+    let modules = FilesystemModules::new(&["my_crate::vfs".to_owned(), String::new()]);
+
+    assert!(modules.module_for("my_crate::vfs::read").is_some());
+    assert!(modules.module_for("std::fs::read").is_none());
+    assert!(modules.module_for("tokio::fs::read").is_none());
 }
 
 #[rstest]
@@ -34,7 +70,12 @@ fn usage_category_labels_are_stable() {
 
 #[rstest]
 fn usage_builder_carries_operation() {
-    let usage = StdFsUsage::new(String::from("std::fs::read"), UsageCategory::Call);
+    let usage = StdFsUsage::new(
+        String::from("std::fs::read"),
+        String::from("std::fs"),
+        UsageCategory::Call,
+    );
     assert_eq!(usage.operation(), "std::fs::read");
+    assert_eq!(usage.module(), "std::fs");
     assert_eq!(usage.category(), UsageCategory::Call);
 }
@@ -34,3 +34,12 @@ Feature: Localised diagnostics for std::fs usage
     And the operation is "std::fs::canonicalize"
     When I localise the std::fs diagnostic
     Then localization fails for "no_std_fs_operations"
+
+  Scenario: Async filesystem modules are named in the messages
+    Given the locale "en-GB" is selected
+    And the module is "tokio::fs"
+    And the operation is "tokio::fs::read_to_string"
+    When I localise the std::fs diagnostic
+    Then the primary mentions "tokio::fs::read_to_string"
+    And the note references "tokio::fs"
+    And the help references "tokio::fs"
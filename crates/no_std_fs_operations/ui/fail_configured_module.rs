@@ -0,0 +1,14 @@
+//! UI fixture showing a configured module linted like `std::fs`.
+#![deny(no_std_fs_operations)]
+
+mod vfs {
+    pub async fn read_to_string(_path: &str) -> String { String::new() }
+}
+
+async fn load() -> String {
+    vfs::read_to_string("config.toml").await
+}
+
+fn main() {
+    let _ = load();
+}
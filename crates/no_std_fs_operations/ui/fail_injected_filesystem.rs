@@ -0,0 +1,11 @@
+//! UI fixture for diagnostics that point at an injected filesystem trait.
+#![deny(no_std_fs_operations)]
+
+fn load() -> std::io::Result<String> {
+    std::fs::read_to_string("config.toml")
+}
+
+fn main() {
+    let _ = load();
+    let _ = std::fs::remove_file;
+}
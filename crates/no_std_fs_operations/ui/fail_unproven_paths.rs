@@ -0,0 +1,25 @@
+//! UI fixture showing `std::fs` calls whose paths may leave configured roots.
+#![deny(no_std_fs_operations)]
+
+mod scratch {
+    use std::path::{Path, PathBuf};
+
+    pub struct TempDir(PathBuf);
+
+    impl TempDir {
+        pub fn new() -> Self { Self(std::env::temp_dir()) }
+
+        pub fn path(&self) -> &Path { &self.0 }
+    }
+}
+
+fn main() {
+    let dir = scratch::TempDir::new();
+    let _ = std::fs::write(dir.path().join("../notes.txt"), "draft");
+
+    let name = std::env::args().nth(1).unwrap_or_default();
+    let _ = std::fs::remove_file(dir.path().join(&name));
+
+    let _ = std::fs::remove_dir_all("/tmp/whitaker/../../etc");
+    let _ = std::fs::copy(dir.path().join("notes.txt"), "/var/notes.txt");
+}
@@ -0,0 +1,27 @@
+//! UI fixture showing `std::fs` values handed to a sanctioned wrapper crate.
+//!
+//! The configuration names this fixture's own crate, so the local `cap`
+//! module stands in for a wrapper such as `cap_std`.
+#![deny(no_std_fs_operations)]
+
+mod cap {
+    pub struct Dir<T>(T);
+
+    impl<T> Dir<T> {
+        pub fn from_std_file(file: T) -> Self { Self(file) }
+
+        pub fn into_inner(self) -> T { self.0 }
+    }
+
+    #[macro_export]
+    macro_rules! ambient_dir {
+        ($path:expr) => {
+            $crate::cap::Dir::from_std_file(std::fs::File::open($path))
+        };
+    }
+}
+
+fn main() {
+    let _ = cap::Dir::from_std_file(std::fs::File::open(".")).into_inner();
+    let _ = ambient_dir!("Cargo.toml").into_inner();
+}
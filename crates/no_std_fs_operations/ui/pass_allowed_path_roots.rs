@@ -0,0 +1,26 @@
+//! UI fixture showing `std::fs` calls whose paths stay under configured roots.
+#![deny(no_std_fs_operations)]
+
+mod scratch {
+    use std::path::{Path, PathBuf};
+
+    pub struct TempDir(PathBuf);
+
+    impl TempDir {
+        pub fn new() -> Self { Self(std::env::temp_dir()) }
+
+        pub fn path(&self) -> &Path { &self.0 }
+    }
+}
+
+fn main() {
+    let dir = scratch::TempDir::new();
+    let _ = std::fs::write(dir.path().join("notes.txt"), "draft");
+
+    let report = dir.path().join("reports/summary.txt");
+    let _ = std::fs::read_to_string(&report);
+    let _ = std::fs::File::create(report.as_path());
+
+    let _ = std::fs::create_dir_all("/tmp/whitaker/cache");
+    let _ = std::fs::copy(dir.path().join("notes.txt"), "/tmp/whitaker/notes.txt");
+}
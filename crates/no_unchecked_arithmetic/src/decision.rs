@@ -0,0 +1,151 @@
+//! Pure decision logic for `no_unchecked_arithmetic`.
+//!
+//! The driver describes each `+`, `-`, and `*`, and their compound
+//! assignments, by the operand type, whether it is evaluated at compile
+//! time, and the module it sits in. [`NoUncheckedArithmeticDecision`]
+//! matches the module against the configured globs and reports the
+//! operation when [`should_flag`] does. Findings use the operator as their
+//! reason and the `checked_*` replacement as their subject.
+//!
+//! The lint is inert until `modules` is configured, so the default decision
+//! reports nothing and publishes no flagged fixture.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::path::ModuleGlobs;
+
+use crate::driver::LINT_NAME;
+use crate::policy::{ArithOp, Operation, should_flag};
+
+/// The operators the lint checks.
+const OPERATORS: [ArithOp; 3] = [ArithOp::Add, ArithOp::Sub, ArithOp::Mul];
+
+/// The facts about one arithmetic operation that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArithmeticProjection {
+    /// The operator as written, such as `+` or `*=`.
+    pub operator: String,
+    /// Whether both operands are primitive integers.
+    pub on_integers: bool,
+    /// Whether the operation is evaluated at compile time.
+    pub in_const: bool,
+    /// The module the operation sits in, relative to the crate root.
+    pub module: String,
+}
+
+impl ArithmeticProjection {
+    fn op(&self) -> Option<(ArithOp, bool)> {
+        OPERATORS.into_iter().find_map(|op| {
+            [false, true]
+                .into_iter()
+                .find(|&assign| op.symbol(assign) == self.operator)
+                .map(|assign| (op, assign))
+        })
+    }
+}
+
+/// Reports unchecked runtime integer arithmetic in the configured modules.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = NoUncheckedArithmeticDecision::new(&["ledger".to_owned()]);
+/// let sum = ArithmeticProjection {
+///     operator: "+".to_owned(),
+///     on_integers: true,
+///     in_const: false,
+///     module: "ledger::balance".to_owned(),
+/// };
+/// assert!(decision.decide(&sum).is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NoUncheckedArithmeticDecision {
+    modules: ModuleGlobs,
+    checked_module: Option<String>,
+}
+
+impl NoUncheckedArithmeticDecision {
+    /// Creates a decision checking the modules matching `modules`.
+    #[must_use]
+    pub fn new(modules: &[String]) -> Self {
+        let globs = ModuleGlobs::new(LINT_NAME, "modules", modules);
+        let checked_module = modules
+            .iter()
+            .map(|glob| glob.replace('*', "inner").replace('?', "m"))
+            .find(|module| globs.matches(module));
+        Self {
+            modules: globs,
+            checked_module,
+        }
+    }
+}
+
+impl LintDecision for NoUncheckedArithmeticDecision {
+    type Projection = ArithmeticProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, operation: &ArithmeticProjection) -> Option<Finding> {
+        let (op, assign) = operation.op()?;
+        let facts = Operation {
+            op,
+            on_integers: operation.on_integers,
+            in_const: operation.in_const,
+            in_configured_module: self.modules.matches(&operation.module),
+        };
+        let [checked, ..] = op.replacements();
+        should_flag(&facts)
+            .then(|| Finding::new(LINT_NAME, op.symbol(assign)).with_subject(checked))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ArithmeticProjection>> {
+        let operation =
+            |operator: &str, on_integers, in_const, module: &str| ArithmeticProjection {
+                operator: operator.to_owned(),
+                on_integers,
+                in_const,
+                module: module.to_owned(),
+            };
+        let Some(module) = self.checked_module.as_deref() else {
+            return vec![DecisionFixture::clean(
+                "no configured modules",
+                operation("+", true, false, ""),
+            )];
+        };
+        vec![
+            DecisionFixture::flagged("integer addition", operation("+", true, false, module), "+"),
+            DecisionFixture::flagged(
+                "compound multiplication",
+                operation("*=", true, false, module),
+                "*=",
+            ),
+            DecisionFixture::clean("float subtraction", operation("-", false, false, module)),
+            DecisionFixture::clean("constant expression", operation("+", true, true, module)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::{ConformanceError, check_conformance};
+
+    #[rstest]
+    fn unconfigured_decision_reports_nothing() {
+        assert_eq!(
+            check_conformance(&NoUncheckedArithmeticDecision::default()),
+            Err(ConformanceError::MissingFlaggedFixture { lint: LINT_NAME })
+        );
+    }
+
+    #[rstest]
+    #[case::plain_module("ledger")]
+    #[case::glob("billing::*")]
+    fn decision_conforms(#[case] module: &str) {
+        let decision = NoUncheckedArithmeticDecision::new(&[module.to_owned()]);
+
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
@@ -0,0 +1,22 @@
+//! Embedded documentation for the `no_unchecked_arithmetic` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_unchecked_arithmetic`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_unchecked_arithmetic",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags integer `+`, `-`, and `*`, and their compound assignments, inside the modules listed in `modules`.",
+    rationale: "Integer arithmetic panics on overflow in debug builds and wraps silently in release builds. In modules where either outcome is a defect, `checked_*`, `saturating_*`, and `wrapping_*` state what should happen instead.",
+    bad_example: "let total = subtotal + tax;",
+    good_example: "let total = subtotal.checked_add(tax).ok_or(BillingError::Overflow)?;",
+    config: &[ConfigKeyDocs {
+        key: "modules",
+        default: "[]",
+        description: "Module path globs, relative to the crate root, whose integer arithmetic must be checked. A glob also matches every module inside the modules it matches. The lint is silent when the list is empty.",
+    }],
+};
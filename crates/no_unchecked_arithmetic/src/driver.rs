@@ -0,0 +1,326 @@
+//! Lint pass reporting unchecked integer arithmetic in configured modules.
+//!
+//! Each binary `+`, `-`, or `*`, and each `+=`, `-=`, or `*=`, whose left
+//! operand is a primitive integer once references are peeled is described as
+//! an [`Operation`]. The module containing it is rendered with
+//! [`whitaker::hir::module_path`] and matched against the configured
+//! [`ModuleGlobs`], and [`should_flag`] makes the decision. Operations in
+//! constant contexts, where overflow is already a compile-time error, and
+//! operations produced by macro expansion are skipped. Nothing is inspected
+//! when no module is configured.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::path::ModuleGlobs;
+
+use crate::policy::{ArithOp, Operation, should_flag};
+
+pub(crate) const LINT_NAME: &str = "no_unchecked_arithmetic";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    modules: Vec<String>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_UNCHECKED_ARITHMETIC,
+    Warn,
+    "integer arithmetic in configured modules should use `checked_*`, `saturating_*`, or `wrapping_*` methods",
+    NoUncheckedArithmetic::default()
+}
+
+/// Lint pass that finds unchecked integer arithmetic in configured modules.
+#[derive(Default)]
+pub struct NoUncheckedArithmetic {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configured modules, finding limit, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    modules: ModuleGlobs,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoUncheckedArithmetic {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            modules: ModuleGlobs::new(LINT_NAME, "modules", &load_configuration().modules),
+            findings: FindingLimit::new(NO_UNCHECKED_ARITHMETIC, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let state = &*self.state;
+        if state.modules.is_empty() {
+            return;
+        }
+        let (op, assign, left) = match expr.kind {
+            hir::ExprKind::Binary(op, left, _) => (binary_op(op.node), false, left),
+            hir::ExprKind::AssignOp(op, left, _) => (assign_op(op.node), true, left),
+            _ => return,
+        };
+        let Some(op) = op else {
+            return;
+        };
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        let ty = cx.typeck_results().expr_ty(left).peel_refs();
+        let owner = cx.tcx.hir_enclosing_body_owner(expr.hir_id);
+        let module = module_path(cx, expr.hir_id);
+        let operation = Operation {
+            op,
+            on_integers: integer_name(ty).is_some(),
+            in_const: cx.tcx.hir_body_const_context(owner).is_some(),
+            in_configured_module: state.modules.matches(&module),
+        };
+        if !should_flag(&operation) {
+            return;
+        }
+        let Some(ty_name) = integer_name(ty) else {
+            return;
+        };
+
+        let module = if module.is_empty() {
+            String::from("crate")
+        } else {
+            module
+        };
+        debug!(
+            target: LINT_NAME,
+            "unchecked `{}` on `{ty_name}` in module `{module}`",
+            op.symbol(assign),
+        );
+        if state.findings.admit(cx, expr.hir_id, expr.span) {
+            let replacements = op.replacements();
+            let message = ArithmeticMessage {
+                operator: op.symbol(assign),
+                ty: ty_name,
+                module: &module,
+                replacements: &replacements,
+            };
+            emit_diagnostic(cx, expr.span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+const fn binary_op(kind: hir::BinOpKind) -> Option<ArithOp> {
+    match kind {
+        hir::BinOpKind::Add => Some(ArithOp::Add),
+        hir::BinOpKind::Sub => Some(ArithOp::Sub),
+        hir::BinOpKind::Mul => Some(ArithOp::Mul),
+        _ => None,
+    }
+}
+
+const fn assign_op(kind: hir::AssignOpKind) -> Option<ArithOp> {
+    match kind {
+        hir::AssignOpKind::AddAssign => Some(ArithOp::Add),
+        hir::AssignOpKind::SubAssign => Some(ArithOp::Sub),
+        hir::AssignOpKind::MulAssign => Some(ArithOp::Mul),
+        _ => None,
+    }
+}
+
+/// Returns the name of `ty` when it is a primitive integer.
+fn integer_name(ty: Ty<'_>) -> Option<&'static str> {
+    match ty.kind() {
+        ty::Int(int) => Some(int.name_str()),
+        ty::Uint(uint) => Some(uint.name_str()),
+        _ => None,
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &ArithmeticMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        NO_UNCHECKED_ARITHMETIC,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one unchecked operation interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ArithmeticMessage<'a> {
+    operator: &'a str,
+    ty: &'a str,
+    module: &'a str,
+    replacements: &'a [String; 3],
+}
+
+impl ArithmeticMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let [checked, saturating, wrapping] = self.replacements;
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("operator"),
+            FluentValue::from(self.operator.to_owned()),
+        );
+        args.insert(Cow::Borrowed("ty"), FluentValue::from(self.ty.to_owned()));
+        args.insert(
+            Cow::Borrowed("module"),
+            FluentValue::from(self.module.to_owned()),
+        );
+        args.insert(Cow::Borrowed("checked"), FluentValue::from(checked.clone()));
+        args.insert(
+            Cow::Borrowed("saturating"),
+            FluentValue::from(saturating.clone()),
+        );
+        args.insert(
+            Cow::Borrowed("wrapping"),
+            FluentValue::from(wrapping.clone()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            operator,
+            ty,
+            module,
+            replacements: [checked, saturating, wrapping],
+        } = *self;
+        DiagnosticMessageSet::new(
+            format!("Unchecked `{operator}` on `{ty}` in module `{module}`."),
+            format!(
+                "`{module}` is configured to handle overflow explicitly, but `{operator}` panics \
+                 on overflow in debug builds and wraps silently in release builds."
+            ),
+            format!(
+                "Use `{checked}`, `{saturating}`, or `{wrapping}` to state what should happen on \
+                 overflow."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn resolve(message: &ArithmeticMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_enforces_no_modules() {
+        assert!(Config::default().modules.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_replacements() {
+        let replacements = ArithOp::Add.replacements();
+        let message = ArithmeticMessage {
+            operator: "+=",
+            ty: "u64",
+            module: "billing::invoice",
+            replacements: &replacements,
+        };
+        let messages = message.fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Unchecked `+=` on `u64` in module `billing::invoice`."
+        );
+        assert!(
+            messages
+                .help()
+                .starts_with("Use `checked_add`, `saturating_add`")
+        );
+    }
+
+    #[rstest]
+    #[case::english("en-GB")]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn messages_resolve(#[case] locale: &str) {
+        let replacements = ArithOp::Mul.replacements();
+        let message = ArithmeticMessage {
+            operator: "*",
+            ty: "i128",
+            module: "crypto",
+            replacements: &replacements,
+        };
+        let messages = resolve(&message, locale);
+
+        if locale == "en-GB" {
+            assert_eq!(messages, message.fallback_messages());
+        }
+        assert!(messages.primary().contains("`i128`"));
+        assert!(messages.help().contains("`wrapping_mul`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
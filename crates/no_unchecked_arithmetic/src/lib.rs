@@ -0,0 +1,43 @@
+//! Lint crate requiring explicit overflow handling in configured modules.
+//!
+//! Integer `+`, `-`, and `*` panic on overflow in debug builds and wrap
+//! silently in release builds. In code where either outcome is a defect,
+//! such as billing or cryptography, overflow should be handled where it can
+//! happen. Within the modules matched by the `modules` globs, the lint
+//! reports those operators and their compound assignments on integer types
+//! and asks for `checked_*`, `saturating_*`, or `wrapping_*` instead. Outside
+//! the configured modules, and when no module is configured, it stays
+//! silent. The lint is experimental and ships behind the
+//! `experimental-no-unchecked-arithmetic` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ArithmeticProjection, NoUncheckedArithmeticDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_unchecked_arithmetic_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,132 @@
+//! Rules deciding which integer operations are reported.
+//!
+//! The driver describes each arithmetic operator it finds without compiler
+//! types, and this module decides whether it is reported and which explicit
+//! methods replace it. The rules are kept free of compiler types so they can
+//! be tested directly.
+
+/// An integer operator that can overflow.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ArithOp {
+    /// `+` or `+=`.
+    Add,
+    /// `-` or `-=`.
+    Sub,
+    /// `*` or `*=`.
+    Mul,
+}
+
+impl ArithOp {
+    /// Returns the operator as written, in its compound assignment form when
+    /// `assign` is set.
+    #[must_use]
+    pub(crate) const fn symbol(self, assign: bool) -> &'static str {
+        match (self, assign) {
+            (Self::Add, false) => "+",
+            (Self::Add, true) => "+=",
+            (Self::Sub, false) => "-",
+            (Self::Sub, true) => "-=",
+            (Self::Mul, false) => "*",
+            (Self::Mul, true) => "*=",
+        }
+    }
+
+    /// Returns the suffix the explicit integer methods share, such as `add`
+    /// in `checked_add`.
+    #[must_use]
+    pub(crate) const fn method_suffix(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+        }
+    }
+
+    /// Returns the `checked_*`, `saturating_*`, and `wrapping_*` methods that
+    /// replace the operator.
+    #[must_use]
+    pub(crate) fn replacements(self) -> [String; 3] {
+        let suffix = self.method_suffix();
+        ["checked", "saturating", "wrapping"].map(|prefix| format!("{prefix}_{suffix}"))
+    }
+}
+
+/// An arithmetic operation found by the driver.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Operation {
+    /// The operator applied.
+    pub(crate) op: ArithOp,
+    /// Both operands are primitive integers.
+    pub(crate) on_integers: bool,
+    /// The operation is evaluated at compile time, where overflow is already
+    /// an error.
+    pub(crate) in_const: bool,
+    /// The enclosing module matches a configured glob.
+    pub(crate) in_configured_module: bool,
+}
+
+/// Returns `true` when `operation` is unchecked runtime integer arithmetic
+/// inside a configured module.
+///
+/// # Examples
+///
+/// ```ignore
+/// let operation = Operation {
+///     op: ArithOp::Add,
+///     on_integers: true,
+///     in_const: false,
+///     in_configured_module: true,
+/// };
+/// assert!(should_flag(&operation));
+/// ```
+#[must_use]
+pub(crate) const fn should_flag(operation: &Operation) -> bool {
+    operation.in_configured_module && operation.on_integers && !operation.in_const
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn operation(on_integers: bool, in_const: bool, in_configured_module: bool) -> Operation {
+        Operation {
+            op: ArithOp::Mul,
+            on_integers,
+            in_const,
+            in_configured_module,
+        }
+    }
+
+    #[rstest]
+    #[case(ArithOp::Add, false, "+")]
+    #[case(ArithOp::Sub, true, "-=")]
+    #[case(ArithOp::Mul, true, "*=")]
+    fn symbols_follow_the_written_form(
+        #[case] op: ArithOp,
+        #[case] assign: bool,
+        #[case] symbol: &str,
+    ) {
+        assert_eq!(op.symbol(assign), symbol);
+    }
+
+    #[rstest]
+    fn replacements_cover_each_overflow_policy() {
+        assert_eq!(
+            ArithOp::Sub.replacements(),
+            ["checked_sub", "saturating_sub", "wrapping_sub"]
+        );
+    }
+
+    #[rstest]
+    #[case::runtime_integers(operation(true, false, true), true)]
+    #[case::outside_modules(operation(true, false, false), false)]
+    #[case::floats(operation(false, false, true), false)]
+    #[case::constants(operation(true, true, true), false)]
+    fn only_runtime_integer_arithmetic_in_configured_modules_is_flagged(
+        #[case] operation: Operation,
+        #[case] flagged: bool,
+    ) {
+        assert_eq!(should_flag(&operation), flagged);
+    }
+}
@@ -0,0 +1,88 @@
+//! Behaviour-driven coverage for the unchecked arithmetic policy.
+
+use crate::policy::{ArithOp, Operation, should_flag};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::path::ModuleGlobs;
+
+#[derive(Default)]
+struct ArithmeticWorld {
+    modules: RefCell<ModuleGlobs>,
+    module: RefCell<String>,
+    on_integers: Cell<bool>,
+    in_const: Cell<bool>,
+    flagged: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> ArithmeticWorld {
+    ArithmeticWorld::default()
+}
+
+fn set_operation(world: &ArithmeticWorld, module: &str, on_integers: bool) {
+    *world.module.borrow_mut() = module.trim_matches('"').to_owned();
+    world.on_integers.set(on_integers);
+}
+
+#[given("the modules {glob} are configured")]
+fn given_modules(world: &ArithmeticWorld, glob: String) {
+    let globs = [glob.trim_matches('"').to_owned()];
+    *world.modules.borrow_mut() = ModuleGlobs::new("no_unchecked_arithmetic", "modules", &globs);
+}
+
+#[given("integer arithmetic in module {module}")]
+fn given_integer_arithmetic(world: &ArithmeticWorld, module: String) {
+    set_operation(world, &module, true);
+}
+
+#[given("float arithmetic in module {module}")]
+fn given_float_arithmetic(world: &ArithmeticWorld, module: String) {
+    set_operation(world, &module, false);
+}
+
+#[given("the operation is evaluated at compile time")]
+fn given_const(world: &ArithmeticWorld) {
+    world.in_const.set(true);
+}
+
+#[when("the operation is checked")]
+fn when_checked(world: &ArithmeticWorld) {
+    let operation = Operation {
+        op: ArithOp::Add,
+        on_integers: world.on_integers.get(),
+        in_const: world.in_const.get(),
+        in_configured_module: world.modules.borrow().matches(&world.module.borrow()),
+    };
+    world.flagged.set(Some(should_flag(&operation)));
+}
+
+#[then("the operation is reported")]
+fn then_reported(world: &ArithmeticWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the operation is allowed")]
+fn then_allowed(world: &ArithmeticWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/no_unchecked_arithmetic.feature", index = 0)]
+fn scenario_configured_module(world: ArithmeticWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_unchecked_arithmetic.feature", index = 1)]
+fn scenario_other_module(world: ArithmeticWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_unchecked_arithmetic.feature", index = 2)]
+fn scenario_floats(world: ArithmeticWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/no_unchecked_arithmetic.feature", index = 3)]
+fn scenario_constants(world: ArithmeticWorld) {
+    let _ = world;
+}
@@ -0,0 +1,28 @@
+Feature: Unchecked integer arithmetic
+  Raw `+`, `-`, and `*` on integers are reported in modules matching the
+  configured globs, and nowhere else.
+
+  Scenario: Integer arithmetic in a configured module is reported
+    Given the modules "billing" are configured
+    And integer arithmetic in module "billing::invoice"
+    When the operation is checked
+    Then the operation is reported
+
+  Scenario: Integer arithmetic outside the configured modules is allowed
+    Given the modules "billing" are configured
+    And integer arithmetic in module "reporting"
+    When the operation is checked
+    Then the operation is allowed
+
+  Scenario: Float arithmetic in a configured module is allowed
+    Given the modules "crypto" are configured
+    And float arithmetic in module "crypto"
+    When the operation is checked
+    Then the operation is allowed
+
+  Scenario: Constant arithmetic in a configured module is allowed
+    Given the modules "billing" are configured
+    And integer arithmetic in module "billing"
+    And the operation is evaluated at compile time
+    When the operation is checked
+    Then the operation is allowed
@@ -0,0 +1,30 @@
+//! Unchecked integer arithmetic inside a configured module.
+
+mod billing {
+    pub mod invoice {
+        pub fn total(net: u64, tax: u64) -> u64 {
+            net + tax
+        }
+
+        pub fn apply_discount(total: &mut i64, discount: i64) {
+            *total -= discount;
+        }
+    }
+
+    pub fn line_cost(quantity: u32, unit_price: u32) -> u32 {
+        quantity * unit_price
+    }
+}
+
+mod reporting {
+    pub fn sum(left: u64, right: u64) -> u64 {
+        left + right
+    }
+}
+
+fn main() {
+    let mut total = billing::invoice::total(100, 20) as i64;
+    billing::invoice::apply_discount(&mut total, 5);
+    let _ = billing::line_cost(3, 7);
+    let _ = reporting::sum(1, 2);
+}
@@ -0,0 +1,38 @@
+//! Configured modules whose arithmetic states its overflow behaviour, works
+//! on floats, or is evaluated at compile time.
+
+mod billing {
+    pub const CENTS_PER_POUND: u64 = 10 * 10;
+    pub const LATE_FEE: u64 = CENTS_PER_POUND + 50;
+
+    pub fn total(net: u64, tax: u64) -> Option<u64> {
+        net.checked_add(tax)
+    }
+
+    pub fn refund(total: u32, amount: u32) -> u32 {
+        total.saturating_sub(amount)
+    }
+
+    pub fn hash_step(state: u64, byte: u64) -> u64 {
+        state.wrapping_mul(31).wrapping_add(byte)
+    }
+
+    pub fn exchange(amount: f64, rate: f64) -> f64 {
+        amount * rate - 0.5
+    }
+}
+
+mod reporting {
+    pub fn sum(left: u64, right: u64) -> u64 {
+        left + right
+    }
+}
+
+fn main() {
+    let _ = billing::total(100, 20);
+    let _ = billing::refund(10, 3);
+    let _ = billing::hash_step(7, 1);
+    let _ = billing::exchange(2.0, 1.5);
+    let _ = billing::LATE_FEE;
+    let _ = reporting::sum(1, 2);
+}
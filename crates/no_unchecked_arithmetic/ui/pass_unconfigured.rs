@@ -0,0 +1,11 @@
+//! Without configured modules the lint stays silent.
+
+mod billing {
+    pub fn total(net: u64, tax: u64) -> u64 {
+        net + tax
+    }
+}
+
+fn main() {
+    let _ = billing::total(100, 20);
+}
@@ -0,0 +1,170 @@
+//! Pure decision logic for `no_unwrap_or_else_panic`.
+//!
+//! The driver finds calls to the configured [`Combinators`] on `Option` and
+//! `Result` receivers, scans the fallback closure for panics, and summarises
+//! the call's context. [`NoUnwrapOrElsePanicDecision`] rebuilds the same
+//! [`PanicInfo`] and [`ContextSummary`], asks [`should_flag`], and names the
+//! combinator as the finding's subject. Blaming helper call sites is left to
+//! the driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::LINT_NAME;
+use crate::context::ContextSummary;
+use crate::panic_detector::PanicInfo;
+use crate::policy::{Combinators, LintPolicy, should_flag};
+
+/// Reason key for fallback closures that panic.
+const PANICKING_FALLBACK: &str = "panicking_fallback";
+
+/// The facts about one combinator call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FallbackCallProjection {
+    /// The combinator called, such as `unwrap_or_else`.
+    pub method: String,
+    /// Whether the fallback closure panics directly or via `unwrap`.
+    pub panics: bool,
+    /// Whether a panic's message is a plain literal.
+    pub plain_panic: bool,
+    /// Whether a panic's message interpolates a runtime value.
+    pub interpolated_panic: bool,
+    /// Whether the call sits in test code.
+    pub in_test: bool,
+    /// Whether the call sits in `main`.
+    pub in_main: bool,
+    /// Whether the crate is a doctest.
+    pub in_doctest: bool,
+}
+
+/// Reports combinator calls whose fallback closure panics.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = FallbackCallProjection {
+///     method: "unwrap_or_else".to_owned(),
+///     panics: true,
+///     plain_panic: true,
+///     ..FallbackCallProjection::default()
+/// };
+/// assert!(NoUnwrapOrElsePanicDecision::default().decide(&call).is_some());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NoUnwrapOrElsePanicDecision {
+    policy: LintPolicy,
+    combinators: Combinators,
+}
+
+impl NoUnwrapOrElsePanicDecision {
+    /// Creates a decision checking the `combinators` named, which exempts
+    /// `main` when `allow_in_main` is set.
+    #[must_use]
+    pub fn new(allow_in_main: bool, combinators: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            policy: LintPolicy::new(allow_in_main),
+            combinators: Combinators::new(combinators),
+        }
+    }
+}
+
+impl LintDecision for NoUnwrapOrElsePanicDecision {
+    type Projection = FallbackCallProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, call: &FallbackCallProjection) -> Option<Finding> {
+        if !self.combinators.contains(&call.method) {
+            return None;
+        }
+        let panic_info = PanicInfo {
+            panics: call.panics,
+            has_plain_panic: call.plain_panic,
+            has_interpolated_panic: call.interpolated_panic,
+        };
+        let summary = ContextSummary {
+            is_test: call.in_test,
+            in_main: call.in_main,
+        };
+        should_flag(&self.policy, &summary, &panic_info, call.in_doctest)
+            .then(|| Finding::new(LINT_NAME, PANICKING_FALLBACK).with_subject(call.method.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<FallbackCallProjection>> {
+        let panicking = FallbackCallProjection {
+            method: "unwrap_or_else".to_owned(),
+            panics: true,
+            plain_panic: true,
+            ..FallbackCallProjection::default()
+        };
+        let interpolated_in_test = FallbackCallProjection {
+            plain_panic: false,
+            interpolated_panic: true,
+            in_test: true,
+            ..panicking.clone()
+        };
+        vec![
+            DecisionFixture::flagged("plain panic", panicking.clone(), PANICKING_FALLBACK),
+            DecisionFixture::clean(
+                "fallback without a panic",
+                FallbackCallProjection {
+                    panics: false,
+                    plain_panic: false,
+                    ..panicking.clone()
+                },
+            ),
+            DecisionFixture::clean("interpolated panic in a test", interpolated_in_test),
+            DecisionFixture::clean(
+                "doctest",
+                FallbackCallProjection {
+                    in_doctest: true,
+                    ..panicking.clone()
+                },
+            ),
+            DecisionFixture::clean(
+                "unchecked combinator",
+                FallbackCallProjection {
+                    method: "map_or".to_owned(),
+                    ..panicking
+                },
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn main_is_exempt_when_allowed() {
+        let call = FallbackCallProjection {
+            method: "unwrap_or_else".to_owned(),
+            panics: true,
+            plain_panic: true,
+            in_main: true,
+            ..FallbackCallProjection::default()
+        };
+        let allowing = NoUnwrapOrElsePanicDecision::new(true, ["unwrap_or_else".to_owned()]);
+
+        assert!(
+            NoUnwrapOrElsePanicDecision::default()
+                .decide(&call)
+                .is_some()
+        );
+        assert_eq!(allowing.decide(&call), None);
+    }
+
+    #[rstest]
+    #[case::defaults(NoUnwrapOrElsePanicDecision::default())]
+    #[case::siblings(NoUnwrapOrElsePanicDecision::new(
+        false,
+        ["unwrap_or_else".to_owned(), "ok_or_else".to_owned()],
+    ))]
+    fn decision_conforms(#[case] decision: NoUnwrapOrElsePanicDecision) {
+        assert_eq!(check_conformance(&decision), Ok(5));
+    }
+}
@@ -3,7 +3,9 @@
 use crate::{LINT_NAME, NO_UNWRAP_OR_ELSE_PANIC};
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LintContext};
+use rustc_span::Symbol;
 use std::borrow::Cow;
+use whitaker::hir::FindingSite;
 use whitaker_common::i18n::{
     Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
     noop_reporter, safe_resolve_message_set,
@@ -11,24 +13,35 @@ use whitaker_common::i18n::{
 
 const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
 
+/// A combinator call whose fallback closure panics.
+pub(crate) struct FallbackCall<'a, 'tcx> {
+    /// The combinator's name, such as `unwrap_or_else`.
+    pub(crate) method: Symbol,
+    /// The `Option` or `Result` the combinator is called on.
+    pub(crate) receiver: &'a hir::Expr<'tcx>,
+}
+
 /// Emit the lint diagnostic using localized messages.
 ///
 /// # Examples
 ///
 /// ```rust,ignore
-/// // Called from a lint driver once `call` and `receiver` are known:
+/// // Called from a lint driver once the finding site and call are known:
 /// // let localizer = resolve_localizer(...);
-/// // emit_diagnostic(cx, call, receiver, &localizer);
+/// // emit_diagnostic(cx, &site, &FallbackCall { method, receiver }, &localizer);
 /// ```
 pub(crate) fn emit_diagnostic(
     cx: &LateContext<'_>,
-    expr: &hir::Expr<'_>,
-    receiver: &hir::Expr<'_>,
+    site: &FindingSite,
+    call: &FallbackCall<'_, '_>,
     localizer: &Localizer,
 ) {
+    let FallbackCall { method, receiver } = *call;
     let receiver_label = format!("`{}`", cx.typeck_results().expr_ty(receiver).peel_refs());
 
+    let method = method.as_str();
     let mut args: Arguments<'_> = Arguments::default();
+    args.insert(Cow::Borrowed("method"), FluentValue::from(method));
     args.insert(
         Cow::Borrowed("receiver"),
         FluentValue::from(receiver_label.as_str()),
@@ -41,23 +54,26 @@ pub(crate) fn emit_diagnostic(
     };
 
     let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
-        fallback_messages(&receiver_label)
+        fallback_messages(method, &receiver_label)
     });
 
     cx.emit_span_lint(
         NO_UNWRAP_OR_ELSE_PANIC,
-        expr.span,
+        site.span,
         rustc_lint::errors::DiagDecorator(|lint| {
             lint.primary_message(messages.primary().to_string());
             lint.span_note(receiver.span, messages.note().to_string());
+            if let Some(origin) = &site.origin {
+                lint.span_note(origin.span, origin.note.clone());
+            }
             lint.help(messages.help().to_string());
         }),
     );
 }
 
-fn fallback_messages(receiver: &str) -> DiagnosticMessageSet {
-    let primary = format!("Replace unwrap_or_else with a non-panicking fallback on {receiver}.");
-    let note = String::from("The closure supplied to unwrap_or_else triggers a panic.");
+fn fallback_messages(method: &str, receiver: &str) -> DiagnosticMessageSet {
+    let primary = format!("Replace {method} on {receiver} with a non-panicking fallback.");
+    let note = format!("The closure supplied to {method} triggers a panic.");
     let help =
         String::from("Propagate the error or use expect with a descriptive message instead.");
 
@@ -0,0 +1,43 @@
+//! Embedded documentation for the `no_unwrap_or_else_panic` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `no_unwrap_or_else_panic`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_unwrap_or_else_panic",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Forbids `unwrap_or_else` and sibling combinators such as `map_or_else` whose fallback closure panics, directly or through `unwrap` or `expect`.",
+    rationale: "A panicking fallback is an `expect` in disguise that hides the failure path from reviewers and from lints that police panics.",
+    bad_example: "let value = input.parse::<u32>().unwrap_or_else(|_| panic!(\"bad input\"));",
+    good_example: "let value = input.parse::<u32>().map_err(InputError::from)?;",
+    config: &[
+        ConfigKeyDocs {
+            key: "allow_in_main",
+            default: "false",
+            description: "Permit panicking fallbacks inside `main`.",
+        },
+        ConfigKeyDocs {
+            key: "panic_functions",
+            default: "[]",
+            description: "Fully qualified paths of further functions, such as a `fatal` helper, whose calls count as panics.",
+        },
+        ConfigKeyDocs {
+            key: "blame_helpers",
+            default: "[]",
+            description: "Fully qualified paths of helper functions whose findings are reported at each call to them instead of inside the helper.",
+        },
+        ConfigKeyDocs {
+            key: "combinators",
+            default: "[\"unwrap_or_else\"]",
+            description: "`Option` and `Result` methods whose first argument, a fallback closure, is checked for panics. From behaviour version 2 the default also lists `map_or_else`, `ok_or_else`, and `get_or_insert_with`.",
+        },
+        ConfigKeyDocs {
+            key: "assertions",
+            default: "\"all\"",
+            description: "Which assertion macros in a fallback closure count as panics: `all`, `release_only` to accept the `debug_assert!` family, or `none`. `assert_eq!` and `assert_ne!` count from behaviour version 2.",
+        },
+    ],
+};
@@ -1,10 +1,13 @@
-//! Lint wiring that flags panicking `unwrap_or_else` fallbacks.
+//! Lint wiring that flags panicking `unwrap_or_else` fallbacks and the
+//! fallback closures of sibling combinators.
 
 use crate::LINT_NAME;
 use crate::context::ContextSummary;
-use crate::diagnostics::emit_diagnostic;
+use crate::diagnostics::{FallbackCall, emit_diagnostic};
 use crate::panic_detector::{closure_panics, receiver_is_option_or_result};
-use crate::policy::{LintPolicy, should_flag};
+use crate::policy::{
+    AssertionPolicy, Combinators, LintPolicy, PanicFunctions, SIBLING_FALLBACKS, should_flag,
+};
 use log::debug;
 use rustc_hir as hir;
 use rustc_hir::ExprKind;
@@ -12,12 +15,14 @@ use rustc_lint::{LateContext, LateLintPass};
 use serde::Deserialize;
 use std::collections::HashSet;
 use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, HelperCallSites};
+use whitaker_common::CrateScopedState;
 use whitaker_common::i18n::{Localizer, get_localizer_for_lint};
 
 dylint_linting::impl_late_lint! {
     pub NO_UNWRAP_OR_ELSE_PANIC,
     Deny,
-    "forbid `unwrap_or_else` whose closure panics (directly or via unwrap/expect)",
+    "forbid `unwrap_or_else` and sibling combinators whose fallback closure panics (directly or via unwrap/expect)",
     NoUnwrapOrElsePanic::default()
 }
 
@@ -25,44 +30,50 @@ dylint_linting::impl_late_lint! {
 #[serde(default, deny_unknown_fields)]
 struct Config {
     allow_in_main: Option<bool>,
+    panic_functions: Vec<String>,
+    blame_helpers: Vec<String>,
+    combinators: Option<Vec<String>>,
+    assertions: AssertionPolicy,
 }
 
 impl Config {
     fn resolved_allow_in_main(&self) -> bool {
         self.allow_in_main.unwrap_or(false)
     }
+
+    fn resolved_combinators(&mut self, siblings: bool) -> Combinators {
+        self.combinators
+            .take()
+            .map_or_else(|| Combinators::defaults(siblings), Combinators::new)
+    }
 }
 
-/// Lint pass that inspects `unwrap_or_else` fallbacks for panics.
+/// Lint pass that inspects `unwrap_or_else` and sibling fallbacks for panics.
+#[derive(Default)]
 pub struct NoUnwrapOrElsePanic {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Policy, test detection, helper call sites, and localization for the crate
+/// being checked.
+#[derive(Default)]
+struct CrateState {
     policy: LintPolicy,
+    panic_functions: PanicFunctions,
+    combinators: Combinators,
+    assertions: AssertionPolicy,
+    helper_calls: HelperCallSites,
+    findings: FindingLimit,
     localizer: Localizer,
     is_doctest: bool,
     is_test_harness: bool,
     harness_test_functions: HashSet<hir::HirId>,
 }
 
-impl Default for NoUnwrapOrElsePanic {
-    fn default() -> Self {
-        Self {
-            policy: LintPolicy::default(),
-            localizer: Localizer::new(None),
-            is_doctest: false,
-            is_test_harness: false,
-            harness_test_functions: HashSet::new(),
-        }
-    }
-}
-
 impl<'tcx> LateLintPass<'tcx> for NoUnwrapOrElsePanic {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
-        self.is_doctest = cx
-            .tcx
-            .env_var_os("UNSTABLE_RUSTDOC_TEST_PATH".as_ref())
-            .is_some();
-
-        self.is_test_harness = cx.tcx.sess.opts.test;
-        self.harness_test_functions = if self.is_test_harness {
+        let is_test_harness = cx.tcx.sess.opts.test;
+        let harness_test_functions = if is_test_harness {
             let mut marked = whitaker::hir::collect_harness_test_functions(cx);
             marked.extend(whitaker::hir::collect_rstest_companion_test_functions(cx));
             marked
@@ -70,11 +81,28 @@ impl<'tcx> LateLintPass<'tcx> for NoUnwrapOrElsePanic {
             HashSet::new()
         };
 
-        let config = load_configuration();
-        self.policy = LintPolicy::new(config.resolved_allow_in_main());
-
+        let mut config = load_configuration();
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
+        let siblings = shared_config
+            .behaviour_version(LINT_NAME)
+            .enables(SIBLING_FALLBACKS);
+        self.state.reset(CrateState {
+            policy: LintPolicy::new(config.resolved_allow_in_main()),
+            combinators: config.resolved_combinators(siblings),
+            assertions: config.assertions,
+            panic_functions: PanicFunctions::new(config.panic_functions)
+                .including_assert_failed(siblings),
+            helper_calls: HelperCallSites::for_crate(cx, &config.blame_helpers),
+            findings: FindingLimit::new(NO_UNWRAP_OR_ELSE_PANIC, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+            is_doctest: whitaker::hir::is_doctest_crate(cx),
+            is_test_harness,
+            harness_test_functions,
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
@@ -82,7 +110,8 @@ impl<'tcx> LateLintPass<'tcx> for NoUnwrapOrElsePanic {
             return;
         };
 
-        if segment.ident.name.as_str() != "unwrap_or_else" {
+        let state = &*self.state;
+        if !state.combinators.contains(segment.ident.name.as_str()) {
             return;
         }
 
@@ -98,19 +127,33 @@ impl<'tcx> LateLintPass<'tcx> for NoUnwrapOrElsePanic {
             return;
         };
 
-        let summary = summarise_context_with_harness(
-            cx,
-            expr.hir_id,
-            self.is_test_harness,
-            &self.harness_test_functions,
-        );
-
-        let panic_info = closure_panics(cx, body_id);
-        if !should_flag(&self.policy, &summary, &panic_info, self.is_doctest) {
+        let panic_info = closure_panics(cx, body_id, &state.panic_functions, state.assertions);
+        let flagged_at = |hir_id| {
+            let summary = summarise_context_with_harness(
+                cx,
+                hir_id,
+                state.is_test_harness,
+                &state.harness_test_functions,
+            );
+            should_flag(&state.policy, &summary, &panic_info, state.is_doctest)
+        };
+        if !flagged_at(expr.hir_id) {
             return;
         }
 
-        emit_diagnostic(cx, expr, receiver, &self.localizer);
+        // A call to a blamed helper is judged by its own context, so calls
+        // from tests stay exempt.
+        let call = FallbackCall {
+            method: segment.ident.name,
+            receiver,
+        };
+        for site in state.helper_calls.finding_sites(cx, expr, &state.localizer) {
+            if (!site.is_redirected() || flagged_at(site.hir_id))
+                && state.findings.admit(cx, site.hir_id, site.span)
+            {
+                emit_diagnostic(cx, &site, &call, &state.localizer);
+            }
+        }
     }
 }
 
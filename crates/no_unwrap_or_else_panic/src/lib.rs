@@ -2,7 +2,9 @@
 //!
 //! The lint inspects `unwrap_or_else` invocations on `Option` and `Result`
 //! receivers, flagging closures that panic directly or indirectly through
-//! `unwrap` or `expect`. Doctest contexts are exempt, and teams may optionally
+//! `unwrap` or `expect`. Sibling combinators that take a fallback closure
+//! first, such as `map_or_else`, `ok_or_else`, and `get_or_insert_with`, are
+//! checked the same way, and configuration selects which methods apply. Doctest contexts are exempt, and teams may optionally
 //! allow panicking fallbacks inside `main` via configuration. In test contexts,
 //! closures whose panic message interpolates a runtime value (e.g.
 //! `|e| panic!("got: {e}")`) are also exempt, because the interpolation
@@ -18,14 +20,26 @@ mod context;
 mod diagnostics;
 #[cfg(feature = "dylint-driver")]
 mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
 #[cfg(feature = "dylint-driver")]
 mod panic_detector;
 #[cfg(feature = "dylint-driver")]
 mod policy;
 
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
 
+#[cfg(feature = "dylint-driver")]
+pub use decision::{FallbackCallProjection, NoUnwrapOrElsePanicDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
 #[cfg(not(feature = "dylint-driver"))]
 mod stub {
     #[expect(dead_code, reason = "stub used when the driver feature is disabled")]
@@ -1,13 +1,15 @@
 //! Detect panics inside `unwrap_or_else` fallback closures.
 
 use rustc_hir as hir;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
-use rustc_span::sym;
+use rustc_span::{ExpnKind, MacroKind, Span, sym};
 use whitaker_common::SimplePath;
 
+use crate::policy::{Assertion, AssertionPolicy, PanicFunctions};
+
 /// All known panic entry points (plain and formatted).
 const PANIC_PATHS: &[&[&str]] = &[
     // core
@@ -33,6 +35,10 @@ const PANIC_PATHS: &[&[&str]] = &[
     &["std", "rt", "begin_panic_fmt"],
 ];
 
+/// The failure path of `assert_eq!` and `assert_ne!`, counted only when
+/// [`PanicFunctions::includes_assert_failed`] is set.
+const ASSERT_FAILED_PATH: &[&str] = &["core", "panicking", "assert_failed"];
+
 /// Summarizes whether a closure contains panics and distinguishes between
 /// plain (non-interpolated) and interpolated panic sites.
 ///
@@ -57,10 +63,21 @@ impl PanicInfo {
 
 /// Analyses the closure referenced by `body_id` and returns a [`PanicInfo`]
 /// describing whether it panics and distinguishing plain vs interpolated panics.
+///
+/// Calls to any of `panic_functions` count as panics alongside the standard
+/// library's entry points. Panics expanded from assertion macros count only
+/// when `assertions` says so.
 #[must_use]
-pub(crate) fn closure_panics<'tcx>(cx: &LateContext<'tcx>, body_id: hir::BodyId) -> PanicInfo {
+pub(crate) fn closure_panics<'tcx>(
+    cx: &LateContext<'tcx>,
+    body_id: hir::BodyId,
+    panic_functions: &PanicFunctions,
+    assertions: AssertionPolicy,
+) -> PanicInfo {
     let mut detector = PanicDetector {
         cx,
+        panic_functions,
+        assertions,
         panics: false,
         has_plain_panic: false,
         has_interpolated_panic: false,
@@ -100,6 +117,8 @@ fn ty_is_option_or_result<'tcx>(cx: &LateContext<'tcx>, ty: ty::Ty<'tcx>) -> boo
 
 struct PanicDetector<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
+    panic_functions: &'a PanicFunctions,
+    assertions: AssertionPolicy,
     panics: bool,
     has_plain_panic: bool,
     has_interpolated_panic: bool,
@@ -107,7 +126,9 @@ struct PanicDetector<'a, 'tcx> {
 
 impl<'a, 'tcx> rustc_hir::intravisit::Visitor<'tcx> for PanicDetector<'a, 'tcx> {
     fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
-        if is_panic_call(self.cx, expr) {
+        let counted_panic = is_panic_call(self.cx, expr, self.panic_functions)
+            && assertion_macro(expr.span).is_none_or(|assertion| self.assertions.counts(assertion));
+        if counted_panic {
             self.panics = true;
             if panic_args_use_interpolation(self.cx, expr) {
                 self.has_interpolated_panic = true;
@@ -132,8 +153,29 @@ fn is_unwrap_or_expect<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) ->
         && receiver_is_option_or_result(cx, receiver)
 }
 
-/// Returns `true` when `expr` calls a known panic entry point.
-fn is_panic_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+/// Returns the assertion macro `span` was expanded from, if any. A
+/// `debug_assert!` expands to `assert!`, so a debug assertion anywhere in the
+/// backtrace takes precedence.
+fn assertion_macro(span: Span) -> Option<Assertion> {
+    let mut assertion = None;
+    for expn in span.macro_backtrace() {
+        let ExpnKind::Macro(MacroKind::Bang, name) = expn.kind else {
+            continue;
+        };
+        match name.as_str() {
+            "debug_assert" | "debug_assert_eq" | "debug_assert_ne" => {
+                return Some(Assertion::DebugOnly);
+            }
+            "assert" | "assert_eq" | "assert_ne" => assertion = Some(Assertion::Always),
+            _ => {}
+        }
+    }
+    assertion
+}
+
+/// Returns `true` when `expr` calls a known panic entry point or one of the
+/// configured `panic_functions`.
+fn is_panic_call(cx: &LateContext<'_>, expr: &Expr<'_>, panic_functions: &PanicFunctions) -> bool {
     let ExprKind::Call(callee, _) = expr.kind else {
         return false;
     };
@@ -142,10 +184,25 @@ fn is_panic_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
         return false;
     };
 
-    let path = SimplePath::from(cx.tcx.def_path_str(def_id).as_str());
+    let qualified = qualified_path(cx, def_id);
+    let path = SimplePath::from(qualified.as_str());
     PANIC_PATHS
         .iter()
         .any(|candidate| whitaker_common::is_path_to(&path, candidate.iter().copied()))
+        || (panic_functions.includes_assert_failed()
+            && whitaker_common::is_path_to(&path, ASSERT_FAILED_PATH.iter().copied()))
+        || panic_functions.contains(&qualified)
+}
+
+/// Names `def_id` by its path, prefixing the crate name for local items so
+/// they match the fully qualified paths given in `panic_functions`.
+fn qualified_path(cx: &LateContext<'_>, def_id: DefId) -> String {
+    let path = cx.tcx.def_path_str(def_id);
+    if def_id.is_local() {
+        format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+    } else {
+        path
+    }
 }
 
 /// Checks whether a panic call's `format_args!` construction uses runtime
@@ -2,6 +2,8 @@
 
 use crate::context::ContextSummary;
 use crate::panic_detector::PanicInfo;
+use serde::Deserialize;
+use whitaker_common::SimplePath;
 
 /// Configuration flags controlling when the lint should emit diagnostics.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -17,6 +19,166 @@ impl LintPolicy {
     }
 }
 
+/// Behaviour version that checks the sibling combinators by default and
+/// counts the `assert_eq!` and `assert_ne!` failure path as a panic.
+pub(crate) const SIBLING_FALLBACKS: u32 = 2;
+
+/// Additional functions treated as panics, such as a project's `fatal`
+/// helper, named by fully qualified path.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct PanicFunctions {
+    paths: Vec<SimplePath>,
+    assert_failed: bool,
+}
+
+impl PanicFunctions {
+    /// Builds the list from configured paths; a leading `::` is ignored.
+    #[must_use]
+    pub(crate) fn new(paths: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            paths: paths.into_iter().map(SimplePath::from).collect(),
+            assert_failed: false,
+        }
+    }
+
+    /// Also counts `core::panicking::assert_failed`, which `assert_eq!` and
+    /// `assert_ne!` call, when `enabled`; see [`SIBLING_FALLBACKS`].
+    #[must_use]
+    pub(crate) fn including_assert_failed(mut self, enabled: bool) -> Self {
+        self.assert_failed = enabled;
+        self
+    }
+
+    /// Returns `true` when `core::panicking::assert_failed` counts as a
+    /// panic.
+    #[must_use]
+    pub(crate) const fn includes_assert_failed(&self) -> bool {
+        self.assert_failed
+    }
+
+    /// Returns `true` when `callee`, a fully qualified function path,
+    /// names one of the configured functions.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let functions = PanicFunctions::new([String::from("app::errors::fatal")]);
+    /// assert!(functions.contains("app::errors::fatal"));
+    /// assert!(!functions.contains("errors::fatal"));
+    /// ```
+    #[must_use]
+    pub(crate) fn contains(&self, callee: &str) -> bool {
+        let callee = SimplePath::from(callee);
+        self.paths.contains(&callee)
+    }
+}
+
+/// The standard library assertion macro a panic was expanded from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Assertion {
+    /// `assert!`, `assert_eq!`, or `assert_ne!`, which run in every build.
+    Always,
+    /// `debug_assert!`, `debug_assert_eq!`, or `debug_assert_ne!`, which run
+    /// only when debug assertions are enabled.
+    DebugOnly,
+}
+
+/// Which assertion macros inside a fallback closure count as panics.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum AssertionPolicy {
+    /// Every assertion counts.
+    #[default]
+    All,
+    /// Only assertions that run in every build count.
+    ReleaseOnly,
+    /// No assertion counts.
+    None,
+}
+
+impl AssertionPolicy {
+    /// Returns `true` when a panic expanded from `assertion` counts against
+    /// the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = AssertionPolicy::ReleaseOnly;
+    /// assert!(policy.counts(Assertion::Always));
+    /// assert!(!policy.counts(Assertion::DebugOnly));
+    /// ```
+    #[must_use]
+    pub(crate) const fn counts(self, assertion: Assertion) -> bool {
+        match self {
+            Self::All => true,
+            Self::ReleaseOnly => matches!(assertion, Assertion::Always),
+            Self::None => false,
+        }
+    }
+}
+
+/// Combinators checked when the configuration names none, before behaviour
+/// version [`SIBLING_FALLBACKS`].
+pub(crate) const LEGACY_COMBINATORS: &[&str] = &["unwrap_or_else"];
+
+/// Combinators checked when the configuration names none, from behaviour
+/// version [`SIBLING_FALLBACKS`].
+pub(crate) const DEFAULT_COMBINATORS: &[&str] = &[
+    "unwrap_or_else",
+    "map_or_else",
+    "ok_or_else",
+    "get_or_insert_with",
+];
+
+/// `Option` and `Result` methods whose first argument is a fallback closure
+/// the lint inspects for panics.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Combinators {
+    names: Vec<String>,
+}
+
+impl Default for Combinators {
+    fn default() -> Self {
+        Self::defaults(false)
+    }
+}
+
+impl Combinators {
+    /// Returns the combinators checked when the configuration names none,
+    /// including the siblings of `unwrap_or_else` when `siblings` is set.
+    #[must_use]
+    pub(crate) fn defaults(siblings: bool) -> Self {
+        let names = if siblings {
+            DEFAULT_COMBINATORS
+        } else {
+            LEGACY_COMBINATORS
+        };
+        Self::new(names.iter().map(|name| (*name).to_owned()))
+    }
+
+    /// Builds the selection from configured method names.
+    #[must_use]
+    pub(crate) fn new(names: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            names: names.into_iter().collect(),
+        }
+    }
+
+    /// Returns `true` when calls to the method `name` are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let combinators = Combinators::new([String::from("ok_or_else")]);
+    /// assert!(combinators.contains("ok_or_else"));
+    /// assert!(!combinators.contains("unwrap_or_else"));
+    /// ```
+    #[must_use]
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.names.iter().any(|candidate| candidate == name)
+    }
+}
+
 /// Decide whether the lint should emit based on context and closure behaviour.
 ///
 /// In test contexts, `.unwrap_or_else(|| panic!(...))` is permitted when the
@@ -164,4 +326,74 @@ mod tests {
             case.should_flag
         );
     }
+
+    #[rstest]
+    #[case::configured("app::errors::fatal", true)]
+    #[case::leading_separator("::app::errors::fatal", true)]
+    #[case::unqualified("errors::fatal", false)]
+    #[case::longer("app::errors::fatal::inner", false)]
+    #[case::other("app::errors::warn", false)]
+    fn panic_functions_match_fully_qualified_paths(#[case] callee: &str, #[case] expected: bool) {
+        let functions = PanicFunctions::new([String::from("::app::errors::fatal")]);
+
+        assert_eq!(functions.contains(callee), expected);
+    }
+
+    #[rstest]
+    fn no_panic_functions_are_configured_by_default() {
+        assert!(!PanicFunctions::default().contains("core::panicking::panic"));
+    }
+
+    #[rstest]
+    fn assert_failed_is_not_counted_by_default() {
+        assert!(!PanicFunctions::default().includes_assert_failed());
+        assert!(
+            PanicFunctions::default()
+                .including_assert_failed(true)
+                .includes_assert_failed()
+        );
+    }
+
+    #[rstest]
+    #[case::unwrap_or_else("unwrap_or_else", true)]
+    #[case::map_or_else("map_or_else", true)]
+    #[case::ok_or_else("ok_or_else", true)]
+    #[case::get_or_insert_with("get_or_insert_with", true)]
+    #[case::or_else("or_else", false)]
+    #[case::unwrap_or_default("unwrap_or_default", false)]
+    fn sibling_defaults(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(Combinators::defaults(true).contains(name), expected);
+    }
+
+    #[rstest]
+    #[case::unwrap_or_else("unwrap_or_else", true)]
+    #[case::map_or_else("map_or_else", false)]
+    #[case::ok_or_else("ok_or_else", false)]
+    #[case::get_or_insert_with("get_or_insert_with", false)]
+    fn legacy_defaults(#[case] name: &str, #[case] expected: bool) {
+        assert_eq!(Combinators::default().contains(name), expected);
+    }
+
+    #[rstest]
+    #[case::all_always(AssertionPolicy::All, Assertion::Always, true)]
+    #[case::all_debug(AssertionPolicy::All, Assertion::DebugOnly, true)]
+    #[case::release_only_always(AssertionPolicy::ReleaseOnly, Assertion::Always, true)]
+    #[case::release_only_debug(AssertionPolicy::ReleaseOnly, Assertion::DebugOnly, false)]
+    #[case::none_always(AssertionPolicy::None, Assertion::Always, false)]
+    #[case::none_debug(AssertionPolicy::None, Assertion::DebugOnly, false)]
+    fn assertion_policy_selects_counted_assertions(
+        #[case] policy: AssertionPolicy,
+        #[case] assertion: Assertion,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(policy.counts(assertion), expected);
+    }
+
+    #[rstest]
+    fn configured_combinators_replace_the_defaults() {
+        let combinators = Combinators::new([String::from("or_else")]);
+
+        assert!(combinators.contains("or_else"));
+        assert!(!combinators.contains("unwrap_or_else"));
+    }
 }
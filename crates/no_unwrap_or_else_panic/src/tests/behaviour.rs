@@ -2,10 +2,10 @@
 
 use crate::context::ContextSummary;
 use crate::panic_detector::PanicInfo;
-use crate::policy::{LintPolicy, should_flag};
+use crate::policy::{Assertion, AssertionPolicy, Combinators, LintPolicy, should_flag};
 use rstest::fixture;
 use rstest_bdd_macros::{given, scenario, then, when};
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 
 #[derive(Default)]
 struct DecisionWorld {
@@ -13,16 +13,36 @@ struct DecisionWorld {
     allow_in_main: Cell<bool>,
     panic_info: Cell<PanicInfo>,
     is_doctest: Cell<bool>,
+    combinator: RefCell<Option<String>>,
+    combinators: RefCell<Combinators>,
+    assertion: Cell<Option<Assertion>>,
+    assertions: Cell<AssertionPolicy>,
     should_flag: Cell<Option<bool>>,
 }
 
 impl DecisionWorld {
     fn evaluate(&self) -> bool {
+        let combinator = self.combinator.borrow();
+        let method = combinator.as_deref().unwrap_or("unwrap_or_else");
+        if !self.combinators.borrow().contains(method) {
+            return false;
+        }
+
+        let counted = self
+            .assertion
+            .get()
+            .is_none_or(|assertion| self.assertions.get().counts(assertion));
+        let panic_info = if counted {
+            self.panic_info.get()
+        } else {
+            PanicInfo::default()
+        };
+
         let policy = LintPolicy::new(self.allow_in_main.get());
         should_flag(
             &policy,
             &self.summary.get(),
-            &self.panic_info.get(),
+            &panic_info,
             self.is_doctest.get(),
         )
     }
@@ -94,6 +114,32 @@ fn given_doctest(world: &DecisionWorld) {
     world.is_doctest.set(true);
 }
 
+#[given("the fallback is passed to {method}")]
+fn given_combinator(world: &DecisionWorld, method: String) {
+    *world.combinator.borrow_mut() = Some(method);
+}
+
+#[given("behaviour version 2 is enabled")]
+fn given_behaviour_version_2(world: &DecisionWorld) {
+    *world.combinators.borrow_mut() = Combinators::defaults(true);
+}
+
+#[given("only {method} is checked")]
+fn given_only_combinator(world: &DecisionWorld, method: String) {
+    *world.combinators.borrow_mut() = Combinators::new([method]);
+}
+
+#[given("a debug assertion in the fallback")]
+fn given_debug_assertion(world: &DecisionWorld) {
+    given_panicking(world);
+    world.assertion.set(Some(Assertion::DebugOnly));
+}
+
+#[given("only release assertions count")]
+fn given_release_assertions(world: &DecisionWorld) {
+    world.assertions.set(AssertionPolicy::ReleaseOnly);
+}
+
 #[when("the lint policy is evaluated")]
 fn when_policy_evaluated(world: &DecisionWorld) {
     world.should_flag.set(Some(world.evaluate()));
@@ -138,3 +184,28 @@ fn scenario_doctest(world: DecisionWorld) {
 fn scenario_interpolated_panic_in_test(world: DecisionWorld) {
     let _ = world;
 }
+
+#[scenario(path = "tests/features/policy.feature", index = 6)]
+fn scenario_sibling_combinator(world: DecisionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/policy.feature", index = 7)]
+fn scenario_deselected_combinator(world: DecisionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/policy.feature", index = 8)]
+fn scenario_debug_assertion_by_default(world: DecisionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/policy.feature", index = 9)]
+fn scenario_debug_assertion_release_only(world: DecisionWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/policy.feature", index = 10)]
+fn scenario_sibling_combinator_by_default(world: DecisionWorld) {
+    let _ = world;
+}
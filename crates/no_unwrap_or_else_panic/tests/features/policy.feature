@@ -38,3 +38,34 @@ Feature: Policy evaluation for no_unwrap_or_else_panic
     And code runs inside a test
     When the lint policy is evaluated
     Then the lint is skipped
+
+  Scenario: Panicking fallback in a sibling combinator
+    Given a panicking unwrap_or_else fallback outside tests
+    And behaviour version 2 is enabled
+    And the fallback is passed to map_or_else
+    When the lint policy is evaluated
+    Then the lint triggers
+
+  Scenario: Panicking fallback in a deselected combinator
+    Given a panicking unwrap_or_else fallback outside tests
+    And the fallback is passed to ok_or_else
+    And only unwrap_or_else is checked
+    When the lint policy is evaluated
+    Then the lint is skipped
+
+  Scenario: Debug assertion in a fallback counts by default
+    Given a debug assertion in the fallback
+    When the lint policy is evaluated
+    Then the lint triggers
+
+  Scenario: Debug assertion in a fallback when only release assertions count
+    Given a debug assertion in the fallback
+    And only release assertions count
+    When the lint policy is evaluated
+    Then the lint is skipped
+
+  Scenario: Panicking fallback in a sibling combinator at the default behaviour version
+    Given a panicking unwrap_or_else fallback outside tests
+    And the fallback is passed to map_or_else
+    When the lint policy is evaluated
+    Then the lint is skipped
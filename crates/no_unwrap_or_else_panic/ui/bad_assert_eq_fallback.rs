@@ -0,0 +1,10 @@
+//! UI test: `assert_eq!` counts as a panic at behaviour version 2.
+#![deny(no_unwrap_or_else_panic)]
+
+fn parse(input: &str, expected: u8) -> u8 {
+    input.parse::<u8>().unwrap_or_else(|_| { assert_eq!(expected, 0, "input must parse"); 0 })
+}
+
+fn main() {
+    let _ = parse("7", 0);
+}
@@ -0,0 +1,21 @@
+//! UI test: findings in a blamed helper are reported at its call sites.
+#![deny(no_unwrap_or_else_panic)]
+
+mod config {
+    pub fn port(raw: &str) -> u16 {
+        raw.parse::<u16>()
+            .unwrap_or_else(|_| panic!("port must be a number"))
+    }
+}
+
+fn listen_port() -> u16 {
+    config::port("8080")
+}
+
+fn admin_port() -> u16 {
+    config::port("9090")
+}
+
+fn main() {
+    let _ = (listen_port(), admin_port());
+}
@@ -0,0 +1,19 @@
+//! UI test: configured panic-like helpers count as panics.
+#![deny(no_unwrap_or_else_panic)]
+
+mod errors {
+    pub fn fatal(message: &str) -> ! {
+        eprintln!("fatal: {message}");
+        std::process::exit(1)
+    }
+}
+
+fn parse(input: &str) -> u8 {
+    input
+        .parse::<u8>()
+        .unwrap_or_else(|_| errors::fatal("input must be a byte"))
+}
+
+fn main() {
+    let _ = parse("7");
+}
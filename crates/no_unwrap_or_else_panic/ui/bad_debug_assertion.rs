@@ -0,0 +1,10 @@
+//! UI test: assertions in the fallback closure count as panics by default.
+#![deny(no_unwrap_or_else_panic)]
+
+fn parse(input: &str, strict: bool) -> u8 {
+    input.parse::<u8>().unwrap_or_else(|_| { debug_assert!(!strict, "strict input must parse"); 0 })
+}
+
+fn main() {
+    let _ = parse("7", false);
+}
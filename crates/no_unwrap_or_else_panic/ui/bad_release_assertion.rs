@@ -0,0 +1,10 @@
+//! UI test: `assert!` still counts when only release assertions are checked.
+#![deny(no_unwrap_or_else_panic)]
+
+fn parse(input: &str, strict: bool) -> u8 {
+    input.parse::<u8>().unwrap_or_else(|_| { assert!(!strict, "strict input must parse"); 0 })
+}
+
+fn main() {
+    let _ = parse("7", false);
+}
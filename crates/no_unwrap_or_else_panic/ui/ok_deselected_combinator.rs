@@ -0,0 +1,10 @@
+//! UI test: combinators missing from the configured list should not lint.
+#![deny(no_unwrap_or_else_panic)]
+
+fn doubled(value: Result<i32, &str>) -> i32 {
+    value.map_or_else(|err| panic!("err: {err}"), |value| value * 2)
+}
+
+fn main() {
+    let _ = doubled(Ok(1));
+}
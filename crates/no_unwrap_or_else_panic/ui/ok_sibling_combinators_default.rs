@@ -0,0 +1,21 @@
+//! UI test: sibling combinators are not checked at the default behaviour
+//! version.
+#![deny(no_unwrap_or_else_panic)]
+
+fn doubled(value: Result<i32, &str>) -> i32 {
+    value.map_or_else(|err| panic!("err: {err}"), |value| value * 2)
+}
+
+fn required(value: Option<i32>) -> Result<i32, String> {
+    value.ok_or_else(|| panic!("missing value"))
+}
+
+fn cached(slot: &mut Option<i32>) -> i32 {
+    *slot.get_or_insert_with(|| panic!("cache miss"))
+}
+
+fn main() {
+    let _ = doubled(Ok(1));
+    let _ = required(Some(2));
+    let _ = cached(&mut Some(3));
+}
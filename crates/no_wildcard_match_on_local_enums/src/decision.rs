@@ -0,0 +1,183 @@
+//! Pure decision logic for `no_wildcard_match_on_local_enums`.
+//!
+//! The driver finds matches with a wildcard arm on crate-local enums and
+//! lists the variants no other arm names.
+//! [`NoWildcardMatchOnLocalEnumsDecision`] applies the same
+//! [`LocalEnum::should_flag`] limit and names the [`replacement`] or-pattern,
+//! when there is one, as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_VARIANTS, LINT_NAME};
+use crate::policy::{Fields, LocalEnum, VariantPattern, replacement};
+
+/// Reason key for wildcard arms on local enums.
+const WILDCARD_ARM: &str = "wildcard_arm";
+
+/// A variant the wildcard arm covers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UncoveredVariantProjection {
+    /// The variant's name.
+    pub name: String,
+    /// How its fields are written: `unit`, `tuple`, or `named`.
+    pub fields: String,
+}
+
+impl UncoveredVariantProjection {
+    fn pattern(&self) -> VariantPattern {
+        let fields = match self.fields.as_str() {
+            "tuple" => Fields::Tuple,
+            "named" => Fields::Named,
+            _ => Fields::Unit,
+        };
+        VariantPattern {
+            name: self.name.clone(),
+            fields,
+        }
+    }
+}
+
+/// The facts about one match with a wildcard arm that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WildcardMatchProjection {
+    /// The matched enum's name.
+    pub enum_name: String,
+    /// Whether the enum is `#[non_exhaustive]`.
+    pub non_exhaustive: bool,
+    /// The number of variants the enum declares.
+    pub variants: usize,
+    /// The variants no other arm names, in declaration order.
+    pub uncovered: Vec<UncoveredVariantProjection>,
+}
+
+/// Reports wildcard arms in matches on enums with at most `max_variants`
+/// variants.
+///
+/// # Examples
+///
+/// ```ignore
+/// let matched = WildcardMatchProjection {
+///     enum_name: "Shape".to_owned(),
+///     variants: 3,
+///     ..WildcardMatchProjection::default()
+/// };
+/// let decision = NoWildcardMatchOnLocalEnumsDecision::default();
+/// assert!(decision.decide(&matched).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoWildcardMatchOnLocalEnumsDecision {
+    max_variants: usize,
+}
+
+impl NoWildcardMatchOnLocalEnumsDecision {
+    /// Creates a decision exempting enums with more than `max_variants`
+    /// variants.
+    #[must_use]
+    pub const fn new(max_variants: usize) -> Self {
+        Self { max_variants }
+    }
+}
+
+impl Default for NoWildcardMatchOnLocalEnumsDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_VARIANTS)
+    }
+}
+
+impl LintDecision for NoWildcardMatchOnLocalEnumsDecision {
+    type Projection = WildcardMatchProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, matched: &WildcardMatchProjection) -> Option<Finding> {
+        let local = LocalEnum {
+            non_exhaustive: matched.non_exhaustive,
+            variants: matched.variants,
+        };
+        if !local.should_flag(self.max_variants) {
+            return None;
+        }
+        let uncovered: Vec<VariantPattern> = matched
+            .uncovered
+            .iter()
+            .map(UncoveredVariantProjection::pattern)
+            .collect();
+        let finding = Finding::new(LINT_NAME, WILDCARD_ARM);
+        Some(match replacement(&matched.enum_name, &uncovered) {
+            Some(pattern) => finding.with_subject(pattern),
+            None => finding,
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<WildcardMatchProjection>> {
+        let variant = |name: &str, fields: &str| UncoveredVariantProjection {
+            name: name.to_owned(),
+            fields: fields.to_owned(),
+        };
+        let matched = WildcardMatchProjection {
+            enum_name: "Shape".to_owned(),
+            non_exhaustive: false,
+            variants: self.max_variants,
+            uncovered: vec![variant("Point", "unit"), variant("Line", "tuple")],
+        };
+        vec![
+            DecisionFixture::flagged("at the variant limit", matched.clone(), WILDCARD_ARM),
+            DecisionFixture::flagged(
+                "every variant named elsewhere",
+                WildcardMatchProjection {
+                    uncovered: Vec::new(),
+                    ..matched.clone()
+                },
+                WILDCARD_ARM,
+            ),
+            DecisionFixture::clean(
+                "non-exhaustive enum",
+                WildcardMatchProjection {
+                    non_exhaustive: true,
+                    ..matched.clone()
+                },
+            ),
+            DecisionFixture::clean(
+                "over the variant limit",
+                WildcardMatchProjection {
+                    variants: self.max_variants + 1,
+                    ..matched
+                },
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_replacement() {
+        let matched = WildcardMatchProjection {
+            enum_name: "Shape".to_owned(),
+            variants: 3,
+            uncovered: vec![UncoveredVariantProjection {
+                name: "Polygon".to_owned(),
+                fields: "named".to_owned(),
+            }],
+            ..WildcardMatchProjection::default()
+        };
+        let finding = NoWildcardMatchOnLocalEnumsDecision::default()
+            .decide(&matched)
+            .expect("the wildcard should be reported");
+
+        assert_eq!(finding.subject(), Some("Shape::Polygon { .. }"));
+    }
+
+    #[rstest]
+    #[case::default_limit(NoWildcardMatchOnLocalEnumsDecision::default())]
+    #[case::small_limit(NoWildcardMatchOnLocalEnumsDecision::new(2))]
+    fn decision_conforms(#[case] decision: NoWildcardMatchOnLocalEnumsDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
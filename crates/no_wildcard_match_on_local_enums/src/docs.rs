@@ -0,0 +1,22 @@
+//! Embedded documentation for the `no_wildcard_match_on_local_enums` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `no_wildcard_match_on_local_enums`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "no_wildcard_match_on_local_enums",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `_` arms in matches on enums defined in the same crate, unless the enum is `#[non_exhaustive]` or has more variants than the configured limit.",
+    rationale: "A wildcard arm silently absorbs variants added to the enum later, so the compiler cannot point at the matches that need updating. The crate owns both the enum and the match, so naming every variant costs little and turns each new variant into a compile error at the places it matters.",
+    bad_example: "match shape {\n    Shape::Circle(radius) => area(radius),\n    _ => 0.0,\n}",
+    good_example: "match shape {\n    Shape::Circle(radius) => area(radius),\n    Shape::Point | Shape::Line(..) => 0.0,\n}",
+    config: &[ConfigKeyDocs {
+        key: "max_variants",
+        default: "10",
+        description: "Enums with more variants than this are exempt, since listing them all would outweigh the benefit.",
+    }],
+};
@@ -0,0 +1,361 @@
+//! Lint pass reporting wildcard arms in matches on crate-local enums.
+//!
+//! Every `match` written in source whose scrutinee, once references are
+//! peeled, is an enum defined in the crate is checked for unguarded `_`
+//! arms. The enum is described as a [`LocalEnum`], so `#[non_exhaustive]`
+//! enums and enums above `max_variants` are exempt. Variants named by other
+//! unguarded arms, with every field matched by a wildcard or binding, count
+//! as covered; the rest are rendered through [`replacement`]. Desugared
+//! matches, such as `?` and `for` loops, and matches produced by macro
+//! expansion are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{ExprKind, MatchSource, PatExprKind, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, AdtDef};
+use rustc_span::Span;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{Fields, LocalEnum, VariantPattern, replacement};
+
+pub(crate) const LINT_NAME: &str = "no_wildcard_match_on_local_enums";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_VARIANTS: usize = 10;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_variants: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_variants: DEFAULT_MAX_VARIANTS,
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub NO_WILDCARD_MATCH_ON_LOCAL_ENUMS,
+    Warn,
+    "matches on enums defined in the same crate should name their variants rather than use a `_` arm",
+    NoWildcardMatchOnLocalEnums::default()
+}
+
+/// Lint pass that finds wildcard arms in matches on crate-local enums.
+#[derive(Default)]
+pub struct NoWildcardMatchOnLocalEnums {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Variant limit, finding limit, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for NoWildcardMatchOnLocalEnums {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(NO_WILDCARD_MATCH_ON_LOCAL_ENUMS, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ExprKind::Match(scrutinee, arms, MatchSource::Normal) = expr.kind else {
+            return;
+        };
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(wildcard) = arms.iter().find(|arm| is_wildcard_arm(arm)) else {
+            return;
+        };
+        let ty = cx.typeck_results().expr_ty(scrutinee).peel_refs();
+        let ty::Adt(adt, _) = ty.kind() else {
+            return;
+        };
+        if !adt.is_enum() || !adt.did().is_local() {
+            return;
+        }
+        let state = &*self.state;
+        let local = LocalEnum {
+            non_exhaustive: adt.is_variant_list_non_exhaustive(),
+            variants: adt.variants().len(),
+        };
+        let span = wildcard.pat.span;
+        let max_variants = state
+            .findings
+            .config_at(cx, span, &state.config)
+            .max_variants;
+        if !local.should_flag(max_variants) {
+            return;
+        }
+
+        let name = cx.tcx.item_name(adt.did());
+        debug!(target: LINT_NAME, "wildcard arm in a match on `{name}`");
+        if state.findings.admit(cx, wildcard.hir_id, span) {
+            let covered = covered_variants(cx, arms);
+            let message = WildcardArmMessage {
+                enum_name: name.as_str(),
+                replacement: replacement(name.as_str(), &uncovered_variants(*adt, &covered)),
+            };
+            emit_diagnostic(cx, span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns `true` for an unguarded `_` arm written in source.
+fn is_wildcard_arm(arm: &hir::Arm<'_>) -> bool {
+    arm.guard.is_none() && matches!(arm.pat.kind, PatKind::Wild) && !arm.pat.span.from_expansion()
+}
+
+/// Returns `true` for a pattern matching any value: `_` or a plain binding.
+fn is_catch_all(pat: &hir::Pat<'_>) -> bool {
+    matches!(pat.kind, PatKind::Wild | PatKind::Binding(.., None))
+}
+
+/// Returns the variants that unguarded `arms` match in full, whatever their
+/// fields hold.
+fn covered_variants(cx: &LateContext<'_>, arms: &[hir::Arm<'_>]) -> Vec<DefId> {
+    let mut pending: Vec<&hir::Pat<'_>> = arms
+        .iter()
+        .filter(|arm| arm.guard.is_none())
+        .map(|arm| arm.pat)
+        .collect();
+    let mut covered = Vec::new();
+    while let Some(pat) = pending.pop() {
+        match pat.kind {
+            PatKind::Or(alternatives) => pending.extend(alternatives),
+            PatKind::Binding(.., Some(inner))
+            | PatKind::Ref(inner, ..)
+            | PatKind::Box(inner)
+            | PatKind::Deref(inner) => pending.push(inner),
+            PatKind::Expr(hir::PatExpr {
+                kind: PatExprKind::Path(qpath),
+                hir_id,
+                ..
+            }) => covered.extend(variant_id(cx, qpath, *hir_id)),
+            PatKind::TupleStruct(ref qpath, fields, _) if fields.iter().all(is_catch_all) => {
+                covered.extend(variant_id(cx, qpath, pat.hir_id));
+            }
+            PatKind::Struct(ref qpath, fields, _)
+                if fields.iter().all(|field| is_catch_all(field.pat)) =>
+            {
+                covered.extend(variant_id(cx, qpath, pat.hir_id));
+            }
+            _ => {}
+        }
+    }
+    covered
+}
+
+/// Resolves a pattern path to the variant it names.
+fn variant_id(cx: &LateContext<'_>, qpath: &hir::QPath<'_>, hir_id: hir::HirId) -> Option<DefId> {
+    match cx.qpath_res(qpath, hir_id) {
+        Res::Def(DefKind::Variant, id) => Some(id),
+        Res::Def(DefKind::Ctor(CtorOf::Variant, _), id) => Some(cx.tcx.parent(id)),
+        _ => None,
+    }
+}
+
+/// Returns the variants of `adt` missing from `covered`, in declaration
+/// order.
+fn uncovered_variants(adt: AdtDef<'_>, covered: &[DefId]) -> Vec<VariantPattern> {
+    adt.variants()
+        .iter()
+        .filter(|variant| !covered.contains(&variant.def_id))
+        .map(|variant| VariantPattern {
+            name: variant.name.to_string(),
+            fields: match variant.ctor_kind() {
+                Some(CtorKind::Const) => Fields::Unit,
+                Some(CtorKind::Fn) => Fields::Tuple,
+                None => Fields::Named,
+            },
+        })
+        .collect()
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &WildcardArmMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        NO_WILDCARD_MATCH_ON_LOCAL_ENUMS,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one wildcard arm interpolates.
+#[derive(Clone, Debug)]
+struct WildcardArmMessage<'a> {
+    enum_name: &'a str,
+    replacement: Option<String>,
+}
+
+impl WildcardArmMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("enum"),
+            FluentValue::from(self.enum_name.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("fix"),
+            FluentValue::from(if self.replacement.is_some() {
+                "list"
+            } else {
+                "spell"
+            }),
+        );
+        args.insert(
+            Cow::Borrowed("replacement"),
+            FluentValue::from(self.replacement.clone().unwrap_or_default()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let enum_name = self.enum_name;
+        DiagnosticMessageSet::new(
+            format!("Wildcard arm in a match on `{enum_name}`, which this crate defines."),
+            format!(
+                "A variant added to `{enum_name}` would fall into this arm silently instead of \
+                 failing to compile here."
+            ),
+            match &self.replacement {
+                Some(replacement) => {
+                    format!("Name the remaining variants instead: `{replacement}`.")
+                }
+                None => String::from(
+                    "Replace `_` with patterns naming the variants it covers, so every variant is \
+                     matched explicitly.",
+                ),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(replacement: Option<&str>) -> WildcardArmMessage<'static> {
+        WildcardArmMessage {
+            enum_name: "Shape",
+            replacement: replacement.map(str::to_owned),
+        }
+    }
+
+    fn resolve(message: &WildcardArmMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_allows_ten_variants() {
+        assert_eq!(Config::default().max_variants, 10);
+    }
+
+    #[rstest]
+    fn fallback_help_gives_the_replacement() {
+        let messages = message(Some("Shape::Point | Shape::Line(..)")).fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Wildcard arm in a match on `Shape`, which this crate defines."
+        );
+        assert_eq!(
+            messages.help(),
+            "Name the remaining variants instead: `Shape::Point | Shape::Line(..)`."
+        );
+    }
+
+    #[rstest]
+    #[case::listed(Some("Shape::Point"))]
+    #[case::spelled(None)]
+    fn english_messages_match_the_fallback(#[case] replacement: Option<&str>) {
+        let message = message(replacement);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&message(Some("Shape::Point")), locale);
+
+        assert!(messages.primary().contains("`Shape`"));
+        assert!(messages.help().contains("`Shape::Point`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
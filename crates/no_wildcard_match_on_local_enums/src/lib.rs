@@ -0,0 +1,45 @@
+//! Lint crate flagging wildcard match arms on enums defined in the same
+//! crate.
+//!
+//! A `_` arm swallows every variant the other arms do not name, including
+//! variants added later, so the compiler cannot point at the matches a new
+//! variant affects. Because a local enum and its matches change together,
+//! the lint reports unguarded `_` arms in matches on such enums and suggests
+//! naming the remaining variants. Enums marked `#[non_exhaustive]`, and enums
+//! with more variants than the configured limit, are exempt. The lint is
+//! experimental and ships behind the
+//! `experimental-no-wildcard-match-on-local-enums` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{
+    NoWildcardMatchOnLocalEnumsDecision, UncoveredVariantProjection, WildcardMatchProjection,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn no_wildcard_match_on_local_enums_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,157 @@
+//! Rules deciding which wildcard arms are reported and what replaces them.
+//!
+//! The driver describes the matched enum as a [`LocalEnum`] and lists the
+//! variants no other arm covers as [`VariantPattern`]s. An enum is exempt
+//! when it is `#[non_exhaustive]` or has more variants than the configured
+//! limit. The uncovered variants become the or-pattern suggested in place of
+//! the wildcard. The rules are kept free of compiler types so they can be
+//! tested directly.
+
+/// The enum a match with a wildcard arm is on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct LocalEnum {
+    /// The enum is marked `#[non_exhaustive]`.
+    pub(crate) non_exhaustive: bool,
+    /// Number of variants the enum declares.
+    pub(crate) variants: usize,
+}
+
+impl LocalEnum {
+    /// Returns `true` when a wildcard arm matching this enum is reported,
+    /// given the `max_variants` limit.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let shape = LocalEnum { non_exhaustive: false, variants: 3 };
+    /// assert!(shape.should_flag(10));
+    /// assert!(!shape.should_flag(2));
+    /// ```
+    #[must_use]
+    pub(crate) const fn should_flag(self, max_variants: usize) -> bool {
+        !self.non_exhaustive && self.variants <= max_variants
+    }
+}
+
+/// How a variant's fields are written in a pattern.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Fields {
+    /// A unit variant, matched by its path alone.
+    Unit,
+    /// A tuple variant, matched with `(..)`.
+    Tuple,
+    /// A struct variant, matched with `{ .. }`.
+    Named,
+}
+
+/// A variant the wildcard arm covers, by name and field shape.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct VariantPattern {
+    pub(crate) name: String,
+    pub(crate) fields: Fields,
+}
+
+impl VariantPattern {
+    /// Renders a pattern matching any value of this variant of `enum_path`.
+    fn render(&self, enum_path: &str) -> String {
+        let name = &self.name;
+        match self.fields {
+            Fields::Unit => format!("{enum_path}::{name}"),
+            Fields::Tuple => format!("{enum_path}::{name}(..)"),
+            Fields::Named => format!("{enum_path}::{name} {{ .. }}"),
+        }
+    }
+}
+
+/// Returns the or-pattern that could replace the wildcard, listing the
+/// `uncovered` variants of `enum_path` in declaration order, or `None` when
+/// other arms name every variant.
+///
+/// # Examples
+///
+/// ```ignore
+/// let uncovered = [
+///     VariantPattern { name: "Point".into(), fields: Fields::Unit },
+///     VariantPattern { name: "Line".into(), fields: Fields::Tuple },
+/// ];
+/// assert_eq!(
+///     replacement("Shape", &uncovered).as_deref(),
+///     Some("Shape::Point | Shape::Line(..)"),
+/// );
+/// ```
+#[must_use]
+pub(crate) fn replacement(enum_path: &str, uncovered: &[VariantPattern]) -> Option<String> {
+    if uncovered.is_empty() {
+        return None;
+    }
+    Some(
+        uncovered
+            .iter()
+            .map(|variant| variant.render(enum_path))
+            .collect::<Vec<_>>()
+            .join(" | "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn variant(name: &str, fields: Fields) -> VariantPattern {
+        VariantPattern {
+            name: name.to_owned(),
+            fields,
+        }
+    }
+
+    #[rstest]
+    #[case::small(false, 3, true)]
+    #[case::at_limit(false, 10, true)]
+    #[case::above_limit(false, 11, false)]
+    #[case::non_exhaustive(true, 3, false)]
+    fn small_exhaustive_enums_are_flagged(
+        #[case] non_exhaustive: bool,
+        #[case] variants: usize,
+        #[case] flagged: bool,
+    ) {
+        let local = LocalEnum {
+            non_exhaustive,
+            variants,
+        };
+
+        assert_eq!(local.should_flag(10), flagged);
+    }
+
+    #[rstest]
+    #[case::unit(Fields::Unit, "Shape::Point")]
+    #[case::tuple(Fields::Tuple, "Shape::Point(..)")]
+    #[case::named(Fields::Named, "Shape::Point { .. }")]
+    fn variants_render_by_field_shape(#[case] fields: Fields, #[case] expected: &str) {
+        assert_eq!(
+            replacement("Shape", &[variant("Point", fields)]).as_deref(),
+            Some(expected)
+        );
+    }
+
+    #[rstest]
+    fn replacements_join_variants_in_order() {
+        let uncovered = [
+            variant("Point", Fields::Unit),
+            variant("Line", Fields::Tuple),
+            variant("Polygon", Fields::Named),
+        ];
+
+        assert_eq!(
+            replacement("geometry::Shape", &uncovered).as_deref(),
+            Some(
+                "geometry::Shape::Point | geometry::Shape::Line(..) | geometry::Shape::Polygon { .. }"
+            )
+        );
+    }
+
+    #[rstest]
+    fn nothing_replaces_a_wildcard_when_every_variant_is_named() {
+        assert_eq!(replacement("Shape", &[]), None);
+    }
+}
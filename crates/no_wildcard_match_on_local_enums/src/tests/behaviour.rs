@@ -0,0 +1,91 @@
+//! Behaviour-driven coverage for the wildcard arm policy.
+
+use crate::policy::LocalEnum;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+struct WildcardWorld {
+    variants: Cell<usize>,
+    non_exhaustive: Cell<bool>,
+    max_variants: Cell<usize>,
+    flagged: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> WildcardWorld {
+    WildcardWorld {
+        variants: Cell::new(0),
+        non_exhaustive: Cell::new(false),
+        max_variants: Cell::new(10),
+        flagged: Cell::new(None),
+    }
+}
+
+#[given("a local enum with {count} variants")]
+fn given_enum(world: &WildcardWorld, count: usize) {
+    world.variants.set(count);
+}
+
+#[given("the enum is non-exhaustive")]
+fn given_non_exhaustive(world: &WildcardWorld) {
+    world.non_exhaustive.set(true);
+}
+
+#[given("the variant limit is {limit}")]
+fn given_limit(world: &WildcardWorld, limit: usize) {
+    world.max_variants.set(limit);
+}
+
+#[when("the wildcard arm is checked")]
+fn when_checked(world: &WildcardWorld) {
+    let local = LocalEnum {
+        non_exhaustive: world.non_exhaustive.get(),
+        variants: world.variants.get(),
+    };
+    world
+        .flagged
+        .set(Some(local.should_flag(world.max_variants.get())));
+}
+
+#[then("the wildcard arm is reported")]
+fn then_reported(world: &WildcardWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the wildcard arm is accepted")]
+fn then_accepted(world: &WildcardWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/no_wildcard_match_on_local_enums.feature",
+    index = 0
+)]
+fn scenario_small_enum(world: WildcardWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_wildcard_match_on_local_enums.feature",
+    index = 1
+)]
+fn scenario_non_exhaustive_enum(world: WildcardWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_wildcard_match_on_local_enums.feature",
+    index = 2
+)]
+fn scenario_large_enum(world: WildcardWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/no_wildcard_match_on_local_enums.feature",
+    index = 3
+)]
+fn scenario_configured_limit(world: WildcardWorld) {
+    let _ = world;
+}
@@ -0,0 +1,25 @@
+Feature: No wildcard match on local enums
+  Wildcard arms in matches on enums defined in the same crate are reported,
+  unless the enum is non-exhaustive or has more variants than the limit.
+
+  Scenario: A wildcard on a small local enum is reported
+    Given a local enum with 3 variants
+    When the wildcard arm is checked
+    Then the wildcard arm is reported
+
+  Scenario: A wildcard on a non-exhaustive enum is accepted
+    Given a local enum with 3 variants
+    And the enum is non-exhaustive
+    When the wildcard arm is checked
+    Then the wildcard arm is accepted
+
+  Scenario: A wildcard on a large enum is accepted
+    Given a local enum with 12 variants
+    When the wildcard arm is checked
+    Then the wildcard arm is accepted
+
+  Scenario: A lower variant limit exempts smaller enums
+    Given a local enum with 3 variants
+    And the variant limit is 2
+    When the wildcard arm is checked
+    Then the wildcard arm is accepted
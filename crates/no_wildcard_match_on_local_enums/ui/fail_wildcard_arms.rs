@@ -0,0 +1,30 @@
+//! Negative UI fixture: wildcard arms in matches on local enums.
+#![warn(no_wildcard_match_on_local_enums)]
+
+enum Shape {
+    Point,
+    Line(f64),
+    Circle { radius: f64 },
+}
+
+fn area(shape: &Shape) -> f64 {
+    match shape {
+        Shape::Circle { radius } => radius * radius * 3.0,
+        _ => 0.0,
+    }
+}
+
+fn length(shape: Shape) -> f64 {
+    match shape {
+        Shape::Line(1.0) => 1.0,
+        Shape::Line(length) if length > 0.0 => length,
+        Shape::Point | Shape::Circle { .. } => 0.0,
+        _ => 0.0,
+    }
+}
+
+fn main() {
+    let _ = area(&Shape::Point);
+    let _ = length(Shape::Line(2.0));
+    let _ = area(&Shape::Circle { radius: 1.0 });
+}
@@ -0,0 +1,57 @@
+//! Positive UI fixture: exhaustive matches, guarded wildcards,
+//! non-exhaustive and foreign enums, and matches from macros are accepted.
+#![warn(no_wildcard_match_on_local_enums)]
+
+use std::cmp::Ordering;
+
+enum Shape {
+    Point,
+    Line(f64),
+}
+
+#[non_exhaustive]
+enum Event {
+    Started,
+    Stopped,
+}
+
+fn length(shape: &Shape) -> f64 {
+    match shape {
+        Shape::Point => 0.0,
+        Shape::Line(length) => *length,
+    }
+}
+
+fn is_point(shape: &Shape) -> bool {
+    matches!(shape, Shape::Point)
+}
+
+fn positive(shape: &Shape) -> bool {
+    match shape {
+        Shape::Line(length) if *length > 0.0 => true,
+        Shape::Line(_) | Shape::Point => false,
+    }
+}
+
+fn started(event: &Event) -> bool {
+    match event {
+        Event::Started => true,
+        _ => false,
+    }
+}
+
+fn is_less(ordering: Ordering) -> bool {
+    match ordering {
+        Ordering::Less => true,
+        _ => false,
+    }
+}
+
+fn main() {
+    let _ = length(&Shape::Line(1.0));
+    let _ = is_point(&Shape::Point);
+    let _ = positive(&Shape::Point);
+    let _ = started(&Event::Started);
+    let _ = started(&Event::Stopped);
+    let _ = is_less(1.cmp(&2));
+}
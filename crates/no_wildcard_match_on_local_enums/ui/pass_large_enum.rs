@@ -0,0 +1,20 @@
+//! Positive UI fixture: enums above the configured variant limit are exempt.
+#![warn(no_wildcard_match_on_local_enums)]
+
+enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+fn is_red(colour: &Colour) -> bool {
+    match colour {
+        Colour::Red => true,
+        _ => false,
+    }
+}
+
+fn main() {
+    let _ = is_red(&Colour::Green);
+    let _ = is_red(&Colour::Blue);
+}
@@ -0,0 +1,119 @@
+//! Pure decision logic for `path_join_with_absolute_component`.
+//!
+//! The driver finds calls to the configured `join` methods whose argument is
+//! a string literal. [`PathJoinWithAbsoluteComponentDecision`] checks the
+//! method against the same list, reports the component when [`Anchor::of`]
+//! finds it absolute, and names the component as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_METHODS, LINT_NAME};
+use crate::policy::Anchor;
+
+/// The facts about one `join` call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JoinCallProjection {
+    /// The fully qualified method called, such as `std::path::Path::join`.
+    pub method: String,
+    /// The literal component joined onto the base path.
+    pub component: String,
+}
+
+/// Reports `join` calls whose literal component is absolute, with the
+/// anchor's key, `root` or `drive`, as the reason.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = JoinCallProjection {
+///     method: "std::path::Path::join".to_owned(),
+///     component: "/etc/passwd".to_owned(),
+/// };
+/// let decision = PathJoinWithAbsoluteComponentDecision::default();
+/// assert!(decision.decide(&call).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathJoinWithAbsoluteComponentDecision {
+    methods: Vec<String>,
+}
+
+impl PathJoinWithAbsoluteComponentDecision {
+    /// Creates a decision checking the fully qualified `methods`.
+    #[must_use]
+    pub fn new(methods: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            methods: methods.into_iter().collect(),
+        }
+    }
+}
+
+impl Default for PathJoinWithAbsoluteComponentDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_METHODS.iter().map(|&path| path.to_owned()))
+    }
+}
+
+impl LintDecision for PathJoinWithAbsoluteComponentDecision {
+    type Projection = JoinCallProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, call: &JoinCallProjection) -> Option<Finding> {
+        let anchor = Anchor::of(&call.component)?;
+        self.methods
+            .contains(&call.method)
+            .then(|| Finding::new(LINT_NAME, anchor.key()).with_subject(call.component.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<JoinCallProjection>> {
+        let Some(method) = self.methods.first() else {
+            return Vec::new();
+        };
+        let call = |component: &str| JoinCallProjection {
+            method: method.clone(),
+            component: component.to_owned(),
+        };
+        vec![
+            DecisionFixture::flagged("root component", call("/etc/passwd"), "root"),
+            DecisionFixture::flagged("drive component", call("C:/temp"), "drive"),
+            DecisionFixture::clean("relative component", call("notes.txt")),
+            DecisionFixture::clean(
+                "unchecked method",
+                JoinCallProjection {
+                    method: "app::Store::join".to_owned(),
+                    ..call("/etc/passwd")
+                },
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::{ConformanceError, check_conformance};
+
+    #[rstest]
+    #[case::default_methods(PathJoinWithAbsoluteComponentDecision::default())]
+    #[case::camino_only(PathJoinWithAbsoluteComponentDecision::new([
+        "camino::Utf8Path::join".to_owned(),
+    ]))]
+    fn decision_conforms(#[case] decision: PathJoinWithAbsoluteComponentDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+
+    #[rstest]
+    fn decision_without_methods_has_no_fixtures() {
+        let decision = PathJoinWithAbsoluteComponentDecision::new(Vec::new());
+
+        assert_eq!(
+            check_conformance(&decision),
+            Err(ConformanceError::MissingFlaggedFixture {
+                lint: "path_join_with_absolute_component",
+            })
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Embedded documentation for the `path_join_with_absolute_component` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `path_join_with_absolute_component`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "path_join_with_absolute_component",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `Path::join` calls whose string-literal component starts with `/`, `\\`, or a drive prefix.",
+    rationale: "`join` returns an absolute component unchanged and discards the base path, so code meant to stay inside a directory reads or writes elsewhere. Extraction and upload handlers are the classic victims of this path confusion.",
+    bad_example: "let target = output_dir.join(\"/config/app.toml\");",
+    good_example: "let target = output_dir.join(\"config/app.toml\");",
+    config: &[ConfigKeyDocs {
+        key: "methods",
+        default: "[\"std::path::Path::join\", \"camino::Utf8Path::join\"]",
+        description: "Fully qualified `join` methods whose literal components are checked.",
+    }],
+};
@@ -0,0 +1,302 @@
+//! Lint pass reporting `join` calls that discard their base path.
+//!
+//! The pass visits method calls that resolve to one of the configured `join`
+//! methods, `std::path::Path::join` and `camino::Utf8Path::join` by default.
+//! When the single argument is a string literal, possibly borrowed, that
+//! [`Anchor::of`] classifies as absolute, the call is reported. Arguments
+//! computed at run time are left alone, since the lint cannot see their
+//! value. Calls written by macros are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{Span, Symbol};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::Anchor;
+
+pub(crate) const LINT_NAME: &str = "path_join_with_absolute_component";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_METHODS: &[&str] = &["std::path::Path::join", "camino::Utf8Path::join"];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    methods: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            methods: DEFAULT_METHODS
+                .iter()
+                .map(|&path| path.to_owned())
+                .collect(),
+        }
+    }
+}
+
+/// Lint pass that inspects literal components passed to `join`.
+#[derive(Default)]
+pub struct PathJoinWithAbsoluteComponent {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configured `join` methods, finding limit, and localization for the crate
+/// being checked.
+#[derive(Default)]
+struct CrateState {
+    methods: Vec<String>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub PATH_JOIN_WITH_ABSOLUTE_COMPONENT,
+    Warn,
+    "joining an absolute path component discards the base path",
+    PathJoinWithAbsoluteComponent::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for PathJoinWithAbsoluteComponent {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            methods: load_configuration().methods,
+            findings: FindingLimit::new(PATH_JOIN_WITH_ABSOLUTE_COMPONENT, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let hir::ExprKind::MethodCall(segment, _, [component], _) = expr.kind else {
+            return;
+        };
+        let Some(literal) = literal_str(component) else {
+            return;
+        };
+        let Some(anchor) = Anchor::of(literal.as_str()) else {
+            return;
+        };
+        let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+            return;
+        };
+        let method = cx.tcx.def_path_str(def_id);
+        if !self.state.methods.contains(&method) {
+            return;
+        }
+
+        debug!(target: LINT_NAME, "`{method}` joins absolute component `{literal}`");
+        if self.state.findings.admit(cx, expr.hir_id, component.span) {
+            let message = JoinMessage {
+                method: segment.ident.as_str(),
+                component: literal.as_str(),
+                anchor,
+            };
+            emit_diagnostic(cx, component.span, &message, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the string literal `expr` holds, looking through borrows.
+fn literal_str(expr: &hir::Expr<'_>) -> Option<Symbol> {
+    match expr.kind {
+        hir::ExprKind::AddrOf(_, _, inner) => literal_str(inner),
+        hir::ExprKind::Lit(lit) => match lit.node {
+            LitKind::Str(value, _) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &JoinMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        PATH_JOIN_WITH_ABSOLUTE_COMPONENT,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for an absolute component interpolates.
+#[derive(Clone, Copy, Debug)]
+struct JoinMessage<'a> {
+    method: &'a str,
+    component: &'a str,
+    anchor: Anchor,
+}
+
+impl JoinMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("method"),
+            FluentValue::from(self.method.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("component"),
+            FluentValue::from(self.component.to_owned()),
+        );
+        args.insert(
+            Cow::Borrowed("anchor"),
+            FluentValue::from(self.anchor.key()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            method,
+            component,
+            anchor,
+        } = *self;
+        let (start, fix) = match anchor {
+            Anchor::Root => ("at the filesystem root", "the leading separator"),
+            Anchor::Drive => ("with a drive prefix", "the drive prefix"),
+        };
+        DiagnosticMessageSet::new(
+            format!("`{method}` with `{component}` discards the base path."),
+            format!(
+                "`{component}` starts {start}, so `{method}` returns it unchanged instead of \
+                 extending the base path."
+            ),
+            format!(
+                "Remove {fix} to stay inside the base path, or use `{component}` on its own if \
+                 an absolute path is intended."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn message(anchor: Anchor) -> JoinMessage<'static> {
+        JoinMessage {
+            method: "join",
+            component: match anchor {
+                Anchor::Root => "/etc/app.toml",
+                Anchor::Drive => "C:/cache",
+            },
+            anchor,
+        }
+    }
+
+    fn resolve(message: &JoinMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_covers_std_and_camino_joins() {
+        assert_eq!(
+            Config::default().methods,
+            ["std::path::Path::join", "camino::Utf8Path::join"]
+        );
+    }
+
+    #[rstest]
+    #[case::root(Anchor::Root, "filesystem root", "leading separator")]
+    #[case::drive(Anchor::Drive, "drive prefix", "Remove the drive prefix")]
+    fn fallback_messages_describe_the_anchor(
+        #[case] anchor: Anchor,
+        #[case] note: &str,
+        #[case] help: &str,
+    ) {
+        let messages = message(anchor).fallback_messages();
+
+        assert!(messages.primary().starts_with("`join` with `"));
+        assert!(messages.note().contains(note));
+        assert!(messages.help().contains(help));
+    }
+
+    #[rstest]
+    #[case::root(Anchor::Root)]
+    #[case::drive(Anchor::Drive)]
+    fn english_messages_match_the_fallback(#[case] anchor: Anchor) {
+        let message = message(anchor);
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let message = message(Anchor::Root);
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`/etc/app.toml`"));
+        assert_ne!(messages.help(), message.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,42 @@
+//! Lint crate flagging `Path::join` calls whose literal component is absolute.
+//!
+//! `Path::join` replaces the base path outright when the joined component
+//! starts at the filesystem root or carries a drive prefix, so
+//! `base.join("/etc/app.toml")` returns `/etc/app.toml` and silently discards
+//! `base`. Code that means to stay inside a directory, such as archive
+//! extraction, then reads or writes somewhere else entirely. The lint reports
+//! string-literal components that begin with `/`, `\`, or a drive letter. The
+//! lint is experimental and ships behind the
+//! `experimental-path-join-with-absolute-component` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{JoinCallProjection, PathJoinWithAbsoluteComponentDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn path_join_with_absolute_component_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,70 @@
+//! Recognition of absolute path components.
+//!
+//! The driver hands each literal component joined onto a path to
+//! [`Anchor::of`], which keeps the classification free of compiler types so
+//! it can be tested directly. Both Unix and Windows forms are recognised,
+//! because a literal that is absolute on either platform makes the join
+//! non-portable at best.
+
+/// How an absolute component anchors itself, replacing the base path.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Anchor {
+    /// The component starts with a `/` or `\` separator.
+    Root,
+    /// The component starts with a drive prefix such as `C:`.
+    Drive,
+}
+
+impl Anchor {
+    /// Returns how `component` anchors itself, or `None` when joining it
+    /// extends the base path.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// assert_eq!(Anchor::of("/etc/passwd"), Some(Anchor::Root));
+    /// assert_eq!(Anchor::of("C:/temp"), Some(Anchor::Drive));
+    /// assert_eq!(Anchor::of("notes.txt"), None);
+    /// ```
+    #[must_use]
+    pub(crate) fn of(component: &str) -> Option<Self> {
+        match component.as_bytes() {
+            [b'/' | b'\\', ..] => Some(Self::Root),
+            [drive, b':', ..] if drive.is_ascii_alphabetic() => Some(Self::Drive),
+            _ => None,
+        }
+    }
+
+    /// Returns the selector the localized messages branch on.
+    #[must_use]
+    pub(crate) const fn key(self) -> &'static str {
+        match self {
+            Self::Root => "root",
+            Self::Drive => "drive",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::unix_root("/etc/app.toml", Some(Anchor::Root))]
+    #[case::bare_root("/", Some(Anchor::Root))]
+    #[case::windows_root("\\shared", Some(Anchor::Root))]
+    #[case::unc_share("\\\\server\\share", Some(Anchor::Root))]
+    #[case::drive_with_slash("C:/cache", Some(Anchor::Drive))]
+    #[case::drive_with_backslash("d:\\cache", Some(Anchor::Drive))]
+    #[case::drive_relative("C:cache", Some(Anchor::Drive))]
+    #[case::relative("cache/app.toml", None)]
+    #[case::current_dir("./app.toml", None)]
+    #[case::parent_dir("../app.toml", None)]
+    #[case::empty("", None)]
+    #[case::colon_later("cache:1", None)]
+    #[case::digit_prefix("1:cache", None)]
+    fn classifies_components(#[case] component: &str, #[case] expected: Option<Anchor>) {
+        assert_eq!(Anchor::of(component), expected);
+    }
+}
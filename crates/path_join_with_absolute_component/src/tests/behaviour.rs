@@ -0,0 +1,76 @@
+//! Behaviour-driven coverage for classifying joined components.
+
+use crate::policy::Anchor;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct JoinWorld {
+    component: RefCell<String>,
+    anchor: Cell<Option<Option<Anchor>>>,
+}
+
+#[fixture]
+fn world() -> JoinWorld {
+    JoinWorld::default()
+}
+
+#[given("the component {component}")]
+fn given_component(world: &JoinWorld, component: String) {
+    *world.component.borrow_mut() = component.trim_matches('"').to_owned();
+}
+
+#[when("the component is classified")]
+fn when_classified(world: &JoinWorld) {
+    world
+        .anchor
+        .set(Some(Anchor::of(world.component.borrow().as_str())));
+}
+
+#[then("it is reported as anchored at the root")]
+fn then_root(world: &JoinWorld) {
+    assert_eq!(world.anchor.get(), Some(Some(Anchor::Root)));
+}
+
+#[then("it is reported as anchored at a drive")]
+fn then_drive(world: &JoinWorld) {
+    assert_eq!(world.anchor.get(), Some(Some(Anchor::Drive)));
+}
+
+#[then("it is accepted")]
+fn then_accepted(world: &JoinWorld) {
+    assert_eq!(world.anchor.get(), Some(None));
+}
+
+#[scenario(
+    path = "tests/features/path_join_with_absolute_component.feature",
+    index = 0
+)]
+fn scenario_slash(world: JoinWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/path_join_with_absolute_component.feature",
+    index = 1
+)]
+fn scenario_backslash(world: JoinWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/path_join_with_absolute_component.feature",
+    index = 2
+)]
+fn scenario_drive(world: JoinWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/path_join_with_absolute_component.feature",
+    index = 3
+)]
+fn scenario_relative(world: JoinWorld) {
+    let _ = world;
+}
@@ -0,0 +1,24 @@
+Feature: Absolute components joined onto a path
+  A literal component passed to `join` is reported when it starts at the
+  filesystem root or with a drive prefix, because the join then discards the
+  base path.
+
+  Scenario: A component starting with a slash is reported
+    Given the component "/etc/app.toml"
+    When the component is classified
+    Then it is reported as anchored at the root
+
+  Scenario: A component starting with a backslash is reported
+    Given the component "\shared"
+    When the component is classified
+    Then it is reported as anchored at the root
+
+  Scenario: A component with a drive prefix is reported
+    Given the component "C:/cache"
+    When the component is classified
+    Then it is reported as anchored at a drive
+
+  Scenario: A relative component is accepted
+    Given the component "cache/app.toml"
+    When the component is classified
+    Then it is accepted
@@ -0,0 +1,19 @@
+//! UI fixture for `join` calls whose literal component discards the base.
+#![warn(path_join_with_absolute_component)]
+
+use std::path::{Path, PathBuf};
+
+fn config_file(base: &Path) -> PathBuf {
+    base.join("/etc/app.toml")
+}
+
+fn cache_dir(base: &PathBuf) -> PathBuf {
+    base.join("C:/cache")
+}
+
+fn main() {
+    let base = Path::new("workspace");
+    let _ = config_file(base);
+    let _ = cache_dir(&base.to_path_buf());
+    let _ = base.join(&"\\shared");
+}
@@ -0,0 +1,23 @@
+//! UI fixture for joins the lint accepts.
+#![warn(path_join_with_absolute_component)]
+
+use std::path::{Path, PathBuf};
+
+struct Url(String);
+
+impl Url {
+    fn join(&self, segment: &str) -> Url {
+        Url(format!("{}{segment}", self.0))
+    }
+}
+
+fn config_file(base: &Path, name: &str) -> PathBuf {
+    base.join("config").join(name)
+}
+
+fn main() {
+    let base = Path::new("workspace");
+    let _ = config_file(base, "/etc/app.toml");
+    let _ = base.join("./cache");
+    let _ = Url("https://example.com".to_owned()).join("/api").0;
+}
@@ -0,0 +1,173 @@
+//! Pure decision logic for `pattern_match_on_boolean`.
+//!
+//! The driver finds two-armed `match` expressions on a `bool` without guards
+//! and reads the scrutinee and arm bodies from the source.
+//! [`PatternMatchOnBooleanDecision`] classifies the arms as the same
+//! [`ArmPattern`]s, asks [`branches`] which arm handles `true`, and names the
+//! `if`/`else` from [`render_if`] as the finding's subject. Re-indenting
+//! multi-line bodies and spotting dropped comments are left to the driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{ArmPattern, Branches, branch_block, branches, condition, render_if};
+
+/// Reason key for a `match` on a `bool`.
+const BOOL_MATCH: &str = "bool_match";
+
+/// One arm of the `match`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArmProjection {
+    /// The arm's pattern as written, such as `true` or `_`.
+    pub pattern: String,
+    /// Whether the arm has a guard.
+    pub guarded: bool,
+    /// The source text of the arm's body.
+    pub body: String,
+    /// Whether the body is a plain `{ ... }` block.
+    pub is_block: bool,
+}
+
+impl ArmProjection {
+    fn arm_pattern(&self) -> ArmPattern {
+        match self.pattern.as_str() {
+            "true" => ArmPattern::True,
+            "false" => ArmPattern::False,
+            "_" => ArmPattern::Wildcard,
+            _ => ArmPattern::Other,
+        }
+    }
+}
+
+/// The facts about one `match` on a `bool` that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BooleanMatchProjection {
+    /// The source text of the scrutinee.
+    pub scrutinee: String,
+    /// The match's arms, in source order.
+    pub arms: Vec<ArmProjection>,
+}
+
+/// Reports a `match` on a `bool` that reads better as `if`/`else`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let arm = |pattern: &str, body: &str| ArmProjection {
+///     pattern: pattern.to_owned(),
+///     body: body.to_owned(),
+///     ..ArmProjection::default()
+/// };
+/// let matched = BooleanMatchProjection {
+///     scrutinee: "ready".to_owned(),
+///     arms: vec![arm("true", "start()"), arm("false", "wait()")],
+/// };
+/// assert!(PatternMatchOnBooleanDecision.decide(&matched).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PatternMatchOnBooleanDecision;
+
+impl LintDecision for PatternMatchOnBooleanDecision {
+    type Projection = BooleanMatchProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, matched: &BooleanMatchProjection) -> Option<Finding> {
+        let [first, second] = matched.arms.as_slice() else {
+            return None;
+        };
+        if first.guarded || second.guarded {
+            return None;
+        }
+        let Branches { then, otherwise } = branches([first.arm_pattern(), second.arm_pattern()])?;
+        let block = |arm: &ArmProjection| branch_block(&arm.body, arm.is_block, 0);
+        let rewrite = render_if(
+            &condition(&matched.scrutinee),
+            &block(&matched.arms[then]),
+            &block(&matched.arms[otherwise]),
+        );
+        Some(Finding::new(LINT_NAME, BOOL_MATCH).with_subject(rewrite))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<BooleanMatchProjection>> {
+        let arm = |pattern: &str, body: &str| ArmProjection {
+            pattern: pattern.to_owned(),
+            guarded: false,
+            body: body.to_owned(),
+            is_block: false,
+        };
+        let matched = |arms| BooleanMatchProjection {
+            scrutinee: "ready".to_owned(),
+            arms,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "true and false arms",
+                matched(vec![arm("true", "start()"), arm("false", "wait()")]),
+                BOOL_MATCH,
+            ),
+            DecisionFixture::flagged(
+                "false and wildcard arms",
+                matched(vec![arm("false", "wait()"), arm("_", "start()")]),
+                BOOL_MATCH,
+            ),
+            DecisionFixture::clean(
+                "guarded arm",
+                matched(vec![
+                    ArmProjection {
+                        guarded: true,
+                        ..arm("true", "start()")
+                    },
+                    arm("_", "wait()"),
+                ]),
+            ),
+            DecisionFixture::clean(
+                "binding arm",
+                matched(vec![arm("true", "start()"), arm("other", "wait()")]),
+            ),
+            DecisionFixture::clean(
+                "three arms",
+                matched(vec![
+                    arm("true", "start()"),
+                    arm("false", "wait()"),
+                    arm("_", "stop()"),
+                ]),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_rewrite() {
+        let arm = |pattern: &str, body: &str| ArmProjection {
+            pattern: pattern.to_owned(),
+            body: body.to_owned(),
+            ..ArmProjection::default()
+        };
+        let matched = BooleanMatchProjection {
+            scrutinee: "ready".to_owned(),
+            arms: vec![arm("false", "wait()"), arm("_", "start()")],
+        };
+        let finding = PatternMatchOnBooleanDecision
+            .decide(&matched)
+            .expect("the match should be reported");
+
+        assert_eq!(
+            finding.subject(),
+            Some("if ready { start() } else { wait() }")
+        );
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&PatternMatchOnBooleanDecision), Ok(5));
+    }
+}
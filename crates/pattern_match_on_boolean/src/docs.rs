@@ -0,0 +1,17 @@
+//! Embedded documentation for the `pattern_match_on_boolean` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for `pattern_match_on_boolean`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "pattern_match_on_boolean",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `match` expressions on a `bool` with `true` and `false` arms, and suggests `if`/`else`.",
+    rationale: "A `match` on a `bool` restates what `if`/`else` already expresses, nests each branch one level deeper, and makes readers check that the arms really are just `true` and `false`. Writing the branch as `if`/`else` is shorter and is what readers expect.",
+    bad_example: "let label = match verbose {\n    true => \"full\",\n    false => \"short\",\n};",
+    good_example: "let label = if verbose { \"full\" } else { \"short\" };",
+    config: &[],
+};
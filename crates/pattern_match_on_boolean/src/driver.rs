@@ -0,0 +1,339 @@
+//! Lint pass reporting `match` expressions on `bool` values.
+//!
+//! The pass inspects each `match` written in source with exactly two
+//! unguarded arms whose scrutinee has type `bool`. When one arm matches a
+//! `bool` literal and the other the opposite literal or `_`, as decided by
+//! [`branches`], the `match` keyword and scrutinee are reported. Desugared
+//! matches and matches produced by macro expansion are skipped. The
+//! suggestion replaces the whole `match` with an `if` on the scrutinee whose
+//! blocks are the arm bodies, built by [`render_if`]; it is machine-applicable
+//! unless comments outside the arm bodies would be dropped.
+
+use log::debug;
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_hir::{ExprKind, MatchSource, PatExprKind, PatKind};
+use rustc_lint::errors::Applicability;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{
+    ArmPattern, Branches, branch_block, branches, condition, has_comments, render_if,
+};
+
+pub(crate) const LINT_NAME: &str = "pattern_match_on_boolean";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub PATTERN_MATCH_ON_BOOLEAN,
+    Warn,
+    "`match` expressions on a `bool` should be written as `if`/`else`",
+    PatternMatchOnBoolean::default()
+}
+
+/// Lint pass that finds `match` expressions on `bool` values.
+#[derive(Default)]
+pub struct PatternMatchOnBoolean {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Finding limit and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// A `match` on a `bool` and the arms taken for each value.
+struct BooleanMatch<'tcx> {
+    expr: &'tcx hir::Expr<'tcx>,
+    scrutinee: &'tcx hir::Expr<'tcx>,
+    then: &'tcx hir::Arm<'tcx>,
+    otherwise: &'tcx hir::Arm<'tcx>,
+}
+
+/// The rewrite that replaces the `match` with `if`/`else`.
+struct IfRewrite {
+    replacement: String,
+    applicability: Applicability,
+}
+
+impl<'tcx> LateLintPass<'tcx> for PatternMatchOnBoolean {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            findings: FindingLimit::new(PATTERN_MATCH_ON_BOOLEAN, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let ExprKind::Match(scrutinee, arms @ [first, second], MatchSource::Normal) = expr.kind
+        else {
+            return;
+        };
+        if expr.span.from_expansion() || arms.iter().any(|arm| arm.guard.is_some()) {
+            return;
+        }
+        let Some(Branches { then, otherwise }) =
+            branches([arm_pattern(first.pat), arm_pattern(second.pat)])
+        else {
+            return;
+        };
+        if !cx.typeck_results().expr_ty(scrutinee).is_bool() {
+            return;
+        }
+        let Some(scrutinee_span) = scrutinee.span.find_ancestor_inside(expr.span) else {
+            return;
+        };
+
+        let span = expr.span.with_hi(scrutinee_span.hi());
+        let state = &*self.state;
+        debug!(target: LINT_NAME, "`match` on a `bool` at {span:?}");
+        if state.findings.admit(cx, expr.hir_id, span) {
+            let finding = BooleanMatch {
+                expr,
+                scrutinee,
+                then: &arms[then],
+                otherwise: &arms[otherwise],
+            };
+            emit_diagnostic(cx, span, &finding, &state.localizer);
+        }
+    }
+}
+
+/// Classifies the pattern of one arm of a `match` on a `bool`.
+fn arm_pattern(pat: &hir::Pat<'_>) -> ArmPattern {
+    if pat.span.from_expansion() {
+        return ArmPattern::Other;
+    }
+    match pat.kind {
+        PatKind::Wild => ArmPattern::Wildcard,
+        PatKind::Expr(hir::PatExpr {
+            kind:
+                PatExprKind::Lit {
+                    lit,
+                    negated: false,
+                },
+            ..
+        }) => match lit.node {
+            LitKind::Bool(true) => ArmPattern::True,
+            LitKind::Bool(false) => ArmPattern::False,
+            _ => ArmPattern::Other,
+        },
+        _ => ArmPattern::Other,
+    }
+}
+
+/// Returns `true` when `body` is a plain `{ ... }` block that can become an
+/// `if` or `else` block as written.
+fn is_plain_block(body: &hir::Expr<'_>) -> bool {
+    matches!(
+        body.kind,
+        ExprKind::Block(
+            hir::Block {
+                rules: hir::BlockCheckMode::DefaultBlock,
+                ..
+            },
+            None,
+        )
+    ) && !body.span.from_expansion()
+}
+
+/// Builds the `if`/`else` replacing the `match`, or `None` when the source
+/// text is unavailable.
+fn if_rewrite(cx: &LateContext<'_>, finding: &BooleanMatch<'_>) -> Option<IfRewrite> {
+    let BooleanMatch {
+        expr,
+        scrutinee,
+        then,
+        otherwise,
+    } = *finding;
+    let source_map = cx.sess().source_map();
+    let snippet = |span: Span| source_map.span_to_snippet(span).ok();
+    let body_span = |arm: &hir::Arm<'_>| arm.body.span.find_ancestor_inside(arm.span);
+
+    let scrutinee_span = scrutinee.span.find_ancestor_inside(expr.span)?;
+    let (then_span, otherwise_span) = (body_span(then)?, body_span(otherwise)?);
+    let match_indent = source_map.indentation_before(expr.span)?;
+    let arm_indent = source_map.indentation_before(then.span)?;
+    let shift = arm_indent.len().saturating_sub(match_indent.len());
+
+    let scrutinee_text = snippet(scrutinee_span)?;
+    let then_block = branch_block(&snippet(then_span)?, is_plain_block(then.body), shift);
+    let otherwise_block = branch_block(
+        &snippet(otherwise_span)?,
+        is_plain_block(otherwise.body),
+        shift,
+    );
+
+    // Everything outside the scrutinee and the arm bodies is replaced.
+    let (first, last) = if then_span.lo() < otherwise_span.lo() {
+        (then_span, otherwise_span)
+    } else {
+        (otherwise_span, then_span)
+    };
+    let dropped = [
+        scrutinee_span.between(first),
+        first.between(last),
+        last.between(expr.span.shrink_to_hi()),
+    ];
+    let drops_comments = dropped
+        .into_iter()
+        .any(|span| snippet(span).is_none_or(|text| has_comments(&text)));
+
+    Some(IfRewrite {
+        replacement: render_if(&condition(&scrutinee_text), &then_block, &otherwise_block),
+        applicability: if drops_comments {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        },
+    })
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    finding: &BooleanMatch<'_>,
+    localizer: &Localizer,
+) {
+    let message = BooleanMatchMessage;
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let suggestion = message.suggestion(localizer);
+    let rewrite = if_rewrite(cx, finding);
+    let match_span = finding.expr.span;
+
+    cx.emit_span_lint(
+        PATTERN_MATCH_ON_BOOLEAN,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+            if let Some(rewrite) = rewrite {
+                lint.span_suggestion(
+                    match_span,
+                    suggestion,
+                    rewrite.replacement,
+                    rewrite.applicability,
+                );
+            }
+        }),
+    );
+}
+
+/// The diagnostic for a `match` on a `bool`, which interpolates no values.
+#[derive(Clone, Copy, Debug)]
+struct BooleanMatchMessage;
+
+impl BooleanMatchMessage {
+    fn args(self) -> Arguments<'static> {
+        Arguments::default()
+    }
+
+    fn fallback_messages(self) -> DiagnosticMessageSet {
+        DiagnosticMessageSet::new(
+            String::from("`match` on a `bool` should be written as `if`/`else`."),
+            String::from(
+                "The arms only tell `true` from `false`, which `if`/`else` says directly \
+                 without nesting each branch inside an arm.",
+            ),
+            String::from(
+                "Replace the `match` with an `if` on the scrutinee, using the `true` arm as the \
+                 `if` block and the other arm as the `else` block.",
+            ),
+        )
+    }
+
+    fn fallback_suggestion(self) -> String {
+        String::from("rewrite the `match` as `if`/`else`")
+    }
+
+    /// Resolves the label of the suggested rewrite. Fluent may wrap
+    /// arguments in directional isolates, which are stripped to keep
+    /// suggestion text stable.
+    fn suggestion(self, localizer: &Localizer) -> String {
+        localizer
+            .attribute_with_args(LINT_NAME, "suggestion", &self.args())
+            .unwrap_or_else(|_| self.fallback_suggestion())
+            .chars()
+            .filter(|ch| !matches!(ch, '\u{2068}' | '\u{2069}' | '\u{FFFD}'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn resolve(locale: &str) -> DiagnosticMessageSet {
+        let message = BooleanMatchMessage;
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        let message = BooleanMatchMessage;
+
+        assert_eq!(resolve("en-GB"), message.fallback_messages());
+        assert_eq!(
+            message.suggestion(&Localizer::new(Some("en-GB"))),
+            message.fallback_suggestion()
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let message = BooleanMatchMessage;
+        let messages = resolve(locale);
+
+        assert!(messages.primary().contains("`match`"));
+        assert!(messages.primary().contains("`bool`"));
+        assert_ne!(
+            message.suggestion(&Localizer::new(Some(locale))),
+            message.fallback_suggestion()
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
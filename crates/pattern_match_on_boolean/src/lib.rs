@@ -0,0 +1,40 @@
+//! Lint crate flagging `match` expressions on `bool` values.
+//!
+//! A `match` whose arms are `true` and `false` spells out what `if`/`else`
+//! already says, with an extra level of nesting and a less familiar shape. The
+//! lint reports these matches when they are written in source and suggests the
+//! equivalent `if`/`else`, which can be applied automatically unless comments
+//! between the arms would be lost. The lint is experimental and ships behind
+//! the `experimental-pattern-match-on-boolean` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ArmProjection, BooleanMatchProjection, PatternMatchOnBooleanDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn pattern_match_on_boolean_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
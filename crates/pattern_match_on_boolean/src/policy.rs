@@ -0,0 +1,224 @@
+//! Rules recognising a `match` on a `bool` and rendering its `if`/`else`.
+//!
+//! The driver classifies each arm's pattern as an [`ArmPattern`] and asks
+//! [`branches`] which arm runs when the scrutinee is `true`. The rewrite is
+//! assembled from source text: [`condition`] wraps scrutinees that would
+//! otherwise be read as the start of the `if` block, [`branch_block`] turns an
+//! arm body into a block at the `match`'s indentation, and [`has_comments`]
+//! tells the driver when text the rewrite drops holds comments. Source is
+//! tokenised with `rustc_lexer`, so braces and line breaks inside string
+//! literals and comments are never mistaken for code. The rules are kept free
+//! of compiler types so they can be tested directly.
+
+use std::borrow::Cow;
+
+use rustc_lexer::{TokenKind, tokenize};
+
+/// What an arm of a `match` on a `bool` matches.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ArmPattern {
+    /// The literal `true`.
+    True,
+    /// The literal `false`.
+    False,
+    /// The wildcard `_`.
+    Wildcard,
+    /// Any other pattern, such as a binding or an or-pattern.
+    Other,
+}
+
+/// Indices of the arms that become the `if` and `else` blocks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Branches {
+    /// The arm taken when the scrutinee is `true`.
+    pub(crate) then: usize,
+    /// The arm taken when the scrutinee is `false`.
+    pub(crate) otherwise: usize,
+}
+
+/// Returns which of two arms handles `true` and which handles `false`, or
+/// `None` unless one arm is a `bool` literal and the other is the opposite
+/// literal or `_`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let branches = branches([ArmPattern::False, ArmPattern::Wildcard]);
+/// assert_eq!(branches, Some(Branches { then: 1, otherwise: 0 }));
+/// assert_eq!(branches([ArmPattern::True, ArmPattern::Other]), None);
+/// ```
+#[must_use]
+pub(crate) const fn branches(patterns: [ArmPattern; 2]) -> Option<Branches> {
+    match patterns {
+        [ArmPattern::True, ArmPattern::False | ArmPattern::Wildcard] => Some(Branches {
+            then: 0,
+            otherwise: 1,
+        }),
+        [ArmPattern::False, ArmPattern::True | ArmPattern::Wildcard] => Some(Branches {
+            then: 1,
+            otherwise: 0,
+        }),
+        _ => None,
+    }
+}
+
+/// Returns `scrutinee` as an `if` condition, parenthesised when it contains a
+/// brace that would otherwise open the `if` block early.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(condition("ready"), "ready");
+/// assert_eq!(condition("Point { x: 1 } == origin"), "(Point { x: 1 } == origin)");
+/// ```
+#[must_use]
+pub(crate) fn condition(scrutinee: &str) -> Cow<'_, str> {
+    if tokenize(scrutinee).any(|token| token.kind == TokenKind::OpenBrace) {
+        Cow::Owned(format!("({scrutinee})"))
+    } else {
+        Cow::Borrowed(scrutinee)
+    }
+}
+
+/// Returns an arm body as a block, wrapping bodies that are not already
+/// blocks in braces and moving continuation lines `shift` columns left so
+/// they line up under the `if`.
+///
+/// Bodies holding a multi-line string literal or block comment are kept at
+/// their original indentation, since shifting would change the literal.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(branch_block("\"full\"", false, 0), "{ \"full\" }");
+/// assert_eq!(
+///     branch_block("{\n            start();\n        }", true, 4),
+///     "{\n        start();\n    }"
+/// );
+/// ```
+#[must_use]
+pub(crate) fn branch_block(body: &str, is_block: bool, shift: usize) -> String {
+    let block = if is_block {
+        body.to_owned()
+    } else {
+        format!("{{ {body} }}")
+    };
+    if shift == 0 || spans_lines_verbatim(&block) {
+        return block;
+    }
+
+    let mut lines = block.split('\n');
+    let first = lines.next().unwrap_or_default().to_owned();
+    lines.fold(first, |mut shifted, line| {
+        let indentation = line.len() - line.trim_start_matches([' ', '\t']).len();
+        shifted.push('\n');
+        shifted.push_str(&line[indentation.min(shift)..]);
+        shifted
+    })
+}
+
+/// Returns the `if`/`else` that replaces the `match`.
+#[must_use]
+pub(crate) fn render_if(condition: &str, then: &str, otherwise: &str) -> String {
+    format!("if {condition} {then} else {otherwise}")
+}
+
+/// Returns `true` when `text` contains a line or block comment.
+#[must_use]
+pub(crate) fn has_comments(text: &str) -> bool {
+    tokenize(text).any(|token| {
+        matches!(
+            token.kind,
+            TokenKind::LineComment | TokenKind::BlockComment { .. }
+        )
+    })
+}
+
+/// Returns `true` when a token other than whitespace spans a line break.
+fn spans_lines_verbatim(text: &str) -> bool {
+    let mut offset = 0;
+    tokenize(text).any(|token| {
+        let end = offset + token.len;
+        let spans = token.kind != TokenKind::Whitespace && text[offset..end].contains('\n');
+        offset = end;
+        spans
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::true_first([ArmPattern::True, ArmPattern::False], Some((0, 1)))]
+    #[case::false_first([ArmPattern::False, ArmPattern::True], Some((1, 0)))]
+    #[case::true_then_wildcard([ArmPattern::True, ArmPattern::Wildcard], Some((0, 1)))]
+    #[case::false_then_wildcard([ArmPattern::False, ArmPattern::Wildcard], Some((1, 0)))]
+    #[case::wildcard_first([ArmPattern::Wildcard, ArmPattern::True], None)]
+    #[case::repeated([ArmPattern::True, ArmPattern::True], None)]
+    #[case::binding([ArmPattern::True, ArmPattern::Other], None)]
+    fn branches_pair_true_with_false(
+        #[case] patterns: [ArmPattern; 2],
+        #[case] expected: Option<(usize, usize)>,
+    ) {
+        let expected = expected.map(|(then, otherwise)| Branches { then, otherwise });
+        assert_eq!(branches(patterns), expected);
+    }
+
+    #[rstest]
+    #[case::path("self.ready", "self.ready")]
+    #[case::call("is_ready(&queue)", "is_ready(&queue)")]
+    #[case::struct_literal("Point { x: 1 } == origin", "(Point { x: 1 } == origin)")]
+    #[case::brace_in_string("label == \"{}\"", "label == \"{}\"")]
+    fn conditions_avoid_opening_the_block_early(#[case] scrutinee: &str, #[case] expected: &str) {
+        assert_eq!(condition(scrutinee), expected);
+    }
+
+    #[rstest]
+    #[case::expression("\"full\"", false, 4, "{ \"full\" }")]
+    #[case::block(
+        "{\n            start();\n        }",
+        true,
+        4,
+        "{\n        start();\n    }"
+    )]
+    #[case::multi_line_expression(
+        "run(\n            first,\n        )",
+        false,
+        4,
+        "{ run(\n        first,\n    ) }"
+    )]
+    #[case::single_line_match("{ start() }", true, 0, "{ start() }")]
+    #[case::multi_line_literal(
+        "{\n            \"one\n            two\"\n        }",
+        true,
+        4,
+        "{\n            \"one\n            two\"\n        }"
+    )]
+    fn branch_blocks_line_up_under_the_if(
+        #[case] body: &str,
+        #[case] is_block: bool,
+        #[case] shift: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(branch_block(body, is_block, shift), expected);
+    }
+
+    #[rstest]
+    fn render_if_joins_the_branches() {
+        assert_eq!(
+            render_if("verbose", "{ \"full\" }", "{ \"short\" }"),
+            "if verbose { \"full\" } else { \"short\" }"
+        );
+    }
+
+    #[rstest]
+    #[case::plain(",\n        false => ", false)]
+    #[case::line_comment(", // fall back\n        false => ", true)]
+    #[case::block_comment(" /* both */ ", true)]
+    #[case::comment_in_string(" \"// not a comment\" ", false)]
+    fn comments_are_found_outside_literals(#[case] text: &str, #[case] expected: bool) {
+        assert_eq!(has_comments(text), expected);
+    }
+}
@@ -0,0 +1,75 @@
+//! Behaviour-driven coverage for pairing the arms of a `match` on a `bool`.
+
+use crate::policy::{ArmPattern, Branches, branches};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+#[derive(Default)]
+struct MatchWorld {
+    patterns: Cell<Option<[ArmPattern; 2]>>,
+    branches: Cell<Option<Branches>>,
+}
+
+#[fixture]
+fn world() -> MatchWorld {
+    MatchWorld::default()
+}
+
+fn pattern(text: &str) -> ArmPattern {
+    match text {
+        "true" => ArmPattern::True,
+        "false" => ArmPattern::False,
+        "_" => ArmPattern::Wildcard,
+        _ => ArmPattern::Other,
+    }
+}
+
+#[given("a match with arms {first} and {second}")]
+fn given_arms(world: &MatchWorld, first: String, second: String) {
+    world
+        .patterns
+        .set(Some([pattern(&first), pattern(&second)]));
+}
+
+#[when("the arms are paired")]
+fn when_paired(world: &MatchWorld) {
+    let patterns = world.patterns.get().expect("arms should be given");
+    world.branches.set(branches(patterns));
+}
+
+#[then("arm {index} becomes the if block")]
+fn then_if_block(world: &MatchWorld, index: usize) {
+    assert_eq!(
+        world.branches.get().map(|branches| branches.then),
+        Some(index)
+    );
+}
+
+#[then("arm {index} becomes the else block")]
+fn then_else_block(world: &MatchWorld, index: usize) {
+    assert_eq!(
+        world.branches.get().map(|branches| branches.otherwise),
+        Some(index)
+    );
+}
+
+#[then("the match is not rewritten")]
+fn then_not_rewritten(world: &MatchWorld) {
+    assert_eq!(world.branches.get(), None);
+}
+
+#[scenario(path = "tests/features/pattern_match_on_boolean.feature", index = 0)]
+fn scenario_true_first(world: MatchWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/pattern_match_on_boolean.feature", index = 1)]
+fn scenario_wildcard_after_false(world: MatchWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/pattern_match_on_boolean.feature", index = 2)]
+fn scenario_binding_arm(world: MatchWorld) {
+    let _ = world;
+}
@@ -0,0 +1,20 @@
+Feature: Pattern match on boolean
+  A `match` whose arms are a `bool` literal and its opposite, or `_`, is
+  rewritten as `if`/`else` with the `true` arm as the `if` block.
+
+  Scenario: A true arm before a false arm becomes the if block
+    Given a match with arms true and false
+    When the arms are paired
+    Then arm 0 becomes the if block
+    And arm 1 becomes the else block
+
+  Scenario: A wildcard after false handles true
+    Given a match with arms false and _
+    When the arms are paired
+    Then arm 1 becomes the if block
+    And arm 0 becomes the else block
+
+  Scenario: A binding arm is not rewritten
+    Given a match with arms true and other
+    When the arms are paired
+    Then the match is not rewritten
@@ -0,0 +1,25 @@
+//! `match` expressions on `bool` values are reported.
+
+fn label(verbose: bool) -> &'static str {
+    match verbose {
+        true => "full",
+        false => "short",
+    }
+}
+
+fn toggle(ready: bool, count: &mut u32) {
+    match ready {
+        false => {
+            *count = 0;
+        }
+        _ => {
+            *count += 1;
+        }
+    }
+}
+
+fn main() {
+    let mut count = 0;
+    let _ = label(true);
+    toggle(false, &mut count);
+}
@@ -0,0 +1,44 @@
+//! `if`/`else`, guarded arms, bindings, and non-`bool` scrutinees are not
+//! reported.
+
+macro_rules! pick {
+    ($flag:expr) => {
+        match $flag {
+            true => 1,
+            false => 0,
+        }
+    };
+}
+
+fn label(verbose: bool) -> &'static str {
+    if verbose { "full" } else { "short" }
+}
+
+fn guarded(ready: bool, count: u32) -> u32 {
+    match ready {
+        true if count > 3 => count,
+        _ => 0,
+    }
+}
+
+fn bound(ready: bool) -> bool {
+    match ready {
+        true => false,
+        other => other,
+    }
+}
+
+fn pair(left: bool, right: bool) -> u8 {
+    match (left, right) {
+        (true, true) => 2,
+        _ => 0,
+    }
+}
+
+fn main() {
+    let _ = label(true);
+    let _ = guarded(true, 4);
+    let _ = bound(false);
+    let _ = pair(true, false);
+    let _ = pick!(true);
+}
@@ -0,0 +1,91 @@
+//! Pure decision logic for `prefer_named_module_files`.
+//!
+//! The driver resolves the file behind each file-backed module declaration.
+//! [`PreferNamedModuleFilesDecision`] hands the same path to
+//! [`named_module_file_for`] and names the suggested `<parent>.rs` file as
+//! the finding's subject.
+
+use std::path::Path;
+
+use whitaker::named_module_file_for;
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+
+/// Reason key for modules implemented as `mod.rs`.
+const MOD_RS: &str = "mod_rs";
+
+/// The facts about one file-backed module that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleFileProjection {
+    /// The path of the file holding the module.
+    pub path: String,
+}
+
+impl ModuleFileProjection {
+    /// Creates a projection for the module file at `path`.
+    #[must_use]
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+/// Reports file-backed modules implemented as `mod.rs`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let module = ModuleFileProjection::new("src/parser/mod.rs");
+/// assert!(PreferNamedModuleFilesDecision.decide(&module).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PreferNamedModuleFilesDecision;
+
+impl LintDecision for PreferNamedModuleFilesDecision {
+    type Projection = ModuleFileProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, module: &ModuleFileProjection) -> Option<Finding> {
+        let suggestion = named_module_file_for(Path::new(&module.path))?;
+        Some(Finding::new(LINT_NAME, MOD_RS).with_subject(suggestion.display().to_string()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ModuleFileProjection>> {
+        vec![
+            DecisionFixture::flagged(
+                "mod.rs",
+                ModuleFileProjection::new("src/parser/mod.rs"),
+                MOD_RS,
+            ),
+            DecisionFixture::clean(
+                "named module file",
+                ModuleFileProjection::new("src/parser.rs"),
+            ),
+            DecisionFixture::clean("crate root", ModuleFileProjection::new("src/lib.rs")),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_suggested_file() {
+        let finding = PreferNamedModuleFilesDecision
+            .decide(&ModuleFileProjection::new("src/parser/mod.rs"))
+            .expect("the module should be reported");
+
+        assert_eq!(finding.subject(), Some("src/parser.rs"));
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(check_conformance(&PreferNamedModuleFilesDecision), Ok(3));
+    }
+}
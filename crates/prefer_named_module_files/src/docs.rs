@@ -0,0 +1,17 @@
+//! Embedded documentation for the `prefer_named_module_files` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, examples, and configuration for `prefer_named_module_files`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "prefer_named_module_files",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Prefers `foo.rs` over `foo/mod.rs` for file-backed modules.",
+    rationale: "Named module files keep editor tabs and search results distinguishable instead of showing many files called `mod.rs`.",
+    bad_example: concat!("// src/parser/mod.rs\n", "mod lexer;",),
+    good_example: concat!("// src/parser.rs\n", "mod lexer;",),
+    config: &[],
+};
@@ -0,0 +1,161 @@
+//! Report file-backed modules implemented as `mod.rs`.
+//!
+//! The lint resolves the file behind each `mod foo;` declaration through the
+//! source map using [`whitaker::hir::module_source_path`]. When that file is
+//! named `mod.rs`, the diagnostic names the `<parent>.rs` path suggested by
+//! [`whitaker::hir::named_module_file_for`]. The lint is allow-by-default so
+//! workspaces that standardise on `mod.rs`, such as those denying Clippy's
+//! `self_named_module_files`, are unaffected until they opt in.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use whitaker::hir::FindingLimit;
+use whitaker::{SharedConfig, module_header_span, module_source_path, named_module_file_for};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+pub(crate) const LINT_NAME: &str = "prefer_named_module_files";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub PREFER_NAMED_MODULE_FILES,
+    Allow,
+    "file-backed modules should live in `foo.rs` rather than `foo/mod.rs`",
+    PreferNamedModuleFiles::default()
+}
+
+/// Lint pass that reports modules implemented in `mod.rs` files.
+#[derive(Default)]
+pub struct PreferNamedModuleFiles {
+    localizer: CrateScopedState<Localizer>,
+    findings: CrateScopedState<FindingLimit>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for PreferNamedModuleFiles {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.localizer
+            .reset(get_localizer_for_lint(LINT_NAME, shared_config.locale()));
+        self.findings
+            .reset(FindingLimit::new(PREFER_NAMED_MODULE_FILES, &shared_config));
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let hir::ItemKind::Mod(ident, module) = item.kind else {
+            return;
+        };
+
+        let Some(path) = module_source_path(cx, item, module) else {
+            return;
+        };
+        let Some(suggestion) = named_module_file_for(&path) else {
+            return;
+        };
+        debug!(
+            target: LINT_NAME,
+            "module `{}` lives in `{}`; suggesting `{}`",
+            ident.name,
+            path.display(),
+            suggestion.display(),
+        );
+
+        let module_file = ModuleFile {
+            span: module_header_span(item.span, ident.span),
+            module: ident.name.as_str(),
+            path: &path,
+            suggestion: &suggestion,
+        };
+        if self.findings.admit(cx, item.hir_id(), module_file.span) {
+            emit_diagnostic(cx, &module_file, &self.localizer);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.findings.summarise(cx, &self.localizer);
+    }
+}
+
+/// A module declaration whose body lives in a `mod.rs` file.
+struct ModuleFile<'a> {
+    span: Span,
+    module: &'a str,
+    path: &'a Path,
+    suggestion: &'a Path,
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, module_file: &ModuleFile<'_>, localizer: &Localizer) {
+    let path = module_file.path.display().to_string();
+    let suggestion = module_file.suggestion.display().to_string();
+
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("module"),
+        FluentValue::from(module_file.module.to_string()),
+    );
+    args.insert(Cow::Borrowed("path"), FluentValue::from(path.clone()));
+    args.insert(
+        Cow::Borrowed("suggestion"),
+        FluentValue::from(suggestion.clone()),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(module_file.module, &path, &suggestion)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        PREFER_NAMED_MODULE_FILES,
+        module_file.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+fn fallback_messages(module: &str, path: &str, suggestion: &str) -> DiagnosticMessageSet {
+    DiagnosticMessageSet::new(
+        format!("Module `{module}` is implemented in a `mod.rs` file."),
+        format!("`{module}` is defined in `{path}`; prefer `{suggestion}`."),
+        format!(
+            "Move `{path}` to `{suggestion}` and keep any submodules in the `{module}` directory."
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn fallback_messages_name_both_paths() {
+        let messages = fallback_messages("parser", "src/parser/mod.rs", "src/parser.rs");
+
+        assert!(messages.primary().contains("`parser`"));
+        assert!(messages.note().contains("`src/parser.rs`"));
+        assert!(messages.help().contains("`src/parser/mod.rs`"));
+        assert!(messages.help().contains("`src/parser.rs`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
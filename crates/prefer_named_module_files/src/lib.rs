@@ -0,0 +1,36 @@
+//! Lint crate preferring `foo.rs` module files over `foo/mod.rs`.
+//!
+//! Editors and review tools show a tab full of `mod.rs` files when modules
+//! use the older directory layout. The lint reports file-backed modules
+//! implemented as `mod.rs` and names the `<parent>.rs` file that should hold
+//! the module instead.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ModuleFileProjection, PreferNamedModuleFilesDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn prefer_named_module_files_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+mod ui {
+    whitaker::declare_ui_tests!("ui");
+}
@@ -0,0 +1,60 @@
+//! Behaviour-driven coverage for `mod.rs` module file suggestions.
+
+use super::fallback_messages;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::RefCell;
+use std::path::PathBuf;
+use whitaker::named_module_file_for;
+use whitaker_common::i18n::DiagnosticMessageSet;
+
+#[derive(Default)]
+struct ModuleFileWorld {
+    path: RefCell<PathBuf>,
+    messages: RefCell<Option<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> ModuleFileWorld {
+    ModuleFileWorld::default()
+}
+
+#[given("a module implemented in {path}")]
+fn given_module_file(world: &ModuleFileWorld, path: String) {
+    *world.path.borrow_mut() = PathBuf::from(path);
+}
+
+#[when("the module file is checked")]
+fn when_checked(world: &ModuleFileWorld) {
+    let path = world.path.borrow();
+    let messages = named_module_file_for(&path).map(|suggestion| {
+        fallback_messages(
+            "parser",
+            &path.display().to_string(),
+            &suggestion.display().to_string(),
+        )
+    });
+    *world.messages.borrow_mut() = messages;
+}
+
+#[then("the note suggests {suggestion}")]
+fn then_note_suggests(world: &ModuleFileWorld, suggestion: String) {
+    let messages = world.messages.borrow();
+    let messages = messages.as_ref().expect("a diagnostic should be rendered");
+    assert!(messages.note().contains(suggestion.as_str()));
+}
+
+#[then("no diagnostic is produced")]
+fn then_no_diagnostic(world: &ModuleFileWorld) {
+    assert!(world.messages.borrow().is_none());
+}
+
+#[scenario(path = "tests/features/prefer_named_module_files.feature", index = 0)]
+fn scenario_mod_rs(world: ModuleFileWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/prefer_named_module_files.feature", index = 1)]
+fn scenario_named_file(world: ModuleFileWorld) {
+    let _ = world;
+}
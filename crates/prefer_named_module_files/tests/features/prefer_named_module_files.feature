@@ -0,0 +1,13 @@
+Feature: Prefer named module files
+  File-backed modules implemented as `mod.rs` are reported with the
+  `<parent>.rs` path that should hold them instead.
+
+  Scenario: A mod.rs module is redirected to its named file
+    Given a module implemented in src/parser/mod.rs
+    When the module file is checked
+    Then the note suggests src/parser.rs
+
+  Scenario: A named module file is accepted
+    Given a module implemented in src/parser.rs
+    When the module file is checked
+    Then no diagnostic is produced
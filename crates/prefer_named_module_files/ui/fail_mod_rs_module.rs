@@ -0,0 +1,8 @@
+//! Negative UI fixture: modules implemented as `mod.rs` are reported.
+#![warn(prefer_named_module_files)]
+
+mod parser;
+
+fn main() {
+    parser::parse();
+}
@@ -0,0 +1,3 @@
+//! Module implemented using the `mod.rs` layout.
+
+pub fn parse() {}
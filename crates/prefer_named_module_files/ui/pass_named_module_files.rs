@@ -0,0 +1,14 @@
+//! Positive UI fixture: named module files and inline modules are accepted.
+#![deny(prefer_named_module_files)]
+
+#[path = "support/lexer.rs"]
+mod lexer;
+
+mod inline {
+    pub fn run() {}
+}
+
+fn main() {
+    lexer::lex();
+    inline::run();
+}
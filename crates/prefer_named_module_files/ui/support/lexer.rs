@@ -0,0 +1,3 @@
+//! Module implemented in a named file.
+
+pub fn lex() {}
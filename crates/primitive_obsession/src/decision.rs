@@ -0,0 +1,148 @@
+//! Pure decision logic for `primitive_obsession`.
+//!
+//! The driver lists the parameters of each function and the fields of each
+//! struct outside test code whose type is a bare primitive.
+//! [`PrimitiveObsessionDecision`] groups the same positions with
+//! [`RepetitionPolicy::repeated`], reports the item when a primitive repeats,
+//! and names the first repeated primitive as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{DEFAULT_MIN_POSITIONS, ItemKind, RepetitionPolicy};
+
+/// A parameter or field whose type is a bare primitive.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PositionProjection {
+    /// The parameter or field name.
+    pub name: String,
+    /// The primitive, such as `u64` or `String`.
+    pub primitive: String,
+}
+
+/// The facts about one function or struct that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ItemPositionsProjection {
+    /// Whether the item is a struct rather than a function.
+    pub is_struct: bool,
+    /// The item's primitive positions, in declaration order.
+    pub positions: Vec<PositionProjection>,
+}
+
+/// Reports items repeating a primitive across at least `min_positions`
+/// positions, with the item kind, `function` or `struct`, as the reason.
+///
+/// # Examples
+///
+/// ```ignore
+/// let position = |name: &str| PositionProjection {
+///     name: name.to_owned(),
+///     primitive: "String".to_owned(),
+/// };
+/// let item = ItemPositionsProjection {
+///     is_struct: false,
+///     positions: vec![position("from"), position("to"), position("memo")],
+/// };
+/// assert!(PrimitiveObsessionDecision::default().decide(&item).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrimitiveObsessionDecision {
+    policy: RepetitionPolicy,
+    min_positions: usize,
+}
+
+impl PrimitiveObsessionDecision {
+    /// Creates a decision reporting primitives filling at least
+    /// `min_positions` positions, ignoring the `exempt_types`.
+    #[must_use]
+    pub fn new(min_positions: usize, exempt_types: Vec<String>) -> Self {
+        Self {
+            policy: RepetitionPolicy::new(min_positions, exempt_types),
+            min_positions,
+        }
+    }
+}
+
+impl Default for PrimitiveObsessionDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_POSITIONS, Vec::new())
+    }
+}
+
+impl LintDecision for PrimitiveObsessionDecision {
+    type Projection = ItemPositionsProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, item: &ItemPositionsProjection) -> Option<Finding> {
+        let named = item
+            .positions
+            .iter()
+            .map(|position| (position.name.as_str(), position.primitive.as_str()));
+        let repetition = self.policy.repeated(named).into_iter().next()?;
+        let kind = if item.is_struct {
+            ItemKind::Struct
+        } else {
+            ItemKind::Function
+        };
+        Some(Finding::new(LINT_NAME, kind.fluent_kind()).with_subject(repetition.primitive()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ItemPositionsProjection>> {
+        let repeated = self.min_positions.max(2);
+        let item = |is_struct, primitive: &str, count: usize| ItemPositionsProjection {
+            is_struct,
+            positions: (0..count)
+                .map(|index| PositionProjection {
+                    name: format!("value_{index}"),
+                    primitive: primitive.to_owned(),
+                })
+                .collect(),
+        };
+        vec![
+            DecisionFixture::flagged(
+                "repeated parameter type",
+                item(false, "u64", repeated),
+                ItemKind::Function.fluent_kind(),
+            ),
+            DecisionFixture::flagged(
+                "repeated field type",
+                item(true, "u64", repeated),
+                ItemKind::Struct.fluent_kind(),
+            ),
+            DecisionFixture::clean("too few positions", item(false, "u64", repeated - 1)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn exempt_types_are_not_reported() {
+        let position = |name: &str| PositionProjection {
+            name: name.to_owned(),
+            primitive: "bool".to_owned(),
+        };
+        let item = ItemPositionsProjection {
+            is_struct: true,
+            positions: vec![position("verbose"), position("quiet"), position("colour")],
+        };
+        let decision = PrimitiveObsessionDecision::new(3, vec!["bool".to_owned()]);
+
+        assert_eq!(decision.decide(&item), None);
+    }
+
+    #[rstest]
+    #[case::default_threshold(PrimitiveObsessionDecision::default())]
+    #[case::pairs(PrimitiveObsessionDecision::new(2, Vec::new()))]
+    #[case::below_the_floor(PrimitiveObsessionDecision::new(1, Vec::new()))]
+    fn decision_conforms(#[case] decision: PrimitiveObsessionDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
@@ -0,0 +1,147 @@
+//! Renders repeated-primitive findings as localized diagnostics.
+//!
+//! The driver finds the repetitions; this module turns each one into a
+//! warning on the item's name with a note pointing at every repeated position.
+
+use std::borrow::Cow;
+
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::Span;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    noop_reporter, safe_resolve_message_set,
+};
+
+use crate::PRIMITIVE_OBSESSION;
+use crate::driver::LINT_NAME;
+use crate::policy::{ItemKind, Repetition};
+
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+/// A primitive repeated across one item's positions.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Finding<'a> {
+    pub(crate) item: &'a str,
+    pub(crate) kind: ItemKind,
+    pub(crate) repetition: &'a Repetition,
+}
+
+/// Where a finding is reported: the item's name and its repeated positions.
+#[derive(Clone, Debug)]
+pub(crate) struct Target {
+    pub(crate) hir_id: hir::HirId,
+    pub(crate) span: Span,
+    pub(crate) position_spans: Vec<Span>,
+}
+
+pub(crate) fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    target: Target,
+    finding: &Finding<'_>,
+    localizer: &Localizer,
+) {
+    let Target {
+        hir_id,
+        span,
+        position_spans,
+    } = target;
+    let repetition = finding.repetition;
+    let positions = format_names(repetition.positions());
+    let mut args: Arguments<'static> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("item"),
+        FluentValue::from(finding.item.to_owned()),
+    );
+    args.insert(
+        Cow::Borrowed("kind"),
+        FluentValue::from(finding.kind.fluent_kind()),
+    );
+    args.insert(
+        Cow::Borrowed("primitive"),
+        FluentValue::from(repetition.primitive().to_owned()),
+    );
+    args.insert(
+        Cow::Borrowed("count"),
+        FluentValue::from(repetition.positions().len()),
+    );
+    args.insert(Cow::Borrowed("positions"), FluentValue::from(positions));
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(finding)
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.tcx.emit_node_span_lint(
+        PRIMITIVE_OBSESSION,
+        hir_id,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(position_spans, note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// Formats names as a comma-separated list of code spans.
+fn format_names(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn fallback_messages(finding: &Finding<'_>) -> DiagnosticMessageSet {
+    let Finding {
+        item,
+        kind,
+        repetition,
+    } = finding;
+    let primitive = repetition.primitive();
+    let count = repetition.positions().len();
+    let primary = match kind {
+        ItemKind::Function => format!("`{item}` takes `{primitive}` in {count} parameters."),
+        ItemKind::Struct => format!("`{item}` stores `{primitive}` in {count} fields."),
+    };
+    DiagnosticMessageSet::new(
+        primary,
+        format!(
+            "{} share the bare type `{primitive}`, so nothing stops one being passed where \
+             another is meant.",
+            format_names(repetition.positions())
+        ),
+        format!(
+            "Introduce a newtype for each value that `{primitive}` stands in for, or list \
+             `{primitive}` in `exempt_types`."
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::single(&["id"], "`id`")]
+    #[case::several(&["from", "to", "memo"], "`from`, `to`, `memo`")]
+    fn format_names_wraps_each_name(#[case] names: &[&str], #[case] expected: &str) {
+        let names: Vec<String> = names.iter().map(|name| (*name).to_owned()).collect();
+
+        assert_eq!(format_names(&names), expected);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
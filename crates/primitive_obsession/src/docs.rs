@@ -0,0 +1,37 @@
+//! Embedded documentation for the `primitive_obsession` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `primitive_obsession`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "primitive_obsession",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags functions and structs that use the same bare primitive, such as `String` or `u64`, in several parameters or fields.",
+    rationale: "Values of one primitive type are interchangeable to the compiler. A user ID can be passed where an account ID belongs, or a duration where a timestamp belongs, and nothing complains. Newtypes name each value's meaning and turn those mix-ups into type errors.",
+    bad_example: concat!(
+        "fn transfer(from: String, to: String, reference: String, amount: u64) {\n",
+        "    // ...\n",
+        "}",
+    ),
+    good_example: concat!(
+        "fn transfer(from: AccountId, to: AccountId, reference: Reference, amount: u64) {\n",
+        "    // ...\n",
+        "}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "min_positions",
+            default: "3",
+            description: "How many parameters or fields of one item must share a primitive before it is reported. Values below 2 are raised to 2.",
+        },
+        ConfigKeyDocs {
+            key: "exempt_types",
+            default: "[]",
+            description: "Primitive names the crate may repeat freely, such as `\"bool\"`, `\"str\"`, or `\"f64\"`. References are peeled before matching, so `\"str\"` also covers `&str`.",
+        },
+    ],
+};
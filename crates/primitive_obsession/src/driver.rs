@@ -0,0 +1,285 @@
+//! Detect items that repeat the same bare primitive type.
+//!
+//! The lint lists the named parameters of free functions, inherent methods,
+//! and trait method declarations, and the fields of structs. Each position
+//! whose type, once references are peeled, is a primitive (`bool`, `char`, an
+//! integer or float, `str`, or `String`) is handed to the policy, which
+//! reports every primitive filling at least `min_positions` of them and not
+//! listed in `exempt_types`.
+//!
+//! `self` receivers, `_` parameters, trait implementations (whose signatures
+//! the trait dictates), macro-generated items, and test code are skipped.
+//! Type aliases are seen through, so `type UserId = u64;` still counts as
+//! `u64`.
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::LangItem;
+use rustc_hir::def_id::LocalDefId;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{Ident, Span, kw};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{Localizer, get_localizer_for_lint};
+
+use crate::diagnostics::{Finding, Target, emit_diagnostic};
+use crate::policy::{DEFAULT_MIN_POSITIONS, ItemKind, RepetitionPolicy};
+
+pub(crate) const LINT_NAME: &str = "primitive_obsession";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    min_positions: usize,
+    exempt_types: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_positions: DEFAULT_MIN_POSITIONS,
+            exempt_types: Vec::new(),
+        }
+    }
+}
+
+/// Lint pass reporting primitives repeated across an item's positions.
+#[derive(Default)]
+pub struct PrimitiveObsession {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Policy, test context, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    policy: RepetitionPolicy,
+    test_context: TestContext,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub PRIMITIVE_OBSESSION,
+    Warn,
+    "repeating the same bare primitive across an item's parameters or fields invites mix-ups that newtypes would catch",
+    PrimitiveObsession::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for PrimitiveObsession {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let config = load_configuration();
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            policy: RepetitionPolicy::new(config.min_positions, config.exempt_types),
+            test_context: TestContext::for_crate(cx, Vec::new()),
+            findings: FindingLimit::new(PRIMITIVE_OBSESSION, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if item.span.from_expansion() || self.is_test(cx, item.hir_id()) {
+            return;
+        }
+        match item.kind {
+            hir::ItemKind::Fn { ident, .. } => {
+                self.check_function(cx, item.owner_id.def_id, ident);
+            }
+            hir::ItemKind::Struct(ident, _, ref data) => {
+                let subject = Subject {
+                    hir_id: item.hir_id(),
+                    ident,
+                    kind: ItemKind::Struct,
+                };
+                self.report(cx, &subject, &struct_positions(cx, data.fields()));
+            }
+            _ => {}
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        let is_inherent = matches!(item.impl_kind, hir::ImplItemImplKind::Inherent { .. });
+        if !is_inherent
+            || !matches!(item.kind, hir::ImplItemKind::Fn(..))
+            || item.span.from_expansion()
+            || self.is_test(cx, item.hir_id())
+        {
+            return;
+        }
+        self.check_function(cx, item.owner_id.def_id, item.ident);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        if !matches!(item.kind, hir::TraitItemKind::Fn(..))
+            || item.span.from_expansion()
+            || self.is_test(cx, item.hir_id())
+        {
+            return;
+        }
+        self.check_function(cx, item.owner_id.def_id, item.ident);
+    }
+}
+
+impl PrimitiveObsession {
+    fn is_test(&self, cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
+        self.state.test_context.is_test(cx, hir_id)
+    }
+
+    fn check_function(&self, cx: &LateContext<'_>, def_id: LocalDefId, ident: Ident) {
+        let subject = Subject {
+            hir_id: cx.tcx.local_def_id_to_hir_id(def_id),
+            ident,
+            kind: ItemKind::Function,
+        };
+        self.report(cx, &subject, &function_positions(cx, def_id));
+    }
+
+    fn report(&self, cx: &LateContext<'_>, subject: &Subject, positions: &[Position]) {
+        let Subject {
+            hir_id,
+            ident,
+            kind,
+        } = *subject;
+        let named = positions
+            .iter()
+            .map(|position| (position.name.as_str(), position.primitive.as_str()));
+        for repetition in self.state.policy.repeated(named) {
+            debug!(
+                target: LINT_NAME,
+                "`{ident}` repeats `{}` in {} positions",
+                repetition.primitive(),
+                repetition.positions().len(),
+            );
+            let spans = positions
+                .iter()
+                .filter(|position| position.primitive == repetition.primitive())
+                .map(|position| position.span)
+                .collect();
+            let target = Target {
+                hir_id,
+                span: ident.span,
+                position_spans: spans,
+            };
+            let finding = Finding {
+                item: ident.as_str(),
+                kind,
+                repetition: &repetition,
+            };
+            if self.state.findings.admit(cx, target.hir_id, target.span) {
+                emit_diagnostic(cx, target, &finding, &self.state.localizer);
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// The function or struct whose positions are being checked.
+#[derive(Clone, Copy, Debug)]
+struct Subject {
+    hir_id: hir::HirId,
+    ident: Ident,
+    kind: ItemKind,
+}
+
+/// A parameter or field whose type is a bare primitive.
+#[derive(Clone, Debug)]
+struct Position {
+    name: String,
+    primitive: String,
+    span: Span,
+}
+
+/// Lists the named, non-`self` parameters of a function that are primitives.
+fn function_positions(cx: &LateContext<'_>, def_id: LocalDefId) -> Vec<Position> {
+    let sig = cx.tcx.instantiate_bound_regions_with_erased(
+        cx.tcx
+            .fn_sig(def_id)
+            .instantiate_identity()
+            .skip_normalization(),
+    );
+    let names = cx.tcx.fn_arg_idents(def_id.to_def_id());
+    names
+        .iter()
+        .zip(sig.inputs())
+        .filter_map(|(name, ty)| {
+            let name = (*name)?;
+            if matches!(name.name, kw::SelfLower | kw::Underscore) {
+                return None;
+            }
+            Some(Position {
+                name: name.to_string(),
+                primitive: primitive_name(cx, *ty)?,
+                span: name.span,
+            })
+        })
+        .collect()
+}
+
+/// Lists the fields of a struct that are primitives.
+fn struct_positions(cx: &LateContext<'_>, fields: &[hir::FieldDef<'_>]) -> Vec<Position> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let ty = cx
+                .tcx
+                .type_of(field.def_id)
+                .instantiate_identity()
+                .skip_normalization();
+            Some(Position {
+                name: field.ident.to_string(),
+                primitive: primitive_name(cx, ty)?,
+                span: field.span,
+            })
+        })
+        .collect()
+}
+
+/// Names `ty` when it is a primitive once references are peeled.
+fn primitive_name<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<String> {
+    let ty = ty.peel_refs();
+    match ty.kind() {
+        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Str => {
+            Some(ty.to_string())
+        }
+        ty::Adt(adt, _) if cx.tcx.is_lang_item(adt.did(), LangItem::String) => {
+            Some(String::from("String"))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn default_config_matches_policy_defaults() {
+        let config = Config::default();
+
+        assert_eq!(
+            RepetitionPolicy::new(config.min_positions, config.exempt_types),
+            RepetitionPolicy::default()
+        );
+    }
+}
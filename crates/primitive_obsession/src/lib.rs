@@ -0,0 +1,45 @@
+//! Lint crate flagging items that repeat the same bare primitive type.
+//!
+//! A function taking three `String` parameters, or a struct holding several
+//! `u64` fields, leaves the compiler unable to tell the values apart: a user
+//! ID can be passed where an order ID belongs, or a timeout where a
+//! timestamp belongs. When one primitive fills at least `min_positions` of an
+//! item's parameters or fields, the lint suggests newtypes. Primitives a
+//! crate is happy to repeat can be listed in `exempt_types`. The lint is
+//! experimental and ships behind the `experimental-primitive-obsession` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod diagnostics;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ItemPositionsProjection, PositionProjection, PrimitiveObsessionDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn primitive_obsession_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
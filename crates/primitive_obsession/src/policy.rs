@@ -0,0 +1,154 @@
+//! Finding primitive types repeated across an item's positions.
+//!
+//! The driver lists the parameters of a function or the fields of a struct
+//! whose type is a bare primitive, naming each position and its primitive.
+//! This module groups them by primitive, drops exempt primitives, and keeps
+//! those filling at least `min_positions` positions, so the decision can be
+//! tested without the compiler.
+
+/// Positions one primitive must fill when `min_positions` is not set.
+pub(crate) const DEFAULT_MIN_POSITIONS: usize = 3;
+
+/// Smallest accepted threshold; a single position cannot repeat.
+const SMALLEST_MIN_POSITIONS: usize = 2;
+
+/// What kind of item a repetition was found in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ItemKind {
+    /// A function or method, whose positions are its parameters.
+    Function,
+    /// A struct, whose positions are its fields.
+    Struct,
+}
+
+impl ItemKind {
+    /// Returns the Fluent selector naming the item kind.
+    pub(crate) const fn fluent_kind(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Struct => "struct",
+        }
+    }
+}
+
+/// A primitive type filling several positions of one item.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Repetition {
+    primitive: String,
+    positions: Vec<String>,
+}
+
+impl Repetition {
+    /// Returns the repeated primitive, such as `u64` or `String`.
+    pub(crate) fn primitive(&self) -> &str {
+        &self.primitive
+    }
+
+    /// Returns the names of the positions holding the primitive, in
+    /// declaration order.
+    pub(crate) fn positions(&self) -> &[String] {
+        &self.positions
+    }
+}
+
+/// The configured threshold and exempt primitives.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct RepetitionPolicy {
+    min_positions: usize,
+    exempt_types: Vec<String>,
+}
+
+impl Default for RepetitionPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_POSITIONS, Vec::new())
+    }
+}
+
+impl RepetitionPolicy {
+    /// Builds the policy, raising `min_positions` to at least two.
+    pub(crate) fn new(min_positions: usize, exempt_types: Vec<String>) -> Self {
+        Self {
+            min_positions: min_positions.max(SMALLEST_MIN_POSITIONS),
+            exempt_types,
+        }
+    }
+
+    /// Returns every non-exempt primitive filling at least `min_positions`
+    /// of `positions`, ordered by its first position.
+    ///
+    /// Each entry of `positions` pairs a position name with its primitive.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = RepetitionPolicy::default();
+    /// let repeated = policy.repeated([("from", "String"), ("to", "String"), ("memo", "String")]);
+    /// assert_eq!(repeated[0].primitive(), "String");
+    /// ```
+    pub(crate) fn repeated<'a>(
+        &self,
+        positions: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Vec<Repetition> {
+        let mut groups: Vec<Repetition> = Vec::new();
+        for (name, primitive) in positions {
+            if self.exempt_types.iter().any(|exempt| exempt == primitive) {
+                continue;
+            }
+            match groups.iter_mut().find(|group| group.primitive == primitive) {
+                Some(group) => group.positions.push(name.to_owned()),
+                None => groups.push(Repetition {
+                    primitive: primitive.to_owned(),
+                    positions: vec![name.to_owned()],
+                }),
+            }
+        }
+        groups.retain(|group| group.positions.len() >= self.min_positions);
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const TRANSFER: [(&str, &str); 4] = [
+        ("from", "String"),
+        ("amount", "u64"),
+        ("to", "String"),
+        ("memo", "String"),
+    ];
+
+    #[rstest]
+    fn groups_positions_by_primitive_in_declaration_order() {
+        let repeated = RepetitionPolicy::default().repeated(TRANSFER);
+
+        assert_eq!(repeated.len(), 1);
+        assert_eq!(repeated[0].primitive(), "String");
+        assert_eq!(repeated[0].positions(), ["from", "to", "memo"]);
+    }
+
+    #[rstest]
+    #[case::below(4, 0)]
+    #[case::at(3, 1)]
+    #[case::clamped(1, 1)]
+    fn threshold_controls_what_repeats(#[case] min_positions: usize, #[case] expected: usize) {
+        let policy = RepetitionPolicy::new(min_positions, Vec::new());
+
+        assert_eq!(policy.repeated(TRANSFER).len(), expected);
+    }
+
+    #[rstest]
+    fn exempt_types_are_ignored() {
+        let policy = RepetitionPolicy::new(3, vec![String::from("String")]);
+
+        assert!(policy.repeated(TRANSFER).is_empty());
+    }
+
+    #[rstest]
+    #[case::function(ItemKind::Function, "function")]
+    #[case::structure(ItemKind::Struct, "struct")]
+    fn item_kinds_have_fluent_selectors(#[case] kind: ItemKind, #[case] expected: &str) {
+        assert_eq!(kind.fluent_kind(), expected);
+    }
+}
@@ -0,0 +1,121 @@
+//! Behaviour-driven coverage for repeated primitive detection.
+
+use super::{Finding, fallback_messages};
+use crate::policy::{DEFAULT_MIN_POSITIONS, ItemKind, RepetitionPolicy};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+use whitaker_common::i18n::DiagnosticMessageSet;
+
+struct PrimitiveWorld {
+    item: RefCell<String>,
+    kind: Cell<ItemKind>,
+    min_positions: Cell<usize>,
+    exempt: RefCell<Vec<String>>,
+    positions: RefCell<Vec<(String, String)>>,
+    messages: RefCell<Vec<DiagnosticMessageSet>>,
+}
+
+#[fixture]
+fn world() -> PrimitiveWorld {
+    PrimitiveWorld {
+        item: RefCell::new(String::new()),
+        kind: Cell::new(ItemKind::Function),
+        min_positions: Cell::new(DEFAULT_MIN_POSITIONS),
+        exempt: RefCell::new(Vec::new()),
+        positions: RefCell::new(Vec::new()),
+        messages: RefCell::new(Vec::new()),
+    }
+}
+
+#[given("a function named {name}")]
+fn given_function(world: &PrimitiveWorld, name: String) {
+    *world.item.borrow_mut() = name;
+    world.kind.set(ItemKind::Function);
+}
+
+#[given("a struct named {name}")]
+fn given_struct(world: &PrimitiveWorld, name: String) {
+    *world.item.borrow_mut() = name;
+    world.kind.set(ItemKind::Struct);
+}
+
+#[given("the minimum number of positions is {count}")]
+fn given_min_positions(world: &PrimitiveWorld, count: usize) {
+    world.min_positions.set(count);
+}
+
+#[given("the exempt types are {primitive}")]
+fn given_exempt(world: &PrimitiveWorld, primitive: String) {
+    world.exempt.borrow_mut().push(primitive);
+}
+
+#[given("the position {name} of type {primitive}")]
+fn given_position(world: &PrimitiveWorld, name: String, primitive: String) {
+    world.positions.borrow_mut().push((name, primitive));
+}
+
+#[when("the item is checked")]
+fn when_checked(world: &PrimitiveWorld) {
+    let policy = RepetitionPolicy::new(world.min_positions.get(), world.exempt.borrow().clone());
+    let positions = world.positions.borrow();
+    let item = world.item.borrow();
+    let messages = policy
+        .repeated(
+            positions
+                .iter()
+                .map(|(name, primitive)| (name.as_str(), primitive.as_str())),
+        )
+        .iter()
+        .map(|repetition| {
+            fallback_messages(&Finding {
+                item: &item,
+                kind: world.kind.get(),
+                repetition,
+            })
+        })
+        .collect();
+    *world.messages.borrow_mut() = messages;
+}
+
+#[then("the item is accepted")]
+fn then_accepted(world: &PrimitiveWorld) {
+    assert!(world.messages.borrow().is_empty());
+}
+
+#[then("the item is reported as {primary}")]
+fn then_reported(world: &PrimitiveWorld, primary: String) {
+    let messages = world.messages.borrow();
+    let [messages] = messages.as_slice() else {
+        panic!(
+            "exactly one diagnostic should be rendered, got {}",
+            messages.len()
+        );
+    };
+    assert_eq!(messages.primary(), primary);
+}
+
+#[scenario(path = "tests/features/primitive_obsession.feature", index = 0)]
+fn scenario_repeated_parameters(world: PrimitiveWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/primitive_obsession.feature", index = 1)]
+fn scenario_repeated_fields(world: PrimitiveWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/primitive_obsession.feature", index = 2)]
+fn scenario_below_threshold(world: PrimitiveWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/primitive_obsession.feature", index = 3)]
+fn scenario_lower_threshold(world: PrimitiveWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/primitive_obsession.feature", index = 4)]
+fn scenario_exempt_types(world: PrimitiveWorld) {
+    let _ = world;
+}
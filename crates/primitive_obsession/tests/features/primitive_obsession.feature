@@ -0,0 +1,45 @@
+Feature: Primitive obsession
+  A function or struct that uses the same bare primitive in at least
+  min_positions parameters or fields is reported, unless the primitive is
+  exempt.
+
+  Scenario: Repeated string parameters are reported
+    Given a function named transfer
+    And the position from of type String
+    And the position amount of type u64
+    And the position to of type String
+    And the position memo of type String
+    When the item is checked
+    Then the item is reported as `transfer` takes `String` in 3 parameters.
+
+  Scenario: Repeated timestamp fields are reported
+    Given a struct named Window
+    And the position opened of type u64
+    And the position closed of type u64
+    And the position expires of type u64
+    When the item is checked
+    Then the item is reported as `Window` stores `u64` in 3 fields.
+
+  Scenario: Primitives below the threshold are accepted
+    Given a function named lookup
+    And the position id of type u64
+    And the position name of type String
+    When the item is checked
+    Then the item is accepted
+
+  Scenario: A lower threshold reports fewer repetitions
+    Given a function named lookup
+    And the minimum number of positions is 2
+    And the position first of type str
+    And the position last of type str
+    When the item is checked
+    Then the item is reported as `lookup` takes `str` in 2 parameters.
+
+  Scenario: Exempt primitives are accepted
+    Given a struct named Flags
+    And the exempt types are bool
+    And the position verbose of type bool
+    And the position quiet of type bool
+    And the position colour of type bool
+    When the item is checked
+    Then the item is accepted
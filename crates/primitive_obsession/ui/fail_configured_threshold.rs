@@ -0,0 +1,14 @@
+//! Negative UI fixture: a lower `min_positions` reports two repeated positions.
+#![warn(primitive_obsession)]
+#![allow(dead_code)]
+
+fn lookup(first: &str, last: &str) -> usize {
+    first.len() + last.len()
+}
+
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+fn main() {}
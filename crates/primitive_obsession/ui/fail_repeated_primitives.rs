@@ -0,0 +1,34 @@
+//! Negative UI fixture: items repeating one bare primitive are reported.
+#![warn(primitive_obsession)]
+#![allow(dead_code)]
+
+type UserId = u64;
+
+fn transfer(from: String, to: &String, memo: String, amount: u64) {
+    let _ = (from, to, memo, amount);
+}
+
+fn follow(follower: UserId, followee: UserId, at: u64) {
+    let _ = (follower, followee, at);
+}
+
+struct Window {
+    opened_at: u64,
+    closed_at: u64,
+    deadline: u64,
+    label: String,
+}
+
+struct Account;
+
+impl Account {
+    fn rename(&self, old: &str, new: &str, reason: &str) {
+        let _ = (old, new, reason);
+    }
+}
+
+trait Store {
+    fn copy(&self, source: &str, target: &str, tag: &str);
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+//! Positive UI fixture: distinct types, exempt primitives, trait impls, and
+//! tests are accepted.
+#![warn(primitive_obsession)]
+#![allow(dead_code)]
+
+struct Payer(String);
+struct Payee(String);
+
+fn transfer(from: Payer, to: Payee, memo: String, amount: u64) {
+    let _ = (from, to, memo, amount);
+}
+
+fn render(bold: bool, italic: bool, underline: bool) {
+    let _ = (bold, italic, underline);
+}
+
+struct Flags {
+    verbose: bool,
+    quiet: bool,
+    colour: bool,
+}
+
+fn ignored(_: u8, _: u8, _: u8, value: u8) -> u8 {
+    value
+}
+
+trait Paint {
+    fn paint(&self, red: Payer, green: Payee);
+}
+
+struct Canvas;
+
+impl Paint for Canvas {
+    fn paint(&self, _red: Payer, _green: Payee) {}
+}
+
+#[cfg(test)]
+mod tests {
+    fn fixture(a: u64, b: u64, c: u64) -> u64 {
+        a + b + c
+    }
+}
+
+fn main() {}
@@ -0,0 +1,168 @@
+//! Pure decision logic for `public_trait_missing_sealed_pattern_marker`.
+//!
+//! The driver describes each trait a library exports by its crate-relative
+//! path, any `impl(..)` restriction, and its direct supertraits.
+//! [`PublicTraitMissingSealedPatternMarkerDecision`] builds the same
+//! [`TraitShape`] and reports the trait when [`missing_seal`] finds it
+//! unsealed, with the intent, `listed` or `hidden`, as the reason.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{SealIntent, SealedTraits, Supertrait, TraitShape, missing_seal};
+
+/// Reason key for traits listed in `sealed_traits`.
+const LISTED: &str = "listed";
+
+/// Reason key for traits requiring a public `#[doc(hidden)]` supertrait.
+const HIDDEN: &str = "hidden";
+
+/// One direct supertrait of the exported trait.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupertraitProjection {
+    /// The supertrait's name.
+    pub name: String,
+    /// Whether the supertrait is marked `#[doc(hidden)]`.
+    pub doc_hidden: bool,
+    /// Whether crates downstream can name the supertrait.
+    pub nameable: bool,
+}
+
+/// The facts about one exported trait that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExportedTraitProjection {
+    /// The trait's path relative to the crate root.
+    pub path: String,
+    /// Whether an `impl(..)` restriction limits where it is implemented.
+    pub restricted: bool,
+    /// The trait's direct supertraits.
+    pub supertraits: Vec<SupertraitProjection>,
+}
+
+/// Reports exported traits that are meant to be sealed but are not.
+///
+/// A listed trait is named as the finding's subject; otherwise the subject
+/// is the hidden supertrait.
+///
+/// # Examples
+///
+/// ```ignore
+/// let exported = ExportedTraitProjection {
+///     path: "codec::Encode".to_owned(),
+///     ..ExportedTraitProjection::default()
+/// };
+/// let decision =
+///     PublicTraitMissingSealedPatternMarkerDecision::new(&["codec::Encode".to_owned()]);
+/// assert!(decision.decide(&exported).is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PublicTraitMissingSealedPatternMarkerDecision {
+    sealed_traits: SealedTraits,
+}
+
+impl PublicTraitMissingSealedPatternMarkerDecision {
+    /// Creates a decision requiring the traits at `sealed_traits` to be
+    /// sealed.
+    #[must_use]
+    pub fn new(sealed_traits: &[String]) -> Self {
+        Self {
+            sealed_traits: SealedTraits::new(sealed_traits),
+        }
+    }
+}
+
+impl LintDecision for PublicTraitMissingSealedPatternMarkerDecision {
+    type Projection = ExportedTraitProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, exported: &ExportedTraitProjection) -> Option<Finding> {
+        let supertraits: Vec<Supertrait> = exported
+            .supertraits
+            .iter()
+            .map(|parent| Supertrait {
+                name: parent.name.clone(),
+                doc_hidden: parent.doc_hidden,
+                nameable: parent.nameable,
+            })
+            .collect();
+        let shape = TraitShape {
+            listed: self.sealed_traits.lists(&exported.path),
+            restricted: exported.restricted,
+            supertraits: &supertraits,
+        };
+        Some(match missing_seal(&shape)? {
+            SealIntent::Listed => {
+                Finding::new(LINT_NAME, LISTED).with_subject(exported.path.as_str())
+            }
+            SealIntent::HiddenSupertrait(name) => {
+                Finding::new(LINT_NAME, HIDDEN).with_subject(name)
+            }
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ExportedTraitProjection>> {
+        let supertrait = |doc_hidden, nameable| SupertraitProjection {
+            name: "Sealed".to_owned(),
+            doc_hidden,
+            nameable,
+        };
+        let exported = |supertraits| ExportedTraitProjection {
+            path: "codec::Encode".to_owned(),
+            restricted: false,
+            supertraits,
+        };
+        vec![
+            DecisionFixture::flagged(
+                "public hidden supertrait",
+                exported(vec![supertrait(true, true)]),
+                HIDDEN,
+            ),
+            DecisionFixture::clean(
+                "private supertrait",
+                exported(vec![supertrait(true, false)]),
+            ),
+            DecisionFixture::clean(
+                "restricted implementations",
+                ExportedTraitProjection {
+                    restricted: true,
+                    ..exported(vec![supertrait(true, true)])
+                },
+            ),
+            DecisionFixture::clean("unlisted open trait", exported(Vec::new())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::bare("codec::Encode")]
+    #[case::anchored("crate::codec::Encode")]
+    fn listed_traits_are_reported(#[case] listed: &str) {
+        let exported = ExportedTraitProjection {
+            path: "codec::Encode".to_owned(),
+            ..ExportedTraitProjection::default()
+        };
+        let decision = PublicTraitMissingSealedPatternMarkerDecision::new(&[listed.to_owned()]);
+
+        assert_eq!(
+            decision.decide(&exported),
+            Some(Finding::new(LINT_NAME, LISTED).with_subject("codec::Encode"))
+        );
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&PublicTraitMissingSealedPatternMarkerDecision::default()),
+            Ok(4)
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! Embedded documentation for the `public_trait_missing_sealed_pattern_marker`
+//! lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `public_trait_missing_sealed_pattern_marker`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "public_trait_missing_sealed_pattern_marker",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags exported traits meant to be sealed, by configuration or a `#[doc(hidden)]` supertrait, that downstream crates can still implement. Allow-by-default.",
+    rationale: "Every trait other crates can implement is a semver promise: adding a required method or supertrait breaks their impls. Sealing the trait behind a supertrait declared in a private module keeps it implementable only inside the defining crate, so it can grow without a major release.",
+    bad_example: "#[doc(hidden)]\npub trait Sealed {}\n\npub trait Backend: Sealed {\n    fn name(&self) -> &str;\n}",
+    good_example: "mod private {\n    pub trait Sealed {}\n}\n\npub trait Backend: private::Sealed {\n    fn name(&self) -> &str;\n}",
+    config: &[ConfigKeyDocs {
+        key: "sealed_traits",
+        default: "[]",
+        description: "Trait paths relative to the crate root, such as `storage::Backend`, that must be sealed whatever their supertraits.",
+    }],
+};
@@ -0,0 +1,307 @@
+//! Lint pass reporting exported traits that should be sealed but are not.
+//!
+//! Each trait the crate exports, according to the compiler's effective
+//! visibilities, is described by its path from [`whitaker::hir::module_path`],
+//! its `impl(..)` restriction, and its explicit supertraits, including those
+//! written as `where Self: Trait` bounds. A local supertrait the crate does
+//! not export is unnameable downstream and seals the trait; supertraits from
+//! other crates are treated as nameable. [`missing_seal`] then decides
+//! whether the trait is reported. Binary crates have no public API and are
+//! skipped, as are traits produced by macro expansion.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::attrs::CrateType;
+use rustc_hir::def_id::DefId;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{SealIntent, SealedTraits, Supertrait, TraitShape, missing_seal};
+
+pub(crate) const LINT_NAME: &str = "public_trait_missing_sealed_pattern_marker";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    sealed_traits: Vec<String>,
+}
+
+dylint_linting::impl_late_lint! {
+    pub PUBLIC_TRAIT_MISSING_SEALED_PATTERN_MARKER,
+    Allow,
+    "public traits meant to be sealed should require a supertrait downstream crates cannot name",
+    PublicTraitMissingSealedPatternMarker::default()
+}
+
+/// Lint pass that finds exported traits missing their seal.
+#[derive(Default)]
+pub struct PublicTraitMissingSealedPatternMarker {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Listed traits, crate kind, finding limit, and localization for the crate
+/// being checked.
+#[derive(Default)]
+struct CrateState {
+    sealed_traits: SealedTraits,
+    is_library: bool,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for PublicTraitMissingSealedPatternMarker {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            sealed_traits: SealedTraits::new(&load_configuration().sealed_traits),
+            is_library: cx
+                .tcx
+                .crate_types()
+                .iter()
+                .any(|crate_type| matches!(crate_type, CrateType::Rlib | CrateType::Dylib)),
+            findings: FindingLimit::new(PUBLIC_TRAIT_MISSING_SEALED_PATTERN_MARKER, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        let hir::ItemKind::Trait {
+            impl_restriction,
+            ident,
+            ..
+        } = item.kind
+        else {
+            return;
+        };
+        let state = &*self.state;
+        if !state.is_library
+            || item.span.from_expansion()
+            || !cx.effective_visibilities.is_exported(item.owner_id.def_id)
+        {
+            return;
+        }
+
+        let module = module_path(cx, item.hir_id());
+        let path = if module.is_empty() {
+            ident.to_string()
+        } else {
+            format!("{module}::{ident}")
+        };
+        let supertraits = supertraits(cx, item.owner_id.to_def_id());
+        let shape = TraitShape {
+            listed: state.sealed_traits.lists(&path),
+            restricted: matches!(impl_restriction.kind, hir::RestrictionKind::Restricted(_)),
+            supertraits: &supertraits,
+        };
+        let Some(intent) = missing_seal(&shape) else {
+            return;
+        };
+
+        debug!(target: LINT_NAME, "exported trait `{path}` is not sealed: {intent:?}");
+        if state.findings.admit(cx, item.hir_id(), ident.span) {
+            let message = UnsealedTraitMessage {
+                name: ident.as_str(),
+                intent: &intent,
+            };
+            emit_diagnostic(cx, ident.span, &message, &state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Describes the explicit supertraits of the trait `def_id`.
+fn supertraits(cx: &LateContext<'_>, def_id: DefId) -> Vec<Supertrait> {
+    cx.tcx
+        .explicit_super_predicates_of(def_id)
+        .skip_binder()
+        .iter()
+        .filter_map(|(clause, _)| clause.as_trait_clause())
+        .map(|predicate| {
+            let parent = predicate.def_id();
+            Supertrait {
+                name: cx.tcx.item_name(parent).to_string(),
+                doc_hidden: cx.tcx.is_doc_hidden(parent),
+                nameable: parent
+                    .as_local()
+                    .is_none_or(|local| cx.effective_visibilities.is_exported(local)),
+            }
+        })
+        .collect()
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &UnsealedTraitMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        PUBLIC_TRAIT_MISSING_SEALED_PATTERN_MARKER,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one unsealed trait interpolates.
+#[derive(Clone, Copy, Debug)]
+struct UnsealedTraitMessage<'a> {
+    name: &'a str,
+    intent: &'a SealIntent,
+}
+
+impl UnsealedTraitMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("name"),
+            FluentValue::from(self.name.to_owned()),
+        );
+        let (intent, supertrait) = match self.intent {
+            SealIntent::Listed => ("listed", String::new()),
+            SealIntent::HiddenSupertrait(supertrait) => ("hidden", supertrait.clone()),
+        };
+        args.insert(Cow::Borrowed("intent"), FluentValue::from(intent));
+        args.insert(Cow::Borrowed("supertrait"), FluentValue::from(supertrait));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let name = self.name;
+        DiagnosticMessageSet::new(
+            format!("Public trait `{name}` can be implemented outside this crate."),
+            match self.intent {
+                SealIntent::Listed => format!(
+                    "`{name}` is listed in `sealed_traits`, but nothing stops downstream crates \
+                     implementing it, so adding a required method is a breaking change."
+                ),
+                SealIntent::HiddenSupertrait(supertrait) => format!(
+                    "`{name}` requires the hidden trait `{supertrait}`, but `{supertrait}` is \
+                     public, so downstream crates can implement both."
+                ),
+            },
+            String::from(
+                "Require a supertrait declared in a private module, such as `private::Sealed`, \
+                 and implement it only for this crate's types.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn resolve(message: &UnsealedTraitMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_lists_no_traits() {
+        assert!(Config::default().sealed_traits.is_empty());
+    }
+
+    #[rstest]
+    fn fallback_note_names_the_hidden_supertrait() {
+        let intent = SealIntent::HiddenSupertrait("Sealed".into());
+        let messages = UnsealedTraitMessage {
+            name: "Backend",
+            intent: &intent,
+        }
+        .fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Public trait `Backend` can be implemented outside this crate."
+        );
+        assert_eq!(
+            messages.note(),
+            "`Backend` requires the hidden trait `Sealed`, but `Sealed` is public, so downstream \
+             crates can implement both."
+        );
+    }
+
+    #[rstest]
+    #[case::listed(SealIntent::Listed)]
+    #[case::hidden(SealIntent::HiddenSupertrait("Sealed".into()))]
+    fn english_messages_match_the_fallback(#[case] intent: SealIntent) {
+        let message = UnsealedTraitMessage {
+            name: "Backend",
+            intent: &intent,
+        };
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let intent = SealIntent::HiddenSupertrait("Sealed".into());
+        let message = UnsealedTraitMessage {
+            name: "Backend",
+            intent: &intent,
+        };
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`Backend`"));
+        assert!(messages.note().contains("`Sealed`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,45 @@
+//! Lint crate flagging public traits that should be sealed but are not.
+//!
+//! A trait downstream crates can implement is a semver promise: adding a
+//! method without a default, or a new supertrait, breaks every outside impl.
+//! Library authors avoid that promise by sealing the trait behind a
+//! supertrait nobody outside the crate can name. The lint reports exported
+//! traits that are meant to be sealed, because `sealed_traits` lists them or
+//! because they require a `#[doc(hidden)]` supertrait, yet have no
+//! unnameable supertrait or `impl(crate)` restriction. The lint is
+//! experimental and ships behind the
+//! `experimental-public-trait-missing-sealed-pattern-marker` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{
+    ExportedTraitProjection, PublicTraitMissingSealedPatternMarkerDecision, SupertraitProjection,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn public_trait_missing_sealed_pattern_marker_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,162 @@
+//! Rules deciding which exported traits are reported as unsealed.
+//!
+//! The driver describes an exported trait by its path relative to the crate
+//! root, whether it carries an `impl(crate)` style restriction, and its
+//! supertraits. This module decides whether the trait is meant to be sealed
+//! and whether anything actually seals it. The rules are kept free of
+//! compiler types so they can be tested directly.
+
+/// Leading path segments that locate a path rather than name a module.
+const PATH_ANCHORS: [&str; 2] = ["crate", "self"];
+
+/// The configured trait paths that must be sealed.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SealedTraits {
+    paths: Vec<String>,
+}
+
+impl SealedTraits {
+    /// Normalises the configured `paths`, dropping a leading `crate::`.
+    #[must_use]
+    pub(crate) fn new(paths: &[String]) -> Self {
+        Self {
+            paths: paths.iter().map(|path| unanchored(path)).collect(),
+        }
+    }
+
+    /// Returns `true` when the trait at crate-relative `path` is listed.
+    #[must_use]
+    pub(crate) fn lists(&self, path: &str) -> bool {
+        self.paths.iter().any(|listed| listed == path)
+    }
+}
+
+/// What the lint needs to know about one supertrait.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Supertrait {
+    /// The supertrait's name, as reported in the diagnostic.
+    pub(crate) name: String,
+    /// Whether the supertrait is marked `#[doc(hidden)]`.
+    pub(crate) doc_hidden: bool,
+    /// Whether crates downstream can name, and so implement, the supertrait.
+    pub(crate) nameable: bool,
+}
+
+/// The trait under check, as the driver sees it.
+#[derive(Clone, Debug)]
+pub(crate) struct TraitShape<'a> {
+    /// Whether `sealed_traits` lists the trait.
+    pub(crate) listed: bool,
+    /// Whether an `impl(..)` restriction limits where the trait is
+    /// implemented.
+    pub(crate) restricted: bool,
+    /// The trait's direct supertraits.
+    pub(crate) supertraits: &'a [Supertrait],
+}
+
+/// Why an unsealed trait was expected to be sealed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum SealIntent {
+    /// `sealed_traits` lists the trait.
+    Listed,
+    /// The trait requires the named `#[doc(hidden)]` supertrait, the usual
+    /// sign of a seal that was meant to be private.
+    HiddenSupertrait(String),
+}
+
+/// Returns why `shape` should be sealed when nothing seals it.
+///
+/// A trait is sealed when an `impl(..)` restriction applies or one of its
+/// supertraits cannot be named downstream. Otherwise it is reported when it
+/// is listed, or when it requires a `#[doc(hidden)]` supertrait.
+#[must_use]
+pub(crate) fn missing_seal(shape: &TraitShape<'_>) -> Option<SealIntent> {
+    if shape.restricted || shape.supertraits.iter().any(|parent| !parent.nameable) {
+        return None;
+    }
+    if shape.listed {
+        return Some(SealIntent::Listed);
+    }
+    shape
+        .supertraits
+        .iter()
+        .find(|parent| parent.doc_hidden)
+        .map(|parent| SealIntent::HiddenSupertrait(parent.name.clone()))
+}
+
+/// Drops a leading `crate::` or `self::` from `path`.
+fn unanchored(path: &str) -> String {
+    path.split("::")
+        .skip_while(|segment| segment.is_empty() || PATH_ANCHORS.contains(segment))
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn supertrait(name: &str, doc_hidden: bool, nameable: bool) -> Supertrait {
+        Supertrait {
+            name: name.to_owned(),
+            doc_hidden,
+            nameable,
+        }
+    }
+
+    fn check(listed: bool, restricted: bool, supertraits: &[Supertrait]) -> Option<SealIntent> {
+        missing_seal(&TraitShape {
+            listed,
+            restricted,
+            supertraits,
+        })
+    }
+
+    #[rstest]
+    #[case::bare("storage::Backend", "storage::Backend", true)]
+    #[case::anchored("crate::storage::Backend", "storage::Backend", true)]
+    #[case::root("Backend", "Backend", true)]
+    #[case::other_module("storage::Backend", "cache::Backend", false)]
+    #[case::name_only("Backend", "storage::Backend", false)]
+    fn lists_configured_paths(#[case] listed: &str, #[case] path: &str, #[case] expected: bool) {
+        let sealed = SealedTraits::new(&[listed.to_owned()]);
+
+        assert_eq!(sealed.lists(path), expected);
+    }
+
+    #[rstest]
+    fn reports_hidden_public_supertraits() {
+        let parents = [supertrait("Sealed", true, true)];
+
+        assert_eq!(
+            check(false, false, &parents),
+            Some(SealIntent::HiddenSupertrait("Sealed".into()))
+        );
+    }
+
+    #[rstest]
+    fn reports_listed_traits() {
+        assert_eq!(check(true, false, &[]), Some(SealIntent::Listed));
+    }
+
+    #[rstest]
+    #[case::private_supertrait(false, vec![supertrait("Sealed", false, false)])]
+    #[case::hidden_private_supertrait(true, vec![supertrait("Sealed", true, false)])]
+    fn accepts_unnameable_supertraits(#[case] listed: bool, #[case] parents: Vec<Supertrait>) {
+        assert_eq!(check(listed, false, &parents), None);
+    }
+
+    #[rstest]
+    fn accepts_restricted_traits() {
+        assert_eq!(check(true, true, &[supertrait("Sealed", true, true)]), None);
+    }
+
+    #[rstest]
+    fn ignores_unmarked_traits() {
+        assert_eq!(
+            check(false, false, &[supertrait("Debug", false, true)]),
+            None
+        );
+    }
+}
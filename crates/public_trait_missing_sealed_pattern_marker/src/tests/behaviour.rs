@@ -0,0 +1,119 @@
+//! Behaviour-driven coverage for the sealed trait policy.
+
+use crate::policy::{SealIntent, SealedTraits, Supertrait, TraitShape, missing_seal};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct SealWorld {
+    sealed_traits: RefCell<Vec<String>>,
+    path: RefCell<String>,
+    restricted: Cell<bool>,
+    supertraits: RefCell<Vec<Supertrait>>,
+    reported: RefCell<Option<Option<SealIntent>>>,
+}
+
+#[fixture]
+fn world() -> SealWorld {
+    SealWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+#[given("the sealed traits {path}")]
+fn given_sealed_traits(world: &SealWorld, path: String) {
+    *world.sealed_traits.borrow_mut() = vec![unquote(&path)];
+}
+
+#[given("a trait at {path}")]
+fn given_trait(world: &SealWorld, path: String) {
+    *world.path.borrow_mut() = unquote(&path);
+}
+
+#[given("it requires the hidden public trait {name}")]
+fn given_hidden_supertrait(world: &SealWorld, name: String) {
+    world.supertraits.borrow_mut().push(Supertrait {
+        name: unquote(&name),
+        doc_hidden: true,
+        nameable: true,
+    });
+}
+
+#[given("it requires the private trait {name}")]
+fn given_private_supertrait(world: &SealWorld, name: String) {
+    world.supertraits.borrow_mut().push(Supertrait {
+        name: unquote(&name),
+        doc_hidden: false,
+        nameable: false,
+    });
+}
+
+#[given("the trait restricts where it is implemented")]
+fn given_restricted(world: &SealWorld) {
+    world.restricted.set(true);
+}
+
+#[when("the trait is checked")]
+fn when_checked(world: &SealWorld) {
+    let sealed_traits = SealedTraits::new(&world.sealed_traits.borrow());
+    let supertraits = world.supertraits.borrow();
+    let shape = TraitShape {
+        listed: sealed_traits.lists(&world.path.borrow()),
+        restricted: world.restricted.get(),
+        supertraits: &supertraits,
+    };
+    *world.reported.borrow_mut() = Some(missing_seal(&shape));
+}
+
+#[then("it is reported because it requires {name}")]
+fn then_reported_hidden(world: &SealWorld, name: String) {
+    assert_eq!(
+        *world.reported.borrow(),
+        Some(Some(SealIntent::HiddenSupertrait(unquote(&name))))
+    );
+}
+
+#[then("it is reported because it is listed")]
+fn then_reported_listed(world: &SealWorld) {
+    assert_eq!(*world.reported.borrow(), Some(Some(SealIntent::Listed)));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &SealWorld) {
+    assert_eq!(*world.reported.borrow(), Some(None));
+}
+
+#[scenario(
+    path = "tests/features/public_trait_missing_sealed_pattern_marker.feature",
+    index = 0
+)]
+fn scenario_hidden_supertrait(world: SealWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/public_trait_missing_sealed_pattern_marker.feature",
+    index = 1
+)]
+fn scenario_private_supertrait(world: SealWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/public_trait_missing_sealed_pattern_marker.feature",
+    index = 2
+)]
+fn scenario_listed(world: SealWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/public_trait_missing_sealed_pattern_marker.feature",
+    index = 3
+)]
+fn scenario_restricted(world: SealWorld) {
+    let _ = world;
+}
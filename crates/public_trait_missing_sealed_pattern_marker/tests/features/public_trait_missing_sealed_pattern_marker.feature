@@ -0,0 +1,29 @@
+Feature: Unsealed public traits
+  Exported traits meant to be sealed, because they are listed or require a
+  hidden supertrait, are reported unless a supertrait downstream crates
+  cannot name, or an impl restriction, seals them.
+
+  Scenario: A hidden public supertrait is reported
+    Given a trait at "storage::Backend"
+    And it requires the hidden public trait "Sealed"
+    When the trait is checked
+    Then it is reported because it requires "Sealed"
+
+  Scenario: A private supertrait seals the trait
+    Given a trait at "storage::Backend"
+    And it requires the private trait "Sealed"
+    When the trait is checked
+    Then nothing is reported
+
+  Scenario: A listed trait without a seal is reported
+    Given the sealed traits "crate::storage::Backend"
+    And a trait at "storage::Backend"
+    When the trait is checked
+    Then it is reported because it is listed
+
+  Scenario: An impl restriction seals a listed trait
+    Given the sealed traits "storage::Backend"
+    And a trait at "storage::Backend"
+    And the trait restricts where it is implemented
+    When the trait is checked
+    Then nothing is reported
@@ -0,0 +1,16 @@
+//! Negative UI fixture: exported traits meant to be sealed that are not.
+#![crate_type = "lib"]
+#![warn(public_trait_missing_sealed_pattern_marker)]
+
+#[doc(hidden)]
+pub trait Sealed {}
+
+pub trait Backend: Sealed {
+    fn name(&self) -> &str;
+}
+
+pub mod storage {
+    pub trait Store {
+        fn get(&self, key: &str) -> Option<String>;
+    }
+}
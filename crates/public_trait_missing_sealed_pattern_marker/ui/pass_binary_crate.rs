@@ -0,0 +1,10 @@
+//! Positive UI fixture: a binary crate has no public API to protect.
+#![allow(dead_code)]
+#![warn(public_trait_missing_sealed_pattern_marker)]
+
+#[doc(hidden)]
+pub trait Sealed {}
+
+pub trait Backend: Sealed {}
+
+fn main() {}
@@ -0,0 +1,30 @@
+//! Positive UI fixture: sealed traits and traits not meant to be sealed.
+#![crate_type = "lib"]
+#![warn(public_trait_missing_sealed_pattern_marker)]
+
+mod private {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    impl Sealed for super::Memory {}
+}
+
+pub struct Memory;
+
+pub trait Backend: private::Sealed {
+    fn name(&self) -> &str;
+}
+
+pub trait Cache
+where
+    Self: private::Sealed,
+{
+    fn clear(&mut self);
+}
+
+pub trait Plugin: std::fmt::Debug {
+    fn run(&self);
+}
+
+#[doc(hidden)]
+pub trait Hidden {}
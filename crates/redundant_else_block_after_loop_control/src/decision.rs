@@ -0,0 +1,91 @@
+//! Pure decision logic for `redundant_else_block_after_loop_control`.
+//!
+//! The driver finds each `if` with a plain `else` block and notes how its
+//! `if` block ends and the source text between the two blocks.
+//! [`RedundantElseBlockAfterLoopControlDecision`] reports the `else` when the
+//! block ends in loop control and [`else_keyword`] finds the keyword, using
+//! the control keyword as the finding's reason.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::else_removal::{ExitKind, else_keyword};
+
+use crate::driver::LINT_NAME;
+
+/// The facts about one `if`/`else` that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IfElseProjection {
+    /// The keyword of the expression ending the `if` block, such as
+    /// `continue`, or the kind of any other final expression.
+    pub then_ends_with: String,
+    /// Whether the `else` branch is a block rather than another `if`.
+    pub else_is_block: bool,
+    /// The source text between the `if` block and the `else` body.
+    pub between: String,
+}
+
+/// Reports `else` blocks after an `if` block that ends in `continue` or
+/// `break`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let branch = IfElseProjection {
+///     then_ends_with: "continue".to_owned(),
+///     else_is_block: true,
+///     between: " else ".to_owned(),
+/// };
+/// let decision = RedundantElseBlockAfterLoopControlDecision;
+/// assert!(decision.decide(&branch).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RedundantElseBlockAfterLoopControlDecision;
+
+impl LintDecision for RedundantElseBlockAfterLoopControlDecision {
+    type Projection = IfElseProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, branch: &IfElseProjection) -> Option<Finding> {
+        if !branch.else_is_block {
+            return None;
+        }
+        let control = match branch.then_ends_with.as_str() {
+            "continue" => ExitKind::Continue,
+            "break" => ExitKind::Break,
+            _ => return None,
+        };
+        else_keyword(&branch.between)?;
+        Some(Finding::new(LINT_NAME, control.keyword()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<IfElseProjection>> {
+        let branch = |then_ends_with: &str, else_is_block| IfElseProjection {
+            then_ends_with: then_ends_with.to_owned(),
+            else_is_block,
+            between: " else ".to_owned(),
+        };
+        vec![
+            DecisionFixture::flagged("after continue", branch("continue", true), "continue"),
+            DecisionFixture::flagged("after break", branch("break", true), "break"),
+            DecisionFixture::clean("after return", branch("return", true)),
+            DecisionFixture::clean("else if chain", branch("continue", false)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&RedundantElseBlockAfterLoopControlDecision),
+            Ok(4)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Embedded documentation for the `redundant_else_block_after_loop_control`
+//! lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::LintDocs;
+
+/// Summary, rationale, and examples for
+/// `redundant_else_block_after_loop_control`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "redundant_else_block_after_loop_control",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `else` blocks following an `if` branch that always ends in `continue` or `break`, and suggests removing the `else`.",
+    rationale: "When the `if` branch always moves on to the next iteration or leaves the loop, the `else` body already runs exactly when the branch is not taken, so the `else` only adds nesting. Writing the `if` as a guard clause keeps the rest of the loop body at one indentation level.",
+    bad_example: "for line in lines {\n    if line.is_empty() {\n        continue;\n    } else {\n        process(line);\n    }\n}",
+    good_example: "for line in lines {\n    if line.is_empty() {\n        continue;\n    }\n    process(line);\n}",
+    config: &[],
+};
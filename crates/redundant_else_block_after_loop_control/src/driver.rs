@@ -0,0 +1,219 @@
+//! Lint pass reporting `else` blocks after branches that end in loop control.
+//!
+//! Each `if` whose block ends in `continue` or `break` and is followed by a
+//! plain `else` block is found by [`whitaker::hir::ElseAfterExit`], which
+//! also builds the suggestion that removes the `else` and dedents its body.
+//! The `else` keyword is reported. `no_else_after_return` reports the same
+//! blocks alongside those ending in `return`; this lint gives loop control
+//! its own name and level.
+
+use std::borrow::Cow;
+
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use whitaker::SharedConfig;
+use whitaker::hir::{ElseAfterExit, FindingLimit};
+use whitaker_common::CrateScopedState;
+use whitaker_common::else_removal::ExitKind;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+pub(crate) const LINT_NAME: &str = "redundant_else_block_after_loop_control";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+dylint_linting::impl_late_lint! {
+    pub REDUNDANT_ELSE_BLOCK_AFTER_LOOP_CONTROL,
+    Warn,
+    "`else` blocks after an `if` branch ending in `continue` or `break` are redundant",
+    RedundantElseBlockAfterLoopControl::default()
+}
+
+/// Lint pass that finds `else` blocks following `continue` or `break`.
+#[derive(Default)]
+pub struct RedundantElseBlockAfterLoopControl {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Finding limit and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for RedundantElseBlockAfterLoopControl {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            findings: FindingLimit::new(REDUNDANT_ELSE_BLOCK_AFTER_LOOP_CONTROL, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let Some(finding) = ElseAfterExit::find(cx, expr, ExitKind::is_loop_control) else {
+            return;
+        };
+        let state = &*self.state;
+        if state.findings.admit(cx, expr.hir_id, finding.else_span()) {
+            emit_diagnostic(cx, &finding, &state.localizer);
+        }
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, finding: &ElseAfterExit<'_>, localizer: &Localizer) {
+    let message = ElseAfterLoopControlMessage {
+        control: finding.exit(),
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let suggestion = message.suggestion(localizer);
+    let rewrite = finding.rewrite(cx);
+
+    cx.emit_span_lint(
+        REDUNDANT_ELSE_BLOCK_AFTER_LOOP_CONTROL,
+        finding.else_span(),
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+            if let Some(rewrite) = rewrite {
+                lint.span_suggestion(
+                    rewrite.span,
+                    suggestion,
+                    rewrite.replacement,
+                    rewrite.applicability,
+                );
+            }
+        }),
+    );
+}
+
+/// The values the diagnostic for an `else` after loop control interpolates.
+#[derive(Clone, Copy, Debug)]
+struct ElseAfterLoopControlMessage {
+    control: ExitKind,
+}
+
+impl ElseAfterLoopControlMessage {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("control"),
+            FluentValue::from(self.control.keyword()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let control = self.control.keyword();
+        DiagnosticMessageSet::new(
+            format!("Redundant `else` after an `if` block that ends with `{control}`."),
+            format!(
+                "The `if` block always ends the iteration with `{control}`, so the `else` body \
+                 already runs only when the condition is false and the `else` just adds nesting."
+            ),
+            String::from(
+                "Remove the `else` and dedent its body so the `if` reads as a guard clause and \
+                 the rest of the loop body stays at one indentation level.",
+            ),
+        )
+    }
+
+    fn fallback_suggestion(&self) -> String {
+        String::from("remove the `else` and dedent its body")
+    }
+
+    /// Resolves the label of the suggested rewrite. Fluent may wrap
+    /// arguments in directional isolates, which are stripped to keep
+    /// suggestion text stable.
+    fn suggestion(&self, localizer: &Localizer) -> String {
+        localizer
+            .attribute_with_args(LINT_NAME, "suggestion", &self.args())
+            .unwrap_or_else(|_| self.fallback_suggestion())
+            .chars()
+            .filter(|ch| !matches!(ch, '\u{2068}' | '\u{2069}' | '\u{FFFD}'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn resolve(message: &ElseAfterLoopControlMessage, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    #[case(ExitKind::Continue)]
+    #[case(ExitKind::Break)]
+    fn english_messages_match_the_fallback(#[case] control: ExitKind) {
+        let message = ElseAfterLoopControlMessage { control };
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+        assert_eq!(
+            message.suggestion(&Localizer::new(Some("en-GB"))),
+            message.fallback_suggestion()
+        );
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_loop_control() {
+        let messages = ElseAfterLoopControlMessage {
+            control: ExitKind::Continue,
+        }
+        .fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Redundant `else` after an `if` block that ends with `continue`."
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let message = ElseAfterLoopControlMessage {
+            control: ExitKind::Break,
+        };
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`break`"));
+        assert!(messages.primary().contains("`else`"));
+        assert_ne!(
+            message.suggestion(&Localizer::new(Some(locale))),
+            message.fallback_suggestion()
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Lint crate flagging `else` blocks that follow a `continue` or `break`.
+//!
+//! When an `if` branch inside a loop always ends in `continue` or `break`,
+//! the `else` that follows it is redundant: its body already runs exactly
+//! when the branch is not taken. The lint reports these `else` blocks and
+//! suggests removing the `else` and dedenting its body, so the loop body
+//! reads as a guard clause followed by the main path. The same shape ending
+//! in `return` is left to `no_else_after_return`. The lint is experimental and
+//! ships behind the `experimental-redundant-else-block-after-loop-control`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{IfElseProjection, RedundantElseBlockAfterLoopControlDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn redundant_else_block_after_loop_control_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,44 @@
+//! `else` blocks after `if` blocks ending in `continue` and `break` are
+//! reported.
+
+fn total(values: &[i32]) -> i32 {
+    let mut sum = 0;
+    for value in values {
+        if *value < 0 {
+            continue;
+        } else {
+            sum += value;
+        }
+        sum += 1;
+    }
+    sum
+}
+
+fn first_even(values: &[u32]) -> Option<u32> {
+    let mut found = None;
+    for value in values {
+        if value % 2 == 0 {
+            found = Some(*value);
+            break;
+        } else {
+            let _ = value;
+        }
+    }
+    found
+}
+
+fn countdown(mut remaining: u32) -> u32 {
+    loop {
+        if remaining == 0 {
+            break remaining;
+        } else {
+            remaining -= 1;
+        }
+    }
+}
+
+fn main() {
+    let _ = total(&[1, -2]);
+    let _ = first_even(&[1, 2]);
+    let _ = countdown(3);
+}
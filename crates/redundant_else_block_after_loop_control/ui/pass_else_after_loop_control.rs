@@ -0,0 +1,63 @@
+//! `else` blocks after `return`, `else if` chains, `if` expressions whose
+//! value is used, branches that do not always leave the iteration, and
+//! `while` loops are accepted.
+
+fn parse(text: &str) -> Option<u32> {
+    if text.is_empty() {
+        return None;
+    } else {
+        text.parse().ok()
+    }
+}
+
+fn positives(values: &[i32]) -> u32 {
+    let mut count = 0;
+    for value in values {
+        if *value < 0 {
+            continue;
+        } else if *value > 0 {
+            count += 1;
+        } else {
+            break;
+        }
+    }
+    count
+}
+
+fn scaled(values: &[u32]) -> u32 {
+    let mut total = 0;
+    for value in values {
+        let factor = if *value > 10 { continue } else { 2 };
+        total += value * factor;
+    }
+    total
+}
+
+fn evens(values: &[u32]) -> u32 {
+    let mut count = 0;
+    for value in values {
+        if value % 2 == 0 {
+            count += 1;
+        } else {
+            continue;
+        }
+    }
+    count
+}
+
+fn drain(values: &mut Vec<u32>) -> u32 {
+    let mut last = 0;
+    while let Some(value) = values.pop() {
+        last = value;
+        continue;
+    }
+    last
+}
+
+fn main() {
+    let _ = parse("7");
+    let _ = positives(&[1, -2, 0]);
+    let _ = scaled(&[4, 12]);
+    let _ = evens(&[1, 2]);
+    let _ = drain(&mut vec![1, 0]);
+}
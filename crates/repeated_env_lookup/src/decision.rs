@@ -0,0 +1,113 @@
+//! Pure decision logic for `repeated_env_lookup`.
+//!
+//! The driver counts lookups of each literal key through `std::env::var` and
+//! `std::env::var_os` per function or `impl` block.
+//! [`RepeatedEnvLookupDecision`] counts the keys of one scope the same way
+//! and reports the first lookup [`RepeatPolicy::reports`], naming its key as
+//! the finding's subject.
+
+use std::collections::HashMap;
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{MIN_REPEAT, RepeatPolicy};
+
+/// Reason key for repeated lookups of one key.
+const REPEATED_LOOKUP: &str = "repeated_lookup";
+
+/// The facts about one function or `impl` block that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScopeLookupsProjection {
+    /// The literal key of each lookup in the scope, in source order.
+    pub keys: Vec<String>,
+}
+
+/// Reports the `min_lookups`th lookup of a key in one scope.
+///
+/// # Examples
+///
+/// ```ignore
+/// let scope = ScopeLookupsProjection {
+///     keys: vec!["HOME".to_owned(), "HOME".to_owned()],
+/// };
+/// assert!(RepeatedEnvLookupDecision::default().decide(&scope).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RepeatedEnvLookupDecision {
+    policy: RepeatPolicy,
+    min_lookups: usize,
+}
+
+impl RepeatedEnvLookupDecision {
+    /// Creates a decision reporting from the `min_lookups`th lookup of a key.
+    #[must_use]
+    pub fn new(min_lookups: usize) -> Self {
+        Self {
+            policy: RepeatPolicy::new(min_lookups),
+            min_lookups: min_lookups.max(MIN_REPEAT),
+        }
+    }
+}
+
+impl Default for RepeatedEnvLookupDecision {
+    fn default() -> Self {
+        Self::new(MIN_REPEAT)
+    }
+}
+
+impl LintDecision for RepeatedEnvLookupDecision {
+    type Projection = ScopeLookupsProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, scope: &ScopeLookupsProjection) -> Option<Finding> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        scope
+            .keys
+            .iter()
+            .find(|key| {
+                let count = counts.entry(key.as_str()).or_default();
+                *count += 1;
+                self.policy.reports(*count)
+            })
+            .map(|key| Finding::new(LINT_NAME, REPEATED_LOOKUP).with_subject(key.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ScopeLookupsProjection>> {
+        let scope = |key: &str, count: usize, other: &str| ScopeLookupsProjection {
+            keys: std::iter::repeat_n(key.to_owned(), count)
+                .chain(std::iter::once(other.to_owned()))
+                .collect(),
+        };
+        vec![
+            DecisionFixture::flagged(
+                "repeated key",
+                scope("HOME", self.min_lookups, "PATH"),
+                REPEATED_LOOKUP,
+            ),
+            DecisionFixture::clean(
+                "below the threshold",
+                scope("HOME", self.min_lookups - 1, "PATH"),
+            ),
+            DecisionFixture::clean("distinct keys", scope("HOME", 1, "PATH")),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_threshold(RepeatedEnvLookupDecision::default())]
+    #[case::third_lookup(RepeatedEnvLookupDecision::new(3))]
+    #[case::below_the_floor(RepeatedEnvLookupDecision::new(1))]
+    fn decision_conforms(#[case] decision: RepeatedEnvLookupDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
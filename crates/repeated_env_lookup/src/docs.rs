@@ -0,0 +1,21 @@
+//! Embedded documentation for the `repeated_env_lookup` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `repeated_env_lookup`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "repeated_env_lookup",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `std::env::var` and `std::env::var_os` calls that look up a literal key already read in the same function or `impl` block.",
+    rationale: "Each lookup repeats the parsing and error handling of one setting, and separate reads can disagree if the environment changes between them. Reading the variable once into a local or a configuration struct keeps the setting in one place.",
+    bad_example: "let pool = Pool::connect(&env::var(\"DB_URL\")?)?;\ninfo!(\"connected to {}\", env::var(\"DB_URL\")?);",
+    good_example: "let db_url = env::var(\"DB_URL\")?;\nlet pool = Pool::connect(&db_url)?;\ninfo!(\"connected to {db_url}\");",
+    config: &[ConfigKeyDocs {
+        key: "min_lookups",
+        default: "2",
+        description: "Number of lookups of one key in a scope at which the lint reports; values below 2 count as 2.",
+    }],
+};
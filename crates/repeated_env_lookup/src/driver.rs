@@ -0,0 +1,339 @@
+//! Lint pass reporting repeated lookups of the same environment variable.
+//!
+//! The pass visits calls to `std::env::var` and `std::env::var_os` whose key
+//! is a string literal. Each lookup is counted against its scope: the `impl`
+//! block when the enclosing function is a method, or the function itself
+//! otherwise. Closures count towards the function that contains them. Once a
+//! key reaches the configured number of lookups in one scope, the lookup is
+//! reported with a note pointing at the first one. Calls written by macros are
+//! skipped.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use log::debug;
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_hir::def::DefKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{Span, Symbol};
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{MIN_REPEAT, RepeatPolicy};
+
+pub(crate) const LINT_NAME: &str = "repeated_env_lookup";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const LOOKUP_FUNCTIONS: &[&str] = &["std::env::var", "std::env::var_os"];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    min_lookups: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_lookups: MIN_REPEAT,
+        }
+    }
+}
+
+impl Config {
+    fn policy(&self) -> RepeatPolicy {
+        RepeatPolicy::new(self.min_lookups)
+    }
+}
+
+/// Lint pass that counts environment lookups per function and `impl` block.
+#[derive(Default)]
+pub struct RepeatedEnvLookup {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Repeat settings, lookups seen so far, finding limit, and localization for
+/// the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    lookups: HashMap<(LocalDefId, Symbol), Lookups>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// The lookups of one key in one scope.
+#[derive(Clone, Copy, Debug)]
+struct Lookups {
+    first: Span,
+    count: usize,
+}
+
+dylint_linting::impl_late_lint! {
+    pub REPEATED_ENV_LOOKUP,
+    Warn,
+    "an environment variable should be read once rather than looked up repeatedly in one scope",
+    RepeatedEnvLookup::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for RepeatedEnvLookup {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            lookups: HashMap::new(),
+            findings: FindingLimit::new(REPEATED_ENV_LOOKUP, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(key) = env_lookup_key(cx, expr) else {
+            return;
+        };
+        let Some((scope, kind)) = lookup_scope(cx, expr.hir_id) else {
+            return;
+        };
+
+        let lookups = self
+            .state
+            .lookups
+            .entry((scope, key))
+            .and_modify(|lookups| lookups.count += 1)
+            .or_insert(Lookups {
+                first: expr.span,
+                count: 1,
+            });
+        let Lookups { first, count } = *lookups;
+        let policy = self
+            .state
+            .findings
+            .config_at(cx, expr.span, &self.state.config)
+            .policy();
+        if !policy.reports(count) {
+            return;
+        }
+
+        debug!(target: LINT_NAME, "`{key}` looked up {count} times in one {kind:?}");
+        if self.state.findings.admit(cx, expr.hir_id, expr.span) {
+            let message = RepeatMessage {
+                key: key.as_str(),
+                scope: kind,
+                first,
+            };
+            emit_diagnostic(cx, expr.span, &message, &self.state.localizer);
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the literal key when `expr` calls one of [`LOOKUP_FUNCTIONS`].
+fn env_lookup_key(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<Symbol> {
+    let hir::ExprKind::Call(callee, [key]) = expr.kind else {
+        return None;
+    };
+    let hir::ExprKind::Path(ref qpath) = callee.kind else {
+        return None;
+    };
+    let def_id = cx.qpath_res(qpath, callee.hir_id).opt_def_id()?;
+    if !LOOKUP_FUNCTIONS.contains(&cx.tcx.def_path_str(def_id).as_str()) {
+        return None;
+    }
+    let hir::ExprKind::Lit(lit) = key.kind else {
+        return None;
+    };
+    match lit.node {
+        LitKind::Str(key, _) => Some(key),
+        _ => None,
+    }
+}
+
+/// Where repeated lookups are counted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ScopeKind {
+    Function,
+    Impl,
+}
+
+impl ScopeKind {
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Impl => "impl",
+        }
+    }
+}
+
+/// Returns the scope that lookups at `hir_id` are counted against.
+fn lookup_scope(cx: &LateContext<'_>, hir_id: hir::HirId) -> Option<(LocalDefId, ScopeKind)> {
+    let owner = cx.tcx.hir_enclosing_body_owner(hir_id);
+    let function = cx.tcx.typeck_root_def_id(owner.to_def_id()).as_local()?;
+    let parent = cx.tcx.local_parent(function);
+    if matches!(cx.tcx.def_kind(parent), DefKind::Impl { .. }) {
+        Some((parent, ScopeKind::Impl))
+    } else {
+        Some((function, ScopeKind::Function))
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &RepeatMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        REPEATED_ENV_LOOKUP,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(message.first, note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a repeated lookup interpolates, and where
+/// the key is first looked up.
+#[derive(Clone, Copy, Debug)]
+struct RepeatMessage<'a> {
+    key: &'a str,
+    scope: ScopeKind,
+    first: Span,
+}
+
+impl RepeatMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(Cow::Borrowed("key"), FluentValue::from(self.key.to_owned()));
+        args.insert(Cow::Borrowed("scope"), FluentValue::from(self.scope.key()));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self { key, scope, .. } = *self;
+        let scope = match scope {
+            ScopeKind::Function => "function",
+            ScopeKind::Impl => "`impl` block",
+        };
+        DiagnosticMessageSet::new(
+            format!("Environment variable `{key}` is looked up again in the same {scope}."),
+            format!("`{key}` is first looked up here."),
+            format!(
+                "Read `{key}` once into a local or a configuration struct and reuse the value."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use rustc_span::DUMMY_SP;
+
+    const MESSAGE: RepeatMessage<'static> = RepeatMessage {
+        key: "DATABASE_URL",
+        scope: ScopeKind::Function,
+        first: DUMMY_SP,
+    };
+
+    fn resolve(message: &RepeatMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_reports_the_second_lookup() {
+        assert_eq!(Config::default().policy(), RepeatPolicy::new(2));
+    }
+
+    #[rstest]
+    #[case::function(ScopeKind::Function, "same function.")]
+    #[case::impl_block(ScopeKind::Impl, "same `impl` block.")]
+    fn fallback_messages_name_the_key_and_scope(#[case] scope: ScopeKind, #[case] tail: &str) {
+        let messages = RepeatMessage { scope, ..MESSAGE }.fallback_messages();
+
+        assert!(
+            messages
+                .primary()
+                .starts_with("Environment variable `DATABASE_URL`")
+        );
+        assert!(messages.primary().ends_with(tail));
+        assert!(messages.help().contains("`DATABASE_URL`"));
+    }
+
+    #[rstest]
+    #[case::function(ScopeKind::Function)]
+    #[case::impl_block(ScopeKind::Impl)]
+    fn english_messages_match_the_fallback(#[case] scope: ScopeKind) {
+        let message = RepeatMessage { scope, ..MESSAGE };
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`DATABASE_URL`"));
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
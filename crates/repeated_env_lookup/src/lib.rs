@@ -0,0 +1,41 @@
+//! Lint crate flagging repeated lookups of the same environment variable.
+//!
+//! Reading `std::env::var("KEY")` in several places within one function, or
+//! across the methods of one `impl` block, scatters a single piece of
+//! configuration through the code. Each lookup repeats the parsing and error
+//! handling, and the reads can disagree if the environment changes between
+//! them. The lint reports every lookup of a literal key after the first in the
+//! same scope and points back at the first one. The lint is experimental and
+//! ships behind the `experimental-repeated-env-lookup` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{RepeatedEnvLookupDecision, ScopeLookupsProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn repeated_env_lookup_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
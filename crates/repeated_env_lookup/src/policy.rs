@@ -0,0 +1,78 @@
+//! Threshold for repeated environment lookups.
+//!
+//! The driver counts lookups of each literal key per function or `impl`
+//! block. [`RepeatPolicy::reports`] decides which of those lookups are
+//! reported, keeping the threshold free of compiler types so it can be
+//! tested directly.
+
+/// The smallest number of lookups that counts as a repeat.
+pub(crate) const MIN_REPEAT: usize = 2;
+
+/// The configured number of lookups of one key at which the lint reports.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct RepeatPolicy {
+    min_lookups: usize,
+}
+
+impl RepeatPolicy {
+    /// Creates a policy reporting from the `min_lookups`th lookup of a key
+    /// onwards. Values below [`MIN_REPEAT`] are raised to it, since a single
+    /// lookup is never a repeat.
+    #[must_use]
+    pub(crate) fn new(min_lookups: usize) -> Self {
+        Self {
+            min_lookups: min_lookups.max(MIN_REPEAT),
+        }
+    }
+
+    /// Returns `true` when the `nth` lookup of a key in one scope, counting
+    /// from one, should be reported.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = RepeatPolicy::new(2);
+    /// assert!(!policy.reports(1));
+    /// assert!(policy.reports(2));
+    /// ```
+    #[must_use]
+    pub(crate) const fn reports(self, nth: usize) -> bool {
+        nth >= self.min_lookups
+    }
+}
+
+impl Default for RepeatPolicy {
+    fn default() -> Self {
+        Self::new(MIN_REPEAT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::first(2, 1, false)]
+    #[case::second(2, 2, true)]
+    #[case::third(2, 3, true)]
+    #[case::below_higher_limit(3, 2, false)]
+    #[case::at_higher_limit(3, 3, true)]
+    fn compares_lookup_counts_with_the_limit(
+        #[case] min_lookups: usize,
+        #[case] nth: usize,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(RepeatPolicy::new(min_lookups).reports(nth), expected);
+    }
+
+    #[rstest]
+    #[case::zero(0)]
+    #[case::one(1)]
+    fn never_reports_the_first_lookup(#[case] min_lookups: usize) {
+        let policy = RepeatPolicy::new(min_lookups);
+
+        assert!(!policy.reports(1));
+        assert!(policy.reports(2));
+    }
+}
@@ -0,0 +1,64 @@
+//! Behaviour-driven coverage for the repeated lookup policy.
+
+use crate::policy::RepeatPolicy;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+#[derive(Default)]
+struct LookupWorld {
+    min_lookups: Cell<usize>,
+    nth: Cell<usize>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> LookupWorld {
+    LookupWorld::default()
+}
+
+#[given("a limit of {count} lookups")]
+fn given_limit(world: &LookupWorld, count: usize) {
+    world.min_lookups.set(count);
+}
+
+#[given("lookup number {nth} of the same key")]
+fn given_lookup(world: &LookupWorld, nth: usize) {
+    world.nth.set(nth);
+}
+
+#[when("the lookup is checked")]
+fn when_checked(world: &LookupWorld) {
+    let policy = RepeatPolicy::new(world.min_lookups.get());
+    world.reported.set(Some(policy.reports(world.nth.get())));
+}
+
+#[then("the lookup is reported")]
+fn then_reported(world: &LookupWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("the lookup is accepted")]
+fn then_accepted(world: &LookupWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/repeated_env_lookup.feature", index = 0)]
+fn scenario_first_lookup(world: LookupWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/repeated_env_lookup.feature", index = 1)]
+fn scenario_second_lookup(world: LookupWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/repeated_env_lookup.feature", index = 2)]
+fn scenario_below_raised_limit(world: LookupWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/repeated_env_lookup.feature", index = 3)]
+fn scenario_limit_below_two(world: LookupWorld) {
+    let _ = world;
+}
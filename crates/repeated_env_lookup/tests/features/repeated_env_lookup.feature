@@ -0,0 +1,28 @@
+Feature: Repeated environment lookups
+  A lookup of an environment variable is reported once the same literal key
+  has been looked up the configured number of times in one function or
+  `impl` block.
+
+  Scenario: The first lookup is accepted
+    Given a limit of 2 lookups
+    And lookup number 1 of the same key
+    When the lookup is checked
+    Then the lookup is accepted
+
+  Scenario: The second lookup is reported
+    Given a limit of 2 lookups
+    And lookup number 2 of the same key
+    When the lookup is checked
+    Then the lookup is reported
+
+  Scenario: A raised limit accepts the second lookup
+    Given a limit of 3 lookups
+    And lookup number 2 of the same key
+    When the lookup is checked
+    Then the lookup is accepted
+
+  Scenario: A limit below two still accepts the first lookup
+    Given a limit of 1 lookups
+    And lookup number 1 of the same key
+    When the lookup is checked
+    Then the lookup is accepted
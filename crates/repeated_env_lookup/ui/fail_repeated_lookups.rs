@@ -0,0 +1,31 @@
+//! UI test: repeated lookups of one key in a function or `impl` block.
+#![warn(repeated_env_lookup)]
+
+use std::env;
+use std::ffi::OsString;
+
+fn database_url() -> String {
+    let url = env::var("DATABASE_URL").unwrap_or_default();
+    if url.is_empty() {
+        return String::from("postgres://localhost");
+    }
+    env::var("DATABASE_URL").unwrap_or(url)
+}
+
+struct Settings;
+
+impl Settings {
+    fn host(&self) -> Option<OsString> {
+        env::var_os("APP_HOST")
+    }
+
+    fn has_host(&self) -> bool {
+        env::var_os("APP_HOST").is_some()
+    }
+}
+
+fn main() {
+    let _ = database_url();
+    let _ = Settings.host();
+    let _ = Settings.has_host();
+}
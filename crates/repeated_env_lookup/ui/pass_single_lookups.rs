@@ -0,0 +1,32 @@
+//! UI test: keys read once per scope, or through a variable, are accepted.
+#![warn(repeated_env_lookup)]
+
+use std::env;
+
+fn database_url() -> Option<String> {
+    env::var("DATABASE_URL").ok()
+}
+
+fn log_level() -> Option<String> {
+    env::var("DATABASE_URL").ok()?;
+    env::var("LOG_LEVEL").ok()
+}
+
+fn lookup_each(keys: &[&str]) -> usize {
+    keys.iter().filter(|key| env::var(key).is_ok()).count()
+}
+
+struct Settings;
+
+impl Settings {
+    fn host(&self) -> Option<String> {
+        env::var("APP_HOST").ok()
+    }
+}
+
+fn main() {
+    let _ = database_url();
+    let _ = log_level();
+    let _ = lookup_each(&["HOME", "HOME"]);
+    let _ = Settings.host();
+}
@@ -201,12 +201,6 @@ impl CallSiteCollector {
     pub(crate) fn record_count(&self) -> usize {
         self.by_callee.values().map(Vec::len).sum()
     }
-
-    /// Removes all stored evidence from the collector.
-    pub(crate) fn clear(&mut self) {
-        self.by_callee.clear();
-        self.seen.clear();
-    }
 }
 
 /// Lowers a HIR argument expression to the pure fingerprint model.
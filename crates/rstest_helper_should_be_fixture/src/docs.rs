@@ -0,0 +1,51 @@
+//! Embedded documentation for the `rstest_helper_should_be_fixture` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `rstest_helper_should_be_fixture`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "rstest_helper_should_be_fixture",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Recommends turning helpers called repeatedly across `#[rstest]` tests into fixtures.",
+    rationale: "Fixtures make shared setup explicit and injectable, instead of repeating the same helper call in every test.",
+    bad_example: concat!(
+        "#[rstest]\n",
+        "fn reads(#[case] key: &str) {\n",
+        "    let store = make_store();\n",
+        "}",
+    ),
+    good_example: concat!(
+        "#[rstest]\n",
+        "fn reads(store: Store, #[case] key: &str) {}",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "min_calls",
+            default: "2",
+            description: "Minimum number of calls before a helper is reported.",
+        },
+        ConfigKeyDocs {
+            key: "min_distinct_tests",
+            default: "2",
+            description: "Minimum number of distinct tests calling the helper.",
+        },
+        ConfigKeyDocs {
+            key: "require_identical_fixture_arg_names",
+            default: "false",
+            description: "Require candidate fixture arguments to use the same names.",
+        },
+        ConfigKeyDocs {
+            key: "provider_param_attributes",
+            default: "[\"case\", \"values\", \"files\", \"future\", \"context\"]",
+            description: "Parameter attributes treated as data providers.",
+        },
+        ConfigKeyDocs {
+            key: "use_source_callee_fallback",
+            default: "false",
+            description: "Recover source call sites for macro-expanded callees.",
+        },
+    ],
+};
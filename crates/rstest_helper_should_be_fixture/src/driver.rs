@@ -21,6 +21,7 @@ use serde::Deserialize;
 use std::collections::HashSet;
 use std::io::Write;
 use whitaker::SharedConfig;
+use whitaker_common::CrateScopedState;
 use whitaker_common::attributes::AttributePath;
 use whitaker_common::i18n::{Localizer, get_localizer_for_lint};
 use whitaker_common::rstest::{RstestDetectionOptions, is_rstest_test_with};
@@ -105,15 +106,27 @@ impl Config {
 }
 
 /// Lint pass bootstrap for repeated `rstest` helper extraction.
+#[derive(Default)]
 pub struct RstestHelperShouldBeFixture {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configuration, collected evidence, and localization for the crate being
+/// checked.
+struct CrateState {
+    #[cfg_attr(
+        not(test),
+        expect(dead_code, reason = "thresholds apply once helpers are reported")
+    )]
     config: Config,
     detection_options: RstestDetectionOptions,
     collector: CallSiteCollector,
     rstest_collection_roots: HashSet<hir::HirId>,
+    #[expect(dead_code, reason = "diagnostics are not emitted yet")]
     localizer: Localizer,
 }
 
-impl Default for RstestHelperShouldBeFixture {
+impl Default for CrateState {
     fn default() -> Self {
         let config = Config::default();
         let detection_options = config.detection_options();
@@ -132,6 +145,7 @@ impl RstestHelperShouldBeFixture {
         &mut self,
         config: ConfigLoadResult,
         shared_config: SharedConfig,
+        rstest_collection_roots: HashSet<hir::HirId>,
     ) {
         let config = match config {
             Ok(config) => config,
@@ -144,10 +158,15 @@ impl RstestHelperShouldBeFixture {
             }
         };
 
-        self.apply_crate_configuration(config, shared_config);
+        self.apply_crate_configuration(config, shared_config, rstest_collection_roots);
     }
 
-    fn apply_crate_configuration(&mut self, config: Config, shared_config: SharedConfig) {
+    fn apply_crate_configuration(
+        &mut self,
+        config: Config,
+        shared_config: SharedConfig,
+        rstest_collection_roots: HashSet<hir::HirId>,
+    ) {
         debug!(
             target: LINT_NAME,
             "applying `{LINT_NAME}` configuration: min_calls={}, min_distinct_tests={}, \
@@ -160,11 +179,13 @@ impl RstestHelperShouldBeFixture {
             config.use_source_callee_fallback,
             shared_config.locale(),
         );
-        self.config = config;
-        self.detection_options = self.config.detection_options();
-        self.collector.clear();
-        self.rstest_collection_roots.clear();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
+        self.state.reset(CrateState {
+            detection_options: config.detection_options(),
+            config,
+            collector: CallSiteCollector::default(),
+            rstest_collection_roots,
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
     }
 
     fn collect_call_sites<'tcx>(
@@ -180,8 +201,8 @@ impl RstestHelperShouldBeFixture {
             .iter()
             .filter_map(attribute_from_hir)
             .collect::<Vec<_>>();
-        if !is_rstest_test_with(&attrs, None, &self.detection_options)
-            && !self.rstest_collection_roots.contains(&hir_id)
+        if !is_rstest_test_with(&attrs, None, &self.state.detection_options)
+            && !self.state.rstest_collection_roots.contains(&hir_id)
         {
             debug!(
                 target: LINT_NAME,
@@ -191,10 +212,10 @@ impl RstestHelperShouldBeFixture {
             return;
         }
 
-        let fixture_local_ids = fixture_local_ids(cx, body, &self.detection_options);
+        let fixture_local_ids = fixture_local_ids(cx, body, &self.state.detection_options);
         let mut visitor = CallSiteVisitor::new(
             cx,
-            &mut self.collector,
+            &mut self.state.collector,
             def_id.to_def_id(),
             &fixture_local_ids,
         );
@@ -204,8 +225,11 @@ impl RstestHelperShouldBeFixture {
 
 impl<'tcx> LateLintPass<'tcx> for RstestHelperShouldBeFixture {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
-        self.apply_loaded_crate_configuration(load_configuration(), load_shared_config());
-        self.rstest_collection_roots = whitaker::hir::collect_rstest_companion_test_functions(cx);
+        self.apply_loaded_crate_configuration(
+            load_configuration(),
+            load_shared_config(),
+            whitaker::hir::collect_rstest_companion_test_functions(cx),
+        );
     }
 
     fn check_fn(
@@ -223,8 +247,8 @@ impl<'tcx> LateLintPass<'tcx> for RstestHelperShouldBeFixture {
     fn check_crate_post(&mut self, _cx: &LateContext<'tcx>) {
         // Order every callee bucket once, after the final `check_fn` record,
         // before any read of the collected evidence.
-        self.collector.finalize();
-        for (callee, records) in self.collector.iter() {
+        self.state.collector.finalize();
+        for (callee, records) in self.state.collector.iter() {
             for record in records {
                 debug!(
                     target: LINT_NAME,
@@ -248,8 +272,8 @@ impl<'tcx> LateLintPass<'tcx> for RstestHelperShouldBeFixture {
         debug!(
             target: LINT_NAME,
             "rstest helper call-site collection complete: {} callees, {} records",
-            self.collector.callee_count(),
-            self.collector.record_count(),
+            self.state.collector.callee_count(),
+            self.state.collector.record_count(),
         );
     }
 }
@@ -258,10 +282,10 @@ impl RstestHelperShouldBeFixture {
     fn collection_summary(&self) -> String {
         let mut summary = format!(
             "callee_count={}\nrecord_count={}\n",
-            self.collector.callee_count(),
-            self.collector.record_count(),
+            self.state.collector.callee_count(),
+            self.state.collector.record_count(),
         );
-        for (callee, records) in self.collector.iter() {
+        for (callee, records) in self.state.collector.iter() {
             summary.push_str(&format!("callee={callee};records={}\n", records.len()));
             for record in records {
                 summary.push_str(&format!(
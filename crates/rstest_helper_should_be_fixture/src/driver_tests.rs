@@ -6,9 +6,14 @@
 
 use proptest::prelude::*;
 use rstest::rstest;
+use rustc_hir::ItemLocalId;
+use rustc_hir::def_id::{DefId, DefIndex};
+use rustc_span::{BytePos, FileName};
 use whitaker::SharedConfig;
+use whitaker_common::rstest::ArgFingerprint;
 
 use super::*;
+use crate::collector::{CallSiteLocation, CallSiteRecord};
 
 #[rstest]
 fn default_configuration_matches_design() {
@@ -105,9 +110,12 @@ fn detection_options_expand_plain_and_rstest_qualified_provider_paths() {
 fn lint_pass_default_derives_detection_options_from_config() {
     let pass = RstestHelperShouldBeFixture::default();
 
-    assert_eq!(pass.config, Config::default());
+    assert_eq!(pass.state.config, Config::default());
     assert_eq!(
-        pass.detection_options.provider_param_attributes().len(),
+        pass.state
+            .detection_options
+            .provider_param_attributes()
+            .len(),
         DEFAULT_PROVIDER_PARAM_ATTRIBUTES.len() * 2
     );
 }
@@ -154,11 +162,17 @@ fn applying_crate_configuration_initializes_pass_state() {
         ..Config::default()
     };
 
-    pass.apply_crate_configuration(config.clone(), SharedConfig::default());
+    pass.apply_crate_configuration(config.clone(), SharedConfig::default(), HashSet::new());
 
-    assert_eq!(pass.config, config.normalized());
-    assert!(pass.detection_options.use_expansion_trace_fallback());
-    assert_eq!(pass.detection_options.provider_param_attributes().len(), 2);
+    assert_eq!(pass.state.config, config.normalized());
+    assert!(pass.state.detection_options.use_expansion_trace_fallback());
+    assert_eq!(
+        pass.state
+            .detection_options
+            .provider_param_attributes()
+            .len(),
+        2
+    );
 }
 
 #[rstest]
@@ -175,13 +189,47 @@ fn check_crate_configuration_loads_and_normalizes_config() {
     pass.apply_loaded_crate_configuration(
         loaded_configuration::<String>(Ok(Some(config))),
         SharedConfig::default(),
+        HashSet::new(),
+    );
+
+    assert_eq!(pass.state.config.min_calls, 2);
+    assert_eq!(pass.state.config.min_distinct_tests, 2);
+    assert_eq!(pass.state.config.provider_param_attributes, ["case"]);
+    assert!(pass.state.detection_options.use_expansion_trace_fallback());
+    assert_eq!(
+        pass.state
+            .detection_options
+            .provider_param_attributes()
+            .len(),
+        2
+    );
+}
+
+#[rstest]
+fn crate_configuration_discards_the_previous_crates_evidence() {
+    let mut pass = RstestHelperShouldBeFixture::default();
+    pass.apply_crate_configuration(
+        Config::default(),
+        SharedConfig::default(),
+        HashSet::from([hir::CRATE_HIR_ID]),
+    );
+    let helper = DefId::local(DefIndex::from_u32(1));
+    let span = Span::with_root_ctxt(BytePos(10), BytePos(18));
+    pass.state.collector.record(
+        CallSiteRecord::new(helper, ArgFingerprint::default(), helper, span),
+        CallSiteLocation::new(
+            "crate::helper".to_string(),
+            FileName::Custom("src/lib.rs".to_string()),
+            span,
+            ItemLocalId::ZERO,
+        ),
     );
 
-    assert_eq!(pass.config.min_calls, 2);
-    assert_eq!(pass.config.min_distinct_tests, 2);
-    assert_eq!(pass.config.provider_param_attributes, ["case"]);
-    assert!(pass.detection_options.use_expansion_trace_fallback());
-    assert_eq!(pass.detection_options.provider_param_attributes().len(), 2);
+    pass.apply_crate_configuration(Config::default(), SharedConfig::default(), HashSet::new());
+
+    assert_eq!(pass.state.collector.record_count(), 0);
+    assert!(pass.state.rstest_collection_roots.is_empty());
+    assert_eq!(pass.state.crates(), 2);
 }
 
 proptest! {
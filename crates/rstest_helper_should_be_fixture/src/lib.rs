@@ -13,5 +13,17 @@ mod driver;
 #[cfg(feature = "dylint-driver")]
 mod visitor;
 
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn rstest_helper_should_be_fixture_disabled_stub() {}
+}
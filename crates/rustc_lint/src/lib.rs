@@ -17,6 +17,7 @@ pub use upstream::*;
 
 pub mod errors {
     //! Diagnostic-construction helpers from `rustc_errors` needed by lint
-    //! emission call sites (e.g. `errors::DiagDecorator`).
-    pub use rustc_errors::{Diag, DiagDecorator};
+    //! emission call sites (e.g. `errors::DiagDecorator`), and the
+    //! `Applicability` attached to suggestions.
+    pub use rustc_errors::{Applicability, Diag, DiagDecorator};
 }
@@ -0,0 +1,15 @@
+#![feature(rustc_private)]
+#![recursion_limit = "512"]
+
+//! Re-exports the nightly `rustc_trait_selection` crate for lint scaffolding.
+//!
+//! This crate provides access to the compiler's trait solver so lint crates
+//! can ask whether a type implements a trait, including through blanket and
+//! generic implementations, without declaring unstable upstream dependencies
+//! themselves.
+
+extern crate rustc_driver;
+
+extern crate rustc_trait_selection as upstream;
+
+pub use upstream::*;
@@ -0,0 +1,165 @@
+//! Pure decision logic for `spawned_task_missing_cancellation_handling`.
+//!
+//! The driver finds calls to the configured spawn functions outside test
+//! code and walks the spawned future for loops that await and for values of
+//! each type it reads.
+//! [`SpawnedTaskMissingCancellationHandlingDecision`] matches the same
+//! [`PathPatterns`], builds the [`TaskBody`], and reports the task when it
+//! [`needs_cancellation`](TaskBody::needs_cancellation), naming the spawn
+//! function as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{DEFAULT_SPAWN_FUNCTIONS, DEFAULT_TOKEN_TYPES, PathPatterns, TaskBody};
+
+/// Reason key for looping tasks that read no cancellation token.
+const UNWATCHED_TASK: &str = "unwatched_task";
+
+/// The facts about one spawn call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpawnProjection {
+    /// The called function's path, such as `tokio::task::spawn`.
+    pub callee: String,
+    /// Whether the future contains a `loop` whose body awaits.
+    pub loops_over_await: bool,
+    /// The paths of the types of values the future reads.
+    pub read_types: Vec<String>,
+}
+
+/// Reports spawned tasks that loop over an `.await` without reading a
+/// cancellation token.
+///
+/// # Examples
+///
+/// ```ignore
+/// let spawn = SpawnProjection {
+///     callee: "tokio::task::spawn".to_owned(),
+///     loops_over_await: true,
+///     read_types: Vec::new(),
+/// };
+/// let decision = SpawnedTaskMissingCancellationHandlingDecision::default();
+/// assert!(decision.decide(&spawn).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpawnedTaskMissingCancellationHandlingDecision {
+    spawn_functions: PathPatterns,
+    token_types: PathPatterns,
+}
+
+impl SpawnedTaskMissingCancellationHandlingDecision {
+    /// Creates a decision checking calls to `spawn_functions` and accepting
+    /// reads of `token_types` as cancellation handling.
+    #[must_use]
+    pub fn new(
+        spawn_functions: impl IntoIterator<Item = String>,
+        token_types: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            spawn_functions: PathPatterns::new(spawn_functions),
+            token_types: PathPatterns::new(token_types),
+        }
+    }
+}
+
+impl Default for SpawnedTaskMissingCancellationHandlingDecision {
+    fn default() -> Self {
+        let owned = |paths: &[&str]| {
+            paths
+                .iter()
+                .map(|&path| path.to_owned())
+                .collect::<Vec<_>>()
+        };
+        Self::new(owned(DEFAULT_SPAWN_FUNCTIONS), owned(DEFAULT_TOKEN_TYPES))
+    }
+}
+
+impl LintDecision for SpawnedTaskMissingCancellationHandlingDecision {
+    type Projection = SpawnProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, spawn: &SpawnProjection) -> Option<Finding> {
+        if !self.spawn_functions.matches(&spawn.callee) {
+            return None;
+        }
+        let task = TaskBody {
+            loops_over_await: spawn.loops_over_await,
+            reads_token: spawn
+                .read_types
+                .iter()
+                .any(|path| self.token_types.matches(path)),
+        };
+        task.needs_cancellation()
+            .then(|| Finding::new(LINT_NAME, UNWATCHED_TASK).with_subject(spawn.callee.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<SpawnProjection>> {
+        let looping = SpawnProjection {
+            callee: "tokio::task::spawn".to_owned(),
+            loops_over_await: true,
+            read_types: vec!["std::string::String".to_owned()],
+        };
+        vec![
+            DecisionFixture::flagged("looping task", looping.clone(), UNWATCHED_TASK),
+            DecisionFixture::clean(
+                "reads a token",
+                SpawnProjection {
+                    read_types: vec!["tokio_util::sync::CancellationToken".to_owned()],
+                    ..looping.clone()
+                },
+            ),
+            DecisionFixture::clean(
+                "finite task",
+                SpawnProjection {
+                    loops_over_await: false,
+                    ..looping.clone()
+                },
+            ),
+            DecisionFixture::clean(
+                "unchecked function",
+                SpawnProjection {
+                    callee: "std::thread::spawn".to_owned(),
+                    ..looping
+                },
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn configured_tokens_replace_the_defaults() {
+        let spawn = SpawnProjection {
+            callee: "tokio::spawn".to_owned(),
+            loops_over_await: true,
+            read_types: vec!["app::Shutdown".to_owned()],
+        };
+        let decision = SpawnedTaskMissingCancellationHandlingDecision::new(
+            ["tokio::spawn".to_owned()],
+            ["Shutdown".to_owned()],
+        );
+
+        assert!(
+            SpawnedTaskMissingCancellationHandlingDecision::default()
+                .decide(&spawn)
+                .is_some()
+        );
+        assert_eq!(decision.decide(&spawn), None);
+    }
+
+    #[rstest]
+    fn decision_conforms() {
+        assert_eq!(
+            check_conformance(&SpawnedTaskMissingCancellationHandlingDecision::default()),
+            Ok(4)
+        );
+    }
+}
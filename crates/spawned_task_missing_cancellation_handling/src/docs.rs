@@ -0,0 +1,30 @@
+//! Embedded documentation for the `spawned_task_missing_cancellation_handling`
+//! lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `spawned_task_missing_cancellation_handling`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "spawned_task_missing_cancellation_handling",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags spawned tasks that loop over `.await` without reading a cancellation token. Allow-by-default.",
+    rationale: "A background task running `loop { ... .await ... }` has no natural end. Unless it selects on a shutdown signal, a graceful shutdown cannot stop it between iterations, so the runtime drops it wherever it happens to be suspended, possibly halfway through writing a batch or holding a lease.",
+    bad_example: "tokio::spawn(async move {\n    loop {\n        let batch = queue.next_batch().await;\n        sink.write(batch).await;\n    }\n});",
+    good_example: "tokio::spawn(async move {\n    loop {\n        tokio::select! {\n            _ = token.cancelled() => break,\n            batch = queue.next_batch() => sink.write(batch).await,\n        }\n    }\n});",
+    config: &[
+        ConfigKeyDocs {
+            key: "spawn_functions",
+            default: "[\"tokio::spawn\", \"tokio::task::spawn\", \"tokio::task::spawn_local\", \"JoinSet::spawn\", \"async_std::task::spawn\", \"smol::spawn\"]",
+            description: "Paths, or trailing path segments, of the functions that spawn tasks.",
+        },
+        ConfigKeyDocs {
+            key: "token_types",
+            default: "[\"CancellationToken\", \"tokio::sync::watch::Receiver\", \"tokio::sync::broadcast::Receiver\", \"tokio::sync::oneshot::Receiver\"]",
+            description: "Paths, or trailing path segments, of the types whose use in a task counts as watching for cancellation.",
+        },
+    ],
+};
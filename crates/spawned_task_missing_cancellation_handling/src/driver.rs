@@ -0,0 +1,294 @@
+//! Lint pass reporting spawned tasks that loop without cancellation handling.
+//!
+//! Each function or method call is resolved to its path and checked against
+//! `spawn_functions`. The first argument of a matching call is the spawned
+//! future, which [`summarise`] inspects for a `loop` that awaits and for any
+//! value of the configured `token_types`. The check is a heuristic: reading a
+//! token anywhere in the task counts as handling cancellation, whether or
+//! not the loop selects on it. Calls produced by macro expansion and calls in
+//! test code are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{DEFAULT_SPAWN_FUNCTIONS, DEFAULT_TOKEN_TYPES, PathPatterns};
+use crate::task::summarise;
+
+pub(crate) const LINT_NAME: &str = "spawned_task_missing_cancellation_handling";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    spawn_functions: Vec<String>,
+    token_types: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let owned = |paths: &[&str]| paths.iter().map(|path| (*path).to_owned()).collect();
+        Self {
+            spawn_functions: owned(DEFAULT_SPAWN_FUNCTIONS),
+            token_types: owned(DEFAULT_TOKEN_TYPES),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub SPAWNED_TASK_MISSING_CANCELLATION_HANDLING,
+    Allow,
+    "spawned tasks that loop over `.await` should watch a cancellation token so they can be shut down",
+    SpawnedTaskMissingCancellationHandling::default()
+}
+
+/// Lint pass that finds looping spawned tasks with no cancellation token.
+#[derive(Default)]
+pub struct SpawnedTaskMissingCancellationHandling {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Spawn functions, token types, test detection, finding limit, and
+/// localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    spawn_functions: PathPatterns,
+    token_types: PathPatterns,
+    test_context: TestContext,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for SpawnedTaskMissingCancellationHandling {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        let config = load_configuration();
+        self.state.reset(CrateState {
+            spawn_functions: PathPatterns::new(config.spawn_functions),
+            token_types: PathPatterns::new(config.token_types),
+            test_context: TestContext::for_crate(cx, Vec::new()),
+            findings: FindingLimit::new(SPAWNED_TASK_MISSING_CANCELLATION_HANDLING, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(SpawnCall {
+            def_id,
+            span,
+            future,
+        }) = SpawnCall::from_expr(cx, expr)
+        else {
+            return;
+        };
+        let callee = cx.tcx.def_path_str(def_id);
+        if !self.state.spawn_functions.matches(&callee)
+            || self.state.test_context.is_test(cx, expr.hir_id)
+            || !summarise(cx, future, &self.state.token_types).needs_cancellation()
+        {
+            return;
+        }
+
+        debug!(target: LINT_NAME, "task spawned with `{callee}` loops without a token");
+        let state = &*self.state;
+        if state.findings.admit(cx, expr.hir_id, span) {
+            emit_diagnostic(
+                cx,
+                span,
+                &UnwatchedTaskMessage { callee: &callee },
+                &state.localizer,
+            );
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// A call that may spawn a task, with the span naming the callee and the
+/// future passed to it.
+struct SpawnCall<'tcx> {
+    def_id: DefId,
+    span: Span,
+    future: &'tcx hir::Expr<'tcx>,
+}
+
+impl<'tcx> SpawnCall<'tcx> {
+    fn from_expr(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) -> Option<Self> {
+        match expr.kind {
+            hir::ExprKind::Call(callee, [future, ..]) => {
+                let hir::ExprKind::Path(ref qpath) = callee.kind else {
+                    return None;
+                };
+                match cx.qpath_res(qpath, callee.hir_id) {
+                    Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => Some(Self {
+                        def_id,
+                        span: callee.span,
+                        future,
+                    }),
+                    _ => None,
+                }
+            }
+            hir::ExprKind::MethodCall(segment, _, [future, ..], _) => Some(Self {
+                def_id: cx.typeck_results().type_dependent_def_id(expr.hir_id)?,
+                span: segment.ident.span,
+                future,
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &UnwatchedTaskMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        SPAWNED_TASK_MISSING_CANCELLATION_HANDLING,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for one unwatched task interpolates.
+#[derive(Clone, Copy, Debug)]
+struct UnwatchedTaskMessage<'a> {
+    callee: &'a str,
+}
+
+impl UnwatchedTaskMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("callee"),
+            FluentValue::from(self.callee.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let callee = self.callee;
+        DiagnosticMessageSet::new(
+            format!(
+                "The task spawned with `{callee}` loops over `.await` without watching for \
+                 cancellation."
+            ),
+            String::from(
+                "Nothing in the task reads a cancellation token, so a graceful shutdown cannot \
+                 stop the loop; the task runs until the runtime drops it mid-iteration.",
+            ),
+            String::from(
+                "Pass a `CancellationToken` into the task and `select!` on `token.cancelled()` \
+                 alongside the work the loop awaits, then break out of the loop when it fires.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: UnwatchedTaskMessage<'static> = UnwatchedTaskMessage {
+        callee: "tokio::spawn",
+    };
+
+    fn resolve(message: &UnwatchedTaskMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_uses_the_default_lists() {
+        let config = Config::default();
+
+        assert_eq!(config.spawn_functions.len(), DEFAULT_SPAWN_FUNCTIONS.len());
+        assert_eq!(config.token_types.len(), DEFAULT_TOKEN_TYPES.len());
+    }
+
+    #[rstest]
+    fn fallback_primary_names_the_callee() {
+        assert_eq!(
+            MESSAGE.fallback_messages().primary(),
+            "The task spawned with `tokio::spawn` loops over `.await` without watching for \
+             cancellation."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`tokio::spawn`"));
+        assert!(messages.help().contains("`token.cancelled()`"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,46 @@
+//! Lint crate flagging spawned tasks that loop without watching for
+//! cancellation.
+//!
+//! A service that spawns a task running `loop { ... .await ... }` needs a way
+//! to stop that task on shutdown. Without a cancellation token to select on,
+//! the task either runs until the runtime drops it mid-iteration or keeps the
+//! process alive. The lint reports calls to the configured spawn functions
+//! whose future contains a `loop` that awaits and never reads a value of one
+//! of the configured token types. The lint is experimental and ships behind
+//! the `experimental-spawned-task-missing-cancellation-handling` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+mod task;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{SpawnProjection, SpawnedTaskMissingCancellationHandlingDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn spawned_task_missing_cancellation_handling_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
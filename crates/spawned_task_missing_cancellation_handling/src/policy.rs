@@ -0,0 +1,123 @@
+//! Rules deciding whether a spawned task needs cancellation handling.
+//!
+//! The driver names spawn functions and token types by their paths, such as
+//! `tokio::task::spawn` or `tokio_util::sync::CancellationToken`. An entry in
+//! `spawn_functions` or `token_types` matches a path in full or as a trailing
+//! run of segments, so `CancellationToken` matches the type wherever it is
+//! defined. A task summarised as a [`TaskBody`] needs cancellation handling
+//! when it loops over an `.await` and reads no token.
+
+/// Spawn functions checked when `spawn_functions` is not set.
+pub(crate) const DEFAULT_SPAWN_FUNCTIONS: &[&str] = &[
+    "tokio::spawn",
+    "tokio::task::spawn",
+    "tokio::task::spawn_local",
+    "JoinSet::spawn",
+    "async_std::task::spawn",
+    "smol::spawn",
+];
+
+/// Token types accepted as cancellation handling when `token_types` is not
+/// set.
+pub(crate) const DEFAULT_TOKEN_TYPES: &[&str] = &[
+    "CancellationToken",
+    "tokio::sync::watch::Receiver",
+    "tokio::sync::broadcast::Receiver",
+    "tokio::sync::oneshot::Receiver",
+];
+
+/// A configured list of item paths.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct PathPatterns {
+    paths: Vec<String>,
+}
+
+impl PathPatterns {
+    /// Builds the list from configured paths.
+    pub(crate) fn new(paths: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            paths: paths.into_iter().collect(),
+        }
+    }
+
+    /// Returns `true` when an entry matches `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let tokens = PathPatterns::new([String::from("CancellationToken")]);
+    /// assert!(tokens.matches("tokio_util::sync::CancellationToken"));
+    /// ```
+    pub(crate) fn matches(&self, path: &str) -> bool {
+        self.paths.iter().any(|pattern| {
+            path == pattern.as_str()
+                || path
+                    .strip_suffix(pattern.as_str())
+                    .is_some_and(|prefix| prefix.ends_with("::"))
+        })
+    }
+}
+
+/// What the lint needs to know about one spawned future.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct TaskBody {
+    /// Whether the task contains a `loop` whose body awaits.
+    pub(crate) loops_over_await: bool,
+    /// Whether the task or its arguments read a value of a token type.
+    pub(crate) reads_token: bool,
+}
+
+impl TaskBody {
+    /// Returns `true` when the task can run indefinitely with no way to be
+    /// told to stop.
+    #[must_use]
+    pub(crate) const fn needs_cancellation(self) -> bool {
+        self.loops_over_await && !self.reads_token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn defaults(paths: &[&str]) -> PathPatterns {
+        PathPatterns::new(paths.iter().map(|path| (*path).to_owned()))
+    }
+
+    #[rstest]
+    #[case::tokio("tokio::spawn", true)]
+    #[case::tokio_task("tokio::task::spawn", true)]
+    #[case::join_set("tokio::task::JoinSet::spawn", true)]
+    #[case::partial_segment("tokio::task::MyJoinSet::spawn", false)]
+    #[case::blocking("tokio::task::spawn_blocking", false)]
+    #[case::bare_name("spawn", false)]
+    fn matches_default_spawn_functions(#[case] path: &str, #[case] expected: bool) {
+        assert_eq!(defaults(DEFAULT_SPAWN_FUNCTIONS).matches(path), expected);
+    }
+
+    #[rstest]
+    #[case::tokio_util("tokio_util::sync::CancellationToken", true)]
+    #[case::watch("tokio::sync::watch::Receiver", true)]
+    #[case::mpsc("tokio::sync::mpsc::Receiver", false)]
+    fn matches_default_token_types(#[case] path: &str, #[case] expected: bool) {
+        assert_eq!(defaults(DEFAULT_TOKEN_TYPES).matches(path), expected);
+    }
+
+    #[rstest]
+    #[case::unwatched_loop(true, false, true)]
+    #[case::watched_loop(true, true, false)]
+    #[case::no_loop(false, false, false)]
+    fn needs_cancellation_for_unwatched_loops(
+        #[case] loops_over_await: bool,
+        #[case] reads_token: bool,
+        #[case] expected: bool,
+    ) {
+        let task = TaskBody {
+            loops_over_await,
+            reads_token,
+        };
+
+        assert_eq!(task.needs_cancellation(), expected);
+    }
+}
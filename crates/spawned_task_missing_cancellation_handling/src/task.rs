@@ -0,0 +1,153 @@
+//! Summarises a spawned future for [`crate::policy`].
+//!
+//! The future handed to a spawn function is either an `async` block or a
+//! call to a local `async fn`; in the second case the function's body is
+//! inspected too. The task loops over an await when a `loop` in its body,
+//! outside nested closures and `async` blocks, contains an `.await`. `while`
+//! and `for` loops are not counted, since they end when their condition or
+//! iterator does, as a `while let` over a closed channel does on shutdown.
+//! The task reads a token when any expression in the future, including the
+//! closures that `select!` expands into and the arguments of the call, has
+//! one of the configured token types.
+
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_lint::LateContext;
+use rustc_middle::ty::TypeckResults;
+
+use crate::policy::{PathPatterns, TaskBody};
+
+/// Summarises the spawned `future` for [`TaskBody::needs_cancellation`].
+pub(crate) fn summarise<'tcx>(
+    cx: &LateContext<'tcx>,
+    future: &'tcx hir::Expr<'tcx>,
+    tokens: &PathPatterns,
+) -> TaskBody {
+    let mut task = TaskBody {
+        loops_over_await: false,
+        reads_token: reads_token(cx, cx.typeck_results(), future, tokens),
+    };
+    if let Some(body) = async_block_body(cx, future) {
+        task.loops_over_await = loops_over_await(body);
+    } else if let Some((def_id, body)) = called_async_fn(cx, future) {
+        let typeck = cx.tcx.typeck(def_id);
+        task.loops_over_await = loops_over_await(async_block_body(cx, body).unwrap_or(body));
+        task.reads_token |= reads_token(cx, typeck, body, tokens);
+    }
+    task
+}
+
+/// Returns the body of `expr` when it is an `async` block.
+fn async_block_body<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    match expr.kind {
+        hir::ExprKind::Closure(&hir::Closure {
+            kind:
+                hir::ClosureKind::Coroutine(hir::CoroutineKind::Desugared(
+                    hir::CoroutineDesugaring::Async,
+                    _,
+                )),
+            body,
+            ..
+        }) => Some(cx.tcx.hir_body(body).value),
+        _ => None,
+    }
+}
+
+/// Returns the local `async fn` `expr` calls, with its body.
+fn called_async_fn<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+) -> Option<(hir::def_id::LocalDefId, &'tcx hir::Expr<'tcx>)> {
+    let def_id = match expr.kind {
+        hir::ExprKind::Call(callee, _) => {
+            let hir::ExprKind::Path(ref qpath) = callee.kind else {
+                return None;
+            };
+            match cx.qpath_res(qpath, callee.hir_id) {
+                Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => def_id,
+                _ => return None,
+            }
+        }
+        hir::ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id)?,
+        _ => return None,
+    };
+    let local = def_id.as_local()?;
+    if !cx.tcx.asyncness(local).is_async() {
+        return None;
+    }
+    let body = cx.tcx.hir_maybe_body_owned_by(local)?;
+    Some((local, body.value))
+}
+
+/// Returns `true` when a `loop` in `body` awaits.
+fn loops_over_await(body: &hir::Expr<'_>) -> bool {
+    struct Loops(bool);
+
+    impl<'tcx> Visitor<'tcx> for Loops {
+        fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+            if let hir::ExprKind::Loop(block, _, hir::LoopSource::Loop, _) = expr.kind {
+                let mut awaits = Awaits(false);
+                awaits.visit_block(block);
+                self.0 |= awaits.0;
+            }
+            intravisit::walk_expr(self, expr);
+        }
+    }
+
+    struct Awaits(bool);
+
+    impl<'tcx> Visitor<'tcx> for Awaits {
+        fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+            if let hir::ExprKind::Match(_, _, hir::MatchSource::AwaitDesugar) = expr.kind {
+                self.0 = true;
+            }
+            intravisit::walk_expr(self, expr);
+        }
+    }
+
+    let mut loops = Loops(false);
+    loops.visit_expr(body);
+    loops.0
+}
+
+/// Returns `true` when an expression in `expr`, or in the closures it
+/// builds, has one of the `tokens` types.
+fn reads_token<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck: &'tcx TypeckResults<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    tokens: &PathPatterns,
+) -> bool {
+    struct Tokens<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        typeck: &'tcx TypeckResults<'tcx>,
+        tokens: &'a PathPatterns,
+        found: bool,
+    }
+
+    impl<'tcx> Visitor<'tcx> for Tokens<'_, 'tcx> {
+        fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+            let ty = self.typeck.expr_ty_opt(expr).map(|ty| ty.peel_refs());
+            if let Some(adt) = ty.and_then(|ty| ty.ty_adt_def()) {
+                self.found |= self.tokens.matches(&self.cx.tcx.def_path_str(adt.did()));
+            }
+            if let hir::ExprKind::Closure(closure) = expr.kind {
+                self.visit_expr(self.cx.tcx.hir_body(closure.body).value);
+            }
+            intravisit::walk_expr(self, expr);
+        }
+    }
+
+    let mut visitor = Tokens {
+        cx,
+        typeck,
+        tokens,
+        found: false,
+    };
+    visitor.visit_expr(expr);
+    visitor.found
+}
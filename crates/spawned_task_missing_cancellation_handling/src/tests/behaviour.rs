@@ -0,0 +1,97 @@
+//! Behaviour-driven coverage for the spawned task cancellation policy.
+
+use crate::policy::{DEFAULT_SPAWN_FUNCTIONS, DEFAULT_TOKEN_TYPES, PathPatterns, TaskBody};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct SpawnWorld {
+    callee: RefCell<String>,
+    task: Cell<TaskBody>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> SpawnWorld {
+    SpawnWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+fn defaults(paths: &[&str]) -> PathPatterns {
+    PathPatterns::new(paths.iter().map(|path| (*path).to_owned()))
+}
+
+#[given("a task spawned with {callee}")]
+fn given_spawn(world: &SpawnWorld, callee: String) {
+    *world.callee.borrow_mut() = unquote(&callee);
+}
+
+#[given("the task loops over an await")]
+fn given_loop(world: &SpawnWorld) {
+    world.task.set(TaskBody {
+        loops_over_await: true,
+        ..world.task.get()
+    });
+}
+
+#[given("the task reads a {token}")]
+fn given_token(world: &SpawnWorld, token: String) {
+    world.task.set(TaskBody {
+        reads_token: defaults(DEFAULT_TOKEN_TYPES).matches(&unquote(&token)),
+        ..world.task.get()
+    });
+}
+
+#[when("the spawn is checked")]
+fn when_checked(world: &SpawnWorld) {
+    let spawns = defaults(DEFAULT_SPAWN_FUNCTIONS).matches(&world.callee.borrow());
+    world
+        .reported
+        .set(Some(spawns && world.task.get().needs_cancellation()));
+}
+
+#[then("the task is reported")]
+fn then_reported(world: &SpawnWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &SpawnWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(
+    path = "tests/features/spawned_task_missing_cancellation_handling.feature",
+    index = 0
+)]
+fn scenario_unwatched_loop(world: SpawnWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/spawned_task_missing_cancellation_handling.feature",
+    index = 1
+)]
+fn scenario_watched_loop(world: SpawnWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/spawned_task_missing_cancellation_handling.feature",
+    index = 2
+)]
+fn scenario_no_loop(world: SpawnWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/spawned_task_missing_cancellation_handling.feature",
+    index = 3
+)]
+fn scenario_other_function(world: SpawnWorld) {
+    let _ = world;
+}
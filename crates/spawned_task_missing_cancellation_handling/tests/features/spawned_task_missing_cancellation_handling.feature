@@ -0,0 +1,27 @@
+Feature: Spawned tasks missing cancellation handling
+  A task handed to a configured spawn function is reported when it loops
+  over an `.await` and reads no value of a configured token type.
+
+  Scenario: A looping task without a token is reported
+    Given a task spawned with "tokio::spawn"
+    And the task loops over an await
+    When the spawn is checked
+    Then the task is reported
+
+  Scenario: A looping task reading a token is accepted
+    Given a task spawned with "tokio::spawn"
+    And the task loops over an await
+    And the task reads a "tokio_util::sync::CancellationToken"
+    When the spawn is checked
+    Then nothing is reported
+
+  Scenario: A task without a loop is accepted
+    Given a task spawned with "tokio::spawn"
+    When the spawn is checked
+    Then nothing is reported
+
+  Scenario: Calls to other functions are ignored
+    Given a task spawned with "std::thread::spawn"
+    And the task loops over an await
+    When the spawn is checked
+    Then nothing is reported
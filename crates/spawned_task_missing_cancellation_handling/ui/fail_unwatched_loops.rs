@@ -0,0 +1,29 @@
+//! Negative UI fixture: spawned tasks that loop over `.await` without a
+//! cancellation token.
+#![warn(spawned_task_missing_cancellation_handling)]
+
+mod tokio {
+    pub fn spawn<F: std::future::Future + 'static>(_future: F) {}
+}
+
+async fn tick() {}
+
+async fn poll_forever(interval: u64) {
+    loop {
+        tick().await;
+        let _ = interval;
+    }
+}
+
+fn start() {
+    tokio::spawn(async move {
+        loop {
+            tick().await;
+        }
+    });
+    tokio::spawn(poll_forever(5));
+}
+
+fn main() {
+    start();
+}
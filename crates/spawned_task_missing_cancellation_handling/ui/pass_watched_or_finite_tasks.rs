@@ -0,0 +1,61 @@
+//! Positive UI fixture: spawned tasks that watch a token or end on their own.
+#![deny(spawned_task_missing_cancellation_handling)]
+
+mod tokio {
+    pub fn spawn<F: std::future::Future + 'static>(_future: F) {}
+}
+
+mod tokio_util {
+    pub mod sync {
+        #[derive(Clone, Default)]
+        pub struct CancellationToken;
+
+        impl CancellationToken {
+            pub fn is_cancelled(&self) -> bool {
+                true
+            }
+        }
+    }
+}
+
+use tokio_util::sync::CancellationToken;
+
+async fn tick() {}
+
+async fn next_message(remaining: &mut u32) -> Option<u32> {
+    *remaining = remaining.checked_sub(1)?;
+    Some(*remaining)
+}
+
+async fn poll_until_cancelled(token: CancellationToken) {
+    loop {
+        if token.is_cancelled() {
+            break;
+        }
+        tick().await;
+    }
+}
+
+fn start(token: CancellationToken) {
+    let watched = token.clone();
+    tokio::spawn(async move {
+        loop {
+            if watched.is_cancelled() {
+                break;
+            }
+            tick().await;
+        }
+    });
+    tokio::spawn(poll_until_cancelled(token));
+    tokio::spawn(async move {
+        let mut remaining = 3;
+        while let Some(_message) = next_message(&mut remaining).await {}
+    });
+    tokio::spawn(async move {
+        tick().await;
+    });
+}
+
+fn main() {
+    start(CancellationToken::default());
+}
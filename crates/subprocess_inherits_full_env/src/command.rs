@@ -0,0 +1,153 @@
+//! Tracing a spawned command back to where it was built.
+//!
+//! A spawn's receiver is followed back through builder methods of the
+//! configured command types, borrows, and `let` bindings until it reaches the
+//! type's `new` constructor. Along the way the trace notes whether any call in
+//! the chain restricts the environment and which binding, if any, holds the
+//! command. A receiver that comes from anywhere else, such as a parameter, a
+//! field, or a helper returning a `Command`, ends the trace, because its
+//! environment may have been set up out of sight.
+
+use rustc_hir as hir;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+use crate::policy::strip_generic_args;
+
+/// Bounds how many builder calls and bindings a receiver is traced through.
+const MAX_TRACE_DEPTH: usize = 32;
+
+/// Methods of a command type that start the child process.
+const SPAWN_METHODS: &[&str] = &["output", "spawn", "status"];
+
+/// The configured command types and environment-restricting calls.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CommandCalls<'a> {
+    pub(crate) command_types: &'a [String],
+    pub(crate) restricting_calls: &'a [String],
+}
+
+impl CommandCalls<'_> {
+    /// Reports whether `path` names a method that spawns a command.
+    pub(crate) fn is_spawn(&self, path: &str) -> bool {
+        path.rsplit_once("::").is_some_and(|(owner, method)| {
+            SPAWN_METHODS.contains(&method) && self.is_command_type(owner)
+        })
+    }
+
+    /// Reports whether `path` names a call that restricts the environment.
+    pub(crate) fn restricts(&self, path: &str) -> bool {
+        self.restricting_calls.iter().any(|call| call == path)
+    }
+
+    fn is_command_method(&self, path: &str) -> bool {
+        path.rsplit_once("::")
+            .is_some_and(|(owner, _)| self.is_command_type(owner))
+    }
+
+    fn is_constructor(&self, path: &str) -> bool {
+        path.strip_suffix("::new")
+            .is_some_and(|owner| self.is_command_type(owner))
+    }
+
+    fn is_command_type(&self, path: &str) -> bool {
+        self.command_types.iter().any(|command| command == path)
+    }
+}
+
+/// Where a spawned command was built.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CommandOrigin {
+    /// The constructor call that created the command.
+    pub(crate) constructor: Span,
+    /// The local binding the command was built in, if any.
+    pub(crate) binding: Option<hir::HirId>,
+    /// Whether a call in the builder chain restricts the environment.
+    pub(crate) restricted: bool,
+}
+
+/// Returns the path of `def_id` without generic arguments, qualified with
+/// the crate name when the item is local.
+fn item_path(cx: &LateContext<'_>, def_id: DefId) -> String {
+    let path = strip_generic_args(&cx.tcx.def_path_str(def_id));
+    if def_id.is_local() {
+        format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+    } else {
+        path
+    }
+}
+
+/// Returns the path of the function or method `expr` calls.
+pub(crate) fn callee_path(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<String> {
+    let def_id = match expr.kind {
+        hir::ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+        hir::ExprKind::Call(callee, _) => match callee.kind {
+            hir::ExprKind::Path(ref qpath) => cx.qpath_res(qpath, callee.hir_id).opt_def_id(),
+            _ => None,
+        },
+        _ => None,
+    }?;
+    Some(item_path(cx, def_id))
+}
+
+/// Traces `expr`, a command or a borrow of one, back to its constructor.
+pub(crate) fn command_origin(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    calls: CommandCalls<'_>,
+) -> Option<CommandOrigin> {
+    let mut binding = None;
+    let mut restricted = false;
+    let mut current = expr;
+    for _ in 0..MAX_TRACE_DEPTH {
+        match current.kind {
+            hir::ExprKind::AddrOf(_, _, inner) | hir::ExprKind::DropTemps(inner) => {
+                current = inner;
+            }
+            hir::ExprKind::MethodCall(_, receiver, _, _) => {
+                let path = callee_path(cx, current)?;
+                if !calls.is_command_method(&path) {
+                    return None;
+                }
+                restricted |= calls.restricts(&path);
+                current = receiver;
+            }
+            hir::ExprKind::Call(..) => {
+                let path = callee_path(cx, current)?;
+                return calls.is_constructor(&path).then_some(CommandOrigin {
+                    constructor: current.span,
+                    binding,
+                    restricted,
+                });
+            }
+            hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => {
+                let Res::Local(local) = path.res else {
+                    return None;
+                };
+                binding.get_or_insert(local);
+                current = let_initializer(cx, local)?;
+            }
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Returns the initialiser of the `let` binding `binding`.
+fn let_initializer<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding: hir::HirId,
+) -> Option<&'tcx hir::Expr<'tcx>> {
+    let hir::Node::Pat(pat) = cx.tcx.hir_node(binding) else {
+        return None;
+    };
+    if !matches!(pat.kind, hir::PatKind::Binding(..)) {
+        return None;
+    }
+    match cx.tcx.parent_hir_node(binding) {
+        hir::Node::LetStmt(local) => local.init,
+        _ => None,
+    }
+}
@@ -0,0 +1,163 @@
+//! Pure decision logic for `subprocess_inherits_full_env`.
+//!
+//! The driver walks each function body in the configured modules, noting
+//! every spawn whose builder chain leaves the environment alone and every
+//! binding whose command is restricted later.
+//! [`SubprocessInheritsFullEnvDecision`] matches the module against the same
+//! globs, replays the body into a [`BodyRecord`], and reports the first
+//! unrestricted spawn, naming its method as the finding's subject. Resolving
+//! command types and restricting calls is left to the driver.
+//!
+//! The lint is inert until `modules` is configured, so the default decision
+//! reports nothing and publishes no flagged fixture.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::path::ModuleGlobs;
+
+use crate::driver::LINT_NAME;
+use crate::policy::BodyRecord;
+
+/// Reason key for spawns that inherit the full environment.
+const INHERITED_ENV: &str = "inherited_env";
+
+/// A spawn whose builder chain restricts nothing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpawnProjection {
+    /// The spawning method, such as `output` or `spawn`.
+    pub method: String,
+    /// The binding holding the command, when it was not built inline.
+    pub binding: Option<String>,
+}
+
+/// The facts about one function body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FunctionBodyProjection {
+    /// The module the function sits in, relative to the crate root.
+    pub module: String,
+    /// The body's spawns, in source order.
+    pub spawns: Vec<SpawnProjection>,
+    /// The bindings whose command's environment is restricted anywhere in
+    /// the body.
+    pub restricted_bindings: Vec<String>,
+}
+
+/// Reports commands spawned with the full parent environment in the
+/// configured modules.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = SubprocessInheritsFullEnvDecision::new(&["deploy".to_owned()]);
+/// let body = FunctionBodyProjection {
+///     module: "deploy".to_owned(),
+///     spawns: vec![SpawnProjection { method: "output".to_owned(), binding: None }],
+///     restricted_bindings: Vec::new(),
+/// };
+/// assert!(decision.decide(&body).is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SubprocessInheritsFullEnvDecision {
+    modules: ModuleGlobs,
+    sensitive_module: Option<String>,
+}
+
+impl SubprocessInheritsFullEnvDecision {
+    /// Creates a decision checking the modules matching `modules`.
+    #[must_use]
+    pub fn new(modules: &[String]) -> Self {
+        let globs = ModuleGlobs::new(LINT_NAME, "modules", modules);
+        let sensitive_module = modules
+            .iter()
+            .map(|glob| glob.replace('*', "inner").replace('?', "m"))
+            .find(|module| globs.matches(module));
+        Self {
+            modules: globs,
+            sensitive_module,
+        }
+    }
+}
+
+impl LintDecision for SubprocessInheritsFullEnvDecision {
+    type Projection = FunctionBodyProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, body: &FunctionBodyProjection) -> Option<Finding> {
+        if !self.modules.matches(&body.module) {
+            return None;
+        }
+        let mut record = BodyRecord::default();
+        for spawn in &body.spawns {
+            record.record_spawn(spawn.binding.as_deref(), spawn.method.as_str());
+        }
+        for binding in &body.restricted_bindings {
+            record.record_restriction(binding.as_str());
+        }
+        let method = record.into_unrestricted_spawns().into_iter().next()?;
+        Some(Finding::new(LINT_NAME, INHERITED_ENV).with_subject(method))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<FunctionBodyProjection>> {
+        let spawn = |binding: Option<&str>| SpawnProjection {
+            method: "output".to_owned(),
+            binding: binding.map(str::to_owned),
+        };
+        let body = |module: &str, spawns, restricted: &[&str]| FunctionBodyProjection {
+            module: module.to_owned(),
+            spawns,
+            restricted_bindings: restricted
+                .iter()
+                .map(|&binding| binding.to_owned())
+                .collect(),
+        };
+        let Some(module) = self.sensitive_module.as_deref() else {
+            return vec![DecisionFixture::clean(
+                "no configured modules",
+                body("", vec![spawn(None)], &[]),
+            )];
+        };
+        vec![
+            DecisionFixture::flagged(
+                "inline command",
+                body(module, vec![spawn(None)], &[]),
+                INHERITED_ENV,
+            ),
+            DecisionFixture::flagged(
+                "binding restricted elsewhere",
+                body(module, vec![spawn(Some("build"))], &["deploy"]),
+                INHERITED_ENV,
+            ),
+            DecisionFixture::clean(
+                "restricted binding",
+                body(module, vec![spawn(Some("build"))], &["build"]),
+            ),
+            DecisionFixture::clean("no spawn", body(module, Vec::new(), &[])),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::{ConformanceError, check_conformance};
+
+    #[rstest]
+    fn unconfigured_decision_reports_nothing() {
+        assert_eq!(
+            check_conformance(&SubprocessInheritsFullEnvDecision::default()),
+            Err(ConformanceError::MissingFlaggedFixture { lint: LINT_NAME })
+        );
+    }
+
+    #[rstest]
+    #[case::plain_module("deploy")]
+    #[case::glob("secrets::*")]
+    fn decision_conforms(#[case] module: &str) {
+        let decision = SubprocessInheritsFullEnvDecision::new(&[module.to_owned()]);
+
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
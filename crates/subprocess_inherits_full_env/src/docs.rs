@@ -0,0 +1,34 @@
+//! Embedded documentation for the `subprocess_inherits_full_env` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `subprocess_inherits_full_env`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "subprocess_inherits_full_env",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags commands spawned in configured security-sensitive modules without `env_clear()` or another call that restricts the inherited environment. Allow-by-default.",
+    rationale: "A child process inherits every variable of its parent, including tokens, credentials, and proxy settings it has no use for. Building the environment explicitly keeps secrets out of tools that log, crash-report, or forward their environment.",
+    bad_example: "Command::new(\"git\").args([\"push\", remote]).status()?;",
+    good_example: "Command::new(\"git\")\n    .env_clear()\n    .env(\"PATH\", path)\n    .args([\"push\", remote])\n    .status()?;",
+    config: &[
+        ConfigKeyDocs {
+            key: "modules",
+            default: "[]",
+            description: "Module path globs, relative to the crate root, whose functions are checked. A glob also matches every module inside the modules it matches. The lint reports nothing until a module is listed.",
+        },
+        ConfigKeyDocs {
+            key: "command_types",
+            default: "[\"std::process::Command\", \"tokio::process::Command\"]",
+            description: "Fully qualified command builder types whose `spawn`, `output`, and `status` calls are checked.",
+        },
+        ConfigKeyDocs {
+            key: "restricting_calls",
+            default: "`env_clear` and `env_remove` on each default command type",
+            description: "Fully qualified functions or methods that restrict the environment of the command they receive; add project helpers such as `my_crate::sandbox::isolate`.",
+        },
+    ],
+};
@@ -0,0 +1,381 @@
+//! Lint pass reporting commands that inherit the full parent environment.
+//!
+//! Each function body whose module, rendered with
+//! [`whitaker::hir::module_path`], matches the configured [`ModuleGlobs`] gets
+//! a [`BodyRecord`]. A call to `spawn`, `output`, or `status` on one of the
+//! configured command types is traced back to its constructor. When no call in
+//! its builder chain restricts the environment, the spawn is recorded with the
+//! binding that holds the command, if any. Restricting calls made on a binding
+//! elsewhere in the body, such as `command.env_clear();` or a configured helper
+//! given `&mut command`, mark that binding as restricted. When the body ends,
+//! the spawns of unrestricted commands are reported. Closures share the record
+//! of the function that contains them.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_hir::BodyOwnerKind;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{Span, Symbol};
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, module_path};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::path::ModuleGlobs;
+
+use crate::command::{CommandCalls, callee_path, command_origin};
+use crate::policy::BodyRecord;
+
+pub(crate) const LINT_NAME: &str = "subprocess_inherits_full_env";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+const DEFAULT_COMMAND_TYPES: &[&str] = &["std::process::Command", "tokio::process::Command"];
+const DEFAULT_RESTRICTING_METHODS: &[&str] = &["env_clear", "env_remove"];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    modules: Vec<String>,
+    command_types: Vec<String>,
+    restricting_calls: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            modules: Vec::new(),
+            command_types: DEFAULT_COMMAND_TYPES
+                .iter()
+                .map(|&command| command.to_owned())
+                .collect(),
+            restricting_calls: DEFAULT_COMMAND_TYPES
+                .iter()
+                .flat_map(|command| {
+                    DEFAULT_RESTRICTING_METHODS
+                        .iter()
+                        .map(move |method| format!("{command}::{method}"))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Config {
+    fn calls(&self) -> CommandCalls<'_> {
+        CommandCalls {
+            command_types: &self.command_types,
+            restricting_calls: &self.restricting_calls,
+        }
+    }
+}
+
+/// Lint pass that tracks spawns and environment restrictions per body.
+#[derive(Default)]
+pub struct SubprocessInheritsFullEnv {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configuration, configured modules, open function bodies, finding limit,
+/// and localization for the crate being checked. Bodies outside the
+/// configured modules hold no record.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    modules: ModuleGlobs,
+    bodies: Vec<Option<BodyRecord<hir::HirId, Spawn>>>,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+/// A spawn of a command whose builder chain restricts nothing.
+#[derive(Clone, Copy, Debug)]
+struct Spawn {
+    hir_id: hir::HirId,
+    span: Span,
+    method: Symbol,
+    constructor: Span,
+}
+
+dylint_linting::impl_late_lint! {
+    pub SUBPROCESS_INHERITS_FULL_ENV,
+    Allow,
+    "commands in security-sensitive modules spawned with the full parent environment",
+    SubprocessInheritsFullEnv::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for SubprocessInheritsFullEnv {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        let config = load_configuration();
+        self.state.reset(CrateState {
+            modules: ModuleGlobs::new(LINT_NAME, "modules", &config.modules),
+            config,
+            bodies: Vec::new(),
+            findings: FindingLimit::new(SUBPROCESS_INHERITS_FULL_ENV, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    // Closures are checked together with the function that contains them.
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            return;
+        }
+        let sensitive = self
+            .state
+            .modules
+            .matches(&module_path(cx, body.value.hir_id));
+        self.state.bodies.push(sensitive.then(BodyRecord::default));
+    }
+
+    fn check_body_post(&mut self, cx: &LateContext<'tcx>, body: &hir::Body<'tcx>) {
+        let owner = cx.tcx.hir_body_owner_def_id(body.id());
+        if matches!(cx.tcx.hir_body_owner_kind(owner), BodyOwnerKind::Closure) {
+            return;
+        }
+        let Some(Some(record)) = self.state.bodies.pop() else {
+            return;
+        };
+        for spawn in record.into_unrestricted_spawns() {
+            if self.state.findings.admit(cx, spawn.hir_id, spawn.span) {
+                emit_diagnostic(cx, &spawn, &self.state.localizer);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let state = &mut *self.state;
+        let Some(Some(record)) = state.bodies.last_mut() else {
+            return;
+        };
+        let Some(path) = callee_path(cx, expr) else {
+            return;
+        };
+        let calls = state.config.calls();
+        if calls.restricts(&path) {
+            debug!(target: LINT_NAME, "`{path}` restricts a command's environment");
+            record_restriction(cx, record, calls, expr);
+        } else if calls.is_spawn(&path) && !expr.span.from_expansion() {
+            record_spawn(cx, record, calls, expr);
+        }
+    }
+}
+
+/// Marks the bindings whose commands `expr`, a restricting call, receives.
+fn record_restriction(
+    cx: &LateContext<'_>,
+    record: &mut BodyRecord<hir::HirId, Spawn>,
+    calls: CommandCalls<'_>,
+    expr: &hir::Expr<'_>,
+) {
+    let (receiver, args) = match expr.kind {
+        hir::ExprKind::MethodCall(_, receiver, args, _) => (Some(receiver), args),
+        hir::ExprKind::Call(_, args) => (None, args),
+        _ => return,
+    };
+    for operand in receiver.into_iter().chain(args) {
+        if let Some(binding) = command_origin(cx, operand, calls).and_then(|origin| origin.binding)
+        {
+            record.record_restriction(binding);
+        }
+    }
+}
+
+/// Records `expr`, a spawn, unless its builder chain restricts the
+/// environment or its command was not built in the body.
+fn record_spawn(
+    cx: &LateContext<'_>,
+    record: &mut BodyRecord<hir::HirId, Spawn>,
+    calls: CommandCalls<'_>,
+    expr: &hir::Expr<'_>,
+) {
+    let hir::ExprKind::MethodCall(segment, receiver, _, _) = expr.kind else {
+        return;
+    };
+    let Some(origin) = command_origin(cx, receiver, calls) else {
+        return;
+    };
+    if origin.restricted {
+        return;
+    }
+
+    debug!(target: LINT_NAME, "`{}` spawns a command with the full environment", segment.ident);
+    record.record_spawn(
+        origin.binding,
+        Spawn {
+            hir_id: expr.hir_id,
+            span: expr.span.with_lo(segment.ident.span.lo()),
+            method: segment.ident.name,
+            constructor: origin.constructor,
+        },
+    );
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+fn emit_diagnostic(cx: &LateContext<'_>, spawn: &Spawn, localizer: &Localizer) {
+    let message = SpawnMessage {
+        method: spawn.method.as_str(),
+    };
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+    let constructor = spawn.constructor;
+
+    cx.emit_span_lint(
+        SUBPROCESS_INHERITS_FULL_ENV,
+        spawn.span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.span_note(constructor, note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for an unrestricted spawn interpolates.
+#[derive(Clone, Copy, Debug)]
+struct SpawnMessage<'a> {
+    method: &'a str,
+}
+
+impl SpawnMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("method"),
+            FluentValue::from(self.method.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let method = self.method;
+        DiagnosticMessageSet::new(
+            format!("`{method}` runs a command that inherits this process's full environment."),
+            String::from(
+                "The command built here starts from a copy of the parent environment, \
+                 including any tokens or credentials it holds.",
+            ),
+            String::from(
+                "Call `env_clear()` and pass only the variables the child needs with `env` \
+                 or `envs`, or remove sensitive variables with `env_remove`.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: SpawnMessage<'static> = SpawnMessage { method: "output" };
+
+    fn resolve(message: &SpawnMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_checks_no_modules() {
+        assert!(Config::default().modules.is_empty());
+    }
+
+    #[rstest]
+    #[case::std("std::process::Command::env_clear")]
+    #[case::std_remove("std::process::Command::env_remove")]
+    #[case::tokio("tokio::process::Command::env_clear")]
+    fn default_config_accepts_env_clear_and_env_remove(#[case] call: &str) {
+        assert!(Config::default().calls().restricts(call));
+    }
+
+    #[rstest]
+    #[case::std_output("std::process::Command::output", true)]
+    #[case::tokio_spawn("tokio::process::Command::spawn", true)]
+    #[case::builder("std::process::Command::arg", false)]
+    #[case::other_type("my_crate::Job::status", false)]
+    fn default_config_recognises_spawns(#[case] path: &str, #[case] expected: bool) {
+        assert_eq!(Config::default().calls().is_spawn(path), expected);
+    }
+
+    #[rstest]
+    fn partial_config_keeps_other_defaults() {
+        let config: Config =
+            toml::from_str("modules = [\"deploy\"]").expect("partial configuration should parse");
+
+        assert_eq!(config.modules, ["deploy"]);
+        assert_eq!(
+            config.restricting_calls,
+            Config::default().restricting_calls
+        );
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_method() {
+        let messages = MESSAGE.fallback_messages();
+
+        assert!(messages.primary().starts_with("`output` runs"));
+        assert!(messages.help().contains("`env_clear()`"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`output`"));
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,44 @@
+//! Lint crate flagging subprocesses that inherit the full parent environment.
+//!
+//! A `Command` starts from a copy of its parent's environment, so every
+//! token, credential, and proxy setting the parent holds reaches the child
+//! unless the builder clears or trims it. In modules a project marks as
+//! security-sensitive, the lint reports commands that are spawned without a
+//! call to `env_clear()`, `env_remove()`, or a configured helper that
+//! restricts the environment. The lint is experimental and ships behind the
+//! `experimental-subprocess-inherits-full-env` suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod command;
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{FunctionBodyProjection, SpawnProjection, SubprocessInheritsFullEnvDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn subprocess_inherits_full_env_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
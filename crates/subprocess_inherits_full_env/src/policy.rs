@@ -0,0 +1,143 @@
+//! Per-function bookkeeping and path normalisation for the lint.
+//!
+//! The driver records every unrestricted spawn and every binding whose
+//! environment is restricted while walking a function body, then asks the
+//! [`BodyRecord`] which spawns to report once the body is finished. Keeping
+//! the decision here, free of compiler types, lets it be tested directly.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// The spawns and environment restrictions seen in one function body.
+///
+/// `B` identifies a local binding holding a command and `S` describes a
+/// spawn.
+#[derive(Debug)]
+pub(crate) struct BodyRecord<B, S> {
+    spawns: Vec<(Option<B>, S)>,
+    restricted: HashSet<B>,
+}
+
+impl<B, S> Default for BodyRecord<B, S> {
+    fn default() -> Self {
+        Self {
+            spawns: Vec::new(),
+            restricted: HashSet::new(),
+        }
+    }
+}
+
+impl<B: Eq + Hash, S> BodyRecord<B, S> {
+    /// Records a spawn whose builder chain restricts nothing, together with
+    /// the binding the command was built in, if any.
+    pub(crate) fn record_spawn(&mut self, binding: Option<B>, spawn: S) {
+        self.spawns.push((binding, spawn));
+    }
+
+    /// Records a call that restricts the environment of the command held in
+    /// `binding`.
+    pub(crate) fn record_restriction(&mut self, binding: B) {
+        self.restricted.insert(binding);
+    }
+
+    /// Returns the spawns to report: those built inline, and those whose
+    /// binding is never restricted anywhere in the body.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mut record = BodyRecord::default();
+    /// record.record_spawn(Some("cmd"), "cmd.output()");
+    /// record.record_restriction("cmd");
+    /// assert!(record.into_unrestricted_spawns().is_empty());
+    /// ```
+    #[must_use]
+    pub(crate) fn into_unrestricted_spawns(self) -> Vec<S> {
+        let Self { spawns, restricted } = self;
+        spawns
+            .into_iter()
+            .filter(|(binding, _)| {
+                binding
+                    .as_ref()
+                    .is_none_or(|binding| !restricted.contains(binding))
+            })
+            .map(|(_, spawn)| spawn)
+            .collect()
+    }
+}
+
+/// Removes generic arguments from a resolved item path, so
+/// `tokio::process::Command::arg::<&str>` compares equal to
+/// `tokio::process::Command::arg`.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(strip_generic_args("std::process::Command::arg::<&str>"), "std::process::Command::arg");
+/// ```
+#[must_use]
+pub(crate) fn strip_generic_args(path: &str) -> String {
+    let mut stripped = String::with_capacity(path.len());
+    let mut depth = 0_usize;
+    for character in path.chars() {
+        match character {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => stripped.push(character),
+            _ => {}
+        }
+    }
+    stripped
+        .split("::")
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::plain("std::process::Command::new", "std::process::Command::new")]
+    #[case::turbofish("std::process::Command::arg::<&str>", "std::process::Command::arg")]
+    fn strip_generic_args_keeps_the_item_path(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(strip_generic_args(path), expected);
+    }
+
+    #[rstest]
+    fn inline_spawns_are_always_reported() {
+        let mut record = BodyRecord::<u8, _>::default();
+        record.record_spawn(None, 1);
+        record.record_spawn(None, 2);
+
+        assert_eq!(record.into_unrestricted_spawns(), [1, 2]);
+    }
+
+    #[rstest]
+    #[case::restriction_first(true)]
+    #[case::restriction_last(false)]
+    fn a_restriction_anywhere_covers_its_binding(#[case] restrict_first: bool) {
+        let mut record = BodyRecord::default();
+        if restrict_first {
+            record.record_restriction("cmd");
+        }
+        record.record_spawn(Some("cmd"), 1);
+        if !restrict_first {
+            record.record_restriction("cmd");
+        }
+
+        assert!(record.into_unrestricted_spawns().is_empty());
+    }
+
+    #[rstest]
+    fn a_restriction_covers_only_its_own_binding() {
+        let mut record = BodyRecord::default();
+        record.record_restriction("scrubbed");
+        record.record_spawn(Some("scrubbed"), 1);
+        record.record_spawn(Some("inherited"), 2);
+
+        assert_eq!(record.into_unrestricted_spawns(), [2]);
+    }
+}
@@ -0,0 +1,93 @@
+//! Behaviour-driven coverage for deciding which spawns to report.
+
+use crate::policy::BodyRecord;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct BodyWorld {
+    record: RefCell<BodyRecord<String, usize>>,
+    spawns: Cell<usize>,
+    reported: Cell<Option<usize>>,
+}
+
+impl BodyWorld {
+    fn spawn(&self, binding: Option<String>) {
+        let spawn = self.spawns.get();
+        self.spawns.set(spawn + 1);
+        self.record.borrow_mut().record_spawn(binding, spawn);
+    }
+}
+
+#[fixture]
+fn world() -> BodyWorld {
+    BodyWorld::default()
+}
+
+#[given("a function that spawns {count} command built inline")]
+fn given_inline_spawns(world: &BodyWorld, count: usize) {
+    for _ in 0..count {
+        world.spawn(None);
+    }
+}
+
+#[given("a function that spawns the command in binding {binding}")]
+fn given_bound_spawn(world: &BodyWorld, binding: String) {
+    world.spawn(Some(binding));
+}
+
+#[given("the function restricts binding {binding}")]
+fn given_restriction(world: &BodyWorld, binding: String) {
+    world.record.borrow_mut().record_restriction(binding);
+}
+
+#[when("the function body ends")]
+fn when_body_ends(world: &BodyWorld) {
+    let record = world.record.take();
+    world
+        .reported
+        .set(Some(record.into_unrestricted_spawns().len()));
+}
+
+#[then("{count} spawn is reported")]
+fn then_one_reported(world: &BodyWorld, count: usize) {
+    then_reported(world, count);
+}
+
+#[then("{count} spawns are reported")]
+fn then_reported(world: &BodyWorld, count: usize) {
+    assert_eq!(world.reported.get(), Some(count));
+}
+
+#[scenario(
+    path = "tests/features/subprocess_inherits_full_env.feature",
+    index = 0
+)]
+fn scenario_inline(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/subprocess_inherits_full_env.feature",
+    index = 1
+)]
+fn scenario_unrestricted_binding(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/subprocess_inherits_full_env.feature",
+    index = 2
+)]
+fn scenario_restricted_binding(world: BodyWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/subprocess_inherits_full_env.feature",
+    index = 3
+)]
+fn scenario_other_binding(world: BodyWorld) {
+    let _ = world;
+}
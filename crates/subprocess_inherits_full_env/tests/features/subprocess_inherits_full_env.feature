@@ -0,0 +1,25 @@
+Feature: Subprocesses that inherit the full environment
+  A function in a security-sensitive module that spawns a command is reported
+  unless the command's environment is cleared or restricted first.
+
+  Scenario: A command built inline is reported
+    Given a function that spawns 1 command built inline
+    When the function body ends
+    Then 1 spawn is reported
+
+  Scenario: A command held in an unrestricted binding is reported
+    Given a function that spawns the command in binding "cmd"
+    When the function body ends
+    Then 1 spawn is reported
+
+  Scenario: Restricting a binding suppresses its spawns
+    Given a function that spawns the command in binding "cmd"
+    And the function restricts binding "cmd"
+    When the function body ends
+    Then 0 spawns are reported
+
+  Scenario: Restricting another binding leaves the spawn reported
+    Given a function that spawns the command in binding "cmd"
+    And the function restricts binding "other"
+    When the function body ends
+    Then 1 spawn is reported
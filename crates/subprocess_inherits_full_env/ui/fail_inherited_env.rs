@@ -0,0 +1,41 @@
+//! UI fixture: commands spawned in a security-sensitive module without
+//! restricting the environment they inherit.
+#![warn(subprocess_inherits_full_env)]
+
+mod deploy {
+    use std::process::Command;
+
+    pub fn push(remote: &str) -> std::io::Result<()> {
+        Command::new("git").args(["push", remote]).status()?;
+        Ok(())
+    }
+
+    pub fn describe() -> std::io::Result<Vec<u8>> {
+        let mut command = Command::new("git");
+        command.arg("describe");
+        Ok(command.output()?.stdout)
+    }
+
+    pub mod remote {
+        use std::process::Command;
+
+        pub fn connect(host: &str) -> std::io::Result<std::process::Child> {
+            Command::new("ssh").arg(host).spawn()
+        }
+    }
+}
+
+mod report {
+    use std::process::Command;
+
+    pub fn uptime() -> std::io::Result<Vec<u8>> {
+        Ok(Command::new("uptime").output()?.stdout)
+    }
+}
+
+fn main() {
+    let _ = deploy::push("origin");
+    let _ = deploy::describe();
+    let _ = deploy::remote::connect("example.org");
+    let _ = report::uptime();
+}
@@ -0,0 +1,63 @@
+//! UI fixture: commands in a security-sensitive module whose environment is
+//! cleared or restricted before they run, and commands outside it.
+#![deny(subprocess_inherits_full_env)]
+
+mod deploy {
+    use std::process::Command;
+
+    pub fn push(remote: &str) -> std::io::Result<()> {
+        Command::new("git")
+            .env_clear()
+            .args(["push", remote])
+            .status()?;
+        Ok(())
+    }
+
+    pub fn describe(path: &str) -> std::io::Result<Vec<u8>> {
+        let mut command = Command::new("git");
+        command.env_clear().env("PATH", path).arg("describe");
+        Ok(command.output()?.stdout)
+    }
+
+    pub fn fetch() -> std::io::Result<()> {
+        let mut command = Command::new("git");
+        crate::sandbox::isolate(&mut command);
+        command.arg("fetch").status()?;
+        Ok(())
+    }
+
+    pub fn status() -> std::io::Result<()> {
+        let mut command = Command::new("git");
+        command.env_remove("GIT_ASKPASS");
+        command.arg("status").status()?;
+        Ok(())
+    }
+
+    pub fn run(command: &mut Command) -> std::io::Result<()> {
+        command.status()?;
+        Ok(())
+    }
+}
+
+mod sandbox {
+    pub fn isolate(command: &mut std::process::Command) {
+        command.env_clear();
+    }
+}
+
+mod report {
+    use std::process::Command;
+
+    pub fn uptime() -> std::io::Result<Vec<u8>> {
+        Ok(Command::new("uptime").output()?.stdout)
+    }
+}
+
+fn main() {
+    let _ = deploy::push("origin");
+    let _ = deploy::describe("/usr/bin");
+    let _ = deploy::fetch();
+    let _ = deploy::status();
+    let _ = deploy::run(&mut std::process::Command::new("true"));
+    let _ = report::uptime();
+}
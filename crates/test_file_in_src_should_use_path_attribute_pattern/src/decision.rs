@@ -0,0 +1,130 @@
+//! Pure decision logic for `test_file_in_src_should_use_path_attribute_pattern`.
+//!
+//! The driver hands the source text of every module body, with the path of
+//! the file declaring it, to [`inline_test_modules`].
+//! [`TestFileInSrcShouldUsePathAttributePatternDecision`] does the same and
+//! reports the first inline test module longer than `max_inline_lines`,
+//! naming the file [`suggested_path`] proposes as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_INLINE_LINES, DEFAULT_TEST_DIRECTORY, LINT_NAME};
+use crate::policy::{inline_test_modules, suggested_path};
+
+/// Reason key for long inline test modules.
+const INLINE_TESTS: &str = "inline_tests";
+
+/// The facts about one module body that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleBodyProjection {
+    /// The path of the file declaring the module, such as `src/driver.rs`.
+    pub file: String,
+    /// The source text of the module body.
+    pub source: String,
+}
+
+/// Reports `#[cfg(test)]` modules written inline over more than
+/// `max_inline_lines` lines.
+///
+/// # Examples
+///
+/// ```ignore
+/// let module = ModuleBodyProjection {
+///     file: "src/driver.rs".to_owned(),
+///     source: format!("#[cfg(test)]\nmod tests {{{}}}", "\n".repeat(100)),
+/// };
+/// let decision = TestFileInSrcShouldUsePathAttributePatternDecision::default();
+/// assert!(decision.decide(&module).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestFileInSrcShouldUsePathAttributePatternDecision {
+    max_inline_lines: usize,
+    test_directory: String,
+}
+
+impl TestFileInSrcShouldUsePathAttributePatternDecision {
+    /// Creates a decision allowing `max_inline_lines` lines inline and
+    /// suggesting files under `test_directory`.
+    #[must_use]
+    pub fn new(max_inline_lines: usize, test_directory: impl Into<String>) -> Self {
+        Self {
+            max_inline_lines,
+            test_directory: test_directory.into(),
+        }
+    }
+}
+
+impl Default for TestFileInSrcShouldUsePathAttributePatternDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_INLINE_LINES, DEFAULT_TEST_DIRECTORY)
+    }
+}
+
+impl LintDecision for TestFileInSrcShouldUsePathAttributePatternDecision {
+    type Projection = ModuleBodyProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, module: &ModuleBodyProjection) -> Option<Finding> {
+        let inline = inline_test_modules(&module.source)
+            .into_iter()
+            .find(|inline| inline.lines > self.max_inline_lines)?;
+        let path = suggested_path(&self.test_directory, &module.file, &inline.name);
+        Some(Finding::new(LINT_NAME, INLINE_TESTS).with_subject(path))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<ModuleBodyProjection>> {
+        // A body spanning `lines` lines, counting those holding its braces.
+        let module = |header: &str, lines: usize| ModuleBodyProjection {
+            file: "src/driver.rs".to_owned(),
+            source: format!(
+                "fn run() {{}}\n\n{header} {{{}}}\n",
+                "\n".repeat(lines.saturating_sub(1))
+            ),
+        };
+        let long = self.max_inline_lines + 1;
+        vec![
+            DecisionFixture::flagged(
+                "long inline tests",
+                module("#[cfg(test)]\nmod tests", long),
+                INLINE_TESTS,
+            ),
+            DecisionFixture::clean(
+                "at the line limit",
+                module("#[cfg(test)]\nmod tests", self.max_inline_lines),
+            ),
+            DecisionFixture::clean("ungated module", module("mod helpers", long)),
+            DecisionFixture::clean("negated gate", module("#[cfg(not(test))]\nmod tests", long)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_suggested_file() {
+        let module = ModuleBodyProjection {
+            file: "src/driver.rs".to_owned(),
+            source: "#[cfg(test)]\nmod tests {\n\n}\n".to_owned(),
+        };
+        let decision = TestFileInSrcShouldUsePathAttributePatternDecision::new(2, "checks");
+        let finding = decision
+            .decide(&module)
+            .expect("the inline module should be reported");
+
+        assert_eq!(finding.subject(), Some("checks/driver.rs"));
+    }
+
+    #[rstest]
+    #[case::default_limit(TestFileInSrcShouldUsePathAttributePatternDecision::default())]
+    #[case::small_limit(TestFileInSrcShouldUsePathAttributePatternDecision::new(1, "tests"))]
+    fn decision_conforms(#[case] decision: TestFileInSrcShouldUsePathAttributePatternDecision) {
+        assert_eq!(check_conformance(&decision), Ok(4));
+    }
+}
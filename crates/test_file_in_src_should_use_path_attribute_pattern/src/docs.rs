@@ -0,0 +1,30 @@
+//! Embedded documentation for the
+//! `test_file_in_src_should_use_path_attribute_pattern` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `test_file_in_src_should_use_path_attribute_pattern`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "test_file_in_src_should_use_path_attribute_pattern",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `#[cfg(test)]` modules written inline over more lines than the configured limit, suggesting a file to load them from with `#[path]`.",
+    rationale: "Long inline test modules double the length of production files, so reviewers scroll past tests to read the code. Loading tests from a separate file keeps source files short and the code and its tests easy to tell apart.",
+    bad_example: "// src/driver.rs\npub fn run() { ... }\n\n#[cfg(test)]\nmod tests {\n    // 150 lines of tests\n}",
+    good_example: "// src/driver.rs\npub fn run() { ... }\n\n#[cfg(test)]\n#[path = \"tests/driver.rs\"]\nmod tests;",
+    config: &[
+        ConfigKeyDocs {
+            key: "max_inline_lines",
+            default: "100",
+            description: "Number of lines an inline test module may span before it should move to its own file.",
+        },
+        ConfigKeyDocs {
+            key: "test_directory",
+            default: "\"tests\"",
+            description: "Directory, relative to the declaring file, that suggested test files are placed in.",
+        },
+    ],
+};
@@ -0,0 +1,327 @@
+//! Lint pass reporting large `#[cfg(test)]` modules written inline.
+//!
+//! The source text of every module body, the crate root included, is handed
+//! to [`inline_test_modules`], because ordinary builds strip `#[cfg(test)]`
+//! modules before HIR exists. Each inline test module spanning more than
+//! `max_inline_lines` lines is reported at its header, with a file under
+//! `test_directory` named by [`suggested_path`]. Module bodies produced by
+//! macro expansion are skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::{BytePos, FileName, Pos, Span};
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::{InlineTestModule, inline_test_modules, suggested_path};
+
+pub(crate) const LINT_NAME: &str = "test_file_in_src_should_use_path_attribute_pattern";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_INLINE_LINES: usize = 100;
+pub(crate) const DEFAULT_TEST_DIRECTORY: &str = "tests";
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_inline_lines: usize,
+    test_directory: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_inline_lines: DEFAULT_MAX_INLINE_LINES,
+            test_directory: DEFAULT_TEST_DIRECTORY.to_owned(),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub TEST_FILE_IN_SRC_SHOULD_USE_PATH_ATTRIBUTE_PATTERN,
+    Warn,
+    "large `#[cfg(test)]` modules should live in their own file, loaded with `#[path]`",
+    TestFileInSrcShouldUsePathAttributePattern::default()
+}
+
+/// Lint pass that finds large test modules written inline.
+#[derive(Default)]
+pub struct TestFileInSrcShouldUsePathAttributePattern {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Configuration, finding limit, and localization for the crate being
+/// checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for TestFileInSrcShouldUsePathAttributePattern {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(
+                TEST_FILE_IN_SRC_SHOULD_USE_PATH_ATTRIBUTE_PATTERN,
+                &shared_config,
+            ),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_mod(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        module: &'tcx hir::Mod<'tcx>,
+        hir_id: hir::HirId,
+    ) {
+        let body = module.spans.inner_span;
+        if body.is_dummy() || body.from_expansion() {
+            return;
+        }
+        let source_map = cx.sess().source_map();
+        let Ok(source) = source_map.span_to_snippet(body) else {
+            return;
+        };
+        let state = &*self.state;
+        let file = declaring_file(cx, body);
+        let config = state.findings.config_at(cx, body, &state.config);
+
+        for inline in inline_test_modules(&source) {
+            if inline.lines <= config.max_inline_lines {
+                continue;
+            }
+            debug!(
+                target: LINT_NAME,
+                "test module `{}` spans {} lines inline",
+                inline.name,
+                inline.lines,
+            );
+            let span = header_span(body, &inline);
+            let node = test_module_hir_id(cx, module, &inline.name).unwrap_or(hir_id);
+            if state.findings.admit(cx, node, span) {
+                let message = InlineTestsMessage {
+                    module: &inline.name,
+                    lines: inline.lines,
+                    limit: config.max_inline_lines,
+                    path: &suggested_path(&config.test_directory, &file, &inline.name),
+                };
+                emit_diagnostic(cx, span, &message, &state.localizer);
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the local path of the file holding `body`, or an empty string
+/// for remapped and synthetic sources.
+fn declaring_file(cx: &LateContext<'_>, body: Span) -> String {
+    match &cx.sess().source_map().lookup_source_file(body.lo()).name {
+        FileName::Real(real) => real
+            .local_path()
+            .map(|path| path.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+/// Converts the byte range the policy found within `body` into a span.
+fn header_span(body: Span, inline: &InlineTestModule) -> Span {
+    let offset = |position: usize| body.lo() + BytePos::from_usize(position);
+    body.with_lo(offset(inline.header.start))
+        .with_hi(offset(inline.header.end))
+}
+
+/// Finds the test module called `name` among `module`'s items, which exist
+/// only when the crate is compiled for tests, so its lint levels apply.
+fn test_module_hir_id(
+    cx: &LateContext<'_>,
+    module: &hir::Mod<'_>,
+    name: &str,
+) -> Option<hir::HirId> {
+    module
+        .item_ids
+        .iter()
+        .map(|&item_id| cx.tcx.hir_item(item_id))
+        .find(
+            |item| matches!(item.kind, hir::ItemKind::Mod(ident, _) if ident.name.as_str() == name),
+        )
+        .map(hir::Item::hir_id)
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &InlineTestsMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    cx.emit_span_lint(
+        TEST_FILE_IN_SRC_SHOULD_USE_PATH_ATTRIBUTE_PATTERN,
+        span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+/// The values the diagnostic for an inline test module interpolates.
+#[derive(Clone, Copy, Debug)]
+struct InlineTestsMessage<'a> {
+    module: &'a str,
+    lines: usize,
+    limit: usize,
+    path: &'a str,
+}
+
+impl InlineTestsMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("module"),
+            FluentValue::from(self.module.to_owned()),
+        );
+        args.insert(Cow::Borrowed("lines"), FluentValue::from(self.lines));
+        args.insert(Cow::Borrowed("limit"), FluentValue::from(self.limit));
+        args.insert(
+            Cow::Borrowed("path"),
+            FluentValue::from(self.path.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            module,
+            lines,
+            limit,
+            path,
+        } = *self;
+        DiagnosticMessageSet::new(
+            format!(
+                "Test module `{module}` is written inline over {lines} lines, above the limit of \
+                 {limit}."
+            ),
+            String::from(
+                "Inline tests make production files longer to read and review, while a separate \
+                 file keeps the code and its tests apart.",
+            ),
+            format!(
+                "Move the body to `{path}` and declare it with `#[cfg(test)] #[path = \"{path}\"] \
+                 mod {module};`."
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn message() -> InlineTestsMessage<'static> {
+        InlineTestsMessage {
+            module: "tests",
+            lines: 140,
+            limit: 100,
+            path: "tests/driver.rs",
+        }
+    }
+
+    fn resolve(message: &InlineTestsMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_allows_a_hundred_lines_inline() {
+        let config = Config::default();
+
+        assert_eq!(config.max_inline_lines, 100);
+        assert_eq!(config.test_directory, "tests");
+    }
+
+    #[rstest]
+    fn fallback_messages_give_the_declaration(message: InlineTestsMessage<'static>) {
+        let messages = message.fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "Test module `tests` is written inline over 140 lines, above the limit of 100."
+        );
+        assert_eq!(
+            messages.help(),
+            "Move the body to `tests/driver.rs` and declare it with `#[cfg(test)] #[path = \
+             \"tests/driver.rs\"] mod tests;`."
+        );
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback(message: InlineTestsMessage<'static>) {
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str, message: InlineTestsMessage<'static>) {
+        let messages = resolve(&message, locale);
+
+        assert!(messages.primary().contains("`tests`"));
+        assert!(messages.help().contains("#[path = \"tests/driver.rs\"]"));
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,43 @@
+//! Lint crate moving large inline test modules into their own files.
+//!
+//! A long `#[cfg(test)] mod tests { ... }` block at the bottom of a source
+//! file doubles the length a reader scrolls through to review production
+//! code. The lint reports inline test modules spanning more than
+//! `max_inline_lines` lines and suggests a file under `test_directory` to
+//! load them from with `#[path]`, as in
+//! `#[cfg(test)] #[path = "tests/driver.rs"] mod tests;`. The lint is
+//! experimental and ships behind the
+//! `experimental-test-file-in-src-should-use-path-attribute-pattern` suite
+//! feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{ModuleBodyProjection, TestFileInSrcShouldUsePathAttributePatternDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn test_file_in_src_should_use_path_attribute_pattern_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
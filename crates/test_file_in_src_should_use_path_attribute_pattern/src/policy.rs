@@ -0,0 +1,300 @@
+//! Rules finding inline test modules and naming the files they move to.
+//!
+//! The driver hands each module body's source text to
+//! [`inline_test_modules`], which finds the `#[cfg(test)]` modules written
+//! inline among the body's own items. Source is tokenised with `rustc_lexer`
+//! because `#[cfg(test)]` items are removed before HIR is built in ordinary
+//! builds. [`suggested_path`] names the file the module could be loaded from.
+//! The rules are kept free of compiler types so they can be tested directly.
+
+use std::ops::Range;
+
+use rustc_lexer::{TokenKind, tokenize};
+
+/// Keywords that may appear in a visibility between attributes and `mod`.
+const VISIBILITY_WORDS: [&str; 5] = ["pub", "crate", "super", "self", "in"];
+
+/// A `#[cfg(test)]` module whose body is written inline.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct InlineTestModule {
+    /// The module name.
+    pub(crate) name: String,
+    /// Byte range of the header, from the visibility or `mod` keyword to the
+    /// end of the name.
+    pub(crate) header: Range<usize>,
+    /// Source lines the body spans, counting the lines holding its braces.
+    pub(crate) lines: usize,
+}
+
+/// A token other than whitespace or a comment, with its byte range.
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    start: usize,
+}
+
+/// Returns the `#[cfg(test)]` modules `source`, the text of a module body,
+/// declares inline at its top level.
+///
+/// Attributes, comments, and a visibility may sit between the `cfg`
+/// attribute and `mod`. Modules loaded from another file, modules nested
+/// inside other items, and `cfg` predicates that negate `test` are ignored.
+#[must_use]
+pub(crate) fn inline_test_modules(source: &str) -> Vec<InlineTestModule> {
+    let tokens = significant_tokens(source);
+    let mut modules = Vec::new();
+    let mut depth = 0_usize;
+    let mut gated = false;
+    let mut start = None;
+    let mut index = 0;
+    while let Some(&token) = tokens.get(index) {
+        match token.kind {
+            TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseBrace => depth = depth.saturating_sub(1),
+            _ if depth > 0 => {}
+            TokenKind::Pound if is_open_bracket(tokens.get(index + 1)) => {
+                let end = closing_bracket(&tokens, index + 1);
+                gated |= is_cfg_test(&tokens[index + 2..end]);
+                index = end + 1;
+                continue;
+            }
+            TokenKind::Ident if token.text == "mod" && gated => {
+                if let Some((module, next)) =
+                    inline_module(source, &tokens, index, start.unwrap_or(token.start))
+                {
+                    modules.push(module);
+                    index = next;
+                }
+            }
+            TokenKind::Ident if VISIBILITY_WORDS.contains(&token.text) => {
+                start.get_or_insert(token.start);
+                index += 1;
+                continue;
+            }
+            TokenKind::OpenParen | TokenKind::CloseParen | TokenKind::Colon if start.is_some() => {
+                index += 1;
+                continue;
+            }
+            _ => {}
+        }
+        gated = false;
+        start = None;
+        index += 1;
+    }
+    modules
+}
+
+/// Describes the module whose `mod` keyword is at `index` when its body is
+/// written inline, returning it with the index of its closing brace.
+fn inline_module(
+    source: &str,
+    tokens: &[Token<'_>],
+    index: usize,
+    start: usize,
+) -> Option<(InlineTestModule, usize)> {
+    let name = tokens
+        .get(index + 1)
+        .filter(|t| t.kind == TokenKind::Ident)?;
+    let open = tokens
+        .get(index + 2)
+        .filter(|t| t.kind == TokenKind::OpenBrace)?;
+    let close = closing_brace(tokens, index + 2);
+    let body_end = tokens.get(close).map_or(source.len(), |t| t.start + 1);
+    let body = &source[open.start..body_end];
+    let module = InlineTestModule {
+        name: name.text.to_owned(),
+        header: start..name.start + name.text.len(),
+        lines: body.matches('\n').count() + 1,
+    };
+    Some((module, close))
+}
+
+/// Returns the path, relative to the declaring file, that a test module
+/// called `module` could be loaded from.
+///
+/// The file is named after the module, except that modules called `tests`
+/// are named after the file declaring them, as in `tests/driver.rs`, so that
+/// each file's tests get their own file. A `mod.rs` file contributes the
+/// name of its directory.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(suggested_path("tests", "src/driver.rs", "tests"), "tests/driver.rs");
+/// assert_eq!(suggested_path("tests", "src/lib.rs", "parsing"), "tests/parsing.rs");
+/// ```
+#[must_use]
+pub(crate) fn suggested_path(directory: &str, declaring_file: &str, module: &str) -> String {
+    let stem = if module == "tests" {
+        file_stem(declaring_file).unwrap_or(module)
+    } else {
+        module
+    };
+    let directory = directory.trim_end_matches('/');
+    if directory.is_empty() {
+        format!("{stem}.rs")
+    } else {
+        format!("{directory}/{stem}.rs")
+    }
+}
+
+fn file_stem(path: &str) -> Option<&str> {
+    let mut components = path.rsplit(['/', '\\']);
+    let file = components.next()?;
+    let stem = file.strip_suffix(".rs").unwrap_or(file);
+    if stem == "mod" {
+        components.next().filter(|parent| !parent.is_empty())
+    } else {
+        Some(stem).filter(|stem| !stem.is_empty())
+    }
+}
+
+/// Tokens other than whitespace and comments, with their text and offset.
+fn significant_tokens(source: &str) -> Vec<Token<'_>> {
+    let mut offset = 0;
+    let mut tokens = Vec::new();
+    for token in tokenize(source) {
+        let end = offset + token.len;
+        if !matches!(
+            token.kind,
+            TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment { .. }
+        ) {
+            tokens.push(Token {
+                kind: token.kind,
+                text: &source[offset..end],
+                start: offset,
+            });
+        }
+        offset = end;
+    }
+    tokens
+}
+
+fn is_open_bracket(token: Option<&Token<'_>>) -> bool {
+    token.is_some_and(|token| token.kind == TokenKind::OpenBracket)
+}
+
+/// Returns the index of the delimiter closing the one at `open`, or the last
+/// index when it is unterminated.
+fn closing_delimiter(
+    tokens: &[Token<'_>],
+    open: usize,
+    opening: TokenKind,
+    closing: TokenKind,
+) -> usize {
+    let mut depth = 0_usize;
+    for (index, token) in tokens.iter().enumerate().skip(open) {
+        if token.kind == opening {
+            depth += 1;
+        } else if token.kind == closing {
+            depth -= 1;
+            if depth == 0 {
+                return index;
+            }
+        }
+    }
+    tokens.len().saturating_sub(1)
+}
+
+fn closing_bracket(tokens: &[Token<'_>], open: usize) -> usize {
+    closing_delimiter(
+        tokens,
+        open,
+        TokenKind::OpenBracket,
+        TokenKind::CloseBracket,
+    )
+}
+
+fn closing_brace(tokens: &[Token<'_>], open: usize) -> usize {
+    closing_delimiter(tokens, open, TokenKind::OpenBrace, TokenKind::CloseBrace)
+}
+
+/// Returns `true` for the contents of a `cfg` attribute whose predicate
+/// mentions `test` without negating it.
+fn is_cfg_test(attribute: &[Token<'_>]) -> bool {
+    let mut idents = attribute
+        .iter()
+        .filter(|token| token.kind == TokenKind::Ident)
+        .map(|token| token.text);
+    idents.next() == Some("cfg")
+        && attribute
+            .iter()
+            .any(|token| token.kind == TokenKind::Ident && token.text == "test")
+        && !idents.any(|text| text == "not")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn found(source: &str) -> Vec<(String, &str, usize)> {
+        inline_test_modules(source)
+            .into_iter()
+            .map(|module| {
+                let header = &source[module.header.clone()];
+                (module.name, header, module.lines)
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case::plain(
+        "fn run() {}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn runs() {}\n}\n",
+        "mod tests",
+        4
+    )]
+    #[case::documented(
+        "#[cfg(test)]\n/// Unit tests.\npub(crate) mod tests {}\n",
+        "pub(crate) mod tests",
+        1
+    )]
+    #[case::combined(
+        "#[cfg(all(test, unix))]\n#[allow(unused)]\nmod unix_tests {\n}\n",
+        "mod unix_tests",
+        2
+    )]
+    fn finds_inline_test_modules(#[case] source: &str, #[case] header: &str, #[case] lines: usize) {
+        let modules = found(source);
+
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules[0].1, header);
+        assert_eq!(modules[0].2, lines);
+    }
+
+    #[rstest]
+    fn finds_each_top_level_module() {
+        let source = "#[cfg(test)]\nmod a {}\nfn b() {}\n#[cfg(test)]\nmod c {\n    #[cfg(test)]\n    mod d {}\n}\n";
+        let names: Vec<String> = found(source).into_iter().map(|(name, ..)| name).collect();
+
+        assert_eq!(names, ["a", "c"]);
+    }
+
+    #[rstest]
+    #[case::file_backed("#[cfg(test)]\n#[path = \"tests/driver.rs\"]\nmod tests;\n")]
+    #[case::ungated("mod tests {}\n")]
+    #[case::negated("#[cfg(not(test))]\nmod fallback {}\n")]
+    #[case::gated_function("#[cfg(test)]\nfn helper() {}\nmod parser {}\n")]
+    #[case::nested("fn run() {\n    #[cfg(test)]\n    mod tests {}\n}\n")]
+    #[case::comment("// #[cfg(test)]\n// mod tests {}\nfn run() {}\n")]
+    #[case::string("const DOC: &str = \"#[cfg(test)] mod tests {}\";\n")]
+    fn ignores_other_modules(#[case] source: &str) {
+        assert!(inline_test_modules(source).is_empty());
+    }
+
+    #[rstest]
+    #[case("tests", "src/driver.rs", "tests", "tests/driver.rs")]
+    #[case("tests/", "src/lib.rs", "parsing", "tests/parsing.rs")]
+    #[case("tests", "src/config/mod.rs", "tests", "tests/config.rs")]
+    #[case("", "src/driver.rs", "tests", "driver.rs")]
+    #[case("unit", "", "tests", "unit/tests.rs")]
+    fn paths_name_the_module_or_its_file(
+        #[case] directory: &str,
+        #[case] file: &str,
+        #[case] module: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(suggested_path(directory, file, module), expected);
+    }
+}
@@ -0,0 +1,92 @@
+//! Behaviour-driven coverage for finding inline test modules.
+
+use crate::policy::{inline_test_modules, suggested_path};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+#[derive(Default)]
+struct InlineTestsWorld {
+    limit: Cell<usize>,
+    source: RefCell<String>,
+    file: RefCell<String>,
+    reported: RefCell<Option<Vec<String>>>,
+}
+
+#[fixture]
+fn world() -> InlineTestsWorld {
+    InlineTestsWorld::default()
+}
+
+fn unquote(value: &str) -> String {
+    value.trim_matches('"').to_owned()
+}
+
+#[given("a limit of {limit} lines")]
+fn given_limit(world: &InlineTestsWorld, limit: usize) {
+    world.limit.set(limit);
+}
+
+#[given("an inline test module {name} spanning {lines} lines in {file}")]
+fn given_inline_module(world: &InlineTestsWorld, name: String, lines: usize, file: String) {
+    let body = "    fn case() {}\n".repeat(lines.saturating_sub(2));
+    *world.source.borrow_mut() = format!(
+        "pub fn run() {{}}\n\n#[cfg(test)]\nmod {} {{\n{body}}}\n",
+        unquote(&name)
+    );
+    *world.file.borrow_mut() = unquote(&file);
+}
+
+#[given("a test module {name} loaded from {path}")]
+fn given_loaded_module(world: &InlineTestsWorld, name: String, path: String) {
+    *world.source.borrow_mut() = format!(
+        "pub fn run() {{}}\n\n#[cfg(test)]\n#[path = \"{}\"]\nmod {};\n",
+        unquote(&path),
+        unquote(&name)
+    );
+}
+
+#[when("the file is checked")]
+fn when_checked(world: &InlineTestsWorld) {
+    let file = world.file.borrow();
+    let reported = inline_test_modules(&world.source.borrow())
+        .into_iter()
+        .filter(|module| module.lines > world.limit.get())
+        .map(|module| suggested_path("tests", &file, &module.name))
+        .collect();
+    *world.reported.borrow_mut() = Some(reported);
+}
+
+#[then("the module is reported with the path {path}")]
+fn then_reported(world: &InlineTestsWorld, path: String) {
+    assert_eq!(*world.reported.borrow(), Some(vec![unquote(&path)]));
+}
+
+#[then("nothing is reported")]
+fn then_nothing(world: &InlineTestsWorld) {
+    assert_eq!(*world.reported.borrow(), Some(Vec::new()));
+}
+
+#[scenario(
+    path = "tests/features/test_file_in_src_should_use_path_attribute_pattern.feature",
+    index = 0
+)]
+fn scenario_long_inline_module(world: InlineTestsWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/test_file_in_src_should_use_path_attribute_pattern.feature",
+    index = 1
+)]
+fn scenario_short_inline_module(world: InlineTestsWorld) {
+    let _ = world;
+}
+
+#[scenario(
+    path = "tests/features/test_file_in_src_should_use_path_attribute_pattern.feature",
+    index = 2
+)]
+fn scenario_loaded_module(world: InlineTestsWorld) {
+    let _ = world;
+}
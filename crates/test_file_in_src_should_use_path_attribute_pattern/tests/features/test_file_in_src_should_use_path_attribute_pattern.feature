@@ -0,0 +1,21 @@
+Feature: Inline test modules
+  `#[cfg(test)]` modules written inline are reported once their body spans
+  more lines than the configured limit, with the file they could move to.
+
+  Scenario: A long inline test module is reported
+    Given a limit of 5 lines
+    And an inline test module "tests" spanning 8 lines in "src/driver.rs"
+    When the file is checked
+    Then the module is reported with the path "tests/driver.rs"
+
+  Scenario: A short inline test module is accepted
+    Given a limit of 5 lines
+    And an inline test module "tests" spanning 4 lines in "src/driver.rs"
+    When the file is checked
+    Then nothing is reported
+
+  Scenario: A test module loaded with a path attribute is accepted
+    Given a limit of 5 lines
+    And a test module "tests" loaded from "tests/driver.rs"
+    When the file is checked
+    Then nothing is reported
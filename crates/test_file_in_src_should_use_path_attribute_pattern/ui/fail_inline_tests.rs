@@ -0,0 +1,19 @@
+//! Negative UI fixture: an inline test module above the configured limit.
+
+pub fn double(value: u32) -> u32 {
+    value * 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::double;
+
+    #[test]
+    fn doubles() {
+        assert_eq!(double(2), 4);
+    }
+}
+
+fn main() {
+    let _ = double(1);
+}
@@ -0,0 +1,24 @@
+//! Positive UI fixture: inline test modules within the default limit and
+//! test modules loaded with `#[path]`.
+
+pub fn double(value: u32) -> u32 {
+    value * 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::double;
+
+    #[test]
+    fn doubles() {
+        assert_eq!(double(2), 4);
+    }
+}
+
+#[cfg(test)]
+#[path = "support/double_tests.rs"]
+mod double_tests;
+
+fn main() {
+    let _ = double(1);
+}
@@ -0,0 +1,9 @@
+//! Tests for `double`, loaded by `pass_short_inline_tests.rs` through
+//! `#[path]`.
+
+use super::double;
+
+#[test]
+fn doubles_zero() {
+    assert_eq!(double(0), 0);
+}
@@ -0,0 +1,134 @@
+//! Pure decision logic for `test_must_not_have_example`.
+//!
+//! The driver gathers each function's outer attribute paths and its
+//! documentation text. [`TestMustNotHaveExampleDecision`] recognises tests
+//! through [`has_test_like_attribute_with`] and hands their documentation to
+//! [`detect_example_violation`], using the violation as the finding's reason
+//! and naming the function as its subject. Harness-marked tests without a
+//! visible attribute are left to the driver.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+use whitaker_common::{Attribute, AttributeKind, AttributePath, has_test_like_attribute_with};
+
+use crate::driver::LINT_NAME;
+use crate::heuristics::{DocExampleViolation, detect_example_violation};
+
+/// The facts about one function that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DocumentedFunctionProjection {
+    /// The function's name.
+    pub name: String,
+    /// The paths of the function's outer attributes, such as `test`.
+    pub attributes: Vec<String>,
+    /// The function's documentation text, one line per doc comment.
+    pub docs: String,
+}
+
+/// Reports test functions whose documentation holds examples.
+///
+/// # Examples
+///
+/// ```ignore
+/// let function = DocumentedFunctionProjection {
+///     name: "parses_input".to_owned(),
+///     attributes: vec!["test".to_owned()],
+///     docs: "# Examples".to_owned(),
+/// };
+/// let decision = TestMustNotHaveExampleDecision::default();
+/// assert!(decision.decide(&function).is_some());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TestMustNotHaveExampleDecision {
+    additional_test_attributes: Vec<AttributePath>,
+}
+
+impl TestMustNotHaveExampleDecision {
+    /// Creates a decision that also treats `additional_test_attributes` as
+    /// test markers.
+    #[must_use]
+    pub fn new(additional_test_attributes: &[String]) -> Self {
+        Self {
+            additional_test_attributes: additional_test_attributes
+                .iter()
+                .map(|path| AttributePath::from(path.as_str()))
+                .collect(),
+        }
+    }
+}
+
+impl LintDecision for TestMustNotHaveExampleDecision {
+    type Projection = DocumentedFunctionProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, function: &DocumentedFunctionProjection) -> Option<Finding> {
+        let attributes: Vec<Attribute> = function
+            .attributes
+            .iter()
+            .map(|path| Attribute::new(AttributePath::from(path.as_str()), AttributeKind::Outer))
+            .collect();
+        if !has_test_like_attribute_with(&attributes, &self.additional_test_attributes) {
+            return None;
+        }
+        let reason = match detect_example_violation(&function.docs)? {
+            DocExampleViolation::ExamplesHeading => "examples_heading",
+            DocExampleViolation::CodeFence => "code_fence",
+        };
+        Some(Finding::new(LINT_NAME, reason).with_subject(function.name.as_str()))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<DocumentedFunctionProjection>> {
+        let function = |attribute: &str, docs: &str| DocumentedFunctionProjection {
+            name: "parses_input".to_owned(),
+            attributes: vec![attribute.to_owned()],
+            docs: docs.to_owned(),
+        };
+        let mut fixtures = vec![
+            DecisionFixture::flagged(
+                "examples heading",
+                function("test", "Checks parsing.\n# Examples"),
+                "examples_heading",
+            ),
+            DecisionFixture::flagged(
+                "code fence",
+                function("test", "Checks parsing.\n```\nparse(\"1\");\n```"),
+                "code_fence",
+            ),
+            DecisionFixture::clean("prose", function("test", "Checks parsing.")),
+            DecisionFixture::clean(
+                "not a test",
+                function("inline", "Parses input.\n# Examples"),
+            ),
+        ];
+        fixtures.extend(self.additional_test_attributes.first().map(|marker| {
+            DecisionFixture::flagged(
+                "custom test attribute",
+                function(&marker.to_string(), "# Examples"),
+                "examples_heading",
+            )
+        }));
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::defaults(TestMustNotHaveExampleDecision::default(), 4)]
+    #[case::custom_test_attribute(
+        TestMustNotHaveExampleDecision::new(&["tokio::test".to_owned()]),
+        5
+    )]
+    fn decision_conforms(
+        #[case] decision: TestMustNotHaveExampleDecision,
+        #[case] fixtures: usize,
+    ) {
+        assert_eq!(check_conformance(&decision), Ok(fixtures));
+    }
+}
@@ -0,0 +1,32 @@
+//! Embedded documentation for the `test_must_not_have_example` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `test_must_not_have_example`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "test_must_not_have_example",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Test functions should not include examples or fenced code in their documentation.",
+    rationale: "Examples on tests are never rendered or run as documentation, so they drift from the code and mislead readers.",
+    bad_example: concat!(
+        "/// # Examples\n",
+        "/// ```\n",
+        "/// assert!(parse(\"1\").is_ok());\n",
+        "/// ```\n",
+        "#[test]\n",
+        "fn parses_numbers() {}",
+    ),
+    good_example: concat!(
+        "/// Parsing accepts plain decimal integers.\n",
+        "#[test]\n",
+        "fn parses_numbers() {}",
+    ),
+    config: &[ConfigKeyDocs {
+        key: "additional_test_attributes",
+        default: "[]",
+        description: "Extra attribute paths that mark a function as a test.",
+    }],
+};
@@ -9,14 +9,14 @@ use rustc_span::{Ident, Span, Symbol};
 use serde::Deserialize;
 use std::borrow::Cow;
 use whitaker::SharedConfig;
-use whitaker::hir::has_test_like_hir_attributes;
-use whitaker_common::AttributePath;
+use whitaker::hir::{FindingLimit, has_test_like_hir_attributes};
 use whitaker_common::i18n::{
     Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
     get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
 };
+use whitaker_common::{AttributePath, CrateScopedState};
 
-const LINT_NAME: &str = "test_must_not_have_example";
+pub(crate) const LINT_NAME: &str = "test_must_not_have_example";
 const MESSAGE_KEY: MessageKey<'static> = MessageKey::new("test_must_not_have_example");
 
 #[derive(Default, Deserialize)]
@@ -33,9 +33,18 @@ dylint_linting::impl_late_lint! {
 }
 
 /// Lint pass that checks test documentation for example sections.
+#[derive(Default)]
 pub struct TestMustNotHaveExample {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Test attributes and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
     /// Additional attribute paths configured as test-like markers.
     additional_test_attributes: Vec<AttributePath>,
+    /// Cap on the diagnostics emitted for the crate.
+    findings: FindingLimit,
     /// Localized message resolver used for emitted diagnostics.
     localizer: Localizer,
 }
@@ -96,15 +105,6 @@ macro_rules! impl_check_method {
     };
 }
 
-impl Default for TestMustNotHaveExample {
-    fn default() -> Self {
-        Self {
-            additional_test_attributes: Vec::new(),
-            localizer: Localizer::new(None),
-        }
-    }
-}
-
 impl<'tcx> LateLintPass<'tcx> for TestMustNotHaveExample {
     fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
         let config = match dylint_linting::config::<Config>(LINT_NAME) {
@@ -120,14 +120,20 @@ impl<'tcx> LateLintPass<'tcx> for TestMustNotHaveExample {
             }
         };
 
-        self.additional_test_attributes = config
-            .additional_test_attributes
-            .iter()
-            .map(|path| AttributePath::from(path.as_str()))
-            .collect();
-
         let shared_config = SharedConfig::load();
-        self.localizer = get_localizer_for_lint(LINT_NAME, shared_config.locale());
+        self.state.reset(CrateState {
+            additional_test_attributes: config
+                .additional_test_attributes
+                .iter()
+                .map(|path| AttributePath::from(path.as_str()))
+                .collect(),
+            findings: FindingLimit::new(TEST_MUST_NOT_HAVE_EXAMPLE, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
@@ -178,7 +184,7 @@ impl TestMustNotHaveExample {
         function: FunctionSite<'_>,
         violation: DocExampleViolation,
     ) {
-        let messages = localized_messages(&self.localizer, function.name, violation);
+        let messages = localized_messages(&self.state.localizer, function.name, violation);
         let primary = messages.primary().to_string();
         let note = messages.note().to_string();
         let help = messages.help().to_string();
@@ -202,12 +208,22 @@ impl TestMustNotHaveExample {
     ) {
         let attrs = item_info.attrs();
         let is_test = if let Some(item) = item {
-            is_test_function_item(cx, item, attrs, self.additional_test_attributes.as_slice())
+            is_test_function_item(
+                cx,
+                item,
+                attrs,
+                self.state.additional_test_attributes.as_slice(),
+            )
         } else {
-            has_test_like_hir_attributes(attrs, self.additional_test_attributes.as_slice())
+            has_test_like_hir_attributes(attrs, self.state.additional_test_attributes.as_slice())
         };
 
-        if let Some(violation) = self.detect_violation(attrs, is_test) {
+        if let Some(violation) = self.detect_violation(attrs, is_test)
+            && self
+                .state
+                .findings
+                .admit(cx, cx.last_node_with_lint_attrs, item_info.ident().span)
+        {
             self.emit_violation(
                 cx,
                 FunctionSite {
@@ -4,6 +4,8 @@
 #[cfg(all(feature = "dylint-driver", test))]
 mod behaviour;
 #[cfg(feature = "dylint-driver")]
+mod decision;
+#[cfg(feature = "dylint-driver")]
 mod driver;
 #[cfg(feature = "dylint-driver")]
 mod heuristics;
@@ -11,9 +13,18 @@ mod heuristics;
 #[path = "lib_ui_tests.rs"]
 mod ui;
 
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
 #[cfg(feature = "dylint-driver")]
 pub use driver::*;
 
+#[cfg(feature = "dylint-driver")]
+pub use decision::{DocumentedFunctionProjection, TestMustNotHaveExampleDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
 #[cfg(not(feature = "dylint-driver"))]
 mod stub {
     //! Stub exports used when the lint driver feature is disabled.
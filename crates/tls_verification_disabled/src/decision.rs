@@ -0,0 +1,155 @@
+//! Pure decision logic for `tls_verification_disabled`.
+//!
+//! The driver resolves each method call, reads a `bool` literal first
+//! argument, and checks whether the call sits in test code.
+//! [`TlsVerificationDisabledDecision`] looks the method up among the same
+//! [`DisablingCall`] patterns and reports the call when [`should_flag`]
+//! does, naming the method as the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_PATTERNS, LINT_NAME};
+use crate::policy::{DisablingCall, TlsCall, should_flag};
+
+/// Reason key for calls that switch verification off.
+const DISABLED_VERIFICATION: &str = "disabled_verification";
+
+/// The facts about one method call that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TlsCallProjection {
+    /// The fully qualified method path, without generic arguments.
+    pub method: String,
+    /// The `bool` literal passed as the first argument, if it is one.
+    pub argument: Option<bool>,
+    /// Whether the call sits in test code.
+    pub in_test: bool,
+}
+
+/// Reports calls that disable TLS certificate or hostname verification.
+///
+/// # Examples
+///
+/// ```ignore
+/// let call = TlsCallProjection {
+///     method: "curl::easy::Easy::ssl_verify_peer".to_owned(),
+///     argument: Some(false),
+///     in_test: false,
+/// };
+/// assert!(TlsVerificationDisabledDecision::default().decide(&call).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TlsVerificationDisabledDecision {
+    patterns: Vec<DisablingCall>,
+}
+
+impl TlsVerificationDisabledDecision {
+    /// Creates a decision from `(method, argument)` patterns, where the
+    /// argument is the literal that disables verification, or `None` when
+    /// any call to the method does.
+    #[must_use]
+    pub fn new(patterns: impl IntoIterator<Item = (String, Option<bool>)>) -> Self {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|(method, argument)| DisablingCall::new(&method, argument))
+                .collect(),
+        }
+    }
+}
+
+impl Default for TlsVerificationDisabledDecision {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_PATTERNS
+                .iter()
+                .map(|&(method, argument)| (method.to_owned(), Some(argument))),
+        )
+    }
+}
+
+impl LintDecision for TlsVerificationDisabledDecision {
+    type Projection = TlsCallProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, call: &TlsCallProjection) -> Option<Finding> {
+        let pattern = self
+            .patterns
+            .iter()
+            .find(|pattern| pattern.method == call.method)?;
+        let facts = TlsCall {
+            argument: call.argument,
+            in_test: call.in_test,
+        };
+        should_flag(pattern, &facts).then(|| {
+            Finding::new(LINT_NAME, DISABLED_VERIFICATION).with_subject(call.method.as_str())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<TlsCallProjection>> {
+        let Some(pattern) = self.patterns.first() else {
+            return Vec::new();
+        };
+        let disabling = pattern.argument.unwrap_or(true);
+        let call = |argument, in_test| TlsCallProjection {
+            method: pattern.method.clone(),
+            argument,
+            in_test,
+        };
+        let mut fixtures = vec![
+            DecisionFixture::flagged(
+                "disabling literal",
+                call(Some(disabling), false),
+                DISABLED_VERIFICATION,
+            ),
+            DecisionFixture::clean("in a test", call(Some(disabling), true)),
+            DecisionFixture::clean(
+                "unconfigured method",
+                TlsCallProjection {
+                    method: "app::Client::verify".to_owned(),
+                    ..call(Some(disabling), false)
+                },
+            ),
+        ];
+        if pattern.argument.is_some() {
+            fixtures.push(DecisionFixture::clean(
+                "enabling literal",
+                call(Some(!disabling), false),
+            ));
+            fixtures.push(DecisionFixture::clean(
+                "runtime argument",
+                call(None, false),
+            ));
+        }
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_patterns(TlsVerificationDisabledDecision::default(), 5)]
+    #[case::curl(
+        TlsVerificationDisabledDecision::new([(
+            "curl::easy::Easy::ssl_verify_host".to_owned(),
+            Some(false),
+        )]),
+        5
+    )]
+    #[case::any_argument(
+        TlsVerificationDisabledDecision::new([(
+            "app::Client::insecure".to_owned(),
+            None,
+        )]),
+        3
+    )]
+    fn decision_conforms(#[case] decision: TlsVerificationDisabledDecision, #[case] count: usize) {
+        assert_eq!(check_conformance(&decision), Ok(count));
+    }
+}
@@ -0,0 +1,29 @@
+//! Embedded documentation for the `tls_verification_disabled` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for
+/// `tls_verification_disabled`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "tls_verification_disabled",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Denies calls such as `danger_accept_invalid_certs(true)` or `ssl_verify_peer(false)` that disable TLS verification outside tests.",
+    rationale: "A client that skips certificate or hostname checks accepts any server, so anyone on the network path can impersonate it and read or alter the traffic. Such switches are convenient against a local test server but must never reach production.",
+    bad_example: "let client = reqwest::Client::builder()\n    .danger_accept_invalid_certs(true)\n    .build()?;",
+    good_example: "let client = reqwest::Client::builder()\n    .add_root_certificate(internal_ca)\n    .build()?;",
+    config: &[
+        ConfigKeyDocs {
+            key: "patterns",
+            default: "`danger_accept_invalid_certs(true)` and `danger_accept_invalid_hostnames(true)` on the `reqwest` and `native_tls` builders, and `ssl_verify_peer(false)` and `ssl_verify_host(false)` on `curl::easy::Easy`",
+            description: "Tables of a fully qualified `method` and the optional `bool` `argument` that disables verification; a pattern without `argument` reports every call.",
+        },
+        ConfigKeyDocs {
+            key: "additional_test_attributes",
+            default: "[]",
+            description: "Extra attribute paths that mark a function as a test.",
+        },
+    ],
+};
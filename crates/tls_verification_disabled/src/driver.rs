@@ -0,0 +1,359 @@
+//! Lint pass denying calls that disable TLS verification outside tests.
+//!
+//! Each method call is resolved and its path, without generic arguments and
+//! qualified by the crate name when local, compared with the configured
+//! [`DisablingCall`] patterns. A `bool` literal passed as the first argument
+//! is handed to [`should_flag`] together with whether the call sits in test
+//! code, as recognized by [`whitaker::hir::TestContext`]. Calls produced by
+//! macro expansion are skipped. The finding spans the method name and its
+//! arguments.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_span::Span;
+use serde::Deserialize;
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, TestContext};
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+use whitaker_common::{AttributePath, CrateScopedState};
+
+use crate::policy::{DisablingCall, TlsCall, should_flag, strip_generic_args};
+
+pub(crate) const LINT_NAME: &str = "tls_verification_disabled";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_PATTERNS: &[(&str, bool)] = &[
+    ("reqwest::ClientBuilder::danger_accept_invalid_certs", true),
+    (
+        "reqwest::ClientBuilder::danger_accept_invalid_hostnames",
+        true,
+    ),
+    (
+        "reqwest::blocking::ClientBuilder::danger_accept_invalid_certs",
+        true,
+    ),
+    (
+        "reqwest::blocking::ClientBuilder::danger_accept_invalid_hostnames",
+        true,
+    ),
+    (
+        "native_tls::TlsConnectorBuilder::danger_accept_invalid_certs",
+        true,
+    ),
+    (
+        "native_tls::TlsConnectorBuilder::danger_accept_invalid_hostnames",
+        true,
+    ),
+    ("curl::easy::Easy::ssl_verify_peer", false),
+    ("curl::easy::Easy::ssl_verify_host", false),
+];
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    patterns: Vec<DisablingCall>,
+    additional_test_attributes: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_PATTERNS
+                .iter()
+                .map(|&(method, argument)| DisablingCall::new(method, Some(argument)))
+                .collect(),
+            additional_test_attributes: Vec::new(),
+        }
+    }
+}
+
+dylint_linting::impl_late_lint! {
+    pub TLS_VERIFICATION_DISABLED,
+    Deny,
+    "disabling TLS certificate or hostname verification outside tests",
+    TlsVerificationDisabled::default()
+}
+
+/// Lint pass that finds calls switching off TLS verification.
+#[derive(Default)]
+pub struct TlsVerificationDisabled {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Patterns, test context, finding limit, and localization for the crate
+/// being checked.
+#[derive(Default)]
+struct CrateState {
+    patterns: Vec<DisablingCall>,
+    test_context: TestContext,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+impl<'tcx> LateLintPass<'tcx> for TlsVerificationDisabled {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let config = load_configuration();
+        let additional = config
+            .additional_test_attributes
+            .iter()
+            .map(|path| AttributePath::from(path.as_str()))
+            .collect();
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            patterns: config.patterns,
+            test_context: TestContext::for_crate(cx, additional),
+            findings: FindingLimit::new(TLS_VERIFICATION_DISABLED, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::MethodCall(segment, _, args, _) = expr.kind else {
+            return;
+        };
+        if expr.span.from_expansion() {
+            return;
+        }
+        let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+            return;
+        };
+        let method = method_path(cx, def_id);
+        let state = &*self.state;
+        let Some(pattern) = state
+            .patterns
+            .iter()
+            .find(|pattern| pattern.method == method)
+        else {
+            return;
+        };
+        let argument = args.first().and_then(bool_literal);
+        let call = TlsCall {
+            argument,
+            in_test: state.test_context.is_test(cx, expr.hir_id),
+        };
+        if !should_flag(pattern, &call) {
+            return;
+        }
+
+        let shown = match (argument, args) {
+            (_, []) => format!("{}()", segment.ident),
+            (Some(value), [_]) => format!("{}({value})", segment.ident),
+            _ => format!("{}(..)", segment.ident),
+        };
+        debug!(target: LINT_NAME, "`{method}` disables TLS verification");
+        let span = expr.span.with_lo(segment.ident.span.lo());
+        if state.findings.admit(cx, expr.hir_id, span) {
+            emit_diagnostic(
+                cx,
+                span,
+                &DisabledMessage { call: &shown },
+                &state.localizer,
+            );
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the path of `def_id` without generic arguments, qualified with
+/// the crate name when the method is local.
+fn method_path(cx: &LateContext<'_>, def_id: DefId) -> String {
+    let path = strip_generic_args(&cx.tcx.def_path_str(def_id));
+    if def_id.is_local() {
+        format!("{}::{path}", cx.tcx.crate_name(LOCAL_CRATE))
+    } else {
+        path
+    }
+}
+
+/// Returns the `bool` literal `expr` holds.
+fn bool_literal(expr: &hir::Expr<'_>) -> Option<bool> {
+    let hir::ExprKind::Lit(lit) = expr.kind else {
+        return None;
+    };
+    match lit.node {
+        LitKind::Bool(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &DisabledMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        TLS_VERIFICATION_DISABLED,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a disabling call interpolates.
+#[derive(Clone, Copy, Debug)]
+struct DisabledMessage<'a> {
+    call: &'a str,
+}
+
+impl DisabledMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("call"),
+            FluentValue::from(self.call.to_owned()),
+        );
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let call = self.call;
+        DiagnosticMessageSet::new(
+            format!("`{call}` disables TLS verification outside tests."),
+            String::from(
+                "Without certificate and hostname checks, anyone on the network path can \
+                 impersonate the server and read or alter the traffic.",
+            ),
+            String::from(
+                "Remove the call. To trust a private or self-signed certificate, add it as a \
+                 root certificate instead, or confine the call to test code.",
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: DisabledMessage<'static> = DisabledMessage {
+        call: "danger_accept_invalid_certs(true)",
+    };
+
+    fn resolve(message: &DisabledMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    #[case::reqwest("reqwest::ClientBuilder::danger_accept_invalid_certs", true)]
+    #[case::native_tls(
+        "native_tls::TlsConnectorBuilder::danger_accept_invalid_hostnames",
+        true
+    )]
+    #[case::curl("curl::easy::Easy::ssl_verify_peer", false)]
+    fn default_config_covers_common_clients(#[case] method: &str, #[case] argument: bool) {
+        assert!(
+            Config::default()
+                .patterns
+                .contains(&DisablingCall::new(method, Some(argument)))
+        );
+    }
+
+    #[rstest]
+    fn configured_patterns_replace_the_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+            patterns = [
+                { method = "my_http::Builder::ssl_verify", argument = false },
+                { method = "my_http::Builder::insecure" },
+            ]
+            "#,
+        )
+        .expect("patterns should parse");
+
+        assert_eq!(
+            config.patterns,
+            [
+                DisablingCall::new("my_http::Builder::ssl_verify", Some(false)),
+                DisablingCall::new("my_http::Builder::insecure", None),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_call() {
+        let messages = MESSAGE.fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "`danger_accept_invalid_certs(true)` disables TLS verification outside tests."
+        );
+        assert!(messages.help().contains("root certificate"));
+    }
+
+    #[rstest]
+    fn english_messages_match_the_fallback() {
+        assert_eq!(resolve(&MESSAGE, "en-GB"), MESSAGE.fallback_messages());
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(
+            messages
+                .primary()
+                .contains("`danger_accept_invalid_certs(true)`")
+        );
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
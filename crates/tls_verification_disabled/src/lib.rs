@@ -0,0 +1,40 @@
+//! Lint crate denying disabled TLS verification outside tests.
+//!
+//! Builder calls such as `danger_accept_invalid_certs(true)` or
+//! `ssl_verify_peer(false)` switch off certificate or hostname checks, which
+//! lets anyone on the network path impersonate the server. They are handy
+//! against a local test server but should never reach production, so the lint
+//! denies them everywhere except test code. The method and argument patterns
+//! are configurable.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{TlsCallProjection, TlsVerificationDisabledDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn tls_verification_disabled_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
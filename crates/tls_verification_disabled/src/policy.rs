@@ -0,0 +1,135 @@
+//! Matching calls against the configured verification-disabling patterns.
+//!
+//! A [`DisablingCall`] names a method and, optionally, the literal argument
+//! that switches verification off. The driver resolves each method call and
+//! hands the literal it was given, if any, to [`should_flag`], which keeps the
+//! decision free of compiler types so it can be tested directly.
+
+use serde::Deserialize;
+
+/// A method whose call, with the given argument, disables TLS verification.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DisablingCall {
+    /// Fully qualified method path, such as
+    /// `reqwest::ClientBuilder::danger_accept_invalid_certs`.
+    pub(crate) method: String,
+    /// The `bool` literal that disables verification, or `None` when any call
+    /// to the method does.
+    #[serde(default)]
+    pub(crate) argument: Option<bool>,
+}
+
+impl DisablingCall {
+    /// Builds a pattern for `method` called with `argument`.
+    #[must_use]
+    pub(crate) fn new(method: &str, argument: Option<bool>) -> Self {
+        Self {
+            method: method.to_owned(),
+            argument,
+        }
+    }
+}
+
+/// A resolved call to a configured method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct TlsCall {
+    /// The `bool` literal passed as the first argument, if it is one.
+    pub(crate) argument: Option<bool>,
+    /// Whether the call sits in test code.
+    pub(crate) in_test: bool,
+}
+
+/// Decides whether `call` to the method of `pattern` is reported.
+///
+/// Calls in tests are accepted. Otherwise the call is reported when the
+/// pattern takes any argument, or when the call passes the disabling literal.
+/// A non-literal argument is not reported, because its value is unknown.
+///
+/// # Examples
+///
+/// ```ignore
+/// let pattern = DisablingCall::new("reqwest::ClientBuilder::danger_accept_invalid_certs", Some(true));
+/// assert!(should_flag(&pattern, &TlsCall { argument: Some(true), in_test: false }));
+/// assert!(!should_flag(&pattern, &TlsCall { argument: Some(false), in_test: false }));
+/// ```
+#[must_use]
+pub(crate) fn should_flag(pattern: &DisablingCall, call: &TlsCall) -> bool {
+    !call.in_test
+        && pattern
+            .argument
+            .is_none_or(|disabling| call.argument == Some(disabling))
+}
+
+/// Removes generic arguments from a resolved item path, so
+/// `curl::easy::Easy2::<H>::ssl_verify_peer` compares equal to the configured
+/// `curl::easy::Easy2::ssl_verify_peer`.
+#[must_use]
+pub(crate) fn strip_generic_args(path: &str) -> String {
+    let mut stripped = String::with_capacity(path.len());
+    let mut depth = 0_usize;
+    for character in path.chars() {
+        match character {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => stripped.push(character),
+            _ => {}
+        }
+    }
+    stripped
+        .split("::")
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const CERTS: &str = "reqwest::ClientBuilder::danger_accept_invalid_certs";
+
+    fn call(argument: Option<bool>, in_test: bool) -> TlsCall {
+        TlsCall { argument, in_test }
+    }
+
+    #[rstest]
+    #[case::disabling_literal(Some(true), Some(true), true)]
+    #[case::enabling_literal(Some(true), Some(false), false)]
+    #[case::runtime_value(Some(true), None, false)]
+    #[case::inverted_pattern(Some(false), Some(false), true)]
+    #[case::any_argument(None, None, true)]
+    #[case::any_argument_with_literal(None, Some(false), true)]
+    fn calls_outside_tests_follow_the_pattern(
+        #[case] pattern: Option<bool>,
+        #[case] argument: Option<bool>,
+        #[case] expected: bool,
+    ) {
+        let pattern = DisablingCall::new(CERTS, pattern);
+
+        assert_eq!(should_flag(&pattern, &call(argument, false)), expected);
+    }
+
+    #[rstest]
+    #[case::literal(Some(true))]
+    #[case::any(None)]
+    fn calls_in_tests_are_accepted(#[case] pattern: Option<bool>) {
+        let pattern = DisablingCall::new(CERTS, pattern);
+
+        assert!(!should_flag(&pattern, &call(Some(true), true)));
+    }
+
+    #[rstest]
+    #[case::plain(
+        "reqwest::ClientBuilder::https_only",
+        "reqwest::ClientBuilder::https_only"
+    )]
+    #[case::generic(
+        "curl::easy::Easy2::<H>::ssl_verify_peer",
+        "curl::easy::Easy2::ssl_verify_peer"
+    )]
+    fn strip_generic_args_keeps_the_item_path(#[case] path: &str, #[case] expected: &str) {
+        assert_eq!(strip_generic_args(path), expected);
+    }
+}
@@ -0,0 +1,83 @@
+//! Behaviour-driven coverage for deciding which TLS calls to report.
+
+use crate::policy::{DisablingCall, TlsCall, should_flag};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+const METHOD: &str = "reqwest::ClientBuilder::danger_accept_invalid_certs";
+
+#[derive(Default)]
+struct TlsWorld {
+    pattern: RefCell<Option<DisablingCall>>,
+    flagged: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> TlsWorld {
+    TlsWorld::default()
+}
+
+#[given("a pattern disabled by {argument}")]
+fn given_pattern(world: &TlsWorld, argument: bool) {
+    world
+        .pattern
+        .replace(Some(DisablingCall::new(METHOD, Some(argument))));
+}
+
+#[given("a pattern disabled by any call")]
+fn given_any_call(world: &TlsWorld) {
+    world
+        .pattern
+        .replace(Some(DisablingCall::new(METHOD, None)));
+}
+
+fn call_with(world: &TlsWorld, argument: bool, in_test: bool) {
+    let pattern = world.pattern.borrow();
+    let pattern = pattern.as_ref().expect("a pattern should be configured");
+    let call = TlsCall {
+        argument: Some(argument),
+        in_test,
+    };
+    world.flagged.set(Some(should_flag(pattern, &call)));
+}
+
+#[when("the method is called with {argument} outside tests")]
+fn when_called_outside_tests(world: &TlsWorld, argument: bool) {
+    call_with(world, argument, false);
+}
+
+#[when("the method is called with {argument} in a test")]
+fn when_called_in_test(world: &TlsWorld, argument: bool) {
+    call_with(world, argument, true);
+}
+
+#[then("the call is reported")]
+fn then_reported(world: &TlsWorld) {
+    assert_eq!(world.flagged.get(), Some(true));
+}
+
+#[then("the call is accepted")]
+fn then_accepted(world: &TlsWorld) {
+    assert_eq!(world.flagged.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/tls_verification_disabled.feature", index = 0)]
+fn scenario_invalid_certs(world: TlsWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/tls_verification_disabled.feature", index = 1)]
+fn scenario_verification_on(world: TlsWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/tls_verification_disabled.feature", index = 2)]
+fn scenario_in_test(world: TlsWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/tls_verification_disabled.feature", index = 3)]
+fn scenario_any_call(world: TlsWorld) {
+    let _ = world;
+}
@@ -0,0 +1,23 @@
+Feature: Disabled TLS verification outside tests
+  A call to a configured method is reported when it passes the literal that
+  disables verification, unless the call sits in test code.
+
+  Scenario: Accepting invalid certificates is reported
+    Given a pattern disabled by true
+    When the method is called with true outside tests
+    Then the call is reported
+
+  Scenario: Keeping verification on is accepted
+    Given a pattern disabled by true
+    When the method is called with false outside tests
+    Then the call is accepted
+
+  Scenario: Disabling verification in a test is accepted
+    Given a pattern disabled by true
+    When the method is called with true in a test
+    Then the call is accepted
+
+  Scenario: A pattern without an argument reports every call
+    Given a pattern disabled by any call
+    When the method is called with false outside tests
+    Then the call is reported
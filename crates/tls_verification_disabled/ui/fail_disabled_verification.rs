@@ -0,0 +1,33 @@
+//! UI fixture for calls that disable TLS verification outside tests.
+#![deny(tls_verification_disabled)]
+
+mod reqwest {
+    pub struct ClientBuilder;
+
+    impl ClientBuilder {
+        pub fn danger_accept_invalid_certs(self, _accept: bool) -> Self {
+            self
+        }
+    }
+}
+
+mod curl {
+    pub struct Easy;
+
+    impl Easy {
+        pub fn ssl_verify_peer(&mut self, _verify: bool) {}
+    }
+}
+
+fn insecure_client() -> reqwest::ClientBuilder {
+    reqwest::ClientBuilder.danger_accept_invalid_certs(true)
+}
+
+fn insecure_transfer(handle: &mut curl::Easy) {
+    handle.ssl_verify_peer(false);
+}
+
+fn main() {
+    let _ = insecure_client();
+    insecure_transfer(&mut curl::Easy);
+}
@@ -0,0 +1,34 @@
+//! Calls that keep verification on, pass a runtime value, or sit in test code
+//! are accepted.
+#![deny(tls_verification_disabled)]
+
+mod reqwest {
+    pub struct ClientBuilder;
+
+    impl ClientBuilder {
+        pub fn danger_accept_invalid_certs(self, _accept: bool) -> Self {
+            self
+        }
+    }
+}
+
+fn verified_client() -> reqwest::ClientBuilder {
+    reqwest::ClientBuilder.danger_accept_invalid_certs(false)
+}
+
+fn configured_client(insecure: bool) -> reqwest::ClientBuilder {
+    reqwest::ClientBuilder.danger_accept_invalid_certs(insecure)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn talks_to_the_local_test_server() {
+        let _ = super::reqwest::ClientBuilder.danger_accept_invalid_certs(true);
+    }
+}
+
+fn main() {
+    let _ = verified_client();
+    let _ = configured_client(false);
+}
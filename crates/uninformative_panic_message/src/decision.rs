@@ -0,0 +1,143 @@
+//! Pure decision logic for `uninformative_panic_message`.
+//!
+//! The driver reads the literal message of each `expect` call and `panic!`
+//! invocation (see [`crate::sites`]) and hands it to
+//! [`UninformativePanicMessageDecision`], which judges it with the
+//! [`crate::policy`]. Findings use the problem's Fluent selector as their
+//! reason and the message text as their subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::LINT_NAME;
+use crate::policy::{MessagePolicy, MessageProblem};
+
+/// A message stating the invariant that failed.
+const INVARIANT: &str = "config should be validated at start-up";
+
+/// The facts about one message site that the decision needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageProjection {
+    /// The literal message passed to `expect` or `panic!`.
+    pub message: String,
+}
+
+impl MessageProjection {
+    /// Creates a projection for `message`.
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// Reports empty, generic, and too-short panic messages.
+///
+/// # Examples
+///
+/// ```ignore
+/// let decision = UninformativePanicMessageDecision::default();
+/// let finding = decision.decide(&MessageProjection::new("oops"));
+/// assert_eq!(finding.map(|finding| finding.reason()), Some("generic"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct UninformativePanicMessageDecision {
+    policy: MessagePolicy,
+}
+
+impl UninformativePanicMessageDecision {
+    /// Creates a decision denying the default phrases plus
+    /// `additional_denied`, with the given minimum length in characters.
+    #[must_use]
+    pub fn new(additional_denied: &[String], min_length: usize) -> Self {
+        Self {
+            policy: MessagePolicy::new(additional_denied, min_length),
+        }
+    }
+
+    /// Returns the minimum message length in characters.
+    #[must_use]
+    pub const fn min_length(&self) -> usize {
+        self.policy.min_length()
+    }
+
+    /// Returns an informative message at least as long as the minimum.
+    fn informative_message(&self) -> String {
+        let repeats = self.min_length().div_ceil(INVARIANT.len()).max(1);
+        vec![INVARIANT; repeats].join(" and ")
+    }
+
+    /// Returns the problem with the projected message, if any.
+    pub(crate) fn problem(&self, projection: &MessageProjection) -> Option<MessageProblem> {
+        self.policy.assess(&projection.message)
+    }
+}
+
+impl LintDecision for UninformativePanicMessageDecision {
+    type Projection = MessageProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, projection: &MessageProjection) -> Option<Finding> {
+        self.problem(projection).map(|problem| {
+            Finding::new(LINT_NAME, problem.as_key()).with_subject(projection.message.as_str())
+        })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<MessageProjection>> {
+        let mut fixtures = vec![
+            DecisionFixture::flagged(
+                "empty message",
+                MessageProjection::new(""),
+                MessageProblem::Empty.as_key(),
+            ),
+            DecisionFixture::flagged(
+                "generic phrase",
+                MessageProjection::new("Something went wrong!"),
+                MessageProblem::Generic.as_key(),
+            ),
+            DecisionFixture::clean(
+                "stated invariant",
+                MessageProjection::new(self.informative_message()),
+            ),
+        ];
+        if self.min_length() > 1 {
+            fixtures.push(DecisionFixture::flagged(
+                "one character short",
+                MessageProjection::new("x".repeat(self.min_length() - 1)),
+                MessageProblem::TooShort.as_key(),
+            ));
+        }
+        fixtures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    fn findings_name_the_message() {
+        let finding = UninformativePanicMessageDecision::default()
+            .decide(&MessageProjection::new("oops"))
+            .expect("a generic phrase should be reported");
+
+        assert_eq!(finding.reason(), "generic");
+        assert_eq!(finding.subject(), Some("oops"));
+    }
+
+    #[rstest]
+    #[case::default_policy(UninformativePanicMessageDecision::default(), 4)]
+    #[case::no_minimum(UninformativePanicMessageDecision::new(&[], 0), 3)]
+    #[case::long_minimum(UninformativePanicMessageDecision::new(&["oops, sorry".to_owned()], 80), 4)]
+    fn decision_conforms(
+        #[case] decision: UninformativePanicMessageDecision,
+        #[case] fixtures: usize,
+    ) {
+        assert_eq!(check_conformance(&decision), Ok(fixtures));
+    }
+}
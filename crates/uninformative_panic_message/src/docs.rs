@@ -0,0 +1,37 @@
+//! Embedded documentation for the `uninformative_panic_message` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `uninformative_panic_message`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "uninformative_panic_message",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `expect` and `panic!` messages that are empty, generic, or too short to describe the failure.",
+    rationale: "A panic message is often the only clue whoever investigates a crash has. Messages such as \"error\", \"failed\", or an empty string say only that something went wrong, which the panic already says. Describing the invariant that should have held, such as \"config should be validated at start-up\", points straight at the broken assumption. Formatted messages and messages that are not string literals are not judged.",
+    bad_example: "let port = config.port.expect(\"error\");",
+    good_example: concat!(
+        "let port = config\n",
+        "    .port\n",
+        "    .expect(\"port should be filled in by Config::validate\");",
+    ),
+    config: &[
+        ConfigKeyDocs {
+            key: "additional_denied_messages",
+            default: "[]",
+            description: "Further generic phrases to report, compared ignoring case, surrounding whitespace, and trailing punctuation.",
+        },
+        ConfigKeyDocs {
+            key: "min_length",
+            default: "8",
+            description: "Messages shorter than this many characters are reported.",
+        },
+        ConfigKeyDocs {
+            key: "blame_helpers",
+            default: "[]",
+            description: "Fully qualified paths of helper functions whose findings are reported at each call to them instead of inside the helper.",
+        },
+    ],
+};
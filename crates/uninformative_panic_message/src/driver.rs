@@ -0,0 +1,288 @@
+//! Flag `expect` and `panic!` messages that do not describe the failure.
+//!
+//! Each `Option::expect`, `Result::expect`, `Result::expect_err`, and
+//! `panic!` with a string-literal message (see [`crate::sites`]) is judged by
+//! the [`crate::policy`]. Empty messages, generic phrases, and messages
+//! shorter than the configured minimum are reported. Doctest crates are
+//! skipped so documentation examples stay terse.
+//!
+//! Configuration lives under `[uninformative_panic_message]` in `dylint.toml`:
+//! `additional_denied_messages` extends the built-in generic phrases,
+//! `min_length` sets the minimum message length in characters, and
+//! `blame_helpers` reports messages inside the listed helpers at each call to
+//! them.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::{FindingLimit, FindingSite, HelperCallSites};
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::decision::{MessageProjection, UninformativePanicMessageDecision};
+use crate::policy::{DEFAULT_MIN_LENGTH, MessageProblem};
+use crate::sites::{CallKind, MessageSite, message_site};
+
+pub(crate) const LINT_NAME: &str = "uninformative_panic_message";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    additional_denied_messages: Vec<String>,
+    min_length: Option<usize>,
+    blame_helpers: Vec<String>,
+}
+
+impl Config {
+    fn decision(&self) -> UninformativePanicMessageDecision {
+        UninformativePanicMessageDecision::new(
+            &self.additional_denied_messages,
+            self.min_length.unwrap_or(DEFAULT_MIN_LENGTH),
+        )
+    }
+}
+
+/// Lint pass reporting uninformative `expect` and `panic!` messages.
+#[derive(Default)]
+pub struct UninformativePanicMessage {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Doctest flag, message settings, helper call sites, and localization for
+/// the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    helper_calls: HelperCallSites,
+    findings: FindingLimit,
+    localizer: Localizer,
+    is_doctest: bool,
+}
+
+dylint_linting::impl_late_lint! {
+    pub UNINFORMATIVE_PANIC_MESSAGE,
+    Warn,
+    "`expect` and `panic!` messages should describe the invariant that failed",
+    UninformativePanicMessage::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for UninformativePanicMessage {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        let config = load_configuration();
+        self.state.reset(CrateState {
+            is_doctest: whitaker::hir::is_doctest_crate(cx),
+            helper_calls: HelperCallSites::for_crate(cx, &config.blame_helpers),
+            config,
+            findings: FindingLimit::new(UNINFORMATIVE_PANIC_MESSAGE, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        if self.state.is_doctest {
+            return;
+        }
+        let Some(site) = message_site(cx, expr) else {
+            return;
+        };
+        let state = &*self.state;
+        let projection = MessageProjection::new(site.message.as_str());
+        let decision = state
+            .findings
+            .config_at(cx, site.span, &state.config)
+            .decision();
+        let Some(problem) = decision.problem(&projection) else {
+            return;
+        };
+
+        debug!(target: LINT_NAME, "{problem:?} message {:?}", site.message);
+        let finding = Finding {
+            site: &site,
+            problem,
+            min_length: decision.min_length(),
+        };
+        for mut reported in state.helper_calls.finding_sites(cx, expr, &state.localizer) {
+            // Point the origin note at the macro invocation, not its expansion.
+            let span = match reported.origin.as_mut() {
+                Some(origin) => {
+                    origin.span = site.span;
+                    reported.span
+                }
+                None => site.span,
+            };
+            if state.findings.admit(cx, reported.hir_id, span) {
+                emit_diagnostic(
+                    cx,
+                    &finding,
+                    &FindingSite { span, ..reported },
+                    &state.localizer,
+                );
+            }
+        }
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// A message site together with the reason it is reported.
+struct Finding<'a> {
+    site: &'a MessageSite,
+    problem: MessageProblem,
+    min_length: usize,
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    finding: &Finding<'_>,
+    reported: &FindingSite,
+    localizer: &Localizer,
+) {
+    let mut args: Arguments<'_> = Arguments::default();
+    args.insert(
+        Cow::Borrowed("call"),
+        FluentValue::from(finding.site.kind.as_key()),
+    );
+    args.insert(
+        Cow::Borrowed("problem"),
+        FluentValue::from(finding.problem.as_key()),
+    );
+    args.insert(
+        Cow::Borrowed("message"),
+        FluentValue::from(finding.site.message.as_str()),
+    );
+    args.insert(
+        Cow::Borrowed("min_length"),
+        FluentValue::from(finding.min_length),
+    );
+
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        fallback_messages(finding)
+    });
+
+    cx.emit_span_lint(
+        UNINFORMATIVE_PANIC_MESSAGE,
+        reported.span,
+        rustc_lint::errors::DiagDecorator(|lint| {
+            lint.primary_message(messages.primary().to_string());
+            lint.note(messages.note().to_string());
+            if let Some(origin) = &reported.origin {
+                lint.span_note(origin.span, origin.note.clone());
+            }
+            lint.help(messages.help().to_string());
+        }),
+    );
+}
+
+fn fallback_messages(finding: &Finding<'_>) -> DiagnosticMessageSet {
+    let primary = match finding.site.kind {
+        CallKind::Expect => "This expect message does not describe what went wrong.",
+        CallKind::Panic => "This panic message does not describe what went wrong.",
+    };
+    let message = &finding.site.message;
+    let note = match finding.problem {
+        MessageProblem::Empty => "The message is empty.".to_string(),
+        MessageProblem::Generic => {
+            format!("The message \"{message}\" is a generic phrase that fits any failure.")
+        }
+        MessageProblem::TooShort => format!(
+            "The message \"{message}\" is shorter than {} characters.",
+            finding.min_length
+        ),
+    };
+
+    DiagnosticMessageSet::new(
+        primary.to_string(),
+        note,
+        "State the invariant that should have held, such as \"config should be validated at start-up\"."
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use rustc_span::DUMMY_SP;
+
+    #[rstest]
+    #[case::empty(CallKind::Expect, MessageProblem::Empty, "", "is empty")]
+    #[case::generic(
+        CallKind::Panic,
+        MessageProblem::Generic,
+        "oops",
+        "\"oops\" is a generic"
+    )]
+    #[case::short(CallKind::Expect, MessageProblem::TooShort, "no cfg", "shorter than 8")]
+    fn fallback_messages_explain_the_problem(
+        #[case] kind: CallKind,
+        #[case] problem: MessageProblem,
+        #[case] message: &str,
+        #[case] expected_note: &str,
+    ) {
+        let site = MessageSite {
+            kind,
+            message: message.to_string(),
+            span: DUMMY_SP,
+        };
+        let finding = Finding {
+            site: &site,
+            problem,
+            min_length: DEFAULT_MIN_LENGTH,
+        };
+        let messages = fallback_messages(&finding);
+
+        assert!(messages.primary().contains(kind.as_key()));
+        assert!(messages.note().contains(expected_note));
+        assert!(messages.help().starts_with("State the invariant"));
+    }
+
+    #[rstest]
+    fn configuration_extends_the_policy() {
+        let config = Config {
+            additional_denied_messages: vec!["computer says no".to_string()],
+            min_length: Some(0),
+            blame_helpers: Vec::new(),
+        };
+        let decision = config.decision();
+
+        assert_eq!(
+            decision.problem(&MessageProjection::new("computer says no")),
+            Some(MessageProblem::Generic)
+        );
+        assert_eq!(decision.problem(&MessageProjection::new("no cfg")), None);
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
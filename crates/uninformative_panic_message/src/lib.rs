@@ -0,0 +1,43 @@
+//! Lint crate flagging `expect` and `panic!` messages that explain nothing.
+//!
+//! A panic message is read by whoever is paged when the invariant breaks.
+//! `.expect("")`, `.expect("error")`, and `panic!("oops")` tell them only
+//! that something failed. The lint reports empty messages, generic phrases,
+//! and messages shorter than a configurable minimum, so each message states
+//! the invariant that should have held. It complements
+//! `no_expect_outside_tests` in codebases that allow `expect` in places.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+mod sites;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{MessageProjection, UninformativePanicMessageDecision};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn uninformative_panic_message_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
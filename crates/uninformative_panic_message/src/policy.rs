@@ -0,0 +1,186 @@
+//! Judgement of whether an `expect` or `panic!` message explains a failure.
+//!
+//! The policy works on message text alone so it can be tested without a
+//! compiler. A message is uninformative when it is empty, when it is a
+//! generic phrase that would fit any failure, or when it is shorter than the
+//! configured minimum. Comparisons ignore case, surrounding whitespace, and
+//! trailing punctuation, so `"Error!"` and `"error"` are judged alike.
+
+/// Messages shorter than this many characters are reported by default.
+pub(crate) const DEFAULT_MIN_LENGTH: usize = 8;
+
+/// Generic phrases reported whatever their length.
+pub(crate) const DEFAULT_DENIED_MESSAGES: &[&str] = &[
+    "bad",
+    "bug",
+    "can't happen",
+    "cannot happen",
+    "err",
+    "error",
+    "expect",
+    "expect failed",
+    "expected",
+    "fail",
+    "failed",
+    "failure",
+    "fixme",
+    "impossible",
+    "invalid",
+    "none",
+    "ok",
+    "oops",
+    "panic",
+    "should not fail",
+    "should not happen",
+    "should never happen",
+    "should work",
+    "some",
+    "something went wrong",
+    "this should never happen",
+    "todo",
+    "unexpected",
+    "unknown error",
+    "unreachable",
+    "unwrap",
+    "unwrap failed",
+    "whoops",
+];
+
+/// Why a message was judged uninformative.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MessageProblem {
+    /// The message is empty or consists only of punctuation.
+    Empty,
+    /// The message is one of the denied generic phrases.
+    Generic,
+    /// The message is shorter than the configured minimum.
+    TooShort,
+}
+
+impl MessageProblem {
+    /// Returns the selector passed to the Fluent message.
+    pub(crate) const fn as_key(self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::Generic => "generic",
+            Self::TooShort => "short",
+        }
+    }
+}
+
+/// The denied phrases and minimum length a message is judged against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MessagePolicy {
+    denied: Vec<String>,
+    min_length: usize,
+}
+
+impl MessagePolicy {
+    /// Builds a policy from the default phrases plus `additional_denied`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = MessagePolicy::new(&["nope".to_owned()], 12);
+    /// assert_eq!(policy.assess("Nope."), Some(MessageProblem::Generic));
+    /// ```
+    pub(crate) fn new(additional_denied: &[String], min_length: usize) -> Self {
+        let denied = DEFAULT_DENIED_MESSAGES
+            .iter()
+            .copied()
+            .chain(additional_denied.iter().map(String::as_str))
+            .map(normalise)
+            .collect();
+        Self { denied, min_length }
+    }
+
+    /// Returns the minimum message length in characters.
+    pub(crate) const fn min_length(&self) -> usize {
+        self.min_length
+    }
+
+    /// Returns the problem with `message`, or `None` when it is informative.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = MessagePolicy::default();
+    /// assert_eq!(policy.assess(""), Some(MessageProblem::Empty));
+    /// assert_eq!(policy.assess("config should be validated at start-up"), None);
+    /// ```
+    pub(crate) fn assess(&self, message: &str) -> Option<MessageProblem> {
+        let normalised = normalise(message);
+        if normalised.is_empty() {
+            Some(MessageProblem::Empty)
+        } else if self.denied.contains(&normalised) {
+            Some(MessageProblem::Generic)
+        } else if normalised.chars().count() < self.min_length {
+            Some(MessageProblem::TooShort)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for MessagePolicy {
+    fn default() -> Self {
+        Self::new(&[], DEFAULT_MIN_LENGTH)
+    }
+}
+
+/// Lower-cases `message`, collapses runs of whitespace, and drops
+/// surrounding punctuation.
+fn normalise(message: &str) -> String {
+    message
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|ch: char| ch.is_ascii_punctuation() && ch != '\'')
+        .trim()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::empty("", MessageProblem::Empty)]
+    #[case::whitespace("   ", MessageProblem::Empty)]
+    #[case::punctuation("...", MessageProblem::Empty)]
+    #[case::generic("error", MessageProblem::Generic)]
+    #[case::generic_decorated("  Something went WRONG!  ", MessageProblem::Generic)]
+    #[case::generic_spacing("should  never\thappen", MessageProblem::Generic)]
+    #[case::short("no cfg", MessageProblem::TooShort)]
+    fn reports_uninformative_messages(#[case] message: &str, #[case] expected: MessageProblem) {
+        assert_eq!(MessagePolicy::default().assess(message), Some(expected));
+    }
+
+    #[rstest]
+    #[case::invariant("config should be validated at start-up")]
+    #[case::contains_generic_word("error log should be writable")]
+    #[case::exactly_minimum("port set")]
+    fn accepts_informative_messages(#[case] message: &str) {
+        assert_eq!(MessagePolicy::default().assess(message), None);
+    }
+
+    #[rstest]
+    fn additional_phrases_extend_the_defaults() {
+        let policy = MessagePolicy::new(&["Computer says no".to_owned()], DEFAULT_MIN_LENGTH);
+
+        assert_eq!(
+            policy.assess("computer says no."),
+            Some(MessageProblem::Generic)
+        );
+        assert_eq!(policy.assess("error"), Some(MessageProblem::Generic));
+    }
+
+    #[rstest]
+    fn minimum_length_is_configurable() {
+        let policy = MessagePolicy::new(&[], 0);
+
+        assert_eq!(policy.assess("no cfg"), None);
+        assert_eq!(policy.assess("failed"), Some(MessageProblem::Generic));
+    }
+}
@@ -0,0 +1,151 @@
+//! Recognition of `expect` calls and `panic!` invocations with literal
+//! messages.
+//!
+//! Only messages written as string literals are judged. A formatted
+//! `panic!("missing {key}")` carries runtime detail, and a message built
+//! elsewhere cannot be read without evaluating it, so both are left alone.
+//!
+//! `panic!("...")` lowers to `panic_fmt(Arguments::from_str("..."))` and a
+//! bare `panic!()` to `panic("explicit panic")`, so the literal is read from
+//! the lowered call and the diagnostic points at the macro invocation.
+
+use rustc_ast::LitKind;
+use rustc_hir as hir;
+use rustc_hir::{ExprKind, LangItem};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{ExpnKind, MacroKind, Span, sym};
+
+/// The kind of call carrying the message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CallKind {
+    /// `Option::expect`, `Result::expect`, or `Result::expect_err`.
+    Expect,
+    /// `std::panic!` or `core::panic!`.
+    Panic,
+}
+
+impl CallKind {
+    /// Returns the selector passed to the Fluent message.
+    pub(crate) const fn as_key(self) -> &'static str {
+        match self {
+            Self::Expect => "expect",
+            Self::Panic => "panic",
+        }
+    }
+}
+
+/// A call whose message the policy should judge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MessageSite {
+    pub(crate) kind: CallKind,
+    pub(crate) message: String,
+    pub(crate) span: Span,
+}
+
+/// Returns the message site for `expr`, if it is an `expect` call or a
+/// `panic!` invocation with a literal message.
+pub(crate) fn message_site<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+) -> Option<MessageSite> {
+    expect_site(cx, expr).or_else(|| panic_site(cx, expr))
+}
+
+fn expect_site<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) -> Option<MessageSite> {
+    let ExprKind::MethodCall(segment, receiver, [argument], _) = expr.kind else {
+        return None;
+    };
+    if expr.span.from_expansion()
+        || !matches!(segment.ident.name.as_str(), "expect" | "expect_err")
+        || !receiver_is_option_or_result(cx, receiver)
+    {
+        return None;
+    }
+
+    Some(MessageSite {
+        kind: CallKind::Expect,
+        message: string_literal(argument)?,
+        span: argument.span,
+    })
+}
+
+fn panic_site(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<MessageSite> {
+    let ExprKind::Call(callee, [argument]) = expr.kind else {
+        return None;
+    };
+    let span = panic_macro_call_site(cx, expr.span)?;
+    let def_id = callee_def_id(cx, callee)?;
+
+    let message = if cx.tcx.is_lang_item(def_id, LangItem::Panic) {
+        // A bare `panic!()` supplies rustc's "explicit panic" placeholder.
+        String::new()
+    } else if cx.tcx.is_lang_item(def_id, LangItem::PanicFmt) {
+        literal_format_arguments(argument)?
+    } else {
+        return None;
+    };
+
+    Some(MessageSite {
+        kind: CallKind::Panic,
+        message,
+        span,
+    })
+}
+
+/// Returns the call site of the `std::panic!` or `core::panic!` invocation
+/// that produced `span`, provided that invocation was written by hand.
+fn panic_macro_call_site(cx: &LateContext<'_>, span: Span) -> Option<Span> {
+    let expn = span.macro_backtrace().find(|expn| {
+        matches!(expn.kind, ExpnKind::Macro(MacroKind::Bang, _))
+            && expn.macro_def_id.is_some_and(|def_id| {
+                cx.tcx.is_diagnostic_item(sym::std_panic_macro, def_id)
+                    || cx.tcx.is_diagnostic_item(sym::core_panic_macro, def_id)
+            })
+    })?;
+    (!expn.call_site.from_expansion()).then_some(expn.call_site)
+}
+
+/// Reads the literal from `Arguments::from_str("...")`, the lowering of a
+/// format string without placeholders.
+fn literal_format_arguments(argument: &hir::Expr<'_>) -> Option<String> {
+    let ExprKind::Call(constructor, [literal]) = argument.kind else {
+        return None;
+    };
+    let ExprKind::Path(hir::QPath::TypeRelative(_, segment)) = constructor.kind else {
+        return None;
+    };
+    if segment.ident.name.as_str() != "from_str" {
+        return None;
+    }
+    string_literal(literal)
+}
+
+fn string_literal(expr: &hir::Expr<'_>) -> Option<String> {
+    match expr.kind {
+        ExprKind::Lit(lit) => match lit.node {
+            LitKind::Str(symbol, _) => Some(symbol.as_str().to_owned()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn callee_def_id(cx: &LateContext<'_>, callee: &hir::Expr<'_>) -> Option<hir::def_id::DefId> {
+    match callee.kind {
+        ExprKind::Path(ref qpath) => cx.qpath_res(qpath, callee.hir_id).opt_def_id(),
+        _ => None,
+    }
+}
+
+fn receiver_is_option_or_result<'tcx>(
+    cx: &LateContext<'tcx>,
+    receiver: &'tcx hir::Expr<'tcx>,
+) -> bool {
+    let ty = cx.typeck_results().expr_ty(receiver).peel_refs();
+    let ty::Adt(adt, _) = ty.kind() else {
+        return false;
+    };
+    let def_id = adt.did();
+    cx.tcx.is_diagnostic_item(sym::Option, def_id) || cx.tcx.is_diagnostic_item(sym::Result, def_id)
+}
@@ -0,0 +1,111 @@
+//! Behaviour-driven coverage for judging `expect` and `panic!` messages.
+
+use crate::policy::{DEFAULT_MIN_LENGTH, MessagePolicy, MessageProblem};
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::{Cell, RefCell};
+
+struct MessageWorld {
+    message: RefCell<String>,
+    additional_denied: RefCell<Vec<String>>,
+    min_length: Cell<usize>,
+    assessment: Cell<Option<Option<MessageProblem>>>,
+}
+
+impl Default for MessageWorld {
+    fn default() -> Self {
+        Self {
+            message: RefCell::default(),
+            additional_denied: RefCell::default(),
+            min_length: Cell::new(DEFAULT_MIN_LENGTH),
+            assessment: Cell::new(None),
+        }
+    }
+}
+
+impl MessageWorld {
+    fn assessment(&self) -> Option<MessageProblem> {
+        self.assessment
+            .get()
+            .expect("the message should have been assessed before the outcome is checked")
+    }
+}
+
+#[fixture]
+fn world() -> MessageWorld {
+    MessageWorld::default()
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|stripped| stripped.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+#[given("the message {message}")]
+fn given_message(world: &MessageWorld, message: String) {
+    *world.message.borrow_mut() = unquote(&message).to_string();
+}
+
+#[given("the additional denied message {message}")]
+fn given_additional_denied(world: &MessageWorld, message: String) {
+    world
+        .additional_denied
+        .borrow_mut()
+        .push(unquote(&message).to_string());
+}
+
+#[given("the minimum length is {length}")]
+fn given_min_length(world: &MessageWorld, length: usize) {
+    world.min_length.set(length);
+}
+
+#[when("the message is assessed")]
+fn when_assessed(world: &MessageWorld) {
+    let policy = MessagePolicy::new(&world.additional_denied.borrow(), world.min_length.get());
+    world
+        .assessment
+        .set(Some(policy.assess(&world.message.borrow())));
+}
+
+#[then("the message is reported as {problem}")]
+fn then_reported(world: &MessageWorld, problem: String) {
+    let reported = world.assessment().map(MessageProblem::as_key);
+    assert_eq!(reported, Some(problem.as_str()));
+}
+
+#[then("the message is accepted")]
+fn then_accepted(world: &MessageWorld) {
+    assert_eq!(world.assessment(), None);
+}
+
+#[scenario(path = "tests/features/uninformative_panic_message.feature", index = 0)]
+fn scenario_empty(world: MessageWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/uninformative_panic_message.feature", index = 1)]
+fn scenario_generic(world: MessageWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/uninformative_panic_message.feature", index = 2)]
+fn scenario_short(world: MessageWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/uninformative_panic_message.feature", index = 3)]
+fn scenario_informative(world: MessageWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/uninformative_panic_message.feature", index = 4)]
+fn scenario_configured_phrase(world: MessageWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/uninformative_panic_message.feature", index = 5)]
+fn scenario_lower_minimum(world: MessageWorld) {
+    let _ = world;
+}
@@ -0,0 +1,36 @@
+Feature: Uninformative expect and panic messages
+  Messages passed to `expect` and `panic!` should describe the invariant
+  that failed. Empty messages, generic phrases, and very short messages are
+  reported.
+
+  Scenario: An empty message is reported
+    Given the message ""
+    When the message is assessed
+    Then the message is reported as empty
+
+  Scenario: A generic phrase is reported whatever its case
+    Given the message "Something went wrong!"
+    When the message is assessed
+    Then the message is reported as generic
+
+  Scenario: A short message is reported
+    Given the message "no cfg"
+    When the message is assessed
+    Then the message is reported as short
+
+  Scenario: A message describing the invariant is accepted
+    Given the message "config should be validated at start-up"
+    When the message is assessed
+    Then the message is accepted
+
+  Scenario: Configured phrases are reported
+    Given the additional denied message "computer says no"
+    And the message "Computer says no."
+    When the message is assessed
+    Then the message is reported as generic
+
+  Scenario: A lower minimum length accepts short messages
+    Given the minimum length is 4
+    And the message "no cfg"
+    When the message is assessed
+    Then the message is accepted
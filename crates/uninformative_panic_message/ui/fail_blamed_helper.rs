@@ -0,0 +1,17 @@
+//! Negative UI fixture: a vague message in a blamed helper is reported where
+//! the helper is called, while other helpers keep their findings.
+#![warn(uninformative_panic_message)]
+
+fn first(values: &[u8]) -> u8 {
+    *values.first().expect("failed")
+}
+
+fn last(values: &[u8]) -> u8 {
+    *values.last().expect("")
+}
+
+fn main() {
+    let values = [1, 2, 3];
+    let _ = first(&values) + first(&values[1..]);
+    let _ = last(&values);
+}
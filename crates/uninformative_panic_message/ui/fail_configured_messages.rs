@@ -0,0 +1,15 @@
+//! Negative UI fixture: configured phrases and minimum lengths are honoured.
+#![warn(uninformative_panic_message)]
+
+fn load(value: Option<u8>) -> u8 {
+    value.expect("computer says no")
+}
+
+fn short(value: Option<u8>) -> u8 {
+    value.expect("no cfg")
+}
+
+fn main() {
+    let _ = load(Some(1));
+    let _ = short(Some(1));
+}
@@ -0,0 +1,20 @@
+//! Negative UI fixture: empty, generic, and short expect messages are reported.
+#![warn(uninformative_panic_message)]
+
+fn port(value: Option<u16>) -> u16 {
+    value.expect("")
+}
+
+fn parse(text: &str) -> u32 {
+    text.parse::<u32>().expect("Error!")
+}
+
+fn error(result: Result<(), String>) -> String {
+    result.expect_err("no cfg")
+}
+
+fn main() {
+    let _ = port(Some(80));
+    let _ = parse("1");
+    let _ = error(Err(String::new()));
+}
@@ -0,0 +1,18 @@
+//! Negative UI fixture: uninformative `panic!` messages are reported.
+#![warn(uninformative_panic_message)]
+
+fn check(value: u8) {
+    if value == 0 {
+        panic!();
+    }
+    if value == 1 {
+        panic!("should never happen");
+    }
+    if value == 2 {
+        std::panic!("bad");
+    }
+}
+
+fn main() {
+    check(3);
+}
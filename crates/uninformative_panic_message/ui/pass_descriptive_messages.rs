@@ -0,0 +1,27 @@
+//! Positive UI fixture: descriptive, formatted, and non-literal messages pass.
+#![deny(uninformative_panic_message)]
+
+const MESSAGE: &str = "error";
+
+fn port(value: Option<u16>) -> u16 {
+    value.expect("port should be filled in by Config::validate")
+}
+
+fn check(value: u8, limit: u8) {
+    if value > limit {
+        panic!("value {value} should not exceed {limit}");
+    }
+    if value == limit {
+        unreachable!("oops");
+    }
+}
+
+fn constant(value: Option<u8>) -> u8 {
+    value.expect(MESSAGE)
+}
+
+fn main() {
+    let _ = port(Some(80));
+    check(1, 2);
+    let _ = constant(Some(1));
+}
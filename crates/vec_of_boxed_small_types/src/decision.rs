@@ -0,0 +1,116 @@
+//! Pure decision logic for `vec_of_boxed_small_types`.
+//!
+//! The driver walks each written type for `Vec<Box<T>>` and measures every
+//! sized `T`. [`VecOfBoxedSmallTypesDecision`] reports the first element the
+//! same [`BoxedElementPolicy::prefers_inline`], naming the element type as
+//! the finding's subject.
+
+use whitaker::testing::decision::{DecisionFixture, Finding, LintDecision};
+
+use crate::driver::{DEFAULT_MAX_BYTES, LINT_NAME};
+use crate::policy::BoxedElementPolicy;
+
+/// Reason key for boxed elements small enough to store inline.
+const SMALL_ELEMENT: &str = "small_element";
+
+/// One `Vec<Box<T>>` inside the written type.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BoxedElementProjection {
+    /// The element type `T`.
+    pub element: String,
+    /// The size of `T` in bytes, or `None` when it is unsized or cannot be
+    /// measured.
+    pub size: Option<u64>,
+}
+
+/// The facts about one written type that the decision needs.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WrittenTypeProjection {
+    /// Every `Vec<Box<T>>` the type contains, outermost first.
+    pub elements: Vec<BoxedElementProjection>,
+}
+
+/// Reports vectors of boxed elements no larger than `max_bytes`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let written = WrittenTypeProjection {
+///     elements: vec![BoxedElementProjection {
+///         element: "Point".to_owned(),
+///         size: Some(16),
+///     }],
+/// };
+/// assert!(VecOfBoxedSmallTypesDecision::default().decide(&written).is_some());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VecOfBoxedSmallTypesDecision {
+    policy: BoxedElementPolicy,
+}
+
+impl VecOfBoxedSmallTypesDecision {
+    /// Creates a decision reporting elements of at most `max_bytes`.
+    #[must_use]
+    pub const fn new(max_bytes: u64) -> Self {
+        Self {
+            policy: BoxedElementPolicy::new(max_bytes),
+        }
+    }
+}
+
+impl Default for VecOfBoxedSmallTypesDecision {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BYTES)
+    }
+}
+
+impl LintDecision for VecOfBoxedSmallTypesDecision {
+    type Projection = WrittenTypeProjection;
+
+    fn lint_name(&self) -> &'static str {
+        LINT_NAME
+    }
+
+    fn decide(&self, written: &WrittenTypeProjection) -> Option<Finding> {
+        written
+            .elements
+            .iter()
+            .find(|boxed| {
+                boxed
+                    .size
+                    .is_some_and(|size| self.policy.prefers_inline(size))
+            })
+            .map(|boxed| {
+                Finding::new(LINT_NAME, SMALL_ELEMENT).with_subject(boxed.element.as_str())
+            })
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<WrittenTypeProjection>> {
+        let max_bytes = self.policy.max_bytes();
+        let written = |size| WrittenTypeProjection {
+            elements: vec![BoxedElementProjection {
+                element: "Point".to_owned(),
+                size,
+            }],
+        };
+        vec![
+            DecisionFixture::flagged("at the size limit", written(Some(max_bytes)), SMALL_ELEMENT),
+            DecisionFixture::clean("over the size limit", written(Some(max_bytes + 1))),
+            DecisionFixture::clean("unsized element", written(None)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use whitaker::testing::decision::check_conformance;
+
+    #[rstest]
+    #[case::default_limit(VecOfBoxedSmallTypesDecision::default())]
+    #[case::pointer_sized(VecOfBoxedSmallTypesDecision::new(8))]
+    fn decision_conforms(#[case] decision: VecOfBoxedSmallTypesDecision) {
+        assert_eq!(check_conformance(&decision), Ok(3));
+    }
+}
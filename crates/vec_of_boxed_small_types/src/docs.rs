@@ -0,0 +1,21 @@
+//! Embedded documentation for the `vec_of_boxed_small_types` lint.
+//!
+//! The suite collects [`LINT_DOCS`] so explanations, lint listings, and
+//! reports describe the lint from the same source as its implementation.
+
+use whitaker_common::lint_docs::{ConfigKeyDocs, LintDocs};
+
+/// Summary, rationale, examples, and configuration for `vec_of_boxed_small_types`.
+pub const LINT_DOCS: LintDocs = LintDocs {
+    name: "vec_of_boxed_small_types",
+    version: env!("CARGO_PKG_VERSION"),
+    summary: "Flags `Vec<Box<T>>` in written types when `T` is `Sized` and no larger than the configured number of bytes.",
+    rationale: "Boxing each element allocates once per item and makes every access follow a pointer to a separate heap location. Small sized values are cheaper to store directly in the vector, where they sit contiguously.",
+    bad_example: "struct Path { points: Vec<Box<Point>> }",
+    good_example: "struct Path { points: Vec<Point> }",
+    config: &[ConfigKeyDocs {
+        key: "max_bytes",
+        default: "64",
+        description: "Largest size, in bytes, of a boxed element that should be stored in the vector directly.",
+    }],
+};
@@ -0,0 +1,376 @@
+//! Lint pass reporting vectors that box small elements.
+//!
+//! The pass checks the types written for struct, enum, and union fields, for
+//! the parameters and return types of functions and methods, and for
+//! annotated `let` bindings. It looks for a `Vec<Box<T>>` anywhere inside
+//! each type, measures `T` with `layout_of`, and hands the size to
+//! [`BoxedElementPolicy`]. Unsized elements such as `Box<dyn Trait>`, types
+//! whose layout depends on generic parameters, trait implementation methods,
+//! whose signatures the trait dictates, and types written by macros are
+//! skipped.
+
+use std::borrow::Cow;
+
+use log::debug;
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::sym;
+use serde::{Deserialize, Serialize};
+use whitaker::SharedConfig;
+use whitaker::hir::FindingLimit;
+use whitaker_common::CrateScopedState;
+use whitaker_common::i18n::{
+    Arguments, DiagnosticMessageSet, FluentValue, Localizer, MessageKey, MessageResolution,
+    get_localizer_for_lint, noop_reporter, safe_resolve_message_set,
+};
+
+use crate::policy::BoxedElementPolicy;
+
+pub(crate) const LINT_NAME: &str = "vec_of_boxed_small_types";
+const MESSAGE_KEY: MessageKey<'static> = MessageKey::new(LINT_NAME);
+pub(crate) const DEFAULT_MAX_BYTES: u64 = 64;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    max_bytes: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+impl Config {
+    const fn policy(&self) -> BoxedElementPolicy {
+        BoxedElementPolicy::new(self.max_bytes)
+    }
+}
+
+/// Lint pass that measures the elements of boxed vectors.
+#[derive(Default)]
+pub struct VecOfBoxedSmallTypes {
+    state: CrateScopedState<CrateState>,
+}
+
+/// Size limit, finding limit, and localization for the crate being checked.
+#[derive(Default)]
+struct CrateState {
+    config: Config,
+    findings: FindingLimit,
+    localizer: Localizer,
+}
+
+dylint_linting::impl_late_lint! {
+    pub VEC_OF_BOXED_SMALL_TYPES,
+    Warn,
+    "vectors should store small sized values directly rather than boxing each element",
+    VecOfBoxedSmallTypes::default()
+}
+
+impl<'tcx> LateLintPass<'tcx> for VecOfBoxedSmallTypes {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.state.reset(CrateState {
+            config: load_configuration(),
+            findings: FindingLimit::new(VEC_OF_BOXED_SMALL_TYPES, &shared_config),
+            localizer: get_localizer_for_lint(LINT_NAME, shared_config.locale()),
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        self.state.findings.summarise(cx, &self.state.localizer);
+    }
+
+    fn check_field_def(&mut self, cx: &LateContext<'tcx>, field: &'tcx hir::FieldDef<'tcx>) {
+        let ty = cx.tcx.type_of(field.def_id).instantiate_identity();
+        self.check_type(cx, field.ty, ty.skip_normalization());
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        if let hir::ItemKind::Fn { sig, .. } = item.kind {
+            self.check_signature(cx, item.owner_id.def_id, &sig);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        if let hir::ImplItemKind::Fn(sig, _) = item.kind
+            && matches!(item.impl_kind, hir::ImplItemImplKind::Inherent { .. })
+        {
+            self.check_signature(cx, item.owner_id.def_id, &sig);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        if let hir::TraitItemKind::Fn(sig, _) = item.kind {
+            self.check_signature(cx, item.owner_id.def_id, &sig);
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx hir::LetStmt<'tcx>) {
+        let Some(hir_ty) = local.ty else {
+            return;
+        };
+        if !matches!(local.source, hir::LocalSource::Normal) {
+            return;
+        }
+        self.check_type(cx, hir_ty, cx.typeck_results().pat_ty(local.pat));
+    }
+}
+
+impl VecOfBoxedSmallTypes {
+    fn check_signature<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        def_id: LocalDefId,
+        sig: &hir::FnSig<'tcx>,
+    ) {
+        if sig.span.from_expansion() {
+            return;
+        }
+        let fn_sig = cx.tcx.instantiate_bound_regions_with_erased(
+            cx.tcx
+                .fn_sig(def_id)
+                .instantiate_identity()
+                .skip_normalization(),
+        );
+        for (hir_ty, &ty) in sig.decl.inputs.iter().zip(fn_sig.inputs()) {
+            self.check_type(cx, hir_ty, ty);
+        }
+        if let hir::FnRetTy::Return(hir_ty) = sig.decl.output {
+            self.check_type(cx, hir_ty, fn_sig.output());
+        }
+    }
+
+    fn check_type<'tcx>(&self, cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'_>, ty: Ty<'tcx>) {
+        if hir_ty.span.from_expansion() {
+            return;
+        }
+        let policy = self
+            .state
+            .findings
+            .config_at(cx, hir_ty.span, &self.state.config)
+            .policy();
+        let Some((element, size)) = small_boxed_element(cx, ty, policy) else {
+            return;
+        };
+        let limit = policy.max_bytes();
+        debug!(
+            target: LINT_NAME,
+            "`Vec<Box<{element}>>` boxes elements of {size} bytes (limit {limit})",
+        );
+        if !self.state.findings.admit(cx, hir_ty.hir_id, hir_ty.span) {
+            return;
+        }
+        let message = BoxedElementMessage {
+            element: &element.to_string(),
+            size,
+            limit,
+        };
+        emit_diagnostic(cx, hir_ty.span, &message, &self.state.localizer);
+    }
+}
+
+fn load_configuration() -> Config {
+    match dylint_linting::config::<Config>(LINT_NAME) {
+        Ok(Some(config)) => config,
+        Ok(None) => Config::default(),
+        Err(error) => {
+            debug!(
+                target: LINT_NAME,
+                "failed to parse `{LINT_NAME}` configuration: {error}; using defaults"
+            );
+            Config::default()
+        }
+    }
+}
+
+/// Returns the element and its size for the first `Vec<Box<T>>` inside `ty`
+/// whose `T` is sized, measurable, and small enough for `policy`.
+fn small_boxed_element<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    policy: BoxedElementPolicy,
+) -> Option<(Ty<'tcx>, u64)> {
+    ty.walk()
+        .filter_map(|arg| arg.as_type())
+        .filter_map(|ty| vec_box_element(cx, ty))
+        .find_map(|element| {
+            if !element.is_sized(cx.tcx, cx.typing_env()) {
+                return None;
+            }
+            let layout = cx
+                .tcx
+                .layout_of(cx.typing_env().as_query_input(element))
+                .ok()?;
+            let size = layout.size.bytes();
+            policy.prefers_inline(size).then_some((element, size))
+        })
+}
+
+/// Returns `T` when `ty` is `Vec<Box<T>>`.
+fn vec_box_element<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    let ty::Adt(adt, args) = ty.kind() else {
+        return None;
+    };
+    if !cx.tcx.is_diagnostic_item(sym::Vec, adt.did()) {
+        return None;
+    }
+    args.type_at(0).boxed_ty()
+}
+
+fn emit_diagnostic(
+    cx: &LateContext<'_>,
+    span: Span,
+    message: &BoxedElementMessage<'_>,
+    localizer: &Localizer,
+) {
+    let args = message.args();
+    let resolution = MessageResolution {
+        lint_name: LINT_NAME,
+        key: MESSAGE_KEY,
+        args: &args,
+    };
+    let messages = safe_resolve_message_set(localizer, resolution, noop_reporter, || {
+        message.fallback_messages()
+    });
+
+    let primary = messages.primary().to_string();
+    let note = messages.note().to_string();
+    let help = messages.help().to_string();
+
+    cx.emit_span_lint(
+        VEC_OF_BOXED_SMALL_TYPES,
+        span,
+        rustc_lint::errors::DiagDecorator(move |lint| {
+            lint.primary_message(primary);
+            lint.note(note);
+            lint.help(help);
+        }),
+    );
+}
+
+/// The values the diagnostic for a boxed vector interpolates.
+#[derive(Clone, Copy, Debug)]
+struct BoxedElementMessage<'a> {
+    element: &'a str,
+    size: u64,
+    limit: u64,
+}
+
+impl BoxedElementMessage<'_> {
+    fn args(&self) -> Arguments<'static> {
+        let mut args: Arguments<'static> = Arguments::default();
+        args.insert(
+            Cow::Borrowed("element"),
+            FluentValue::from(self.element.to_owned()),
+        );
+        args.insert(Cow::Borrowed("size"), FluentValue::from(self.size));
+        args.insert(Cow::Borrowed("limit"), FluentValue::from(self.limit));
+        args
+    }
+
+    fn fallback_messages(&self) -> DiagnosticMessageSet {
+        let Self {
+            element,
+            size,
+            limit,
+        } = *self;
+        let unit = if size == 1 { "byte" } else { "bytes" };
+        DiagnosticMessageSet::new(
+            format!("`Vec<Box<{element}>>` boxes elements of only {size} {unit}."),
+            format!(
+                "Each element sits in its own heap allocation, so building the vector allocates \
+                 once per item and every access follows a pointer; sized values of {limit} bytes \
+                 or fewer are cheaper to store inline."
+            ),
+            format!("Store the elements directly as `Vec<{element}>`."),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MESSAGE: BoxedElementMessage<'static> = BoxedElementMessage {
+        element: "Point",
+        size: 16,
+        limit: 64,
+    };
+
+    fn resolve(message: &BoxedElementMessage<'_>, locale: &str) -> DiagnosticMessageSet {
+        let args = message.args();
+        let resolution = MessageResolution {
+            lint_name: LINT_NAME,
+            key: MESSAGE_KEY,
+            args: &args,
+        };
+        safe_resolve_message_set(
+            &Localizer::new(Some(locale)),
+            resolution,
+            |error| panic!("`{locale}` message should resolve: {error}"),
+            || message.fallback_messages(),
+        )
+    }
+
+    #[rstest]
+    fn default_config_reports_elements_up_to_a_cache_line() {
+        assert_eq!(Config::default().policy(), BoxedElementPolicy::new(64));
+    }
+
+    #[rstest]
+    fn fallback_messages_name_the_element_and_size() {
+        let messages = MESSAGE.fallback_messages();
+
+        assert_eq!(
+            messages.primary(),
+            "`Vec<Box<Point>>` boxes elements of only 16 bytes."
+        );
+        assert!(messages.note().contains("64 bytes"));
+        assert!(messages.help().contains("`Vec<Point>`"));
+    }
+
+    #[rstest]
+    #[case::plural(16)]
+    #[case::singular(1)]
+    fn english_messages_match_the_fallback(#[case] size: u64) {
+        let message = BoxedElementMessage { size, ..MESSAGE };
+
+        assert_eq!(resolve(&message, "en-GB"), message.fallback_messages());
+    }
+
+    #[rstest]
+    fn single_byte_elements_use_the_singular() {
+        let message = BoxedElementMessage { size: 1, ..MESSAGE };
+
+        assert!(
+            message
+                .fallback_messages()
+                .primary()
+                .ends_with("only 1 byte.")
+        );
+    }
+
+    #[rstest]
+    #[case::welsh("cy")]
+    #[case::gaelic("gd")]
+    fn translations_resolve(#[case] locale: &str) {
+        let messages = resolve(&MESSAGE, locale);
+
+        assert!(messages.primary().contains("`Vec<Box<Point>>`"));
+        assert!(messages.primary().contains("16"));
+        assert_ne!(messages.help(), MESSAGE.fallback_messages().help());
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/behaviour.rs"]
+mod behaviour;
@@ -0,0 +1,41 @@
+//! Lint crate flagging vectors of boxed values small enough to store inline.
+//!
+//! A `Vec<Box<T>>` places every element in its own heap allocation, so
+//! building the vector allocates once per item and walking it follows a
+//! pointer per item. When `T` is `Sized` and small, `Vec<T>` stores the same
+//! values contiguously. The lint measures the boxed type with `layout_of` and
+//! reports vectors whose elements are no larger than `max_bytes`. The lint is
+//! experimental and ships behind the `experimental-vec-of-boxed-small-types`
+//! suite feature.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[cfg(feature = "dylint-driver")]
+mod driver;
+
+#[cfg(feature = "dylint-driver")]
+mod decision;
+
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+#[cfg(feature = "dylint-driver")]
+mod policy;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::*;
+
+#[cfg(feature = "dylint-driver")]
+pub use decision::{BoxedElementProjection, VecOfBoxedSmallTypesDecision, WrittenTypeProjection};
+
+#[cfg(feature = "dylint-driver")]
+pub use docs::LINT_DOCS;
+
+#[cfg(not(feature = "dylint-driver"))]
+mod stub {
+    #[expect(dead_code, reason = "stub when dylint-driver is disabled")]
+    pub fn vec_of_boxed_small_types_disabled_stub() {}
+}
+
+#[cfg(all(test, feature = "dylint-driver"))]
+#[path = "lib_ui_tests.rs"]
+mod ui;
@@ -0,0 +1,56 @@
+//! Size threshold for boxed vector elements.
+//!
+//! The driver finds each `Vec<Box<T>>` in a written type and measures `T`.
+//! [`BoxedElementPolicy::prefers_inline`] then decides whether the element is
+//! small enough that storing it directly in the vector is the better layout,
+//! keeping the threshold free of compiler types so it can be tested directly.
+
+/// The configured size below which boxed elements are reported.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct BoxedElementPolicy {
+    max_bytes: u64,
+}
+
+impl BoxedElementPolicy {
+    /// Creates a policy reporting elements of at most `max_bytes`.
+    #[must_use]
+    pub(crate) const fn new(max_bytes: u64) -> Self {
+        Self { max_bytes }
+    }
+
+    /// Returns the configured size limit in bytes.
+    #[must_use]
+    pub(crate) const fn max_bytes(self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Returns `true` when an element of `size` bytes should be stored in
+    /// the vector rather than boxed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let policy = BoxedElementPolicy::new(64);
+    /// assert!(policy.prefers_inline(16));
+    /// assert!(!policy.prefers_inline(4096));
+    /// ```
+    #[must_use]
+    pub(crate) const fn prefers_inline(self, size: u64) -> bool {
+        size <= self.max_bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::zero_sized(0, true)]
+    #[case::below(16, true)]
+    #[case::at_limit(64, true)]
+    #[case::above(65, false)]
+    fn compares_sizes_with_the_limit(#[case] size: u64, #[case] expected: bool) {
+        assert_eq!(BoxedElementPolicy::new(64).prefers_inline(size), expected);
+    }
+}
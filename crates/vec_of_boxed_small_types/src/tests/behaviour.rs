@@ -0,0 +1,66 @@
+//! Behaviour-driven coverage for the boxed element size policy.
+
+use crate::policy::BoxedElementPolicy;
+use rstest::fixture;
+use rstest_bdd_macros::{given, scenario, then, when};
+use std::cell::Cell;
+
+#[derive(Default)]
+struct BoxedElementWorld {
+    max_bytes: Cell<u64>,
+    size: Cell<u64>,
+    reported: Cell<Option<bool>>,
+}
+
+#[fixture]
+fn world() -> BoxedElementWorld {
+    BoxedElementWorld::default()
+}
+
+#[given("a limit of {bytes} bytes")]
+fn given_limit(world: &BoxedElementWorld, bytes: u64) {
+    world.max_bytes.set(bytes);
+}
+
+#[given("a boxed element of {bytes} bytes")]
+fn given_element(world: &BoxedElementWorld, bytes: u64) {
+    world.size.set(bytes);
+}
+
+#[when("the element is checked")]
+fn when_checked(world: &BoxedElementWorld) {
+    let policy = BoxedElementPolicy::new(world.max_bytes.get());
+    world
+        .reported
+        .set(Some(policy.prefers_inline(world.size.get())));
+}
+
+#[then("the vector is reported")]
+fn then_reported(world: &BoxedElementWorld) {
+    assert_eq!(world.reported.get(), Some(true));
+}
+
+#[then("the vector is accepted")]
+fn then_accepted(world: &BoxedElementWorld) {
+    assert_eq!(world.reported.get(), Some(false));
+}
+
+#[scenario(path = "tests/features/vec_of_boxed_small_types.feature", index = 0)]
+fn scenario_small_element(world: BoxedElementWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/vec_of_boxed_small_types.feature", index = 1)]
+fn scenario_element_at_limit(world: BoxedElementWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/vec_of_boxed_small_types.feature", index = 2)]
+fn scenario_large_element(world: BoxedElementWorld) {
+    let _ = world;
+}
+
+#[scenario(path = "tests/features/vec_of_boxed_small_types.feature", index = 3)]
+fn scenario_lower_limit(world: BoxedElementWorld) {
+    let _ = world;
+}
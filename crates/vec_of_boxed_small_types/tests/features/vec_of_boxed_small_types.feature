@@ -0,0 +1,27 @@
+Feature: Vectors of boxed small types
+  A `Vec<Box<T>>` is reported when the boxed type is no larger than the
+  configured limit.
+
+  Scenario: A small boxed element is reported
+    Given a limit of 64 bytes
+    And a boxed element of 16 bytes
+    When the element is checked
+    Then the vector is reported
+
+  Scenario: An element at the limit is reported
+    Given a limit of 64 bytes
+    And a boxed element of 64 bytes
+    When the element is checked
+    Then the vector is reported
+
+  Scenario: A large boxed element is accepted
+    Given a limit of 64 bytes
+    And a boxed element of 4096 bytes
+    When the element is checked
+    Then the vector is accepted
+
+  Scenario: A lower limit accepts the element
+    Given a limit of 8 bytes
+    And a boxed element of 16 bytes
+    When the element is checked
+    Then the vector is accepted
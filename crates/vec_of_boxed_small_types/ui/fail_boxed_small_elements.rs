@@ -0,0 +1,27 @@
+//! Negative UI fixture: vectors that box small sized elements.
+#![warn(vec_of_boxed_small_types)]
+
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+struct Route {
+    stops: Vec<Box<Point>>,
+}
+
+fn lengths(values: Vec<Box<u32>>) -> usize {
+    values.len()
+}
+
+fn flags() -> Option<Vec<Box<bool>>> {
+    None
+}
+
+fn main() {
+    let ids: Vec<Box<u8>> = Vec::new();
+    let route = Route { stops: Vec::new() };
+    let _ = (ids, route.stops.len(), lengths(Vec::new()), flags());
+    let origin = Point { x: 0.0, y: 0.0 };
+    let _ = origin.x + origin.y;
+}
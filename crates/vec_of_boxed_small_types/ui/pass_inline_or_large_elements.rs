@@ -0,0 +1,38 @@
+//! Positive UI fixture: vectors whose boxed elements are unsized, large,
+//! generic, or stored inline.
+#![warn(vec_of_boxed_small_types)]
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Unit;
+
+impl Shape for Unit {
+    fn area(&self) -> f64 {
+        1.0
+    }
+}
+
+struct Scene {
+    shapes: Vec<Box<dyn Shape>>,
+    names: Vec<Box<str>>,
+    pages: Vec<Box<[u8; 4096]>>,
+    counts: Vec<u32>,
+}
+
+fn store<T>(values: Vec<Box<T>>) -> usize {
+    values.len()
+}
+
+fn main() {
+    let scene = Scene {
+        shapes: vec![Box::new(Unit)],
+        names: Vec::new(),
+        pages: Vec::new(),
+        counts: Vec::new(),
+    };
+    let inferred = vec![Box::new(1_u8)];
+    let total: f64 = scene.shapes.iter().map(|shape| shape.area()).sum();
+    let _ = (total, scene.names, scene.pages, scene.counts, store(inferred));
+}
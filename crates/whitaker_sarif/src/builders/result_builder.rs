@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 
 use serde_json::Value;
+use whitaker_common::diagnostics::{FINGERPRINT_KEY, Fingerprint};
 
 use crate::error::{Result, SarifError};
 use crate::model::location::{Location, RelatedLocation};
@@ -90,6 +91,33 @@ impl ResultBuilder {
         self
     }
 
+    /// Records a stable finding fingerprint under
+    /// [`FINGERPRINT_KEY`](whitaker_common::diagnostics::FINGERPRINT_KEY).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker_common::diagnostics::{FINGERPRINT_KEY, FingerprintSource, fingerprint};
+    /// use whitaker_sarif::ResultBuilder;
+    ///
+    /// let source = FingerprintSource {
+    ///     path: "src/lib.rs",
+    ///     snippet: "static mut COUNTER: u32 = 0;",
+    ///     enclosing_item: None,
+    /// };
+    /// let result = ResultBuilder::new("no_static_mut")
+    ///     .with_message("`static mut` item")
+    ///     .with_stable_fingerprint(fingerprint("no_static_mut", &source))
+    ///     .build()
+    ///     .expect("build result");
+    ///
+    /// assert!(result.partial_fingerprints.contains_key(FINGERPRINT_KEY));
+    /// ```
+    #[must_use]
+    pub fn with_stable_fingerprint(self, fingerprint: Fingerprint) -> Self {
+        self.with_fingerprint(FINGERPRINT_KEY, fingerprint.to_string())
+    }
+
     /// Sets the tool-specific properties.
     #[must_use]
     pub fn with_properties(mut self, properties: Value) -> Self {
@@ -136,6 +164,7 @@ mod tests {
     use super::*;
     use crate::merge::WHITAKER_FRAGMENT_KEY;
     use rstest::rstest;
+    use whitaker_common::diagnostics::{FingerprintSource, fingerprint};
 
     #[rstest]
     #[case("WHK001", "msg", Level::Warning)]
@@ -186,4 +215,27 @@ mod tests {
             Err(e) => panic!("failed to build: {e}"),
         }
     }
+
+    #[test]
+    fn adds_stable_fingerprint_under_versioned_key() {
+        let source = FingerprintSource {
+            path: "src/lib.rs",
+            snippet: "static mut COUNTER: u32 = 0;",
+            enclosing_item: None,
+        };
+        let expected = fingerprint("no_static_mut", &source);
+        match ResultBuilder::new("no_static_mut")
+            .with_message("msg")
+            .with_stable_fingerprint(expected)
+            .build()
+        {
+            Ok(r) => {
+                assert_eq!(
+                    r.partial_fingerprints.get("whitaker/v1"),
+                    Some(&expected.to_string())
+                );
+            }
+            Err(e) => panic!("failed to build: {e}"),
+        }
+    }
 }
@@ -27,16 +27,74 @@ use clap::{Parser, Subcommand};
 #[command(after_help = concat!(
     "DEFAULT LINTS:\n",
     "  bumpy_road_function           Detect multiple complexity clusters in functions\n",
+    "  closure_max_lines             Closure line budget\n",
+    "  commented_out_code            Commented-out code left in comments\n",
     "  conditional_max_n_branches    Limit boolean branches in conditionals\n",
     "  function_attrs_follow_docs    Doc comments must precede other attributes\n",
+    "  method_chain_max_length       Method chain call limit\n",
     "  module_max_lines              Warn when modules exceed line threshold\n",
     "  module_must_have_inner_docs   Require inner doc comments on modules\n",
     "  no_expect_outside_tests       Forbid .expect() outside test contexts\n",
+    "  no_shadowing                  Unrelated variable shadowing\n",
+    "  no_static_mut                 Forbid static mut declarations and accesses\n",
     "  no_std_fs_operations          Enforce capability-based filesystem access\n",
     "  no_unwrap_or_else_panic       Deny panicking unwrap_or_else fallbacks\n",
-    "  test_must_not_have_example    Forbid examples in test documentation\n\n",
+    "  prefer_named_module_files     Prefer foo.rs over foo/mod.rs (allow by default)\n",
+    "  test_must_not_have_example    Forbid examples in test documentation\n",
+    "  tls_verification_disabled     TLS verification disabled outside tests\n",
+    "  uninformative_panic_message   Uninformative expect and panic messages\n\n",
     "EXPERIMENTAL LINTS (requires --experimental):\n",
-    "  (none currently)\n\n",
+    "  allow_must_have_reason        Require a reason on #[allow] attributes\n",
+    "  arc_clone_prefer_explicit     .clone() on Arc or Rc (allow by default)\n",
+    "  archive_extraction_must_sanitise_paths  Archive entry paths joined without a containment check (allow by default)\n",
+    "  async_fn_returning_boxed_future_prefer_async_trait_or_impl  Trait methods returning boxed futures\n",
+    "  await_in_loop_without_concurrency  Loops awaiting independent futures one at a time\n",
+    "  chained_unwrap_or_default_hides_errors  unwrap_or_default on results with displayable errors\n",
+    "  config_struct_fields_must_have_defaults_documented  serde-defaulted config fields without a documented default\n",
+    "  constructor_max_arguments     Constructors with argument lists that want builders\n",
+    "  data_clumps                   Parameter groups repeated across signatures\n",
+    "  deserialised_input_used_as_path_without_validation  Deserialised input used as a path unchecked\n",
+    "  doc_ignore_must_have_reason   Requires a reason on ignored doc examples\n",
+    "  duplicated_blocks             Near-identical statement blocks shared by functions\n",
+    "  error_context_missing_on_io_operations  I/O errors passed on by `?` without context\n",
+    "  error_types_implement_error   Public error types missing Display or Error impls\n",
+    "  feature_envy                  Methods using another type more than their own\n",
+    "  feature_gated_pub_use_must_have_cfg_doc  Flags feature-gated `pub use` items lacking `doc(cfg(...))`\n",
+    "  function_max_return_points    Functions with too many return and ? exits\n",
+    "  generic_function_monomorphisation_bloat  Long generic functions with many instantiations\n",
+    "  impl_trait_for_reference_smell  Flag local trait impls on &T or &mut T beside one on T (allow by default)\n",
+    "  iterator_returning_fn_should_not_collect_internally  Helpers collecting a Vec callers only iterate\n",
+    "  large_stack_values            Locals and arguments too large for the stack\n",
+    "  lint_crate_missing_stub_for_non_driver_builds  Lint crates need a stub module for non-driver builds\n",
+    "  metrics_or_log_in_tight_loop  Logging and metrics macros called on every loop iteration\n",
+    "  module_must_have_tests        Non-trivial modules without tests (allow by default)\n",
+    "  module_named_after_parent     Flags modules and types repeating the name of their module\n",
+    "  nested_result_option_in_signatures  Flag public signatures nesting Result and Option too deeply\n",
+    "  no_block_on_in_async          Blocking executor calls inside async code\n",
+    "  no_detached_threads           Spawned threads whose join handle is discarded\n",
+    "  no_else_after_return          else blocks after return\n",
+    "  no_float_equality             Exact == and != between floating-point values\n",
+    "  no_glob_reexports             Libraries should re-export items by name, not with globs\n",
+    "  no_global_mutable_state       Global locks and static mut items\n",
+    "  no_include_str_of_large_files  include_str! and include_bytes! of files above a size limit\n",
+    "  no_indexing_outside_tests     Run-time slice and Vec indexing outside tests\n",
+    "  no_lock_unwrap                Flag .lock().unwrap() and RwLock equivalents outside tests\n",
+    "  no_lossy_casts                Numeric as casts that truncate, round, or change sign\n",
+    "  no_recursion                  Functions that recurse directly or through a cycle\n",
+    "  no_single_letter_identifiers  Single-letter bindings and parameters\n",
+    "  no_unchecked_arithmetic       Requires explicit integer overflow handling in configured modules\n",
+    "  no_wildcard_match_on_local_enums  Flags `_` match arms on enums defined in the same crate\n",
+    "  path_join_with_absolute_component  Path joins with an absolute literal component\n",
+    "  pattern_match_on_boolean      match on a bool that reads better as if/else\n",
+    "  primitive_obsession           Items repeating one bare primitive type\n",
+    "  public_trait_missing_sealed_pattern_marker  Exported traits meant to be sealed that are not (allow by default)\n",
+    "  redundant_else_block_after_loop_control  else blocks after continue or break\n",
+    "  repeated_env_lookup           Environment variables looked up repeatedly in one scope\n",
+    "  rstest_helper_should_be_fixture  Repeated rstest helpers that want fixtures\n",
+    "  spawned_task_missing_cancellation_handling  Spawned looping tasks that never watch a cancellation token (allow by default)\n",
+    "  subprocess_inherits_full_env  Commands spawned with the full parent environment (allow by default)\n",
+    "  test_file_in_src_should_use_path_attribute_pattern  Large inline test modules should move to a #[path] file\n\n",
+    "  vec_of_boxed_small_types      Vectors of boxed small sized values\n",
     "EXAMPLES:\n",
     "  Build and stage the aggregated suite:\n",
     "    $ whitaker-installer\n\n",
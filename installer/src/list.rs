@@ -1,16 +1,19 @@
 //! List command implementation.
 //!
 //! This module provides the `run_list` command handler and supporting functions
-//! for querying and displaying installed lint libraries.
+//! for querying and displaying installed lint libraries. Callers that link the
+//! suite can pass its embedded [`LintDocs`] to [`run_list_with_docs`] so each
+//! listed lint is described from the lint's own documentation.
 
 use camino::{Utf8Path, Utf8PathBuf};
 use log::trace;
 use std::io::Write;
+use whitaker_common::lint_docs::LintDocs;
 
 use crate::cli::ListArgs;
 use crate::dirs::{BaseDirs, SystemBaseDirs};
 use crate::error::{InstallerError, Result};
-use crate::list_output::{format_human, format_json};
+use crate::list_output::{format_human_with_docs, format_json_with_docs};
 use crate::scanner::{InstalledLints, scan_installed};
 use crate::stager::default_target_dir;
 use crate::toolchain::Toolchain;
@@ -29,11 +32,33 @@ use crate::toolchain::Toolchain;
 /// - The staging directory cannot be scanned
 /// - Writing to stdout fails
 pub fn run_list(args: &ListArgs, stdout: &mut dyn Write) -> Result<()> {
-    run_list_with(args, stdout, detect_active_toolchain)
+    run_list_with_docs(args, stdout, &[])
+}
+
+/// Lists installed lint libraries, describing each lint found in `docs`.
+///
+/// Behaves like [`run_list`], but each lint with an entry in `docs` is listed
+/// with its summary and configuration keys, and the JSON output also carries
+/// its rationale.
+///
+/// # Errors
+///
+/// Returns the same errors as [`run_list`].
+pub fn run_list_with_docs(
+    args: &ListArgs,
+    stdout: &mut dyn Write,
+    docs: &[&LintDocs],
+) -> Result<()> {
+    run_list_with(args, stdout, detect_active_toolchain, docs)
 }
 
 /// Internal implementation with injectable toolchain detection for testability.
-fn run_list_with<F>(args: &ListArgs, stdout: &mut dyn Write, detect_toolchain: F) -> Result<()>
+fn run_list_with<F>(
+    args: &ListArgs,
+    stdout: &mut dyn Write,
+    detect_toolchain: F,
+    docs: &[&LintDocs],
+) -> Result<()>
 where
     F: FnOnce() -> Option<String>,
 {
@@ -49,9 +74,9 @@ where
     let active_toolchain = detect_toolchain();
 
     let output = if args.json {
-        format_json(&installed, active_toolchain.as_deref())
+        format_json_with_docs(&installed, active_toolchain.as_deref(), docs)
     } else {
-        format_human(&installed, active_toolchain.as_deref())
+        format_human_with_docs(&installed, active_toolchain.as_deref(), docs)
     };
 
     writeln!(stdout, "{output}").map_err(|e| InstallerError::WriteFailed { source: e })?;
@@ -277,7 +302,7 @@ mod tests {
         };
         let mut stdout = Vec::new();
 
-        let result = run_list_with(&args, &mut stdout, || None);
+        let result = run_list_with(&args, &mut stdout, || None, &[]);
 
         assert!(result.is_ok(), "expected success, got: {result:?}");
         let output = String::from_utf8_lossy(&stdout);
@@ -299,7 +324,12 @@ mod tests {
         };
         let mut stdout = Vec::new();
 
-        let result = run_list_with(&args, &mut stdout, || Some("nightly-2026-05-28".to_owned()));
+        let result = run_list_with(
+            &args,
+            &mut stdout,
+            || Some("nightly-2026-05-28".to_owned()),
+            &[],
+        );
 
         assert!(result.is_ok(), "expected success, got: {result:?}");
         let output = String::from_utf8_lossy(&stdout);
@@ -324,7 +354,12 @@ mod tests {
         };
         let mut stdout = Vec::new();
 
-        let result = run_list_with(&args, &mut stdout, || Some("nightly-2026-05-28".to_owned()));
+        let result = run_list_with(
+            &args,
+            &mut stdout,
+            || Some("nightly-2026-05-28".to_owned()),
+            &[],
+        );
 
         assert!(result.is_ok(), "expected success, got: {result:?}");
         let output = String::from_utf8_lossy(&stdout);
@@ -340,7 +375,7 @@ mod tests {
         };
         let mut failing_stdout = FailingWriter;
 
-        let result = run_list_with(&args, &mut failing_stdout, || None);
+        let result = run_list_with(&args, &mut failing_stdout, || None, &[]);
 
         let err = result.expect_err("expected error on write failure");
         assert!(
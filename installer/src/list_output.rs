@@ -1,9 +1,12 @@
 //! Output formatting for lint listing.
 //!
 //! This module provides utilities to format installed lint information for
-//! human-readable or JSON output.
+//! human-readable or JSON output. When the caller supplies the suite's
+//! embedded [`LintDocs`], each listed lint also shows its summary and
+//! configuration keys, and the JSON output carries its rationale too.
 
 use serde::Serialize;
+use whitaker_common::lint_docs::LintDocs;
 
 use crate::scanner::{InstalledLints, lints_for_library};
 
@@ -21,6 +24,48 @@ use crate::scanner::{InstalledLints, lints_for_library};
 /// ```
 #[must_use]
 pub fn format_human(lints: &InstalledLints, active_toolchain: Option<&str>) -> String {
+    format_human_with_docs(lints, active_toolchain, &[])
+}
+
+/// Format installed lints for human-readable output, describing each lint
+/// found in `docs`.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_common::lint_docs::LintDocs;
+/// use whitaker_installer::builder::CrateName;
+/// use whitaker_installer::list_output::format_human_with_docs;
+/// use whitaker_installer::scanner::{InstalledLibrary, InstalledLints};
+///
+/// const DOCS: LintDocs = LintDocs {
+///     name: "module_max_lines",
+///     version: "0.2.7",
+///     summary: "Flags modules longer than `max_lines`.",
+///     rationale: "Long modules mix concerns.",
+///     bad_example: "",
+///     good_example: "",
+///     config: &[],
+/// };
+/// let mut lints = InstalledLints::default();
+/// lints.by_toolchain.insert(
+///     "nightly-2026-05-28".to_owned(),
+///     vec![InstalledLibrary {
+///         crate_name: CrateName::from("module_max_lines"),
+///         toolchain: "nightly-2026-05-28".to_owned(),
+///         path: "/lints/libmodule_max_lines@nightly-2026-05-28.so".into(),
+///     }],
+/// );
+///
+/// let output = format_human_with_docs(&lints, None, &[&DOCS]);
+/// assert!(output.contains("- module_max_lines: Flags modules longer than `max_lines`."));
+/// ```
+#[must_use]
+pub fn format_human_with_docs(
+    lints: &InstalledLints,
+    active_toolchain: Option<&str>,
+    docs: &[&LintDocs],
+) -> String {
     if lints.is_empty() {
         return String::from(
             "No lints installed.\n\nRun `whitaker-installer` to install the default lint suite.",
@@ -44,7 +89,7 @@ pub fn format_human(lints: &InstalledLints, active_toolchain: Option<&str>) -> S
 
             let lint_names = lints_for_library(&library.crate_name);
             for lint in lint_names {
-                output.push_str(&format!("      - {lint}\n"));
+                push_lint_line(&mut output, lint, find_docs(docs, lint));
             }
         }
     }
@@ -52,6 +97,26 @@ pub fn format_human(lints: &InstalledLints, active_toolchain: Option<&str>) -> S
     output
 }
 
+/// Appends a lint's listing line, followed by its configuration keys when
+/// documentation is available.
+fn push_lint_line(output: &mut String, lint: &str, docs: Option<&LintDocs>) {
+    let Some(docs) = docs else {
+        output.push_str(&format!("      - {lint}\n"));
+        return;
+    };
+    output.push_str(&format!("      - {lint}: {}\n", docs.summary));
+    for entry in docs.config {
+        output.push_str(&format!(
+            "          {} = {} ({})\n",
+            entry.key, entry.default, entry.description
+        ));
+    }
+}
+
+fn find_docs<'a>(docs: &[&'a LintDocs], lint: &str) -> Option<&'a LintDocs> {
+    docs.iter().copied().find(|entry| entry.name == lint)
+}
+
 /// Format installed lints as JSON.
 ///
 /// # Examples
@@ -66,7 +131,28 @@ pub fn format_human(lints: &InstalledLints, active_toolchain: Option<&str>) -> S
 /// ```
 #[must_use]
 pub fn format_json(lints: &InstalledLints, active_toolchain: Option<&str>) -> String {
-    let json_data = InstalledLintsJson::from_installed(lints, active_toolchain);
+    format_json_with_docs(lints, active_toolchain, &[])
+}
+
+/// Format installed lints as JSON, adding a `docs` entry to each library for
+/// the lints found in `docs`.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_installer::list_output::format_json_with_docs;
+/// use whitaker_installer::scanner::InstalledLints;
+///
+/// let json = format_json_with_docs(&InstalledLints::default(), None, &[]);
+/// assert!(json.contains("\"toolchains\""));
+/// ```
+#[must_use]
+pub fn format_json_with_docs(
+    lints: &InstalledLints,
+    active_toolchain: Option<&str>,
+    docs: &[&LintDocs],
+) -> String {
+    let json_data = InstalledLintsJson::from_installed(lints, active_toolchain, docs);
 
     // Use pretty printing for readability
     serde_json::to_string_pretty(&json_data).unwrap_or_else(|_| "{}".to_owned())
@@ -81,7 +167,11 @@ pub struct InstalledLintsJson {
 
 impl InstalledLintsJson {
     /// Create from `InstalledLints`.
-    fn from_installed(lints: &InstalledLints, active_toolchain: Option<&str>) -> Self {
+    fn from_installed(
+        lints: &InstalledLints,
+        active_toolchain: Option<&str>,
+        docs: &[&LintDocs],
+    ) -> Self {
         let toolchains = lints
             .by_toolchain
             .iter()
@@ -95,6 +185,11 @@ impl InstalledLintsJson {
                         LibraryEntry {
                             name: lib.crate_name.as_str().to_owned(),
                             lints: lint_names.iter().map(|s| (*s).to_owned()).collect(),
+                            docs: lint_names
+                                .iter()
+                                .filter_map(|lint| find_docs(docs, lint))
+                                .map(LintDocsEntry::from)
+                                .collect(),
                         }
                     })
                     .collect();
@@ -129,6 +224,55 @@ pub struct LibraryEntry {
     pub name: String,
     /// Lints provided by this library.
     pub lints: Vec<String>,
+    /// Embedded documentation for the lints that have it; omitted when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<LintDocsEntry>,
+}
+
+/// JSON entry for a lint's embedded documentation.
+#[derive(Debug, Serialize)]
+pub struct LintDocsEntry {
+    /// Lint name.
+    pub name: String,
+    /// Version of the lint crate the documentation describes.
+    pub version: String,
+    /// One-line description of what the lint flags.
+    pub summary: String,
+    /// Why the flagged pattern is a problem.
+    pub rationale: String,
+    /// Configuration keys read from the lint's `dylint.toml` table.
+    pub config: Vec<ConfigKeyEntry>,
+}
+
+impl From<&LintDocs> for LintDocsEntry {
+    fn from(docs: &LintDocs) -> Self {
+        Self {
+            name: docs.name.to_owned(),
+            version: docs.version.to_owned(),
+            summary: docs.summary.to_owned(),
+            rationale: docs.rationale.to_owned(),
+            config: docs
+                .config
+                .iter()
+                .map(|entry| ConfigKeyEntry {
+                    key: entry.key.to_owned(),
+                    default: entry.default.to_owned(),
+                    description: entry.description.to_owned(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// JSON entry for one configuration key.
+#[derive(Debug, Serialize)]
+pub struct ConfigKeyEntry {
+    /// Key name under the lint's `dylint.toml` table.
+    pub key: String,
+    /// Default value, written as TOML.
+    pub default: String,
+    /// One-sentence description of the key's effect.
+    pub description: String,
 }
 
 #[cfg(test)]
@@ -138,6 +282,21 @@ mod tests {
     use crate::scanner::InstalledLibrary;
     use camino::Utf8PathBuf;
     use std::collections::BTreeMap;
+    use whitaker_common::lint_docs::ConfigKeyDocs;
+
+    const MODULE_MAX_LINES_DOCS: LintDocs = LintDocs {
+        name: "module_max_lines",
+        version: "0.2.7",
+        summary: "Flags modules longer than `max_lines`.",
+        rationale: "Long modules mix concerns.",
+        bad_example: "mod parser { /* 900 lines */ }",
+        good_example: "mod parser { mod lexer; }",
+        config: &[ConfigKeyDocs {
+            key: "max_lines",
+            default: "400",
+            description: "Maximum number of lines in a module.",
+        }],
+    };
 
     fn sample_lints() -> InstalledLints {
         let mut by_toolchain = BTreeMap::new();
@@ -218,4 +377,38 @@ mod tests {
         assert!(parsed.is_object());
         assert!(parsed.get("toolchains").is_some());
     }
+
+    #[test]
+    fn format_human_with_docs_describes_documented_lints() {
+        let lints = sample_lints();
+        let output = format_human_with_docs(&lints, None, &[&MODULE_MAX_LINES_DOCS]);
+
+        assert!(
+            output.contains("      - module_max_lines: Flags modules longer than `max_lines`.\n")
+        );
+        assert!(
+            output.contains("          max_lines = 400 (Maximum number of lines in a module.)\n")
+        );
+        assert!(output.contains("      - no_static_mut\n"));
+    }
+
+    #[test]
+    fn format_json_with_docs_includes_documented_lints() {
+        let lints = sample_lints();
+        let json = format_json_with_docs(&lints, None, &[&MODULE_MAX_LINES_DOCS]);
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
+        let docs = &parsed["toolchains"][0]["libraries"][0]["docs"];
+        assert_eq!(docs.as_array().map(Vec::len), Some(1));
+        assert_eq!(docs[0]["name"], "module_max_lines");
+        assert_eq!(docs[0]["rationale"], "Long modules mix concerns.");
+        assert_eq!(docs[0]["config"][0]["default"], "400");
+    }
+
+    #[test]
+    fn format_json_omits_docs_without_documentation() {
+        let json = format_json(&sample_lints(), None);
+
+        assert!(!json.contains("\"docs\""));
+    }
 }
@@ -21,13 +21,73 @@ pub const LINT_CRATES: &[&str] = &[
     "test_must_not_have_example",
     "no_std_fs_operations",
     "no_unwrap_or_else_panic",
+    "no_static_mut",
+    "prefer_named_module_files",
+    "method_chain_max_length",
+    "closure_max_lines",
+    "no_shadowing",
+    "commented_out_code",
+    "uninformative_panic_message",
+    "tls_verification_disabled",
 ];
 
 /// Static list of experimental lint crates.
 ///
 /// These lints are not included in the default suite but can be enabled via
 /// the `--experimental` flag.
-pub const EXPERIMENTAL_LINT_CRATES: &[&str] = &["rstest_helper_should_be_fixture"];
+pub const EXPERIMENTAL_LINT_CRATES: &[&str] = &[
+    "rstest_helper_should_be_fixture",
+    "feature_envy",
+    "data_clumps",
+    "duplicated_blocks",
+    "no_recursion",
+    "constructor_max_arguments",
+    "no_global_mutable_state",
+    "no_block_on_in_async",
+    "no_detached_threads",
+    "primitive_obsession",
+    "no_indexing_outside_tests",
+    "no_float_equality",
+    "no_lossy_casts",
+    "error_types_implement_error",
+    "large_stack_values",
+    "allow_must_have_reason",
+    "function_max_return_points",
+    "async_fn_returning_boxed_future_prefer_async_trait_or_impl",
+    "iterator_returning_fn_should_not_collect_internally",
+    "no_else_after_return",
+    "arc_clone_prefer_explicit",
+    "no_single_letter_identifiers",
+    "module_must_have_tests",
+    "nested_result_option_in_signatures",
+    "no_lock_unwrap",
+    "impl_trait_for_reference_smell",
+    "no_unchecked_arithmetic",
+    "module_named_after_parent",
+    "doc_ignore_must_have_reason",
+    "test_file_in_src_should_use_path_attribute_pattern",
+    "no_glob_reexports",
+    "lint_crate_missing_stub_for_non_driver_builds",
+    "no_wildcard_match_on_local_enums",
+    "feature_gated_pub_use_must_have_cfg_doc",
+    "no_include_str_of_large_files",
+    "pattern_match_on_boolean",
+    "redundant_else_block_after_loop_control",
+    "chained_unwrap_or_default_hides_errors",
+    "config_struct_fields_must_have_defaults_documented",
+    "public_trait_missing_sealed_pattern_marker",
+    "generic_function_monomorphisation_bloat",
+    "await_in_loop_without_concurrency",
+    "spawned_task_missing_cancellation_handling",
+    "metrics_or_log_in_tight_loop",
+    "vec_of_boxed_small_types",
+    "repeated_env_lookup",
+    "error_context_missing_on_io_operations",
+    "path_join_with_absolute_component",
+    "archive_extraction_must_sanitise_paths",
+    "subprocess_inherits_full_env",
+    "deserialised_input_used_as_path_without_validation",
+];
 
 /// The aggregated suite crate name.
 pub const SUITE_CRATE: &str = "whitaker_suite";
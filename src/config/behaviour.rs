@@ -0,0 +1,90 @@
+//! Opt-in gates for behaviour changes within a lint.
+//!
+//! Fixes that make a lint report more findings would otherwise fail CI for
+//! every team that upgrades the binaries. Such fixes instead ship behind a
+//! [`BehaviourVersion`]: each lint reads the version configured for it in the
+//! shared `[whitaker.behaviour_version]` table and enables the new behaviour
+//! only when that version has reached the one the fix was introduced in.
+//! Unconfigured lints use [`BehaviourVersion::DEFAULT`], which advances only
+//! on major releases.
+
+use std::fmt;
+
+/// Behaviour version a lint runs at.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker::config::BehaviourVersion;
+///
+/// assert!(BehaviourVersion::DEFAULT.enables(1));
+/// assert!(!BehaviourVersion::DEFAULT.enables(2));
+/// assert!(BehaviourVersion::new(2).enables(2));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BehaviourVersion(u32);
+
+impl BehaviourVersion {
+    /// Version every lint runs at unless configured otherwise. It advances
+    /// only on major Whitaker releases.
+    pub const DEFAULT: Self = Self(1);
+
+    /// Creates a behaviour version.
+    #[must_use]
+    pub const fn new(version: u32) -> Self {
+        Self(version)
+    }
+
+    /// Returns the version number.
+    #[must_use]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` when behaviour introduced in version `introduced_in`
+    /// is enabled at this version.
+    #[must_use]
+    pub const fn enables(self, introduced_in: u32) -> bool {
+        self.0 >= introduced_in
+    }
+}
+
+impl Default for BehaviourVersion {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl fmt::Display for BehaviourVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 1, true)]
+    #[case(1, 2, false)]
+    #[case(2, 2, true)]
+    #[case(3, 2, true)]
+    fn versions_enable_behaviour_introduced_at_or_before_them(
+        #[case] version: u32,
+        #[case] introduced_in: u32,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            BehaviourVersion::new(version).enables(introduced_in),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn default_is_the_first_version() {
+        assert_eq!(BehaviourVersion::default(), BehaviourVersion::new(1));
+        assert_eq!(BehaviourVersion::DEFAULT.to_string(), "1");
+    }
+}
@@ -0,0 +1,64 @@
+//! Table merging shared by directory overrides and their consumers.
+//!
+//! Deeper `whitaker.toml` layers are folded into shallower ones key by key,
+//! and the merged table is then laid over a typed configuration by
+//! round-tripping it through TOML.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use super::DirectoryConfigError;
+
+/// Recursively merges `overlay` into `base`.
+///
+/// Nested tables are merged key by key; any other value in `overlay`
+/// replaces the corresponding value in `base`.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker::config::merge_tables;
+///
+/// let mut base: toml::Table = "[a]\nx = 1\ny = 2\n".parse().expect("valid TOML");
+/// let overlay: toml::Table = "[a]\ny = 3\n".parse().expect("valid TOML");
+/// merge_tables(&mut base, overlay);
+///
+/// assert_eq!(base["a"]["x"].as_integer(), Some(1));
+/// assert_eq!(base["a"]["y"].as_integer(), Some(3));
+/// ```
+pub fn merge_tables(base: &mut Table, overlay: Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(existing)), Value::Table(nested)) => merge_tables(existing, nested),
+            (_, replacement) => {
+                base.insert(key, replacement);
+            }
+        }
+    }
+}
+
+pub(super) fn overlay_onto<T>(
+    base: &T,
+    overlay: Table,
+    section: &str,
+) -> Result<T, DirectoryConfigError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let invalid = |message: String| DirectoryConfigError::Invalid {
+        section: section.to_owned(),
+        message,
+    };
+    let mut merged = Table::try_from(base).map_err(|error| invalid(error.to_string()))?;
+    merge_tables(&mut merged, overlay);
+    merged
+        .try_into()
+        .map_err(|error: toml::de::Error| invalid(error.to_string()))
+}
+
+pub(super) fn list_contains(value: &Value, needle: &str) -> bool {
+    value
+        .as_array()
+        .is_some_and(|items| items.iter().any(|item| item.as_str() == Some(needle)))
+}
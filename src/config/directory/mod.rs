@@ -0,0 +1,357 @@
+//! Per-directory `whitaker.toml` overrides layered over the shared config.
+//!
+//! Workspaces often carry a legacy subsystem that cannot meet the suite's
+//! thresholds straight away. Placing a `whitaker.toml` inside that subsystem's
+//! directory lets the team loosen limits or switch lints off for the files
+//! beneath it without relaxing the rest of the workspace.
+//!
+//! Discovery follows `.editorconfig` semantics. Starting at the workspace
+//! root, every directory between the root and the file under analysis may
+//! contribute a layer. Deeper layers win: tables are merged key by key while
+//! scalars and arrays are replaced wholesale. A layer that sets `root = true`
+//! discards everything discovered above it.
+
+use std::io;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use toml::{Table, Value};
+
+use super::SharedConfig;
+
+mod merge;
+
+pub use merge::merge_tables;
+use merge::{list_contains, overlay_onto};
+
+/// File name consulted in each directory between the workspace root and a
+/// source file.
+pub const DIRECTORY_CONFIG_FILE: &str = "whitaker.toml";
+
+/// Keys owned by [`SharedConfig`]; other tables belong to individual lints.
+const SHARED_CONFIG_KEYS: [&str; 5] = [
+    "behaviour_version",
+    "crate_summary",
+    "locale",
+    "max_findings_per_lint_per_crate",
+    "module_max_lines",
+];
+
+/// Top-level key that stops discovery from inheriting parent layers.
+const ROOT_KEY: &str = "root";
+
+/// Table holding lint selection controls.
+const LINT_TABLE: &str = "lint";
+
+/// Errors raised while discovering or applying directory overrides.
+#[derive(Debug, Error)]
+pub enum DirectoryConfigError {
+    /// The override file exists but could not be read.
+    #[error("failed to read `{path}`: {source}")]
+    Read {
+        /// Path of the unreadable override file.
+        path: Utf8PathBuf,
+        /// Underlying I/O failure.
+        #[source]
+        source: io::Error,
+    },
+    /// The override file is not valid TOML.
+    #[error("failed to parse `{path}`: {source}")]
+    Parse {
+        /// Path of the malformed override file.
+        path: Utf8PathBuf,
+        /// Underlying parser failure.
+        #[source]
+        source: toml::de::Error,
+    },
+    /// The merged overrides do not match the target configuration schema.
+    #[error("directory overrides for `{section}` are invalid: {message}")]
+    Invalid {
+        /// Configuration section that rejected the merged values.
+        section: String,
+        /// Description of the schema mismatch.
+        message: String,
+    },
+    /// The analysed file does not live beneath the workspace root.
+    #[error("`{file}` is not beneath the workspace root `{root}`")]
+    OutsideRoot {
+        /// File that was requested.
+        file: Utf8PathBuf,
+        /// Workspace root used for discovery.
+        root: Utf8PathBuf,
+    },
+}
+
+/// A single `whitaker.toml` discovered during the directory walk.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverrideLayer {
+    directory: Utf8PathBuf,
+    table: Table,
+}
+
+impl OverrideLayer {
+    /// Returns the directory that contains the override file.
+    #[must_use]
+    pub fn directory(&self) -> &Utf8Path {
+        &self.directory
+    }
+
+    /// Returns the parsed override table, excluding the `root` marker.
+    #[must_use]
+    pub const fn table(&self) -> &Table {
+        &self.table
+    }
+}
+
+/// Ordered stack of directory overrides that apply to one source file.
+///
+/// Layers are stored outermost first so that merging in order lets the
+/// deepest directory win.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DirectoryOverrides {
+    layers: Vec<OverrideLayer>,
+}
+
+impl DirectoryOverrides {
+    /// Discovers the overrides that apply to `file` by reading
+    /// `whitaker.toml` from each directory between `root` and the file.
+    ///
+    /// Relative `file` paths are resolved against `root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DirectoryConfigError`] when `file` lies outside `root` or an
+    /// override file cannot be read or parsed.
+    pub fn discover(root: &Utf8Path, file: &Utf8Path) -> Result<Self, DirectoryConfigError> {
+        Self::discover_with(root, file, read_if_present)
+    }
+
+    /// Discovers overrides using the supplied reader.
+    ///
+    /// The reader receives the candidate `whitaker.toml` path for each
+    /// directory and returns `Ok(None)` when no file exists. This mirrors
+    /// [`SharedConfig::load_with`] so tests can simulate a directory tree
+    /// without touching the file system.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DirectoryConfigError`] when `file` lies outside `root`, the
+    /// reader fails, or an override file cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use camino::Utf8Path;
+    /// use whitaker::config::DirectoryOverrides;
+    ///
+    /// let overrides = DirectoryOverrides::discover_with(
+    ///     Utf8Path::new("/work"),
+    ///     Utf8Path::new("/work/legacy/src/lib.rs"),
+    ///     |path| {
+    ///         Ok((path == "/work/legacy/whitaker.toml")
+    ///             .then(|| "[module_max_lines]\nmax_lines = 900\n".to_owned()))
+    ///     },
+    /// )
+    /// .expect("overrides should load");
+    ///
+    /// assert_eq!(overrides.layers().len(), 1);
+    /// ```
+    pub fn discover_with<F>(
+        root: &Utf8Path,
+        file: &Utf8Path,
+        mut reader: F,
+    ) -> Result<Self, DirectoryConfigError>
+    where
+        F: FnMut(&Utf8Path) -> io::Result<Option<String>>,
+    {
+        let absolute = if file.is_absolute() {
+            file.to_owned()
+        } else {
+            root.join(file)
+        };
+        let relative =
+            absolute
+                .strip_prefix(root)
+                .map_err(|_| DirectoryConfigError::OutsideRoot {
+                    file: absolute.clone(),
+                    root: root.to_owned(),
+                })?;
+
+        let mut overrides = Self::default();
+        let mut directory = root.to_owned();
+        overrides.visit(&directory, &mut reader)?;
+        for component in relative.parent().into_iter().flat_map(Utf8Path::components) {
+            directory.push(component);
+            overrides.visit(&directory, &mut reader)?;
+        }
+
+        Ok(overrides)
+    }
+
+    fn visit<F>(&mut self, directory: &Utf8Path, reader: &mut F) -> Result<(), DirectoryConfigError>
+    where
+        F: FnMut(&Utf8Path) -> io::Result<Option<String>>,
+    {
+        let path = directory.join(DIRECTORY_CONFIG_FILE);
+        let Some(contents) = reader(&path).map_err(|source| DirectoryConfigError::Read {
+            path: path.clone(),
+            source,
+        })?
+        else {
+            return Ok(());
+        };
+
+        let mut table = contents
+            .parse::<Table>()
+            .map_err(|source| DirectoryConfigError::Parse { path, source })?;
+        if matches!(table.remove(ROOT_KEY), Some(Value::Boolean(true))) {
+            self.layers.clear();
+        }
+
+        self.layers.push(OverrideLayer {
+            directory: directory.to_owned(),
+            table,
+        });
+        Ok(())
+    }
+
+    /// Returns the discovered layers, outermost first.
+    #[must_use]
+    pub fn layers(&self) -> &[OverrideLayer] {
+        &self.layers
+    }
+
+    /// Returns `true` when no override files apply.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Merges every layer into a single table, deepest layer last.
+    #[must_use]
+    pub fn effective_table(&self) -> Table {
+        self.layers.iter().fold(Table::new(), |mut merged, layer| {
+            merge_tables(&mut merged, layer.table.clone());
+            merged
+        })
+    }
+
+    /// Applies the directory overrides to the workspace-level configuration.
+    ///
+    /// Only keys owned by [`SharedConfig`] participate; per-lint tables are
+    /// resolved through [`Self::apply_to_lint`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DirectoryConfigError::Invalid`] when the merged values do
+    /// not satisfy the [`SharedConfig`] schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use camino::Utf8Path;
+    /// use whitaker::SharedConfig;
+    /// use whitaker::config::DirectoryOverrides;
+    ///
+    /// let overrides = DirectoryOverrides::discover_with(
+    ///     Utf8Path::new("/work"),
+    ///     Utf8Path::new("legacy/mod.rs"),
+    ///     |path| {
+    ///         Ok((path == "/work/legacy/whitaker.toml")
+    ///             .then(|| "[module_max_lines]\nmax_lines = 900\n".to_owned()))
+    ///     },
+    /// )
+    /// .expect("overrides should load");
+    ///
+    /// let config = overrides
+    ///     .apply_to(&SharedConfig::default())
+    ///     .expect("overrides should match the schema");
+    /// assert_eq!(config.module_max_lines.max_lines, 900);
+    /// ```
+    pub fn apply_to(&self, base: &SharedConfig) -> Result<SharedConfig, DirectoryConfigError> {
+        let mut overlay = self.effective_table();
+        overlay.retain(|key, _| SHARED_CONFIG_KEYS.contains(&key));
+        overlay_onto(base, overlay, "whitaker")
+    }
+
+    /// Applies the `[lint_name]` table from the directory overrides to a
+    /// lint's own configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DirectoryConfigError::Invalid`] when the merged values do
+    /// not satisfy the lint's configuration schema.
+    pub fn apply_to_lint<T>(&self, lint_name: &str, base: &T) -> Result<T, DirectoryConfigError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        match self.effective_table().remove(lint_name) {
+            Some(Value::Table(overlay)) => overlay_onto(base, overlay, lint_name),
+            Some(_) => Err(DirectoryConfigError::Invalid {
+                section: lint_name.to_owned(),
+                message: "expected a table".to_owned(),
+            }),
+            None => overlay_onto(base, Table::new(), lint_name),
+        }
+    }
+
+    /// Reports whether `lint_name` remains enabled for files beneath the
+    /// deepest override.
+    ///
+    /// The `[lint]` table accepts `select` and `ignore` lists of canonical
+    /// lint names. When `select` is present, only the listed lints (or
+    /// `DEFAULT`/`ALL`) stay enabled; `ignore` then removes lints from that
+    /// selection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use camino::Utf8Path;
+    /// use whitaker::config::DirectoryOverrides;
+    ///
+    /// let overrides = DirectoryOverrides::discover_with(
+    ///     Utf8Path::new("/work"),
+    ///     Utf8Path::new("/work/legacy/lib.rs"),
+    ///     |path| {
+    ///         Ok((path == "/work/legacy/whitaker.toml")
+    ///             .then(|| "[lint]\nignore = [\"module_max_lines\"]\n".to_owned()))
+    ///     },
+    /// )
+    /// .expect("overrides should load");
+    ///
+    /// assert!(!overrides.is_lint_enabled("module_max_lines"));
+    /// assert!(overrides.is_lint_enabled("no_expect_outside_tests"));
+    /// ```
+    #[must_use]
+    pub fn is_lint_enabled(&self, lint_name: &str) -> bool {
+        let merged = self.effective_table();
+        let Some(Value::Table(selection)) = merged.get(LINT_TABLE) else {
+            return true;
+        };
+
+        let selected = selection.get("select").is_none_or(|select| {
+            list_contains(select, lint_name)
+                || list_contains(select, "DEFAULT")
+                || list_contains(select, "ALL")
+        });
+        let ignored = selection
+            .get("ignore")
+            .is_some_and(|ignore| list_contains(ignore, lint_name));
+
+        selected && !ignored
+    }
+}
+
+/// Reads `path`, treating a missing file as absent rather than an error.
+pub(crate) fn read_if_present(path: &Utf8Path) -> io::Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(source) => Ok(Some(source)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,231 @@
+//! Tests for per-directory `whitaker.toml` discovery and merging.
+
+use super::*;
+use crate::config::ModuleMaxLinesConfig;
+use rstest::rstest;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+fn tree(entries: &[(&str, &str)]) -> impl FnMut(&Utf8Path) -> io::Result<Option<String>> {
+    let files: BTreeMap<String, String> = entries
+        .iter()
+        .map(|(path, source)| ((*path).to_owned(), (*source).to_owned()))
+        .collect();
+    move |path| Ok(files.get(path.as_str()).cloned())
+}
+
+fn discover(files: &[(&str, &str)], file: &str) -> DirectoryOverrides {
+    DirectoryOverrides::discover_with(Utf8Path::new("/work"), Utf8Path::new(file), tree(files))
+        .expect("discovery should succeed")
+}
+
+#[rstest]
+fn no_override_files_leave_configuration_untouched() {
+    let overrides = discover(&[], "/work/src/lib.rs");
+
+    assert!(overrides.is_empty());
+    let config = overrides
+        .apply_to(&SharedConfig::default())
+        .expect("empty overrides should apply");
+    assert_eq!(config, SharedConfig::default());
+}
+
+#[rstest]
+#[case::root_file("/work/src/lib.rs", 400)]
+#[case::legacy_file("/work/legacy/src/lib.rs", 900)]
+#[case::nested_legacy_file("/work/legacy/parser/mod.rs", 1200)]
+#[case::sibling_file("/work/legacy2/lib.rs", 400)]
+fn deeper_layers_override_shallower_ones(#[case] file: &str, #[case] expected: usize) {
+    let files = [
+        (
+            "/work/legacy/whitaker.toml",
+            "[module_max_lines]\nmax_lines = 900\n",
+        ),
+        (
+            "/work/legacy/parser/whitaker.toml",
+            "[module_max_lines]\nmax_lines = 1200\n",
+        ),
+    ];
+
+    let config = discover(&files, file)
+        .apply_to(&SharedConfig::default())
+        .expect("overrides should apply");
+
+    assert_eq!(config.module_max_lines.max_lines, expected);
+}
+
+#[rstest]
+fn relative_paths_resolve_against_the_root() {
+    let files = [("/work/legacy/whitaker.toml", "locale = \"cy\"\n")];
+
+    let config = discover(&files, "legacy/lib.rs")
+        .apply_to(&SharedConfig::default())
+        .expect("overrides should apply");
+
+    assert_eq!(config.locale(), Some("cy"));
+}
+
+#[rstest]
+fn tables_merge_while_scalars_replace() {
+    let files = [
+        (
+            "/work/whitaker.toml",
+            "[lint]\nselect = [\"ALL\"]\nignore = [\"a\"]\n",
+        ),
+        ("/work/legacy/whitaker.toml", "[lint]\nignore = [\"b\"]\n"),
+    ];
+
+    let merged = discover(&files, "/work/legacy/lib.rs").effective_table();
+
+    let lint = merged
+        .get("lint")
+        .and_then(Value::as_table)
+        .expect("lint table should merge");
+    let list = |key: &str| {
+        lint.get(key)
+            .and_then(Value::as_array)
+            .map(|items| items.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+    };
+    assert_eq!(list("select"), Some(vec!["ALL"]));
+    assert_eq!(list("ignore"), Some(vec!["b"]));
+}
+
+#[rstest]
+fn root_marker_discards_parent_layers() {
+    let files = [
+        ("/work/whitaker.toml", "locale = \"gd\"\n"),
+        (
+            "/work/vendor/whitaker.toml",
+            "root = true\n[module_max_lines]\nmax_lines = 50\n",
+        ),
+    ];
+
+    let overrides = discover(&files, "/work/vendor/lib.rs");
+
+    assert_eq!(overrides.layers().len(), 1);
+    let layer = overrides.layers().first().expect("one layer");
+    assert_eq!(layer.directory(), "/work/vendor");
+    assert!(!layer.table().contains_key("root"));
+    let config = overrides
+        .apply_to(&SharedConfig::default())
+        .expect("overrides should apply");
+    assert!(config.locale().is_none());
+    assert_eq!(config.module_max_lines.max_lines, 50);
+}
+
+#[rstest]
+#[case::untouched("no_expect_outside_tests", true)]
+#[case::ignored("module_max_lines", false)]
+fn ignore_disables_lints_beneath_the_directory(#[case] lint: &str, #[case] enabled: bool) {
+    let files = [(
+        "/work/legacy/whitaker.toml",
+        "[lint]\nignore = [\"module_max_lines\"]\n",
+    )];
+
+    assert_eq!(
+        discover(&files, "/work/legacy/lib.rs").is_lint_enabled(lint),
+        enabled
+    );
+    assert!(discover(&files, "/work/src/lib.rs").is_lint_enabled(lint));
+}
+
+#[rstest]
+#[case::listed("[\"module_max_lines\"]", true)]
+#[case::unlisted("[\"no_expect_outside_tests\"]", false)]
+#[case::default_selector("[\"DEFAULT\"]", true)]
+#[case::all_selector("[\"ALL\"]", true)]
+fn select_restricts_enabled_lints(#[case] select: &str, #[case] enabled: bool) {
+    let source = format!("[lint]\nselect = {select}\n");
+    let files = [("/work/whitaker.toml", source.as_str())];
+
+    assert_eq!(
+        discover(&files, "/work/lib.rs").is_lint_enabled("module_max_lines"),
+        enabled
+    );
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct BranchConfig {
+    max_branches: usize,
+}
+
+#[rstest]
+fn lint_tables_override_lint_configuration() {
+    let files = [(
+        "/work/legacy/whitaker.toml",
+        "[conditional_max_n_branches]\nmax_branches = 4\n",
+    )];
+    let overrides = discover(&files, "/work/legacy/lib.rs");
+
+    let config = overrides
+        .apply_to_lint(
+            "conditional_max_n_branches",
+            &BranchConfig { max_branches: 2 },
+        )
+        .expect("lint overrides should apply");
+    let untouched = overrides
+        .apply_to_lint("other_lint", &BranchConfig { max_branches: 2 })
+        .expect("missing sections should fall back to the base");
+
+    assert_eq!(config, BranchConfig { max_branches: 4 });
+    assert_eq!(untouched, BranchConfig { max_branches: 2 });
+}
+
+#[rstest]
+#[case::wrong_type("[module_max_lines]\nmax_lines = \"lots\"\n")]
+#[case::unknown_field("[module_max_lines]\nmax_files = 3\n")]
+fn invalid_shared_overrides_are_reported(#[case] source: &str) {
+    let files = [("/work/whitaker.toml", source)];
+
+    let outcome = discover(&files, "/work/lib.rs").apply_to(&SharedConfig::default());
+
+    assert!(matches!(outcome, Err(DirectoryConfigError::Invalid { .. })));
+}
+
+#[rstest]
+fn malformed_override_files_are_reported() {
+    let outcome = DirectoryOverrides::discover_with(
+        Utf8Path::new("/work"),
+        Utf8Path::new("/work/lib.rs"),
+        tree(&[("/work/whitaker.toml", "[module_max_lines")]),
+    );
+
+    assert!(matches!(
+        outcome,
+        Err(DirectoryConfigError::Parse { path, .. }) if path == "/work/whitaker.toml"
+    ));
+}
+
+#[rstest]
+fn files_outside_the_root_are_rejected() {
+    let outcome = DirectoryOverrides::discover_with(
+        Utf8Path::new("/work"),
+        Utf8Path::new("/elsewhere/lib.rs"),
+        tree(&[]),
+    );
+
+    assert!(matches!(
+        outcome,
+        Err(DirectoryConfigError::OutsideRoot { .. })
+    ));
+}
+
+#[rstest]
+fn shared_keys_cover_the_serialised_schema() {
+    let config = SharedConfig {
+        behaviour_version: [("bumpy_road_function".to_owned(), 2)].into(),
+        crate_summary: true,
+        locale: Some("cy".to_owned()),
+        max_findings_per_lint_per_crate: Some(25),
+        module_max_lines: ModuleMaxLinesConfig::default(),
+    };
+
+    let table = Table::try_from(&config).expect("shared config should serialise");
+
+    assert!(
+        table
+            .keys()
+            .all(|key| SHARED_CONFIG_KEYS.contains(&key.as_str()))
+    );
+    assert_eq!(table.len(), SHARED_CONFIG_KEYS.len());
+}
@@ -6,14 +6,44 @@
 //! The loader defers to `dylint_linting::config_or_default` so that the
 //! semantics match what Dylint expects: values are deserialized from
 //! `dylint.toml` when present and fall back to sensible defaults otherwise.
+//! Nested `whitaker.toml` files can then tighten or relax those settings for
+//! individual directories through [`DirectoryOverrides`].
 
-use serde::Deserialize;
+mod behaviour;
+mod directory;
+mod module_max_lines;
+
+pub use behaviour::BehaviourVersion;
+pub use module_max_lines::{LineCountMode, ModuleMaxLinesConfig};
+
+pub(crate) use directory::read_if_present;
+pub use directory::{
+    DIRECTORY_CONFIG_FILE, DirectoryConfigError, DirectoryOverrides, OverrideLayer, merge_tables,
+};
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
 use whitaker_common::i18n::normalise_locale;
 
 /// Shared configuration for the workspace-level crate.
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct SharedConfig {
+    /// Behaviour versions pinned per lint, keyed by lint name.
+    ///
+    /// Fixes that make a lint report more findings ship behind a
+    /// [`BehaviourVersion`] so that upgrading the binaries does not fail CI.
+    /// Lints missing from the table run at [`BehaviourVersion::DEFAULT`].
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub behaviour_version: BTreeMap<String, u32>,
+    /// Emits a note after each crate with every lint's finding total.
+    ///
+    /// CI logs can hold hundreds of diagnostics, so the summary repeats the
+    /// headline numbers at the end of each crate. The suite emits it only
+    /// when this is enabled, and only for crates with at least one finding.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub crate_summary: bool,
     /// Preferred locale for Whitaker lints when the environment is silent.
     ///
     /// This optional override allows CI and editor integrations to pin a
@@ -21,7 +51,16 @@ pub struct SharedConfig {
     /// variables. The resolver trims whitespace and ignores blank values, so
     /// configuration such as `locale = ""` falls back cleanly to the bundled
     /// default.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub locale: Option<String>,
+    /// Most findings each lint reports per crate before summarising the rest.
+    ///
+    /// Legacy crates can produce thousands of findings for a single lint. When
+    /// set, each lint emits at most this many diagnostics per crate and then
+    /// one summary with the remaining count. Leaving it unset reports every
+    /// finding.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_findings_per_lint_per_crate: Option<usize>,
     /// Overrides for the `module_max_lines` lint. This field falls back to
     /// its default when omitted from `dylint.toml`, which avoids duplicating the
     /// baseline settings in every workspace.
@@ -35,6 +74,12 @@ impl SharedConfig {
     /// [`Self::load_with`] variant allows downstream lint crates to resolve their own
     /// configuration namespace explicitly.
     ///
+    /// # Panics
+    ///
+    /// Panics when the crate is built without the `dylint-driver` feature,
+    /// because the Dylint loader is unavailable. Use [`Self::load_with`] to
+    /// inject a stub loader instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -86,6 +131,30 @@ impl SharedConfig {
         loader(crate_name)
     }
 
+    /// Returns the behaviour version configured for `lint_name`, or
+    /// [`BehaviourVersion::DEFAULT`] when the lint is not listed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::SharedConfig;
+    /// use whitaker::config::BehaviourVersion;
+    ///
+    /// let mut config = SharedConfig::default();
+    /// config.behaviour_version.insert("bumpy_road_function".to_owned(), 2);
+    ///
+    /// assert!(config.behaviour_version("bumpy_road_function").enables(2));
+    /// assert_eq!(config.behaviour_version("no_shadowing"), BehaviourVersion::DEFAULT);
+    /// ```
+    #[must_use]
+    pub fn behaviour_version(&self, lint_name: &str) -> BehaviourVersion {
+        self.behaviour_version
+            .get(lint_name)
+            .map_or(BehaviourVersion::DEFAULT, |&version| {
+                BehaviourVersion::new(version)
+            })
+    }
+
     /// Returns the configured locale override, if present.
     ///
     /// Whitespace-only values are treated as absent to avoid surprising
@@ -96,29 +165,6 @@ impl SharedConfig {
     }
 }
 
-/// Settings that influence the forthcoming `module_max_lines` lint.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
-#[serde(default, deny_unknown_fields)]
-pub struct ModuleMaxLinesConfig {
-    /// Maximum number of lines permitted per module before the lint fires.
-    #[serde(default = "ModuleMaxLinesConfig::default_max_lines")]
-    pub max_lines: usize,
-}
-
-impl ModuleMaxLinesConfig {
-    const fn default_max_lines() -> usize {
-        400
-    }
-}
-
-impl Default for ModuleMaxLinesConfig {
-    fn default() -> Self {
-        Self {
-            max_lines: Self::default_max_lines(),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +187,7 @@ mod tests {
             .expect("expected configuration to parse successfully");
 
         assert_eq!(config.module_max_lines.max_lines, 120);
+        assert_eq!(config.module_max_lines.count_mode, LineCountMode::Physical);
     }
 
     #[rstest]
@@ -153,6 +200,49 @@ mod tests {
         assert_eq!(config.locale(), Some("cy"));
     }
 
+    #[rstest]
+    fn finding_limit_defaults_to_unlimited() {
+        let config = SharedConfig::default();
+
+        assert!(config.max_findings_per_lint_per_crate.is_none());
+    }
+
+    #[rstest]
+    fn deserialises_crate_summary() {
+        let config = toml::from_str::<SharedConfig>("crate_summary = true\n")
+            .expect("expected configuration to parse successfully");
+
+        assert!(config.crate_summary);
+        assert!(!SharedConfig::default().crate_summary);
+    }
+
+    #[rstest]
+    fn deserialises_behaviour_versions() {
+        let source = "[behaviour_version]\nbumpy_road_function = 2\n";
+
+        let config = toml::from_str::<SharedConfig>(source)
+            .expect("expected configuration to parse successfully");
+
+        assert_eq!(
+            config.behaviour_version("bumpy_road_function"),
+            BehaviourVersion::new(2)
+        );
+        assert_eq!(
+            config.behaviour_version("no_shadowing"),
+            BehaviourVersion::DEFAULT
+        );
+    }
+
+    #[rstest]
+    fn deserialises_finding_limit() {
+        let source = "max_findings_per_lint_per_crate = 50\n";
+
+        let config = toml::from_str::<SharedConfig>(source)
+            .expect("expected configuration to parse successfully");
+
+        assert_eq!(config.max_findings_per_lint_per_crate, Some(50));
+    }
+
     #[rstest]
     fn trims_whitespace_only_locale_entries() {
         let source = "locale = \"  \"\n";
@@ -196,8 +286,14 @@ mod tests {
         fn stub_loader(crate_name: &str) -> SharedConfig {
             assert_eq!(crate_name, "module_max_lines");
             SharedConfig {
+                behaviour_version: BTreeMap::new(),
+                crate_summary: false,
                 locale: None,
-                module_max_lines: ModuleMaxLinesConfig { max_lines: 123 },
+                max_findings_per_lint_per_crate: None,
+                module_max_lines: ModuleMaxLinesConfig {
+                    max_lines: 123,
+                    ..ModuleMaxLinesConfig::default()
+                },
             }
         }
 
@@ -0,0 +1,163 @@
+//! Settings for the `module_max_lines` lint.
+//!
+//! The table sits in [`super::SharedConfig`] as well as under the lint's own
+//! name, so both the suite and the standalone lint read the same defaults.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use whitaker_common::path::module_override;
+use whitaker_common::span::{LineKind, classify_lines};
+
+/// Settings that influence the forthcoming `module_max_lines` lint.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModuleMaxLinesConfig {
+    /// Maximum number of lines permitted per module before the lint fires.
+    #[serde(default = "ModuleMaxLinesConfig::default_max_lines")]
+    pub max_lines: usize,
+    /// Which lines count towards `max_lines`. Defaults to every line.
+    pub count_mode: LineCountMode,
+    /// Line budgets for particular modules, keyed by module path such as
+    /// `crate::generated`. Defaults to no overrides.
+    pub overrides: BTreeMap<String, usize>,
+}
+
+impl ModuleMaxLinesConfig {
+    const fn default_max_lines() -> usize {
+        400
+    }
+
+    /// Returns the line budget for the module at `path`.
+    ///
+    /// An override applies to the module it names and to every module nested
+    /// inside it; when several match, the one naming the deepest module wins.
+    /// Modules no override matches use `max_lines`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::ModuleMaxLinesConfig;
+    ///
+    /// let config = ModuleMaxLinesConfig {
+    ///     overrides: [("crate::generated".to_owned(), 2000)].into(),
+    ///     ..ModuleMaxLinesConfig::default()
+    /// };
+    /// assert_eq!(config.max_lines_for("generated::schema"), 2000);
+    /// assert_eq!(config.max_lines_for("handlers"), 400);
+    /// ```
+    #[must_use]
+    pub fn max_lines_for(&self, path: &str) -> usize {
+        module_override(&self.overrides, path).unwrap_or(self.max_lines)
+    }
+}
+
+impl Default for ModuleMaxLinesConfig {
+    fn default() -> Self {
+        Self {
+            max_lines: Self::default_max_lines(),
+            count_mode: LineCountMode::default(),
+            overrides: BTreeMap::new(),
+        }
+    }
+}
+
+/// Which source lines count towards a line limit.
+///
+/// Documentation-heavy modules can exceed a physical line limit while holding
+/// little code, so `code_only` and `non_blank` let teams measure what they
+/// intend to limit.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineCountMode {
+    /// Every line, blank and comment lines included.
+    #[default]
+    Physical,
+    /// Lines holding code; blank and comment-only lines are skipped.
+    CodeOnly,
+    /// Lines holding code or comments; blank lines are skipped.
+    NonBlank,
+}
+
+impl LineCountMode {
+    /// Counts the lines of `source` this mode includes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::config::LineCountMode;
+    ///
+    /// let source = "//! Docs.\n\nfn main() {}\n";
+    /// assert_eq!(LineCountMode::Physical.count(source), 3);
+    /// assert_eq!(LineCountMode::NonBlank.count(source), 2);
+    /// assert_eq!(LineCountMode::CodeOnly.count(source), 1);
+    /// ```
+    #[must_use]
+    pub fn count(self, source: &str) -> usize {
+        match self {
+            Self::Physical => source.lines().count(),
+            Self::CodeOnly | Self::NonBlank => classify_lines(source)
+                .into_iter()
+                .filter(|kind| self.includes(*kind))
+                .count(),
+        }
+    }
+
+    /// Returns `true` when lines of `kind` count under this mode.
+    #[must_use]
+    pub const fn includes(self, kind: LineKind) -> bool {
+        match self {
+            Self::Physical => true,
+            Self::CodeOnly => matches!(kind, LineKind::Code),
+            Self::NonBlank => !matches!(kind, LineKind::Blank),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::physical("physical", LineCountMode::Physical)]
+    #[case::code_only("code_only", LineCountMode::CodeOnly)]
+    #[case::non_blank("non_blank", LineCountMode::NonBlank)]
+    fn deserialises_count_modes(#[case] value: &str, #[case] expected: LineCountMode) {
+        let source = format!("count_mode = \"{value}\"\n");
+
+        let config = toml::from_str::<ModuleMaxLinesConfig>(&source)
+            .expect("expected configuration to parse successfully");
+
+        assert_eq!(config.count_mode, expected);
+    }
+
+    #[rstest]
+    #[case::named("crate::generated", 2000)]
+    #[case::nested("crate::generated::schema", 2000)]
+    #[case::deepest_wins("crate::generated::migrations", 5000)]
+    #[case::sibling_prefix("crate::generated_extra", 400)]
+    #[case::unanchored_path("handlers", 120)]
+    #[case::unmatched("crate::routes", 400)]
+    fn selects_module_overrides(#[case] path: &str, #[case] expected: usize) {
+        let config = toml::from_str::<ModuleMaxLinesConfig>(
+            "[overrides]\n\
+             \"crate::generated\" = 2000\n\
+             \"crate::generated::migrations\" = 5000\n\
+             \"handlers\" = 120\n",
+        )
+        .expect("expected configuration to parse successfully");
+
+        assert_eq!(config.max_lines_for(path), expected);
+    }
+
+    #[rstest]
+    #[case::physical(LineCountMode::Physical, 5)]
+    #[case::code_only(LineCountMode::CodeOnly, 2)]
+    #[case::non_blank(LineCountMode::NonBlank, 4)]
+    fn counts_the_lines_each_mode_includes(#[case] mode: LineCountMode, #[case] expected: usize) {
+        let source = "//! Docs.\n\n/// Item.\nfn f() {}\nfn g() {} // note\n";
+
+        assert_eq!(mode.count(source), expected);
+    }
+}
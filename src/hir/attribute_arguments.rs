@@ -0,0 +1,298 @@
+//! Lowering of unparsed HIR attribute arguments into
+//! [`whitaker_common::AttributeArguments`].
+//!
+//! rustc keeps the arguments of attributes it does not interpret itself as a
+//! token stream. [`attribute_arguments`] reads them through rustc's own
+//! meta-item parser so lints receive words, `key = literal` pairs, and nested
+//! lists instead of raw tokens.
+
+use rustc_ast::ast::{MetaItem, MetaItemInner, MetaItemKind, MetaItemLit};
+use rustc_ast::token::LitKind as TokenLitKind;
+use rustc_ast::{LitKind, Path};
+use rustc_hir as hir;
+use whitaker_common::{AttributeArgument, AttributeArguments, AttributeLiteral, AttributePath};
+
+/// Returns the structured arguments of an unparsed attribute.
+///
+/// Returns `None` for attributes rustc has already parsed into its own
+/// representation (such as `#[must_use]` or doc comments), and for
+/// parenthesised arguments that are not a comma-separated meta-item list,
+/// such as the body of `#[cfg_attr]`-generated macro invocations.
+///
+/// # Examples
+///
+/// ```ignore
+/// let reason = cx
+///     .tcx
+///     .hir_attrs(hir_id)
+///     .iter()
+///     .filter_map(attribute_arguments)
+///     .find_map(|arguments| arguments.reason().map(str::to_owned));
+/// ```
+#[must_use]
+pub fn attribute_arguments(attr: &hir::Attribute) -> Option<AttributeArguments> {
+    let hir::Attribute::Unparsed(item) = attr else {
+        return None;
+    };
+    match &item.args {
+        hir::AttrArgs::Empty => Some(AttributeArguments::Empty),
+        hir::AttrArgs::Eq { expr, .. } => Some(AttributeArguments::Value(literal(expr))),
+        hir::AttrArgs::Delimited(_) => attr
+            .meta_item_list()
+            .map(|items| AttributeArguments::List(items.iter().map(argument).collect())),
+    }
+}
+
+fn argument(item: &MetaItemInner) -> AttributeArgument {
+    match item {
+        MetaItemInner::MetaItem(meta) => meta_argument(meta),
+        MetaItemInner::Lit(lit) => AttributeArgument::Literal(literal(lit)),
+    }
+}
+
+fn meta_argument(meta: &MetaItem) -> AttributeArgument {
+    let path = attribute_path(&meta.path);
+    match &meta.kind {
+        MetaItemKind::Word => AttributeArgument::Word(path),
+        MetaItemKind::List(items) => AttributeArgument::List {
+            path,
+            arguments: items.iter().map(argument).collect(),
+        },
+        MetaItemKind::NameValue(lit) => AttributeArgument::KeyValue {
+            key: path,
+            value: literal(lit),
+        },
+    }
+}
+
+fn attribute_path(path: &Path) -> AttributePath {
+    AttributePath::new(
+        path.segments
+            .iter()
+            .map(|segment| segment.ident.name.to_string()),
+    )
+}
+
+fn literal(lit: &MetaItemLit) -> AttributeLiteral {
+    match lit.kind {
+        LitKind::Str(value, _) => AttributeLiteral::Str(value.to_string()),
+        LitKind::Bool(value) => AttributeLiteral::Bool(value),
+        _ => AttributeLiteral::Other(source_text(lit)),
+    }
+}
+
+/// Rebuilds a literal as written, restoring the quotes the symbol omits.
+fn source_text(lit: &MetaItemLit) -> String {
+    let token = lit.as_token_lit();
+    let suffix = token
+        .suffix
+        .map(|suffix| suffix.to_string())
+        .unwrap_or_default();
+    let body = token.symbol.to_string();
+    match token.kind {
+        TokenLitKind::Char => format!("'{body}'{suffix}"),
+        TokenLitKind::Byte => format!("b'{body}'{suffix}"),
+        TokenLitKind::ByteStr => format!("b\"{body}\"{suffix}"),
+        TokenLitKind::CStr => format!("c\"{body}\"{suffix}"),
+        _ => format!("{body}{suffix}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use rustc_ast::AttrStyle;
+    use rustc_ast::ast::{AttrId, DelimArgs};
+    use rustc_ast::token::{self, Delimiter, IdentIsRaw, TokenKind};
+    use rustc_ast::tokenstream::{DelimSpacing, DelimSpan, Spacing, TokenStream, TokenTree};
+    use rustc_span::{DUMMY_SP, Symbol, create_default_session_globals_then};
+
+    fn ident(name: &str) -> TokenTree {
+        TokenTree::token_alone(
+            TokenKind::Ident(Symbol::intern(name), IdentIsRaw::No),
+            DUMMY_SP,
+        )
+    }
+
+    fn punct(kind: TokenKind) -> TokenTree {
+        TokenTree::token_alone(kind, DUMMY_SP)
+    }
+
+    fn lit(kind: token::LitKind, text: &str) -> TokenTree {
+        punct(TokenKind::Literal(token::Lit::new(
+            kind,
+            Symbol::intern(text),
+            None,
+        )))
+    }
+
+    fn parenthesised(tokens: Vec<TokenTree>) -> TokenTree {
+        TokenTree::Delimited(
+            DelimSpan::from_single(DUMMY_SP),
+            DelimSpacing::new(Spacing::Alone, Spacing::Alone),
+            Delimiter::Parenthesis,
+            TokenStream::new(tokens),
+        )
+    }
+
+    fn unparsed(name: &str, args: hir::AttrArgs) -> hir::Attribute {
+        hir::Attribute::Unparsed(Box::new(hir::AttrItem {
+            path: hir::AttrPath {
+                segments: vec![Symbol::intern(name)].into_boxed_slice(),
+                span: DUMMY_SP,
+            },
+            args,
+            id: hir::HashIgnoredAttrId {
+                attr_id: AttrId::from_u32(0),
+            },
+            style: AttrStyle::Outer,
+            span: DUMMY_SP,
+        }))
+    }
+
+    fn delimited(name: &str, tokens: Vec<TokenTree>) -> hir::Attribute {
+        unparsed(
+            name,
+            hir::AttrArgs::Delimited(DelimArgs {
+                dspan: DelimSpan::from_single(DUMMY_SP),
+                delim: Delimiter::Parenthesis,
+                tokens: TokenStream::new(tokens),
+            }),
+        )
+    }
+
+    fn word(path: &str) -> AttributeArgument {
+        AttributeArgument::Word(AttributePath::from(path))
+    }
+
+    #[rstest]
+    fn lowers_words_pairs_and_paths() {
+        create_default_session_globals_then(|| {
+            // #[allow(dead_code, clippy::unwrap_used, reason = "kept for FFI")]
+            let attr = delimited(
+                "allow",
+                vec![
+                    ident("dead_code"),
+                    punct(TokenKind::Comma),
+                    ident("clippy"),
+                    punct(TokenKind::PathSep),
+                    ident("unwrap_used"),
+                    punct(TokenKind::Comma),
+                    ident("reason"),
+                    punct(TokenKind::Eq),
+                    lit(token::LitKind::Str, "kept for FFI"),
+                ],
+            );
+
+            let arguments = attribute_arguments(&attr).unwrap_or_default();
+
+            assert_eq!(
+                arguments.items().first(),
+                Some(&word("dead_code")),
+                "{arguments:?}"
+            );
+            let words: Vec<String> = arguments.words().map(ToString::to_string).collect();
+            assert_eq!(words, ["dead_code", "clippy::unwrap_used"]);
+            assert_eq!(arguments.reason(), Some("kept for FFI"));
+        });
+    }
+
+    #[rstest]
+    fn lowers_nested_lists_and_literals() {
+        create_default_session_globals_then(|| {
+            // #[cfg(all(not(test), 42, true))]
+            let attr = delimited(
+                "cfg",
+                vec![
+                    ident("all"),
+                    parenthesised(vec![
+                        ident("not"),
+                        parenthesised(vec![ident("test")]),
+                        punct(TokenKind::Comma),
+                        lit(token::LitKind::Integer, "42"),
+                        punct(TokenKind::Comma),
+                        ident("true"),
+                    ]),
+                ],
+            );
+
+            let arguments = attribute_arguments(&attr).unwrap_or_default();
+
+            let expected = AttributeArgument::List {
+                path: AttributePath::from("all"),
+                arguments: vec![
+                    AttributeArgument::List {
+                        path: AttributePath::from("not"),
+                        arguments: vec![word("test")],
+                    },
+                    AttributeArgument::Literal(AttributeLiteral::Other("42".into())),
+                    AttributeArgument::Literal(AttributeLiteral::Bool(true)),
+                ],
+            };
+            assert_eq!(arguments.items(), [expected]);
+        });
+    }
+
+    #[rstest]
+    fn lowers_value_and_empty_forms() {
+        create_default_session_globals_then(|| {
+            let empty = unparsed("inline", hir::AttrArgs::Empty);
+            assert_eq!(attribute_arguments(&empty), Some(AttributeArguments::Empty));
+
+            let value = unparsed(
+                "ignore",
+                hir::AttrArgs::Eq {
+                    eq_span: DUMMY_SP,
+                    expr: MetaItemLit {
+                        symbol: Symbol::intern("needs network"),
+                        suffix: None,
+                        kind: LitKind::Str(
+                            Symbol::intern("needs network"),
+                            rustc_ast::StrStyle::Cooked,
+                        ),
+                        span: DUMMY_SP,
+                    },
+                },
+            );
+            assert_eq!(
+                attribute_arguments(&value)
+                    .and_then(|arguments| arguments.reason().map(str::to_owned)),
+                Some("needs network".to_owned())
+            );
+        });
+    }
+
+    #[rstest]
+    fn rejects_token_soup() {
+        create_default_session_globals_then(|| {
+            // #[custom(a b)] is not a meta-item list.
+            let attr = delimited("custom", vec![ident("a"), ident("b")]);
+
+            assert_eq!(attribute_arguments(&attr), None);
+        });
+    }
+
+    #[rstest]
+    #[case::character(token::LitKind::Char, "x", "'x'")]
+    #[case::byte_string(token::LitKind::ByteStr, "raw", "b\"raw\"")]
+    #[case::float(token::LitKind::Float, "1.5", "1.5")]
+    fn other_literals_keep_source_text(
+        #[case] kind: token::LitKind,
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        create_default_session_globals_then(|| {
+            let attr = delimited("custom", vec![lit(kind, text)]);
+
+            let arguments = attribute_arguments(&attr).unwrap_or_default();
+
+            assert_eq!(
+                arguments.items(),
+                [AttributeArgument::Literal(AttributeLiteral::Other(
+                    expected.into()
+                ))]
+            );
+        });
+    }
+}
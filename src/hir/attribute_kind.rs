@@ -0,0 +1,107 @@
+//! Classification of HIR attributes as inner or outer.
+//!
+//! rustc records whether an attribute was written as `#![...]` or `#[...]`
+//! when it lowers the attribute, so lints can read the style from HIR instead
+//! of re-reading the source text behind each attribute's span.
+
+use rustc_ast::AttrStyle;
+use rustc_hir as hir;
+use rustc_hir::attrs::AttributeKind as HirAttributeKind;
+use whitaker_common::AttributeKind;
+
+/// Returns whether `attr` is an inner or an outer attribute.
+///
+/// Unparsed attributes and doc comments carry the style they were written
+/// with. Other attributes rustc has parsed into its own representation do not
+/// record a style and are reported as outer, which is how they are almost
+/// always written.
+///
+/// # Examples
+///
+/// ```ignore
+/// let inner_docs = cx
+///     .tcx
+///     .hir_attrs(hir_id)
+///     .iter()
+///     .filter(|attr| attr.doc_str().is_some() && attribute_kind(attr).is_inner())
+///     .count();
+/// ```
+#[must_use]
+pub fn attribute_kind(attr: &hir::Attribute) -> AttributeKind {
+    match attribute_style(attr) {
+        AttrStyle::Inner => AttributeKind::Inner,
+        AttrStyle::Outer => AttributeKind::Outer,
+    }
+}
+
+fn attribute_style(attr: &hir::Attribute) -> AttrStyle {
+    match attr {
+        hir::Attribute::Unparsed(item) => item.style,
+        hir::Attribute::Parsed(HirAttributeKind::DocComment { style, .. }) => *style,
+        hir::Attribute::Parsed(_) => AttrStyle::Outer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use rustc_ast::ast::AttrId;
+    use rustc_ast::token::{CommentKind, DocFragmentKind};
+    use rustc_span::{DUMMY_SP, Symbol, create_default_session_globals_then};
+
+    fn unparsed(style: AttrStyle) -> hir::Attribute {
+        hir::Attribute::Unparsed(Box::new(hir::AttrItem {
+            path: hir::AttrPath {
+                segments: vec![Symbol::intern("allow")].into_boxed_slice(),
+                span: DUMMY_SP,
+            },
+            args: hir::AttrArgs::Empty,
+            id: hir::HashIgnoredAttrId {
+                attr_id: AttrId::from_u32(0),
+            },
+            style,
+            span: DUMMY_SP,
+        }))
+    }
+
+    fn doc_comment(style: AttrStyle) -> hir::Attribute {
+        hir::Attribute::Parsed(HirAttributeKind::DocComment {
+            style,
+            kind: DocFragmentKind::Sugared(CommentKind::Line),
+            span: DUMMY_SP,
+            comment: Symbol::intern("Docs."),
+        })
+    }
+
+    #[rstest]
+    #[case::inner(AttrStyle::Inner, AttributeKind::Inner)]
+    #[case::outer(AttrStyle::Outer, AttributeKind::Outer)]
+    fn unparsed_attributes_keep_their_style(
+        #[case] style: AttrStyle,
+        #[case] expected: AttributeKind,
+    ) {
+        create_default_session_globals_then(|| {
+            assert_eq!(attribute_kind(&unparsed(style)), expected);
+        });
+    }
+
+    #[rstest]
+    #[case::inner(AttrStyle::Inner, AttributeKind::Inner)]
+    #[case::outer(AttrStyle::Outer, AttributeKind::Outer)]
+    fn doc_comments_keep_their_style(#[case] style: AttrStyle, #[case] expected: AttributeKind) {
+        create_default_session_globals_then(|| {
+            assert_eq!(attribute_kind(&doc_comment(style)), expected);
+        });
+    }
+
+    #[rstest]
+    fn other_parsed_attributes_are_outer() {
+        let attr = hir::Attribute::Parsed(HirAttributeKind::MustUse {
+            span: DUMMY_SP,
+            reason: None,
+        });
+
+        assert_eq!(attribute_kind(&attr), AttributeKind::Outer);
+    }
+}
@@ -0,0 +1,217 @@
+//! Attribution of findings inside helper functions to their call sites.
+//!
+//! A finding inside a small helper yields one diagnostic at the helper, which
+//! hides the callers that need to change. Lints that accept a `blame_helpers`
+//! list build [`HelperCallSites`] in `check_crate`: it finds the listed
+//! functions defined in the crate and every call to them, looking one level
+//! deep. [`HelperCallSites::finding_sites`] then moves a finding inside a
+//! helper to each of those calls, keeping a note on the original expression.
+
+use std::collections::HashMap;
+
+use rustc_hir as hir;
+use rustc_hir::ExprKind;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE, LocalDefId};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+use whitaker_common::SimplePath;
+use whitaker_common::context::BlamedHelper;
+use whitaker_common::i18n::BundleLookup;
+
+/// Calls to the configured helper functions of the crate being linted.
+#[derive(Clone, Debug, Default)]
+pub struct HelperCallSites {
+    helpers: HashMap<LocalDefId, Helper>,
+}
+
+#[derive(Clone, Debug)]
+struct Helper {
+    blamed: BlamedHelper,
+    call_sites: Vec<CallSite>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CallSite {
+    hir_id: hir::HirId,
+    span: Span,
+}
+
+/// Where a finding is reported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FindingSite {
+    /// The node the diagnostic is attributed to.
+    pub hir_id: hir::HirId,
+    /// The span the diagnostic points at.
+    pub span: Span,
+    /// The helper expression the finding was moved from, when it was moved.
+    pub origin: Option<FindingOrigin>,
+}
+
+/// The expression inside a helper that a redirected finding came from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FindingOrigin {
+    /// The span of the flagged expression inside the helper.
+    pub span: Span,
+    /// A localised note naming the helper.
+    pub note: String,
+}
+
+impl FindingSite {
+    /// Returns `true` when the finding was moved to a call of a helper.
+    #[must_use]
+    pub const fn is_redirected(&self) -> bool {
+        self.origin.is_some()
+    }
+}
+
+impl HelperCallSites {
+    /// Collects the calls to `helpers` in the crate being linted.
+    ///
+    /// Each helper is a fully qualified path whose first segment is the crate
+    /// name, such as `my_crate::util::fetch`; a leading `::` is ignored. Only
+    /// functions and methods defined in the crate can be helpers. The crate's
+    /// bodies are walked only when at least one helper is found.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let helper_calls = HelperCallSites::for_crate(cx, &config.blame_helpers);
+    /// ```
+    #[must_use]
+    pub fn for_crate(cx: &LateContext<'_>, helpers: &[String]) -> Self {
+        if helpers.is_empty() {
+            return Self::default();
+        }
+
+        let configured: Vec<_> = helpers
+            .iter()
+            .map(|path| SimplePath::from(path.as_str()))
+            .collect();
+        let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+        let crate_items = cx.tcx.hir_crate_items(());
+        let mut found = HashMap::new();
+        for def_id in crate_items.definitions() {
+            if !matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+                continue;
+            }
+            let path = cx.tcx.def_path_str(def_id);
+            let qualified = SimplePath::from(format!("{crate_name}::{path}"));
+            if configured.contains(&qualified) {
+                let helper = Helper {
+                    blamed: BlamedHelper::new(path),
+                    call_sites: Vec::new(),
+                };
+                found.insert(def_id, helper);
+            }
+        }
+
+        if !found.is_empty() {
+            for owner in cx.tcx.hir_body_owners() {
+                let mut collector = CallCollector {
+                    cx,
+                    owner,
+                    helpers: &mut found,
+                };
+                collector.visit_body(cx.tcx.hir_body_owned_by(owner));
+            }
+        }
+        Self { helpers: found }
+    }
+
+    /// Returns where a finding at `expr` should be reported.
+    ///
+    /// A finding inside a configured helper that is called somewhere in the
+    /// crate is reported at each call, carrying a note in the lookup's locale
+    /// that points back at `expr`. Any other finding, including one in a
+    /// helper nobody in the crate calls, stays at `expr`. Lints apply their
+    /// own exemptions, such as for test code, to redirected sites.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for site in helper_calls.finding_sites(cx, expr, &localizer) {
+    ///     if site.is_redirected() && test_context.is_test(cx, site.hir_id) {
+    ///         continue;
+    ///     }
+    ///     emit_diagnostic(cx, &site, &info, &localizer);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn finding_sites(
+        &self,
+        cx: &LateContext<'_>,
+        expr: &hir::Expr<'_>,
+        lookup: &impl BundleLookup,
+    ) -> Vec<FindingSite> {
+        let body_owner = cx.tcx.hir_enclosing_body_owner(expr.hir_id);
+        let owner = cx
+            .tcx
+            .typeck_root_def_id(body_owner.to_def_id())
+            .expect_local();
+        let Some(helper) = self
+            .helpers
+            .get(&owner)
+            .filter(|helper| !helper.call_sites.is_empty())
+        else {
+            return vec![FindingSite {
+                hir_id: expr.hir_id,
+                span: expr.span,
+                origin: None,
+            }];
+        };
+
+        let note = helper.blamed.describe(lookup);
+        helper
+            .call_sites
+            .iter()
+            .map(|site| FindingSite {
+                hir_id: site.hir_id,
+                span: site.span,
+                origin: Some(FindingOrigin {
+                    span: expr.span,
+                    note: note.clone(),
+                }),
+            })
+            .collect()
+    }
+}
+
+/// Records the calls to helpers made directly in one body.
+struct CallCollector<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    owner: LocalDefId,
+    helpers: &'a mut HashMap<LocalDefId, Helper>,
+}
+
+impl CallCollector<'_, '_> {
+    /// Returns the function or method `expr` calls, if it is a call.
+    fn callee(&self, expr: &hir::Expr<'_>) -> Option<DefId> {
+        let typeck = self.cx.tcx.typeck(self.owner);
+        match expr.kind {
+            ExprKind::Call(callee, _) => match callee.kind {
+                ExprKind::Path(ref qpath) => typeck.qpath_res(qpath, callee.hir_id).opt_def_id(),
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => typeck.type_dependent_def_id(expr.hir_id),
+            _ => None,
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for CallCollector<'_, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let Some(helper) = self
+            .callee(expr)
+            .and_then(DefId::as_local)
+            .and_then(|def_id| self.helpers.get_mut(&def_id))
+        {
+            helper.call_sites.push(CallSite {
+                hir_id: expr.hir_id,
+                span: expr.span,
+            });
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
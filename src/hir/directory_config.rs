@@ -0,0 +1,108 @@
+//! Per-source-file resolution of nested `whitaker.toml` overrides.
+//!
+//! [`DirectoryOverrides`] knows how to discover and merge override layers for
+//! one path; this module maps the span a lint is about to report onto that
+//! path. The discovery root is the session's working directory, which Cargo
+//! sets to the workspace root, falling back to the directory holding the
+//! crate root when the file lies elsewhere. Overrides are cached per source
+//! directory, so files in one directory share a single discovery. Each
+//! directory is discovered independently, however, so a `whitaker.toml` is
+//! read again for every distinct directory beneath it that holds a finding.
+//!
+//! An override file that cannot be read or parsed is logged and ignored, in
+//! line with how lints treat a malformed `dylint.toml` table.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use log::debug;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::{FileName, Span};
+
+use crate::config::DirectoryOverrides;
+
+const LOG_TARGET: &str = "whitaker::directory_config";
+
+/// Cache of the directory overrides that apply to the files of one crate.
+///
+/// Lints with their own thresholds lay the `[lint_name]` tables above a
+/// finding over their configuration with
+/// [`FindingLimit::config_at`](super::FindingLimit::config_at).
+#[derive(Clone, Debug, Default)]
+pub struct DirectoryConfig {
+    by_directory: RefCell<HashMap<Utf8PathBuf, Rc<DirectoryOverrides>>>,
+}
+
+impl DirectoryConfig {
+    /// Returns the overrides that apply to the file containing `span`.
+    ///
+    /// Spans outside any local file, such as those from other crates, get
+    /// no overrides.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let overrides = state.directories.overrides_at(cx, item.span);
+    /// ```
+    pub fn overrides_at(&self, cx: &LateContext<'_>, span: Span) -> Rc<DirectoryOverrides> {
+        let Some((root, file)) = discovery_paths(cx, span) else {
+            return Rc::default();
+        };
+        let Some(directory) = file.parent().map(Utf8Path::to_owned) else {
+            return Rc::default();
+        };
+
+        let mut cache = self.by_directory.borrow_mut();
+        let overrides = cache.entry(directory).or_insert_with(|| {
+            Rc::new(
+                DirectoryOverrides::discover(&root, &file).unwrap_or_else(|error| {
+                    debug!(target: LOG_TARGET, "ignoring directory overrides: {error}");
+                    DirectoryOverrides::default()
+                }),
+            )
+        });
+        Rc::clone(overrides)
+    }
+
+    /// Reports whether the `[lint]` tables above `span` leave `lint_name`
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if !state.directories.is_lint_enabled(cx, span, "module_max_lines") {
+    ///     return;
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_lint_enabled(&self, cx: &LateContext<'_>, span: Span, lint_name: &str) -> bool {
+        self.overrides_at(cx, span).is_lint_enabled(lint_name)
+    }
+}
+
+/// Returns the discovery root and the absolute path of the file containing
+/// `span`.
+fn discovery_paths(cx: &LateContext<'_>, span: Span) -> Option<(Utf8PathBuf, Utf8PathBuf)> {
+    let source_map = cx.sess().source_map();
+    let working_dir = utf8_path(source_map.working_dir().local_path()?)?.to_owned();
+    let FileName::Real(name) = source_map.span_to_filename(span) else {
+        return None;
+    };
+    let file = working_dir.join(utf8_path(name.local_path()?)?);
+
+    let crate_dir = cx
+        .sess()
+        .local_crate_source_file()
+        .and_then(|root| Some(working_dir.join(utf8_path(root.local_path()?)?.parent()?)));
+    [Some(working_dir), crate_dir]
+        .into_iter()
+        .flatten()
+        .find(|root| file.starts_with(root))
+        .map(|root| (root, file))
+}
+
+fn utf8_path(path: &std::path::Path) -> Option<&Utf8Path> {
+    Utf8Path::from_path(path)
+}
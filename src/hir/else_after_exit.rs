@@ -0,0 +1,197 @@
+//! Detection and removal of `else` blocks after `if` branches that always
+//! exit.
+//!
+//! `no_else_after_return` and `redundant_else_block_after_loop_control` share
+//! this logic and differ only in which [`ExitKind`]s they report.
+//! [`ElseAfterExit::find`] inspects an `if` with a plain `else` block,
+//! skipping `else if` chains and code produced by macro expansion or
+//! desugaring such as `while` loops. It matches when the `if` block's last
+//! statement or trailing expression is a `return`, `continue`, or `break`,
+//! and the `if` stands as a statement or as its block's trailing expression.
+//! [`ElseAfterExit::rewrite`] replaces everything from the end of the `if`
+//! block to the end of the `else` block with the `else` body re-indented at
+//! the `if`'s level, adding a semicolon after the body's trailing expression
+//! when more code follows. [`ElseRemoval`] decides whether the rewrite is
+//! machine-applicable.
+
+use rustc_hir as hir;
+use rustc_lint::errors::Applicability;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::{InnerSpan, Span};
+use whitaker_common::else_removal::{ElseRemoval, ExitKind, else_keyword, reindent_block_body};
+
+/// An `else` block following an `if` block that always exits.
+#[derive(Clone, Copy, Debug)]
+pub struct ElseAfterExit<'tcx> {
+    if_expr: &'tcx hir::Expr<'tcx>,
+    then: &'tcx hir::Expr<'tcx>,
+    else_block: &'tcx hir::Block<'tcx>,
+    else_span: Span,
+    exit: ExitKind,
+    removal: ElseRemoval,
+}
+
+/// The rewrite that removes an `else` and dedents its body.
+#[derive(Clone, Debug)]
+pub struct ElseRewrite {
+    /// Span from the end of the `if` block to the end of the `else` block.
+    pub span: Span,
+    /// The dedented `else` body, or an empty string for an empty body.
+    pub replacement: String,
+    /// Whether the rewrite can be applied without review.
+    pub applicability: Applicability,
+}
+
+impl<'tcx> ElseAfterExit<'tcx> {
+    /// Returns the redundant `else` of `expr` when `expr` is an `if` whose
+    /// block ends in an exit that `reports` accepts.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let Some(finding) = ElseAfterExit::find(cx, expr, ExitKind::is_loop_control) else {
+    ///     return;
+    /// };
+    /// ```
+    pub fn find(
+        cx: &LateContext<'tcx>,
+        expr: &'tcx hir::Expr<'tcx>,
+        reports: impl FnOnce(ExitKind) -> bool,
+    ) -> Option<Self> {
+        let hir::ExprKind::If(_, then, Some(otherwise)) = expr.kind else {
+            return None;
+        };
+        let (hir::ExprKind::Block(then_block, _), hir::ExprKind::Block(else_block, _)) =
+            (then.kind, otherwise.kind)
+        else {
+            return None;
+        };
+        if expr.span.from_expansion() || otherwise.span.from_expansion() {
+            return None;
+        }
+        let exit = block_exit(then_block).filter(|&exit| reports(exit))?;
+        let code_follows = code_follows_statement(cx, expr)?;
+
+        let between = then.span.between(otherwise.span);
+        let keyword = cx
+            .sess()
+            .source_map()
+            .span_to_snippet(between)
+            .ok()
+            .and_then(|text| else_keyword(&text))?;
+
+        Some(Self {
+            if_expr: expr,
+            then,
+            else_block,
+            else_span: between.from_inner(InnerSpan::new(keyword.offset, keyword.offset + 4)),
+            exit,
+            removal: ElseRemoval {
+                code_follows,
+                declares_bindings: else_block
+                    .stmts
+                    .iter()
+                    .any(|stmt| matches!(stmt.kind, hir::StmtKind::Let(_))),
+                comments_before_else: !keyword.bare,
+            },
+        })
+    }
+
+    /// Returns the span of the `else` keyword, where the finding is
+    /// reported.
+    #[must_use]
+    pub const fn else_span(&self) -> Span {
+        self.else_span
+    }
+
+    /// Returns how the `if` block leaves.
+    #[must_use]
+    pub const fn exit(&self) -> ExitKind {
+        self.exit
+    }
+
+    /// Builds the rewrite replacing ` else { body }` with the dedented body,
+    /// or `None` when the source text is unavailable.
+    #[must_use]
+    pub fn rewrite(&self, cx: &LateContext<'_>) -> Option<ElseRewrite> {
+        let Self {
+            if_expr,
+            then,
+            else_block,
+            removal,
+            ..
+        } = *self;
+        let source_map = cx.sess().source_map();
+        let indent = source_map.indentation_before(if_expr.span)?;
+        let block_text = source_map.span_to_snippet(else_block.span).ok()?;
+        let mut body = block_text.strip_prefix('{')?.strip_suffix('}')?.to_owned();
+
+        // A trailing expression becomes a statement once code follows it.
+        if removal.code_follows
+            && let Some(tail) = else_block.expr
+        {
+            let tail_span = tail.span.find_ancestor_inside(else_block.span)?;
+            let offset = (tail_span.hi() - else_block.span.lo()).0 as usize - 1;
+            body.insert(offset, ';');
+        }
+
+        let dedented = reindent_block_body(&body, &indent);
+        let replacement = if dedented.is_empty() {
+            String::new()
+        } else {
+            format!("\n{dedented}")
+        };
+        let applicability = if removal.is_machine_applicable() {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        Some(ElseRewrite {
+            span: else_block.span.with_lo(then.span.hi()),
+            replacement,
+            applicability,
+        })
+    }
+}
+
+/// Returns how `block` leaves when its final statement or trailing
+/// expression is an exit.
+fn block_exit(block: &hir::Block<'_>) -> Option<ExitKind> {
+    let last = match block.expr {
+        Some(expr) => expr,
+        None => match block.stmts.last()?.kind {
+            hir::StmtKind::Semi(expr) | hir::StmtKind::Expr(expr) => expr,
+            _ => return None,
+        },
+    };
+    if last.span.from_expansion() {
+        return None;
+    }
+    match last.kind {
+        hir::ExprKind::Ret(_) => Some(ExitKind::Return),
+        hir::ExprKind::Continue(_) => Some(ExitKind::Continue),
+        hir::ExprKind::Break(..) => Some(ExitKind::Break),
+        _ => None,
+    }
+}
+
+/// Returns whether code follows `expr` in its block, or `None` when `expr`
+/// is not a statement or trailing expression and its value is used.
+fn code_follows_statement(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> Option<bool> {
+    match cx.tcx.parent_hir_node(expr.hir_id) {
+        hir::Node::Block(_) => Some(false),
+        hir::Node::Stmt(
+            stmt @ hir::Stmt {
+                kind: hir::StmtKind::Expr(_),
+                ..
+            },
+        ) => {
+            let hir::Node::Block(block) = cx.tcx.parent_hir_node(stmt.hir_id) else {
+                return None;
+            };
+            let is_last = block.stmts.last().map(|last| last.hir_id) == Some(stmt.hir_id);
+            Some(!is_last || block.expr.is_some())
+        }
+        _ => None,
+    }
+}
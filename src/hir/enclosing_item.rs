@@ -0,0 +1,123 @@
+//! Classification of the item enclosing a HIR node.
+//!
+//! [`describe_enclosing_item`] walks outwards from a node to the nearest
+//! function, method, closure, constant, or static and returns it as a
+//! [`whitaker_common::EnclosingItem`] with a span to point at, so diagnostics
+//! can say where a call originates in consistent, localised wording.
+
+use rustc_hir as hir;
+use rustc_hir::Node;
+use rustc_lint::LateContext;
+use rustc_span::{Ident, Span, kw};
+use whitaker_common::i18n::BundleLookup;
+use whitaker_common::{EnclosingItem, EnclosingItemKind};
+
+/// The item enclosing a HIR node and where it is declared.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnclosingItemDescription {
+    /// The classified item.
+    pub item: EnclosingItem,
+    /// The span of the item's name, or of the closure for closures; `None`
+    /// for the surrounding scope.
+    pub span: Option<Span>,
+}
+
+impl EnclosingItemDescription {
+    /// Describes the item in the lookup's locale.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let context = describe_enclosing_item(cx, expr.hir_id).describe(&localizer);
+    /// assert_eq!(context, "function `main`");
+    /// ```
+    #[must_use]
+    pub fn describe(&self, lookup: &impl BundleLookup) -> String {
+        self.item.describe(lookup)
+    }
+}
+
+/// Returns the innermost function, method, closure, constant, or static
+/// around `hir_id`.
+///
+/// Closures are described by the item that contains them, so a call inside
+/// `items.iter().map(|item| ...)` in `load` is "a closure in `load`". Async
+/// blocks are not closures for this purpose. Nodes outside any such item,
+/// such as those in module-level attributes, get the surrounding scope.
+///
+/// # Examples
+///
+/// ```ignore
+/// let enclosing = describe_enclosing_item(cx, expr.hir_id);
+/// if let Some(span) = enclosing.span {
+///     diag.span_note(span, enclosing.describe(&localizer));
+/// }
+/// ```
+#[must_use]
+pub fn describe_enclosing_item(
+    cx: &LateContext<'_>,
+    hir_id: hir::HirId,
+) -> EnclosingItemDescription {
+    let mut closure_span = None;
+    for (_, node) in cx.tcx.hir_parent_iter(hir_id) {
+        if let Node::Expr(expr) = node
+            && is_plain_closure(expr)
+        {
+            closure_span.get_or_insert(expr.span);
+            continue;
+        }
+        let Some((item_kind, ident)) = named_item(node) else {
+            if matches!(node, Node::Item(_)) {
+                break;
+            }
+            continue;
+        };
+        let (kind, span) = closure_span.map_or((item_kind, ident.span), |span| {
+            (EnclosingItemKind::Closure, span)
+        });
+        return EnclosingItemDescription {
+            item: EnclosingItem::new(kind, ident.name.to_string()),
+            span: Some(span),
+        };
+    }
+
+    EnclosingItemDescription {
+        item: EnclosingItem::scope(),
+        span: None,
+    }
+}
+
+const fn is_plain_closure(expr: &hir::Expr<'_>) -> bool {
+    matches!(
+        expr.kind,
+        hir::ExprKind::Closure(hir::Closure {
+            kind: hir::ClosureKind::Closure,
+            ..
+        })
+    )
+}
+
+/// Returns the kind and name of a function, method, constant, or static.
+/// Anonymous `const _` items have no name to report.
+fn named_item(node: Node<'_>) -> Option<(EnclosingItemKind, Ident)> {
+    let named = match node {
+        Node::Item(item) => match item.kind {
+            hir::ItemKind::Fn { ident, .. } => Some((EnclosingItemKind::Function, ident)),
+            hir::ItemKind::Const(ident, ..) => Some((EnclosingItemKind::Const, ident)),
+            hir::ItemKind::Static(_, ident, ..) => Some((EnclosingItemKind::Static, ident)),
+            _ => None,
+        },
+        Node::ImplItem(item) => match item.kind {
+            hir::ImplItemKind::Fn(..) => Some((EnclosingItemKind::Method, item.ident)),
+            hir::ImplItemKind::Const(..) => Some((EnclosingItemKind::Const, item.ident)),
+            hir::ImplItemKind::Type(..) => None,
+        },
+        Node::TraitItem(item) => match item.kind {
+            hir::TraitItemKind::Fn(..) => Some((EnclosingItemKind::Method, item.ident)),
+            hir::TraitItemKind::Const(..) => Some((EnclosingItemKind::Const, item.ident)),
+            hir::TraitItemKind::Type(..) => None,
+        },
+        _ => None,
+    };
+    named.filter(|(_, ident)| ident.name != kw::Underscore)
+}
@@ -0,0 +1,221 @@
+//! Enforcement of `max_findings_per_lint_per_crate` inside lint passes.
+//!
+//! Each lint pass builds a [`FindingLimit`] in `check_crate` from the shared
+//! configuration and asks it to [`admit`](FindingLimit::admit) every finding
+//! before emitting the diagnostic. Findings the lint level allows at their
+//! node are not counted, so silenced code cannot use up the budget. Findings
+//! beyond the limit are counted instead of emitted, and
+//! [`FindingLimit::summarise`] in `check_crate_post` reports them as one
+//! diagnostic at the first finding that was held back.
+//!
+//! Findings in files whose nested `whitaker.toml` switches the lint off
+//! through its `[lint]` table are rejected before they reach the budget; the
+//! per-file overrides are cached in a [`DirectoryConfig`], and lints lay their
+//! own `[lint_name]` tables over their thresholds with
+//! [`FindingLimit::config_at`].
+//!
+//! `summarise` also records each lint's total for the crate, which the suite
+//! collects through [`take_crate_totals`] for its optional end-of-crate
+//! summary.
+
+use std::cell::Cell;
+use std::sync::{Mutex, PoisonError};
+
+use log::debug;
+use rustc_hir::HirId;
+use rustc_lint::{LateContext, Level, Lint, LintContext};
+use rustc_span::Span;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use whitaker_common::diagnostics::{FindingBudget, LintTotal};
+use whitaker_common::i18n::Localizer;
+
+use super::DirectoryConfig;
+use crate::SharedConfig;
+
+const LOG_TARGET: &str = "whitaker::finding_limit";
+
+/// Totals recorded by [`FindingLimit::summarise`] since the last
+/// [`take_crate_totals`] call.
+static CRATE_TOTALS: Mutex<Vec<LintTotal>> = Mutex::new(Vec::new());
+
+/// Returns the totals each lint recorded for the current crate, leaving the
+/// record empty.
+///
+/// Lints record their totals in `check_crate_post`, so a pass must read them
+/// after every lint's `check_crate_post` has run.
+///
+/// # Examples
+///
+/// ```ignore
+/// fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+///     let totals = whitaker::hir::take_crate_totals();
+/// }
+/// ```
+#[must_use]
+pub fn take_crate_totals() -> Vec<LintTotal> {
+    std::mem::take(&mut *CRATE_TOTALS.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+fn record_crate_total(total: LintTotal) {
+    CRATE_TOTALS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(total);
+}
+
+/// A lint's finding budget for the crate being checked.
+#[derive(Clone, Debug, Default)]
+pub struct FindingLimit {
+    lint: Option<&'static Lint>,
+    budget: FindingBudget,
+    first_suppressed: Cell<Option<Span>>,
+    directories: DirectoryConfig,
+}
+
+impl FindingLimit {
+    /// Creates the limit `max_findings_per_lint_per_crate` sets for `lint`.
+    ///
+    /// The default value, used before the first crate is checked, admits
+    /// every finding.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let shared_config = SharedConfig::load();
+    /// let findings = FindingLimit::new(MY_LINT, &shared_config);
+    /// ```
+    #[must_use]
+    pub fn new(lint: &'static Lint, config: &SharedConfig) -> Self {
+        Self {
+            lint: Some(lint),
+            budget: FindingBudget::new(config.max_findings_per_lint_per_crate),
+            first_suppressed: Cell::new(None),
+            directories: DirectoryConfig::default(),
+        }
+    }
+
+    /// Returns the directory overrides cached for this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let overrides = state.findings.directories().overrides_at(cx, span);
+    /// ```
+    #[must_use]
+    pub const fn directories(&self) -> &DirectoryConfig {
+        &self.directories
+    }
+
+    /// Returns the lint's configuration for a finding at `span`, with the
+    /// `[lint_name]` tables of the directory overrides above it applied.
+    ///
+    /// Overrides that do not match the lint's configuration schema are logged
+    /// and ignored, leaving `base` in force. The default value returns `base`
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let config = state.findings.config_at(cx, expr.span, &state.config);
+    /// ```
+    #[must_use]
+    pub fn config_at<T>(&self, cx: &LateContext<'_>, span: Span, base: &T) -> T
+    where
+        T: Clone + Serialize + DeserializeOwned,
+    {
+        let Some(lint) = self.lint else {
+            return base.clone();
+        };
+        let overrides = self.directories.overrides_at(cx, span);
+        if overrides.is_empty() {
+            return base.clone();
+        }
+        let lint_name = lint.name_lower();
+        overrides
+            .apply_to_lint(&lint_name, base)
+            .unwrap_or_else(|error| {
+                debug!(
+                    target: LOG_TARGET,
+                    "ignoring directory overrides for `{lint_name}`: {error}"
+                );
+                base.clone()
+            })
+    }
+
+    /// Records a finding at `span`, attributed to `hir_id`, and returns
+    /// `true` when it should be emitted.
+    ///
+    /// A finding the lint level allows at `hir_id` is admitted without being
+    /// counted; rustc discards its diagnostic anyway. A finding in a file
+    /// whose directory overrides switch the lint off is rejected without
+    /// being counted.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if state.findings.admit(cx, expr.hir_id, expr.span) {
+    ///     emit_diagnostic(cx, expr.span, &state.localizer);
+    /// }
+    /// ```
+    #[must_use = "findings the limit rejects must not be emitted"]
+    pub fn admit(&self, cx: &LateContext<'_>, hir_id: HirId, span: Span) -> bool {
+        let Some(lint) = self.lint else {
+            return true;
+        };
+        if cx.tcx.lint_level_spec_at_node(lint, hir_id).level() == Level::Allow {
+            return true;
+        }
+        if !self
+            .directories
+            .is_lint_enabled(cx, span, &lint.name_lower())
+        {
+            return false;
+        }
+        let admitted = self.budget.admit();
+        if !admitted && self.first_suppressed.get().is_none() {
+            self.first_suppressed.set(Some(span));
+        }
+        admitted
+    }
+
+    /// Emits one summary for the findings held back in this crate, if any,
+    /// and records the lint's total for [`take_crate_totals`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+    ///     self.state.findings.summarise(cx, &self.state.localizer);
+    /// }
+    /// ```
+    pub fn summarise(&self, cx: &LateContext<'_>, localizer: &Localizer) {
+        let Some(lint) = self.lint else {
+            return;
+        };
+        if self.budget.total() > 0 {
+            record_crate_total(LintTotal::new(lint.name_lower(), self.budget.total()));
+        }
+        let (Some(span), Some(summary)) = (
+            self.first_suppressed.get(),
+            self.budget.summary(&lint.name_lower()),
+        ) else {
+            return;
+        };
+
+        let messages = summary.messages(localizer);
+        let primary = messages.primary().to_owned();
+        let note = messages.note().to_owned();
+        let help = messages.help().to_owned();
+
+        cx.emit_span_lint(
+            lint,
+            span,
+            rustc_lint::errors::DiagDecorator(move |diag| {
+                diag.primary_message(primary);
+                diag.note(note);
+                diag.help(help);
+            }),
+        );
+    }
+}
@@ -3,12 +3,35 @@
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
-use rustc_ast::AttrStyle;
 use rustc_hir as hir;
-use rustc_hir::attrs::AttributeKind as HirAttributeKind;
 use rustc_lint::LateContext;
 use rustc_span::Span;
-use whitaker_common::{Attribute, AttributeKind, AttributePath, SpanRecoveryFrame};
+use whitaker_common::{Attribute, AttributePath, SpanRecoveryFrame};
+
+mod attribute_arguments;
+mod attribute_kind;
+mod blame;
+mod directory_config;
+mod else_after_exit;
+mod enclosing_item;
+mod finding_limit;
+mod module_files;
+mod module_path;
+mod test_context;
+
+pub use attribute_arguments::attribute_arguments;
+pub use attribute_kind::attribute_kind;
+pub use blame::{FindingOrigin, FindingSite, HelperCallSites};
+pub use directory_config::DirectoryConfig;
+pub use else_after_exit::{ElseAfterExit, ElseRewrite};
+pub use enclosing_item::{EnclosingItemDescription, describe_enclosing_item};
+pub use finding_limit::{FindingLimit, take_crate_totals};
+pub use module_files::{companion_test_file_for, module_source_path, named_module_file_for};
+pub use module_path::module_path;
+pub use test_context::{
+    TestContext, is_cfg_test_attribute, is_cfg_trace_test_attribute, is_doctest_crate,
+    is_integration_test_crate_root,
+};
 
 static HARNESS_DESCRIPTOR_SYMBOL: LazyLock<rustc_span::Symbol> =
     LazyLock::new(|| rustc_span::Symbol::intern("RSTEST_HARNESS_DESCRIPTOR"));
@@ -124,21 +147,6 @@ fn attribute_path(attr: &hir::Attribute) -> Option<AttributePath> {
     Some(AttributePath::new(std::iter::once(first).chain(names)))
 }
 
-fn attribute_kind(attr: &hir::Attribute) -> AttributeKind {
-    match attribute_style(attr) {
-        AttrStyle::Inner => AttributeKind::Inner,
-        AttrStyle::Outer => AttributeKind::Outer,
-    }
-}
-
-fn attribute_style(attr: &hir::Attribute) -> AttrStyle {
-    match attr {
-        hir::Attribute::Unparsed(item) => item.style,
-        hir::Attribute::Parsed(HirAttributeKind::DocComment { style, .. }) => *style,
-        hir::Attribute::Parsed(_) => AttrStyle::Outer,
-    }
-}
-
 /// Extracts `(hir_id, name, parent, span)` from `item` when `kind_matches` returns `true`.
 fn item_components<'tcx>(
     cx: &LateContext<'tcx>,
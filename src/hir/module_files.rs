@@ -0,0 +1,130 @@
+//! Source-map helpers for file-backed modules.
+//!
+//! Lints that reason about module layout need to know which file on disk
+//! implements a `mod foo;` declaration. These helpers resolve that path from
+//! the source map, derive the conventional `foo.rs` sibling for modules
+//! implemented as `foo/mod.rs`, and name the `foo_tests.rs` companion that
+//! holds a module's tests.
+
+use std::path::{Path, PathBuf};
+
+use rustc_hir as hir;
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::FileName;
+
+const MOD_RS: &str = "mod.rs";
+const TESTS_SUFFIX: &str = "_tests.rs";
+
+/// Returns the local path of the file implementing `module`.
+///
+/// Inline modules (`mod foo { ... }`) share a source file with their
+/// declaration, so the helper returns `None` for them. File-backed modules
+/// resolve to the file containing the module body. Paths that were remapped
+/// away (for example via `--remap-path-prefix`) and synthetic sources also
+/// yield `None`.
+#[must_use]
+pub fn module_source_path<'tcx>(
+    cx: &LateContext<'tcx>,
+    item: &'tcx hir::Item<'tcx>,
+    module: &hir::Mod<'tcx>,
+) -> Option<PathBuf> {
+    let inner = module.spans.inner_span;
+    if inner.is_dummy() || item.span.from_expansion() {
+        return None;
+    }
+
+    let source_map = cx.sess().source_map();
+    let body_file = source_map.lookup_source_file(inner.lo());
+    let declaration_file = source_map.lookup_source_file(item.span.lo());
+    if body_file.name == declaration_file.name {
+        return None;
+    }
+
+    match &body_file.name {
+        FileName::Real(real) => real.local_path().map(Path::to_path_buf),
+        _ => None,
+    }
+}
+
+/// Suggests the `<parent>.rs` path for a module implemented as `mod.rs`.
+///
+/// Returns `None` when `path` is not named `mod.rs` or has no parent
+/// directory to name the module after.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::path::Path;
+/// use whitaker::hir::named_module_file_for;
+///
+/// let suggestion = named_module_file_for(Path::new("src/parser/mod.rs"));
+/// assert_eq!(suggestion.as_deref(), Some(Path::new("src/parser.rs")));
+/// assert!(named_module_file_for(Path::new("src/parser.rs")).is_none());
+/// ```
+#[must_use]
+pub fn named_module_file_for(path: &Path) -> Option<PathBuf> {
+    if path.file_name()? != MOD_RS {
+        return None;
+    }
+
+    let directory = path.parent()?;
+    let module_name = directory.file_name()?;
+    let mut file_name = module_name.to_os_string();
+    file_name.push(".rs");
+    Some(directory.with_file_name(file_name))
+}
+
+/// Returns the `<stem>_tests.rs` companion path for a module file.
+///
+/// Companion files sit beside the module they test and are usually loaded
+/// with `#[cfg(test)] #[path = "parser_tests.rs"] mod tests;`. Returns
+/// `None` when `path` has no `.rs` file name.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::path::Path;
+/// use whitaker::hir::companion_test_file_for;
+///
+/// let companion = companion_test_file_for(Path::new("src/parser.rs"));
+/// assert_eq!(companion.as_deref(), Some(Path::new("src/parser_tests.rs")));
+/// ```
+#[must_use]
+pub fn companion_test_file_for(path: &Path) -> Option<PathBuf> {
+    let file_name = path.file_name()?.to_str()?;
+    let stem = file_name.strip_suffix(".rs")?;
+    Some(path.with_file_name(format!("{stem}{TESTS_SUFFIX}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{companion_test_file_for, named_module_file_for};
+    use rstest::rstest;
+    use std::path::Path;
+
+    #[rstest]
+    #[case::nested("src/parser/mod.rs", Some("src/parser.rs"))]
+    #[case::absolute("/work/src/a/b/mod.rs", Some("/work/src/a/b.rs"))]
+    #[case::dotted_directory("src/v1.2/mod.rs", Some("src/v1.2.rs"))]
+    #[case::named_file("src/parser.rs", None)]
+    #[case::bare_mod_rs("mod.rs", None)]
+    #[case::similar_name("src/parser/mod.rs.bak", None)]
+    fn suggests_named_module_files(#[case] path: &str, #[case] expected: Option<&str>) {
+        assert_eq!(
+            named_module_file_for(Path::new(path)).as_deref(),
+            expected.map(Path::new)
+        );
+    }
+
+    #[rstest]
+    #[case::named_file("src/parser.rs", Some("src/parser_tests.rs"))]
+    #[case::mod_rs("src/parser/mod.rs", Some("src/parser/mod_tests.rs"))]
+    #[case::crate_root("src/lib.rs", Some("src/lib_tests.rs"))]
+    #[case::not_rust("src/parser.txt", None)]
+    fn names_companion_test_files(#[case] path: &str, #[case] expected: Option<&str>) {
+        assert_eq!(
+            companion_test_file_for(Path::new(path)).as_deref(),
+            expected.map(Path::new)
+        );
+    }
+}
@@ -0,0 +1,34 @@
+//! Module paths for HIR nodes.
+//!
+//! Module allow-lists name modules relative to the crate root, so lints need
+//! the path of the module a node is declared in, written the same way.
+
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+/// Returns the path of the module containing `hir_id`, relative to the crate
+/// root, with segments separated by `::`.
+///
+/// The crate root is the empty path. An item that is itself a module reports
+/// the module it is declared in.
+///
+/// # Examples
+///
+/// ```ignore
+/// let module = module_path(cx, item.hir_id());
+/// if allowed_modules.allows(&module) {
+///     return;
+/// }
+/// ```
+#[must_use]
+pub fn module_path(cx: &LateContext<'_>, hir_id: hir::HirId) -> String {
+    let module = cx.tcx.parent_module(hir_id);
+    cx.tcx
+        .def_path(module.to_def_id())
+        .data
+        .iter()
+        .filter_map(|segment| segment.data.get_opt_name())
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
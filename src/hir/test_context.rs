@@ -0,0 +1,302 @@
+//! Shared detection of test-only code for lints that exempt tests.
+//!
+//! Several lints relax their rules inside tests. [`TestContext`] gathers the
+//! crate-level facts once in `check_crate`, then answers per-node queries by
+//! walking HIR ancestors. A node is treated as test code when:
+//!
+//! - an ancestor carries `#[cfg(test)]`, or `#[cfg_attr(test, cfg(test))]`;
+//! - an enclosing function has a test-like attribute such as `#[test]`,
+//!   `#[tokio::test]`, or a configured additional attribute;
+//! - an enclosing function is registered with the `rustc --test` harness,
+//!   including functions lowered from `#[rstest]`; or
+//! - the crate is an integration test target under `tests/`, where helpers
+//!   and `#[rstest]` fixtures are test code even without a marker.
+
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::path::Path;
+
+use rustc_ast::ast::{MetaItem, MetaItemInner};
+use rustc_hir as hir;
+use rustc_hir::Node;
+use rustc_hir::attrs::{AttributeKind as HirAttributeKind, CfgEntry};
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::LateContext;
+use rustc_span::{RemapPathScopeComponents, sym};
+use whitaker_common::AttributePath;
+use whitaker_common::context::DoctestSignals;
+
+use super::{
+    collect_harness_test_functions, collect_rstest_companion_test_functions,
+    has_test_like_hir_attributes,
+};
+
+/// Crate-level facts used to classify HIR nodes as test code.
+#[derive(Clone, Debug, Default)]
+pub struct TestContext {
+    additional_test_attributes: Vec<AttributePath>,
+    harness_test_functions: HashSet<hir::HirId>,
+    is_integration_test: bool,
+}
+
+impl TestContext {
+    /// Collects test context for the crate being linted.
+    ///
+    /// `additional_test_attributes` extends the built-in list of test-like
+    /// attributes, typically from a lint's `dylint.toml` configuration.
+    #[must_use]
+    pub fn for_crate(cx: &LateContext<'_>, additional_test_attributes: Vec<AttributePath>) -> Self {
+        let is_test_harness = cx.tcx.sess.opts.test;
+        let harness_test_functions = if is_test_harness {
+            let mut marked = collect_harness_test_functions(cx);
+            marked.extend(collect_rstest_companion_test_functions(cx));
+            marked
+        } else {
+            HashSet::new()
+        };
+        let is_integration_test = is_test_harness
+            && cx.tcx.sess.local_crate_source_file().is_some_and(|source| {
+                is_integration_test_crate_root(source.path(RemapPathScopeComponents::DIAGNOSTICS))
+            });
+
+        Self {
+            additional_test_attributes,
+            harness_test_functions,
+            is_integration_test,
+        }
+    }
+
+    /// Returns `true` when `hir_id` sits inside test-only code.
+    #[must_use]
+    pub fn is_test(&self, cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
+        self.is_integration_test
+            || cx.tcx.hir_parent_iter(hir_id).any(|(ancestor_id, node)| {
+                let attrs = cx.tcx.hir_attrs(ancestor_id);
+                attrs.iter().any(is_cfg_test_attribute)
+                    || (is_function_node(node) && self.is_test_function(ancestor_id, attrs))
+            })
+    }
+
+    fn is_test_function(&self, hir_id: hir::HirId, attrs: &[hir::Attribute]) -> bool {
+        self.harness_test_functions.contains(&hir_id)
+            || has_test_like_hir_attributes(attrs, &self.additional_test_attributes)
+    }
+}
+
+const fn is_function_node(node: Node<'_>) -> bool {
+    match node {
+        Node::Item(item) => matches!(item.kind, hir::ItemKind::Fn { .. }),
+        Node::ImplItem(item) => matches!(item.kind, hir::ImplItemKind::Fn(..)),
+        Node::TraitItem(item) => matches!(item.kind, hir::TraitItemKind::Fn(..)),
+        _ => false,
+    }
+}
+
+/// Returns `true` when `crate_root` is an integration test target, either
+/// `tests/<name>.rs` or `tests/<name>/main.rs`.
+#[must_use]
+pub fn is_integration_test_crate_root(crate_root: &Path) -> bool {
+    let tests = OsStr::new("tests");
+    let is_direct_test = crate_root
+        .parent()
+        .and_then(Path::file_name)
+        .is_some_and(|directory| directory == tests);
+    let is_multi_file_test = crate_root.file_name() == Some(OsStr::new("main.rs"))
+        && crate_root
+            .parent()
+            .and_then(Path::parent)
+            .and_then(Path::file_name)
+            .is_some_and(|directory| directory == tests);
+
+    is_direct_test || is_multi_file_test
+}
+
+/// Returns `true` when the crate being linted is a rustdoc doctest.
+///
+/// This is the one place lints should ask: it gathers the compiler signals
+/// into [`DoctestSignals`] and leaves the decision to
+/// [`DoctestSignals::is_doctest`].
+#[must_use]
+pub fn is_doctest_crate(cx: &LateContext<'_>) -> bool {
+    let sess = cx.tcx.sess;
+    let mut signals = DoctestSignals::new(cx.tcx.crate_name(LOCAL_CRATE).as_str())
+        .with_rustdoc_test_path(
+            cx.tcx
+                .env_var_os("UNSTABLE_RUSTDOC_TEST_PATH".as_ref())
+                .is_some(),
+        )
+        .with_test_harness(sess.opts.test);
+    if let Some(source) = sess.local_crate_source_file() {
+        signals = signals.with_source_name(
+            source
+                .path(RemapPathScopeComponents::DIAGNOSTICS)
+                .to_string_lossy(),
+        );
+    }
+    signals.is_doctest()
+}
+
+/// Returns whether a HIR attribute enables `cfg(test)` semantics.
+///
+/// Recognizes both direct `cfg(test)` attributes (including `cfg(doctest)`
+/// and combinations such as `cfg(all(test, unix))`) and
+/// `cfg_attr(test, cfg(test))` forms. Negated conditions such as
+/// `cfg(not(test))` do not count.
+///
+/// Once expansion keeps a node, rustc replaces its satisfied `cfg` with a
+/// parsed `cfg_trace` attribute, so that form is recognized too.
+#[must_use]
+pub fn is_cfg_test_attribute(attr: &hir::Attribute) -> bool {
+    // Other parsed attributes (like #[must_use]) are not cfg-related; calling
+    // `path()` on them would panic.
+    let hir::Attribute::Unparsed(_) = attr else {
+        return is_cfg_trace_test_attribute(attr);
+    };
+
+    let path = attr.path();
+    let [name] = path.as_slice() else {
+        return false;
+    };
+
+    if *name == sym::cfg {
+        return attr
+            .meta_item_list()
+            .is_some_and(|items| items.iter().cloned().any(meta_item_inner_contains_test));
+    }
+
+    *name == sym::cfg_attr && attr.meta_item_list().is_some_and(check_cfg_attr_for_test)
+}
+
+/// Returns whether a parsed `cfg_trace` attribute records a `cfg` predicate
+/// that enables test builds.
+#[must_use]
+pub fn is_cfg_trace_test_attribute(attr: &hir::Attribute) -> bool {
+    let hir::Attribute::Parsed(HirAttributeKind::CfgTrace(entries)) = attr else {
+        return false;
+    };
+    entries
+        .iter()
+        .any(|(entry, _)| cfg_entry_contains_test(entry, true))
+}
+
+fn cfg_entry_contains_test(entry: &CfgEntry, is_positive: bool) -> bool {
+    match entry {
+        CfgEntry::NameValue {
+            name, value: None, ..
+        } => is_positive && (*name == sym::test || *name == sym::doctest),
+        CfgEntry::All(entries, _) | CfgEntry::Any(entries, _) => entries
+            .iter()
+            .any(|inner| cfg_entry_contains_test(inner, is_positive)),
+        CfgEntry::Not(inner, _) => cfg_entry_contains_test(inner, !is_positive),
+        CfgEntry::NameValue { .. } | CfgEntry::Bool(..) | CfgEntry::Version(..) => false,
+    }
+}
+
+fn check_cfg_attr_for_test<I>(items: I) -> bool
+where
+    I: IntoIterator<Item = MetaItemInner>,
+{
+    let mut iter = items.into_iter();
+    let Some(condition) = iter.next() else {
+        return false;
+    };
+
+    meta_item_inner_contains_test(condition)
+        && iter.any(|item| match item {
+            MetaItemInner::MetaItem(inner) => meta_contains_test_cfg(&inner),
+            MetaItemInner::Lit(_) => false,
+        })
+}
+
+fn meta_item_inner_contains_test(item: MetaItemInner) -> bool {
+    meta_item_inner_contains_test_with_polarity(item, true)
+}
+
+fn meta_item_inner_contains_test_with_polarity(item: MetaItemInner, is_positive: bool) -> bool {
+    match item {
+        MetaItemInner::MetaItem(meta) => meta_contains_test_with_polarity(&meta, is_positive),
+        MetaItemInner::Lit(_) => false,
+    }
+}
+
+fn meta_contains_test_with_polarity(meta: &MetaItem, is_positive: bool) -> bool {
+    if path_is_ident(&meta.path, sym::test) || path_is_ident(&meta.path, sym::doctest) {
+        return is_positive;
+    }
+
+    let polarity = if path_is_ident(&meta.path, sym::not) {
+        !is_positive
+    } else {
+        is_positive
+    };
+    meta.meta_item_list().is_some_and(|items| {
+        items
+            .iter()
+            .cloned()
+            .any(|item| meta_item_inner_contains_test_with_polarity(item, polarity))
+    })
+}
+
+fn meta_contains_test_cfg(meta: &MetaItem) -> bool {
+    if path_is_ident(&meta.path, sym::cfg) {
+        return meta
+            .meta_item_list()
+            .is_some_and(|items| items.iter().cloned().any(meta_item_inner_contains_test));
+    }
+
+    path_is_ident(&meta.path, sym::cfg_attr)
+        && meta
+            .meta_item_list()
+            .is_some_and(|items| check_cfg_attr_for_test(items.iter().cloned()))
+}
+
+fn path_is_ident(path: &rustc_ast::Path, symbol: rustc_span::Symbol) -> bool {
+    matches!(path.segments.as_slice(), [segment] if segment.ident.name == symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use rustc_span::{DUMMY_SP, create_default_session_globals_then};
+
+    fn cfg_trace(entry: CfgEntry) -> hir::Attribute {
+        hir::Attribute::Parsed(HirAttributeKind::CfgTrace(vec![(entry, DUMMY_SP)].into()))
+    }
+
+    fn name(symbol: rustc_span::Symbol) -> CfgEntry {
+        CfgEntry::NameValue {
+            name: symbol,
+            value: None,
+            span: DUMMY_SP,
+        }
+    }
+
+    #[rstest]
+    #[case::bare_test(|| name(sym::test), true)]
+    #[case::bare_doctest(|| name(sym::doctest), true)]
+    #[case::unix(|| name(sym::unix), false)]
+    #[case::all(|| CfgEntry::All(vec![name(sym::test), name(sym::unix)].into(), DUMMY_SP), true)]
+    #[case::negated(|| CfgEntry::Not(Box::new(name(sym::test)), DUMMY_SP), false)]
+    #[case::double_negated(
+        || CfgEntry::Not(Box::new(CfgEntry::Not(Box::new(name(sym::test)), DUMMY_SP)), DUMMY_SP),
+        true
+    )]
+    fn recognises_cfg_trace_test_predicates(
+        #[case] entry: fn() -> CfgEntry,
+        #[case] expected: bool,
+    ) {
+        create_default_session_globals_then(|| {
+            assert_eq!(is_cfg_test_attribute(&cfg_trace(entry())), expected);
+        });
+    }
+
+    #[rstest]
+    #[case::direct("/repo/tests/cli.rs", true)]
+    #[case::multi_file("/repo/tests/cli/main.rs", true)]
+    #[case::helper_module("/repo/tests/cli/support.rs", false)]
+    #[case::library("/repo/src/lib.rs", false)]
+    fn recognises_integration_test_roots(#[case] path: &str, #[case] expected: bool) {
+        assert_eq!(is_integration_test_crate_root(Path::new(path)), expected);
+    }
+}
@@ -13,12 +13,14 @@ pub mod config;
 #[cfg(feature = "dylint-driver")]
 pub mod hir;
 pub mod lints;
+pub mod report;
 pub mod testing;
 
-pub use config::{ModuleMaxLinesConfig, SharedConfig};
+pub use config::{DirectoryOverrides, ModuleMaxLinesConfig, SharedConfig};
 #[cfg(feature = "dylint-driver")]
 pub use hir::{
-    module_body_span, module_header_span, recover_user_editable_hir_span, span_recovery_frames,
+    module_body_span, module_header_span, module_source_path, named_module_file_for,
+    recover_user_editable_hir_span, span_recovery_frames,
 };
 pub use lints::{LintCrateTemplate, TemplateError, TemplateFiles};
 
@@ -0,0 +1,178 @@
+//! `CODEOWNERS` parsing for attributing findings to owning teams.
+//!
+//! Suite adoption usually happens team by team. Attaching the owners listed in
+//! the repository's `CODEOWNERS` file to each finding lets reports group
+//! findings per team, so dashboards and reminders reach the people who can fix
+//! them.
+//!
+//! Rules are evaluated in file order and the last matching rule wins, exactly
+//! as GitHub resolves review requests. A rule without owners therefore marks
+//! the matching paths as unowned.
+
+mod pattern;
+
+use std::io;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use thiserror::Error;
+
+use crate::config::read_if_present;
+use pattern::Pattern;
+
+/// Repository-relative locations searched for a `CODEOWNERS` file, in the
+/// order GitHub consults them.
+pub const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Errors raised while loading or parsing a `CODEOWNERS` file.
+#[derive(Debug, Error)]
+pub enum CodeOwnersError {
+    /// The `CODEOWNERS` file exists but could not be read.
+    #[error("failed to read `{path}`: {source}")]
+    Read {
+        /// Path of the unreadable file.
+        path: Utf8PathBuf,
+        /// Underlying I/O failure.
+        #[source]
+        source: io::Error,
+    },
+    /// A rule uses pattern syntax that `CODEOWNERS` does not support.
+    #[error("line {line}: invalid pattern `{pattern}`: {reason}")]
+    InvalidPattern {
+        /// One-based line number of the offending rule.
+        line: usize,
+        /// Pattern text as written.
+        pattern: String,
+        /// Why the pattern was rejected.
+        reason: &'static str,
+    },
+}
+
+/// A single `pattern owner...` rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Rule {
+    pattern: Pattern,
+    owners: Vec<String>,
+}
+
+/// Parsed `CODEOWNERS` rules.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CodeOwners {
+    rules: Vec<Rule>,
+}
+
+impl CodeOwners {
+    /// Parses the contents of a `CODEOWNERS` file.
+    ///
+    /// Blank lines and `#` comments are ignored. A pattern starting with a
+    /// literal `#` may be escaped as `\#`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodeOwnersError::InvalidPattern`] when a rule uses negation or
+    /// character ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use camino::Utf8Path;
+    /// use whitaker::report::CodeOwners;
+    ///
+    /// let owners = CodeOwners::parse("*.rs @org/rust\n/docs/ @org/docs\n")
+    ///     .expect("CODEOWNERS should parse");
+    ///
+    /// assert_eq!(owners.owners_for(Utf8Path::new("src/lib.rs")), ["@org/rust"]);
+    /// assert_eq!(owners.owners_for(Utf8Path::new("docs/guide.md")), ["@org/docs"]);
+    /// assert!(owners.owners_for(Utf8Path::new("README.md")).is_empty());
+    /// ```
+    pub fn parse(contents: &str) -> Result<Self, CodeOwnersError> {
+        let rules = contents
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| parse_rule(index + 1, line).transpose())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Loads the first `CODEOWNERS` file found beneath `root`.
+    ///
+    /// Returns `Ok(None)` when none of [`CODEOWNERS_LOCATIONS`] exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodeOwnersError`] when the file cannot be read or parsed.
+    pub fn discover(root: &Utf8Path) -> Result<Option<Self>, CodeOwnersError> {
+        Self::discover_with(root, read_if_present)
+    }
+
+    /// Loads the first `CODEOWNERS` file found beneath `root` using `reader`.
+    ///
+    /// The reader returns `Ok(None)` for missing files, mirroring
+    /// [`crate::config::DirectoryOverrides::discover_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodeOwnersError`] when the reader fails or the file cannot be
+    /// parsed.
+    pub fn discover_with<F>(root: &Utf8Path, mut reader: F) -> Result<Option<Self>, CodeOwnersError>
+    where
+        F: FnMut(&Utf8Path) -> io::Result<Option<String>>,
+    {
+        for location in CODEOWNERS_LOCATIONS {
+            let path = root.join(location);
+            let found = reader(&path).map_err(|source| CodeOwnersError::Read {
+                path: path.clone(),
+                source,
+            })?;
+            if let Some(contents) = found {
+                return Self::parse(&contents).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the owners of the repository-relative `path`.
+    ///
+    /// The slice is empty when no rule matches or the last matching rule
+    /// lists no owners.
+    #[must_use]
+    pub fn owners_for(&self, path: &Utf8Path) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.pattern.matches(path))
+            .map_or(&[], |rule| rule.owners.as_slice())
+    }
+
+    /// Returns `true` when the file defines no rules.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+fn parse_rule(line_number: usize, line: &str) -> Result<Option<Rule>, CodeOwnersError> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(None);
+    }
+
+    let mut tokens = trimmed.split_whitespace();
+    let Some(raw) = tokens.next() else {
+        return Ok(None);
+    };
+    let text = raw.strip_prefix('\\').unwrap_or(raw);
+    let pattern = Pattern::parse(text).map_err(|reason| CodeOwnersError::InvalidPattern {
+        line: line_number,
+        pattern: text.to_owned(),
+        reason,
+    })?;
+    let owners = tokens
+        .take_while(|token| !token.starts_with('#'))
+        .map(str::to_owned)
+        .collect();
+
+    Ok(Some(Rule { pattern, owners }))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,120 @@
+//! Path patterns used by `CODEOWNERS` rules.
+//!
+//! The syntax follows GitHub's subset of `.gitignore` patterns:
+//!
+//! - a pattern without an interior `/` matches at any depth;
+//! - a leading `/` or an interior `/` anchors the pattern at the repository
+//!   root;
+//! - a trailing `/` matches only directories, and therefore every file beneath
+//!   them;
+//! - `*` and `?` match within a single path segment, while `**` matches any
+//!   number of segments; and
+//! - a pattern whose final segment is a plain name also matches everything
+//!   beneath a directory of that name, whereas `docs/*` matches only the
+//!   direct children of `docs`.
+//!
+//! Negation (`!`) and character ranges (`[a-z]`) are rejected because GitHub
+//! ignores them too.
+
+use camino::Utf8Path;
+
+/// One `/`-separated component of a pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    /// `**`, matching zero or more path segments.
+    AnyDepth,
+    /// A segment that may contain `*` and `?` wildcards.
+    Glob(Vec<char>),
+}
+
+/// A compiled `CODEOWNERS` path pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Pattern {
+    segments: Vec<Segment>,
+    directory_only: bool,
+    matches_descendants: bool,
+}
+
+impl Pattern {
+    /// Compiles `raw`, returning a human-readable reason when the syntax is
+    /// unsupported.
+    pub(super) fn parse(raw: &str) -> Result<Self, &'static str> {
+        if raw.starts_with('!') {
+            return Err("negated patterns are not supported");
+        }
+        if raw.contains(['[', ']']) {
+            return Err("character ranges are not supported");
+        }
+
+        let directory_only = raw.ends_with('/');
+        let trimmed = raw.trim_end_matches('/');
+        let body = trimmed.trim_start_matches('/');
+        if body.is_empty() {
+            return Err("pattern does not name any path");
+        }
+
+        let anchored = trimmed.starts_with('/') || body.contains('/');
+        let mut segments: Vec<Segment> = body
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| match segment {
+                "**" => Segment::AnyDepth,
+                glob => Segment::Glob(glob.chars().collect()),
+            })
+            .collect();
+        if !anchored {
+            segments.insert(0, Segment::AnyDepth);
+        }
+
+        let matches_descendants = directory_only
+            || segments.last().is_some_and(|segment| match segment {
+                Segment::AnyDepth => true,
+                Segment::Glob(chars) => !chars.contains(&'*'),
+            });
+
+        Ok(Self {
+            segments,
+            directory_only,
+            matches_descendants,
+        })
+    }
+
+    /// Returns whether the repository-relative `path` matches the pattern.
+    pub(super) fn matches(&self, path: &Utf8Path) -> bool {
+        let components: Vec<&str> = path
+            .as_str()
+            .split('/')
+            .filter(|component| !component.is_empty() && *component != ".")
+            .collect();
+        self.match_from(&self.segments, &components)
+    }
+
+    fn match_from(&self, segments: &[Segment], path: &[&str]) -> bool {
+        match segments.split_first() {
+            None if path.is_empty() => !self.directory_only,
+            None => self.matches_descendants,
+            Some((Segment::AnyDepth, rest)) => (0..=path.len())
+                .filter_map(|skip| path.get(skip..))
+                .any(|tail| self.match_from(rest, tail)),
+            Some((Segment::Glob(glob), rest)) => path.split_first().is_some_and(|(head, tail)| {
+                glob_matches(glob, &head.chars().collect::<Vec<_>>()) && self.match_from(rest, tail)
+            }),
+        }
+    }
+}
+
+/// Matches one path segment against a glob containing `*` and `?`.
+fn glob_matches(glob: &[char], text: &[char]) -> bool {
+    match glob.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len())
+            .filter_map(|skip| text.get(skip..))
+            .any(|tail| glob_matches(rest, tail)),
+        Some(('?', rest)) => text
+            .split_first()
+            .is_some_and(|(_, tail)| glob_matches(rest, tail)),
+        Some((expected, rest)) => text
+            .split_first()
+            .is_some_and(|(actual, tail)| actual == expected && glob_matches(rest, tail)),
+    }
+}
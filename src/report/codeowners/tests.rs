@@ -0,0 +1,126 @@
+//! Tests for `CODEOWNERS` parsing, pattern matching, and discovery.
+
+use super::*;
+use rstest::rstest;
+use std::collections::BTreeMap;
+
+fn owners_of(contents: &str, path: &str) -> Vec<String> {
+    CodeOwners::parse(contents)
+        .expect("CODEOWNERS should parse")
+        .owners_for(Utf8Path::new(path))
+        .to_vec()
+}
+
+#[rstest]
+#[case::extension_anywhere("*.rs @rust", "crates/a/src/lib.rs", true)]
+#[case::extension_mismatch("*.rs @rust", "docs/guide.md", false)]
+#[case::catch_all("* @all", "deeply/nested/file.txt", true)]
+#[case::anchored_directory("/docs/ @docs", "docs/a/b.md", true)]
+#[case::anchored_directory_elsewhere("/docs/ @docs", "src/docs/a.md", false)]
+#[case::unanchored_directory("apps/ @apps", "src/apps/main.rs", true)]
+#[case::directory_only_rejects_file("apps/ @apps", "src/apps", false)]
+#[case::plain_name_covers_descendants("/build/logs @ops", "build/logs/today.log", true)]
+#[case::plain_name_matches_file("/build/logs @ops", "build/logs", true)]
+#[case::direct_children_only("docs/* @docs", "docs/guide.md", true)]
+#[case::direct_children_not_nested("docs/* @docs", "docs/build/guide.md", false)]
+#[case::any_depth_prefix("**/logs @ops", "a/b/logs/x.log", true)]
+#[case::any_depth_suffix("/src/** @src", "src/a/b/c.rs", true)]
+#[case::single_character("/src/?.rs @src", "src/a.rs", true)]
+#[case::single_character_mismatch("/src/?.rs @src", "src/ab.rs", false)]
+#[case::interior_slash_anchors("src/bin @bin", "crates/src/bin/main.rs", false)]
+#[case::leading_dot_slash("*.rs @rust", "./src/lib.rs", true)]
+fn patterns_follow_codeowners_semantics(
+    #[case] contents: &str,
+    #[case] path: &str,
+    #[case] owned: bool,
+) {
+    assert_eq!(!owners_of(contents, path).is_empty(), owned);
+}
+
+#[rstest]
+fn last_matching_rule_wins() {
+    let contents = "* @everyone\n/crates/ @crates\n/crates/legacy/ @legacy @archivists\n";
+
+    assert_eq!(owners_of(contents, "README.md"), ["@everyone"]);
+    assert_eq!(owners_of(contents, "crates/new/lib.rs"), ["@crates"]);
+    assert_eq!(
+        owners_of(contents, "crates/legacy/lib.rs"),
+        ["@legacy", "@archivists"]
+    );
+}
+
+#[rstest]
+fn rules_without_owners_clear_ownership() {
+    let contents = "* @everyone\n/vendor/\n";
+
+    assert!(owners_of(contents, "vendor/lib.rs").is_empty());
+}
+
+#[rstest]
+fn comments_and_blank_lines_are_ignored() {
+    let contents = "# Owners\n\n*.rs @rust # trailing comment\n\\#notes.md @docs\n";
+
+    assert_eq!(owners_of(contents, "src/lib.rs"), ["@rust"]);
+    assert_eq!(owners_of(contents, "#notes.md"), ["@docs"]);
+}
+
+#[rstest]
+#[case::negation("!*.rs @rust", 2)]
+#[case::character_range("*.[ch] @c", 2)]
+fn unsupported_syntax_is_rejected(#[case] rule: &str, #[case] expected_line: usize) {
+    let contents = format!("* @everyone\n{rule}\n");
+
+    let outcome = CodeOwners::parse(&contents);
+
+    assert!(matches!(
+        outcome,
+        Err(CodeOwnersError::InvalidPattern { line, .. }) if line == expected_line
+    ));
+}
+
+#[rstest]
+#[case::github(".github/CODEOWNERS", "@github")]
+#[case::root("CODEOWNERS", "@root")]
+#[case::docs("docs/CODEOWNERS", "@docs")]
+fn discovery_prefers_the_first_known_location(#[case] present: &str, #[case] owner: &str) {
+    let files: BTreeMap<String, String> = CODEOWNERS_LOCATIONS
+        .iter()
+        .skip_while(|location| **location != present)
+        .enumerate()
+        .map(|(index, location)| {
+            let contents = if index == 0 {
+                format!("* {owner}\n")
+            } else {
+                "* @shadowed\n".to_owned()
+            };
+            (format!("/repo/{location}"), contents)
+        })
+        .collect();
+
+    let codeowners = CodeOwners::discover_with(Utf8Path::new("/repo"), |path| {
+        Ok(files.get(path.as_str()).cloned())
+    })
+    .expect("discovery should succeed")
+    .expect("a CODEOWNERS file should be found");
+
+    assert_eq!(codeowners.owners_for(Utf8Path::new("src/lib.rs")), [owner]);
+}
+
+#[rstest]
+fn discovery_without_codeowners_returns_none() {
+    let outcome = CodeOwners::discover_with(Utf8Path::new("/repo"), |_| Ok(None));
+
+    assert!(matches!(outcome, Ok(None)));
+}
+
+#[rstest]
+fn unreadable_codeowners_are_reported() {
+    let outcome = CodeOwners::discover_with(Utf8Path::new("/repo"), |_| {
+        Err(io::Error::new(io::ErrorKind::PermissionDenied, "denied"))
+    });
+
+    assert!(matches!(
+        outcome,
+        Err(CodeOwnersError::Read { path, .. }) if path == "/repo/.github/CODEOWNERS"
+    ));
+}
@@ -0,0 +1,345 @@
+//! Trend comparison between two reports.
+//!
+//! [`ReportComparison::between`] matches the findings of the current run
+//! against an earlier run by fingerprint and classifies each one as new,
+//! fixed, or unchanged. Fingerprints ignore line and column numbers, so
+//! unrelated edits that shift code around do not churn the comparison. When a
+//! fingerprint occurs several times, occurrences are matched by count: three
+//! identical findings before and two after yield one fixed finding.
+//!
+//! A run that recorded source fingerprints can be compared with one that did
+//! not, such as a report saved by an older release. A finding left unmatched
+//! by fingerprint is then matched on its lint, path, and message, provided at
+//! least one side of the pair lacks a source fingerprint.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use super::{Finding, Report};
+
+/// Direction of travel between two runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Trend {
+    /// More findings were fixed than introduced.
+    Improved,
+    /// More findings were introduced than fixed.
+    Degraded,
+    /// New and fixed findings balance out.
+    Unchanged,
+}
+
+impl Trend {
+    const fn from_counts(new: usize, fixed: usize) -> Self {
+        if fixed > new {
+            Self::Improved
+        } else if new > fixed {
+            Self::Degraded
+        } else {
+            Self::Unchanged
+        }
+    }
+
+    /// Returns the stable, language-neutral label for the trend.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Improved => "improved",
+            Self::Degraded => "degraded",
+            Self::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// Changes to one lint's findings between two runs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct LintDelta {
+    /// Findings present now but not in the previous run.
+    pub new: Vec<Finding>,
+    /// Findings present in the previous run but not now.
+    pub fixed: Vec<Finding>,
+    /// Number of findings present in both runs.
+    pub unchanged: usize,
+}
+
+impl LintDelta {
+    /// Returns the trend for this lint alone.
+    #[must_use]
+    pub const fn trend(&self) -> Trend {
+        Trend::from_counts(self.new.len(), self.fixed.len())
+    }
+}
+
+/// Per-lint deltas between a previous and a current report.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ReportComparison {
+    /// Deltas keyed by lint name, in lexical order.
+    pub lints: BTreeMap<String, LintDelta>,
+}
+
+impl ReportComparison {
+    /// Compares `current` against `previous`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::report::{Finding, Location, Report, ReportComparison, Trend};
+    ///
+    /// let finding = |line| {
+    ///     Finding::new("no_static_mut", Location::new("src/lib.rs", line, 1), "`static mut`")
+    /// };
+    /// let previous = Report::new(vec![finding(4), finding(9)]);
+    /// let current = Report::new(vec![finding(6)]);
+    ///
+    /// let comparison = ReportComparison::between(&previous, &current);
+    ///
+    /// assert_eq!(comparison.fixed_count(), 1);
+    /// assert_eq!(comparison.unchanged_count(), 1);
+    /// assert_eq!(comparison.trend(), Trend::Improved);
+    /// ```
+    #[must_use]
+    pub fn between(previous: &Report, current: &Report) -> Self {
+        let mut before = group_by(&previous.findings, Finding::fingerprint);
+        let mut lints: BTreeMap<String, LintDelta> = BTreeMap::new();
+        let mut unmatched = Vec::new();
+
+        for finding in &current.findings {
+            let delta = lints.entry(finding.lint.clone()).or_default();
+            let matched = before
+                .get_mut(&finding.fingerprint())
+                .and_then(Vec::pop)
+                .is_some();
+            if matched {
+                delta.unchanged += 1;
+            } else {
+                unmatched.push(finding);
+            }
+        }
+
+        let remaining: Vec<_> = before.into_values().flatten().collect();
+        let mut by_message = group_by(remaining, Finding::message_fingerprint);
+        for finding in unmatched {
+            let delta = lints.entry(finding.lint.clone()).or_default();
+            if take_message_match(&mut by_message, finding) {
+                delta.unchanged += 1;
+            } else {
+                delta.new.push(finding.clone());
+            }
+        }
+
+        for finding in by_message.into_values().flatten() {
+            lints
+                .entry(finding.lint.clone())
+                .or_default()
+                .fixed
+                .push(finding.clone());
+        }
+
+        Self { lints }
+    }
+
+    /// Total findings introduced since the previous run.
+    #[must_use]
+    pub fn new_count(&self) -> usize {
+        self.lints.values().map(|delta| delta.new.len()).sum()
+    }
+
+    /// Total findings resolved since the previous run.
+    #[must_use]
+    pub fn fixed_count(&self) -> usize {
+        self.lints.values().map(|delta| delta.fixed.len()).sum()
+    }
+
+    /// Total findings present in both runs.
+    #[must_use]
+    pub fn unchanged_count(&self) -> usize {
+        self.lints.values().map(|delta| delta.unchanged).sum()
+    }
+
+    /// Returns the overall trend across every lint.
+    #[must_use]
+    pub fn trend(&self) -> Trend {
+        Trend::from_counts(self.new_count(), self.fixed_count())
+    }
+
+    /// Renders a concise plain-text trend summary.
+    ///
+    /// The first line gives the totals and overall trend; each following line
+    /// lists one lint with its new (`+`), fixed (`-`), and unchanged (`=`)
+    /// counts. Lints whose findings did not change are omitted.
+    #[must_use]
+    pub fn render_summary(&self) -> String {
+        let width = self.lints.keys().map(String::len).max().unwrap_or(0);
+        let header = format!(
+            "Whitaker trend: {} new, {} fixed, {} unchanged ({})\n",
+            self.new_count(),
+            self.fixed_count(),
+            self.unchanged_count(),
+            self.trend().as_str(),
+        );
+        let rows = self
+            .lints
+            .iter()
+            .filter(|(_, delta)| !delta.new.is_empty() || !delta.fixed.is_empty())
+            .map(|(lint, delta)| {
+                format!(
+                    "  {lint:<width$}  +{}  -{}  ={}  {}\n",
+                    delta.new.len(),
+                    delta.fixed.len(),
+                    delta.unchanged,
+                    delta.trend().as_str(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        header + &rows
+    }
+}
+
+/// Indexes findings by `key`, keeping every occurrence.
+fn group_by<'a>(
+    findings: impl IntoIterator<Item = &'a Finding>,
+    key: impl Fn(&Finding) -> String,
+) -> BTreeMap<String, Vec<&'a Finding>> {
+    let mut groups: BTreeMap<String, Vec<&Finding>> = BTreeMap::new();
+    for finding in findings {
+        groups.entry(key(finding)).or_default().push(finding);
+    }
+    groups
+}
+
+/// Removes a previous finding sharing `finding`'s message fingerprint, unless
+/// both carry source fingerprints, which already failed to match.
+fn take_message_match(by_message: &mut BTreeMap<String, Vec<&Finding>>, finding: &Finding) -> bool {
+    let Some(candidates) = by_message.get_mut(&finding.message_fingerprint()) else {
+        return false;
+    };
+    let current_has_source = finding.has_source_fingerprint();
+    let Some(index) = candidates
+        .iter()
+        .rposition(|previous| !(current_has_source && previous.has_source_fingerprint()))
+    else {
+        return false;
+    };
+    candidates.remove(index);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Location;
+    use rstest::rstest;
+
+    fn finding(lint: &str, message: &str, at: usize) -> Finding {
+        Finding::new(lint, Location::new("src/lib.rs", at, 1), message)
+    }
+
+    #[rstest]
+    fn moved_findings_are_unchanged() {
+        let previous = Report::new(vec![finding("a", "m", 10)]);
+        let current = Report::new(vec![finding("a", "m", 42)]);
+
+        let comparison = ReportComparison::between(&previous, &current);
+
+        assert_eq!(comparison.unchanged_count(), 1);
+        assert_eq!(comparison.trend(), Trend::Unchanged);
+    }
+
+    #[rstest]
+    fn deltas_are_grouped_per_lint() {
+        let previous = Report::new(vec![finding("a", "old", 1), finding("b", "kept", 2)]);
+        let current = Report::new(vec![finding("b", "kept", 2), finding("c", "fresh", 3)]);
+
+        let comparison = ReportComparison::between(&previous, &current);
+
+        let counts: Vec<_> = comparison
+            .lints
+            .iter()
+            .map(|(lint, delta)| {
+                (
+                    lint.as_str(),
+                    delta.new.len(),
+                    delta.fixed.len(),
+                    delta.unchanged,
+                )
+            })
+            .collect();
+        assert_eq!(counts, [("a", 0, 1, 0), ("b", 0, 0, 1), ("c", 1, 0, 0)]);
+    }
+
+    #[rstest]
+    #[case::some_fixed((3, 2), (0, 1, Trend::Improved))]
+    #[case::some_new((1, 3), (2, 0, Trend::Degraded))]
+    #[case::same((2, 2), (0, 0, Trend::Unchanged))]
+    fn repeated_fingerprints_match_by_count(
+        #[case] (before, after): (usize, usize),
+        #[case] (expected_new, expected_fixed, expected_trend): (usize, usize, Trend),
+    ) {
+        let repeated =
+            |count: usize| Report::new((1..=count).map(|line| finding("a", "dup", line)).collect());
+
+        let comparison = ReportComparison::between(&repeated(before), &repeated(after));
+
+        assert_eq!(comparison.new_count(), expected_new);
+        assert_eq!(comparison.fixed_count(), expected_fixed);
+        assert_eq!(comparison.unchanged_count(), before.min(after));
+        assert_eq!(comparison.trend(), expected_trend);
+    }
+
+    #[rstest]
+    #[case::previous_without_source(false, true)]
+    #[case::current_without_source(true, false)]
+    fn mixed_fingerprints_match_on_message(
+        #[case] previous_has_source: bool,
+        #[case] current_has_source: bool,
+    ) {
+        let at = |line, with_source: bool| {
+            let plain = finding("no_static_mut", "`static mut`", line);
+            if with_source {
+                plain.with_source("static mut COUNTER: u32 = 0;", Some("COUNTER"))
+            } else {
+                plain
+            }
+        };
+        let previous = Report::new(vec![at(3, previous_has_source), finding("a", "gone", 1)]);
+        let current = Report::new(vec![at(30, current_has_source), finding("b", "fresh", 2)]);
+
+        let comparison = ReportComparison::between(&previous, &current);
+
+        assert_eq!(comparison.unchanged_count(), 1);
+        assert_eq!(comparison.new_count(), 1);
+        assert_eq!(comparison.fixed_count(), 1);
+    }
+
+    #[rstest]
+    fn distinct_source_fingerprints_do_not_match_on_message() {
+        let at = |snippet| finding("no_static_mut", "`static mut`", 3).with_source(snippet, None);
+        let previous = Report::new(vec![at("static mut A: u32 = 0;")]);
+        let current = Report::new(vec![at("static mut B: u32 = 0;")]);
+
+        let comparison = ReportComparison::between(&previous, &current);
+
+        assert_eq!(comparison.unchanged_count(), 0);
+        assert_eq!(comparison.new_count(), 1);
+        assert_eq!(comparison.fixed_count(), 1);
+    }
+
+    #[rstest]
+    fn summary_lists_changed_lints_only() {
+        let previous = Report::new(vec![
+            finding("module_max_lines", "long", 1),
+            finding("no_static_mut", "static", 1),
+        ]);
+        let current = Report::new(vec![finding("no_static_mut", "static", 1)]);
+
+        let summary = ReportComparison::between(&previous, &current).render_summary();
+
+        assert_eq!(
+            summary,
+            "Whitaker trend: 0 new, 1 fixed, 1 unchanged (improved)\n\
+             \x20 module_max_lines  +0  -1  =0  improved\n"
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! Diff-aware filtering of findings for incremental adoption.
+//!
+//! [`ChangedLines::from_git`] collects the lines added or modified on the
+//! current branch, and [`Report::partition_by_diff`] splits findings into
+//! those whose span touches one of them and the rest. A caller fails only on
+//! the former and reports everything else informationally, so legacy code can
+//! be cleaned up gradually without blocking unrelated changes. The planned
+//! `whitaker check --diff-aware` flag will wrap this API once the CLI lands.
+//!
+//! Changed lines come from `git diff --unified=0 --merge-base <base>`, which
+//! compares the working tree with the point where the branch left `base`.
+//! Only the new side of each hunk matters: pure deletions leave no line for a
+//! finding to sit on.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::ops::RangeInclusive;
+use std::process::Command;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Serialize;
+use thiserror::Error;
+
+use super::{Finding, Location, Report};
+
+/// Errors raised while collecting changed lines.
+#[derive(Debug, Error)]
+pub enum DiffError {
+    /// `git` could not be started.
+    #[error("failed to run `git diff`: {source}")]
+    Spawn {
+        /// Underlying I/O failure.
+        #[source]
+        source: io::Error,
+    },
+    /// `git diff` exited unsuccessfully, for example because `base` is unknown.
+    #[error("`git diff` failed: {stderr}")]
+    Git {
+        /// Trimmed standard error from `git`.
+        stderr: String,
+    },
+    /// A hunk header could not be parsed.
+    #[error("line {line}: malformed hunk header `{header}`")]
+    MalformedHunk {
+        /// One-based line number within the diff.
+        line: usize,
+        /// Header text as written.
+        header: String,
+    },
+}
+
+/// Lines added or modified per repository-relative file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChangedLines {
+    files: BTreeMap<Utf8PathBuf, Vec<RangeInclusive<usize>>>,
+}
+
+impl ChangedLines {
+    /// Parses a unified diff, keeping the new-side line ranges of each hunk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiffError::MalformedHunk`] when a hunk header cannot be
+    /// parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use camino::Utf8Path;
+    /// use whitaker::report::ChangedLines;
+    ///
+    /// let diff = "\
+    /// --- a/src/lib.rs
+    /// +++ b/src/lib.rs
+    /// @@ -10,0 +11,2 @@ fn main() {
+    /// +    let a = 1;
+    /// +    let b = 2;
+    /// ";
+    /// let changes = ChangedLines::parse(diff).expect("diff should parse");
+    ///
+    /// assert!(changes.contains(Utf8Path::new("src/lib.rs"), 12));
+    /// assert!(!changes.contains(Utf8Path::new("src/lib.rs"), 13));
+    /// ```
+    pub fn parse(diff: &str) -> Result<Self, DiffError> {
+        let mut changes = Self::default();
+        let mut current: Option<Utf8PathBuf> = None;
+        let mut hunk = Hunk::default();
+
+        for (index, line) in diff.lines().enumerate() {
+            if hunk.is_open() {
+                let added = hunk.advance(line);
+                changes.record(current.as_deref(), added);
+            } else if let Some(header) = line.strip_prefix("@@ ") {
+                hunk = Hunk::parse(header).ok_or_else(|| DiffError::MalformedHunk {
+                    line: index + 1,
+                    header: line.to_owned(),
+                })?;
+            } else if let Some(target) = line.strip_prefix("+++ ") {
+                current = new_file_path(target);
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Records an added line, merging it into the previous range when
+    /// adjacent.
+    fn record(&mut self, file: Option<&Utf8Path>, added: Option<usize>) {
+        let (Some(path), Some(line)) = (file, added) else {
+            return;
+        };
+        let ranges = self.files.entry(path.to_owned()).or_default();
+        match ranges.last_mut() {
+            Some(range) if *range.end() + 1 == line => *range = *range.start()..=line,
+            _ => ranges.push(line..=line),
+        }
+    }
+
+    /// Collects the lines changed since the current branch diverged from
+    /// `base`, including uncommitted edits in the working tree.
+    ///
+    /// `root` may be any directory inside the repository; `git` reports paths
+    /// relative to the repository root, matching report locations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiffError`] when `git` cannot be run, rejects `base`, or
+    /// produces a diff that cannot be parsed.
+    pub fn from_git(root: &Utf8Path, base: &str) -> Result<Self, DiffError> {
+        let output = Command::new("git")
+            .current_dir(root)
+            .args([
+                "diff",
+                "--unified=0",
+                "--no-color",
+                "--no-ext-diff",
+                "--merge-base",
+                base,
+            ])
+            .output()
+            .map_err(|source| DiffError::Spawn { source })?;
+        if !output.status.success() {
+            return Err(DiffError::Git {
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            });
+        }
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Returns `true` when `line` of `path` was added or modified.
+    #[must_use]
+    pub fn contains(&self, path: &Utf8Path, line: usize) -> bool {
+        self.files
+            .get(path)
+            .is_some_and(|ranges| ranges.iter().any(|range| range.contains(&line)))
+    }
+
+    /// Returns `true` when any line spanned by `location` was changed.
+    #[must_use]
+    pub fn touches(&self, location: &Location) -> bool {
+        self.files.get(&location.path).is_some_and(|ranges| {
+            let span = location.lines();
+            ranges
+                .iter()
+                .any(|range| range.start() <= span.end() && span.start() <= range.end())
+        })
+    }
+
+    /// Returns `true` when the diff changes no lines.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// Findings split by whether they touch changed lines.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct DiffPartition {
+    /// Findings on added or modified lines; these fail the run.
+    pub blocking: Vec<Finding>,
+    /// Findings elsewhere, reported without affecting the exit status.
+    pub informational: Vec<Finding>,
+}
+
+impl DiffPartition {
+    /// Splits `report` using `changes`, preserving emission order.
+    #[must_use]
+    pub fn new(report: &Report, changes: &ChangedLines) -> Self {
+        let (blocking, informational) = report
+            .findings
+            .iter()
+            .cloned()
+            .partition(|finding| changes.touches(&finding.location));
+        Self {
+            blocking,
+            informational,
+        }
+    }
+
+    /// Returns `true` when the run should fail.
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        !self.blocking.is_empty()
+    }
+}
+
+/// Maps the target of a `+++` line to a repository-relative path, or `None`
+/// when the file was deleted.
+fn new_file_path(target: &str) -> Option<Utf8PathBuf> {
+    let unquoted = target.trim_matches('"');
+    (unquoted != "/dev/null")
+        .then(|| Utf8PathBuf::from(unquoted.strip_prefix("b/").unwrap_or(unquoted)))
+}
+
+/// Line accounting for the hunk currently being read.
+///
+/// Tracking the remaining old and new line counts tells content lines apart
+/// from file headers, even when removed or added text itself starts with
+/// `---` or `+++`.
+#[derive(Clone, Copy, Debug, Default)]
+struct Hunk {
+    old_left: usize,
+    new_left: usize,
+    next_line: usize,
+}
+
+impl Hunk {
+    /// Parses the text after `@@ ` in a header such as `-3,2 +4,5 @@`.
+    fn parse(header: &str) -> Option<Self> {
+        let mut sides = header.split_whitespace();
+        let (_, old_count) = parse_side(sides.next()?.strip_prefix('-')?)?;
+        let (new_start, new_count) = parse_side(sides.next()?.strip_prefix('+')?)?;
+        Some(Self {
+            old_left: old_count,
+            new_left: new_count,
+            next_line: new_start,
+        })
+    }
+
+    const fn is_open(&self) -> bool {
+        self.old_left > 0 || self.new_left > 0
+    }
+
+    /// Consumes one content line, returning its new-side number when added.
+    fn advance(&mut self, line: &str) -> Option<usize> {
+        match line.chars().next() {
+            Some('+') => {
+                self.new_left = self.new_left.saturating_sub(1);
+                self.next_line += 1;
+                Some(self.next_line - 1)
+            }
+            Some('-') => {
+                self.old_left = self.old_left.saturating_sub(1);
+                None
+            }
+            Some('\\') => None,
+            _ => {
+                self.old_left = self.old_left.saturating_sub(1);
+                self.new_left = self.new_left.saturating_sub(1);
+                self.next_line += 1;
+                None
+            }
+        }
+    }
+}
+
+/// Parses `start[,count]`, where an omitted count means one line.
+fn parse_side(side: &str) -> Option<(usize, usize)> {
+    match side.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((side.parse().ok()?, 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const DIFF: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -4 +4 @@ fn first() {
+-    old();
++    new();
+@@ -20 +19,0 @@ fn removed() {
+--- gone;
+@@ -30,0 +30,3 @@ fn added() {
++++ counter;
++    two();
++    three();
+@@ -50,3 +52,3 @@ fn context() {
+     keep();
+-    before();
++    after();
+     keep();
+diff --git a/src/old.rs b/src/old.rs
+deleted file mode 100644
+--- a/src/old.rs
++++ /dev/null
+@@ -1,2 +0,0 @@
+-fn old() {}
+-fn older() {}
+";
+
+    #[rstest]
+    #[case::single_line(4, true)]
+    #[case::pure_deletion(19, false)]
+    #[case::added_block_start(30, true)]
+    #[case::added_block_end(32, true)]
+    #[case::after_block(33, false)]
+    #[case::leading_context(52, false)]
+    #[case::modified_between_context(53, true)]
+    #[case::trailing_context(54, false)]
+    fn parses_added_lines(#[case] line: usize, #[case] expected: bool) {
+        let changes = ChangedLines::parse(DIFF).expect("diff should parse");
+
+        assert_eq!(
+            changes.contains(Utf8Path::new("src/lib.rs"), line),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn content_resembling_headers_is_not_a_file() {
+        let changes = ChangedLines::parse(DIFF).expect("diff should parse");
+
+        assert!(!changes.contains(Utf8Path::new("counter;"), 1));
+        assert!(!changes.contains(Utf8Path::new("src/old.rs"), 1));
+    }
+
+    #[rstest]
+    fn malformed_hunk_reports_line() {
+        let diff = "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1 +x @@\n";
+
+        let error = ChangedLines::parse(diff).expect_err("header should be rejected");
+
+        assert!(matches!(error, DiffError::MalformedHunk { line: 3, .. }));
+    }
+
+    #[rstest]
+    #[case::starts_before(Location::new("src/lib.rs", 28, 1).with_end_line(30), true)]
+    #[case::inside(Location::new("src/lib.rs", 31, 5), true)]
+    #[case::ends_before(Location::new("src/lib.rs", 25, 1).with_end_line(29), false)]
+    #[case::other_file(Location::new("src/main.rs", 4, 1), false)]
+    fn touches_overlapping_spans(#[case] location: Location, #[case] expected: bool) {
+        let changes = ChangedLines::parse(DIFF).expect("diff should parse");
+
+        assert_eq!(changes.touches(&location), expected);
+    }
+}
@@ -0,0 +1,135 @@
+//! Standalone HTML rendering for [`Report`].
+
+use std::collections::BTreeSet;
+
+use whitaker_common::lint_docs::LintDocs;
+
+use super::{Finding, Report};
+
+const HEADER: &str = "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+                      <title>Whitaker report</title>\n</head>\n<body>\n<h1>Whitaker report</h1>\n";
+const FOOTER: &str = "</body>\n</html>\n";
+
+/// Renders `report` as a self-contained HTML document.
+///
+/// Lints with findings and an entry in `docs` get a guidance section after the
+/// findings table.
+pub(super) fn render(report: &Report, docs: &[&LintDocs]) -> String {
+    let summary = format!("<p>{} findings</p>\n", report.findings.len());
+    let findings = report
+        .findings
+        .iter()
+        .map(finding_row)
+        .collect::<Vec<_>>()
+        .concat();
+    format!(
+        "{HEADER}{summary}{owners}<h2>Findings</h2>\n<table>\n<thead><tr><th>Lint</th>\
+         <th>Location</th><th>Message</th><th>Owners</th></tr></thead>\n<tbody>\n{findings}\
+         </tbody>\n</table>\n{guidance}{FOOTER}",
+        owners = owner_section(report),
+        guidance = guidance_section(report, docs),
+    )
+}
+
+/// Renders the per-owner summary, or nothing when no owners are attached.
+fn owner_section(report: &Report) -> String {
+    if report
+        .findings
+        .iter()
+        .all(|finding| finding.owners.is_empty())
+    {
+        return String::new();
+    }
+
+    let rows = report
+        .owner_counts()
+        .into_iter()
+        .map(|(owner, count)| format!("<tr><td>{}</td><td>{count}</td></tr>\n", escape(owner)))
+        .collect::<Vec<_>>()
+        .concat();
+    format!(
+        "<h2>Findings by owner</h2>\n<table>\n<thead><tr><th>Owner</th><th>Findings</th></tr>\
+         </thead>\n<tbody>\n{rows}</tbody>\n</table>\n"
+    )
+}
+
+/// Renders the documentation of each reported lint, or nothing when `docs`
+/// covers none of them.
+fn guidance_section(report: &Report, docs: &[&LintDocs]) -> String {
+    let reported: BTreeSet<&str> = report
+        .findings
+        .iter()
+        .map(|finding| finding.lint.as_str())
+        .collect();
+    let entries = reported
+        .into_iter()
+        .filter_map(|lint| docs.iter().find(|entry| entry.name == lint))
+        .map(|entry| lint_guidance(entry))
+        .collect::<Vec<_>>()
+        .concat();
+    if entries.is_empty() {
+        return String::new();
+    }
+    format!("<h2>Lint guidance</h2>\n{entries}")
+}
+
+fn lint_guidance(docs: &LintDocs) -> String {
+    let config = if docs.config.is_empty() {
+        String::new()
+    } else {
+        let rows = docs
+            .config
+            .iter()
+            .map(|entry| {
+                format!(
+                    "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>\n",
+                    escape(entry.key),
+                    escape(entry.default),
+                    escape(entry.description),
+                )
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        format!(
+            "<table>\n<thead><tr><th>Key</th><th>Default</th><th>Description</th></tr></thead>\n\
+             <tbody>\n{rows}</tbody>\n</table>\n"
+        )
+    };
+    format!(
+        "<h3 id=\"{name}\"><code>{name}</code> ({version})</h3>\n<p>{summary}</p>\n\
+         <p>{rationale}</p>\n{config}",
+        name = escape(docs.name),
+        version = escape(docs.version),
+        summary = escape(docs.summary),
+        rationale = escape(docs.rationale),
+    )
+}
+
+fn finding_row(finding: &Finding) -> String {
+    let location = &finding.location;
+    format!(
+        "<tr><td>{}</td><td>{}:{}:{}</td><td>{}</td><td>{}</td></tr>\n",
+        escape(&finding.lint),
+        escape(location.path.as_str()),
+        location.line,
+        location.column,
+        escape(&finding.message),
+        escape(&finding.owners.join(", ")),
+    )
+}
+
+/// Escapes text for inclusion in HTML element content.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,368 @@
+//! Lint findings collected into JSON and HTML reports.
+//!
+//! A [`Report`] is the runner's record of every diagnostic produced during a
+//! `whitaker check` run. Reports serialise to stable JSON for automation and
+//! render to a self-contained HTML page for people. When the repository has a
+//! `CODEOWNERS` file, [`Report::attach_owners`] annotates each finding with the
+//! teams that own its file so the output can be sliced per team.
+
+mod codeowners;
+mod compare;
+mod diff;
+mod html;
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
+use whitaker_common::diagnostics::{FingerprintSource, fingerprint};
+use whitaker_common::lint_docs::LintDocs;
+
+pub use codeowners::{CODEOWNERS_LOCATIONS, CodeOwners, CodeOwnersError};
+pub use compare::{LintDelta, ReportComparison, Trend};
+pub use diff::{ChangedLines, DiffError, DiffPartition};
+
+/// Label used in owner summaries for findings without an owner.
+pub const UNOWNED: &str = "(unowned)";
+
+/// Source position of a finding's primary span.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    /// Repository-relative path of the file containing the finding.
+    pub path: Utf8PathBuf,
+    /// One-based line of the primary span.
+    pub line: usize,
+    /// One-based column of the primary span.
+    pub column: usize,
+    /// One-based last line of the primary span, when it covers several lines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+}
+
+impl Location {
+    /// Creates a location from a repository-relative path and position.
+    #[must_use]
+    pub fn new(path: impl Into<Utf8PathBuf>, line: usize, column: usize) -> Self {
+        Self {
+            path: path.into(),
+            line,
+            column,
+            end_line: None,
+        }
+    }
+
+    /// Records the last line of a multi-line span.
+    #[must_use]
+    pub const fn with_end_line(mut self, end_line: usize) -> Self {
+        self.end_line = Some(end_line);
+        self
+    }
+
+    /// Returns the lines covered by the primary span.
+    #[must_use]
+    pub fn lines(&self) -> RangeInclusive<usize> {
+        let end = self.end_line.unwrap_or(self.line).max(self.line);
+        self.line..=end
+    }
+}
+
+/// A single diagnostic emitted by a Whitaker lint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Name of the lint that produced the finding.
+    pub lint: String,
+    /// Where the primary span starts.
+    pub location: Location,
+    /// Primary diagnostic message.
+    pub message: String,
+    /// Owners resolved from `CODEOWNERS`; empty when unknown or unowned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+    /// Stable fingerprint of the finding; see [`Finding::fingerprint`].
+    ///
+    /// Findings built with [`Finding::new`] and reports loaded with
+    /// [`Report::from_json`] always carry one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+}
+
+impl Finding {
+    /// Creates a finding without owner information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::report::{Finding, Location};
+    ///
+    /// let finding = Finding::new(
+    ///     "no_static_mut",
+    ///     Location::new("src/lib.rs", 4, 1),
+    ///     "`static mut` item `COUNTER` permits unsynchronised global mutation.",
+    /// );
+    /// assert!(finding.owners.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(lint: impl Into<String>, location: Location, message: impl Into<String>) -> Self {
+        Self {
+            lint: lint.into(),
+            location,
+            message: message.into(),
+            owners: Vec::new(),
+            fingerprint: None,
+        }
+        .with_message_fingerprint()
+    }
+
+    /// Records the stable fingerprint of the flagged source.
+    ///
+    /// The fingerprint follows the scheme in
+    /// [`whitaker_common::diagnostics::fingerprint`]: it hashes the lint
+    /// name, the normalised path, `snippet` with whitespace removed, and the
+    /// enclosing item's name, so it survives both line-number churn and
+    /// reformatting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::report::{Finding, Location};
+    ///
+    /// let finding = |line| {
+    ///     Finding::new("no_expect_outside_tests", Location::new("src/lib.rs", line, 5), "m")
+    ///         .with_source("config.expect(\"loaded\")", Some("run"))
+    /// };
+    ///
+    /// assert!(finding(4).fingerprint.is_some());
+    /// assert_eq!(finding(4).fingerprint(), finding(40).fingerprint());
+    /// ```
+    #[must_use]
+    pub fn with_source(mut self, snippet: &str, enclosing_item: Option<&str>) -> Self {
+        let source = FingerprintSource {
+            path: self.location.path.as_str(),
+            snippet,
+            enclosing_item,
+        };
+        self.fingerprint = Some(fingerprint(&self.lint, &source).to_string());
+        self
+    }
+
+    /// Returns a stable identifier for the finding across runs.
+    ///
+    /// Every fingerprint follows the scheme in
+    /// [`whitaker_common::diagnostics::fingerprint`]. One recorded with
+    /// [`Finding::with_source`] hashes the flagged source; otherwise the
+    /// message stands in for the source. Either way, line and column numbers
+    /// are excluded so that edits elsewhere in a file do not make an existing
+    /// finding look new.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::report::{Finding, Location};
+    ///
+    /// let before = Finding::new("no_static_mut", Location::new("src/lib.rs", 4, 1), "m");
+    /// let after = Finding::new("no_static_mut", Location::new("./src/lib.rs", 40, 5), "m");
+    /// assert_eq!(before.fingerprint(), after.fingerprint());
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> String {
+        self.fingerprint
+            .clone()
+            .unwrap_or_else(|| self.message_fingerprint())
+    }
+
+    /// Reports whether the fingerprint hashes the flagged source rather than
+    /// the message.
+    fn has_source_fingerprint(&self) -> bool {
+        self.fingerprint() != self.message_fingerprint()
+    }
+
+    /// Fingerprints the finding with its message in place of the flagged
+    /// source.
+    fn message_fingerprint(&self) -> String {
+        let source = FingerprintSource {
+            path: self.location.path.as_str(),
+            snippet: &self.message,
+            enclosing_item: None,
+        };
+        fingerprint(&self.lint, &source).to_string()
+    }
+
+    fn with_message_fingerprint(mut self) -> Self {
+        if self.fingerprint.is_none() {
+            self.fingerprint = Some(self.message_fingerprint());
+        }
+        self
+    }
+}
+
+/// Findings gathered from one run of the suite.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    /// Findings in the order they were emitted.
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    /// Creates a report from `findings`.
+    #[must_use]
+    pub const fn new(findings: Vec<Finding>) -> Self {
+        Self { findings }
+    }
+
+    /// Replaces each finding's owners with those listed in `codeowners`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::report::{CodeOwners, Finding, Location, Report};
+    ///
+    /// let codeowners = CodeOwners::parse("/crates/parser/ @org/parsing\n")
+    ///     .expect("CODEOWNERS should parse");
+    /// let mut report = Report::new(vec![Finding::new(
+    ///     "module_max_lines",
+    ///     Location::new("crates/parser/src/lib.rs", 1, 1),
+    ///     "Module lib spans 900 lines",
+    /// )]);
+    ///
+    /// report.attach_owners(&codeowners);
+    ///
+    /// assert_eq!(report.findings[0].owners, ["@org/parsing"]);
+    /// ```
+    pub fn attach_owners(&mut self, codeowners: &CodeOwners) {
+        for finding in &mut self.findings {
+            finding.owners = codeowners.owners_for(&finding.location.path).to_vec();
+        }
+    }
+
+    /// Counts findings per owner, using [`UNOWNED`] for findings without one.
+    ///
+    /// A finding with several owners counts once towards each of them.
+    #[must_use]
+    pub fn owner_counts(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        for finding in &self.findings {
+            if finding.owners.is_empty() {
+                *counts.entry(UNOWNED).or_default() += 1;
+            }
+            for owner in &finding.owners {
+                *counts.entry(owner.as_str()).or_default() += 1;
+            }
+        }
+        counts
+    }
+
+    /// Serialises the report as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`serde_json::Error`] if serialisation fails.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parses a report previously written by [`Report::to_json`].
+    ///
+    /// Findings saved without a fingerprint, as older reports were, gain the
+    /// one [`Finding::new`] would have given them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`serde_json::Error`] when `json` is not a valid report.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let report: Self = serde_json::from_str(json)?;
+        Ok(Self::new(
+            report
+                .findings
+                .into_iter()
+                .map(Finding::with_message_fingerprint)
+                .collect(),
+        ))
+    }
+
+    /// Compares this report against an earlier run.
+    ///
+    /// See [`ReportComparison::between`] for the matching rules.
+    #[must_use]
+    pub fn compare(&self, previous: &Self) -> ReportComparison {
+        ReportComparison::between(previous, self)
+    }
+
+    /// Splits findings by whether they touch lines in `changes`.
+    ///
+    /// Callers fail the run on the blocking findings only. The planned
+    /// `whitaker check --diff-aware` flag will be built on this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::report::{ChangedLines, Finding, Location, Report};
+    ///
+    /// let changes = ChangedLines::parse("--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -3,0 +4 @@\n+x\n")
+    ///     .expect("diff should parse");
+    /// let report = Report::new(vec![
+    ///     Finding::new("no_static_mut", Location::new("src/lib.rs", 4, 1), "new"),
+    ///     Finding::new("no_static_mut", Location::new("src/lib.rs", 90, 1), "legacy"),
+    /// ]);
+    ///
+    /// let partition = report.partition_by_diff(&changes);
+    ///
+    /// assert!(partition.is_failure());
+    /// assert_eq!(partition.blocking.len(), 1);
+    /// assert_eq!(partition.informational.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn partition_by_diff(&self, changes: &ChangedLines) -> DiffPartition {
+        DiffPartition::new(self, changes)
+    }
+
+    /// Renders the report as a standalone HTML page.
+    ///
+    /// The page lists every finding and, when owners are attached, a summary
+    /// table of findings per owner.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        html::render(self, &[])
+    }
+
+    /// Renders the report as a standalone HTML page with lint guidance.
+    ///
+    /// Alongside the [`Report::to_html`] content, the page explains each lint
+    /// that produced a finding and has an entry in `docs`: its summary,
+    /// rationale, and configuration keys with their defaults. Pass the suite's
+    /// embedded documentation so the guidance matches the lint version that
+    /// reported the findings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::report::{Finding, Location, Report};
+    /// use whitaker_common::lint_docs::LintDocs;
+    ///
+    /// const DOCS: LintDocs = LintDocs {
+    ///     name: "no_static_mut",
+    ///     version: "0.2.7",
+    ///     summary: "Flags `static mut` items.",
+    ///     rationale: "Every access is unsafe.",
+    ///     bad_example: "static mut COUNT: u32 = 0;",
+    ///     good_example: "static COUNT: AtomicU32 = AtomicU32::new(0);",
+    ///     config: &[],
+    /// };
+    /// let report = Report::new(vec![Finding::new(
+    ///     "no_static_mut",
+    ///     Location::new("src/lib.rs", 3, 1),
+    ///     "`static mut` item",
+    /// )]);
+    ///
+    /// let page = report.to_html_with_docs(&[&DOCS]);
+    ///
+    /// assert!(page.contains("<p>Every access is unsafe.</p>"));
+    /// ```
+    #[must_use]
+    pub fn to_html_with_docs(&self, docs: &[&LintDocs]) -> String {
+        html::render(self, docs)
+    }
+}
+
+#[cfg(test)]
+mod tests;
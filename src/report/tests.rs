@@ -0,0 +1,257 @@
+//! Tests for report serialisation, ownership, diff filtering, and HTML
+//! rendering.
+
+use super::*;
+use rstest::{fixture, rstest};
+use whitaker_common::lint_docs::ConfigKeyDocs;
+
+const MODULE_MAX_LINES_DOCS: LintDocs = LintDocs {
+    name: "module_max_lines",
+    version: "0.2.7",
+    summary: "Flags modules longer than `max_lines`.",
+    rationale: "Long modules <mix> concerns.",
+    bad_example: "mod parser { /* 900 lines */ }",
+    good_example: "mod parser { mod lexer; mod grammar; }",
+    config: &[ConfigKeyDocs {
+        key: "max_lines",
+        default: "400",
+        description: "Maximum number of lines in a module.",
+    }],
+};
+
+const UNREPORTED_DOCS: LintDocs = LintDocs {
+    name: "no_recursion",
+    version: "0.2.7",
+    summary: "Flags recursive functions.",
+    rationale: "Recursion depth is unbounded.",
+    bad_example: "fn walk() { walk() }",
+    good_example: "fn walk() { loop {} }",
+    config: &[],
+};
+
+#[fixture]
+fn report() -> Report {
+    Report::new(vec![
+        Finding::new(
+            "module_max_lines",
+            Location::new("crates/parser/src/lib.rs", 1, 1),
+            "too long",
+        ),
+        Finding::new(
+            "no_static_mut",
+            Location::new("crates/cli/src/main.rs", 4, 1),
+            "`static mut`",
+        ),
+        Finding::new(
+            "no_expect_outside_tests",
+            Location::new("build.rs", 9, 5),
+            "expect",
+        ),
+    ])
+}
+
+#[fixture]
+fn codeowners() -> CodeOwners {
+    CodeOwners::parse("/crates/ @org/platform\n/crates/parser/ @org/parsing @org/platform\n")
+        .expect("CODEOWNERS should parse")
+}
+
+#[rstest]
+fn attach_owners_resolves_each_finding(mut report: Report, codeowners: CodeOwners) {
+    report.attach_owners(&codeowners);
+
+    let owners: Vec<_> = report
+        .findings
+        .iter()
+        .map(|finding| finding.owners.clone())
+        .collect();
+    assert_eq!(
+        owners,
+        [
+            vec!["@org/parsing".to_owned(), "@org/platform".to_owned()],
+            vec!["@org/platform".to_owned()],
+            Vec::new(),
+        ]
+    );
+}
+
+#[rstest]
+fn owner_counts_include_unowned_findings(mut report: Report, codeowners: CodeOwners) {
+    report.attach_owners(&codeowners);
+
+    let counts = report.owner_counts();
+
+    assert_eq!(counts.get("@org/platform"), Some(&2));
+    assert_eq!(counts.get("@org/parsing"), Some(&1));
+    assert_eq!(counts.get(UNOWNED), Some(&1));
+}
+
+#[rstest]
+fn json_round_trips_with_owners(mut report: Report, codeowners: CodeOwners) {
+    report.attach_owners(&codeowners);
+
+    let json = report.to_json().expect("report should serialise");
+    let parsed = Report::from_json(&json).expect("report should parse");
+
+    assert_eq!(parsed, report);
+    assert!(json.contains("\"owners\""));
+}
+
+#[rstest]
+fn json_omits_owners_until_attached(report: Report) {
+    let json = report.to_json().expect("report should serialise");
+
+    assert!(!json.contains("\"owners\""));
+    assert!(!json.contains("\"end_line\""));
+}
+
+#[rstest]
+fn findings_saved_without_fingerprints_gain_them_on_load(report: Report) {
+    let mut legacy = report.clone();
+    for finding in &mut legacy.findings {
+        finding.fingerprint = None;
+    }
+    let json = legacy.to_json().expect("report should serialise");
+    assert!(!json.contains("\"fingerprint\""));
+
+    let parsed = Report::from_json(&json).expect("report should parse");
+
+    assert_eq!(parsed, report);
+}
+
+#[rstest]
+fn html_summarises_findings_per_owner(mut report: Report, codeowners: CodeOwners) {
+    report.attach_owners(&codeowners);
+
+    let page = report.to_html();
+
+    assert!(page.contains("<h2>Findings by owner</h2>"));
+    assert!(page.contains("<tr><td>@org/platform</td><td>2</td></tr>"));
+    assert!(page.contains("<tr><td>(unowned)</td><td>1</td></tr>"));
+}
+
+#[rstest]
+fn html_omits_owner_summary_without_owners(report: Report) {
+    assert!(!report.to_html().contains("Findings by owner"));
+}
+
+#[rstest]
+fn html_explains_reported_lints_from_docs(report: Report) {
+    let page = report.to_html_with_docs(&[&MODULE_MAX_LINES_DOCS, &UNREPORTED_DOCS]);
+
+    assert!(page.contains("<h2>Lint guidance</h2>"));
+    assert!(page.contains("<code>module_max_lines</code> (0.2.7)</h3>"));
+    assert!(page.contains("<p>Flags modules longer than `max_lines`.</p>"));
+    assert!(page.contains("<p>Long modules &lt;mix&gt; concerns.</p>"));
+    assert!(page.contains(
+        "<tr><td><code>max_lines</code></td><td><code>400</code></td>\
+         <td>Maximum number of lines in a module.</td></tr>"
+    ));
+    assert!(!page.contains("no_recursion"));
+}
+
+#[rstest]
+fn html_omits_guidance_without_matching_docs(report: Report) {
+    assert!(!report.to_html().contains("Lint guidance"));
+    assert!(
+        !report
+            .to_html_with_docs(&[&UNREPORTED_DOCS])
+            .contains("Lint guidance")
+    );
+}
+
+#[rstest]
+fn html_escapes_finding_text() {
+    let report = Report::new(vec![Finding::new(
+        "lint",
+        Location::new("src/lib.rs", 1, 1),
+        "<script>alert('x') & \"y\"</script>",
+    )]);
+
+    let page = report.to_html();
+
+    assert!(page.contains("&lt;script&gt;alert(&#39;x&#39;) &amp; &quot;y&quot;&lt;/script&gt;"));
+    assert!(!page.contains("<script>"));
+}
+
+#[rstest]
+#[case::lint("other", "crates/cli/src/main.rs", "`static mut`")]
+#[case::path("no_static_mut", "crates/cli/src/lib.rs", "`static mut`")]
+#[case::message("no_static_mut", "crates/cli/src/main.rs", "`static mut` item")]
+fn fingerprints_distinguish_lint_path_and_message(
+    report: Report,
+    #[case] lint: &str,
+    #[case] path: &str,
+    #[case] message: &str,
+) {
+    let original = report.findings.get(1).expect("fixture has three findings");
+    let variant = Finding::new(lint, Location::new(path, 4, 1), message);
+
+    assert_ne!(original.fingerprint(), variant.fingerprint());
+    assert_eq!(original.fingerprint().len(), 16);
+}
+
+#[rstest]
+fn compare_treats_the_receiver_as_the_current_run(report: Report) {
+    let previous = Report::new(report.findings.iter().take(1).cloned().collect());
+
+    let comparison = report.compare(&previous);
+
+    assert_eq!(comparison.new_count(), 2);
+    assert_eq!(comparison.fixed_count(), 0);
+}
+
+#[rstest]
+fn diff_partition_blocks_only_changed_findings(report: Report) {
+    let changes = ChangedLines::parse(
+        "--- a/crates/cli/src/main.rs\n+++ b/crates/cli/src/main.rs\n@@ -3,2 +3,2 @@\n\
+         -a\n-b\n+c\n+d\n",
+    )
+    .expect("diff should parse");
+
+    let partition = report.partition_by_diff(&changes);
+
+    let blocking: Vec<_> = partition
+        .blocking
+        .iter()
+        .map(|finding| finding.lint.as_str())
+        .collect();
+    let informational: Vec<_> = partition
+        .informational
+        .iter()
+        .map(|finding| finding.lint.as_str())
+        .collect();
+    assert_eq!(blocking, ["no_static_mut"]);
+    assert_eq!(
+        informational,
+        ["module_max_lines", "no_expect_outside_tests"]
+    );
+    assert!(partition.is_failure());
+}
+
+#[rstest]
+fn diff_partition_without_changes_never_fails(report: Report) {
+    let partition = report.partition_by_diff(&ChangedLines::default());
+
+    assert!(!partition.is_failure());
+    assert_eq!(partition.informational.len(), report.findings.len());
+}
+
+#[rstest]
+fn source_fingerprints_survive_moves_and_round_trip_through_json() {
+    let at = |line| {
+        Finding::new("no_static_mut", Location::new("src/lib.rs", line, 1), "m")
+            .with_source("static mut COUNTER: u32 = 0;", None)
+    };
+    let report = Report::new(vec![at(3)]);
+
+    let json = report.to_json().expect("report should serialise");
+    let parsed = Report::from_json(&json).expect("report should parse");
+
+    assert!(json.contains("\"fingerprint\""));
+    assert_eq!(parsed, report);
+    assert_eq!(
+        report.compare(&Report::new(vec![at(30)])).unchanged_count(),
+        1
+    );
+}
@@ -0,0 +1,315 @@
+//! Pure decision functions for lints and a conformance harness over them.
+//!
+//! Each suite lint splits its check in two. The driver projects the
+//! compiler's HIR into a small data-only value, and a [`LintDecision`] judges
+//! that value without touching `rustc_*` types. The decision can then be
+//! simulated from plain Rust. A driver that reports through the decision
+//! emits exactly what the simulation predicts; the others call the same
+//! policy helpers the decision is built from.
+//!
+//! Every decision also publishes [`DecisionFixture`]s: projections paired
+//! with the outcome the lint must produce. The lint's own tests and the
+//! suite-wide conformance test both run them through [`check_conformance`],
+//! which additionally checks that decisions are deterministic and that
+//! findings carry the lint's name.
+//!
+//! # Examples
+//!
+//! ```
+//! use whitaker::testing::decision::{
+//!     DecisionFixture, Finding, LintDecision, check_conformance,
+//! };
+//!
+//! struct MaxArgs {
+//!     limit: usize,
+//! }
+//!
+//! impl LintDecision for MaxArgs {
+//!     type Projection = usize;
+//!
+//!     fn lint_name(&self) -> &'static str {
+//!         "max_args"
+//!     }
+//!
+//!     fn decide(&self, arguments: &usize) -> Option<Finding> {
+//!         (*arguments > self.limit).then(|| Finding::new("max_args", "too_many"))
+//!     }
+//!
+//!     fn fixtures(&self) -> Vec<DecisionFixture<usize>> {
+//!         vec![
+//!             DecisionFixture::clean("at the limit", self.limit),
+//!             DecisionFixture::flagged("over the limit", self.limit + 1, "too_many"),
+//!         ]
+//!     }
+//! }
+//!
+//! assert_eq!(check_conformance(&MaxArgs { limit: 4 }), Ok(2));
+//! ```
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// What a lint reports about a projection.
+///
+/// `reason` is a stable, lint-specific key such as `"exceeds_limit"`, so
+/// tests can tell apart the different ways one lint fires. `subject` names
+/// what was reported when the projection carries a name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    lint: &'static str,
+    reason: &'static str,
+    subject: Option<String>,
+}
+
+impl Finding {
+    /// Creates a finding for `lint` with the given reason key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::testing::decision::Finding;
+    ///
+    /// let finding = Finding::new("module_max_lines", "exceeds_limit");
+    /// assert_eq!(finding.reason(), "exceeds_limit");
+    /// assert_eq!(finding.subject(), None);
+    /// ```
+    #[must_use]
+    pub const fn new(lint: &'static str, reason: &'static str) -> Self {
+        Self {
+            lint,
+            reason,
+            subject: None,
+        }
+    }
+
+    /// Names the item, call, or value the finding is about.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use whitaker::testing::decision::Finding;
+    ///
+    /// let finding = Finding::new("no_static_mut", "declaration").with_subject("COUNTER");
+    /// assert_eq!(finding.subject(), Some("COUNTER"));
+    /// ```
+    #[must_use]
+    pub fn with_subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /// Returns the name of the lint that produced the finding.
+    #[must_use]
+    pub const fn lint(&self) -> &'static str {
+        self.lint
+    }
+
+    /// Returns the reason key.
+    #[must_use]
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+
+    /// Returns the subject, if one was recorded.
+    #[must_use]
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+}
+
+/// A lint's decision logic over a data-only projection of the HIR.
+pub trait LintDecision {
+    /// The data the driver extracts from the compiler for one check.
+    type Projection: fmt::Debug;
+
+    /// Returns the lint name, as used in `#[allow(...)]` and `dylint.toml`.
+    fn lint_name(&self) -> &'static str;
+
+    /// Judges `projection`, returning a finding when the lint should fire.
+    fn decide(&self, projection: &Self::Projection) -> Option<Finding>;
+
+    /// Returns projections paired with the outcome this decision must give.
+    ///
+    /// Fixtures should include at least one flagged and one clean case so
+    /// the conformance check exercises both outcomes.
+    fn fixtures(&self) -> Vec<DecisionFixture<Self::Projection>>;
+}
+
+/// A projection and the reason key it must produce, or `None` when the lint
+/// must stay silent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecisionFixture<P> {
+    /// A short description used in failure messages.
+    pub name: &'static str,
+    /// The projection passed to [`LintDecision::decide`].
+    pub projection: P,
+    /// The reason key the finding must carry, or `None` for no finding.
+    pub expected: Option<&'static str>,
+}
+
+impl<P> DecisionFixture<P> {
+    /// A fixture the lint must report with `reason`.
+    #[must_use]
+    pub const fn flagged(name: &'static str, projection: P, reason: &'static str) -> Self {
+        Self {
+            name,
+            projection,
+            expected: Some(reason),
+        }
+    }
+
+    /// A fixture the lint must accept.
+    #[must_use]
+    pub const fn clean(name: &'static str, projection: P) -> Self {
+        Self {
+            name,
+            projection,
+            expected: None,
+        }
+    }
+}
+
+/// Ways a decision can fail the conformance check.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum ConformanceError {
+    /// The decision published no flagged fixture.
+    #[error("`{lint}` has no fixture that it reports")]
+    MissingFlaggedFixture {
+        /// The lint under test.
+        lint: &'static str,
+    },
+    /// The decision published no clean fixture.
+    #[error("`{lint}` has no fixture that it accepts")]
+    MissingCleanFixture {
+        /// The lint under test.
+        lint: &'static str,
+    },
+    /// A fixture produced a different outcome from the one it records.
+    #[error("`{lint}` fixture `{fixture}` expected {expected:?} but the decision gave {actual:?}")]
+    Mismatch {
+        /// The lint under test.
+        lint: &'static str,
+        /// The fixture name.
+        fixture: &'static str,
+        /// The expected reason key.
+        expected: Option<&'static str>,
+        /// The reason key the decision returned.
+        actual: Option<&'static str>,
+    },
+    /// A finding named a different lint.
+    #[error("`{lint}` fixture `{fixture}` produced a finding for `{reported}`")]
+    WrongLint {
+        /// The lint under test.
+        lint: &'static str,
+        /// The fixture name.
+        fixture: &'static str,
+        /// The lint named by the finding.
+        reported: &'static str,
+    },
+    /// Deciding the same projection twice gave different findings.
+    #[error("`{lint}` fixture `{fixture}` is not decided deterministically")]
+    NonDeterministic {
+        /// The lint under test.
+        lint: &'static str,
+        /// The fixture name.
+        fixture: &'static str,
+    },
+}
+
+/// Runs every fixture of `decision` and returns how many were checked.
+///
+/// # Errors
+///
+/// Returns the first [`ConformanceError`] found: a missing flagged or clean
+/// fixture, an outcome that differs from the fixture, a finding for another
+/// lint, or a projection decided differently on a second attempt.
+///
+/// # Examples
+///
+/// ```ignore
+/// let checked = check_conformance(&ModuleMaxLinesDecision::default())?;
+/// assert!(checked >= 2);
+/// ```
+pub fn check_conformance<D: LintDecision + ?Sized>(
+    decision: &D,
+) -> Result<usize, ConformanceError> {
+    let lint = decision.lint_name();
+    let fixtures = decision.fixtures();
+
+    if !fixtures.iter().any(|fixture| fixture.expected.is_some()) {
+        return Err(ConformanceError::MissingFlaggedFixture { lint });
+    }
+    if !fixtures.iter().any(|fixture| fixture.expected.is_none()) {
+        return Err(ConformanceError::MissingCleanFixture { lint });
+    }
+
+    for fixture in &fixtures {
+        check_fixture(decision, fixture)?;
+    }
+    Ok(fixtures.len())
+}
+
+fn check_fixture<D: LintDecision + ?Sized>(
+    decision: &D,
+    fixture: &DecisionFixture<D::Projection>,
+) -> Result<(), ConformanceError> {
+    let lint = decision.lint_name();
+    let finding = decision.decide(&fixture.projection);
+
+    if decision.decide(&fixture.projection) != finding {
+        return Err(ConformanceError::NonDeterministic {
+            lint,
+            fixture: fixture.name,
+        });
+    }
+    if let Some(reported) = finding
+        .as_ref()
+        .map(Finding::lint)
+        .filter(|name| *name != lint)
+    {
+        return Err(ConformanceError::WrongLint {
+            lint,
+            fixture: fixture.name,
+            reported,
+        });
+    }
+
+    let actual = finding.as_ref().map(Finding::reason);
+    if actual != fixture.expected {
+        return Err(ConformanceError::Mismatch {
+            lint,
+            fixture: fixture.name,
+            expected: fixture.expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Object-safe view of a [`LintDecision`], so a registry can hold the
+/// decisions of many lints with different projection types.
+pub trait ConformanceCheck {
+    /// Returns the lint name.
+    fn lint_name(&self) -> &'static str;
+
+    /// Runs [`check_conformance`] on the decision.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ConformanceError`] reported by [`check_conformance`].
+    fn check(&self) -> Result<usize, ConformanceError>;
+}
+
+impl<D: LintDecision> ConformanceCheck for D {
+    fn lint_name(&self) -> &'static str {
+        LintDecision::lint_name(self)
+    }
+
+    fn check(&self) -> Result<usize, ConformanceError> {
+        check_conformance(self)
+    }
+}
+
+#[cfg(test)]
+mod tests;
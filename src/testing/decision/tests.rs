@@ -0,0 +1,164 @@
+//! Tests for the decision conformance harness.
+use super::{ConformanceCheck, ConformanceError, DecisionFixture, Finding, LintDecision};
+use rstest::rstest;
+use std::cell::Cell;
+
+/// Reports values above a limit, with fixtures chosen by each test.
+struct Threshold {
+    lint: &'static str,
+    limit: u32,
+    fixtures: Vec<DecisionFixture<u32>>,
+}
+
+impl Threshold {
+    fn with_fixtures(fixtures: Vec<DecisionFixture<u32>>) -> Self {
+        Self {
+            lint: "threshold",
+            limit: 3,
+            fixtures,
+        }
+    }
+}
+
+impl LintDecision for Threshold {
+    type Projection = u32;
+
+    fn lint_name(&self) -> &'static str {
+        "threshold"
+    }
+
+    fn decide(&self, value: &u32) -> Option<Finding> {
+        (*value > self.limit).then(|| Finding::new(self.lint, "above_limit"))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<u32>> {
+        self.fixtures.clone()
+    }
+}
+
+fn both_outcomes() -> Vec<DecisionFixture<u32>> {
+    vec![
+        DecisionFixture::clean("at limit", 3),
+        DecisionFixture::flagged("above limit", 4, "above_limit"),
+    ]
+}
+
+#[rstest]
+fn accepts_matching_fixtures() {
+    assert_eq!(Threshold::with_fixtures(both_outcomes()).check(), Ok(2));
+}
+
+#[rstest]
+#[case::no_flagged(
+    vec![DecisionFixture::clean("at limit", 3)],
+    ConformanceError::MissingFlaggedFixture { lint: "threshold" },
+)]
+#[case::no_clean(
+    vec![DecisionFixture::flagged("above limit", 4, "above_limit")],
+    ConformanceError::MissingCleanFixture { lint: "threshold" },
+)]
+#[case::wrong_reason(
+    vec![
+        DecisionFixture::clean("at limit", 3),
+        DecisionFixture::flagged("above limit", 4, "too_high"),
+    ],
+    ConformanceError::Mismatch {
+        lint: "threshold",
+        fixture: "above limit",
+        expected: Some("too_high"),
+        actual: Some("above_limit"),
+    },
+)]
+#[case::unexpected_finding(
+    vec![
+        DecisionFixture::clean("above limit", 5),
+        DecisionFixture::flagged("far above", 9, "above_limit"),
+    ],
+    ConformanceError::Mismatch {
+        lint: "threshold",
+        fixture: "above limit",
+        expected: None,
+        actual: Some("above_limit"),
+    },
+)]
+fn rejects_inconsistent_fixtures(
+    #[case] fixtures: Vec<DecisionFixture<u32>>,
+    #[case] expected: ConformanceError,
+) {
+    assert_eq!(Threshold::with_fixtures(fixtures).check(), Err(expected));
+}
+
+#[rstest]
+fn rejects_findings_for_other_lints() {
+    let decision = Threshold {
+        lint: "other",
+        ..Threshold::with_fixtures(both_outcomes())
+    };
+
+    assert_eq!(
+        decision.check(),
+        Err(ConformanceError::WrongLint {
+            lint: "threshold",
+            fixture: "above limit",
+            reported: "other",
+        })
+    );
+}
+
+/// Fires on every other call, which no projection-only decision should do.
+struct Flaky {
+    calls: Cell<u32>,
+}
+
+impl LintDecision for Flaky {
+    type Projection = ();
+
+    fn lint_name(&self) -> &'static str {
+        "flaky"
+    }
+
+    fn decide(&self, (): &()) -> Option<Finding> {
+        let calls = self.calls.get();
+        self.calls.set(calls + 1);
+        calls
+            .is_multiple_of(2)
+            .then(|| Finding::new("flaky", "odd"))
+    }
+
+    fn fixtures(&self) -> Vec<DecisionFixture<()>> {
+        vec![
+            DecisionFixture::flagged("first", (), "odd"),
+            DecisionFixture::clean("second", ()),
+        ]
+    }
+}
+
+#[rstest]
+fn rejects_non_deterministic_decisions() {
+    let decision = Flaky {
+        calls: Cell::new(0),
+    };
+
+    assert_eq!(
+        decision.check(),
+        Err(ConformanceError::NonDeterministic {
+            lint: "flaky",
+            fixture: "first",
+        })
+    );
+}
+
+#[rstest]
+fn errors_name_the_lint_and_fixture() {
+    let error = ConformanceError::Mismatch {
+        lint: "threshold",
+        fixture: "above limit",
+        expected: Some("above_limit"),
+        actual: None,
+    };
+
+    assert_eq!(
+        error.to_string(),
+        "`threshold` fixture `above limit` expected Some(\"above_limit\") but the decision gave None"
+    );
+}
@@ -1,3 +1,4 @@
 //! Shared test infrastructure used by Whitaker lint crates.
 
+pub mod decision;
 pub mod ui;
@@ -0,0 +1,151 @@
+//! Registry of the decision logic of every suite lint.
+//!
+//! Each suite lint splits its check into a HIR projection and a pure
+//! [`LintDecision`](whitaker::testing::decision::LintDecision), listed here
+//! with its default configuration so one test can hold every lint to its
+//! published fixtures. `rstest_helper_should_be_fixture` is the exception:
+//! it only collects call sites and emits no findings yet, so it has nothing
+//! to decide.
+
+use whitaker::testing::decision::ConformanceCheck;
+
+/// Returns the decision of every suite lint, configured with its defaults,
+/// in suite order.
+///
+/// Lints that report nothing until configured are registered with the
+/// smallest configuration that enables them. Experimental lints appear only
+/// when their suite feature is enabled.
+///
+/// # Examples
+///
+/// ```ignore
+/// for decision in suite_lint_decisions() {
+///     decision.check().expect("suite decisions should match their fixtures");
+/// }
+/// ```
+#[must_use]
+pub fn suite_lint_decisions() -> Vec<Box<dyn ConformanceCheck>> {
+    vec![
+        Box::new(function_attrs_follow_docs::FunctionAttrsFollowDocsDecision),
+        Box::new(no_expect_outside_tests::NoExpectOutsideTestsDecision::default()),
+        Box::new(test_must_not_have_example::TestMustNotHaveExampleDecision::default()),
+        Box::new(module_must_have_inner_docs::ModuleMustHaveInnerDocsDecision),
+        Box::new(conditional_max_n_branches::ConditionalMaxNBranchesDecision::default()),
+        Box::new(module_max_lines::ModuleMaxLinesDecision::default()),
+        Box::new(no_unwrap_or_else_panic::NoUnwrapOrElsePanicDecision::default()),
+        Box::new(no_std_fs_operations::NoStdFsOperationsDecision::default()),
+        Box::new(bumpy_road_function::BumpyRoadFunctionDecision::default()),
+        Box::new(no_static_mut::NoStaticMutDecision),
+        Box::new(prefer_named_module_files::PreferNamedModuleFilesDecision),
+        Box::new(method_chain_max_length::MethodChainMaxLengthDecision::default()),
+        Box::new(closure_max_lines::ClosureMaxLinesDecision::default()),
+        Box::new(no_shadowing::NoShadowingDecision::default()),
+        Box::new(commented_out_code::CommentedOutCodeDecision),
+        Box::new(uninformative_panic_message::UninformativePanicMessageDecision::default()),
+        Box::new(tls_verification_disabled::TlsVerificationDisabledDecision::default()),
+        #[cfg(feature = "experimental-feature-envy")]
+        Box::new(feature_envy::FeatureEnvyDecision::default()),
+        #[cfg(feature = "experimental-data-clumps")]
+        Box::new(data_clumps::DataClumpsDecision::default()),
+        #[cfg(feature = "experimental-duplicated-blocks")]
+        Box::new(duplicated_blocks::DuplicatedBlocksDecision::default()),
+        #[cfg(feature = "experimental-no-recursion")]
+        Box::new(no_recursion::NoRecursionDecision::default()),
+        #[cfg(feature = "experimental-constructor-max-arguments")]
+        Box::new(constructor_max_arguments::ConstructorMaxArgumentsDecision::default()),
+        #[cfg(feature = "experimental-no-global-mutable-state")]
+        Box::new(no_global_mutable_state::NoGlobalMutableStateDecision::default()),
+        #[cfg(feature = "experimental-no-block-on-in-async")]
+        Box::new(no_block_on_in_async::NoBlockOnInAsyncDecision::default()),
+        #[cfg(feature = "experimental-no-detached-threads")]
+        Box::new(no_detached_threads::NoDetachedThreadsDecision::default()),
+        #[cfg(feature = "experimental-primitive-obsession")]
+        Box::new(primitive_obsession::PrimitiveObsessionDecision::default()),
+        #[cfg(feature = "experimental-no-indexing-outside-tests")]
+        Box::new(no_indexing_outside_tests::NoIndexingOutsideTestsDecision),
+        #[cfg(feature = "experimental-no-float-equality")]
+        Box::new(no_float_equality::NoFloatEqualityDecision::default()),
+        #[cfg(feature = "experimental-no-lossy-casts")]
+        Box::new(no_lossy_casts::NoLossyCastsDecision),
+        #[cfg(feature = "experimental-error-types-implement-error")]
+        Box::new(error_types_implement_error::ErrorTypesImplementErrorDecision),
+        #[cfg(feature = "experimental-large-stack-values")]
+        Box::new(large_stack_values::LargeStackValuesDecision::default()),
+        #[cfg(feature = "experimental-allow-must-have-reason")]
+        Box::new(allow_must_have_reason::AllowMustHaveReasonDecision::default()),
+        #[cfg(feature = "experimental-function-max-return-points")]
+        Box::new(function_max_return_points::FunctionMaxReturnPointsDecision::default()),
+        #[cfg(feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl")]
+        Box::new(async_fn_returning_boxed_future_prefer_async_trait_or_impl::AsyncFnReturningBoxedFuturePreferAsyncTraitOrImplDecision::default()),
+        #[cfg(feature = "experimental-iterator-returning-fn-should-not-collect-internally")]
+        Box::new(iterator_returning_fn_should_not_collect_internally::IteratorReturningFnShouldNotCollectInternallyDecision),
+        #[cfg(feature = "experimental-no-else-after-return")]
+        Box::new(no_else_after_return::NoElseAfterReturnDecision),
+        #[cfg(feature = "experimental-arc-clone-prefer-explicit")]
+        Box::new(arc_clone_prefer_explicit::ArcClonePreferExplicitDecision),
+        #[cfg(feature = "experimental-no-single-letter-identifiers")]
+        Box::new(no_single_letter_identifiers::NoSingleLetterIdentifiersDecision::default()),
+        #[cfg(feature = "experimental-module-must-have-tests")]
+        Box::new(module_must_have_tests::ModuleMustHaveTestsDecision::default()),
+        #[cfg(feature = "experimental-nested-result-option-in-signatures")]
+        Box::new(nested_result_option_in_signatures::NestedResultOptionInSignaturesDecision::default()),
+        #[cfg(feature = "experimental-no-lock-unwrap")]
+        Box::new(no_lock_unwrap::NoLockUnwrapDecision),
+        #[cfg(feature = "experimental-impl-trait-for-reference-smell")]
+        Box::new(impl_trait_for_reference_smell::ImplTraitForReferenceSmellDecision),
+        #[cfg(feature = "experimental-no-unchecked-arithmetic")]
+        // The lint is inert until `modules` names a module to check, so the
+        // registry configures one.
+        Box::new(no_unchecked_arithmetic::NoUncheckedArithmeticDecision::new(&["ledger".to_owned()])),
+        #[cfg(feature = "experimental-module-named-after-parent")]
+        Box::new(module_named_after_parent::ModuleNamedAfterParentDecision::default()),
+        #[cfg(feature = "experimental-doc-ignore-must-have-reason")]
+        Box::new(doc_ignore_must_have_reason::DocIgnoreMustHaveReasonDecision),
+        #[cfg(feature = "experimental-test-file-in-src-should-use-path-attribute-pattern")]
+        Box::new(test_file_in_src_should_use_path_attribute_pattern::TestFileInSrcShouldUsePathAttributePatternDecision::default()),
+        #[cfg(feature = "experimental-no-glob-reexports")]
+        Box::new(no_glob_reexports::NoGlobReexportsDecision::default()),
+        #[cfg(feature = "experimental-lint-crate-missing-stub-for-non-driver-builds")]
+        Box::new(lint_crate_missing_stub_for_non_driver_builds::LintCrateMissingStubForNonDriverBuildsDecision::default()),
+        #[cfg(feature = "experimental-no-wildcard-match-on-local-enums")]
+        Box::new(no_wildcard_match_on_local_enums::NoWildcardMatchOnLocalEnumsDecision::default()),
+        #[cfg(feature = "experimental-feature-gated-pub-use-must-have-cfg-doc")]
+        Box::new(feature_gated_pub_use_must_have_cfg_doc::FeatureGatedPubUseMustHaveCfgDocDecision::default()),
+        #[cfg(feature = "experimental-no-include-str-of-large-files")]
+        Box::new(no_include_str_of_large_files::NoIncludeStrOfLargeFilesDecision::default()),
+        #[cfg(feature = "experimental-pattern-match-on-boolean")]
+        Box::new(pattern_match_on_boolean::PatternMatchOnBooleanDecision),
+        #[cfg(feature = "experimental-redundant-else-block-after-loop-control")]
+        Box::new(redundant_else_block_after_loop_control::RedundantElseBlockAfterLoopControlDecision),
+        #[cfg(feature = "experimental-chained-unwrap-or-default-hides-errors")]
+        Box::new(chained_unwrap_or_default_hides_errors::ChainedUnwrapOrDefaultHidesErrorsDecision),
+        #[cfg(feature = "experimental-config-struct-fields-must-have-defaults-documented")]
+        Box::new(config_struct_fields_must_have_defaults_documented::ConfigStructFieldsMustHaveDefaultsDocumentedDecision::default()),
+        #[cfg(feature = "experimental-public-trait-missing-sealed-pattern-marker")]
+        Box::new(public_trait_missing_sealed_pattern_marker::PublicTraitMissingSealedPatternMarkerDecision::default()),
+        #[cfg(feature = "experimental-generic-function-monomorphisation-bloat")]
+        Box::new(generic_function_monomorphisation_bloat::GenericFunctionMonomorphisationBloatDecision::default()),
+        #[cfg(feature = "experimental-await-in-loop-without-concurrency")]
+        Box::new(await_in_loop_without_concurrency::AwaitInLoopWithoutConcurrencyDecision),
+        #[cfg(feature = "experimental-spawned-task-missing-cancellation-handling")]
+        Box::new(spawned_task_missing_cancellation_handling::SpawnedTaskMissingCancellationHandlingDecision::default()),
+        #[cfg(feature = "experimental-metrics-or-log-in-tight-loop")]
+        Box::new(metrics_or_log_in_tight_loop::MetricsOrLogInTightLoopDecision::default()),
+        #[cfg(feature = "experimental-vec-of-boxed-small-types")]
+        Box::new(vec_of_boxed_small_types::VecOfBoxedSmallTypesDecision::default()),
+        #[cfg(feature = "experimental-repeated-env-lookup")]
+        Box::new(repeated_env_lookup::RepeatedEnvLookupDecision::default()),
+        #[cfg(feature = "experimental-error-context-missing-on-io-operations")]
+        Box::new(error_context_missing_on_io_operations::ErrorContextMissingOnIoOperationsDecision::default()),
+        #[cfg(feature = "experimental-path-join-with-absolute-component")]
+        Box::new(path_join_with_absolute_component::PathJoinWithAbsoluteComponentDecision::default()),
+        #[cfg(feature = "experimental-archive-extraction-must-sanitise-paths")]
+        Box::new(archive_extraction_must_sanitise_paths::ArchiveExtractionMustSanitisePathsDecision),
+        #[cfg(feature = "experimental-subprocess-inherits-full-env")]
+        // The lint is inert until `modules` names a sensitive module, so the
+        // registry configures one.
+        Box::new(subprocess_inherits_full_env::SubprocessInheritsFullEnvDecision::new(&["deploy".to_owned()])),
+        #[cfg(feature = "experimental-deserialised-input-used-as-path-without-validation")]
+        Box::new(deserialised_input_used_as_path_without_validation::DeserialisedInputUsedAsPathWithoutValidationDecision),
+    ]
+}
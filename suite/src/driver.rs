@@ -1,51 +1,384 @@
 //! Combined lint wiring for the suite cdylib.
 
-use crate::lints::SUITE_LINT_DECLS;
+use crate::lints::{SUITE_LINT_DECLS, SUITE_LINT_DOCS};
+use crate::summary::FindingSummary;
 use dylint_linting::dylint_library;
-use rustc_lint::{Lint, LintStore, LintVec, declare_combined_late_lint_pass};
+use rustc_lint::{LateLintPass, Lint, LintStore, LintVec, declare_combined_late_lint_pass};
 use rustc_session::Session;
+use whitaker_common::lint_docs::LintDocs;
 
 // Import constituent lint pass types required by `late_lint_methods!`.
+#[cfg(feature = "experimental-allow-must-have-reason")]
+use allow_must_have_reason::AllowMustHaveReason;
+#[cfg(feature = "experimental-arc-clone-prefer-explicit")]
+use arc_clone_prefer_explicit::ArcClonePreferExplicit;
+#[cfg(feature = "experimental-archive-extraction-must-sanitise-paths")]
+use archive_extraction_must_sanitise_paths::ArchiveExtractionMustSanitisePaths;
+#[cfg(feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl")]
+use async_fn_returning_boxed_future_prefer_async_trait_or_impl::AsyncFnReturningBoxedFuturePreferAsyncTraitOrImpl;
+#[cfg(feature = "experimental-await-in-loop-without-concurrency")]
+use await_in_loop_without_concurrency::AwaitInLoopWithoutConcurrency;
 use bumpy_road_function::BumpyRoadFunction;
+#[cfg(feature = "experimental-chained-unwrap-or-default-hides-errors")]
+use chained_unwrap_or_default_hides_errors::ChainedUnwrapOrDefaultHidesErrors;
+use closure_max_lines::ClosureMaxLines;
+use commented_out_code::CommentedOutCode;
 use conditional_max_n_branches::ConditionalMaxNBranches;
+#[cfg(feature = "experimental-config-struct-fields-must-have-defaults-documented")]
+use config_struct_fields_must_have_defaults_documented::ConfigStructFieldsMustHaveDefaultsDocumented;
+#[cfg(feature = "experimental-constructor-max-arguments")]
+use constructor_max_arguments::ConstructorMaxArguments;
+#[cfg(feature = "experimental-data-clumps")]
+use data_clumps::DataClumps;
+#[cfg(feature = "experimental-deserialised-input-used-as-path-without-validation")]
+use deserialised_input_used_as_path_without_validation::DeserialisedInputUsedAsPathWithoutValidation;
+#[cfg(feature = "experimental-doc-ignore-must-have-reason")]
+use doc_ignore_must_have_reason::DocIgnoreMustHaveReason;
+#[cfg(feature = "experimental-duplicated-blocks")]
+use duplicated_blocks::DuplicatedBlocks;
+#[cfg(feature = "experimental-error-context-missing-on-io-operations")]
+use error_context_missing_on_io_operations::ErrorContextMissingOnIoOperations;
+#[cfg(feature = "experimental-error-types-implement-error")]
+use error_types_implement_error::ErrorTypesImplementError;
+#[cfg(feature = "experimental-feature-envy")]
+use feature_envy::FeatureEnvy;
+#[cfg(feature = "experimental-feature-gated-pub-use-must-have-cfg-doc")]
+use feature_gated_pub_use_must_have_cfg_doc::FeatureGatedPubUseMustHaveCfgDoc;
 use function_attrs_follow_docs::FunctionAttrsFollowDocs;
+#[cfg(feature = "experimental-function-max-return-points")]
+use function_max_return_points::FunctionMaxReturnPoints;
+#[cfg(feature = "experimental-generic-function-monomorphisation-bloat")]
+use generic_function_monomorphisation_bloat::GenericFunctionMonomorphisationBloat;
+#[cfg(feature = "experimental-impl-trait-for-reference-smell")]
+use impl_trait_for_reference_smell::ImplTraitForReferenceSmell;
+#[cfg(feature = "experimental-iterator-returning-fn-should-not-collect-internally")]
+use iterator_returning_fn_should_not_collect_internally::IteratorReturningFnShouldNotCollectInternally;
+#[cfg(feature = "experimental-large-stack-values")]
+use large_stack_values::LargeStackValues;
+#[cfg(feature = "experimental-lint-crate-missing-stub-for-non-driver-builds")]
+use lint_crate_missing_stub_for_non_driver_builds::LintCrateMissingStubForNonDriverBuilds;
+use method_chain_max_length::MethodChainMaxLength;
+#[cfg(feature = "experimental-metrics-or-log-in-tight-loop")]
+use metrics_or_log_in_tight_loop::MetricsOrLogInTightLoop;
 use module_max_lines::ModuleMaxLines;
 use module_must_have_inner_docs::ModuleMustHaveInnerDocs;
+#[cfg(feature = "experimental-module-must-have-tests")]
+use module_must_have_tests::ModuleMustHaveTests;
+#[cfg(feature = "experimental-module-named-after-parent")]
+use module_named_after_parent::ModuleNamedAfterParent;
+#[cfg(feature = "experimental-nested-result-option-in-signatures")]
+use nested_result_option_in_signatures::NestedResultOptionInSignatures;
+#[cfg(feature = "experimental-no-block-on-in-async")]
+use no_block_on_in_async::NoBlockOnInAsync;
+#[cfg(feature = "experimental-no-detached-threads")]
+use no_detached_threads::NoDetachedThreads;
+#[cfg(feature = "experimental-no-else-after-return")]
+use no_else_after_return::NoElseAfterReturn;
 use no_expect_outside_tests::NoExpectOutsideTests;
+#[cfg(feature = "experimental-no-float-equality")]
+use no_float_equality::NoFloatEquality;
+#[cfg(feature = "experimental-no-glob-reexports")]
+use no_glob_reexports::NoGlobReexports;
+#[cfg(feature = "experimental-no-global-mutable-state")]
+use no_global_mutable_state::NoGlobalMutableState;
+#[cfg(feature = "experimental-no-include-str-of-large-files")]
+use no_include_str_of_large_files::NoIncludeStrOfLargeFiles;
+#[cfg(feature = "experimental-no-indexing-outside-tests")]
+use no_indexing_outside_tests::NoIndexingOutsideTests;
+#[cfg(feature = "experimental-no-lock-unwrap")]
+use no_lock_unwrap::NoLockUnwrap;
+#[cfg(feature = "experimental-no-lossy-casts")]
+use no_lossy_casts::NoLossyCasts;
+#[cfg(feature = "experimental-no-recursion")]
+use no_recursion::NoRecursion;
+use no_shadowing::NoShadowing;
+#[cfg(feature = "experimental-no-single-letter-identifiers")]
+use no_single_letter_identifiers::NoSingleLetterIdentifiers;
+use no_static_mut::NoStaticMut;
 use no_std_fs_operations::NoStdFsOperations;
+#[cfg(feature = "experimental-no-unchecked-arithmetic")]
+use no_unchecked_arithmetic::NoUncheckedArithmetic;
 use no_unwrap_or_else_panic::NoUnwrapOrElsePanic;
+#[cfg(feature = "experimental-no-wildcard-match-on-local-enums")]
+use no_wildcard_match_on_local_enums::NoWildcardMatchOnLocalEnums;
+#[cfg(feature = "experimental-path-join-with-absolute-component")]
+use path_join_with_absolute_component::PathJoinWithAbsoluteComponent;
+#[cfg(feature = "experimental-pattern-match-on-boolean")]
+use pattern_match_on_boolean::PatternMatchOnBoolean;
+use prefer_named_module_files::PreferNamedModuleFiles;
+#[cfg(feature = "experimental-primitive-obsession")]
+use primitive_obsession::PrimitiveObsession;
+#[cfg(feature = "experimental-public-trait-missing-sealed-pattern-marker")]
+use public_trait_missing_sealed_pattern_marker::PublicTraitMissingSealedPatternMarker;
+#[cfg(feature = "experimental-redundant-else-block-after-loop-control")]
+use redundant_else_block_after_loop_control::RedundantElseBlockAfterLoopControl;
+#[cfg(feature = "experimental-repeated-env-lookup")]
+use repeated_env_lookup::RepeatedEnvLookup;
 #[cfg(feature = "experimental-rstest-helper-should-be-fixture")]
 use rstest_helper_should_be_fixture::RstestHelperShouldBeFixture;
+#[cfg(feature = "experimental-spawned-task-missing-cancellation-handling")]
+use spawned_task_missing_cancellation_handling::SpawnedTaskMissingCancellationHandling;
+#[cfg(feature = "experimental-subprocess-inherits-full-env")]
+use subprocess_inherits_full_env::SubprocessInheritsFullEnv;
+#[cfg(feature = "experimental-test-file-in-src-should-use-path-attribute-pattern")]
+use test_file_in_src_should_use_path_attribute_pattern::TestFileInSrcShouldUsePathAttributePattern;
 use test_must_not_have_example::TestMustNotHaveExample;
+use tls_verification_disabled::TlsVerificationDisabled;
+use uninformative_panic_message::UninformativePanicMessage;
+#[cfg(feature = "experimental-vec-of-boxed-small-types")]
+use vec_of_boxed_small_types::VecOfBoxedSmallTypes;
 
 dylint_library!();
 
-macro_rules! define_suite_pass {
-    ($($experimental_pass:tt)*) => {
-        rustc_lint::late_lint_methods!(
-            declare_combined_late_lint_pass,
-            [SuitePass, [
-                FunctionAttrsFollowDocs: function_attrs_follow_docs::FunctionAttrsFollowDocs::default(),
-                NoExpectOutsideTests: no_expect_outside_tests::NoExpectOutsideTests::default(),
-                TestMustNotHaveExample: test_must_not_have_example::TestMustNotHaveExample::default(),
-                ModuleMustHaveInnerDocs: module_must_have_inner_docs::ModuleMustHaveInnerDocs::default(),
-                ConditionalMaxNBranches: conditional_max_n_branches::ConditionalMaxNBranches::default(),
-                ModuleMaxLines: module_max_lines::ModuleMaxLines::default(),
-                NoUnwrapOrElsePanic: no_unwrap_or_else_panic::NoUnwrapOrElsePanic::default(),
-                NoStdFsOperations: no_std_fs_operations::NoStdFsOperations::default(),
-                BumpyRoadFunction: bumpy_road_function::BumpyRoadFunction::default(),
-                $($experimental_pass)*
-            ]]
-        );
-    };
-}
+/// Stand-in for an experimental pass whose suite feature is disabled.
+///
+/// Each experimental lint is aliased to this type when its feature is off, so
+/// the combined pass lists every lint once regardless of which experimental
+/// features are enabled.
+#[cfg_attr(
+    all(
+        feature = "experimental-rstest-helper-should-be-fixture",
+        feature = "experimental-feature-envy",
+        feature = "experimental-data-clumps",
+        feature = "experimental-duplicated-blocks",
+        feature = "experimental-no-recursion",
+        feature = "experimental-constructor-max-arguments",
+        feature = "experimental-no-global-mutable-state",
+        feature = "experimental-no-block-on-in-async",
+        feature = "experimental-no-detached-threads",
+        feature = "experimental-primitive-obsession",
+        feature = "experimental-no-indexing-outside-tests",
+        feature = "experimental-no-float-equality",
+        feature = "experimental-no-lossy-casts",
+        feature = "experimental-error-types-implement-error",
+        feature = "experimental-large-stack-values",
+        feature = "experimental-allow-must-have-reason",
+        feature = "experimental-function-max-return-points",
+        feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl",
+        feature = "experimental-iterator-returning-fn-should-not-collect-internally",
+        feature = "experimental-no-else-after-return",
+        feature = "experimental-arc-clone-prefer-explicit",
+        feature = "experimental-no-single-letter-identifiers",
+        feature = "experimental-module-must-have-tests",
+        feature = "experimental-nested-result-option-in-signatures",
+        feature = "experimental-no-lock-unwrap",
+        feature = "experimental-impl-trait-for-reference-smell",
+        feature = "experimental-no-unchecked-arithmetic",
+        feature = "experimental-module-named-after-parent",
+        feature = "experimental-doc-ignore-must-have-reason",
+        feature = "experimental-test-file-in-src-should-use-path-attribute-pattern",
+        feature = "experimental-no-glob-reexports",
+        feature = "experimental-lint-crate-missing-stub-for-non-driver-builds",
+        feature = "experimental-no-wildcard-match-on-local-enums",
+        feature = "experimental-feature-gated-pub-use-must-have-cfg-doc",
+        feature = "experimental-no-include-str-of-large-files",
+        feature = "experimental-pattern-match-on-boolean",
+        feature = "experimental-redundant-else-block-after-loop-control",
+        feature = "experimental-chained-unwrap-or-default-hides-errors",
+        feature = "experimental-config-struct-fields-must-have-defaults-documented",
+        feature = "experimental-public-trait-missing-sealed-pattern-marker",
+        feature = "experimental-generic-function-monomorphisation-bloat",
+        feature = "experimental-await-in-loop-without-concurrency",
+        feature = "experimental-spawned-task-missing-cancellation-handling",
+        feature = "experimental-metrics-or-log-in-tight-loop",
+        feature = "experimental-vec-of-boxed-small-types",
+        feature = "experimental-repeated-env-lookup",
+        feature = "experimental-error-context-missing-on-io-operations",
+        feature = "experimental-path-join-with-absolute-component",
+        feature = "experimental-archive-extraction-must-sanitise-paths",
+        feature = "experimental-subprocess-inherits-full-env",
+        feature = "experimental-deserialised-input-used-as-path-without-validation"
+    ),
+    expect(dead_code, reason = "every experimental feature is enabled")
+)]
+#[derive(Clone, Copy, Debug, Default)]
+struct DisabledPass;
+
+rustc_session::impl_lint_pass!(DisabledPass => []);
+
+impl LateLintPass<'_> for DisabledPass {}
 
 #[cfg(not(feature = "experimental-rstest-helper-should-be-fixture"))]
-define_suite_pass!();
+type RstestHelperShouldBeFixture = DisabledPass;
+#[cfg(not(feature = "experimental-feature-envy"))]
+type FeatureEnvy = DisabledPass;
+#[cfg(not(feature = "experimental-data-clumps"))]
+type DataClumps = DisabledPass;
+#[cfg(not(feature = "experimental-duplicated-blocks"))]
+type DuplicatedBlocks = DisabledPass;
+#[cfg(not(feature = "experimental-no-recursion"))]
+type NoRecursion = DisabledPass;
+#[cfg(not(feature = "experimental-constructor-max-arguments"))]
+type ConstructorMaxArguments = DisabledPass;
+#[cfg(not(feature = "experimental-no-global-mutable-state"))]
+type NoGlobalMutableState = DisabledPass;
+#[cfg(not(feature = "experimental-no-block-on-in-async"))]
+type NoBlockOnInAsync = DisabledPass;
+#[cfg(not(feature = "experimental-no-detached-threads"))]
+type NoDetachedThreads = DisabledPass;
+#[cfg(not(feature = "experimental-primitive-obsession"))]
+type PrimitiveObsession = DisabledPass;
+#[cfg(not(feature = "experimental-no-indexing-outside-tests"))]
+type NoIndexingOutsideTests = DisabledPass;
+#[cfg(not(feature = "experimental-no-float-equality"))]
+type NoFloatEquality = DisabledPass;
+#[cfg(not(feature = "experimental-no-lossy-casts"))]
+type NoLossyCasts = DisabledPass;
+#[cfg(not(feature = "experimental-error-types-implement-error"))]
+type ErrorTypesImplementError = DisabledPass;
+#[cfg(not(feature = "experimental-large-stack-values"))]
+type LargeStackValues = DisabledPass;
+#[cfg(not(feature = "experimental-allow-must-have-reason"))]
+type AllowMustHaveReason = DisabledPass;
+#[cfg(not(feature = "experimental-function-max-return-points"))]
+type FunctionMaxReturnPoints = DisabledPass;
+#[cfg(not(feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl"))]
+type AsyncFnReturningBoxedFuturePreferAsyncTraitOrImpl = DisabledPass;
+#[cfg(not(feature = "experimental-iterator-returning-fn-should-not-collect-internally"))]
+type IteratorReturningFnShouldNotCollectInternally = DisabledPass;
+#[cfg(not(feature = "experimental-no-else-after-return"))]
+type NoElseAfterReturn = DisabledPass;
+#[cfg(not(feature = "experimental-arc-clone-prefer-explicit"))]
+type ArcClonePreferExplicit = DisabledPass;
+#[cfg(not(feature = "experimental-no-single-letter-identifiers"))]
+type NoSingleLetterIdentifiers = DisabledPass;
+#[cfg(not(feature = "experimental-module-must-have-tests"))]
+type ModuleMustHaveTests = DisabledPass;
+#[cfg(not(feature = "experimental-nested-result-option-in-signatures"))]
+type NestedResultOptionInSignatures = DisabledPass;
+#[cfg(not(feature = "experimental-no-lock-unwrap"))]
+type NoLockUnwrap = DisabledPass;
+#[cfg(not(feature = "experimental-impl-trait-for-reference-smell"))]
+type ImplTraitForReferenceSmell = DisabledPass;
+#[cfg(not(feature = "experimental-no-unchecked-arithmetic"))]
+type NoUncheckedArithmetic = DisabledPass;
+#[cfg(not(feature = "experimental-module-named-after-parent"))]
+type ModuleNamedAfterParent = DisabledPass;
+#[cfg(not(feature = "experimental-doc-ignore-must-have-reason"))]
+type DocIgnoreMustHaveReason = DisabledPass;
+#[cfg(not(feature = "experimental-test-file-in-src-should-use-path-attribute-pattern"))]
+type TestFileInSrcShouldUsePathAttributePattern = DisabledPass;
+#[cfg(not(feature = "experimental-no-glob-reexports"))]
+type NoGlobReexports = DisabledPass;
+#[cfg(not(feature = "experimental-lint-crate-missing-stub-for-non-driver-builds"))]
+type LintCrateMissingStubForNonDriverBuilds = DisabledPass;
+#[cfg(not(feature = "experimental-no-wildcard-match-on-local-enums"))]
+type NoWildcardMatchOnLocalEnums = DisabledPass;
+#[cfg(not(feature = "experimental-feature-gated-pub-use-must-have-cfg-doc"))]
+type FeatureGatedPubUseMustHaveCfgDoc = DisabledPass;
+#[cfg(not(feature = "experimental-no-include-str-of-large-files"))]
+type NoIncludeStrOfLargeFiles = DisabledPass;
+#[cfg(not(feature = "experimental-pattern-match-on-boolean"))]
+type PatternMatchOnBoolean = DisabledPass;
+#[cfg(not(feature = "experimental-redundant-else-block-after-loop-control"))]
+type RedundantElseBlockAfterLoopControl = DisabledPass;
+#[cfg(not(feature = "experimental-chained-unwrap-or-default-hides-errors"))]
+type ChainedUnwrapOrDefaultHidesErrors = DisabledPass;
+#[cfg(not(feature = "experimental-config-struct-fields-must-have-defaults-documented"))]
+type ConfigStructFieldsMustHaveDefaultsDocumented = DisabledPass;
+#[cfg(not(feature = "experimental-public-trait-missing-sealed-pattern-marker"))]
+type PublicTraitMissingSealedPatternMarker = DisabledPass;
+#[cfg(not(feature = "experimental-generic-function-monomorphisation-bloat"))]
+type GenericFunctionMonomorphisationBloat = DisabledPass;
+#[cfg(not(feature = "experimental-await-in-loop-without-concurrency"))]
+type AwaitInLoopWithoutConcurrency = DisabledPass;
+#[cfg(not(feature = "experimental-spawned-task-missing-cancellation-handling"))]
+type SpawnedTaskMissingCancellationHandling = DisabledPass;
+#[cfg(not(feature = "experimental-metrics-or-log-in-tight-loop"))]
+type MetricsOrLogInTightLoop = DisabledPass;
+#[cfg(not(feature = "experimental-vec-of-boxed-small-types"))]
+type VecOfBoxedSmallTypes = DisabledPass;
+#[cfg(not(feature = "experimental-repeated-env-lookup"))]
+type RepeatedEnvLookup = DisabledPass;
+#[cfg(not(feature = "experimental-error-context-missing-on-io-operations"))]
+type ErrorContextMissingOnIoOperations = DisabledPass;
+#[cfg(not(feature = "experimental-path-join-with-absolute-component"))]
+type PathJoinWithAbsoluteComponent = DisabledPass;
+#[cfg(not(feature = "experimental-archive-extraction-must-sanitise-paths"))]
+type ArchiveExtractionMustSanitisePaths = DisabledPass;
+#[cfg(not(feature = "experimental-subprocess-inherits-full-env"))]
+type SubprocessInheritsFullEnv = DisabledPass;
+#[cfg(not(feature = "experimental-deserialised-input-used-as-path-without-validation"))]
+type DeserialisedInputUsedAsPathWithoutValidation = DisabledPass;
 
-#[cfg(feature = "experimental-rstest-helper-should-be-fixture")]
-define_suite_pass!(
-    RstestHelperShouldBeFixture: rstest_helper_should_be_fixture::RstestHelperShouldBeFixture::default(),
+// The combined pass is the suite's single traversal. rustc walks the crate's
+// HIR once and calls each constituent's `check_expr`, `check_item`, and other
+// hooks at every node, so expression lints share one walk rather than each
+// visiting every expression again. `FindingSummary` stays last: it reads the
+// totals the constituents record in their `check_crate_post`.
+rustc_lint::late_lint_methods!(
+    declare_combined_late_lint_pass,
+    [SuitePass, [
+        FunctionAttrsFollowDocs: FunctionAttrsFollowDocs::default(),
+        NoExpectOutsideTests: NoExpectOutsideTests::default(),
+        TestMustNotHaveExample: TestMustNotHaveExample::default(),
+        ModuleMustHaveInnerDocs: ModuleMustHaveInnerDocs::default(),
+        ConditionalMaxNBranches: ConditionalMaxNBranches::default(),
+        ModuleMaxLines: ModuleMaxLines::default(),
+        NoUnwrapOrElsePanic: NoUnwrapOrElsePanic::default(),
+        NoStdFsOperations: NoStdFsOperations::default(),
+        BumpyRoadFunction: BumpyRoadFunction::default(),
+        NoStaticMut: NoStaticMut::default(),
+        PreferNamedModuleFiles: PreferNamedModuleFiles::default(),
+        MethodChainMaxLength: MethodChainMaxLength::default(),
+        ClosureMaxLines: ClosureMaxLines::default(),
+        NoShadowing: NoShadowing::default(),
+        CommentedOutCode: CommentedOutCode::default(),
+        UninformativePanicMessage: UninformativePanicMessage::default(),
+        TlsVerificationDisabled: TlsVerificationDisabled::default(),
+        RstestHelperShouldBeFixture: RstestHelperShouldBeFixture::default(),
+        FeatureEnvy: FeatureEnvy::default(),
+        DataClumps: DataClumps::default(),
+        DuplicatedBlocks: DuplicatedBlocks::default(),
+        NoRecursion: NoRecursion::default(),
+        ConstructorMaxArguments: ConstructorMaxArguments::default(),
+        NoGlobalMutableState: NoGlobalMutableState::default(),
+        NoBlockOnInAsync: NoBlockOnInAsync::default(),
+        NoDetachedThreads: NoDetachedThreads::default(),
+        PrimitiveObsession: PrimitiveObsession::default(),
+        NoIndexingOutsideTests: NoIndexingOutsideTests::default(),
+        NoFloatEquality: NoFloatEquality::default(),
+        NoLossyCasts: NoLossyCasts::default(),
+        ErrorTypesImplementError: ErrorTypesImplementError::default(),
+        LargeStackValues: LargeStackValues::default(),
+        AllowMustHaveReason: AllowMustHaveReason::default(),
+        FunctionMaxReturnPoints: FunctionMaxReturnPoints::default(),
+        AsyncFnReturningBoxedFuturePreferAsyncTraitOrImpl: AsyncFnReturningBoxedFuturePreferAsyncTraitOrImpl::default(),
+        IteratorReturningFnShouldNotCollectInternally: IteratorReturningFnShouldNotCollectInternally::default(),
+        NoElseAfterReturn: NoElseAfterReturn::default(),
+        ArcClonePreferExplicit: ArcClonePreferExplicit::default(),
+        NoSingleLetterIdentifiers: NoSingleLetterIdentifiers::default(),
+        ModuleMustHaveTests: ModuleMustHaveTests::default(),
+        NestedResultOptionInSignatures: NestedResultOptionInSignatures::default(),
+        NoLockUnwrap: NoLockUnwrap::default(),
+        ImplTraitForReferenceSmell: ImplTraitForReferenceSmell::default(),
+        NoUncheckedArithmetic: NoUncheckedArithmetic::default(),
+        ModuleNamedAfterParent: ModuleNamedAfterParent::default(),
+        DocIgnoreMustHaveReason: DocIgnoreMustHaveReason::default(),
+        TestFileInSrcShouldUsePathAttributePattern: TestFileInSrcShouldUsePathAttributePattern::default(),
+        NoGlobReexports: NoGlobReexports::default(),
+        LintCrateMissingStubForNonDriverBuilds: LintCrateMissingStubForNonDriverBuilds::default(),
+        NoWildcardMatchOnLocalEnums: NoWildcardMatchOnLocalEnums::default(),
+        FeatureGatedPubUseMustHaveCfgDoc: FeatureGatedPubUseMustHaveCfgDoc::default(),
+        NoIncludeStrOfLargeFiles: NoIncludeStrOfLargeFiles::default(),
+        PatternMatchOnBoolean: PatternMatchOnBoolean::default(),
+        RedundantElseBlockAfterLoopControl: RedundantElseBlockAfterLoopControl::default(),
+        ChainedUnwrapOrDefaultHidesErrors: ChainedUnwrapOrDefaultHidesErrors::default(),
+        ConfigStructFieldsMustHaveDefaultsDocumented: ConfigStructFieldsMustHaveDefaultsDocumented::default(),
+        PublicTraitMissingSealedPatternMarker: PublicTraitMissingSealedPatternMarker::default(),
+        GenericFunctionMonomorphisationBloat: GenericFunctionMonomorphisationBloat::default(),
+        AwaitInLoopWithoutConcurrency: AwaitInLoopWithoutConcurrency::default(),
+        SpawnedTaskMissingCancellationHandling: SpawnedTaskMissingCancellationHandling::default(),
+        MetricsOrLogInTightLoop: MetricsOrLogInTightLoop::default(),
+        VecOfBoxedSmallTypes: VecOfBoxedSmallTypes::default(),
+        RepeatedEnvLookup: RepeatedEnvLookup::default(),
+        ErrorContextMissingOnIoOperations: ErrorContextMissingOnIoOperations::default(),
+        PathJoinWithAbsoluteComponent: PathJoinWithAbsoluteComponent::default(),
+        ArchiveExtractionMustSanitisePaths: ArchiveExtractionMustSanitisePaths::default(),
+        SubprocessInheritsFullEnv: SubprocessInheritsFullEnv::default(),
+        DeserialisedInputUsedAsPathWithoutValidation: DeserialisedInputUsedAsPathWithoutValidation::default(),
+        FindingSummary: FindingSummary::default(),
+    ]]
 );
 
 /// Registers the suite lints into the provided lint store.
@@ -60,7 +393,7 @@ define_suite_pass!(
 /// # use whitaker_suite::register_suite_lints;
 /// let mut store = LintStore::new();
 /// register_suite_lints(&mut store);
-/// assert_eq!(store.get_lints().len(), 9);
+/// assert_eq!(store.get_lints().len(), 16);
 /// ```
 pub fn register_suite_lints(store: &mut LintStore) {
     store.register_lints(SUITE_LINT_DECLS);
@@ -84,6 +417,27 @@ pub fn suite_lint_decls() -> &'static [&'static Lint] {
     SUITE_LINT_DECLS
 }
 
+/// Returns the embedded documentation for the suite lint called `name`.
+///
+/// The HTML report and the installer's lint listing render this metadata,
+/// passed in as [`SUITE_LINT_DOCS`], so their guidance matches the lint
+/// version that produced a finding.
+///
+/// # Examples
+///
+/// ```ignore
+/// # use whitaker_suite::suite_lint_docs;
+/// let docs = suite_lint_docs("module_max_lines").expect("suite lint");
+/// assert_eq!(docs.config_key("max_lines").map(|key| key.default), Some("400"));
+/// ```
+#[must_use]
+pub fn suite_lint_docs(name: &str) -> Option<&'static LintDocs> {
+    SUITE_LINT_DOCS
+        .iter()
+        .copied()
+        .find(|docs| docs.name == name)
+}
+
 /// Dylint entrypoint that initializes configuration and registers lints.
 ///
 /// # Safety
@@ -10,10 +10,25 @@
 
 mod lints;
 
-pub use lints::{LintDescriptor, SUITE_LINTS, suite_lint_names};
+pub use lints::{
+    LintDescriptor, SUITE_LINT_SCHEMA_VERSION, SUITE_LINTS, SuiteLintSchema,
+    suite_lint_descriptors, suite_lint_names, suite_lint_schema,
+};
+
+#[cfg(feature = "dylint-driver")]
+pub use lints::SUITE_LINT_DOCS;
+
+#[cfg(feature = "dylint-driver")]
+mod decisions;
+
+#[cfg(feature = "dylint-driver")]
+pub use decisions::suite_lint_decisions;
 
 #[cfg(feature = "dylint-driver")]
 mod driver;
 
 #[cfg(feature = "dylint-driver")]
-pub use driver::{register_suite_lints, suite_lint_decls};
+mod summary;
+
+#[cfg(feature = "dylint-driver")]
+pub use driver::{register_suite_lints, suite_lint_decls, suite_lint_docs};
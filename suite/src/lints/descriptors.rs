@@ -0,0 +1,403 @@
+//! Descriptors for every lint in this build of the suite.
+
+use super::LintDescriptor;
+
+/// Static list of the lints exposed by the Whitaker suite.
+///
+/// The order is part of the suite's contract: the stable lints come first in
+/// the order they were added, followed by the enabled experimental lints.
+/// New lints are appended to their group, and a lint's name is never reused
+/// for different behaviour.
+pub const SUITE_LINTS: &[LintDescriptor] = &[
+    LintDescriptor {
+        name: "function_attrs_follow_docs",
+        crate_name: "function_attrs_follow_docs",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "no_expect_outside_tests",
+        crate_name: "no_expect_outside_tests",
+        behaviour_version: "2.0.0",
+    },
+    LintDescriptor {
+        name: "test_must_not_have_example",
+        crate_name: "test_must_not_have_example",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "module_must_have_inner_docs",
+        crate_name: "module_must_have_inner_docs",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "conditional_max_n_branches",
+        crate_name: "conditional_max_n_branches",
+        behaviour_version: "2.0.0",
+    },
+    LintDescriptor {
+        name: "module_max_lines",
+        crate_name: "module_max_lines",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "no_unwrap_or_else_panic",
+        crate_name: "no_unwrap_or_else_panic",
+        behaviour_version: "2.0.0",
+    },
+    LintDescriptor {
+        name: "no_std_fs_operations",
+        crate_name: "no_std_fs_operations",
+        behaviour_version: "2.0.0",
+    },
+    LintDescriptor {
+        name: "bumpy_road_function",
+        crate_name: "bumpy_road_function",
+        behaviour_version: "2.1.0",
+    },
+    LintDescriptor {
+        name: "no_static_mut",
+        crate_name: "no_static_mut",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "prefer_named_module_files",
+        crate_name: "prefer_named_module_files",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "method_chain_max_length",
+        crate_name: "method_chain_max_length",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "closure_max_lines",
+        crate_name: "closure_max_lines",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "no_shadowing",
+        crate_name: "no_shadowing",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "commented_out_code",
+        crate_name: "commented_out_code",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "uninformative_panic_message",
+        crate_name: "uninformative_panic_message",
+        behaviour_version: "1.0.0",
+    },
+    LintDescriptor {
+        name: "tls_verification_disabled",
+        crate_name: "tls_verification_disabled",
+        behaviour_version: "1.0.0",
+    },
+    #[cfg(feature = "experimental-rstest-helper-should-be-fixture")]
+    LintDescriptor {
+        name: "rstest_helper_should_be_fixture",
+        crate_name: "rstest_helper_should_be_fixture",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-feature-envy")]
+    LintDescriptor {
+        name: "feature_envy",
+        crate_name: "feature_envy",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-data-clumps")]
+    LintDescriptor {
+        name: "data_clumps",
+        crate_name: "data_clumps",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-duplicated-blocks")]
+    LintDescriptor {
+        name: "duplicated_blocks",
+        crate_name: "duplicated_blocks",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-recursion")]
+    LintDescriptor {
+        name: "no_recursion",
+        crate_name: "no_recursion",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-constructor-max-arguments")]
+    LintDescriptor {
+        name: "constructor_max_arguments",
+        crate_name: "constructor_max_arguments",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-global-mutable-state")]
+    LintDescriptor {
+        name: "no_global_mutable_state",
+        crate_name: "no_global_mutable_state",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-block-on-in-async")]
+    LintDescriptor {
+        name: "no_block_on_in_async",
+        crate_name: "no_block_on_in_async",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-detached-threads")]
+    LintDescriptor {
+        name: "no_detached_threads",
+        crate_name: "no_detached_threads",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-primitive-obsession")]
+    LintDescriptor {
+        name: "primitive_obsession",
+        crate_name: "primitive_obsession",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-indexing-outside-tests")]
+    LintDescriptor {
+        name: "no_indexing_outside_tests",
+        crate_name: "no_indexing_outside_tests",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-float-equality")]
+    LintDescriptor {
+        name: "no_float_equality",
+        crate_name: "no_float_equality",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-lossy-casts")]
+    LintDescriptor {
+        name: "no_lossy_casts",
+        crate_name: "no_lossy_casts",
+        behaviour_version: "0.2.0",
+    },
+    #[cfg(feature = "experimental-error-types-implement-error")]
+    LintDescriptor {
+        name: "error_types_implement_error",
+        crate_name: "error_types_implement_error",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-large-stack-values")]
+    LintDescriptor {
+        name: "large_stack_values",
+        crate_name: "large_stack_values",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-allow-must-have-reason")]
+    LintDescriptor {
+        name: "allow_must_have_reason",
+        crate_name: "allow_must_have_reason",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-function-max-return-points")]
+    LintDescriptor {
+        name: "function_max_return_points",
+        crate_name: "function_max_return_points",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl")]
+    LintDescriptor {
+        name: "async_fn_returning_boxed_future_prefer_async_trait_or_impl",
+        crate_name: "async_fn_returning_boxed_future_prefer_async_trait_or_impl",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-iterator-returning-fn-should-not-collect-internally")]
+    LintDescriptor {
+        name: "iterator_returning_fn_should_not_collect_internally",
+        crate_name: "iterator_returning_fn_should_not_collect_internally",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-else-after-return")]
+    LintDescriptor {
+        name: "no_else_after_return",
+        crate_name: "no_else_after_return",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-arc-clone-prefer-explicit")]
+    LintDescriptor {
+        name: "arc_clone_prefer_explicit",
+        crate_name: "arc_clone_prefer_explicit",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-single-letter-identifiers")]
+    LintDescriptor {
+        name: "no_single_letter_identifiers",
+        crate_name: "no_single_letter_identifiers",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-module-must-have-tests")]
+    LintDescriptor {
+        name: "module_must_have_tests",
+        crate_name: "module_must_have_tests",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-nested-result-option-in-signatures")]
+    LintDescriptor {
+        name: "nested_result_option_in_signatures",
+        crate_name: "nested_result_option_in_signatures",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-lock-unwrap")]
+    LintDescriptor {
+        name: "no_lock_unwrap",
+        crate_name: "no_lock_unwrap",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-impl-trait-for-reference-smell")]
+    LintDescriptor {
+        name: "impl_trait_for_reference_smell",
+        crate_name: "impl_trait_for_reference_smell",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-unchecked-arithmetic")]
+    LintDescriptor {
+        name: "no_unchecked_arithmetic",
+        crate_name: "no_unchecked_arithmetic",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-module-named-after-parent")]
+    LintDescriptor {
+        name: "module_named_after_parent",
+        crate_name: "module_named_after_parent",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-doc-ignore-must-have-reason")]
+    LintDescriptor {
+        name: "doc_ignore_must_have_reason",
+        crate_name: "doc_ignore_must_have_reason",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-test-file-in-src-should-use-path-attribute-pattern")]
+    LintDescriptor {
+        name: "test_file_in_src_should_use_path_attribute_pattern",
+        crate_name: "test_file_in_src_should_use_path_attribute_pattern",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-glob-reexports")]
+    LintDescriptor {
+        name: "no_glob_reexports",
+        crate_name: "no_glob_reexports",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-lint-crate-missing-stub-for-non-driver-builds")]
+    LintDescriptor {
+        name: "lint_crate_missing_stub_for_non_driver_builds",
+        crate_name: "lint_crate_missing_stub_for_non_driver_builds",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-wildcard-match-on-local-enums")]
+    LintDescriptor {
+        name: "no_wildcard_match_on_local_enums",
+        crate_name: "no_wildcard_match_on_local_enums",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-feature-gated-pub-use-must-have-cfg-doc")]
+    LintDescriptor {
+        name: "feature_gated_pub_use_must_have_cfg_doc",
+        crate_name: "feature_gated_pub_use_must_have_cfg_doc",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-no-include-str-of-large-files")]
+    LintDescriptor {
+        name: "no_include_str_of_large_files",
+        crate_name: "no_include_str_of_large_files",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-pattern-match-on-boolean")]
+    LintDescriptor {
+        name: "pattern_match_on_boolean",
+        crate_name: "pattern_match_on_boolean",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-redundant-else-block-after-loop-control")]
+    LintDescriptor {
+        name: "redundant_else_block_after_loop_control",
+        crate_name: "redundant_else_block_after_loop_control",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-chained-unwrap-or-default-hides-errors")]
+    LintDescriptor {
+        name: "chained_unwrap_or_default_hides_errors",
+        crate_name: "chained_unwrap_or_default_hides_errors",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-config-struct-fields-must-have-defaults-documented")]
+    LintDescriptor {
+        name: "config_struct_fields_must_have_defaults_documented",
+        crate_name: "config_struct_fields_must_have_defaults_documented",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-public-trait-missing-sealed-pattern-marker")]
+    LintDescriptor {
+        name: "public_trait_missing_sealed_pattern_marker",
+        crate_name: "public_trait_missing_sealed_pattern_marker",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-generic-function-monomorphisation-bloat")]
+    LintDescriptor {
+        name: "generic_function_monomorphisation_bloat",
+        crate_name: "generic_function_monomorphisation_bloat",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-await-in-loop-without-concurrency")]
+    LintDescriptor {
+        name: "await_in_loop_without_concurrency",
+        crate_name: "await_in_loop_without_concurrency",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-spawned-task-missing-cancellation-handling")]
+    LintDescriptor {
+        name: "spawned_task_missing_cancellation_handling",
+        crate_name: "spawned_task_missing_cancellation_handling",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-metrics-or-log-in-tight-loop")]
+    LintDescriptor {
+        name: "metrics_or_log_in_tight_loop",
+        crate_name: "metrics_or_log_in_tight_loop",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-vec-of-boxed-small-types")]
+    LintDescriptor {
+        name: "vec_of_boxed_small_types",
+        crate_name: "vec_of_boxed_small_types",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-repeated-env-lookup")]
+    LintDescriptor {
+        name: "repeated_env_lookup",
+        crate_name: "repeated_env_lookup",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-error-context-missing-on-io-operations")]
+    LintDescriptor {
+        name: "error_context_missing_on_io_operations",
+        crate_name: "error_context_missing_on_io_operations",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-path-join-with-absolute-component")]
+    LintDescriptor {
+        name: "path_join_with_absolute_component",
+        crate_name: "path_join_with_absolute_component",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-archive-extraction-must-sanitise-paths")]
+    LintDescriptor {
+        name: "archive_extraction_must_sanitise_paths",
+        crate_name: "archive_extraction_must_sanitise_paths",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-subprocess-inherits-full-env")]
+    LintDescriptor {
+        name: "subprocess_inherits_full_env",
+        crate_name: "subprocess_inherits_full_env",
+        behaviour_version: "0.1.0",
+    },
+    #[cfg(feature = "experimental-deserialised-input-used-as-path-without-validation")]
+    LintDescriptor {
+        name: "deserialised_input_used_as_path_without_validation",
+        crate_name: "deserialised_input_used_as_path_without_validation",
+        behaviour_version: "0.1.0",
+    },
+];
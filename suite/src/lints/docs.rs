@@ -0,0 +1,253 @@
+//! Compiler-facing lint declarations and embedded documentation.
+//!
+//! Both lists follow the order of [`super::SUITE_LINTS`] and exist only in
+//! driver builds, since they name the lint crates' `rustc_lint` statics.
+
+use rustc_lint::Lint;
+use whitaker_common::lint_docs::LintDocs;
+
+/// Lint declarations derived from the suite membership.
+pub const SUITE_LINT_DECLS: &[&Lint] = &[
+    function_attrs_follow_docs::FUNCTION_ATTRS_FOLLOW_DOCS,
+    no_expect_outside_tests::NO_EXPECT_OUTSIDE_TESTS,
+    test_must_not_have_example::TEST_MUST_NOT_HAVE_EXAMPLE,
+    module_must_have_inner_docs::MODULE_MUST_HAVE_INNER_DOCS,
+    conditional_max_n_branches::CONDITIONAL_MAX_N_BRANCHES,
+    module_max_lines::MODULE_MAX_LINES,
+    no_unwrap_or_else_panic::NO_UNWRAP_OR_ELSE_PANIC,
+    no_std_fs_operations::NO_STD_FS_OPERATIONS,
+    bumpy_road_function::BUMPY_ROAD_FUNCTION,
+    no_static_mut::NO_STATIC_MUT,
+    prefer_named_module_files::PREFER_NAMED_MODULE_FILES,
+    method_chain_max_length::METHOD_CHAIN_MAX_LENGTH,
+    closure_max_lines::CLOSURE_MAX_LINES,
+    no_shadowing::NO_SHADOWING,
+    commented_out_code::COMMENTED_OUT_CODE,
+    uninformative_panic_message::UNINFORMATIVE_PANIC_MESSAGE,
+    tls_verification_disabled::TLS_VERIFICATION_DISABLED,
+    #[cfg(feature = "experimental-rstest-helper-should-be-fixture")]
+    rstest_helper_should_be_fixture::RSTEST_HELPER_SHOULD_BE_FIXTURE,
+    #[cfg(feature = "experimental-feature-envy")]
+    feature_envy::FEATURE_ENVY,
+    #[cfg(feature = "experimental-data-clumps")]
+    data_clumps::DATA_CLUMPS,
+    #[cfg(feature = "experimental-duplicated-blocks")]
+    duplicated_blocks::DUPLICATED_BLOCKS,
+    #[cfg(feature = "experimental-no-recursion")]
+    no_recursion::NO_RECURSION,
+    #[cfg(feature = "experimental-constructor-max-arguments")]
+    constructor_max_arguments::CONSTRUCTOR_MAX_ARGUMENTS,
+    #[cfg(feature = "experimental-no-global-mutable-state")]
+    no_global_mutable_state::NO_GLOBAL_MUTABLE_STATE,
+    #[cfg(feature = "experimental-no-block-on-in-async")]
+    no_block_on_in_async::NO_BLOCK_ON_IN_ASYNC,
+    #[cfg(feature = "experimental-no-detached-threads")]
+    no_detached_threads::NO_DETACHED_THREADS,
+    #[cfg(feature = "experimental-primitive-obsession")]
+    primitive_obsession::PRIMITIVE_OBSESSION,
+    #[cfg(feature = "experimental-no-indexing-outside-tests")]
+    no_indexing_outside_tests::NO_INDEXING_OUTSIDE_TESTS,
+    #[cfg(feature = "experimental-no-float-equality")]
+    no_float_equality::NO_FLOAT_EQUALITY,
+    #[cfg(feature = "experimental-no-lossy-casts")]
+    no_lossy_casts::NO_LOSSY_CASTS,
+    #[cfg(feature = "experimental-error-types-implement-error")]
+    error_types_implement_error::ERROR_TYPES_IMPLEMENT_ERROR,
+    #[cfg(feature = "experimental-large-stack-values")]
+    large_stack_values::LARGE_STACK_VALUES,
+    #[cfg(feature = "experimental-allow-must-have-reason")]
+    allow_must_have_reason::ALLOW_MUST_HAVE_REASON,
+    #[cfg(feature = "experimental-function-max-return-points")]
+    function_max_return_points::FUNCTION_MAX_RETURN_POINTS,
+    #[cfg(feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl")]
+    async_fn_returning_boxed_future_prefer_async_trait_or_impl::ASYNC_FN_RETURNING_BOXED_FUTURE_PREFER_ASYNC_TRAIT_OR_IMPL,
+    #[cfg(feature = "experimental-iterator-returning-fn-should-not-collect-internally")]
+    iterator_returning_fn_should_not_collect_internally::ITERATOR_RETURNING_FN_SHOULD_NOT_COLLECT_INTERNALLY,
+    #[cfg(feature = "experimental-no-else-after-return")]
+    no_else_after_return::NO_ELSE_AFTER_RETURN,
+    #[cfg(feature = "experimental-arc-clone-prefer-explicit")]
+    arc_clone_prefer_explicit::ARC_CLONE_PREFER_EXPLICIT,
+    #[cfg(feature = "experimental-no-single-letter-identifiers")]
+    no_single_letter_identifiers::NO_SINGLE_LETTER_IDENTIFIERS,
+    #[cfg(feature = "experimental-module-must-have-tests")]
+    module_must_have_tests::MODULE_MUST_HAVE_TESTS,
+    #[cfg(feature = "experimental-nested-result-option-in-signatures")]
+    nested_result_option_in_signatures::NESTED_RESULT_OPTION_IN_SIGNATURES,
+    #[cfg(feature = "experimental-no-lock-unwrap")]
+    no_lock_unwrap::NO_LOCK_UNWRAP,
+    #[cfg(feature = "experimental-impl-trait-for-reference-smell")]
+    impl_trait_for_reference_smell::IMPL_TRAIT_FOR_REFERENCE_SMELL,
+    #[cfg(feature = "experimental-no-unchecked-arithmetic")]
+    no_unchecked_arithmetic::NO_UNCHECKED_ARITHMETIC,
+    #[cfg(feature = "experimental-module-named-after-parent")]
+    module_named_after_parent::MODULE_NAMED_AFTER_PARENT,
+    #[cfg(feature = "experimental-doc-ignore-must-have-reason")]
+    doc_ignore_must_have_reason::DOC_IGNORE_MUST_HAVE_REASON,
+    #[cfg(feature = "experimental-test-file-in-src-should-use-path-attribute-pattern")]
+    test_file_in_src_should_use_path_attribute_pattern::TEST_FILE_IN_SRC_SHOULD_USE_PATH_ATTRIBUTE_PATTERN,
+    #[cfg(feature = "experimental-no-glob-reexports")]
+    no_glob_reexports::NO_GLOB_REEXPORTS,
+    #[cfg(feature = "experimental-lint-crate-missing-stub-for-non-driver-builds")]
+    lint_crate_missing_stub_for_non_driver_builds::LINT_CRATE_MISSING_STUB_FOR_NON_DRIVER_BUILDS,
+    #[cfg(feature = "experimental-no-wildcard-match-on-local-enums")]
+    no_wildcard_match_on_local_enums::NO_WILDCARD_MATCH_ON_LOCAL_ENUMS,
+    #[cfg(feature = "experimental-feature-gated-pub-use-must-have-cfg-doc")]
+    feature_gated_pub_use_must_have_cfg_doc::FEATURE_GATED_PUB_USE_MUST_HAVE_CFG_DOC,
+    #[cfg(feature = "experimental-no-include-str-of-large-files")]
+    no_include_str_of_large_files::NO_INCLUDE_STR_OF_LARGE_FILES,
+    #[cfg(feature = "experimental-pattern-match-on-boolean")]
+    pattern_match_on_boolean::PATTERN_MATCH_ON_BOOLEAN,
+    #[cfg(feature = "experimental-redundant-else-block-after-loop-control")]
+    redundant_else_block_after_loop_control::REDUNDANT_ELSE_BLOCK_AFTER_LOOP_CONTROL,
+    #[cfg(feature = "experimental-chained-unwrap-or-default-hides-errors")]
+    chained_unwrap_or_default_hides_errors::CHAINED_UNWRAP_OR_DEFAULT_HIDES_ERRORS,
+    #[cfg(feature = "experimental-config-struct-fields-must-have-defaults-documented")]
+    config_struct_fields_must_have_defaults_documented::CONFIG_STRUCT_FIELDS_MUST_HAVE_DEFAULTS_DOCUMENTED,
+    #[cfg(feature = "experimental-public-trait-missing-sealed-pattern-marker")]
+    public_trait_missing_sealed_pattern_marker::PUBLIC_TRAIT_MISSING_SEALED_PATTERN_MARKER,
+    #[cfg(feature = "experimental-generic-function-monomorphisation-bloat")]
+    generic_function_monomorphisation_bloat::GENERIC_FUNCTION_MONOMORPHISATION_BLOAT,
+    #[cfg(feature = "experimental-await-in-loop-without-concurrency")]
+    await_in_loop_without_concurrency::AWAIT_IN_LOOP_WITHOUT_CONCURRENCY,
+    #[cfg(feature = "experimental-spawned-task-missing-cancellation-handling")]
+    spawned_task_missing_cancellation_handling::SPAWNED_TASK_MISSING_CANCELLATION_HANDLING,
+    #[cfg(feature = "experimental-metrics-or-log-in-tight-loop")]
+    metrics_or_log_in_tight_loop::METRICS_OR_LOG_IN_TIGHT_LOOP,
+    #[cfg(feature = "experimental-vec-of-boxed-small-types")]
+    vec_of_boxed_small_types::VEC_OF_BOXED_SMALL_TYPES,
+    #[cfg(feature = "experimental-repeated-env-lookup")]
+    repeated_env_lookup::REPEATED_ENV_LOOKUP,
+    #[cfg(feature = "experimental-error-context-missing-on-io-operations")]
+    error_context_missing_on_io_operations::ERROR_CONTEXT_MISSING_ON_IO_OPERATIONS,
+    #[cfg(feature = "experimental-path-join-with-absolute-component")]
+    path_join_with_absolute_component::PATH_JOIN_WITH_ABSOLUTE_COMPONENT,
+    #[cfg(feature = "experimental-archive-extraction-must-sanitise-paths")]
+    archive_extraction_must_sanitise_paths::ARCHIVE_EXTRACTION_MUST_SANITISE_PATHS,
+    #[cfg(feature = "experimental-subprocess-inherits-full-env")]
+    subprocess_inherits_full_env::SUBPROCESS_INHERITS_FULL_ENV,
+    #[cfg(feature = "experimental-deserialised-input-used-as-path-without-validation")]
+    deserialised_input_used_as_path_without_validation::DESERIALISED_INPUT_USED_AS_PATH_WITHOUT_VALIDATION,
+];
+
+/// Embedded documentation for each suite lint, in suite order.
+pub const SUITE_LINT_DOCS: &[&LintDocs] = &[
+    &function_attrs_follow_docs::LINT_DOCS,
+    &no_expect_outside_tests::LINT_DOCS,
+    &test_must_not_have_example::LINT_DOCS,
+    &module_must_have_inner_docs::LINT_DOCS,
+    &conditional_max_n_branches::LINT_DOCS,
+    &module_max_lines::LINT_DOCS,
+    &no_unwrap_or_else_panic::LINT_DOCS,
+    &no_std_fs_operations::LINT_DOCS,
+    &bumpy_road_function::LINT_DOCS,
+    &no_static_mut::LINT_DOCS,
+    &prefer_named_module_files::LINT_DOCS,
+    &method_chain_max_length::LINT_DOCS,
+    &closure_max_lines::LINT_DOCS,
+    &no_shadowing::LINT_DOCS,
+    &commented_out_code::LINT_DOCS,
+    &uninformative_panic_message::LINT_DOCS,
+    &tls_verification_disabled::LINT_DOCS,
+    #[cfg(feature = "experimental-rstest-helper-should-be-fixture")]
+    &rstest_helper_should_be_fixture::LINT_DOCS,
+    #[cfg(feature = "experimental-feature-envy")]
+    &feature_envy::LINT_DOCS,
+    #[cfg(feature = "experimental-data-clumps")]
+    &data_clumps::LINT_DOCS,
+    #[cfg(feature = "experimental-duplicated-blocks")]
+    &duplicated_blocks::LINT_DOCS,
+    #[cfg(feature = "experimental-no-recursion")]
+    &no_recursion::LINT_DOCS,
+    #[cfg(feature = "experimental-constructor-max-arguments")]
+    &constructor_max_arguments::LINT_DOCS,
+    #[cfg(feature = "experimental-no-global-mutable-state")]
+    &no_global_mutable_state::LINT_DOCS,
+    #[cfg(feature = "experimental-no-block-on-in-async")]
+    &no_block_on_in_async::LINT_DOCS,
+    #[cfg(feature = "experimental-no-detached-threads")]
+    &no_detached_threads::LINT_DOCS,
+    #[cfg(feature = "experimental-primitive-obsession")]
+    &primitive_obsession::LINT_DOCS,
+    #[cfg(feature = "experimental-no-indexing-outside-tests")]
+    &no_indexing_outside_tests::LINT_DOCS,
+    #[cfg(feature = "experimental-no-float-equality")]
+    &no_float_equality::LINT_DOCS,
+    #[cfg(feature = "experimental-no-lossy-casts")]
+    &no_lossy_casts::LINT_DOCS,
+    #[cfg(feature = "experimental-error-types-implement-error")]
+    &error_types_implement_error::LINT_DOCS,
+    #[cfg(feature = "experimental-large-stack-values")]
+    &large_stack_values::LINT_DOCS,
+    #[cfg(feature = "experimental-allow-must-have-reason")]
+    &allow_must_have_reason::LINT_DOCS,
+    #[cfg(feature = "experimental-function-max-return-points")]
+    &function_max_return_points::LINT_DOCS,
+    #[cfg(feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl")]
+    &async_fn_returning_boxed_future_prefer_async_trait_or_impl::LINT_DOCS,
+    #[cfg(feature = "experimental-iterator-returning-fn-should-not-collect-internally")]
+    &iterator_returning_fn_should_not_collect_internally::LINT_DOCS,
+    #[cfg(feature = "experimental-no-else-after-return")]
+    &no_else_after_return::LINT_DOCS,
+    #[cfg(feature = "experimental-arc-clone-prefer-explicit")]
+    &arc_clone_prefer_explicit::LINT_DOCS,
+    #[cfg(feature = "experimental-no-single-letter-identifiers")]
+    &no_single_letter_identifiers::LINT_DOCS,
+    #[cfg(feature = "experimental-module-must-have-tests")]
+    &module_must_have_tests::LINT_DOCS,
+    #[cfg(feature = "experimental-nested-result-option-in-signatures")]
+    &nested_result_option_in_signatures::LINT_DOCS,
+    #[cfg(feature = "experimental-no-lock-unwrap")]
+    &no_lock_unwrap::LINT_DOCS,
+    #[cfg(feature = "experimental-impl-trait-for-reference-smell")]
+    &impl_trait_for_reference_smell::LINT_DOCS,
+    #[cfg(feature = "experimental-no-unchecked-arithmetic")]
+    &no_unchecked_arithmetic::LINT_DOCS,
+    #[cfg(feature = "experimental-module-named-after-parent")]
+    &module_named_after_parent::LINT_DOCS,
+    #[cfg(feature = "experimental-doc-ignore-must-have-reason")]
+    &doc_ignore_must_have_reason::LINT_DOCS,
+    #[cfg(feature = "experimental-test-file-in-src-should-use-path-attribute-pattern")]
+    &test_file_in_src_should_use_path_attribute_pattern::LINT_DOCS,
+    #[cfg(feature = "experimental-no-glob-reexports")]
+    &no_glob_reexports::LINT_DOCS,
+    #[cfg(feature = "experimental-lint-crate-missing-stub-for-non-driver-builds")]
+    &lint_crate_missing_stub_for_non_driver_builds::LINT_DOCS,
+    #[cfg(feature = "experimental-no-wildcard-match-on-local-enums")]
+    &no_wildcard_match_on_local_enums::LINT_DOCS,
+    #[cfg(feature = "experimental-feature-gated-pub-use-must-have-cfg-doc")]
+    &feature_gated_pub_use_must_have_cfg_doc::LINT_DOCS,
+    #[cfg(feature = "experimental-no-include-str-of-large-files")]
+    &no_include_str_of_large_files::LINT_DOCS,
+    #[cfg(feature = "experimental-pattern-match-on-boolean")]
+    &pattern_match_on_boolean::LINT_DOCS,
+    #[cfg(feature = "experimental-redundant-else-block-after-loop-control")]
+    &redundant_else_block_after_loop_control::LINT_DOCS,
+    #[cfg(feature = "experimental-chained-unwrap-or-default-hides-errors")]
+    &chained_unwrap_or_default_hides_errors::LINT_DOCS,
+    #[cfg(feature = "experimental-config-struct-fields-must-have-defaults-documented")]
+    &config_struct_fields_must_have_defaults_documented::LINT_DOCS,
+    #[cfg(feature = "experimental-public-trait-missing-sealed-pattern-marker")]
+    &public_trait_missing_sealed_pattern_marker::LINT_DOCS,
+    #[cfg(feature = "experimental-generic-function-monomorphisation-bloat")]
+    &generic_function_monomorphisation_bloat::LINT_DOCS,
+    #[cfg(feature = "experimental-await-in-loop-without-concurrency")]
+    &await_in_loop_without_concurrency::LINT_DOCS,
+    #[cfg(feature = "experimental-spawned-task-missing-cancellation-handling")]
+    &spawned_task_missing_cancellation_handling::LINT_DOCS,
+    #[cfg(feature = "experimental-metrics-or-log-in-tight-loop")]
+    &metrics_or_log_in_tight_loop::LINT_DOCS,
+    #[cfg(feature = "experimental-vec-of-boxed-small-types")]
+    &vec_of_boxed_small_types::LINT_DOCS,
+    #[cfg(feature = "experimental-repeated-env-lookup")]
+    &repeated_env_lookup::LINT_DOCS,
+    #[cfg(feature = "experimental-error-context-missing-on-io-operations")]
+    &error_context_missing_on_io_operations::LINT_DOCS,
+    #[cfg(feature = "experimental-path-join-with-absolute-component")]
+    &path_join_with_absolute_component::LINT_DOCS,
+    #[cfg(feature = "experimental-archive-extraction-must-sanitise-paths")]
+    &archive_extraction_must_sanitise_paths::LINT_DOCS,
+    #[cfg(feature = "experimental-subprocess-inherits-full-env")]
+    &subprocess_inherits_full_env::LINT_DOCS,
+    #[cfg(feature = "experimental-deserialised-input-used-as-path-without-validation")]
+    &deserialised_input_used_as_path_without_validation::LINT_DOCS,
+];
@@ -0,0 +1,233 @@
+//! Suite lint registry and shared metadata.
+//!
+//! [`SUITE_LINTS`] is the pure-Rust view of the suite: it needs no compiler
+//! crates, so tooling can read it to learn which lints a build contains and
+//! whether their behaviour changed since a baseline was recorded.
+
+mod descriptors;
+#[cfg(feature = "dylint-driver")]
+mod docs;
+
+pub use descriptors::SUITE_LINTS;
+#[cfg(feature = "dylint-driver")]
+pub use docs::{SUITE_LINT_DECLS, SUITE_LINT_DOCS};
+
+use serde::Serialize;
+
+/// Version of the layout [`SuiteLintSchema`] serialises to.
+///
+/// The number changes only when a field is removed or renamed, or when a
+/// field's meaning changes. Adding a field leaves it unchanged, so consumers
+/// should ignore fields they do not recognise.
+pub const SUITE_LINT_SCHEMA_VERSION: u32 = 1;
+
+/// Minimal metadata describing an included lint.
+///
+/// Descriptors serialise to objects with `name`, `crate_name`, and
+/// `behaviour_version` string fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct LintDescriptor {
+    /// Canonical lint name used by the driver.
+    pub name: &'static str,
+    /// Crate that defines the lint.
+    pub crate_name: &'static str,
+    /// Semantic version of what the lint reports, independent of the crate
+    /// version.
+    ///
+    /// The major part is bumped when the lint starts reporting code it
+    /// previously accepted or a configuration key changes meaning, the minor
+    /// part when it stops reporting code it previously flagged, and the patch
+    /// part when only the wording of its diagnostics changes. Tools that keep
+    /// baselines of findings should rebuild them when the major or minor part
+    /// differs from the version the baseline recorded. Experimental lints stay
+    /// below `1.0.0` until they are promoted.
+    pub behaviour_version: &'static str,
+}
+
+/// The suite's lints in a stable, serialisable layout.
+///
+/// Serialising the schema produces a `schema_version` number and a `lints`
+/// array of [`LintDescriptor`] objects in suite order.
+///
+/// # Examples
+///
+/// ```
+/// use whitaker_suite::{SUITE_LINT_SCHEMA_VERSION, suite_lint_schema};
+///
+/// let schema = suite_lint_schema();
+/// assert_eq!(schema.schema_version, SUITE_LINT_SCHEMA_VERSION);
+/// assert_eq!(schema.lints[0].name, "function_attrs_follow_docs");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct SuiteLintSchema {
+    /// Layout version; see [`SUITE_LINT_SCHEMA_VERSION`].
+    pub schema_version: u32,
+    /// Every lint in this build of the suite, in suite order.
+    pub lints: &'static [LintDescriptor],
+}
+
+/// Returns an iterator over the canonical lint names in suite order.
+///
+/// # Examples
+///
+/// ```
+/// # use whitaker_suite::suite_lint_names;
+/// let names: Vec<_> = suite_lint_names().collect();
+/// for expected in [
+///     "function_attrs_follow_docs",
+///     "no_expect_outside_tests",
+///     "test_must_not_have_example",
+///     "module_must_have_inner_docs",
+///     "conditional_max_n_branches",
+///     "module_max_lines",
+///     "no_unwrap_or_else_panic",
+///     "no_std_fs_operations",
+///     "bumpy_road_function",
+///     "no_static_mut",
+///     "prefer_named_module_files",
+///     "method_chain_max_length",
+///     "closure_max_lines",
+///     "no_shadowing",
+///     "commented_out_code",
+///     "uninformative_panic_message",
+///     "tls_verification_disabled",
+/// ] {
+///     assert!(names.contains(&expected));
+/// }
+/// #[cfg(feature = "experimental-rstest-helper-should-be-fixture")]
+/// assert!(names.contains(&"rstest_helper_should_be_fixture"));
+/// #[cfg(feature = "experimental-feature-envy")]
+/// assert!(names.contains(&"feature_envy"));
+/// #[cfg(feature = "experimental-data-clumps")]
+/// assert!(names.contains(&"data_clumps"));
+/// #[cfg(feature = "experimental-duplicated-blocks")]
+/// assert!(names.contains(&"duplicated_blocks"));
+/// #[cfg(feature = "experimental-no-recursion")]
+/// assert!(names.contains(&"no_recursion"));
+/// #[cfg(feature = "experimental-constructor-max-arguments")]
+/// assert!(names.contains(&"constructor_max_arguments"));
+/// #[cfg(feature = "experimental-no-global-mutable-state")]
+/// assert!(names.contains(&"no_global_mutable_state"));
+/// #[cfg(feature = "experimental-no-block-on-in-async")]
+/// assert!(names.contains(&"no_block_on_in_async"));
+/// #[cfg(feature = "experimental-no-detached-threads")]
+/// assert!(names.contains(&"no_detached_threads"));
+/// #[cfg(feature = "experimental-primitive-obsession")]
+/// assert!(names.contains(&"primitive_obsession"));
+/// #[cfg(feature = "experimental-no-indexing-outside-tests")]
+/// assert!(names.contains(&"no_indexing_outside_tests"));
+/// #[cfg(feature = "experimental-no-float-equality")]
+/// assert!(names.contains(&"no_float_equality"));
+/// #[cfg(feature = "experimental-no-lossy-casts")]
+/// assert!(names.contains(&"no_lossy_casts"));
+/// #[cfg(feature = "experimental-error-types-implement-error")]
+/// assert!(names.contains(&"error_types_implement_error"));
+/// #[cfg(feature = "experimental-large-stack-values")]
+/// assert!(names.contains(&"large_stack_values"));
+/// #[cfg(feature = "experimental-allow-must-have-reason")]
+/// assert!(names.contains(&"allow_must_have_reason"));
+/// #[cfg(feature = "experimental-function-max-return-points")]
+/// assert!(names.contains(&"function_max_return_points"));
+/// #[cfg(feature = "experimental-async-fn-returning-boxed-future-prefer-async-trait-or-impl")]
+/// assert!(names.contains(&"async_fn_returning_boxed_future_prefer_async_trait_or_impl"));
+/// #[cfg(feature = "experimental-iterator-returning-fn-should-not-collect-internally")]
+/// assert!(names.contains(&"iterator_returning_fn_should_not_collect_internally"));
+/// #[cfg(feature = "experimental-no-else-after-return")]
+/// assert!(names.contains(&"no_else_after_return"));
+/// #[cfg(feature = "experimental-arc-clone-prefer-explicit")]
+/// assert!(names.contains(&"arc_clone_prefer_explicit"));
+/// #[cfg(feature = "experimental-no-single-letter-identifiers")]
+/// assert!(names.contains(&"no_single_letter_identifiers"));
+/// #[cfg(feature = "experimental-module-must-have-tests")]
+/// assert!(names.contains(&"module_must_have_tests"));
+/// #[cfg(feature = "experimental-nested-result-option-in-signatures")]
+/// assert!(names.contains(&"nested_result_option_in_signatures"));
+/// #[cfg(feature = "experimental-no-lock-unwrap")]
+/// assert!(names.contains(&"no_lock_unwrap"));
+/// #[cfg(feature = "experimental-impl-trait-for-reference-smell")]
+/// assert!(names.contains(&"impl_trait_for_reference_smell"));
+/// #[cfg(feature = "experimental-no-unchecked-arithmetic")]
+/// assert!(names.contains(&"no_unchecked_arithmetic"));
+/// #[cfg(feature = "experimental-module-named-after-parent")]
+/// assert!(names.contains(&"module_named_after_parent"));
+/// #[cfg(feature = "experimental-doc-ignore-must-have-reason")]
+/// assert!(names.contains(&"doc_ignore_must_have_reason"));
+/// #[cfg(feature = "experimental-test-file-in-src-should-use-path-attribute-pattern")]
+/// assert!(names.contains(&"test_file_in_src_should_use_path_attribute_pattern"));
+/// #[cfg(feature = "experimental-no-glob-reexports")]
+/// assert!(names.contains(&"no_glob_reexports"));
+/// #[cfg(feature = "experimental-lint-crate-missing-stub-for-non-driver-builds")]
+/// assert!(names.contains(&"lint_crate_missing_stub_for_non_driver_builds"));
+/// #[cfg(feature = "experimental-no-wildcard-match-on-local-enums")]
+/// assert!(names.contains(&"no_wildcard_match_on_local_enums"));
+/// #[cfg(feature = "experimental-feature-gated-pub-use-must-have-cfg-doc")]
+/// assert!(names.contains(&"feature_gated_pub_use_must_have_cfg_doc"));
+/// #[cfg(feature = "experimental-no-include-str-of-large-files")]
+/// assert!(names.contains(&"no_include_str_of_large_files"));
+/// #[cfg(feature = "experimental-pattern-match-on-boolean")]
+/// assert!(names.contains(&"pattern_match_on_boolean"));
+/// #[cfg(feature = "experimental-redundant-else-block-after-loop-control")]
+/// assert!(names.contains(&"redundant_else_block_after_loop_control"));
+/// #[cfg(feature = "experimental-chained-unwrap-or-default-hides-errors")]
+/// assert!(names.contains(&"chained_unwrap_or_default_hides_errors"));
+/// #[cfg(feature = "experimental-config-struct-fields-must-have-defaults-documented")]
+/// assert!(names.contains(&"config_struct_fields_must_have_defaults_documented"));
+/// #[cfg(feature = "experimental-public-trait-missing-sealed-pattern-marker")]
+/// assert!(names.contains(&"public_trait_missing_sealed_pattern_marker"));
+/// #[cfg(feature = "experimental-generic-function-monomorphisation-bloat")]
+/// assert!(names.contains(&"generic_function_monomorphisation_bloat"));
+/// #[cfg(feature = "experimental-await-in-loop-without-concurrency")]
+/// assert!(names.contains(&"await_in_loop_without_concurrency"));
+/// #[cfg(feature = "experimental-spawned-task-missing-cancellation-handling")]
+/// assert!(names.contains(&"spawned_task_missing_cancellation_handling"));
+/// #[cfg(feature = "experimental-metrics-or-log-in-tight-loop")]
+/// assert!(names.contains(&"metrics_or_log_in_tight_loop"));
+/// #[cfg(feature = "experimental-vec-of-boxed-small-types")]
+/// assert!(names.contains(&"vec_of_boxed_small_types"));
+/// #[cfg(feature = "experimental-repeated-env-lookup")]
+/// assert!(names.contains(&"repeated_env_lookup"));
+/// #[cfg(feature = "experimental-error-context-missing-on-io-operations")]
+/// assert!(names.contains(&"error_context_missing_on_io_operations"));
+/// #[cfg(feature = "experimental-path-join-with-absolute-component")]
+/// assert!(names.contains(&"path_join_with_absolute_component"));
+/// #[cfg(feature = "experimental-archive-extraction-must-sanitise-paths")]
+/// assert!(names.contains(&"archive_extraction_must_sanitise_paths"));
+/// #[cfg(feature = "experimental-subprocess-inherits-full-env")]
+/// assert!(names.contains(&"subprocess_inherits_full_env"));
+/// #[cfg(feature = "experimental-deserialised-input-used-as-path-without-validation")]
+/// assert!(names.contains(&"deserialised_input_used_as_path_without_validation"));
+/// ```
+#[must_use = "Discarding the iterator hides suite wiring errors"]
+pub fn suite_lint_names() -> impl Iterator<Item = &'static str> {
+    suite_lint_descriptors().map(|descriptor| descriptor.name)
+}
+
+/// Returns an iterator over the lint descriptors in suite order.
+///
+/// Each descriptor carries the lint's behaviour version alongside its name,
+/// so tooling can tell when a lint's findings may have changed.
+///
+/// # Examples
+///
+/// ```
+/// # use whitaker_suite::suite_lint_descriptors;
+/// let shadowing = suite_lint_descriptors()
+///     .find(|descriptor| descriptor.name == "no_shadowing")
+///     .expect("no_shadowing is a stable suite lint");
+/// assert_eq!(shadowing.behaviour_version, "1.0.0");
+/// ```
+#[must_use = "Discarding the iterator hides suite wiring errors"]
+pub fn suite_lint_descriptors() -> impl Iterator<Item = &'static LintDescriptor> {
+    SUITE_LINTS.iter()
+}
+
+/// Returns the serialisable schema describing this build of the suite.
+#[must_use]
+pub const fn suite_lint_schema() -> SuiteLintSchema {
+    SuiteLintSchema {
+        schema_version: SUITE_LINT_SCHEMA_VERSION,
+        lints: SUITE_LINTS,
+    }
+}
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,51 @@
+//! Unit tests for the suite lint registry.
+
+use super::*;
+use rstest::rstest;
+use serde_json::json;
+
+fn parse_version(version: &str) -> Option<[u64; 3]> {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+    let parsed = [parts.next()??, parts.next()??, parts.next()??];
+    parts.next().is_none().then_some(parsed)
+}
+
+#[rstest]
+fn behaviour_versions_are_semantic_versions() {
+    for descriptor in suite_lint_descriptors() {
+        assert!(
+            parse_version(descriptor.behaviour_version).is_some(),
+            "`{}` has behaviour version `{}`",
+            descriptor.name,
+            descriptor.behaviour_version
+        );
+    }
+}
+
+#[rstest]
+fn lint_names_are_unique() {
+    let mut names: Vec<_> = suite_lint_names().collect();
+    names.sort_unstable();
+    names.dedup();
+
+    assert_eq!(names.len(), SUITE_LINTS.len());
+}
+
+#[rstest]
+fn schema_serialises_to_the_documented_layout() {
+    let schema = serde_json::to_value(suite_lint_schema()).expect("schema serialises");
+
+    assert_eq!(schema["schema_version"], json!(SUITE_LINT_SCHEMA_VERSION));
+    assert_eq!(
+        schema["lints"][0],
+        json!({
+            "name": "function_attrs_follow_docs",
+            "crate_name": "function_attrs_follow_docs",
+            "behaviour_version": "1.0.0",
+        })
+    );
+    assert_eq!(
+        schema["lints"].as_array().map(Vec::len),
+        Some(SUITE_LINTS.len())
+    );
+}
@@ -0,0 +1,54 @@
+//! Optional end-of-crate summary of each lint's findings.
+//!
+//! With `crate_summary = true` in the shared configuration, the suite emits
+//! one note after checking a crate that lists how many findings each lint
+//! reported, so CI logs show the headline numbers without scrolling through
+//! every diagnostic. The pass runs last in the combined pass so that every
+//! constituent has recorded its total by the time it reads them.
+
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::{LateContext, LateLintPass};
+use whitaker::SharedConfig;
+use whitaker::hir::take_crate_totals;
+use whitaker_common::CrateScopedState;
+use whitaker_common::diagnostics::CrateSummary;
+use whitaker_common::i18n::{Localizer, get_localizer_for_lint};
+
+/// Emits the crate summary when `crate_summary` is enabled.
+#[derive(Default)]
+pub(crate) struct FindingSummary {
+    localizer: CrateScopedState<Option<Localizer>>,
+}
+
+rustc_session::impl_lint_pass!(FindingSummary => []);
+
+impl<'tcx> LateLintPass<'tcx> for FindingSummary {
+    fn check_crate(&mut self, _cx: &LateContext<'tcx>) {
+        let shared_config = SharedConfig::load();
+        self.localizer.reset(
+            shared_config
+                .crate_summary
+                .then(|| get_localizer_for_lint("whitaker_suite", shared_config.locale())),
+        );
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let totals = take_crate_totals();
+        let Some(localizer) = self.localizer.as_ref() else {
+            return;
+        };
+        let crate_name = cx.tcx.crate_name(LOCAL_CRATE);
+        let Some(summary) = CrateSummary::new(crate_name.as_str(), totals) else {
+            return;
+        };
+
+        let messages = summary.messages(localizer);
+        let mut diag = cx.tcx.dcx().struct_note(messages.primary().to_owned());
+        for line in summary.lines() {
+            diag.note(line);
+        }
+        diag.note(messages.note().to_owned());
+        diag.help(messages.help().to_owned());
+        diag.emit();
+    }
+}
@@ -8,6 +8,9 @@ Feature: Register the Whitaker lint suite
     And the late pass count is 1
     And the lint names mirror the suite descriptors
     And the suite lint declarations align with the descriptors
+    And every suite lint has embedded documentation
+    And reports and listings describe the suite lints from their documentation
+    And every suite lint decision conforms to its fixtures
 
   Scenario: Registering twice surfaces a duplicate lint error
     Given the suite lints are already registered
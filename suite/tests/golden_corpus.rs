@@ -0,0 +1,98 @@
+//! Golden corpus regression suite over pinned real-world crates.
+//!
+//! The corpus in `tests/golden_corpus/` vendors permissively licensed crate
+//! tarballs and records how many findings each suite lint reports for them.
+//! The corpus test runs the whole suite over every crate and fails when any
+//! count drifts, catching both false-positive explosions and silent
+//! detection regressions before release.
+//!
+//! # Prerequisites
+//!
+//! - `cargo-dylint` and `dylint-link` must be installed
+//! - Network access, so Cargo can resolve the corpus crates' dependencies
+//!
+//! The corpus test is marked `#[ignore]`; run it with `make golden-corpus`.
+//! The manifest checks below run with the ordinary test suite.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[path = "golden_corpus/manifest.rs"]
+mod manifest;
+#[path = "golden_corpus/runner.rs"]
+mod runner;
+#[path = "support/suite_library.rs"]
+mod suite_library;
+
+use anyhow::Context as _;
+use rstest::rstest;
+use whitaker_suite::suite_lint_names;
+
+use manifest::{compare_counts, load_manifest, render_expected_table};
+
+#[test]
+#[ignore = "requires cargo-dylint and network access to resolve corpus dependencies"]
+fn corpus_finding_counts_match_expectations() -> anyhow::Result<()> {
+    let manifest = load_manifest()?;
+    let library =
+        suite_library::build_suite_library().context("failed to build the suite library")?;
+
+    let mut failures = Vec::new();
+    for krate in &manifest.crates {
+        let counts = runner::lint_corpus_crate(krate, &library)?;
+        let drift = compare_counts(&krate.expected, &counts);
+        if drift.is_empty() {
+            continue;
+        }
+        let details: Vec<String> = drift.iter().map(|lint| format!("  {lint}")).collect();
+        failures.push(format!(
+            "`{}` drifted:\n{}\nobserved:\n{}",
+            krate.package_dir_name(),
+            details.join("\n"),
+            render_expected_table(&counts)
+        ));
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+    Ok(())
+}
+
+#[rstest]
+fn archives_match_their_pinned_checksums() -> anyhow::Result<()> {
+    let manifest = load_manifest()?;
+    assert!(
+        !manifest.crates.is_empty(),
+        "the corpus should not be empty"
+    );
+    for krate in &manifest.crates {
+        krate.read_verified_archive()?;
+    }
+    Ok(())
+}
+
+#[rstest]
+fn corpus_crates_are_permissively_licensed() -> anyhow::Result<()> {
+    for krate in load_manifest()?.crates {
+        assert!(
+            ["MIT", "Apache-2.0"]
+                .iter()
+                .any(|licence| krate.license.contains(licence)),
+            "`{}` is licensed under {}, which the corpus does not accept",
+            krate.package_dir_name(),
+            krate.license
+        );
+    }
+    Ok(())
+}
+
+#[rstest]
+fn expectations_name_suite_lints() -> anyhow::Result<()> {
+    for krate in load_manifest()?.crates {
+        for lint in krate.expected.keys() {
+            assert!(
+                suite_lint_names().any(|name| name == lint),
+                "`{}` records findings for `{lint}`, which is not a suite lint",
+                krate.package_dir_name()
+            );
+        }
+    }
+    Ok(())
+}
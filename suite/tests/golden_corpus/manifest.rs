@@ -0,0 +1,198 @@
+//! The golden corpus manifest and comparison of recorded finding counts.
+//!
+//! `corpus.toml` pins each vendored crate by name, version, and checksum and
+//! records how many findings each suite lint reports for it. Comparison is
+//! exact: more findings suggest a false-positive explosion, fewer a silent
+//! detection regression, and both fail the corpus test.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+/// Finding counts keyed by lint name.
+pub(crate) type FindingCounts = BTreeMap<String, usize>;
+
+/// Returns the directory holding `corpus.toml` and the vendored tarballs.
+pub(crate) fn corpus_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden_corpus")
+}
+
+/// The pinned crates that make up the corpus.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CorpusManifest {
+    #[serde(rename = "crate")]
+    pub(crate) crates: Vec<CorpusCrate>,
+}
+
+/// One vendored crate and the findings it is expected to produce.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CorpusCrate {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) license: String,
+    pub(crate) sha256: String,
+    #[serde(default)]
+    pub(crate) expected: FindingCounts,
+}
+
+impl CorpusCrate {
+    /// Returns the `name-version` directory the tarball unpacks into.
+    pub(crate) fn package_dir_name(&self) -> String {
+        format!("{}-{}", self.name, self.version)
+    }
+
+    /// Returns the path of the vendored `.crate` tarball.
+    pub(crate) fn archive_path(&self) -> PathBuf {
+        corpus_dir()
+            .join("crates")
+            .join(format!("{}.crate", self.package_dir_name()))
+    }
+
+    /// Reads the tarball and checks it against the pinned checksum.
+    pub(crate) fn read_verified_archive(&self) -> anyhow::Result<Vec<u8>> {
+        let path = self.archive_path();
+        let bytes =
+            fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+        let actual = format!("{:x}", Sha256::digest(&bytes));
+        anyhow::ensure!(
+            actual == self.sha256,
+            "{} has checksum {actual}, but corpus.toml pins {}",
+            path.display(),
+            self.sha256
+        );
+        Ok(bytes)
+    }
+}
+
+/// Loads `corpus.toml`.
+pub(crate) fn load_manifest() -> anyhow::Result<CorpusManifest> {
+    let path = corpus_dir().join("corpus.toml");
+    let text =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    toml::from_str(&text).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// A lint whose finding count differs from the recorded expectation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CountDrift {
+    pub(crate) lint: String,
+    pub(crate) expected: usize,
+    pub(crate) actual: usize,
+}
+
+impl fmt::Display for CountDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = if self.actual > self.expected {
+            "more findings than recorded; check for false positives"
+        } else {
+            "fewer findings than recorded; check for missed detections"
+        };
+        write!(
+            f,
+            "`{}`: expected {}, found {} ({direction})",
+            self.lint, self.expected, self.actual
+        )
+    }
+}
+
+/// Returns every lint whose count in `actual` differs from `expected`.
+///
+/// Lints missing from either map count as zero.
+pub(crate) fn compare_counts(expected: &FindingCounts, actual: &FindingCounts) -> Vec<CountDrift> {
+    let count = |counts: &FindingCounts, lint: &str| counts.get(lint).copied().unwrap_or(0);
+    let mut lints: Vec<&String> = expected.keys().chain(actual.keys()).collect();
+    lints.sort();
+    lints.dedup();
+
+    lints
+        .into_iter()
+        .filter_map(|lint| {
+            let drift = CountDrift {
+                lint: lint.clone(),
+                expected: count(expected, lint),
+                actual: count(actual, lint),
+            };
+            (drift.expected != drift.actual).then_some(drift)
+        })
+        .collect()
+}
+
+/// Renders `counts` as a `[crate.expected]` table for re-recording.
+pub(crate) fn render_expected_table(counts: &FindingCounts) -> String {
+    let mut table = String::from("[crate.expected]\n");
+    for (lint, count) in counts.iter().filter(|(_, count)| **count > 0) {
+        table.push_str(&format!("{lint} = {count}\n"));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn counts(entries: &[(&str, usize)]) -> FindingCounts {
+        entries
+            .iter()
+            .map(|(lint, count)| ((*lint).to_owned(), *count))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::identical(&[("no_shadowing", 2)], &[("no_shadowing", 2)], &[])]
+    #[case::explicit_zero(&[("no_shadowing", 0)], &[], &[])]
+    #[case::new_findings(&[], &[("no_shadowing", 3)], &[("no_shadowing", 0, 3)])]
+    #[case::lost_findings(&[("bumpy_road_function", 2)], &[], &[("bumpy_road_function", 2, 0)])]
+    #[case::sorted_by_lint(
+        &[("no_shadowing", 1), ("bumpy_road_function", 1)],
+        &[("no_shadowing", 2), ("bumpy_road_function", 2)],
+        &[("bumpy_road_function", 1, 2), ("no_shadowing", 1, 2)]
+    )]
+    fn compare_counts_reports_every_difference(
+        #[case] expected: &[(&str, usize)],
+        #[case] actual: &[(&str, usize)],
+        #[case] drift: &[(&str, usize, usize)],
+    ) {
+        let drift: Vec<CountDrift> = drift
+            .iter()
+            .map(|(lint, expected, actual)| CountDrift {
+                lint: (*lint).to_owned(),
+                expected: *expected,
+                actual: *actual,
+            })
+            .collect();
+
+        assert_eq!(compare_counts(&counts(expected), &counts(actual)), drift);
+    }
+
+    #[rstest]
+    #[case::explosion(1, 9, "check for false positives")]
+    #[case::regression(3, 0, "check for missed detections")]
+    fn drift_explains_its_direction(
+        #[case] expected: usize,
+        #[case] actual: usize,
+        #[case] hint: &str,
+    ) {
+        let drift = CountDrift {
+            lint: "bumpy_road_function".to_owned(),
+            expected,
+            actual,
+        };
+
+        assert!(drift.to_string().ends_with(&format!("{hint})")));
+    }
+
+    #[rstest]
+    fn expected_table_omits_silent_lints() {
+        let table = render_expected_table(&counts(&[("no_shadowing", 2), ("module_max_lines", 0)]));
+
+        assert_eq!(table, "[crate.expected]\nno_shadowing = 2\n");
+    }
+}
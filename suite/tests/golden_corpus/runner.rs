@@ -0,0 +1,80 @@
+//! Running the suite over a corpus crate.
+//!
+//! Each corpus crate is unpacked into a temporary directory, detached from
+//! any enclosing workspace, pinned to the workspace toolchain, and checked
+//! with `cargo dylint` using the staged suite library. Findings are counted
+//! from the JSON diagnostics by lint code.
+
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+use cargo_metadata::Message;
+use flate2::read::GzDecoder;
+use tempfile::TempDir;
+use whitaker_suite::suite_lint_names;
+
+use crate::manifest::{CorpusCrate, FindingCounts};
+use crate::suite_library::{SuiteLibrary, pin_toolchain};
+
+/// Unpacks `krate`, runs the suite over it, and counts findings per lint.
+pub(crate) fn lint_corpus_crate(
+    krate: &CorpusCrate,
+    library: &SuiteLibrary,
+) -> anyhow::Result<FindingCounts> {
+    let workspace = TempDir::new().context("failed to create the corpus workspace")?;
+    let package = unpack(krate, workspace.path())?;
+
+    let output = library
+        .dylint_command()
+        .args(["--message-format", "json"])
+        .current_dir(&package)
+        .env("CARGO_TARGET_DIR", workspace.path().join("target"))
+        .output()
+        .context("failed to execute cargo dylint")?;
+    anyhow::ensure!(
+        output.status.success(),
+        "cargo dylint failed for `{}`: {}",
+        krate.package_dir_name(),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    count_findings(&output.stdout)
+}
+
+/// Unpacks the verified tarball and prepares it to build on its own.
+fn unpack(krate: &CorpusCrate, destination: &Path) -> anyhow::Result<PathBuf> {
+    let archive = krate.read_verified_archive()?;
+    tar::Archive::new(GzDecoder::new(archive.as_slice()))
+        .unpack(destination)
+        .with_context(|| format!("failed to unpack `{}`", krate.package_dir_name()))?;
+
+    let package = destination.join(krate.package_dir_name());
+    let manifest_path = package.join("Cargo.toml");
+    let mut manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+    manifest.push_str("\n[workspace]\n");
+    fs::write(&manifest_path, manifest)
+        .with_context(|| format!("failed to write {}", manifest_path.display()))?;
+
+    pin_toolchain(&package)?;
+    Ok(package)
+}
+
+fn count_findings(stdout: &[u8]) -> anyhow::Result<FindingCounts> {
+    let mut counts = FindingCounts::new();
+    for message in Message::parse_stream(Cursor::new(stdout)) {
+        let message = message.context("failed to parse cargo dylint JSON output")?;
+        let Message::CompilerMessage(message) = message else {
+            continue;
+        };
+        let Some(code) = message.message.code else {
+            continue;
+        };
+        if suite_lint_names().any(|name| name == code.code) {
+            *counts.entry(code.code).or_default() += 1;
+        }
+    }
+    Ok(counts)
+}
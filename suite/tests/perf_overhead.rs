@@ -0,0 +1,92 @@
+//! End-to-end timing of the suite's overhead on a medium package.
+//!
+//! The harness generates a package of about four thousand lines (see
+//! `perf_overhead/fixture.rs`), then times `cargo check` and `cargo dylint`
+//! with the suite over it, each from a fresh target directory. It fails when
+//! the median `cargo dylint` run takes more than `MAX_OVERHEAD_RATIO` times
+//! as long as the median `cargo check`, so a lint that adds a costly pass is
+//! caught before release.
+//!
+//! # Prerequisites
+//!
+//! - `cargo-dylint` and `dylint-link` must be installed
+//!
+//! The test is marked `#[ignore]`; run it with `make perf`.
+#![cfg_attr(feature = "dylint-driver", feature(rustc_private))]
+
+#[path = "perf_overhead/fixture.rs"]
+mod fixture;
+#[path = "support/suite_library.rs"]
+mod suite_library;
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use anyhow::Context as _;
+use tempfile::TempDir;
+
+/// Timed runs of each command; the medians are compared.
+const SAMPLES: usize = 3;
+
+/// Longest accepted `cargo dylint` time as a multiple of `cargo check`.
+const MAX_OVERHEAD_RATIO: f64 = 3.0;
+
+#[test]
+#[ignore = "requires cargo-dylint; run with `make perf`"]
+fn suite_overhead_stays_within_budget() -> anyhow::Result<()> {
+    let library = suite_library::build_suite_library().context("failed to build the suite")?;
+    let workspace = TempDir::new().context("failed to create the fixture workspace")?;
+    let package = fixture::write_package(workspace.path())?;
+    let target = |label: &str, sample: usize| workspace.path().join(format!("{label}-{sample}"));
+
+    let check = median_time(|sample| {
+        let mut command = Command::new("cargo");
+        command.arg("check");
+        run(command, &package, &target("check", sample))
+    })?;
+    let dylint = median_time(|sample| {
+        run(
+            library.dylint_command(),
+            &package,
+            &target("dylint", sample),
+        )
+    })?;
+
+    let ratio = dylint.as_secs_f64() / check.as_secs_f64();
+    assert!(
+        ratio <= MAX_OVERHEAD_RATIO,
+        "`cargo dylint` took {dylint:?} against {check:?} for `cargo check`, \
+         {ratio:.2} times as long; the budget is {MAX_OVERHEAD_RATIO}"
+    );
+    Ok(())
+}
+
+/// Runs `routine` once to warm caches, then returns the median of
+/// `SAMPLES` timed runs. Each run receives its sample number.
+fn median_time(mut routine: impl FnMut(usize) -> anyhow::Result<()>) -> anyhow::Result<Duration> {
+    routine(0)?;
+    let mut timings = Vec::with_capacity(SAMPLES);
+    for sample in 1..=SAMPLES {
+        let start = Instant::now();
+        routine(sample)?;
+        timings.push(start.elapsed());
+    }
+    timings.sort_unstable();
+    Ok(timings[SAMPLES / 2])
+}
+
+fn run(mut command: Command, package: &Path, target_dir: &Path) -> anyhow::Result<()> {
+    let output = command
+        .arg("--quiet")
+        .current_dir(package)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .output()
+        .context("failed to execute cargo")?;
+    anyhow::ensure!(
+        output.status.success(),
+        "{command:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Generation of the medium fixture package.
+//!
+//! The package has no dependencies, so it builds offline, and its modules
+//! mix the constructs the suite inspects: documented items, inherent
+//! methods, branching, closures in iterator chains, `match` expressions, and
+//! unit tests. It comes to roughly four thousand lines.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context as _;
+
+use crate::suite_library::pin_toolchain;
+
+/// Number of generated modules.
+const MODULES: usize = 40;
+/// Number of free functions in each module.
+const FUNCTIONS_PER_MODULE: usize = 6;
+
+const MANIFEST: &str = r#"[package]
+name = "perf_fixture"
+version = "0.1.0"
+edition = "2024"
+publish = false
+
+[workspace]
+"#;
+
+/// Writes the fixture package under `root` and returns its directory.
+pub(crate) fn write_package(root: &Path) -> anyhow::Result<PathBuf> {
+    let package = root.join("perf_fixture");
+    let source = package.join("src");
+    fs::create_dir_all(&source)
+        .with_context(|| format!("failed to create {}", source.display()))?;
+    write(&package.join("Cargo.toml"), MANIFEST)?;
+    pin_toolchain(&package)?;
+
+    let mut lib = String::from("//! Medium fixture for measuring lint overhead.\n\n");
+    for module in 0..MODULES {
+        lib.push_str(&format!("pub mod module_{module};\n"));
+        write(
+            &source.join(format!("module_{module}.rs")),
+            &module_source(module),
+        )?;
+    }
+    write(&source.join("lib.rs"), &lib)?;
+    Ok(package)
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+fn module_source(module: usize) -> String {
+    let mut source = format!(
+        r#"//! Generated module {module}.
+
+/// A record handled by module {module}.
+#[derive(Clone, Debug, Default)]
+pub struct Record{module} {{
+    /// Identifier.
+    pub id: u64,
+    /// Display name.
+    pub name: String,
+    /// Scores gathered for the record.
+    pub scores: Vec<i64>,
+}}
+
+impl Record{module} {{
+    /// Returns the sum of the scores.
+    #[must_use]
+    pub fn total(&self) -> i64 {{
+        self.scores.iter().sum()
+    }}
+
+    /// Classifies the record by its total.
+    #[must_use]
+    pub fn classify(&self) -> &'static str {{
+        let total = self.total();
+        if total > 100 && self.id > 10 {{
+            "high"
+        }} else if total > 10 {{
+            "medium"
+        }} else {{
+            "low"
+        }}
+    }}
+
+    /// Returns a copy with a new name.
+    #[must_use]
+    pub fn renamed(&self, name: &str) -> Self {{
+        Self {{
+            name: name.to_owned(),
+            ..self.clone()
+        }}
+    }}
+}}
+"#
+    );
+    for function in 0..FUNCTIONS_PER_MODULE {
+        source.push_str(&format!(
+            r#"
+/// Describes the records scoring above `threshold`.
+#[must_use]
+pub fn summarise_{function}(records: &[Record{module}], threshold: i64) -> Vec<String> {{
+    records
+        .iter()
+        .filter(|record| record.total() > threshold + {function})
+        .map(|record| match record.classify() {{
+            "high" => format!("{{}} stands out", record.name),
+            other => format!("{{}} is {{other}}", record.name),
+        }})
+        .collect()
+}}
+"#
+        ));
+    }
+    source.push_str(&format!(
+        r#"
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn empty_records_are_low() {{
+        assert_eq!(Record{module}::default().classify(), "low");
+    }}
+
+    #[test]
+    fn summaries_skip_low_scores() {{
+        let record = Record{module}::default().renamed("quiet");
+        assert!(summarise_0(&[record], 0).is_empty());
+    }}
+}}
+"#
+    ));
+    source
+}
@@ -6,8 +6,16 @@ use rstest::fixture;
 use rstest_bdd_macros::{given, scenario, then, when};
 use rustc_lint::LintStore;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::panic::{AssertUnwindSafe, catch_unwind};
-use whitaker_suite::{register_suite_lints, suite_lint_decls, suite_lint_names};
+use whitaker::report::{Finding, Location, Report};
+use whitaker_installer::builder::CrateName;
+use whitaker_installer::list_output::format_human_with_docs;
+use whitaker_installer::scanner::{InstalledLibrary, InstalledLints};
+use whitaker_suite::{
+    SUITE_LINT_DOCS, register_suite_lints, suite_lint_decisions, suite_lint_decls, suite_lint_docs,
+    suite_lint_names,
+};
 
 struct RegistrationWorld {
     store: RefCell<LintStore>,
@@ -99,6 +107,74 @@ fn then_decls_align() {
     assert_eq!(declared, expected);
 }
 
+#[then("every suite lint has embedded documentation")]
+fn then_docs_cover_suite() {
+    for name in suite_lint_names() {
+        let Some(docs) = suite_lint_docs(name) else {
+            panic!("`{name}` has no embedded documentation");
+        };
+        assert!(!docs.summary.is_empty(), "`{name}` needs a summary");
+        assert!(!docs.rationale.is_empty(), "`{name}` needs a rationale");
+        assert!(!docs.bad_example.is_empty() && !docs.good_example.is_empty());
+        assert_eq!(docs.version, env!("CARGO_PKG_VERSION"));
+    }
+}
+
+#[then("reports and listings describe the suite lints from their documentation")]
+fn then_docs_reach_consumers() {
+    let docs = suite_lint_docs("module_max_lines").expect("module_max_lines is a suite lint");
+    let report = Report::new(vec![Finding::new(
+        "module_max_lines",
+        Location::new("src/lib.rs", 1, 1),
+        "Module lib spans 900 lines",
+    )]);
+    let page = report.to_html_with_docs(SUITE_LINT_DOCS);
+    assert!(page.contains(&format!(
+        "<code>module_max_lines</code> ({})</h3>",
+        docs.version
+    )));
+
+    let toolchain = String::from("nightly-2026-05-28");
+    let library = InstalledLibrary {
+        crate_name: CrateName::from("whitaker_suite"),
+        toolchain: toolchain.clone(),
+        path: "/lints/libwhitaker_suite@nightly-2026-05-28.so".into(),
+    };
+    let installed = InstalledLints {
+        by_toolchain: BTreeMap::from([(toolchain, vec![library])]),
+    };
+    let listing = format_human_with_docs(&installed, None, SUITE_LINT_DOCS);
+    assert!(listing.contains(&format!("- module_max_lines: {}\n", docs.summary)));
+}
+
+/// Suite lints without a decision, because they emit no findings yet.
+const UNDECIDED_LINTS: &[&str] = &["rstest_helper_should_be_fixture"];
+
+#[then("every suite lint decision conforms to its fixtures")]
+fn then_decisions_conform() {
+    let decisions = suite_lint_decisions();
+    let decided: Vec<&str> = decisions
+        .iter()
+        .map(|decision| decision.lint_name())
+        .collect();
+    for name in suite_lint_names().filter(|name| !UNDECIDED_LINTS.contains(name)) {
+        assert!(
+            decided.contains(&name),
+            "`{name}` has no registered decision"
+        );
+    }
+    for decision in decisions {
+        let name = decision.lint_name();
+        assert!(
+            suite_lint_names().any(|suite_name| suite_name == name),
+            "`{name}` is not a suite lint"
+        );
+        if let Err(error) = decision.check() {
+            panic!("{error}");
+        }
+    }
+}
+
 #[then("registration fails with a duplicate lint error")]
 fn then_registration_fails(world: &RegistrationWorld) {
     assert!(world.result.borrow().is_err(), "registration should fail");
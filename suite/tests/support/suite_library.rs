@@ -0,0 +1,109 @@
+//! Building the suite library and invoking `cargo dylint` with it.
+//!
+//! The suite is built once as a cdylib and staged under the
+//! toolchain-qualified name Dylint expects, so integration tests can run it
+//! over arbitrary packages without installing it.
+
+use std::env;
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::Context as _;
+use cargo_metadata::Message;
+use tempfile::TempDir;
+
+pub(crate) const SUITE_CRATE_NAME: &str = "whitaker_suite";
+
+/// A suite library staged for Dylint discovery.
+pub(crate) struct SuiteLibrary {
+    directory: TempDir,
+}
+
+impl SuiteLibrary {
+    /// Returns a `cargo dylint` command that loads only the suite.
+    ///
+    /// Arguments added by the caller are passed through to Cargo.
+    pub(crate) fn dylint_command(&self) -> Command {
+        let mut command = Command::new("cargo");
+        command
+            .args(["dylint", "--lib", SUITE_CRATE_NAME, "--"])
+            .env("DYLINT_LIBRARY_PATH", self.directory.path());
+        command
+    }
+}
+
+/// Builds the suite cdylib and stages a toolchain-qualified copy.
+pub(crate) fn build_suite_library() -> anyhow::Result<SuiteLibrary> {
+    let output = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+        .args(["build", "--lib", "--quiet", "--message-format=json"])
+        .args(["--package", SUITE_CRATE_NAME, "--features", "dylint-driver"])
+        .current_dir(workspace_root()?)
+        .output()
+        .context("failed to execute cargo build")?;
+    anyhow::ensure!(
+        output.status.success(),
+        "suite library build failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let cdylib = find_cdylib(&output.stdout)?;
+    let directory = TempDir::new().context("failed to create the library directory")?;
+    let staged = directory.path().join(toolchain_qualified_name()?);
+    fs::copy(&cdylib, &staged)
+        .with_context(|| format!("failed to stage the suite library at {}", staged.display()))?;
+    Ok(SuiteLibrary { directory })
+}
+
+/// Returns the root of the Whitaker workspace.
+pub(crate) fn workspace_root() -> anyhow::Result<&'static Path> {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .context("the suite crate should live inside the workspace")
+}
+
+/// Pins `package` to the workspace toolchain, so the staged library loads.
+pub(crate) fn pin_toolchain(package: &Path) -> anyhow::Result<()> {
+    fs::copy(
+        workspace_root()?.join("rust-toolchain.toml"),
+        package.join("rust-toolchain.toml"),
+    )
+    .with_context(|| {
+        format!(
+            "failed to pin {} to the workspace toolchain",
+            package.display()
+        )
+    })?;
+    Ok(())
+}
+
+fn find_cdylib(stdout: &[u8]) -> anyhow::Result<PathBuf> {
+    for message in Message::parse_stream(Cursor::new(stdout)) {
+        let message = message.context("failed to parse cargo build JSON output")?;
+        let Message::CompilerArtifact(artefact) = message else {
+            continue;
+        };
+        if artefact.target.name != SUITE_CRATE_NAME || !artefact.target.is_cdylib() {
+            continue;
+        }
+        if let Some(path) = artefact
+            .filenames
+            .iter()
+            .find(|candidate| candidate.as_str().ends_with(env::consts::DLL_SUFFIX))
+        {
+            return Ok(path.clone().into_std_path_buf());
+        }
+    }
+    anyhow::bail!("cdylib artefact for `{SUITE_CRATE_NAME}` not found in build output")
+}
+
+fn toolchain_qualified_name() -> anyhow::Result<String> {
+    let toolchain = env::var("RUSTUP_TOOLCHAIN")
+        .context("RUSTUP_TOOLCHAIN should name the toolchain; run the tests through rustup")?;
+    Ok(format!(
+        "{}{SUITE_CRATE_NAME}@{toolchain}{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ))
+}